@@ -0,0 +1,37 @@
+//! Stamps the binary with what `GET /api/meta/version` reports.
+//!
+//! `GIT_SHA` comes from the environment when set (Docker builds have no
+//! `.git`), otherwise from `git rev-parse HEAD`. `BUILD_TIME` is Unix
+//! seconds, taken from `SOURCE_DATE_EPOCH` for reproducible builds.
+
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn main() {
+    let git_sha = std::env::var("GIT_SHA")
+        .ok()
+        .filter(|sha| !sha.trim().is_empty())
+        .or_else(|| {
+            Command::new("git")
+                .args(["rev-parse", "HEAD"])
+                .output()
+                .ok()
+                .filter(|out| out.status.success())
+                .and_then(|out| String::from_utf8(out.stdout).ok())
+        })
+        .map(|sha| sha.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let build_time = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.trim().parse::<u64>().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0)
+        });
+
+    println!("cargo:rustc-env=GIT_SHA={git_sha}");
+    println!("cargo:rustc-env=BUILD_TIME={build_time}");
+}
@@ -0,0 +1,180 @@
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use clap::Subcommand;
+use sea_orm::DatabaseConnection;
+use serde::Serialize;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use backend_actix::{
+    admin::{
+        adapter::outgoing::IntegrityQueryPostgres,
+        application::{
+            ports::incoming::use_cases::{CheckIntegrityCommand, CheckIntegrityUseCase},
+            services::CheckIntegrityService,
+        },
+    },
+    auth::{
+        adapter::outgoing::user_query_postgres::UserQueryPostgres,
+        application::{domain::entities::UserId, helpers::UserIdentityResolver},
+    },
+    cv::{
+        adapter::outgoing::CVQueryPostgres,
+        application::ports::outgoing::{CVListFilter, CVPageRequest, CVQuery, CVSort},
+        domain::entities::CVInfo,
+    },
+    multimedia::adapter::outgoing::cloud_storage::GcsObjectInventory,
+    project::{
+        adapter::outgoing::ProjectQueryPostgres,
+        application::ports::outgoing::project_query::{
+            PageRequest, ProjectListFilter, ProjectQuery, ProjectSort, ProjectView,
+        },
+    },
+    shared::field_cipher::FieldCipher,
+};
+
+const PAGE_SIZE: u32 = 100;
+
+#[derive(Subcommand)]
+pub enum ContentCommand {
+    /// Dump a user's CVs and projects as JSON
+    Export {
+        username: String,
+        /// Defaults to stdout
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Report broken references across all content as JSON, like
+    /// `GET /api/admin/integrity`
+    Check {
+        /// Don't list the variant buckets to find missing objects
+        #[arg(long)]
+        skip_storage: bool,
+    },
+}
+
+#[derive(Serialize)]
+struct ContentExport {
+    exported_at: DateTime<Utc>,
+    username: String,
+    cvs: Vec<CVInfo>,
+    projects: Vec<ProjectView>,
+}
+
+pub async fn run(
+    command: ContentCommand,
+    db: Arc<DatabaseConnection>,
+    cipher: FieldCipher,
+) -> anyhow::Result<()> {
+    match command {
+        ContentCommand::Export { username, out } => {
+            let owner =
+                UserIdentityResolver::new(Arc::new(UserQueryPostgres::new(Arc::clone(&db))))
+                    .by_username(&username)
+                    .await?;
+
+            let export = ContentExport {
+                exported_at: Utc::now(),
+                cvs: export_cvs(
+                    &CVQueryPostgres::new(Arc::clone(&db)).with_cipher(cipher),
+                    owner,
+                )
+                .await?,
+                projects: export_projects(&ProjectQueryPostgres::new(db), owner).await?,
+                username,
+            };
+
+            let json = serde_json::to_string_pretty(&export)?;
+            match out {
+                Some(path) => {
+                    std::fs::write(&path, json)
+                        .with_context(|| format!("Failed to write {}", path.display()))?;
+                    eprintln!(
+                        "Exported {} CVs and {} projects to {}",
+                        export.cvs.len(),
+                        export.projects.len(),
+                        path.display()
+                    );
+                }
+                None => println!("{}", json),
+            }
+        }
+        ContentCommand::Check { skip_storage } => {
+            let service = CheckIntegrityService::new(
+                IntegrityQueryPostgres::new(db),
+                GcsObjectInventory::new(),
+            );
+
+            let report = service
+                .execute(CheckIntegrityCommand {
+                    check_storage: !skip_storage,
+                })
+                .await?;
+
+            println!("{}", serde_json::to_string_pretty(&report)?);
+            eprintln!("{} issues found", report.issues.len());
+        }
+    }
+
+    Ok(())
+}
+
+async fn export_cvs(query: &CVQueryPostgres, owner: UserId) -> anyhow::Result<Vec<CVInfo>> {
+    let mut cvs = Vec::new();
+
+    for page in 1.. {
+        let result = query
+            .list(
+                owner.into(),
+                CVListFilter::default(),
+                CVSort::Oldest,
+                CVPageRequest {
+                    page,
+                    per_page: PAGE_SIZE,
+                },
+            )
+            .await?;
+
+        let fetched = result.items.len();
+        cvs.extend(result.items);
+        if fetched < PAGE_SIZE as usize || cvs.len() as u64 >= result.total {
+            break;
+        }
+    }
+
+    Ok(cvs)
+}
+
+/// Listing returns cards; each project is re-read for its full view
+async fn export_projects(
+    query: &ProjectQueryPostgres,
+    owner: UserId,
+) -> anyhow::Result<Vec<ProjectView>> {
+    let mut projects = Vec::new();
+    let mut offset = 0;
+
+    loop {
+        let page = query
+            .list(
+                owner,
+                ProjectListFilter::default(),
+                ProjectSort::Oldest,
+                PageRequest {
+                    offset,
+                    limit: PAGE_SIZE,
+                },
+            )
+            .await?;
+
+        for card in &page.items {
+            projects.push(query.get_by_id(owner, card.id).await?);
+        }
+
+        offset += page.items.len() as u64;
+        if page.items.len() < PAGE_SIZE as usize || offset >= page.total {
+            break;
+        }
+    }
+
+    Ok(projects)
+}
@@ -0,0 +1,274 @@
+use anyhow::Context;
+use clap::Subcommand;
+use sea_orm::{ConnectionTrait, DatabaseConnection, Statement, Value};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use backend_actix::{cv::domain::entities::ContactDetail, shared::field_cipher::FieldCipher};
+
+#[derive(Subcommand)]
+pub enum KeysCommand {
+    /// Rewrite CV contact details, contact messages and storage override
+    /// keys under the current key
+    /// (the first of FIELD_ENCRYPTION_KEYS), encrypting plaintext left from
+    /// before encryption was on
+    ReEncrypt {
+        /// Rows read per query
+        #[arg(long, default_value_t = 200, value_parser = clap::value_parser!(u32).range(1..))]
+        batch_size: u32,
+        /// Only count the rows that would be rewritten
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Default)]
+struct Report {
+    checked: u64,
+    rewritten: u64,
+    /// Edited between our read and write; a second run picks them up
+    changed_meanwhile: u64,
+}
+
+pub async fn run(
+    command: KeysCommand,
+    db: Arc<DatabaseConnection>,
+    cipher: FieldCipher,
+) -> anyhow::Result<()> {
+    match command {
+        KeysCommand::ReEncrypt {
+            batch_size,
+            dry_run,
+        } => {
+            anyhow::ensure!(
+                cipher.is_enabled(),
+                "FIELD_ENCRYPTION_KEYS is not set: there is no key to encrypt with"
+            );
+
+            let cvs = reencrypt_cvs(&db, &cipher, batch_size, dry_run).await?;
+            let messages = reencrypt_messages(&db, &cipher, batch_size, dry_run).await?;
+            let overrides = reencrypt_storage_overrides(&db, &cipher, batch_size, dry_run).await?;
+
+            for (name, report) in [
+                ("CVs", cvs),
+                ("contact messages", messages),
+                ("storage overrides", overrides),
+            ] {
+                println!(
+                    "{name}: checked {}, re-encrypted {}, changed meanwhile {}",
+                    report.checked, report.rewritten, report.changed_meanwhile
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Rows of `columns` after `after`, in id order
+fn batch_stmt(
+    db: &DatabaseConnection,
+    table: &str,
+    columns: &str,
+    after: Option<Uuid>,
+    limit: u32,
+) -> Statement {
+    let (filter, mut values): (&str, Vec<Value>) = match after {
+        Some(after) => ("WHERE id > $2", vec![after.into()]),
+        None => ("", Vec::new()),
+    };
+    values.insert(0, (limit as i64).into());
+
+    Statement::from_sql_and_values(
+        db.get_database_backend(),
+        format!("SELECT id, {columns} FROM {table} {filter} ORDER BY id LIMIT $1"),
+        values,
+    )
+}
+
+async fn reencrypt_cvs(
+    db: &DatabaseConnection,
+    cipher: &FieldCipher,
+    batch_size: u32,
+    dry_run: bool,
+) -> anyhow::Result<Report> {
+    let mut report = Report::default();
+    let mut after = None;
+
+    loop {
+        let rows = db
+            .query_all(batch_stmt(
+                db,
+                "resumes",
+                "user_id, contact_info",
+                after,
+                batch_size,
+            ))
+            .await?;
+
+        for row in &rows {
+            let id: Uuid = row.try_get("", "id")?;
+            let owner: Uuid = row.try_get("", "user_id")?;
+            let stored: serde_json::Value = row.try_get("", "contact_info")?;
+            let mut contacts: Vec<ContactDetail> = serde_json::from_value(stored.clone())
+                .with_context(|| format!("CV {id}: unreadable contact_info"))?;
+            after = Some(id);
+            report.checked += 1;
+
+            let mut changed = false;
+            for contact in &mut contacts {
+                if let Some(content) = cipher
+                    .reencrypt(owner, &contact.content)
+                    .with_context(|| format!("CV {id}"))?
+                {
+                    contact.content = content;
+                    changed = true;
+                }
+            }
+            if !changed {
+                continue;
+            }
+            if dry_run {
+                report.rewritten += 1;
+                continue;
+            }
+
+            // Only if nobody saved the CV since we read it
+            let res = db
+                .execute(Statement::from_sql_and_values(
+                    db.get_database_backend(),
+                    "UPDATE resumes SET contact_info = $1 WHERE id = $2 AND contact_info = $3",
+                    vec![
+                        serde_json::to_value(&contacts)?.into(),
+                        id.into(),
+                        stored.into(),
+                    ],
+                ))
+                .await?;
+            if res.rows_affected() == 0 {
+                report.changed_meanwhile += 1;
+            } else {
+                report.rewritten += 1;
+            }
+        }
+
+        if rows.len() < batch_size as usize {
+            return Ok(report);
+        }
+    }
+}
+
+async fn reencrypt_messages(
+    db: &DatabaseConnection,
+    cipher: &FieldCipher,
+    batch_size: u32,
+    dry_run: bool,
+) -> anyhow::Result<Report> {
+    let mut report = Report::default();
+    let mut after = None;
+
+    loop {
+        let rows = db
+            .query_all(batch_stmt(
+                db,
+                "contact_messages",
+                "message",
+                after,
+                batch_size,
+            ))
+            .await?;
+
+        for row in &rows {
+            let id: Uuid = row.try_get("", "id")?;
+            let stored: String = row.try_get("", "message")?;
+            after = Some(id);
+            report.checked += 1;
+
+            let Some(message) = cipher
+                .reencrypt(FieldCipher::SITE, &stored)
+                .with_context(|| format!("contact message {id}"))?
+            else {
+                continue;
+            };
+            if dry_run {
+                report.rewritten += 1;
+                continue;
+            }
+
+            let res = db
+                .execute(Statement::from_sql_and_values(
+                    db.get_database_backend(),
+                    "UPDATE contact_messages SET message = $1 WHERE id = $2 AND message = $3",
+                    vec![message.into(), id.into(), stored.into()],
+                ))
+                .await?;
+            if res.rows_affected() == 0 {
+                report.changed_meanwhile += 1;
+            } else {
+                report.rewritten += 1;
+            }
+        }
+
+        if rows.len() < batch_size as usize {
+            return Ok(report);
+        }
+    }
+}
+
+async fn reencrypt_storage_overrides(
+    db: &DatabaseConnection,
+    cipher: &FieldCipher,
+    batch_size: u32,
+    dry_run: bool,
+) -> anyhow::Result<Report> {
+    let mut report = Report::default();
+    let mut after = None;
+
+    loop {
+        let rows = db
+            .query_all(batch_stmt(
+                db,
+                "storage_overrides",
+                "owner_id, credentials",
+                after,
+                batch_size,
+            ))
+            .await?;
+
+        for row in &rows {
+            let id: Uuid = row.try_get("", "id")?;
+            let owner: Uuid = row.try_get("", "owner_id")?;
+            let stored: String = row.try_get("", "credentials")?;
+            after = Some(id);
+            report.checked += 1;
+
+            let Some(credentials) = cipher
+                .reencrypt(owner, &stored)
+                .with_context(|| format!("storage override {id}"))?
+            else {
+                continue;
+            };
+            if dry_run {
+                report.rewritten += 1;
+                continue;
+            }
+
+            let res = db
+                .execute(Statement::from_sql_and_values(
+                    db.get_database_backend(),
+                    "UPDATE storage_overrides SET credentials = $1 WHERE id = $2 AND credentials = $3",
+                    vec![credentials.into(), id.into(), stored.into()],
+                ))
+                .await?;
+            if res.rows_affected() == 0 {
+                report.changed_meanwhile += 1;
+            } else {
+                report.rewritten += 1;
+            }
+        }
+
+        if rows.len() < batch_size as usize {
+            return Ok(report);
+        }
+    }
+}
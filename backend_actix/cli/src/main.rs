@@ -0,0 +1,102 @@
+//! Admin CLI: account, media and content maintenance straight against the
+//! database, through the same adapters and use cases as the HTTP server.
+
+mod content;
+mod keys;
+mod media;
+mod user;
+
+use anyhow::Context;
+use backend_actix::shared::field_cipher::{self, FieldCipher};
+use clap::{Parser, Subcommand};
+use sea_orm::{ConnectOptions, Database, DatabaseConnection};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(name = "cli", about = "Admin tasks for the blog CMS backend")]
+struct Cli {
+    /// Database connection string: Postgres, or SQLite when built with `--features sqlite`
+    #[arg(long, env = "DATABASE_URL", hide_env_values = true)]
+    database_url: String,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create, verify and reset user accounts
+    #[command(subcommand)]
+    User(user::UserCommand),
+
+    /// Media maintenance
+    #[command(subcommand)]
+    Media(media::MediaCommand),
+
+    /// Content backups and integrity checks
+    #[command(subcommand)]
+    Content(content::ContentCommand),
+
+    /// Field encryption keys (FIELD_ENCRYPTION_KEYS)
+    #[command(subcommand)]
+    Keys(keys::KeysCommand),
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    // Same lookup as the server: .env.{RUST_ENV}, then .env. Runs before
+    // parsing so `DATABASE_URL` can come from the file.
+    let env = std::env::var("RUST_ENV").unwrap_or_else(|_| "development".to_string());
+    if dotenvy::from_filename(format!(".env.{}", env)).is_err() {
+        dotenvy::dotenv().ok();
+    }
+
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| "warn".into()),
+        )
+        .with_writer(std::io::stderr)
+        .init();
+
+    rustls::crypto::ring::default_provider()
+        .install_default()
+        .expect("Failed to install rustls crypto provider");
+
+    let cli = Cli::parse();
+    let db = connect(&cli.database_url).await?;
+
+    match cli.command {
+        Command::User(command) => user::run(command, db).await,
+        Command::Media(command) => media::run(command, db).await,
+        Command::Content(command) => content::run(command, db, field_cipher()?).await,
+        Command::Keys(command) => keys::run(command, db, field_cipher()?).await,
+    }
+}
+
+/// Reads `FIELD_ENCRYPTION_KEYS` like the server does
+fn field_cipher() -> anyhow::Result<FieldCipher> {
+    let keys = match std::env::var("FIELD_ENCRYPTION_KEYS") {
+        Ok(raw) => field_cipher::parse_keys(&raw)
+            .map_err(anyhow::Error::msg)
+            .context("Invalid FIELD_ENCRYPTION_KEYS")?,
+        Err(_) => Vec::new(),
+    };
+
+    Ok(FieldCipher::new(keys))
+}
+
+async fn connect(database_url: &str) -> anyhow::Result<Arc<DatabaseConnection>> {
+    let mut opt = ConnectOptions::new(database_url.to_string());
+    // Same PgBouncer constraint as the server
+    opt.map_sqlx_postgres_opts(|pg| pg.statement_cache_capacity(0));
+    opt.max_connections(2)
+        .connect_timeout(Duration::from_secs(30))
+        .sqlx_logging(false);
+
+    let conn = Database::connect(opt)
+        .await
+        .context("Failed to connect to the database")?;
+
+    Ok(Arc::new(conn))
+}
@@ -0,0 +1,155 @@
+use clap::Subcommand;
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+
+use backend_actix::multimedia::{
+    adapter::outgoing::{
+        cloud_storage::{GcsObjectInventory, GcsStorageQuery, HttpMediaTransfer},
+        db::{LegacyScreenshotStorePostgres, MediaQueryPostgres, MediaRepositoryPostgres},
+    },
+    application::{
+        domain::policies::upload_policy::UploadPolicy,
+        ports::incoming::{
+            services::{
+                BackfillScreenshotsService, CollectOrphanObjectsService, ReconcileMediaService,
+            },
+            use_cases::{
+                BackfillScreenshotsCommand, BackfillScreenshotsUseCase,
+                CollectOrphanObjectsCommand, CollectOrphanObjectsUseCase, ReconcileMediaCommand,
+                ReconcileMediaUseCase,
+            },
+        },
+    },
+};
+
+#[derive(Subcommand)]
+pub enum MediaCommand {
+    /// Settle uploads stuck in `pending`/`processing` from the processor's manifests
+    Reconcile {
+        /// Skip media updated more recently than this
+        #[arg(long, default_value_t = 30)]
+        older_than_mins: i64,
+        #[arg(long, default_value_t = 500)]
+        limit: u64,
+        /// Fail media still `processing` this long after its last update
+        #[arg(long, default_value_t = 60)]
+        processing_timeout_mins: i64,
+    },
+    /// Import the URLs of projects' legacy `screenshots` column as project media
+    BackfillScreenshots {
+        #[arg(long, default_value_t = 500)]
+        limit: u64,
+        /// Try URLs that failed on an earlier run again
+        #[arg(long)]
+        retry_failed: bool,
+        /// Only count the URLs that would be imported
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Delete stored objects whose media no longer exists, or never did
+    Gc {
+        /// Skip objects created more recently than this
+        #[arg(long, default_value_t = 24)]
+        grace_hours: i64,
+        /// Only count the objects that would be deleted
+        #[arg(long)]
+        dry_run: bool,
+        #[arg(
+            long,
+            env = "MULTIMEDIA_UPLOAD_BUCKET",
+            default_value = "blogport-cms-upload"
+        )]
+        upload_bucket: String,
+        #[arg(
+            long,
+            env = "MULTIMEDIA_READY_BUCKET",
+            default_value = "blogport-cms-ready"
+        )]
+        ready_bucket: String,
+    },
+}
+
+pub async fn run(command: MediaCommand, db: Arc<DatabaseConnection>) -> anyhow::Result<()> {
+    match command {
+        MediaCommand::Reconcile {
+            older_than_mins,
+            limit,
+            processing_timeout_mins,
+        } => {
+            let service = ReconcileMediaService::new(
+                MediaQueryPostgres::new(Arc::clone(&db)),
+                GcsStorageQuery::new(),
+                MediaRepositoryPostgres::new(db),
+            );
+
+            let report = service
+                .execute(ReconcileMediaCommand {
+                    older_than: chrono::Duration::minutes(older_than_mins),
+                    limit,
+                    processing_timeout: chrono::Duration::minutes(processing_timeout_mins),
+                })
+                .await?;
+
+            println!(
+                "checked {}, updated {}, missing manifest {}, timed out {}, errors {}",
+                report.checked,
+                report.updated,
+                report.missing_manifest,
+                report.timed_out,
+                report.errors
+            );
+        }
+        MediaCommand::BackfillScreenshots {
+            limit,
+            retry_failed,
+            dry_run,
+        } => {
+            let service = BackfillScreenshotsService::new(
+                LegacyScreenshotStorePostgres::new(Arc::clone(&db)),
+                MediaRepositoryPostgres::new(db),
+                GcsStorageQuery::new(),
+                HttpMediaTransfer::new()?,
+                UploadPolicy::from_env(),
+            );
+
+            let report = service
+                .execute(BackfillScreenshotsCommand {
+                    limit,
+                    retry_failed,
+                    dry_run,
+                })
+                .await?;
+
+            println!(
+                "checked {}, migrated {}, failed {}",
+                report.checked, report.migrated, report.failed
+            );
+        }
+        MediaCommand::Gc {
+            grace_hours,
+            dry_run,
+            upload_bucket,
+            ready_bucket,
+        } => {
+            let service = CollectOrphanObjectsService::new(
+                MediaQueryPostgres::new(db),
+                GcsObjectInventory::new(),
+                vec![upload_bucket, ready_bucket],
+            );
+
+            let report = service
+                .execute(CollectOrphanObjectsCommand {
+                    grace: chrono::Duration::hours(grace_hours),
+                    dry_run,
+                })
+                .await?;
+
+            println!(
+                "scanned {}, orphaned {}, deleted {}, unrecognized {}, errors {}",
+                report.scanned, report.orphaned, report.deleted, report.unrecognized, report.errors
+            );
+        }
+    }
+
+    Ok(())
+}
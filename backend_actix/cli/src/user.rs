@@ -0,0 +1,140 @@
+use anyhow::{anyhow, bail, Context};
+use clap::Subcommand;
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+
+use backend_actix::auth::{
+    adapter::outgoing::{
+        security::argon2_hasher::Argon2Hasher, user_query_postgres::UserQueryPostgres,
+        user_repository_postgres::UserRepositoryPostgres,
+    },
+    application::{
+        ports::{
+            incoming::password_policy::PasswordPolicy,
+            outgoing::{
+                password_hasher::PasswordHasher,
+                user_query::{UserQuery, UserQueryResult},
+                user_repository::UserRepository,
+            },
+        },
+        services::password::BasicPasswordPolicy,
+        use_cases::create_user::{CreateUserInput, CreateUserUseCase, ICreateUserUseCase},
+    },
+};
+
+#[derive(Subcommand)]
+pub enum UserCommand {
+    /// Create an account. The verification email is queued as for a signup.
+    Create {
+        #[arg(long)]
+        username: String,
+        #[arg(long)]
+        email: String,
+        #[arg(long)]
+        full_name: String,
+        /// Prefer the env var: flags end up in shell history
+        #[arg(long, env = "CLI_USER_PASSWORD", hide_env_values = true)]
+        password: String,
+        /// Mark the email as verified right away
+        #[arg(long)]
+        verified: bool,
+    },
+
+    /// Mark an account's email as verified
+    Verify {
+        /// Username or email
+        login: String,
+    },
+
+    /// Replace an account's password
+    ResetPassword {
+        /// Username or email
+        login: String,
+        #[arg(long, env = "CLI_USER_PASSWORD", hide_env_values = true)]
+        password: String,
+    },
+}
+
+pub async fn run(command: UserCommand, db: Arc<DatabaseConnection>) -> anyhow::Result<()> {
+    let user_query = UserQueryPostgres::new(Arc::clone(&db));
+    let user_repo = UserRepositoryPostgres::new(Arc::clone(&db));
+
+    match command {
+        UserCommand::Create {
+            username,
+            email,
+            full_name,
+            password,
+            verified,
+        } => {
+            let create_user = CreateUserUseCase::new(user_query, user_repo.clone(), hasher());
+            let user = create_user
+                .execute(CreateUserInput {
+                    username,
+                    email,
+                    password,
+                    full_name,
+                    preferred_locale: None,
+                })
+                .await?;
+
+            if verified {
+                user_repo.activate_user(user.user_id).await?;
+            }
+
+            println!("Created user {} ({})", user.username, user.user_id);
+        }
+
+        UserCommand::Verify { login } => {
+            let user = find_user(&user_query, &login).await?;
+            if user.is_verified {
+                println!("{} is already verified", user.username);
+                return Ok(());
+            }
+
+            user_repo.activate_user(user.id).await?;
+            println!("Verified {}", user.username);
+        }
+
+        UserCommand::ResetPassword { login, password } => {
+            BasicPasswordPolicy
+                .validate(&password)
+                .map_err(|e| anyhow!("Password rejected: {:?}", e))?;
+
+            let user = find_user(&user_query, &login).await?;
+            let hash = hasher()
+                .hash_password(&password)
+                .await
+                .context("Failed to hash password")?;
+
+            user_repo.update_password(user.id, hash).await?;
+            println!("Password updated for {}", user.username);
+        }
+    }
+
+    Ok(())
+}
+
+/// Same cost parameters as the server for this `RUST_ENV`
+fn hasher() -> Arc<dyn PasswordHasher> {
+    let production = std::env::var("RUST_ENV").is_ok_and(|env| env == "production");
+    if production {
+        Arc::new(Argon2Hasher::budget_vps())
+    } else {
+        Arc::new(Argon2Hasher::fast_env())
+    }
+}
+
+async fn find_user(query: &UserQueryPostgres, login: &str) -> anyhow::Result<UserQueryResult> {
+    let login = login.trim().to_lowercase();
+    let user = if login.contains('@') {
+        query.find_by_email(&login).await?
+    } else {
+        query.find_by_username(&login).await?
+    };
+
+    match user {
+        Some(user) if !user.is_deleted => Ok(user),
+        _ => bail!("No active user matches '{}'", login),
+    }
+}
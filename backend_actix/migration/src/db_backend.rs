@@ -0,0 +1,86 @@
+//! # Database Backends
+//!
+//! Postgres is the reference schema. With the `sqlite` feature the same
+//! migrations build the closest SQLite equivalent:
+//! - UUIDs are 16-byte blobs and timestamps RFC 3339 text, the shapes sqlx
+//!   writes, so database defaults and application values compare correctly
+//! - the `media_status` enum and `TEXT[]` columns become plain text
+//! - triggers are rewritten in SQLite's syntax; there are no stored functions
+//! - GIN indexes are skipped
+
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+
+/// Current time in the text form sqlx uses for chrono timestamps on SQLite.
+/// `CURRENT_TIMESTAMP` has no `T` or offset and would sort before them.
+pub(crate) const SQLITE_NOW: &str = "strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')";
+
+pub(crate) fn is_sqlite(manager: &SchemaManager) -> bool {
+    manager.get_database_backend() == DatabaseBackend::Sqlite
+}
+
+/// Default for UUID primary keys
+pub(crate) fn uuid_default(manager: &SchemaManager) -> SimpleExpr {
+    if is_sqlite(manager) {
+        Expr::cust("(randomblob(16))")
+    } else {
+        Expr::cust("gen_random_uuid()")
+    }
+}
+
+/// Default for timestamp columns
+pub(crate) fn now_default(manager: &SchemaManager) -> SimpleExpr {
+    if is_sqlite(manager) {
+        Expr::cust(format!("({SQLITE_NOW})"))
+    } else {
+        Expr::current_timestamp().into()
+    }
+}
+
+/// `update_<table>_updated_at`, keeping `updated_at` current on every
+/// update. On Postgres it calls `update_updated_at_column()`, created by the
+/// users migration. SQLite triggers can't assign to `NEW`, so there the row is
+/// updated again afterwards, unless the statement already set `updated_at`.
+pub(crate) fn updated_at_trigger(manager: &SchemaManager, table: &str) -> String {
+    if is_sqlite(manager) {
+        format!(
+            r#"
+            CREATE TRIGGER update_{table}_updated_at
+            AFTER UPDATE ON {table}
+            FOR EACH ROW
+            WHEN NEW.updated_at IS OLD.updated_at
+            BEGIN
+                UPDATE {table} SET updated_at = {SQLITE_NOW} WHERE id = NEW.id;
+            END;
+            "#
+        )
+    } else {
+        format!(
+            r#"
+            CREATE TRIGGER update_{table}_updated_at
+            BEFORE UPDATE ON {table}
+            FOR EACH ROW
+            EXECUTE FUNCTION update_updated_at_column();
+            "#
+        )
+    }
+}
+
+/// `DROP TRIGGER` in the backend's syntax; only Postgres names the table
+pub(crate) fn drop_trigger(manager: &SchemaManager, trigger: &str, table: &str) -> String {
+    if is_sqlite(manager) {
+        format!("DROP TRIGGER IF EXISTS {trigger};")
+    } else {
+        format!("DROP TRIGGER IF EXISTS {trigger} ON {table};")
+    }
+}
+
+/// A UUID blob as the hyphenated text Postgres renders, for JSON payloads
+/// built inside SQLite triggers
+pub(crate) fn sqlite_uuid_text(column: &str) -> String {
+    format!(
+        "lower(substr(hex({column}), 1, 8) || '-' || substr(hex({column}), 9, 4) || '-' \
+         || substr(hex({column}), 13, 4) || '-' || substr(hex({column}), 17, 4) || '-' \
+         || substr(hex({column}), 21))"
+    )
+}
@@ -1,5 +1,6 @@
 pub use sea_orm_migration::prelude::*;
 
+mod db_backend;
 mod m20210304_000001_create_users_table;
 mod m20220101_000010_create_resume_table;
 mod m20260127_144214_create_table_topics;
@@ -8,6 +9,41 @@ mod m20260127_144248_create_table_project_topics;
 mod m20260202_230522_create_table_media;
 mod m20260202_231146_create_table_media_attachments;
 mod m20260202_231525_create_table_media_variants;
+mod m20261016_000001_add_checksum_to_media_variants;
+mod m20261016_000002_create_table_email_outbox;
+mod m20261016_000003_add_deleted_at_for_trash;
+mod m20261016_000004_create_table_webhooks;
+mod m20261016_000005_add_listing_indexes;
+mod m20261016_000006_add_preferred_locale_to_users;
+mod m20261016_000007_add_email_bounced_at_to_users;
+mod m20261016_000008_create_table_contact_messages;
+mod m20261016_000009_add_email_unsubscribed_at_to_users;
+mod m20261016_000010_create_table_site_settings;
+mod m20261016_000011_create_table_pages;
+mod m20261016_000012_create_table_page_views;
+mod m20261016_000013_create_table_redirects;
+mod m20261016_000014_create_table_activities;
+mod m20261016_000015_create_table_content_translations;
+mod m20261016_000016_add_token_version_to_users;
+mod m20261016_000017_add_is_active_to_users;
+mod m20261016_000018_create_table_consumed_tokens;
+mod m20261016_000019_create_table_project_readme_syncs;
+mod m20261016_000020_create_table_project_screenshot_migrations;
+mod m20261016_000021_add_failure_code_to_media;
+mod m20261016_000022_create_table_webauthn_credentials;
+mod m20261016_000023_add_profile_fields_to_users;
+mod m20261016_000024_add_seo_fields_to_pages_and_projects;
+mod m20261016_000025_add_checksum_to_media;
+mod m20261016_000026_create_table_page_edit_locks;
+mod m20261016_000027_create_table_page_tombstones;
+mod m20261016_000028_create_table_block_rules;
+mod m20261016_000029_create_table_project_updates;
+mod m20261016_000030_create_table_skills;
+mod m20261016_000031_add_autosave_to_pages;
+mod m20261016_000032_create_table_media_retries;
+mod m20261016_000033_create_table_media_upload_batches;
+mod m20261016_000034_create_table_storage_overrides;
+mod m20261016_000035_strip_contact_messages_from_email_outbox;
 
 pub struct Migrator;
 
@@ -23,6 +59,41 @@ impl MigratorTrait for Migrator {
             Box::new(m20260202_230522_create_table_media::Migration),
             Box::new(m20260202_231146_create_table_media_attachments::Migration),
             Box::new(m20260202_231525_create_table_media_variants::Migration),
+            Box::new(m20261016_000001_add_checksum_to_media_variants::Migration),
+            Box::new(m20261016_000002_create_table_email_outbox::Migration),
+            Box::new(m20261016_000003_add_deleted_at_for_trash::Migration),
+            Box::new(m20261016_000004_create_table_webhooks::Migration),
+            Box::new(m20261016_000005_add_listing_indexes::Migration),
+            Box::new(m20261016_000006_add_preferred_locale_to_users::Migration),
+            Box::new(m20261016_000007_add_email_bounced_at_to_users::Migration),
+            Box::new(m20261016_000008_create_table_contact_messages::Migration),
+            Box::new(m20261016_000009_add_email_unsubscribed_at_to_users::Migration),
+            Box::new(m20261016_000010_create_table_site_settings::Migration),
+            Box::new(m20261016_000011_create_table_pages::Migration),
+            Box::new(m20261016_000012_create_table_page_views::Migration),
+            Box::new(m20261016_000013_create_table_redirects::Migration),
+            Box::new(m20261016_000014_create_table_activities::Migration),
+            Box::new(m20261016_000015_create_table_content_translations::Migration),
+            Box::new(m20261016_000016_add_token_version_to_users::Migration),
+            Box::new(m20261016_000017_add_is_active_to_users::Migration),
+            Box::new(m20261016_000018_create_table_consumed_tokens::Migration),
+            Box::new(m20261016_000019_create_table_project_readme_syncs::Migration),
+            Box::new(m20261016_000020_create_table_project_screenshot_migrations::Migration),
+            Box::new(m20261016_000021_add_failure_code_to_media::Migration),
+            Box::new(m20261016_000022_create_table_webauthn_credentials::Migration),
+            Box::new(m20261016_000023_add_profile_fields_to_users::Migration),
+            Box::new(m20261016_000024_add_seo_fields_to_pages_and_projects::Migration),
+            Box::new(m20261016_000025_add_checksum_to_media::Migration),
+            Box::new(m20261016_000026_create_table_page_edit_locks::Migration),
+            Box::new(m20261016_000027_create_table_page_tombstones::Migration),
+            Box::new(m20261016_000028_create_table_block_rules::Migration),
+            Box::new(m20261016_000029_create_table_project_updates::Migration),
+            Box::new(m20261016_000030_create_table_skills::Migration),
+            Box::new(m20261016_000031_add_autosave_to_pages::Migration),
+            Box::new(m20261016_000032_create_table_media_retries::Migration),
+            Box::new(m20261016_000033_create_table_media_upload_batches::Migration),
+            Box::new(m20261016_000034_create_table_storage_overrides::Migration),
+            Box::new(m20261016_000035_strip_contact_messages_from_email_outbox::Migration),
         ]
     }
 }
@@ -1,5 +1,7 @@
 use sea_orm_migration::prelude::*;
 
+use crate::db_backend::{drop_trigger, is_sqlite, now_default, updated_at_trigger};
+
 #[derive(DeriveMigrationName)]
 pub struct Migration;
 
@@ -35,13 +37,13 @@ impl MigrationTrait for Migration {
                         ColumnDef::new(Users::CreatedAt)
                             .timestamp_with_time_zone()
                             .not_null()
-                            .default(Expr::current_timestamp()),
+                            .default(now_default(manager)),
                     )
                     .col(
                         ColumnDef::new(Users::UpdatedAt)
                             .timestamp_with_time_zone()
                             .not_null()
-                            .default(Expr::current_timestamp()),
+                            .default(now_default(manager)),
                     )
                     .col(
                         ColumnDef::new(Users::IsVerified)
@@ -141,31 +143,27 @@ impl MigrationTrait for Migration {
         // TRIGGER FOR updated_at
         // ============================================
 
-        manager
-            .get_connection()
-            .execute_unprepared(
-                r#"
-                CREATE OR REPLACE FUNCTION update_updated_at_column()
-                RETURNS TRIGGER AS $$
-                BEGIN
-                    NEW.updated_at = CURRENT_TIMESTAMP;
-                    RETURN NEW;
-                END;
-                $$ language 'plpgsql';
-                "#,
-            )
-            .await?;
+        // Postgres triggers share one function; SQLite has none
+        if !is_sqlite(manager) {
+            manager
+                .get_connection()
+                .execute_unprepared(
+                    r#"
+                    CREATE OR REPLACE FUNCTION update_updated_at_column()
+                    RETURNS TRIGGER AS $$
+                    BEGIN
+                        NEW.updated_at = CURRENT_TIMESTAMP;
+                        RETURN NEW;
+                    END;
+                    $$ language 'plpgsql';
+                    "#,
+                )
+                .await?;
+        }
 
         manager
             .get_connection()
-            .execute_unprepared(
-                r#"
-                CREATE TRIGGER update_users_updated_at
-                BEFORE UPDATE ON users
-                FOR EACH ROW
-                EXECUTE FUNCTION update_updated_at_column();
-                "#,
-            )
+            .execute_unprepared(&updated_at_trigger(manager, "users"))
             .await?;
 
         Ok(())
@@ -175,13 +173,15 @@ impl MigrationTrait for Migration {
         // Drop trigger and function
         manager
             .get_connection()
-            .execute_unprepared("DROP TRIGGER IF EXISTS update_users_updated_at ON users")
+            .execute_unprepared(&drop_trigger(manager, "update_users_updated_at", "users"))
             .await?;
 
-        manager
-            .get_connection()
-            .execute_unprepared("DROP FUNCTION IF EXISTS update_updated_at_column")
-            .await?;
+        if !is_sqlite(manager) {
+            manager
+                .get_connection()
+                .execute_unprepared("DROP FUNCTION IF EXISTS update_updated_at_column")
+                .await?;
+        }
 
         // Drop all indexes (they'll be dropped with the table, but explicit is clearer)
         manager
@@ -1,5 +1,7 @@
 use sea_orm_migration::prelude::*;
 
+use crate::db_backend::{drop_trigger, is_sqlite, now_default, updated_at_trigger, uuid_default};
+
 #[derive(DeriveMigrationName)]
 pub struct Migration;
 
@@ -16,7 +18,7 @@ impl MigrationTrait for Migration {
                             .uuid()
                             .not_null()
                             .primary_key()
-                            .default(Expr::cust("gen_random_uuid()")),
+                            .default(uuid_default(manager)),
                     )
                     .col(ColumnDef::new(Resumes::UserId).uuid().not_null())
                     .col(
@@ -48,13 +50,13 @@ impl MigrationTrait for Migration {
                         ColumnDef::new(Resumes::CreatedAt)
                             .timestamp_with_time_zone()
                             .not_null()
-                            .default(Expr::current_timestamp()),
+                            .default(now_default(manager)),
                     )
                     .col(
                         ColumnDef::new(Resumes::UpdatedAt)
                             .timestamp_with_time_zone()
                             .not_null()
-                            .default(Expr::current_timestamp()),
+                            .default(now_default(manager)),
                     )
                     .col(
                         ColumnDef::new(Resumes::IsDeleted)
@@ -98,31 +100,27 @@ impl MigrationTrait for Migration {
             .await?;
 
         // Trigger for updated_at
-        manager
-            .get_connection()
-            .execute_unprepared(
-                r#"
-                CREATE OR REPLACE FUNCTION update_updated_at_column()
-                RETURNS TRIGGER AS $$
-                BEGIN
-                    NEW.updated_at = CURRENT_TIMESTAMP;
-                    RETURN NEW;
-                END;
-                $$ language 'plpgsql';
-                "#,
-            )
-            .await?;
+        // Postgres triggers share one function; SQLite has none
+        if !is_sqlite(manager) {
+            manager
+                .get_connection()
+                .execute_unprepared(
+                    r#"
+                    CREATE OR REPLACE FUNCTION update_updated_at_column()
+                    RETURNS TRIGGER AS $$
+                    BEGIN
+                        NEW.updated_at = CURRENT_TIMESTAMP;
+                        RETURN NEW;
+                    END;
+                    $$ language 'plpgsql';
+                    "#,
+                )
+                .await?;
+        }
 
         manager
             .get_connection()
-            .execute_unprepared(
-                r#"
-                CREATE TRIGGER update_resumes_updated_at
-                BEFORE UPDATE ON resumes
-                FOR EACH ROW
-                EXECUTE FUNCTION update_updated_at_column();
-                "#,
-            )
+            .execute_unprepared(&updated_at_trigger(manager, "resumes"))
             .await?;
 
         Ok(())
@@ -131,7 +129,11 @@ impl MigrationTrait for Migration {
     async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
         manager
             .get_connection()
-            .execute_unprepared("DROP TRIGGER IF EXISTS update_resumes_updated_at ON resumes")
+            .execute_unprepared(&drop_trigger(
+                manager,
+                "update_resumes_updated_at",
+                "resumes",
+            ))
             .await?;
 
         manager
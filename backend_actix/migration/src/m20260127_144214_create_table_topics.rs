@@ -1,5 +1,7 @@
 use sea_orm_migration::prelude::*;
 
+use crate::db_backend::{drop_trigger, now_default, updated_at_trigger, uuid_default};
+
 #[derive(DeriveMigrationName)]
 pub struct Migration;
 
@@ -19,7 +21,7 @@ impl MigrationTrait for Migration {
                             .uuid()
                             .not_null()
                             .primary_key()
-                            .default(Expr::cust("gen_random_uuid()")),
+                            .default(uuid_default(manager)),
                     )
                     .col(ColumnDef::new(Topics::UserId).uuid().not_null())
                     .col(ColumnDef::new(Topics::Title).string_len(100).not_null())
@@ -34,13 +36,13 @@ impl MigrationTrait for Migration {
                         ColumnDef::new(Topics::CreatedAt)
                             .timestamp_with_time_zone()
                             .not_null()
-                            .default(Expr::current_timestamp()),
+                            .default(now_default(manager)),
                     )
                     .col(
                         ColumnDef::new(Topics::UpdatedAt)
                             .timestamp_with_time_zone()
                             .not_null()
-                            .default(Expr::current_timestamp()),
+                            .default(now_default(manager)),
                     )
                     .foreign_key(
                         ForeignKey::create()
@@ -86,14 +88,7 @@ impl MigrationTrait for Migration {
 
         manager
             .get_connection()
-            .execute_unprepared(
-                r#"
-                CREATE TRIGGER update_topics_updated_at
-                BEFORE UPDATE ON topics
-                FOR EACH ROW
-                EXECUTE FUNCTION update_updated_at_column();
-                "#,
-            )
+            .execute_unprepared(&updated_at_trigger(manager, "topics"))
             .await?;
 
         Ok(())
@@ -103,11 +98,7 @@ impl MigrationTrait for Migration {
         // Drop trigger
         manager
             .get_connection()
-            .execute_unprepared(
-                r#"
-                DROP TRIGGER IF EXISTS update_topics_updated_at ON topics;
-                "#,
-            )
+            .execute_unprepared(&drop_trigger(manager, "update_topics_updated_at", "topics"))
             .await?;
 
         // Drop indexes explicitly
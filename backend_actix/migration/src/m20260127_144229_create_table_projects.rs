@@ -1,5 +1,7 @@
 use sea_orm_migration::prelude::*;
 
+use crate::db_backend::{drop_trigger, is_sqlite, now_default, updated_at_trigger, uuid_default};
+
 #[derive(DeriveMigrationName)]
 pub struct Migration;
 
@@ -19,7 +21,7 @@ impl MigrationTrait for Migration {
                             .uuid()
                             .not_null()
                             .primary_key()
-                            .default(Expr::cust("gen_random_uuid()")),
+                            .default(uuid_default(manager)),
                     )
                     .col(ColumnDef::new(Projects::UserId).uuid().not_null())
                     .col(ColumnDef::new(Projects::Title).string_len(150).not_null())
@@ -37,7 +39,7 @@ impl MigrationTrait for Migration {
                         ColumnDef::new(Projects::CreatedAt)
                             .timestamp_with_time_zone()
                             .not_null()
-                            .default(Expr::current_timestamp()),
+                            .default(now_default(manager)),
                     )
                     .col(
                         ColumnDef::new(Projects::IsDeleted)
@@ -49,7 +51,7 @@ impl MigrationTrait for Migration {
                         ColumnDef::new(Projects::UpdatedAt)
                             .timestamp_with_time_zone()
                             .not_null()
-                            .default(Expr::current_timestamp()),
+                            .default(now_default(manager)),
                     )
                     .foreign_key(
                         ForeignKey::create()
@@ -91,15 +93,18 @@ impl MigrationTrait for Migration {
             .await?;
         // GIN index for fast containment queries
         // Handle fast retrieval for query like "find projects with tech X" or `SELECT * FROM projects WHERE tech_stack @> '["Rust"]';`
-        manager
-            .get_connection()
-            .execute_unprepared(
-                r#"
-                CREATE INDEX IF NOT EXISTS idx_projects_tech_stack
-                ON projects USING GIN (tech_stack);
-                "#,
-            )
-            .await?;
+        // SQLite has no index type for JSON containment
+        if !is_sqlite(manager) {
+            manager
+                .get_connection()
+                .execute_unprepared(
+                    r#"
+                    CREATE INDEX IF NOT EXISTS idx_projects_tech_stack
+                    ON projects USING GIN (tech_stack);
+                    "#,
+                )
+                .await?;
+        }
 
         // =====================================================
         // updated_at trigger
@@ -107,14 +112,7 @@ impl MigrationTrait for Migration {
 
         manager
             .get_connection()
-            .execute_unprepared(
-                r#"
-                CREATE TRIGGER update_projects_updated_at
-                BEFORE UPDATE ON projects
-                FOR EACH ROW
-                EXECUTE FUNCTION update_updated_at_column();
-                "#,
-            )
+            .execute_unprepared(&updated_at_trigger(manager, "projects"))
             .await?;
 
         Ok(())
@@ -124,11 +122,11 @@ impl MigrationTrait for Migration {
         // Drop trigger
         manager
             .get_connection()
-            .execute_unprepared(
-                r#"
-                DROP TRIGGER IF EXISTS update_projects_updated_at ON projects;
-                "#,
-            )
+            .execute_unprepared(&drop_trigger(
+                manager,
+                "update_projects_updated_at",
+                "projects",
+            ))
             .await?;
 
         // Drop indexes
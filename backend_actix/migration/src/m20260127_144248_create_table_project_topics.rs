@@ -1,5 +1,7 @@
 use sea_orm_migration::prelude::*;
 
+use crate::db_backend::now_default;
+
 #[derive(DeriveMigrationName)]
 pub struct Migration;
 
@@ -20,7 +22,7 @@ impl MigrationTrait for Migration {
                         ColumnDef::new(ProjectTopics::CreatedAt)
                             .timestamp_with_time_zone()
                             .not_null()
-                            .default(Expr::current_timestamp()),
+                            .default(now_default(manager)),
                     )
                     // Composite primary key
                     .primary_key(
@@ -48,28 +48,44 @@
 
 use sea_orm_migration::prelude::*;
 
+use crate::db_backend::{drop_trigger, is_sqlite, now_default, updated_at_trigger, uuid_default};
+
 #[derive(DeriveMigrationName)]
 pub struct Migration;
 
 #[async_trait::async_trait]
 impl MigrationTrait for Migration {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let sqlite = is_sqlite(manager);
+
         // =====================================================
         // Create enum type for media.status
         // =====================================================
-        manager
-            .get_connection()
-            .execute_unprepared(
-                r#"
-                DO $$
-                BEGIN
-                    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'media_status') THEN
-                        CREATE TYPE media_status AS ENUM ('pending', 'processing', 'ready', 'failed');
-                    END IF;
-                END$$;
-                "#,
-            )
-            .await?;
+        // SQLite has no enum types; the column is text there
+        if !sqlite {
+            manager
+                .get_connection()
+                .execute_unprepared(
+                    r#"
+                    DO $$
+                    BEGIN
+                        IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'media_status') THEN
+                            CREATE TYPE media_status AS ENUM ('pending', 'processing', 'ready', 'failed');
+                        END IF;
+                    END$$;
+                    "#,
+                )
+                .await?;
+        }
+
+        let mut status = ColumnDef::new(Media::Status);
+        if sqlite {
+            status.string_len(16).default("pending");
+        } else {
+            status
+                .custom(Alias::new("media_status"))
+                .default(Expr::cust("'pending'::media_status"));
+        }
 
         // =====================================================
         // Create media table
@@ -85,7 +101,7 @@ impl MigrationTrait for Migration {
                             .uuid()
                             .not_null()
                             .primary_key()
-                            .default(Expr::cust("gen_random_uuid()")),
+                            .default(uuid_default(manager)),
                     )
                     // Ownership - every file belongs to a user
                     .col(ColumnDef::new(Media::UserId).uuid().not_null())
@@ -134,12 +150,7 @@ impl MigrationTrait for Migration {
                     // =========================================
                     // Processing status
                     // =========================================
-                    .col(
-                        ColumnDef::new(Media::Status)
-                            .custom(Alias::new("media_status"))
-                            .not_null()
-                            .default(Expr::cust("'pending'::media_status")),
-                    )
+                    .col(status.not_null())
                     // =========================================
                     // Metadata (JSON)
                     // =========================================
@@ -148,7 +159,7 @@ impl MigrationTrait for Migration {
                         ColumnDef::new(Media::Metadata)
                             .json_binary()
                             .not_null()
-                            .default(Expr::cust("'{}'::jsonb")),
+                            .default(Expr::cust("'{}'")),
                     )
                     // =========================================
                     // Audit timestamps
@@ -157,13 +168,13 @@ impl MigrationTrait for Migration {
                         ColumnDef::new(Media::CreatedAt)
                             .timestamp_with_time_zone()
                             .not_null()
-                            .default(Expr::current_timestamp()),
+                            .default(now_default(manager)),
                     )
                     .col(
                         ColumnDef::new(Media::UpdatedAt)
                             .timestamp_with_time_zone()
                             .not_null()
-                            .default(Expr::current_timestamp()),
+                            .default(now_default(manager)),
                     )
                     // Soft delete - allows async GCS cleanup
                     .col(ColumnDef::new(Media::DeletedAt).timestamp_with_time_zone())
@@ -251,14 +262,7 @@ impl MigrationTrait for Migration {
         // =====================================================
         manager
             .get_connection()
-            .execute_unprepared(
-                r#"
-                CREATE TRIGGER update_media_updated_at
-                BEFORE UPDATE ON media
-                FOR EACH ROW
-                EXECUTE FUNCTION update_updated_at_column();
-                "#,
-            )
+            .execute_unprepared(&updated_at_trigger(manager, "media"))
             .await?;
 
         Ok(())
@@ -268,11 +272,7 @@ impl MigrationTrait for Migration {
         // Drop trigger
         manager
             .get_connection()
-            .execute_unprepared(
-                r#"
-                DROP TRIGGER IF EXISTS update_media_updated_at ON media;
-                "#,
-            )
+            .execute_unprepared(&drop_trigger(manager, "update_media_updated_at", "media"))
             .await?;
 
         // Drop indexes
@@ -295,14 +295,16 @@ impl MigrationTrait for Migration {
             .await?;
 
         // Drop enum type (only if nothing else depends on it)
-        manager
-            .get_connection()
-            .execute_unprepared(
-                r#"
-                DROP TYPE IF EXISTS media_status;
-                "#,
-            )
-            .await?;
+        if !is_sqlite(manager) {
+            manager
+                .get_connection()
+                .execute_unprepared(
+                    r#"
+                    DROP TYPE IF EXISTS media_status;
+                    "#,
+                )
+                .await?;
+        }
 
         Ok(())
     }
@@ -70,6 +70,8 @@
 
 use sea_orm_migration::prelude::*;
 
+use crate::db_backend::{now_default, uuid_default};
+
 #[derive(DeriveMigrationName)]
 pub struct Migration;
 
@@ -90,7 +92,7 @@ impl MigrationTrait for Migration {
                             .uuid()
                             .not_null()
                             .primary_key()
-                            .default(Expr::cust("gen_random_uuid()")),
+                            .default(uuid_default(manager)),
                     )
                     // Reference to the media file
                     .col(ColumnDef::new(MediaAttachments::MediaId).uuid().not_null())
@@ -142,7 +144,7 @@ impl MigrationTrait for Migration {
                         ColumnDef::new(MediaAttachments::CreatedAt)
                             .timestamp_with_time_zone()
                             .not_null()
-                            .default(Expr::current_timestamp()),
+                            .default(now_default(manager)),
                     )
                     // =========================================
                     // Foreign keys
@@ -67,6 +67,8 @@
 
 use sea_orm_migration::prelude::*;
 
+use crate::db_backend::{now_default, uuid_default};
+
 #[derive(DeriveMigrationName)]
 pub struct Migration;
 
@@ -87,7 +89,7 @@ impl MigrationTrait for Migration {
                             .uuid()
                             .not_null()
                             .primary_key()
-                            .default(Expr::cust("gen_random_uuid()")),
+                            .default(uuid_default(manager)),
                     )
                     // Reference to original media
                     .col(ColumnDef::new(MediaVariants::MediaId).uuid().not_null())
@@ -153,7 +155,7 @@ impl MigrationTrait for Migration {
                         ColumnDef::new(MediaVariants::CreatedAt)
                             .timestamp_with_time_zone()
                             .not_null()
-                            .default(Expr::current_timestamp()),
+                            .default(now_default(manager)),
                     )
                     // =========================================
                     // Foreign keys
@@ -0,0 +1,68 @@
+//! # Media Variant Checksums Migration
+//!
+//! ## Purpose
+//! Adds `checksum_sha256` to `media_variants`. The image processor records a
+//! SHA-256 digest for every object it writes; persisting it lets us verify a
+//! served object end to end and spot identical uploads.
+//!
+//! ## Key Columns Explained
+//! - `checksum_sha256`: Lowercase hex digest (64 chars). Nullable because rows
+//!   written before the processor reported checksums have none.
+//!
+//! ## Indexes
+//! - `idx_media_variants_checksum`: Find variants with identical content
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(MediaVariants::Table)
+                    .add_column(ColumnDef::new(MediaVariants::ChecksumSha256).char_len(64))
+                    .to_owned(),
+            )
+            .await?;
+
+        // Only rows that have a checksum are worth indexing
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                CREATE INDEX idx_media_variants_checksum
+                ON media_variants (checksum_sha256)
+                WHERE checksum_sha256 IS NOT NULL;
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP INDEX IF EXISTS idx_media_variants_checksum;")
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(MediaVariants::Table)
+                    .drop_column(MediaVariants::ChecksumSha256)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum MediaVariants {
+    Table,
+    ChecksumSha256,
+}
@@ -0,0 +1,126 @@
+//! # Email Outbox Migration
+//!
+//! ## Purpose
+//! Transactional outbox for emails that must not get lost. The row is written in
+//! the same transaction as the change that triggers the email (e.g. user
+//! registration); a background dispatcher delivers it and retries with backoff.
+//!
+//! ## Key Columns Explained
+//! - `kind`: Which email to send (`verification`). Decides how `payload` is read.
+//! - `payload`: Everything needed to render the email, so delivery does not depend
+//!   on the triggering row still looking the same.
+//! - `attempts`: Delivery attempts made so far.
+//! - `next_attempt_at`: When the dispatcher may pick the row up. Also used as a
+//!   lease while a delivery is in flight. `NULL` once sent or given up.
+//! - `last_error`: Error of the most recent failed attempt.
+//! - `sent_at`: Set when delivery succeeded.
+//!
+//! ## Indexes
+//! - `idx_email_outbox_due`: Partial index for the dispatcher's "what is due" scan
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::{now_default, uuid_default};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EmailOutbox::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(EmailOutbox::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(uuid_default(manager)),
+                    )
+                    .col(ColumnDef::new(EmailOutbox::UserId).uuid().not_null())
+                    .col(ColumnDef::new(EmailOutbox::Kind).string_len(50).not_null())
+                    .col(
+                        ColumnDef::new(EmailOutbox::Payload)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EmailOutbox::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(EmailOutbox::NextAttemptAt)
+                            .timestamp_with_time_zone()
+                            .default(now_default(manager)),
+                    )
+                    .col(ColumnDef::new(EmailOutbox::LastError).text())
+                    .col(ColumnDef::new(EmailOutbox::SentAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(EmailOutbox::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_email_outbox_user_id")
+                            .from(EmailOutbox::Table, EmailOutbox::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Only undelivered rows are ever scanned
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                CREATE INDEX idx_email_outbox_due
+                ON email_outbox (next_attempt_at)
+                WHERE next_attempt_at IS NOT NULL;
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP INDEX IF EXISTS idx_email_outbox_due;")
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(EmailOutbox::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum EmailOutbox {
+    Table,
+    Id,
+    UserId,
+    Kind,
+    Payload,
+    Attempts,
+    NextAttemptAt,
+    LastError,
+    SentAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,147 @@
+//! # Trash Timestamps Migration
+//!
+//! ## Purpose
+//! Gives the soft-deletable content tables a `deleted_at`, so the trash can list items
+//! by deletion time and purge them after a retention period. `media` already
+//! has one; `resumes`, `projects` and `topics` only had `is_deleted`.
+//!
+//! ## Key Columns Explained
+//! - `deleted_at`: Set when `is_deleted` flips to true, cleared on restore.
+//!   Maintained by the `sync_<table>_deleted_at` triggers, so every existing
+//!   soft-delete path keeps working unchanged. Rows deleted before this
+//!   migration are backfilled with their `updated_at`.
+//!
+//! ## Indexes
+//! - `idx_<table>_trash`: Partial index on (user_id, deleted_at) over deleted
+//!   rows, for the trash listing and the purge job
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::{drop_trigger, is_sqlite, SQLITE_NOW};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Tables that soft-delete through `is_deleted`
+const TABLES: [&str; 3] = ["resumes", "projects", "topics"];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        if is_sqlite(manager) {
+            for table in TABLES {
+                db.execute_unprepared(&format!(
+                    r#"
+                    ALTER TABLE {table} ADD COLUMN deleted_at TIMESTAMPTZ;
+
+                    UPDATE {table} SET deleted_at = updated_at WHERE is_deleted = true;
+
+                    CREATE TRIGGER sync_{table}_deleted_at
+                    AFTER UPDATE OF is_deleted ON {table}
+                    FOR EACH ROW
+                    BEGIN
+                        UPDATE {table}
+                        SET deleted_at = CASE
+                            WHEN NEW.is_deleted AND NOT OLD.is_deleted THEN {SQLITE_NOW}
+                            WHEN NOT NEW.is_deleted THEN NULL
+                            ELSE deleted_at
+                        END
+                        WHERE id = NEW.id;
+                    END;
+
+                    CREATE INDEX idx_{table}_trash
+                    ON {table} (user_id, deleted_at)
+                    WHERE is_deleted = true;
+                    "#
+                ))
+                .await?;
+            }
+        } else {
+            Self::create_postgres_triggers(manager).await?;
+        }
+
+        db.execute_unprepared(
+            r#"
+            CREATE INDEX idx_media_trash
+            ON media (user_id, deleted_at)
+            WHERE deleted_at IS NOT NULL;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared("DROP INDEX IF EXISTS idx_media_trash;")
+            .await?;
+
+        for table in TABLES {
+            db.execute_unprepared(&format!(
+                r#"
+                DROP INDEX IF EXISTS idx_{table}_trash;
+                {drop_trigger}
+                ALTER TABLE {table} DROP COLUMN deleted_at;
+                "#,
+                drop_trigger = drop_trigger(manager, &format!("sync_{table}_deleted_at"), table),
+            ))
+            .await?;
+        }
+
+        if !is_sqlite(manager) {
+            db.execute_unprepared("DROP FUNCTION IF EXISTS sync_deleted_at();")
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Migration {
+    async fn create_postgres_triggers(manager: &SchemaManager<'_>) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            r#"
+            CREATE OR REPLACE FUNCTION sync_deleted_at()
+            RETURNS TRIGGER AS $$
+            BEGIN
+                IF NEW.is_deleted AND NOT OLD.is_deleted THEN
+                    NEW.deleted_at = CURRENT_TIMESTAMP;
+                ELSIF NOT NEW.is_deleted THEN
+                    NEW.deleted_at = NULL;
+                END IF;
+                RETURN NEW;
+            END;
+            $$ language 'plpgsql';
+            "#,
+        )
+        .await?;
+
+        for table in TABLES {
+            db.execute_unprepared(&format!(
+                r#"
+                ALTER TABLE {table} ADD COLUMN deleted_at TIMESTAMPTZ;
+
+                UPDATE {table} SET deleted_at = updated_at WHERE is_deleted = true;
+
+                CREATE TRIGGER sync_{table}_deleted_at
+                    BEFORE UPDATE OF is_deleted ON {table}
+                    FOR EACH ROW
+                    EXECUTE FUNCTION sync_deleted_at();
+
+                CREATE INDEX idx_{table}_trash
+                ON {table} (user_id, deleted_at)
+                WHERE is_deleted = true;
+                "#
+            ))
+            .await?;
+        }
+
+        Ok(())
+    }
+}
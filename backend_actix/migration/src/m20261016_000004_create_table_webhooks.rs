@@ -0,0 +1,464 @@
+//! # Webhooks Migration
+//!
+//! ## Purpose
+//! Owner-registered HTTP endpoints that are notified when their content changes,
+//! e.g. so a static-site builder can rebuild. Events are queued by triggers on the
+//! content tables, inside the transaction that made the change, so every writer
+//! (API, CLI, the media status updater) produces them. The backend delivers the
+//! queue with retries, like the email outbox.
+//!
+//! ## Key Columns Explained
+//! - `webhook_endpoints.secret`: HMAC key for the delivery signature. Generated
+//!   server-side and only shown to the owner when the endpoint is created.
+//! - `webhook_endpoints.events`: Event names the endpoint subscribes to
+//!   (`project.updated`, `media.ready`, ...).
+//! - `webhook_deliveries.payload`: The exact JSON body to POST.
+//! - `webhook_deliveries.next_attempt_at`: When the dispatcher may pick the row
+//!   up; doubles as a lease while in flight. `NULL` once delivered or given up.
+//! - `webhook_deliveries.last_status_code` / `last_error`: Outcome of the most
+//!   recent attempt, shown in the delivery log.
+//!
+//! ## Triggers
+//! - `resumes`: `cv.created`, `cv.updated`, `cv.deleted` (soft or hard)
+//! - `projects`: `project.created`, `project.updated`, `project.deleted`
+//! - `media`: `media.ready` when processing finishes
+//!
+//! Updates to rows that stay in the trash don't fire anything. SQLite has no
+//! stored functions, so there each table gets one trigger per event instead;
+//! see [`sqlite_triggers`].
+//!
+//! ## Indexes
+//! - `idx_webhook_endpoints_user_id`: Endpoint lookup per owner, used by the triggers
+//! - `idx_webhook_deliveries_due`: Partial index for the dispatcher's "what is due" scan
+//! - `idx_webhook_deliveries_endpoint`: Delivery log, newest first
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::{
+    drop_trigger, is_sqlite, now_default, sqlite_uuid_text, uuid_default, SQLITE_NOW,
+};
+
+/// Content tables with webhook events: (table, event prefix, text columns in
+/// the event data besides `id`, columns whose update is an edit)
+const SQLITE_CONTENT_TABLES: [(&str, &str, &[&str], &str); 2] = [
+    (
+        "resumes",
+        "cv",
+        &[],
+        "user_id, display_name, role, bio, photo_url, core_skills, educations, experiences, \
+         highlighted_projects, contact_info, is_deleted",
+    ),
+    (
+        "projects",
+        "project",
+        &["slug"],
+        "user_id, title, slug, description, tech_stack, screenshots, repo_url, live_demo_url, \
+         is_deleted",
+    ),
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let sqlite = is_sqlite(manager);
+
+        // Comma-separated names on SQLite, which has no arrays
+        let mut events = ColumnDef::new(WebhookEndpoints::Events);
+        if sqlite {
+            events.text();
+        } else {
+            events.array(ColumnType::Text);
+        }
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookEndpoints::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(WebhookEndpoints::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(uuid_default(manager)),
+                    )
+                    .col(ColumnDef::new(WebhookEndpoints::UserId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(WebhookEndpoints::Url)
+                            .string_len(2048)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookEndpoints::Secret)
+                            .string_len(128)
+                            .not_null(),
+                    )
+                    .col(events.not_null())
+                    .col(
+                        ColumnDef::new(WebhookEndpoints::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_webhook_endpoints_user_id")
+                            .from(WebhookEndpoints::Table, WebhookEndpoints::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookDeliveries::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(uuid_default(manager)),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::EndpointId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::Event)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::Payload)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::NextAttemptAt)
+                            .timestamp_with_time_zone()
+                            .default(now_default(manager)),
+                    )
+                    .col(ColumnDef::new(WebhookDeliveries::LastStatusCode).integer())
+                    .col(ColumnDef::new(WebhookDeliveries::LastError).text())
+                    .col(ColumnDef::new(WebhookDeliveries::DeliveredAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_webhook_deliveries_endpoint_id")
+                            .from(WebhookDeliveries::Table, WebhookDeliveries::EndpointId)
+                            .to(WebhookEndpoints::Table, WebhookEndpoints::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            r#"
+            CREATE INDEX idx_webhook_endpoints_user_id
+            ON webhook_endpoints (user_id);
+
+            CREATE INDEX idx_webhook_deliveries_due
+            ON webhook_deliveries (next_attempt_at)
+            WHERE next_attempt_at IS NOT NULL;
+
+            CREATE INDEX idx_webhook_deliveries_endpoint
+            ON webhook_deliveries (endpoint_id, created_at DESC);
+            "#,
+        )
+        .await?;
+
+        // =====================================================
+        // Event queueing
+        // =====================================================
+
+        if sqlite {
+            db.execute_unprepared(&sqlite_triggers()).await?;
+            return Ok(());
+        }
+
+        db.execute_unprepared(
+            r#"
+            CREATE OR REPLACE FUNCTION enqueue_webhook_event(
+                p_user_id UUID,
+                p_event TEXT,
+                p_data JSONB
+            ) RETURNS VOID AS $$
+            BEGIN
+                INSERT INTO webhook_deliveries (endpoint_id, event, payload)
+                SELECT
+                    e.id,
+                    p_event,
+                    jsonb_build_object(
+                        'event', p_event,
+                        'occurred_at', CURRENT_TIMESTAMP,
+                        'data', p_data
+                    )
+                FROM webhook_endpoints e
+                WHERE e.user_id = p_user_id
+                  AND p_event = ANY (e.events)
+                  -- rows cascading from a deleted account have nobody to notify
+                  AND EXISTS (SELECT 1 FROM users u WHERE u.id = p_user_id);
+            END;
+            $$ LANGUAGE plpgsql;
+
+            -- created / updated / deleted for tables with an is_deleted flag
+            CREATE OR REPLACE FUNCTION webhook_content_event() RETURNS TRIGGER AS $$
+            DECLARE
+                prefix TEXT := TG_ARGV[0];
+                row_data JSONB;
+                action TEXT;
+            BEGIN
+                IF TG_OP = 'INSERT' THEN
+                    action := 'created';
+                    row_data := to_jsonb(NEW);
+                ELSIF TG_OP = 'DELETE' THEN
+                    IF OLD.is_deleted THEN
+                        RETURN OLD; -- already reported when it was trashed
+                    END IF;
+                    action := 'deleted';
+                    row_data := to_jsonb(OLD);
+                ELSIF NEW.is_deleted AND NOT OLD.is_deleted THEN
+                    action := 'deleted';
+                    row_data := to_jsonb(NEW);
+                ELSIF NEW.is_deleted THEN
+                    RETURN NEW; -- edits inside the trash
+                ELSE
+                    action := 'updated';
+                    row_data := to_jsonb(NEW);
+                END IF;
+
+                PERFORM enqueue_webhook_event(
+                    (row_data ->> 'user_id')::UUID,
+                    prefix || '.' || action,
+                    jsonb_strip_nulls(jsonb_build_object(
+                        'id', row_data -> 'id',
+                        'slug', row_data -> 'slug'
+                    ))
+                );
+
+                RETURN COALESCE(NEW, OLD);
+            END;
+            $$ LANGUAGE plpgsql;
+
+            CREATE OR REPLACE FUNCTION webhook_media_ready() RETURNS TRIGGER AS $$
+            BEGIN
+                PERFORM enqueue_webhook_event(
+                    NEW.user_id,
+                    'media.ready',
+                    jsonb_build_object('id', NEW.id)
+                );
+                RETURN NEW;
+            END;
+            $$ LANGUAGE plpgsql;
+            "#,
+        )
+        .await?;
+
+        db.execute_unprepared(
+            r#"
+            CREATE TRIGGER webhook_resumes_event
+            AFTER INSERT OR UPDATE OR DELETE ON resumes
+            FOR EACH ROW
+            EXECUTE FUNCTION webhook_content_event('cv');
+
+            CREATE TRIGGER webhook_projects_event
+            AFTER INSERT OR UPDATE OR DELETE ON projects
+            FOR EACH ROW
+            EXECUTE FUNCTION webhook_content_event('project');
+
+            CREATE TRIGGER webhook_media_ready
+            AFTER UPDATE OF status ON media
+            FOR EACH ROW
+            WHEN (NEW.status = 'ready' AND OLD.status IS DISTINCT FROM NEW.status)
+            EXECUTE FUNCTION webhook_media_ready();
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(&drop_trigger(manager, "webhook_media_ready", "media"))
+            .await?;
+
+        if is_sqlite(manager) {
+            for (table, ..) in SQLITE_CONTENT_TABLES {
+                for op in ["insert", "update", "trash", "delete"] {
+                    db.execute_unprepared(&format!("DROP TRIGGER IF EXISTS webhook_{table}_{op};"))
+                        .await?;
+                }
+            }
+        } else {
+            db.execute_unprepared(
+                r#"
+                DROP TRIGGER IF EXISTS webhook_projects_event ON projects;
+                DROP TRIGGER IF EXISTS webhook_resumes_event ON resumes;
+                DROP FUNCTION IF EXISTS webhook_media_ready();
+                DROP FUNCTION IF EXISTS webhook_content_event();
+                DROP FUNCTION IF EXISTS enqueue_webhook_event(UUID, TEXT, JSONB);
+                "#,
+            )
+            .await?;
+        }
+
+        db.execute_unprepared(
+            r#"
+                DROP INDEX IF EXISTS idx_webhook_deliveries_endpoint;
+                DROP INDEX IF EXISTS idx_webhook_deliveries_due;
+                DROP INDEX IF EXISTS idx_webhook_endpoints_user_id;
+                "#,
+        )
+        .await?;
+
+        manager
+            .drop_table(Table::drop().table(WebhookDeliveries::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(WebhookEndpoints::Table).to_owned())
+            .await
+    }
+}
+
+/// `enqueue_webhook_event` inlined: queue `event` for every endpoint of the
+/// row's owner subscribed to it
+pub(crate) fn sqlite_enqueue(row: &str, event: &str, data: &str) -> String {
+    format!(
+        r#"
+        INSERT INTO webhook_deliveries (endpoint_id, event, payload)
+        SELECT
+            e.id,
+            '{event}',
+            json_object('event', '{event}', 'occurred_at', {SQLITE_NOW}, 'data', {data})
+        FROM webhook_endpoints e
+        WHERE e.user_id = {row}.user_id
+          AND instr(',' || e.events || ',', ',{event},') > 0
+          AND EXISTS (SELECT 1 FROM users u WHERE u.id = {row}.user_id);
+        "#
+    )
+}
+
+/// The Postgres triggers split per event. Edits only fire on `UPDATE OF` the
+/// content columns: the `updated_at` and `deleted_at` triggers update the row
+/// again, and those nested updates must not queue a second event.
+fn sqlite_triggers() -> String {
+    let mut sql = String::new();
+
+    for (table, prefix, data_columns, columns) in SQLITE_CONTENT_TABLES {
+        let data = |row: &str| {
+            let mut fields = format!("'id', {}", sqlite_uuid_text(&format!("{row}.id")));
+            for column in data_columns {
+                fields.push_str(&format!(", '{column}', {row}.{column}"));
+            }
+            format!("json_object({fields})")
+        };
+        let created = sqlite_enqueue("NEW", &format!("{prefix}.created"), &data("NEW"));
+        let updated = sqlite_enqueue("NEW", &format!("{prefix}.updated"), &data("NEW"));
+        let trashed = sqlite_enqueue("NEW", &format!("{prefix}.deleted"), &data("NEW"));
+        let deleted = sqlite_enqueue("OLD", &format!("{prefix}.deleted"), &data("OLD"));
+
+        sql.push_str(&format!(
+            r#"
+            CREATE TRIGGER webhook_{table}_insert
+            AFTER INSERT ON {table}
+            FOR EACH ROW
+            BEGIN {created} END;
+
+            CREATE TRIGGER webhook_{table}_update
+            AFTER UPDATE OF {columns} ON {table}
+            FOR EACH ROW
+            WHEN NOT NEW.is_deleted
+            BEGIN {updated} END;
+
+            CREATE TRIGGER webhook_{table}_trash
+            AFTER UPDATE OF is_deleted ON {table}
+            FOR EACH ROW
+            WHEN NEW.is_deleted AND NOT OLD.is_deleted
+            BEGIN {trashed} END;
+
+            -- already reported when it was trashed
+            CREATE TRIGGER webhook_{table}_delete
+            AFTER DELETE ON {table}
+            FOR EACH ROW
+            WHEN NOT OLD.is_deleted
+            BEGIN {deleted} END;
+            "#
+        ));
+    }
+
+    let ready = sqlite_enqueue(
+        "NEW",
+        "media.ready",
+        &format!("json_object('id', {})", sqlite_uuid_text("NEW.id")),
+    );
+    sql.push_str(&format!(
+        r#"
+        CREATE TRIGGER webhook_media_ready
+        AFTER UPDATE OF status ON media
+        FOR EACH ROW
+        WHEN NEW.status = 'ready' AND OLD.status IS NOT NEW.status
+        BEGIN {ready} END;
+        "#
+    ));
+
+    sql
+}
+
+#[derive(DeriveIden)]
+enum WebhookEndpoints {
+    Table,
+    Id,
+    UserId,
+    Url,
+    Secret,
+    Events,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum WebhookDeliveries {
+    Table,
+    Id,
+    EndpointId,
+    Event,
+    Payload,
+    Attempts,
+    NextAttemptAt,
+    LastStatusCode,
+    LastError,
+    DeliveredAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,76 @@
+//! # Listing Indexes Migration
+//!
+//! ## Purpose
+//! Composite indexes matching the filter and sort order of the hot listing
+//! queries, so they are answered from the index without a separate sort.
+//!
+//! ## Indexes
+//! - `idx_projects_user_active_updated`: (user_id, is_deleted, updated_at) for
+//!   the owner's project list, which defaults to most recently updated first.
+//!   Replaces `idx_projects_user_id`, which is a prefix of it.
+//! - `idx_media_attachments_target_position`: (attachable_type, attachable_id,
+//!   position) for an entity's attachments in display order. Replaces
+//!   `idx_media_attachments_attachable`, likewise a prefix.
+//!
+//! `users (email) WHERE is_deleted = false` is not created here: the users
+//! migration already has it as the unique `idx_users_email_active`.
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            r#"
+            CREATE INDEX IF NOT EXISTS idx_projects_user_active_updated
+            ON projects (user_id, is_deleted, updated_at);
+
+            DROP INDEX IF EXISTS idx_projects_user_id;
+            "#,
+        )
+        .await?;
+
+        db.execute_unprepared(
+            r#"
+            CREATE INDEX IF NOT EXISTS idx_media_attachments_target_position
+            ON media_attachments (attachable_type, attachable_id, position);
+
+            DROP INDEX IF EXISTS idx_media_attachments_attachable;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            r#"
+            CREATE INDEX IF NOT EXISTS idx_media_attachments_attachable
+            ON media_attachments (attachable_type, attachable_id);
+
+            DROP INDEX IF EXISTS idx_media_attachments_target_position;
+            "#,
+        )
+        .await?;
+
+        db.execute_unprepared(
+            r#"
+            CREATE INDEX IF NOT EXISTS idx_projects_user_id
+            ON projects (user_id);
+
+            DROP INDEX IF EXISTS idx_projects_user_active_updated;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+//! # User Locale Migration
+//!
+//! ## Purpose
+//! Adds `preferred_locale` to `users`, so transactional emails can be sent in
+//! the user's language.
+//!
+//! ## Key Columns Explained
+//! - `preferred_locale`: Language tag such as `en`, `id` or `pt-BR`. Existing
+//!   users get `en`, which is also what emails fall back to when no template
+//!   exists for a locale.
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(
+                        ColumnDef::new(Users::PreferredLocale)
+                            .string_len(16)
+                            .not_null()
+                            .default("en"),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::PreferredLocale)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    PreferredLocale,
+}
@@ -0,0 +1,46 @@
+//! # Email Bounce Migration
+//!
+//! ## Purpose
+//! Adds `email_bounced_at` to `users`, set when the email provider reports a
+//! hard bounce or a spam complaint for the address. No more mail is queued
+//! for a user once it is set.
+//!
+//! ## Key Columns Explained
+//! - `email_bounced_at`: When the address was first reported undeliverable;
+//!   `NULL` for addresses in good standing.
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(ColumnDef::new(Users::EmailBouncedAt).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::EmailBouncedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    EmailBouncedAt,
+}
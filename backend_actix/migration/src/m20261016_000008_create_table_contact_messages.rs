@@ -0,0 +1,92 @@
+//! # Contact Messages Migration
+//!
+//! ## Purpose
+//! Messages sent through the public contact form. The owners are notified
+//! through the email outbox in the same transaction as the insert.
+//!
+//! ## Key Columns Explained
+//! - `name`, `email`: Who wrote the message, as they entered it. Not tied to an
+//!   account; the form is public.
+//! - `subject`: Optional one-line subject.
+//! - `ip_address`: Client address the message came from, for abuse handling.
+//! - `read_at`: Set when an owner marks the message read; `NULL` while unread.
+//!
+//! ## Indexes
+//! - `idx_contact_messages_created_at`: Newest-first listing
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::{now_default, uuid_default};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ContactMessages::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ContactMessages::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(uuid_default(manager)),
+                    )
+                    .col(
+                        ColumnDef::new(ContactMessages::Name)
+                            .string_len(100)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ContactMessages::Email)
+                            .string_len(254)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ContactMessages::Subject).string_len(200))
+                    .col(ColumnDef::new(ContactMessages::Message).text().not_null())
+                    .col(ColumnDef::new(ContactMessages::IpAddress).string_len(45))
+                    .col(ColumnDef::new(ContactMessages::ReadAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(ContactMessages::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_contact_messages_created_at")
+                    .table(ContactMessages::Table)
+                    .col(ContactMessages::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ContactMessages::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ContactMessages {
+    Table,
+    Id,
+    Name,
+    Email,
+    Subject,
+    Message,
+    IpAddress,
+    ReadAt,
+    CreatedAt,
+}
@@ -0,0 +1,48 @@
+//! # Email Unsubscribe Migration
+//!
+//! ## Purpose
+//! Adds `email_unsubscribed_at` to `users`, set when the user follows the
+//! unsubscribe link of an email. Only account mail (e.g. verification) is
+//! queued for a user once it is set.
+//!
+//! ## Key Columns Explained
+//! - `email_unsubscribed_at`: When the user first unsubscribed; `NULL` for
+//!   users who still receive every email.
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(
+                        ColumnDef::new(Users::EmailUnsubscribedAt).timestamp_with_time_zone(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::EmailUnsubscribedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    EmailUnsubscribedAt,
+}
@@ -0,0 +1,58 @@
+//! # Site Settings Migration
+//!
+//! ## Purpose
+//! Key-value store for site-wide settings the frontend shell reads: title,
+//! tagline, social links, analytics id and theme. Keys without a row use the
+//! application's default.
+//!
+//! ## Key Columns Explained
+//! - `key`: Setting name, e.g. `title` or `social_links`.
+//! - `value`: The setting as JSON, so lists and strings share one column.
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::now_default;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SiteSettings::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SiteSettings::Key)
+                            .string_len(64)
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SiteSettings::Value).json_binary().not_null())
+                    .col(
+                        ColumnDef::new(SiteSettings::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SiteSettings::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SiteSettings {
+    Table,
+    Key,
+    Value,
+    UpdatedAt,
+}
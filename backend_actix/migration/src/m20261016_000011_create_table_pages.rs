@@ -0,0 +1,120 @@
+//! # Pages Migration
+//!
+//! ## Purpose
+//! Standalone pages of an owner's site, such as About, Now or Uses. Unlike
+//! projects they have no structured fields: the body is Markdown, rendered by
+//! the frontend.
+//!
+//! ## Key Columns Explained
+//! - `slug`: Lowercase URL segment, unique per owner. Cannot be changed after
+//!   creation.
+//! - `body`: Markdown source.
+//! - `status`: `draft` or `published`. Only published pages are served publicly.
+//! - `seo_title`, `seo_description`: Optional overrides for the `<title>` and
+//!   meta description; the frontend falls back to `title`.
+//! - `published_at`: First time the page was published. Kept when the page goes
+//!   back to draft, so republishing does not change its date.
+//!
+//! ## Indexes
+//! - `idx_pages_user_id_slug`: Unique slug per owner; also serves the public lookup
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::{now_default, uuid_default};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Pages::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Pages::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(uuid_default(manager)),
+                    )
+                    .col(ColumnDef::new(Pages::UserId).uuid().not_null())
+                    .col(ColumnDef::new(Pages::Slug).string_len(100).not_null())
+                    .col(ColumnDef::new(Pages::Title).string_len(200).not_null())
+                    .col(ColumnDef::new(Pages::Body).text().not_null())
+                    .col(
+                        ColumnDef::new(Pages::Status)
+                            .string_len(16)
+                            .not_null()
+                            .default("draft"),
+                    )
+                    .col(ColumnDef::new(Pages::SeoTitle).string_len(100))
+                    .col(ColumnDef::new(Pages::SeoDescription).string_len(300))
+                    .col(ColumnDef::new(Pages::PublishedAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(Pages::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .col(
+                        ColumnDef::new(Pages::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_pages_user_id")
+                            .from(Pages::Table, Pages::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_pages_user_id_slug")
+                    .table(Pages::Table)
+                    .col(Pages::UserId)
+                    .col(Pages::Slug)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Pages::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Pages {
+    Table,
+    Id,
+    UserId,
+    Slug,
+    Title,
+    Body,
+    Status,
+    SeoTitle,
+    SeoDescription,
+    PublishedAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
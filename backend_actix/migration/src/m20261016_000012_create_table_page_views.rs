@@ -0,0 +1,83 @@
+//! # Page Views Migration
+//!
+//! ## Purpose
+//! One row per page view counted by `POST /api/public/analytics/pageview`.
+//! Rows are written in batches by the page view flusher, not per request.
+//!
+//! ## Key Columns Explained
+//! - `path`: Viewed path without query string or fragment.
+//! - `referrer`: Host of the referring site; `NULL` for direct visits.
+//! - `visitor_id`: Keyed hash of the client address, user agent and UTC day.
+//!   It changes daily, so it can't link a visitor's days together; neither
+//!   the address nor the user agent is stored.
+//! - `viewed_at`: When the view was received.
+//!
+//! ## Indexes
+//! - `idx_page_views_viewed_at`: Every report filters on a date range
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::{now_default, uuid_default};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PageViews::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PageViews::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(uuid_default(manager)),
+                    )
+                    .col(ColumnDef::new(PageViews::Path).string_len(500).not_null())
+                    .col(ColumnDef::new(PageViews::Referrer).string_len(255))
+                    .col(
+                        ColumnDef::new(PageViews::VisitorId)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PageViews::ViewedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_page_views_viewed_at")
+                    .table(PageViews::Table)
+                    .col(PageViews::ViewedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PageViews::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PageViews {
+    Table,
+    Id,
+    Path,
+    Referrer,
+    VisitorId,
+    ViewedAt,
+}
@@ -0,0 +1,109 @@
+//! # Redirects Migration
+//!
+//! ## Purpose
+//! Old URLs and short links managed by the site owner. A request for
+//! `source_path` is answered with a 301 or 302 to `target_url`.
+//!
+//! ## Key Columns Explained
+//! - `source_path`: Site path that redirects, stored without a trailing slash
+//!   or query string, e.g. `/2019/05/old-post` or `/r/cv`.
+//! - `target_url`: Absolute http(s) URL or a path on this site.
+//! - `status_code`: 301 (permanent) or 302 (temporary).
+//! - `hits`: Times the redirect was followed; incremented in place.
+//! - `last_hit_at`: Last time it was followed; `NULL` until then.
+//!
+//! ## Indexes
+//! - `idx_redirects_source_path`: Unique; every lookup is by source path
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::{now_default, uuid_default};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Redirects::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Redirects::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(uuid_default(manager)),
+                    )
+                    .col(
+                        ColumnDef::new(Redirects::SourcePath)
+                            .string_len(500)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Redirects::TargetUrl)
+                            .string_len(2000)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Redirects::StatusCode)
+                            .small_integer()
+                            .not_null()
+                            .default(301),
+                    )
+                    .col(
+                        ColumnDef::new(Redirects::Hits)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(Redirects::LastHitAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(Redirects::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .col(
+                        ColumnDef::new(Redirects::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_redirects_source_path")
+                    .table(Redirects::Table)
+                    .col(Redirects::SourcePath)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Redirects::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Redirects {
+    Table,
+    Id,
+    SourcePath,
+    TargetUrl,
+    StatusCode,
+    Hits,
+    LastHitAt,
+    CreatedAt,
+    UpdatedAt,
+}
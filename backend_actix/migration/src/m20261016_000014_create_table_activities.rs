@@ -0,0 +1,469 @@
+//! # Activities Migration
+//!
+//! ## Purpose
+//! An owner's activity feed: what content they created, edited, published or
+//! deleted, and when, for the dashboard's "recent changes". Like webhook
+//! events, entries are written by triggers on the content tables inside the
+//! transaction that made the change, so every writer is covered.
+//!
+//! ## Key Columns Explained
+//! - `kind`: `cv`, `project`, `page` or `media`.
+//! - `target_id`: Id of the changed row. Not a foreign key: entries outlive
+//!   the content they describe.
+//! - `action`: `created`, `updated`, `published` (pages), `deleted` (trashed or
+//!   hard-deleted) or `restored` (taken out of the trash).
+//! - `title`: CV display name, project or page title, or media file name at the
+//!   time of the change.
+//! - `occurred_at`: When it happened. A run of edits to one item within
+//!   10 minutes is one `updated` entry, moved forward on every edit.
+//!
+//! ## Triggers
+//! - `resumes`, `projects`: created, updated, deleted, restored
+//! - `pages`: created, updated, published (status turns `published`), deleted
+//! - `media`: created (uploaded), deleted, restored; processing updates are not
+//!   recorded
+//!
+//! Edits to rows in the trash are not recorded. SQLite has no stored
+//! functions, so there each table gets one trigger per action instead; see
+//! [`sqlite_triggers`].
+//!
+//! ## Indexes
+//! - `idx_activities_user_id_occurred_at`: The feed, newest first
+//! - `idx_activities_target_id`: Latest entry of an item, for merging edits
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::{is_sqlite, now_default, uuid_default, SQLITE_NOW};
+
+/// Soft-deletable tables with an activity trail: (table, kind, title column,
+/// columns whose update is an edit)
+const SQLITE_CONTENT_TABLES: [(&str, &str, &str, &str); 2] = [
+    (
+        "resumes",
+        "cv",
+        "display_name",
+        "display_name, role, bio, photo_url, core_skills, educations, experiences, \
+         highlighted_projects, contact_info",
+    ),
+    (
+        "projects",
+        "project",
+        "title",
+        "title, slug, description, tech_stack, screenshots, repo_url, live_demo_url",
+    ),
+];
+
+/// SQLite triggers per table, for `down`
+const SQLITE_TRIGGERS: [(&str, &[&str]); 4] = [
+    (
+        "resumes",
+        &["insert", "update", "trash", "restore", "delete"],
+    ),
+    (
+        "projects",
+        &["insert", "update", "trash", "restore", "delete"],
+    ),
+    ("pages", &["insert", "update", "publish", "delete"]),
+    ("media", &["insert", "trash", "restore", "delete"]),
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Activities::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Activities::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(uuid_default(manager)),
+                    )
+                    .col(ColumnDef::new(Activities::UserId).uuid().not_null())
+                    .col(ColumnDef::new(Activities::Kind).string_len(16).not_null())
+                    .col(ColumnDef::new(Activities::TargetId).uuid().not_null())
+                    .col(ColumnDef::new(Activities::Action).string_len(16).not_null())
+                    .col(ColumnDef::new(Activities::Title).text())
+                    .col(
+                        ColumnDef::new(Activities::OccurredAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_activities_user_id")
+                            .from(Activities::Table, Activities::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            r#"
+            CREATE INDEX idx_activities_user_id_occurred_at
+            ON activities (user_id, occurred_at DESC);
+
+            CREATE INDEX idx_activities_target_id
+            ON activities (target_id, occurred_at DESC);
+            "#,
+        )
+        .await?;
+
+        // =====================================================
+        // Recording
+        // =====================================================
+
+        if is_sqlite(manager) {
+            db.execute_unprepared(&sqlite_triggers()).await?;
+            return Ok(());
+        }
+
+        db.execute_unprepared(
+            r#"
+            CREATE OR REPLACE FUNCTION record_activity(
+                p_user_id UUID,
+                p_kind TEXT,
+                p_target_id UUID,
+                p_action TEXT,
+                p_title TEXT
+            ) RETURNS VOID AS $$
+            BEGIN
+                -- rows cascading from a deleted account have no feed left
+                IF NOT EXISTS (SELECT 1 FROM users u WHERE u.id = p_user_id) THEN
+                    RETURN;
+                END IF;
+
+                -- a run of edits is one entry, moved to the latest edit
+                IF p_action = 'updated' THEN
+                    UPDATE activities
+                    SET occurred_at = CURRENT_TIMESTAMP,
+                        title = p_title
+                    WHERE id = (
+                            SELECT a.id
+                            FROM activities a
+                            WHERE a.target_id = p_target_id
+                            ORDER BY a.occurred_at DESC
+                            LIMIT 1
+                        )
+                      AND action = 'updated'
+                      AND occurred_at > CURRENT_TIMESTAMP - INTERVAL '10 minutes';
+                    IF FOUND THEN
+                        RETURN;
+                    END IF;
+                END IF;
+
+                INSERT INTO activities (user_id, kind, target_id, action, title)
+                VALUES (p_user_id, p_kind, p_target_id, p_action, p_title);
+            END;
+            $$ LANGUAGE plpgsql;
+
+            -- Arguments: kind, title column, whether plain edits are recorded.
+            -- Trash state is read from deleted_at, which the sync_deleted_at
+            -- triggers keep in line with is_deleted before this runs.
+            CREATE OR REPLACE FUNCTION activity_content_event() RETURNS TRIGGER AS $$
+            DECLARE
+                kind TEXT := TG_ARGV[0];
+                title_column TEXT := TG_ARGV[1];
+                records_edits BOOLEAN := TG_ARGV[2]::BOOLEAN;
+                old_data JSONB;
+                new_data JSONB;
+                row_data JSONB;
+                action TEXT;
+            BEGIN
+                IF TG_OP <> 'INSERT' THEN
+                    old_data := to_jsonb(OLD);
+                END IF;
+                IF TG_OP <> 'DELETE' THEN
+                    new_data := to_jsonb(NEW);
+                END IF;
+                row_data := COALESCE(new_data, old_data);
+
+                IF TG_OP = 'INSERT' THEN
+                    action := 'created';
+                ELSIF TG_OP = 'DELETE' THEN
+                    IF old_data ->> 'deleted_at' IS NOT NULL THEN
+                        RETURN OLD; -- already recorded when it was trashed
+                    END IF;
+                    action := 'deleted';
+                ELSIF new_data ->> 'deleted_at' IS NOT NULL
+                      AND old_data ->> 'deleted_at' IS NULL THEN
+                    action := 'deleted';
+                ELSIF new_data ->> 'deleted_at' IS NULL
+                      AND old_data ->> 'deleted_at' IS NOT NULL THEN
+                    action := 'restored';
+                ELSIF new_data ->> 'deleted_at' IS NOT NULL OR NOT records_edits THEN
+                    RETURN NEW; -- edits inside the trash, or not tracked
+                ELSIF new_data ->> 'status' = 'published'
+                      AND old_data ->> 'status' IS DISTINCT FROM 'published' THEN
+                    action := 'published';
+                ELSE
+                    action := 'updated';
+                END IF;
+
+                PERFORM record_activity(
+                    (row_data ->> 'user_id')::UUID,
+                    kind,
+                    (row_data ->> 'id')::UUID,
+                    action,
+                    row_data ->> title_column
+                );
+
+                RETURN COALESCE(NEW, OLD);
+            END;
+            $$ LANGUAGE plpgsql;
+            "#,
+        )
+        .await?;
+
+        db.execute_unprepared(
+            r#"
+            CREATE TRIGGER activity_resumes_event
+            AFTER INSERT OR UPDATE OR DELETE ON resumes
+            FOR EACH ROW
+            EXECUTE FUNCTION activity_content_event('cv', 'display_name', 'true');
+
+            CREATE TRIGGER activity_projects_event
+            AFTER INSERT OR UPDATE OR DELETE ON projects
+            FOR EACH ROW
+            EXECUTE FUNCTION activity_content_event('project', 'title', 'true');
+
+            CREATE TRIGGER activity_pages_event
+            AFTER INSERT OR UPDATE OR DELETE ON pages
+            FOR EACH ROW
+            EXECUTE FUNCTION activity_content_event('page', 'title', 'true');
+
+            CREATE TRIGGER activity_media_event
+            AFTER INSERT OR DELETE OR UPDATE OF deleted_at ON media
+            FOR EACH ROW
+            EXECUTE FUNCTION activity_content_event('media', 'original_filename', 'false');
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        if is_sqlite(manager) {
+            for (table, ops) in SQLITE_TRIGGERS {
+                for op in ops {
+                    db.execute_unprepared(&format!(
+                        "DROP TRIGGER IF EXISTS activity_{table}_{op};"
+                    ))
+                    .await?;
+                }
+            }
+        } else {
+            db.execute_unprepared(
+                r#"
+                DROP TRIGGER IF EXISTS activity_media_event ON media;
+                DROP TRIGGER IF EXISTS activity_pages_event ON pages;
+                DROP TRIGGER IF EXISTS activity_projects_event ON projects;
+                DROP TRIGGER IF EXISTS activity_resumes_event ON resumes;
+                DROP FUNCTION IF EXISTS activity_content_event();
+                DROP FUNCTION IF EXISTS record_activity(UUID, TEXT, UUID, TEXT, TEXT);
+                "#,
+            )
+            .await?;
+        }
+
+        db.execute_unprepared(
+            r#"
+                DROP INDEX IF EXISTS idx_activities_target_id;
+                DROP INDEX IF EXISTS idx_activities_user_id_occurred_at;
+                "#,
+        )
+        .await?;
+
+        manager
+            .drop_table(Table::drop().table(Activities::Table).to_owned())
+            .await
+    }
+}
+
+/// `record_activity` inlined for any action but `updated`
+fn sqlite_record(row: &str, kind: &str, action: &str, title_column: &str) -> String {
+    format!(
+        r#"
+        INSERT INTO activities (user_id, kind, target_id, action, title)
+        SELECT {row}.user_id, '{kind}', {row}.id, '{action}', {row}.{title_column}
+        WHERE EXISTS (SELECT 1 FROM users u WHERE u.id = {row}.user_id);
+        "#
+    )
+}
+
+/// `record_activity` inlined for `updated`: move the item's latest entry
+/// forward if it is an edit from the last 10 minutes, otherwise add one. The
+/// insert checks the same condition, which holds after a successful move.
+fn sqlite_record_edit(kind: &str, title_column: &str) -> String {
+    let recent_edit = r#"
+        id = (
+            SELECT a.id
+            FROM activities a
+            WHERE a.target_id = NEW.id
+            ORDER BY a.occurred_at DESC
+            LIMIT 1
+        )
+        AND action = 'updated'
+        AND occurred_at > strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now', '-10 minutes')
+    "#;
+
+    format!(
+        r#"
+        UPDATE activities
+        SET occurred_at = {SQLITE_NOW},
+            title = NEW.{title_column}
+        WHERE {recent_edit};
+
+        INSERT INTO activities (user_id, kind, target_id, action, title)
+        SELECT NEW.user_id, '{kind}', NEW.id, 'updated', NEW.{title_column}
+        WHERE EXISTS (SELECT 1 FROM users u WHERE u.id = NEW.user_id)
+          AND NOT EXISTS (SELECT 1 FROM activities WHERE {recent_edit});
+        "#
+    )
+}
+
+/// The Postgres trigger split per action. As with the webhook triggers, edits
+/// only fire on `UPDATE OF` the content columns, so the nested updates of the
+/// `updated_at` and `deleted_at` triggers don't count as edits.
+fn sqlite_triggers() -> String {
+    let mut sql = String::new();
+
+    for (table, kind, title, columns) in SQLITE_CONTENT_TABLES {
+        let created = sqlite_record("NEW", kind, "created", title);
+        let updated = sqlite_record_edit(kind, title);
+        let trashed = sqlite_record("NEW", kind, "deleted", title);
+        let restored = sqlite_record("NEW", kind, "restored", title);
+        let deleted = sqlite_record("OLD", kind, "deleted", title);
+
+        sql.push_str(&format!(
+            r#"
+            CREATE TRIGGER activity_{table}_insert
+            AFTER INSERT ON {table}
+            FOR EACH ROW
+            BEGIN {created} END;
+
+            CREATE TRIGGER activity_{table}_update
+            AFTER UPDATE OF {columns} ON {table}
+            FOR EACH ROW
+            WHEN NOT NEW.is_deleted AND NOT OLD.is_deleted
+            BEGIN {updated} END;
+
+            CREATE TRIGGER activity_{table}_trash
+            AFTER UPDATE OF is_deleted ON {table}
+            FOR EACH ROW
+            WHEN NEW.is_deleted AND NOT OLD.is_deleted
+            BEGIN {trashed} END;
+
+            CREATE TRIGGER activity_{table}_restore
+            AFTER UPDATE OF is_deleted ON {table}
+            FOR EACH ROW
+            WHEN OLD.is_deleted AND NOT NEW.is_deleted
+            BEGIN {restored} END;
+
+            -- already recorded when it was trashed
+            CREATE TRIGGER activity_{table}_delete
+            AFTER DELETE ON {table}
+            FOR EACH ROW
+            WHEN NOT OLD.is_deleted
+            BEGIN {deleted} END;
+            "#
+        ));
+    }
+
+    // Pages have no trash and no `updated_at` trigger, so any update is an edit
+    sql.push_str(&format!(
+        r#"
+        CREATE TRIGGER activity_pages_insert
+        AFTER INSERT ON pages
+        FOR EACH ROW
+        BEGIN {created} END;
+
+        CREATE TRIGGER activity_pages_publish
+        AFTER UPDATE ON pages
+        FOR EACH ROW
+        WHEN NEW.status = 'published' AND OLD.status IS NOT 'published'
+        BEGIN {published} END;
+
+        CREATE TRIGGER activity_pages_update
+        AFTER UPDATE ON pages
+        FOR EACH ROW
+        WHEN NOT (NEW.status = 'published' AND OLD.status IS NOT 'published')
+        BEGIN {updated} END;
+
+        CREATE TRIGGER activity_pages_delete
+        AFTER DELETE ON pages
+        FOR EACH ROW
+        BEGIN {deleted} END;
+        "#,
+        created = sqlite_record("NEW", "page", "created", "title"),
+        published = sqlite_record("NEW", "page", "published", "title"),
+        updated = sqlite_record_edit("page", "title"),
+        deleted = sqlite_record("OLD", "page", "deleted", "title"),
+    ));
+
+    sql.push_str(&format!(
+        r#"
+        CREATE TRIGGER activity_media_insert
+        AFTER INSERT ON media
+        FOR EACH ROW
+        BEGIN {created} END;
+
+        CREATE TRIGGER activity_media_trash
+        AFTER UPDATE OF deleted_at ON media
+        FOR EACH ROW
+        WHEN NEW.deleted_at IS NOT NULL AND OLD.deleted_at IS NULL
+        BEGIN {trashed} END;
+
+        CREATE TRIGGER activity_media_restore
+        AFTER UPDATE OF deleted_at ON media
+        FOR EACH ROW
+        WHEN NEW.deleted_at IS NULL AND OLD.deleted_at IS NOT NULL
+        BEGIN {restored} END;
+
+        CREATE TRIGGER activity_media_delete
+        AFTER DELETE ON media
+        FOR EACH ROW
+        WHEN OLD.deleted_at IS NULL
+        BEGIN {deleted} END;
+        "#,
+        created = sqlite_record("NEW", "media", "created", "original_filename"),
+        trashed = sqlite_record("NEW", "media", "deleted", "original_filename"),
+        restored = sqlite_record("NEW", "media", "restored", "original_filename"),
+        deleted = sqlite_record("OLD", "media", "deleted", "original_filename"),
+    ));
+
+    sql
+}
+
+#[derive(DeriveIden)]
+enum Activities {
+    Table,
+    Id,
+    UserId,
+    Kind,
+    TargetId,
+    Action,
+    Title,
+    OccurredAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,138 @@
+//! # Content Translations Migration
+//!
+//! ## Purpose
+//! Per-locale text for projects and pages. The content tables keep the
+//! original; a translation only holds the fields that were translated, and
+//! public reads fall back to the original field by field.
+//!
+//! ## Key Columns Explained
+//! - `project_id`, `page_id`: The translated item; exactly one is set. Real
+//!   foreign keys, so translations go when their item is deleted for good.
+//! - `locale`: `ll` or `ll-RR`, normalized (`pt-BR`).
+//! - `field`: Column of the item the text replaces, e.g. `title` or `body`.
+//! - `value`: The translated text.
+//!
+//! ## Indexes
+//! - `idx_content_translations_project`, `idx_content_translations_page`: One
+//!   row per item, locale and field; also serve the lookups by item
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::{now_default, uuid_default};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ContentTranslations::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ContentTranslations::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(uuid_default(manager)),
+                    )
+                    .col(ColumnDef::new(ContentTranslations::ProjectId).uuid())
+                    .col(ColumnDef::new(ContentTranslations::PageId).uuid())
+                    .col(
+                        ColumnDef::new(ContentTranslations::Locale)
+                            .string_len(16)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ContentTranslations::Field)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ContentTranslations::Value).text().not_null())
+                    .col(
+                        ColumnDef::new(ContentTranslations::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .check(Expr::cust("(project_id IS NULL) <> (page_id IS NULL)"))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_content_translations_project_id")
+                            .from(ContentTranslations::Table, ContentTranslations::ProjectId)
+                            .to(Projects::Table, Projects::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_content_translations_page_id")
+                            .from(ContentTranslations::Table, ContentTranslations::PageId)
+                            .to(Pages::Table, Pages::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // NULLs are distinct, so each index only constrains its own kind
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_content_translations_project")
+                    .table(ContentTranslations::Table)
+                    .col(ContentTranslations::ProjectId)
+                    .col(ContentTranslations::Locale)
+                    .col(ContentTranslations::Field)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_content_translations_page")
+                    .table(ContentTranslations::Table)
+                    .col(ContentTranslations::PageId)
+                    .col(ContentTranslations::Locale)
+                    .col(ContentTranslations::Field)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ContentTranslations::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ContentTranslations {
+    Table,
+    Id,
+    ProjectId,
+    PageId,
+    Locale,
+    Field,
+    Value,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Projects {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Pages {
+    Table,
+    Id,
+}
@@ -0,0 +1,51 @@
+//! # Token Version Migration
+//!
+//! ## Purpose
+//! Adds `token_version` to `users`. Every access and refresh token carries
+//! the version it was issued under, and a token whose version is no longer
+//! the user's is rejected; logging out from all devices bumps it.
+//!
+//! ## Key Columns Explained
+//! - `token_version`: Starts at 0, only ever goes up. Tokens issued before
+//!   the column existed carry no version and count as 0.
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(
+                        ColumnDef::new(Users::TokenVersion)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::TokenVersion)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    TokenVersion,
+}
@@ -0,0 +1,51 @@
+//! # Account Activation Migration
+//!
+//! ## Purpose
+//! Adds `is_active` to `users`. A deactivated account can't log in and its
+//! content is hidden from public endpoints, but nothing is deleted; the
+//! owner can reactivate it with their password.
+//!
+//! ## Key Columns Explained
+//! - `is_active`: TRUE for every existing account. Independent of
+//!   `is_deleted`, which is a one-way trip.
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(
+                        ColumnDef::new(Users::IsActive)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::IsActive)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    IsActive,
+}
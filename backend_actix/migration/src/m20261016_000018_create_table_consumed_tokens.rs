@@ -0,0 +1,79 @@
+//! # Consumed Tokens Migration
+//!
+//! ## Purpose
+//! Single-use tokens (email verification) carry a unique `jti` claim. It is
+//! recorded here the first time the token is used, and a token whose `jti`
+//! is already present is refused, so a leaked link can't be replayed.
+//!
+//! ## Key Columns Explained
+//! - `jti`: The token's id; the primary key makes consuming it atomic.
+//! - `user_id`: Who the token was issued to, for auditing.
+//! - `expires_at`: When the token itself expires. Past that it is refused
+//!   anyway, so the row can be deleted.
+//!
+//! ## Indexes
+//! - `idx_consumed_tokens_expires_at`: Purging expired rows
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::now_default;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ConsumedTokens::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ConsumedTokens::Jti)
+                            .string_len(64)
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ConsumedTokens::UserId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(ConsumedTokens::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ConsumedTokens::ConsumedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_consumed_tokens_expires_at")
+                    .table(ConsumedTokens::Table)
+                    .col(ConsumedTokens::ExpiresAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ConsumedTokens::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ConsumedTokens {
+    Table,
+    Jti,
+    UserId,
+    ExpiresAt,
+    ConsumedAt,
+}
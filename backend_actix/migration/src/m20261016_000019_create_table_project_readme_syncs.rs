@@ -0,0 +1,100 @@
+//! # Project README Syncs Migration
+//!
+//! ## Purpose
+//! Records which projects took their description from their repository's
+//! README, and whether it should be kept in sync. A row exists once the
+//! README was synced at least once.
+//!
+//! ## Key Columns Explained
+//! - `project_id`: One row per project; removed with the project.
+//! - `keep_in_sync`: The background job refreshes the description from the
+//!   README while this is set.
+//! - `readme_url`: Where the README was read from, shown to the owner.
+//! - `synced_at`: Last successful sync, which orders the background job.
+//!
+//! ## Indexes
+//! - `idx_project_readme_syncs_due`: Projects kept in sync, oldest sync first
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::now_default;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ProjectReadmeSyncs::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ProjectReadmeSyncs::ProjectId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ProjectReadmeSyncs::KeepInSync)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(ProjectReadmeSyncs::ReadmeUrl)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProjectReadmeSyncs::SyncedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_project_readme_syncs_project_id")
+                            .from(ProjectReadmeSyncs::Table, ProjectReadmeSyncs::ProjectId)
+                            .to(Projects::Table, Projects::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_project_readme_syncs_due")
+                    .table(ProjectReadmeSyncs::Table)
+                    .col(ProjectReadmeSyncs::KeepInSync)
+                    .col(ProjectReadmeSyncs::SyncedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ProjectReadmeSyncs::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ProjectReadmeSyncs {
+    Table,
+    ProjectId,
+    KeepInSync,
+    ReadmeUrl,
+    SyncedAt,
+}
+
+#[derive(DeriveIden)]
+enum Projects {
+    Table,
+    Id,
+}
@@ -0,0 +1,112 @@
+//! # Project Screenshot Migrations Migration
+//!
+//! ## Purpose
+//! Tracks the backfill of the legacy `projects.screenshots` JSON URLs into
+//! media rows attached to the project (role `screenshoot`). The JSON column
+//! stays readable while clients move over; this table is what keeps the
+//! backfill from importing the same URL twice.
+//!
+//! ## Key Columns Explained
+//! - `project_id` + `source_url`: One row per URL of a project.
+//! - `media_id`: The media the URL became; `NULL` when the import failed.
+//!   Set to `NULL` if that media is hard-deleted later, so it can be retried.
+//! - `error`: Why the import failed, for the operator.
+//! - `migrated_at`: When the URL was last attempted.
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::now_default;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ProjectScreenshotMigrations::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ProjectScreenshotMigrations::ProjectId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProjectScreenshotMigrations::SourceUrl)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ProjectScreenshotMigrations::MediaId).uuid())
+                    .col(ColumnDef::new(ProjectScreenshotMigrations::Error).text())
+                    .col(
+                        ColumnDef::new(ProjectScreenshotMigrations::MigratedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(ProjectScreenshotMigrations::ProjectId)
+                            .col(ProjectScreenshotMigrations::SourceUrl),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_project_screenshot_migrations_project_id")
+                            .from(
+                                ProjectScreenshotMigrations::Table,
+                                ProjectScreenshotMigrations::ProjectId,
+                            )
+                            .to(Projects::Table, Projects::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_project_screenshot_migrations_media_id")
+                            .from(
+                                ProjectScreenshotMigrations::Table,
+                                ProjectScreenshotMigrations::MediaId,
+                            )
+                            .to(Media::Table, Media::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(ProjectScreenshotMigrations::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ProjectScreenshotMigrations {
+    Table,
+    ProjectId,
+    SourceUrl,
+    MediaId,
+    Error,
+    MigratedAt,
+}
+
+#[derive(DeriveIden)]
+enum Projects {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Media {
+    Table,
+    Id,
+}
@@ -0,0 +1,111 @@
+//! # Media Failure Code Migration
+//!
+//! ## Purpose
+//! Adds `failure_code` to `media`, for media the server failed itself rather
+//! than the image processor, and a `media.failed` webhook event so the owner
+//! learns an upload has to be retried.
+//!
+//! ## Key Columns Explained
+//! - `failure_code`: e.g. `PROCESSING_TIMEOUT` when the media sat in
+//!   `processing` past the deadline. `NULL` for every other state and for
+//!   failures reported by the processor; any later state change clears it.
+//!
+//! ## Triggers
+//! - `media`: `media.failed` when the status becomes `failed`, with the
+//!   failure code in the event data when there is one
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::{drop_trigger, is_sqlite, sqlite_uuid_text};
+use crate::m20261016_000004_create_table_webhooks::sqlite_enqueue;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Media::Table)
+                    .add_column(ColumnDef::new(Media::FailureCode).string_len(64).null())
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+
+        if is_sqlite(manager) {
+            let failed = sqlite_enqueue(
+                "NEW",
+                "media.failed",
+                &format!(
+                    "json_object('id', {}, 'code', NEW.failure_code)",
+                    sqlite_uuid_text("NEW.id")
+                ),
+            );
+            db.execute_unprepared(&format!(
+                r#"
+                CREATE TRIGGER webhook_media_failed
+                AFTER UPDATE OF status ON media
+                FOR EACH ROW
+                WHEN NEW.status = 'failed' AND OLD.status IS NOT NEW.status
+                BEGIN {failed} END;
+                "#
+            ))
+            .await?;
+            return Ok(());
+        }
+
+        db.execute_unprepared(
+            r#"
+            CREATE OR REPLACE FUNCTION webhook_media_failed() RETURNS TRIGGER AS $$
+            BEGIN
+                PERFORM enqueue_webhook_event(
+                    NEW.user_id,
+                    'media.failed',
+                    jsonb_strip_nulls(jsonb_build_object('id', NEW.id, 'code', NEW.failure_code))
+                );
+                RETURN NEW;
+            END;
+            $$ LANGUAGE plpgsql;
+
+            CREATE TRIGGER webhook_media_failed
+            AFTER UPDATE OF status ON media
+            FOR EACH ROW
+            WHEN (NEW.status = 'failed' AND OLD.status IS DISTINCT FROM NEW.status)
+            EXECUTE FUNCTION webhook_media_failed();
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(&drop_trigger(manager, "webhook_media_failed", "media"))
+            .await?;
+        if !is_sqlite(manager) {
+            db.execute_unprepared("DROP FUNCTION IF EXISTS webhook_media_failed();")
+                .await?;
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Media::Table)
+                    .drop_column(Media::FailureCode)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Media {
+    Table,
+    FailureCode,
+}
@@ -0,0 +1,114 @@
+//! # WebAuthn Credentials Migration
+//!
+//! ## Purpose
+//! Passkeys users registered to sign in with instead of their password.
+//! A user can have several, one per authenticator.
+//!
+//! ## Key Columns Explained
+//! - `credential_id`: The authenticator's id for the credential
+//!   (base64url); unique, so one authenticator can't be registered twice.
+//! - `passkey`: Public key and signature counter, serialized by the WebAuthn
+//!   library. Rewritten after each sign-in when the counter moves.
+//! - `name`: Label the user gave it, e.g. "Work laptop".
+//! - `last_used_at`: Last sign-in with it; `NULL` if never used.
+//!
+//! ## Indexes
+//! - `idx_webauthn_credentials_user_id`: A user's passkeys
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::{now_default, uuid_default};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebauthnCredentials::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(WebauthnCredentials::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(uuid_default(manager)),
+                    )
+                    .col(
+                        ColumnDef::new(WebauthnCredentials::UserId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebauthnCredentials::CredentialId)
+                            .string_len(1024)
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(WebauthnCredentials::Passkey)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebauthnCredentials::Name)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebauthnCredentials::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .col(ColumnDef::new(WebauthnCredentials::LastUsedAt).timestamp_with_time_zone())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_webauthn_credentials_user_id")
+                            .from(WebauthnCredentials::Table, WebauthnCredentials::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webauthn_credentials_user_id")
+                    .table(WebauthnCredentials::Table)
+                    .col(WebauthnCredentials::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WebauthnCredentials::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WebauthnCredentials {
+    Table,
+    Id,
+    UserId,
+    CredentialId,
+    Passkey,
+    Name,
+    CreatedAt,
+    LastUsedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
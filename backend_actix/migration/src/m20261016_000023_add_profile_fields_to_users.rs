@@ -0,0 +1,73 @@
+//! # User Profile Fields Migration
+//!
+//! ## Purpose
+//! Adds the profile settings a user edits at `PATCH /api/auth/me` besides
+//! their name and locale.
+//!
+//! ## Key Columns Explained
+//! - `timezone`: IANA name, e.g. `Europe/Berlin`, dates are shown in.
+//!   `UTC` for every existing account.
+//! - `avatar_media_id`: One of the user's own media, or `NULL`. No foreign
+//!   key, so hard-deleting the media is not blocked; reads skip an avatar
+//!   that is gone or trashed.
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // One column per statement, as SQLite requires
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(
+                        ColumnDef::new(Users::Timezone)
+                            .string_len(64)
+                            .not_null()
+                            .default("UTC"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(ColumnDef::new(Users::AvatarMediaId).uuid().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::AvatarMediaId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::Timezone)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Timezone,
+    AvatarMediaId,
+}
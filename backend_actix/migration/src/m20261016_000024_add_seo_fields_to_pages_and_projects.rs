@@ -0,0 +1,113 @@
+//! # SEO Fields Migration
+//!
+//! ## Purpose
+//! Lets owners control how search engines and link previews treat each
+//! page and project. Pages already have `seo_title` and `seo_description`;
+//! projects get them here.
+//!
+//! ## Key Columns Explained
+//! - `seo_title`: Overrides the title in the document `<title>`.
+//! - `seo_description`: Meta description.
+//! - `canonical_url`: Absolute http(s) URL of the original when the item
+//!   is a copy of something published elsewhere, or `NULL`.
+//! - `noindex`: Asks search engines not to index the item; `false` for
+//!   every existing row.
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // One column per statement, as SQLite requires
+        let columns = [
+            (Pages::Table.into_iden(), canonical_url(Pages::CanonicalUrl)),
+            (Pages::Table.into_iden(), noindex(Pages::Noindex)),
+            (
+                Projects::Table.into_iden(),
+                ColumnDef::new(Projects::SeoTitle)
+                    .string_len(100)
+                    .null()
+                    .to_owned(),
+            ),
+            (
+                Projects::Table.into_iden(),
+                ColumnDef::new(Projects::SeoDescription)
+                    .string_len(300)
+                    .null()
+                    .to_owned(),
+            ),
+            (
+                Projects::Table.into_iden(),
+                canonical_url(Projects::CanonicalUrl),
+            ),
+            (Projects::Table.into_iden(), noindex(Projects::Noindex)),
+        ];
+
+        for (table, mut column) in columns {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .add_column(&mut column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let columns = [
+            (Projects::Table.into_iden(), Projects::Noindex.into_iden()),
+            (
+                Projects::Table.into_iden(),
+                Projects::CanonicalUrl.into_iden(),
+            ),
+            (
+                Projects::Table.into_iden(),
+                Projects::SeoDescription.into_iden(),
+            ),
+            (Projects::Table.into_iden(), Projects::SeoTitle.into_iden()),
+            (Pages::Table.into_iden(), Pages::Noindex.into_iden()),
+            (Pages::Table.into_iden(), Pages::CanonicalUrl.into_iden()),
+        ];
+
+        for (table, column) in columns {
+            manager
+                .alter_table(Table::alter().table(table).drop_column(column).to_owned())
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+fn canonical_url(column: impl IntoIden) -> ColumnDef {
+    ColumnDef::new(column).string_len(2048).null().to_owned()
+}
+
+fn noindex(column: impl IntoIden) -> ColumnDef {
+    ColumnDef::new(column)
+        .boolean()
+        .not_null()
+        .default(false)
+        .to_owned()
+}
+
+#[derive(DeriveIden)]
+enum Pages {
+    Table,
+    CanonicalUrl,
+    Noindex,
+}
+
+#[derive(DeriveIden)]
+enum Projects {
+    Table,
+    SeoTitle,
+    SeoDescription,
+    CanonicalUrl,
+    Noindex,
+}
@@ -0,0 +1,69 @@
+//! # Media Checksums Migration
+//!
+//! ## Purpose
+//! Adds `checksum_sha256` to `media`, the digest of the original file the
+//! client declares when asking for an upload URL. A second upload of the same
+//! file by the same owner is then spotted before it is stored and processed
+//! again.
+//!
+//! ## Key Columns Explained
+//! - `checksum_sha256`: Lowercase hex digest (64 chars). Nullable: declaring
+//!   it is optional, and rows from before this migration have none.
+//!
+//! ## Indexes
+//! - `idx_media_user_checksum`: Find an owner's live media with a checksum
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Media::Table)
+                    .add_column(ColumnDef::new(Media::ChecksumSha256).char_len(64))
+                    .to_owned(),
+            )
+            .await?;
+
+        // Duplicates are only looked for among the owner's live media
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                CREATE INDEX idx_media_user_checksum
+                ON media (user_id, checksum_sha256)
+                WHERE checksum_sha256 IS NOT NULL AND deleted_at IS NULL;
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP INDEX IF EXISTS idx_media_user_checksum;")
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Media::Table)
+                    .drop_column(Media::ChecksumSha256)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Media {
+    Table,
+    ChecksumSha256,
+}
@@ -0,0 +1,82 @@
+//! # Page Edit Locks Migration
+//!
+//! ## Purpose
+//! Advisory locks an editing session takes on a page, so an edit from
+//! another tab or device is refused instead of overwriting it. At most one
+//! lock per page; it lapses unless its session keeps renewing it.
+//!
+//! ## Key Columns Explained
+//! - `page_id`: The locked page; the lock goes with it.
+//! - `lock_id`: Secret the holding session sends with its edits and renewals.
+//! - `label`: What the session calls itself, e.g. "Firefox on laptop", shown
+//!   to the sessions it keeps out; `NULL` if none was given.
+//! - `acquired_at`: When the current holder took the lock. Renewals keep it.
+//! - `expires_at`: The lock is free from this moment on. Expired rows are
+//!   taken over in place rather than deleted.
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PageEditLocks::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PageEditLocks::PageId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(PageEditLocks::LockId).uuid().not_null())
+                    .col(ColumnDef::new(PageEditLocks::Label).string_len(100).null())
+                    .col(
+                        ColumnDef::new(PageEditLocks::AcquiredAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PageEditLocks::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_page_edit_locks_page_id")
+                            .from(PageEditLocks::Table, PageEditLocks::PageId)
+                            .to(Pages::Table, Pages::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PageEditLocks::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PageEditLocks {
+    Table,
+    PageId,
+    LockId,
+    Label,
+    AcquiredAt,
+    ExpiresAt,
+}
+
+#[derive(DeriveIden)]
+enum Pages {
+    Table,
+    Id,
+}
@@ -0,0 +1,77 @@
+//! # Page Tombstones Migration
+//!
+//! ## Purpose
+//! Remembers the slugs of deleted pages that had been published, so the
+//! public endpoint can answer 410 Gone for them instead of 404 and crawlers
+//! drop the URL. Pages are hard-deleted, so nothing else is left to look at.
+//!
+//! ## Key Columns Explained
+//! - `user_id` + `slug`: One row per slug of an owner. Deleting another
+//!   published page at the same slug later just moves `deleted_at`.
+//! - `deleted_at`: When the page was deleted.
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::now_default;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PageTombstones::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(PageTombstones::UserId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(PageTombstones::Slug)
+                            .string_len(100)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PageTombstones::DeletedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(PageTombstones::UserId)
+                            .col(PageTombstones::Slug),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_page_tombstones_user_id")
+                            .from(PageTombstones::Table, PageTombstones::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PageTombstones::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PageTombstones {
+    Table,
+    UserId,
+    Slug,
+    DeletedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
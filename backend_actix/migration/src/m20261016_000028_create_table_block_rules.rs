@@ -0,0 +1,96 @@
+//! # Block Rules Migration
+//!
+//! ## Purpose
+//! Client addresses and user agents the administrators have blocked from the
+//! public write endpoints (contact form, registration, page views), so a
+//! spam wave can be stopped without a deploy.
+//!
+//! ## Key Columns Explained
+//! - `kind`: `ip` or `user_agent`.
+//! - `pattern`: For `ip`, a CIDR range in canonical form (`203.0.113.0/24`,
+//!   a single address is `/32` or `/128`); for `user_agent`, a substring
+//!   matched case-insensitively.
+//! - `note`: Why the rule was added, for whoever reviews the list later.
+//! - `hits`: Requests the rule has blocked; incremented in place.
+//! - `last_hit_at`: Last blocked request; `NULL` until then.
+//!
+//! ## Indexes
+//! - `idx_block_rules_kind_pattern`: Unique; the same rule can't be added twice
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::{now_default, uuid_default};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BlockRules::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BlockRules::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(uuid_default(manager)),
+                    )
+                    .col(ColumnDef::new(BlockRules::Kind).string_len(16).not_null())
+                    .col(
+                        ColumnDef::new(BlockRules::Pattern)
+                            .string_len(200)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(BlockRules::Note).string_len(200))
+                    .col(
+                        ColumnDef::new(BlockRules::Hits)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(BlockRules::LastHitAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(BlockRules::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_block_rules_kind_pattern")
+                    .table(BlockRules::Table)
+                    .col(BlockRules::Kind)
+                    .col(BlockRules::Pattern)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BlockRules::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum BlockRules {
+    Table,
+    Id,
+    Kind,
+    Pattern,
+    Note,
+    Hits,
+    LastHitAt,
+    CreatedAt,
+}
@@ -0,0 +1,111 @@
+//! # Project Updates Migration
+//!
+//! ## Purpose
+//! Dated progress entries of a project, shown as its timeline on the public
+//! project page, so ongoing work can show more than a static description.
+//!
+//! ## Key Columns Explained
+//! - `project_id`: The project the entry belongs to; removed with it.
+//! - `title`: Short heading, e.g. a version or milestone.
+//! - `body`: Markdown.
+//! - `posted_at`: The date the entry is shown under. Defaults to creation
+//!   time, and can be set earlier to backfill a timeline.
+//!
+//! ## Indexes
+//! - `idx_project_updates_project_posted`: A project's entries, newest first
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::{now_default, uuid_default};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ProjectUpdates::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ProjectUpdates::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(uuid_default(manager)),
+                    )
+                    .col(ColumnDef::new(ProjectUpdates::ProjectId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(ProjectUpdates::Title)
+                            .string_len(200)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ProjectUpdates::Body).text().not_null())
+                    .col(
+                        ColumnDef::new(ProjectUpdates::PostedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .col(
+                        ColumnDef::new(ProjectUpdates::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .col(
+                        ColumnDef::new(ProjectUpdates::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_project_updates_project_id")
+                            .from(ProjectUpdates::Table, ProjectUpdates::ProjectId)
+                            .to(Projects::Table, Projects::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_project_updates_project_posted")
+                    .table(ProjectUpdates::Table)
+                    .col(ProjectUpdates::ProjectId)
+                    .col(ProjectUpdates::PostedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ProjectUpdates::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ProjectUpdates {
+    Table,
+    Id,
+    ProjectId,
+    Title,
+    Body,
+    PostedAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Projects {
+    Table,
+    Id,
+}
@@ -0,0 +1,281 @@
+//! # Skills Migration
+//!
+//! ## Purpose
+//! One catalog of the skills named in CV `core_skills` and project
+//! `tech_stack`, for autocomplete, so "PostgreSQL", "Postgres" and "postgres"
+//! can be folded into one skill instead of showing up as three.
+//!
+//! ## Key Columns Explained
+//! - `skills.name`: How the skill is shown, e.g. `PostgreSQL`.
+//! - `skills.name_key`: `name` trimmed and lowercased; spellings that only
+//!   differ in case are the same skill.
+//! - `skill_aliases.name_key`: Another spelling of a skill (`postgres`), left
+//!   behind by a merge or rename. Content using it is not added again.
+//!
+//! ## Triggers
+//! - `resumes`: core skill titles, on insert and on updates of `core_skills`
+//! - `projects`: tech stack entries, on insert and on updates of `tech_stack`
+//!
+//! Names that are blank, longer than 100 characters, or already known as a
+//! skill or alias are skipped. Skills are never removed when the content
+//! naming them changes. Existing CVs and projects are read in once here.
+//!
+//! ## Indexes
+//! - `idx_skills_name_key`: Unique; one skill per spelling
+//! - `skill_aliases` primary key: one skill per alias
+//! - `idx_skill_aliases_skill_id`: A skill's aliases, moved along on a merge
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::{is_sqlite, now_default, uuid_default};
+
+/// Skill names in each table: (table, JSON array column, key of the name in
+/// one entry, or `""` for an array of plain strings)
+const SKILL_SOURCES: [(&str, &str, &str); 2] = [
+    ("resumes", "core_skills", "title"),
+    ("projects", "tech_stack", ""),
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Skills::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Skills::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(uuid_default(manager)),
+                    )
+                    .col(ColumnDef::new(Skills::Name).string_len(100).not_null())
+                    .col(ColumnDef::new(Skills::NameKey).string_len(100).not_null())
+                    .col(
+                        ColumnDef::new(Skills::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_skills_name_key")
+                    .table(Skills::Table)
+                    .col(Skills::NameKey)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(SkillAliases::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SkillAliases::NameKey)
+                            .string_len(100)
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SkillAliases::SkillId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(SkillAliases::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_skill_aliases_skill_id")
+                            .from(SkillAliases::Table, SkillAliases::SkillId)
+                            .to(Skills::Table, Skills::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_skill_aliases_skill_id")
+                    .table(SkillAliases::Table)
+                    .col(SkillAliases::SkillId)
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+        let sqlite = is_sqlite(manager);
+
+        // =====================================================
+        // Recording
+        // =====================================================
+
+        if sqlite {
+            for (table, column, key) in SKILL_SOURCES {
+                db.execute_unprepared(&format!(
+                    r#"
+                    CREATE TRIGGER skills_{table}_insert
+                    AFTER INSERT ON {table}
+                    FOR EACH ROW
+                    BEGIN
+                        {record}
+                    END;
+
+                    CREATE TRIGGER skills_{table}_update
+                    AFTER UPDATE OF {column} ON {table}
+                    FOR EACH ROW
+                    BEGIN
+                        {record}
+                    END;
+                    "#,
+                    record = record_skills(
+                        true,
+                        &format!("json_each(NEW.{column})"),
+                        &format!("'{key}'")
+                    ),
+                ))
+                .await?;
+            }
+        } else {
+            db.execute_unprepared(&format!(
+                r#"
+                -- Arguments: JSON array column, key of the name in one entry
+                CREATE OR REPLACE FUNCTION skills_content_event() RETURNS TRIGGER AS $$
+                DECLARE
+                    entries JSONB := to_jsonb(NEW) -> TG_ARGV[0];
+                BEGIN
+                    IF jsonb_typeof(entries) = 'array' THEN
+                        {record}
+                    END IF;
+                    RETURN NEW;
+                END;
+                $$ LANGUAGE plpgsql;
+
+                CREATE TRIGGER skills_resumes_event
+                AFTER INSERT OR UPDATE OF core_skills ON resumes
+                FOR EACH ROW
+                EXECUTE FUNCTION skills_content_event('core_skills', 'title');
+
+                CREATE TRIGGER skills_projects_event
+                AFTER INSERT OR UPDATE OF tech_stack ON projects
+                FOR EACH ROW
+                EXECUTE FUNCTION skills_content_event('tech_stack', '');
+                "#,
+                record = record_skills(
+                    false,
+                    "jsonb_array_elements(entries) AS e(value)",
+                    "TG_ARGV[1]"
+                ),
+            ))
+            .await?;
+        }
+
+        // =====================================================
+        // Backfill
+        // =====================================================
+
+        for (table, column, key) in SKILL_SOURCES {
+            let entries = if sqlite {
+                format!("{table} t, json_each(t.{column})")
+            } else {
+                format!(
+                    "(SELECT {column} FROM {table} WHERE jsonb_typeof({column}) = 'array') t, \
+                     jsonb_array_elements(t.{column}) AS e(value)"
+                )
+            };
+            db.execute_unprepared(&record_skills(sqlite, &entries, &format!("'{key}'")))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        if is_sqlite(manager) {
+            for (table, _, _) in SKILL_SOURCES {
+                db.execute_unprepared(&format!(
+                    r#"
+                    DROP TRIGGER IF EXISTS skills_{table}_insert;
+                    DROP TRIGGER IF EXISTS skills_{table}_update;
+                    "#
+                ))
+                .await?;
+            }
+        } else {
+            db.execute_unprepared(
+                r#"
+                DROP TRIGGER IF EXISTS skills_projects_event ON projects;
+                DROP TRIGGER IF EXISTS skills_resumes_event ON resumes;
+                DROP FUNCTION IF EXISTS skills_content_event();
+                "#,
+            )
+            .await?;
+        }
+
+        manager
+            .drop_table(Table::drop().table(SkillAliases::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Skills::Table).to_owned())
+            .await
+    }
+}
+
+/// Adds every new skill named in `entries`, a `FROM` list whose rows have
+/// one JSON array entry in `value`. `key` is an SQL expression for the key of
+/// the name in an entry, `''` when entries are plain strings. Names seen
+/// twice, or already known, are left to `ON CONFLICT` and the alias check.
+fn record_skills(sqlite: bool, entries: &str, key: &str) -> String {
+    let name = if sqlite {
+        format!("trim(CASE WHEN {key} = '' THEN value ELSE json_extract(value, '$.' || {key}) END)")
+    } else {
+        format!("btrim(CASE WHEN {key} = '' THEN value #>> '{{}}' ELSE value ->> {key} END)")
+    };
+
+    format!(
+        r#"
+        INSERT INTO skills (name, name_key)
+        SELECT name, lower(name)
+        FROM (SELECT {name} AS name FROM {entries}) named
+        WHERE name IS NOT NULL
+          AND name <> ''
+          AND length(name) <= 100
+          AND lower(name) NOT IN (SELECT a.name_key FROM skill_aliases a)
+        ON CONFLICT (name_key) DO NOTHING;
+        "#
+    )
+}
+
+#[derive(DeriveIden)]
+enum Skills {
+    Table,
+    Id,
+    Name,
+    NameKey,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum SkillAliases {
+    Table,
+    NameKey,
+    SkillId,
+    CreatedAt,
+}
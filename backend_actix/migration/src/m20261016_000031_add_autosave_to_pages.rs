@@ -0,0 +1,65 @@
+//! # Page Autosave Migration
+//!
+//! ## Purpose
+//! Keeps the editor's unsaved body next to the page, so work in progress
+//! survives a crashed tab or browser. The page's own `body` is untouched
+//! until the owner saves.
+//!
+//! ## Key Columns Explained
+//! - `autosave_body`: Latest body the editor autosaved, or `NULL`. Cleared
+//!   when the page is published.
+//! - `autosaved_at`: When the editor took that autosave. Only newer than
+//!   `updated_at` is it offered back; older ones were saved or published
+//!   over.
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // One column per statement, as SQLite requires
+        let columns = [
+            ColumnDef::new(Pages::AutosaveBody).text().null().to_owned(),
+            ColumnDef::new(Pages::AutosavedAt)
+                .timestamp_with_time_zone()
+                .null()
+                .to_owned(),
+        ];
+
+        for mut column in columns {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Pages::Table)
+                        .add_column(&mut column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [Pages::AutosavedAt, Pages::AutosaveBody] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Pages::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Pages {
+    Table,
+    AutosaveBody,
+    AutosavedAt,
+}
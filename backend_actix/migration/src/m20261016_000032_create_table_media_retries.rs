@@ -0,0 +1,92 @@
+//! # Media Retries Migration
+//!
+//! ## Purpose
+//! Queue of media whose processing failed, waiting for the backend to decide
+//! between another run of the image processor and giving up. The status
+//! updater files a failed manifest here instead of marking the media
+//! `failed`, so a passing download or upload error never reaches the owner.
+//!
+//! ## Key Columns Explained
+//! - `media_id`: The failed media; one row per media, gone with it.
+//! - `failure_code`: `error.code` of the latest failed manifest.
+//! - `attempts`: Retries already sent to the processor.
+//! - `failed_at`: When the latest failure was filed; `NULL` while a retry
+//!   is running. Rows with a value are the ones waiting for a decision.
+//! - `last_attempt_at`: When the latest retry was sent, `NULL` before the
+//!   first.
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(MediaRetries::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(MediaRetries::MediaId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(MediaRetries::FailureCode)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(MediaRetries::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(MediaRetries::FailedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(MediaRetries::LastAttemptAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_media_retries_media_id")
+                            .from(MediaRetries::Table, MediaRetries::MediaId)
+                            .to(Media::Table, Media::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(MediaRetries::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum MediaRetries {
+    Table,
+    MediaId,
+    FailureCode,
+    Attempts,
+    FailedAt,
+    LastAttemptAt,
+}
+
+#[derive(DeriveIden)]
+enum Media {
+    Table,
+    Id,
+}
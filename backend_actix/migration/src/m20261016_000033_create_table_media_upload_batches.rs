@@ -0,0 +1,92 @@
+//! # Media Upload Batches Migration
+//!
+//! ## Purpose
+//! Groups the media registered by one `POST /api/media/upload-batch`, so the
+//! client can follow all of them with a single status request. A batch has
+//! no row of its own; it is the media sharing a `batch_id`.
+//!
+//! ## Key Columns Explained
+//! - `batch_id`: Returned with the upload URLs; shared by the batch's media.
+//! - `media_id`: One of the batch's media; a media is in at most one batch
+//!   and leaves it when purged.
+//! - `position`: The file's index in the request, for listing them in order.
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(MediaUploadBatches::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(MediaUploadBatches::BatchId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(MediaUploadBatches::MediaId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(MediaUploadBatches::Position)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(MediaUploadBatches::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_media_upload_batches_media_id")
+                            .from(MediaUploadBatches::Table, MediaUploadBatches::MediaId)
+                            .to(Media::Table, Media::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_media_upload_batches_batch_id")
+                    .table(MediaUploadBatches::Table)
+                    .col(MediaUploadBatches::BatchId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(MediaUploadBatches::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum MediaUploadBatches {
+    Table,
+    BatchId,
+    MediaId,
+    Position,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Media {
+    Table,
+    Id,
+}
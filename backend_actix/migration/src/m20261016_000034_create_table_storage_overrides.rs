@@ -0,0 +1,153 @@
+//! # Storage Overrides Migration
+//!
+//! ## Purpose
+//! Buckets an account's media is stored in instead of the server's, with the
+//! service account key that reaches them, so a heavy user can pay for their
+//! own storage. Set by administrators; one row per account at most.
+//!
+//! ## Key Columns Explained
+//! - `owner_id`: The account whose new uploads go to these buckets.
+//! - `upload_bucket` / `ready_bucket`: Where uploads land and where the image
+//!   processor writes their variants. A bucket belongs to one account.
+//! - `tenant`: The image processor's `TENANT_ROUTES` entry writing to
+//!   `ready_bucket`; signed into upload URLs as the `tenant` metadata.
+//! - `credentials`: Service account key JSON, encrypted with
+//!   `FIELD_ENCRYPTION_KEYS` under the owner's subkey.
+//!
+//! ## Indexes
+//! - `idx_storage_overrides_owner_id`: Unique; one override per account
+//! - `idx_storage_overrides_upload_bucket`, `idx_storage_overrides_ready_bucket`:
+//!   Unique; signing finds the credentials of a bucket by its name
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::{drop_trigger, now_default, updated_at_trigger, uuid_default};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(StorageOverrides::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(StorageOverrides::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(uuid_default(manager)),
+                    )
+                    .col(ColumnDef::new(StorageOverrides::OwnerId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(StorageOverrides::UploadBucket)
+                            .string_len(63)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StorageOverrides::ReadyBucket)
+                            .string_len(63)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StorageOverrides::Tenant)
+                            .string_len(63)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StorageOverrides::Credentials)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StorageOverrides::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .col(
+                        ColumnDef::new(StorageOverrides::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(now_default(manager)),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_storage_overrides_owner_id")
+                            .from(StorageOverrides::Table, StorageOverrides::OwnerId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        for (name, column) in [
+            ("idx_storage_overrides_owner_id", StorageOverrides::OwnerId),
+            (
+                "idx_storage_overrides_upload_bucket",
+                StorageOverrides::UploadBucket,
+            ),
+            (
+                "idx_storage_overrides_ready_bucket",
+                StorageOverrides::ReadyBucket,
+            ),
+        ] {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(name)
+                        .table(StorageOverrides::Table)
+                        .col(column)
+                        .unique()
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .get_connection()
+            .execute_unprepared(&updated_at_trigger(manager, "storage_overrides"))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(&drop_trigger(
+                manager,
+                "update_storage_overrides_updated_at",
+                "storage_overrides",
+            ))
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(StorageOverrides::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum StorageOverrides {
+    Table,
+    Id,
+    OwnerId,
+    UploadBucket,
+    ReadyBucket,
+    Tenant,
+    Credentials,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
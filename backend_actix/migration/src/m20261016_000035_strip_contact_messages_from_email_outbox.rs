@@ -0,0 +1,43 @@
+//! # Strip Contact Messages From The Email Outbox Migration
+//!
+//! ## Purpose
+//! `contact_message` rows of `email_outbox` used to carry the sender and the
+//! message in `payload`, in plaintext next to the encrypted copy in
+//! `contact_messages`. The dispatcher now reads both from `contact_messages`
+//! when it sends, so the copies are removed from rows already queued or sent.
+//!
+//! Not reversible: the removed text is still in `contact_messages` unless the
+//! message was deleted.
+
+use sea_orm_migration::prelude::*;
+
+use crate::db_backend::is_sqlite;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let strip = if is_sqlite(manager) {
+            r#"
+            UPDATE email_outbox
+            SET payload = json_remove(payload, '$.sender_name', '$.sender_email', '$.subject', '$.message')
+            WHERE kind = 'contact_message'
+            "#
+        } else {
+            r#"
+            UPDATE email_outbox
+            SET payload = payload - 'sender_name' - 'sender_email' - 'subject' - 'message'
+            WHERE kind = 'contact_message'
+            "#
+        };
+
+        manager.get_connection().execute_unprepared(strip).await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+//! Default bucket names and the object names inside them.
+//!
+//! - upload bucket: `{media_id}/{original_name}`, written by the client
+//! - ready bucket: `variants/{media_id}/...`, written by the processor
+//! - manifest bucket: `{media_id}/manifest.json`, written by the processor
+
+/// Where clients upload originals (`MULTIMEDIA_UPLOAD_BUCKET`)
+pub const DEFAULT_UPLOAD_BUCKET: &str = "blogport-cms-upload";
+/// Where the processor writes variants (`OUTPUT_BUCKET`, `MULTIMEDIA_READY_BUCKET`)
+pub const DEFAULT_READY_BUCKET: &str = "blogport-cms-ready";
+/// Where the processor writes manifests (`MANIFEST_BUCKET`)
+pub const DEFAULT_MANIFEST_BUCKET: &str = "blogport-cms-manifests";
+
+/// google-cloud-storage names buckets as `projects/_/buckets/{bucket}`
+pub fn bucket_resource(bucket: &str) -> String {
+    format!("projects/_/buckets/{bucket}")
+}
+
+/// Object name of an original in the upload bucket
+pub fn upload_object_key(media_id: &str, original_name: &str) -> String {
+    format!("{media_id}/{original_name}")
+}
+
+/// Object name of a media's manifest in the manifest bucket
+pub fn manifest_object_key(media_id: &str) -> String {
+    format!("{media_id}/manifest.json")
+}
+
+/// The media id an uploaded object belongs to: its folder, or for a
+/// top-level object its name without the extension
+pub fn media_id_from_object_key(name: &str) -> &str {
+    match name.split_once('/') {
+        Some((folder, _)) => folder,
+        None => name.rsplit_once('.').map(|(stem, _)| stem).unwrap_or(name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upload_key_round_trips_to_its_media_id() {
+        let key = upload_object_key("7f3c", "cat.png");
+        assert_eq!(key, "7f3c/cat.png");
+        assert_eq!(media_id_from_object_key(&key), "7f3c");
+    }
+
+    #[test]
+    fn test_top_level_object_is_named_after_its_media() {
+        assert_eq!(media_id_from_object_key("7f3c.png"), "7f3c");
+        assert_eq!(media_id_from_object_key("7f3c"), "7f3c");
+    }
+
+    #[test]
+    fn test_manifest_key_and_bucket_resource() {
+        assert_eq!(manifest_object_key("7f3c"), "7f3c/manifest.json");
+        assert_eq!(
+            bucket_resource(DEFAULT_MANIFEST_BUCKET),
+            "projects/_/buckets/blogport-cms-manifests"
+        );
+    }
+}
@@ -0,0 +1,46 @@
+//! `error.code` of a failed manifest. Stable; messages may change.
+
+/// Not a JPEG, PNG, WebP, PDF or SVG
+pub const INVALID_TYPE: &str = "INVALID_TYPE";
+/// Skipped on its name and content type, before downloading
+pub const INVALID_FILE_TYPE: &str = "INVALID_FILE_TYPE";
+pub const MAX_SIZE_EXCEEDED: &str = "MAX_SIZE_EXCEEDED";
+pub const MAX_PIXELS_EXCEEDED: &str = "MAX_PIXELS_EXCEEDED";
+pub const MAX_DIM_EXCEEDED: &str = "MAX_DIM_EXCEEDED";
+pub const DECODE_FAILED: &str = "DECODE_FAILED";
+/// Refused by the moderation service
+pub const CONTENT_REJECTED: &str = "CONTENT_REJECTED";
+/// The moderation service could not be reached and fails closed
+pub const MODERATION_ERROR: &str = "MODERATION_ERROR";
+pub const DOWNLOAD_ERROR: &str = "DOWNLOAD_ERROR";
+pub const UPLOAD_ERROR: &str = "UPLOAD_ERROR";
+pub const INTERNAL_ERROR: &str = "INTERNAL_ERROR";
+/// Set by the backend, not the processor: `processing` for longer than the
+/// deadline with no manifest
+pub const PROCESSING_TIMEOUT: &str = "PROCESSING_TIMEOUT";
+
+pub const ALL: [&str; 12] = [
+    INVALID_TYPE,
+    INVALID_FILE_TYPE,
+    MAX_SIZE_EXCEEDED,
+    MAX_PIXELS_EXCEEDED,
+    MAX_DIM_EXCEEDED,
+    DECODE_FAILED,
+    CONTENT_REJECTED,
+    MODERATION_ERROR,
+    DOWNLOAD_ERROR,
+    UPLOAD_ERROR,
+    INTERNAL_ERROR,
+    PROCESSING_TIMEOUT,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_codes_are_unique() {
+        assert_eq!(ALL.iter().collect::<HashSet<_>>().len(), ALL.len());
+    }
+}
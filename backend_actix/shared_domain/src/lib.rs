@@ -0,0 +1,13 @@
+//! What the backend and the image processor have to agree on: the manifest
+//! the processor writes and the backend reads, the failure codes in it, the
+//! variant sizes, the upload limits, and where uploads, variants and
+//! manifests are stored.
+//!
+//! Both sides depend on this crate instead of keeping their own copy, so a
+//! change to the format is a change to both.
+
+pub mod buckets;
+pub mod error_codes;
+pub mod limits;
+pub mod manifest;
+pub mod variants;
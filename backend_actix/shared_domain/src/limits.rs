@@ -0,0 +1,22 @@
+//! What an upload may be. The backend checks the declared metadata against
+//! these before it signs an upload URL, tells clients about them at
+//! `GET /api/media/upload-policy`, and the processor enforces them on the
+//! actual bytes.
+
+/// Largest image original
+pub const MAX_IMAGE_BYTES: u64 = 5 * 1024 * 1024;
+/// Largest document (PDF) original; they are routinely larger than images
+pub const MAX_DOCUMENT_BYTES: u64 = 20 * 1024 * 1024;
+/// Longest side of an image, in pixels
+pub const MAX_DIMENSION: u32 = 6000;
+/// Most pixels an image may have, whatever its sides
+pub const MAX_TOTAL_PIXELS: u64 = 20_000_000;
+
+/// Types an original may have
+pub const ALLOWED_MIME_TYPES: &[&str] = &[
+    "image/jpeg",
+    "image/png",
+    "image/webp",
+    "image/svg+xml",
+    "application/pdf",
+];
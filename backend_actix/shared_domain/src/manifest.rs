@@ -0,0 +1,207 @@
+//! The manifest the image processor writes to `{media_id}/manifest.json` in
+//! the manifest bucket once a media is done, ready or failed, and POSTs to
+//! the completion callback unchanged.
+
+use serde::{Deserialize, Serialize};
+
+/// Layout of the outputs and of this manifest. Bumped when widths, quality
+/// or encoders change; older ready manifests are then migrated.
+pub const PIPELINE_VERSION: &str = "v1";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ManifestState {
+    Ready,
+    Failed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestOriginal {
+    pub bucket: String,
+    pub path: String,
+    /// Lowercase hex SHA-256 of the uploaded bytes
+    pub sha256: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestVariant {
+    /// Width, one of [`crate::variants::VariantSize`]
+    pub size: u32,
+    pub path: String,
+    pub width: u32,
+    pub height: u32,
+    pub file_size_bytes: usize,
+    pub sha256: String,
+    pub quality: u32,
+    pub has_alpha: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flattened_onto: Option<String>,
+    /// PNG fallback (SVG uploads only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub png_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub png_sha256: Option<String>,
+}
+
+/// A single file written next to the variants
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestFile {
+    pub path: String,
+    pub file_size_bytes: usize,
+    pub sha256: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestMetrics {
+    pub total_ms: u64,
+    pub download_ms: u64,
+    pub processing_ms: u64,
+    pub upload_ms: u64,
+    pub encoder: String,
+    pub quality: u32,
+    pub resize_filter: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestError {
+    /// One of [`crate::error_codes`]
+    pub code: String,
+    pub message: String,
+    /// "validation" | "download" | "processing" | "moderation" | "upload"
+    pub stage: String,
+}
+
+/// What a finished media produced; the body of [`Manifest::Ready`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReadyManifest {
+    pub media_id: String,
+    pub pipeline_version: String,
+    pub updated_at: String,
+    /// "image" | "document"
+    pub kind: String,
+    /// Tenant the outputs were routed to (`TENANT_ROUTES`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+    /// Bucket the variants, sanitized SVG and passthrough copy were written
+    /// to; missing from manifests written before it was recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_bucket: Option<String>,
+    pub original: ManifestOriginal,
+    /// Sanitized SVG, served in place of the uploaded markup
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub svg: Option<ManifestFile>,
+    /// Metadata-stripped copy of the original for full-size links
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub passthrough: Option<ManifestFile>,
+    pub variants: Vec<ManifestVariant>,
+    pub metrics: ManifestMetrics,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "state")]
+pub enum Manifest {
+    /// Boxed, being several times the size of `Failed`
+    #[serde(rename = "ready")]
+    Ready(Box<ReadyManifest>),
+    #[serde(rename = "failed")]
+    Failed {
+        media_id: String,
+        pipeline_version: String,
+        updated_at: String,
+        error: ManifestError,
+    },
+}
+
+/// The fields every manifest has had since the first pipeline version, for
+/// readers that only need to know where a media stands. Unlike [`Manifest`]
+/// it still parses manifests written by an older pipeline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestHeader {
+    pub state: ManifestState,
+    pub media_id: String,
+    pub pipeline_version: String,
+    pub updated_at: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error_codes::DECODE_FAILED;
+
+    fn failed() -> Manifest {
+        Manifest::Failed {
+            media_id: "m1".to_string(),
+            pipeline_version: PIPELINE_VERSION.to_string(),
+            updated_at: "2026-10-16T00:00:00Z".to_string(),
+            error: ManifestError {
+                code: DECODE_FAILED.to_string(),
+                message: "Truncated file".to_string(),
+                stage: "validation".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_state_is_the_tag() {
+        let json = serde_json::to_value(failed()).unwrap();
+        assert_eq!(json["state"], "failed");
+        assert_eq!(json["error"]["code"], "DECODE_FAILED");
+
+        let back: Manifest = serde_json::from_value(json).unwrap();
+        assert_eq!(back, failed());
+    }
+
+    #[test]
+    fn test_ready_fields_sit_next_to_the_tag() {
+        let ready = Manifest::Ready(Box::new(ReadyManifest {
+            media_id: "m1".to_string(),
+            pipeline_version: PIPELINE_VERSION.to_string(),
+            updated_at: "2026-10-16T00:00:00Z".to_string(),
+            kind: "image".to_string(),
+            tenant: None,
+            output_bucket: Some("ready".to_string()),
+            original: ManifestOriginal {
+                bucket: "uploads".to_string(),
+                path: "m1/original.png".to_string(),
+                sha256: "ab".repeat(32),
+                width: Some(640),
+                height: Some(480),
+            },
+            svg: None,
+            passthrough: None,
+            variants: vec![],
+            metrics: ManifestMetrics {
+                total_ms: 10,
+                download_ms: 2,
+                processing_ms: 5,
+                upload_ms: 3,
+                encoder: "webp".to_string(),
+                quality: 80,
+                resize_filter: "lanczos3".to_string(),
+            },
+        }));
+
+        let json = serde_json::to_value(&ready).unwrap();
+        assert_eq!(json["state"], "ready");
+        assert_eq!(json["media_id"], "m1");
+        assert_eq!(json["original"]["width"], 640);
+        assert_eq!(json["output_bucket"], "ready");
+
+        let back: Manifest = serde_json::from_value(json).unwrap();
+        assert_eq!(back, ready);
+    }
+
+    #[test]
+    fn test_header_reads_a_full_manifest() {
+        let json = serde_json::to_vec(&failed()).unwrap();
+        let header: ManifestHeader = serde_json::from_slice(&json).unwrap();
+
+        assert_eq!(header.state, ManifestState::Failed);
+        assert_eq!(header.media_id, "m1");
+        assert_eq!(header.pipeline_version, PIPELINE_VERSION);
+    }
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+/// The sizes every image gets. A manifest variant's `size` is its width;
+/// the thumbnail is a square of that side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VariantSize {
+    Thumbnail,
+    Small,
+    Medium,
+    Large,
+}
+
+impl VariantSize {
+    pub const ALL: [VariantSize; 4] = [
+        VariantSize::Thumbnail,
+        VariantSize::Small,
+        VariantSize::Medium,
+        VariantSize::Large,
+    ];
+
+    /// Resized to a width, keeping the aspect ratio; the thumbnail is cropped
+    pub const RESIZED: [VariantSize; 3] =
+        [VariantSize::Small, VariantSize::Medium, VariantSize::Large];
+
+    pub const fn width(self) -> u32 {
+        match self {
+            VariantSize::Thumbnail => 150,
+            VariantSize::Small => 320,
+            VariantSize::Medium => 768,
+            VariantSize::Large => 1200,
+        }
+    }
+
+    pub fn from_width(width: u32) -> Option<Self> {
+        Self::ALL.into_iter().find(|size| size.width() == width)
+    }
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            VariantSize::Thumbnail => "thumbnail",
+            VariantSize::Small => "small",
+            VariantSize::Medium => "medium",
+            VariantSize::Large => "large",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_width_round_trips() {
+        for size in VariantSize::ALL {
+            assert_eq!(VariantSize::from_width(size.width()), Some(size));
+        }
+        assert_eq!(VariantSize::from_width(640), None);
+    }
+
+    #[test]
+    fn test_serializes_as_its_name() {
+        for size in VariantSize::ALL {
+            assert_eq!(
+                serde_json::to_value(size).unwrap(),
+                serde_json::Value::from(size.as_str())
+            );
+        }
+    }
+}
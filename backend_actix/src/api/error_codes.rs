@@ -0,0 +1,157 @@
+use actix_web::{get, Responder};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::admin::adapter::incoming::web::error_codes as admin;
+use crate::analytics::adapter::incoming::web::error_codes as analytics;
+use crate::api::schemas::SuccessResponse;
+use crate::auth::adapter::incoming::web::error_codes as auth;
+use crate::batch::adapter::incoming::web::error_codes as batch;
+use crate::blocklist::adapter::incoming::web::error_codes as blocklist;
+use crate::contact::adapter::incoming::web::error_codes as contact;
+use crate::cv::adapter::incoming::web::error_codes as cv;
+use crate::email::adapter::incoming::web::error_codes as email;
+use crate::import::adapter::incoming::web::error_codes as import;
+use crate::multimedia::adapter::incoming::web::error_codes as multimedia;
+use crate::pages::adapter::incoming::web::error_codes as pages;
+use crate::project::adapter::incoming::web::error_codes as project;
+use crate::redirects::adapter::incoming::web::error_codes as redirects;
+use crate::search::adapter::incoming::web::error_codes as search;
+use crate::shared::api::{error_codes as common, ApiResponse, ErrorCode};
+use crate::site::adapter::incoming::web::error_codes as site;
+use crate::skills::adapter::incoming::web::error_codes as skills;
+use crate::topic::adapter::incoming::web::error_codes as topic;
+use crate::translations::adapter::incoming::web::error_codes as translations;
+use crate::trash::adapter::incoming::web::error_codes as trash;
+use crate::webhooks::adapter::incoming::web::error_codes as webhooks;
+
+/// Every code the API answers with, by the module declaring it. `common`
+/// holds the codes several modules share.
+const MODULES: &[(&str, &[ErrorCode])] = &[
+    ("common", common::ALL),
+    ("admin", admin::ALL),
+    ("analytics", analytics::ALL),
+    ("auth", auth::ALL),
+    ("batch", batch::ALL),
+    ("blocklist", blocklist::ALL),
+    ("contact", contact::ALL),
+    ("cv", cv::ALL),
+    ("email", email::ALL),
+    ("import", import::ALL),
+    ("multimedia", multimedia::ALL),
+    ("pages", pages::ALL),
+    ("project", project::ALL),
+    ("redirects", redirects::ALL),
+    ("search", search::ALL),
+    ("site", site::ALL),
+    ("skills", skills::ALL),
+    ("topic", topic::ALL),
+    ("translations", translations::ALL),
+    ("trash", trash::ALL),
+    ("webhooks", webhooks::ALL),
+];
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ErrorCodeInfo {
+    #[schema(example = "CV_NOT_FOUND")]
+    pub code: &'static str,
+    /// HTTP status the code is answered with
+    #[schema(example = 404)]
+    pub status: u16,
+    /// Default English message; a response may carry a more specific one
+    #[schema(example = "CV not found")]
+    pub message: &'static str,
+    #[schema(example = "cv")]
+    pub module: &'static str,
+}
+
+pub fn registry() -> Vec<ErrorCodeInfo> {
+    MODULES
+        .iter()
+        .flat_map(|&(module, codes)| {
+            codes.iter().map(move |code| ErrorCodeInfo {
+                code: code.code,
+                status: code.status.as_u16(),
+                message: code.message,
+                module,
+            })
+        })
+        .collect()
+}
+
+/// List every error code
+///
+/// The `code` of an error response, or of an entry in its `errors`, is
+/// always one of these, so clients can localize by code. Codes are stable;
+/// messages may change.
+#[utoipa::path(
+    get,
+    path = "/api/meta/error-codes",
+    tag = "meta",
+    responses(
+        (status = 200, description = "All error codes", body = inline(SuccessResponse<Vec<ErrorCodeInfo>>)),
+    )
+)]
+#[get("/api/meta/error-codes")]
+pub async fn list_error_codes_handler() -> impl Responder {
+    ApiResponse::success(registry())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::Value;
+    use std::collections::HashSet;
+
+    #[actix_web::test]
+    async fn test_codes_are_unique_across_modules() {
+        let mut seen = HashSet::new();
+        for info in registry() {
+            assert!(
+                seen.insert(info.code),
+                "{} is declared twice; move it to shared::api::error_codes",
+                info.code
+            );
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_page_command_codes_are_registered() {
+        use crate::pages::application::ports::incoming::use_cases::PageCommandError;
+
+        let codes: HashSet<_> = registry().into_iter().map(|info| info.code).collect();
+        for err in [
+            PageCommandError::InvalidSlug,
+            PageCommandError::InvalidTitle,
+            PageCommandError::BodyTooLong,
+            PageCommandError::SeoTitleTooLong,
+            PageCommandError::SeoDescriptionTooLong,
+            PageCommandError::InvalidCanonicalUrl,
+            PageCommandError::InvalidStatus,
+        ] {
+            assert!(codes.contains(err.code()), "{} not registered", err.code());
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_list_error_codes() {
+        let app = test::init_service(App::new().service(list_error_codes_handler)).await;
+
+        let req = test::TestRequest::get()
+            .uri("/api/meta/error-codes")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: Value = test::read_body_json(resp).await;
+        let cv_not_found = body["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|entry| entry["code"] == "CV_NOT_FOUND")
+            .unwrap();
+        assert_eq!(cv_not_found["status"], 404);
+        assert_eq!(cv_not_found["module"], "cv");
+    }
+}
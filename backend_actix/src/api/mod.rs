@@ -1,2 +1,4 @@
+pub mod error_codes;
 pub mod openapi;
 pub mod schemas;
+pub mod version;
@@ -4,10 +4,11 @@ use utoipa::OpenApi;
 
 // Auth
 use crate::auth::adapter::incoming::web::routes::{
-    CreateUserRequest, LoginRequestDto, LoginResponse, LoginUserInfo, LogoutRequestDto,
-    LogoutResponseBody, RefreshTokenRequestDto, RefreshTokenResponseBody, RegisterUserResponse,
-    RegisteredUser, UpdateUserRequest, UpdateUserResponse, UserProfileResponse,
-    VerifyEmailResponse,
+    CreateUserRequest, FinishPasskeyLoginRequest, FinishPasskeyRegistrationRequest,
+    LoginRequestDto, LoginResponse, LoginUserInfo, LogoutRequestDto, LogoutResponseBody,
+    MeResponse, PasskeyChallengeResponse, PasskeyResponse, PatchMeRequest, RefreshTokenRequestDto,
+    RefreshTokenResponseBody, RegisterUserResponse, RegisteredUser, StartPasskeyLoginRequest,
+    UpdateUserRequest, UpdateUserResponse, UserProfileResponse, VerifyEmailResponse,
 };
 
 #[derive(OpenApi)]
@@ -26,45 +27,180 @@ use crate::auth::adapter::incoming::web::routes::{
         crate::auth::adapter::incoming::web::routes::register_user_handler,
         crate::auth::adapter::incoming::web::routes::login_user_handler,
         crate::auth::adapter::incoming::web::routes::logout_user_handler,
+        crate::auth::adapter::incoming::web::routes::logout_all_handler,
+        crate::auth::adapter::incoming::web::routes::introspect_token_handler,
+        crate::auth::adapter::incoming::web::routes::deactivate_account_handler,
+        crate::auth::adapter::incoming::web::routes::reactivate_account_handler,
         crate::auth::adapter::incoming::web::routes::get_user_profile_handler,
+        crate::auth::adapter::incoming::web::routes::get_me_handler,
+        crate::auth::adapter::incoming::web::routes::patch_me_handler,
         crate::auth::adapter::incoming::web::routes::refresh_token_handler,
         crate::auth::adapter::incoming::web::routes::verify_user_email_handler,
+        crate::auth::adapter::incoming::web::routes::start_passkey_registration_handler,
+        crate::auth::adapter::incoming::web::routes::finish_passkey_registration_handler,
+        crate::auth::adapter::incoming::web::routes::list_passkeys_handler,
+        crate::auth::adapter::incoming::web::routes::delete_passkey_handler,
+        crate::auth::adapter::incoming::web::routes::start_passkey_login_handler,
+        crate::auth::adapter::incoming::web::routes::finish_passkey_login_handler,
 
         // User endpoints
         crate::auth::adapter::incoming::web::routes::update_user_profile_handler,
         crate::auth::adapter::incoming::web::routes::soft_delete_user_handler,
 
         // CV endpoints
-        // create_cv_handler,
-        // get_cvs_handler,
-        // get_cv_by_id_handler,
-        // get_public_cv_by_id_handler,
-        // update_cv_handler,
-        // patch_cv_handler,
-        // hard_delete_cv_handler,
+        crate::cv::adapter::incoming::web::routes::create_cv_handler,
+        crate::cv::adapter::incoming::web::routes::get_cvs_handler,
+        crate::cv::adapter::incoming::web::routes::get_cv_by_id_handler,
+        crate::cv::adapter::incoming::web::routes::get_cv_stats_handler,
+        crate::cv::adapter::incoming::web::routes::export_cv_text_handler,
+        crate::cv::adapter::incoming::web::routes::get_public_cv_by_id_handler,
+        crate::cv::adapter::incoming::web::routes::update_cv_handler,
+        crate::cv::adapter::incoming::web::routes::patch_cv_handler,
+        crate::cv::adapter::incoming::web::routes::hard_delete_cv_handler,
 
         // Project endpoints
-        // create_project_handler,
-        // get_projects_handler,
-        // get_public_projects_handler,
-        // get_project_by_id_handler,
-        // get_public_single_project_handler,
-        // patch_project_handler,
-        // hard_delete_project_handler,
-        // add_project_topic_handler,
-        // remove_project_topic_handler,
-        // get_project_topics_handler,
-        // clear_project_topics_handler,
+        crate::project::adapter::incoming::web::routes::create_project_handler,
+        crate::project::adapter::incoming::web::routes::get_projects_handler,
+        crate::project::adapter::incoming::web::routes::get_public_projects_handler,
+        crate::project::adapter::incoming::web::routes::get_project_by_id_handler,
+        crate::project::adapter::incoming::web::routes::get_public_single_project_handler,
+        crate::project::adapter::incoming::web::routes::oembed_handler,
+        crate::project::adapter::incoming::web::routes::embed_project_handler,
+        crate::project::adapter::incoming::web::routes::patch_project_handler,
+        crate::project::adapter::incoming::web::routes::soft_delete_project_handler,
+        crate::project::adapter::incoming::web::routes::hard_delete_project_handler,
+        crate::project::adapter::incoming::web::routes::sync_project_readme_handler,
+        crate::project::adapter::incoming::web::routes::create_project_update_handler,
+        crate::project::adapter::incoming::web::routes::patch_project_update_handler,
+        crate::project::adapter::incoming::web::routes::delete_project_update_handler,
+        crate::project::adapter::incoming::web::routes::add_project_topic_handler,
+        crate::project::adapter::incoming::web::routes::remove_project_topic_handler,
+        crate::project::adapter::incoming::web::routes::get_project_topics_handler,
+        crate::project::adapter::incoming::web::routes::clear_project_topics_handler,
 
         // Topic endpoints
-        // create_topic_handler,
-        // get_topics_handler,
-        // soft_delete_topic_handler,
+        crate::topic::adapter::incoming::web::routes::create_topic_handler,
+        crate::topic::adapter::incoming::web::routes::get_topics_handler,
+        crate::topic::adapter::incoming::web::routes::soft_delete_topic_handler,
 
         // Media endpoints
-        // init_upload_handler,
-        // get_variant_read_url_handler,
-        // list_media_handler,
+        crate::multimedia::adapter::incoming::web::routes::init_upload_handler,
+        crate::multimedia::adapter::incoming::web::routes::finalize_upload_handler,
+        crate::multimedia::adapter::incoming::web::routes::get_variant_read_url_handler,
+        crate::multimedia::adapter::incoming::web::routes::get_variant_read_urls_handler,
+        crate::multimedia::adapter::incoming::web::routes::list_media_handler,
+        crate::multimedia::adapter::incoming::web::routes::regenerate_media_handler,
+        crate::multimedia::adapter::incoming::web::routes::upload_policy_handler,
+        crate::multimedia::adapter::incoming::web::routes::create_upload_batch_handler,
+        crate::multimedia::adapter::incoming::web::routes::get_upload_batch_handler,
+        crate::multimedia::adapter::incoming::web::routes::proxy_image_handler,
+        crate::multimedia::adapter::incoming::web::routes::ingest_media_manifest_handler,
+        crate::multimedia::adapter::incoming::web::routes::get_storage_override_handler,
+        crate::multimedia::adapter::incoming::web::routes::set_storage_override_handler,
+        crate::multimedia::adapter::incoming::web::routes::delete_storage_override_handler,
+
+        // Admin endpoints
+        crate::admin::adapter::incoming::web::routes::get_admin_stats_handler,
+        crate::admin::adapter::incoming::web::routes::get_metrics_handler,
+        crate::admin::adapter::incoming::web::routes::reload_config_handler,
+        crate::admin::adapter::incoming::web::routes::get_logging_handler,
+        crate::admin::adapter::incoming::web::routes::update_logging_handler,
+        crate::admin::adapter::incoming::web::routes::check_integrity_handler,
+        crate::admin::adapter::incoming::web::routes::quick_search_handler,
+        crate::admin::adapter::incoming::web::routes::get_storage_report_handler,
+        crate::export::adapter::incoming::web::routes::export_content_handler,
+        crate::import::adapter::incoming::web::routes::import_content_handler,
+        crate::email::adapter::incoming::web::routes::list_outbox_emails_handler,
+
+        // Email provider callbacks
+        crate::email::adapter::incoming::web::routes::ingest_email_events_handler,
+
+        // Unsubscribe links
+        crate::email::adapter::incoming::web::routes::unsubscribe_handler,
+        crate::email::adapter::incoming::web::routes::one_click_unsubscribe_handler,
+
+        // Trash endpoints
+        crate::trash::adapter::incoming::web::routes::list_trash_handler,
+        crate::trash::adapter::incoming::web::routes::restore_trash_item_handler,
+
+        // Webhook endpoints
+        crate::webhooks::adapter::incoming::web::routes::create_webhook_handler,
+        crate::webhooks::adapter::incoming::web::routes::list_webhooks_handler,
+        crate::webhooks::adapter::incoming::web::routes::delete_webhook_handler,
+        crate::webhooks::adapter::incoming::web::routes::list_webhook_deliveries_handler,
+
+        // Contact endpoints
+        crate::contact::adapter::incoming::web::routes::submit_contact_message_handler,
+        crate::contact::adapter::incoming::web::routes::list_contact_messages_handler,
+        crate::contact::adapter::incoming::web::routes::mark_contact_message_read_handler,
+        crate::contact::adapter::incoming::web::routes::delete_contact_message_handler,
+
+        // Site endpoints
+        crate::site::adapter::incoming::web::routes::get_site_profile_handler,
+        crate::site::adapter::incoming::web::routes::update_site_settings_handler,
+        crate::site::adapter::incoming::web::routes::get_theme_handler,
+        crate::site::adapter::incoming::web::routes::get_theme_schema_handler,
+        crate::site::adapter::incoming::web::routes::update_theme_handler,
+
+        // Analytics endpoints
+        crate::analytics::adapter::incoming::web::routes::record_page_view_handler,
+        crate::analytics::adapter::incoming::web::routes::get_top_pages_handler,
+        crate::analytics::adapter::incoming::web::routes::get_top_referrers_handler,
+        crate::analytics::adapter::incoming::web::routes::get_daily_visitors_handler,
+
+        // Page endpoints
+        crate::pages::adapter::incoming::web::routes::create_page_handler,
+        crate::pages::adapter::incoming::web::routes::list_pages_handler,
+        crate::pages::adapter::incoming::web::routes::get_page_handler,
+        crate::pages::adapter::incoming::web::routes::update_page_handler,
+        crate::pages::adapter::incoming::web::routes::delete_page_handler,
+        crate::pages::adapter::incoming::web::routes::get_public_page_handler,
+        crate::pages::adapter::incoming::web::routes::create_preview_token_handler,
+        crate::pages::adapter::incoming::web::routes::get_page_preview_handler,
+        crate::pages::adapter::incoming::web::routes::acquire_page_lock_handler,
+        crate::pages::adapter::incoming::web::routes::renew_page_lock_handler,
+        crate::pages::adapter::incoming::web::routes::release_page_lock_handler,
+        crate::pages::adapter::incoming::web::routes::autosave_page_handler,
+
+        // Redirect endpoints
+        crate::redirects::adapter::incoming::web::routes::create_redirect_handler,
+        crate::redirects::adapter::incoming::web::routes::list_redirects_handler,
+        crate::redirects::adapter::incoming::web::routes::update_redirect_handler,
+        crate::redirects::adapter::incoming::web::routes::delete_redirect_handler,
+        crate::redirects::adapter::incoming::web::routes::resolve_redirect_handler,
+
+        // Blocklist endpoints
+        crate::blocklist::adapter::incoming::web::routes::create_block_rule_handler,
+        crate::blocklist::adapter::incoming::web::routes::list_block_rules_handler,
+        crate::blocklist::adapter::incoming::web::routes::delete_block_rule_handler,
+
+        // Search endpoints
+        crate::search::adapter::incoming::web::routes::search_content_handler,
+
+        // Activity feed
+        crate::activity::adapter::incoming::web::routes::list_activities_handler,
+
+        // Translations
+        crate::translations::adapter::incoming::web::routes::list_translations_handler,
+        crate::translations::adapter::incoming::web::routes::put_translation_handler,
+        crate::translations::adapter::incoming::web::routes::delete_translation_handler,
+
+        // Skills
+        crate::skills::adapter::incoming::web::routes::suggest_skills_handler,
+        crate::skills::adapter::incoming::web::routes::rename_skill_handler,
+        crate::skills::adapter::incoming::web::routes::merge_skills_handler,
+
+        // Batch
+        crate::batch::adapter::incoming::web::routes::run_batch_handler,
+
+        // Meta
+        crate::api::error_codes::list_error_codes_handler,
+        crate::api::version::version_handler,
+
+        // Health probes
+        crate::health::liveness,
+        crate::health::readiness,
+        crate::health::health_details,
     ),
     components(
         schemas(
@@ -72,6 +208,9 @@ use crate::auth::adapter::incoming::web::routes::{
             SuccessResponse<RegisterUserResponse>,
             ErrorResponse,
             ErrorDetail,
+            crate::shared::validation::FieldError,
+            crate::api::error_codes::ErrorCodeInfo,
+            crate::api::version::VersionInfo,
 
             // Auth DTOs
             CreateUserRequest,
@@ -83,11 +222,22 @@ use crate::auth::adapter::incoming::web::routes::{
             LogoutRequestDto,
             LogoutResponseBody,
             UserProfileResponse,
+            MeResponse,
+            PatchMeRequest,
             RefreshTokenRequestDto,
             RefreshTokenResponseBody,
             UpdateUserRequest,
             UpdateUserResponse,
-            VerifyEmailResponse
+            VerifyEmailResponse,
+            PasskeyChallengeResponse,
+            PasskeyResponse,
+            FinishPasskeyRegistrationRequest,
+            StartPasskeyLoginRequest,
+            FinishPasskeyLoginRequest,
+
+            // Content DTOs
+            crate::cv::domain::entities::CVInfo,
+            crate::project::application::ports::outgoing::project_query::ProjectView
         )
     ),
     modifiers(&SecurityAddon),
@@ -98,6 +248,23 @@ use crate::auth::adapter::incoming::web::routes::{
         (name = "projects", description = "Project management endpoints"),
         (name = "topics", description = "Topic management endpoints"),
         (name = "media", description = "Media/file management endpoints"),
+        (name = "admin", description = "Site-wide administration endpoints"),
+        (name = "trash", description = "Soft-deleted items: listing and restore"),
+        (name = "webhooks", description = "Outgoing notifications on content changes"),
+        (name = "email", description = "Delivery events posted by the email provider, and unsubscribe links"),
+        (name = "contact", description = "Public contact form and the owners' inbox"),
+        (name = "site", description = "Site title, tagline, social links and theme"),
+        (name = "pages", description = "Standalone Markdown pages such as About, Now or Uses"),
+        (name = "analytics", description = "Cookieless page views and traffic reports"),
+        (name = "redirects", description = "Old URLs and short links, with hit counts"),
+        (name = "blocklist", description = "Addresses and user agents kept out of the public write endpoints"),
+        (name = "search", description = "Find the owner's CVs, projects, pages and media"),
+        (name = "activity", description = "The owner's recent content changes"),
+        (name = "translations", description = "Per-locale text for projects and pages"),
+        (name = "skills", description = "One catalog of the skills CVs and projects name, for autocomplete"),
+        (name = "batch", description = "Several content changes in one request"),
+        (name = "meta", description = "Facts about the API itself, such as its error codes and version"),
+        (name = "health", description = "Liveness and readiness probes"),
     )
 )]
 pub struct ApiDoc;
@@ -120,3 +287,92 @@ impl utoipa::Modify for SecurityAddon {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_module_is_documented() {
+        let doc = ApiDoc::openapi();
+        let paths = &doc.paths.paths;
+
+        for path in [
+            "/api/auth/deactivate",
+            "/api/auth/introspect",
+            "/api/auth/login",
+            "/api/auth/logout-all",
+            "/api/auth/me",
+            "/api/auth/reactivate",
+            "/api/cvs",
+            "/api/cvs/{cv_id}",
+            "/api/cvs/{cv_id}/stats",
+            "/api/cvs/{cv_id}/export.txt",
+            "/api/public/cvs/{username}/{cv_id}",
+            "/api/projects",
+            "/api/projects/{project_id}/topics",
+            "/api/projects/{project_id}/updates",
+            "/api/projects/{project_id}/updates/{update_id}",
+            "/api/projects/{project_id}/sync-readme",
+            "/api/public/projects/{username}/{project_slug}",
+            "/api/public/oembed",
+            "/embed/projects/{username}/{project_slug}",
+            "/api/topics",
+            "/api/topics/{topic_id}",
+            "/api/media/upload-url",
+            "/api/media/{media_id}/finalize",
+            "/api/media/{media_id}/{media_size}",
+            "/api/media/variant-urls",
+            "/api/admin/stats",
+            "/api/admin/emails",
+            "/api/internal/email-events",
+            "/api/public/unsubscribe",
+            "/api/trash",
+            "/api/trash/{type}/{id}/restore",
+            "/api/webhooks",
+            "/api/webhooks/{webhook_id}/deliveries",
+            "/api/public/contact",
+            "/api/admin/contact-messages/{message_id}/read",
+            "/api/public/site",
+            "/api/admin/site",
+            "/api/site/theme",
+            "/api/site/theme/schema",
+            "/api/pages/{page_id}",
+            "/api/public/pages/{username}/{slug}",
+            "/api/pages/{page_id}/preview-token",
+            "/api/public/preview/{token}",
+            "/api/public/analytics/pageview",
+            "/api/admin/analytics/top-pages",
+            "/api/admin/analytics/referrers",
+            "/api/admin/analytics/daily",
+            "/api/admin/redirects/{redirect_id}",
+            "/api/public/redirects/resolve",
+            "/api/admin/blocklist/{rule_id}",
+            "/api/search",
+            "/api/activities",
+            "/api/translations/{kind}/{content_id}",
+            "/api/translations/{kind}/{content_id}/{locale}",
+            "/api/skills/suggest",
+            "/api/admin/skills/{skill_id}/merge",
+            "/api/admin/export",
+            "/api/admin/import",
+            "/api/batch",
+            "/api/meta/error-codes",
+            "/api/meta/version",
+            "/health/ready",
+            "/api/admin/health/details",
+        ] {
+            assert!(paths.contains_key(path), "{path} missing from the spec");
+        }
+
+        let schemas = &doc.components.as_ref().unwrap().schemas;
+        for schema in [
+            "CVInfo",
+            "ProjectView",
+            "InitUploadRequest",
+            "ErrorResponse",
+        ] {
+            assert!(schemas.contains_key(schema), "{schema} schema missing");
+        }
+    }
+}
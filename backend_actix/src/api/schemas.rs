@@ -2,6 +2,8 @@
 use serde::Serialize;
 use utoipa::ToSchema;
 
+use crate::shared::validation::FieldError;
+
 /// Standard success response wrapper
 #[derive(Serialize, ToSchema)]
 #[serde(bound = "T: Serialize")]
@@ -32,4 +34,8 @@ pub struct ErrorDetail {
     /// Human-readable error message
     #[schema(example = "Invalid file name")]
     pub message: String,
+
+    /// Every invalid field, on validation failures (400)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<FieldError>>,
 }
@@ -0,0 +1,98 @@
+use actix_web::{get, Responder};
+use chrono::DateTime;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::sync::LazyLock;
+use utoipa::{OpenApi, ToSchema};
+
+use crate::api::schemas::SuccessResponse;
+use crate::multimedia::application::domain::entities::EXPECTED_PIPELINE_VERSION;
+use crate::shared::api::ApiResponse;
+
+/// What build is serving, for clients checking they were generated against
+/// the same contract
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct VersionInfo {
+    /// `info.version` of `/api/openapi.json`
+    #[schema(example = "1.0.0")]
+    pub api_version: String,
+    /// Lowercase hex SHA-256 of the spec; changes with any route or schema
+    #[schema(example = "3b4c1f0e9a...")]
+    pub openapi_sha256: String,
+    /// Commit the server was built from, `unknown` when the build had none
+    #[schema(example = "6719c44e0b1f...")]
+    pub git_sha: &'static str,
+    /// RFC 3339, UTC
+    #[schema(example = "2026-10-16T08:30:00Z")]
+    pub build_time: String,
+    /// Manifest layout the server expects from the image processor
+    #[schema(example = "v1")]
+    pub image_pipeline_version: &'static str,
+}
+
+static VERSION: LazyLock<VersionInfo> = LazyLock::new(|| {
+    let spec = crate::api::openapi::ApiDoc::openapi();
+    let json = spec.to_json().expect("OpenAPI spec serializes");
+
+    VersionInfo {
+        api_version: spec.info.version.clone(),
+        openapi_sha256: hex::encode(Sha256::digest(json.as_bytes())),
+        git_sha: env!("GIT_SHA"),
+        build_time: env!("BUILD_TIME")
+            .parse::<i64>()
+            .ok()
+            .and_then(|secs| DateTime::from_timestamp(secs, 0))
+            .map(|at| at.format("%Y-%m-%dT%H:%M:%SZ").to_string())
+            .unwrap_or_else(|| "unknown".to_string()),
+        image_pipeline_version: EXPECTED_PIPELINE_VERSION,
+    }
+});
+
+/// Describe the running build
+///
+/// Always on, unlike the spec itself. Frontend CI can compare
+/// `openapi_sha256` with the spec its client was generated from to spot
+/// contract drift without fetching the spec.
+#[utoipa::path(
+    get,
+    path = "/api/meta/version",
+    tag = "meta",
+    responses(
+        (status = 200, description = "Build and contract versions", body = inline(SuccessResponse<VersionInfo>)),
+    )
+)]
+#[get("/api/meta/version")]
+pub async fn version_handler() -> impl Responder {
+    ApiResponse::success(VERSION.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::Value;
+
+    #[actix_web::test]
+    async fn test_spec_hash_is_stable() {
+        let json = || crate::api::openapi::ApiDoc::openapi().to_json().unwrap();
+        assert_eq!(json(), json());
+    }
+
+    #[actix_web::test]
+    async fn test_version() {
+        let app = test::init_service(App::new().service(version_handler)).await;
+
+        let req = test::TestRequest::get()
+            .uri("/api/meta/version")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: Value = test::read_body_json(resp).await;
+        let data = &body["data"];
+        assert_eq!(data["api_version"], "1.0.0");
+        assert_eq!(data["image_pipeline_version"], EXPECTED_PIPELINE_VERSION);
+        assert_eq!(data["openapi_sha256"].as_str().unwrap().len(), 64);
+        assert!(!data["git_sha"].as_str().unwrap().is_empty());
+    }
+}
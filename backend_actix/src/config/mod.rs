@@ -0,0 +1,1317 @@
+// src/config/mod.rs
+use figment::{
+    providers::{Format, Toml},
+    Figment,
+};
+use serde::Deserialize;
+use shared_domain::buckets::{DEFAULT_READY_BUCKET, DEFAULT_UPLOAD_BUCKET};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::auth::adapter::outgoing::jwt::JwtConfig;
+use crate::email::adapter::outgoing::ses_sender::SesConfig;
+use crate::shared::api::json_case::JsonCase;
+use crate::shared::api::problem::ErrorFormat;
+use crate::shared::bot_check::{BotCheckConfig, BotCheckProvider, BotCheckedEndpoint};
+use crate::shared::field_cipher::{self, FieldKey};
+use crate::shared::image_proxy::ImageOrigin;
+use crate::shared::ip_range::IpRange;
+use crate::shared::telemetry::{self, EventPolicies};
+
+pub mod preflight;
+pub mod reload;
+
+/// Config file read when `APP_CONFIG_FILE` is not set. Missing is fine.
+const DEFAULT_CONFIG_FILE: &str = "config.toml";
+
+/// Every key the application reads. Env vars win over the TOML file;
+/// file keys are the same names in lowercase (`database_url = "..."`).
+const KEYS: &[&str] = &[
+    "RUST_ENV",
+    "RUN_MODE",
+    "HOST",
+    "PORT",
+    "DATABASE_URL",
+    "REDIS_URL",
+    "EMAIL_FROM",
+    "EMAIL_PROVIDER",
+    "SMTP_HOST",
+    "SMTP_PORT",
+    "SMTP_SERVER",
+    "SMTP_USERNAME",
+    "SMTP_PASSWORD",
+    "SENDGRID_API_KEY",
+    "SES_REGION",
+    "SES_ACCESS_KEY_ID",
+    "SES_SECRET_ACCESS_KEY",
+    "JWT_SECRET",
+    "JWT_ISSUER",
+    "JWT_AUDIENCE",
+    "JWT_ACCESS_EXPIRY",
+    "JWT_REFRESH_EXPIRY",
+    "JWT_REMEMBER_ME_REFRESH_EXPIRY",
+    "JWT_VERIFICATION_EXPIRY",
+    "VERIFICATION_HANDLER_URL",
+    "MULTIMEDIA_UPLOAD_BUCKET",
+    "MULTIMEDIA_READY_BUCKET",
+    "IMAGE_PROCESSOR_URL",
+    "IMAGE_PROCESSOR_TOKEN",
+    "LOCAL_STORAGE_DIR",
+    "IMAGE_PROXY_ORIGINS",
+    "TRUSTED_PROXIES",
+    "SHUTDOWN_TIMEOUT_SECS",
+    "AUTO_MIGRATE",
+    "SLOW_QUERY_MS",
+    "LOG_FILTER",
+    "EVENT_LOG_SAMPLE",
+    "EVENT_LOG_MAX_PER_MINUTE",
+    "ERROR_FORMAT",
+    "JSON_CASE",
+    "OPENAPI_ENABLED",
+    "ADMIN_USER_IDS",
+    "CACHE_TTL_SECS",
+    "TRASH_RETENTION_DAYS",
+    "MEDIA_RECONCILE_INTERVAL_SECS",
+    "MEDIA_PROCESSING_TIMEOUT_MINS",
+    "MEDIA_RETRY_MAX_ATTEMPTS",
+    "STORAGE_GC_INTERVAL_SECS",
+    "STORAGE_GC_GRACE_HOURS",
+    "STORAGE_GC_DRY_RUN",
+    "GITHUB_TOKEN",
+    "README_SYNC_INTERVAL_SECS",
+    "BOT_CHECK_PROVIDER",
+    "BOT_CHECK_SECRET",
+    "BOT_CHECK_ENDPOINTS",
+    "BOT_CHECK_LOGIN_AFTER_FAILURES",
+    "EMAIL_EVENTS_TOKEN",
+    "MEDIA_CALLBACK_SECRET",
+    "INTROSPECTION_TOKEN",
+    "METRICS_TOKEN",
+    "WEBAUTHN_RP_ID",
+    "WEBAUTHN_RP_ORIGIN",
+    "CONTACT_RATE_LIMIT",
+    "UPLOAD_URL_RATE_LIMIT",
+    "UNSUBSCRIBE_SECRET",
+    "ANALYTICS_SECRET",
+    "PREVIEW_SECRET",
+    "FIELD_ENCRYPTION_KEYS",
+    "AUTH_TRANSPORT",
+    "AUTH_COOKIE_DOMAIN",
+    "AUTH_COOKIE_SECURE",
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("failed to read configuration: {0}")]
+    Source(String),
+
+    #[error("invalid configuration:\n  - {}", .0.join("\n  - "))]
+    Invalid(Vec<String>),
+}
+
+/// Which adapters the server is wired with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RunMode {
+    /// Postgres, Redis, GCS and SMTP
+    #[default]
+    Standard,
+    /// In-memory adapters only: nothing to install, nothing survives a restart.
+    /// For demos and frontend work; refused in production.
+    Standalone,
+}
+
+impl FromStr for RunMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "standard" => Ok(Self::Standard),
+            "standalone" => Ok(Self::Standalone),
+            _ => Err("expected 'standard' or 'standalone'".to_string()),
+        }
+    }
+}
+
+/// `AUTH_TRANSPORT`: how clients carry their tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AuthTransport {
+    /// `Authorization: Bearer` header, tokens in response bodies
+    #[default]
+    Bearer,
+    /// HttpOnly cookies set by login and refresh, plus a CSRF token for
+    /// mutating requests. For browser-first deployments.
+    Cookie,
+}
+
+impl FromStr for AuthTransport {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "bearer" => Ok(Self::Bearer),
+            "cookie" => Ok(Self::Cookie),
+            _ => Err("expected 'bearer' or 'cookie'".to_string()),
+        }
+    }
+}
+
+/// `EMAIL_PROVIDER`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmailProvider {
+    #[default]
+    Smtp,
+    /// SendGrid HTTP API, for hosts that block outbound SMTP
+    SendGrid,
+    /// Amazon SES HTTP API, for hosts that block outbound SMTP
+    Ses,
+}
+
+impl FromStr for EmailProvider {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "smtp" => Ok(Self::Smtp),
+            "sendgrid" => Ok(Self::SendGrid),
+            "ses" => Ok(Self::Ses),
+            _ => Err("expected 'smtp', 'sendgrid' or 'ses'".to_string()),
+        }
+    }
+}
+
+/// How outgoing email is delivered.
+#[derive(Clone)]
+pub enum EmailConfig {
+    Smtp(SmtpConfig),
+    SendGrid { api_key: String },
+    Ses(SesConfig),
+}
+
+/// `EMAIL_PROVIDER=smtp` settings
+#[derive(Clone)]
+pub enum SmtpConfig {
+    /// Local catcher (Mailpit, MailHog): plain SMTP, no auth. Used when `RUST_ENV=test`.
+    Local { host: String, port: u16 },
+    /// Authenticated relay over TLS.
+    Relay {
+        server: String,
+        username: String,
+        password: String,
+    },
+}
+
+/// Application configuration, loaded and validated once at startup.
+///
+/// No `Debug`: it holds credentials.
+#[derive(Clone)]
+pub struct AppConfig {
+    pub rust_env: String,
+    pub run_mode: RunMode,
+    pub host: String,
+    pub port: u16,
+    /// Empty in standalone mode, like the Redis URL and email sender
+    pub database_url: String,
+    pub redis_url: String,
+    pub email_from: String,
+    pub email: EmailConfig,
+    pub jwt: JwtConfig,
+    pub verification_handler_url: String,
+    pub multimedia_upload_bucket: String,
+    /// Where the image processor writes variants
+    pub multimedia_ready_bucket: String,
+    /// Base URL of the image processor, which `POST /api/media/{id}/regenerate`
+    /// calls; unset disables regeneration
+    pub image_processor_url: Option<String>,
+    /// Bearer token sent to the image processor
+    pub image_processor_token: Option<String>,
+    /// Standalone only: `LOCAL_STORAGE_DIR` of the image processor at
+    /// `image_processor_url` running with `PROCESSOR_MODE=local`, so uploads
+    /// are stored and processed there; unset signs `memory://` URLs
+    pub local_storage_dir: Option<String>,
+    /// Origins whose images `GET /api/proxy/image` fetches, e.g.
+    /// `https://i.imgur.com`; empty turns the proxy off
+    pub image_proxy_origins: Vec<ImageOrigin>,
+    /// Proxies (addresses or CIDR ranges) whose `Forwarded`/`X-Forwarded-For`
+    /// headers name the client; empty trusts no header
+    pub trusted_proxies: Vec<IpRange>,
+    /// Drain budget for in-flight requests, then again for background jobs
+    pub shutdown_timeout_secs: u64,
+    /// Apply pending migrations at startup instead of refusing to start
+    pub auto_migrate: bool,
+    /// Statements taking at least this long are logged at WARN; 0 turns that off
+    pub slow_query_ms: u64,
+    /// Overrides `RUST_LOG`, e.g. `info,sqlx::query=debug`; reloadable
+    pub log_filter: Option<String>,
+    /// Business event sampling and caps by event, e.g. `media_failed=10`
+    pub event_log_policies: EventPolicies,
+    /// `envelope` (default) or `problem` for RFC 7807 error bodies
+    pub error_format: ErrorFormat,
+    /// `snake` (default) or `camel` keys in JSON bodies
+    pub json_case: JsonCase,
+    /// Serve Swagger UI and `/api/openapi.json`; defaults to off in production
+    pub openapi_enabled: bool,
+    /// Verified users allowed on `/api/admin/*` (comma-separated UUIDs). Empty by default.
+    pub admin_user_ids: Vec<Uuid>,
+    /// Lifetime of cached public reads; 0 turns the cache off
+    pub cache_ttl_secs: u64,
+    /// Days a soft-deleted item stays in the trash before it is purged; 0 keeps it forever
+    pub trash_retention_days: u32,
+    /// Seconds between refreshes of projects kept in sync with their README; 0 turns them off
+    pub readme_sync_interval_secs: u64,
+    /// Seconds between checks of media stuck in `pending`/`processing`; 0 turns them off
+    pub media_reconcile_interval_secs: u64,
+    /// Minutes a media may stay `processing` before it is failed with `PROCESSING_TIMEOUT`
+    pub media_processing_timeout_mins: u32,
+    /// Most times a media is sent back after a transient processing failure; 0 fails it at once
+    pub media_retry_max_attempts: u32,
+    /// Seconds between sweeps for stored objects no media row points to; 0 turns them off
+    pub storage_gc_interval_secs: u64,
+    /// Objects younger than this are never swept
+    pub storage_gc_grace_hours: u32,
+    /// Only log orphaned objects instead of deleting them
+    pub storage_gc_dry_run: bool,
+    /// GitHub API token for README sync; raises the anonymous rate limit
+    pub github_token: Option<String>,
+    /// CAPTCHA provider and the endpoints it guards
+    pub bot_check: BotCheckConfig,
+    /// Shared secret for `POST /api/internal/email-events`; unset disables it
+    pub email_events_token: Option<String>,
+    /// HMAC key of the image processor's completion callback
+    /// (`POST /api/internal/media/manifest`), its `CALLBACK_SECRET`; unset disables it
+    pub media_callback_secret: Option<String>,
+    /// Shared secret of `POST /api/auth/introspect`; unset disables it
+    pub introspection_token: Option<String>,
+    /// Shared secret of `GET /api/admin/metrics`; unset disables it
+    pub metrics_token: Option<String>,
+    /// Domain passkeys are bound to; unset disables passkey sign-in
+    pub webauthn_rp_id: Option<String>,
+    /// Origin of the pages that call WebAuthn, e.g. `https://example.com`;
+    /// required with `webauthn_rp_id`
+    pub webauthn_rp_origin: Option<String>,
+    /// Contact form messages accepted per client address per hour; 0 turns the limit off
+    pub contact_rate_limit: u32,
+    /// Upload URLs issued per user per hour; 0 turns the limit off
+    pub upload_url_rate_limit: u32,
+    /// Signs unsubscribe links; defaults to the JWT secret
+    pub unsubscribe_secret: String,
+    /// Keys the daily visitor hash of page views; defaults to the JWT secret
+    pub analytics_secret: String,
+    /// Signs draft preview links; defaults to the JWT secret
+    pub preview_secret: String,
+    /// Encrypt CV contact details and contact messages at rest, the first
+    /// key for new values; empty leaves them in plaintext
+    pub field_encryption_keys: Vec<FieldKey>,
+    pub auth_transport: AuthTransport,
+    /// `Domain` of the auth cookies; unset means the API host only
+    pub auth_cookie_domain: Option<String>,
+    /// Off only for plain-HTTP development on hosts browsers don't treat as secure
+    pub auth_cookie_secure: bool,
+}
+
+/// A raw value from the TOML file, which may carry numbers and booleans.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Scalar {
+    Str(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+}
+
+impl Scalar {
+    fn into_string(self) -> String {
+        match self {
+            Scalar::Str(s) => s,
+            Scalar::Int(i) => i.to_string(),
+            Scalar::Float(f) => f.to_string(),
+            Scalar::Bool(b) => b.to_string(),
+        }
+    }
+}
+
+/// Reads keys out of the merged sources, collecting every problem instead of
+/// stopping at the first one.
+struct Reader {
+    values: BTreeMap<String, String>,
+    errors: Vec<String>,
+}
+
+impl Reader {
+    fn optional(&self, key: &str) -> Option<String> {
+        self.values
+            .get(&key.to_ascii_lowercase())
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    }
+
+    fn required(&mut self, key: &str) -> String {
+        self.optional(key).unwrap_or_else(|| {
+            self.errors.push(format!("{key} is required"));
+            String::new()
+        })
+    }
+
+    fn or(&self, key: &str, default: &str) -> String {
+        self.optional(key).unwrap_or_else(|| default.to_string())
+    }
+
+    fn parsed<T>(&mut self, key: &str, default: T) -> T
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.optional(key) {
+            None => default,
+            Some(raw) => raw.parse().unwrap_or_else(|e| {
+                self.errors
+                    .push(format!("{key}: invalid value '{raw}' ({e})"));
+                default
+            }),
+        }
+    }
+
+    /// Comma-separated values; empty entries are skipped. Missing key means an empty list.
+    fn list<T>(&mut self, key: &str) -> Vec<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let Some(raw) = self.optional(key) else {
+            return Vec::new();
+        };
+
+        raw.split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .filter_map(|item| match item.parse() {
+                Ok(value) => Some(value),
+                Err(e) => {
+                    self.errors
+                        .push(format!("{key}: invalid value '{item}' ({e})"));
+                    None
+                }
+            })
+            .collect()
+    }
+
+    fn check(&mut self, ok: bool, message: &str) {
+        if !ok {
+            self.errors.push(message.to_string());
+        }
+    }
+}
+
+impl AppConfig {
+    /// Load from the optional TOML file (`APP_CONFIG_FILE`, default `config.toml`)
+    /// overlaid with environment variables.
+    ///
+    /// `.env` files must already be loaded into the environment.
+    pub fn load() -> Result<Self, ConfigError> {
+        let explicit_file = std::env::var("APP_CONFIG_FILE").ok();
+        if let Some(path) = &explicit_file {
+            if !Path::new(path).is_file() {
+                return Err(ConfigError::Source(format!(
+                    "APP_CONFIG_FILE points to a missing file: {path}"
+                )));
+            }
+        }
+        let file = explicit_file.unwrap_or_else(|| DEFAULT_CONFIG_FILE.to_string());
+
+        let file_values: BTreeMap<String, Scalar> = Figment::new()
+            .merge(Toml::file(file))
+            .extract()
+            .map_err(|e| ConfigError::Source(e.to_string()))?;
+
+        let mut values: BTreeMap<String, String> = file_values
+            .into_iter()
+            .map(|(k, v)| (k.to_ascii_lowercase(), v.into_string()))
+            .collect();
+
+        // Env vars are read verbatim (no type guessing), so a numeric password stays intact
+        for key in KEYS {
+            if let Ok(value) = std::env::var(key) {
+                values.insert(key.to_ascii_lowercase(), value);
+            }
+        }
+
+        Self::from_values(values)
+    }
+
+    /// Validate raw values (lowercase keys). Reports every missing or invalid key at once.
+    fn from_values(values: BTreeMap<String, String>) -> Result<Self, ConfigError> {
+        let mut r = Reader {
+            values,
+            errors: Vec::new(),
+        };
+
+        let rust_env = r.or("RUST_ENV", "development");
+        let host = r.or("HOST", "0.0.0.0");
+        let port = r.parsed("PORT", 8080u16);
+        let run_mode = r.parsed("RUN_MODE", RunMode::Standard);
+        let standalone = run_mode == RunMode::Standalone;
+        r.check(
+            !(standalone && rust_env == "production"),
+            "RUN_MODE=standalone is not allowed in production",
+        );
+
+        // Standalone mode connects to nothing, so it needs none of these
+        let (database_url, redis_url, email_from) = if standalone {
+            (
+                r.or("DATABASE_URL", ""),
+                r.or("REDIS_URL", ""),
+                r.or("EMAIL_FROM", "noreply@localhost"),
+            )
+        } else {
+            (
+                r.required("DATABASE_URL"),
+                r.required("REDIS_URL"),
+                r.required("EMAIL_FROM"),
+            )
+        };
+
+        // Standalone mode only logs emails, whatever the provider
+        let email_provider = if standalone {
+            EmailProvider::Smtp
+        } else {
+            r.parsed("EMAIL_PROVIDER", EmailProvider::Smtp)
+        };
+        let email = match email_provider {
+            EmailProvider::Smtp if rust_env == "test" || standalone => {
+                EmailConfig::Smtp(SmtpConfig::Local {
+                    host: r.or("SMTP_HOST", "localhost"),
+                    port: r.parsed("SMTP_PORT", 1025u16),
+                })
+            }
+            EmailProvider::Smtp => EmailConfig::Smtp(SmtpConfig::Relay {
+                server: r.required("SMTP_SERVER"),
+                username: r.required("SMTP_USERNAME"),
+                password: r.required("SMTP_PASSWORD"),
+            }),
+            EmailProvider::SendGrid => EmailConfig::SendGrid {
+                api_key: r.required("SENDGRID_API_KEY"),
+            },
+            EmailProvider::Ses => EmailConfig::Ses(SesConfig {
+                region: r.required("SES_REGION"),
+                access_key_id: r.required("SES_ACCESS_KEY_ID"),
+                secret_access_key: r.required("SES_SECRET_ACCESS_KEY"),
+            }),
+        };
+
+        // A random secret only invalidates tokens on restart, which standalone
+        // mode does to all data anyway
+        let secret_key = match r.optional("JWT_SECRET") {
+            None if standalone => hex::encode(rand::random::<[u8; 32]>()),
+            _ => r.required("JWT_SECRET"),
+        };
+        // HS256 requires at least 32 bytes
+        if !secret_key.is_empty() {
+            r.check(
+                secret_key.len() >= 32,
+                "JWT_SECRET must be at least 32 characters long for HS256 algorithm",
+            );
+        }
+        let access_token_expiry = r.parsed("JWT_ACCESS_EXPIRY", 1800i64);
+        let refresh_token_expiry = r.parsed("JWT_REFRESH_EXPIRY", 604800i64);
+        let remember_me_refresh_token_expiry =
+            r.parsed("JWT_REMEMBER_ME_REFRESH_EXPIRY", 2592000i64);
+        let verification_token_expiry = r.parsed("JWT_VERIFICATION_EXPIRY", 86400i64);
+        r.check(
+            access_token_expiry > 0 && access_token_expiry <= 86400,
+            "JWT_ACCESS_EXPIRY must be between 1 and 86400 seconds (24 hours)",
+        );
+        r.check(
+            refresh_token_expiry > access_token_expiry,
+            "JWT_REFRESH_EXPIRY must be greater than JWT_ACCESS_EXPIRY",
+        );
+        r.check(
+            remember_me_refresh_token_expiry >= refresh_token_expiry,
+            "JWT_REMEMBER_ME_REFRESH_EXPIRY must not be shorter than JWT_REFRESH_EXPIRY",
+        );
+        r.check(
+            verification_token_expiry > 0,
+            "JWT_VERIFICATION_EXPIRY must be positive",
+        );
+        let jwt = JwtConfig {
+            secret_key,
+            issuer: r.or("JWT_ISSUER", "Ekstion"),
+            audience: r.or("JWT_AUDIENCE", "Ekstion API"),
+            access_token_expiry,
+            refresh_token_expiry,
+            verification_token_expiry,
+            remember_me_refresh_token_expiry,
+        };
+
+        let verification_handler_url = r.or(
+            "VERIFICATION_HANDLER_URL",
+            "0.0.0.0:5177/email/verification",
+        );
+        let multimedia_upload_bucket = r.or("MULTIMEDIA_UPLOAD_BUCKET", DEFAULT_UPLOAD_BUCKET);
+        let multimedia_ready_bucket = r.or("MULTIMEDIA_READY_BUCKET", DEFAULT_READY_BUCKET);
+        let image_processor_url = r.optional("IMAGE_PROCESSOR_URL");
+        r.check(
+            image_processor_url
+                .as_ref()
+                .is_none_or(|url| url.starts_with("https://") || url.starts_with("http://")),
+            "IMAGE_PROCESSOR_URL must be an http(s):// URL",
+        );
+        let image_processor_token = r.optional("IMAGE_PROCESSOR_TOKEN");
+        r.check(
+            image_processor_token
+                .as_ref()
+                .is_none_or(|token| token.len() >= 16),
+            "IMAGE_PROCESSOR_TOKEN must be at least 16 characters",
+        );
+        let local_storage_dir = r.optional("LOCAL_STORAGE_DIR");
+        r.check(
+            local_storage_dir.is_none() || standalone,
+            "LOCAL_STORAGE_DIR only applies to RUN_MODE=standalone",
+        );
+        r.check(
+            local_storage_dir.is_none() || image_processor_url.is_some(),
+            "LOCAL_STORAGE_DIR needs IMAGE_PROCESSOR_URL, the processor serving it",
+        );
+        let shutdown_timeout_secs = r.parsed("SHUTDOWN_TIMEOUT_SECS", 30u64);
+        let auto_migrate = r.parsed("AUTO_MIGRATE", false);
+        let slow_query_ms = r.parsed("SLOW_QUERY_MS", 500u64);
+        let log_filter = r.optional("LOG_FILTER");
+        r.check(
+            log_filter
+                .as_deref()
+                .is_none_or(|filter| crate::shared::log_filter::parse(filter).is_ok()),
+            "LOG_FILTER must be valid `RUST_LOG` directives, e.g. `info,sqlx::query=debug`",
+        );
+        let mut event_log_policies = EventPolicies::new();
+        for (key, sampling) in [
+            ("EVENT_LOG_SAMPLE", true),
+            ("EVENT_LOG_MAX_PER_MINUTE", false),
+        ] {
+            let Some(raw) = r.optional(key) else {
+                continue;
+            };
+            match telemetry::parse_event_settings(&raw) {
+                Ok(settings) => {
+                    for (kind, value) in settings {
+                        let policy = event_log_policies.entry(kind).or_default();
+                        if sampling {
+                            policy.sample_every = value;
+                        } else {
+                            policy.max_per_minute = value;
+                        }
+                    }
+                }
+                Err(e) => r.errors.push(format!("{key}: {e}")),
+            }
+        }
+        let error_format = r.parsed("ERROR_FORMAT", ErrorFormat::Envelope);
+        let json_case = r.parsed("JSON_CASE", JsonCase::Snake);
+        let openapi_enabled = r.parsed("OPENAPI_ENABLED", rust_env != "production");
+        let admin_user_ids = r.list("ADMIN_USER_IDS");
+        let image_proxy_origins = r.list("IMAGE_PROXY_ORIGINS");
+        let trusted_proxies = r.list("TRUSTED_PROXIES");
+        let cache_ttl_secs = r.parsed("CACHE_TTL_SECS", 300u64);
+        let trash_retention_days = r.parsed("TRASH_RETENTION_DAYS", 30u32);
+        let readme_sync_interval_secs = r.parsed("README_SYNC_INTERVAL_SECS", 21600u64);
+        let media_reconcile_interval_secs = r.parsed("MEDIA_RECONCILE_INTERVAL_SECS", 300u64);
+        let media_processing_timeout_mins = r.parsed("MEDIA_PROCESSING_TIMEOUT_MINS", 60u32);
+        let media_retry_max_attempts = r.parsed("MEDIA_RETRY_MAX_ATTEMPTS", 3u32);
+        let storage_gc_interval_secs = r.parsed("STORAGE_GC_INTERVAL_SECS", 86400u64);
+        let storage_gc_grace_hours = r.parsed("STORAGE_GC_GRACE_HOURS", 24u32);
+        let storage_gc_dry_run = r.parsed("STORAGE_GC_DRY_RUN", true);
+        let github_token = r.optional("GITHUB_TOKEN");
+
+        let bot_check_provider = r.parsed("BOT_CHECK_PROVIDER", BotCheckProvider::None);
+        r.check(
+            !(bot_check_provider == BotCheckProvider::Noop && rust_env == "production"),
+            "BOT_CHECK_PROVIDER=noop is not allowed in production",
+        );
+        let bot_check_secret = match bot_check_provider {
+            BotCheckProvider::HCaptcha | BotCheckProvider::Turnstile => {
+                r.required("BOT_CHECK_SECRET")
+            }
+            _ => String::new(),
+        };
+        let bot_check_endpoints = if r.optional("BOT_CHECK_ENDPOINTS").is_some() {
+            r.list("BOT_CHECK_ENDPOINTS")
+        } else {
+            vec![
+                BotCheckedEndpoint::Register,
+                BotCheckedEndpoint::Login,
+                BotCheckedEndpoint::Contact,
+            ]
+        };
+        let bot_check = BotCheckConfig {
+            provider: bot_check_provider,
+            secret: bot_check_secret,
+            endpoints: bot_check_endpoints,
+            login_after_failures: r.parsed("BOT_CHECK_LOGIN_AFTER_FAILURES", 3u32),
+        };
+
+        let email_events_token = r.optional("EMAIL_EVENTS_TOKEN");
+        r.check(
+            email_events_token
+                .as_ref()
+                .is_none_or(|token| token.len() >= 16),
+            "EMAIL_EVENTS_TOKEN must be at least 16 characters",
+        );
+
+        let media_callback_secret = r.optional("MEDIA_CALLBACK_SECRET");
+        r.check(
+            media_callback_secret
+                .as_ref()
+                .is_none_or(|secret| secret.len() >= 16),
+            "MEDIA_CALLBACK_SECRET must be at least 16 characters",
+        );
+
+        let introspection_token = r.optional("INTROSPECTION_TOKEN");
+        r.check(
+            introspection_token
+                .as_ref()
+                .is_none_or(|token| token.len() >= 16),
+            "INTROSPECTION_TOKEN must be at least 16 characters",
+        );
+
+        let metrics_token = r.optional("METRICS_TOKEN");
+        r.check(
+            metrics_token.as_ref().is_none_or(|token| token.len() >= 16),
+            "METRICS_TOKEN must be at least 16 characters",
+        );
+
+        let webauthn_rp_id = r.optional("WEBAUTHN_RP_ID");
+        let webauthn_rp_origin = r.optional("WEBAUTHN_RP_ORIGIN");
+        r.check(
+            webauthn_rp_id.is_some() == webauthn_rp_origin.is_some(),
+            "WEBAUTHN_RP_ID and WEBAUTHN_RP_ORIGIN must be set together",
+        );
+        // Browsers only allow WebAuthn in secure contexts
+        r.check(
+            webauthn_rp_origin.as_ref().is_none_or(|origin| {
+                origin.starts_with("https://") || origin.starts_with("http://localhost")
+            }),
+            "WEBAUTHN_RP_ORIGIN must be an https:// origin (or http://localhost)",
+        );
+
+        let contact_rate_limit = r.parsed("CONTACT_RATE_LIMIT", 5u32);
+        let upload_url_rate_limit = r.parsed("UPLOAD_URL_RATE_LIMIT", 30u32);
+
+        // Rotating it breaks the links in mail already sent, so it can be kept
+        // apart from the JWT secret
+        let unsubscribe_secret = r.optional("UNSUBSCRIBE_SECRET");
+        r.check(
+            unsubscribe_secret
+                .as_ref()
+                .is_none_or(|secret| secret.len() >= 32),
+            "UNSUBSCRIBE_SECRET must be at least 32 characters",
+        );
+        let unsubscribe_secret = unsubscribe_secret.unwrap_or_else(|| jwt.secret_key.clone());
+
+        let analytics_secret = r.optional("ANALYTICS_SECRET");
+        r.check(
+            analytics_secret
+                .as_ref()
+                .is_none_or(|secret| secret.len() >= 32),
+            "ANALYTICS_SECRET must be at least 32 characters",
+        );
+        let analytics_secret = analytics_secret.unwrap_or_else(|| jwt.secret_key.clone());
+
+        let preview_secret = r.optional("PREVIEW_SECRET");
+        r.check(
+            preview_secret
+                .as_ref()
+                .is_none_or(|secret| secret.len() >= 32),
+            "PREVIEW_SECRET must be at least 32 characters",
+        );
+        let preview_secret = preview_secret.unwrap_or_else(|| jwt.secret_key.clone());
+
+        // Not `r.list`: its errors quote the value, which here is a secret
+        let field_encryption_keys = match r.optional("FIELD_ENCRYPTION_KEYS") {
+            None => Vec::new(),
+            Some(raw) => field_cipher::parse_keys(&raw).unwrap_or_else(|e| {
+                r.errors.push(format!("FIELD_ENCRYPTION_KEYS: {e}"));
+                Vec::new()
+            }),
+        };
+
+        let auth_transport = r.parsed("AUTH_TRANSPORT", AuthTransport::Bearer);
+        let auth_cookie_domain = r.optional("AUTH_COOKIE_DOMAIN");
+        let auth_cookie_secure = r.parsed("AUTH_COOKIE_SECURE", true);
+        r.check(
+            auth_cookie_secure || rust_env != "production",
+            "AUTH_COOKIE_SECURE=false is not allowed in production",
+        );
+
+        if !r.errors.is_empty() {
+            return Err(ConfigError::Invalid(r.errors));
+        }
+
+        Ok(Self {
+            rust_env,
+            run_mode,
+            host,
+            port,
+            database_url,
+            redis_url,
+            email_from,
+            email,
+            jwt,
+            verification_handler_url,
+            multimedia_upload_bucket,
+            multimedia_ready_bucket,
+            image_processor_url,
+            image_processor_token,
+            local_storage_dir,
+            image_proxy_origins,
+            trusted_proxies,
+            shutdown_timeout_secs,
+            auto_migrate,
+            slow_query_ms,
+            log_filter,
+            event_log_policies,
+            error_format,
+            json_case,
+            openapi_enabled,
+            admin_user_ids,
+            cache_ttl_secs,
+            trash_retention_days,
+            readme_sync_interval_secs,
+            media_reconcile_interval_secs,
+            media_processing_timeout_mins,
+            media_retry_max_attempts,
+            storage_gc_interval_secs,
+            storage_gc_grace_hours,
+            storage_gc_dry_run,
+            github_token,
+            bot_check,
+            email_events_token,
+            media_callback_secret,
+            introspection_token,
+            metrics_token,
+            webauthn_rp_id,
+            webauthn_rp_origin,
+            contact_rate_limit,
+            upload_url_rate_limit,
+            unsubscribe_secret,
+            analytics_secret,
+            preview_secret,
+            field_encryption_keys,
+            auth_transport,
+            auth_cookie_domain,
+            auth_cookie_secure,
+        })
+    }
+
+    pub fn is_standalone(&self) -> bool {
+        self.run_mode == RunMode::Standalone
+    }
+
+    pub fn is_production(&self) -> bool {
+        self.rust_env == "production"
+    }
+
+    pub fn server_url(&self) -> String {
+        format!("{}:{}", self.host, self.port)
+    }
+
+    pub fn shutdown_timeout(&self) -> Duration {
+        Duration::from_secs(self.shutdown_timeout_secs)
+    }
+
+    pub fn slow_query(&self) -> Duration {
+        Duration::from_millis(self.slow_query_ms)
+    }
+
+    pub fn cache_ttl(&self) -> Duration {
+        Duration::from_secs(self.cache_ttl_secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "0123456789abcdef0123456789abcdef";
+
+    fn values(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_ascii_lowercase(), v.to_string()))
+            .collect()
+    }
+
+    fn minimal() -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("DATABASE_URL", "postgres://localhost/cms"),
+            ("REDIS_URL", "redis://localhost"),
+            ("EMAIL_FROM", "noreply@example.com"),
+            ("SMTP_SERVER", "smtp.example.com"),
+            ("SMTP_USERNAME", "user"),
+            ("SMTP_PASSWORD", "pass"),
+            ("JWT_SECRET", SECRET),
+        ]
+    }
+
+    fn errors(result: Result<AppConfig, ConfigError>) -> Vec<String> {
+        match result {
+            Err(ConfigError::Invalid(errors)) => errors,
+            Err(e) => panic!("unexpected error: {e}"),
+            Ok(_) => panic!("expected invalid configuration"),
+        }
+    }
+
+    #[test]
+    fn test_minimal_config_uses_defaults() {
+        let config = AppConfig::from_values(values(&minimal())).unwrap();
+
+        assert_eq!(config.rust_env, "development");
+        assert_eq!(config.run_mode, RunMode::Standard);
+        assert_eq!(config.server_url(), "0.0.0.0:8080");
+        assert_eq!(config.jwt.access_token_expiry, 1800);
+        assert_eq!(config.jwt.issuer, "Ekstion");
+        assert_eq!(config.multimedia_upload_bucket, "blogport-cms-upload");
+        assert_eq!(config.shutdown_timeout(), Duration::from_secs(30));
+        assert!(!config.auto_migrate);
+        assert_eq!(config.slow_query_ms, 500);
+        assert!(config.log_filter.is_none());
+        assert_eq!(config.error_format, ErrorFormat::Envelope);
+        assert_eq!(config.json_case, JsonCase::Snake);
+        assert!(config.openapi_enabled);
+        assert_eq!(config.cache_ttl_secs, 300);
+        assert_eq!(config.trash_retention_days, 30);
+        assert_eq!(config.readme_sync_interval_secs, 21600);
+        assert_eq!(config.multimedia_ready_bucket, "blogport-cms-ready");
+        assert!(config.image_processor_url.is_none());
+        assert!(config.local_storage_dir.is_none());
+        assert_eq!(config.media_reconcile_interval_secs, 300);
+        assert_eq!(config.media_processing_timeout_mins, 60);
+        assert_eq!(config.media_retry_max_attempts, 3);
+        assert_eq!(config.storage_gc_interval_secs, 86400);
+        assert_eq!(config.storage_gc_grace_hours, 24);
+        assert!(config.storage_gc_dry_run);
+        assert!(config.github_token.is_none());
+        assert!(config.admin_user_ids.is_empty());
+        assert!(matches!(
+            config.email,
+            EmailConfig::Smtp(SmtpConfig::Relay { .. })
+        ));
+        assert_eq!(config.bot_check.provider, BotCheckProvider::None);
+        assert!(config.email_events_token.is_none());
+        assert!(config.media_callback_secret.is_none());
+        assert!(config.introspection_token.is_none());
+        assert!(config.metrics_token.is_none());
+        assert!(config.webauthn_rp_id.is_none());
+        assert_eq!(config.contact_rate_limit, 5);
+        assert_eq!(config.upload_url_rate_limit, 30);
+        assert_eq!(config.unsubscribe_secret, SECRET);
+        assert_eq!(config.analytics_secret, SECRET);
+        assert_eq!(config.preview_secret, SECRET);
+        assert!(config.field_encryption_keys.is_empty());
+        assert_eq!(config.auth_transport, AuthTransport::Bearer);
+        assert!(config.auth_cookie_secure);
+    }
+
+    #[test]
+    fn test_reports_all_missing_keys_together() {
+        let errors = errors(AppConfig::from_values(BTreeMap::new()));
+
+        for key in [
+            "DATABASE_URL",
+            "REDIS_URL",
+            "EMAIL_FROM",
+            "SMTP_SERVER",
+            "SMTP_USERNAME",
+            "SMTP_PASSWORD",
+            "JWT_SECRET",
+        ] {
+            assert!(
+                errors.iter().any(|e| e == &format!("{key} is required")),
+                "missing error for {key}: {errors:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_reports_invalid_values_together() {
+        let mut pairs = minimal();
+        pairs.retain(|(k, _)| *k != "JWT_SECRET");
+        pairs.extend([
+            ("JWT_SECRET", "too-short"),
+            ("PORT", "eighty"),
+            ("JWT_ACCESS_EXPIRY", "90000"),
+            ("ERROR_FORMAT", "xml"),
+        ]);
+
+        let errors = errors(AppConfig::from_values(values(&pairs)));
+
+        assert_eq!(errors.len(), 4, "{errors:?}");
+        assert!(errors
+            .iter()
+            .any(|e| e.starts_with("ERROR_FORMAT: invalid value 'xml'")));
+        assert!(errors
+            .iter()
+            .any(|e| e.starts_with("PORT: invalid value 'eighty'")));
+        assert!(errors.iter().any(|e| e.starts_with("JWT_SECRET must be")));
+        assert!(errors
+            .iter()
+            .any(|e| e.starts_with("JWT_ACCESS_EXPIRY must be")));
+    }
+
+    #[test]
+    fn test_admin_user_ids_are_parsed_and_validated() {
+        let mut pairs = minimal();
+        pairs.push((
+            "ADMIN_USER_IDS",
+            "6f1c2f7e-3b5a-4d8e-9c0a-1b2c3d4e5f60, ,00000000-0000-0000-0000-000000000001",
+        ));
+
+        let config = AppConfig::from_values(values(&pairs)).unwrap();
+        assert_eq!(config.admin_user_ids.len(), 2);
+        assert_eq!(
+            config.admin_user_ids[0].to_string(),
+            "6f1c2f7e-3b5a-4d8e-9c0a-1b2c3d4e5f60"
+        );
+
+        let mut pairs = minimal();
+        pairs.push(("ADMIN_USER_IDS", "not-a-uuid"));
+        let errors = errors(AppConfig::from_values(values(&pairs)));
+        assert!(errors
+            .iter()
+            .any(|e| e.starts_with("ADMIN_USER_IDS: invalid value 'not-a-uuid'")));
+    }
+
+    #[test]
+    fn test_image_proxy_origins_must_be_bare_origins() {
+        let mut pairs = minimal();
+        pairs.push((
+            "IMAGE_PROXY_ORIGINS",
+            "https://i.imgur.com, https://images.unsplash.com/",
+        ));
+        let config = AppConfig::from_values(values(&pairs)).unwrap();
+        assert_eq!(config.image_proxy_origins.len(), 2);
+        assert_eq!(
+            config.image_proxy_origins[1].to_string(),
+            "https://images.unsplash.com"
+        );
+
+        let mut pairs = minimal();
+        pairs.push(("IMAGE_PROXY_ORIGINS", "https://i.imgur.com/gallery"));
+        let errors = errors(AppConfig::from_values(values(&pairs)));
+        assert!(errors
+            .iter()
+            .any(|e| e.starts_with("IMAGE_PROXY_ORIGINS: invalid value")));
+    }
+
+    #[test]
+    fn test_email_events_token_must_be_long_enough() {
+        let mut pairs = minimal();
+        pairs.push(("EMAIL_EVENTS_TOKEN", "0123456789abcdef"));
+        let config = AppConfig::from_values(values(&pairs)).unwrap();
+        assert_eq!(
+            config.email_events_token.as_deref(),
+            Some("0123456789abcdef")
+        );
+
+        let mut pairs = minimal();
+        pairs.push(("EMAIL_EVENTS_TOKEN", "short"));
+        let errors = errors(AppConfig::from_values(values(&pairs)));
+        assert!(errors
+            .iter()
+            .any(|e| e == "EMAIL_EVENTS_TOKEN must be at least 16 characters"));
+    }
+
+    #[test]
+    fn test_media_callback_secret_must_be_long_enough() {
+        let mut pairs = minimal();
+        pairs.push(("MEDIA_CALLBACK_SECRET", "short"));
+        let errors = errors(AppConfig::from_values(values(&pairs)));
+        assert!(errors
+            .iter()
+            .any(|e| e == "MEDIA_CALLBACK_SECRET must be at least 16 characters"));
+    }
+
+    #[test]
+    fn test_webauthn_needs_id_and_secure_origin() {
+        let mut pairs = minimal();
+        pairs.push(("WEBAUTHN_RP_ID", "example.com"));
+        pairs.push(("WEBAUTHN_RP_ORIGIN", "https://example.com"));
+        let config = AppConfig::from_values(values(&pairs)).unwrap();
+        assert_eq!(config.webauthn_rp_id.as_deref(), Some("example.com"));
+
+        let mut pairs = minimal();
+        pairs.push(("WEBAUTHN_RP_ID", "example.com"));
+        let unpaired = errors(AppConfig::from_values(values(&pairs)));
+        assert!(unpaired
+            .iter()
+            .any(|e| e == "WEBAUTHN_RP_ID and WEBAUTHN_RP_ORIGIN must be set together"));
+
+        let mut pairs = minimal();
+        pairs.push(("WEBAUTHN_RP_ID", "example.com"));
+        pairs.push(("WEBAUTHN_RP_ORIGIN", "http://example.com"));
+        let errors = errors(AppConfig::from_values(values(&pairs)));
+        assert!(errors
+            .iter()
+            .any(|e| e.starts_with("WEBAUTHN_RP_ORIGIN must be an https:// origin")));
+    }
+
+    #[test]
+    fn test_event_log_policies_combine_both_keys() {
+        let mut pairs = minimal();
+        pairs.push(("EVENT_LOG_SAMPLE", "media_failed=10"));
+        pairs.push((
+            "EVENT_LOG_MAX_PER_MINUTE",
+            "media_failed=60,user_registered=5",
+        ));
+        let config = AppConfig::from_values(values(&pairs)).unwrap();
+        let policy = |kind| config.event_log_policies[&kind];
+        assert_eq!(policy(telemetry::EventKind::MediaFailed).sample_every, 10);
+        assert_eq!(policy(telemetry::EventKind::MediaFailed).max_per_minute, 60);
+        assert_eq!(policy(telemetry::EventKind::UserRegistered).sample_every, 1);
+
+        let mut pairs = minimal();
+        pairs.push(("EVENT_LOG_SAMPLE", "post_published=2"));
+        let errors = errors(AppConfig::from_values(values(&pairs)));
+        assert!(errors
+            .iter()
+            .any(|e| e.starts_with("EVENT_LOG_SAMPLE: expected")));
+    }
+
+    #[test]
+    fn test_log_filter_must_parse() {
+        let mut pairs = minimal();
+        pairs.push(("LOG_FILTER", "info,sqlx::query=debug"));
+        let config = AppConfig::from_values(values(&pairs)).unwrap();
+        assert_eq!(config.log_filter.as_deref(), Some("info,sqlx::query=debug"));
+
+        let mut pairs = minimal();
+        pairs.push(("LOG_FILTER", "info,sqlx::query=loud"));
+        let errors = errors(AppConfig::from_values(values(&pairs)));
+        assert!(errors.iter().any(|e| e.starts_with("LOG_FILTER must be")));
+    }
+
+    #[test]
+    fn test_unsubscribe_secret_must_be_long_enough() {
+        let mut pairs = minimal();
+        pairs.push(("UNSUBSCRIBE_SECRET", "fedcba9876543210fedcba9876543210"));
+        let config = AppConfig::from_values(values(&pairs)).unwrap();
+        assert_eq!(
+            config.unsubscribe_secret,
+            "fedcba9876543210fedcba9876543210"
+        );
+
+        let mut pairs = minimal();
+        pairs.push(("UNSUBSCRIBE_SECRET", "short"));
+        let errors = errors(AppConfig::from_values(values(&pairs)));
+        assert!(errors
+            .iter()
+            .any(|e| e == "UNSUBSCRIBE_SECRET must be at least 32 characters"));
+    }
+
+    #[test]
+    fn test_field_encryption_keys_are_parsed_without_echoing_them() {
+        let mut pairs = minimal();
+        pairs.push((
+            "FIELD_ENCRYPTION_KEYS",
+            "2026-10:AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQE=",
+        ));
+        let config = AppConfig::from_values(values(&pairs)).unwrap();
+        assert_eq!(config.field_encryption_keys.len(), 1);
+        assert_eq!(config.field_encryption_keys[0].id(), "2026-10");
+
+        let mut pairs = minimal();
+        pairs.push(("FIELD_ENCRYPTION_KEYS", "2026-10:dG9vLXNob3J0"));
+        let errors = errors(AppConfig::from_values(values(&pairs)));
+        assert_eq!(
+            errors,
+            vec!["FIELD_ENCRYPTION_KEYS: key '2026-10' must be 32 base64 bytes".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_bot_check_settings() {
+        let mut pairs = minimal();
+        pairs.extend([
+            ("BOT_CHECK_PROVIDER", "turnstile"),
+            ("BOT_CHECK_SECRET", "0x4AAA"),
+            ("BOT_CHECK_ENDPOINTS", "login"),
+            ("BOT_CHECK_LOGIN_AFTER_FAILURES", "5"),
+        ]);
+
+        let config = AppConfig::from_values(values(&pairs)).unwrap();
+        assert_eq!(config.bot_check.provider, BotCheckProvider::Turnstile);
+        assert_eq!(config.bot_check.secret, "0x4AAA");
+        assert_eq!(config.bot_check.endpoints, vec![BotCheckedEndpoint::Login]);
+        assert_eq!(config.bot_check.login_after_failures, 5);
+
+        let mut pairs = minimal();
+        pairs.extend([
+            ("RUST_ENV", "production"),
+            ("BOT_CHECK_PROVIDER", "hcaptcha"),
+            ("BOT_CHECK_ENDPOINTS", "register,comments"),
+        ]);
+        let misconfigured = errors(AppConfig::from_values(values(&pairs)));
+        assert!(misconfigured
+            .iter()
+            .any(|e| e == "BOT_CHECK_SECRET is required"));
+        assert!(misconfigured
+            .iter()
+            .any(|e| e.starts_with("BOT_CHECK_ENDPOINTS: invalid value 'comments'")));
+
+        let mut pairs = minimal();
+        pairs.extend([("RUST_ENV", "production"), ("BOT_CHECK_PROVIDER", "noop")]);
+        let errors = errors(AppConfig::from_values(values(&pairs)));
+        assert_eq!(
+            errors,
+            vec!["BOT_CHECK_PROVIDER=noop is not allowed in production".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_http_email_providers_replace_smtp_settings() {
+        let mut pairs = minimal();
+        pairs.retain(|(k, _)| !k.starts_with("SMTP_"));
+        pairs.extend([
+            ("EMAIL_PROVIDER", "sendgrid"),
+            ("SENDGRID_API_KEY", "SG.key"),
+        ]);
+
+        let config = AppConfig::from_values(values(&pairs)).unwrap();
+        assert!(matches!(
+            config.email,
+            EmailConfig::SendGrid { ref api_key } if api_key == "SG.key"
+        ));
+
+        let mut pairs = minimal();
+        pairs.retain(|(k, _)| !k.starts_with("SMTP_"));
+        pairs.extend([("EMAIL_PROVIDER", "SES"), ("SES_REGION", "eu-west-1")]);
+        let ses_errors = errors(AppConfig::from_values(values(&pairs)));
+        assert_eq!(
+            ses_errors,
+            vec![
+                "SES_ACCESS_KEY_ID is required".to_string(),
+                "SES_SECRET_ACCESS_KEY is required".to_string(),
+            ]
+        );
+
+        let mut pairs = minimal();
+        pairs.push(("EMAIL_PROVIDER", "mailgun"));
+        let errors = errors(AppConfig::from_values(values(&pairs)));
+        assert!(errors
+            .iter()
+            .any(|e| e.starts_with("EMAIL_PROVIDER: invalid value 'mailgun'")));
+    }
+
+    #[test]
+    fn test_test_env_uses_local_smtp_without_credentials() {
+        let config = AppConfig::from_values(values(&[
+            ("RUST_ENV", "test"),
+            ("DATABASE_URL", "postgres://localhost/cms"),
+            ("REDIS_URL", "redis://localhost"),
+            ("EMAIL_FROM", "noreply@example.com"),
+            ("JWT_SECRET", SECRET),
+            ("SMTP_PORT", "2525"),
+        ]))
+        .unwrap();
+
+        match config.email {
+            EmailConfig::Smtp(SmtpConfig::Local { host, port }) => {
+                assert_eq!(host, "localhost");
+                assert_eq!(port, 2525);
+            }
+            _ => panic!("expected local SMTP"),
+        }
+    }
+
+    #[test]
+    fn test_standalone_needs_no_infrastructure_keys() {
+        let config = AppConfig::from_values(values(&[("RUN_MODE", "standalone")])).unwrap();
+
+        assert!(config.is_standalone());
+        assert!(config.database_url.is_empty());
+        assert!(matches!(
+            config.email,
+            EmailConfig::Smtp(SmtpConfig::Local { .. })
+        ));
+        // A generated secret, long enough for HS256
+        assert!(config.jwt.secret_key.len() >= 32);
+
+        let mut pairs = vec![("RUN_MODE", "standalone"), ("JWT_SECRET", "too-short")];
+        let weak_secret = errors(AppConfig::from_values(values(&pairs)));
+        assert!(weak_secret
+            .iter()
+            .any(|e| e.starts_with("JWT_SECRET must be")));
+
+        pairs = vec![("RUN_MODE", "standalone"), ("RUST_ENV", "production")];
+        let errors = errors(AppConfig::from_values(values(&pairs)));
+        assert_eq!(
+            errors,
+            vec!["RUN_MODE=standalone is not allowed in production".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_local_storage_needs_standalone_and_a_processor() {
+        let config = AppConfig::from_values(values(&[
+            ("RUN_MODE", "standalone"),
+            ("LOCAL_STORAGE_DIR", "./local-storage"),
+            ("IMAGE_PROCESSOR_URL", "http://localhost:8081"),
+        ]))
+        .unwrap();
+        assert_eq!(config.local_storage_dir.as_deref(), Some("./local-storage"));
+
+        let without_processor = errors(AppConfig::from_values(values(&[
+            ("RUN_MODE", "standalone"),
+            ("LOCAL_STORAGE_DIR", "./local-storage"),
+        ])));
+        assert_eq!(
+            without_processor,
+            vec!["LOCAL_STORAGE_DIR needs IMAGE_PROCESSOR_URL, the processor serving it"]
+        );
+
+        let mut pairs = minimal();
+        pairs.push(("LOCAL_STORAGE_DIR", "./local-storage"));
+        pairs.push(("IMAGE_PROCESSOR_URL", "http://localhost:8081"));
+        let standard = errors(AppConfig::from_values(values(&pairs)));
+        assert_eq!(
+            standard,
+            vec!["LOCAL_STORAGE_DIR only applies to RUN_MODE=standalone"]
+        );
+    }
+
+    #[test]
+    fn test_cookie_transport_settings() {
+        let mut pairs = minimal();
+        pairs.extend([
+            ("AUTH_TRANSPORT", "cookie"),
+            ("AUTH_COOKIE_DOMAIN", "example.com"),
+        ]);
+        let config = AppConfig::from_values(values(&pairs)).unwrap();
+        assert_eq!(config.auth_transport, AuthTransport::Cookie);
+        assert_eq!(config.auth_cookie_domain.as_deref(), Some("example.com"));
+
+        let mut pairs = minimal();
+        pairs.extend([
+            ("RUST_ENV", "production"),
+            ("AUTH_TRANSPORT", "session"),
+            ("AUTH_COOKIE_SECURE", "false"),
+        ]);
+        let errors = errors(AppConfig::from_values(values(&pairs)));
+        assert!(errors
+            .iter()
+            .any(|e| e.starts_with("AUTH_TRANSPORT: invalid value 'session'")));
+        assert!(errors
+            .iter()
+            .any(|e| e == "AUTH_COOKIE_SECURE=false is not allowed in production"));
+    }
+
+    #[test]
+    fn test_error_message_lists_every_problem() {
+        let err = ConfigError::Invalid(vec!["A is required".into(), "B is required".into()]);
+
+        assert_eq!(
+            err.to_string(),
+            "invalid configuration:\n  - A is required\n  - B is required"
+        );
+    }
+}
@@ -0,0 +1,297 @@
+//! Startup checks that go beyond parsing: settings that are well-formed but
+//! won't work (a guessable JWT secret, a bucket name GCS refuses, an SMTP relay
+//! TLS can't be set up for), and dependencies that don't answer. Every problem
+//! is reported in one go so a single restart can fix them all.
+
+use lettre::{message::Mailbox, AsyncSmtpTransport, Tokio1Executor};
+use std::net::Ipv4Addr;
+use tracing::{error, warn};
+
+use super::{AppConfig, EmailConfig, SmtpConfig};
+
+/// Fragments of secrets copied from examples and tutorials
+const PLACEHOLDER_SECRETS: &[&str] = &["changeme", "change_me", "change-me", "your", "example"];
+
+/// A JWT secret with fewer distinct characters is a word or a pattern
+const MIN_DISTINCT_SECRET_CHARS: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    /// The setting to change
+    pub key: &'static str,
+    /// What is wrong and what to do about it
+    pub message: String,
+    /// The server can't run like this, whatever the environment
+    pub blocking: bool,
+}
+
+/// Problems found before serving anything
+#[derive(Debug, Default)]
+pub struct Preflight {
+    problems: Vec<Problem>,
+}
+
+impl Preflight {
+    /// Checks on the loaded settings alone; nothing is contacted
+    pub fn check_config(config: &AppConfig) -> Self {
+        let mut preflight = Self::default();
+
+        if let Some(message) = weak_secret(&config.jwt.secret_key) {
+            preflight.warn("JWT_SECRET", message);
+        }
+        if let Some(message) = invalid_bucket_name(&config.multimedia_upload_bucket) {
+            preflight.warn("MULTIMEDIA_UPLOAD_BUCKET", message);
+        }
+        if let Some(message) = invalid_bucket_name(&config.multimedia_ready_bucket) {
+            preflight.warn("MULTIMEDIA_READY_BUCKET", message);
+        }
+        if let Err(e) = config.email_from.parse::<Mailbox>() {
+            preflight.warn(
+                "EMAIL_FROM",
+                format!(
+                    "'{}' is not an email address ({e}); use `name@domain` or `Name <name@domain>`",
+                    config.email_from
+                ),
+            );
+        }
+        match &config.email {
+            EmailConfig::Smtp(SmtpConfig::Relay { server, .. }) => {
+                if let Some(message) = invalid_smtp_server(server) {
+                    // The transport can't even be built
+                    preflight.block("SMTP_SERVER", message);
+                }
+            }
+            EmailConfig::Smtp(SmtpConfig::Local { port: 0, .. }) => {
+                preflight.warn("SMTP_PORT", "must not be 0".to_string());
+            }
+            _ => {}
+        }
+
+        preflight
+    }
+
+    /// A problem that only stops the server in production
+    pub fn warn(&mut self, key: &'static str, message: String) {
+        self.problems.push(Problem {
+            key,
+            message,
+            blocking: false,
+        });
+    }
+
+    /// A problem that stops the server everywhere, like an unreachable database
+    pub fn block(&mut self, key: &'static str, message: String) {
+        self.problems.push(Problem {
+            key,
+            message,
+            blocking: true,
+        });
+    }
+
+    pub fn problems(&self) -> &[Problem] {
+        &self.problems
+    }
+
+    /// Whether the server must not start. Outside production only blocking
+    /// problems stop it, so a dev setup with a short secret still runs.
+    pub fn must_stop(&self, production: bool) -> bool {
+        self.problems.iter().any(|p| p.blocking || production)
+    }
+
+    /// Every problem, one per line
+    pub fn report(&self) -> String {
+        self.problems
+            .iter()
+            .map(|p| format!("  - {}: {}", p.key, p.message))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Log the report, and exit with status 1 when the server must not start
+    pub fn finish(&self, production: bool) {
+        if self.problems.is_empty() {
+            return;
+        }
+
+        if self.must_stop(production) {
+            error!(
+                "Startup checks failed ({} problems):\n{}",
+                self.problems.len(),
+                self.report()
+            );
+            std::process::exit(1);
+        }
+        warn!(
+            "Startup checks found {} problems (fatal in production):\n{}",
+            self.problems.len(),
+            self.report()
+        );
+    }
+}
+
+/// Why `secret` is easy to guess, if it is
+fn weak_secret(secret: &str) -> Option<String> {
+    let lower = secret.to_ascii_lowercase();
+    if let Some(fragment) = PLACEHOLDER_SECRETS.iter().find(|f| lower.contains(*f)) {
+        return Some(format!(
+            "looks like a placeholder (contains '{fragment}'); generate one with `openssl rand -hex 32`"
+        ));
+    }
+
+    let mut chars: Vec<char> = secret.chars().collect();
+    chars.sort_unstable();
+    chars.dedup();
+    if chars.len() < MIN_DISTINCT_SECRET_CHARS {
+        return Some(format!(
+            "uses only {} distinct characters; generate one with `openssl rand -hex 32`",
+            chars.len()
+        ));
+    }
+
+    None
+}
+
+/// Why GCS would refuse `name` as a bucket name, if it would
+/// (<https://cloud.google.com/storage/docs/buckets#naming>)
+fn invalid_bucket_name(name: &str) -> Option<String> {
+    let problem = if !name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '_' | '.'))
+    {
+        "may only contain lowercase letters, digits, '-', '_' and '.'"
+    } else if !name.starts_with(|c: char| c.is_ascii_alphanumeric())
+        || !name.ends_with(|c: char| c.is_ascii_alphanumeric())
+    {
+        "must start and end with a letter or digit"
+    } else if name.contains('.') {
+        if name.len() > 222
+            || name
+                .split('.')
+                .any(|part| part.is_empty() || part.len() > 63)
+        {
+            "must be at most 222 characters with dots, and each dot-separated part 1 to 63"
+        } else if name.parse::<Ipv4Addr>().is_ok() {
+            "must not be an IP address"
+        } else {
+            ""
+        }
+    } else if !(3..=63).contains(&name.len()) {
+        "must be 3 to 63 characters"
+    } else {
+        ""
+    };
+    let problem = if problem.is_empty() && (name.starts_with("goog") || name.contains("google")) {
+        "must not start with 'goog' or contain 'google'"
+    } else {
+        problem
+    };
+
+    (!problem.is_empty()).then(|| format!("'{name}' is not a valid GCS bucket name: {problem}"))
+}
+
+/// Why no TLS relay can be set up for `server`, if it can't
+fn invalid_smtp_server(server: &str) -> Option<String> {
+    if server.contains("://") || server.contains(':') || server.contains(char::is_whitespace) {
+        return Some(format!(
+            "'{server}' must be a bare host name like `smtp.example.com`, without scheme or port"
+        ));
+    }
+
+    AsyncSmtpTransport::<Tokio1Executor>::relay(server)
+        .err()
+        .map(|e| format!("cannot set up a TLS relay to '{server}' ({e})"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn config(pairs: &[(&str, &str)]) -> AppConfig {
+        let values: BTreeMap<String, String> = [
+            ("DATABASE_URL", "postgres://localhost/cms"),
+            ("REDIS_URL", "redis://localhost"),
+            ("EMAIL_FROM", "noreply@example.com"),
+            ("SMTP_SERVER", "smtp.mailhost.com"),
+            ("SMTP_USERNAME", "user"),
+            ("SMTP_PASSWORD", "pass"),
+            ("JWT_SECRET", "0123456789abcdef0123456789abcdef"),
+        ]
+        .iter()
+        .chain(pairs)
+        .map(|(k, v)| (k.to_ascii_lowercase(), v.to_string()))
+        .collect();
+        AppConfig::from_values(values).unwrap()
+    }
+
+    fn keys(preflight: &Preflight) -> Vec<&'static str> {
+        preflight.problems().iter().map(|p| p.key).collect()
+    }
+
+    #[test]
+    fn test_sound_config_has_no_problems() {
+        assert!(Preflight::check_config(&config(&[])).problems().is_empty());
+    }
+
+    #[test]
+    fn test_reports_every_problem_together() {
+        let preflight = Preflight::check_config(&config(&[
+            ("JWT_SECRET", "change-me-change-me-change-me-change-me"),
+            ("MULTIMEDIA_UPLOAD_BUCKET", "My_Bucket"),
+            ("EMAIL_FROM", "not an address"),
+            ("SMTP_SERVER", "smtps://smtp.mailhost.com:465"),
+        ]));
+
+        assert_eq!(
+            keys(&preflight),
+            vec![
+                "JWT_SECRET",
+                "MULTIMEDIA_UPLOAD_BUCKET",
+                "EMAIL_FROM",
+                "SMTP_SERVER"
+            ]
+        );
+        let report = preflight.report();
+        assert!(report.contains("openssl rand -hex 32"));
+        assert!(report.contains("without scheme or port"));
+    }
+
+    #[test]
+    fn test_only_blocking_problems_stop_outside_production() {
+        let mut preflight = Preflight::default();
+        preflight.warn("JWT_SECRET", "weak".to_string());
+        assert!(!preflight.must_stop(false));
+        assert!(preflight.must_stop(true));
+
+        preflight.block("DATABASE_URL", "unreachable".to_string());
+        assert!(preflight.must_stop(false));
+    }
+
+    #[test]
+    fn test_weak_secret() {
+        assert!(weak_secret("0123456789abcdef0123456789abcdef").is_none());
+        assert!(weak_secret("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa").is_some());
+        assert!(weak_secret("your-256-bit-secret-goes-here-1234").is_some());
+    }
+
+    #[test]
+    fn test_invalid_bucket_name() {
+        for valid in ["blogport-cms-upload", "media_2024", "assets.example.com"] {
+            assert_eq!(invalid_bucket_name(valid), None, "{valid}");
+        }
+        for invalid in [
+            "ab",
+            "Uploads",
+            "-uploads",
+            "uploads-",
+            "my bucket",
+            "google-media",
+            "goog-media",
+            "192.168.1.1",
+            "a..b",
+            &"a".repeat(64),
+        ] {
+            assert!(invalid_bucket_name(invalid).is_some(), "{invalid}");
+        }
+    }
+}
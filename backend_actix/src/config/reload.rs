@@ -0,0 +1,183 @@
+//! The settings that can change while the server runs. `SIGHUP` or
+//! `POST /api/admin/config/reload` reads the configuration again and applies
+//! them; everything else still needs a restart.
+
+use std::sync::Arc;
+
+use tracing::{info, warn};
+
+use crate::config::{AppConfig, ConfigError};
+#[cfg(unix)]
+use crate::shared::lifecycle::ShutdownSignal;
+use crate::shared::log_filter::LogFilter;
+use crate::shared::rate_limit::RateLimiter;
+
+/// Reads the configuration the way startup does
+pub type ConfigLoader = Arc<dyn Fn() -> Result<AppConfig, ConfigError> + Send + Sync>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReloadError {
+    /// The new configuration is rejected as a whole; nothing changes
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+
+    #[error("LOG_FILTER could not be applied: {0}")]
+    LogFilter(String),
+}
+
+/// Applies a fresh configuration to the live rate limiters and log filter.
+/// In-flight requests and uploads are not affected.
+#[derive(Clone)]
+pub struct ConfigReloader {
+    load: ConfigLoader,
+    contact_rate_limiter: RateLimiter,
+    upload_url_rate_limiter: RateLimiter,
+    log_filter: Option<LogFilter>,
+}
+
+impl ConfigReloader {
+    /// The limiters must be the ones serving requests (clones share their limit)
+    pub fn new(
+        load: ConfigLoader,
+        contact_rate_limiter: RateLimiter,
+        upload_url_rate_limiter: RateLimiter,
+        log_filter: Option<LogFilter>,
+    ) -> Self {
+        Self {
+            load,
+            contact_rate_limiter,
+            upload_url_rate_limiter,
+            log_filter,
+        }
+    }
+
+    /// The live log filter; `None` where no subscriber was installed (tests)
+    pub fn log_filter(&self) -> Option<&LogFilter> {
+        self.log_filter.as_ref()
+    }
+
+    /// Load the configuration and apply what changed. Returns the keys applied.
+    pub fn reload(&self) -> Result<Vec<&'static str>, ReloadError> {
+        let config = (self.load)()?;
+        let mut changed = Vec::new();
+
+        if let (Some(log_filter), Some(directives)) = (&self.log_filter, &config.log_filter) {
+            if log_filter.current() != *directives {
+                log_filter.set(directives).map_err(ReloadError::LogFilter)?;
+                changed.push("LOG_FILTER");
+            }
+        }
+        if self.contact_rate_limiter.limit() != config.contact_rate_limit {
+            self.contact_rate_limiter
+                .set_limit(config.contact_rate_limit);
+            changed.push("CONTACT_RATE_LIMIT");
+        }
+        if self.upload_url_rate_limiter.limit() != config.upload_url_rate_limit {
+            self.upload_url_rate_limiter
+                .set_limit(config.upload_url_rate_limit);
+            changed.push("UPLOAD_URL_RATE_LIMIT");
+        }
+
+        Ok(changed)
+    }
+
+    /// Reload on every `SIGHUP` until shutdown. Runs as a background job.
+    #[cfg(unix)]
+    pub async fn run(self, mut signal: ShutdownSignal) {
+        use tokio::signal::unix::{signal as unix_signal, SignalKind};
+
+        let mut hangups = match unix_signal(SignalKind::hangup()) {
+            Ok(hangups) => hangups,
+            Err(e) => {
+                warn!(error = %e, "Cannot listen for SIGHUP, config reload only via the API");
+                return;
+            }
+        };
+
+        loop {
+            tokio::select! {
+                _ = signal.wait() => break,
+                _ = hangups.recv() => match self.reload() {
+                    Ok(changed) => info!(?changed, "Configuration reloaded on SIGHUP"),
+                    Err(e) => warn!(error = %e, "Configuration reload failed, keeping the current one"),
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::time::Duration;
+
+    fn loader(pairs: &'static [(&'static str, &'static str)]) -> ConfigLoader {
+        Arc::new(move || {
+            let mut values: BTreeMap<String, String> = [
+                ("database_url", "postgres://localhost/cms"),
+                ("redis_url", "redis://localhost"),
+                ("email_from", "noreply@example.com"),
+                ("smtp_server", "smtp.example.com"),
+                ("smtp_username", "user"),
+                ("smtp_password", "pass"),
+                ("jwt_secret", "0123456789abcdef0123456789abcdef"),
+            ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+            for (k, v) in pairs {
+                values.insert(k.to_ascii_lowercase(), v.to_string());
+            }
+            AppConfig::from_values(values)
+        })
+    }
+
+    fn limiters() -> (RateLimiter, RateLimiter) {
+        (
+            RateLimiter::new(5, Duration::from_secs(3600)),
+            RateLimiter::new(30, Duration::from_secs(3600)),
+        )
+    }
+
+    #[test]
+    fn test_reload_applies_new_rate_limits() {
+        let (contact, upload) = limiters();
+        let reloader = ConfigReloader::new(
+            loader(&[("CONTACT_RATE_LIMIT", "2"), ("UPLOAD_URL_RATE_LIMIT", "30")]),
+            contact.clone(),
+            upload.clone(),
+            None,
+        );
+
+        let changed = reloader.reload().unwrap();
+
+        assert_eq!(changed, vec!["CONTACT_RATE_LIMIT"]);
+        assert_eq!(contact.limit(), 2);
+        assert_eq!(upload.limit(), 30);
+    }
+
+    #[test]
+    fn test_unchanged_configuration_changes_nothing() {
+        let (contact, upload) = limiters();
+        let reloader = ConfigReloader::new(loader(&[]), contact, upload, None);
+
+        assert!(reloader.reload().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_configuration_keeps_the_current_limits() {
+        let (contact, upload) = limiters();
+        let reloader = ConfigReloader::new(
+            loader(&[("CONTACT_RATE_LIMIT", "2"), ("PORT", "not a port")]),
+            contact.clone(),
+            upload,
+            None,
+        );
+
+        let err = reloader.reload().unwrap_err();
+
+        assert!(matches!(err, ReloadError::Config(ConfigError::Invalid(_))));
+        assert_eq!(contact.limit(), 5);
+    }
+}
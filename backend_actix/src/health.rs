@@ -1,74 +1,550 @@
 use actix_web::{get, web, HttpResponse, Responder};
-use redis::aio::ConnectionManager;
+use deadpool_redis::{redis, Pool};
+use futures::future::BoxFuture;
 use sea_orm::{ConnectionTrait, DatabaseConnection, Statement};
 use serde::Serialize;
-use std::sync::Arc;
-use tokio::sync::Mutex;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::warn;
+use utoipa::ToSchema;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::auth::adapter::incoming::web::extractors::auth::AdminUser;
+use crate::email::adapter::outgoing::smtp_sender::Mailer;
+use crate::multimedia::adapter::outgoing::cloud_storage::BucketCheck;
+use crate::shared::api::ApiResponse;
+use crate::shared::schema_check::{self, SchemaReport};
+
+/// How long an SMTP or GCS check result is reused before the dependency is contacted again
+const CHECK_TTL: Duration = Duration::from_secs(60);
+const CHECK_TIMEOUT: Duration = Duration::from_secs(5);
 
 #[derive(Serialize)]
 struct HealthResponse {
     status: &'static str,
 }
 
-#[derive(Serialize)]
-struct ReadinessResponse {
+#[derive(Serialize, ToSchema)]
+pub struct DependencyStatus {
+    /// "ok" | "unhealthy" | "skipped" (not used by this deployment)
+    status: &'static str,
+    latency_ms: u64,
+    /// Why the check failed; only reported to administrators
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct DependencyChecks {
+    database: DependencyStatus,
+    redis: DependencyStatus,
+    smtp: DependencyStatus,
+    gcs: DependencyStatus,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SchemaStatus {
+    /// "ok" | "drift" (migrations or columns differ from this build) |
+    /// "unchecked" (the database couldn't be read)
     status: &'static str,
-    database: &'static str,
-    redis: &'static str,
+    /// What differs; only reported to administrators
+    #[serde(skip_serializing_if = "Option::is_none")]
+    drift: Option<SchemaReport>,
+}
+
+impl SchemaStatus {
+    fn of(report: &SchemaReport, detailed: bool) -> Self {
+        let drift = report.has_drift();
+        Self {
+            status: if drift { "drift" } else { "ok" },
+            drift: (drift && detailed).then(|| report.clone()),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ReadinessResponse {
+    /// "ok" | "degraded" (non-critical dependency down) | "unhealthy"
+    status: &'static str,
+    checks: DependencyChecks,
+    /// Readiness reports the check made at startup; the details check again
+    schema: SchemaStatus,
+}
+
+type CheckFn = Box<dyn Fn() -> BoxFuture<'static, Result<(), String>> + Send + Sync>;
+
+/// A non-critical dependency check for the readiness probe.
+///
+/// - Nothing is contacted at startup; the first readiness call runs the check
+/// - Results are cached for `CHECK_TTL` so frequent probes don't hammer the dependency
+/// - A failure marks the instance `degraded`, not unready
+/// - Without a check (dependency not used) it always passes
+struct CachedProbe {
+    name: &'static str,
+    check: Option<CheckFn>,
+    ttl: Duration,
+    last: Mutex<Option<(Instant, bool)>>,
+}
+
+impl CachedProbe {
+    fn new(name: &'static str, check: Option<CheckFn>, ttl: Duration) -> Self {
+        Self {
+            name,
+            check,
+            ttl,
+            last: Mutex::new(None),
+        }
+    }
+
+    /// Cached result, for the readiness probe
+    async fn check(&self) -> bool {
+        let cached = *self.last.lock().unwrap();
+        if let Some((at, ok)) = cached {
+            if at.elapsed() < self.ttl {
+                return ok;
+            }
+        }
+
+        let ok = match self.check_now().await {
+            None | Some(Ok(())) => true,
+            Some(Err(e)) => {
+                warn!(dependency = self.name, error = %e, "Readiness check failed");
+                false
+            }
+        };
+
+        *self.last.lock().unwrap() = Some((Instant::now(), ok));
+        ok
+    }
+
+    /// Fresh result with the reason of a failure; `None` without a check
+    async fn check_now(&self) -> Option<Result<(), String>> {
+        let check = self.check.as_ref()?;
+        let result = match tokio::time::timeout(CHECK_TIMEOUT, check()).await {
+            Ok(result) => result,
+            Err(_) => Err(format!("timed out after {}s", CHECK_TIMEOUT.as_secs())),
+        };
+        Some(result)
+    }
+}
+
+/// SMTP connectivity check: connects and sends NOOP.
+/// With an HTTP API email provider there is no SMTP server and the check passes.
+pub struct SmtpProbe(CachedProbe);
+
+impl SmtpProbe {
+    pub fn new(mailer: Arc<dyn Mailer>) -> Self {
+        Self::with_ttl(mailer, CHECK_TTL)
+    }
+
+    pub fn with_ttl(mailer: Arc<dyn Mailer>, ttl: Duration) -> Self {
+        let check: CheckFn = Box::new(move || {
+            let mailer = Arc::clone(&mailer);
+            Box::pin(async move { mailer.test_connection().await })
+        });
+        Self(CachedProbe::new("smtp", Some(check), ttl))
+    }
+
+    /// For email providers reached over HTTP (SendGrid, SES)
+    pub fn disabled() -> Self {
+        Self(CachedProbe::new("smtp", None, CHECK_TTL))
+    }
+
+    pub async fn check(&self) -> bool {
+        self.0.check().await
+    }
+}
+
+/// Upload bucket check: reads the bucket's metadata with the server's
+/// credentials, so a wrong key or bucket shows before the first upload fails
+pub struct GcsProbe(CachedProbe);
+
+impl GcsProbe {
+    pub fn new(bucket: Arc<dyn BucketCheck>) -> Self {
+        Self::with_ttl(bucket, CHECK_TTL)
+    }
+
+    pub fn with_ttl(bucket: Arc<dyn BucketCheck>, ttl: Duration) -> Self {
+        let check: CheckFn = Box::new(move || {
+            let bucket = Arc::clone(&bucket);
+            Box::pin(async move { bucket.check_bucket().await })
+        });
+        Self(CachedProbe::new("gcs", Some(check), ttl))
+    }
+
+    pub async fn check(&self) -> bool {
+        self.0.check().await
+    }
+}
+
+fn status_of(ok: bool, started: Instant) -> DependencyStatus {
+    DependencyStatus {
+        status: if ok { "ok" } else { "unhealthy" },
+        latency_ms: started.elapsed().as_millis() as u64,
+        error: None,
+    }
+}
+
+/// Like `status_of`, keeping the reason of a failure
+fn detail_of(result: Option<Result<(), String>>, started: Instant) -> DependencyStatus {
+    let (status, error) = match result {
+        None => ("skipped", None),
+        Some(Ok(())) => ("ok", None),
+        Some(Err(e)) => ("unhealthy", Some(e)),
+    };
+    DependencyStatus {
+        status,
+        latency_ms: started.elapsed().as_millis() as u64,
+        error,
+    }
+}
+
+/// Database, Redis and schema drift are critical; SMTP and GCS only degrade
+/// the instance
+fn overall_status(checks: &DependencyChecks, schema: &SchemaStatus) -> &'static str {
+    let failed = |check: &DependencyStatus| check.status == "unhealthy";
+    if failed(&checks.database) || failed(&checks.redis) || schema.status == "drift" {
+        "unhealthy"
+    } else if failed(&checks.smtp) || failed(&checks.gcs) {
+        "degraded"
+    } else {
+        "ok"
+    }
+}
+
+async fn check_database(db: &DatabaseConnection) -> Result<(), String> {
+    db.execute(Statement::from_string(
+        db.get_database_backend(),
+        "SELECT 1",
+    ))
+    .await
+    .map(|_| ())
+    .map_err(|e| e.to_string())
+}
+
+async fn check_redis(pool: &Pool) -> Result<(), String> {
+    let mut conn = pool
+        .get()
+        .await
+        .map_err(|e| format!("no connection: {e}"))?;
+    redis::cmd("PING")
+        .query_async::<String>(&mut conn)
+        .await
+        .map(|_| ())
+        .map_err(|e| e.to_string())
 }
 
 /// LIVENESS PROBE
 /// - No I/O
 /// - No DB
 /// - No Redis
-#[get("/health")]
-pub async fn health() -> impl Responder {
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "health",
+    responses(
+        (status = 200, description = "Process is up"),
+    )
+)]
+#[get("/health/live")]
+pub async fn liveness() -> impl Responder {
     HttpResponse::Ok().json(HealthResponse { status: "ok" })
 }
 
 /// READINESS PROBE
-/// - Checks critical dependencies
-#[get("/ready")]
+/// - Database and Redis are critical: either one down -> 503
+/// - So is a schema that didn't match the build at startup (only possible
+///   outside production, which refuses to start)
+/// - SMTP and GCS are reported but only degrade the status
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "`ok`, or `degraded` when only SMTP or GCS is failing", body = ReadinessResponse),
+        (status = 503, description = "Database or Redis unreachable, or schema drift", body = ReadinessResponse),
+    )
+)]
+#[get("/health/ready")]
 pub async fn readiness(
     db: web::Data<Arc<DatabaseConnection>>,
-    redis: web::Data<Arc<Mutex<ConnectionManager>>>,
+    redis_pool: web::Data<Arc<Pool>>,
+    smtp: web::Data<SmtpProbe>,
+    gcs: web::Data<GcsProbe>,
+    schema: web::Data<SchemaReport>,
 ) -> impl Responder {
-    let db_status = match db
-        .execute(Statement::from_string(
-            db.get_database_backend(),
-            "SELECT 1",
-        ))
-        .await
-    {
-        Ok(_) => "ok",
-        Err(_) => "unhealthy",
+    let started = Instant::now();
+    let db_ok = match check_database(&db).await {
+        Ok(()) => true,
+        Err(e) => {
+            warn!(error = %e, "Database readiness check failed");
+            false
+        }
     };
+    let database = status_of(db_ok, started);
 
-    let redis_status = {
-        let mut conn = redis.lock().await;
-        match redis::cmd("PING").query_async::<String>(&mut *conn).await {
-            Ok(_) => "ok",
-            Err(_) => "unhealthy",
+    let started = Instant::now();
+    let redis_ok = match check_redis(&redis_pool).await {
+        Ok(()) => true,
+        Err(e) => {
+            warn!(error = %e, "Redis readiness check failed");
+            false
         }
     };
+    let redis = status_of(redis_ok, started);
 
-    let overall_status = if db_status == "ok" && redis_status == "ok" {
-        "ok"
-    } else {
-        "unhealthy"
+    let started = Instant::now();
+    let smtp = status_of(smtp.check().await, started);
+
+    let started = Instant::now();
+    let gcs = status_of(gcs.check().await, started);
+
+    let checks = DependencyChecks {
+        database,
+        redis,
+        smtp,
+        gcs,
+    };
+    let schema = SchemaStatus::of(&schema, false);
+    let status = overall_status(&checks, &schema);
+    let response = ReadinessResponse {
+        status,
+        checks,
+        schema,
     };
 
-    if overall_status == "ok" {
-        HttpResponse::Ok().json(ReadinessResponse {
-            status: "ok",
-            database: db_status,
-            redis: redis_status,
-        })
+    if status == "unhealthy" {
+        HttpResponse::ServiceUnavailable().json(response)
     } else {
-        HttpResponse::ServiceUnavailable().json(ReadinessResponse {
-            status: "unhealthy",
-            database: db_status,
-            redis: redis_status,
-        })
+        HttpResponse::Ok().json(response)
+    }
+}
+
+/// Check every dependency now, with the reason of each failure
+///
+/// Unlike `/health/ready` nothing is cached: SMTP gets a fresh connection
+/// and NOOP, and GCS a metadata read of the upload bucket with the server's
+/// credentials. Checks run in parallel, each with its own latency. The schema
+/// is compared with the build again, listing what differs.
+#[utoipa::path(
+    get,
+    path = "/api/admin/health/details",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Result of each check", body = inline(SuccessResponse<ReadinessResponse>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/admin/health/details")]
+pub async fn health_details(
+    _admin: AdminUser,
+    db: web::Data<Arc<DatabaseConnection>>,
+    redis_pool: web::Data<Arc<Pool>>,
+    smtp: web::Data<SmtpProbe>,
+    gcs: web::Data<GcsProbe>,
+) -> impl Responder {
+    let timed = |check: BoxFuture<'static, Option<Result<(), String>>>| async move {
+        let started = Instant::now();
+        let result = check.await;
+        detail_of(result, started)
+    };
+
+    let schema = |db: Arc<DatabaseConnection>| async move {
+        match schema_check::check(&db).await {
+            Ok(report) => SchemaStatus::of(&report, true),
+            Err(e) => {
+                warn!(error = %e, "Schema check failed");
+                SchemaStatus {
+                    status: "unchecked",
+                    drift: None,
+                }
+            }
+        }
+    };
+
+    let db = db.get_ref().clone();
+    let redis_pool = redis_pool.get_ref().clone();
+    let smtp = smtp.into_inner();
+    let gcs = gcs.into_inner();
+    let (database, redis, smtp, gcs, schema) = tokio::join!(
+        timed(Box::pin({
+            let db = Arc::clone(&db);
+            async move { Some(check_database(&db).await) }
+        })),
+        timed(Box::pin(
+            async move { Some(check_redis(&redis_pool).await) }
+        )),
+        timed(Box::pin(async move { smtp.0.check_now().await })),
+        timed(Box::pin(async move { gcs.0.check_now().await })),
+        schema(db),
+    );
+
+    let checks = DependencyChecks {
+        database,
+        redis,
+        smtp,
+        gcs,
+    };
+    ApiResponse::success(ReadinessResponse {
+        status: overall_status(&checks, &schema),
+        checks,
+        schema,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{test, App};
+    use async_trait::async_trait;
+    use lettre::Message;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingMailer {
+        calls: Arc<AtomicUsize>,
+        reachable: bool,
+    }
+
+    #[async_trait]
+    impl Mailer for CountingMailer {
+        async fn send(&self, _email: Message) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn test_connection(&self) -> Result<(), String> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.reachable {
+                Ok(())
+            } else {
+                Err("connection refused".to_string())
+            }
+        }
+    }
+
+    fn probe(reachable: bool, ttl: Duration) -> (SmtpProbe, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mailer = CountingMailer {
+            calls: Arc::clone(&calls),
+            reachable,
+        };
+        (SmtpProbe::with_ttl(Arc::new(mailer), ttl), calls)
+    }
+
+    #[actix_web::test]
+    async fn test_liveness_returns_ok_without_dependencies() {
+        let app = test::init_service(App::new().service(liveness)).await;
+
+        let req = test::TestRequest::get().uri("/health/live").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), 200);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["status"], "ok");
+    }
+
+    #[tokio::test]
+    async fn test_smtp_probe_is_lazy_and_cached() {
+        let (probe, calls) = probe(true, Duration::from_secs(60));
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        assert!(probe.check().await);
+        assert!(probe.check().await);
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_smtp_probe_rechecks_after_ttl() {
+        let (probe, calls) = probe(false, Duration::ZERO);
+
+        assert!(!probe.check().await);
+        assert!(!probe.check().await);
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_smtp_probe_passes() {
+        assert!(SmtpProbe::disabled().check().await);
+        assert_eq!(SmtpProbe::disabled().0.check_now().await, None);
+    }
+
+    struct FakeBucket {
+        result: Result<(), String>,
+    }
+
+    #[async_trait]
+    impl BucketCheck for FakeBucket {
+        fn bucket(&self) -> &str {
+            "uploads"
+        }
+
+        async fn check_bucket(&self) -> Result<(), String> {
+            self.result.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_gcs_probe_keeps_failure_reason() {
+        let probe = GcsProbe::new(Arc::new(FakeBucket {
+            result: Err("permission denied".to_string()),
+        }));
+
+        assert!(!probe.check().await);
+        let detail = detail_of(probe.0.check_now().await, Instant::now());
+        assert_eq!(detail.status, "unhealthy");
+        assert_eq!(detail.error.as_deref(), Some("permission denied"));
+    }
+
+    #[actix_web::test]
+    async fn test_only_database_redis_and_schema_make_unhealthy() {
+        let ok = || detail_of(Some(Ok(())), Instant::now());
+        let down = || detail_of(Some(Err("down".to_string())), Instant::now());
+        let skipped = || detail_of(None, Instant::now());
+        let matching = SchemaStatus::of(&SchemaReport::default(), false);
+
+        let checks = DependencyChecks {
+            database: ok(),
+            redis: ok(),
+            smtp: skipped(),
+            gcs: ok(),
+        };
+        assert_eq!(overall_status(&checks, &matching), "ok");
+
+        let checks = DependencyChecks {
+            database: ok(),
+            redis: ok(),
+            smtp: ok(),
+            gcs: down(),
+        };
+        assert_eq!(overall_status(&checks, &matching), "degraded");
+
+        let checks = DependencyChecks {
+            database: ok(),
+            redis: down(),
+            smtp: ok(),
+            gcs: ok(),
+        };
+        assert_eq!(overall_status(&checks, &matching), "unhealthy");
+
+        let checks = DependencyChecks {
+            database: ok(),
+            redis: ok(),
+            smtp: ok(),
+            gcs: ok(),
+        };
+        let drift = SchemaStatus::of(
+            &SchemaReport {
+                missing_columns: vec!["users.token_version".to_string()],
+                ..SchemaReport::default()
+            },
+            false,
+        );
+        assert_eq!(overall_status(&checks, &drift), "unhealthy");
+        assert!(drift.drift.is_none());
     }
 }
@@ -0,0 +1,1070 @@
+pub mod modules;
+pub use modules::activity;
+pub use modules::admin;
+pub use modules::analytics;
+pub use modules::auth;
+pub use modules::batch;
+pub use modules::blocklist;
+pub use modules::contact;
+pub use modules::cv;
+pub use modules::email;
+pub use modules::export;
+pub use modules::import;
+pub use modules::multimedia;
+pub use modules::pages;
+pub use modules::project;
+pub use modules::redirects;
+pub use modules::search;
+pub use modules::site;
+pub use modules::skills;
+pub use modules::topic;
+pub use modules::translations;
+pub use modules::trash;
+pub use modules::webhooks;
+pub mod api;
+pub mod config;
+pub mod health;
+pub mod shared;
+mod standalone;
+
+// Test helpers module - only compiled with feature flag
+#[cfg(feature = "test-helpers")]
+mod test_helpers;
+
+// ... (all your existing imports remain the same)
+use crate::activity::application::ports::incoming::use_cases::ListActivitiesUseCase;
+use crate::admin::application::ports::incoming::use_cases::{
+    CheckIntegrityUseCase, GetAdminStatsUseCase, GetStorageReportUseCase, QuickSearchUseCase,
+};
+use crate::analytics::application::analytics_use_cases::AnalyticsUseCases;
+use crate::auth::adapter::incoming::web::cookies::AuthCookies;
+use crate::auth::adapter::outgoing::consumed_token_postgres::ConsumedTokenRepositoryPostgres;
+use crate::auth::adapter::outgoing::jwt::JwtTokenService;
+use crate::auth::adapter::outgoing::passkey_challenges_redis::RedisPasskeyChallenges;
+use crate::auth::adapter::outgoing::passkey_repository_postgres::PasskeyRepositoryPostgres;
+use crate::auth::adapter::outgoing::token_repository_redis::RedisTokenRepository;
+use crate::auth::adapter::outgoing::user_query_postgres::UserQueryPostgres;
+use crate::auth::adapter::outgoing::user_repository_postgres::UserRepositoryPostgres;
+use crate::auth::adapter::outgoing::webauthn_ceremony::{DisabledPasskeys, WebauthnCeremony};
+use crate::auth::application::auth_use_cases::AuthUseCases;
+use crate::auth::application::passkey_use_cases::PasskeyUseCases;
+use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+use crate::auth::application::ports::outgoing::PasskeyCeremony;
+
+use crate::cv::adapter::outgoing::cv_repo_postgres::CVRepoPostgres;
+use crate::cv::adapter::outgoing::PlainTextCvRenderer;
+use crate::cv::application::cv_use_cases::CvUseCases;
+
+use crate::batch::application::ports::incoming::use_cases::RunBatchUseCase;
+use crate::email::adapter::outgoing::email_outbox_postgres::EmailOutboxPostgres;
+use crate::email::adapter::outgoing::sendgrid_sender::SendGridEmailSender;
+use crate::email::adapter::outgoing::ses_sender::SesEmailSender;
+use crate::email::adapter::outgoing::smtp_sender::SmtpEmailSender;
+use crate::email::application::domain::unsubscribe_token::UnsubscribeTokens;
+use crate::email::application::email_use_cases::EmailUseCases;
+use crate::email::application::ports::outgoing::EmailSender;
+use crate::email::application::services::{DispatchPolicy, OutboxDispatcher, UserEmailService};
+use crate::email::application::templates::EmailTemplates;
+use crate::export::application::ports::incoming::use_cases::ExportContentUseCase;
+use crate::import::application::ports::incoming::use_cases::ImportContentUseCase;
+use crate::modules::auth::application::helpers::{TokenVersionGuard, UserIdentityResolver};
+
+use crate::blocklist::application::blocklist_use_cases::BlocklistUseCases;
+use crate::config::{
+    preflight::Preflight, reload::ConfigReloader, AppConfig, AuthTransport, EmailConfig, SmtpConfig,
+};
+use crate::contact::application::contact_use_cases::ContactUseCases;
+use crate::modules::multimedia::application::domain::policies::upload_policy::UploadPolicy;
+use crate::modules::multimedia::application::media_use_cases::MultimediaUseCases;
+use crate::modules::project::application::project_use_cases::ProjectUseCases;
+use crate::modules::topic::application::topic_use_cases::TopicUseCases;
+use crate::multimedia::adapter::outgoing::processor::{
+    HttpMediaProcessorClient, UnconfiguredMediaProcessor,
+};
+use crate::multimedia::application::ports::outgoing::processor::MediaProcessorClient;
+use crate::pages::application::page_use_cases::PageUseCases;
+use crate::project::application::ports::incoming::use_cases::SyncProjectReadmeUseCase;
+use crate::redirects::application::redirect_use_cases::RedirectUseCases;
+use crate::search::application::ports::incoming::use_cases::SearchContentUseCase;
+use crate::shared::api::custom_json_config;
+use crate::shared::bot_check::BotGate;
+use crate::shared::cache::{CachePort, NoopCache, RedisCache};
+use crate::shared::client_ip::TrustedProxies;
+use crate::shared::field_cipher::FieldCipher;
+use crate::shared::image_proxy::AllowedOrigins;
+use crate::shared::lifecycle::BackgroundJobs;
+use crate::shared::log_filter::LogFilter;
+use crate::shared::metrics::Metered;
+use crate::shared::rate_limit::RateLimiter;
+use crate::shared::schema_check::{self, SchemaReport};
+use crate::shared::sql_log::SlowStatementLog;
+use crate::shared::telemetry::LogFormat;
+use crate::shared::unit_of_work::UnitOfWorkPostgres;
+use crate::site::application::site_use_cases::SiteUseCases;
+use crate::skills::application::skill_use_cases::SkillUseCases;
+use crate::translations::application::translation_use_cases::TranslationUseCases;
+use crate::trash::application::trash_use_cases::TrashUseCases;
+use crate::webhooks::application::webhook_use_cases::WebhookUseCases;
+
+use actix_web::{web, App, HttpServer};
+use deadpool_redis::{Config, Runtime};
+
+use sea_orm::{ConnectOptions, Database};
+use sqlx::postgres::PgConnectOptions;
+#[cfg(feature = "sqlite")]
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tracing::{error, info};
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone)]
+pub struct AppState {
+    pub cv: CvUseCases,
+    pub auth: AuthUseCases,
+    /// Rejects tokens issued before the user's last logout from all devices
+    pub token_version_guard: TokenVersionGuard,
+    /// Set in cookie mode (`AUTH_TRANSPORT=cookie`); `None` means bearer tokens only
+    pub auth_cookies: Option<AuthCookies>,
+    pub passkeys: PasskeyUseCases,
+    pub topics: TopicUseCases,
+    pub project: ProjectUseCases,
+    pub multimedia: MultimediaUseCases,
+    pub user_identity_resolver: UserIdentityResolver,
+    pub multimedia_upload_policy: UploadPolicy,
+    /// Per owner, on `POST /api/media/upload-url`; shared with that use case
+    pub upload_url_rate_limiter: RateLimiter,
+    pub admin_stats_use_case: Arc<dyn GetAdminStatsUseCase + Send + Sync>,
+    pub integrity_check_use_case: Arc<dyn CheckIntegrityUseCase + Send + Sync>,
+    pub quick_search_use_case: Arc<dyn QuickSearchUseCase + Send + Sync>,
+    pub storage_report_use_case: Arc<dyn GetStorageReportUseCase + Send + Sync>,
+    pub admin_user_ids: Vec<Uuid>,
+    pub trash: TrashUseCases,
+    pub webhooks: WebhookUseCases,
+    pub email: EmailUseCases,
+    /// Shared secret of `POST /api/internal/email-events`; `None` disables it
+    pub email_events_token: Option<String>,
+    /// HMAC key of `POST /api/internal/media/manifest`; `None` disables it
+    pub media_callback_secret: Option<String>,
+    /// Shared secret of `POST /api/auth/introspect`; `None` disables it
+    pub introspection_token: Option<String>,
+    /// Shared secret of `GET /api/admin/metrics`; `None` disables it
+    pub metrics_token: Option<String>,
+    pub config_reloader: ConfigReloader,
+    pub contact: ContactUseCases,
+    /// Per client address, on `POST /api/public/contact`
+    pub contact_rate_limiter: RateLimiter,
+    pub bot_gate: BotGate,
+    pub site: SiteUseCases,
+    pub pages: PageUseCases,
+    pub analytics: AnalyticsUseCases,
+    pub redirects: RedirectUseCases,
+    /// Checked on public writes by `blocklist_middleware`
+    pub blocklist: BlocklistUseCases,
+    pub search_content_use_case: Arc<dyn SearchContentUseCase + Send + Sync>,
+    pub export_content_use_case: Arc<dyn ExportContentUseCase + Send + Sync>,
+    pub import_content_use_case: Arc<dyn ImportContentUseCase + Send + Sync>,
+    pub run_batch_use_case: Arc<dyn RunBatchUseCase + Send + Sync>,
+    pub list_activities_use_case: Arc<dyn ListActivitiesUseCase + Send + Sync>,
+    pub translations: TranslationUseCases,
+    pub skills: SkillUseCases,
+}
+
+#[actix_web::main]
+#[cfg(not(tarpaulin_include))]
+async fn start() -> std::io::Result<()> {
+    use crate::{
+        activity::{
+            adapter::outgoing::ActivityLogPostgres, application::services::ListActivitiesService,
+        },
+        admin::{
+            adapter::outgoing::{
+                IntegrityQueryPostgres, QuickSearchQueryPostgres, StatsQueryPostgres,
+                StorageReportQueryPostgres,
+            },
+            application::services::{
+                CheckIntegrityService, GetAdminStatsService, GetStorageReportService,
+                QuickSearchService,
+            },
+        },
+        analytics::{
+            adapter::outgoing::PageViewRepositoryPostgres,
+            application::{
+                domain::visitor_id::VisitorHasher,
+                services::{PageViewBuffer, PageViewFlusher},
+            },
+        },
+        auth::adapter::outgoing::security::argon2_hasher::Argon2Hasher,
+        batch::application::services::{BatchTargets, RunBatchService},
+        blocklist::adapter::outgoing::BlockRuleRepositoryPostgres,
+        contact::adapter::outgoing::ContactMessageRepositoryPostgres,
+        cv::adapter::outgoing::{CVArchiverPostgres, CVQueryPostgres},
+        export::{
+            adapter::outgoing::ExportSourcePostgres, application::services::ExportContentService,
+        },
+        import::{
+            adapter::outgoing::SlugLookupPostgres,
+            application::services::{ImportContentService, ImportTargets},
+        },
+        multimedia::{
+            adapter::outgoing::{
+                cloud_storage::{
+                    GcsBucketCheck, GcsObjectInventory, GcsStorageQuery, HttpMediaTransfer,
+                },
+                db::{
+                    MediaQueryPostgres, MediaRepositoryPostgres, MediaRetryQueuePostgres,
+                    MediaUploadBatchPostgres, StorageOverrideStorePostgres,
+                },
+            },
+            application::ports::incoming::services::{
+                CollectOrphanObjectsService, IngestMediaManifestService, MediaReconciler,
+                MediaRetrier, OrphanObjectCollector, ProxyImageService, ReconcileMediaService,
+                RetryFailedMediaService,
+            },
+        },
+        pages::{
+            adapter::outgoing::PageRepositoryPostgres,
+            application::{
+                domain::preview_token::PreviewTokens,
+                services::{AutosaveBuffer, AutosaveFlusher},
+            },
+        },
+        project::{
+            adapter::outgoing::{
+                GitHubRepoMetadataProvider, ProjectArchiverPostgres, ProjectQueryPostgres,
+                ProjectRepositoryPostgres, ProjectTopicRepositoryPostgres,
+                ProjectUpdateRepositoryPostgres, ReadmeSyncRepositoryPostgres,
+            },
+            application::service::{ReadmeSyncer, SyncProjectReadmeService},
+        },
+        redirects::adapter::outgoing::RedirectRepositoryPostgres,
+        search::{
+            adapter::outgoing::ContentSearchQueryPostgres,
+            application::services::SearchContentService,
+        },
+        site::adapter::outgoing::SiteSettingsRepositoryPostgres,
+        skills::adapter::outgoing::SkillRepositoryPostgres,
+        topic::adapter::outgoing::{TopicQueryPostgres, TopicRepositoryPostgres},
+        translations::adapter::outgoing::TranslationRepositoryPostgres,
+        trash::{adapter::outgoing::TrashRepositoryPostgres, application::services::TrashPurger},
+        webhooks::{
+            adapter::outgoing::{
+                HttpWebhookSender, WebhookOutboxPostgres, WebhookRepositoryPostgres,
+            },
+            application::services::WebhookDispatcher,
+        },
+    };
+
+    // Reloadable, so LOG_FILTER can replace RUST_LOG once the config is read.
+    // LOG_FORMAT, like RUST_LOG, comes from the process environment only.
+    let log_format = LogFormat::from_env();
+    let log_filter = LogFilter::init(log_format.clone().unwrap_or_default());
+
+    info!("Starting application...");
+    if let Err(e) = log_format {
+        tracing::warn!("{e}; logging pretty lines");
+    }
+
+    // 🚨 SAFETY GUARD: Prevent test-helpers in production
+    #[cfg(feature = "test-helpers")]
+    {
+        let env = std::env::var("RUST_ENV").unwrap_or_else(|_| "development".to_string());
+        if env == "production" {
+            panic!("🚨 FATAL: test-helpers feature enabled in production environment!");
+        }
+        tracing::warn!(
+            "⚠️  Test helper routes are ENABLED for environment: {}",
+            env
+        );
+    }
+    // Environtment variable loading
+    let env = std::env::var("RUST_ENV").unwrap_or_else(|_| "development".to_string());
+
+    // Try .env.{environment} first, then fall back to .env
+    let env_file = format!(".env.{}", env);
+    if dotenvy::from_filename(&env_file).is_err() {
+        dotenvy::dotenv().ok();
+    }
+
+    // Typed configuration: every missing/invalid key is reported at once
+    let config = AppConfig::load().unwrap_or_else(|e| {
+        error!("{e}");
+        std::process::exit(1);
+    });
+    shared::api::problem::set_error_format(config.error_format);
+    shared::api::json_case::set_json_case(config.json_case);
+    shared::telemetry::event_log().set_policies(config.event_log_policies.clone());
+    shared::image_proxy::set_allowed_origins(AllowedOrigins::new(
+        config.image_proxy_origins.clone(),
+    ));
+    shared::client_ip::set_trusted_proxies(TrustedProxies::new(config.trusted_proxies.clone()));
+    if let Some(directives) = &config.log_filter {
+        if let Err(e) = log_filter.set(directives) {
+            tracing::warn!(error = %e, "LOG_FILTER not applied, keeping RUST_LOG");
+        }
+    }
+
+    if config.is_standalone() {
+        return standalone::start(config, log_filter).await;
+    }
+
+    // Settings that parse but can't work, and unreachable dependencies below,
+    // are collected and reported together
+    let mut preflight = Preflight::check_config(&config);
+
+    // Database connection
+    let mut opt = ConnectOptions::new(config.database_url.clone());
+    // IMPORTANT for PgBouncer / pooled connections
+    opt.map_sqlx_postgres_opts(|pg: PgConnectOptions| pg.statement_cache_capacity(0));
+    // SQLite has one writer; WAL keeps readers from waiting on it
+    #[cfg(feature = "sqlite")]
+    opt.map_sqlx_sqlite_opts(|sqlite: SqliteConnectOptions| {
+        sqlite.journal_mode(SqliteJournalMode::Wal)
+    });
+    opt.max_connections(5)
+        .min_connections(0)
+        .connect_timeout(Duration::from_secs(30))
+        .acquire_timeout(Duration::from_secs(10))
+        .idle_timeout(Duration::from_secs(300))
+        .max_lifetime(Duration::from_secs(1800))
+        // Every statement with its row counts, at debug under `sqlx::query`
+        .sqlx_logging(true)
+        .sqlx_logging_level(log::LevelFilter::Debug)
+        .sqlx_slow_statements_logging_settings(log::LevelFilter::Debug, config.slow_query());
+
+    let conn = match Database::connect(opt).await {
+        Ok(conn) => Some(conn),
+        Err(e) => {
+            preflight.block(
+                "DATABASE_URL",
+                format!(
+                    "cannot connect ({e}); check host, credentials and that the database exists"
+                ),
+            );
+            None
+        }
+    };
+
+    // Redis connection; the pool connects on first use
+    let redis_pool = match Config::from_url(&config.redis_url).create_pool(Some(Runtime::Tokio1)) {
+        Ok(pool) => Some(pool),
+        Err(e) => {
+            preflight.block(
+                "REDIS_URL",
+                format!("invalid ({e}); expected `redis://[user:password@]host[:port][/db]`"),
+            );
+            None
+        }
+    };
+
+    // Everything found so far in one report; exits when the server can't start
+    preflight.finish(config.is_production());
+    let (Some(mut conn), Some(redis_pool)) = (conn, redis_pool) else {
+        unreachable!("startup checks stop the server without a database or Redis pool");
+    };
+
+    // Schema must match the code before anything serves traffic
+    let schema_report =
+        web::Data::new(ensure_migrations(&conn, config.auto_migrate, config.is_production()).await);
+
+    if config.slow_query_ms > 0 {
+        let slow_log = SlowStatementLog::new(config.slow_query());
+        conn.set_metric_callback(move |info| slow_log.record(info));
+    }
+
+    let db_arc = Arc::new(conn);
+    let redis_arc = Arc::new(redis_pool);
+
+    // EMAIL PROVIDER SETUPS; readiness checks SMTP through the same transport, on demand
+    let (email_sender, smtp_probe): (Arc<dyn EmailSender>, _) = match &config.email {
+        EmailConfig::Smtp(smtp) => {
+            let smtp_sender = match smtp {
+                // Local Mailpit
+                SmtpConfig::Local { host, port } => {
+                    SmtpEmailSender::new_local(host, *port, &config.email_from)
+                }
+                // Production SMTP
+                SmtpConfig::Relay {
+                    server,
+                    username,
+                    password,
+                } => SmtpEmailSender::new(server, username, password, &config.email_from),
+            };
+            let probe = crate::health::SmtpProbe::new(smtp_sender.mailer());
+            (Arc::new(smtp_sender), probe)
+        }
+        EmailConfig::SendGrid { api_key } => (
+            Arc::new(
+                SendGridEmailSender::new(api_key, &config.email_from)
+                    .expect("Failed to build SendGrid HTTP client"),
+            ),
+            crate::health::SmtpProbe::disabled(),
+        ),
+        EmailConfig::Ses(ses) => (
+            Arc::new(
+                SesEmailSender::new(ses.clone(), &config.email_from)
+                    .expect("Failed to build SES HTTP client"),
+            ),
+            crate::health::SmtpProbe::disabled(),
+        ),
+    };
+    let smtp_probe = web::Data::new(smtp_probe);
+    let gcs_probe = web::Data::new(crate::health::GcsProbe::new(Arc::new(GcsBucketCheck::new(
+        config.multimedia_upload_bucket.clone(),
+    ))));
+
+    // Read-through cache for hot public reads; CACHE_TTL_SECS=0 turns it off
+    let cache: Arc<dyn CachePort> = if config.cache_ttl_secs == 0 {
+        Arc::new(NoopCache)
+    } else {
+        Arc::new(RedisCache::new(Arc::clone(&redis_arc), config.cache_ttl()))
+    };
+
+    // CV contact details and contact messages; no keys leaves them in plaintext
+    let field_cipher = FieldCipher::new(config.field_encryption_keys.clone());
+
+    // CV
+    let cv_use_cases = CvUseCases::build(
+        fault_injected(CVRepoPostgres::new(Arc::clone(&db_arc)).with_cipher(field_cipher.clone())),
+        fault_injected(CVQueryPostgres::new(Arc::clone(&db_arc)).with_cipher(field_cipher.clone())),
+        CVArchiverPostgres::new(Arc::clone(&db_arc)).with_cipher(field_cipher.clone()),
+        Arc::new(PlainTextCvRenderer),
+        Arc::clone(&cache),
+    );
+
+    // Auth related services and adapters
+    let jwt_service = JwtTokenService::new(config.jwt.clone());
+    let unsubscribe_tokens = UnsubscribeTokens::new(&config.unsubscribe_secret);
+
+    let user_email_service = UserEmailService::new(
+        jwt_service.clone(),
+        fault_injected(email_sender),
+        config.verification_handler_url.clone(),
+        load_email_templates(),
+        unsubscribe_tokens.clone(),
+    );
+
+    let user_repo = UserRepositoryPostgres::new(Arc::clone(&db_arc));
+    let user_query = UserQueryPostgres::new(Arc::clone(&db_arc));
+    let argon2_password_hasher = if config.is_production() {
+        Argon2Hasher::budget_vps()
+    } else {
+        Argon2Hasher::fast_env()
+    };
+
+    // Verification emails queued by registration are sent from here
+    let email_outbox_dispatcher = OutboxDispatcher::new(
+        EmailOutboxPostgres::new(Arc::clone(&db_arc)).with_cipher(field_cipher.clone()),
+        user_email_service,
+        DispatchPolicy::default(),
+    );
+
+    let token_version_guard =
+        TokenVersionGuard::new(Arc::new(user_repo.clone()), Arc::clone(&cache));
+    let auth_use_cases = AuthUseCases::build(
+        user_query.clone(),
+        user_repo,
+        RedisTokenRepository::new(Arc::clone(&redis_arc)),
+        Arc::new(ConsumedTokenRepositoryPostgres::new(Arc::clone(&db_arc))),
+        Arc::new(argon2_password_hasher),
+        Arc::new(jwt_service.clone()),
+        token_version_guard.clone(),
+        Arc::clone(&cache),
+        UnitOfWorkPostgres::new(Arc::clone(&db_arc)),
+    );
+    let auth_cookies = auth_cookies(&config);
+    let passkey_use_cases = PasskeyUseCases::build(
+        Arc::new(user_query.clone()),
+        Arc::new(PasskeyRepositoryPostgres::new(Arc::clone(&db_arc))),
+        Arc::new(RedisPasskeyChallenges::new(Arc::clone(&redis_arc))),
+        passkey_ceremony(&config),
+        Arc::new(jwt_service.clone()),
+    );
+    let identity_resolver = UserIdentityResolver::new(Arc::new(user_query));
+
+    // Topics
+    let topic_use_cases = TopicUseCases::build(
+        fault_injected(TopicRepositoryPostgres::new(Arc::clone(&db_arc))),
+        fault_injected(TopicQueryPostgres::new(Arc::clone(&db_arc))),
+    );
+
+    // Project use cases, repos and query
+    let project_repo = ProjectRepositoryPostgres::new(Arc::clone(&db_arc));
+
+    let project_query = ProjectQueryPostgres::new(Arc::clone(&db_arc));
+    let readme_sync_repo = ReadmeSyncRepositoryPostgres::new(Arc::clone(&db_arc));
+    let sync_readme_uc: Arc<dyn SyncProjectReadmeUseCase + Send + Sync> =
+        Arc::new(Metered(SyncProjectReadmeService::new(
+            project_query.clone(),
+            project_repo.clone(),
+            readme_sync_repo.clone(),
+            Arc::new(
+                GitHubRepoMetadataProvider::new(config.github_token.clone())
+                    .expect("Failed to build GitHub HTTP client"),
+            ),
+            Arc::clone(&cache),
+        )));
+
+    let project_use_cases = ProjectUseCases::build(
+        fault_injected(project_repo),
+        fault_injected(project_query),
+        ProjectTopicRepositoryPostgres::new(Arc::clone(&db_arc)),
+        ProjectUpdateRepositoryPostgres::new(Arc::clone(&db_arc)),
+        ProjectArchiverPostgres::new(Arc::clone(&db_arc)),
+        Arc::clone(&sync_readme_uc),
+        Arc::clone(&cache),
+    );
+
+    // Mulitmedia Use Cases
+    let upload_url_rate_limiter =
+        RateLimiter::new(config.upload_url_rate_limit, Duration::from_secs(3600));
+    // Accounts with their own buckets; their keys are encrypted like CV fields
+    let storage_overrides =
+        StorageOverrideStorePostgres::new(Arc::clone(&db_arc)).with_cipher(field_cipher.clone());
+    let default_buckets = vec![
+        config.multimedia_upload_bucket.clone(),
+        config.multimedia_ready_bucket.clone(),
+    ];
+    let media_storage = GcsStorageQuery::new()
+        .with_overrides(Arc::new(storage_overrides.clone()), default_buckets.clone());
+    let media_use_cases = MultimediaUseCases::build(
+        fault_injected(media_storage.clone()),
+        MediaRepositoryPostgres::new(Arc::clone(&db_arc)),
+        MediaQueryPostgres::new(Arc::clone(&db_arc)),
+        MediaUploadBatchPostgres::new(Arc::clone(&db_arc)),
+        UnitOfWorkPostgres::new(Arc::clone(&db_arc)),
+        media_processor(&config),
+        upload_url_rate_limiter.clone(),
+        Arc::new(Metered(ProxyImageService::new(
+            GcsStorageQuery::new(),
+            HttpMediaTransfer::allowing_only(AllowedOrigins::new(
+                config.image_proxy_origins.clone(),
+            ))
+            .expect("Failed to build image proxy HTTP client"),
+            UploadPolicy::new(config.multimedia_upload_bucket.clone()),
+            config.multimedia_ready_bucket.clone(),
+            AllowedOrigins::new(config.image_proxy_origins.clone()),
+        ))),
+        Arc::new(Metered(IngestMediaManifestService::new(
+            MediaQueryPostgres::new(Arc::clone(&db_arc)),
+            MediaRepositoryPostgres::new(Arc::clone(&db_arc)),
+            MediaRetryQueuePostgres::new(Arc::clone(&db_arc)),
+            config.multimedia_ready_bucket.clone(),
+        ))),
+        storage_overrides,
+        default_buckets,
+    );
+    let image_upload_policy = UploadPolicy::new(config.multimedia_upload_bucket.clone());
+
+    // Admin dashboard
+    let admin_stats_uc = GetAdminStatsService::new(StatsQueryPostgres::new(Arc::clone(&db_arc)));
+    let integrity_check_uc = CheckIntegrityService::new(
+        IntegrityQueryPostgres::new(Arc::clone(&db_arc)),
+        GcsObjectInventory::new(),
+    );
+    let quick_search_uc =
+        QuickSearchService::new(QuickSearchQueryPostgres::new(Arc::clone(&db_arc)));
+    let storage_report_uc =
+        GetStorageReportService::new(StorageReportQueryPostgres::new(Arc::clone(&db_arc)));
+
+    // Trash
+    let trash_repo = TrashRepositoryPostgres::new(Arc::clone(&db_arc));
+    let trash_use_cases = TrashUseCases::build(trash_repo.clone(), Arc::clone(&cache));
+
+    // Webhooks: deliveries are queued by database triggers and posted from here
+    let webhook_use_cases =
+        WebhookUseCases::build(WebhookRepositoryPostgres::new(Arc::clone(&db_arc)));
+    let webhook_dispatcher = WebhookDispatcher::new(
+        WebhookOutboxPostgres::new(Arc::clone(&db_arc)),
+        HttpWebhookSender::new(Duration::from_secs(10))
+            .expect("Failed to build webhook HTTP client"),
+        DispatchPolicy::default(),
+    );
+
+    // Contact form: owners (the admins) are emailed through the outbox
+    let contact_use_cases = ContactUseCases::build(
+        ContactMessageRepositoryPostgres::new(Arc::clone(&db_arc))
+            .with_cipher(field_cipher.clone()),
+        config.admin_user_ids.clone(),
+    );
+
+    let site_use_cases = SiteUseCases::build(
+        SiteSettingsRepositoryPostgres::new(Arc::clone(&db_arc)),
+        Arc::clone(&cache),
+    );
+
+    let page_view_repo = PageViewRepositoryPostgres::new(Arc::clone(&db_arc));
+    let page_view_buffer = PageViewBuffer::default();
+    let analytics_use_cases = AnalyticsUseCases::build(
+        VisitorHasher::new(&config.analytics_secret),
+        page_view_buffer.clone(),
+        page_view_repo.clone(),
+    );
+    let page_view_flusher =
+        PageViewFlusher::new(page_view_buffer, page_view_repo, Duration::from_secs(10));
+
+    let page_repo = fault_injected(PageRepositoryPostgres::new(Arc::clone(&db_arc)));
+    let autosave_buffer = AutosaveBuffer::default();
+    let page_use_cases = PageUseCases::build(
+        page_repo.clone(),
+        PreviewTokens::new(&config.preview_secret),
+        Arc::clone(&cache),
+        autosave_buffer.clone(),
+    );
+    let autosave_flusher = AutosaveFlusher::new(autosave_buffer, page_repo, Duration::from_secs(5));
+
+    let import_content_uc = ImportContentService::new(
+        SlugLookupPostgres::new(Arc::clone(&db_arc)),
+        ImportTargets {
+            create_page: Arc::clone(&page_use_cases.create),
+            create_project: Arc::clone(&project_use_cases.create),
+            add_project_topic: Arc::clone(&project_use_cases.add_topic),
+            get_topics: Arc::clone(&topic_use_cases.get_list),
+            create_topic: Arc::clone(&topic_use_cases.create),
+        },
+        UnitOfWorkPostgres::new(Arc::clone(&db_arc)),
+    );
+
+    let run_batch_uc = RunBatchService::new(BatchTargets {
+        patch_project: Arc::clone(&project_use_cases.patch),
+        get_project: Arc::clone(&project_use_cases.get_single),
+        add_project_topic: Arc::clone(&project_use_cases.add_topic),
+        remove_project_topic: Arc::clone(&project_use_cases.remove_topic),
+        get_page: Arc::clone(&page_use_cases.get),
+        update_page: Arc::clone(&page_use_cases.update),
+    });
+
+    let redirect_use_cases =
+        RedirectUseCases::build(RedirectRepositoryPostgres::new(Arc::clone(&db_arc)));
+
+    let blocklist_use_cases =
+        BlocklistUseCases::build(BlockRuleRepositoryPostgres::new(Arc::clone(&db_arc)));
+
+    let translation_use_cases =
+        TranslationUseCases::build(TranslationRepositoryPostgres::new(Arc::clone(&db_arc)));
+
+    let skill_use_cases = SkillUseCases::build(SkillRepositoryPostgres::new(Arc::clone(&db_arc)));
+
+    // SIGHUP and the admin endpoint apply new limits and log filter in place
+    let contact_rate_limiter =
+        RateLimiter::new(config.contact_rate_limit, Duration::from_secs(3600));
+    let config_reloader = ConfigReloader::new(
+        Arc::new(AppConfig::load),
+        contact_rate_limiter.clone(),
+        upload_url_rate_limiter.clone(),
+        Some(log_filter),
+    );
+
+    let state = AppState {
+        cv: cv_use_cases,
+        auth: auth_use_cases,
+        token_version_guard,
+        auth_cookies,
+        passkeys: passkey_use_cases,
+        topics: topic_use_cases,
+        project: project_use_cases,
+        multimedia: media_use_cases,
+        user_identity_resolver: identity_resolver,
+        multimedia_upload_policy: image_upload_policy,
+        upload_url_rate_limiter,
+        admin_stats_use_case: Arc::new(Metered(admin_stats_uc)),
+        integrity_check_use_case: Arc::new(Metered(integrity_check_uc)),
+        quick_search_use_case: Arc::new(Metered(quick_search_uc)),
+        storage_report_use_case: Arc::new(Metered(storage_report_uc)),
+        admin_user_ids: config.admin_user_ids.clone(),
+        trash: trash_use_cases,
+        webhooks: webhook_use_cases,
+        email: EmailUseCases::build(
+            EmailOutboxPostgres::new(Arc::clone(&db_arc)),
+            unsubscribe_tokens,
+        ),
+        email_events_token: config.email_events_token.clone(),
+        media_callback_secret: config.media_callback_secret.clone(),
+        introspection_token: config.introspection_token.clone(),
+        metrics_token: config.metrics_token.clone(),
+        config_reloader: config_reloader.clone(),
+        contact: contact_use_cases,
+        contact_rate_limiter,
+        bot_gate: BotGate::from_config(&config.bot_check)
+            .expect("Failed to build bot check HTTP client"),
+        site: site_use_cases,
+        pages: page_use_cases,
+        analytics: analytics_use_cases,
+        redirects: redirect_use_cases,
+        blocklist: blocklist_use_cases,
+        search_content_use_case: Arc::new(Metered(SearchContentService::new(
+            ContentSearchQueryPostgres::new(Arc::clone(&db_arc)),
+        ))),
+        export_content_use_case: Arc::new(ExportContentService::new(
+            ExportSourcePostgres::new(Arc::clone(&db_arc)).with_cipher(field_cipher),
+        )),
+        import_content_use_case: Arc::new(Metered(import_content_uc)),
+        run_batch_use_case: Arc::new(Metered(run_batch_uc)),
+        list_activities_use_case: Arc::new(Metered(ListActivitiesService::new(
+            ActivityLogPostgres::new(Arc::clone(&db_arc)),
+        ))),
+        translations: translation_use_cases,
+        skills: skill_use_cases,
+    };
+
+    // Background jobs (queues, buffered counters) are stopped after the server drains
+    let mut background_jobs = BackgroundJobs::new();
+    background_jobs.spawn("email_outbox", move |signal| {
+        email_outbox_dispatcher.run(signal)
+    });
+    background_jobs.spawn("webhook_dispatch", move |signal| {
+        webhook_dispatcher.run(signal)
+    });
+    background_jobs.spawn("page_views", move |signal| page_view_flusher.run(signal));
+    background_jobs.spawn("page_autosaves", move |signal| autosave_flusher.run(signal));
+    #[cfg(unix)]
+    background_jobs.spawn("config_reload", move |signal| config_reloader.run(signal));
+    if config.trash_retention_days > 0 {
+        let trash_purger = TrashPurger::new(
+            trash_repo,
+            chrono::Duration::days(config.trash_retention_days.into()),
+            Duration::from_secs(3600),
+        );
+        background_jobs.spawn("trash_purge", move |signal| trash_purger.run(signal));
+    }
+    if config.readme_sync_interval_secs > 0 {
+        let readme_syncer = ReadmeSyncer::new(
+            readme_sync_repo,
+            sync_readme_uc,
+            Duration::from_secs(config.readme_sync_interval_secs),
+        );
+        background_jobs.spawn("readme_sync", move |signal| readme_syncer.run(signal));
+    }
+    if config.media_reconcile_interval_secs > 0 {
+        let media_reconciler = MediaReconciler::new(
+            Arc::new(Metered(ReconcileMediaService::new(
+                MediaQueryPostgres::new(Arc::clone(&db_arc)),
+                media_storage,
+                MediaRepositoryPostgres::new(Arc::clone(&db_arc)),
+            ))),
+            chrono::Duration::minutes(config.media_processing_timeout_mins.into()),
+            Duration::from_secs(config.media_reconcile_interval_secs),
+        );
+        background_jobs.spawn("media_reconcile", move |signal| {
+            media_reconciler.run(signal)
+        });
+    }
+    let media_retrier = MediaRetrier::new(Arc::new(Metered(RetryFailedMediaService::new(
+        MediaRetryQueuePostgres::new(Arc::clone(&db_arc)),
+        MediaRepositoryPostgres::new(Arc::clone(&db_arc)),
+        media_processor(&config),
+        config.media_retry_max_attempts,
+    ))));
+    background_jobs.spawn("media_retry", move |signal| media_retrier.run(signal));
+    if config.storage_gc_interval_secs > 0 {
+        let orphan_collector = OrphanObjectCollector::new(
+            Arc::new(Metered(CollectOrphanObjectsService::new(
+                MediaQueryPostgres::new(Arc::clone(&db_arc)),
+                GcsObjectInventory::new(),
+                vec![
+                    config.multimedia_upload_bucket.clone(),
+                    config.multimedia_ready_bucket.clone(),
+                ],
+            ))),
+            chrono::Duration::hours(config.storage_gc_grace_hours.into()),
+            config.storage_gc_dry_run,
+            Duration::from_secs(config.storage_gc_interval_secs),
+        );
+        background_jobs.spawn("storage_gc", move |signal| orphan_collector.run(signal));
+    }
+
+    let token_provider_arc: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service);
+    // Clone db_arc for use in HttpServer closure
+    let db_for_server = Arc::clone(&db_arc);
+
+    // Handlers that reach infrastructure directly: health checks and test helpers
+    let infra = move |cfg: &mut web::ServiceConfig| {
+        cfg.app_data(web::Data::new(Arc::clone(&db_for_server)))
+            .app_data(web::Data::new(Arc::clone(&redis_arc)))
+            .app_data(smtp_probe.clone())
+            .app_data(gcs_probe.clone())
+            .app_data(schema_report.clone())
+            .service(crate::health::readiness)
+            .service(crate::health::health_details);
+
+        #[cfg(feature = "test-helpers")]
+        test_helpers::configure_routes(cfg);
+    };
+
+    serve(&config, state, token_provider_arc, background_jobs, infra).await?;
+
+    // The server's app state held the other handles; they are gone now
+    match Arc::try_unwrap(db_arc) {
+        Ok(conn) => {
+            if let Err(e) = conn.close().await {
+                tracing::warn!(error = %e, "Failed to close database pool");
+            }
+        }
+        Err(_) => tracing::warn!("Database pool still shared at shutdown, dropping it"),
+    }
+
+    info!("Shutdown complete");
+    Ok(())
+}
+
+/// Serves the API until shutdown, then stops the background jobs. `infra`
+/// registers whatever depends on the run mode's infrastructure.
+#[cfg(not(tarpaulin_include))]
+async fn serve(
+    config: &AppConfig,
+    state: AppState,
+    token_provider: Arc<dyn TokenProvider + Send + Sync>,
+    background_jobs: BackgroundJobs,
+    infra: impl Fn(&mut web::ServiceConfig) + Clone + Send + 'static,
+) -> std::io::Result<()> {
+    // Application host and port
+    let server_url = config.server_url();
+    info!(%server_url, "Server listening");
+
+    // Built once; every worker serves the same document
+    let openapi = config
+        .openapi_enabled
+        .then(crate::api::openapi::ApiDoc::openapi);
+
+    HttpServer::new(move || {
+        use utoipa_swagger_ui::SwaggerUi;
+
+        let openapi = openapi.clone();
+
+        App::new()
+            .app_data(web::Data::new(state.clone()))
+            .app_data(web::Data::new(Arc::clone(&token_provider)))
+            .app_data(custom_json_config())
+            // Swagger UI + spec, only when OPENAPI_ENABLED
+            .configure(move |cfg| {
+                if let Some(openapi) = openapi {
+                    cfg.service(
+                        SwaggerUi::new("/swagger-ui/{_:.*}").url("/api/openapi.json", openapi),
+                    );
+                }
+            })
+            .configure(infra.clone())
+            // Last: its auth scopes catch every path not matched above
+            .configure(init_routes)
+            .wrap(actix_web::middleware::from_fn(
+                crate::auth::adapter::incoming::web::cookies::csrf_middleware,
+            ))
+            .wrap(actix_web::middleware::from_fn(
+                crate::blocklist::adapter::incoming::web::middleware::blocklist_middleware,
+            ))
+            .wrap(actix_web::middleware::from_fn(
+                crate::shared::api::json_case::json_case_middleware,
+            ))
+            .wrap(actix_web::middleware::from_fn(
+                crate::shared::api::body_limit::body_limit_middleware,
+            ))
+            .wrap(actix_web::middleware::from_fn(
+                crate::shared::api::cache::cache_control_middleware,
+            ))
+            .wrap(crate::shared::api::cache::compression())
+            .wrap(actix_web::middleware::from_fn(
+                crate::shared::access_log::access_log_middleware,
+            ))
+            .wrap(actix_web::middleware::from_fn(
+                crate::shared::request_id::request_id_middleware,
+            ))
+    })
+    .bind(server_url)?
+    // SIGTERM: stop accepting, give in-flight requests this long to finish
+    .shutdown_timeout(config.shutdown_timeout_secs)
+    .run()
+    .await?;
+
+    info!("HTTP server stopped, flushing background jobs");
+    background_jobs.shutdown(config.shutdown_timeout()).await;
+    Ok(())
+}
+
+/// Compile the email templates, or stop: a broken template would only show up
+/// when the first email is sent.
+#[cfg(not(tarpaulin_include))]
+fn load_email_templates() -> EmailTemplates {
+    EmailTemplates::new().unwrap_or_else(|e| {
+        error!(error = %e, "Invalid email templates");
+        std::process::exit(1);
+    })
+}
+
+/// Cookie writer for `AUTH_TRANSPORT=cookie`, `None` in bearer mode
+fn auth_cookies(config: &AppConfig) -> Option<AuthCookies> {
+    (config.auth_transport == AuthTransport::Cookie).then(|| {
+        AuthCookies::new(
+            &config.jwt,
+            config.auth_cookie_domain.clone(),
+            config.auth_cookie_secure,
+        )
+    })
+}
+
+/// The WebAuthn relying party from `WEBAUTHN_RP_ID`/`WEBAUTHN_RP_ORIGIN`;
+/// passkeys are disabled without them
+fn passkey_ceremony(config: &AppConfig) -> Arc<dyn PasskeyCeremony> {
+    match (&config.webauthn_rp_id, &config.webauthn_rp_origin) {
+        (Some(rp_id), Some(rp_origin)) => Arc::new(
+            WebauthnCeremony::new(rp_id, rp_origin).expect("Invalid WebAuthn relying party"),
+        ),
+        _ => Arc::new(DisabledPasskeys),
+    }
+}
+
+/// With test helpers, the adapter wrapped so `/test/faults` can make it fail
+/// (see `shared::faults`); otherwise the adapter itself
+#[cfg(feature = "test-helpers")]
+fn fault_injected<P>(port: P) -> shared::faults::Faulty<P> {
+    shared::faults::Faulty(port)
+}
+
+#[cfg(not(feature = "test-helpers"))]
+fn fault_injected<P>(port: P) -> P {
+    port
+}
+
+/// The image processor at `IMAGE_PROCESSOR_URL`; media regeneration is
+/// disabled without it
+fn media_processor(config: &AppConfig) -> Arc<dyn MediaProcessorClient> {
+    match &config.image_processor_url {
+        Some(url) => Arc::new(
+            HttpMediaProcessorClient::new(url, config.image_processor_token.clone())
+                .expect("Failed to build image processor HTTP client"),
+        ),
+        None => Arc::new(UnconfiguredMediaProcessor),
+    }
+}
+
+/// Refuse to start with pending migrations, unless `AUTO_MIGRATE` allows applying them.
+#[cfg(not(tarpaulin_include))]
+async fn ensure_migrations(
+    conn: &sea_orm::DatabaseConnection,
+    auto_migrate: bool,
+    production: bool,
+) -> SchemaReport {
+    use migration::{Migrator, MigratorTrait};
+
+    let check = move || async move {
+        schema_check::check(conn).await.unwrap_or_else(|e| {
+            error!(error = %e, "Failed to read migration status");
+            std::process::exit(1);
+        })
+    };
+
+    let mut report = check().await;
+    if !report.pending.is_empty() {
+        if !auto_migrate {
+            error!(
+                migrations = ?report.pending,
+                "Pending migrations. Apply them or start with AUTO_MIGRATE=true"
+            );
+            std::process::exit(1);
+        }
+
+        info!(migrations = ?report.pending, "Applying pending migrations");
+        if let Err(e) = Migrator::up(conn, None).await {
+            error!(error = %e, "Migration failed");
+            std::process::exit(1);
+        }
+        report = check().await;
+    }
+
+    // Left: migrations this build doesn't know, or columns it reads that are
+    // gone. Either one turns requests into 500s, so production won't serve.
+    if report.has_drift() {
+        if production {
+            error!(
+                unknown_migrations = ?report.unknown,
+                missing_columns = ?report.missing_columns,
+                "Database schema doesn't match this build"
+            );
+            std::process::exit(1);
+        }
+        tracing::warn!(
+            unknown_migrations = ?report.unknown,
+            missing_columns = ?report.missing_columns,
+            "Database schema doesn't match this build (fatal in production)"
+        );
+    }
+    report
+}
+
+#[cfg(not(tarpaulin_include))]
+/// Registers every route. Anything that needs a signed-in user goes inside
+/// the `RequireAuth` scopes, so forgetting an extractor can't make it public.
+/// Those scopes have no prefix and catch every path nothing earlier matched:
+/// register after them and a route is unreachable without a token, and
+/// unknown paths get 401 rather than 404.
+fn init_routes(cfg: &mut web::ServiceConfig) {
+    use crate::auth::adapter::incoming::web::require_auth::RequireAuth;
+
+    cfg.service(crate::health::liveness)
+        .service(crate::api::error_codes::list_error_codes_handler)
+        .service(crate::api::version::version_handler);
+    // Every module registers its own routes, one function per access level
+    cfg.configure(crate::auth::adapter::incoming::web::configure_public)
+        .configure(crate::cv::adapter::incoming::web::configure_public)
+        .configure(crate::project::adapter::incoming::web::configure_public)
+        .configure(crate::pages::adapter::incoming::web::configure_public)
+        .configure(crate::site::adapter::incoming::web::configure_public)
+        .configure(crate::redirects::adapter::incoming::web::configure_public)
+        .configure(crate::multimedia::adapter::incoming::web::configure_public)
+        .configure(crate::contact::adapter::incoming::web::configure_public)
+        .configure(crate::analytics::adapter::incoming::web::configure_public)
+        .configure(crate::email::adapter::incoming::web::configure_public)
+        .configure(crate::admin::adapter::incoming::web::configure_public);
+
+    cfg.service(
+        web::scope("")
+            .wrap(RequireAuth { verified: false })
+            .configure(crate::auth::adapter::incoming::web::configure_account)
+            .service(
+                web::scope("")
+                    .wrap(RequireAuth { verified: true })
+                    .configure(verified_routes),
+            ),
+    );
+}
+
+/// Routes for users with a verified email; admin routes check the allowlist
+/// on top through their `AdminUser` extractor
+fn verified_routes(cfg: &mut web::ServiceConfig) {
+    cfg.configure(crate::cv::adapter::incoming::web::configure)
+        .configure(crate::topic::adapter::incoming::web::configure)
+        .configure(crate::project::adapter::incoming::web::configure)
+        .configure(crate::multimedia::adapter::incoming::web::configure)
+        .configure(crate::admin::adapter::incoming::web::configure)
+        .configure(crate::export::adapter::incoming::web::configure)
+        .configure(crate::import::adapter::incoming::web::configure)
+        .configure(crate::batch::adapter::incoming::web::configure)
+        .configure(crate::activity::adapter::incoming::web::configure)
+        .configure(crate::email::adapter::incoming::web::configure)
+        .configure(crate::trash::adapter::incoming::web::configure)
+        .configure(crate::webhooks::adapter::incoming::web::configure)
+        .configure(crate::contact::adapter::incoming::web::configure)
+        .configure(crate::site::adapter::incoming::web::configure)
+        .configure(crate::pages::adapter::incoming::web::configure)
+        .configure(crate::analytics::adapter::incoming::web::configure)
+        .configure(crate::redirects::adapter::incoming::web::configure)
+        .configure(crate::blocklist::adapter::incoming::web::configure)
+        .configure(crate::search::adapter::incoming::web::configure)
+        .configure(crate::translations::adapter::incoming::web::configure)
+        .configure(crate::skills::adapter::incoming::web::configure);
+}
+
+/// Entry point of the HTTP server binary.
+#[cfg(not(tarpaulin_include))]
+pub fn run() {
+    rustls::crypto::ring::default_provider()
+        .install_default()
+        .expect("Failed to install rustls crypto provider");
+    if let Err(e) = start() {
+        error!(error = %e, "Error starting app");
+    }
+}
@@ -1,458 +1,5 @@
-pub mod modules;
-pub use modules::auth;
-pub use modules::cv;
-pub use modules::email;
-pub use modules::multimedia;
-pub use modules::project;
-pub use modules::topic;
-pub mod api;
-pub mod health;
-pub mod shared;
-
-// Test helpers module - only compiled with feature flag
-#[cfg(feature = "test-helpers")]
-mod test_helpers;
-
-// ... (all your existing imports remain the same)
-use crate::auth::adapter::outgoing::jwt::{JwtConfig, JwtTokenService};
-use crate::auth::adapter::outgoing::token_repository_redis::RedisTokenRepository;
-use crate::auth::adapter::outgoing::user_query_postgres::UserQueryPostgres;
-use crate::auth::adapter::outgoing::user_repository_postgres::UserRepositoryPostgres;
-use crate::auth::application::orchestrator::user_registration::UserRegistrationOrchestrator;
-use crate::auth::application::use_cases::{
-    create_user::{CreateUserUseCase, ICreateUserUseCase},
-    login_user::{ILoginUserUseCase, LoginUserUseCase},
-    logout_user::{ILogoutUseCase, LogoutUseCase},
-    soft_delete_user::{ISoftDeleteUserUseCase, SoftDeleteUserUseCase},
-    verify_user_email::{IVerifyUserEmailUseCase, VerifyUserEmailUseCase},
-};
-
-use crate::cv::adapter::outgoing::cv_repo_postgres::CVRepoPostgres;
-use crate::cv::application::use_cases::create_cv::{CreateCVUseCase, ICreateCVUseCase};
-use crate::cv::application::use_cases::fetch_cv_by_id::{FetchCVByIdUseCase, IFetchCVByIdUseCase};
-use crate::cv::application::use_cases::fetch_user_cvs::{FetchCVService, IFetchCVUseCase};
-use crate::cv::application::use_cases::patch_cv::{IPatchCVUseCase, PatchCVUseCase};
-use crate::cv::application::use_cases::update_cv::{IUpdateCVUseCase, UpdateCVUseCase};
-
-use crate::email::adapter::outgoing::smtp_sender::SmtpEmailSender;
-use crate::email::application::services::UserEmailService;
-use crate::modules::auth::application::helpers::UserIdentityResolver;
-use crate::modules::auth::application::services::UpdateUserProfileService;
-use crate::modules::auth::application::use_cases::fetch_profile::FetchUserProfileUseCase;
-use crate::modules::auth::application::use_cases::refresh_token::IRefreshTokenUseCase;
-use crate::modules::auth::application::use_cases::update_profile::UpdateUserProfileUseCase;
-use crate::modules::cv::application::use_cases::get_public_single_cv::GetPublicSingleCvUseCase;
-use crate::modules::cv::application::use_cases::hard_delete_cv::HardDeleteCvUseCase;
-use crate::modules::email::application::ports::outgoing::user_email_notifier::UserEmailNotifier;
-
-use crate::modules::multimedia::application::domain::policies::upload_policy::UploadPolicy;
-use crate::modules::multimedia::application::media_use_cases::MultimediaUseCases;
-use crate::modules::project::application::project_use_cases::ProjectUseCases;
-use crate::modules::topic::application::ports::incoming::use_cases::CreateTopicUseCase;
-use crate::modules::topic::application::ports::incoming::use_cases::GetTopicsUseCase;
-use crate::modules::topic::application::ports::incoming::use_cases::SoftDeleteTopicUseCase;
-use crate::shared::api::custom_json_config;
-
-use actix_web::{web, App, HttpServer};
-use deadpool_redis::{Config, Runtime};
-
-use sea_orm::{ConnectOptions, Database};
-use sqlx::postgres::PgConnectOptions;
-use std::env;
-use std::sync::Arc;
-use std::time::Duration;
-
-use tracing::info;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
-use utoipa::OpenApi;
-
-#[cfg(test)]
-mod tests;
-
-#[derive(Clone)]
-pub struct AppState {
-    pub fetch_cv_use_case: Arc<dyn IFetchCVUseCase + Send + Sync>,
-    pub fetch_cv_by_id_use_case: Arc<dyn IFetchCVByIdUseCase + Send + Sync>,
-    pub get_public_single_cv_use_case: Arc<dyn GetPublicSingleCvUseCase + Send + Sync>,
-    pub create_cv_use_case: Arc<dyn ICreateCVUseCase + Send + Sync>,
-    pub update_cv_use_case: Arc<dyn IUpdateCVUseCase + Send + Sync>,
-    pub patch_cv_use_case: Arc<dyn IPatchCVUseCase + Send + Sync>,
-    pub register_user_orchestrator: Arc<UserRegistrationOrchestrator>,
-    pub verify_user_email_use_case: Arc<dyn IVerifyUserEmailUseCase + Send + Sync>,
-    pub login_user_use_case: Arc<dyn ILoginUserUseCase + Send + Sync>,
-    pub refresh_token_use_case: Arc<dyn IRefreshTokenUseCase + Send + Sync>,
-    pub logout_user_use_case: Arc<dyn ILogoutUseCase + Send + Sync>,
-    pub soft_delete_user_use_case: Arc<dyn ISoftDeleteUserUseCase + Send + Sync>,
-    pub fetch_user_profile_use_case: Arc<dyn FetchUserProfileUseCase + Send + Sync>,
-    pub update_user_profile_use_case: Arc<dyn UpdateUserProfileUseCase + Send + Sync>,
-    pub hard_delete_cv_use_case: Arc<dyn HardDeleteCvUseCase + Send + Sync>,
-    pub create_topic_use_case: Arc<dyn CreateTopicUseCase + Send + Sync>,
-    pub get_topics_use_case: Arc<dyn GetTopicsUseCase + Send + Sync>,
-    pub soft_delete_topic_use_case: Arc<dyn SoftDeleteTopicUseCase + Send + Sync>,
-    pub project: ProjectUseCases,
-    pub multimedia: MultimediaUseCases,
-    pub user_identity_resolver: UserIdentityResolver,
-    pub multimedia_upload_policy: UploadPolicy,
-}
-
-#[actix_web::main]
-#[cfg(not(tarpaulin_include))]
-async fn start() -> std::io::Result<()> {
-    use crate::{
-        auth::{
-            adapter::outgoing::security::argon2_hasher::Argon2Hasher,
-            application::{
-                orchestrator::user_registration::UserRegistrationOrchestrator,
-                ports::outgoing::token_provider::TokenProvider, services::FetchUserProfileService,
-                use_cases::refresh_token::RefreshTokenUseCase,
-            },
-        },
-        cv::{
-            adapter::outgoing::{CVArchiverPostgres, CVQueryPostgres},
-            application::services::{GetPublicSingleCvService, HardDeleteCvService},
-        },
-        multimedia::{
-            adapter::outgoing::{
-                cloud_storage::GcsStorageQuery,
-                db::{MediaQueryPostgres, MediaRepositoryPostgres},
-            },
-            application::ports::incoming::services::{
-                CreateUploadMediaUrlService, GetVariantReadUrlService, ListMediaService,
-            },
-        },
-        project::{
-            adapter::outgoing::{
-                ProjectArchiverPostgres, ProjectQueryPostgres, ProjectRepositoryPostgres,
-                ProjectTopicRepositoryPostgres,
-            },
-            application::service::{
-                AddProjectTopicService, ClearProjectTopicsService, CreateProjectService,
-                GetProjectTopicsService, GetProjectsService, GetPublicSingleProjectService,
-                GetSingleProjectService, HardDeleteProjectService, PatchProjectService,
-                RemoveProjectTopicService,
-            },
-        },
-        topic::{
-            adapter::outgoing::{TopicQueryPostgres, TopicRepositoryPostgres},
-            application::services::{CreateTopicService, GetTopicsService, SoftDeleteTopicService},
-        },
-    };
-
-    tracing_subscriber::registry()
-        .with(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "info,actix_web=info".into()),
-        )
-        .with(tracing_subscriber::fmt::layer())
-        .init();
-
-    info!("Starting application...");
-
-    // 🚨 SAFETY GUARD: Prevent test-helpers in production
-    #[cfg(feature = "test-helpers")]
-    {
-        let env = env::var("RUST_ENV").unwrap_or_else(|_| "development".to_string());
-        if env == "production" {
-            panic!("🚨 FATAL: test-helpers feature enabled in production environment!");
-        }
-        tracing::warn!(
-            "⚠️  Test helper routes are ENABLED for environment: {}",
-            env
-        );
-    }
-    // Environtment variable loading
-    let env = std::env::var("RUST_ENV").unwrap_or_else(|_| "development".to_string());
-
-    // Try .env.{environment} first, then fall back to .env
-    let env_file = format!(".env.{}", env);
-    if dotenvy::from_filename(&env_file).is_err() {
-        dotenvy::dotenv().ok();
-    }
-
-    // Postgres and Redis
-    let db_url = env::var("DATABASE_URL").expect("DATABASE_URL is not set in .env file");
-    let redis_url = env::var("REDIS_URL").expect("REDIS_URL is not set in .env file");
-
-    // Application host and port
-    let host = env::var("HOST").unwrap_or_else(|_| "0.0.0.0".to_string());
-    let port = env::var("PORT").unwrap_or_else(|_| "8080".to_string());
-    let server_url = format!("{host}:{port}");
-    println!("Server run on: {}", server_url);
-
-    // SMTP SETUPS
-    let from_email = std::env::var("EMAIL_FROM").expect("EMAIL_FROM not set");
-    let smtp_sender = if std::env::var("RUST_ENV").as_deref() == Ok("test") {
-        // Local Mailpit
-        let host = std::env::var("SMTP_HOST").unwrap_or_else(|_| "localhost".to_string());
-        let port: u16 = std::env::var("SMTP_PORT")
-            .unwrap_or_else(|_| "1025".to_string())
-            .parse()
-            .expect("Invalid SMTP_PORT");
-
-        SmtpEmailSender::new_local(&host, port, &from_email)
-    } else {
-        // Production SMTP
-        let smtp_server = std::env::var("SMTP_SERVER").expect("SMTP_SERVER not set");
-        let smtp_user = std::env::var("SMTP_USERNAME").expect("SMTP_USERNAME not set");
-        let smtp_pass = std::env::var("SMTP_PASSWORD").expect("SMTP_PASSWORD not set");
-
-        SmtpEmailSender::new(&smtp_server, &smtp_user, &smtp_pass, &from_email)
-    };
-
-    // Database connection
-    let mut opt = ConnectOptions::new(db_url);
-    // IMPORTANT for PgBouncer / pooled connections
-    opt.map_sqlx_postgres_opts(|pg: PgConnectOptions| pg.statement_cache_capacity(0));
-    opt.max_connections(5)
-        .min_connections(0)
-        .connect_timeout(Duration::from_secs(30))
-        .acquire_timeout(Duration::from_secs(10))
-        .idle_timeout(Duration::from_secs(300))
-        .max_lifetime(Duration::from_secs(1800))
-        .sqlx_logging(false);
-
-    let conn = Database::connect(opt).await.unwrap_or_else(|e| {
-        eprintln!("DB connect error: {e:?}");
-        std::process::exit(1);
-    });
-
-    let db_arc = Arc::new(conn);
-
-    // Redis connection
-    let redis_pool = Config::from_url(&redis_url)
-        .create_pool(Some(Runtime::Tokio1))
-        .expect("Failed to create Redis pool");
-
-    let redis_arc = Arc::new(redis_pool);
-
-    // Create CV repositories and use cases (unchanged)
-    let cv_repo = CVRepoPostgres::new(Arc::clone(&db_arc));
-    let cv_query = CVQueryPostgres::new(Arc::clone(&db_arc));
-
-    let cv_archiver = CVArchiverPostgres::new(Arc::clone(&db_arc));
-    let fetch_cv_use_case = FetchCVService::new(cv_query.clone());
-    let fetch_cv_by_id_use_case = FetchCVByIdUseCase::new(cv_repo.clone());
-    let get_public_single_cv_uc = GetPublicSingleCvService::new(cv_query.clone());
-
-    let create_cv_use_case = CreateCVUseCase::new(cv_repo.clone());
-    let update_cv_use_case = UpdateCVUseCase::new(cv_repo.clone());
-    let patch_cv_use_case = PatchCVUseCase::new(cv_repo.clone());
-    let hard_delete_cv_use_case = HardDeleteCvService::new(cv_archiver, cv_repo.clone());
-
-    // Auth related services and adapters
-    let jwt_service = JwtTokenService::new(JwtConfig::from_env());
-
-    let verification_handler_url = env::var("VERIFICATION_HANDLER_URL")
-        .unwrap_or_else(|_| "0.0.0.0:5177/email/verification".to_string());
-    let user_email_service = UserEmailService::new(
-        jwt_service.clone(),
-        smtp_sender,
-        String::from(&verification_handler_url),
-    );
-
-    let user_repo = UserRepositoryPostgres::new(Arc::clone(&db_arc));
-    let user_query = UserQueryPostgres::new(Arc::clone(&db_arc));
-    let redis_token_repo = RedisTokenRepository::new(Arc::clone(&redis_arc));
-    let argon2_password_hasher = if std::env::var("RUST_ENV").as_deref() == Ok("production") {
-        Argon2Hasher::budget_vps()
-    } else {
-        Argon2Hasher::fast_env()
-    };
-
-    // User Registration componenets
-    let create_user_use_case = CreateUserUseCase::new(
-        user_query.clone(),
-        user_repo.clone(),
-        Arc::new(argon2_password_hasher.clone()),
-    );
-    let create_user_uc_arc: Arc<dyn ICreateUserUseCase + Send + Sync> =
-        Arc::new(create_user_use_case);
-    let email_notifier_arc: Arc<dyn UserEmailNotifier + Send + Sync> = Arc::new(user_email_service);
-
-    let register_user_orchestrator =
-        UserRegistrationOrchestrator::new(create_user_uc_arc, email_notifier_arc);
-
-    let verify_user_email_use_case =
-        VerifyUserEmailUseCase::new(user_repo.clone(), Arc::new(jwt_service.clone()));
-    let login_user_use_case = LoginUserUseCase::new(
-        user_query.clone(),
-        Arc::new(argon2_password_hasher),
-        Arc::new(jwt_service.clone()),
-    );
-    let refresh_token_use_case = RefreshTokenUseCase::new(Arc::new(jwt_service.clone()));
-    let logout_user_use_case =
-        LogoutUseCase::new(redis_token_repo.clone(), Arc::new(jwt_service.clone()));
-    let soft_delete_user_use_case = SoftDeleteUserUseCase::new(user_repo.clone(), redis_token_repo);
-    let fetch_user_profile_service = FetchUserProfileService::new(user_query.clone());
-    let update_user_profile_service = UpdateUserProfileService::new(user_repo.clone());
-    let identity_resolver = UserIdentityResolver::new(Arc::new(user_query.clone()));
-
-    // Topics use cases, repo and query
-    let topic_repo = TopicRepositoryPostgres::new(Arc::clone(&db_arc));
-    let topic_query = TopicQueryPostgres::new(Arc::clone(&db_arc));
-    let create_topic_uc = CreateTopicService::new(topic_repo.clone());
-    let get_topics_uc = GetTopicsService::new(topic_query.clone());
-    let soft_delete_topic_uc = SoftDeleteTopicService::new(topic_query.clone(), topic_repo.clone());
-
-    // Project use cases, repos and query
-    let project_repo = ProjectRepositoryPostgres::new(Arc::clone(&db_arc));
-    let project_topic_repo = ProjectTopicRepositoryPostgres::new(Arc::clone(&db_arc));
-    let project_archiver = ProjectArchiverPostgres::new(Arc::clone(&db_arc));
-
-    let project_query = ProjectQueryPostgres::new(Arc::clone(&db_arc));
-    let create_project_uc = CreateProjectService::new(project_repo.clone());
-    let get_project_uc = GetProjectsService::new(project_query.clone());
-    let get_single_project_uc = GetSingleProjectService::new(project_query.clone());
-    let patch_project_uc = PatchProjectService::new(project_repo.clone());
-    let get_public_single_project_uc = GetPublicSingleProjectService::new(project_query.clone());
-    let add_topic_uc = AddProjectTopicService::new(project_topic_repo.clone());
-    let remove_topic_uc = RemoveProjectTopicService::new(project_topic_repo.clone());
-    let clear_topics_uc = ClearProjectTopicsService::new(project_topic_repo.clone());
-    let get_project_topics_uc = GetProjectTopicsService::new(project_query.clone());
-    let hard_delete_project_uc = HardDeleteProjectService::new(project_archiver.clone());
-
-    let project_use_cases = ProjectUseCases {
-        create: Arc::new(create_project_uc),
-        hard_delete: Arc::new(hard_delete_project_uc),
-        patch: Arc::new(patch_project_uc),
-        get_list: Arc::new(get_project_uc),
-        get_single: Arc::new(get_single_project_uc),
-        get_public_single: Arc::new(get_public_single_project_uc),
-
-        add_topic: Arc::new(add_topic_uc),
-        get_topics: Arc::new(get_project_topics_uc),
-        remove_topic: Arc::new(remove_topic_uc),
-        clear_topics: Arc::new(clear_topics_uc),
-    };
-
-    // Mulitmedia Use Cases
-    let storage_query = GcsStorageQuery::new();
-    let media_repo = MediaRepositoryPostgres::new(Arc::clone(&db_arc));
-    let create_upload_media_signed_url =
-        CreateUploadMediaUrlService::new(storage_query.clone(), media_repo);
-    let media_query = MediaQueryPostgres::new(Arc::clone(&db_arc));
-    let create_variant_get_url = GetVariantReadUrlService::new(storage_query, media_query.clone());
-    let list_media = ListMediaService::new(media_query);
-    let media_use_cases = MultimediaUseCases {
-        create_signed_post_url: Arc::new(create_upload_media_signed_url),
-        create_signed_get_url: Arc::new(create_variant_get_url),
-        list_media: Arc::new(list_media),
-    };
-    let image_upload_policy = UploadPolicy::from_env();
-
-    let state = AppState {
-        fetch_cv_use_case: Arc::new(fetch_cv_use_case),
-        fetch_cv_by_id_use_case: Arc::new(fetch_cv_by_id_use_case),
-        get_public_single_cv_use_case: Arc::new(get_public_single_cv_uc),
-        create_cv_use_case: Arc::new(create_cv_use_case),
-        update_cv_use_case: Arc::new(update_cv_use_case),
-        patch_cv_use_case: Arc::new(patch_cv_use_case),
-        register_user_orchestrator: Arc::new(register_user_orchestrator),
-        verify_user_email_use_case: Arc::new(verify_user_email_use_case),
-        login_user_use_case: Arc::new(login_user_use_case),
-        refresh_token_use_case: Arc::new(refresh_token_use_case),
-        logout_user_use_case: Arc::new(logout_user_use_case),
-        soft_delete_user_use_case: Arc::new(soft_delete_user_use_case),
-        fetch_user_profile_use_case: Arc::new(fetch_user_profile_service),
-        update_user_profile_use_case: Arc::new(update_user_profile_service),
-        hard_delete_cv_use_case: Arc::new(hard_delete_cv_use_case),
-        create_topic_use_case: Arc::new(create_topic_uc),
-        get_topics_use_case: Arc::new(get_topics_uc),
-        soft_delete_topic_use_case: Arc::new(soft_delete_topic_uc),
-        project: project_use_cases,
-        multimedia: media_use_cases,
-        user_identity_resolver: identity_resolver,
-        multimedia_upload_policy: image_upload_policy,
-    };
-
-    let token_provider_arc: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service);
-    // Clone db_arc for use in HttpServer closure
-    let db_for_server = Arc::clone(&db_arc);
-
-    HttpServer::new(move || {
-        use utoipa::OpenApi;
-        use utoipa_swagger_ui::SwaggerUi;
-
-        use crate::api::openapi::ApiDoc;
-
-        #[allow(unused_mut)]
-        let mut app = App::new()
-            .app_data(web::Data::new(state.clone()))
-            .app_data(web::Data::new(Arc::clone(&token_provider_arc)))
-            .app_data(web::Data::new(Arc::clone(&db_for_server)))
-            .app_data(web::Data::new(Arc::clone(&redis_arc)))
-            .app_data(custom_json_config())
-            // ✅ Swagger UI service
-            .service(
-                SwaggerUi::new("/swagger-ui/{_:.*}")
-                    .url("/api-docs/openapi.json", ApiDoc::openapi()),
-            )
-            .configure(init_routes);
-
-        // Conditionally add test routes
-        #[cfg(feature = "test-helpers")]
-        {
-            app = app.configure(test_helpers::configure_routes);
-        }
-
-        app
-    })
-    .bind(server_url)?
-    .run()
-    .await
-}
-
-#[cfg(not(tarpaulin_include))]
-fn init_routes(cfg: &mut web::ServiceConfig) {
-    // Health
-    cfg.service(crate::health::health);
-    cfg.service(crate::health::readiness);
-    // CV
-    cfg.service(crate::cv::adapter::incoming::web::routes::get_cvs_handler);
-    cfg.service(crate::cv::adapter::incoming::web::routes::get_cv_by_id_handler);
-    cfg.service(crate::cv::adapter::incoming::web::routes::get_public_cv_by_id_handler);
-    cfg.service(crate::cv::adapter::incoming::web::routes::create_cv_handler);
-    cfg.service(crate::cv::adapter::incoming::web::routes::update_cv_handler);
-    cfg.service(crate::cv::adapter::incoming::web::routes::patch_cv_handler);
-    cfg.service(crate::cv::adapter::incoming::web::routes::hard_delete_cv_handler);
-    // Auth
-    cfg.service(crate::auth::adapter::incoming::web::routes::register_user_handler);
-    cfg.service(crate::auth::adapter::incoming::web::routes::verify_user_email_handler);
-    cfg.service(crate::auth::adapter::incoming::web::routes::login_user_handler);
-    cfg.service(crate::auth::adapter::incoming::web::routes::refresh_token_handler);
-    cfg.service(crate::auth::adapter::incoming::web::routes::logout_user_handler);
-    cfg.service(crate::auth::adapter::incoming::web::routes::soft_delete_user_handler);
-    cfg.service(crate::auth::adapter::incoming::web::routes::get_user_profile_handler);
-    cfg.service(crate::auth::adapter::incoming::web::routes::update_user_profile_handler);
-    // Topic
-    cfg.service(crate::topic::adapter::incoming::web::routes::get_topics_handler);
-    cfg.service(crate::topic::adapter::incoming::web::routes::create_topic_handler);
-    cfg.service(crate::topic::adapter::incoming::web::routes::soft_delete_topic_handler);
-    // Project
-    cfg.service(crate::project::adapter::incoming::web::routes::get_projects_handler);
-    cfg.service(crate::project::adapter::incoming::web::routes::get_public_projects_handler);
-    cfg.service(crate::project::adapter::incoming::web::routes::create_project_handler);
-    cfg.service(crate::project::adapter::incoming::web::routes::hard_delete_project_handler);
-    cfg.service(crate::project::adapter::incoming::web::routes::get_project_by_id_handler);
-    cfg.service(crate::project::adapter::incoming::web::routes::get_public_single_project_handler);
-    cfg.service(crate::project::adapter::incoming::web::routes::patch_project_handler);
-    cfg.service(crate::project::adapter::incoming::web::routes::soft_delete_project_handler);
-    cfg.service(crate::project::adapter::incoming::web::routes::add_project_topic_handler);
-    cfg.service(crate::project::adapter::incoming::web::routes::get_project_topics_handler);
-    cfg.service(crate::project::adapter::incoming::web::routes::remove_project_topic_handler);
-    cfg.service(crate::project::adapter::incoming::web::routes::clear_project_topics_handler);
-    // Multimedia
-    cfg.service(crate::multimedia::adapter::incoming::web::routes::init_upload_handler);
-    cfg.service(crate::multimedia::adapter::incoming::web::routes::get_variant_read_url_handler);
-    cfg.service(crate::multimedia::adapter::incoming::web::routes::list_media_handler);
-}
-
+// The server lives in the library so the `cli` workspace member can reuse its adapters
 #[cfg(not(tarpaulin_include))]
 fn main() {
-    rustls::crypto::ring::default_provider()
-        .install_default()
-        .expect("Failed to install rustls crypto provider");
-    if let Err(e) = start() {
-        eprintln!("Error starting app: {e}");
-    }
+    backend_actix::run();
 }
@@ -0,0 +1,151 @@
+use actix_web::{get, web, Responder};
+use serde::Deserialize;
+use tracing::error;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    activity::application::ports::{
+        incoming::use_cases::ListActivitiesError,
+        outgoing::{Activity, ActivityKind},
+    },
+    auth::{
+        adapter::incoming::web::extractors::auth::VerifiedUser,
+        application::domain::entities::UserId,
+    },
+    shared::api::{
+        pagination::{PageParams, PagedResponse},
+        ApiResponse,
+    },
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct ListActivitiesQuery {
+    #[serde(rename = "type")]
+    pub kind: Option<ActivityKind>,
+}
+
+/// The current user's recent changes, newest first
+///
+/// Every CV, project, page and media item the user creates, edits, publishes,
+/// deletes or restores is recorded, whichever client made the change. Edits
+/// to one item within 10 minutes of each other show as a single `updated`
+/// entry. Entries stay after the item itself is gone; `title` is the one it
+/// had at the time.
+#[utoipa::path(
+    get,
+    path = "/api/activities",
+    tag = "activity",
+    params(
+        ("type" = Option<ActivityKind>, Query, description = "Only changes to this type of content"),
+        ("limit" = Option<u32>, Query, description = "Page size, 1-100 (default 20)"),
+        ("cursor" = Option<String>, Query, description = "`next_cursor` from the previous page"),
+    ),
+    responses(
+        (status = 200, description = "One page of activity", body = inline(SuccessResponse<PagedResponse<Activity>>)),
+        (status = 400, description = "Invalid type or pagination parameters", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/activities")]
+pub async fn list_activities_handler(
+    user: VerifiedUser,
+    query: web::Query<ListActivitiesQuery>,
+    page: PageParams,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data
+        .list_activities_use_case
+        .execute(
+            UserId::from(user.user_id),
+            query.kind,
+            page.offset,
+            page.limit,
+        )
+        .await
+    {
+        Ok(result) => ApiResponse::success(PagedResponse::new(result.items, result.total, &page)),
+        Err(ListActivitiesError::QueryFailed(msg)) => {
+            error!(user = %user.user_id, "Failed to list activities: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, web, App};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubListActivitiesUseCase,
+        },
+    };
+
+    async fn call(state: web::Data<AppState>, uri: &str) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(list_activities_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_list_activities_returns_page() {
+        let state = TestAppStateBuilder::default()
+            .with_list_activities(StubListActivitiesUseCase::success())
+            .build();
+
+        let resp = call(state, "/api/activities?type=page&limit=10").await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["total"], 1);
+        assert_eq!(json["data"]["items"][0]["type"], "page");
+        assert_eq!(json["data"]["items"][0]["action"], "published");
+        assert!(json["data"]["items"][0]["occurred_at"].is_string());
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_type_is_rejected() {
+        let state = TestAppStateBuilder::default().build();
+
+        let resp = call(state, "/api/activities?type=post").await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_query_failure_returns_internal_error() {
+        let state = TestAppStateBuilder::default()
+            .with_list_activities(StubListActivitiesUseCase::failure("db down"))
+            .build();
+
+        let resp = call(state, "/api/activities").await;
+
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,3 @@
+mod list_activities;
+
+pub use list_activities::{__path_list_activities_handler, list_activities_handler};
@@ -0,0 +1,216 @@
+use async_trait::async_trait;
+use sea_orm::{ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, Statement};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::activity::application::ports::outgoing::{
+    Activity, ActivityAction, ActivityKind, ActivityLog, ActivityLogError, ActivityPage,
+};
+use crate::auth::application::domain::entities::UserId;
+
+#[derive(Clone)]
+pub struct ActivityLogPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl ActivityLogPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    fn list_stmt(
+        backend: DatabaseBackend,
+        owner: Uuid,
+        kind: Option<ActivityKind>,
+        offset: u64,
+        limit: u32,
+    ) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT id, kind, target_id, action, title, occurred_at
+            FROM activities
+            WHERE user_id = $1
+              AND (CAST($2 AS TEXT) IS NULL OR kind = $2)
+            ORDER BY occurred_at DESC, id
+            LIMIT $4
+            OFFSET $3
+            "#,
+            vec![
+                owner.into(),
+                kind.map(kind_str).into(),
+                (offset as i64).into(),
+                (limit as i64).into(),
+            ],
+        )
+    }
+
+    fn count_stmt(backend: DatabaseBackend, owner: Uuid, kind: Option<ActivityKind>) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT COUNT(*) AS total
+            FROM activities
+            WHERE user_id = $1
+              AND (CAST($2 AS TEXT) IS NULL OR kind = $2)
+            "#,
+            vec![owner.into(), kind.map(kind_str).into()],
+        )
+    }
+}
+
+fn kind_str(kind: ActivityKind) -> &'static str {
+    match kind {
+        ActivityKind::Cv => "cv",
+        ActivityKind::Project => "project",
+        ActivityKind::Page => "page",
+        ActivityKind::Media => "media",
+    }
+}
+
+fn parse_kind(value: &str) -> Result<ActivityKind, ActivityLogError> {
+    match value {
+        "cv" => Ok(ActivityKind::Cv),
+        "project" => Ok(ActivityKind::Project),
+        "page" => Ok(ActivityKind::Page),
+        "media" => Ok(ActivityKind::Media),
+        other => Err(ActivityLogError::DatabaseError(format!(
+            "unknown activity kind '{other}'"
+        ))),
+    }
+}
+
+fn parse_action(value: &str) -> Result<ActivityAction, ActivityLogError> {
+    match value {
+        "created" => Ok(ActivityAction::Created),
+        "updated" => Ok(ActivityAction::Updated),
+        "published" => Ok(ActivityAction::Published),
+        "deleted" => Ok(ActivityAction::Deleted),
+        "restored" => Ok(ActivityAction::Restored),
+        other => Err(ActivityLogError::DatabaseError(format!(
+            "unknown activity action '{other}'"
+        ))),
+    }
+}
+
+fn map_db_err(e: DbErr) -> ActivityLogError {
+    ActivityLogError::DatabaseError(e.to_string())
+}
+
+#[async_trait]
+impl ActivityLog for ActivityLogPostgres {
+    async fn list(
+        &self,
+        owner: UserId,
+        kind: Option<ActivityKind>,
+        offset: u64,
+        limit: u32,
+    ) -> Result<ActivityPage, ActivityLogError> {
+        let backend = self.db.get_database_backend();
+        let owner_uuid: Uuid = owner.into();
+
+        let rows = self
+            .db
+            .query_all(Self::list_stmt(backend, owner_uuid, kind, offset, limit))
+            .await
+            .map_err(map_db_err)?;
+
+        let items = rows
+            .iter()
+            .map(|row| {
+                let kind: String = row.try_get("", "kind").map_err(map_db_err)?;
+                let action: String = row.try_get("", "action").map_err(map_db_err)?;
+                Ok(Activity {
+                    id: row.try_get("", "id").map_err(map_db_err)?,
+                    kind: parse_kind(&kind)?,
+                    target_id: row.try_get("", "target_id").map_err(map_db_err)?,
+                    action: parse_action(&action)?,
+                    title: row.try_get("", "title").map_err(map_db_err)?,
+                    occurred_at: row.try_get("", "occurred_at").map_err(map_db_err)?,
+                })
+            })
+            .collect::<Result<Vec<_>, ActivityLogError>>()?;
+
+        let total: i64 = self
+            .db
+            .query_one(Self::count_stmt(backend, owner_uuid, kind))
+            .await
+            .map_err(map_db_err)?
+            .ok_or_else(|| ActivityLogError::DatabaseError("count returned no row".into()))?
+            .try_get("", "total")
+            .map_err(map_db_err)?;
+
+        Ok(ActivityPage {
+            items,
+            total: total.max(0) as u64,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use sea_orm::{MockDatabase, Value};
+    use std::collections::BTreeMap;
+
+    fn activity_row(kind: &str, action: &str, title: Option<&str>) -> BTreeMap<String, Value> {
+        BTreeMap::from([
+            ("id".to_string(), Value::from(Uuid::new_v4())),
+            ("kind".to_string(), Value::from(kind)),
+            ("target_id".to_string(), Value::from(Uuid::new_v4())),
+            ("action".to_string(), Value::from(action)),
+            (
+                "title".to_string(),
+                Value::String(title.map(|title| Box::new(title.to_string()))),
+            ),
+            ("occurred_at".to_string(), Value::from(Utc::now())),
+        ])
+    }
+
+    fn total_row(total: i64) -> BTreeMap<String, Value> {
+        BTreeMap::from([("total".to_string(), Value::BigInt(Some(total)))])
+    }
+
+    #[tokio::test]
+    async fn test_list_maps_rows_and_total() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![
+                activity_row("page", "published", Some("About")),
+                activity_row("cv", "updated", None),
+            ]])
+            .append_query_results(vec![vec![total_row(12)]])
+            .into_connection();
+
+        let log = ActivityLogPostgres::new(Arc::new(db));
+        let page = log
+            .list(UserId::from(Uuid::new_v4()), None, 0, 2)
+            .await
+            .unwrap();
+
+        assert_eq!(page.total, 12);
+        assert_eq!(page.items[0].kind, ActivityKind::Page);
+        assert_eq!(page.items[0].action, ActivityAction::Published);
+        assert_eq!(page.items[0].title.as_deref(), Some("About"));
+        assert_eq!(page.items[1].title, None);
+    }
+
+    #[tokio::test]
+    async fn test_list_rejects_unknown_action() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![activity_row("media", "archived", None)]])
+            .into_connection();
+
+        let log = ActivityLogPostgres::new(Arc::new(db));
+        let err = log
+            .list(UserId::from(Uuid::new_v4()), None, 0, 20)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ActivityLogError::DatabaseError(msg) if msg.contains("archived")));
+    }
+}
@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::activity::application::ports::outgoing::{
+    Activity, ActivityAction, ActivityKind, ActivityLog, ActivityLogError, ActivityPage,
+};
+use crate::auth::application::domain::entities::UserId;
+use crate::shared::in_memory::Table;
+
+struct ActivityRow {
+    owner: UserId,
+    activity: Activity,
+}
+
+/// Process-local `ActivityLog`. Entries are recorded by Postgres triggers,
+/// which have no in-memory counterpart, so in standalone mode the feed stays
+/// empty; `record` is there for tests.
+#[derive(Clone, Default)]
+pub struct InMemoryActivityLog {
+    activities: Table<ActivityRow>,
+}
+
+impl InMemoryActivityLog {
+    pub fn record(
+        &self,
+        owner: UserId,
+        kind: ActivityKind,
+        target_id: Uuid,
+        action: ActivityAction,
+        title: &str,
+    ) {
+        let activity = Activity {
+            id: Uuid::new_v4(),
+            kind,
+            target_id,
+            action,
+            title: Some(title.to_string()),
+            occurred_at: Utc::now(),
+        };
+        self.activities
+            .write(|activities| activities.push(ActivityRow { owner, activity }));
+    }
+}
+
+#[async_trait]
+impl ActivityLog for InMemoryActivityLog {
+    async fn list(
+        &self,
+        owner: UserId,
+        kind: Option<ActivityKind>,
+        offset: u64,
+        limit: u32,
+    ) -> Result<ActivityPage, ActivityLogError> {
+        let items: Vec<Activity> = self.activities.read(|activities| {
+            activities
+                .iter()
+                .rev()
+                .filter(|row| row.owner == owner)
+                .filter(|row| kind.is_none_or(|kind| row.activity.kind == kind))
+                .map(|row| row.activity.clone())
+                .collect()
+        });
+
+        Ok(ActivityPage {
+            total: items.len() as u64,
+            items: items
+                .into_iter()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .collect(),
+        })
+    }
+}
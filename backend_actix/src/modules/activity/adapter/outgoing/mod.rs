@@ -0,0 +1,5 @@
+mod activity_log_postgres;
+mod in_memory;
+
+pub use activity_log_postgres::ActivityLogPostgres;
+pub use in_memory::InMemoryActivityLog;
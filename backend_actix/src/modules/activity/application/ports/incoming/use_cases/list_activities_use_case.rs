@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+
+use crate::activity::application::ports::outgoing::{ActivityKind, ActivityPage};
+use crate::auth::application::domain::entities::UserId;
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ListActivitiesError {
+    #[error("Failed to list activities: {0}")]
+    QueryFailed(String),
+}
+
+#[async_trait]
+pub trait ListActivitiesUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        owner: UserId,
+        kind: Option<ActivityKind>,
+        offset: u64,
+        limit: u32,
+    ) -> Result<ActivityPage, ListActivitiesError>;
+}
+
+metered_use_case!(
+    "activity",
+    "list_activities",
+    ListActivitiesUseCase,
+    fn execute(
+        &self,
+        owner: UserId,
+        kind: Option<ActivityKind>,
+        offset: u64,
+        limit: u32,
+    ) -> Result<ActivityPage, ListActivitiesError>
+);
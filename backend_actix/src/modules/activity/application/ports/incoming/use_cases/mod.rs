@@ -0,0 +1,3 @@
+mod list_activities_use_case;
+
+pub use list_activities_use_case::{ListActivitiesError, ListActivitiesUseCase};
@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+
+/// What changed. Entries are written by database triggers (see the
+/// activities migration), so the names must match them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ActivityKind {
+    Cv,
+    Project,
+    Page,
+    Media,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ActivityAction {
+    Created,
+    /// One or more edits; a run of edits within 10 minutes is one entry
+    Updated,
+    /// A page went from draft to published
+    Published,
+    /// Moved to the trash, or deleted outright
+    Deleted,
+    /// Taken back out of the trash
+    Restored,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct Activity {
+    pub id: Uuid,
+    #[serde(rename = "type")]
+    pub kind: ActivityKind,
+    /// Id of the CV, project, page or media item; it may no longer exist
+    pub target_id: Uuid,
+    pub action: ActivityAction,
+    /// CV display name, project or page title, or media file name at the time
+    pub title: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActivityPage {
+    pub items: Vec<Activity>,
+    pub total: u64,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ActivityLogError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Read side of the owner's activity feed.
+#[async_trait]
+pub trait ActivityLog: Send + Sync {
+    /// Newest first
+    async fn list(
+        &self,
+        owner: UserId,
+        kind: Option<ActivityKind>,
+        offset: u64,
+        limit: u32,
+    ) -> Result<ActivityPage, ActivityLogError>;
+}
@@ -0,0 +1,5 @@
+mod activity_log;
+
+pub use activity_log::{
+    Activity, ActivityAction, ActivityKind, ActivityLog, ActivityLogError, ActivityPage,
+};
@@ -0,0 +1,115 @@
+use async_trait::async_trait;
+
+use crate::activity::application::ports::{
+    incoming::use_cases::{ListActivitiesError, ListActivitiesUseCase},
+    outgoing::{ActivityKind, ActivityLog, ActivityPage},
+};
+use crate::auth::application::domain::entities::UserId;
+
+pub struct ListActivitiesService<L>
+where
+    L: ActivityLog,
+{
+    log: L,
+}
+
+impl<L> ListActivitiesService<L>
+where
+    L: ActivityLog,
+{
+    pub fn new(log: L) -> Self {
+        Self { log }
+    }
+}
+
+#[async_trait]
+impl<L> ListActivitiesUseCase for ListActivitiesService<L>
+where
+    L: ActivityLog,
+{
+    async fn execute(
+        &self,
+        owner: UserId,
+        kind: Option<ActivityKind>,
+        offset: u64,
+        limit: u32,
+    ) -> Result<ActivityPage, ListActivitiesError> {
+        self.log
+            .list(owner, kind, offset, limit)
+            .await
+            .map_err(|e| ListActivitiesError::QueryFailed(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    use crate::activity::adapter::outgoing::InMemoryActivityLog;
+    use crate::activity::application::ports::outgoing::ActivityAction;
+
+    #[tokio::test]
+    async fn test_lists_only_the_owners_entries_newest_first() {
+        let owner = UserId::from(Uuid::new_v4());
+        let log = InMemoryActivityLog::default();
+        let page_id = Uuid::new_v4();
+        log.record(
+            owner,
+            ActivityKind::Page,
+            page_id,
+            ActivityAction::Created,
+            "About",
+        );
+        log.record(
+            owner,
+            ActivityKind::Page,
+            page_id,
+            ActivityAction::Published,
+            "About",
+        );
+        log.record(
+            UserId::from(Uuid::new_v4()),
+            ActivityKind::Cv,
+            Uuid::new_v4(),
+            ActivityAction::Created,
+            "Someone else",
+        );
+        let service = ListActivitiesService::new(log);
+
+        let page = service.execute(owner, None, 0, 1).await.unwrap();
+
+        assert_eq!(page.total, 2);
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].action, ActivityAction::Published);
+    }
+
+    #[tokio::test]
+    async fn test_filters_by_kind() {
+        let owner = UserId::from(Uuid::new_v4());
+        let log = InMemoryActivityLog::default();
+        log.record(
+            owner,
+            ActivityKind::Page,
+            Uuid::new_v4(),
+            ActivityAction::Created,
+            "Now",
+        );
+        log.record(
+            owner,
+            ActivityKind::Media,
+            Uuid::new_v4(),
+            ActivityAction::Deleted,
+            "photo.png",
+        );
+        let service = ListActivitiesService::new(log);
+
+        let page = service
+            .execute(owner, Some(ActivityKind::Media), 0, 20)
+            .await
+            .unwrap();
+
+        assert_eq!(page.total, 1);
+        assert_eq!(page.items[0].title.as_deref(), Some("photo.png"));
+    }
+}
@@ -0,0 +1,3 @@
+mod list_activities_service;
+
+pub use list_activities_service::ListActivitiesService;
@@ -0,0 +1,9 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    CONFIG_INVALID = (UNPROCESSABLE_ENTITY, "The new configuration is invalid");
+    INVALID_LOG_FILTER = (BAD_REQUEST, "Invalid log filter");
+    LOGGING_UNAVAILABLE = (SERVICE_UNAVAILABLE, "Logging cannot be changed on this instance");
+    INVALID_QUICK_SEARCH = (BAD_REQUEST, "Invalid quick search");
+    INVALID_STORAGE_REPORT = (BAD_REQUEST, "Invalid storage report request");
+}
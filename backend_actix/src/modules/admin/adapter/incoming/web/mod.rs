@@ -0,0 +1,20 @@
+use actix_web::web;
+
+pub mod error_codes;
+pub mod routes;
+
+/// Routes authenticated by a shared secret instead of a user token
+pub fn configure_public(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::get_metrics_handler);
+}
+
+/// Routes for signed-in users with a verified email
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::get_admin_stats_handler)
+        .service(routes::reload_config_handler)
+        .service(routes::get_logging_handler)
+        .service(routes::update_logging_handler)
+        .service(routes::check_integrity_handler)
+        .service(routes::quick_search_handler)
+        .service(routes::get_storage_report_handler);
+}
@@ -0,0 +1,144 @@
+use actix_web::{get, web, Responder};
+use serde::Deserialize;
+use tracing::{error, info};
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    admin::application::ports::incoming::use_cases::{CheckIntegrityCommand, IntegrityReport},
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    shared::api::ApiResponse,
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct CheckIntegrityParams {
+    pub storage: Option<bool>,
+}
+
+/// Scan the whole site for broken references
+///
+/// Reports CVs highlighting projects that are gone or in the trash,
+/// attachments whose media is gone, variants missing from storage and slugs
+/// that collide ignoring case, each with a suggested fix. Nothing is
+/// changed; `cli content check` runs the same scan.
+#[utoipa::path(
+    get,
+    path = "/api/admin/integrity",
+    tag = "admin",
+    params(
+        ("storage" = Option<bool>, Query, description = "Look variants up in storage; `false` skips listing the buckets, the slow part (default true)"),
+    ),
+    responses(
+        (status = 200, description = "Issues found, possibly none", body = inline(SuccessResponse<IntegrityReport>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/admin/integrity")]
+pub async fn check_integrity_handler(
+    admin: AdminUser,
+    params: web::Query<CheckIntegrityParams>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let command = CheckIntegrityCommand {
+        check_storage: params.storage.unwrap_or(true),
+    };
+
+    match data.integrity_check_use_case.execute(command).await {
+        Ok(report) => {
+            info!(admin = %admin.user_id, issues = report.issues.len(), "Integrity check ran");
+            ApiResponse::success(report)
+        }
+        Err(e) => {
+            error!(admin = %admin.user_id, error = %e, "Integrity check failed");
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::admin::application::ports::incoming::use_cases::CheckIntegrityError;
+    use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, auth_helper::test_helpers::create_test_jwt_service,
+        stubs::StubCheckIntegrityUseCase,
+    };
+
+    async fn call(check: StubCheckIntegrityUseCase, uri: &str) -> actix_web::dev::ServiceResponse {
+        let admin_id = Uuid::new_v4();
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(admin_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin_id])
+            .with_integrity_check(check)
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(check_integrity_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_issues_are_reported_with_suggestions() {
+        let resp = call(
+            StubCheckIntegrityUseCase::with_dangling_highlight(),
+            "/api/admin/integrity",
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        let issue = &body["data"]["issues"][0];
+        assert_eq!(issue["kind"], "dangling_highlighted_project");
+        assert_eq!(issue["resource"], "cv");
+        assert!(issue["suggestion"].is_string());
+        assert_eq!(body["data"]["storage_checked"], true);
+    }
+
+    #[actix_web::test]
+    async fn test_storage_can_be_skipped() {
+        let resp = call(
+            StubCheckIntegrityUseCase::clean(),
+            "/api/admin/integrity?storage=false",
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["storage_checked"], false);
+    }
+
+    #[actix_web::test]
+    async fn test_failed_check_is_internal_error() {
+        let resp = call(
+            StubCheckIntegrityUseCase::failure(CheckIntegrityError::StorageFailed(
+                "timeout".to_string(),
+            )),
+            "/api/admin/integrity",
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
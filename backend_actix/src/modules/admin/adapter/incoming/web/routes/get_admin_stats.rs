@@ -0,0 +1,119 @@
+use actix_web::{get, web, Responder};
+use tracing::error;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    admin::application::ports::incoming::use_cases::{AdminStats, GetAdminStatsError},
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    shared::api::ApiResponse,
+    AppState,
+};
+
+/// Site-wide counts for the admin dashboard overview card
+#[utoipa::path(
+    get,
+    path = "/api/admin/stats",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Current counts", body = inline(SuccessResponse<AdminStats>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/admin/stats")]
+pub async fn get_admin_stats_handler(
+    admin: AdminUser,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data.admin_stats_use_case.execute().await {
+        Ok(stats) => ApiResponse::success(stats),
+        Err(GetAdminStatsError::QueryFailed(msg)) => {
+            error!(admin = %admin.user_id, "Failed to load admin stats: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, web, App};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubGetAdminStatsUseCase,
+        },
+    };
+
+    async fn call(state: web::Data<AppState>, user_id: Uuid) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(user_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(get_admin_stats_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/api/admin/stats")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_admin_gets_stats() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .with_admin_stats(StubGetAdminStatsUseCase::success())
+            .build();
+
+        let resp = call(state, admin).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["users"]["total"], 3);
+        assert_eq!(json["data"]["media"]["failed"], 1);
+        assert_eq!(json["data"]["failed_processing_last_24h"], 1);
+    }
+
+    #[actix_web::test]
+    async fn test_non_admin_is_forbidden() {
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![Uuid::new_v4()])
+            .build();
+
+        let resp = call(state, Uuid::new_v4()).await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "ADMIN_REQUIRED");
+    }
+
+    #[actix_web::test]
+    async fn test_query_failure_returns_internal_error() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .with_admin_stats(StubGetAdminStatsUseCase::failure("db down"))
+            .build();
+
+        let resp = call(state, admin).await;
+
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
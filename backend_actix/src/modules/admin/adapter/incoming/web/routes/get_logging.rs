@@ -0,0 +1,31 @@
+use actix_web::{get, web, Responder};
+
+use super::LoggingResponse;
+use crate::admin::adapter::incoming::web::error_codes::LOGGING_UNAVAILABLE;
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::AdminUser, shared::api::ApiResponse, AppState,
+};
+
+/// The log filter and format this instance is using
+#[utoipa::path(
+    get,
+    path = "/api/admin/logging",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Logging in effect", body = inline(SuccessResponse<LoggingResponse>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 503, description = "Logging can't be changed on this instance", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/admin/logging")]
+pub async fn get_logging_handler(_admin: AdminUser, data: web::Data<AppState>) -> impl Responder {
+    match data.config_reloader.log_filter() {
+        Some(logging) => ApiResponse::success(LoggingResponse::from(logging)),
+        None => LOGGING_UNAVAILABLE.response(),
+    }
+}
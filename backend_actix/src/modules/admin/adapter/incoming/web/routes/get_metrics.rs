@@ -0,0 +1,98 @@
+use actix_web::{get, http::header::AUTHORIZATION, web, HttpRequest, HttpResponse, Responder};
+
+use crate::api::schemas::ErrorResponse;
+use crate::shared::api::error_codes::{NOT_FOUND, UNAUTHORIZED};
+use crate::shared::constant_time::constant_time_eq;
+use crate::shared::metrics::use_case_metrics;
+use crate::AppState;
+
+/// Use case latency and errors, for Prometheus
+///
+/// `use_case_duration_seconds` is a histogram of every use case execution,
+/// labeled by `module` and `use_case`; `use_case_errors_total` counts the
+/// failed ones by error `code` as well. Numbers are this instance's since it
+/// started. Scrapers authenticate with `Authorization: Bearer <METRICS_TOKEN>`.
+#[utoipa::path(
+    get,
+    path = "/api/admin/metrics",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Prometheus text format", content_type = "text/plain", body = String),
+        (status = 401, description = "Missing or wrong metrics secret", body = ErrorResponse),
+        (status = 404, description = "`METRICS_TOKEN` is not configured", body = ErrorResponse),
+    )
+)]
+#[get("/api/admin/metrics")]
+pub async fn get_metrics_handler(req: HttpRequest, data: web::Data<AppState>) -> impl Responder {
+    let Some(expected) = data.metrics_token.as_deref() else {
+        return NOT_FOUND.response();
+    };
+    let presented = req
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    if !presented.is_some_and(|secret| constant_time_eq(expected.as_bytes(), secret.as_bytes())) {
+        return UNAUTHORIZED.with_message("Missing or invalid metrics secret");
+    }
+
+    HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4")
+        .body(use_case_metrics().render())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+
+    use crate::tests::support::app_state_builder::TestAppStateBuilder;
+
+    const SECRET: &str = "0123456789abcdef";
+
+    async fn call(
+        state: web::Data<AppState>,
+        secret: Option<&str>,
+    ) -> actix_web::dev::ServiceResponse {
+        let app = test::init_service(App::new().app_data(state).service(get_metrics_handler)).await;
+        let mut req = test::TestRequest::get().uri("/api/admin/metrics");
+        if let Some(secret) = secret {
+            req = req.insert_header(("Authorization", format!("Bearer {secret}")));
+        }
+        test::call_service(&app, req.to_request()).await
+    }
+
+    #[actix_web::test]
+    async fn test_metrics_are_served_with_the_secret() {
+        let state = TestAppStateBuilder::default()
+            .with_metrics_token(SECRET)
+            .build();
+
+        let resp = call(state, Some(SECRET)).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = test::read_body(resp).await;
+        let body = std::str::from_utf8(&body).unwrap();
+        assert!(body.contains("# TYPE use_case_duration_seconds histogram"));
+    }
+
+    #[actix_web::test]
+    async fn test_wrong_secret_is_unauthorized() {
+        let state = TestAppStateBuilder::default()
+            .with_metrics_token(SECRET)
+            .build();
+
+        let resp = call(state, Some("fedcba9876543210")).await;
+
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn test_unconfigured_endpoint_is_not_found() {
+        let state = TestAppStateBuilder::default().build();
+
+        let resp = call(state, Some(SECRET)).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,149 @@
+use actix_web::{get, web, Responder};
+use serde::Deserialize;
+use tracing::error;
+
+use crate::admin::adapter::incoming::web::error_codes::INVALID_STORAGE_REPORT;
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    admin::application::ports::incoming::use_cases::{
+        StorageReport, StorageReportCommand, StorageReportError,
+    },
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    shared::api::ApiResponse,
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct StorageReportParams {
+    pub top_users: Option<u32>,
+}
+
+/// Bytes taken by generated variants, per bucket, size, format and user
+///
+/// Sums `file_size_bytes` of every stored variant, trashed media included
+/// until purged; originals are not counted. Each group also shows the bytes
+/// written this calendar month and last one (UTC), so the effect of a new
+/// width or output format can be read off before it is enabled everywhere.
+#[utoipa::path(
+    get,
+    path = "/api/admin/storage-report",
+    tag = "admin",
+    params(
+        ("top_users" = Option<u32>, Query, description = "Largest owners to list, 1-200 (default 20)"),
+    ),
+    responses(
+        (status = 200, description = "Storage breakdown", body = inline(SuccessResponse<StorageReport>)),
+        (status = 400, description = "Invalid top_users", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/admin/storage-report")]
+pub async fn get_storage_report_handler(
+    admin: AdminUser,
+    params: web::Query<StorageReportParams>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let command = match StorageReportCommand::new(params.top_users) {
+        Ok(command) => command,
+        Err(err) => return INVALID_STORAGE_REPORT.with_message(&err.to_string()),
+    };
+
+    match data.storage_report_use_case.execute(command).await {
+        Ok(report) => ApiResponse::success(report),
+        Err(StorageReportError::InvalidQuery(msg)) => INVALID_STORAGE_REPORT.with_message(&msg),
+        Err(StorageReportError::QueryFailed(msg)) => {
+            error!(admin = %admin.user_id, "Failed to build storage report: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, auth_helper::test_helpers::create_test_jwt_service,
+        stubs::StubGetStorageReportUseCase,
+    };
+
+    async fn call(
+        report: StubGetStorageReportUseCase,
+        uri: &str,
+    ) -> actix_web::dev::ServiceResponse {
+        let admin_id = Uuid::new_v4();
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(admin_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin_id])
+            .with_storage_report(report)
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(get_storage_report_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_groups_flatten_their_usage() {
+        let resp = call(
+            StubGetStorageReportUseCase::with_avif(),
+            "/api/admin/storage-report",
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        let format = &body["data"]["by_format"][0];
+        assert_eq!(format["key"], "image/avif");
+        assert_eq!(format["bytes"], 2048);
+        assert_eq!(format["bytes_added_this_month"], 2048);
+        assert_eq!(body["data"]["total"]["objects"], 3);
+    }
+
+    #[actix_web::test]
+    async fn test_out_of_range_top_users_is_rejected() {
+        let resp = call(
+            StubGetStorageReportUseCase::with_avif(),
+            "/api/admin/storage-report?top_users=0",
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "INVALID_STORAGE_REPORT");
+    }
+
+    #[actix_web::test]
+    async fn test_failed_report_is_internal_error() {
+        let resp = call(
+            StubGetStorageReportUseCase::failure(StorageReportError::QueryFailed(
+                "db down".to_string(),
+            )),
+            "/api/admin/storage-report",
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,38 @@
+mod check_integrity;
+mod get_admin_stats;
+mod get_logging;
+mod get_metrics;
+mod get_storage_report;
+mod quick_search;
+mod reload_config;
+mod update_logging;
+pub use check_integrity::{__path_check_integrity_handler, check_integrity_handler};
+pub use get_admin_stats::{__path_get_admin_stats_handler, get_admin_stats_handler};
+pub use get_logging::{__path_get_logging_handler, get_logging_handler};
+pub use get_metrics::{__path_get_metrics_handler, get_metrics_handler};
+pub use get_storage_report::{__path_get_storage_report_handler, get_storage_report_handler};
+pub use quick_search::{__path_quick_search_handler, quick_search_handler};
+pub use reload_config::{__path_reload_config_handler, reload_config_handler};
+pub use update_logging::{__path_update_logging_handler, update_logging_handler};
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::shared::{log_filter::LogFilter, telemetry::LogFormat};
+
+/// Logging of the instance that answered
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LoggingResponse {
+    /// `RUST_LOG` directives in effect
+    pub filter: String,
+    pub format: LogFormat,
+}
+
+impl From<&LogFilter> for LoggingResponse {
+    fn from(logging: &LogFilter) -> Self {
+        Self {
+            filter: logging.current(),
+            format: logging.format(),
+        }
+    }
+}
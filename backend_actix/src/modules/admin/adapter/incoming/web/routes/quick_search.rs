@@ -0,0 +1,154 @@
+use actix_web::{get, web, Responder};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use utoipa::ToSchema;
+
+use crate::admin::adapter::incoming::web::error_codes::INVALID_QUICK_SEARCH;
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    admin::application::ports::incoming::use_cases::{
+        QuickSearchCommand, QuickSearchError, QuickSearchHit,
+    },
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    shared::api::ApiResponse,
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct QuickSearchParams {
+    pub q: String,
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct QuickSearchResponse {
+    /// Id matches first, then exact and prefix matches
+    pub items: Vec<QuickSearchHit>,
+}
+
+/// Jump to any user or content by id, slug or email
+///
+/// One lookup for the admin UI's command palette, across every owner:
+/// users by id or the start of their email or username, projects and pages
+/// by id or the start of their slug, CVs and media by id. Deleted and
+/// trashed items are included and flagged. Each hit's `link` names the kind
+/// of item and the ids the UI needs to open it.
+#[utoipa::path(
+    get,
+    path = "/api/admin/quick-search",
+    tag = "admin",
+    params(
+        ("q" = String, Query, description = "An id, or the start of a slug, email or username; 2-254 characters, case-insensitive"),
+        ("limit" = Option<u32>, Query, description = "Most hits to return, 1-50 (default 20)"),
+    ),
+    responses(
+        (status = 200, description = "Matching items, possibly none", body = inline(SuccessResponse<QuickSearchResponse>)),
+        (status = 400, description = "Search term too short or too long, or invalid limit", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/admin/quick-search")]
+pub async fn quick_search_handler(
+    admin: AdminUser,
+    params: web::Query<QuickSearchParams>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let command = match QuickSearchCommand::new(&params.q, params.limit) {
+        Ok(command) => command,
+        Err(err) => return INVALID_QUICK_SEARCH.with_message(&err.to_string()),
+    };
+
+    match data.quick_search_use_case.execute(command).await {
+        Ok(items) => ApiResponse::success(QuickSearchResponse { items }),
+        Err(QuickSearchError::InvalidQuery(msg)) => INVALID_QUICK_SEARCH.with_message(&msg),
+        Err(QuickSearchError::QueryFailed(msg)) => {
+            error!(admin = %admin.user_id, "Quick search failed: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, auth_helper::test_helpers::create_test_jwt_service,
+        stubs::StubQuickSearchUseCase,
+    };
+
+    async fn call(search: StubQuickSearchUseCase, uri: &str) -> actix_web::dev::ServiceResponse {
+        let admin_id = Uuid::new_v4();
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(admin_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin_id])
+            .with_quick_search(search)
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(quick_search_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_hits_carry_typed_links() {
+        let resp = call(
+            StubQuickSearchUseCase::with_user(),
+            "/api/admin/quick-search?q=ada%40",
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        let hit = &body["data"]["items"][0];
+        assert_eq!(hit["matched"], "email");
+        assert_eq!(hit["link"]["type"], "user");
+        assert_eq!(hit["link"]["username"], "ada");
+        assert!(hit["link"]["id"].is_string());
+    }
+
+    #[actix_web::test]
+    async fn test_short_term_is_rejected() {
+        let resp = call(
+            StubQuickSearchUseCase::with_user(),
+            "/api/admin/quick-search?q=a",
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "INVALID_QUICK_SEARCH");
+    }
+
+    #[actix_web::test]
+    async fn test_failed_search_is_internal_error() {
+        let resp = call(
+            StubQuickSearchUseCase::failure(QuickSearchError::QueryFailed("db down".to_string())),
+            "/api/admin/quick-search?q=port",
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
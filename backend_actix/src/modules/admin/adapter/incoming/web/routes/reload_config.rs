@@ -0,0 +1,107 @@
+use actix_web::{post, web, Responder};
+use serde::Serialize;
+use tracing::{info, warn};
+use utoipa::ToSchema;
+
+use crate::admin::adapter::incoming::web::error_codes::CONFIG_INVALID;
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::AdminUser, shared::api::ApiResponse, AppState,
+};
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReloadConfigResponse {
+    /// Keys whose new value is now in effect, e.g. `CONTACT_RATE_LIMIT`
+    pub changed: Vec<&'static str>,
+}
+
+/// Read the configuration again and apply what can change without a restart
+///
+/// Same as sending the server `SIGHUP`. Applies `LOG_FILTER`,
+/// `CONTACT_RATE_LIMIT` and `UPLOAD_URL_RATE_LIMIT` on this instance; other
+/// settings still need a restart. A configuration that would not start the
+/// server is rejected as a whole and nothing changes.
+#[utoipa::path(
+    post,
+    path = "/api/admin/config/reload",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Configuration applied", body = inline(SuccessResponse<ReloadConfigResponse>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 422, description = "The configuration is invalid (`CONFIG_INVALID`)", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/admin/config/reload")]
+pub async fn reload_config_handler(admin: AdminUser, data: web::Data<AppState>) -> impl Responder {
+    match data.config_reloader.reload() {
+        Ok(changed) => {
+            info!(admin = %admin.user_id, ?changed, "Configuration reloaded");
+            ApiResponse::success(ReloadConfigResponse { changed })
+        }
+        Err(e) => {
+            warn!(admin = %admin.user_id, error = %e, "Configuration reload failed");
+            CONFIG_INVALID.with_message(&e.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+    use crate::config::reload::ConfigReloader;
+    use crate::config::ConfigError;
+    use crate::shared::rate_limit::RateLimiter;
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, auth_helper::test_helpers::create_test_jwt_service,
+    };
+
+    async fn call(reloader: ConfigReloader) -> actix_web::dev::ServiceResponse {
+        let admin_id = Uuid::new_v4();
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(admin_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin_id])
+            .with_config_reloader(reloader)
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(reload_config_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/admin/config/reload")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_failed_reload_is_unprocessable() {
+        let reloader = ConfigReloader::new(
+            Arc::new(|| Err(ConfigError::Invalid(vec!["PORT must be a number".into()]))),
+            RateLimiter::disabled(),
+            RateLimiter::disabled(),
+            None,
+        );
+
+        let resp = call(reloader).await;
+
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "CONFIG_INVALID");
+    }
+}
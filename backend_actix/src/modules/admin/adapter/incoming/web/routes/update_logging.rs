@@ -0,0 +1,135 @@
+use actix_web::{put, web, Responder};
+use serde::Deserialize;
+use tracing::{error, info};
+use utoipa::ToSchema;
+
+use super::LoggingResponse;
+use crate::admin::adapter::incoming::web::error_codes::{INVALID_LOG_FILTER, LOGGING_UNAVAILABLE};
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    shared::{api::ApiResponse, log_filter, telemetry::LogFormat},
+    AppState,
+};
+
+/// Omitted fields are kept
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateLoggingRequest {
+    /// `RUST_LOG` directives, e.g. `info,backend_actix::modules::pages=debug`
+    pub filter: Option<String>,
+    pub format: Option<LogFormat>,
+}
+
+/// Change the log filter or format of this instance without a restart
+///
+/// Lasts until the next configuration reload (`SIGHUP` or
+/// `POST /api/admin/config/reload`) re-applies `LOG_FILTER`, or the next
+/// restart. Other instances are not affected.
+#[utoipa::path(
+    put,
+    path = "/api/admin/logging",
+    tag = "admin",
+    request_body = UpdateLoggingRequest,
+    responses(
+        (status = 200, description = "Logging changed", body = inline(SuccessResponse<LoggingResponse>)),
+        (status = 400, description = "Invalid filter directives (`INVALID_LOG_FILTER`) or format", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 503, description = "Logging can't be changed on this instance", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[put("/api/admin/logging")]
+pub async fn update_logging_handler(
+    admin: AdminUser,
+    payload: web::Json<UpdateLoggingRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let request = payload.into_inner();
+    if let Some(Err(e)) = request.filter.as_deref().map(log_filter::parse) {
+        return INVALID_LOG_FILTER.with_message(&format!("Invalid log filter: {e}"));
+    }
+    let Some(logging) = data.config_reloader.log_filter() else {
+        return LOGGING_UNAVAILABLE.response();
+    };
+
+    let applied = request
+        .filter
+        .as_deref()
+        .map_or(Ok(()), |directives| logging.set(directives))
+        .and_then(|()| request.format.map_or(Ok(()), |f| logging.set_format(f)));
+    if let Err(e) = applied {
+        error!(admin = %admin.user_id, error = %e, "Failed to change logging");
+        return ApiResponse::internal_error();
+    }
+
+    let current = LoggingResponse::from(logging);
+    info!(
+        admin = %admin.user_id,
+        filter = %current.filter,
+        format = ?current.format,
+        "Logging changed"
+    );
+    ApiResponse::success(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::json;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, auth_helper::test_helpers::create_test_jwt_service,
+    };
+
+    // The test state has no log filter: installing one would take over the
+    // process-wide subscriber
+    async fn call(body: serde_json::Value) -> actix_web::dev::ServiceResponse {
+        let admin_id = Uuid::new_v4();
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(admin_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin_id])
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(update_logging_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::put()
+            .uri("/api/admin/logging")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(body)
+            .to_request();
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_invalid_filter_is_rejected() {
+        let resp = call(json!({ "filter": "info,sqlx::query=loud" })).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "INVALID_LOG_FILTER");
+    }
+
+    #[actix_web::test]
+    async fn test_instance_without_log_filter_is_unavailable() {
+        let resp = call(json!({ "format": "json" })).await;
+
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "LOGGING_UNAVAILABLE");
+    }
+}
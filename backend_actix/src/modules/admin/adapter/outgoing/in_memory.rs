@@ -0,0 +1,469 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::admin::application::ports::incoming::use_cases::{
+    MatchedField, QuickLink, QuickSearchCommand, QuickSearchHit, StorageGroup,
+};
+use crate::admin::application::ports::outgoing::{
+    HighlightedProjectRef, IntegrityQuery, IntegrityQueryError, MediaCounts, OrphanedAttachment,
+    QuickSearchQuery, QuickSearchQueryError, SlugHolder, StatsQuery, StatsQueryError,
+    StorageGrouping, StorageReportQuery, StorageReportQueryError, StorageUsage, UserCounts,
+    VariantObject,
+};
+use crate::auth::adapter::outgoing::in_memory::InMemoryUserStore;
+use crate::cv::adapter::outgoing::InMemoryCvStore;
+use crate::multimedia::adapter::outgoing::db::InMemoryMediaStore;
+use crate::multimedia::application::domain::entities::MediaState;
+use crate::pages::adapter::outgoing::InMemoryPageStore;
+use crate::project::adapter::outgoing::InMemoryProjectStore;
+
+/// `StatsQuery` over the other modules' in-memory stores
+#[derive(Clone)]
+pub struct InMemoryStatsQuery {
+    users: InMemoryUserStore,
+    projects: InMemoryProjectStore,
+    media: InMemoryMediaStore,
+}
+
+impl InMemoryStatsQuery {
+    pub fn new(
+        users: InMemoryUserStore,
+        projects: InMemoryProjectStore,
+        media: InMemoryMediaStore,
+    ) -> Self {
+        Self {
+            users,
+            projects,
+            media,
+        }
+    }
+}
+
+#[async_trait]
+impl StatsQuery for InMemoryStatsQuery {
+    async fn count_users(&self) -> Result<UserCounts, StatsQueryError> {
+        Ok(self.users.users.read(|users| {
+            let live = users.iter().filter(|user| !user.is_deleted);
+            UserCounts {
+                total: live.clone().count() as u64,
+                verified: live.filter(|user| user.is_verified).count() as u64,
+            }
+        }))
+    }
+
+    async fn count_published_projects(&self) -> Result<u64, StatsQueryError> {
+        Ok(self.projects.projects.read(|projects| {
+            projects
+                .iter()
+                .filter(|row| row.deleted_at.is_none())
+                .count() as u64
+        }))
+    }
+
+    async fn media_counts(&self) -> Result<MediaCounts, StatsQueryError> {
+        Ok(self.media.media.read(|media| {
+            let mut counts = MediaCounts::default();
+            for row in media.iter().filter(|row| row.deleted_at.is_none()) {
+                match row.state {
+                    MediaState::Pending => counts.pending += 1,
+                    MediaState::Processing => counts.processing += 1,
+                    MediaState::Ready => counts.ready += 1,
+                    MediaState::Failed => counts.failed += 1,
+                }
+                counts.storage_bytes += row.file_size_bytes
+                    + row
+                        .variants
+                        .iter()
+                        .map(|variant| variant.file_size_bytes)
+                        .sum::<u64>();
+            }
+            counts
+        }))
+    }
+
+    async fn count_failed_media_since(&self, since: DateTime<Utc>) -> Result<u64, StatsQueryError> {
+        Ok(self.media.media.read(|media| {
+            media
+                .iter()
+                .filter(|row| row.deleted_at.is_none())
+                .filter(|row| matches!(row.state, MediaState::Failed) && row.updated_at >= since)
+                .count() as u64
+        }))
+    }
+}
+
+/// `IntegrityQuery` over the other modules' in-memory stores. Attachments
+/// live on their media row there, so none can be orphaned.
+#[derive(Clone)]
+pub struct InMemoryIntegrityQuery {
+    cvs: InMemoryCvStore,
+    projects: InMemoryProjectStore,
+    media: InMemoryMediaStore,
+    pages: InMemoryPageStore,
+}
+
+impl InMemoryIntegrityQuery {
+    pub fn new(
+        cvs: InMemoryCvStore,
+        projects: InMemoryProjectStore,
+        media: InMemoryMediaStore,
+        pages: InMemoryPageStore,
+    ) -> Self {
+        Self {
+            cvs,
+            projects,
+            media,
+            pages,
+        }
+    }
+}
+
+/// Holders sharing a lowercase slug within the same `scope`, oldest first
+fn colliding(mut holders: Vec<(Option<Uuid>, SlugHolder)>) -> Vec<SlugHolder> {
+    let mut counts: HashMap<(Option<Uuid>, String), usize> = HashMap::new();
+    for (scope, holder) in &holders {
+        *counts
+            .entry((*scope, holder.slug.to_lowercase()))
+            .or_default() += 1;
+    }
+
+    holders.retain(|(scope, holder)| counts[&(*scope, holder.slug.to_lowercase())] > 1);
+    holders.sort_by_key(|(_, holder)| (holder.created_at, holder.id));
+    holders.into_iter().map(|(_, holder)| holder).collect()
+}
+
+#[async_trait]
+impl IntegrityQuery for InMemoryIntegrityQuery {
+    async fn highlighted_projects(
+        &self,
+    ) -> Result<Vec<HighlightedProjectRef>, IntegrityQueryError> {
+        Ok(self.cvs.cvs.read(|cvs| {
+            cvs.iter()
+                .filter(|row| row.deleted_at.is_none())
+                .flat_map(|row| {
+                    row.cv
+                        .highlighted_projects
+                        .iter()
+                        .map(|project| HighlightedProjectRef {
+                            cv_id: row.cv.id,
+                            owner_id: row.cv.user_id,
+                            project_id: project.id.clone(),
+                        })
+                })
+                .collect()
+        }))
+    }
+
+    async fn project_states(
+        &self,
+        ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, bool>, IntegrityQueryError> {
+        Ok(self.projects.projects.read(|projects| {
+            projects
+                .iter()
+                .filter(|row| ids.contains(&row.project.id))
+                .map(|row| (row.project.id, row.deleted_at.is_some()))
+                .collect()
+        }))
+    }
+
+    async fn orphaned_attachments(&self) -> Result<Vec<OrphanedAttachment>, IntegrityQueryError> {
+        Ok(vec![])
+    }
+
+    async fn variant_objects(&self) -> Result<Vec<VariantObject>, IntegrityQueryError> {
+        Ok(self.media.media.read(|media| {
+            media
+                .iter()
+                .filter(|row| row.deleted_at.is_none())
+                .flat_map(|row| {
+                    row.variants.iter().map(|variant| VariantObject {
+                        media_id: row.media_id,
+                        owner_id: row.owner.value(),
+                        size: variant.size.to_string(),
+                        bucket_name: variant.bucket_name.clone(),
+                        object_key: variant.object_name.clone(),
+                    })
+                })
+                .collect()
+        }))
+    }
+
+    async fn colliding_project_slugs(&self) -> Result<Vec<SlugHolder>, IntegrityQueryError> {
+        Ok(colliding(self.projects.projects.read(|projects| {
+            projects
+                .iter()
+                .map(|row| {
+                    let holder = SlugHolder {
+                        id: row.project.id,
+                        owner_id: row.project.owner.value(),
+                        slug: row.project.slug.clone(),
+                        created_at: row.project.created_at,
+                    };
+                    (None, holder)
+                })
+                .collect()
+        })))
+    }
+
+    async fn colliding_page_slugs(&self) -> Result<Vec<SlugHolder>, IntegrityQueryError> {
+        Ok(colliding(self.pages.pages.read(|pages| {
+            pages
+                .iter()
+                .map(|page| {
+                    let holder = SlugHolder {
+                        id: page.id,
+                        owner_id: page.owner_id,
+                        slug: page.slug.clone(),
+                        created_at: page.created_at,
+                    };
+                    (Some(page.owner_id), holder)
+                })
+                .collect()
+        })))
+    }
+}
+
+/// `QuickSearchQuery` over the other modules' in-memory stores
+#[derive(Clone)]
+pub struct InMemoryQuickSearchQuery {
+    users: InMemoryUserStore,
+    cvs: InMemoryCvStore,
+    projects: InMemoryProjectStore,
+    pages: InMemoryPageStore,
+    media: InMemoryMediaStore,
+}
+
+impl InMemoryQuickSearchQuery {
+    pub fn new(
+        users: InMemoryUserStore,
+        cvs: InMemoryCvStore,
+        projects: InMemoryProjectStore,
+        pages: InMemoryPageStore,
+        media: InMemoryMediaStore,
+    ) -> Self {
+        Self {
+            users,
+            cvs,
+            projects,
+            pages,
+            media,
+        }
+    }
+}
+
+/// How `value` matches the term: 1 exactly, 2 by prefix, as in the
+/// Postgres query's `rank`
+fn text_rank(command: &QuickSearchCommand, value: &str) -> Option<u8> {
+    let value = value.to_lowercase();
+    if value == command.text() {
+        Some(1)
+    } else if value.starts_with(command.text()) {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+fn slug_hit(command: &QuickSearchCommand, id: Uuid, slug: &str) -> Option<(u8, MatchedField)> {
+    if command.id() == Some(id) {
+        Some((0, MatchedField::Id))
+    } else {
+        text_rank(command, slug).map(|rank| (rank, MatchedField::Slug))
+    }
+}
+
+#[async_trait]
+impl QuickSearchQuery for InMemoryQuickSearchQuery {
+    async fn search(
+        &self,
+        command: &QuickSearchCommand,
+    ) -> Result<Vec<QuickSearchHit>, QuickSearchQueryError> {
+        let id_hit = |id: Uuid| (command.id() == Some(id)).then_some((0, MatchedField::Id));
+        let mut hits: Vec<(u8, &'static str, QuickSearchHit)> = Vec::new();
+
+        self.users.users.read(|users| {
+            for user in users {
+                let hit = id_hit(user.id).or_else(|| {
+                    let email = text_rank(command, &user.email);
+                    let username = text_rank(command, &user.username);
+                    match (email, username) {
+                        (Some(e), Some(u)) => Some((e.min(u), MatchedField::Email)),
+                        (Some(e), None) => Some((e, MatchedField::Email)),
+                        (None, Some(u)) => Some((u, MatchedField::Username)),
+                        (None, None) => None,
+                    }
+                });
+                if let Some((rank, matched)) = hit {
+                    hits.push((
+                        rank,
+                        "user",
+                        QuickSearchHit {
+                            label: user.username.clone(),
+                            detail: Some(user.email.clone()),
+                            matched,
+                            deleted: user.is_deleted,
+                            link: QuickLink::User {
+                                id: user.id,
+                                username: user.username.clone(),
+                            },
+                        },
+                    ));
+                }
+            }
+        });
+
+        self.cvs.cvs.read(|cvs| {
+            for row in cvs.iter().filter(|row| command.id() == Some(row.cv.id)) {
+                hits.push((
+                    0,
+                    "cv",
+                    QuickSearchHit {
+                        label: row.cv.display_name.clone(),
+                        detail: Some(row.cv.role.clone()),
+                        matched: MatchedField::Id,
+                        deleted: row.deleted_at.is_some(),
+                        link: QuickLink::Cv {
+                            id: row.cv.id,
+                            owner_id: row.cv.user_id,
+                        },
+                    },
+                ));
+            }
+        });
+
+        self.projects.projects.read(|projects| {
+            for row in projects {
+                let project = &row.project;
+                if let Some((rank, matched)) = slug_hit(command, project.id, &project.slug) {
+                    hits.push((
+                        rank,
+                        "project",
+                        QuickSearchHit {
+                            label: project.title.clone(),
+                            detail: Some(project.slug.clone()),
+                            matched,
+                            deleted: row.deleted_at.is_some(),
+                            link: QuickLink::Project {
+                                id: project.id,
+                                owner_id: project.owner.value(),
+                                slug: project.slug.clone(),
+                            },
+                        },
+                    ));
+                }
+            }
+        });
+
+        self.pages.pages.read(|pages| {
+            for page in pages {
+                if let Some((rank, matched)) = slug_hit(command, page.id, &page.slug) {
+                    hits.push((
+                        rank,
+                        "page",
+                        QuickSearchHit {
+                            label: page.title.clone(),
+                            detail: Some(page.slug.clone()),
+                            matched,
+                            deleted: false,
+                            link: QuickLink::Page {
+                                id: page.id,
+                                owner_id: page.owner_id,
+                                slug: page.slug.clone(),
+                            },
+                        },
+                    ));
+                }
+            }
+        });
+
+        self.media.media.read(|media| {
+            for row in media
+                .iter()
+                .filter(|row| command.id() == Some(row.media_id))
+            {
+                hits.push((
+                    0,
+                    "media",
+                    QuickSearchHit {
+                        label: row.original_name.clone(),
+                        detail: None,
+                        matched: MatchedField::Id,
+                        deleted: row.deleted_at.is_some(),
+                        link: QuickLink::Media {
+                            id: row.media_id,
+                            owner_id: row.owner.value(),
+                        },
+                    },
+                ));
+            }
+        });
+
+        hits.sort_by(|(a_rank, a_kind, a), (b_rank, b_kind, b)| {
+            (a_rank, a_kind, &a.label).cmp(&(b_rank, b_kind, &b.label))
+        });
+        Ok(hits
+            .into_iter()
+            .take(command.limit() as usize)
+            .map(|(_, _, hit)| hit)
+            .collect())
+    }
+}
+
+/// `StorageReportQuery` over the in-memory media store. Variants there carry
+/// no timestamp of their own, so the media row's last update stands in.
+#[derive(Clone)]
+pub struct InMemoryStorageReportQuery {
+    media: InMemoryMediaStore,
+}
+
+impl InMemoryStorageReportQuery {
+    pub fn new(media: InMemoryMediaStore) -> Self {
+        Self { media }
+    }
+}
+
+#[async_trait]
+impl StorageReportQuery for InMemoryStorageReportQuery {
+    async fn usage_by(
+        &self,
+        grouping: StorageGrouping,
+        this_month: DateTime<Utc>,
+        last_month: DateTime<Utc>,
+        limit: Option<u32>,
+    ) -> Result<Vec<StorageGroup>, StorageReportQueryError> {
+        let mut usage: HashMap<String, StorageUsage> = HashMap::new();
+        self.media.media.read(|media| {
+            for row in media.iter() {
+                for variant in &row.variants {
+                    let key = match grouping {
+                        StorageGrouping::Bucket => variant.bucket_name.clone(),
+                        StorageGrouping::Size => variant.size.to_string(),
+                        StorageGrouping::Format => variant.mime_type.clone(),
+                        StorageGrouping::Owner => row.owner.value().to_string(),
+                    };
+                    let group = usage.entry(key).or_default();
+                    group.objects += 1;
+                    group.bytes += variant.file_size_bytes;
+                    if row.updated_at >= this_month {
+                        group.bytes_added_this_month += variant.file_size_bytes;
+                    } else if row.updated_at >= last_month {
+                        group.bytes_added_last_month += variant.file_size_bytes;
+                    }
+                }
+            }
+        });
+
+        let mut groups: Vec<StorageGroup> = usage
+            .into_iter()
+            .map(|(key, usage)| StorageGroup { key, usage })
+            .collect();
+        groups.sort_by(|a, b| b.usage.bytes.cmp(&a.usage.bytes).then(a.key.cmp(&b.key)));
+        if let Some(limit) = limit {
+            groups.truncate(limit as usize);
+        }
+        Ok(groups)
+    }
+}
@@ -0,0 +1,304 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement, Value,
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::admin::application::ports::outgoing::{
+    HighlightedProjectRef, IntegrityQuery, IntegrityQueryError, OrphanedAttachment, SlugHolder,
+    VariantObject,
+};
+
+/// The part of a `highlighted_projects` entry the check needs
+#[derive(Deserialize)]
+struct Highlight {
+    #[serde(default)]
+    id: String,
+}
+
+#[derive(Clone)]
+pub struct IntegrityQueryPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl IntegrityQueryPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    fn highlighted_projects_stmt() -> Statement {
+        Statement::from_string(
+            DatabaseBackend::Postgres,
+            r#"
+            SELECT id, user_id, highlighted_projects
+            FROM resumes
+            WHERE is_deleted = false
+            "#,
+        )
+    }
+
+    /// `ids` is never empty
+    fn project_states_stmt(ids: &[Uuid]) -> Statement {
+        let placeholders = (1..=ids.len())
+            .map(|i| format!("${i}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!("SELECT id, is_deleted FROM projects WHERE id IN ({placeholders})"),
+            ids.iter().map(|id| Value::from(*id)),
+        )
+    }
+
+    /// The foreign key cascades deletes, so only rows written around it
+    /// show up, e.g. on SQLite without `foreign_keys`
+    fn orphaned_attachments_stmt() -> Statement {
+        Statement::from_string(
+            DatabaseBackend::Postgres,
+            r#"
+            SELECT a.id, a.media_id, a.attachable_type, a.attachable_id
+            FROM media_attachments a
+            LEFT JOIN media m ON m.id = a.media_id
+            WHERE m.id IS NULL
+            "#,
+        )
+    }
+
+    fn variant_objects_stmt() -> Statement {
+        Statement::from_string(
+            DatabaseBackend::Postgres,
+            r#"
+            SELECT
+                v.media_id,
+                m.user_id,
+                CAST(v.variant_type AS TEXT) AS variant_type,
+                v.bucket_name,
+                v.object_key
+            FROM media_variants v
+            INNER JOIN media m ON m.id = v.media_id
+            WHERE m.deleted_at IS NULL
+            ORDER BY v.media_id, v.variant_type
+            "#,
+        )
+    }
+
+    /// Trashed projects included, like `idx_projects_slug_unique`
+    fn colliding_project_slugs_stmt() -> Statement {
+        Statement::from_string(
+            DatabaseBackend::Postgres,
+            r#"
+            SELECT id, user_id, slug, created_at
+            FROM projects
+            WHERE lower(slug) IN (
+                SELECT lower(slug) FROM projects GROUP BY lower(slug) HAVING COUNT(*) > 1
+            )
+            ORDER BY created_at, id
+            "#,
+        )
+    }
+
+    fn colliding_page_slugs_stmt() -> Statement {
+        Statement::from_string(
+            DatabaseBackend::Postgres,
+            r#"
+            SELECT p.id, p.user_id, p.slug, p.created_at
+            FROM pages p
+            WHERE EXISTS (
+                SELECT 1 FROM pages o
+                WHERE o.user_id = p.user_id
+                  AND lower(o.slug) = lower(p.slug)
+                  AND o.id <> p.id
+            )
+            ORDER BY p.created_at, p.id
+            "#,
+        )
+    }
+
+    // =====================================================
+    // Helpers
+    // =====================================================
+
+    async fn rows(&self, stmt: Statement) -> Result<Vec<QueryResult>, IntegrityQueryError> {
+        self.db.query_all(stmt).await.map_err(Self::map_db_err)
+    }
+
+    fn to_slug_holder(row: &QueryResult) -> Result<SlugHolder, DbErr> {
+        Ok(SlugHolder {
+            id: row.try_get("", "id")?,
+            owner_id: row.try_get("", "user_id")?,
+            slug: row.try_get("", "slug")?,
+            created_at: row.try_get("", "created_at")?,
+        })
+    }
+
+    fn map_db_err(e: DbErr) -> IntegrityQueryError {
+        IntegrityQueryError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl IntegrityQuery for IntegrityQueryPostgres {
+    async fn highlighted_projects(
+        &self,
+    ) -> Result<Vec<HighlightedProjectRef>, IntegrityQueryError> {
+        let mut refs = Vec::new();
+        for row in self.rows(Self::highlighted_projects_stmt()).await? {
+            let cv_id: Uuid = row.try_get("", "id").map_err(Self::map_db_err)?;
+            let owner_id: Uuid = row.try_get("", "user_id").map_err(Self::map_db_err)?;
+            let value: serde_json::Value = row
+                .try_get("", "highlighted_projects")
+                .map_err(Self::map_db_err)?;
+            let highlights: Vec<Highlight> = serde_json::from_value(value).map_err(|e| {
+                IntegrityQueryError::DatabaseError(format!("highlighted_projects of {cv_id}: {e}"))
+            })?;
+
+            refs.extend(highlights.into_iter().map(|h| HighlightedProjectRef {
+                cv_id,
+                owner_id,
+                project_id: h.id,
+            }));
+        }
+        Ok(refs)
+    }
+
+    async fn project_states(
+        &self,
+        ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, bool>, IntegrityQueryError> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        self.rows(Self::project_states_stmt(ids))
+            .await?
+            .iter()
+            .map(|row| Ok((row.try_get("", "id")?, row.try_get("", "is_deleted")?)))
+            .collect::<Result<_, DbErr>>()
+            .map_err(Self::map_db_err)
+    }
+
+    async fn orphaned_attachments(&self) -> Result<Vec<OrphanedAttachment>, IntegrityQueryError> {
+        self.rows(Self::orphaned_attachments_stmt())
+            .await?
+            .iter()
+            .map(|row| {
+                Ok(OrphanedAttachment {
+                    attachment_id: row.try_get("", "id")?,
+                    media_id: row.try_get("", "media_id")?,
+                    attachable_type: row.try_get("", "attachable_type")?,
+                    attachable_id: row.try_get("", "attachable_id")?,
+                })
+            })
+            .collect::<Result<_, DbErr>>()
+            .map_err(Self::map_db_err)
+    }
+
+    async fn variant_objects(&self) -> Result<Vec<VariantObject>, IntegrityQueryError> {
+        self.rows(Self::variant_objects_stmt())
+            .await?
+            .iter()
+            .map(|row| {
+                Ok(VariantObject {
+                    media_id: row.try_get("", "media_id")?,
+                    owner_id: row.try_get("", "user_id")?,
+                    size: row.try_get("", "variant_type")?,
+                    bucket_name: row.try_get("", "bucket_name")?,
+                    object_key: row.try_get("", "object_key")?,
+                })
+            })
+            .collect::<Result<_, DbErr>>()
+            .map_err(Self::map_db_err)
+    }
+
+    async fn colliding_project_slugs(&self) -> Result<Vec<SlugHolder>, IntegrityQueryError> {
+        self.rows(Self::colliding_project_slugs_stmt())
+            .await?
+            .iter()
+            .map(Self::to_slug_holder)
+            .collect::<Result<_, DbErr>>()
+            .map_err(Self::map_db_err)
+    }
+
+    async fn colliding_page_slugs(&self) -> Result<Vec<SlugHolder>, IntegrityQueryError> {
+        self.rows(Self::colliding_page_slugs_stmt())
+            .await?
+            .iter()
+            .map(Self::to_slug_holder)
+            .collect::<Result<_, DbErr>>()
+            .map_err(Self::map_db_err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::MockDatabase;
+    use std::collections::BTreeMap;
+
+    fn make_row(data: Vec<(&str, Value)>) -> BTreeMap<String, Value> {
+        data.into_iter().map(|(k, v)| (k.to_string(), v)).collect()
+    }
+
+    #[tokio::test]
+    async fn test_highlighted_projects_are_read_from_the_cv_json() {
+        let (cv_id, owner_id, project_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let highlights = serde_json::json!([
+            { "id": project_id.to_string(), "title": "Portfolio", "slug": "portfolio", "short_description": "" },
+            { "title": "Untitled" }
+        ]);
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![make_row(vec![
+                ("id", cv_id.into()),
+                ("user_id", owner_id.into()),
+                ("highlighted_projects", highlights.into()),
+            ])]])
+            .into_connection();
+
+        let query = IntegrityQueryPostgres::new(Arc::new(db));
+        let refs = query.highlighted_projects().await.unwrap();
+
+        assert_eq!(refs.len(), 2);
+        assert_eq!(refs[0].cv_id, cv_id);
+        assert_eq!(refs[0].project_id, project_id.to_string());
+        assert_eq!(refs[1].project_id, "");
+    }
+
+    #[tokio::test]
+    async fn test_project_states_without_ids_skip_the_query() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+
+        let query = IntegrityQueryPostgres::new(Arc::new(db));
+
+        assert!(query.project_states(&[]).await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_project_slugs_compare_lowercase_including_trash() {
+        let stmt = IntegrityQueryPostgres::colliding_project_slugs_stmt();
+
+        assert!(stmt.sql.contains("GROUP BY lower(slug)"));
+        assert!(!stmt.sql.contains("is_deleted"));
+    }
+
+    #[tokio::test]
+    async fn test_database_error_is_mapped() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_errors(vec![DbErr::Custom("connection lost".into())])
+            .into_connection();
+
+        let query = IntegrityQueryPostgres::new(Arc::new(db));
+        let err = query.colliding_page_slugs().await.unwrap_err();
+
+        match err {
+            IntegrityQueryError::DatabaseError(msg) => assert!(msg.contains("connection lost")),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+mod in_memory;
+mod integrity_query_postgres;
+mod quick_search_query_postgres;
+mod stats_query_postgres;
+mod storage_report_query_postgres;
+
+pub use in_memory::{
+    InMemoryIntegrityQuery, InMemoryQuickSearchQuery, InMemoryStatsQuery,
+    InMemoryStorageReportQuery,
+};
+pub use integrity_query_postgres::IntegrityQueryPostgres;
+pub use quick_search_query_postgres::QuickSearchQueryPostgres;
+pub use stats_query_postgres::StatsQueryPostgres;
+pub use storage_report_query_postgres::StorageReportQueryPostgres;
@@ -0,0 +1,242 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement,
+};
+use std::sync::Arc;
+
+use crate::admin::application::ports::incoming::use_cases::{
+    MatchedField, QuickLink, QuickSearchCommand, QuickSearchHit,
+};
+use crate::admin::application::ports::outgoing::{QuickSearchQuery, QuickSearchQueryError};
+use crate::shared::sql;
+
+#[derive(Clone)]
+pub struct QuickSearchQueryPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl QuickSearchQueryPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    /// `LIKE` pattern matching values that start with `text`
+    fn prefix_pattern(text: &str) -> String {
+        format!("{}%", sql::escape_like(text))
+    }
+
+    /// `$1` is the term as an id (NULL when it isn't one), `$2` the term and
+    /// `$3` its prefix pattern. `rank` puts id matches first, then exact
+    /// slugs, emails and usernames.
+    fn search_stmt(command: &QuickSearchCommand) -> Statement {
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"
+            SELECT kind, id, owner_id, label, detail, matched, deleted
+            FROM (
+                SELECT 'user' AS kind, id, id AS owner_id, username AS label,
+                       email AS detail,
+                       CASE WHEN id = $1 THEN 'id'
+                            WHEN lower(email) LIKE $3 ESCAPE '\' THEN 'email'
+                            ELSE 'username' END AS matched,
+                       CASE WHEN id = $1 THEN 0
+                            WHEN lower(email) = $2 OR lower(username) = $2 THEN 1
+                            ELSE 2 END AS rank,
+                       is_deleted AS deleted
+                FROM users
+                WHERE id = $1
+                   OR lower(email) LIKE $3 ESCAPE '\'
+                   OR lower(username) LIKE $3 ESCAPE '\'
+                UNION ALL
+                SELECT 'cv', id, user_id, display_name, role, 'id', 0, is_deleted
+                FROM resumes
+                WHERE id = $1
+                UNION ALL
+                SELECT 'project', id, user_id, title, slug,
+                       CASE WHEN id = $1 THEN 'id' ELSE 'slug' END,
+                       CASE WHEN id = $1 THEN 0 WHEN lower(slug) = $2 THEN 1 ELSE 2 END,
+                       is_deleted
+                FROM projects
+                WHERE id = $1 OR lower(slug) LIKE $3 ESCAPE '\'
+                UNION ALL
+                SELECT 'page', id, user_id, title, slug,
+                       CASE WHEN id = $1 THEN 'id' ELSE 'slug' END,
+                       CASE WHEN id = $1 THEN 0 WHEN lower(slug) = $2 THEN 1 ELSE 2 END,
+                       false
+                FROM pages
+                WHERE id = $1 OR lower(slug) LIKE $3 ESCAPE '\'
+                UNION ALL
+                SELECT 'media', id, user_id, original_filename, CAST(NULL AS TEXT), 'id', 0,
+                       deleted_at IS NOT NULL
+                FROM media
+                WHERE id = $1
+            ) hits
+            ORDER BY rank, kind, label, id
+            LIMIT $4
+            "#,
+            vec![
+                command.id().into(),
+                command.text().into(),
+                Self::prefix_pattern(command.text()).into(),
+                (command.limit() as i64).into(),
+            ],
+        )
+    }
+
+    // =====================================================
+    // Mapping
+    // =====================================================
+
+    fn to_hit(row: &QueryResult) -> Result<QuickSearchHit, QuickSearchQueryError> {
+        let kind: String = row.try_get("", "kind").map_err(Self::map_db_err)?;
+        let matched: String = row.try_get("", "matched").map_err(Self::map_db_err)?;
+        let id = row.try_get("", "id").map_err(Self::map_db_err)?;
+        let owner_id = row.try_get("", "owner_id").map_err(Self::map_db_err)?;
+        let label: String = row.try_get("", "label").map_err(Self::map_db_err)?;
+        let detail: Option<String> = row.try_get("", "detail").map_err(Self::map_db_err)?;
+
+        let link = match kind.as_str() {
+            "user" => QuickLink::User {
+                id,
+                username: label.clone(),
+            },
+            "cv" => QuickLink::Cv { id, owner_id },
+            "project" => QuickLink::Project {
+                id,
+                owner_id,
+                slug: detail.clone().unwrap_or_default(),
+            },
+            "page" => QuickLink::Page {
+                id,
+                owner_id,
+                slug: detail.clone().unwrap_or_default(),
+            },
+            "media" => QuickLink::Media { id, owner_id },
+            other => {
+                return Err(QuickSearchQueryError::DatabaseError(format!(
+                    "unknown quick search kind '{other}'"
+                )))
+            }
+        };
+        let matched = match matched.as_str() {
+            "id" => MatchedField::Id,
+            "slug" => MatchedField::Slug,
+            "email" => MatchedField::Email,
+            _ => MatchedField::Username,
+        };
+
+        Ok(QuickSearchHit {
+            label,
+            detail,
+            matched,
+            deleted: row.try_get("", "deleted").map_err(Self::map_db_err)?,
+            link,
+        })
+    }
+
+    fn map_db_err(e: DbErr) -> QuickSearchQueryError {
+        QuickSearchQueryError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl QuickSearchQuery for QuickSearchQueryPostgres {
+    async fn search(
+        &self,
+        command: &QuickSearchCommand,
+    ) -> Result<Vec<QuickSearchHit>, QuickSearchQueryError> {
+        self.db
+            .query_all(Self::search_stmt(command))
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(Self::to_hit)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{MockDatabase, Value};
+    use std::collections::BTreeMap;
+    use uuid::Uuid;
+
+    fn row(
+        kind: &str,
+        id: Uuid,
+        owner_id: Uuid,
+        detail: Option<&str>,
+        matched: &str,
+    ) -> BTreeMap<String, Value> {
+        BTreeMap::from([
+            ("kind".to_string(), Value::from(kind)),
+            ("id".to_string(), Value::from(id)),
+            ("owner_id".to_string(), Value::from(owner_id)),
+            ("label".to_string(), Value::from("Port CMS")),
+            (
+                "detail".to_string(),
+                Value::from(detail.map(str::to_string)),
+            ),
+            ("matched".to_string(), Value::from(matched)),
+            ("deleted".to_string(), Value::from(false)),
+        ])
+    }
+
+    #[test]
+    fn test_prefix_pattern_escapes_wildcards() {
+        assert_eq!(
+            QuickSearchQueryPostgres::prefix_pattern("a_b%c\\"),
+            "a\\_b\\%c\\\\%"
+        );
+    }
+
+    #[test]
+    fn test_search_is_one_statement_over_every_table() {
+        let id = Uuid::new_v4();
+        let command = QuickSearchCommand::new(&id.to_string(), Some(5)).unwrap();
+
+        let stmt = QuickSearchQueryPostgres::search_stmt(&command);
+
+        for table in ["users", "resumes", "projects", "pages", "media"] {
+            assert!(stmt.sql.contains(&format!("FROM {table}")), "{table}");
+        }
+        let values = stmt.values.unwrap().0;
+        assert_eq!(values[0], Value::from(Some(id)));
+        assert_eq!(values[3], Value::from(5i64));
+    }
+
+    #[tokio::test]
+    async fn test_rows_become_typed_links() {
+        let (project_id, owner_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![
+                row("project", project_id, owner_id, Some("port-cms"), "slug"),
+                row("media", Uuid::new_v4(), owner_id, None, "id"),
+            ]])
+            .into_connection();
+        let query = QuickSearchQueryPostgres::new(Arc::new(db));
+
+        let hits = query
+            .search(&QuickSearchCommand::new("port", None).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].matched, MatchedField::Slug);
+        assert_eq!(
+            hits[0].link,
+            QuickLink::Project {
+                id: project_id,
+                owner_id,
+                slug: "port-cms".to_string(),
+            }
+        );
+        assert!(matches!(hits[1].link, QuickLink::Media { .. }));
+        assert_eq!(hits[1].detail, None);
+    }
+}
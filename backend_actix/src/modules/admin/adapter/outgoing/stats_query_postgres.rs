@@ -0,0 +1,219 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement,
+};
+use std::sync::Arc;
+
+use crate::admin::application::ports::outgoing::{
+    MediaCounts, StatsQuery, StatsQueryError, UserCounts,
+};
+
+#[derive(Clone)]
+pub struct StatsQueryPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl StatsQueryPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    fn users_stmt() -> Statement {
+        Statement::from_string(
+            DatabaseBackend::Postgres,
+            r#"
+            SELECT
+                COUNT(*) AS total,
+                COUNT(*) FILTER (WHERE is_verified) AS verified
+            FROM users
+            WHERE is_deleted = false
+            "#,
+        )
+    }
+
+    fn published_projects_stmt() -> Statement {
+        Statement::from_string(
+            DatabaseBackend::Postgres,
+            r#"
+            SELECT COUNT(*) AS total
+            FROM projects
+            WHERE is_deleted = false
+            "#,
+        )
+    }
+
+    fn media_stmt() -> Statement {
+        Statement::from_string(
+            DatabaseBackend::Postgres,
+            r#"
+            SELECT
+                COUNT(*) FILTER (WHERE m.status = 'pending') AS pending,
+                COUNT(*) FILTER (WHERE m.status = 'processing') AS processing,
+                COUNT(*) FILTER (WHERE m.status = 'ready') AS ready,
+                COUNT(*) FILTER (WHERE m.status = 'failed') AS failed,
+                CAST((
+                    COALESCE(SUM(m.file_size_bytes), 0)
+                    + COALESCE((
+                        SELECT SUM(mv.file_size_bytes)
+                        FROM media_variants mv
+                        INNER JOIN media vm ON vm.id = mv.media_id
+                        WHERE vm.deleted_at IS NULL
+                    ), 0)
+                ) AS BIGINT) AS storage_bytes
+            FROM media m
+            WHERE m.deleted_at IS NULL
+            "#,
+        )
+    }
+
+    fn failed_media_since_stmt(since: DateTime<Utc>) -> Statement {
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"
+            SELECT COUNT(*) AS total
+            FROM media
+            WHERE status = 'failed'
+              AND updated_at >= $1
+              AND deleted_at IS NULL
+            "#,
+            vec![since.into()],
+        )
+    }
+
+    // =====================================================
+    // Helpers
+    // =====================================================
+
+    async fn one_row(&self, stmt: Statement) -> Result<QueryResult, StatsQueryError> {
+        self.db
+            .query_one(stmt)
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or_else(|| StatsQueryError::DatabaseError("aggregate returned no row".into()))
+    }
+
+    fn count(row: &QueryResult, column: &str) -> Result<u64, StatsQueryError> {
+        let value: i64 = row.try_get("", column).map_err(Self::map_db_err)?;
+        Ok(value.max(0) as u64)
+    }
+
+    fn map_db_err(e: DbErr) -> StatsQueryError {
+        StatsQueryError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl StatsQuery for StatsQueryPostgres {
+    async fn count_users(&self) -> Result<UserCounts, StatsQueryError> {
+        let row = self.one_row(Self::users_stmt()).await?;
+
+        Ok(UserCounts {
+            total: Self::count(&row, "total")?,
+            verified: Self::count(&row, "verified")?,
+        })
+    }
+
+    async fn count_published_projects(&self) -> Result<u64, StatsQueryError> {
+        let row = self.one_row(Self::published_projects_stmt()).await?;
+        Self::count(&row, "total")
+    }
+
+    async fn media_counts(&self) -> Result<MediaCounts, StatsQueryError> {
+        let row = self.one_row(Self::media_stmt()).await?;
+
+        Ok(MediaCounts {
+            pending: Self::count(&row, "pending")?,
+            processing: Self::count(&row, "processing")?,
+            ready: Self::count(&row, "ready")?,
+            failed: Self::count(&row, "failed")?,
+            storage_bytes: Self::count(&row, "storage_bytes")?,
+        })
+    }
+
+    async fn count_failed_media_since(&self, since: DateTime<Utc>) -> Result<u64, StatsQueryError> {
+        let row = self.one_row(Self::failed_media_since_stmt(since)).await?;
+        Self::count(&row, "total")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{MockDatabase, Value};
+    use std::collections::BTreeMap;
+
+    fn make_row(data: Vec<(&str, i64)>) -> BTreeMap<String, Value> {
+        data.into_iter()
+            .map(|(k, v)| (k.to_string(), Value::BigInt(Some(v))))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_count_users() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![make_row(vec![("total", 4), ("verified", 3)])]])
+            .into_connection();
+
+        let query = StatsQueryPostgres::new(Arc::new(db));
+        let counts = query.count_users().await.unwrap();
+
+        assert_eq!(
+            counts,
+            UserCounts {
+                total: 4,
+                verified: 3
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_media_counts() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![make_row(vec![
+                ("pending", 1),
+                ("processing", 2),
+                ("ready", 10),
+                ("failed", 3),
+                ("storage_bytes", 123_456),
+            ])]])
+            .into_connection();
+
+        let query = StatsQueryPostgres::new(Arc::new(db));
+        let counts = query.media_counts().await.unwrap();
+
+        assert_eq!(counts.ready, 10);
+        assert_eq!(counts.failed, 3);
+        assert_eq!(counts.storage_bytes, 123_456);
+    }
+
+    #[tokio::test]
+    async fn test_count_failed_media_since() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![make_row(vec![("total", 2)])]])
+            .into_connection();
+
+        let query = StatsQueryPostgres::new(Arc::new(db));
+        let failed = query.count_failed_media_since(Utc::now()).await.unwrap();
+
+        assert_eq!(failed, 2);
+    }
+
+    #[tokio::test]
+    async fn test_database_error_is_mapped() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_errors(vec![DbErr::Custom("connection lost".into())])
+            .into_connection();
+
+        let query = StatsQueryPostgres::new(Arc::new(db));
+        let err = query.count_published_projects().await.unwrap_err();
+
+        match err {
+            StatsQueryError::DatabaseError(msg) => assert!(msg.contains("connection lost")),
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement,
+};
+use std::sync::Arc;
+
+use crate::admin::application::ports::incoming::use_cases::StorageGroup;
+use crate::admin::application::ports::outgoing::{
+    StorageGrouping, StorageReportQuery, StorageReportQueryError, StorageUsage,
+};
+
+#[derive(Clone)]
+pub struct StorageReportQueryPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl StorageReportQueryPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    /// The grouping column; owners need the join to `media`
+    fn group_key(grouping: StorageGrouping) -> &'static str {
+        match grouping {
+            StorageGrouping::Bucket => "v.bucket_name",
+            StorageGrouping::Size => "CAST(v.variant_type AS TEXT)",
+            StorageGrouping::Format => "v.mime_type",
+            StorageGrouping::Owner => "CAST(m.user_id AS TEXT)",
+        }
+    }
+
+    /// `$1` is the start of this month, `$2` of last month, `$3` the most
+    /// groups to return (NULL for all)
+    fn usage_stmt(
+        grouping: StorageGrouping,
+        this_month: DateTime<Utc>,
+        last_month: DateTime<Utc>,
+        limit: Option<u32>,
+    ) -> Statement {
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!(
+                r#"
+                SELECT
+                    {key} AS key,
+                    COUNT(*) AS objects,
+                    CAST(COALESCE(SUM(v.file_size_bytes), 0) AS BIGINT) AS bytes,
+                    CAST(COALESCE(SUM(v.file_size_bytes)
+                        FILTER (WHERE v.created_at >= $1), 0) AS BIGINT)
+                        AS bytes_added_this_month,
+                    CAST(COALESCE(SUM(v.file_size_bytes)
+                        FILTER (WHERE v.created_at >= $2 AND v.created_at < $1), 0) AS BIGINT)
+                        AS bytes_added_last_month
+                FROM media_variants v
+                INNER JOIN media m ON m.id = v.media_id
+                GROUP BY 1
+                ORDER BY bytes DESC, key
+                LIMIT $3
+                "#,
+                key = Self::group_key(grouping),
+            ),
+            vec![
+                this_month.into(),
+                last_month.into(),
+                limit.map(i64::from).into(),
+            ],
+        )
+    }
+
+    // =====================================================
+    // Mapping
+    // =====================================================
+
+    fn to_group(row: &QueryResult) -> Result<StorageGroup, StorageReportQueryError> {
+        Ok(StorageGroup {
+            key: row.try_get("", "key").map_err(Self::map_db_err)?,
+            usage: StorageUsage {
+                objects: Self::count(row, "objects")?,
+                bytes: Self::count(row, "bytes")?,
+                bytes_added_this_month: Self::count(row, "bytes_added_this_month")?,
+                bytes_added_last_month: Self::count(row, "bytes_added_last_month")?,
+            },
+        })
+    }
+
+    fn count(row: &QueryResult, column: &str) -> Result<u64, StorageReportQueryError> {
+        let value: i64 = row.try_get("", column).map_err(Self::map_db_err)?;
+        Ok(value.max(0) as u64)
+    }
+
+    fn map_db_err(e: DbErr) -> StorageReportQueryError {
+        StorageReportQueryError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl StorageReportQuery for StorageReportQueryPostgres {
+    async fn usage_by(
+        &self,
+        grouping: StorageGrouping,
+        this_month: DateTime<Utc>,
+        last_month: DateTime<Utc>,
+        limit: Option<u32>,
+    ) -> Result<Vec<StorageGroup>, StorageReportQueryError> {
+        self.db
+            .query_all(Self::usage_stmt(grouping, this_month, last_month, limit))
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(Self::to_group)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{MockDatabase, Value};
+    use std::collections::BTreeMap;
+
+    fn row(key: &str, bytes: i64, this_month: i64, last_month: i64) -> BTreeMap<String, Value> {
+        BTreeMap::from([
+            ("key".to_string(), Value::from(key)),
+            ("objects".to_string(), Value::BigInt(Some(3))),
+            ("bytes".to_string(), Value::BigInt(Some(bytes))),
+            (
+                "bytes_added_this_month".to_string(),
+                Value::BigInt(Some(this_month)),
+            ),
+            (
+                "bytes_added_last_month".to_string(),
+                Value::BigInt(Some(last_month)),
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_owner_grouping_is_limited() {
+        let now = Utc::now();
+
+        let stmt =
+            StorageReportQueryPostgres::usage_stmt(StorageGrouping::Owner, now, now, Some(10));
+
+        assert!(stmt.sql.contains("CAST(m.user_id AS TEXT) AS key"));
+        let values = stmt.values.unwrap().0;
+        assert_eq!(values[2], Value::from(Some(10i64)));
+    }
+
+    #[tokio::test]
+    async fn test_rows_become_groups() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![
+                row("image/avif", 4096, 4096, 0),
+                row("image/webp", 1024, 128, 256),
+            ]])
+            .into_connection();
+        let query = StorageReportQueryPostgres::new(Arc::new(db));
+
+        let groups = query
+            .usage_by(StorageGrouping::Format, Utc::now(), Utc::now(), None)
+            .await
+            .unwrap();
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].key, "image/avif");
+        assert_eq!(groups[0].usage.bytes_added_this_month, 4096);
+        assert_eq!(groups[1].usage.bytes_added_last_month, 256);
+        assert_eq!(groups[1].usage.objects, 3);
+    }
+
+    #[tokio::test]
+    async fn test_database_error_is_mapped() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_errors(vec![DbErr::Custom("connection lost".into())])
+            .into_connection();
+        let query = StorageReportQueryPostgres::new(Arc::new(db));
+
+        let err = query
+            .usage_by(StorageGrouping::Bucket, Utc::now(), Utc::now(), None)
+            .await
+            .unwrap_err();
+
+        match err {
+            StorageReportQueryError::DatabaseError(msg) => assert!(msg.contains("connection lost")),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegrityIssueKind {
+    /// A live CV highlights a project that is gone or in the trash
+    DanglingHighlightedProject,
+    /// An attachment points at a media row that is gone
+    OrphanedAttachment,
+    /// A variant of live media has no object in storage
+    MissingVariantObject,
+    /// A slug equals an older one ignoring case, so URLs can resolve to
+    /// either
+    SlugCollision,
+}
+
+/// One broken reference, with what to do about it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct IntegrityIssue {
+    pub kind: IntegrityIssueKind,
+    /// What holds the broken reference: `cv`, `media_attachment`, `media`,
+    /// `project` or `page`
+    pub resource: &'static str,
+    pub resource_id: Uuid,
+    /// Owner of the resource, when known
+    pub owner_id: Option<Uuid>,
+    /// What it references or collides with: a project id, an object as
+    /// `bucket/key`, or the id of the older slug holder
+    pub target: String,
+    pub suggestion: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct IntegrityReport {
+    pub issues: Vec<IntegrityIssue>,
+    /// Whether variants were looked up in storage
+    pub storage_checked: bool,
+    pub generated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CheckIntegrityCommand {
+    /// List the variant buckets to find missing objects; the slow part
+    pub check_storage: bool,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum CheckIntegrityError {
+    #[error("Failed to query content: {0}")]
+    QueryFailed(String),
+
+    #[error("Failed to list storage: {0}")]
+    StorageFailed(String),
+}
+
+/// Scans the whole site for broken references. Reports only; nothing is
+/// changed.
+#[async_trait]
+pub trait CheckIntegrityUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        command: CheckIntegrityCommand,
+    ) -> Result<IntegrityReport, CheckIntegrityError>;
+}
+
+metered_use_case!(
+    "admin",
+    "check_integrity",
+    CheckIntegrityUseCase,
+    fn execute(
+        &self,
+        command: CheckIntegrityCommand,
+    ) -> Result<IntegrityReport, CheckIntegrityError>
+);
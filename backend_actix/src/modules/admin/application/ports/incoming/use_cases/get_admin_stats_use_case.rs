@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::admin::application::ports::outgoing::{MediaCounts, UserCounts};
+use crate::shared::metrics::metered_use_case;
+
+/// Overview card for the admin dashboard
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct AdminStats {
+    pub users: UserCounts,
+    pub published_projects: u64,
+    pub media: MediaCounts,
+    pub failed_processing_last_24h: u64,
+    pub generated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum GetAdminStatsError {
+    #[error("Failed to fetch stats: {0}")]
+    QueryFailed(String),
+}
+
+#[async_trait]
+pub trait GetAdminStatsUseCase: Send + Sync {
+    async fn execute(&self) -> Result<AdminStats, GetAdminStatsError>;
+}
+
+metered_use_case!(
+    "admin",
+    "get_admin_stats",
+    GetAdminStatsUseCase,
+    fn execute(&self) -> Result<AdminStats, GetAdminStatsError>
+);
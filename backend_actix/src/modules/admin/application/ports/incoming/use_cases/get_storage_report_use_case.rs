@@ -0,0 +1,134 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::admin::application::ports::outgoing::StorageUsage;
+use crate::shared::metrics::metered_use_case;
+
+pub const DEFAULT_TOP_USERS: u32 = 20;
+pub const MAX_TOP_USERS: u32 = 200;
+
+/// A storage breakdown row: a bucket, variant size, MIME type or owner id
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct StorageGroup {
+    pub key: String,
+    #[serde(flatten)]
+    pub usage: StorageUsage,
+}
+
+/// Bytes held by generated variants, grouped several ways. Every group
+/// carries what it added this calendar month and last one, so a new size or
+/// format shows up as a jump before it shows up on the bill.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct StorageReport {
+    pub total: StorageUsage,
+    pub by_bucket: Vec<StorageGroup>,
+    pub by_size: Vec<StorageGroup>,
+    /// By MIME type, e.g. `image/webp` next to `image/avif`
+    pub by_format: Vec<StorageGroup>,
+    /// Largest owners first
+    pub by_user: Vec<StorageGroup>,
+    /// Start of the current month (UTC)
+    pub this_month_from: DateTime<Utc>,
+    /// Start of the previous month (UTC)
+    pub last_month_from: DateTime<Utc>,
+    pub generated_at: DateTime<Utc>,
+}
+
+/// Start of the month `now` falls in, and of the month before it
+pub fn month_starts(now: DateTime<Utc>) -> (DateTime<Utc>, DateTime<Utc>) {
+    let (year, month) = (now.year(), now.month());
+    let (last_year, last_month) = if month == 1 {
+        (year - 1, 12)
+    } else {
+        (year, month - 1)
+    };
+    let start = |year, month| {
+        Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0)
+            .single()
+            .expect("the first of a month at midnight UTC exists")
+    };
+
+    (start(year, month), start(last_year, last_month))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StorageReportCommand {
+    top_users: u32,
+}
+
+impl StorageReportCommand {
+    pub fn new(top_users: Option<u32>) -> Result<Self, StorageReportError> {
+        let top_users = top_users.unwrap_or(DEFAULT_TOP_USERS);
+        if top_users == 0 || top_users > MAX_TOP_USERS {
+            return Err(StorageReportError::InvalidQuery(format!(
+                "top_users must be between 1 and {MAX_TOP_USERS}"
+            )));
+        }
+
+        Ok(Self { top_users })
+    }
+
+    pub fn top_users(&self) -> u32 {
+        self.top_users
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum StorageReportError {
+    #[error("{0}")]
+    InvalidQuery(String),
+
+    #[error("Failed to build storage report: {0}")]
+    QueryFailed(String),
+}
+
+#[async_trait]
+pub trait GetStorageReportUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        command: StorageReportCommand,
+    ) -> Result<StorageReport, StorageReportError>;
+}
+
+metered_use_case!(
+    "admin",
+    "get_storage_report",
+    GetStorageReportUseCase,
+    fn execute(
+        &self,
+        command: StorageReportCommand,
+    ) -> Result<StorageReport, StorageReportError>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_month_starts_wrap_the_year() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 15, 9, 30, 0).unwrap();
+
+        let (this_month, last_month) = month_starts(now);
+
+        assert_eq!(
+            this_month,
+            Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            last_month,
+            Utc.with_ymd_and_hms(2025, 12, 1, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_command_bounds_top_users() {
+        assert_eq!(
+            StorageReportCommand::new(None).unwrap().top_users(),
+            DEFAULT_TOP_USERS
+        );
+        assert!(StorageReportCommand::new(Some(0)).is_err());
+        assert!(StorageReportCommand::new(Some(MAX_TOP_USERS + 1)).is_err());
+    }
+}
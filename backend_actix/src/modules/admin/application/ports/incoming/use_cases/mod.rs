@@ -0,0 +1,18 @@
+mod check_integrity_use_case;
+mod get_admin_stats_use_case;
+mod get_storage_report_use_case;
+mod quick_search_use_case;
+
+pub use check_integrity_use_case::{
+    CheckIntegrityCommand, CheckIntegrityError, CheckIntegrityUseCase, IntegrityIssue,
+    IntegrityIssueKind, IntegrityReport,
+};
+pub use get_admin_stats_use_case::{AdminStats, GetAdminStatsError, GetAdminStatsUseCase};
+pub use get_storage_report_use_case::{
+    month_starts, GetStorageReportUseCase, StorageGroup, StorageReport, StorageReportCommand,
+    StorageReportError, DEFAULT_TOP_USERS, MAX_TOP_USERS,
+};
+pub use quick_search_use_case::{
+    MatchedField, QuickLink, QuickSearchCommand, QuickSearchError, QuickSearchHit,
+    QuickSearchUseCase, DEFAULT_QUICK_SEARCH_LIMIT, MAX_QUICK_SEARCH_LIMIT,
+};
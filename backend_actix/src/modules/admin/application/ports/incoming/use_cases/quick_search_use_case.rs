@@ -0,0 +1,171 @@
+use async_trait::async_trait;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::shared::metrics::metered_use_case;
+
+pub const DEFAULT_QUICK_SEARCH_LIMIT: u32 = 20;
+pub const MAX_QUICK_SEARCH_LIMIT: u32 = 50;
+/// Shorter terms match most of the site by prefix
+const MIN_TERM_CHARS: usize = 2;
+/// The longest an email address can be
+const MAX_TERM_CHARS: usize = 254;
+
+/// What the term matched
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchedField {
+    Id,
+    Slug,
+    Email,
+    Username,
+}
+
+/// Where a hit lives, for the admin UI to route to its screen
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum QuickLink {
+    User {
+        id: Uuid,
+        username: String,
+    },
+    Cv {
+        id: Uuid,
+        owner_id: Uuid,
+    },
+    Project {
+        id: Uuid,
+        owner_id: Uuid,
+        slug: String,
+    },
+    Page {
+        id: Uuid,
+        owner_id: Uuid,
+        slug: String,
+    },
+    Media {
+        id: Uuid,
+        owner_id: Uuid,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct QuickSearchHit {
+    /// Username, CV display name, project or page title, or media file name
+    pub label: String,
+    /// Email of a user, role of a CV, slug of a project or page
+    pub detail: Option<String>,
+    pub matched: MatchedField,
+    /// Soft-deleted or in the trash
+    pub deleted: bool,
+    pub link: QuickLink,
+}
+
+/// A quick search term: an id, or the start of a slug, email or username
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuickSearchCommand {
+    text: String,
+    id: Option<Uuid>,
+    limit: u32,
+}
+
+impl QuickSearchCommand {
+    pub fn new(q: &str, limit: Option<u32>) -> Result<Self, QuickSearchError> {
+        let text = q.trim().to_lowercase();
+        let chars = text.chars().count();
+        if chars < MIN_TERM_CHARS {
+            return Err(QuickSearchError::InvalidQuery(format!(
+                "q must be at least {MIN_TERM_CHARS} characters"
+            )));
+        }
+        if chars > MAX_TERM_CHARS {
+            return Err(QuickSearchError::InvalidQuery(format!(
+                "q must be at most {MAX_TERM_CHARS} characters"
+            )));
+        }
+
+        let limit = limit.unwrap_or(DEFAULT_QUICK_SEARCH_LIMIT);
+        if limit == 0 || limit > MAX_QUICK_SEARCH_LIMIT {
+            return Err(QuickSearchError::InvalidQuery(format!(
+                "limit must be between 1 and {MAX_QUICK_SEARCH_LIMIT}"
+            )));
+        }
+
+        Ok(Self {
+            id: Uuid::parse_str(&text).ok(),
+            text,
+            limit,
+        })
+    }
+
+    /// Trimmed and lowercased
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The term, when it is a UUID
+    pub fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    pub fn limit(&self) -> u32 {
+        self.limit
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum QuickSearchError {
+    #[error("{0}")]
+    InvalidQuery(String),
+
+    #[error("Quick search failed: {0}")]
+    QueryFailed(String),
+}
+
+/// Finds users, CVs, projects, pages and media of every owner by exact id,
+/// or by slug, email or username prefix. Exact matches come first.
+#[async_trait]
+pub trait QuickSearchUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        command: QuickSearchCommand,
+    ) -> Result<Vec<QuickSearchHit>, QuickSearchError>;
+}
+
+metered_use_case!(
+    "admin",
+    "quick_search",
+    QuickSearchUseCase,
+    fn execute(
+        &self,
+        command: QuickSearchCommand,
+    ) -> Result<Vec<QuickSearchHit>, QuickSearchError>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_normalizes_the_term() {
+        let id = Uuid::new_v4();
+
+        let command =
+            QuickSearchCommand::new(&format!(" {} ", id.to_string().to_uppercase()), None).unwrap();
+        assert_eq!(command.id(), Some(id));
+        assert_eq!(command.text(), id.to_string());
+        assert_eq!(command.limit(), DEFAULT_QUICK_SEARCH_LIMIT);
+
+        let command = QuickSearchCommand::new("Ada@Example", Some(5)).unwrap();
+        assert_eq!(command.text(), "ada@example");
+        assert_eq!(command.id(), None);
+    }
+
+    #[test]
+    fn test_command_rejects_short_terms_and_bad_limits() {
+        assert!(QuickSearchCommand::new(" a ", None).is_err());
+        assert!(QuickSearchCommand::new("ada", Some(0)).is_err());
+        assert!(QuickSearchCommand::new("ada", Some(MAX_QUICK_SEARCH_LIMIT + 1)).is_err());
+    }
+}
@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// A project a live CV highlights, by the id stored in the CV
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HighlightedProjectRef {
+    pub cv_id: Uuid,
+    pub owner_id: Uuid,
+    /// As the CV holds it; not necessarily a UUID
+    pub project_id: String,
+}
+
+/// A `media_attachments` row whose media row is gone
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrphanedAttachment {
+    pub attachment_id: Uuid,
+    pub media_id: Uuid,
+    /// e.g. `project`
+    pub attachable_type: String,
+    pub attachable_id: Uuid,
+}
+
+/// Where a variant of live media is stored
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VariantObject {
+    pub media_id: Uuid,
+    pub owner_id: Uuid,
+    /// e.g. `thumbnail`
+    pub size: String,
+    pub bucket_name: String,
+    pub object_key: String,
+}
+
+/// A project or page whose slug equals another's ignoring case
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlugHolder {
+    pub id: Uuid,
+    pub owner_id: Uuid,
+    pub slug: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum IntegrityQueryError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Reads across modules for the content integrity check; nothing is written.
+#[async_trait]
+pub trait IntegrityQuery: Send + Sync {
+    /// Every highlighted project of every live CV
+    async fn highlighted_projects(&self)
+        -> Result<Vec<HighlightedProjectRef>, IntegrityQueryError>;
+
+    /// Projects among `ids` that exist, trashed ones included, mapped to
+    /// whether they are in the trash
+    async fn project_states(
+        &self,
+        ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, bool>, IntegrityQueryError>;
+
+    async fn orphaned_attachments(&self) -> Result<Vec<OrphanedAttachment>, IntegrityQueryError>;
+
+    /// Variants of media that isn't in the trash
+    async fn variant_objects(&self) -> Result<Vec<VariantObject>, IntegrityQueryError>;
+
+    /// Projects, trashed ones included, sharing a slug ignoring case with
+    /// another project; oldest first
+    async fn colliding_project_slugs(&self) -> Result<Vec<SlugHolder>, IntegrityQueryError>;
+
+    /// Pages sharing a slug ignoring case with another page of the same
+    /// owner; oldest first
+    async fn colliding_page_slugs(&self) -> Result<Vec<SlugHolder>, IntegrityQueryError>;
+}
@@ -0,0 +1,14 @@
+mod integrity_query;
+mod quick_search_query;
+mod stats_query;
+mod storage_report_query;
+
+pub use integrity_query::{
+    HighlightedProjectRef, IntegrityQuery, IntegrityQueryError, OrphanedAttachment, SlugHolder,
+    VariantObject,
+};
+pub use quick_search_query::{QuickSearchQuery, QuickSearchQueryError};
+pub use stats_query::{MediaCounts, StatsQuery, StatsQueryError, UserCounts};
+pub use storage_report_query::{
+    StorageGrouping, StorageReportQuery, StorageReportQueryError, StorageUsage,
+};
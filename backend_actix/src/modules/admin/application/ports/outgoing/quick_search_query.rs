@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+
+use crate::admin::application::ports::incoming::use_cases::{QuickSearchCommand, QuickSearchHit};
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum QuickSearchQueryError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Looks a term up in the users, CVs, projects, pages and media of every
+/// owner, trashed ones included, in a single query
+#[async_trait]
+pub trait QuickSearchQuery: Send + Sync {
+    /// Exact id matches, then exact slug, email or username matches, then
+    /// prefix matches; at most `command.limit()`
+    async fn search(
+        &self,
+        command: &QuickSearchCommand,
+    ) -> Result<Vec<QuickSearchHit>, QuickSearchQueryError>;
+}
@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Accounts that are not soft-deleted
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, ToSchema)]
+pub struct UserCounts {
+    pub total: u64,
+    pub verified: u64,
+}
+
+/// Live (not deleted) media by processing state, plus the bytes they occupy
+/// in storage: originals and every generated variant.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, ToSchema)]
+pub struct MediaCounts {
+    pub pending: u64,
+    pub processing: u64,
+    pub ready: u64,
+    pub failed: u64,
+    pub storage_bytes: u64,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum StatsQueryError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Site-wide aggregates; each method is a single aggregate query.
+#[async_trait]
+pub trait StatsQuery: Send + Sync {
+    async fn count_users(&self) -> Result<UserCounts, StatsQueryError>;
+
+    async fn count_published_projects(&self) -> Result<u64, StatsQueryError>;
+
+    async fn media_counts(&self) -> Result<MediaCounts, StatsQueryError>;
+
+    /// Media that ended in `failed` with its last update at or after `since`
+    async fn count_failed_media_since(&self, since: DateTime<Utc>) -> Result<u64, StatsQueryError>;
+}
@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::admin::application::ports::incoming::use_cases::StorageGroup;
+
+/// Variant objects and their bytes, and the bytes written in the current
+/// and previous calendar month. Trashed media count until they are purged.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, ToSchema)]
+pub struct StorageUsage {
+    pub objects: u64,
+    pub bytes: u64,
+    pub bytes_added_this_month: u64,
+    pub bytes_added_last_month: u64,
+}
+
+impl StorageUsage {
+    pub fn add(&mut self, other: &StorageUsage) {
+        self.objects += other.objects;
+        self.bytes += other.bytes;
+        self.bytes_added_this_month += other.bytes_added_this_month;
+        self.bytes_added_last_month += other.bytes_added_last_month;
+    }
+}
+
+/// What a storage breakdown groups variants by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageGrouping {
+    Bucket,
+    Size,
+    Format,
+    Owner,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum StorageReportQueryError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Aggregates over `media_variants.file_size_bytes`; each call is a single
+/// aggregate query.
+#[async_trait]
+pub trait StorageReportQuery: Send + Sync {
+    /// Usage per group, largest first and at most `limit` groups when set.
+    /// Variants written at or after `this_month` count as this month's,
+    /// those from `last_month` up to it as last month's.
+    async fn usage_by(
+        &self,
+        grouping: StorageGrouping,
+        this_month: DateTime<Utc>,
+        last_month: DateTime<Utc>,
+        limit: Option<u32>,
+    ) -> Result<Vec<StorageGroup>, StorageReportQueryError>;
+}
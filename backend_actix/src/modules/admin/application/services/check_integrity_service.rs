@@ -0,0 +1,470 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::admin::application::ports::{
+    incoming::use_cases::{
+        CheckIntegrityCommand, CheckIntegrityError, CheckIntegrityUseCase, IntegrityIssue,
+        IntegrityIssueKind, IntegrityReport,
+    },
+    outgoing::{IntegrityQuery, IntegrityQueryError, SlugHolder},
+};
+use crate::multimedia::application::ports::outgoing::cloud_storage::{
+    ObjectInventory, ObjectInventoryError,
+};
+
+/// Project ids looked up per query
+const LOOKUP_BATCH: usize = 500;
+
+impl From<IntegrityQueryError> for CheckIntegrityError {
+    fn from(err: IntegrityQueryError) -> Self {
+        CheckIntegrityError::QueryFailed(err.to_string())
+    }
+}
+
+impl From<ObjectInventoryError> for CheckIntegrityError {
+    fn from(err: ObjectInventoryError) -> Self {
+        CheckIntegrityError::StorageFailed(err.to_string())
+    }
+}
+
+pub struct CheckIntegrityService<Q, I>
+where
+    Q: IntegrityQuery,
+    I: ObjectInventory,
+{
+    query: Q,
+    inventory: I,
+}
+
+impl<Q, I> CheckIntegrityService<Q, I>
+where
+    Q: IntegrityQuery,
+    I: ObjectInventory,
+{
+    pub fn new(query: Q, inventory: I) -> Self {
+        Self { query, inventory }
+    }
+
+    async fn dangling_highlights(&self) -> Result<Vec<IntegrityIssue>, CheckIntegrityError> {
+        let highlights = self.query.highlighted_projects().await?;
+
+        let mut ids: Vec<Uuid> = highlights
+            .iter()
+            .filter_map(|h| Uuid::parse_str(&h.project_id).ok())
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        let mut states = HashMap::new();
+        for batch in ids.chunks(LOOKUP_BATCH) {
+            states.extend(self.query.project_states(batch).await?);
+        }
+
+        Ok(highlights
+            .into_iter()
+            .filter_map(|h| {
+                let trashed = Uuid::parse_str(&h.project_id)
+                    .ok()
+                    .and_then(|id| states.get(&id).copied());
+                let suggestion = match trashed {
+                    Some(false) => return None,
+                    Some(true) => format!(
+                        "Restore the project with POST /api/trash/project/{}/restore, or remove it from the CV's highlighted_projects with PATCH /api/cvs/{}",
+                        h.project_id, h.cv_id
+                    ),
+                    None => format!(
+                        "Remove it from the CV's highlighted_projects with PATCH /api/cvs/{}",
+                        h.cv_id
+                    ),
+                };
+                Some(IntegrityIssue {
+                    kind: IntegrityIssueKind::DanglingHighlightedProject,
+                    resource: "cv",
+                    resource_id: h.cv_id,
+                    owner_id: Some(h.owner_id),
+                    target: h.project_id,
+                    suggestion,
+                })
+            })
+            .collect())
+    }
+
+    async fn orphaned_attachments(&self) -> Result<Vec<IntegrityIssue>, CheckIntegrityError> {
+        Ok(self
+            .query
+            .orphaned_attachments()
+            .await?
+            .into_iter()
+            .map(|a| IntegrityIssue {
+                kind: IntegrityIssueKind::OrphanedAttachment,
+                resource: "media_attachment",
+                resource_id: a.attachment_id,
+                owner_id: None,
+                target: a.media_id.to_string(),
+                suggestion: format!(
+                    "Delete the attachment; the {} {} shows no media for it",
+                    a.attachable_type, a.attachable_id
+                ),
+            })
+            .collect())
+    }
+
+    /// Lists each bucket the variants are in once
+    async fn missing_variant_objects(&self) -> Result<Vec<IntegrityIssue>, CheckIntegrityError> {
+        let variants = self.query.variant_objects().await?;
+
+        let mut stored: HashMap<&str, HashSet<String>> = HashMap::new();
+        for variant in &variants {
+            if stored.contains_key(variant.bucket_name.as_str()) {
+                continue;
+            }
+            let names = self
+                .inventory
+                .list_objects(&variant.bucket_name)
+                .await?
+                .into_iter()
+                .map(|object| object.name)
+                .collect();
+            stored.insert(variant.bucket_name.as_str(), names);
+        }
+
+        let mut issues = Vec::new();
+        for variant in &variants {
+            if stored[variant.bucket_name.as_str()].contains(&variant.object_key) {
+                continue;
+            }
+            issues.push(IntegrityIssue {
+                kind: IntegrityIssueKind::MissingVariantObject,
+                resource: "media",
+                resource_id: variant.media_id,
+                owner_id: Some(variant.owner_id),
+                target: format!("{}/{}", variant.bucket_name, variant.object_key),
+                suggestion: format!(
+                    "Regenerate the {} variant with POST /api/media/{}/regenerate",
+                    variant.size, variant.media_id
+                ),
+            });
+        }
+        Ok(issues)
+    }
+
+    async fn slug_collisions(&self) -> Result<Vec<IntegrityIssue>, CheckIntegrityError> {
+        let projects = self.query.colliding_project_slugs().await?;
+        let pages = self.query.colliding_page_slugs().await?;
+
+        let mut issues = collisions(
+            projects,
+            "project",
+            |_| None,
+            |holder| {
+                format!(
+                    "Change the slug with PATCH /api/projects/{}; `{}` is taken ignoring case",
+                    holder.id, holder.slug
+                )
+            },
+        );
+        issues.extend(collisions(
+            pages,
+            "page",
+            |holder| Some(holder.owner_id),
+            |holder| {
+                format!(
+                "Page slugs can't be changed: re-create the page under another slug and delete {}",
+                holder.id
+            )
+            },
+        ));
+        Ok(issues)
+    }
+}
+
+/// Every holder but the oldest of each slug, compared lowercase within the
+/// scope `scope` returns, gets an issue
+fn collisions(
+    holders: Vec<SlugHolder>,
+    resource: &'static str,
+    scope: impl Fn(&SlugHolder) -> Option<Uuid>,
+    suggestion: impl Fn(&SlugHolder) -> String,
+) -> Vec<IntegrityIssue> {
+    let mut oldest: HashMap<(Option<Uuid>, String), Uuid> = HashMap::new();
+    let mut issues = Vec::new();
+
+    for holder in holders {
+        let key = (scope(&holder), holder.slug.to_lowercase());
+        match oldest.get(&key) {
+            None => {
+                oldest.insert(key, holder.id);
+            }
+            Some(kept) => issues.push(IntegrityIssue {
+                kind: IntegrityIssueKind::SlugCollision,
+                resource,
+                resource_id: holder.id,
+                owner_id: Some(holder.owner_id),
+                target: kept.to_string(),
+                suggestion: suggestion(&holder),
+            }),
+        }
+    }
+    issues
+}
+
+#[async_trait]
+impl<Q, I> CheckIntegrityUseCase for CheckIntegrityService<Q, I>
+where
+    Q: IntegrityQuery,
+    I: ObjectInventory,
+{
+    async fn execute(
+        &self,
+        command: CheckIntegrityCommand,
+    ) -> Result<IntegrityReport, CheckIntegrityError> {
+        let mut issues = self.dangling_highlights().await?;
+        issues.extend(self.orphaned_attachments().await?);
+        if command.check_storage {
+            issues.extend(self.missing_variant_objects().await?);
+        }
+        issues.extend(self.slug_collisions().await?);
+
+        Ok(IntegrityReport {
+            issues,
+            storage_checked: command.check_storage,
+            generated_at: Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Duration};
+
+    use crate::admin::application::ports::outgoing::{
+        HighlightedProjectRef, OrphanedAttachment, VariantObject,
+    };
+    use crate::multimedia::application::ports::outgoing::cloud_storage::StoredObject;
+
+    #[derive(Clone, Default)]
+    struct MockIntegrityQuery {
+        highlights: Vec<HighlightedProjectRef>,
+        /// Existing projects and whether they are trashed
+        projects: HashMap<Uuid, bool>,
+        variants: Vec<VariantObject>,
+        project_slugs: Vec<SlugHolder>,
+        page_slugs: Vec<SlugHolder>,
+    }
+
+    #[async_trait]
+    impl IntegrityQuery for MockIntegrityQuery {
+        async fn highlighted_projects(
+            &self,
+        ) -> Result<Vec<HighlightedProjectRef>, IntegrityQueryError> {
+            Ok(self.highlights.clone())
+        }
+
+        async fn project_states(
+            &self,
+            ids: &[Uuid],
+        ) -> Result<HashMap<Uuid, bool>, IntegrityQueryError> {
+            Ok(ids
+                .iter()
+                .filter_map(|id| self.projects.get(id).map(|trashed| (*id, *trashed)))
+                .collect())
+        }
+
+        async fn orphaned_attachments(
+            &self,
+        ) -> Result<Vec<OrphanedAttachment>, IntegrityQueryError> {
+            Ok(vec![])
+        }
+
+        async fn variant_objects(&self) -> Result<Vec<VariantObject>, IntegrityQueryError> {
+            Ok(self.variants.clone())
+        }
+
+        async fn colliding_project_slugs(&self) -> Result<Vec<SlugHolder>, IntegrityQueryError> {
+            Ok(self.project_slugs.clone())
+        }
+
+        async fn colliding_page_slugs(&self) -> Result<Vec<SlugHolder>, IntegrityQueryError> {
+            Ok(self.page_slugs.clone())
+        }
+    }
+
+    /// Objects of one bucket; listing any other bucket fails
+    #[derive(Clone, Default)]
+    struct MockInventory {
+        bucket: String,
+        names: Vec<String>,
+    }
+
+    #[async_trait]
+    impl ObjectInventory for MockInventory {
+        async fn list_objects(
+            &self,
+            bucket: &str,
+        ) -> Result<Vec<StoredObject>, ObjectInventoryError> {
+            if bucket != self.bucket {
+                return Err(ObjectInventoryError::Failed("no such bucket".to_string()));
+            }
+            Ok(self
+                .names
+                .iter()
+                .map(|name| StoredObject {
+                    name: name.clone(),
+                    created_at: Utc::now(),
+                })
+                .collect())
+        }
+
+        async fn delete_object(&self, _: &str, _: &str) -> Result<(), ObjectInventoryError> {
+            unreachable!("the check never deletes")
+        }
+    }
+
+    fn highlight(cv_id: Uuid, project_id: &str) -> HighlightedProjectRef {
+        HighlightedProjectRef {
+            cv_id,
+            owner_id: Uuid::new_v4(),
+            project_id: project_id.to_string(),
+        }
+    }
+
+    fn holder(owner_id: Uuid, slug: &str, created_at: DateTime<Utc>) -> SlugHolder {
+        SlugHolder {
+            id: Uuid::new_v4(),
+            owner_id,
+            slug: slug.to_string(),
+            created_at,
+        }
+    }
+
+    fn variant(media_id: Uuid, size: &str, bucket: &str) -> VariantObject {
+        VariantObject {
+            media_id,
+            owner_id: Uuid::new_v4(),
+            size: size.to_string(),
+            bucket_name: bucket.to_string(),
+            object_key: format!("variants/{media_id}/{size}.webp"),
+        }
+    }
+
+    const NO_STORAGE: CheckIntegrityCommand = CheckIntegrityCommand {
+        check_storage: false,
+    };
+
+    #[tokio::test]
+    async fn test_highlights_of_missing_and_trashed_projects_are_reported() {
+        let (live, trashed, gone) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let cv_id = Uuid::new_v4();
+        let query = MockIntegrityQuery {
+            highlights: vec![
+                highlight(cv_id, &live.to_string()),
+                highlight(cv_id, &trashed.to_string()),
+                highlight(cv_id, &gone.to_string()),
+                highlight(cv_id, "portfolio"),
+            ],
+            projects: HashMap::from([(live, false), (trashed, true)]),
+            ..Default::default()
+        };
+        let service = CheckIntegrityService::new(query, MockInventory::default());
+
+        let report = service.execute(NO_STORAGE).await.unwrap();
+
+        let targets: Vec<String> = report.issues.iter().map(|i| i.target.clone()).collect();
+        assert_eq!(
+            targets,
+            vec![
+                trashed.to_string(),
+                gone.to_string(),
+                "portfolio".to_string()
+            ]
+        );
+        assert!(report.issues[0].suggestion.contains("/api/trash/project/"));
+        assert!(report.issues[1]
+            .suggestion
+            .contains(&format!("/api/cvs/{cv_id}")));
+        assert!(report
+            .issues
+            .iter()
+            .all(|i| i.kind == IntegrityIssueKind::DanglingHighlightedProject));
+    }
+
+    #[tokio::test]
+    async fn test_only_newer_slug_holders_are_reported() {
+        let owner = Uuid::new_v4();
+        let now = Utc::now();
+        let older = holder(owner, "rust", now - Duration::days(1));
+        let newer = holder(Uuid::new_v4(), "Rust", now);
+        let query = MockIntegrityQuery {
+            project_slugs: vec![older.clone(), newer.clone()],
+            // Same slug under different owners doesn't collide for pages
+            page_slugs: vec![
+                holder(owner, "about", now),
+                holder(Uuid::new_v4(), "About", now),
+                holder(owner, "About", now),
+            ],
+            ..Default::default()
+        };
+        let service = CheckIntegrityService::new(query, MockInventory::default());
+
+        let report = service.execute(NO_STORAGE).await.unwrap();
+
+        assert_eq!(report.issues.len(), 2);
+        assert_eq!(report.issues[0].resource, "project");
+        assert_eq!(report.issues[0].resource_id, newer.id);
+        assert_eq!(report.issues[0].target, older.id.to_string());
+        assert_eq!(report.issues[1].resource, "page");
+        assert_eq!(report.issues[1].owner_id, Some(owner));
+    }
+
+    #[tokio::test]
+    async fn test_missing_variant_objects_are_reported_when_storage_is_checked() {
+        let (present, missing) = (Uuid::new_v4(), Uuid::new_v4());
+        let stored = variant(present, "thumbnail", "ready");
+        let query = MockIntegrityQuery {
+            variants: vec![stored.clone(), variant(missing, "small", "ready")],
+            ..Default::default()
+        };
+        let inventory = MockInventory {
+            bucket: "ready".to_string(),
+            names: vec![stored.object_key],
+        };
+        let service = CheckIntegrityService::new(query, inventory);
+
+        let skipped = service.execute(NO_STORAGE).await.unwrap();
+        let checked = service
+            .execute(CheckIntegrityCommand {
+                check_storage: true,
+            })
+            .await
+            .unwrap();
+
+        assert!(skipped.issues.is_empty());
+        assert!(!skipped.storage_checked);
+        assert_eq!(checked.issues.len(), 1);
+        assert_eq!(checked.issues[0].resource_id, missing);
+        assert_eq!(
+            checked.issues[0].kind,
+            IntegrityIssueKind::MissingVariantObject
+        );
+        assert!(checked.issues[0].suggestion.contains("regenerate"));
+    }
+
+    #[tokio::test]
+    async fn test_storage_failure_fails_the_check() {
+        let query = MockIntegrityQuery {
+            variants: vec![variant(Uuid::new_v4(), "small", "elsewhere")],
+            ..Default::default()
+        };
+        let service = CheckIntegrityService::new(query, MockInventory::default());
+
+        let result = service
+            .execute(CheckIntegrityCommand {
+                check_storage: true,
+            })
+            .await;
+
+        assert!(matches!(result, Err(CheckIntegrityError::StorageFailed(_))));
+    }
+}
@@ -0,0 +1,159 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+
+use crate::admin::application::ports::{
+    incoming::use_cases::{AdminStats, GetAdminStatsError, GetAdminStatsUseCase},
+    outgoing::{StatsQuery, StatsQueryError},
+};
+
+#[derive(Debug, Clone)]
+pub struct GetAdminStatsService<Q>
+where
+    Q: StatsQuery + Send + Sync,
+{
+    query: Q,
+}
+
+impl<Q> GetAdminStatsService<Q>
+where
+    Q: StatsQuery + Send + Sync,
+{
+    pub fn new(query: Q) -> Self {
+        Self { query }
+    }
+}
+
+impl From<StatsQueryError> for GetAdminStatsError {
+    fn from(err: StatsQueryError) -> Self {
+        GetAdminStatsError::QueryFailed(err.to_string())
+    }
+}
+
+#[async_trait]
+impl<Q> GetAdminStatsUseCase for GetAdminStatsService<Q>
+where
+    Q: StatsQuery + Send + Sync,
+{
+    async fn execute(&self) -> Result<AdminStats, GetAdminStatsError> {
+        let now = Utc::now();
+
+        // Sequential on purpose: the pool is small and each query is a single aggregate
+        let users = self.query.count_users().await?;
+        let published_projects = self.query.count_published_projects().await?;
+        let media = self.query.media_counts().await?;
+        let failed_processing_last_24h = self
+            .query
+            .count_failed_media_since(now - Duration::hours(24))
+            .await?;
+
+        Ok(AdminStats {
+            users,
+            published_projects,
+            media,
+            failed_processing_last_24h,
+            generated_at: now,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+    use std::sync::{Arc, Mutex};
+
+    use crate::admin::application::ports::outgoing::{MediaCounts, UserCounts};
+
+    // ============================================================
+    // Mock Query
+    // ============================================================
+
+    #[derive(Clone, Default)]
+    struct MockStatsQuery {
+        fail_media: bool,
+        since: Arc<Mutex<Option<DateTime<Utc>>>>,
+    }
+
+    #[async_trait]
+    impl StatsQuery for MockStatsQuery {
+        async fn count_users(&self) -> Result<UserCounts, StatsQueryError> {
+            Ok(UserCounts {
+                total: 3,
+                verified: 2,
+            })
+        }
+
+        async fn count_published_projects(&self) -> Result<u64, StatsQueryError> {
+            Ok(5)
+        }
+
+        async fn media_counts(&self) -> Result<MediaCounts, StatsQueryError> {
+            if self.fail_media {
+                return Err(StatsQueryError::DatabaseError("db down".to_string()));
+            }
+            Ok(MediaCounts {
+                pending: 1,
+                processing: 0,
+                ready: 7,
+                failed: 2,
+                storage_bytes: 4096,
+            })
+        }
+
+        async fn count_failed_media_since(
+            &self,
+            since: DateTime<Utc>,
+        ) -> Result<u64, StatsQueryError> {
+            *self.since.lock().unwrap() = Some(since);
+            Ok(1)
+        }
+    }
+
+    // ============================================================
+    // Tests
+    // ============================================================
+
+    #[tokio::test]
+    async fn test_get_admin_stats_combines_every_aggregate() {
+        // Arrange
+        let query = MockStatsQuery::default();
+        let service = GetAdminStatsService::new(query.clone());
+
+        // Act
+        let stats = service.execute().await.unwrap();
+
+        // Assert
+        assert_eq!(stats.users.total, 3);
+        assert_eq!(stats.users.verified, 2);
+        assert_eq!(stats.published_projects, 5);
+        assert_eq!(stats.media.ready, 7);
+        assert_eq!(stats.media.storage_bytes, 4096);
+        assert_eq!(stats.failed_processing_last_24h, 1);
+
+        let since = query
+            .since
+            .lock()
+            .unwrap()
+            .expect("failed count was queried");
+        assert_eq!(stats.generated_at - since, Duration::hours(24));
+    }
+
+    #[tokio::test]
+    async fn test_get_admin_stats_query_failure() {
+        // Arrange
+        let query = MockStatsQuery {
+            fail_media: true,
+            ..Default::default()
+        };
+        let service = GetAdminStatsService::new(query);
+
+        // Act
+        let result = service.execute().await;
+
+        // Assert
+        match result {
+            Err(GetAdminStatsError::QueryFailed(msg)) => assert!(msg.contains("db down")),
+            other => panic!("Expected QueryFailed error, got {:?}", other),
+        }
+    }
+}
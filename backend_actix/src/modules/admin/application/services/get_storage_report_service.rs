@@ -0,0 +1,189 @@
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::admin::application::ports::{
+    incoming::use_cases::{
+        month_starts, GetStorageReportUseCase, StorageReport, StorageReportCommand,
+        StorageReportError,
+    },
+    outgoing::{StorageGrouping, StorageReportQuery, StorageReportQueryError, StorageUsage},
+};
+
+#[derive(Debug, Clone)]
+pub struct GetStorageReportService<Q>
+where
+    Q: StorageReportQuery + Send + Sync,
+{
+    query: Q,
+}
+
+impl<Q> GetStorageReportService<Q>
+where
+    Q: StorageReportQuery + Send + Sync,
+{
+    pub fn new(query: Q) -> Self {
+        Self { query }
+    }
+}
+
+impl From<StorageReportQueryError> for StorageReportError {
+    fn from(err: StorageReportQueryError) -> Self {
+        StorageReportError::QueryFailed(err.to_string())
+    }
+}
+
+#[async_trait]
+impl<Q> GetStorageReportUseCase for GetStorageReportService<Q>
+where
+    Q: StorageReportQuery + Send + Sync,
+{
+    async fn execute(
+        &self,
+        command: StorageReportCommand,
+    ) -> Result<StorageReport, StorageReportError> {
+        let now = Utc::now();
+        let (this_month, last_month) = month_starts(now);
+
+        // Sequential on purpose: the pool is small and each query is a single aggregate
+        let by_bucket = self
+            .query
+            .usage_by(StorageGrouping::Bucket, this_month, last_month, None)
+            .await?;
+        let by_size = self
+            .query
+            .usage_by(StorageGrouping::Size, this_month, last_month, None)
+            .await?;
+        let by_format = self
+            .query
+            .usage_by(StorageGrouping::Format, this_month, last_month, None)
+            .await?;
+        let by_user = self
+            .query
+            .usage_by(
+                StorageGrouping::Owner,
+                this_month,
+                last_month,
+                Some(command.top_users()),
+            )
+            .await?;
+
+        // Every variant lives in exactly one bucket
+        let total = by_bucket
+            .iter()
+            .fold(StorageUsage::default(), |mut total, group| {
+                total.add(&group.usage);
+                total
+            });
+
+        Ok(StorageReport {
+            total,
+            by_bucket,
+            by_size,
+            by_format,
+            by_user,
+            this_month_from: this_month,
+            last_month_from: last_month,
+            generated_at: now,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Datelike};
+    use std::sync::{Arc, Mutex};
+
+    use crate::admin::application::ports::incoming::use_cases::StorageGroup;
+
+    /// Grouping, reference time and top-N limit of one query
+    type ReportCall = (StorageGrouping, DateTime<Utc>, Option<u32>);
+
+    #[derive(Clone, Default)]
+    struct MockStorageReportQuery {
+        fail: bool,
+        calls: Arc<Mutex<Vec<ReportCall>>>,
+    }
+
+    fn group(key: &str, bytes: u64, this_month: u64) -> StorageGroup {
+        StorageGroup {
+            key: key.to_string(),
+            usage: StorageUsage {
+                objects: 2,
+                bytes,
+                bytes_added_this_month: this_month,
+                bytes_added_last_month: 0,
+            },
+        }
+    }
+
+    #[async_trait]
+    impl StorageReportQuery for MockStorageReportQuery {
+        async fn usage_by(
+            &self,
+            grouping: StorageGrouping,
+            this_month: DateTime<Utc>,
+            _last_month: DateTime<Utc>,
+            limit: Option<u32>,
+        ) -> Result<Vec<StorageGroup>, StorageReportQueryError> {
+            if self.fail {
+                return Err(StorageReportQueryError::DatabaseError(
+                    "db down".to_string(),
+                ));
+            }
+            self.calls
+                .lock()
+                .unwrap()
+                .push((grouping, this_month, limit));
+            Ok(match grouping {
+                StorageGrouping::Bucket => vec![group("media", 300, 100), group("cold", 50, 0)],
+                _ => vec![group("other", 350, 100)],
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_report_totals_the_buckets() {
+        let query = MockStorageReportQuery::default();
+        let service = GetStorageReportService::new(query.clone());
+
+        let report = service
+            .execute(StorageReportCommand::new(Some(5)).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(report.total.objects, 4);
+        assert_eq!(report.total.bytes, 350);
+        assert_eq!(report.total.bytes_added_this_month, 100);
+        assert_eq!(report.by_bucket.len(), 2);
+        assert_eq!(report.this_month_from.day(), 1);
+        assert!(report.last_month_from < report.this_month_from);
+
+        let calls = query.calls.lock().unwrap();
+        assert_eq!(calls.len(), 4);
+        assert!(calls
+            .iter()
+            .all(|(_, from, _)| *from == report.this_month_from));
+        assert_eq!(
+            calls[3],
+            (StorageGrouping::Owner, report.this_month_from, Some(5))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_query_failure() {
+        let service = GetStorageReportService::new(MockStorageReportQuery {
+            fail: true,
+            ..Default::default()
+        });
+
+        let result = service
+            .execute(StorageReportCommand::new(None).unwrap())
+            .await;
+
+        match result {
+            Err(StorageReportError::QueryFailed(msg)) => assert!(msg.contains("db down")),
+            other => panic!("Expected QueryFailed error, got {:?}", other),
+        }
+    }
+}
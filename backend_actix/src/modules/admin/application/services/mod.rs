@@ -0,0 +1,8 @@
+mod check_integrity_service;
+mod get_admin_stats_service;
+mod get_storage_report_service;
+mod quick_search_service;
+pub use check_integrity_service::CheckIntegrityService;
+pub use get_admin_stats_service::GetAdminStatsService;
+pub use get_storage_report_service::GetStorageReportService;
+pub use quick_search_service::QuickSearchService;
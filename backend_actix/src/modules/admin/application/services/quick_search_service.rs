@@ -0,0 +1,105 @@
+use async_trait::async_trait;
+
+use crate::admin::application::ports::{
+    incoming::use_cases::{
+        QuickSearchCommand, QuickSearchError, QuickSearchHit, QuickSearchUseCase,
+    },
+    outgoing::{QuickSearchQuery, QuickSearchQueryError},
+};
+
+#[derive(Debug, Clone)]
+pub struct QuickSearchService<Q>
+where
+    Q: QuickSearchQuery + Send + Sync,
+{
+    query: Q,
+}
+
+impl<Q> QuickSearchService<Q>
+where
+    Q: QuickSearchQuery + Send + Sync,
+{
+    pub fn new(query: Q) -> Self {
+        Self { query }
+    }
+}
+
+impl From<QuickSearchQueryError> for QuickSearchError {
+    fn from(err: QuickSearchQueryError) -> Self {
+        QuickSearchError::QueryFailed(err.to_string())
+    }
+}
+
+#[async_trait]
+impl<Q> QuickSearchUseCase for QuickSearchService<Q>
+where
+    Q: QuickSearchQuery + Send + Sync,
+{
+    async fn execute(
+        &self,
+        command: QuickSearchCommand,
+    ) -> Result<Vec<QuickSearchHit>, QuickSearchError> {
+        Ok(self.query.search(&command).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    use crate::admin::application::ports::incoming::use_cases::{MatchedField, QuickLink};
+
+    struct MockQuickSearchQuery {
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl QuickSearchQuery for MockQuickSearchQuery {
+        async fn search(
+            &self,
+            command: &QuickSearchCommand,
+        ) -> Result<Vec<QuickSearchHit>, QuickSearchQueryError> {
+            if self.fail {
+                return Err(QuickSearchQueryError::DatabaseError("db down".to_string()));
+            }
+            Ok(vec![QuickSearchHit {
+                label: "ada".to_string(),
+                detail: Some(format!("{}@example.com", command.text())),
+                matched: MatchedField::Username,
+                deleted: false,
+                link: QuickLink::User {
+                    id: Uuid::new_v4(),
+                    username: "ada".to_string(),
+                },
+            }])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_quick_search_returns_the_hits() {
+        let service = QuickSearchService::new(MockQuickSearchQuery { fail: false });
+
+        let hits = service
+            .execute(QuickSearchCommand::new("Ada", None).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].detail.as_deref(), Some("ada@example.com"));
+    }
+
+    #[tokio::test]
+    async fn test_quick_search_query_failure() {
+        let service = QuickSearchService::new(MockQuickSearchQuery { fail: true });
+
+        let result = service
+            .execute(QuickSearchCommand::new("ada", None).unwrap())
+            .await;
+
+        match result {
+            Err(QuickSearchError::QueryFailed(msg)) => assert!(msg.contains("db down")),
+            other => panic!("Expected QueryFailed error, got {:?}", other),
+        }
+    }
+}
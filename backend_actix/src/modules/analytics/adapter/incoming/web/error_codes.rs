@@ -0,0 +1,7 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    INVALID_PATH = (BAD_REQUEST, "Invalid page path");
+    INVALID_RANGE = (BAD_REQUEST, "Invalid date range");
+    INVALID_LIMIT = (BAD_REQUEST, "Limit is out of range");
+}
@@ -0,0 +1,16 @@
+use actix_web::web;
+
+pub mod error_codes;
+pub mod routes;
+
+/// Routes anyone can call
+pub fn configure_public(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::record_page_view_handler);
+}
+
+/// Routes for signed-in users with a verified email
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::get_top_pages_handler)
+        .service(routes::get_top_referrers_handler)
+        .service(routes::get_daily_visitors_handler);
+}
@@ -0,0 +1,101 @@
+use actix_web::{get, web, HttpRequest, Responder};
+use tracing::error;
+
+use super::ReportQuery;
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    analytics::application::{
+        domain::entities::DailyVisitors, ports::incoming::use_cases::AnalyticsReportError,
+    },
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    shared::api::ApiResponse,
+    AppState,
+};
+
+/// Views and unique visitors per day
+///
+/// Every day of the range is listed, oldest first, with zeros for days
+/// without views.
+#[utoipa::path(
+    get,
+    path = "/api/admin/analytics/daily",
+    tag = "analytics",
+    params(
+        ("from" = Option<String>, Query, description = "First UTC day, YYYY-MM-DD (default: 29 days before `to`)"),
+        ("to" = Option<String>, Query, description = "Last UTC day, YYYY-MM-DD (default: today); at most 366 days in all"),
+    ),
+    responses(
+        (status = 200, description = "One entry per day", body = inline(SuccessResponse<Vec<DailyVisitors>>)),
+        (status = 400, description = "Invalid range", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/admin/analytics/daily")]
+pub async fn get_daily_visitors_handler(
+    admin: AdminUser,
+    req: HttpRequest,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let query = match ReportQuery::parse(req.query_string()) {
+        Ok(query) => query,
+        Err(response) => return response,
+    };
+
+    match data.analytics.daily_visitors.execute(query.range).await {
+        Ok(days) => ApiResponse::success(days),
+        Err(AnalyticsReportError::QueryFailed(msg)) => {
+            error!(admin = %admin.user_id, "Failed to report daily visitors: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service,
+        },
+    };
+
+    #[actix_web::test]
+    async fn test_admin_sees_daily_visitors() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .build();
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(admin, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(get_daily_visitors_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/api/admin/analytics/daily?from=2026-10-16&to=2026-10-16")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"][0]["day"], "2026-10-16");
+        assert_eq!(json["data"][0]["visitors"], 3);
+    }
+}
@@ -0,0 +1,171 @@
+use actix_web::{get, web, HttpRequest, Responder};
+use tracing::error;
+
+use super::ReportQuery;
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    analytics::application::{
+        domain::entities::PageStats, ports::incoming::use_cases::AnalyticsReportError,
+    },
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    shared::api::ApiResponse,
+    AppState,
+};
+
+/// Most viewed pages over a date range
+#[utoipa::path(
+    get,
+    path = "/api/admin/analytics/top-pages",
+    tag = "analytics",
+    params(
+        ("from" = Option<String>, Query, description = "First UTC day, YYYY-MM-DD (default: 29 days before `to`)"),
+        ("to" = Option<String>, Query, description = "Last UTC day, YYYY-MM-DD (default: today)"),
+        ("limit" = Option<u32>, Query, description = "Pages returned, 1-100 (default 10)"),
+    ),
+    responses(
+        (status = 200, description = "Pages by views, most viewed first", body = inline(SuccessResponse<Vec<PageStats>>)),
+        (status = 400, description = "Invalid range or limit", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/admin/analytics/top-pages")]
+pub async fn get_top_pages_handler(
+    admin: AdminUser,
+    req: HttpRequest,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let query = match ReportQuery::parse(req.query_string()) {
+        Ok(query) => query,
+        Err(response) => return response,
+    };
+
+    match data
+        .analytics
+        .top_pages
+        .execute(query.range, query.limit)
+        .await
+    {
+        Ok(pages) => ApiResponse::success(pages),
+        Err(AnalyticsReportError::QueryFailed(msg)) => {
+            error!(admin = %admin.user_id, "Failed to report top pages: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubGetTopPagesUseCase,
+        },
+    };
+
+    async fn call(
+        state: web::Data<AppState>,
+        user_id: Uuid,
+        uri: &str,
+    ) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(user_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(get_top_pages_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_admin_sees_top_pages() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .build();
+
+        let resp = call(
+            state,
+            admin,
+            "/api/admin/analytics/top-pages?from=2026-10-01&to=2026-10-16&limit=5",
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"][0]["path"], "/projects");
+        assert_eq!(json["data"][0]["views"], 12);
+    }
+
+    #[actix_web::test]
+    async fn test_invalid_range_and_limit_are_rejected() {
+        let admin = Uuid::new_v4();
+
+        for (uri, code) in [
+            (
+                "/api/admin/analytics/top-pages?from=yesterday",
+                "INVALID_RANGE",
+            ),
+            (
+                "/api/admin/analytics/top-pages?from=2026-10-16&to=2026-10-01",
+                "INVALID_RANGE",
+            ),
+            ("/api/admin/analytics/top-pages?limit=0", "INVALID_LIMIT"),
+        ] {
+            let state = TestAppStateBuilder::default()
+                .with_admin_user_ids(vec![admin])
+                .build();
+
+            let resp = call(state, admin, uri).await;
+
+            assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "{uri}");
+            let json: serde_json::Value = test::read_body_json(resp).await;
+            assert_eq!(json["error"]["code"], code, "{uri}");
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_non_admin_is_forbidden() {
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![Uuid::new_v4()])
+            .build();
+
+        let resp = call(state, Uuid::new_v4(), "/api/admin/analytics/top-pages").await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn test_query_failure_returns_internal_error() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .with_get_top_pages(StubGetTopPagesUseCase::failure("db down"))
+            .build();
+
+        let resp = call(state, admin, "/api/admin/analytics/top-pages").await;
+
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,71 @@
+mod get_daily_visitors;
+mod get_top_pages;
+mod get_top_referrers;
+mod record_page_view;
+
+pub use get_daily_visitors::{__path_get_daily_visitors_handler, get_daily_visitors_handler};
+pub use get_top_pages::{__path_get_top_pages_handler, get_top_pages_handler};
+pub use get_top_referrers::{__path_get_top_referrers_handler, get_top_referrers_handler};
+pub use record_page_view::{__path_record_page_view_handler, record_page_view_handler};
+
+use actix_web::{http::header, web, HttpRequest, HttpResponse};
+use chrono::{NaiveDate, Utc};
+use serde::Deserialize;
+
+use crate::analytics::adapter::incoming::web::error_codes::{INVALID_LIMIT, INVALID_RANGE};
+use crate::analytics::application::ports::incoming::use_cases::{
+    RecordPageViewCommand, RecordPageViewCommandError, ReportRange,
+};
+use crate::shared::client_ip::client_ip;
+
+const DEFAULT_REPORT_LIMIT: u32 = 10;
+const MAX_REPORT_LIMIT: u32 = 100;
+
+#[derive(Debug, Deserialize)]
+struct RawReportQuery {
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+    limit: Option<u32>,
+}
+
+/// What a report covers, from `?from=YYYY-MM-DD&to=YYYY-MM-DD&limit=N`
+pub(crate) struct ReportQuery {
+    pub(crate) range: ReportRange,
+    pub(crate) limit: u32,
+}
+
+impl ReportQuery {
+    pub(crate) fn parse(query: &str) -> Result<Self, HttpResponse> {
+        let raw = web::Query::<RawReportQuery>::from_query(query)
+            .map_err(|e| INVALID_RANGE.with_message(&e.to_string()))?
+            .into_inner();
+
+        let range = ReportRange::new(raw.from, raw.to, Utc::now().date_naive())
+            .map_err(|e| INVALID_RANGE.with_message(&e.to_string()))?;
+
+        let limit = raw.limit.unwrap_or(DEFAULT_REPORT_LIMIT);
+        if !(1..=MAX_REPORT_LIMIT).contains(&limit) {
+            return Err(INVALID_LIMIT
+                .with_message(&format!("limit must be between 1 and {MAX_REPORT_LIMIT}")));
+        }
+
+        Ok(Self { range, limit })
+    }
+}
+
+/// A view of `path` by the client of `req`, which is only identified by its
+/// address and user agent
+pub(crate) fn page_view_of(
+    req: &HttpRequest,
+    path: String,
+    referrer: Option<String>,
+) -> Result<RecordPageViewCommand, RecordPageViewCommandError> {
+    let client_ip = client_ip(req).map(|ip| ip.to_string());
+    let user_agent = req
+        .headers()
+        .get(header::USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    RecordPageViewCommand::new(path, referrer, client_ip, user_agent)
+}
@@ -0,0 +1,104 @@
+use actix_web::{post, web, HttpRequest, Responder};
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use super::page_view_of;
+use crate::analytics::adapter::incoming::web::error_codes::INVALID_PATH;
+use crate::api::schemas::ErrorResponse;
+use crate::{
+    analytics::application::ports::incoming::use_cases::RecordPageViewCommandError,
+    shared::api::ApiResponse, AppState,
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RecordPageViewRequest {
+    /// Path of the viewed page, starting with '/'; the query string is dropped
+    pub path: String,
+    /// `document.referrer`; only its host is kept
+    pub referrer: Option<String>,
+}
+
+/// Count a page view
+///
+/// Sets no cookie and stores neither the client address nor the user agent:
+/// the visitor is a hash of both and the current day, so a visitor can't be
+/// followed from one day to the next. Views are written in batches, so
+/// reports trail by a few seconds. Crawlers are not counted.
+#[utoipa::path(
+    post,
+    path = "/api/public/analytics/pageview",
+    tag = "analytics",
+    request_body = RecordPageViewRequest,
+    responses(
+        (status = 204, description = "View accepted"),
+        (status = 400, description = "Invalid path or malformed request body", body = ErrorResponse),
+    )
+)]
+#[post("/api/public/analytics/pageview")]
+pub async fn record_page_view_handler(
+    req: HttpRequest,
+    payload: web::Json<RecordPageViewRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let payload = payload.into_inner();
+    let command = match page_view_of(&req, payload.path, payload.referrer) {
+        Ok(cmd) => cmd,
+        Err(err @ RecordPageViewCommandError::InvalidPath) => {
+            return INVALID_PATH.with_message(&err.to_string())
+        }
+    };
+
+    data.analytics.record.execute(command).await;
+    ApiResponse::no_content()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{
+        http::{header, StatusCode},
+        test, App,
+    };
+    use serde_json::json;
+
+    use crate::tests::support::app_state_builder::TestAppStateBuilder;
+
+    #[actix_web::test]
+    async fn test_view_is_accepted() {
+        let app = test::init_service(
+            App::new()
+                .app_data(TestAppStateBuilder::default().build())
+                .service(record_page_view_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/public/analytics/pageview")
+            .insert_header((header::USER_AGENT, "Mozilla/5.0 Firefox/131.0"))
+            .set_json(json!({ "path": "/projects?page=2", "referrer": "https://example.com/" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[actix_web::test]
+    async fn test_invalid_path_is_rejected() {
+        let app = test::init_service(
+            App::new()
+                .app_data(TestAppStateBuilder::default().build())
+                .service(record_page_view_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/public/analytics/pageview")
+            .set_json(json!({ "path": "https://example.com/" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "INVALID_PATH");
+    }
+}
@@ -0,0 +1,155 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::analytics::application::domain::entities::{
+    DailyVisitors, PageStats, PageView, ReferrerStats,
+};
+use crate::analytics::application::ports::incoming::use_cases::ReportRange;
+use crate::analytics::application::ports::outgoing::{PageViewRepository, PageViewRepositoryError};
+use crate::shared::in_memory::Table;
+
+/// Process-local `PageViewRepository`
+#[derive(Clone, Default)]
+pub struct InMemoryPageViewStore {
+    pub(crate) views: Table<PageView>,
+}
+
+/// Views and distinct visitors per key. Visitor ids change daily, so a
+/// visitor is counted once per day, as in the Postgres reports.
+#[derive(Default)]
+struct Tally<'a> {
+    views: u64,
+    visitors: HashSet<&'a str>,
+}
+
+impl InMemoryPageViewStore {
+    /// Tallies the views in `range` by `key`, most viewed first, ties by key
+    fn tally<K>(
+        &self,
+        range: ReportRange,
+        key: impl Fn(&PageView) -> Option<K>,
+    ) -> Vec<(K, u64, u64)>
+    where
+        K: Eq + Ord + std::hash::Hash,
+    {
+        self.views.read(|views| {
+            let mut tallies: HashMap<K, Tally<'_>> = HashMap::new();
+            for view in views
+                .iter()
+                .filter(|view| view.viewed_at >= range.start() && view.viewed_at < range.end())
+            {
+                let Some(key) = key(view) else {
+                    continue;
+                };
+                let tally = tallies.entry(key).or_default();
+                tally.views += 1;
+                tally.visitors.insert(view.visitor_id.as_str());
+            }
+
+            let mut rows: Vec<(K, u64, u64)> = tallies
+                .into_iter()
+                .map(|(key, tally)| (key, tally.views, tally.visitors.len() as u64))
+                .collect();
+            rows.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            rows
+        })
+    }
+
+    /// Days with views in `range`, of every path or only `path`, oldest first
+    fn days(&self, range: ReportRange, path: Option<&str>) -> Vec<DailyVisitors> {
+        let days: BTreeMap<NaiveDate, (u64, u64)> = self
+            .tally(range, |view| {
+                path.is_none_or(|path| view.path == path)
+                    .then(|| view.viewed_at.date_naive())
+            })
+            .into_iter()
+            .map(|(day, views, visitors)| (day, (views, visitors)))
+            .collect();
+
+        days.into_iter()
+            .map(|(day, (views, visitors))| DailyVisitors {
+                day,
+                views,
+                visitors,
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl PageViewRepository for InMemoryPageViewStore {
+    async fn insert_batch(&self, views: &[PageView]) -> Result<(), PageViewRepositoryError> {
+        self.views.write(|rows| rows.extend_from_slice(views));
+        Ok(())
+    }
+
+    async fn top_pages(
+        &self,
+        range: ReportRange,
+        limit: u32,
+    ) -> Result<Vec<PageStats>, PageViewRepositoryError> {
+        Ok(self
+            .tally(range, |view| Some(view.path.clone()))
+            .into_iter()
+            .take(limit as usize)
+            .map(|(path, views, visitors)| PageStats {
+                path,
+                views,
+                visitors,
+            })
+            .collect())
+    }
+
+    async fn top_referrers(
+        &self,
+        range: ReportRange,
+        limit: u32,
+    ) -> Result<Vec<ReferrerStats>, PageViewRepositoryError> {
+        Ok(self
+            .tally(range, |view| view.referrer.clone())
+            .into_iter()
+            .take(limit as usize)
+            .map(|(referrer, views, visitors)| ReferrerStats {
+                referrer,
+                views,
+                visitors,
+            })
+            .collect())
+    }
+
+    async fn daily_visitors(
+        &self,
+        range: ReportRange,
+    ) -> Result<Vec<DailyVisitors>, PageViewRepositoryError> {
+        Ok(self.days(range, None))
+    }
+
+    async fn path_referrers(
+        &self,
+        path: &str,
+        range: ReportRange,
+        limit: u32,
+    ) -> Result<Vec<ReferrerStats>, PageViewRepositoryError> {
+        Ok(self
+            .tally(range, |view| {
+                view.referrer.clone().filter(|_| view.path == path)
+            })
+            .into_iter()
+            .take(limit as usize)
+            .map(|(referrer, views, visitors)| ReferrerStats {
+                referrer,
+                views,
+                visitors,
+            })
+            .collect())
+    }
+
+    async fn path_daily_visitors(
+        &self,
+        path: &str,
+        range: ReportRange,
+    ) -> Result<Vec<DailyVisitors>, PageViewRepositoryError> {
+        Ok(self.days(range, Some(path)))
+    }
+}
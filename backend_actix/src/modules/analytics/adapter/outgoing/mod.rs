@@ -0,0 +1,5 @@
+mod in_memory;
+mod page_view_repository_postgres;
+
+pub use in_memory::InMemoryPageViewStore;
+pub use page_view_repository_postgres::PageViewRepositoryPostgres;
@@ -0,0 +1,385 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement,
+    TransactionTrait, Value,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::analytics::application::domain::entities::{
+    DailyVisitors, PageStats, PageView, ReferrerStats,
+};
+use crate::analytics::application::ports::incoming::use_cases::ReportRange;
+use crate::analytics::application::ports::outgoing::{PageViewRepository, PageViewRepositoryError};
+use crate::shared::sql;
+
+/// Rows per `INSERT`, well under the bind parameter limits of both backends
+const INSERT_CHUNK: usize = 1000;
+
+#[derive(Clone)]
+pub struct PageViewRepositoryPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl PageViewRepositoryPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    fn insert_stmt(backend: DatabaseBackend, views: &[PageView]) -> Statement {
+        let mut values: Vec<Value> = Vec::with_capacity(views.len() * 5);
+        let rows = views
+            .iter()
+            .enumerate()
+            .map(|(i, view)| {
+                values.extend([
+                    Uuid::new_v4().into(),
+                    view.path.clone().into(),
+                    view.referrer.clone().into(),
+                    view.visitor_id.clone().into(),
+                    view.viewed_at.into(),
+                ]);
+                let n = i * 5;
+                format!(
+                    "(${}, ${}, ${}, ${}, ${})",
+                    n + 1,
+                    n + 2,
+                    n + 3,
+                    n + 4,
+                    n + 5
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                "INSERT INTO page_views (id, path, referrer, visitor_id, viewed_at) VALUES {rows}"
+            ),
+            values,
+        )
+    }
+
+    /// Views and visitors in `range` grouped by `column`, most viewed first;
+    /// only the views of `path` when given
+    fn top_stmt(
+        backend: DatabaseBackend,
+        column: &str,
+        path: Option<&str>,
+        range: ReportRange,
+        limit: u32,
+    ) -> Statement {
+        let mut values: Vec<Value> = vec![range.start().into(), range.end().into()];
+        let path_filter = Self::path_filter(path, &mut values);
+        values.push((limit as i64).into());
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                SELECT {column}, COUNT(*) AS views, COUNT(DISTINCT visitor_id) AS visitors
+                FROM page_views
+                WHERE viewed_at >= $1 AND viewed_at < $2 AND {column} IS NOT NULL{path_filter}
+                GROUP BY {column}
+                ORDER BY views DESC, {column}
+                LIMIT ${limit_param}
+                "#,
+                limit_param = values.len(),
+            ),
+            values,
+        )
+    }
+
+    fn daily_stmt(backend: DatabaseBackend, path: Option<&str>, range: ReportRange) -> Statement {
+        let mut values: Vec<Value> = vec![range.start().into(), range.end().into()];
+        let path_filter = Self::path_filter(path, &mut values);
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                SELECT {day} AS day, COUNT(*) AS views, COUNT(DISTINCT visitor_id) AS visitors
+                FROM page_views
+                WHERE viewed_at >= $1 AND viewed_at < $2{path_filter}
+                GROUP BY 1
+                ORDER BY 1
+                "#,
+                day = sql::utc_day(backend, "viewed_at"),
+            ),
+            values,
+        )
+    }
+
+    /// `AND path = $n` with `path` bound next, or nothing
+    fn path_filter(path: Option<&str>, values: &mut Vec<Value>) -> String {
+        match path {
+            Some(path) => {
+                values.push(path.into());
+                format!(" AND path = ${}", values.len())
+            }
+            None => String::new(),
+        }
+    }
+
+    // =====================================================
+    // Mapping
+    // =====================================================
+
+    fn counts(row: &QueryResult) -> Result<(u64, u64), PageViewRepositoryError> {
+        let views: i64 = row.try_get("", "views").map_err(Self::map_db_err)?;
+        let visitors: i64 = row.try_get("", "visitors").map_err(Self::map_db_err)?;
+        Ok((views.max(0) as u64, visitors.max(0) as u64))
+    }
+
+    fn to_page_stats(row: &QueryResult) -> Result<PageStats, PageViewRepositoryError> {
+        let (views, visitors) = Self::counts(row)?;
+        Ok(PageStats {
+            path: row.try_get("", "path").map_err(Self::map_db_err)?,
+            views,
+            visitors,
+        })
+    }
+
+    fn to_referrer_stats(row: &QueryResult) -> Result<ReferrerStats, PageViewRepositoryError> {
+        let (views, visitors) = Self::counts(row)?;
+        Ok(ReferrerStats {
+            referrer: row.try_get("", "referrer").map_err(Self::map_db_err)?,
+            views,
+            visitors,
+        })
+    }
+
+    fn to_daily_visitors(row: &QueryResult) -> Result<DailyVisitors, PageViewRepositoryError> {
+        let (views, visitors) = Self::counts(row)?;
+        let day: String = row.try_get("", "day").map_err(Self::map_db_err)?;
+        Ok(DailyVisitors {
+            day: NaiveDate::parse_from_str(&day, "%Y-%m-%d").map_err(|e| {
+                PageViewRepositoryError::DatabaseError(format!("invalid day '{day}': {e}"))
+            })?,
+            views,
+            visitors,
+        })
+    }
+
+    fn map_db_err(e: DbErr) -> PageViewRepositoryError {
+        PageViewRepositoryError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl PageViewRepository for PageViewRepositoryPostgres {
+    async fn insert_batch(&self, views: &[PageView]) -> Result<(), PageViewRepositoryError> {
+        if views.is_empty() {
+            return Ok(());
+        }
+
+        let backend = self.db.get_database_backend();
+        let txn = self.db.begin().await.map_err(Self::map_db_err)?;
+        for chunk in views.chunks(INSERT_CHUNK) {
+            txn.execute(Self::insert_stmt(backend, chunk))
+                .await
+                .map_err(Self::map_db_err)?;
+        }
+        txn.commit().await.map_err(Self::map_db_err)
+    }
+
+    async fn top_pages(
+        &self,
+        range: ReportRange,
+        limit: u32,
+    ) -> Result<Vec<PageStats>, PageViewRepositoryError> {
+        let stmt = Self::top_stmt(self.db.get_database_backend(), "path", None, range, limit);
+        self.db
+            .query_all(stmt)
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(Self::to_page_stats)
+            .collect()
+    }
+
+    async fn top_referrers(
+        &self,
+        range: ReportRange,
+        limit: u32,
+    ) -> Result<Vec<ReferrerStats>, PageViewRepositoryError> {
+        let stmt = Self::top_stmt(
+            self.db.get_database_backend(),
+            "referrer",
+            None,
+            range,
+            limit,
+        );
+        self.db
+            .query_all(stmt)
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(Self::to_referrer_stats)
+            .collect()
+    }
+
+    async fn daily_visitors(
+        &self,
+        range: ReportRange,
+    ) -> Result<Vec<DailyVisitors>, PageViewRepositoryError> {
+        let stmt = Self::daily_stmt(self.db.get_database_backend(), None, range);
+        self.db
+            .query_all(stmt)
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(Self::to_daily_visitors)
+            .collect()
+    }
+
+    async fn path_referrers(
+        &self,
+        path: &str,
+        range: ReportRange,
+        limit: u32,
+    ) -> Result<Vec<ReferrerStats>, PageViewRepositoryError> {
+        let backend = self.db.get_database_backend();
+        let stmt = Self::top_stmt(backend, "referrer", Some(path), range, limit);
+        self.db
+            .query_all(stmt)
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(Self::to_referrer_stats)
+            .collect()
+    }
+
+    async fn path_daily_visitors(
+        &self,
+        path: &str,
+        range: ReportRange,
+    ) -> Result<Vec<DailyVisitors>, PageViewRepositoryError> {
+        let stmt = Self::daily_stmt(self.db.get_database_backend(), Some(path), range);
+        self.db
+            .query_all(stmt)
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(Self::to_daily_visitors)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use sea_orm::{MockDatabase, MockExecResult};
+    use std::collections::BTreeMap;
+
+    fn range() -> ReportRange {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+        ReportRange::new(None, None, today).unwrap()
+    }
+
+    fn view(path: &str) -> PageView {
+        PageView {
+            path: path.to_string(),
+            referrer: None,
+            visitor_id: "0123456789abcdef0123456789abcdef".to_string(),
+            viewed_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_insert_numbers_placeholders_per_row() {
+        let stmt = PageViewRepositoryPostgres::insert_stmt(
+            DatabaseBackend::Postgres,
+            &[view("/a"), view("/b")],
+        );
+
+        assert!(stmt
+            .sql
+            .ends_with("VALUES ($1, $2, $3, $4, $5), ($6, $7, $8, $9, $10)"));
+        assert_eq!(stmt.values.unwrap().0.len(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_insert_batch_writes_in_chunks() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: INSERT_CHUNK as u64,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+            ])
+            .into_connection();
+        let repo = PageViewRepositoryPostgres::new(Arc::new(db));
+        let views = vec![view("/"); INSERT_CHUNK + 1];
+
+        repo.insert_batch(&views).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_daily_visitors_parses_days() {
+        let row = BTreeMap::from([
+            ("day".to_string(), Value::from("2026-10-14")),
+            ("views".to_string(), Value::BigInt(Some(3))),
+            ("visitors".to_string(), Value::BigInt(Some(2))),
+        ]);
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![row]])
+            .into_connection();
+        let repo = PageViewRepositoryPostgres::new(Arc::new(db));
+
+        let days = repo.daily_visitors(range()).await.unwrap();
+
+        assert_eq!(
+            days,
+            vec![DailyVisitors {
+                day: NaiveDate::from_ymd_opt(2026, 10, 14).unwrap(),
+                views: 3,
+                visitors: 2,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_daily_groups_by_utc_day_per_backend() {
+        let pg = PageViewRepositoryPostgres::daily_stmt(DatabaseBackend::Postgres, None, range());
+        let sqlite = PageViewRepositoryPostgres::daily_stmt(DatabaseBackend::Sqlite, None, range());
+
+        assert!(pg
+            .sql
+            .contains("to_char(viewed_at AT TIME ZONE 'UTC', 'YYYY-MM-DD')"));
+        assert!(sqlite.sql.contains("substr(viewed_at, 1, 10)"));
+    }
+
+    #[test]
+    fn test_path_filter_is_bound_before_the_limit() {
+        let all = PageViewRepositoryPostgres::top_stmt(
+            DatabaseBackend::Postgres,
+            "referrer",
+            None,
+            range(),
+            10,
+        );
+        assert!(all.sql.contains("LIMIT $3"));
+        assert!(!all.sql.contains("path ="));
+
+        let one = PageViewRepositoryPostgres::top_stmt(
+            DatabaseBackend::Postgres,
+            "referrer",
+            Some("/api/public/cvs/1"),
+            range(),
+            10,
+        );
+        assert!(one.sql.contains("AND path = $3"));
+        assert!(one.sql.contains("LIMIT $4"));
+        assert_eq!(one.values.unwrap().0[2], Value::from("/api/public/cvs/1"));
+    }
+}
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use crate::analytics::application::domain::visitor_id::VisitorHasher;
+use crate::analytics::application::ports::incoming::use_cases::{
+    GetDailyVisitorsUseCase, GetPathReportUseCase, GetTopPagesUseCase, GetTopReferrersUseCase,
+    RecordPageViewUseCase,
+};
+use crate::analytics::application::ports::outgoing::PageViewRepository;
+use crate::analytics::application::services::{
+    GetDailyVisitorsService, GetPathReportService, GetTopPagesService, GetTopReferrersService,
+    PageViewBuffer, RecordPageViewService,
+};
+use crate::shared::metrics::Metered;
+
+#[derive(Clone)]
+pub struct AnalyticsUseCases {
+    pub record: Arc<dyn RecordPageViewUseCase + Send + Sync>,
+    pub top_pages: Arc<dyn GetTopPagesUseCase + Send + Sync>,
+    pub top_referrers: Arc<dyn GetTopReferrersUseCase + Send + Sync>,
+    pub daily_visitors: Arc<dyn GetDailyVisitorsUseCase + Send + Sync>,
+    pub path_report: Arc<dyn GetPathReportUseCase + Send + Sync>,
+}
+
+impl AnalyticsUseCases {
+    /// Recorded views go to `buffer`; the `PageViewFlusher` draining it
+    /// writes them to `repository`
+    pub fn build<R>(visitor_hasher: VisitorHasher, buffer: PageViewBuffer, repository: R) -> Self
+    where
+        R: PageViewRepository + Clone + 'static,
+    {
+        Self {
+            record: Arc::new(RecordPageViewService::new(visitor_hasher, buffer)),
+            top_pages: Arc::new(Metered(GetTopPagesService::new(repository.clone()))),
+            top_referrers: Arc::new(Metered(GetTopReferrersService::new(repository.clone()))),
+            daily_visitors: Arc::new(Metered(GetDailyVisitorsService::new(repository.clone()))),
+            path_report: Arc::new(Metered(GetPathReportService::new(repository))),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Views of public CVs are recorded as page views of this path
+pub fn cv_view_path(cv_id: Uuid) -> String {
+    format!("/api/public/cvs/{cv_id}")
+}
+
+/// One page load as recorded: no cookie, no address, only a visitor hash
+/// that changes every day
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageView {
+    /// Path without query string or fragment, e.g. `/projects/rust-cms`
+    pub path: String,
+    /// Host of the referring site; `None` for direct visits
+    pub referrer: Option<String>,
+    pub visitor_id: String,
+    pub viewed_at: DateTime<Utc>,
+}
+
+/// Views of one path over a report range
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PageStats {
+    pub path: String,
+    pub views: u64,
+    /// Distinct visitors, counted per day
+    pub visitors: u64,
+}
+
+/// Views arriving from one referring host over a report range
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ReferrerStats {
+    pub referrer: String,
+    pub views: u64,
+    pub visitors: u64,
+}
+
+/// Views and unique visitors of one UTC day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct DailyVisitors {
+    pub day: NaiveDate,
+    pub views: u64,
+    pub visitors: u64,
+}
+
+/// Views of a single path over a report range
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PathReport {
+    pub views: u64,
+    /// Distinct visitors, counted per day
+    pub visitors: u64,
+    /// Every day of the range, oldest first
+    pub daily: Vec<DailyVisitors>,
+    /// Referring hosts with the most views first; direct visits are left out
+    pub referrers: Vec<ReferrerStats>,
+}
@@ -0,0 +1,85 @@
+//! Cookieless visitor ids.
+//!
+//! A visitor id is the first 16 bytes, in hex, of the HMAC-SHA256 of
+//! `"{day}|{client address}|{user agent}"` keyed with `ANALYTICS_SECRET`.
+//! The day is part of the input, so the same browser gets a new id every
+//! UTC day and visits can't be linked across days. Neither the address nor
+//! the user agent is stored.
+
+use chrono::NaiveDate;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::fmt;
+
+/// Bytes of the MAC kept in the id
+const VISITOR_ID_BYTES: usize = 16;
+
+#[derive(Clone)]
+pub struct VisitorHasher {
+    secret: Vec<u8>,
+}
+
+impl VisitorHasher {
+    pub fn new(secret: &str) -> Self {
+        Self {
+            secret: secret.as_bytes().to_vec(),
+        }
+    }
+
+    pub fn visitor_id(&self, day: NaiveDate, client_ip: &str, user_agent: &str) -> String {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC accepts any key length");
+        mac.update(day.to_string().as_bytes());
+        mac.update(b"|");
+        mac.update(client_ip.as_bytes());
+        mac.update(b"|");
+        mac.update(user_agent.as_bytes());
+        hex::encode(&mac.finalize().into_bytes()[..VISITOR_ID_BYTES])
+    }
+}
+
+/// Keeps the secret out of logs
+impl fmt::Debug for VisitorHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VisitorHasher").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "0123456789abcdef0123456789abcdef";
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, d).unwrap()
+    }
+
+    #[test]
+    fn test_same_visitor_same_day_gets_same_id() {
+        let hasher = VisitorHasher::new(SECRET);
+
+        let id = hasher.visitor_id(day(16), "203.0.113.7", "Firefox");
+
+        assert_eq!(id.len(), VISITOR_ID_BYTES * 2);
+        assert_eq!(id, hasher.visitor_id(day(16), "203.0.113.7", "Firefox"));
+    }
+
+    #[test]
+    fn test_id_changes_with_day_client_and_secret() {
+        let hasher = VisitorHasher::new(SECRET);
+        let id = hasher.visitor_id(day(16), "203.0.113.7", "Firefox");
+
+        assert_ne!(id, hasher.visitor_id(day(17), "203.0.113.7", "Firefox"));
+        assert_ne!(id, hasher.visitor_id(day(16), "203.0.113.8", "Firefox"));
+        assert_ne!(id, hasher.visitor_id(day(16), "203.0.113.7", "Safari"));
+        assert_ne!(
+            id,
+            VisitorHasher::new("fedcba9876543210fedcba9876543210").visitor_id(
+                day(16),
+                "203.0.113.7",
+                "Firefox"
+            )
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+
+use crate::analytics::application::domain::entities::DailyVisitors;
+use crate::analytics::application::ports::incoming::use_cases::{
+    AnalyticsReportError, ReportRange,
+};
+use crate::shared::metrics::metered_use_case;
+
+#[async_trait]
+pub trait GetDailyVisitorsUseCase: Send + Sync {
+    /// One entry per day of the range, oldest first, days without views included
+    async fn execute(&self, range: ReportRange)
+        -> Result<Vec<DailyVisitors>, AnalyticsReportError>;
+}
+
+metered_use_case!(
+    "analytics",
+    "get_daily_visitors",
+    GetDailyVisitorsUseCase,
+    fn execute(&self, range: ReportRange) -> Result<Vec<DailyVisitors>, AnalyticsReportError>
+);
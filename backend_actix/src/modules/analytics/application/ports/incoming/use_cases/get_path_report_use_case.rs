@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+
+use crate::analytics::application::domain::entities::PathReport;
+use crate::analytics::application::ports::incoming::use_cases::{
+    AnalyticsReportError, ReportRange,
+};
+use crate::shared::metrics::metered_use_case;
+
+#[async_trait]
+pub trait GetPathReportUseCase: Send + Sync {
+    /// Totals, every day of the range and the top `referrer_limit` referring
+    /// hosts of the views of one path
+    async fn execute(
+        &self,
+        path: &str,
+        range: ReportRange,
+        referrer_limit: u32,
+    ) -> Result<PathReport, AnalyticsReportError>;
+}
+
+metered_use_case!(
+    "analytics",
+    "get_path_report",
+    GetPathReportUseCase,
+    fn execute(
+        &self,
+        path: &str,
+        range: ReportRange,
+        referrer_limit: u32,
+    ) -> Result<PathReport, AnalyticsReportError>
+);
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+
+use crate::analytics::application::domain::entities::PageStats;
+use crate::analytics::application::ports::incoming::use_cases::{
+    AnalyticsReportError, ReportRange,
+};
+use crate::shared::metrics::metered_use_case;
+
+#[async_trait]
+pub trait GetTopPagesUseCase: Send + Sync {
+    /// Most viewed paths first
+    async fn execute(
+        &self,
+        range: ReportRange,
+        limit: u32,
+    ) -> Result<Vec<PageStats>, AnalyticsReportError>;
+}
+
+metered_use_case!(
+    "analytics",
+    "get_top_pages",
+    GetTopPagesUseCase,
+    fn execute(
+        &self,
+        range: ReportRange,
+        limit: u32,
+    ) -> Result<Vec<PageStats>, AnalyticsReportError>
+);
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+
+use crate::analytics::application::domain::entities::ReferrerStats;
+use crate::analytics::application::ports::incoming::use_cases::{
+    AnalyticsReportError, ReportRange,
+};
+use crate::shared::metrics::metered_use_case;
+
+#[async_trait]
+pub trait GetTopReferrersUseCase: Send + Sync {
+    /// Referring hosts with the most views first; direct visits are left out
+    async fn execute(
+        &self,
+        range: ReportRange,
+        limit: u32,
+    ) -> Result<Vec<ReferrerStats>, AnalyticsReportError>;
+}
+
+metered_use_case!(
+    "analytics",
+    "get_top_referrers",
+    GetTopReferrersUseCase,
+    fn execute(
+        &self,
+        range: ReportRange,
+        limit: u32,
+    ) -> Result<Vec<ReferrerStats>, AnalyticsReportError>
+);
@@ -0,0 +1,15 @@
+mod get_daily_visitors_use_case;
+mod get_path_report_use_case;
+mod get_top_pages_use_case;
+mod get_top_referrers_use_case;
+mod record_page_view_use_case;
+mod report_range;
+
+pub use get_daily_visitors_use_case::GetDailyVisitorsUseCase;
+pub use get_path_report_use_case::GetPathReportUseCase;
+pub use get_top_pages_use_case::GetTopPagesUseCase;
+pub use get_top_referrers_use_case::GetTopReferrersUseCase;
+pub use record_page_view_use_case::{
+    RecordPageViewCommand, RecordPageViewCommandError, RecordPageViewUseCase,
+};
+pub use report_range::{AnalyticsReportError, ReportRange, ReportRangeError};
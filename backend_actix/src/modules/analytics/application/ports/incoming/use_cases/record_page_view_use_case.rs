@@ -0,0 +1,185 @@
+use async_trait::async_trait;
+
+pub const MAX_PATH_LENGTH: usize = 500;
+pub const MAX_REFERRER_LENGTH: usize = 255;
+
+/// User agent fragments of crawlers and link previewers, lowercase
+const BOT_USER_AGENT_MARKERS: [&str; 6] =
+    ["bot", "crawler", "spider", "headless", "preview", "monitor"];
+
+//
+// ──────────────────────────────────────────────────────────
+// Record Page View Command
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone)]
+pub struct RecordPageViewCommand {
+    path: String,
+    referrer: Option<String>,
+    client_ip: String,
+    user_agent: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RecordPageViewCommandError {
+    #[error("Path must start with '/' and be at most {MAX_PATH_LENGTH} characters")]
+    InvalidPath,
+}
+
+impl RecordPageViewCommand {
+    /// The query string and fragment are dropped from `path`, and only the
+    /// host is kept of `referrer`; a referrer that isn't an http(s) URL is
+    /// treated as a direct visit.
+    pub fn new(
+        path: String,
+        referrer: Option<String>,
+        client_ip: Option<String>,
+        user_agent: Option<String>,
+    ) -> Result<Self, RecordPageViewCommandError> {
+        let path = path.trim();
+        let path = path.split(['?', '#']).next().unwrap_or_default();
+        if !path.starts_with('/')
+            || path.chars().count() > MAX_PATH_LENGTH
+            || path.chars().any(|c| c.is_whitespace() || c.is_control())
+        {
+            return Err(RecordPageViewCommandError::InvalidPath);
+        }
+
+        Ok(Self {
+            path: path.to_string(),
+            referrer: referrer.as_deref().and_then(referrer_host),
+            client_ip: client_ip.unwrap_or_default(),
+            user_agent: user_agent.unwrap_or_default(),
+        })
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn referrer(&self) -> Option<&str> {
+        self.referrer.as_deref()
+    }
+
+    pub fn client_ip(&self) -> &str {
+        &self.client_ip
+    }
+
+    pub fn user_agent(&self) -> &str {
+        &self.user_agent
+    }
+
+    /// Crawlers and clients that send no user agent are not counted
+    pub fn is_bot(&self) -> bool {
+        let user_agent = self.user_agent.to_ascii_lowercase();
+        user_agent.is_empty()
+            || BOT_USER_AGENT_MARKERS
+                .iter()
+                .any(|marker| user_agent.contains(marker))
+    }
+}
+
+/// `https://www.example.com:443/post?q=1` -> `www.example.com`
+fn referrer_host(referrer: &str) -> Option<String> {
+    let referrer = referrer.trim();
+    let (scheme, rest) = referrer.split_once("://")?;
+    if !scheme.eq_ignore_ascii_case("http") && !scheme.eq_ignore_ascii_case("https") {
+        return None;
+    }
+
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    // Credentials are never kept
+    let host_port = authority.rsplit('@').next().unwrap_or_default();
+    let host = host_port
+        .split(':')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+
+    let valid = !host.is_empty()
+        && host.len() <= MAX_REFERRER_LENGTH
+        && host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-');
+    valid.then_some(host)
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Incoming Port (Use Case)
+// ──────────────────────────────────────────────────────────
+//
+
+#[async_trait]
+pub trait RecordPageViewUseCase: Send + Sync {
+    /// Queues the view for the next batch write. Views from bots are dropped.
+    async fn execute(&self, command: RecordPageViewCommand);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(path: &str, referrer: Option<&str>) -> RecordPageViewCommand {
+        RecordPageViewCommand::new(
+            path.to_string(),
+            referrer.map(str::to_string),
+            Some("203.0.113.7".to_string()),
+            Some("Mozilla/5.0 Firefox/131.0".to_string()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_path_drops_query_and_fragment() {
+        assert_eq!(command(" /projects?page=2#top ", None).path(), "/projects");
+        assert_eq!(command("/", None).path(), "/");
+    }
+
+    #[test]
+    fn test_invalid_paths_are_rejected() {
+        let long = format!("/{}", "a".repeat(MAX_PATH_LENGTH));
+
+        for path in ["projects", "", "?q=1", "/with space", long.as_str()] {
+            assert!(
+                matches!(
+                    RecordPageViewCommand::new(path.to_string(), None, None, None),
+                    Err(RecordPageViewCommandError::InvalidPath)
+                ),
+                "{path:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_referrer_keeps_only_the_host() {
+        assert_eq!(
+            command("/", Some("https://User:pw@News.Example.com:443/item?id=1")).referrer(),
+            Some("news.example.com")
+        );
+        assert_eq!(
+            command("/", Some("http://localhost")).referrer(),
+            Some("localhost")
+        );
+        assert_eq!(
+            command("/", Some("android-app://com.slack")).referrer(),
+            None
+        );
+        assert_eq!(command("/", Some("not a url")).referrer(), None);
+        assert_eq!(command("/", Some("")).referrer(), None);
+    }
+
+    #[test]
+    fn test_bots_and_missing_user_agents_are_flagged() {
+        let with_agent = |agent: Option<&str>| {
+            RecordPageViewCommand::new("/".to_string(), None, None, agent.map(str::to_string))
+                .unwrap()
+        };
+
+        assert!(!with_agent(Some("Mozilla/5.0 Firefox/131.0")).is_bot());
+        assert!(with_agent(Some("Mozilla/5.0 (compatible; Googlebot/2.1)")).is_bot());
+        assert!(with_agent(Some("HeadlessChrome/120.0")).is_bot());
+        assert!(with_agent(None).is_bot());
+    }
+}
@@ -0,0 +1,109 @@
+use chrono::{DateTime, Days, NaiveDate, Utc};
+
+/// Days covered when the range is not given
+pub const DEFAULT_RANGE_DAYS: u64 = 30;
+pub const MAX_RANGE_DAYS: u64 = 366;
+
+/// UTC days a report covers, both ends included
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReportRange {
+    from: NaiveDate,
+    to: NaiveDate,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReportRangeError {
+    #[error("'from' must not be after 'to'")]
+    Inverted,
+
+    #[error("A report covers at most {MAX_RANGE_DAYS} days")]
+    TooLong,
+}
+
+impl ReportRange {
+    /// `to` defaults to `today`, `from` to the [`DEFAULT_RANGE_DAYS`] days
+    /// ending at `to`
+    pub fn new(
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+        today: NaiveDate,
+    ) -> Result<Self, ReportRangeError> {
+        let to = to.unwrap_or(today);
+        let from = from.unwrap_or_else(|| to - Days::new(DEFAULT_RANGE_DAYS - 1));
+
+        if from > to {
+            return Err(ReportRangeError::Inverted);
+        }
+        if (to - from).num_days() >= MAX_RANGE_DAYS as i64 {
+            return Err(ReportRangeError::TooLong);
+        }
+        Ok(Self { from, to })
+    }
+
+    pub fn from(&self) -> NaiveDate {
+        self.from
+    }
+
+    pub fn to(&self) -> NaiveDate {
+        self.to
+    }
+
+    /// First instant of the range
+    pub fn start(&self) -> DateTime<Utc> {
+        self.from.and_time(Default::default()).and_utc()
+    }
+
+    /// First instant after the range
+    pub fn end(&self) -> DateTime<Utc> {
+        (self.to + Days::new(1))
+            .and_time(Default::default())
+            .and_utc()
+    }
+
+    /// Every day of the range, oldest first
+    pub fn days(&self) -> impl Iterator<Item = NaiveDate> {
+        let to = self.to;
+        self.from.iter_days().take_while(move |day| *day <= to)
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum AnalyticsReportError {
+    #[error("Failed to build analytics report: {0}")]
+    QueryFailed(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_defaults_to_last_thirty_days() {
+        let range = ReportRange::new(None, None, day(10, 16)).unwrap();
+
+        assert_eq!(range.from(), day(9, 17));
+        assert_eq!(range.to(), day(10, 16));
+        assert_eq!(range.days().count(), DEFAULT_RANGE_DAYS as usize);
+        assert_eq!(range.start().to_rfc3339(), "2026-09-17T00:00:00+00:00");
+        assert_eq!(range.end().to_rfc3339(), "2026-10-17T00:00:00+00:00");
+    }
+
+    #[test]
+    fn test_rejects_inverted_and_overlong_ranges() {
+        let today = day(10, 16);
+
+        assert!(matches!(
+            ReportRange::new(Some(day(10, 2)), Some(day(10, 1)), today),
+            Err(ReportRangeError::Inverted)
+        ));
+        assert!(matches!(
+            ReportRange::new(Some(day(1, 1) - Days::new(MAX_RANGE_DAYS)), None, today),
+            Err(ReportRangeError::TooLong)
+        ));
+        assert!(ReportRange::new(Some(day(10, 16)), Some(day(10, 16)), today).is_ok());
+    }
+}
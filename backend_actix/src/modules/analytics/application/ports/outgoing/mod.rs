@@ -0,0 +1,3 @@
+pub mod page_view_repository;
+
+pub use page_view_repository::{PageViewRepository, PageViewRepositoryError};
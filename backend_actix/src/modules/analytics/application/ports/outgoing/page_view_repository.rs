@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+
+use crate::analytics::application::domain::entities::{
+    DailyVisitors, PageStats, PageView, ReferrerStats,
+};
+use crate::analytics::application::ports::incoming::use_cases::ReportRange;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum PageViewRepositoryError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait PageViewRepository: Send + Sync {
+    /// Stores a batch in one statement; `views` may be empty
+    async fn insert_batch(&self, views: &[PageView]) -> Result<(), PageViewRepositoryError>;
+
+    async fn top_pages(
+        &self,
+        range: ReportRange,
+        limit: u32,
+    ) -> Result<Vec<PageStats>, PageViewRepositoryError>;
+
+    async fn top_referrers(
+        &self,
+        range: ReportRange,
+        limit: u32,
+    ) -> Result<Vec<ReferrerStats>, PageViewRepositoryError>;
+
+    /// Only days with at least one view, oldest first
+    async fn daily_visitors(
+        &self,
+        range: ReportRange,
+    ) -> Result<Vec<DailyVisitors>, PageViewRepositoryError>;
+
+    /// [`Self::top_referrers`] of the views of one path
+    async fn path_referrers(
+        &self,
+        path: &str,
+        range: ReportRange,
+        limit: u32,
+    ) -> Result<Vec<ReferrerStats>, PageViewRepositoryError>;
+
+    /// [`Self::daily_visitors`] of one path
+    async fn path_daily_visitors(
+        &self,
+        path: &str,
+        range: ReportRange,
+    ) -> Result<Vec<DailyVisitors>, PageViewRepositoryError>;
+}
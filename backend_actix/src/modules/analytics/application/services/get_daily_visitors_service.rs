@@ -0,0 +1,116 @@
+use async_trait::async_trait;
+
+use crate::analytics::application::domain::entities::DailyVisitors;
+use crate::analytics::application::ports::{
+    incoming::use_cases::{AnalyticsReportError, GetDailyVisitorsUseCase, ReportRange},
+    outgoing::PageViewRepository,
+};
+
+pub struct GetDailyVisitorsService<R>
+where
+    R: PageViewRepository,
+{
+    repository: R,
+}
+
+impl<R> GetDailyVisitorsService<R>
+where
+    R: PageViewRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> GetDailyVisitorsUseCase for GetDailyVisitorsService<R>
+where
+    R: PageViewRepository,
+{
+    async fn execute(
+        &self,
+        range: ReportRange,
+    ) -> Result<Vec<DailyVisitors>, AnalyticsReportError> {
+        let recorded = self
+            .repository
+            .daily_visitors(range)
+            .await
+            .map_err(|e| AnalyticsReportError::QueryFailed(e.to_string()))?;
+
+        Ok(every_day(range, recorded))
+    }
+}
+
+/// The repository skips days without views; charts want every day
+pub(super) fn every_day(range: ReportRange, recorded: Vec<DailyVisitors>) -> Vec<DailyVisitors> {
+    let mut recorded = recorded.into_iter().peekable();
+    range
+        .days()
+        .map(|day| match recorded.next_if(|stats| stats.day == day) {
+            Some(stats) => stats,
+            None => DailyVisitors {
+                day,
+                views: 0,
+                visitors: 0,
+            },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, NaiveDate};
+
+    use crate::analytics::adapter::outgoing::InMemoryPageViewStore;
+    use crate::analytics::application::domain::entities::PageView;
+
+    fn at(day: u32, hour: u32) -> chrono::DateTime<chrono::Utc> {
+        NaiveDate::from_ymd_opt(2026, 10, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+            .and_utc()
+    }
+
+    fn view(visitor: &str, viewed_at: chrono::DateTime<chrono::Utc>) -> PageView {
+        PageView {
+            path: "/".to_string(),
+            referrer: None,
+            visitor_id: visitor.to_string(),
+            viewed_at,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_every_day_of_the_range_is_reported() {
+        let store = InMemoryPageViewStore::default();
+        store
+            .insert_batch(&[
+                view("a", at(14, 9)),
+                view("a", at(14, 10)),
+                view("b", at(14, 23)),
+                view("c", at(16, 0)),
+                view("d", at(17, 0)),
+            ])
+            .await
+            .unwrap();
+        let range = ReportRange::new(
+            Some(NaiveDate::from_ymd_opt(2026, 10, 14).unwrap()),
+            None,
+            NaiveDate::from_ymd_opt(2026, 10, 16).unwrap(),
+        )
+        .unwrap();
+
+        let days = GetDailyVisitorsService::new(store)
+            .execute(range)
+            .await
+            .unwrap();
+
+        let counts: Vec<(u32, u64, u64)> = days
+            .iter()
+            .map(|d| (d.day.day(), d.views, d.visitors))
+            .collect();
+        assert_eq!(counts, vec![(14, 3, 2), (15, 0, 0), (16, 1, 1)]);
+    }
+}
@@ -0,0 +1,121 @@
+use async_trait::async_trait;
+
+use super::get_daily_visitors_service::every_day;
+use crate::analytics::application::domain::entities::PathReport;
+use crate::analytics::application::ports::{
+    incoming::use_cases::{AnalyticsReportError, GetPathReportUseCase, ReportRange},
+    outgoing::{PageViewRepository, PageViewRepositoryError},
+};
+
+pub struct GetPathReportService<R>
+where
+    R: PageViewRepository,
+{
+    repository: R,
+}
+
+impl<R> GetPathReportService<R>
+where
+    R: PageViewRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> GetPathReportUseCase for GetPathReportService<R>
+where
+    R: PageViewRepository,
+{
+    async fn execute(
+        &self,
+        path: &str,
+        range: ReportRange,
+        referrer_limit: u32,
+    ) -> Result<PathReport, AnalyticsReportError> {
+        let query_failed =
+            |e: PageViewRepositoryError| AnalyticsReportError::QueryFailed(e.to_string());
+        let daily = self
+            .repository
+            .path_daily_visitors(path, range)
+            .await
+            .map_err(query_failed)?;
+        let referrers = self
+            .repository
+            .path_referrers(path, range, referrer_limit)
+            .await
+            .map_err(query_failed)?;
+
+        // Visitor ids change daily, so the days add up
+        Ok(PathReport {
+            views: daily.iter().map(|day| day.views).sum(),
+            visitors: daily.iter().map(|day| day.visitors).sum(),
+            daily: every_day(range, daily),
+            referrers,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, NaiveDate, Utc};
+
+    use crate::analytics::adapter::outgoing::InMemoryPageViewStore;
+    use crate::analytics::application::domain::entities::PageView;
+
+    fn at(day: u32) -> DateTime<Utc> {
+        NaiveDate::from_ymd_opt(2026, 10, day)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+            .and_utc()
+    }
+
+    fn view(path: &str, referrer: Option<&str>, visitor: &str, day: u32) -> PageView {
+        PageView {
+            path: path.to_string(),
+            referrer: referrer.map(str::to_string),
+            visitor_id: visitor.to_string(),
+            viewed_at: at(day),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_report_covers_only_the_path() {
+        let store = InMemoryPageViewStore::default();
+        store
+            .insert_batch(&[
+                view("/cv", Some("linkedin.com"), "a", 14),
+                view("/cv", Some("linkedin.com"), "a", 14),
+                view("/cv", None, "b", 16),
+                view("/cv", Some("mail.google.com"), "c", 16),
+                view("/projects", Some("news.example.com"), "d", 15),
+            ])
+            .await
+            .unwrap();
+        let range = ReportRange::new(
+            Some(NaiveDate::from_ymd_opt(2026, 10, 14).unwrap()),
+            None,
+            NaiveDate::from_ymd_opt(2026, 10, 16).unwrap(),
+        )
+        .unwrap();
+
+        let report = GetPathReportService::new(store)
+            .execute("/cv", range, 10)
+            .await
+            .unwrap();
+
+        assert_eq!(report.views, 4);
+        assert_eq!(report.visitors, 3);
+        let daily: Vec<u64> = report.daily.iter().map(|day| day.views).collect();
+        assert_eq!(daily, vec![2, 0, 2]);
+        let referrers: Vec<(&str, u64)> = report
+            .referrers
+            .iter()
+            .map(|r| (r.referrer.as_str(), r.views))
+            .collect();
+        assert_eq!(referrers, vec![("linkedin.com", 2), ("mail.google.com", 1)]);
+    }
+}
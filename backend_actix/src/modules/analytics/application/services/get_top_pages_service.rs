@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+
+use crate::analytics::application::domain::entities::PageStats;
+use crate::analytics::application::ports::{
+    incoming::use_cases::{AnalyticsReportError, GetTopPagesUseCase, ReportRange},
+    outgoing::PageViewRepository,
+};
+
+pub struct GetTopPagesService<R>
+where
+    R: PageViewRepository,
+{
+    repository: R,
+}
+
+impl<R> GetTopPagesService<R>
+where
+    R: PageViewRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> GetTopPagesUseCase for GetTopPagesService<R>
+where
+    R: PageViewRepository,
+{
+    async fn execute(
+        &self,
+        range: ReportRange,
+        limit: u32,
+    ) -> Result<Vec<PageStats>, AnalyticsReportError> {
+        self.repository
+            .top_pages(range, limit)
+            .await
+            .map_err(|e| AnalyticsReportError::QueryFailed(e.to_string()))
+    }
+}
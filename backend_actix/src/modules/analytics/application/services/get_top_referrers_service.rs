@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+
+use crate::analytics::application::domain::entities::ReferrerStats;
+use crate::analytics::application::ports::{
+    incoming::use_cases::{AnalyticsReportError, GetTopReferrersUseCase, ReportRange},
+    outgoing::PageViewRepository,
+};
+
+pub struct GetTopReferrersService<R>
+where
+    R: PageViewRepository,
+{
+    repository: R,
+}
+
+impl<R> GetTopReferrersService<R>
+where
+    R: PageViewRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> GetTopReferrersUseCase for GetTopReferrersService<R>
+where
+    R: PageViewRepository,
+{
+    async fn execute(
+        &self,
+        range: ReportRange,
+        limit: u32,
+    ) -> Result<Vec<ReferrerStats>, AnalyticsReportError> {
+        self.repository
+            .top_referrers(range, limit)
+            .await
+            .map_err(|e| AnalyticsReportError::QueryFailed(e.to_string()))
+    }
+}
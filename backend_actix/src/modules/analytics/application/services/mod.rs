@@ -0,0 +1,15 @@
+mod get_daily_visitors_service;
+mod get_path_report_service;
+mod get_top_pages_service;
+mod get_top_referrers_service;
+mod page_view_buffer;
+mod page_view_flusher;
+mod record_page_view_service;
+
+pub use get_daily_visitors_service::GetDailyVisitorsService;
+pub use get_path_report_service::GetPathReportService;
+pub use get_top_pages_service::GetTopPagesService;
+pub use get_top_referrers_service::GetTopReferrersService;
+pub use page_view_buffer::PageViewBuffer;
+pub use page_view_flusher::PageViewFlusher;
+pub use record_page_view_service::RecordPageViewService;
@@ -0,0 +1,126 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::sync::Notify;
+
+use crate::analytics::application::domain::entities::PageView;
+
+/// Pending views that wake the flusher before its interval is up
+pub const BATCH_SIZE: usize = 500;
+/// Views held while the database is unreachable; newer views are dropped past this
+pub const MAX_PENDING: usize = 10_000;
+
+/// Page views waiting to be written. Handlers push, [`super::PageViewFlusher`]
+/// takes them in batches. Clones share the same queue.
+#[derive(Clone, Default)]
+pub struct PageViewBuffer {
+    inner: Arc<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    views: Mutex<Vec<PageView>>,
+    batch_ready: Notify,
+    dropped: AtomicU64,
+}
+
+impl PageViewBuffer {
+    pub fn push(&self, view: PageView) {
+        let mut views = self.lock();
+        if views.len() >= MAX_PENDING {
+            self.inner.dropped.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+        views.push(view);
+        if views.len() == BATCH_SIZE {
+            self.inner.batch_ready.notify_one();
+        }
+    }
+
+    /// Everything pending, oldest first
+    pub fn take(&self) -> Vec<PageView> {
+        std::mem::take(&mut *self.lock())
+    }
+
+    /// Puts back a batch that could not be written, ahead of the views
+    /// pushed since, keeping at most [`MAX_PENDING`]
+    pub fn requeue(&self, mut batch: Vec<PageView>) {
+        let mut views = self.lock();
+        let room = MAX_PENDING.saturating_sub(views.len());
+        if batch.len() > room {
+            let lost = batch.len() - room;
+            self.inner.dropped.fetch_add(lost as u64, Ordering::Relaxed);
+            batch.drain(..lost);
+        }
+        batch.append(&mut views);
+        *views = batch;
+    }
+
+    pub fn pending(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Views dropped because the buffer was full since the last call
+    pub fn take_dropped(&self) -> u64 {
+        self.inner.dropped.swap(0, Ordering::Relaxed)
+    }
+
+    /// Resolves once a full batch is pending
+    pub async fn batch_ready(&self) {
+        self.inner.batch_ready.notified().await
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<PageView>> {
+        self.inner
+            .views
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn view(path: &str) -> PageView {
+        PageView {
+            path: path.to_string(),
+            referrer: None,
+            visitor_id: "v".to_string(),
+            viewed_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_requeued_views_go_before_newer_ones() {
+        let buffer = PageViewBuffer::default();
+        buffer.push(view("/a"));
+        let batch = buffer.take();
+        buffer.push(view("/b"));
+
+        buffer.requeue(batch);
+
+        let paths: Vec<String> = buffer.take().into_iter().map(|v| v.path).collect();
+        assert_eq!(paths, vec!["/a", "/b"]);
+    }
+
+    #[test]
+    fn test_full_buffer_drops_and_counts() {
+        let buffer = PageViewBuffer::default();
+        for _ in 0..MAX_PENDING + 2 {
+            buffer.push(view("/"));
+        }
+
+        assert_eq!(buffer.pending(), MAX_PENDING);
+        assert_eq!(buffer.take_dropped(), 2);
+        assert_eq!(buffer.take_dropped(), 0);
+
+        // Only the oldest of a failed batch are lost when newer views fill the room
+        let batch = buffer.take();
+        buffer.push(view("/new"));
+        buffer.requeue(batch);
+        assert_eq!(buffer.pending(), MAX_PENDING);
+        assert_eq!(buffer.take_dropped(), 1);
+        assert_eq!(buffer.take().last().unwrap().path, "/new");
+    }
+}
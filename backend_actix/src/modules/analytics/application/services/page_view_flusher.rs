@@ -0,0 +1,191 @@
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::analytics::application::ports::outgoing::{PageViewRepository, PageViewRepositoryError};
+use crate::analytics::application::services::PageViewBuffer;
+use crate::shared::lifecycle::ShutdownSignal;
+
+/// Writes buffered page views every `interval`, or sooner once a full batch
+/// is pending. A batch that fails to write is kept for the next round, and
+/// whatever is left is written once more on shutdown.
+pub struct PageViewFlusher<R>
+where
+    R: PageViewRepository,
+{
+    buffer: PageViewBuffer,
+    repository: R,
+    interval: Duration,
+}
+
+impl<R> PageViewFlusher<R>
+where
+    R: PageViewRepository,
+{
+    pub fn new(buffer: PageViewBuffer, repository: R, interval: Duration) -> Self {
+        Self {
+            buffer,
+            repository,
+            interval,
+        }
+    }
+
+    pub async fn run(self, mut signal: ShutdownSignal) {
+        info!(
+            interval_secs = self.interval.as_secs(),
+            "Page view flusher started"
+        );
+
+        loop {
+            tokio::select! {
+                _ = signal.wait() => break,
+                _ = tokio::time::sleep(self.interval) => {}
+                _ = self.buffer.batch_ready() => {}
+            }
+
+            if let Err(e) = self.flush().await {
+                warn!(error = %e, "Page view flush failed, retrying next round");
+            }
+        }
+
+        match self.flush().await {
+            Ok(0) => {}
+            Ok(written) => info!(written, "Flushed page views on shutdown"),
+            Err(e) => warn!(error = %e, "Page views lost on shutdown"),
+        }
+    }
+
+    /// Writes everything pending; returns how many views were written
+    pub async fn flush(&self) -> Result<usize, PageViewRepositoryError> {
+        let dropped = self.buffer.take_dropped();
+        if dropped > 0 {
+            warn!(dropped, "Page view buffer was full, views dropped");
+        }
+
+        let batch = self.buffer.take();
+        if batch.is_empty() {
+            return Ok(0);
+        }
+        match self.repository.insert_batch(&batch).await {
+            Ok(()) => Ok(batch.len()),
+            Err(e) => {
+                self.buffer.requeue(batch);
+                Err(e)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    use crate::analytics::adapter::outgoing::InMemoryPageViewStore;
+    use crate::analytics::application::domain::entities::{
+        DailyVisitors, PageStats, PageView, ReferrerStats,
+    };
+    use crate::analytics::application::ports::incoming::use_cases::ReportRange;
+    use crate::shared::lifecycle::BackgroundJobs;
+
+    fn view(path: &str) -> PageView {
+        PageView {
+            path: path.to_string(),
+            referrer: None,
+            visitor_id: "v".to_string(),
+            viewed_at: Utc::now(),
+        }
+    }
+
+    /// Fails inserts while `down` is set
+    #[derive(Clone, Default)]
+    struct FlakyStore {
+        store: InMemoryPageViewStore,
+        down: Arc<AtomicBool>,
+    }
+
+    #[async_trait::async_trait]
+    impl PageViewRepository for FlakyStore {
+        async fn insert_batch(&self, views: &[PageView]) -> Result<(), PageViewRepositoryError> {
+            if self.down.load(Ordering::SeqCst) {
+                return Err(PageViewRepositoryError::DatabaseError("down".to_string()));
+            }
+            self.store.insert_batch(views).await
+        }
+
+        async fn top_pages(
+            &self,
+            range: ReportRange,
+            limit: u32,
+        ) -> Result<Vec<PageStats>, PageViewRepositoryError> {
+            self.store.top_pages(range, limit).await
+        }
+
+        async fn top_referrers(
+            &self,
+            range: ReportRange,
+            limit: u32,
+        ) -> Result<Vec<ReferrerStats>, PageViewRepositoryError> {
+            self.store.top_referrers(range, limit).await
+        }
+
+        async fn daily_visitors(
+            &self,
+            range: ReportRange,
+        ) -> Result<Vec<DailyVisitors>, PageViewRepositoryError> {
+            self.store.daily_visitors(range).await
+        }
+
+        async fn path_referrers(
+            &self,
+            path: &str,
+            range: ReportRange,
+            limit: u32,
+        ) -> Result<Vec<ReferrerStats>, PageViewRepositoryError> {
+            self.store.path_referrers(path, range, limit).await
+        }
+
+        async fn path_daily_visitors(
+            &self,
+            path: &str,
+            range: ReportRange,
+        ) -> Result<Vec<DailyVisitors>, PageViewRepositoryError> {
+            self.store.path_daily_visitors(path, range).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_batch_is_retried() {
+        let buffer = PageViewBuffer::default();
+        let store = FlakyStore::default();
+        let flusher = PageViewFlusher::new(buffer.clone(), store.clone(), Duration::from_secs(60));
+        buffer.push(view("/a"));
+
+        store.down.store(true, Ordering::SeqCst);
+        assert!(flusher.flush().await.is_err());
+        assert_eq!(buffer.pending(), 1);
+
+        store.down.store(false, Ordering::SeqCst);
+        assert_eq!(flusher.flush().await.unwrap(), 1);
+        assert_eq!(buffer.pending(), 0);
+        assert_eq!(store.store.views.read(|views| views.len()), 1);
+    }
+
+    #[tokio::test]
+    async fn test_pending_views_are_written_on_shutdown() {
+        let buffer = PageViewBuffer::default();
+        let store = InMemoryPageViewStore::default();
+        let flusher =
+            PageViewFlusher::new(buffer.clone(), store.clone(), Duration::from_secs(3600));
+        let mut jobs = BackgroundJobs::new();
+        jobs.spawn("page_views", move |signal| flusher.run(signal));
+
+        buffer.push(view("/a"));
+        buffer.push(view("/b"));
+        let aborted = jobs.shutdown(Duration::from_secs(1)).await;
+
+        assert!(aborted.is_empty());
+        assert_eq!(store.views.read(|views| views.len()), 2);
+    }
+}
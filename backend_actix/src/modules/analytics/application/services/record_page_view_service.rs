@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::analytics::application::domain::{entities::PageView, visitor_id::VisitorHasher};
+use crate::analytics::application::ports::incoming::use_cases::{
+    RecordPageViewCommand, RecordPageViewUseCase,
+};
+use crate::analytics::application::services::PageViewBuffer;
+
+pub struct RecordPageViewService {
+    hasher: VisitorHasher,
+    buffer: PageViewBuffer,
+}
+
+impl RecordPageViewService {
+    pub fn new(hasher: VisitorHasher, buffer: PageViewBuffer) -> Self {
+        Self { hasher, buffer }
+    }
+}
+
+#[async_trait]
+impl RecordPageViewUseCase for RecordPageViewService {
+    async fn execute(&self, command: RecordPageViewCommand) {
+        if command.is_bot() {
+            return;
+        }
+
+        let now = Utc::now();
+        self.buffer.push(PageView {
+            path: command.path().to_string(),
+            referrer: command.referrer().map(str::to_string),
+            visitor_id: self.hasher.visitor_id(
+                now.date_naive(),
+                command.client_ip(),
+                command.user_agent(),
+            ),
+            viewed_at: now,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(user_agent: &str) -> RecordPageViewCommand {
+        RecordPageViewCommand::new(
+            "/projects".to_string(),
+            Some("https://news.example.com/item".to_string()),
+            Some("203.0.113.7".to_string()),
+            Some(user_agent.to_string()),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_views_are_buffered_with_hashed_visitor() {
+        let buffer = PageViewBuffer::default();
+        let service = RecordPageViewService::new(
+            VisitorHasher::new("0123456789abcdef0123456789abcdef"),
+            buffer.clone(),
+        );
+
+        service.execute(command("Mozilla/5.0 Firefox/131.0")).await;
+        service.execute(command("Googlebot/2.1")).await;
+
+        let views = buffer.take();
+        assert_eq!(views.len(), 1);
+        assert_eq!(views[0].path, "/projects");
+        assert_eq!(views[0].referrer.as_deref(), Some("news.example.com"));
+        assert!(!views[0].visitor_id.contains("203.0.113.7"));
+    }
+}
@@ -0,0 +1,306 @@
+//! Browser-first auth transport (`AUTH_TRANSPORT=cookie`).
+//!
+//! Login and refresh put the tokens in HttpOnly cookies instead of the
+//! response body, so page scripts never see them. Browsers attach those
+//! cookies to every request, which makes mutating requests forgeable from
+//! other sites; [`csrf_middleware`] requires them to echo the readable
+//! `csrf_token` cookie in `X-CSRF-Token` (double submit), which another site
+//! can't read.
+
+use actix_web::{
+    body::{EitherBody, MessageBody},
+    cookie::{time::Duration, Cookie, SameSite},
+    dev::{ServiceRequest, ServiceResponse},
+    http::Method,
+    middleware::Next,
+    web, Error, HttpRequest, HttpResponse,
+};
+
+use crate::auth::adapter::incoming::web::error_codes::CSRF_TOKEN_INVALID;
+use crate::auth::adapter::outgoing::jwt::JwtConfig;
+use crate::shared::constant_time::constant_time_eq;
+use crate::AppState;
+
+pub const ACCESS_COOKIE: &str = "access_token";
+pub const REFRESH_COOKIE: &str = "refresh_token";
+pub const CSRF_COOKIE: &str = "csrf_token";
+pub const CSRF_HEADER: &str = "x-csrf-token";
+
+/// Sent with every `/api` request
+const ACCESS_PATH: &str = "/api";
+/// Only refresh and logout need the refresh token
+const REFRESH_PATH: &str = "/api/auth";
+/// Page scripts only see cookies whose path covers the page
+const CSRF_PATH: &str = "/";
+
+/// Writes and reads the auth cookies; present in `AppState` only in cookie mode
+#[derive(Debug, Clone)]
+pub struct AuthCookies {
+    domain: Option<String>,
+    secure: bool,
+    access_max_age: i64,
+    refresh_max_age: i64,
+    remember_me_max_age: i64,
+}
+
+impl AuthCookies {
+    /// Cookies live exactly as long as the tokens they carry
+    pub fn new(jwt: &JwtConfig, domain: Option<String>, secure: bool) -> Self {
+        Self {
+            domain,
+            secure,
+            access_max_age: jwt.access_token_expiry,
+            refresh_max_age: jwt.refresh_token_expiry,
+            remember_me_max_age: jwt.remember_me_refresh_token_expiry,
+        }
+    }
+
+    /// Sets both tokens and a fresh CSRF token
+    pub fn set_session(
+        &self,
+        resp: &mut HttpResponse,
+        access_token: &str,
+        refresh_token: &str,
+        remember_me: bool,
+    ) {
+        let refresh_max_age = if remember_me {
+            self.remember_me_max_age
+        } else {
+            self.refresh_max_age
+        };
+
+        let csrf_token = new_csrf_token();
+        for (name, value, path, http_only, max_age) in [
+            (
+                ACCESS_COOKIE,
+                access_token,
+                ACCESS_PATH,
+                true,
+                self.access_max_age,
+            ),
+            (
+                REFRESH_COOKIE,
+                refresh_token,
+                REFRESH_PATH,
+                true,
+                refresh_max_age,
+            ),
+            // Outlives the access cookie, so a refresh can still pass the check
+            (
+                CSRF_COOKIE,
+                csrf_token.as_str(),
+                CSRF_PATH,
+                false,
+                refresh_max_age,
+            ),
+        ] {
+            let mut cookie = self.cookie(name, value, path, http_only);
+            cookie.set_max_age(Duration::seconds(max_age));
+            add_cookie(resp, &cookie);
+        }
+    }
+
+    /// Expires every auth cookie
+    pub fn clear(&self, resp: &mut HttpResponse) {
+        for (name, path, http_only) in [
+            (ACCESS_COOKIE, ACCESS_PATH, true),
+            (REFRESH_COOKIE, REFRESH_PATH, true),
+            (CSRF_COOKIE, CSRF_PATH, false),
+        ] {
+            let mut cookie = self.cookie(name, "", path, http_only);
+            cookie.make_removal();
+            add_cookie(resp, &cookie);
+        }
+    }
+
+    pub fn access_token(&self, req: &HttpRequest) -> Option<String> {
+        cookie_value(req.cookie(ACCESS_COOKIE))
+    }
+
+    pub fn refresh_token(&self, req: &HttpRequest) -> Option<String> {
+        cookie_value(req.cookie(REFRESH_COOKIE))
+    }
+
+    fn cookie(&self, name: &str, value: &str, path: &str, http_only: bool) -> Cookie<'static> {
+        let mut cookie = Cookie::build(name.to_string(), value.to_string())
+            .path(path.to_string())
+            .http_only(http_only)
+            .secure(self.secure)
+            .same_site(SameSite::Strict)
+            .finish();
+        if let Some(domain) = &self.domain {
+            cookie.set_domain(domain.clone());
+        }
+        cookie
+    }
+}
+
+fn add_cookie(resp: &mut HttpResponse, cookie: &Cookie<'_>) {
+    // Only fails for a cookie that isn't a valid header value, which ours always are
+    if let Err(e) = resp.add_cookie(cookie) {
+        tracing::error!("Failed to set cookie {}: {}", cookie.name(), e);
+    }
+}
+
+fn cookie_value(cookie: Option<Cookie<'_>>) -> Option<String> {
+    cookie
+        .map(|cookie| cookie.value().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn new_csrf_token() -> String {
+    hex::encode(rand::random::<[u8; 32]>())
+}
+
+/// Double-submit CSRF check, active in cookie mode.
+///
+/// Mutating requests (anything but GET, HEAD and OPTIONS) that carry an auth
+/// cookie must send the `csrf_token` cookie's value in `X-CSRF-Token`, or
+/// get 403 `CSRF_TOKEN_INVALID`. Requests without auth cookies (bearer
+/// clients, login from a fresh browser) are not cookie-authenticated and
+/// pass untouched.
+pub async fn csrf_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let cookie_mode = req
+        .app_data::<web::Data<AppState>>()
+        .is_some_and(|state| state.auth_cookies.is_some());
+    let safe_method = matches!(*req.method(), Method::GET | Method::HEAD | Method::OPTIONS);
+    let cookie_authenticated =
+        req.cookie(ACCESS_COOKIE).is_some() || req.cookie(REFRESH_COOKIE).is_some();
+
+    if cookie_mode && !safe_method && cookie_authenticated {
+        let expected = req.cookie(CSRF_COOKIE);
+        let sent = req
+            .headers()
+            .get(CSRF_HEADER)
+            .and_then(|value| value.to_str().ok());
+
+        let valid = match (&expected, sent) {
+            (Some(expected), Some(sent)) => {
+                !expected.value().is_empty()
+                    && constant_time_eq(expected.value().as_bytes(), sent.as_bytes())
+            }
+            _ => false,
+        };
+        if !valid {
+            tracing::warn!(path = %req.path(), "Rejected request without a valid CSRF token");
+            return Ok(req
+                .into_response(CSRF_TOKEN_INVALID.response())
+                .map_into_right_body());
+        }
+    }
+
+    next.call(req)
+        .await
+        .map(ServiceResponse::map_into_left_body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::support::app_state_builder::TestAppStateBuilder;
+    use crate::tests::support::auth_helper::test_helpers::create_test_auth_cookies;
+    use actix_web::{
+        cookie::Cookie,
+        middleware::from_fn,
+        test::{self, TestRequest},
+        App,
+    };
+
+    async fn ok() -> HttpResponse {
+        HttpResponse::Ok().finish()
+    }
+
+    macro_rules! app {
+        ($state:expr) => {
+            test::init_service(
+                App::new()
+                    .app_data($state)
+                    .wrap(from_fn(csrf_middleware))
+                    .route("/api/projects", web::post().to(ok)),
+            )
+            .await
+        };
+    }
+
+    #[actix_web::test]
+    async fn test_set_session_sets_http_only_tokens_and_readable_csrf_token() {
+        let mut resp = HttpResponse::Ok().finish();
+        create_test_auth_cookies().set_session(&mut resp, "access", "refresh", true);
+
+        let cookies: Vec<Cookie> = resp.cookies().collect();
+        let find = |name: &str| cookies.iter().find(|c| c.name() == name).unwrap();
+
+        let access = find(ACCESS_COOKIE);
+        assert_eq!(access.value(), "access");
+        assert_eq!(access.http_only(), Some(true));
+        assert_eq!(access.same_site(), Some(SameSite::Strict));
+        assert_eq!(access.path(), Some("/api"));
+        assert_eq!(access.max_age(), Some(Duration::seconds(3600)));
+
+        let refresh = find(REFRESH_COOKIE);
+        assert_eq!(refresh.path(), Some("/api/auth"));
+        assert_eq!(refresh.max_age(), Some(Duration::seconds(2592000)));
+
+        let csrf = find(CSRF_COOKIE);
+        assert_ne!(csrf.http_only(), Some(true));
+        assert_eq!(csrf.value().len(), 64);
+    }
+
+    #[actix_web::test]
+    async fn test_cookie_authenticated_post_needs_matching_csrf_header() {
+        let state = TestAppStateBuilder::default()
+            .with_auth_cookies(create_test_auth_cookies())
+            .build();
+        let app = app!(state);
+
+        let without_header = TestRequest::post()
+            .uri("/api/projects")
+            .cookie(Cookie::new(ACCESS_COOKIE, "token"))
+            .cookie(Cookie::new(CSRF_COOKIE, "abc"))
+            .to_request();
+        assert_eq!(test::call_service(&app, without_header).await.status(), 403);
+
+        let wrong_header = TestRequest::post()
+            .uri("/api/projects")
+            .cookie(Cookie::new(ACCESS_COOKIE, "token"))
+            .cookie(Cookie::new(CSRF_COOKIE, "abc"))
+            .insert_header((CSRF_HEADER, "abd"))
+            .to_request();
+        assert_eq!(test::call_service(&app, wrong_header).await.status(), 403);
+
+        let matching = TestRequest::post()
+            .uri("/api/projects")
+            .cookie(Cookie::new(ACCESS_COOKIE, "token"))
+            .cookie(Cookie::new(CSRF_COOKIE, "abc"))
+            .insert_header((CSRF_HEADER, "abc"))
+            .to_request();
+        assert_eq!(test::call_service(&app, matching).await.status(), 200);
+    }
+
+    #[actix_web::test]
+    async fn test_requests_without_auth_cookies_or_in_bearer_mode_pass() {
+        let cookie_mode = app!(TestAppStateBuilder::default()
+            .with_auth_cookies(create_test_auth_cookies())
+            .build());
+        let bearer = TestRequest::post()
+            .uri("/api/projects")
+            .insert_header(("Authorization", "Bearer token"))
+            .to_request();
+        assert_eq!(test::call_service(&cookie_mode, bearer).await.status(), 200);
+
+        let bearer_mode = app!(TestAppStateBuilder::default().build());
+        let stray_cookie = TestRequest::post()
+            .uri("/api/projects")
+            .cookie(Cookie::new(ACCESS_COOKIE, "token"))
+            .to_request();
+        assert_eq!(
+            test::call_service(&bearer_mode, stray_cookie)
+                .await
+                .status(),
+            200
+        );
+    }
+}
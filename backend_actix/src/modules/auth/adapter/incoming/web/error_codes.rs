@@ -0,0 +1,33 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    MISSING_AUTH_HEADER = (UNAUTHORIZED, "Missing or invalid authorization header");
+    INVALID_TOKEN = (UNAUTHORIZED, "Invalid or expired token");
+    INVALID_TOKEN_TYPE = (UNAUTHORIZED, "Invalid token type");
+    TOKEN_EXPIRED = (UNAUTHORIZED, "Token has expired");
+    TOKEN_INVALID = (UNAUTHORIZED, "Invalid token");
+    TOKEN_NOT_YET_VALID = (BAD_REQUEST, "Token is not yet valid");
+    TOKEN_REVOKED = (UNAUTHORIZED, "Token has been revoked");
+    TOKEN_ALREADY_USED = (BAD_REQUEST, "This verification link has already been used");
+    CSRF_TOKEN_INVALID = (FORBIDDEN, "Missing or invalid CSRF token");
+    EMAIL_NOT_VERIFIED = (FORBIDDEN, "Email verification required");
+    ADMIN_REQUIRED = (FORBIDDEN, "Administrator access required");
+    INVALID_CREDENTIALS = (UNAUTHORIZED, "Invalid email or password");
+    INVALID_REQUEST = (BAD_REQUEST, "Invalid request");
+    INVALID_USERNAME = (BAD_REQUEST, "Invalid username");
+    INVALID_PASSWORD = (BAD_REQUEST, "Password does not meet the requirements");
+    INVALID_FULL_NAME = (BAD_REQUEST, "Invalid full name");
+    INVALID_TIMEZONE = (BAD_REQUEST, "Timezone must be an IANA name like 'Europe/Berlin'");
+    AVATAR_NOT_FOUND = (NOT_FOUND, "Avatar media not found");
+    USER_ALREADY_EXISTS = (CONFLICT, "User already exists");
+    USER_NOT_FOUND = (NOT_FOUND, "User not found");
+    USER_UNAUTHORIZED = (UNAUTHORIZED, "You are not authorized to delete this account");
+    USER_DELETED = (FORBIDDEN, "This account has been deleted");
+    ACCOUNT_DEACTIVATED = (FORBIDDEN, "This account is deactivated; reactivate it to log in");
+    PASSKEYS_DISABLED = (NOT_FOUND, "Passkeys are not enabled");
+    PASSKEY_CHALLENGE_EXPIRED = (BAD_REQUEST, "The passkey challenge is unknown or has expired; start again");
+    PASSKEY_REJECTED = (BAD_REQUEST, "The passkey could not be verified");
+    PASSKEY_ALREADY_REGISTERED = (CONFLICT, "This passkey is already registered");
+    PASSKEY_NOT_FOUND = (NOT_FOUND, "Passkey not found");
+    NO_PASSKEYS = (BAD_REQUEST, "No passkey is registered for this email");
+}
@@ -1,12 +1,13 @@
 use actix_web::{dev::Payload, web, Error as ActixError, FromRequest, HttpRequest, HttpResponse};
-use std::{
-    future::{ready, Ready},
-    sync::Arc,
-};
+use futures::future::LocalBoxFuture;
 use uuid::Uuid;
 
+use crate::auth::adapter::incoming::web::error_codes::{
+    ADMIN_REQUIRED, EMAIL_NOT_VERIFIED, USER_NOT_FOUND,
+};
+use crate::auth::adapter::incoming::web::require_auth::{authenticate, AuthContext};
+use crate::AppState;
 use crate::{auth::application::helpers::ResolveUserIdError, shared::api::ApiResponse};
-use crate::{auth::application::ports::outgoing::token_provider::TokenProvider, AppState};
 
 /// Represents an authenticated user (verified or not)
 #[derive(Debug, Clone)]
@@ -21,48 +22,47 @@ fn create_api_error(response: HttpResponse) -> ActixError {
 
 impl FromRequest for AuthenticatedUser {
     type Error = ActixError;
-    type Future = Ready<Result<Self, Self::Error>>;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
 
     fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
-        let jwt_service =
-            match req.app_data::<actix_web::web::Data<Arc<dyn TokenProvider + Send + Sync>>>() {
-                Some(service) => service,
-                None => {
-                    return ready(Err(create_api_error(ApiResponse::internal_error())));
-                }
+        let req = req.clone();
+
+        Box::pin(async move {
+            // Routes inside a `RequireAuth` scope were checked already
+            let context = match AuthContext::of(&req) {
+                Some(context) => context,
+                None => authenticate(&req).await.map_err(create_api_error)?,
             };
 
-        // Extract token from Authorization header
-        let token = match extract_token_from_header(req) {
-            Some(t) => t,
-            None => {
-                return ready(Err(create_api_error(ApiResponse::unauthorized(
-                    "MISSING_AUTH_HEADER",
-                    "Missing or invalid authorization header",
-                ))));
-            }
-        };
-
-        // Verify token
-        match jwt_service.verify_token(&token) {
-            Ok(claims) => {
-                if claims.token_type != "access" {
-                    return ready(Err(create_api_error(ApiResponse::unauthorized(
-                        "INVALID_TOKEN_TYPE",
-                        "Invalid token type",
-                    ))));
-                }
-
-                ready(Ok(AuthenticatedUser {
-                    user_id: claims.sub,
-                    is_verified: claims.is_verified,
-                }))
-            }
-            Err(_) => ready(Err(create_api_error(ApiResponse::unauthorized(
-                "INVALID_TOKEN",
-                "Invalid or expired token",
-            )))),
-        }
+            Ok(AuthenticatedUser {
+                user_id: context.user_id,
+                is_verified: context.is_verified,
+            })
+        })
+    }
+}
+
+/// The signed-in visitor of a public endpoint, if any. Missing, invalid and
+/// revoked tokens make an anonymous visitor rather than an error, so public
+/// handlers can show owner-only fields without locking anyone out.
+#[derive(Debug, Clone, Default)]
+pub struct MaybeUser(pub Option<AuthenticatedUser>);
+
+impl MaybeUser {
+    /// Whether the visitor is signed in as `user_id`
+    pub fn is(&self, user_id: Uuid) -> bool {
+        self.0.as_ref().is_some_and(|user| user.user_id == user_id)
+    }
+}
+
+impl FromRequest for MaybeUser {
+    type Error = ActixError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let user = AuthenticatedUser::from_request(req, payload);
+
+        Box::pin(async move { Ok(MaybeUser(user.await.ok())) })
     }
 }
 
@@ -74,36 +74,53 @@ pub struct VerifiedUser {
 
 impl FromRequest for VerifiedUser {
     type Error = ActixError;
-    type Future = Ready<Result<Self, Self::Error>>;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
 
     fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
-        let auth_user_future = AuthenticatedUser::from_request(req, payload);
-
-        match auth_user_future.into_inner() {
-            Ok(auth_user) => {
-                if !auth_user.is_verified {
-                    return ready(Err(create_api_error(ApiResponse::forbidden(
-                        "EMAIL_NOT_VERIFIED",
-                        "Email verification required",
-                    ))));
-                }
-
-                ready(Ok(VerifiedUser {
-                    user_id: auth_user.user_id,
-                }))
+        let auth_user = AuthenticatedUser::from_request(req, payload);
+
+        Box::pin(async move {
+            let auth_user = auth_user.await?;
+            if !auth_user.is_verified {
+                return Err(create_api_error(EMAIL_NOT_VERIFIED.response()));
             }
-            Err(e) => ready(Err(e)),
-        }
+
+            Ok(VerifiedUser {
+                user_id: auth_user.user_id,
+            })
+        })
     }
 }
 
-fn extract_token_from_header(req: &HttpRequest) -> Option<String> {
-    req.headers()
-        .get("Authorization")?
-        .to_str()
-        .ok()?
-        .strip_prefix("Bearer ")
-        .map(|s| s.to_string())
+/// A verified user listed in `ADMIN_USER_IDS`
+#[derive(Debug, Clone)]
+pub struct AdminUser {
+    pub user_id: Uuid,
+}
+
+impl FromRequest for AdminUser {
+    type Error = ActixError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let user = VerifiedUser::from_request(req, payload);
+        let req = req.clone();
+
+        Box::pin(async move {
+            let user = user.await?;
+
+            let is_admin = req
+                .app_data::<web::Data<AppState>>()
+                .is_some_and(|state| state.admin_user_ids.contains(&user.user_id));
+            if !is_admin {
+                return Err(create_api_error(ADMIN_REQUIRED.response()));
+            }
+
+            Ok(AdminUser {
+                user_id: user.user_id,
+            })
+        })
+    }
 }
 
 pub async fn resolve_owner_id_or_response(
@@ -113,9 +130,7 @@ pub async fn resolve_owner_id_or_response(
     match data.user_identity_resolver.by_username(username).await {
         Ok(owner_id) => Ok(owner_id.value()),
 
-        Err(ResolveUserIdError::NotFound) => {
-            Err(ApiResponse::not_found("USER_NOT_FOUND", "User not found"))
-        }
+        Err(ResolveUserIdError::NotFound) => Err(USER_NOT_FOUND.response()),
 
         Err(ResolveUserIdError::RepositoryError(msg)) => {
             tracing::error!("Repository error resolving username {}: {}", username, msg);
@@ -1,3 +1,36 @@
+use actix_web::web;
+
+pub mod cookies;
+pub mod error_codes;
 pub mod extractors;
+pub mod require_auth;
 
 pub mod routes;
+
+/// Routes anyone can call
+pub fn configure_public(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::register_user_handler)
+        .service(routes::verify_user_email_handler)
+        .service(routes::login_user_handler)
+        .service(routes::refresh_token_handler)
+        .service(routes::logout_user_handler)
+        .service(routes::introspect_token_handler)
+        .service(routes::reactivate_account_handler)
+        .service(routes::start_passkey_login_handler)
+        .service(routes::finish_passkey_login_handler);
+}
+
+/// Routes for signed-in users, reachable before the email is verified
+pub fn configure_account(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::logout_all_handler)
+        .service(routes::soft_delete_user_handler)
+        .service(routes::deactivate_account_handler)
+        .service(routes::get_user_profile_handler)
+        .service(routes::update_user_profile_handler)
+        .service(routes::get_me_handler)
+        .service(routes::patch_me_handler)
+        .service(routes::start_passkey_registration_handler)
+        .service(routes::finish_passkey_registration_handler)
+        .service(routes::list_passkeys_handler)
+        .service(routes::delete_passkey_handler);
+}
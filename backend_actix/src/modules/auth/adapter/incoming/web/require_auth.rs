@@ -0,0 +1,299 @@
+//! Scope-level authentication.
+//!
+//! `init_routes` puts every route that needs a signed-in user inside a scope
+//! wrapped with [`RequireAuth`], so a new handler registered there can't ship
+//! unauthenticated by forgetting its extractor. The middleware checks the
+//! access token once and leaves an [`AuthContext`] in the request extensions;
+//! the `AuthenticatedUser`, `VerifiedUser` and `AdminUser` extractors pick it
+//! up instead of checking the token again.
+
+use actix_web::{
+    body::EitherBody,
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    web, Error, HttpMessage, HttpRequest, HttpResponse,
+};
+use futures::future::LocalBoxFuture;
+use std::future::{ready, Ready};
+use std::rc::Rc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::adapter::incoming::web::error_codes::{
+    EMAIL_NOT_VERIFIED, INVALID_TOKEN, INVALID_TOKEN_TYPE, MISSING_AUTH_HEADER, TOKEN_REVOKED,
+};
+use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+use crate::shared::access_log;
+use crate::shared::api::ApiResponse;
+use crate::AppState;
+
+/// The user behind an accepted access token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuthContext {
+    pub user_id: Uuid,
+    pub is_verified: bool,
+}
+
+impl AuthContext {
+    /// Set by [`RequireAuth`] for routes inside its scope
+    pub fn of(req: &HttpRequest) -> Option<AuthContext> {
+        req.extensions().get::<AuthContext>().copied()
+    }
+}
+
+/// Checks the access token of a request: from the `Authorization` header, or
+/// the access cookie in cookie mode. Refused requests get the response to send.
+pub(crate) async fn authenticate(req: &HttpRequest) -> Result<AuthContext, HttpResponse> {
+    let jwt_service = req
+        .app_data::<web::Data<Arc<dyn TokenProvider + Send + Sync>>>()
+        .ok_or_else(ApiResponse::internal_error)?;
+
+    let state = req.app_data::<web::Data<AppState>>();
+    let token = extract_token_from_header(req)
+        .or_else(|| {
+            state
+                .and_then(|state| state.auth_cookies.as_ref())
+                .and_then(|cookies| cookies.access_token(req))
+        })
+        .ok_or_else(|| MISSING_AUTH_HEADER.response())?;
+
+    let claims = jwt_service
+        .verify_token(&token)
+        .map_err(|_| INVALID_TOKEN.response())?;
+
+    if claims.token_type != "access" {
+        return Err(INVALID_TOKEN_TYPE.response());
+    }
+
+    // Tokens issued before the user's last "logout from all devices"
+    if let Some(state) = state {
+        match state
+            .token_version_guard
+            .is_current(claims.sub, claims.token_version)
+            .await
+        {
+            Ok(true) => {}
+            Ok(false) => {
+                return Err(TOKEN_REVOKED.response());
+            }
+            Err(e) => {
+                tracing::error!("Failed to check token version of {}: {}", claims.sub, e);
+                return Err(ApiResponse::internal_error());
+            }
+        }
+    }
+
+    access_log::record_user(req, claims.sub);
+    Ok(AuthContext {
+        user_id: claims.sub,
+        is_verified: claims.is_verified,
+    })
+}
+
+fn extract_token_from_header(req: &HttpRequest) -> Option<String> {
+    req.headers()
+        .get("Authorization")?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .map(|s| s.to_string())
+}
+
+/// Refuses requests without a valid access token with 401, and with
+/// `verified: true` those of users who haven't verified their email with
+/// 403 `EMAIL_NOT_VERIFIED`.
+///
+/// Scopes can nest: an inner `RequireAuth` reuses the context of the outer
+/// one and only adds its own verification check.
+#[derive(Debug, Clone, Copy)]
+pub struct RequireAuth {
+    pub verified: bool,
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RequireAuth
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = RequireAuthMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequireAuthMiddleware {
+            service: Rc::new(service),
+            verified: self.verified,
+        }))
+    }
+}
+
+pub struct RequireAuthMiddleware<S> {
+    service: Rc<S>,
+    verified: bool,
+}
+
+impl<S, B> Service<ServiceRequest> for RequireAuthMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = Rc::clone(&self.service);
+        let require_verified = self.verified;
+
+        Box::pin(async move {
+            let existing = AuthContext::of(req.request());
+            let context = match existing {
+                Some(context) => context,
+                None => match authenticate(req.request()).await {
+                    Ok(context) => {
+                        req.extensions_mut().insert(context);
+                        context
+                    }
+                    Err(response) => return Ok(req.into_response(response).map_into_right_body()),
+                },
+            };
+
+            if require_verified && !context.is_verified {
+                return Ok(req
+                    .into_response(EMAIL_NOT_VERIFIED.response())
+                    .map_into_right_body());
+            }
+
+            service
+                .call(req)
+                .await
+                .map(ServiceResponse::map_into_left_body)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::adapter::incoming::web::extractors::auth::VerifiedUser;
+    use crate::tests::support::app_state_builder::TestAppStateBuilder;
+    use crate::tests::support::auth_helper::test_helpers::create_test_jwt_service;
+    use crate::tests::support::stubs::StubTokenVersionRepository;
+    use actix_web::{
+        http::StatusCode,
+        test::{self, TestRequest},
+        App,
+    };
+
+    async fn public() -> HttpResponse {
+        HttpResponse::Ok().finish()
+    }
+
+    async fn whoami(req: HttpRequest) -> HttpResponse {
+        match AuthContext::of(&req) {
+            Some(context) => HttpResponse::Ok().body(context.user_id.to_string()),
+            None => HttpResponse::InternalServerError().finish(),
+        }
+    }
+
+    async fn verified_only(user: VerifiedUser) -> HttpResponse {
+        HttpResponse::Ok().body(user.user_id.to_string())
+    }
+
+    macro_rules! app {
+        ($state:expr) => {
+            test::init_service(
+                App::new()
+                    .app_data($state)
+                    .app_data(web::Data::new(
+                        Arc::new(create_test_jwt_service()) as Arc<dyn TokenProvider + Send + Sync>
+                    ))
+                    .route("/api/public", web::get().to(public))
+                    .service(
+                        web::scope("")
+                            .wrap(RequireAuth { verified: false })
+                            .route("/api/account", web::get().to(whoami))
+                            .service(
+                                web::scope("")
+                                    .wrap(RequireAuth { verified: true })
+                                    .route("/api/content", web::get().to(verified_only)),
+                            ),
+                    ),
+            )
+            .await
+        };
+    }
+
+    fn get(uri: &str, token: Option<&str>) -> TestRequest {
+        let req = TestRequest::get().uri(uri);
+        match token {
+            Some(token) => req.insert_header(("Authorization", format!("Bearer {token}"))),
+            None => req,
+        }
+    }
+
+    fn access_token(user_id: Uuid, is_verified: bool) -> String {
+        create_test_jwt_service()
+            .generate_access_token(user_id, is_verified, 0)
+            .unwrap()
+    }
+
+    #[actix_web::test]
+    async fn test_scoped_routes_need_a_token_and_public_ones_do_not() {
+        let app = app!(TestAppStateBuilder::default().build());
+
+        let resp = test::call_service(&app, get("/api/public", None).to_request()).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        for uri in ["/api/account", "/api/content", "/api/unregistered"] {
+            let resp = test::call_service(&app, get(uri, None).to_request()).await;
+            assert_eq!(resp.status(), StatusCode::UNAUTHORIZED, "{uri}");
+        }
+
+        let resp =
+            test::call_service(&app, get("/api/content", Some("not.a.token")).to_request()).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn test_context_reaches_handlers_and_extractors() {
+        let app = app!(TestAppStateBuilder::default().build());
+        let user_id = Uuid::new_v4();
+        let token = access_token(user_id, true);
+
+        for uri in ["/api/account", "/api/content"] {
+            let resp = test::call_service(&app, get(uri, Some(&token)).to_request()).await;
+            assert_eq!(resp.status(), StatusCode::OK, "{uri}");
+            let body = test::read_body(resp).await;
+            assert_eq!(body, user_id.to_string());
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_verified_scope_refuses_unverified_users() {
+        let app = app!(TestAppStateBuilder::default().build());
+        let token = access_token(Uuid::new_v4(), false);
+
+        let resp = test::call_service(&app, get("/api/account", Some(&token)).to_request()).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let resp = test::call_service(&app, get("/api/content", Some(&token)).to_request()).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "EMAIL_NOT_VERIFIED");
+    }
+
+    #[actix_web::test]
+    async fn test_revoked_tokens_are_refused() {
+        let app = app!(TestAppStateBuilder::default()
+            .with_token_version_repository(StubTokenVersionRepository::at(1))
+            .build());
+        let token = access_token(Uuid::new_v4(), true);
+
+        let resp = test::call_service(&app, get("/api/account", Some(&token)).to_request()).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    }
+}
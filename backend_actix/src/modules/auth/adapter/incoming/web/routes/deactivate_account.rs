@@ -0,0 +1,130 @@
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::auth::adapter::incoming::web::error_codes::USER_NOT_FOUND;
+use crate::auth::adapter::incoming::web::extractors::auth::AuthenticatedUser;
+use crate::auth::application::use_cases::deactivate_account::DeactivateAccountError;
+use crate::shared::api::ApiResponse;
+use crate::AppState;
+use actix_web::{post, web, Responder};
+use serde::Serialize;
+use tracing::error;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+pub struct AccountStatusResponse {
+    /// Whether the account can log in and its content is public
+    #[schema(example = false)]
+    is_active: bool,
+}
+
+impl AccountStatusResponse {
+    pub fn new(is_active: bool) -> Self {
+        Self { is_active }
+    }
+}
+
+/// Deactivate current user account
+///
+/// Takes the account offline without deleting anything: its public projects,
+/// CVs and pages answer 404, every session ends and login is refused with
+/// `ACCOUNT_DEACTIVATED` until the owner calls `POST /api/auth/reactivate`.
+#[utoipa::path(
+    post,
+    path = "/api/auth/deactivate",
+    tag = "auth",
+    responses(
+        (
+            status = 200,
+            description = "Account deactivated and all sessions ended",
+            body = inline(SuccessResponse<AccountStatusResponse>),
+            example = json!({
+                "success": true,
+                "data": {
+                    "is_active": false
+                }
+            })
+        ),
+        (status = 401, description = "Missing, invalid or revoked access token", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/auth/deactivate")]
+pub async fn deactivate_account_handler(
+    user: AuthenticatedUser,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data.auth.deactivate_account.execute(user.user_id).await {
+        Ok(()) => {
+            let mut resp = ApiResponse::success(AccountStatusResponse::new(false));
+            if let Some(cookies) = &data.auth_cookies {
+                cookies.clear(&mut resp);
+            }
+            resp
+        }
+
+        Err(DeactivateAccountError::UserNotFound) => USER_NOT_FOUND.response(),
+
+        Err(DeactivateAccountError::DatabaseError(e)) => {
+            error!("Failed to deactivate user {}: {}", user.user_id, e);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use actix_web::{http::StatusCode, test, App};
+    use uuid::Uuid;
+
+    use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+    use crate::tests::support::app_state_builder::TestAppStateBuilder;
+    use crate::tests::support::auth_helper::test_helpers::create_test_jwt_service;
+    use crate::tests::support::stubs::StubDeactivateAccountUseCase;
+
+    async fn call(state: web::Data<AppState>) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(deactivate_account_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/auth/deactivate")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_deactivate_account_success() {
+        let resp = call(TestAppStateBuilder::default().build()).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["is_active"], false);
+    }
+
+    #[actix_web::test]
+    async fn test_deactivate_account_database_error() {
+        let state = TestAppStateBuilder::default()
+            .with_deactivate_account(StubDeactivateAccountUseCase::failure())
+            .build();
+
+        let resp = call(state).await;
+
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::api::schemas::ErrorResponse;
+use crate::auth::adapter::incoming::web::error_codes::{USER_NOT_FOUND, USER_UNAUTHORIZED};
 use crate::auth::adapter::incoming::web::extractors::auth::AuthenticatedUser;
 use crate::auth::application::use_cases::soft_delete_user::{
     SoftDeleteUserError, SoftDeleteUserRequest,
@@ -11,7 +12,9 @@ use tracing::error;
 /// Delete current user account
 ///
 /// Soft deletes the authenticated user's account. The account is marked as deleted
-/// but data is retained in the database. This action requires authentication.
+/// but data is retained in the database. Its projects, pages, CVs and media leave
+/// the public endpoints at once, and every session of the account ends. This
+/// action requires authentication.
 #[utoipa::path(
     delete,
     path = "/api/users/me",
@@ -69,19 +72,23 @@ pub async fn soft_delete_user_handler(
 ) -> impl Responder {
     let request = SoftDeleteUserRequest::new(user.user_id);
 
-    match data.soft_delete_user_use_case.execute(request).await {
-        Ok(_) => ApiResponse::no_content(),
+    match data.auth.soft_delete_user.execute(request).await {
+        Ok(_) => {
+            // The account is gone either way, and public queries skip its
+            // content even when this fails
+            if let Err(e) = data.auth.lockdown_user_content.execute(user.user_id).await {
+                error!("Failed to lock down content of deleted user: {}", e);
+            }
+            ApiResponse::no_content()
+        }
 
-        Err(SoftDeleteUserError::Unauthorized) => ApiResponse::unauthorized(
-            "USER_UNAUTHORIZED",
-            "You are not authorized to delete this account",
-        ),
+        Err(SoftDeleteUserError::Unauthorized) => USER_UNAUTHORIZED.response(),
 
         Err(SoftDeleteUserError::DatabaseError(e)) => {
             error!("Database error soft deleting user: {}", e);
 
             if e.contains("User not found") {
-                ApiResponse::not_found("USER_NOT_FOUND", "User not found")
+                USER_NOT_FOUND.response()
             } else {
                 ApiResponse::internal_error()
             }
@@ -103,6 +110,7 @@ mod tests {
     };
     use crate::tests::support::app_state_builder::TestAppStateBuilder;
     use crate::tests::support::auth_helper::test_helpers::create_test_jwt_service;
+    use crate::tests::support::stubs::StubLockdownUserContentUseCase;
 
     // ==========================================================
     // Mocks
@@ -163,7 +171,39 @@ mod tests {
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service.clone());
 
         let token = jwt_service
-            .generate_access_token(uuid::Uuid::new_v4(), true)
+            .generate_access_token(uuid::Uuid::new_v4(), true, 0)
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .app_data(actix_web::web::Data::new(token_provider))
+                .service(soft_delete_user_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::delete()
+            .uri("/api/users/me")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), actix_web::http::StatusCode::NO_CONTENT);
+    }
+
+    #[actix_web::test]
+    async fn test_soft_delete_user_succeeds_when_lockdown_fails() {
+        let app_state = TestAppStateBuilder::default()
+            .with_soft_delete_user(MockSoftDeleteUserSuccess)
+            .with_lockdown_user_content(StubLockdownUserContentUseCase::failure())
+            .build();
+
+        let jwt_service = create_test_jwt_service();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service.clone());
+
+        let token = jwt_service
+            .generate_access_token(uuid::Uuid::new_v4(), true, 0)
             .unwrap();
 
         let app = test::init_service(
@@ -194,7 +234,7 @@ mod tests {
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service.clone());
 
         let token = jwt_service
-            .generate_access_token(uuid::Uuid::new_v4(), true)
+            .generate_access_token(uuid::Uuid::new_v4(), true, 0)
             .unwrap();
 
         let app = test::init_service(
@@ -234,7 +274,7 @@ mod tests {
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service.clone());
 
         let token = jwt_service
-            .generate_access_token(uuid::Uuid::new_v4(), true)
+            .generate_access_token(uuid::Uuid::new_v4(), true, 0)
             .unwrap();
 
         let app = test::init_service(
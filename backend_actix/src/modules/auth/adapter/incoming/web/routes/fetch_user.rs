@@ -1,3 +1,4 @@
+use crate::auth::adapter::incoming::web::error_codes::USER_NOT_FOUND;
 use crate::{
     auth::{
         adapter::incoming::web::extractors::auth::AuthenticatedUser,
@@ -30,6 +31,10 @@ pub struct UserProfileResponse {
     /// Full name
     #[schema(example = "John Doe")]
     full_name: String,
+
+    /// Locale emails are sent in
+    #[schema(example = "en")]
+    preferred_locale: String,
 }
 
 /// Get current user profile
@@ -51,7 +56,8 @@ pub struct UserProfileResponse {
                     "userId": "123e4567-e89b-12d3-a456-426614174000",
                     "email": "john@example.com",
                     "username": "johndoe",
-                    "fullName": "John Doe"
+                    "fullName": "John Doe",
+                    "preferredLocale": "en"
                 }
             })
         ),
@@ -102,7 +108,8 @@ pub async fn get_user_profile_handler(
     data: web::Data<AppState>,
 ) -> impl Responder {
     match data
-        .fetch_user_profile_use_case
+        .auth
+        .fetch_profile
         .execute(UserId::from(user.user_id))
         .await
     {
@@ -111,9 +118,10 @@ pub async fn get_user_profile_handler(
             email: output.email,
             username: output.username,
             full_name: output.full_name,
+            preferred_locale: output.preferred_locale,
         }),
         Err(FetchUserError::UserNotFound(msg)) => {
-            ApiResponse::not_found("USER_NOT_FOUND", &format!("User not found: {}", msg))
+            USER_NOT_FOUND.with_message(&format!("User not found: {}", msg))
         }
         Err(FetchUserError::QueryError(e)) => {
             error!("Database error fetching user profile: {}", e);
@@ -162,6 +170,7 @@ mod tests {
             &self,
             _user_id: Uuid,
             _is_verified: bool,
+            _token_version: i32,
         ) -> Result<String, TokenError> {
             unimplemented!()
         }
@@ -170,6 +179,8 @@ mod tests {
             &self,
             _user_id: Uuid,
             _is_verified: bool,
+            _token_version: i32,
+            _remember_me: bool,
         ) -> Result<String, TokenError> {
             unimplemented!()
         }
@@ -182,6 +193,11 @@ mod tests {
                 exp: 9999999999,
                 iat: 0,
                 nbf: 0,
+                token_version: 0,
+                remember_me: false,
+                iss: String::new(),
+                aud: String::new(),
+                jti: None,
             })
         }
 
@@ -193,7 +209,7 @@ mod tests {
             unimplemented!()
         }
 
-        fn verify_verification_token(&self, _token: &str) -> Result<Uuid, TokenError> {
+        fn verify_verification_token(&self, _token: &str) -> Result<TokenClaims, TokenError> {
             unimplemented!()
         }
     }
@@ -204,6 +220,7 @@ mod tests {
             email: "test@example.com".to_string(),
             username: "testuser".to_string(),
             full_name: "Test User".to_string(),
+            preferred_locale: "en".to_string(),
         }
     }
 
@@ -253,6 +270,7 @@ mod tests {
         assert_eq!(body["data"]["email"], "test@example.com");
         assert_eq!(body["data"]["username"], "testuser");
         assert_eq!(body["data"]["full_name"], "Test User");
+        assert_eq!(body["data"]["preferred_locale"], "en");
         assert!(body.get("error").is_none());
     }
 
@@ -0,0 +1,229 @@
+use actix_web::{http::header::AUTHORIZATION, post, web, HttpRequest, Responder};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::auth::application::use_cases::introspect_token::{
+    IntrospectTokenError, TokenIntrospection,
+};
+use crate::shared::api::error_codes::{NOT_FOUND, UNAUTHORIZED};
+use crate::shared::api::ApiResponse;
+use crate::shared::constant_time::constant_time_eq;
+use crate::AppState;
+
+#[derive(Deserialize, ToSchema)]
+pub struct IntrospectTokenRequestDto {
+    /// Access, refresh or verification token to check
+    #[schema(example = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9...")]
+    pub token: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct IntrospectTokenResponse {
+    /// Whether this server would accept the token right now
+    active: bool,
+    /// Why it would not: `invalid`, `expired`, `not_yet_valid`,
+    /// `blacklisted` or `revoked`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "revoked")]
+    reason: Option<String>,
+    /// Given up at logout
+    blacklisted: bool,
+    /// Seconds until the token expires; absent if its claims can't be read
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = 1740)]
+    expires_in: Option<i64>,
+    /// Absent if the token is malformed, forged or expired
+    #[serde(skip_serializing_if = "Option::is_none")]
+    claims: Option<IntrospectedClaims>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct IntrospectedClaims {
+    sub: Uuid,
+    #[schema(example = "access")]
+    token_type: String,
+    is_verified: bool,
+    iss: String,
+    aud: String,
+    exp: i64,
+    iat: i64,
+    token_version: i32,
+}
+
+impl From<TokenIntrospection> for IntrospectTokenResponse {
+    fn from(result: TokenIntrospection) -> Self {
+        Self {
+            active: result.is_active(),
+            reason: result.inactive_reason.map(|r| r.as_str().to_string()),
+            blacklisted: result.blacklisted,
+            expires_in: result.expires_in(),
+            claims: result.claims.map(|claims| IntrospectedClaims {
+                sub: claims.sub,
+                token_type: claims.token_type,
+                is_verified: claims.is_verified,
+                iss: claims.iss,
+                aud: claims.aud,
+                exp: claims.exp,
+                iat: claims.iat,
+                token_version: claims.token_version,
+            }),
+        }
+    }
+}
+
+/// Token introspection for trusted services
+///
+/// Tells internal services (the image processor callback, edge workers)
+/// whether a token would be accepted by this API, with its claims, blacklist
+/// status and remaining lifetime. Callers authenticate with
+/// `Authorization: Bearer <INTROSPECTION_TOKEN>`.
+#[utoipa::path(
+    post,
+    path = "/api/auth/introspect",
+    tag = "auth",
+    request_body = IntrospectTokenRequestDto,
+    responses(
+        (
+            status = 200,
+            description = "Token checked; `active` tells whether it is usable",
+            body = inline(SuccessResponse<IntrospectTokenResponse>),
+            example = json!({
+                "success": true,
+                "data": {
+                    "active": true,
+                    "blacklisted": false,
+                    "expires_in": 1740,
+                    "claims": {
+                        "sub": "123e4567-e89b-12d3-a456-426614174000",
+                        "token_type": "access",
+                        "is_verified": true,
+                        "iss": "Ekstion",
+                        "aud": "Ekstion API",
+                        "exp": 1760000000,
+                        "iat": 1759998200,
+                        "token_version": 0
+                    }
+                }
+            })
+        ),
+        (status = 401, description = "Missing or wrong introspection secret", body = ErrorResponse),
+        (status = 404, description = "`INTROSPECTION_TOKEN` is not configured", body = ErrorResponse),
+        (status = 500, description = "Blacklist or token version lookup failed", body = ErrorResponse),
+    )
+)]
+#[post("/api/auth/introspect")]
+pub async fn introspect_token_handler(
+    http_req: HttpRequest,
+    req: web::Json<IntrospectTokenRequestDto>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let Some(expected) = data.introspection_token.as_deref() else {
+        return NOT_FOUND.response();
+    };
+    let presented = http_req
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    if !presented.is_some_and(|secret| constant_time_eq(expected.as_bytes(), secret.as_bytes())) {
+        return UNAUTHORIZED.with_message("Missing or invalid introspection secret");
+    }
+
+    match data.auth.introspect_token.execute(&req.token).await {
+        Ok(result) => ApiResponse::success(IntrospectTokenResponse::from(result)),
+        Err(IntrospectTokenError::LookupFailed(e)) => {
+            error!("Token introspection failed: {}", e);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::json;
+
+    use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+    use crate::tests::support::app_state_builder::TestAppStateBuilder;
+    use crate::tests::support::auth_helper::test_helpers::create_test_jwt_service;
+    use crate::tests::support::stubs::StubTokenVersionRepository;
+
+    const SECRET: &str = "0123456789abcdef";
+
+    async fn call(
+        state: web::Data<AppState>,
+        secret: Option<&str>,
+        token: &str,
+    ) -> actix_web::dev::ServiceResponse {
+        let app =
+            test::init_service(App::new().app_data(state).service(introspect_token_handler)).await;
+        let mut req = test::TestRequest::post()
+            .uri("/api/auth/introspect")
+            .set_json(json!({ "token": token }));
+        if let Some(secret) = secret {
+            req = req.insert_header((AUTHORIZATION, format!("Bearer {secret}")));
+        }
+        test::call_service(&app, req.to_request()).await
+    }
+
+    #[actix_web::test]
+    async fn test_introspect_returns_claims_of_active_token() {
+        let user_id = Uuid::new_v4();
+        let token = create_test_jwt_service()
+            .generate_access_token(user_id, true, 0)
+            .unwrap();
+        let state = TestAppStateBuilder::default()
+            .with_introspection_token(SECRET)
+            .build();
+
+        let resp = call(state, Some(SECRET), &token).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["active"], true);
+        assert_eq!(body["data"]["claims"]["sub"], user_id.to_string());
+        assert_eq!(body["data"]["claims"]["token_type"], "access");
+        assert!(body["data"]["expires_in"].as_i64().unwrap() > 0);
+        assert!(body["data"].get("reason").is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_introspect_reports_revoked_token_as_inactive() {
+        let token = create_test_jwt_service()
+            .generate_access_token(Uuid::new_v4(), true, 0)
+            .unwrap();
+        let state = TestAppStateBuilder::default()
+            .with_introspection_token(SECRET)
+            .with_token_version_repository(StubTokenVersionRepository::at(1))
+            .build();
+
+        let body: serde_json::Value =
+            test::read_body_json(call(state, Some(SECRET), &token).await).await;
+        assert_eq!(body["data"]["active"], false);
+        assert_eq!(body["data"]["reason"], "revoked");
+    }
+
+    #[actix_web::test]
+    async fn test_introspect_needs_the_shared_secret() {
+        let state = TestAppStateBuilder::default()
+            .with_introspection_token(SECRET)
+            .build();
+        let resp = call(state.clone(), None, "token").await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        let resp = call(state, Some("fedcba9876543210"), "token").await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        let resp = call(
+            TestAppStateBuilder::default().build(),
+            Some(SECRET),
+            "token",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
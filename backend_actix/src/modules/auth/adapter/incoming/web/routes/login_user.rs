@@ -1,9 +1,14 @@
 use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::auth::adapter::incoming::web::error_codes::{
+    ACCOUNT_DEACTIVATED, INVALID_CREDENTIALS, USER_DELETED,
+};
 use crate::auth::application::use_cases::login_user::LoginError;
 use crate::auth::application::use_cases::login_user::LoginRequest;
+use crate::auth::application::use_cases::login_user::LoginUserResponse;
+use crate::shared::api::error_codes::VALIDATION_ERROR;
 use crate::shared::api::ApiResponse;
 use crate::AppState;
-use actix_web::{post, web, Responder};
+use actix_web::{post, web, HttpRequest, HttpResponse, Responder};
 use serde::Deserialize;
 use serde::Serialize;
 use tracing::{error, info, warn};
@@ -20,17 +25,25 @@ pub struct LoginRequestDto {
     /// Password
     #[schema(example = "SecurePass123!")]
     pub password: String,
+
+    /// Issue a long-lived refresh token (`JWT_REMEMBER_ME_REFRESH_EXPIRY`)
+    /// instead of the default one (`JWT_REFRESH_EXPIRY`)
+    #[serde(default)]
+    #[schema(example = false)]
+    pub remember_me: bool,
 }
 
 #[derive(Serialize, ToSchema)]
 pub struct LoginResponse {
-    /// JWT access token (short-lived)
+    /// JWT access token (short-lived); absent in cookie mode, where it is set as a cookie
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[schema(example = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9...")]
-    access_token: String,
+    access_token: Option<String>,
 
-    /// JWT refresh token (long-lived)
+    /// JWT refresh token (long-lived); absent in cookie mode, where it is set as a cookie
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[schema(example = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9...")]
-    refresh_token: String,
+    refresh_token: Option<String>,
 
     /// Authenticated user information
     user: LoginUserInfo,
@@ -63,6 +76,9 @@ pub struct LoginUserInfo {
     path = "/api/auth/login",
     tag = "auth",
     request_body = LoginRequestDto,  // ✅ Use the DTO
+    params(
+        ("X-Bot-Check-Token" = Option<String>, Header, description = "CAPTCHA token; required after repeated failed logins when the bot check is enabled")
+    ),
     responses(
         (
             status = 200,
@@ -95,17 +111,45 @@ pub struct LoginUserInfo {
             })
         ),
         (
-            status = 403,
-            description = "Account has been deleted",
+            status = 400,
+            description = "Bot check token required",
             body = ErrorResponse,
             example = json!({
                 "success": false,
                 "error": {
-                    "code": "USER_DELETED",
-                    "message": "This account has been deleted"
+                    "code": "BOT_CHECK_REQUIRED",
+                    "message": "Complete the bot check and send its token in X-Bot-Check-Token"
                 }
             })
         ),
+        (
+            status = 403,
+            description = "Account has been deleted or deactivated, or the bot check failed",
+            body = ErrorResponse,
+            examples(
+                ("User deleted" = (value = json!({
+                    "success": false,
+                    "error": {
+                        "code": "USER_DELETED",
+                        "message": "This account has been deleted"
+                    }
+                }))),
+                ("Account deactivated" = (value = json!({
+                    "success": false,
+                    "error": {
+                        "code": "ACCOUNT_DEACTIVATED",
+                        "message": "This account is deactivated; reactivate it to log in"
+                    }
+                }))),
+                ("Bot check failed" = (value = json!({
+                    "success": false,
+                    "error": {
+                        "code": "BOT_CHECK_FAILED",
+                        "message": "Bot check failed, please retry"
+                    }
+                })))
+            )
+        ),
         (
             status = 500,
             description = "Internal server error",
@@ -122,20 +166,27 @@ pub struct LoginUserInfo {
 )]
 #[post("/api/auth/login")]
 pub async fn login_user_handler(
+    http_req: HttpRequest,
     req: web::Json<LoginRequestDto>,
     data: web::Data<AppState>,
 ) -> impl Responder {
-    let use_case = &data.login_user_use_case;
+    let use_case = &data.auth.login;
     let dto = req.into_inner();
+    let email = dto.email.clone();
+
+    if let Err(e) = data.bot_gate.check_login(&email, &http_req).await {
+        warn!(error = %e, "Login blocked by bot check");
+        return e.to_response();
+    }
 
-    info!(email = %dto.email, "Login attempt");
+    let remember_me = dto.remember_me;
 
     // ✅ Convert DTO to domain LoginRequest
     let request = match LoginRequest::new(dto.email, dto.password) {
-        Ok(req) => req,
+        Ok(req) => req.with_remember_me(remember_me),
         Err(e) => {
             // Handle validation error if LoginRequest::new validates
-            return ApiResponse::bad_request("VALIDATION_ERROR", &e.to_string());
+            return VALIDATION_ERROR.with_message(&e.to_string());
         }
     };
 
@@ -143,32 +194,30 @@ pub async fn login_user_handler(
 
     match result {
         Ok(response) => {
+            data.bot_gate.clear_login_failures(&email);
             info!(
                 user_id = %response.user.id,
                 email = %response.user.email,
                 "User logged in successfully"
             );
-
-            ApiResponse::success(LoginResponse {
-                access_token: response.access_token,
-                refresh_token: response.refresh_token,
-                user: LoginUserInfo {
-                    id: response.user.id.to_string(),
-                    username: response.user.username,
-                    email: response.user.email,
-                    is_verified: response.user.is_verified,
-                },
-            })
+            session_response(&data, response, remember_me)
         }
 
         Err(LoginError::InvalidCredentials) => {
+            data.bot_gate.record_login_failure(&email);
             warn!("Login failed: Invalid credentials");
-            ApiResponse::unauthorized("INVALID_CREDENTIALS", "Invalid email or password")
+            INVALID_CREDENTIALS.response()
         }
 
         Err(LoginError::UserDeleted) => {
             warn!("Login failed: User deleted");
-            ApiResponse::forbidden("USER_DELETED", "This account has been deleted")
+            USER_DELETED.response()
+        }
+
+        Err(LoginError::AccountDeactivated) => {
+            data.bot_gate.clear_login_failures(&email);
+            info!("Login refused: account deactivated");
+            ACCOUNT_DEACTIVATED.response()
         }
 
         Err(LoginError::PasswordVerificationFailed(ref e)) => {
@@ -188,16 +237,56 @@ pub async fn login_user_handler(
     }
 }
 
+/// `LoginResponse` for a successful login; in cookie mode the tokens are
+/// set as cookies instead. Passkey login answers the same way.
+pub(crate) fn session_response(
+    data: &AppState,
+    response: LoginUserResponse,
+    remember_me: bool,
+) -> HttpResponse {
+    let user = LoginUserInfo {
+        id: response.user.id.to_string(),
+        username: response.user.username,
+        email: response.user.email,
+        is_verified: response.user.is_verified,
+    };
+    match &data.auth_cookies {
+        Some(cookies) => {
+            let mut resp = ApiResponse::success(LoginResponse {
+                access_token: None,
+                refresh_token: None,
+                user,
+            });
+            cookies.set_session(
+                &mut resp,
+                &response.access_token,
+                &response.refresh_token,
+                remember_me,
+            );
+            resp
+        }
+        None => ApiResponse::success(LoginResponse {
+            access_token: Some(response.access_token),
+            refresh_token: Some(response.refresh_token),
+            user,
+        }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::auth::adapter::incoming::web::cookies::ACCESS_COOKIE;
     use crate::auth::application::use_cases::login_user::{
         ILoginUserUseCase, LoginError, LoginRequest, LoginUserResponse, UserInfo,
     };
+    use crate::shared::bot_check::{BotCheckedEndpoint, BotGate, NoopBotCheck, BOT_CHECK_HEADER};
     use crate::tests::support::app_state_builder::TestAppStateBuilder;
+    use crate::tests::support::auth_helper::test_helpers::create_test_auth_cookies;
     use crate::tests::support::load_test_env;
     use actix_web::{test, App};
     use async_trait::async_trait;
+    use std::sync::Arc;
     use uuid::Uuid;
 
     // ========================================================================
@@ -272,6 +361,16 @@ mod tests {
         }
     }
 
+    #[derive(Clone)]
+    struct MockLoginAccountDeactivated;
+
+    #[async_trait]
+    impl ILoginUserUseCase for MockLoginAccountDeactivated {
+        async fn execute(&self, _request: LoginRequest) -> Result<LoginUserResponse, LoginError> {
+            Err(LoginError::AccountDeactivated)
+        }
+    }
+
     #[derive(Clone)]
     struct MockLoginPasswordVerificationFailed;
 
@@ -352,6 +451,41 @@ mod tests {
         assert!(body.get("error").is_none());
     }
 
+    #[actix_web::test]
+    async fn test_login_user_in_cookie_mode_sets_cookies_instead_of_body_tokens() {
+        let app_state = TestAppStateBuilder::default()
+            .with_login_user(MockLoginUserSuccess)
+            .with_auth_cookies(create_test_auth_cookies())
+            .build();
+
+        let app =
+            test::init_service(App::new().app_data(app_state).service(login_user_handler)).await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/auth/login")
+            .set_json(&create_test_login_request_json())
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+
+        let access = resp
+            .response()
+            .cookies()
+            .find(|cookie| cookie.name() == ACCESS_COOKIE)
+            .expect("access cookie");
+        assert_eq!(
+            access.value(),
+            "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.access"
+        );
+        assert_eq!(access.http_only(), Some(true));
+
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert!(body["data"].get("access_token").is_none());
+        assert!(body["data"].get("refresh_token").is_none());
+        assert_eq!(body["data"]["user"]["username"], "testuser");
+    }
+
     #[actix_web::test]
     async fn test_login_user_success_with_unverified_user() {
         let app_state = TestAppStateBuilder::default()
@@ -424,6 +558,27 @@ mod tests {
         assert!(body.get("data").is_none());
     }
 
+    #[actix_web::test]
+    async fn test_login_account_deactivated() {
+        let app_state = TestAppStateBuilder::default()
+            .with_login_user(MockLoginAccountDeactivated)
+            .build();
+
+        let app =
+            test::init_service(App::new().app_data(app_state).service(login_user_handler)).await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/auth/login")
+            .set_json(&create_test_login_request_json())
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 403);
+
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "ACCOUNT_DEACTIVATED");
+    }
+
     #[actix_web::test]
     async fn test_login_password_verification_failed() {
         let app_state = TestAppStateBuilder::default()
@@ -706,4 +861,46 @@ mod tests {
         assert_eq!(body["error"]["code"], "VALIDATION_ERROR");
         assert!(body.get("data").is_none());
     }
+
+    #[actix_web::test]
+    async fn test_login_requires_bot_check_after_repeated_failures() {
+        let app_state = TestAppStateBuilder::default()
+            .with_login_user(MockLoginUserInvalidCredentials)
+            .with_bot_gate(BotGate::new(
+                Arc::new(NoopBotCheck),
+                vec![BotCheckedEndpoint::Login],
+                2,
+            ))
+            .build();
+
+        let app =
+            test::init_service(App::new().app_data(app_state).service(login_user_handler)).await;
+
+        for _ in 0..2 {
+            let req = test::TestRequest::post()
+                .uri("/api/auth/login")
+                .set_json(&create_test_login_request_json())
+                .to_request();
+            let resp = test::call_service(&app, req).await;
+            assert_eq!(resp.status(), 401);
+        }
+
+        let req = test::TestRequest::post()
+            .uri("/api/auth/login")
+            .set_json(&create_test_login_request_json())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 400);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "BOT_CHECK_REQUIRED");
+
+        // With a token the credentials are checked again
+        let req = test::TestRequest::post()
+            .uri("/api/auth/login")
+            .insert_header((BOT_CHECK_HEADER, "solved"))
+            .set_json(&create_test_login_request_json())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 401);
+    }
 }
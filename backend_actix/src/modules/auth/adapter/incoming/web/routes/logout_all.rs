@@ -0,0 +1,139 @@
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::auth::adapter::incoming::web::error_codes::USER_NOT_FOUND;
+use crate::auth::adapter::incoming::web::extractors::auth::AuthenticatedUser;
+use crate::auth::adapter::incoming::web::routes::LogoutResponseBody;
+use crate::auth::application::use_cases::logout_all::LogoutAllError;
+use crate::shared::api::ApiResponse;
+use crate::AppState;
+use actix_web::{post, web, Responder};
+use tracing::error;
+
+/// Logout from all devices
+///
+/// Revokes every access and refresh token issued to the authenticated user so far,
+/// including the one used for this request. Log in again to get a new pair.
+#[utoipa::path(
+    post,
+    path = "/api/auth/logout-all",
+    tag = "auth",
+    responses(
+        (
+            status = 200,
+            description = "All sessions ended",
+            body = inline(SuccessResponse<LogoutResponseBody>),
+            example = json!({
+                "success": true,
+                "data": {
+                    "message": "Logged out from all devices"
+                }
+            })
+        ),
+        (status = 401, description = "Missing, invalid or revoked access token", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/auth/logout-all")]
+pub async fn logout_all_handler(
+    user: AuthenticatedUser,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data.auth.logout_all.execute(user.user_id).await {
+        Ok(()) => {
+            let mut resp =
+                ApiResponse::success(LogoutResponseBody::new("Logged out from all devices"));
+            if let Some(cookies) = &data.auth_cookies {
+                cookies.clear(&mut resp);
+            }
+            resp
+        }
+
+        Err(LogoutAllError::UserNotFound) => USER_NOT_FOUND.response(),
+
+        Err(LogoutAllError::DatabaseError(e)) => {
+            error!(
+                "Failed to revoke all tokens of user {}: {}",
+                user.user_id, e
+            );
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use actix_web::{http::StatusCode, test, App};
+    use uuid::Uuid;
+
+    use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+    use crate::tests::support::app_state_builder::TestAppStateBuilder;
+    use crate::tests::support::auth_helper::test_helpers::create_test_jwt_service;
+    use crate::tests::support::stubs::{StubLogoutAllUseCase, StubTokenVersionRepository};
+
+    async fn call(
+        state: web::Data<AppState>,
+        token_version: i32,
+    ) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt
+            .generate_access_token(Uuid::new_v4(), true, token_version)
+            .unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(logout_all_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/auth/logout-all")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_logout_all_success() {
+        let state = TestAppStateBuilder::default().build();
+
+        let resp = call(state, 0).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["message"], "Logged out from all devices");
+    }
+
+    #[actix_web::test]
+    async fn test_logout_all_database_error() {
+        let state = TestAppStateBuilder::default()
+            .with_logout_all(StubLogoutAllUseCase::failure())
+            .build();
+
+        let resp = call(state, 0).await;
+
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[actix_web::test]
+    async fn test_token_from_before_logout_all_is_rejected() {
+        let state = TestAppStateBuilder::default()
+            .with_token_version_repository(StubTokenVersionRepository::at(1))
+            .build();
+
+        let resp = call(state, 0).await;
+
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "TOKEN_REVOKED");
+    }
+}
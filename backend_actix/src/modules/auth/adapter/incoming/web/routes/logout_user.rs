@@ -1,8 +1,9 @@
 use crate::api::schemas::SuccessResponse;
+use crate::auth::adapter::incoming::web::error_codes::INVALID_REQUEST;
 use crate::modules::auth::application::use_cases::logout_user::{LogoutError, LogoutRequest};
 use crate::shared::api::ApiResponse;
 use crate::AppState;
-use actix_web::{post, web, Responder};
+use actix_web::{post, web, HttpRequest, Responder};
 use serde::{Deserialize, Serialize};
 use tracing::{error, info, warn};
 use utoipa::ToSchema;
@@ -10,7 +11,8 @@ use utoipa::ToSchema;
 /// Logout request from client
 #[derive(Deserialize, ToSchema)]
 pub struct LogoutRequestDto {
-    /// Refresh token to revoke (optional)
+    /// Refresh token to revoke (optional); in cookie mode the `refresh_token`
+    /// cookie is used when left out
     #[serde(default)]
     #[schema(example = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9...")]
     pub refresh_token: Option<String>,
@@ -23,6 +25,14 @@ pub struct LogoutResponseBody {
     message: String,
 }
 
+impl LogoutResponseBody {
+    pub fn new(message: &str) -> Self {
+        Self {
+            message: message.to_string(),
+        }
+    }
+}
+
 /// User logout
 ///
 /// Revokes the provided refresh token, preventing it from being used to generate new access tokens.
@@ -48,27 +58,32 @@ pub struct LogoutResponseBody {
 )]
 #[post("/api/auth/logout")]
 pub async fn logout_user_handler(
+    http_req: HttpRequest,
     req: web::Json<LogoutRequestDto>,
     data: web::Data<AppState>,
 ) -> impl Responder {
-    let use_case = &data.logout_user_use_case;
+    let use_case = &data.auth.logout;
     let dto = req.into_inner();
 
-    info!("User logout attempt");
+    // In cookie mode the refresh token can come from its cookie
+    let refresh_token = dto.refresh_token.or_else(|| {
+        data.auth_cookies
+            .as_ref()
+            .and_then(|cookies| cookies.refresh_token(&http_req))
+    });
 
-    // Convert DTO to domain LogoutRequest
     // Convert DTO to domain LogoutRequest - handle validation
-    let request = match LogoutRequest::new(dto.refresh_token) {
+    let request = match LogoutRequest::new(refresh_token) {
         Ok(req) => req,
         Err(e) => {
             warn!("Invalid logout request: {}", e);
-            return ApiResponse::bad_request("INVALID_REQUEST", &e.to_string());
+            return INVALID_REQUEST.with_message(&e.to_string());
         }
     };
 
     let result = use_case.execute(request).await;
 
-    match result {
+    let mut resp = match result {
         Ok(response) => {
             info!("User logged out successfully");
             ApiResponse::success(LogoutResponseBody {
@@ -91,17 +106,24 @@ pub async fn logout_user_handler(
                 message: "Logged out successfully".to_string(),
             })
         }
+    };
+
+    if let Some(cookies) = &data.auth_cookies {
+        cookies.clear(&mut resp);
     }
+    resp
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::auth::adapter::incoming::web::cookies::REFRESH_COOKIE;
     use crate::auth::application::use_cases::logout_user::{
         ILogoutUseCase, LogoutError, LogoutRequest, LogoutResponse,
     };
     use crate::tests::support::app_state_builder::TestAppStateBuilder;
-    use actix_web::{test, App};
+    use crate::tests::support::auth_helper::test_helpers::create_test_auth_cookies;
+    use actix_web::{cookie::Cookie, test, App};
     use async_trait::async_trait;
 
     // ========================================================================
@@ -144,6 +166,20 @@ mod tests {
         }
     }
 
+    /// Fails unless it is handed the refresh token from the cookie
+    #[derive(Clone)]
+    struct MockLogoutExpectingCookieToken;
+
+    #[async_trait]
+    impl ILogoutUseCase for MockLogoutExpectingCookieToken {
+        async fn execute(&self, request: LogoutRequest) -> Result<LogoutResponse, LogoutError> {
+            assert_eq!(request.refresh_token(), Some("refresh-from-cookie"));
+            Ok(LogoutResponse {
+                message: "Logged out successfully".to_string(),
+            })
+        }
+    }
+
     // ========================================================================
     // Tests
     // ========================================================================
@@ -449,4 +485,28 @@ mod tests {
             assert!(body.get("error").is_none());
         }
     }
+
+    #[actix_web::test]
+    async fn test_logout_in_cookie_mode_revokes_cookie_token_and_clears_cookies() {
+        let app_state = TestAppStateBuilder::default()
+            .with_logout_user(MockLogoutExpectingCookieToken)
+            .with_auth_cookies(create_test_auth_cookies())
+            .build();
+
+        let app =
+            test::init_service(App::new().app_data(app_state).service(logout_user_handler)).await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/auth/logout")
+            .cookie(Cookie::new(REFRESH_COOKIE, "refresh-from-cookie"))
+            .set_json(&serde_json::json!({}))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+
+        let cleared: Vec<_> = resp.response().cookies().collect();
+        assert_eq!(cleared.len(), 3);
+        assert!(cleared.iter().all(|cookie| cookie.value().is_empty()));
+    }
 }
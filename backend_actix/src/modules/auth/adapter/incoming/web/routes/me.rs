@@ -0,0 +1,299 @@
+use actix_web::{get, patch, web, HttpResponse, Responder};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::auth::adapter::incoming::web::error_codes::{
+    AVATAR_NOT_FOUND, INVALID_FULL_NAME, INVALID_TIMEZONE, USER_NOT_FOUND,
+};
+use crate::shared::api::error_codes::INVALID_LOCALE;
+use crate::{
+    auth::{
+        adapter::incoming::web::extractors::auth::AuthenticatedUser,
+        application::{
+            domain::entities::UserId,
+            use_cases::user_profile::{UserProfile, UserProfileError, UserProfilePatch},
+        },
+    },
+    project::application::ports::outgoing::project_repository::PatchField,
+    shared::api::ApiResponse,
+    AppState,
+};
+
+#[derive(Serialize, ToSchema)]
+pub struct MeResponse {
+    #[schema(example = "123e4567-e89b-12d3-a456-426614174000")]
+    user_id: Uuid,
+
+    #[schema(example = "john@example.com")]
+    email: String,
+
+    #[schema(example = "johndoe")]
+    username: String,
+
+    #[schema(example = "John Doe")]
+    full_name: String,
+
+    /// Locale emails are sent in
+    #[schema(example = "en")]
+    preferred_locale: String,
+
+    /// IANA timezone dates are shown in
+    #[schema(example = "Europe/Berlin")]
+    timezone: String,
+
+    /// One of the user's media; `null` when none is set or it was trashed
+    avatar_media_id: Option<Uuid>,
+}
+
+impl From<UserProfile> for MeResponse {
+    fn from(profile: UserProfile) -> Self {
+        Self {
+            user_id: profile.user_id.value(),
+            email: profile.email,
+            username: profile.username,
+            full_name: profile.full_name,
+            preferred_locale: profile.preferred_locale,
+            timezone: profile.timezone,
+            avatar_media_id: profile.avatar_media_id,
+        }
+    }
+}
+
+/// Omitted fields are kept; `null` clears the avatar
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PatchMeRequest {
+    #[schema(example = "John Smith")]
+    full_name: Option<String>,
+
+    /// `ll` or `ll-RR`
+    #[schema(example = "id")]
+    preferred_locale: Option<String>,
+
+    /// IANA name, e.g. `Asia/Jakarta`
+    #[schema(example = "Asia/Jakarta")]
+    timezone: Option<String>,
+
+    /// One of the caller's own media
+    #[serde(default)]
+    #[schema(value_type = Option<Uuid>)]
+    avatar_media_id: PatchField<Uuid>,
+}
+
+fn profile_error(err: UserProfileError) -> HttpResponse {
+    match err {
+        UserProfileError::UserNotFound => USER_NOT_FOUND.response(),
+        UserProfileError::InvalidFullName(msg) => INVALID_FULL_NAME.with_message(&msg),
+        UserProfileError::InvalidLocale(msg) => INVALID_LOCALE.with_message(&msg),
+        UserProfileError::InvalidTimezone(msg) => INVALID_TIMEZONE.with_message(&msg),
+        UserProfileError::AvatarNotFound => AVATAR_NOT_FOUND.response(),
+        UserProfileError::RepositoryError(e) => {
+            error!("Repository error on user profile: {}", e);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+/// Get the caller's profile
+///
+/// Everything the user can edit at `PATCH /api/auth/me`, along with their
+/// email and username.
+#[utoipa::path(
+    get,
+    path = "/api/auth/me",
+    tag = "auth",
+    responses(
+        (status = 200, description = "The caller's profile", body = inline(SuccessResponse<MeResponse>)),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/auth/me")]
+pub async fn get_me_handler(user: AuthenticatedUser, data: web::Data<AppState>) -> impl Responder {
+    match data
+        .auth
+        .get_profile
+        .execute(UserId::from(user.user_id))
+        .await
+    {
+        Ok(profile) => ApiResponse::success(MeResponse::from(profile)),
+        Err(err) => profile_error(err),
+    }
+}
+
+/// Edit the caller's profile
+///
+/// Changes the full name, preferred locale, timezone or avatar; the avatar
+/// must be one of the caller's own media.
+#[utoipa::path(
+    patch,
+    path = "/api/auth/me",
+    tag = "auth",
+    request_body = PatchMeRequest,
+    responses(
+        (status = 200, description = "Profile updated", body = inline(SuccessResponse<MeResponse>)),
+        (status = 400, description = "Invalid full name, locale or timezone", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 404, description = "User or avatar media not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[patch("/api/auth/me")]
+pub async fn patch_me_handler(
+    user: AuthenticatedUser,
+    payload: web::Json<PatchMeRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let payload = payload.into_inner();
+    let patch = UserProfilePatch {
+        full_name: payload.full_name,
+        preferred_locale: payload.preferred_locale,
+        timezone: payload.timezone,
+        avatar_media_id: payload.avatar_media_id,
+    };
+
+    match data
+        .auth
+        .patch_profile
+        .execute(UserId::from(user.user_id), patch)
+        .await
+    {
+        Ok(profile) => ApiResponse::success(MeResponse::from(profile)),
+        Err(err) => profile_error(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::{json, Value};
+
+    use crate::auth::adapter::outgoing::in_memory::InMemoryUserStore;
+    use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+    use crate::auth::application::ports::outgoing::user_repository::{
+        CreateUserData, UserRepository,
+    };
+    use crate::auth::application::services::{GetUserProfileService, PatchUserProfileService};
+    use crate::tests::support::app_state_builder::TestAppStateBuilder;
+    use crate::tests::support::auth_helper::test_helpers::create_test_jwt_service;
+
+    async fn call(
+        store: &InMemoryUserStore,
+        user_id: Uuid,
+        req: test::TestRequest,
+    ) -> actix_web::dev::ServiceResponse {
+        let state = TestAppStateBuilder::default()
+            .with_get_profile(GetUserProfileService::new(store.clone()))
+            .with_patch_profile(PatchUserProfileService::new(store.clone()))
+            .build();
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(user_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(get_me_handler)
+                .service(patch_me_handler),
+        )
+        .await;
+
+        let req = req
+            .uri("/api/auth/me")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+        test::call_service(&app, req).await
+    }
+
+    async fn user(store: &InMemoryUserStore) -> Uuid {
+        store
+            .create_user(CreateUserData {
+                email: "jane@example.com".to_string(),
+                username: "jane".to_string(),
+                password_hash: "hash".to_string(),
+                full_name: "Jane Doe".to_string(),
+                preferred_locale: "en".to_string(),
+            })
+            .await
+            .unwrap()
+            .id
+    }
+
+    #[actix_web::test]
+    async fn test_get_me() {
+        let store = InMemoryUserStore::default();
+        let user_id = user(&store).await;
+
+        let resp = call(&store, user_id, test::TestRequest::get()).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["username"], "jane");
+        assert_eq!(body["data"]["timezone"], "UTC");
+        assert_eq!(body["data"]["avatar_media_id"], Value::Null);
+    }
+
+    #[actix_web::test]
+    async fn test_patch_me_sets_and_clears_fields() {
+        let store = InMemoryUserStore::default();
+        let user_id = user(&store).await;
+        let avatar = Uuid::new_v4();
+
+        let resp = call(
+            &store,
+            user_id,
+            test::TestRequest::patch().set_json(json!({
+                "timezone": "Asia/Jakarta",
+                "avatar_media_id": avatar,
+            })),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["full_name"], "Jane Doe");
+        assert_eq!(body["data"]["timezone"], "Asia/Jakarta");
+        assert_eq!(body["data"]["avatar_media_id"], avatar.to_string());
+
+        let resp = call(
+            &store,
+            user_id,
+            test::TestRequest::patch().set_json(json!({ "avatar_media_id": null })),
+        )
+        .await;
+
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["timezone"], "Asia/Jakarta");
+        assert_eq!(body["data"]["avatar_media_id"], Value::Null);
+    }
+
+    #[actix_web::test]
+    async fn test_patch_me_invalid_timezone() {
+        let store = InMemoryUserStore::default();
+        let user_id = user(&store).await;
+
+        let resp = call(
+            &store,
+            user_id,
+            test::TestRequest::patch().set_json(json!({ "timezone": "not a zone" })),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "INVALID_TIMEZONE");
+    }
+}
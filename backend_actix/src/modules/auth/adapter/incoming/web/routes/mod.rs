@@ -1,16 +1,28 @@
+mod deactivate_account;
 mod delete_user;
 mod fetch_user;
+mod introspect_token;
 mod login_user;
+mod logout_all;
 mod logout_user;
+mod me;
+mod passkeys;
+mod reactivate_account;
 mod refresh_token;
 mod register_user;
 mod update_profile;
 mod verify_email;
 
+pub use deactivate_account::*;
 pub use delete_user::*;
 pub use fetch_user::*;
+pub use introspect_token::*;
 pub use login_user::*;
+pub use logout_all::*;
 pub use logout_user::*;
+pub use me::*;
+pub use passkeys::*;
+pub use reactivate_account::*;
 pub use refresh_token::*;
 pub use register_user::*;
 pub use update_profile::*;
@@ -0,0 +1,512 @@
+use actix_web::{delete, get, post, web, HttpResponse, Responder};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{error, info, warn};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::login_user::{session_response, LoginResponse};
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::auth::adapter::incoming::web::error_codes::{
+    ACCOUNT_DEACTIVATED, INVALID_CREDENTIALS, NO_PASSKEYS, PASSKEYS_DISABLED,
+    PASSKEY_ALREADY_REGISTERED, PASSKEY_CHALLENGE_EXPIRED, PASSKEY_NOT_FOUND, PASSKEY_REJECTED,
+    USER_DELETED, USER_NOT_FOUND,
+};
+use crate::auth::adapter::incoming::web::extractors::auth::AuthenticatedUser;
+use crate::auth::application::use_cases::passkeys::{
+    finish_passkey_login::FinishPasskeyLogin,
+    finish_passkey_registration::FinishPasskeyRegistration, PasskeyChallenge, PasskeyError,
+    PasskeyInfo,
+};
+use crate::shared::api::error_codes::VALIDATION_ERROR;
+use crate::shared::api::ApiResponse;
+use crate::AppState;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyChallengeResponse {
+    /// Send back with the authenticator's answer, within 5 minutes
+    pub challenge_id: Uuid,
+    /// Pass to `navigator.credentials.create()` (registration) or
+    /// `navigator.credentials.get()` (login) as is
+    #[schema(value_type = Object)]
+    pub options: serde_json::Value,
+}
+
+impl From<PasskeyChallenge> for PasskeyChallengeResponse {
+    fn from(challenge: PasskeyChallenge) -> Self {
+        Self {
+            challenge_id: challenge.challenge_id,
+            options: challenge.options,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyResponse {
+    pub id: Uuid,
+    #[schema(example = "Work laptop")]
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    /// Last sign-in with it; absent if never used
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<PasskeyInfo> for PasskeyResponse {
+    fn from(info: PasskeyInfo) -> Self {
+        Self {
+            id: info.id,
+            name: info.name,
+            created_at: info.created_at,
+            last_used_at: info.last_used_at,
+        }
+    }
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FinishPasskeyRegistrationRequest {
+    pub challenge_id: Uuid,
+    /// Label to tell the user's passkeys apart, up to 64 characters
+    #[schema(example = "Work laptop")]
+    pub name: String,
+    /// The `PublicKeyCredential` from `navigator.credentials.create()`,
+    /// binary fields base64url encoded
+    #[schema(value_type = Object)]
+    pub credential: serde_json::Value,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct StartPasskeyLoginRequest {
+    #[schema(example = "john@example.com")]
+    pub email: String,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FinishPasskeyLoginRequest {
+    pub challenge_id: Uuid,
+    /// The `PublicKeyCredential` from `navigator.credentials.get()`,
+    /// binary fields base64url encoded
+    #[schema(value_type = Object)]
+    pub credential: serde_json::Value,
+    /// As for `POST /api/auth/login`
+    #[serde(default)]
+    pub remember_me: bool,
+}
+
+/// Answers for the errors every passkey endpoint can hit
+fn passkey_error(e: PasskeyError) -> HttpResponse {
+    match e {
+        PasskeyError::Disabled => PASSKEYS_DISABLED.response(),
+        PasskeyError::ChallengeNotFound => PASSKEY_CHALLENGE_EXPIRED.response(),
+        PasskeyError::VerificationFailed(reason) => {
+            warn!(reason = %reason, "Passkey verification failed");
+            PASSKEY_REJECTED.response()
+        }
+        PasskeyError::AlreadyRegistered => PASSKEY_ALREADY_REGISTERED.response(),
+        PasskeyError::PasskeyNotFound => PASSKEY_NOT_FOUND.response(),
+        PasskeyError::NoPasskeys => NO_PASSKEYS.response(),
+        PasskeyError::InvalidName(reason) => {
+            VALIDATION_ERROR.with_message(&format!("Invalid name: {reason}"))
+        }
+        PasskeyError::UserNotFound => USER_NOT_FOUND.response(),
+        PasskeyError::UserDeleted => USER_DELETED.response(),
+        PasskeyError::AccountDeactivated => ACCOUNT_DEACTIVATED.response(),
+        PasskeyError::TokenGenerationFailed(e) | PasskeyError::RepositoryError(e) => {
+            error!(error = %e, "Passkey request failed");
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+/// Start adding a passkey
+///
+/// Returns the options for `navigator.credentials.create()`. Passkeys the
+/// user already has are excluded, so an authenticator is registered once.
+#[utoipa::path(
+    post,
+    path = "/api/auth/passkeys/register/start",
+    tag = "auth",
+    responses(
+        (status = 200, description = "Challenge issued", body = inline(SuccessResponse<PasskeyChallengeResponse>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 404, description = "Passkeys are not enabled (`PASSKEYS_DISABLED`)", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/auth/passkeys/register/start")]
+pub async fn start_passkey_registration_handler(
+    user: AuthenticatedUser,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data.passkeys.start_registration.execute(user.user_id).await {
+        Ok(challenge) => ApiResponse::success(PasskeyChallengeResponse::from(challenge)),
+        Err(e) => passkey_error(e),
+    }
+}
+
+/// Finish adding a passkey
+///
+/// Verifies the authenticator's answer to the challenge and stores the
+/// passkey. It can sign in from then on, the password still works too.
+#[utoipa::path(
+    post,
+    path = "/api/auth/passkeys/register/finish",
+    tag = "auth",
+    request_body = FinishPasskeyRegistrationRequest,
+    responses(
+        (status = 201, description = "Passkey added", body = inline(SuccessResponse<PasskeyResponse>)),
+        (status = 400, description = "Invalid name, expired challenge (`PASSKEY_CHALLENGE_EXPIRED`), or the answer does not verify (`PASSKEY_REJECTED`)", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 404, description = "Passkeys are not enabled (`PASSKEYS_DISABLED`)", body = ErrorResponse),
+        (status = 409, description = "The passkey is already registered", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/auth/passkeys/register/finish")]
+pub async fn finish_passkey_registration_handler(
+    user: AuthenticatedUser,
+    req: web::Json<FinishPasskeyRegistrationRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let req = req.into_inner();
+    let request = FinishPasskeyRegistration {
+        challenge_id: req.challenge_id,
+        name: req.name,
+        credential: req.credential,
+    };
+
+    match data
+        .passkeys
+        .finish_registration
+        .execute(user.user_id, request)
+        .await
+    {
+        Ok(passkey) => {
+            info!(user_id = %user.user_id, passkey_id = %passkey.id, "Passkey registered");
+            ApiResponse::created(PasskeyResponse::from(passkey))
+        }
+        Err(e) => passkey_error(e),
+    }
+}
+
+/// List the signed-in user's passkeys
+#[utoipa::path(
+    get,
+    path = "/api/auth/passkeys",
+    tag = "auth",
+    responses(
+        (status = 200, description = "Passkeys, oldest first", body = inline(SuccessResponse<Vec<PasskeyResponse>>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/auth/passkeys")]
+pub async fn list_passkeys_handler(
+    user: AuthenticatedUser,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data.passkeys.list.execute(user.user_id).await {
+        Ok(passkeys) => ApiResponse::success(
+            passkeys
+                .into_iter()
+                .map(PasskeyResponse::from)
+                .collect::<Vec<_>>(),
+        ),
+        Err(e) => passkey_error(e),
+    }
+}
+
+/// Remove a passkey
+///
+/// It can no longer sign in. The password and other passkeys are unaffected.
+#[utoipa::path(
+    delete,
+    path = "/api/auth/passkeys/{passkey_id}",
+    tag = "auth",
+    params(
+        ("passkey_id" = Uuid, Path, description = "Passkey id"),
+    ),
+    responses(
+        (status = 204, description = "Passkey removed"),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 404, description = "Passkey not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[delete("/api/auth/passkeys/{passkey_id}")]
+pub async fn delete_passkey_handler(
+    user: AuthenticatedUser,
+    path: web::Path<Uuid>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data
+        .passkeys
+        .delete
+        .execute(user.user_id, path.into_inner())
+        .await
+    {
+        Ok(()) => ApiResponse::no_content(),
+        Err(e) => passkey_error(e),
+    }
+}
+
+/// Start signing in with a passkey
+///
+/// Returns the options for `navigator.credentials.get()`, allowing the
+/// account's passkeys. Unknown emails and accounts without passkeys get the
+/// same `NO_PASSKEYS` error.
+#[utoipa::path(
+    post,
+    path = "/api/auth/passkeys/login/start",
+    tag = "auth",
+    request_body = StartPasskeyLoginRequest,
+    responses(
+        (status = 200, description = "Challenge issued", body = inline(SuccessResponse<PasskeyChallengeResponse>)),
+        (status = 400, description = "No passkey for this email (`NO_PASSKEYS`)", body = ErrorResponse),
+        (status = 404, description = "Passkeys are not enabled (`PASSKEYS_DISABLED`)", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    )
+)]
+#[post("/api/auth/passkeys/login/start")]
+pub async fn start_passkey_login_handler(
+    req: web::Json<StartPasskeyLoginRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data
+        .passkeys
+        .start_login
+        .execute(req.into_inner().email)
+        .await
+    {
+        Ok(challenge) => ApiResponse::success(PasskeyChallengeResponse::from(challenge)),
+        Err(e) => passkey_error(e),
+    }
+}
+
+/// Finish signing in with a passkey
+///
+/// Verifies the passkey's signature over the challenge and answers like
+/// `POST /api/auth/login`: the tokens in the body, or as cookies in cookie
+/// mode.
+#[utoipa::path(
+    post,
+    path = "/api/auth/passkeys/login/finish",
+    tag = "auth",
+    request_body = FinishPasskeyLoginRequest,
+    responses(
+        (status = 200, description = "Login successful", body = inline(SuccessResponse<LoginResponse>)),
+        (status = 400, description = "Expired challenge (`PASSKEY_CHALLENGE_EXPIRED`)", body = ErrorResponse),
+        (status = 401, description = "The passkey could not be verified (`INVALID_CREDENTIALS`)", body = ErrorResponse),
+        (status = 403, description = "Account has been deleted or deactivated", body = ErrorResponse),
+        (status = 404, description = "Passkeys are not enabled (`PASSKEYS_DISABLED`)", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    )
+)]
+#[post("/api/auth/passkeys/login/finish")]
+pub async fn finish_passkey_login_handler(
+    req: web::Json<FinishPasskeyLoginRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let req = req.into_inner();
+    let remember_me = req.remember_me;
+    let request = FinishPasskeyLogin {
+        challenge_id: req.challenge_id,
+        credential: req.credential,
+        remember_me,
+    };
+
+    match data.passkeys.finish_login.execute(request).await {
+        Ok(response) => {
+            info!(
+                user_id = %response.user.id,
+                email = %response.user.email,
+                "User logged in with a passkey"
+            );
+            session_response(&data, response, remember_me)
+        }
+        Err(PasskeyError::VerificationFailed(reason)) => {
+            warn!(reason = %reason, "Passkey login failed");
+            INVALID_CREDENTIALS.with_message("The passkey could not be verified")
+        }
+        Err(e) => passkey_error(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+
+    use crate::auth::adapter::incoming::web::cookies::ACCESS_COOKIE;
+    use crate::auth::adapter::outgoing::in_memory::{
+        InMemoryPasskeyChallenges, InMemoryPasskeyStore, InMemoryUserStore,
+    };
+    use crate::auth::adapter::outgoing::webauthn_ceremony::DisabledPasskeys;
+    use crate::auth::application::passkey_use_cases::PasskeyUseCases;
+    use crate::auth::application::ports::outgoing::passkey_repository::NewPasskey;
+    use crate::auth::application::ports::outgoing::user_query::UserQueryResult;
+    use crate::auth::application::ports::outgoing::PasskeyRepository;
+    use crate::auth::application::use_cases::passkeys::test_support::FakeCeremony;
+    use crate::tests::support::app_state_builder::TestAppStateBuilder;
+    use crate::tests::support::auth_helper::test_helpers::{
+        create_test_auth_cookies, create_test_jwt_service,
+    };
+
+    /// Passkey use cases over in-memory stores holding one user with the
+    /// passkey `cred-1`
+    async fn passkeys_with_user() -> PasskeyUseCases {
+        let users = InMemoryUserStore::default();
+        let user_id = Uuid::new_v4();
+        users.users.write(|users| {
+            users.push(UserQueryResult {
+                id: user_id,
+                username: "passkeyuser".to_string(),
+                email: "passkey@example.com".to_string(),
+                password_hash: "hash".to_string(),
+                full_name: "Passkey User".to_string(),
+                preferred_locale: "en".to_string(),
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                is_verified: true,
+                is_deleted: false,
+                token_version: 0,
+                is_active: true,
+            })
+        });
+        let store = InMemoryPasskeyStore::default();
+        store
+            .add(NewPasskey {
+                user_id,
+                name: "Phone".to_string(),
+                credential_id: "cred-1".to_string(),
+                credential: "{}".to_string(),
+            })
+            .await
+            .unwrap();
+
+        PasskeyUseCases::build(
+            Arc::new(users),
+            Arc::new(store),
+            Arc::new(InMemoryPasskeyChallenges::default()),
+            Arc::new(FakeCeremony),
+            Arc::new(create_test_jwt_service()),
+        )
+    }
+
+    async fn login(
+        builder: TestAppStateBuilder,
+        credential_id: &str,
+    ) -> actix_web::dev::ServiceResponse {
+        let app = test::init_service(
+            App::new()
+                .app_data(builder.build())
+                .service(start_passkey_login_handler)
+                .service(finish_passkey_login_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/auth/passkeys/login/start")
+            .set_json(json!({ "email": "passkey@example.com" }))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        let challenge_id = body["data"]["challengeId"].as_str().unwrap().to_string();
+
+        let req = test::TestRequest::post()
+            .uri("/api/auth/passkeys/login/finish")
+            .set_json(json!({
+                "challengeId": challenge_id,
+                "credential": { "id": credential_id },
+            }))
+            .to_request();
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_passkey_login_returns_tokens() {
+        let builder = TestAppStateBuilder::default().with_passkeys(passkeys_with_user().await);
+
+        let resp = login(builder, "cred-1").await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: Value = test::read_body_json(resp).await;
+        assert!(body["data"]["access_token"].is_string());
+        assert_eq!(body["data"]["user"]["email"], "passkey@example.com");
+    }
+
+    #[actix_web::test]
+    async fn test_passkey_login_sets_cookies_in_cookie_mode() {
+        let builder = TestAppStateBuilder::default()
+            .with_passkeys(passkeys_with_user().await)
+            .with_auth_cookies(create_test_auth_cookies());
+
+        let resp = login(builder, "cred-1").await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert!(resp
+            .response()
+            .cookies()
+            .any(|cookie| cookie.name() == ACCESS_COOKIE));
+        let body: Value = test::read_body_json(resp).await;
+        assert!(body["data"]["access_token"].is_null());
+    }
+
+    #[actix_web::test]
+    async fn test_passkey_login_with_unknown_credential_is_unauthorized() {
+        let builder = TestAppStateBuilder::default().with_passkeys(passkeys_with_user().await);
+
+        let resp = login(builder, "someone-elses").await;
+
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "INVALID_CREDENTIALS");
+    }
+
+    #[actix_web::test]
+    async fn test_passkeys_disabled_is_not_found() {
+        let passkeys = PasskeyUseCases::build(
+            Arc::new(InMemoryUserStore::default()),
+            Arc::new(InMemoryPasskeyStore::default()),
+            Arc::new(InMemoryPasskeyChallenges::default()),
+            Arc::new(DisabledPasskeys),
+            Arc::new(create_test_jwt_service()),
+        );
+        let app = test::init_service(
+            App::new()
+                .app_data(
+                    TestAppStateBuilder::default()
+                        .with_passkeys(passkeys)
+                        .build(),
+                )
+                .service(start_passkey_login_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/auth/passkeys/login/start")
+            .set_json(json!({ "email": "passkey@example.com" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "PASSKEYS_DISABLED");
+    }
+}
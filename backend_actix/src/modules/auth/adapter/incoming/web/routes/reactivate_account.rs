@@ -0,0 +1,140 @@
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::auth::adapter::incoming::web::error_codes::{INVALID_CREDENTIALS, USER_DELETED};
+use crate::auth::adapter::incoming::web::routes::AccountStatusResponse;
+use crate::auth::application::use_cases::login_user::LoginRequest;
+use crate::auth::application::use_cases::reactivate_account::ReactivateAccountError;
+use crate::shared::api::error_codes::VALIDATION_ERROR;
+use crate::shared::api::ApiResponse;
+use crate::AppState;
+use actix_web::{post, web, HttpRequest, Responder};
+use serde::Deserialize;
+use tracing::{error, info, warn};
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct ReactivateAccountRequestDto {
+    #[schema(example = "john@example.com")]
+    pub email: String,
+    #[schema(example = "SecurePass123!")]
+    pub password: String,
+}
+
+/// Reactivate a deactivated account
+///
+/// Takes the login credentials, since a deactivated account holds no valid
+/// token. Its content becomes public again; log in afterwards as usual.
+/// Reactivating an active account changes nothing. Deleted accounts can't be
+/// reactivated.
+#[utoipa::path(
+    post,
+    path = "/api/auth/reactivate",
+    tag = "auth",
+    request_body = ReactivateAccountRequestDto,
+    responses(
+        (
+            status = 200,
+            description = "Account is active",
+            body = inline(SuccessResponse<AccountStatusResponse>),
+            example = json!({
+                "success": true,
+                "data": {
+                    "is_active": true
+                }
+            })
+        ),
+        (status = 400, description = "Invalid email or empty password, or bot check token required", body = ErrorResponse),
+        (status = 401, description = "Invalid email or password", body = ErrorResponse),
+        (status = 403, description = "Account has been deleted, or the bot check failed", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+#[post("/api/auth/reactivate")]
+pub async fn reactivate_account_handler(
+    http_req: HttpRequest,
+    req: web::Json<ReactivateAccountRequestDto>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let dto = req.into_inner();
+    let email = dto.email.clone();
+
+    // Checks a password, so it gets the same guard as login
+    if let Err(e) = data.bot_gate.check_login(&email, &http_req).await {
+        warn!(error = %e, "Reactivation blocked by bot check");
+        return e.to_response();
+    }
+
+    let request = match LoginRequest::new(dto.email, dto.password) {
+        Ok(request) => request,
+        Err(e) => return VALIDATION_ERROR.with_message(&e.to_string()),
+    };
+
+    match data.auth.reactivate_account.execute(request).await {
+        Ok(()) => {
+            data.bot_gate.clear_login_failures(&email);
+            info!("Account reactivated");
+            ApiResponse::success(AccountStatusResponse::new(true))
+        }
+
+        Err(ReactivateAccountError::InvalidCredentials) => {
+            data.bot_gate.record_login_failure(&email);
+            INVALID_CREDENTIALS.response()
+        }
+
+        Err(ReactivateAccountError::UserDeleted) => USER_DELETED.response(),
+
+        Err(e) => {
+            error!(error = %e, "Account reactivation failed");
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::json;
+
+    use crate::tests::support::app_state_builder::TestAppStateBuilder;
+    use crate::tests::support::stubs::StubReactivateAccountUseCase;
+
+    async fn call(state: web::Data<AppState>) -> actix_web::dev::ServiceResponse {
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .service(reactivate_account_handler),
+        )
+        .await;
+        let req = test::TestRequest::post()
+            .uri("/api/auth/reactivate")
+            .set_json(json!({ "email": "john@example.com", "password": "SecurePass123!" }))
+            .to_request();
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_reactivate_account_success() {
+        let resp = call(TestAppStateBuilder::default().build()).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["is_active"], true);
+    }
+
+    #[actix_web::test]
+    async fn test_reactivate_account_errors() {
+        let state = TestAppStateBuilder::default()
+            .with_reactivate_account(StubReactivateAccountUseCase::failing(
+                ReactivateAccountError::InvalidCredentials,
+            ))
+            .build();
+        assert_eq!(call(state).await.status(), StatusCode::UNAUTHORIZED);
+
+        let state = TestAppStateBuilder::default()
+            .with_reactivate_account(StubReactivateAccountUseCase::failing(
+                ReactivateAccountError::UserDeleted,
+            ))
+            .build();
+        assert_eq!(call(state).await.status(), StatusCode::FORBIDDEN);
+    }
+}
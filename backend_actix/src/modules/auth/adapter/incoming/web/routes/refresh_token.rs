@@ -1,26 +1,34 @@
 use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::auth::adapter::incoming::web::error_codes::{
+    INVALID_TOKEN_TYPE, TOKEN_EXPIRED, TOKEN_INVALID, TOKEN_NOT_YET_VALID, TOKEN_REVOKED,
+};
 use crate::auth::application::use_cases::refresh_token::{RefreshTokenError, RefreshTokenRequest};
+use crate::shared::api::error_codes::VALIDATION_ERROR;
 use crate::shared::api::ApiResponse;
 use crate::AppState;
-use actix_web::{post, web, Responder};
+use actix_web::{http::StatusCode, post, web, HttpRequest, Responder};
 use serde::{Deserialize, Serialize};
 use tracing::{error, info, warn};
 use utoipa::ToSchema;
 
 #[derive(Serialize, Deserialize, ToSchema)]
 pub struct RefreshTokenResponseBody {
-    /// New JWT access token
+    /// New JWT access token; absent in cookie mode, where it is set as a cookie
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[schema(example = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9...")]
-    access_token: String,
+    access_token: Option<String>,
 
-    /// New JWT refresh token
+    /// New JWT refresh token; absent in cookie mode, where it is set as a cookie
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[schema(example = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9...")]
-    refresh_token: String,
+    refresh_token: Option<String>,
 }
 
 #[derive(Deserialize, ToSchema)]
 pub struct RefreshTokenRequestDto {
-    /// Refresh token to exchange for new tokens
+    /// Refresh token to exchange for new tokens. In cookie mode it may be
+    /// left out (send `{}`) to use the `refresh_token` cookie.
+    #[serde(default)]
     #[schema(example = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9...")]
     pub refresh_token: String,
 }
@@ -70,7 +78,7 @@ pub struct RefreshTokenRequestDto {
         ),
         (
             status = 401,
-            description = "Unauthorized - token expired or invalid",
+            description = "Unauthorized - token expired, invalid or revoked",
             body = ErrorResponse,
             examples(
                 ("Token expired" = (value = json!({
@@ -86,6 +94,13 @@ pub struct RefreshTokenRequestDto {
                         "code": "TOKEN_INVALID",
                         "message": "Invalid refresh token"
                     }
+                }))),
+                ("Token revoked" = (value = json!({
+                    "success": false,
+                    "error": {
+                        "code": "TOKEN_REVOKED",
+                        "message": "Refresh token has been revoked. Please login again."
+                    }
                 })))
             )
         ),
@@ -105,17 +120,27 @@ pub struct RefreshTokenRequestDto {
 )]
 #[post("/api/auth/refresh")]
 pub async fn refresh_token_handler(
+    http_req: HttpRequest,
     req: web::Json<RefreshTokenRequestDto>,
     data: web::Data<AppState>,
 ) -> impl Responder {
-    let use_case = &data.refresh_token_use_case;
-    let dto = req.into_inner();
+    let use_case = &data.auth.refresh_token;
+    let mut refresh_token = req.into_inner().refresh_token;
+
+    if refresh_token.trim().is_empty() {
+        if let Some(cookie) = data
+            .auth_cookies
+            .as_ref()
+            .and_then(|cookies| cookies.refresh_token(&http_req))
+        {
+            refresh_token = cookie;
+        }
+    }
 
-    info!("Token refresh attempt");
-    let request = match RefreshTokenRequest::new(dto.refresh_token) {
+    let request = match RefreshTokenRequest::new(refresh_token) {
         Ok(req) => req,
         Err(e) => {
-            return ApiResponse::bad_request("VALIDATION_ERROR", &e.to_string());
+            return VALIDATION_ERROR.with_message(&e.to_string());
         }
     };
 
@@ -124,55 +149,81 @@ pub async fn refresh_token_handler(
     match result {
         Ok(response) => {
             info!("Token refreshed successfully");
-            ApiResponse::success(RefreshTokenResponseBody {
-                access_token: response.access_token,
-                refresh_token: response.refresh_token,
-            })
+            match &data.auth_cookies {
+                Some(cookies) => {
+                    let mut resp = ApiResponse::success(RefreshTokenResponseBody {
+                        access_token: None,
+                        refresh_token: None,
+                    });
+                    cookies.set_session(
+                        &mut resp,
+                        &response.access_token,
+                        &response.refresh_token,
+                        response.remember_me,
+                    );
+                    resp
+                }
+                None => ApiResponse::success(RefreshTokenResponseBody {
+                    access_token: Some(response.access_token),
+                    refresh_token: Some(response.refresh_token),
+                }),
+            }
         }
 
         Err(RefreshTokenError::TokenExpired) => {
             warn!("Token refresh failed: Token expired");
-            ApiResponse::unauthorized(
-                "TOKEN_EXPIRED",
-                "Refresh token has expired. Please login again.",
-            )
+            TOKEN_EXPIRED.with_message("Refresh token has expired. Please login again.")
         }
 
         Err(RefreshTokenError::TokenInvalid) | Err(RefreshTokenError::InvalidSignature) => {
             warn!("Token refresh failed: Invalid token");
-            ApiResponse::unauthorized("TOKEN_INVALID", "Invalid refresh token")
+            TOKEN_INVALID.with_message("Invalid refresh token")
         }
 
         Err(RefreshTokenError::InvalidTokenType) => {
             warn!("Token refresh failed: Wrong token type");
-            ApiResponse::bad_request(
-                "INVALID_TOKEN_TYPE",
+            INVALID_TOKEN_TYPE.with_status(
+                StatusCode::BAD_REQUEST,
                 "Invalid token type. Please use a refresh token.",
             )
         }
 
         Err(RefreshTokenError::TokenNotYetValid) => {
             warn!("Token refresh failed: Token not yet valid");
-            ApiResponse::bad_request("TOKEN_NOT_YET_VALID", "Token is not yet valid")
+            TOKEN_NOT_YET_VALID.response()
+        }
+
+        Err(RefreshTokenError::TokenRevoked) => {
+            warn!("Token refresh failed: Token revoked");
+            TOKEN_REVOKED.with_message("Refresh token has been revoked. Please login again.")
         }
 
         Err(RefreshTokenError::TokenGenerationFailed(ref e)) => {
             error!(error = %e, "Token generation failed during refresh");
             ApiResponse::internal_error()
         }
+
+        Err(RefreshTokenError::RevocationCheckFailed(ref e)) => {
+            error!(error = %e, "Revocation check failed during refresh");
+            ApiResponse::internal_error()
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::auth::adapter::incoming::web::cookies::{
+        ACCESS_COOKIE, CSRF_COOKIE, REFRESH_COOKIE,
+    };
     use crate::auth::application::use_cases::refresh_token::{
         IRefreshTokenUseCase, RefreshTokenError, RefreshTokenRequest, RefreshTokenResponse,
     };
     use crate::shared::api::custom_json_config;
     use crate::tests::support::app_state_builder::TestAppStateBuilder;
+    use crate::tests::support::auth_helper::test_helpers::create_test_auth_cookies;
     use crate::tests::support::load_test_env;
-    use actix_web::{test, App};
+    use actix_web::{cookie::Cookie, test, App};
     use async_trait::async_trait;
 
     // ========================================================================
@@ -193,6 +244,7 @@ mod tests {
                     .unwrap_or_else(|_| "FAKE_TEST_ACCESS_TOKEN_DO_NOT_USE".to_string()),
                 refresh_token: std::env::var("TEST_REFRESH_TOKEN")
                     .unwrap_or_else(|_| "FAKE_TEST_REFRESH_TOKEN_DO_NOT_USE".to_string()),
+                remember_me: false,
             })
         }
     }
@@ -655,4 +707,41 @@ mod tests {
             assert!(body.get("error").is_none());
         }
     }
+
+    #[actix_web::test]
+    async fn test_refresh_token_in_cookie_mode_reads_and_sets_cookies() {
+        let app_state = TestAppStateBuilder::default()
+            .with_refresh_token(MockRefreshTokenSuccess)
+            .with_auth_cookies(create_test_auth_cookies())
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .service(refresh_token_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/auth/refresh")
+            .cookie(Cookie::new(REFRESH_COOKIE, "refresh-from-cookie"))
+            .set_json(serde_json::json!({}))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+
+        let cookies: Vec<String> = resp
+            .response()
+            .cookies()
+            .map(|cookie| cookie.name().to_string())
+            .collect();
+        assert!(cookies.contains(&ACCESS_COOKIE.to_string()));
+        assert!(cookies.contains(&REFRESH_COOKIE.to_string()));
+        assert!(cookies.contains(&CSRF_COOKIE.to_string()));
+
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert!(body["data"].get("access_token").is_none());
+        assert!(body["data"].get("refresh_token").is_none());
+    }
 }
@@ -1,10 +1,12 @@
 use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::auth::adapter::incoming::web::error_codes::USER_ALREADY_EXISTS;
 use crate::auth::application::orchestrator::user_registration::UserRegistrationError;
 use crate::auth::application::use_cases::create_user::CreateUserInput;
 use crate::modules::auth::application::use_cases::create_user::CreateUserError;
 use crate::shared::api::ApiResponse;
+use crate::shared::bot_check::BotCheckedEndpoint;
 use crate::AppState;
-use actix_web::{post, web, HttpResponse, Responder};
+use actix_web::{post, web, HttpRequest, HttpResponse, Responder};
 use serde::{Deserialize, Serialize};
 use tracing::{error, info, warn};
 use utoipa::ToSchema;
@@ -27,6 +29,10 @@ pub struct CreateUserRequest {
     /// Full name of the user
     #[schema(example = "John Doe")]
     pub full_name: String,
+
+    /// Locale for emails, `ll` or `ll-RR`; defaults to `en`
+    #[schema(example = "en")]
+    pub preferred_locale: Option<String>,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -62,44 +68,14 @@ pub struct RegisteredUser {
 
 fn map_create_user_error(err: CreateUserError, req: &CreateUserRequest) -> HttpResponse {
     match &err {
-        CreateUserError::InvalidUsername(msg) => {
+        CreateUserError::Invalid(errors) => {
             warn!(
                 username = %req.username,
                 email = %req.email,
                 error = %err,
                 "Invalid registration input"
             );
-            ApiResponse::bad_request("INVALID_USERNAME", &msg)
-        }
-
-        CreateUserError::InvalidEmail(msg) => {
-            warn!(
-                username = %req.username,
-                email = %req.email,
-                error = %err,
-                "Invalid registration input"
-            );
-            ApiResponse::bad_request("INVALID_EMAIL", &msg)
-        }
-
-        CreateUserError::InvalidPassword(msg) => {
-            warn!(
-                username = %req.username,
-                email = %req.email,
-                error = %err,
-                "Invalid registration input"
-            );
-            ApiResponse::bad_request("INVALID_PASSWORD", &msg)
-        }
-
-        CreateUserError::InvalidFullName(msg) => {
-            warn!(
-                username = %req.username,
-                email = %req.email,
-                error = %err,
-                "Invalid registration input"
-            );
-            ApiResponse::bad_request("INVALID_FULL_NAME", &msg)
+            ApiResponse::validation_failed(errors)
         }
 
         CreateUserError::UserAlreadyExists => {
@@ -108,7 +84,7 @@ fn map_create_user_error(err: CreateUserError, req: &CreateUserRequest) -> HttpR
                 email = %req.email,
                 "User already exists"
             );
-            ApiResponse::conflict("USER_ALREADY_EXISTS", "User already exists")
+            USER_ALREADY_EXISTS.response()
         }
 
         other => {
@@ -132,6 +108,9 @@ fn map_create_user_error(err: CreateUserError, req: &CreateUserRequest) -> HttpR
     path = "/api/auth/register",
     tag = "auth",
     request_body = CreateUserRequest,
+    params(
+        ("X-Bot-Check-Token" = Option<String>, Header, description = "CAPTCHA token; required when the bot check is enabled for registration")
+    ),
     responses(
         (
             status = 201,
@@ -182,9 +161,47 @@ fn map_create_user_error(err: CreateUserError, req: &CreateUserRequest) -> HttpR
                         "code": "INVALID_FULL_NAME",
                         "message": "Full name is required"
                     }
+                }))),
+                ("Several invalid fields" = (value = json!({
+                    "success": false,
+                    "error": {
+                        "code": "VALIDATION_ERROR",
+                        "message": "2 fields are invalid",
+                        "errors": [
+                            {
+                                "field": "email",
+                                "code": "INVALID_EMAIL",
+                                "message": "Invalid email format"
+                            },
+                            {
+                                "field": "password",
+                                "code": "INVALID_PASSWORD",
+                                "message": "Password must be at least 12 characters"
+                            }
+                        ]
+                    }
+                }))),
+                ("Bot check required" = (value = json!({
+                    "success": false,
+                    "error": {
+                        "code": "BOT_CHECK_REQUIRED",
+                        "message": "Complete the bot check and send its token in X-Bot-Check-Token"
+                    }
                 })))
             )
         ),
+        (
+            status = 403,
+            description = "Bot check failed",
+            body = ErrorResponse,
+            example = json!({
+                "success": false,
+                "error": {
+                    "code": "BOT_CHECK_FAILED",
+                    "message": "Bot check failed, please retry"
+                }
+            })
+        ),
         (
             status = 409,
             description = "User already exists",
@@ -213,22 +230,27 @@ fn map_create_user_error(err: CreateUserError, req: &CreateUserRequest) -> HttpR
 )]
 #[post("/api/auth/register")]
 pub async fn register_user_handler(
+    http_req: HttpRequest,
     req: web::Json<CreateUserRequest>,
     data: web::Data<AppState>,
 ) -> impl Responder {
-    let orchestrator = &data.register_user_orchestrator;
-
-    info!(
-        username = %req.username,
-        email = %req.email,
-        "User registration attempt"
-    );
+    let orchestrator = &data.auth.register;
+
+    if let Err(e) = data
+        .bot_gate
+        .check(BotCheckedEndpoint::Register, &http_req)
+        .await
+    {
+        warn!(error = %e, "Registration blocked by bot check");
+        return e.to_response();
+    }
 
     let user_input = CreateUserInput {
         username: req.username.clone(),
         email: req.email.clone(),
         password: req.password.clone(),
         full_name: req.full_name.clone(),
+        preferred_locale: req.preferred_locale.clone(),
     };
     let result = orchestrator.register_user(user_input).await;
 
@@ -280,9 +302,8 @@ mod tests {
     use crate::auth::application::use_cases::create_user::{
         CreateUserError, CreateUserInput, CreateUserOutput, ICreateUserUseCase,
     };
-    use crate::email::application::ports::outgoing::user_email_notifier::{
-        UserEmailNotificationError, UserEmailNotifier,
-    };
+    use crate::shared::bot_check::{BotGate, NoopBotCheck, BOT_CHECK_HEADER};
+    use crate::shared::validation::ValidationErrors;
     use crate::tests::support::app_state_builder::TestAppStateBuilder;
     use actix_web::{test, App};
     use async_trait::async_trait;
@@ -306,6 +327,7 @@ mod tests {
                 username: input.username,
                 email: input.email,
                 full_name: input.full_name,
+                preferred_locale: "en".to_string(),
             })
         }
     }
@@ -316,9 +338,11 @@ mod tests {
     #[async_trait]
     impl ICreateUserUseCase for MockCreateUserInvalidUsername {
         async fn execute(&self, _: CreateUserInput) -> Result<CreateUserOutput, CreateUserError> {
-            Err(CreateUserError::InvalidUsername(
-                "Username must be 3-20 characters".to_string(),
-            ))
+            Err(CreateUserError::Invalid(ValidationErrors::single(
+                "username",
+                "INVALID_USERNAME",
+                "Username must be 3-20 characters",
+            )))
         }
     }
 
@@ -328,9 +352,11 @@ mod tests {
     #[async_trait]
     impl ICreateUserUseCase for MockCreateUserInvalidEmail {
         async fn execute(&self, _: CreateUserInput) -> Result<CreateUserOutput, CreateUserError> {
-            Err(CreateUserError::InvalidEmail(
-                "Invalid email format".to_string(),
-            ))
+            Err(CreateUserError::Invalid(ValidationErrors::single(
+                "email",
+                "INVALID_EMAIL",
+                "Invalid email format",
+            )))
         }
     }
 
@@ -340,9 +366,11 @@ mod tests {
     #[async_trait]
     impl ICreateUserUseCase for MockCreateUserInvalidPassword {
         async fn execute(&self, _: CreateUserInput) -> Result<CreateUserOutput, CreateUserError> {
-            Err(CreateUserError::InvalidPassword(
-                "Password must be at least 8 characters".to_string(),
-            ))
+            Err(CreateUserError::Invalid(ValidationErrors::single(
+                "password",
+                "INVALID_PASSWORD",
+                "Password must be at least 8 characters",
+            )))
         }
     }
 
@@ -352,9 +380,28 @@ mod tests {
     #[async_trait]
     impl ICreateUserUseCase for MockCreateUserInvalidFullName {
         async fn execute(&self, _: CreateUserInput) -> Result<CreateUserOutput, CreateUserError> {
-            Err(CreateUserError::InvalidFullName(
-                "Full name cannot be empty".to_string(),
-            ))
+            Err(CreateUserError::Invalid(ValidationErrors::single(
+                "full_name",
+                "INVALID_FULL_NAME",
+                "Full name cannot be empty",
+            )))
+        }
+    }
+
+    #[derive(Clone)]
+    struct MockCreateUserInvalidFields;
+
+    #[async_trait]
+    impl ICreateUserUseCase for MockCreateUserInvalidFields {
+        async fn execute(&self, _: CreateUserInput) -> Result<CreateUserOutput, CreateUserError> {
+            let mut errors = ValidationErrors::new();
+            errors.add("email", "INVALID_EMAIL", "Invalid email format");
+            errors.add(
+                "password",
+                "INVALID_PASSWORD",
+                "Password must be at least 12 characters",
+            );
+            Err(CreateUserError::Invalid(errors))
         }
     }
 
@@ -404,34 +451,6 @@ mod tests {
         }
     }
 
-    #[derive(Clone)]
-    struct MockEmailNotifierSuccess;
-
-    #[async_trait]
-    impl UserEmailNotifier for MockEmailNotifierSuccess {
-        async fn send_verification_email(
-            &self,
-            _: CreateUserOutput,
-        ) -> Result<(), UserEmailNotificationError> {
-            Ok(())
-        }
-    }
-
-    #[derive(Clone)]
-    struct MockEmailNotifierFailure;
-
-    #[async_trait]
-    impl UserEmailNotifier for MockEmailNotifierFailure {
-        async fn send_verification_email(
-            &self,
-            _: CreateUserOutput,
-        ) -> Result<(), UserEmailNotificationError> {
-            Err(UserEmailNotificationError::EmailSendingFailed(
-                "SMTP connection failed".to_string(),
-            ))
-        }
-    }
-
     // ========================================================================
     // Helper Functions
     // ========================================================================
@@ -442,17 +461,14 @@ mod tests {
             email: "test@example.com".to_string(),
             password: "SecurePass123!".to_string(),
             full_name: "Test User".to_string(),
+            preferred_locale: None,
         }
     }
 
     fn create_orchestrator(
         create_user: impl ICreateUserUseCase + Send + Sync + 'static,
-        email_notifier: impl UserEmailNotifier + Send + Sync + 'static,
     ) -> Arc<UserRegistrationOrchestrator> {
-        Arc::new(UserRegistrationOrchestrator::new(
-            Arc::new(create_user),
-            Arc::new(email_notifier),
-        ))
+        Arc::new(UserRegistrationOrchestrator::new(Arc::new(create_user)))
     }
 
     // ========================================================================
@@ -461,7 +477,7 @@ mod tests {
 
     #[actix_web::test]
     async fn test_register_user_success() {
-        let orchestrator = create_orchestrator(MockCreateUserSuccess, MockEmailNotifierSuccess);
+        let orchestrator = create_orchestrator(MockCreateUserSuccess);
 
         let app_state = TestAppStateBuilder::default()
             .with_register_user_orchestrator(orchestrator)
@@ -497,8 +513,7 @@ mod tests {
 
     #[actix_web::test]
     async fn test_register_user_invalid_username() {
-        let orchestrator =
-            create_orchestrator(MockCreateUserInvalidUsername, MockEmailNotifierSuccess);
+        let orchestrator = create_orchestrator(MockCreateUserInvalidUsername);
 
         let app_state = TestAppStateBuilder::default()
             .with_register_user_orchestrator(orchestrator)
@@ -531,8 +546,7 @@ mod tests {
 
     #[actix_web::test]
     async fn test_register_user_invalid_email() {
-        let orchestrator =
-            create_orchestrator(MockCreateUserInvalidEmail, MockEmailNotifierSuccess);
+        let orchestrator = create_orchestrator(MockCreateUserInvalidEmail);
 
         let app_state = TestAppStateBuilder::default()
             .with_register_user_orchestrator(orchestrator)
@@ -562,8 +576,7 @@ mod tests {
 
     #[actix_web::test]
     async fn test_register_user_invalid_password() {
-        let orchestrator =
-            create_orchestrator(MockCreateUserInvalidPassword, MockEmailNotifierSuccess);
+        let orchestrator = create_orchestrator(MockCreateUserInvalidPassword);
 
         let app_state = TestAppStateBuilder::default()
             .with_register_user_orchestrator(orchestrator)
@@ -596,8 +609,7 @@ mod tests {
 
     #[actix_web::test]
     async fn test_register_user_invalid_full_name() {
-        let orchestrator =
-            create_orchestrator(MockCreateUserInvalidFullName, MockEmailNotifierSuccess);
+        let orchestrator = create_orchestrator(MockCreateUserInvalidFullName);
 
         let app_state = TestAppStateBuilder::default()
             .with_register_user_orchestrator(orchestrator)
@@ -625,10 +637,40 @@ mod tests {
         assert!(body.get("data").is_none());
     }
 
+    #[actix_web::test]
+    async fn test_register_user_lists_every_invalid_field() {
+        let orchestrator = create_orchestrator(MockCreateUserInvalidFields);
+
+        let app_state = TestAppStateBuilder::default()
+            .with_register_user_orchestrator(orchestrator)
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .service(register_user_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/auth/register")
+            .set_json(&create_test_request())
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 400);
+
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "VALIDATION_ERROR");
+        assert_eq!(body["error"]["message"], "2 fields are invalid");
+        assert_eq!(body["error"]["errors"][0]["field"], "email");
+        assert_eq!(body["error"]["errors"][1]["field"], "password");
+        assert_eq!(body["error"]["errors"][1]["code"], "INVALID_PASSWORD");
+    }
+
     #[actix_web::test]
     async fn test_register_user_already_exists() {
-        let orchestrator =
-            create_orchestrator(MockCreateUserAlreadyExists, MockEmailNotifierSuccess);
+        let orchestrator = create_orchestrator(MockCreateUserAlreadyExists);
 
         let app_state = TestAppStateBuilder::default()
             .with_register_user_orchestrator(orchestrator)
@@ -658,8 +700,7 @@ mod tests {
 
     #[actix_web::test]
     async fn test_register_user_hashing_failed() {
-        let orchestrator =
-            create_orchestrator(MockCreateUserHashingFailed, MockEmailNotifierSuccess);
+        let orchestrator = create_orchestrator(MockCreateUserHashingFailed);
 
         let app_state = TestAppStateBuilder::default()
             .with_register_user_orchestrator(orchestrator)
@@ -689,8 +730,7 @@ mod tests {
 
     #[actix_web::test]
     async fn test_register_user_repository_error() {
-        let orchestrator =
-            create_orchestrator(MockCreateUserRepositoryError, MockEmailNotifierSuccess);
+        let orchestrator = create_orchestrator(MockCreateUserRepositoryError);
 
         let app_state = TestAppStateBuilder::default()
             .with_register_user_orchestrator(orchestrator)
@@ -720,7 +760,7 @@ mod tests {
 
     #[actix_web::test]
     async fn test_register_user_query_error() {
-        let orchestrator = create_orchestrator(MockCreateUserQueryError, MockEmailNotifierSuccess);
+        let orchestrator = create_orchestrator(MockCreateUserQueryError);
 
         let app_state = TestAppStateBuilder::default()
             .with_register_user_orchestrator(orchestrator)
@@ -749,11 +789,14 @@ mod tests {
     }
 
     #[actix_web::test]
-    async fn test_register_user_succeeds_even_when_email_fails() {
-        let orchestrator = create_orchestrator(MockCreateUserSuccess, MockEmailNotifierFailure);
-
+    async fn test_register_user_requires_bot_check_token_when_enabled() {
         let app_state = TestAppStateBuilder::default()
-            .with_register_user_orchestrator(orchestrator)
+            .with_register_user_orchestrator(create_orchestrator(MockCreateUserSuccess))
+            .with_bot_gate(BotGate::new(
+                Arc::new(NoopBotCheck),
+                vec![BotCheckedEndpoint::Register],
+                0,
+            ))
             .build();
 
         let app = test::init_service(
@@ -767,15 +810,17 @@ mod tests {
             .uri("/api/auth/register")
             .set_json(&create_test_request())
             .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 400);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "BOT_CHECK_REQUIRED");
 
+        let req = test::TestRequest::post()
+            .uri("/api/auth/register")
+            .insert_header((BOT_CHECK_HEADER, "solved"))
+            .set_json(&create_test_request())
+            .to_request();
         let resp = test::call_service(&app, req).await;
         assert_eq!(resp.status(), 201);
-
-        let body: serde_json::Value = test::read_body_json(resp).await;
-        assert_eq!(body["success"], true);
-        assert!(body["data"]["message"]
-            .as_str()
-            .unwrap()
-            .contains("check your email"));
     }
 }
@@ -1,4 +1,6 @@
 use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::auth::adapter::incoming::web::error_codes::INVALID_FULL_NAME;
+use crate::shared::api::error_codes::INVALID_LOCALE;
 use crate::{
     auth::{
         adapter::incoming::web::extractors::auth::AuthenticatedUser,
@@ -20,6 +22,10 @@ pub struct UpdateUserRequest {
     /// New full name for the user
     #[schema(example = "John Smith")]
     full_name: String,
+
+    /// Locale for emails, `ll` or `ll-RR`; unchanged when omitted
+    #[schema(example = "id")]
+    preferred_locale: Option<String>,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -39,11 +45,16 @@ pub struct UpdateUserResponse {
     /// Updated full name
     #[schema(example = "John Smith")]
     full_name: String,
+
+    /// Locale emails are sent in
+    #[schema(example = "id")]
+    preferred_locale: String,
 }
 
 /// Update current user profile
 ///
-/// Updates the authenticated user's profile information. Currently only full name can be updated.
+/// Updates the authenticated user's full name and, optionally, the locale their emails are sent in.
+/// Emails fall back to English when no template exists for the locale.
 #[utoipa::path(
     put,
     path = "/api/users/me",
@@ -60,13 +71,14 @@ pub struct UpdateUserResponse {
                     "userId": "123e4567-e89b-12d3-a456-426614174000",
                     "email": "john@example.com",
                     "username": "johndoe",
-                    "fullName": "John Smith"
+                    "fullName": "John Smith",
+                    "preferredLocale": "id"
                 }
             })
         ),
         (
             status = 400,
-            description = "Invalid full name",
+            description = "Invalid full name or locale",
             body = ErrorResponse,
             example = json!({
                 "success": false,
@@ -111,21 +123,23 @@ pub async fn update_user_profile_handler(
     req: web::Json<UpdateUserRequest>,
     app_data: web::Data<AppState>,
 ) -> impl Responder {
+    let req = req.into_inner();
     let input = UpdateUserInput {
         user_id: UserId::from(user.user_id),
-        full_name: req.into_inner().full_name,
+        full_name: req.full_name,
+        preferred_locale: req.preferred_locale,
     };
 
-    match app_data.update_user_profile_use_case.execute(input).await {
+    match app_data.auth.update_profile.execute(input).await {
         Ok(output) => ApiResponse::success(UpdateUserResponse {
             user_id: output.user_id.value().to_string(),
             email: output.email,
             username: output.username,
             full_name: output.full_name,
+            preferred_locale: output.preferred_locale,
         }),
-        Err(UpdateUserError::InvalidFullName(msg)) => {
-            ApiResponse::bad_request("INVALID_FULL_NAME", &msg)
-        }
+        Err(UpdateUserError::InvalidFullName(msg)) => INVALID_FULL_NAME.with_message(&msg),
+        Err(UpdateUserError::InvalidLocale(msg)) => INVALID_LOCALE.with_message(&msg),
         Err(UpdateUserError::RepositoryError(e)) => {
             error!("Repository error updating user profile: {}", e);
             ApiResponse::internal_error()
@@ -182,6 +196,7 @@ mod tests {
             &self,
             _user_id: Uuid,
             _is_verified: bool,
+            _token_version: i32,
         ) -> Result<String, TokenError> {
             unimplemented!()
         }
@@ -190,6 +205,8 @@ mod tests {
             &self,
             _user_id: Uuid,
             _is_verified: bool,
+            _token_version: i32,
+            _remember_me: bool,
         ) -> Result<String, TokenError> {
             unimplemented!()
         }
@@ -202,6 +219,11 @@ mod tests {
                 exp: 9999999999,
                 iat: 0,
                 nbf: 0,
+                token_version: 0,
+                remember_me: false,
+                iss: String::new(),
+                aud: String::new(),
+                jti: None,
             })
         }
 
@@ -213,7 +235,7 @@ mod tests {
             unimplemented!()
         }
 
-        fn verify_verification_token(&self, _token: &str) -> Result<Uuid, TokenError> {
+        fn verify_verification_token(&self, _token: &str) -> Result<TokenClaims, TokenError> {
             unimplemented!()
         }
     }
@@ -224,6 +246,7 @@ mod tests {
             email: "test@example.com".to_string(),
             username: "testuser".to_string(),
             full_name: full_name.to_string(),
+            preferred_locale: "en".to_string(),
         }
     }
 
@@ -279,6 +302,7 @@ mod tests {
         assert_eq!(body["data"]["email"], "test@example.com");
         assert_eq!(body["data"]["username"], "testuser");
         assert_eq!(body["data"]["full_name"], new_full_name);
+        assert_eq!(body["data"]["preferred_locale"], "en");
         assert!(body.get("error").is_none());
     }
 
@@ -329,6 +353,47 @@ mod tests {
         assert!(body.get("data").is_none());
     }
 
+    #[actix_web::test]
+    async fn test_update_user_profile_invalid_locale() {
+        let user_id = Uuid::new_v4();
+
+        let mock_use_case = MockUpdateUserProfileUseCase {
+            result: Err(UpdateUserError::InvalidLocale(
+                "Locale must look like 'en' or 'pt-BR'".to_string(),
+            )),
+        };
+
+        let app_state = TestAppStateBuilder::default()
+            .with_update_user_profile(mock_use_case)
+            .build();
+
+        let token_provider = create_token_provider(user_id, true);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .app_data(token_provider)
+                .service(update_user_profile_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::put()
+            .uri("/api/users/me")
+            .insert_header(("Authorization", "Bearer test_token"))
+            .set_json(serde_json::json!({
+                "full_name": "Valid Name",
+                "preferred_locale": "english"
+            }))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), 400);
+
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "INVALID_LOCALE");
+    }
+
     #[actix_web::test]
     async fn test_update_user_profile_repository_error() {
         let user_id = Uuid::new_v4();
@@ -1,8 +1,11 @@
 use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::auth::adapter::incoming::web::error_codes::{
+    TOKEN_ALREADY_USED, TOKEN_EXPIRED, TOKEN_INVALID, USER_NOT_FOUND,
+};
 use crate::auth::application::use_cases::verify_user_email::VerifyUserEmailError;
 use crate::shared::api::ApiResponse;
 use crate::AppState;
-use actix_web::{web, HttpRequest, Responder};
+use actix_web::{http::StatusCode, web, HttpRequest, Responder};
 use serde::Serialize;
 use tracing::error;
 use utoipa::ToSchema;
@@ -39,7 +42,7 @@ pub struct VerifyEmailResponse {
         ),
         (
             status = 400,
-            description = "Invalid or expired token",
+            description = "Invalid, expired or already used token",
             body = ErrorResponse,
             examples(
                 ("Token expired" = (value = json!({
@@ -55,6 +58,13 @@ pub struct VerifyEmailResponse {
                         "code": "TOKEN_INVALID",
                         "message": "Invalid token"
                     }
+                }))),
+                ("Token already used" = (value = json!({
+                    "success": false,
+                    "error": {
+                        "code": "TOKEN_ALREADY_USED",
+                        "message": "This verification link has already been used"
+                    }
                 })))
             )
         ),
@@ -91,21 +101,20 @@ pub async fn verify_user_email_handler(
 ) -> impl Responder {
     let token = req.match_info().get("token").unwrap();
 
-    let use_case = &data.verify_user_email_use_case;
+    let use_case = &data.auth.verify_email;
 
     match use_case.execute(token).await {
         Ok(()) => ApiResponse::success(VerifyEmailResponse {
             message: "Email verified successfully".to_string(),
         }),
         Err(VerifyUserEmailError::TokenExpired) => {
-            ApiResponse::bad_request("TOKEN_EXPIRED", "Token has expired")
+            TOKEN_EXPIRED.with_status(StatusCode::BAD_REQUEST, "Token has expired")
         }
         Err(VerifyUserEmailError::TokenInvalid) => {
-            ApiResponse::bad_request("TOKEN_INVALID", "Invalid token")
-        }
-        Err(VerifyUserEmailError::UserNotFound) => {
-            ApiResponse::not_found("USER_NOT_FOUND", "User not found")
+            TOKEN_INVALID.with_status(StatusCode::BAD_REQUEST, "Invalid token")
         }
+        Err(VerifyUserEmailError::TokenAlreadyUsed) => TOKEN_ALREADY_USED.response(),
+        Err(VerifyUserEmailError::UserNotFound) => USER_NOT_FOUND.response(),
         Err(VerifyUserEmailError::DatabaseError) => {
             error!("Database error during email verification");
             ApiResponse::internal_error()
@@ -147,6 +156,16 @@ mod tests {
         }
     }
 
+    #[derive(Clone)]
+    struct MockVerifyUserEmailTokenAlreadyUsed;
+
+    #[async_trait]
+    impl IVerifyUserEmailUseCase for MockVerifyUserEmailTokenAlreadyUsed {
+        async fn execute(&self, _token: &str) -> Result<(), VerifyUserEmailError> {
+            Err(VerifyUserEmailError::TokenAlreadyUsed)
+        }
+    }
+
     #[derive(Clone)]
     struct MockVerifyUserEmailTokenInvalid;
 
@@ -234,6 +253,30 @@ mod tests {
         assert!(body.get("data").is_none());
     }
 
+    #[actix_web::test]
+    async fn test_verify_user_email_token_already_used() {
+        let app_state = TestAppStateBuilder::default()
+            .with_verify_user_email(MockVerifyUserEmailTokenAlreadyUsed)
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .service(verify_user_email_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/api/auth/email-verification/used-token-12345")
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 400);
+
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "TOKEN_ALREADY_USED");
+    }
+
     #[actix_web::test]
     async fn test_verify_user_email_token_invalid() {
         let app_state = TestAppStateBuilder::default()
@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{ConnectionTrait, DatabaseConnection, Statement};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::application::ports::outgoing::token_repository::TokenRepositoryError;
+use crate::auth::application::ports::outgoing::ConsumedTokenRepository;
+use crate::shared::sql;
+use crate::shared::unit_of_work::connection;
+
+#[derive(Clone, Debug)]
+pub struct ConsumedTokenRepositoryPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl ConsumedTokenRepositoryPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ConsumedTokenRepository for ConsumedTokenRepositoryPostgres {
+    async fn consume(
+        &self,
+        jti: &str,
+        user_id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, TokenRepositoryError> {
+        let db = connection(&self.db);
+        let backend = db.get_database_backend();
+
+        // Tokens are consumed rarely, so expired rows are purged on the way
+        db.execute(Statement::from_string(
+            backend,
+            format!(
+                r#"DELETE FROM consumed_tokens WHERE expires_at < {}"#,
+                sql::now(backend)
+            ),
+        ))
+        .await
+        .map_err(|e| TokenRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = db
+            .execute(Statement::from_sql_and_values(
+                backend,
+                r#"INSERT INTO consumed_tokens (jti, user_id, expires_at) VALUES ($1, $2, $3)
+                   ON CONFLICT (jti) DO NOTHING"#,
+                [jti.into(), user_id.into(), expires_at.into()],
+            ))
+            .await
+            .map_err(|e| TokenRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DatabaseBackend, DbErr, MockDatabase, MockExecResult};
+
+    fn exec(rows_affected: u64) -> MockExecResult {
+        MockExecResult {
+            last_insert_id: 0,
+            rows_affected,
+        }
+    }
+
+    async fn consume(db: MockDatabase) -> Result<bool, TokenRepositoryError> {
+        ConsumedTokenRepositoryPostgres::new(Arc::new(db.into_connection()))
+            .consume("jti", Uuid::new_v4(), Utc::now())
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_first_use_consumes_and_replay_is_refused() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results(vec![exec(3), exec(1)]);
+        assert!(consume(db).await.unwrap());
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results(vec![exec(0), exec(0)]);
+        assert!(!consume(db).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_database_error() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_errors(vec![DbErr::Custom("db down".to_string())]);
+
+        assert!(matches!(
+            consume(db).await,
+            Err(TokenRepositoryError::DatabaseError(_))
+        ));
+    }
+}
@@ -0,0 +1,672 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::DEFAULT_TIMEZONE;
+use crate::auth::application::ports::outgoing::account_status_repository::AccountStatusRepository;
+use crate::auth::application::ports::outgoing::consumed_token_repository::ConsumedTokenRepository;
+use crate::auth::application::ports::outgoing::passkey_challenge_store::{
+    PasskeyChallengeStore, PendingCeremony,
+};
+use crate::auth::application::ports::outgoing::passkey_repository::{
+    NewPasskey, PasskeyRepository, PasskeyStoreError, StoredPasskey,
+};
+use crate::auth::application::ports::outgoing::token_repository::{
+    TokenRepository, TokenRepositoryError,
+};
+use crate::auth::application::ports::outgoing::token_version_repository::TokenVersionRepository;
+use crate::auth::application::ports::outgoing::user_profile_repository::{
+    StoredUserProfile, UserProfileChanges, UserProfileRepository, UserProfileRepositoryError,
+};
+use crate::auth::application::ports::outgoing::user_query::{
+    UserQuery, UserQueryError, UserQueryResult,
+};
+use crate::auth::application::ports::outgoing::user_repository::{
+    CreateUserData, UserRepository, UserRepositoryError, UserResult,
+};
+use crate::auth::application::use_cases::create_user::CreateUserOutput;
+use crate::email::adapter::outgoing::in_memory::InMemoryEmailOutbox;
+use crate::email::application::ports::outgoing::email_outbox::OutboxEmail;
+use crate::project::application::ports::outgoing::project_repository::PatchField;
+use crate::shared::in_memory::Table;
+
+/// Process-local users, implementing `UserRepository`, `UserQuery`,
+/// `TokenVersionRepository`, `AccountStatusRepository` and
+/// `UserProfileRepository` over the same rows. Email and username are unique across all rows,
+/// deleted ones included, as in Postgres.
+#[derive(Clone, Default)]
+pub struct InMemoryUserStore {
+    pub(crate) users: Table<UserQueryResult>,
+    /// Profile settings of users who changed them from the defaults
+    settings: Table<ProfileSettings>,
+    outbox: Option<InMemoryEmailOutbox>,
+}
+
+struct ProfileSettings {
+    user_id: Uuid,
+    timezone: String,
+    avatar_media_id: Option<Uuid>,
+}
+
+impl InMemoryUserStore {
+    /// Queue each new user's verification email in `outbox`, as the Postgres
+    /// repository does. Without one, no email is queued.
+    pub fn with_email_outbox(outbox: InMemoryEmailOutbox) -> Self {
+        Self {
+            users: Table::default(),
+            settings: Table::default(),
+            outbox: Some(outbox),
+        }
+    }
+
+    /// Applies `change` to the user if it exists and `applies` holds for it
+    fn update_where(
+        &self,
+        user_id: Uuid,
+        applies: impl Fn(&UserQueryResult) -> bool,
+        change: impl FnOnce(&mut UserQueryResult),
+    ) -> Result<UserResult, UserRepositoryError> {
+        self.users.write(|users| {
+            let user = users
+                .iter_mut()
+                .find(|user| user.id == user_id && applies(user))
+                .ok_or(UserRepositoryError::UserNotFound)?;
+            change(user);
+            user.updated_at = Utc::now();
+            Ok(to_user_result(user))
+        })
+    }
+
+    fn find(&self, matches: impl Fn(&UserQueryResult) -> bool) -> Option<UserQueryResult> {
+        self.users.read(|users| {
+            // An active account wins over a deleted one, like the Postgres query
+            users
+                .iter()
+                .filter(|user| matches(user))
+                .min_by_key(|user| user.is_deleted)
+                .cloned()
+        })
+    }
+}
+
+fn to_user_result(user: &UserQueryResult) -> UserResult {
+    UserResult {
+        id: user.id,
+        email: user.email.clone(),
+        username: user.username.clone(),
+        full_name: user.full_name.clone(),
+        preferred_locale: user.preferred_locale.clone(),
+    }
+}
+
+#[async_trait]
+impl UserRepository for InMemoryUserStore {
+    async fn create_user(&self, data: CreateUserData) -> Result<UserResult, UserRepositoryError> {
+        let user = self.users.write(|users| {
+            if users
+                .iter()
+                .any(|user| user.email == data.email || user.username == data.username)
+            {
+                return Err(UserRepositoryError::UserAlreadyExists);
+            }
+
+            let now = Utc::now();
+            let user = UserQueryResult {
+                id: Uuid::new_v4(),
+                email: data.email,
+                username: data.username,
+                password_hash: data.password_hash,
+                full_name: data.full_name,
+                preferred_locale: data.preferred_locale,
+                created_at: now,
+                updated_at: now,
+                is_verified: false,
+                is_deleted: false,
+                token_version: 0,
+                is_active: true,
+            };
+            users.push(user.clone());
+            Ok(user)
+        })?;
+
+        if let Some(outbox) = &self.outbox {
+            outbox.enqueue(OutboxEmail::Verification(CreateUserOutput {
+                user_id: user.id,
+                email: user.email.clone(),
+                username: user.username.clone(),
+                full_name: user.full_name.clone(),
+                preferred_locale: user.preferred_locale.clone(),
+            }));
+        }
+
+        Ok(to_user_result(&user))
+    }
+
+    async fn restore_user(&self, user_id: Uuid) -> Result<UserResult, UserRepositoryError> {
+        self.update_where(user_id, |u| u.is_deleted, |u| u.is_deleted = false)
+    }
+
+    async fn activate_user(&self, user_id: Uuid) -> Result<UserResult, UserRepositoryError> {
+        self.update_where(user_id, |u| !u.is_deleted, |u| u.is_verified = true)
+    }
+
+    async fn update_profile(
+        &self,
+        user_id: Uuid,
+        full_name: String,
+        preferred_locale: Option<String>,
+    ) -> Result<UserResult, UserRepositoryError> {
+        self.update_where(
+            user_id,
+            |u| !u.is_deleted,
+            |u| {
+                u.full_name = full_name;
+                if let Some(locale) = preferred_locale {
+                    u.preferred_locale = locale;
+                }
+            },
+        )
+    }
+
+    async fn update_password(
+        &self,
+        user_id: Uuid,
+        new_password_hash: String,
+    ) -> Result<(), UserRepositoryError> {
+        self.update_where(
+            user_id,
+            |u| !u.is_deleted,
+            |u| u.password_hash = new_password_hash,
+        )
+        .map(|_| ())
+    }
+
+    async fn delete_user(&self, user_id: Uuid) -> Result<(), UserRepositoryError> {
+        self.users.write(|users| {
+            let before = users.len();
+            users.retain(|user| user.id != user_id);
+            if users.len() == before {
+                return Err(UserRepositoryError::UserNotFound);
+            }
+            Ok(())
+        })
+    }
+
+    async fn soft_delete_user(&self, user_id: Uuid) -> Result<(), UserRepositoryError> {
+        self.update_where(user_id, |u| !u.is_deleted, |u| u.is_deleted = true)
+            .map(|_| ())
+    }
+}
+
+#[async_trait]
+impl UserQuery for InMemoryUserStore {
+    async fn find_by_id(&self, user_id: Uuid) -> Result<Option<UserQueryResult>, UserQueryError> {
+        Ok(self.find(|user| user.id == user_id))
+    }
+
+    async fn find_by_email(&self, email: &str) -> Result<Option<UserQueryResult>, UserQueryError> {
+        Ok(self.find(|user| user.email == email))
+    }
+
+    async fn find_by_username(
+        &self,
+        username: &str,
+    ) -> Result<Option<UserQueryResult>, UserQueryError> {
+        Ok(self.find(|user| user.username == username))
+    }
+}
+
+#[async_trait]
+impl TokenVersionRepository for InMemoryUserStore {
+    async fn current_version(&self, user_id: Uuid) -> Result<Option<i32>, UserRepositoryError> {
+        Ok(self.users.read(|users| {
+            users
+                .iter()
+                .find(|user| user.id == user_id)
+                .map(|user| user.token_version)
+        }))
+    }
+
+    async fn bump_version(&self, user_id: Uuid) -> Result<i32, UserRepositoryError> {
+        self.users.write(|users| {
+            let user = users
+                .iter_mut()
+                .find(|user| user.id == user_id)
+                .ok_or(UserRepositoryError::UserNotFound)?;
+            user.token_version += 1;
+            user.updated_at = Utc::now();
+            Ok(user.token_version)
+        })
+    }
+}
+
+#[async_trait]
+impl AccountStatusRepository for InMemoryUserStore {
+    async fn set_active(&self, user_id: Uuid, active: bool) -> Result<(), UserRepositoryError> {
+        self.update_where(user_id, |_| true, |user| user.is_active = active)
+            .map(|_| ())
+    }
+}
+
+impl InMemoryUserStore {
+    fn profile_of(&self, user: UserResult) -> StoredUserProfile {
+        let (timezone, avatar_media_id) = self.settings.read(|settings| {
+            settings
+                .iter()
+                .find(|s| s.user_id == user.id)
+                .map(|s| (s.timezone.clone(), s.avatar_media_id))
+                .unwrap_or_else(|| (DEFAULT_TIMEZONE.to_string(), None))
+        });
+
+        StoredUserProfile {
+            user_id: user.id,
+            email: user.email,
+            username: user.username,
+            full_name: user.full_name,
+            preferred_locale: user.preferred_locale,
+            timezone,
+            avatar_media_id,
+        }
+    }
+}
+
+/// Avatars are not checked against the media store
+#[async_trait]
+impl UserProfileRepository for InMemoryUserStore {
+    async fn find_profile(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Option<StoredUserProfile>, UserProfileRepositoryError> {
+        let user = self.users.read(|users| {
+            users
+                .iter()
+                .find(|user| user.id == user_id && !user.is_deleted)
+                .map(to_user_result)
+        });
+        Ok(user.map(|user| self.profile_of(user)))
+    }
+
+    async fn update_profile_fields(
+        &self,
+        user_id: Uuid,
+        changes: UserProfileChanges,
+    ) -> Result<StoredUserProfile, UserProfileRepositoryError> {
+        let user = self
+            .update_where(
+                user_id,
+                |u| !u.is_deleted,
+                |u| {
+                    if let Some(full_name) = changes.full_name {
+                        u.full_name = full_name;
+                    }
+                    if let Some(locale) = changes.preferred_locale {
+                        u.preferred_locale = locale;
+                    }
+                },
+            )
+            .map_err(|e| match e {
+                UserRepositoryError::UserNotFound => UserProfileRepositoryError::UserNotFound,
+                other => UserProfileRepositoryError::DatabaseError(other.to_string()),
+            })?;
+
+        self.settings.write(|settings| {
+            let index = match settings.iter().position(|s| s.user_id == user_id) {
+                Some(index) => index,
+                None => {
+                    settings.push(ProfileSettings {
+                        user_id,
+                        timezone: DEFAULT_TIMEZONE.to_string(),
+                        avatar_media_id: None,
+                    });
+                    settings.len() - 1
+                }
+            };
+            let entry = &mut settings[index];
+            if let Some(timezone) = changes.timezone {
+                entry.timezone = timezone;
+            }
+            match changes.avatar_media_id {
+                PatchField::Unset => {}
+                PatchField::Null => entry.avatar_media_id = None,
+                PatchField::Value(id) => entry.avatar_media_id = Some(id),
+            }
+        });
+
+        Ok(self.profile_of(user))
+    }
+}
+
+struct BlacklistedToken {
+    token_hash: String,
+    user_id: Uuid,
+    expires_at: DateTime<Utc>,
+}
+
+/// Process-local `TokenRepository`. Expired entries are ignored on read and
+/// dropped by `cleanup_expired_tokens`, standing in for the Redis TTLs.
+#[derive(Clone, Default)]
+pub struct InMemoryTokenRepository {
+    blacklist: Table<BlacklistedToken>,
+}
+
+#[async_trait]
+impl TokenRepository for InMemoryTokenRepository {
+    async fn blacklist_token(
+        &self,
+        token_hash: String,
+        user_id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), TokenRepositoryError> {
+        if expires_at <= Utc::now() {
+            return Err(TokenRepositoryError::InvalidToken);
+        }
+
+        self.blacklist.write(|blacklist| {
+            blacklist.retain(|entry| entry.token_hash != token_hash);
+            blacklist.push(BlacklistedToken {
+                token_hash,
+                user_id,
+                expires_at,
+            });
+        });
+        Ok(())
+    }
+
+    async fn is_token_blacklisted(&self, token_hash: &str) -> Result<bool, TokenRepositoryError> {
+        let now = Utc::now();
+        Ok(self.blacklist.read(|blacklist| {
+            blacklist
+                .iter()
+                .any(|entry| entry.token_hash == token_hash && entry.expires_at > now)
+        }))
+    }
+
+    async fn remove_blacklisted_token(&self, token_hash: &str) -> Result<(), TokenRepositoryError> {
+        self.blacklist
+            .write(|blacklist| blacklist.retain(|entry| entry.token_hash != token_hash));
+        Ok(())
+    }
+
+    async fn revoke_all_user_tokens(&self, user_id: Uuid) -> Result<(), TokenRepositoryError> {
+        self.blacklist
+            .write(|blacklist| blacklist.retain(|entry| entry.user_id != user_id));
+        Ok(())
+    }
+
+    async fn cleanup_expired_tokens(&self) -> Result<u64, TokenRepositoryError> {
+        let now = Utc::now();
+        Ok(self.blacklist.write(|blacklist| {
+            let before = blacklist.len();
+            blacklist.retain(|entry| entry.expires_at > now);
+            (before - blacklist.len()) as u64
+        }))
+    }
+}
+
+/// Process-local `ConsumedTokenRepository`; expired entries are dropped on
+/// the next consume, as in Postgres
+#[derive(Clone, Default)]
+pub struct InMemoryConsumedTokens {
+    consumed: Table<ConsumedToken>,
+}
+
+struct ConsumedToken {
+    jti: String,
+    expires_at: DateTime<Utc>,
+}
+
+#[async_trait]
+impl ConsumedTokenRepository for InMemoryConsumedTokens {
+    async fn consume(
+        &self,
+        jti: &str,
+        _user_id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, TokenRepositoryError> {
+        let now = Utc::now();
+        Ok(self.consumed.write(|consumed| {
+            consumed.retain(|entry| entry.expires_at >= now);
+            if consumed.iter().any(|entry| entry.jti == jti) {
+                return false;
+            }
+            consumed.push(ConsumedToken {
+                jti: jti.to_string(),
+                expires_at,
+            });
+            true
+        }))
+    }
+}
+
+/// Process-local `PasskeyRepository`
+#[derive(Clone, Default)]
+pub struct InMemoryPasskeyStore {
+    passkeys: Table<StoredPasskey>,
+}
+
+#[async_trait]
+impl PasskeyRepository for InMemoryPasskeyStore {
+    async fn add(&self, passkey: NewPasskey) -> Result<StoredPasskey, PasskeyStoreError> {
+        self.passkeys.write(|passkeys| {
+            if passkeys
+                .iter()
+                .any(|stored| stored.credential_id == passkey.credential_id)
+            {
+                return Err(PasskeyStoreError::AlreadyRegistered);
+            }
+            let stored = StoredPasskey {
+                id: Uuid::new_v4(),
+                user_id: passkey.user_id,
+                name: passkey.name,
+                credential_id: passkey.credential_id,
+                credential: passkey.credential,
+                created_at: Utc::now(),
+                last_used_at: None,
+            };
+            passkeys.push(stored.clone());
+            Ok(stored)
+        })
+    }
+
+    async fn list_for_user(&self, user_id: Uuid) -> Result<Vec<StoredPasskey>, PasskeyStoreError> {
+        Ok(self.passkeys.read(|passkeys| {
+            passkeys
+                .iter()
+                .filter(|passkey| passkey.user_id == user_id)
+                .cloned()
+                .collect()
+        }))
+    }
+
+    async fn record_use(
+        &self,
+        passkey_id: Uuid,
+        credential: Option<String>,
+    ) -> Result<(), PasskeyStoreError> {
+        self.passkeys.write(|passkeys| {
+            if let Some(passkey) = passkeys.iter_mut().find(|passkey| passkey.id == passkey_id) {
+                passkey.last_used_at = Some(Utc::now());
+                if let Some(credential) = credential {
+                    passkey.credential = credential;
+                }
+            }
+        });
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: Uuid, passkey_id: Uuid) -> Result<bool, PasskeyStoreError> {
+        Ok(self.passkeys.write(|passkeys| {
+            let before = passkeys.len();
+            passkeys.retain(|passkey| !(passkey.id == passkey_id && passkey.user_id == user_id));
+            passkeys.len() < before
+        }))
+    }
+}
+
+struct StoredCeremony {
+    challenge_id: Uuid,
+    ceremony: PendingCeremony,
+    expires_at: DateTime<Utc>,
+}
+
+/// Process-local `PasskeyChallengeStore`; expired ceremonies are dropped on
+/// the next write, standing in for the Redis TTLs
+#[derive(Clone, Default)]
+pub struct InMemoryPasskeyChallenges {
+    ceremonies: Table<StoredCeremony>,
+}
+
+#[async_trait]
+impl PasskeyChallengeStore for InMemoryPasskeyChallenges {
+    async fn put(
+        &self,
+        challenge_id: Uuid,
+        ceremony: PendingCeremony,
+        ttl: std::time::Duration,
+    ) -> Result<(), PasskeyStoreError> {
+        let now = Utc::now();
+        let ttl = chrono::Duration::from_std(ttl)
+            .map_err(|e| PasskeyStoreError::DatabaseError(e.to_string()))?;
+        self.ceremonies.write(|ceremonies| {
+            ceremonies.retain(|stored| stored.expires_at > now);
+            ceremonies.push(StoredCeremony {
+                challenge_id,
+                ceremony,
+                expires_at: now + ttl,
+            });
+        });
+        Ok(())
+    }
+
+    async fn take(&self, challenge_id: Uuid) -> Result<Option<PendingCeremony>, PasskeyStoreError> {
+        let now = Utc::now();
+        Ok(self.ceremonies.write(|ceremonies| {
+            let i = ceremonies
+                .iter()
+                .position(|stored| stored.challenge_id == challenge_id)?;
+            let stored = ceremonies.swap_remove(i);
+            (stored.expires_at > now).then_some(stored.ceremony)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::email::application::ports::outgoing::email_outbox::EmailOutbox;
+
+    fn jane() -> CreateUserData {
+        CreateUserData {
+            email: "jane@example.com".to_string(),
+            username: "jane".to_string(),
+            password_hash: "hash".to_string(),
+            full_name: "Jane Doe".to_string(),
+            preferred_locale: "en".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_created_user_is_queryable_and_gets_verification_email() {
+        let outbox = InMemoryEmailOutbox::default();
+        let store = InMemoryUserStore::with_email_outbox(outbox.clone());
+
+        let created = store.create_user(jane()).await.unwrap();
+        store.activate_user(created.id).await.unwrap();
+
+        let found = store
+            .find_by_email("jane@example.com")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.id, created.id);
+        assert_eq!(found.password_hash, "hash");
+        assert!(found.is_verified);
+
+        let queued = outbox.claim_due(10, Utc::now()).await.unwrap();
+        assert_eq!(queued.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_deleted_user_is_still_found_but_flagged() {
+        let store = InMemoryUserStore::default();
+        let created = store.create_user(jane()).await.unwrap();
+
+        store.soft_delete_user(created.id).await.unwrap();
+
+        let found = store.find_by_username("jane").await.unwrap().unwrap();
+        assert!(found.is_deleted);
+    }
+
+    #[tokio::test]
+    async fn test_bumping_token_version_is_seen_by_queries() {
+        let store = InMemoryUserStore::default();
+        let created = store.create_user(jane()).await.unwrap();
+
+        assert_eq!(store.bump_version(created.id).await.unwrap(), 1);
+
+        assert_eq!(store.current_version(created.id).await.unwrap(), Some(1));
+        let found = store.find_by_id(created.id).await.unwrap().unwrap();
+        assert_eq!(found.token_version, 1);
+        assert_eq!(store.current_version(Uuid::new_v4()).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_deactivation_is_seen_by_queries_and_reversible() {
+        let store = InMemoryUserStore::default();
+        let created = store.create_user(jane()).await.unwrap();
+
+        store.set_active(created.id, false).await.unwrap();
+        let found = store.find_by_id(created.id).await.unwrap().unwrap();
+        assert!(!found.is_active);
+
+        store.set_active(created.id, true).await.unwrap();
+        let found = store.find_by_id(created.id).await.unwrap().unwrap();
+        assert!(found.is_active);
+
+        assert!(matches!(
+            store.set_active(Uuid::new_v4(), false).await,
+            Err(UserRepositoryError::UserNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_token_can_be_consumed_once() {
+        let consumed = InMemoryConsumedTokens::default();
+        let expires_at = Utc::now() + chrono::Duration::hours(1);
+
+        assert!(consumed
+            .consume("a", Uuid::new_v4(), expires_at)
+            .await
+            .unwrap());
+        assert!(!consumed
+            .consume("a", Uuid::new_v4(), expires_at)
+            .await
+            .unwrap());
+        assert!(consumed
+            .consume("b", Uuid::new_v4(), expires_at)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_blacklist_honours_expiry_and_revocation() {
+        let tokens = InMemoryTokenRepository::default();
+        let user_id = Uuid::new_v4();
+        let later = Utc::now() + chrono::Duration::hours(1);
+
+        tokens
+            .blacklist_token("a".to_string(), user_id, later)
+            .await
+            .unwrap();
+        assert!(tokens.is_token_blacklisted("a").await.unwrap());
+        assert!(matches!(
+            tokens
+                .blacklist_token("b".to_string(), user_id, Utc::now())
+                .await,
+            Err(TokenRepositoryError::InvalidToken)
+        ));
+
+        tokens.revoke_all_user_tokens(user_id).await.unwrap();
+        assert!(!tokens.is_token_blacklisted("a").await.unwrap());
+    }
+}
@@ -43,6 +43,8 @@ impl JwtTokenService {
         &self,
         user_id: Uuid,
         is_verified: bool,
+        token_version: i32,
+        remember_me: bool,
         token_type: &str,
         expiry_seconds: i64,
     ) -> Result<String, TokenError> {
@@ -51,11 +53,17 @@ impl JwtTokenService {
 
         let claims = TokenClaims {
             sub: user_id,
+            iss: self.config.issuer.clone(),
+            aud: self.config.audience.clone(),
             exp: expiration.timestamp(),
             iat: now.timestamp(),
             nbf: now.timestamp(),
             token_type: token_type.to_string(),
             is_verified,
+            token_version,
+            remember_me,
+            // Consumed on use, so each one needs its own id
+            jti: (token_type == "verification").then(|| Uuid::new_v4().to_string()),
         };
 
         encode(&Header::new(Algorithm::HS256), &claims, &self.encoding_key)
@@ -68,9 +76,17 @@ impl TokenProvider for JwtTokenService {
         &self,
         user_id: Uuid,
         is_verified: bool,
+        token_version: i32,
     ) -> Result<String, TokenError> {
         let expiry_seconds = self.config.access_token_expiry;
-        self.generate_token(user_id, is_verified, "access", expiry_seconds)
+        self.generate_token(
+            user_id,
+            is_verified,
+            token_version,
+            false,
+            "access",
+            expiry_seconds,
+        )
     }
 
     /// Generate a refresh token
@@ -78,9 +94,22 @@ impl TokenProvider for JwtTokenService {
         &self,
         user_id: Uuid,
         is_verified: bool,
+        token_version: i32,
+        remember_me: bool,
     ) -> Result<String, TokenError> {
-        let expiry_seconds = self.config.refresh_token_expiry;
-        self.generate_token(user_id, is_verified, "refresh", expiry_seconds)
+        let expiry_seconds = if remember_me {
+            self.config.remember_me_refresh_token_expiry
+        } else {
+            self.config.refresh_token_expiry
+        };
+        self.generate_token(
+            user_id,
+            is_verified,
+            token_version,
+            remember_me,
+            "refresh",
+            expiry_seconds,
+        )
     }
 
     /// Verify and decode a token
@@ -88,6 +117,10 @@ impl TokenProvider for JwtTokenService {
         let mut validation = Validation::new(Algorithm::HS256);
         validation.leeway = 30;
         validation.validate_nbf = true;
+        // Tokens of other environments share nothing but, possibly, the secret
+        validation.set_issuer(&[&self.config.issuer]);
+        validation.set_audience(&[&self.config.audience]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud"]);
 
         let decoded =
             decode::<TokenClaims>(token, &self.decoding_key, &validation).map_err(|e| {
@@ -106,6 +139,12 @@ impl TokenProvider for JwtTokenService {
                         tracing::error!("Security alert: Invalid token signature detected");
                         TokenError::InvalidSignature
                     }
+                    ErrorKind::InvalidIssuer
+                    | ErrorKind::InvalidAudience
+                    | ErrorKind::MissingRequiredClaim(_) => {
+                        tracing::warn!("Token verification failed: Issuer or audience mismatch");
+                        TokenError::WrongIssuerOrAudience
+                    }
                     ErrorKind::InvalidToken | ErrorKind::InvalidAlgorithm => {
                         tracing::error!("Security alert: Malformed or invalid algorithm token");
                         TokenError::MalformedToken
@@ -142,11 +181,11 @@ impl TokenProvider for JwtTokenService {
             "Refresh token validated, issuing new access token for user: {}",
             claims.sub
         );
-        self.generate_access_token(claims.sub, claims.is_verified)
+        self.generate_access_token(claims.sub, claims.is_verified, claims.token_version)
     }
 
-    /// Verify an email verification token and extract the user ID
-    fn verify_verification_token(&self, token: &str) -> Result<Uuid, TokenError> {
+    /// Verify an email verification token and return its claims
+    fn verify_verification_token(&self, token: &str) -> Result<TokenClaims, TokenError> {
         let claims = self.verify_token(token)?;
 
         if claims.token_type != "verification" {
@@ -161,12 +200,12 @@ impl TokenProvider for JwtTokenService {
             "Verification token validated successfully for user: {}",
             claims.sub
         );
-        Ok(claims.sub)
+        Ok(claims)
     }
 
     fn generate_verification_token(&self, user_id: Uuid) -> Result<String, TokenError> {
         let token_expiry = self.config.verification_token_expiry;
-        self.generate_token(user_id, false, "verification", token_expiry)
+        self.generate_token(user_id, false, 0, false, "verification", token_expiry)
     }
 }
 
@@ -182,9 +221,11 @@ mod tests {
             secret_key: std::env::var("TEST_JWT_SECRET")
                 .unwrap_or_else(|_| "FAKE_JWT_SECRET_DO_NOT_USE".to_string()),
             issuer: "test_issuer".to_string(),
+            audience: "test_audience".to_string(),
             access_token_expiry: 3600,        // 1 hour
             refresh_token_expiry: 86400,      // 24 hours
             verification_token_expiry: 86400, // 24 hours
+            remember_me_refresh_token_expiry: 2592000,
         };
         JwtTokenService::new(config)
     }
@@ -196,9 +237,11 @@ mod tests {
                 .unwrap_or_else(|_| "FAKE_JWT_SECRET_DO_NOT_USE".to_string()),
 
             issuer: "myapp".to_string(),
+            audience: "test_audience".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         };
 
         let jwt_service = JwtTokenService::new(config);
@@ -206,7 +249,7 @@ mod tests {
 
         // Generate token
         let token = jwt_service
-            .generate_access_token(user_id, true)
+            .generate_access_token(user_id, true, 0)
             .expect("Token should be generated");
 
         // Verify token
@@ -225,7 +268,7 @@ mod tests {
 
         // Generate token for unverified user
         let token = service
-            .generate_access_token(user_id, false)
+            .generate_access_token(user_id, false, 0)
             .expect("Token should be generated");
 
         // Verify token
@@ -278,9 +321,11 @@ mod tests {
             secret_key: std::env::var("TEST_JWT_SECRET")
                 .unwrap_or_else(|_| "FAKE_JWT_SECRET_DO_NOT_USE".to_string()),
             issuer: "myapp".to_string(),
+            audience: "test_audience".to_string(),
             access_token_expiry: -35, // Already expired (beyond leeway)
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         };
 
         let jwt_service = JwtTokenService::new(config);
@@ -288,7 +333,7 @@ mod tests {
 
         // Generate token (will be immediately expired)
         let token = jwt_service
-            .generate_access_token(user_id, true)
+            .generate_access_token(user_id, true, 0)
             .expect("Token should be generated");
 
         // Verify expired token (no sleep needed)
@@ -313,7 +358,7 @@ mod tests {
         let service = create_test_jwt_service();
         let user_id = Uuid::new_v4();
 
-        let token = service.generate_access_token(user_id, true).unwrap();
+        let token = service.generate_access_token(user_id, true, 0).unwrap();
 
         // pastikan berbeda dari secret service (apa pun nilai env-nya)
         let different_secret = format!("{}_DIFFERENT", service.config.secret_key);
@@ -321,9 +366,11 @@ mod tests {
         let different_config = JwtConfig {
             secret_key: different_secret,
             issuer: "test".to_string(),
+            audience: "test_audience".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         };
 
         let different_service = JwtTokenService::new(different_config);
@@ -334,6 +381,32 @@ mod tests {
         assert!(matches!(result.unwrap_err(), TokenError::InvalidSignature));
     }
 
+    #[test]
+    fn test_token_for_another_issuer_or_audience_is_rejected() {
+        let service = create_test_jwt_service();
+        let token = service
+            .generate_access_token(Uuid::new_v4(), true, 0)
+            .unwrap();
+
+        let other_issuer = JwtTokenService::new(JwtConfig {
+            issuer: "other_issuer".to_string(),
+            ..service.config.clone()
+        });
+        let other_audience = JwtTokenService::new(JwtConfig {
+            audience: "other_audience".to_string(),
+            ..service.config.clone()
+        });
+
+        assert!(matches!(
+            other_issuer.verify_token(&token),
+            Err(TokenError::WrongIssuerOrAudience)
+        ));
+        assert!(matches!(
+            other_audience.verify_token(&token),
+            Err(TokenError::WrongIssuerOrAudience)
+        ));
+    }
+
     #[test]
     fn test_generate_refresh_token() {
         let service = create_test_jwt_service();
@@ -341,7 +414,7 @@ mod tests {
 
         // Generate refresh token
         let token = service
-            .generate_refresh_token(user_id, true)
+            .generate_refresh_token(user_id, true, 0, false)
             .expect("Refresh token should be generated");
 
         // Verify refresh token
@@ -358,13 +431,38 @@ mod tests {
         let service = create_test_jwt_service();
         let user_id = Uuid::new_v4();
 
-        let token = service.generate_refresh_token(user_id, false).unwrap();
+        let token = service
+            .generate_refresh_token(user_id, false, 0, false)
+            .unwrap();
         let claims = service.verify_token(&token).unwrap();
 
         assert_eq!(claims.is_verified, false);
         assert_eq!(claims.token_type, "refresh");
     }
 
+    #[test]
+    fn test_remember_me_refresh_token_gets_long_expiry() {
+        let service = create_test_jwt_service();
+        let user_id = Uuid::new_v4();
+
+        let short = service
+            .generate_refresh_token(user_id, true, 0, false)
+            .unwrap();
+        let long = service
+            .generate_refresh_token(user_id, true, 0, true)
+            .unwrap();
+
+        let short = service.verify_token(&short).unwrap();
+        let long = service.verify_token(&long).unwrap();
+        assert!(!short.remember_me);
+        assert!(long.remember_me);
+        assert_eq!(short.exp - short.iat, service.config.refresh_token_expiry);
+        assert_eq!(
+            long.exp - long.iat,
+            service.config.remember_me_refresh_token_expiry
+        );
+    }
+
     #[test]
     fn test_generate_and_verify_verification_token() {
         let service = create_test_jwt_service();
@@ -383,7 +481,22 @@ mod tests {
         // Verify using verify_verification_token
         let result = service.verify_verification_token(&token);
         assert!(result.is_ok(), "Token should be valid");
-        assert_eq!(result.unwrap(), user_id, "User ID should match");
+        assert_eq!(result.unwrap().sub, user_id, "User ID should match");
+    }
+
+    #[test]
+    fn test_only_verification_tokens_get_a_unique_jti() {
+        let service = create_test_jwt_service();
+        let user_id = Uuid::new_v4();
+
+        let first = service.generate_verification_token(user_id).unwrap();
+        let second = service.generate_verification_token(user_id).unwrap();
+        let first_jti = service.verify_token(&first).unwrap().jti.unwrap();
+        let second_jti = service.verify_token(&second).unwrap().jti.unwrap();
+        assert_ne!(first_jti, second_jti);
+
+        let access = service.generate_access_token(user_id, true, 0).unwrap();
+        assert_eq!(service.verify_token(&access).unwrap().jti, None);
     }
 
     #[test]
@@ -392,7 +505,7 @@ mod tests {
         let user_id = Uuid::new_v4();
 
         // Generate an access token
-        let access_token = service.generate_access_token(user_id, true).unwrap();
+        let access_token = service.generate_access_token(user_id, true, 0).unwrap();
 
         // Try to verify it as verification token
         let result = service.verify_verification_token(&access_token);
@@ -411,7 +524,9 @@ mod tests {
         let service = create_test_jwt_service();
         let user_id = Uuid::new_v4();
 
-        let refresh_token = service.generate_refresh_token(user_id, true).unwrap();
+        let refresh_token = service
+            .generate_refresh_token(user_id, true, 0, false)
+            .unwrap();
         let result = service.verify_verification_token(&refresh_token);
 
         assert!(result.is_err());
@@ -427,7 +542,9 @@ mod tests {
         let user_id = Uuid::new_v4();
 
         // Generate a valid refresh token
-        let refresh_token = service.generate_refresh_token(user_id, true).unwrap();
+        let refresh_token = service
+            .generate_refresh_token(user_id, true, 0, false)
+            .unwrap();
 
         // Refresh the access token
         let result = service.refresh_access_token(&refresh_token);
@@ -448,7 +565,7 @@ mod tests {
         let user_id = Uuid::new_v4();
 
         // Generate an access token (not a refresh token)
-        let access_token = service.generate_access_token(user_id, true).unwrap();
+        let access_token = service.generate_access_token(user_id, true, 0).unwrap();
 
         // Try to refresh using an access token (should fail)
         let result = service.refresh_access_token(&access_token);
@@ -498,15 +615,19 @@ mod tests {
             secret_key: std::env::var("TEST_JWT_SECRET")
                 .unwrap_or_else(|_| "FAKE_JWT_SECRET_DO_NOT_USE".to_string()),
             issuer: "test_issuer".to_string(),
+            audience: "test_audience".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: -32, // 1 second
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         };
         let service = JwtTokenService::new(config);
         let user_id = Uuid::new_v4();
 
         // Generate a refresh token
-        let refresh_token = service.generate_refresh_token(user_id, true).unwrap();
+        let refresh_token = service
+            .generate_refresh_token(user_id, true, 0, false)
+            .unwrap();
 
         // Try to refresh with expired token
         let result = service.refresh_access_token(&refresh_token);
@@ -521,7 +642,9 @@ mod tests {
         let user_id = Uuid::new_v4();
 
         // Generate a valid refresh token
-        let mut refresh_token = service.generate_refresh_token(user_id, true).unwrap();
+        let mut refresh_token = service
+            .generate_refresh_token(user_id, true, 0, false)
+            .unwrap();
 
         // Tamper with the token (change a character)
         refresh_token.push('x');
@@ -538,7 +661,9 @@ mod tests {
         let user_id = Uuid::new_v4();
 
         // Generate a refresh token
-        let refresh_token = service.generate_refresh_token(user_id, true).unwrap();
+        let refresh_token = service
+            .generate_refresh_token(user_id, true, 0, false)
+            .unwrap();
 
         // Refresh to get new access token
         let new_access_token = service.refresh_access_token(&refresh_token).unwrap();
@@ -554,7 +679,9 @@ mod tests {
         let user_id = Uuid::new_v4();
 
         // Test with verified user
-        let refresh_token_verified = service.generate_refresh_token(user_id, true).unwrap();
+        let refresh_token_verified = service
+            .generate_refresh_token(user_id, true, 0, false)
+            .unwrap();
         let access_token = service
             .refresh_access_token(&refresh_token_verified)
             .unwrap();
@@ -562,7 +689,9 @@ mod tests {
         assert_eq!(claims.is_verified, true);
 
         // Test with unverified user
-        let refresh_token_unverified = service.generate_refresh_token(user_id, false).unwrap();
+        let refresh_token_unverified = service
+            .generate_refresh_token(user_id, false, 0, false)
+            .unwrap();
         let access_token = service
             .refresh_access_token(&refresh_token_unverified)
             .unwrap();
@@ -576,7 +705,9 @@ mod tests {
         let user_id = Uuid::new_v4();
 
         // Generate initial refresh token
-        let refresh_token = service.generate_refresh_token(user_id, true).unwrap();
+        let refresh_token = service
+            .generate_refresh_token(user_id, true, 0, false)
+            .unwrap();
 
         // Refresh multiple times with the same refresh token
         let access_token_1 = service.refresh_access_token(&refresh_token).unwrap();
@@ -597,7 +728,7 @@ mod tests {
         let service = create_test_jwt_service();
         let user_id = Uuid::new_v4();
 
-        let token = service.generate_access_token(user_id, true).unwrap();
+        let token = service.generate_access_token(user_id, true, 0).unwrap();
         let claims = service.verify_token(&token).unwrap();
 
         // Verify all required fields are present
@@ -613,7 +744,7 @@ mod tests {
         let service = create_test_jwt_service();
         let user_id = Uuid::new_v4();
 
-        let token = service.generate_access_token(user_id, true).unwrap();
+        let token = service.generate_access_token(user_id, true, 0).unwrap();
         let claims = service.verify_token(&token).unwrap();
 
         let now = Utc::now().timestamp();
@@ -666,6 +797,11 @@ mod tests {
             nbf: 12340,
             token_type: "access".to_string(),
             is_verified: true,
+            token_version: 0,
+            remember_me: false,
+            iss: String::new(),
+            aud: String::new(),
+            jti: None,
         };
         let debug_str = format!("{:?}", claims);
         assert!(debug_str.contains("TokenClaims"));
@@ -678,8 +814,10 @@ mod tests {
         let cloned_service = service.clone();
 
         let user_id = Uuid::new_v4();
-        let token1 = service.generate_access_token(user_id, true).unwrap();
-        let token2 = cloned_service.generate_access_token(user_id, true).unwrap();
+        let token1 = service.generate_access_token(user_id, true, 0).unwrap();
+        let token2 = cloned_service
+            .generate_access_token(user_id, true, 0)
+            .unwrap();
 
         // Both services should produce valid tokens
         assert!(service.verify_token(&token1).is_ok());
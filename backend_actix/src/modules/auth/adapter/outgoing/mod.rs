@@ -1,6 +1,11 @@
+pub mod consumed_token_postgres;
+pub mod in_memory;
 pub mod jwt;
+pub mod passkey_challenges_redis;
+pub mod passkey_repository_postgres;
 pub mod sea_orm_entity;
 pub mod security;
 pub mod token_repository_redis;
 pub mod user_query_postgres;
 pub mod user_repository_postgres;
+pub mod webauthn_ceremony;
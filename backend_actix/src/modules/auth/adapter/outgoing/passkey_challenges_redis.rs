@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use deadpool_redis::{redis::AsyncCommands, Pool};
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::auth::application::ports::outgoing::passkey_challenge_store::{
+    PasskeyChallengeStore, PendingCeremony,
+};
+use crate::auth::application::ports::outgoing::passkey_repository::PasskeyStoreError;
+
+/// Redis-backed `PasskeyChallengeStore`.
+///
+/// ```text
+/// auth:passkey:challenge:{challenge_id} -> PendingCeremony as JSON
+/// ```
+/// The key's TTL is the challenge's lifetime; `GETDEL` makes answering it
+/// single-use across instances.
+#[derive(Clone)]
+pub struct RedisPasskeyChallenges {
+    pool: Arc<Pool>,
+}
+
+impl RedisPasskeyChallenges {
+    pub fn new(pool: Arc<Pool>) -> Self {
+        Self { pool }
+    }
+
+    fn key(challenge_id: Uuid) -> String {
+        format!("auth:passkey:challenge:{challenge_id}")
+    }
+
+    async fn get_conn(&self) -> Result<deadpool_redis::Connection, PasskeyStoreError> {
+        self.pool
+            .get()
+            .await
+            .map_err(|e| PasskeyStoreError::DatabaseError(format!("Pool error: {}", e)))
+    }
+}
+
+#[async_trait]
+impl PasskeyChallengeStore for RedisPasskeyChallenges {
+    async fn put(
+        &self,
+        challenge_id: Uuid,
+        ceremony: PendingCeremony,
+        ttl: Duration,
+    ) -> Result<(), PasskeyStoreError> {
+        let value = serde_json::to_string(&ceremony)
+            .map_err(|e| PasskeyStoreError::DatabaseError(e.to_string()))?;
+        let mut conn = self.get_conn().await?;
+
+        conn.set_ex::<_, _, ()>(Self::key(challenge_id), value, ttl.as_secs().max(1))
+            .await
+            .map_err(|e| PasskeyStoreError::DatabaseError(e.to_string()))
+    }
+
+    async fn take(&self, challenge_id: Uuid) -> Result<Option<PendingCeremony>, PasskeyStoreError> {
+        let mut conn = self.get_conn().await?;
+        let value: Option<String> = conn
+            .get_del(Self::key(challenge_id))
+            .await
+            .map_err(|e| PasskeyStoreError::DatabaseError(e.to_string()))?;
+
+        value
+            .map(|value| {
+                serde_json::from_str(&value)
+                    .map_err(|e| PasskeyStoreError::DatabaseError(e.to_string()))
+            })
+            .transpose()
+    }
+}
@@ -0,0 +1,186 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, QueryResult, Statement};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::application::ports::outgoing::passkey_repository::{
+    NewPasskey, PasskeyRepository, PasskeyStoreError, StoredPasskey,
+};
+use crate::shared::sql;
+
+/// `PasskeyRepository` over the `webauthn_credentials` table
+#[derive(Clone, Debug)]
+pub struct PasskeyRepositoryPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl PasskeyRepositoryPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn to_passkey(row: &QueryResult) -> Result<StoredPasskey, PasskeyStoreError> {
+        Ok(StoredPasskey {
+            id: row.try_get("", "id").map_err(Self::map_db_err)?,
+            user_id: row.try_get("", "user_id").map_err(Self::map_db_err)?,
+            name: row.try_get("", "name").map_err(Self::map_db_err)?,
+            credential_id: row.try_get("", "credential_id").map_err(Self::map_db_err)?,
+            credential: row.try_get("", "passkey").map_err(Self::map_db_err)?,
+            created_at: row.try_get("", "created_at").map_err(Self::map_db_err)?,
+            last_used_at: row.try_get("", "last_used_at").map_err(Self::map_db_err)?,
+        })
+    }
+
+    /// The insert only hits the unique credential id
+    fn map_write_err(e: DbErr) -> PasskeyStoreError {
+        let msg = e.to_string().to_lowercase();
+
+        if msg.contains("duplicate") || msg.contains("unique") || msg.contains("23505") {
+            PasskeyStoreError::AlreadyRegistered
+        } else {
+            PasskeyStoreError::DatabaseError(e.to_string())
+        }
+    }
+
+    fn map_db_err(e: DbErr) -> PasskeyStoreError {
+        PasskeyStoreError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl PasskeyRepository for PasskeyRepositoryPostgres {
+    async fn add(&self, passkey: NewPasskey) -> Result<StoredPasskey, PasskeyStoreError> {
+        let stored = StoredPasskey {
+            id: Uuid::new_v4(),
+            user_id: passkey.user_id,
+            name: passkey.name,
+            credential_id: passkey.credential_id,
+            credential: passkey.credential,
+            created_at: Utc::now(),
+            last_used_at: None,
+        };
+
+        self.db
+            .execute(Statement::from_sql_and_values(
+                self.db.get_database_backend(),
+                r#"INSERT INTO webauthn_credentials
+                       (id, user_id, credential_id, passkey, name, created_at)
+                   VALUES ($1, $2, $3, $4, $5, $6)"#,
+                [
+                    stored.id.into(),
+                    stored.user_id.into(),
+                    stored.credential_id.clone().into(),
+                    stored.credential.clone().into(),
+                    stored.name.clone().into(),
+                    stored.created_at.into(),
+                ],
+            ))
+            .await
+            .map_err(Self::map_write_err)?;
+
+        Ok(stored)
+    }
+
+    async fn list_for_user(&self, user_id: Uuid) -> Result<Vec<StoredPasskey>, PasskeyStoreError> {
+        let rows = self
+            .db
+            .query_all(Statement::from_sql_and_values(
+                self.db.get_database_backend(),
+                r#"SELECT id, user_id, credential_id, passkey, name, created_at, last_used_at
+                   FROM webauthn_credentials
+                   WHERE user_id = $1
+                   ORDER BY created_at ASC"#,
+                [user_id.into()],
+            ))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        rows.iter().map(Self::to_passkey).collect()
+    }
+
+    async fn record_use(
+        &self,
+        passkey_id: Uuid,
+        credential: Option<String>,
+    ) -> Result<(), PasskeyStoreError> {
+        let backend = self.db.get_database_backend();
+
+        self.db
+            .execute(Statement::from_sql_and_values(
+                backend,
+                format!(
+                    r#"UPDATE webauthn_credentials
+                       SET last_used_at = {}, passkey = COALESCE($2, passkey)
+                       WHERE id = $1"#,
+                    sql::now(backend)
+                ),
+                [passkey_id.into(), credential.into()],
+            ))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: Uuid, passkey_id: Uuid) -> Result<bool, PasskeyStoreError> {
+        let result = self
+            .db
+            .execute(Statement::from_sql_and_values(
+                self.db.get_database_backend(),
+                r#"DELETE FROM webauthn_credentials WHERE id = $1 AND user_id = $2"#,
+                [passkey_id.into(), user_id.into()],
+            ))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        Ok(result.rows_affected() == 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
+
+    fn new_passkey() -> NewPasskey {
+        NewPasskey {
+            user_id: Uuid::new_v4(),
+            name: "Phone".to_string(),
+            credential_id: "cred-1".to_string(),
+            credential: "{}".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_returns_the_stored_passkey() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).append_exec_results(vec![
+            MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            },
+        ]);
+        let repo = PasskeyRepositoryPostgres::new(Arc::new(db.into_connection()));
+
+        let stored = repo.add(new_passkey()).await.unwrap();
+
+        assert_eq!(stored.credential_id, "cred-1");
+        assert_eq!(stored.last_used_at, None);
+    }
+
+    #[tokio::test]
+    async fn test_add_duplicate_credential_is_already_registered() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).append_exec_errors(vec![
+            DbErr::Custom(
+                "duplicate key value violates unique constraint \"webauthn_credentials_credential_id_key\""
+                    .to_string(),
+            ),
+        ]);
+        let repo = PasskeyRepositoryPostgres::new(Arc::new(db.into_connection()));
+
+        assert_eq!(
+            repo.add(new_passkey()).await,
+            Err(PasskeyStoreError::AlreadyRegistered)
+        );
+    }
+}
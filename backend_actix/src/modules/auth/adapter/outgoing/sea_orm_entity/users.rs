@@ -13,10 +13,23 @@ pub struct Model {
     pub email: String,
     pub password_hash: String,
     pub full_name: String,
+    pub preferred_locale: String,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
     pub is_verified: bool,
     pub is_deleted: bool,
+    /// Set once the email provider reports the address undeliverable
+    pub email_bounced_at: Option<DateTimeWithTimeZone>,
+    /// Set once the user follows an unsubscribe link
+    pub email_unsubscribed_at: Option<DateTimeWithTimeZone>,
+    /// Bumped to revoke every token issued so far
+    pub token_version: i32,
+    /// Cleared while the owner has deactivated the account
+    pub is_active: bool,
+    /// IANA name, `UTC` unless the user chose one
+    pub timezone: String,
+    /// One of the user's own media
+    pub avatar_media_id: Option<Uuid>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -5,7 +5,7 @@ use crate::auth::application::ports::outgoing::user_query::UserQueryError;
 use crate::auth::application::ports::outgoing::user_query::UserQueryResult;
 use crate::modules::auth::application::ports::outgoing::UserQuery;
 use async_trait::async_trait;
-use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Select};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -20,6 +20,15 @@ impl UserQueryPostgres {
     }
 }
 impl UserQueryPostgres {
+    /// Deleted accounts are still returned (login and re-registration need
+    /// them), but an active account with the same email wins. Served by
+    /// idx_users_email_deleted (email, is_deleted).
+    fn find_by_email_query(email: &str) -> Select<UserEntity> {
+        UserEntity::find()
+            .filter(UserColumn::Email.eq(email))
+            .order_by_asc(UserColumn::IsDeleted)
+    }
+
     /// Helper to map SeaORM model to UserQueryResult
     fn map_to_query_result(model: UserModel) -> UserQueryResult {
         UserQueryResult {
@@ -28,10 +37,13 @@ impl UserQueryPostgres {
             username: model.username,
             password_hash: model.password_hash,
             full_name: model.full_name,
+            preferred_locale: model.preferred_locale,
             created_at: model.created_at.with_timezone(&chrono::Utc),
             updated_at: model.updated_at.with_timezone(&chrono::Utc),
             is_verified: model.is_verified,
             is_deleted: model.is_deleted,
+            token_version: model.token_version,
+            is_active: model.is_active,
         }
     }
 }
@@ -48,8 +60,7 @@ impl UserQuery for UserQueryPostgres {
     }
 
     async fn find_by_email(&self, email: &str) -> Result<Option<UserQueryResult>, UserQueryError> {
-        let user = UserEntity::find()
-            .filter(UserColumn::Email.eq(email))
+        let user = Self::find_by_email_query(email)
             .one(&*self.db)
             .await
             .map_err(|e| UserQueryError::DatabaseError(e.to_string()))?;
@@ -74,7 +85,7 @@ impl UserQuery for UserQueryPostgres {
 mod tests {
     use super::*;
     use chrono::Utc;
-    use sea_orm::{DatabaseBackend, MockDatabase};
+    use sea_orm::{DatabaseBackend, MockDatabase, QuerySelect, QueryTrait};
 
     fn create_mock_user_model(id: Uuid) -> UserModel {
         let now = Utc::now();
@@ -84,10 +95,17 @@ mod tests {
             email: "test@example.com".to_string(),
             password_hash: "hashed_password".to_string(),
             full_name: "Test User".to_string(),
+            preferred_locale: "en".to_string(),
             created_at: now.into(),
             updated_at: now.into(),
             is_verified: true,
             is_deleted: false,
+            email_bounced_at: None,
+            email_unsubscribed_at: None,
+            token_version: 0,
+            is_active: true,
+            timezone: "UTC".to_string(),
+            avatar_media_id: None,
         }
     }
 
@@ -227,10 +245,17 @@ mod tests {
             email: "test@example.com".to_string(),
             password_hash: "hashed_password".to_string(),
             full_name: "Test User".to_string(),
+            preferred_locale: "en".to_string(),
             created_at: now.into(),
             updated_at: now.into(),
             is_verified: true,
             is_deleted: false,
+            email_bounced_at: None,
+            email_unsubscribed_at: None,
+            token_version: 0,
+            is_active: true,
+            timezone: "UTC".to_string(),
+            avatar_media_id: None,
         };
 
         let query_result = UserQueryPostgres::map_to_query_result(model.clone());
@@ -243,4 +268,47 @@ mod tests {
         assert_eq!(query_result.is_verified, model.is_verified);
         assert_eq!(query_result.is_deleted, model.is_deleted);
     }
+
+    #[tokio::test]
+    async fn test_account_flags_are_read_from_the_row() {
+        // Login and re-registration tell a deleted or unverified account
+        // apart by these flags, so none may be defaulted
+        let user_id = Uuid::new_v4();
+        let model = UserModel {
+            is_verified: false,
+            is_deleted: true,
+            is_active: false,
+            token_version: 3,
+            ..create_mock_user_model(user_id)
+        };
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![model]])
+            .into_connection();
+        let query = UserQueryPostgres::new(Arc::new(db));
+
+        let user = query
+            .find_by_email("test@example.com")
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(!user.is_verified);
+        assert!(user.is_deleted);
+        assert!(!user.is_active);
+        assert_eq!(user.token_version, 3);
+    }
+
+    #[tokio::test]
+    async fn test_explain_find_by_email_uses_email_index() {
+        let Some(db) = crate::tests::support::database::test_postgres_db().await else {
+            return;
+        };
+
+        let stmt = UserQueryPostgres::find_by_email_query("someone@example.com")
+            .limit(1)
+            .build(DatabaseBackend::Postgres);
+        let plan = crate::tests::support::explain::explain(&db, stmt).await;
+
+        assert!(plan.contains("idx_users_email_deleted"), "{plan}");
+    }
 }
@@ -1,15 +1,28 @@
 use async_trait::async_trait;
 use sea_orm::ActiveValue::NotSet;
 use sea_orm::{
-    ActiveModelTrait, DatabaseBackend, DatabaseConnection, FromQueryResult, Set, Statement,
+    ActiveModelTrait, ConnectionTrait, DatabaseConnection, DbErr, FromQueryResult, QueryResult,
+    Set, Statement, TransactionTrait,
 };
 use std::sync::Arc;
 use uuid::Uuid;
 
+use crate::auth::application::ports::outgoing::user_profile_repository::{
+    StoredUserProfile, UserProfileChanges, UserProfileRepository, UserProfileRepositoryError,
+};
 use crate::auth::application::ports::outgoing::user_repository::{CreateUserData, UserResult};
+use crate::auth::application::use_cases::create_user::CreateUserOutput;
+use crate::email::adapter::outgoing::email_outbox_postgres::EmailOutboxPostgres;
+use crate::email::application::ports::outgoing::email_outbox::OutboxEmail;
 use crate::modules::auth::application::ports::outgoing::user_repository::{
     UserRepository, UserRepositoryError,
 };
+use crate::modules::auth::application::ports::outgoing::{
+    AccountStatusRepository, TokenVersionRepository,
+};
+use crate::project::application::ports::outgoing::project_repository::PatchField;
+use crate::shared::sql;
+use crate::shared::unit_of_work::connection;
 
 use super::sea_orm_entity::users::{ActiveModel as UserActiveModel, Model as UserModel};
 
@@ -30,6 +43,7 @@ impl UserRepositoryPostgres {
             email: model.email,
             username: model.username,
             full_name: model.full_name,
+            preferred_locale: model.preferred_locale,
         }
     }
 }
@@ -44,13 +58,26 @@ impl UserRepository for UserRepositoryPostgres {
             email: Set(user.email),
             password_hash: Set(user.password_hash),
             full_name: Set(user.full_name),
+            preferred_locale: Set(user.preferred_locale),
             created_at: NotSet,
             updated_at: NotSet,
             is_verified: Set(false),
             is_deleted: Set(false),
+            email_bounced_at: NotSet,
+            email_unsubscribed_at: NotSet,
+            token_version: NotSet,
+            is_active: NotSet,
+            timezone: NotSet,
+            avatar_media_id: NotSet,
         };
 
-        let inserted = active_user.insert(&*self.db).await.map_err(|e| {
+        // A savepoint when registering inside a unit of work
+        let txn = connection(&self.db)
+            .begin()
+            .await
+            .map_err(|e| UserRepositoryError::DatabaseError(e.to_string()))?;
+
+        let inserted = active_user.insert(&txn).await.map_err(|e| {
             let err_str = e.to_string().to_lowercase();
             if err_str.contains("23505")
                 || err_str.contains("duplicate key")
@@ -61,6 +88,22 @@ impl UserRepository for UserRepositoryPostgres {
             UserRepositoryError::DatabaseError(e.to_string())
         })?;
 
+        // Queued in the same transaction: no user without their verification email
+        let verification = OutboxEmail::Verification(CreateUserOutput {
+            user_id: inserted.id,
+            email: inserted.email.clone(),
+            username: inserted.username.clone(),
+            full_name: inserted.full_name.clone(),
+            preferred_locale: inserted.preferred_locale.clone(),
+        });
+        EmailOutboxPostgres::enqueue(&txn, &verification)
+            .await
+            .map_err(|e| UserRepositoryError::DatabaseError(e.to_string()))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| UserRepositoryError::DatabaseError(e.to_string()))?;
+
         Ok(Self::map_to_user_result(inserted))
     }
 
@@ -69,9 +112,13 @@ impl UserRepository for UserRepositoryPostgres {
         user_id: Uuid,
         new_password_hash: String,
     ) -> Result<(), UserRepositoryError> {
+        let backend = self.db.get_database_backend();
         let result = UserModel::find_by_statement(Statement::from_sql_and_values(
-                DatabaseBackend::Postgres,
-                r#"UPDATE users SET password_hash = $1, updated_at = NOW() WHERE id = $2 AND is_deleted = false RETURNING id"#,
+                backend,
+                format!(
+                    r#"UPDATE users SET password_hash = $1, updated_at = {} WHERE id = $2 AND is_deleted = false RETURNING id"#,
+                    sql::now(backend)
+                ),
                 [new_password_hash.into(), user_id.into()],
             ))
             .one(&*self.db)
@@ -92,7 +139,7 @@ impl UserRepository for UserRepositoryPostgres {
         }
 
         let result = DeleteResult::find_by_statement(Statement::from_sql_and_values(
-            DatabaseBackend::Postgres,
+            self.db.get_database_backend(),
             r#"DELETE FROM users WHERE id = $1 RETURNING id"#,
             [user_id.into()],
         ))
@@ -112,9 +159,13 @@ impl UserRepository for UserRepositoryPostgres {
             id: Uuid,
         }
 
+        let backend = self.db.get_database_backend();
         let result = UpdateResult::find_by_statement(Statement::from_sql_and_values(
-                DatabaseBackend::Postgres,
-                r#"UPDATE users SET is_deleted = true, updated_at = NOW() WHERE id = $1 AND is_deleted = false RETURNING id"#,
+                backend,
+                format!(
+                    r#"UPDATE users SET is_deleted = true, updated_at = {} WHERE id = $1 AND is_deleted = false RETURNING id"#,
+                    sql::now(backend)
+                ),
                 [user_id.into()],
             ))
             .one(&*self.db)
@@ -128,9 +179,13 @@ impl UserRepository for UserRepositoryPostgres {
         Ok(())
     }
     async fn restore_user(&self, user_id: Uuid) -> Result<UserResult, UserRepositoryError> {
+        let backend = self.db.get_database_backend();
         let result = UserModel::find_by_statement(Statement::from_sql_and_values(
-                DatabaseBackend::Postgres,
-                r#"UPDATE users SET is_deleted = false, updated_at = NOW() WHERE id = $1 AND is_deleted = true RETURNING *"#,
+                backend,
+                format!(
+                    r#"UPDATE users SET is_deleted = false, updated_at = {} WHERE id = $1 AND is_deleted = true RETURNING *"#,
+                    sql::now(backend)
+                ),
                 [user_id.into()],
             ))
             .one(&*self.db)
@@ -142,12 +197,16 @@ impl UserRepository for UserRepositoryPostgres {
             .ok_or(UserRepositoryError::UserNotFound)
     }
     async fn activate_user(&self, user_id: Uuid) -> Result<UserResult, UserRepositoryError> {
+        let backend = self.db.get_database_backend();
         let result = UserModel::find_by_statement(Statement::from_sql_and_values(
-                DatabaseBackend::Postgres,
-                r#"UPDATE users SET is_verified = true, updated_at = NOW() WHERE id = $1 AND is_deleted = false RETURNING *"#,
+                backend,
+                format!(
+                    r#"UPDATE users SET is_verified = true, updated_at = {} WHERE id = $1 AND is_deleted = false RETURNING *"#,
+                    sql::now(backend)
+                ),
                 [user_id.into()],
             ))
-            .one(&*self.db)
+            .one(&connection(&self.db))
             .await
             .map_err(|e| UserRepositoryError::DatabaseError(e.to_string()))?;
 
@@ -155,15 +214,20 @@ impl UserRepository for UserRepositoryPostgres {
             .map(Self::map_to_user_result)
             .ok_or(UserRepositoryError::UserNotFound)
     }
-    async fn set_full_name(
+    async fn update_profile(
         &self,
         user_id: Uuid,
         full_name: String,
+        preferred_locale: Option<String>,
     ) -> Result<UserResult, UserRepositoryError> {
+        let backend = self.db.get_database_backend();
         let result = UserModel::find_by_statement(Statement::from_sql_and_values(
-                DatabaseBackend::Postgres,
-                r#"UPDATE users SET full_name = $1, updated_at = NOW() WHERE id = $2 AND is_deleted = false RETURNING *"#,
-                [full_name.into(), user_id.into()],
+                backend,
+                format!(
+                    r#"UPDATE users SET full_name = $1, preferred_locale = COALESCE($2, preferred_locale), updated_at = {} WHERE id = $3 AND is_deleted = false RETURNING *"#,
+                    sql::now(backend)
+                ),
+                [full_name.into(), preferred_locale.into(), user_id.into()],
             ))
             .one(&*self.db)
             .await
@@ -175,6 +239,183 @@ impl UserRepository for UserRepositoryPostgres {
     }
 }
 
+#[async_trait]
+impl TokenVersionRepository for UserRepositoryPostgres {
+    async fn current_version(&self, user_id: Uuid) -> Result<Option<i32>, UserRepositoryError> {
+        #[derive(FromQueryResult)]
+        struct VersionRow {
+            token_version: i32,
+        }
+
+        let row = VersionRow::find_by_statement(Statement::from_sql_and_values(
+            self.db.get_database_backend(),
+            r#"SELECT token_version FROM users WHERE id = $1"#,
+            [user_id.into()],
+        ))
+        .one(&*self.db)
+        .await
+        .map_err(|e| UserRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(|row| row.token_version))
+    }
+
+    async fn bump_version(&self, user_id: Uuid) -> Result<i32, UserRepositoryError> {
+        let backend = self.db.get_database_backend();
+        let result = UserModel::find_by_statement(Statement::from_sql_and_values(
+                backend,
+                format!(
+                    r#"UPDATE users SET token_version = token_version + 1, updated_at = {} WHERE id = $1 RETURNING *"#,
+                    sql::now(backend)
+                ),
+                [user_id.into()],
+            ))
+            .one(&*self.db)
+            .await
+            .map_err(|e| UserRepositoryError::DatabaseError(e.to_string()))?;
+
+        result
+            .map(|model| model.token_version)
+            .ok_or(UserRepositoryError::UserNotFound)
+    }
+}
+
+#[async_trait]
+impl AccountStatusRepository for UserRepositoryPostgres {
+    async fn set_active(&self, user_id: Uuid, active: bool) -> Result<(), UserRepositoryError> {
+        let backend = self.db.get_database_backend();
+        let result = self
+            .db
+            .execute(Statement::from_sql_and_values(
+                backend,
+                format!(
+                    r#"UPDATE users SET is_active = $2, updated_at = {} WHERE id = $1"#,
+                    sql::now(backend)
+                ),
+                [user_id.into(), active.into()],
+            ))
+            .await
+            .map_err(|e| UserRepositoryError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(UserRepositoryError::UserNotFound);
+        }
+        Ok(())
+    }
+}
+
+impl UserRepositoryPostgres {
+    fn find_profile_stmt(backend: sea_orm::DatabaseBackend, user_id: Uuid) -> Statement {
+        // An avatar trashed or deleted since it was chosen reads as none
+        Statement::from_sql_and_values(
+            backend,
+            r#"SELECT u.id, u.email, u.username, u.full_name, u.preferred_locale, u.timezone,
+                      m.id AS avatar_media_id
+               FROM users u
+               LEFT JOIN media m ON m.id = u.avatar_media_id AND m.deleted_at IS NULL
+               WHERE u.id = $1 AND u.is_deleted = false"#,
+            [user_id.into()],
+        )
+    }
+
+    fn to_profile(row: &QueryResult) -> Result<StoredUserProfile, DbErr> {
+        Ok(StoredUserProfile {
+            user_id: row.try_get("", "id")?,
+            email: row.try_get("", "email")?,
+            username: row.try_get("", "username")?,
+            full_name: row.try_get("", "full_name")?,
+            preferred_locale: row.try_get("", "preferred_locale")?,
+            timezone: row.try_get("", "timezone")?,
+            avatar_media_id: row.try_get("", "avatar_media_id")?,
+        })
+    }
+
+    fn profile_db_err(e: DbErr) -> UserProfileRepositoryError {
+        UserProfileRepositoryError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl UserProfileRepository for UserRepositoryPostgres {
+    async fn find_profile(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Option<StoredUserProfile>, UserProfileRepositoryError> {
+        self.db
+            .query_one(Self::find_profile_stmt(
+                self.db.get_database_backend(),
+                user_id,
+            ))
+            .await
+            .map_err(Self::profile_db_err)?
+            .map(|row| Self::to_profile(&row))
+            .transpose()
+            .map_err(Self::profile_db_err)
+    }
+
+    async fn update_profile_fields(
+        &self,
+        user_id: Uuid,
+        changes: UserProfileChanges,
+    ) -> Result<StoredUserProfile, UserProfileRepositoryError> {
+        let backend = self.db.get_database_backend();
+
+        if let PatchField::Value(media_id) = changes.avatar_media_id {
+            let owned = self
+                .db
+                .query_one(Statement::from_sql_and_values(
+                    backend,
+                    r#"SELECT 1 AS owned FROM media
+                       WHERE id = $1 AND user_id = $2 AND deleted_at IS NULL"#,
+                    [media_id.into(), user_id.into()],
+                ))
+                .await
+                .map_err(Self::profile_db_err)?;
+            if owned.is_none() {
+                return Err(UserProfileRepositoryError::AvatarNotFound);
+            }
+        }
+
+        let (set_avatar, avatar) = match changes.avatar_media_id {
+            PatchField::Unset => (false, None),
+            PatchField::Null => (true, None),
+            PatchField::Value(id) => (true, Some(id)),
+        };
+        let result = self
+            .db
+            .execute(Statement::from_sql_and_values(
+                backend,
+                format!(
+                    r#"UPDATE users
+                       SET full_name = COALESCE($2, full_name),
+                           preferred_locale = COALESCE($3, preferred_locale),
+                           timezone = COALESCE($4, timezone),
+                           avatar_media_id = CASE WHEN $5 THEN $6 ELSE avatar_media_id END,
+                           updated_at = {}
+                       WHERE id = $1 AND is_deleted = false"#,
+                    sql::now(backend)
+                ),
+                [
+                    user_id.into(),
+                    changes.full_name.into(),
+                    changes.preferred_locale.into(),
+                    changes.timezone.into(),
+                    set_avatar.into(),
+                    avatar.into(),
+                ],
+            ))
+            .await
+            .map_err(Self::profile_db_err)?;
+
+        if result.rows_affected() == 0 {
+            return Err(UserProfileRepositoryError::UserNotFound);
+        }
+
+        self.find_profile(user_id)
+            .await?
+            .ok_or(UserProfileRepositoryError::UserNotFound)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -188,6 +429,7 @@ mod tests {
             email: "test@example.com".to_string(),
             password_hash: "hashed_password".to_string(),
             full_name: "Gregor Brenkenstein".to_string(),
+            preferred_locale: "en".to_string(),
         }
     }
 
@@ -203,10 +445,17 @@ mod tests {
             email: "test@example.com".to_string(),
             password_hash: "hashedpassword".to_string(),
             full_name: "Test User".to_string(),
+            preferred_locale: "en".to_string(),
             created_at: to_fixed_offset(now),
             updated_at: to_fixed_offset(now),
             is_verified: false,
             is_deleted: false,
+            email_bounced_at: None,
+            email_unsubscribed_at: None,
+            token_version: 0,
+            is_active: true,
+            timezone: "UTC".to_string(),
+            avatar_media_id: None,
         }
     }
 
@@ -224,10 +473,17 @@ mod tests {
             email: user_data.email.clone(),
             password_hash: user_data.password_hash.clone(),
             full_name: user_data.full_name.clone(),
+            preferred_locale: "en".to_string(),
             created_at: curr_time.into(),
             updated_at: curr_time.into(),
             is_verified: false,
             is_deleted: false,
+            email_bounced_at: None,
+            email_unsubscribed_at: None,
+            token_version: 0,
+            is_active: true,
+            timezone: "UTC".to_string(),
+            avatar_media_id: None,
         };
 
         let db = MockDatabase::new(DatabaseBackend::Postgres)
@@ -291,6 +547,28 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_create_user_fails_when_outbox_insert_fails() {
+        let user_data = create_test_user_data();
+        let user_id = Uuid::new_v4();
+
+        let mock_db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![create_user_model(user_id)]])
+            .append_exec_errors([DbErr::Custom("outbox unavailable".to_string())])
+            .into_connection();
+
+        let repository = UserRepositoryPostgres::new(Arc::new(mock_db));
+
+        let result = repository.create_user(user_data).await;
+
+        match result.unwrap_err() {
+            UserRepositoryError::DatabaseError(msg) => {
+                assert!(msg.contains("outbox unavailable"));
+            }
+            _ => panic!("Expected DatabaseError variant"),
+        }
+    }
+
     // ==================== update_password tests ====================
 
     #[tokio::test]
@@ -582,10 +860,10 @@ mod tests {
         }
     }
 
-    // ==================== set_full_name tests ====================
+    // ==================== update_profile tests ====================
 
     #[tokio::test]
-    async fn test_set_full_name_success() {
+    async fn test_update_profile_success() {
         let user_id = Uuid::new_v4();
         let new_full_name = "Updated Name".to_string();
         let now = Utc::now();
@@ -596,10 +874,17 @@ mod tests {
             email: "test@example.com".to_string(),
             password_hash: "hashedpassword".to_string(),
             full_name: new_full_name.clone(),
+            preferred_locale: "id".to_string(),
             created_at: to_fixed_offset(now),
             updated_at: to_fixed_offset(now),
             is_verified: false,
             is_deleted: false,
+            email_bounced_at: None,
+            email_unsubscribed_at: None,
+            token_version: 0,
+            is_active: true,
+            timezone: "UTC".to_string(),
+            avatar_media_id: None,
         };
 
         let db = MockDatabase::new(DatabaseBackend::Postgres)
@@ -609,19 +894,20 @@ mod tests {
         let repository = UserRepositoryPostgres::new(Arc::new(db));
 
         let result = repository
-            .set_full_name(user_id, new_full_name.clone())
+            .update_profile(user_id, new_full_name.clone(), Some("id".to_string()))
             .await;
 
         assert!(result.is_ok());
         let updated_user = result.unwrap();
         assert_eq!(updated_user.id, user_id);
         assert_eq!(updated_user.full_name, new_full_name);
+        assert_eq!(updated_user.preferred_locale, "id");
         assert_eq!(updated_user.username, "testuser");
         assert_eq!(updated_user.email, "test@example.com");
     }
 
     #[tokio::test]
-    async fn test_set_full_name_not_found() {
+    async fn test_update_profile_not_found() {
         let user_id = Uuid::new_v4();
         let new_full_name = "Updated Name".to_string();
 
@@ -631,7 +917,9 @@ mod tests {
 
         let repository = UserRepositoryPostgres::new(Arc::new(db));
 
-        let result = repository.set_full_name(user_id, new_full_name).await;
+        let result = repository
+            .update_profile(user_id, new_full_name, None)
+            .await;
 
         assert!(matches!(
             result.unwrap_err(),
@@ -640,7 +928,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_set_full_name_database_error() {
+    async fn test_update_profile_database_error() {
         let user_id = Uuid::new_v4();
         let new_full_name = "Updated Name".to_string();
 
@@ -650,7 +938,9 @@ mod tests {
 
         let repository = UserRepositoryPostgres::new(Arc::new(db));
 
-        let result = repository.set_full_name(user_id, new_full_name).await;
+        let result = repository
+            .update_profile(user_id, new_full_name, None)
+            .await;
 
         assert!(result.is_err());
         match result.unwrap_err() {
@@ -661,6 +951,122 @@ mod tests {
         }
     }
 
+    // ==================== token version tests ====================
+
+    #[tokio::test]
+    async fn test_bump_version_returns_new_version() {
+        let user_id = Uuid::new_v4();
+        let mut model = create_user_model(user_id);
+        model.token_version = 3;
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![model]])
+            .into_connection();
+
+        let repository = UserRepositoryPostgres::new(Arc::new(db));
+
+        assert_eq!(repository.bump_version(user_id).await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_bump_version_user_not_found() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![Vec::<UserModel>::new()])
+            .into_connection();
+
+        let repository = UserRepositoryPostgres::new(Arc::new(db));
+
+        assert!(matches!(
+            repository.bump_version(Uuid::new_v4()).await.unwrap_err(),
+            UserRepositoryError::UserNotFound
+        ));
+    }
+
+    // ==================== account status tests ====================
+
+    #[tokio::test]
+    async fn test_set_active_success() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let repository = UserRepositoryPostgres::new(Arc::new(db));
+
+        assert!(repository.set_active(Uuid::new_v4(), false).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_set_active_user_not_found() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }])
+            .into_connection();
+
+        let repository = UserRepositoryPostgres::new(Arc::new(db));
+
+        assert!(matches!(
+            repository
+                .set_active(Uuid::new_v4(), true)
+                .await
+                .unwrap_err(),
+            UserRepositoryError::UserNotFound
+        ));
+    }
+
+    // ==================== profile settings tests ====================
+
+    #[tokio::test]
+    async fn test_update_profile_fields_rejects_foreign_avatar() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![Vec::<
+                std::collections::BTreeMap<String, sea_orm::Value>,
+            >::new()])
+            .into_connection();
+
+        let repository = UserRepositoryPostgres::new(Arc::new(db));
+        let changes = UserProfileChanges {
+            avatar_media_id: PatchField::Value(Uuid::new_v4()),
+            ..UserProfileChanges::default()
+        };
+
+        assert!(matches!(
+            repository
+                .update_profile_fields(Uuid::new_v4(), changes)
+                .await
+                .unwrap_err(),
+            UserProfileRepositoryError::AvatarNotFound
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_profile_fields_user_not_found() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }])
+            .into_connection();
+
+        let repository = UserRepositoryPostgres::new(Arc::new(db));
+        let changes = UserProfileChanges {
+            timezone: Some("Asia/Jakarta".to_string()),
+            ..UserProfileChanges::default()
+        };
+
+        assert!(matches!(
+            repository
+                .update_profile_fields(Uuid::new_v4(), changes)
+                .await
+                .unwrap_err(),
+            UserProfileRepositoryError::UserNotFound
+        ));
+    }
+
     // ==================== helper function tests ====================
 
     #[test]
@@ -674,5 +1080,6 @@ mod tests {
         assert_eq!(user_result.username, user_model.username);
         assert_eq!(user_result.email, user_model.email);
         assert_eq!(user_result.full_name, user_model.full_name);
+        assert_eq!(user_result.preferred_locale, user_model.preferred_locale);
     }
 }
@@ -0,0 +1,230 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use std::sync::Arc;
+use uuid::Uuid;
+use webauthn_rs::prelude::{
+    Passkey, PasskeyAuthentication, PasskeyRegistration, PublicKeyCredential,
+    RegisterPublicKeyCredential, Url, Webauthn, WebauthnBuilder,
+};
+
+use crate::auth::application::ports::outgoing::passkey_ceremony::{
+    Challenge, PasskeyCeremony, PasskeyCeremonyError, VerifiedLogin, VerifiedRegistration,
+};
+use crate::auth::application::ports::outgoing::passkey_repository::StoredPasskey;
+
+/// `PasskeyCeremony` by webauthn-rs, as the relying party `rp_id` (the
+/// site's domain) serving pages from `rp_origin`
+#[derive(Clone)]
+pub struct WebauthnCeremony {
+    webauthn: Arc<Webauthn>,
+}
+
+impl WebauthnCeremony {
+    pub fn new(rp_id: &str, rp_origin: &str) -> Result<Self, String> {
+        let origin = Url::parse(rp_origin).map_err(|e| format!("invalid origin: {e}"))?;
+        let webauthn = WebauthnBuilder::new(rp_id, &origin)
+            .and_then(|builder| builder.rp_name(rp_id).build())
+            .map_err(|e| e.to_string())?;
+
+        Ok(Self {
+            webauthn: Arc::new(webauthn),
+        })
+    }
+
+    fn credential_id(passkey: &Passkey) -> String {
+        let bytes: &[u8] = passkey.cred_id().as_ref();
+        URL_SAFE_NO_PAD.encode(bytes)
+    }
+
+    fn parse_passkeys(
+        passkeys: &[StoredPasskey],
+    ) -> Result<Vec<(Uuid, Passkey)>, PasskeyCeremonyError> {
+        passkeys
+            .iter()
+            .map(|stored| {
+                serde_json::from_str(&stored.credential)
+                    .map(|passkey| (stored.id, passkey))
+                    .map_err(|e| PasskeyCeremonyError::Internal(format!("stored passkey: {e}")))
+            })
+            .collect()
+    }
+
+    fn to_options(
+        options: impl serde::Serialize,
+    ) -> Result<serde_json::Value, PasskeyCeremonyError> {
+        serde_json::to_value(options).map_err(|e| PasskeyCeremonyError::Internal(e.to_string()))
+    }
+
+    fn to_state(state: impl serde::Serialize) -> Result<String, PasskeyCeremonyError> {
+        serde_json::to_string(&state).map_err(|e| PasskeyCeremonyError::Internal(e.to_string()))
+    }
+
+    fn from_state<T: serde::de::DeserializeOwned>(state: &str) -> Result<T, PasskeyCeremonyError> {
+        serde_json::from_str(state).map_err(|e| PasskeyCeremonyError::Internal(e.to_string()))
+    }
+
+    /// The browser's answer; malformed is the client's fault
+    fn from_response<T: serde::de::DeserializeOwned>(
+        response: &serde_json::Value,
+    ) -> Result<T, PasskeyCeremonyError> {
+        T::deserialize(response).map_err(|e| PasskeyCeremonyError::Rejected(e.to_string()))
+    }
+}
+
+impl PasskeyCeremony for WebauthnCeremony {
+    fn start_registration(
+        &self,
+        user_id: Uuid,
+        username: &str,
+        display_name: &str,
+        existing: &[StoredPasskey],
+    ) -> Result<Challenge, PasskeyCeremonyError> {
+        let exclude = Self::parse_passkeys(existing)?
+            .iter()
+            .map(|(_, passkey)| passkey.cred_id().clone())
+            .collect();
+
+        let (options, state) = self
+            .webauthn
+            .start_passkey_registration(user_id, username, display_name, Some(exclude))
+            .map_err(|e| PasskeyCeremonyError::Internal(e.to_string()))?;
+
+        Ok(Challenge {
+            options: Self::to_options(options)?,
+            state: Self::to_state(state)?,
+        })
+    }
+
+    fn finish_registration(
+        &self,
+        response: &serde_json::Value,
+        state: &str,
+    ) -> Result<VerifiedRegistration, PasskeyCeremonyError> {
+        let response: RegisterPublicKeyCredential = Self::from_response(response)?;
+        let state: PasskeyRegistration = Self::from_state(state)?;
+
+        let passkey = self
+            .webauthn
+            .finish_passkey_registration(&response, &state)
+            .map_err(|e| PasskeyCeremonyError::Rejected(e.to_string()))?;
+
+        Ok(VerifiedRegistration {
+            credential_id: Self::credential_id(&passkey),
+            credential: Self::to_state(&passkey)?,
+        })
+    }
+
+    fn start_login(&self, passkeys: &[StoredPasskey]) -> Result<Challenge, PasskeyCeremonyError> {
+        let passkeys: Vec<Passkey> = Self::parse_passkeys(passkeys)?
+            .into_iter()
+            .map(|(_, passkey)| passkey)
+            .collect();
+
+        let (options, state) = self
+            .webauthn
+            .start_passkey_authentication(&passkeys)
+            .map_err(|e| PasskeyCeremonyError::Internal(e.to_string()))?;
+
+        Ok(Challenge {
+            options: Self::to_options(options)?,
+            state: Self::to_state(state)?,
+        })
+    }
+
+    fn finish_login(
+        &self,
+        response: &serde_json::Value,
+        state: &str,
+        passkeys: &[StoredPasskey],
+    ) -> Result<VerifiedLogin, PasskeyCeremonyError> {
+        let response: PublicKeyCredential = Self::from_response(response)?;
+        let state: PasskeyAuthentication = Self::from_state(state)?;
+
+        // Only the credentials the challenge allowed verify, and those
+        // were the user's passkeys
+        let result = self
+            .webauthn
+            .finish_passkey_authentication(&response, &state)
+            .map_err(|e| PasskeyCeremonyError::Rejected(e.to_string()))?;
+
+        let (passkey_id, mut passkey) = Self::parse_passkeys(passkeys)?
+            .into_iter()
+            .find(|(_, passkey)| passkey.cred_id() == result.cred_id())
+            .ok_or_else(|| PasskeyCeremonyError::Rejected("passkey was removed".to_string()))?;
+
+        let updated_credential = match passkey.update_credential(&result) {
+            Some(true) => Some(Self::to_state(&passkey)?),
+            _ => None,
+        };
+
+        Ok(VerifiedLogin {
+            passkey_id,
+            updated_credential,
+        })
+    }
+}
+
+/// `PasskeyCeremony` when no relying party is configured; every ceremony
+/// fails with `Disabled`
+#[derive(Clone, Copy, Default)]
+pub struct DisabledPasskeys;
+
+impl PasskeyCeremony for DisabledPasskeys {
+    fn enabled(&self) -> bool {
+        false
+    }
+
+    fn start_registration(
+        &self,
+        _user_id: Uuid,
+        _username: &str,
+        _display_name: &str,
+        _existing: &[StoredPasskey],
+    ) -> Result<Challenge, PasskeyCeremonyError> {
+        Err(PasskeyCeremonyError::Disabled)
+    }
+
+    fn finish_registration(
+        &self,
+        _response: &serde_json::Value,
+        _state: &str,
+    ) -> Result<VerifiedRegistration, PasskeyCeremonyError> {
+        Err(PasskeyCeremonyError::Disabled)
+    }
+
+    fn start_login(&self, _passkeys: &[StoredPasskey]) -> Result<Challenge, PasskeyCeremonyError> {
+        Err(PasskeyCeremonyError::Disabled)
+    }
+
+    fn finish_login(
+        &self,
+        _response: &serde_json::Value,
+        _state: &str,
+        _passkeys: &[StoredPasskey],
+    ) -> Result<VerifiedLogin, PasskeyCeremonyError> {
+        Err(PasskeyCeremonyError::Disabled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_origin_must_be_a_url_of_the_rp_id() {
+        assert!(WebauthnCeremony::new("example.com", "https://example.com").is_ok());
+        assert!(WebauthnCeremony::new("example.com", "not a url").is_err());
+    }
+
+    #[test]
+    fn test_registration_options_carry_the_challenge() {
+        let ceremony = WebauthnCeremony::new("example.com", "https://example.com").unwrap();
+
+        let challenge = ceremony
+            .start_registration(Uuid::new_v4(), "jane@example.com", "Jane", &[])
+            .unwrap();
+
+        assert!(challenge.options["publicKey"]["challenge"].is_string());
+        assert_eq!(challenge.options["publicKey"]["rp"]["id"], "example.com");
+        assert!(!challenge.state.is_empty());
+    }
+}
@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use crate::auth::application::helpers::TokenVersionGuard;
+use crate::auth::application::orchestrator::user_registration::UserRegistrationOrchestrator;
+use crate::auth::application::ports::outgoing::password_hasher::PasswordHasher;
+use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+use crate::auth::application::ports::outgoing::token_repository::TokenRepository;
+use crate::auth::application::ports::outgoing::{
+    AccountStatusRepository, ConsumedTokenRepository, UserProfileRepository, UserQuery,
+    UserRepository,
+};
+use crate::auth::application::services::{
+    FetchUserProfileService, GetUserProfileService, PatchUserProfileService,
+    UpdateUserProfileService,
+};
+use crate::auth::application::use_cases::{
+    create_user::CreateUserUseCase,
+    deactivate_account::{DeactivateAccountUseCase, IDeactivateAccountUseCase},
+    fetch_profile::FetchUserProfileUseCase,
+    introspect_token::{IIntrospectTokenUseCase, IntrospectTokenUseCase},
+    lockdown_user_content::{ILockdownUserContentUseCase, LockdownUserContentUseCase},
+    login_user::{ILoginUserUseCase, LoginUserUseCase},
+    logout_all::{ILogoutAllUseCase, LogoutAllUseCase},
+    logout_user::{ILogoutUseCase, LogoutUseCase},
+    reactivate_account::{IReactivateAccountUseCase, ReactivateAccountUseCase},
+    refresh_token::{IRefreshTokenUseCase, RefreshTokenUseCase},
+    soft_delete_user::{ISoftDeleteUserUseCase, SoftDeleteUserUseCase},
+    update_profile::UpdateUserProfileUseCase,
+    user_profile::{GetUserProfileUseCase, PatchUserProfileUseCase},
+    verify_user_email::{IVerifyUserEmailUseCase, VerifyUserEmailUseCase},
+};
+use crate::shared::cache::CachePort;
+use crate::shared::metrics::Metered;
+use crate::shared::unit_of_work::UnitOfWork;
+
+#[derive(Clone)]
+pub struct AuthUseCases {
+    pub register: Arc<UserRegistrationOrchestrator>,
+    pub verify_email: Arc<dyn IVerifyUserEmailUseCase + Send + Sync>,
+    pub login: Arc<dyn ILoginUserUseCase + Send + Sync>,
+    pub refresh_token: Arc<dyn IRefreshTokenUseCase + Send + Sync>,
+    pub logout: Arc<dyn ILogoutUseCase + Send + Sync>,
+    pub logout_all: Arc<dyn ILogoutAllUseCase + Send + Sync>,
+    pub soft_delete_user: Arc<dyn ISoftDeleteUserUseCase + Send + Sync>,
+    pub lockdown_user_content: Arc<dyn ILockdownUserContentUseCase + Send + Sync>,
+    pub deactivate_account: Arc<dyn IDeactivateAccountUseCase + Send + Sync>,
+    pub reactivate_account: Arc<dyn IReactivateAccountUseCase + Send + Sync>,
+    pub fetch_profile: Arc<dyn FetchUserProfileUseCase + Send + Sync>,
+    pub update_profile: Arc<dyn UpdateUserProfileUseCase + Send + Sync>,
+    pub get_profile: Arc<dyn GetUserProfileUseCase + Send + Sync>,
+    pub patch_profile: Arc<dyn PatchUserProfileUseCase + Send + Sync>,
+    pub introspect_token: Arc<dyn IIntrospectTokenUseCase + Send + Sync>,
+}
+
+impl AuthUseCases {
+    /// `users` is the user store, which also keeps the account status and
+    /// profile settings.
+    /// `token_version_guard` must be the one the request extractors use, and
+    /// `cache` the one the public content endpoints read through.
+    /// `unit_of_work` must cover `users` and `consumed_tokens`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn build<Q, R, T, U>(
+        user_query: Q,
+        users: R,
+        tokens: T,
+        consumed_tokens: Arc<dyn ConsumedTokenRepository>,
+        password_hasher: Arc<dyn PasswordHasher>,
+        token_provider: Arc<dyn TokenProvider>,
+        token_version_guard: TokenVersionGuard,
+        cache: Arc<dyn CachePort>,
+        unit_of_work: U,
+    ) -> Self
+    where
+        Q: UserQuery + Clone + 'static,
+        R: UserRepository + AccountStatusRepository + UserProfileRepository + Clone + 'static,
+        T: TokenRepository + Clone + 'static,
+        U: UnitOfWork + 'static,
+    {
+        let create_user = CreateUserUseCase::new(
+            user_query.clone(),
+            users.clone(),
+            Arc::clone(&password_hasher),
+        );
+
+        Self {
+            register: Arc::new(UserRegistrationOrchestrator::new(Arc::new(Metered(
+                create_user,
+            )))),
+            verify_email: Arc::new(Metered(VerifyUserEmailUseCase::new(
+                users.clone(),
+                Arc::clone(&token_provider),
+                consumed_tokens,
+                unit_of_work,
+            ))),
+            login: Arc::new(Metered(LoginUserUseCase::new(
+                user_query.clone(),
+                Arc::clone(&password_hasher),
+                Arc::clone(&token_provider),
+            ))),
+            refresh_token: Arc::new(Metered(
+                RefreshTokenUseCase::new(Arc::clone(&token_provider))
+                    .with_token_version_guard(token_version_guard.clone()),
+            )),
+            logout: Arc::new(Metered(LogoutUseCase::new(
+                tokens.clone(),
+                Arc::clone(&token_provider),
+            ))),
+            logout_all: Arc::new(Metered(LogoutAllUseCase::new(token_version_guard.clone()))),
+            soft_delete_user: Arc::new(Metered(SoftDeleteUserUseCase::new(
+                users.clone(),
+                tokens.clone(),
+            ))),
+            lockdown_user_content: Arc::new(Metered(LockdownUserContentUseCase::new(
+                token_version_guard.clone(),
+                cache,
+            ))),
+            deactivate_account: Arc::new(Metered(DeactivateAccountUseCase::new(
+                Arc::new(users.clone()),
+                token_version_guard.clone(),
+            ))),
+            reactivate_account: Arc::new(Metered(ReactivateAccountUseCase::new(
+                user_query.clone(),
+                password_hasher,
+                Arc::new(users.clone()),
+            ))),
+            fetch_profile: Arc::new(Metered(FetchUserProfileService::new(user_query))),
+            get_profile: Arc::new(Metered(GetUserProfileService::new(users.clone()))),
+            patch_profile: Arc::new(Metered(PatchUserProfileService::new(users.clone()))),
+            update_profile: Arc::new(Metered(UpdateUserProfileService::new(users))),
+            introspect_token: Arc::new(Metered(IntrospectTokenUseCase::new(
+                tokens,
+                token_provider,
+                token_version_guard,
+            ))),
+        }
+    }
+}
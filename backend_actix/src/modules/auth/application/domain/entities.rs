@@ -1,8 +1,9 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
 pub struct UserId(Uuid);
 
 impl UserId {
@@ -23,6 +24,62 @@ impl From<UserId> for Uuid {
     }
 }
 
+/// Locale for users who have not chosen one, and the email fallback
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Normalize a locale tag of the form `ll` or `ll-RR` (`_` also accepted),
+/// e.g. `pt_br` becomes `pt-BR`. Anything else is `None`.
+pub fn normalize_locale(raw: &str) -> Option<String> {
+    let raw = raw.trim();
+    let (language, region) = match raw.split_once(['-', '_']) {
+        Some((language, region)) => (language, Some(region)),
+        None => (raw, None),
+    };
+
+    let is_alpha =
+        |part: &str, len: usize| part.len() == len && part.chars().all(|c| c.is_ascii_alphabetic());
+    if !is_alpha(language, 2) || region.is_some_and(|region| !is_alpha(region, 2)) {
+        return None;
+    }
+
+    let language = language.to_ascii_lowercase();
+    Some(match region {
+        Some(region) => format!("{language}-{}", region.to_ascii_uppercase()),
+        None => language,
+    })
+}
+
+/// Timezone for users who have not chosen one
+pub const DEFAULT_TIMEZONE: &str = "UTC";
+
+/// Whether `raw` looks like an IANA timezone name, e.g. `UTC` or
+/// `America/Argentina/Buenos_Aires`. Only the shape is checked; there is no
+/// timezone database to look names up in.
+pub fn is_timezone_name(raw: &str) -> bool {
+    let is_part = |part: &str| {
+        part.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+'))
+    };
+    raw.len() <= 64 && raw.split('/').all(is_part)
+}
+
+/// Trimmed full name, or why it is refused
+pub fn normalize_full_name(raw: &str) -> Result<String, &'static str> {
+    let trimmed = raw.trim();
+
+    if trimmed.is_empty() {
+        return Err("Full name cannot be empty");
+    }
+
+    if trimmed.len() < 2 || trimmed.len() > 100 {
+        return Err("Full name must be 2-100 characters");
+    }
+
+    Ok(trimmed.to_string())
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct User {
     pub id: Uuid,
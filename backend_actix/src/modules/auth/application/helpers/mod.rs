@@ -1,2 +1,4 @@
+mod token_version_guard;
 mod user_identity_resolver;
+pub use token_version_guard::TokenVersionGuard;
 pub use user_identity_resolver::{ResolveUserIdError, UserIdentityResolver};
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::application::ports::outgoing::{TokenVersionRepository, UserRepositoryError};
+use crate::shared::cache::{self, CachePort};
+
+fn cache_key(user_id: Uuid) -> String {
+    format!("cache:auth:token_version:{}", user_id)
+}
+
+/// Decides whether a token's `token_version` claim is still honoured.
+///
+/// Every authenticated request asks, so the current version is read through
+/// the cache; [`revoke_all`](Self::revoke_all) invalidates it along with the
+/// bump.
+#[derive(Clone)]
+pub struct TokenVersionGuard {
+    repository: Arc<dyn TokenVersionRepository + Send + Sync>,
+    cache: Arc<dyn CachePort>,
+}
+
+impl TokenVersionGuard {
+    pub fn new(
+        repository: Arc<dyn TokenVersionRepository + Send + Sync>,
+        cache: Arc<dyn CachePort>,
+    ) -> Self {
+        Self { repository, cache }
+    }
+
+    /// Tokens of users that no longer exist are never current
+    pub async fn is_current(
+        &self,
+        user_id: Uuid,
+        token_version: i32,
+    ) -> Result<bool, UserRepositoryError> {
+        let current = cache::read_through(self.cache.as_ref(), &cache_key(user_id), || {
+            self.repository.current_version(user_id)
+        })
+        .await?;
+
+        Ok(current == Some(token_version))
+    }
+
+    /// Revokes every token issued to the user so far
+    pub async fn revoke_all(&self, user_id: Uuid) -> Result<(), UserRepositoryError> {
+        self.repository.bump_version(user_id).await?;
+        cache::invalidate(self.cache.as_ref(), &cache_key(user_id)).await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::adapter::outgoing::in_memory::InMemoryUserStore;
+    use crate::auth::application::ports::outgoing::user_repository::{
+        CreateUserData, UserRepository,
+    };
+    use crate::tests::support::stubs::InMemoryCache;
+
+    async fn guard_with_user() -> (TokenVersionGuard, Uuid) {
+        let store = InMemoryUserStore::default();
+        let user = store
+            .create_user(CreateUserData {
+                email: "jane@example.com".to_string(),
+                username: "jane".to_string(),
+                password_hash: "hash".to_string(),
+                full_name: "Jane Doe".to_string(),
+                preferred_locale: "en".to_string(),
+            })
+            .await
+            .unwrap();
+
+        let guard = TokenVersionGuard::new(Arc::new(store), Arc::new(InMemoryCache::default()));
+        (guard, user.id)
+    }
+
+    #[tokio::test]
+    async fn test_revoke_all_retires_cached_version() {
+        let (guard, user_id) = guard_with_user().await;
+        assert!(guard.is_current(user_id, 0).await.unwrap());
+
+        guard.revoke_all(user_id).await.unwrap();
+
+        assert!(!guard.is_current(user_id, 0).await.unwrap());
+        assert!(guard.is_current(user_id, 1).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_user_is_never_current() {
+        let (guard, _) = guard_with_user().await;
+
+        assert!(!guard.is_current(Uuid::new_v4(), 0).await.unwrap());
+    }
+}
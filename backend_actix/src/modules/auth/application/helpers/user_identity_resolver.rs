@@ -22,9 +22,11 @@ impl UserIdentityResolver {
         Self { user_query }
     }
 
+    /// Deleted and deactivated accounts are `NotFound`, which hides their
+    /// content from every public endpoint
     pub async fn by_username(&self, username: &str) -> Result<UserId, ResolveUserIdError> {
         match self.user_query.find_by_username(username).await {
-            Ok(Some(user)) if !user.is_deleted => Ok(UserId::from(user.id)),
+            Ok(Some(user)) if !user.is_deleted && user.is_active => Ok(UserId::from(user.id)),
             Ok(_) => Err(ResolveUserIdError::NotFound),
             Err(UserQueryError::DatabaseError(msg)) | Err(UserQueryError::QueryFailed(msg)) => {
                 Err(ResolveUserIdError::RepositoryError(msg))
@@ -104,10 +106,13 @@ mod tests {
             username: "testuser".to_string(),
             password_hash: "hashed".to_string(),
             full_name: "Test User".to_string(),
+            preferred_locale: "en".to_string(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
             is_verified: true,
             is_deleted: deleted,
+            token_version: 0,
+            is_active: true,
         }
     }
 
@@ -151,6 +156,18 @@ mod tests {
         assert!(matches!(result, Err(ResolveUserIdError::NotFound)));
     }
 
+    #[tokio::test]
+    async fn returns_not_found_when_user_is_deactivated() {
+        let mut user = sample_user(Uuid::new_v4(), false);
+        user.is_active = false;
+
+        let resolver = UserIdentityResolver::new(Arc::new(MockUserQuery::found(user)));
+
+        let result = resolver.by_username("testuser").await;
+
+        assert!(matches!(result, Err(ResolveUserIdError::NotFound)));
+    }
+
     #[tokio::test]
     async fn maps_database_error_to_repository_error() {
         let query = MockUserQuery::error(UserQueryError::DatabaseError("db down".to_string()));
@@ -1,6 +1,8 @@
+pub mod auth_use_cases;
 pub mod domain;
 pub mod helpers;
 pub mod orchestrator;
+pub mod passkey_use_cases;
 pub mod ports;
 pub mod services;
 pub mod use_cases;
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+use crate::auth::application::ports::outgoing::{
+    PasskeyCeremony, PasskeyChallengeStore, PasskeyRepository, UserQuery,
+};
+use crate::auth::application::use_cases::passkeys::{
+    delete_passkey::{DeletePasskeyUseCase, IDeletePasskeyUseCase},
+    finish_passkey_login::{FinishPasskeyLoginUseCase, IFinishPasskeyLoginUseCase},
+    finish_passkey_registration::{
+        FinishPasskeyRegistrationUseCase, IFinishPasskeyRegistrationUseCase,
+    },
+    list_passkeys::{IListPasskeysUseCase, ListPasskeysUseCase},
+    start_passkey_login::{IStartPasskeyLoginUseCase, StartPasskeyLoginUseCase},
+    start_passkey_registration::{
+        IStartPasskeyRegistrationUseCase, StartPasskeyRegistrationUseCase,
+    },
+};
+use crate::shared::metrics::Metered;
+
+#[derive(Clone)]
+pub struct PasskeyUseCases {
+    pub start_registration: Arc<dyn IStartPasskeyRegistrationUseCase + Send + Sync>,
+    pub finish_registration: Arc<dyn IFinishPasskeyRegistrationUseCase + Send + Sync>,
+    pub list: Arc<dyn IListPasskeysUseCase + Send + Sync>,
+    pub delete: Arc<dyn IDeletePasskeyUseCase + Send + Sync>,
+    pub start_login: Arc<dyn IStartPasskeyLoginUseCase + Send + Sync>,
+    pub finish_login: Arc<dyn IFinishPasskeyLoginUseCase + Send + Sync>,
+}
+
+impl PasskeyUseCases {
+    /// `ceremony` is `DisabledPasskeys` when no relying party is configured
+    pub fn build(
+        users: Arc<dyn UserQuery>,
+        passkeys: Arc<dyn PasskeyRepository>,
+        challenges: Arc<dyn PasskeyChallengeStore>,
+        ceremony: Arc<dyn PasskeyCeremony>,
+        token_provider: Arc<dyn TokenProvider>,
+    ) -> Self {
+        Self {
+            start_registration: Arc::new(Metered(StartPasskeyRegistrationUseCase::new(
+                Arc::clone(&users),
+                Arc::clone(&passkeys),
+                Arc::clone(&challenges),
+                Arc::clone(&ceremony),
+            ))),
+            finish_registration: Arc::new(Metered(FinishPasskeyRegistrationUseCase::new(
+                Arc::clone(&passkeys),
+                Arc::clone(&challenges),
+                Arc::clone(&ceremony),
+            ))),
+            list: Arc::new(Metered(ListPasskeysUseCase::new(Arc::clone(&passkeys)))),
+            delete: Arc::new(Metered(DeletePasskeyUseCase::new(Arc::clone(&passkeys)))),
+            start_login: Arc::new(Metered(StartPasskeyLoginUseCase::new(
+                Arc::clone(&users),
+                Arc::clone(&passkeys),
+                Arc::clone(&challenges),
+                Arc::clone(&ceremony),
+            ))),
+            finish_login: Arc::new(Metered(FinishPasskeyLoginUseCase::new(
+                users,
+                passkeys,
+                challenges,
+                ceremony,
+                token_provider,
+            ))),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+// application/ports/outgoing/account_status_repository.rs
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::user_repository::UserRepositoryError;
+
+/// Temporary deactivation, which unlike deletion the owner can undo. A
+/// deactivated account can't log in and its content is hidden from public
+/// endpoints.
+#[async_trait]
+pub trait AccountStatusRepository: Send + Sync {
+    /// `UserNotFound` if the user does not exist
+    async fn set_active(&self, user_id: Uuid, active: bool) -> Result<(), UserRepositoryError>;
+}
@@ -0,0 +1,20 @@
+// application/ports/outgoing/consumed_token_repository.rs
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::token_repository::TokenRepositoryError;
+
+/// The `jti`s of single-use tokens that have been used
+#[async_trait]
+pub trait ConsumedTokenRepository: Send + Sync {
+    /// Records `jti` as used, atomically; `false` if it already was. The
+    /// record may be dropped once `expires_at` has passed, since the token
+    /// is refused as expired from then on.
+    async fn consume(
+        &self,
+        jti: &str,
+        user_id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, TokenRepositoryError>;
+}
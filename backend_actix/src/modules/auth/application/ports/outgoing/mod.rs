@@ -1,7 +1,21 @@
+pub mod account_status_repository;
+pub mod consumed_token_repository;
+pub mod passkey_ceremony;
+pub mod passkey_challenge_store;
+pub mod passkey_repository;
 pub mod token_repository;
+pub mod token_version_repository;
+pub mod user_profile_repository;
 pub mod user_query;
 pub mod user_repository;
 
+pub use account_status_repository::AccountStatusRepository;
+pub use consumed_token_repository::ConsumedTokenRepository;
+pub use passkey_ceremony::PasskeyCeremony;
+pub use passkey_challenge_store::PasskeyChallengeStore;
+pub use passkey_repository::PasskeyRepository;
+pub use token_version_repository::TokenVersionRepository;
+pub use user_profile_repository::UserProfileRepository;
 pub use user_query::UserQuery;
 pub use user_repository::{UserRepository, UserRepositoryError};
 
@@ -0,0 +1,74 @@
+// application/ports/outgoing/passkey_ceremony.rs
+use uuid::Uuid;
+
+use super::passkey_repository::StoredPasskey;
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum PasskeyCeremonyError {
+    /// No relying party configured (`WEBAUTHN_RP_ID`)
+    #[error("Passkeys are not enabled")]
+    Disabled,
+
+    /// The browser's answer doesn't verify against the challenge
+    #[error("Passkey verification failed: {0}")]
+    Rejected(String),
+
+    #[error("Passkey ceremony failed: {0}")]
+    Internal(String),
+}
+
+/// A ceremony started: what the browser needs and what the server keeps
+#[derive(Debug, Clone)]
+pub struct Challenge {
+    /// Options for `navigator.credentials.create()` or `.get()`
+    pub options: serde_json::Value,
+    /// Kept server side until the answer comes back
+    pub state: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifiedRegistration {
+    pub credential_id: String,
+    pub credential: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifiedLogin {
+    /// Which of the user's passkeys signed
+    pub passkey_id: Uuid,
+    /// The passkey's credential with its new counter, when it moved
+    pub updated_credential: Option<String>,
+}
+
+/// The WebAuthn relying party: issues challenges and verifies what
+/// authenticators sign
+pub trait PasskeyCeremony: Send + Sync {
+    /// `false` when no relying party is configured
+    fn enabled(&self) -> bool {
+        true
+    }
+
+    fn start_registration(
+        &self,
+        user_id: Uuid,
+        username: &str,
+        display_name: &str,
+        existing: &[StoredPasskey],
+    ) -> Result<Challenge, PasskeyCeremonyError>;
+
+    fn finish_registration(
+        &self,
+        response: &serde_json::Value,
+        state: &str,
+    ) -> Result<VerifiedRegistration, PasskeyCeremonyError>;
+
+    fn start_login(&self, passkeys: &[StoredPasskey]) -> Result<Challenge, PasskeyCeremonyError>;
+
+    /// `passkeys` are the same user's, as given to `start_login`
+    fn finish_login(
+        &self,
+        response: &serde_json::Value,
+        state: &str,
+        passkeys: &[StoredPasskey],
+    ) -> Result<VerifiedLogin, PasskeyCeremonyError>;
+}
@@ -0,0 +1,40 @@
+// application/ports/outgoing/passkey_challenge_store.rs
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::passkey_repository::PasskeyStoreError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CeremonyKind {
+    Registration,
+    Login,
+}
+
+/// A passkey ceremony waiting for the browser's answer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingCeremony {
+    pub kind: CeremonyKind,
+    pub user_id: Uuid,
+    /// From `PasskeyCeremony`; holds the challenge the answer must sign
+    pub state: String,
+}
+
+/// Ceremonies between their start and finish requests, which can land on
+/// different instances
+#[async_trait]
+pub trait PasskeyChallengeStore: Send + Sync {
+    async fn put(
+        &self,
+        challenge_id: Uuid,
+        ceremony: PendingCeremony,
+        ttl: Duration,
+    ) -> Result<(), PasskeyStoreError>;
+
+    /// Removes and returns the ceremony, so each challenge is answered at
+    /// most once; `None` if unknown or expired
+    async fn take(&self, challenge_id: Uuid) -> Result<Option<PendingCeremony>, PasskeyStoreError>;
+}
@@ -0,0 +1,55 @@
+// application/ports/outgoing/passkey_repository.rs
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum PasskeyStoreError {
+    /// The authenticator's credential is registered already, to anyone
+    #[error("Passkey already registered")]
+    AlreadyRegistered,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredPasskey {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    /// The authenticator's id for the credential, base64url
+    pub credential_id: String,
+    /// Public key and signature counter, as `PasskeyCeremony` serialized them
+    pub credential: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewPasskey {
+    pub user_id: Uuid,
+    pub name: String,
+    pub credential_id: String,
+    pub credential: String,
+}
+
+/// Users' passkeys, the `webauthn_credentials` table
+#[async_trait]
+pub trait PasskeyRepository: Send + Sync {
+    async fn add(&self, passkey: NewPasskey) -> Result<StoredPasskey, PasskeyStoreError>;
+
+    /// Oldest first
+    async fn list_for_user(&self, user_id: Uuid) -> Result<Vec<StoredPasskey>, PasskeyStoreError>;
+
+    /// Stamps `last_used_at`, replacing the credential when the sign-in
+    /// moved its counter
+    async fn record_use(
+        &self,
+        passkey_id: Uuid,
+        credential: Option<String>,
+    ) -> Result<(), PasskeyStoreError>;
+
+    /// `false` if the user has no such passkey
+    async fn delete(&self, user_id: Uuid, passkey_id: Uuid) -> Result<bool, PasskeyStoreError>;
+}
@@ -9,6 +9,8 @@ pub enum TokenError {
     TokenNotYetValid,
     InvalidTokenType(String),
     InvalidSignature,
+    /// Issued by another environment, or for another audience
+    WrongIssuerOrAudience,
     MalformedToken,
     EncodingError(String),
 }
@@ -22,6 +24,7 @@ impl fmt::Display for TokenError {
                 write!(f, "Invalid token type, expected: {}", expected)
             }
             TokenError::InvalidSignature => write!(f, "Invalid token signature"),
+            TokenError::WrongIssuerOrAudience => write!(f, "Token issuer or audience mismatch"),
             TokenError::MalformedToken => write!(f, "Malformed token"),
             TokenError::EncodingError(msg) => write!(f, "Token encoding error: {}", msg),
         }
@@ -33,23 +36,45 @@ impl Error for TokenError {}
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TokenClaims {
     pub sub: Uuid,          // User ID
+    pub iss: String,        // `JwtConfig::issuer` of the issuing server
+    pub aud: String,        // `JwtConfig::audience` the token is meant for
     pub exp: i64,           // Expiration timestamp
     pub iat: i64,           // Issued at timestamp - ADD THIS
     pub nbf: i64,           // Not before timestamp - ADD THIS
     pub token_type: String, // "access", "refresh", or "verification"
     pub is_verified: bool,  // User verification status
+    /// The user's `token_version` at issue; tokens from before it existed
+    /// have none and count as 0
+    #[serde(default)]
+    pub token_version: i32,
+    /// Refresh tokens only: issued for "remember me", with the long expiry.
+    /// Carried over when the token is rotated.
+    #[serde(default)]
+    pub remember_me: bool,
+    /// Single-use tokens only (verification): recorded once consumed, so a
+    /// replayed token is refused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jti: Option<String>,
 }
 
 pub trait TokenProvider: Send + Sync {
-    fn generate_access_token(&self, user_id: Uuid, is_verified: bool)
-        -> Result<String, TokenError>;
+    fn generate_access_token(
+        &self,
+        user_id: Uuid,
+        is_verified: bool,
+        token_version: i32,
+    ) -> Result<String, TokenError>;
+    /// `remember_me` picks the long expiry over the default one
     fn generate_refresh_token(
         &self,
         user_id: Uuid,
         is_verified: bool,
+        token_version: i32,
+        remember_me: bool,
     ) -> Result<String, TokenError>;
     fn verify_token(&self, token: &str) -> Result<TokenClaims, TokenError>;
     fn refresh_access_token(&self, refresh_token: &str) -> Result<String, TokenError>;
     fn generate_verification_token(&self, user_id: Uuid) -> Result<String, TokenError>;
-    fn verify_verification_token(&self, token: &str) -> Result<Uuid, TokenError>;
+    /// The claims of a valid verification token, including its `jti`
+    fn verify_verification_token(&self, token: &str) -> Result<TokenClaims, TokenError>;
 }
@@ -0,0 +1,17 @@
+// application/ports/outgoing/token_version_repository.rs
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::user_repository::UserRepositoryError;
+
+/// The per-user counter embedded in every token pair. Tokens carrying an
+/// older value than the user's current one are revoked.
+#[async_trait]
+pub trait TokenVersionRepository: Send + Sync {
+    /// `None` if the user does not exist
+    async fn current_version(&self, user_id: Uuid) -> Result<Option<i32>, UserRepositoryError>;
+
+    /// Increments the counter, revoking every token issued so far, and
+    /// returns the new value
+    async fn bump_version(&self, user_id: Uuid) -> Result<i32, UserRepositoryError>;
+}
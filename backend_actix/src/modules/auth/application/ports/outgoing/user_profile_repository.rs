@@ -0,0 +1,57 @@
+// application/ports/outgoing/user_profile_repository.rs
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::project::application::ports::outgoing::project_repository::PatchField;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredUserProfile {
+    pub user_id: Uuid,
+    pub email: String,
+    pub username: String,
+    pub full_name: String,
+    pub preferred_locale: String,
+    pub timezone: String,
+    /// `None` as well when the avatar's media was trashed or deleted
+    pub avatar_media_id: Option<Uuid>,
+}
+
+/// Fields left `None` (or `Unset`) keep their value. Values are validated
+/// by the caller.
+#[derive(Debug, Clone, Default)]
+pub struct UserProfileChanges {
+    pub full_name: Option<String>,
+    pub preferred_locale: Option<String>,
+    pub timezone: Option<String>,
+    pub avatar_media_id: PatchField<Uuid>,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum UserProfileRepositoryError {
+    #[error("User not found")]
+    UserNotFound,
+
+    /// Not one of the user's own live media
+    #[error("Avatar media not found")]
+    AvatarNotFound,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// The profile settings a user edits themselves, on their `users` row.
+/// Deleted users have no profile.
+#[async_trait]
+pub trait UserProfileRepository: Send + Sync {
+    async fn find_profile(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Option<StoredUserProfile>, UserProfileRepositoryError>;
+
+    /// Applies `changes` and returns the profile as saved
+    async fn update_profile_fields(
+        &self,
+        user_id: Uuid,
+        changes: UserProfileChanges,
+    ) -> Result<StoredUserProfile, UserProfileRepositoryError>;
+}
@@ -12,10 +12,15 @@ pub struct UserQueryResult {
     pub username: String,
     pub password_hash: String,
     pub full_name: String,
+    pub preferred_locale: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub is_verified: bool,
     pub is_deleted: bool,
+    /// Tokens issued under an older version are revoked
+    pub token_version: i32,
+    /// Deactivated accounts can't log in and are hidden from the public
+    pub is_active: bool,
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -9,6 +9,7 @@ pub struct CreateUserData {
     pub username: String,
     pub password_hash: String,
     pub full_name: String,
+    pub preferred_locale: String,
 }
 
 // Unified output DTO for all user operations that return user data
@@ -19,6 +20,7 @@ pub struct UserResult {
     pub email: String,
     pub username: String,
     pub full_name: String,
+    pub preferred_locale: String,
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -36,13 +38,16 @@ pub enum UserRepositoryError {
 #[async_trait]
 pub trait UserRepository: Send + Sync {
     // Operations that confirm the new state by returning user data
+    /// Also queues the new user's verification email, atomically with the insert
     async fn create_user(&self, data: CreateUserData) -> Result<UserResult, UserRepositoryError>;
     async fn restore_user(&self, user_id: Uuid) -> Result<UserResult, UserRepositoryError>;
     async fn activate_user(&self, user_id: Uuid) -> Result<UserResult, UserRepositoryError>;
-    async fn set_full_name(
+    /// Sets the full name, and the preferred locale when given
+    async fn update_profile(
         &self,
         user_id: Uuid,
         full_name: String,
+        preferred_locale: Option<String>,
     ) -> Result<UserResult, UserRepositoryError>;
 
     // Operations that don't need to return user data (pure commands)
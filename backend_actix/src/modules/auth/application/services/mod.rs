@@ -2,5 +2,7 @@ pub mod password;
 mod user_profile;
 
 pub use user_profile::{
-    fetch_user::FetchUserProfileService, update_profile::UpdateUserProfileService,
+    fetch_user::FetchUserProfileService,
+    profile_settings::{GetUserProfileService, PatchUserProfileService},
+    update_profile::UpdateUserProfileService,
 };
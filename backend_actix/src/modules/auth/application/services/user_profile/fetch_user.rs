@@ -39,6 +39,7 @@ where
             email: user.email,
             username: user.username,
             full_name: user.full_name,
+            preferred_locale: user.preferred_locale,
         })
     }
 }
@@ -86,10 +87,13 @@ mod tests {
             username: "testuser".to_string(),
             password_hash: "hashed".to_string(),
             full_name: "Test User".to_string(),
+            preferred_locale: "en".to_string(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
             is_verified: true,
             is_deleted: false,
+            token_version: 0,
+            is_active: true,
         }
     }
 
@@ -1,2 +1,3 @@
 pub mod fetch_user;
+pub mod profile_settings;
 pub mod update_profile;
@@ -0,0 +1,244 @@
+use async_trait::async_trait;
+
+use crate::auth::application::{
+    domain::entities::{is_timezone_name, normalize_full_name, normalize_locale, UserId},
+    ports::outgoing::user_profile_repository::{UserProfileChanges, UserProfileRepository},
+    use_cases::user_profile::{
+        GetUserProfileUseCase, PatchUserProfileUseCase, UserProfile, UserProfileError,
+        UserProfilePatch,
+    },
+};
+
+pub struct GetUserProfileService<R>
+where
+    R: UserProfileRepository,
+{
+    profiles: R,
+}
+
+impl<R> GetUserProfileService<R>
+where
+    R: UserProfileRepository,
+{
+    pub fn new(profiles: R) -> Self {
+        Self { profiles }
+    }
+}
+
+#[async_trait]
+impl<R> GetUserProfileUseCase for GetUserProfileService<R>
+where
+    R: UserProfileRepository,
+{
+    async fn execute(&self, user_id: UserId) -> Result<UserProfile, UserProfileError> {
+        self.profiles
+            .find_profile(user_id.value())
+            .await?
+            .map(UserProfile::from)
+            .ok_or(UserProfileError::UserNotFound)
+    }
+}
+
+pub struct PatchUserProfileService<R>
+where
+    R: UserProfileRepository,
+{
+    profiles: R,
+}
+
+impl<R> PatchUserProfileService<R>
+where
+    R: UserProfileRepository,
+{
+    pub fn new(profiles: R) -> Self {
+        Self { profiles }
+    }
+
+    fn validate(patch: UserProfilePatch) -> Result<UserProfileChanges, UserProfileError> {
+        let full_name = patch
+            .full_name
+            .map(|name| {
+                normalize_full_name(&name)
+                    .map_err(|reason| UserProfileError::InvalidFullName(reason.to_string()))
+            })
+            .transpose()?;
+        let preferred_locale = patch
+            .preferred_locale
+            .map(|locale| {
+                normalize_locale(&locale).ok_or_else(|| {
+                    UserProfileError::InvalidLocale(
+                        "Locale must look like 'en' or 'pt-BR'".to_string(),
+                    )
+                })
+            })
+            .transpose()?;
+        let timezone = patch
+            .timezone
+            .map(|timezone| {
+                let timezone = timezone.trim().to_string();
+                if is_timezone_name(&timezone) {
+                    Ok(timezone)
+                } else {
+                    Err(UserProfileError::InvalidTimezone(
+                        "Timezone must be an IANA name like 'Europe/Berlin'".to_string(),
+                    ))
+                }
+            })
+            .transpose()?;
+
+        Ok(UserProfileChanges {
+            full_name,
+            preferred_locale,
+            timezone,
+            avatar_media_id: patch.avatar_media_id,
+        })
+    }
+}
+
+#[async_trait]
+impl<R> PatchUserProfileUseCase for PatchUserProfileService<R>
+where
+    R: UserProfileRepository,
+{
+    async fn execute(
+        &self,
+        user_id: UserId,
+        patch: UserProfilePatch,
+    ) -> Result<UserProfile, UserProfileError> {
+        let changes = Self::validate(patch)?;
+
+        let profile = self
+            .profiles
+            .update_profile_fields(user_id.value(), changes)
+            .await?;
+
+        Ok(profile.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    use crate::auth::adapter::outgoing::in_memory::InMemoryUserStore;
+    use crate::auth::application::ports::outgoing::user_repository::{
+        CreateUserData, UserRepository,
+    };
+    use crate::project::application::ports::outgoing::project_repository::PatchField;
+
+    async fn user(store: &InMemoryUserStore) -> UserId {
+        store
+            .create_user(CreateUserData {
+                email: "jane@example.com".to_string(),
+                username: "jane".to_string(),
+                password_hash: "hash".to_string(),
+                full_name: "Jane Doe".to_string(),
+                preferred_locale: "en".to_string(),
+            })
+            .await
+            .unwrap()
+            .id
+            .into()
+    }
+
+    #[tokio::test]
+    async fn test_new_user_has_default_settings() {
+        let store = InMemoryUserStore::default();
+        let user_id = user(&store).await;
+
+        let profile = GetUserProfileService::new(store)
+            .execute(user_id)
+            .await
+            .unwrap();
+
+        assert_eq!(profile.full_name, "Jane Doe");
+        assert_eq!(profile.timezone, "UTC");
+        assert_eq!(profile.avatar_media_id, None);
+    }
+
+    #[tokio::test]
+    async fn test_patch_changes_only_given_fields() {
+        let store = InMemoryUserStore::default();
+        let user_id = user(&store).await;
+        let avatar = Uuid::new_v4();
+        let service = PatchUserProfileService::new(store.clone());
+
+        let profile = service
+            .execute(
+                user_id,
+                UserProfilePatch {
+                    timezone: Some(" Asia/Jakarta ".to_string()),
+                    avatar_media_id: PatchField::Value(avatar),
+                    ..UserProfilePatch::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(profile.full_name, "Jane Doe");
+        assert_eq!(profile.timezone, "Asia/Jakarta");
+        assert_eq!(profile.avatar_media_id, Some(avatar));
+
+        let profile = service
+            .execute(
+                user_id,
+                UserProfilePatch {
+                    preferred_locale: Some("pt_br".to_string()),
+                    avatar_media_id: PatchField::Null,
+                    ..UserProfilePatch::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(profile.preferred_locale, "pt-BR");
+        assert_eq!(profile.timezone, "Asia/Jakarta");
+        assert_eq!(profile.avatar_media_id, None);
+    }
+
+    #[tokio::test]
+    async fn test_patch_rejects_invalid_timezone() {
+        let store = InMemoryUserStore::default();
+        let user_id = user(&store).await;
+
+        let result = PatchUserProfileService::new(store)
+            .execute(
+                user_id,
+                UserProfilePatch {
+                    timezone: Some("GMT+7 (Jakarta)".to_string()),
+                    ..UserProfilePatch::default()
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(UserProfileError::InvalidTimezone(_))));
+    }
+
+    #[tokio::test]
+    async fn test_patch_rejects_invalid_full_name() {
+        let store = InMemoryUserStore::default();
+        let user_id = user(&store).await;
+
+        let result = PatchUserProfileService::new(store)
+            .execute(
+                user_id,
+                UserProfilePatch {
+                    full_name: Some(" ".to_string()),
+                    ..UserProfilePatch::default()
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(UserProfileError::InvalidFullName(_))));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_user_not_found() {
+        let result = GetUserProfileService::new(InMemoryUserStore::default())
+            .execute(Uuid::new_v4().into())
+            .await;
+
+        assert!(matches!(result, Err(UserProfileError::UserNotFound)));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::auth::application::{
+    domain::entities::{normalize_full_name, normalize_locale},
     ports::outgoing::UserRepository,
     use_cases::update_profile::{
         UpdateUserError, UpdateUserInput, UpdateUserOutput, UpdateUserProfileUseCase,
@@ -24,21 +25,20 @@ where
     }
 
     fn validate_full_name(&self, full_name: &str) -> Result<String, UpdateUserError> {
-        let trimmed = full_name.trim();
-
-        if trimmed.is_empty() {
-            return Err(UpdateUserError::InvalidFullName(
-                "Full name cannot be empty".to_string(),
-            ));
-        }
-
-        if trimmed.len() < 2 || trimmed.len() > 100 {
-            return Err(UpdateUserError::InvalidFullName(
-                "Full name must be 2-100 characters".to_string(),
-            ));
-        }
+        normalize_full_name(full_name)
+            .map_err(|reason| UpdateUserError::InvalidFullName(reason.to_string()))
+    }
 
-        Ok(trimmed.to_string())
+    fn validate_locale(&self, locale: Option<&str>) -> Result<Option<String>, UpdateUserError> {
+        locale
+            .map(|locale| {
+                normalize_locale(locale).ok_or_else(|| {
+                    UpdateUserError::InvalidLocale(
+                        "Locale must look like 'en' or 'pt-BR'".to_string(),
+                    )
+                })
+            })
+            .transpose()
     }
 }
 
@@ -49,10 +49,11 @@ where
 {
     async fn execute(&self, data: UpdateUserInput) -> Result<UpdateUserOutput, UpdateUserError> {
         let full_name = self.validate_full_name(&data.full_name)?;
+        let preferred_locale = self.validate_locale(data.preferred_locale.as_deref())?;
 
         let user = self
             .user_repository
-            .set_full_name(data.user_id.value(), full_name)
+            .update_profile(data.user_id.value(), full_name, preferred_locale)
             .await?;
 
         Ok(UpdateUserOutput {
@@ -60,6 +61,7 @@ where
             username: user.username,
             email: user.email,
             full_name: user.full_name,
+            preferred_locale: user.preferred_locale,
         })
     }
 }
@@ -97,10 +99,11 @@ mod tests {
             unimplemented!()
         }
 
-        async fn set_full_name(
+        async fn update_profile(
             &self,
             _user_id: Uuid,
             _full_name: String,
+            _preferred_locale: Option<String>,
         ) -> Result<UserResult, UserRepositoryError> {
             self.result.clone()
         }
@@ -128,6 +131,7 @@ mod tests {
             email: "test@example.com".to_string(),
             username: "testuser".to_string(),
             full_name: full_name.to_string(),
+            preferred_locale: "en".to_string(),
         }
     }
 
@@ -135,6 +139,7 @@ mod tests {
         UpdateUserInput {
             user_id: user_id.into(),
             full_name: full_name.to_string(),
+            preferred_locale: None,
         }
     }
 
@@ -314,4 +319,27 @@ mod tests {
         let error = result.unwrap_err();
         assert!(matches!(error, UpdateUserError::RepositoryError(_)));
     }
+
+    #[tokio::test]
+    async fn test_execute_normalizes_locale() {
+        let user_id = Uuid::new_v4();
+        let mock_repo = MockUserRepository {
+            result: Ok(create_user_result(user_id, "John Doe")),
+        };
+
+        let service = UpdateUserProfileService::new(mock_repo);
+
+        assert_eq!(
+            service.validate_locale(Some(" pt_br ")).unwrap(),
+            Some("pt-BR".to_string())
+        );
+        assert_eq!(service.validate_locale(None).unwrap(), None);
+
+        let input = UpdateUserInput {
+            preferred_locale: Some("english".to_string()),
+            ..create_update_input(user_id, "John Doe")
+        };
+        let error = service.execute(input).await.unwrap_err();
+        assert!(matches!(error, UpdateUserError::InvalidLocale(_)));
+    }
 }
@@ -1,3 +1,4 @@
+use crate::auth::application::domain::entities::{normalize_locale, DEFAULT_LOCALE};
 use crate::auth::application::ports::outgoing::user_query::UserQueryError;
 use crate::auth::application::ports::outgoing::user_repository::CreateUserData;
 
@@ -8,6 +9,9 @@ use async_trait::async_trait;
 use email_address::EmailAddress;
 
 use crate::auth::application::ports::outgoing::password_hasher::{HashError, PasswordHasher};
+use crate::shared::metrics::metered_use_case;
+use crate::shared::telemetry::{self, BusinessEvent};
+use crate::shared::validation::ValidationErrors;
 use std::sync::Arc;
 
 // ============================================================================
@@ -19,13 +23,24 @@ pub struct CreateUserInput {
     pub email: String,
     pub password: String,
     pub full_name: String,
+    /// Defaults to [`DEFAULT_LOCALE`] when `None`
+    pub preferred_locale: Option<String>,
 }
+/// Normalized fields of a [`CreateUserInput`] that passed validation
+struct ValidInput {
+    username: String,
+    email: String,
+    full_name: String,
+    preferred_locale: String,
+}
+
 #[derive(Clone, Debug)]
 pub struct CreateUserOutput {
     pub user_id: uuid::Uuid,
     pub email: String,
     pub username: String,
     pub full_name: String,
+    pub preferred_locale: String,
 }
 
 // ============================================================================
@@ -34,17 +49,9 @@ pub struct CreateUserOutput {
 
 #[derive(Debug, thiserror::Error, Clone)]
 pub enum CreateUserError {
-    #[error("Invalid username: {0}")]
-    InvalidUsername(String),
-
-    #[error("Invalid email: {0}")]
-    InvalidEmail(String),
-
-    #[error("Invalid password: {0}")]
-    InvalidPassword(String),
-
-    #[error("Invalid full name: {0}")]
-    InvalidFullName(String),
+    /// Every invalid field, with codes like `INVALID_USERNAME`
+    #[error("Invalid input: {0}")]
+    Invalid(ValidationErrors),
 
     #[error("User already exists")]
     UserAlreadyExists,
@@ -68,6 +75,13 @@ pub trait ICreateUserUseCase: Send + Sync {
     async fn execute(&self, input: CreateUserInput) -> Result<CreateUserOutput, CreateUserError>;
 }
 
+metered_use_case!(
+    "auth",
+    "create_user",
+    ICreateUserUseCase,
+    fn execute(&self, input: CreateUserInput) -> Result<CreateUserOutput, CreateUserError>
+);
+
 // ============================================================================
 // Use Case Implementation - FOCUSED ON ONE THING
 // ============================================================================
@@ -103,73 +117,107 @@ where
     // Validation - Business Rules
     // ========================================================================
 
-    fn validate_username(&self, username: &str) -> Result<String, CreateUserError> {
+    fn validate_username(username: &str) -> Result<String, String> {
         let trimmed = username.trim();
 
         if trimmed.is_empty() {
-            return Err(CreateUserError::InvalidUsername(
-                "Username cannot be empty".to_string(),
-            ));
+            return Err("Username cannot be empty".to_string());
         }
 
         if trimmed.len() < 3 || trimmed.len() > 50 {
-            return Err(CreateUserError::InvalidUsername(
-                "Username must be 3-50 characters".to_string(),
-            ));
+            return Err("Username must be 3-50 characters".to_string());
         }
 
         if !trimmed.chars().all(|c| c.is_alphanumeric() || c == '_') {
-            return Err(CreateUserError::InvalidUsername(
-                "Username can only contain letters, numbers, and underscores".to_string(),
-            ));
+            return Err("Username can only contain letters, numbers, and underscores".to_string());
         }
 
         // Return normalized (lowercase)
         Ok(trimmed.to_lowercase())
     }
 
-    fn validate_email(&self, email: &str) -> Result<String, CreateUserError> {
+    fn validate_email(email: &str) -> Result<String, String> {
         let trimmed = email.trim();
 
         if !EmailAddress::is_valid(trimmed) {
-            return Err(CreateUserError::InvalidEmail(
-                "Invalid email format".to_string(),
-            ));
+            return Err("Invalid email format".to_string());
         }
 
         // Return normalized (lowercase)
         Ok(trimmed.to_lowercase())
     }
 
-    fn validate_password(&self, password: &str) -> Result<(), CreateUserError> {
+    fn validate_password(password: &str) -> Result<(), String> {
         if password.len() < 12 {
-            return Err(CreateUserError::InvalidPassword(
-                "Password must be at least 12 characters".to_string(),
-            ));
+            return Err("Password must be at least 12 characters".to_string());
         }
 
         Ok(())
     }
 
-    fn validate_full_name(&self, full_name: &str) -> Result<String, CreateUserError> {
+    fn validate_full_name(full_name: &str) -> Result<String, String> {
         let trimmed = full_name.trim();
 
         if trimmed.is_empty() {
-            return Err(CreateUserError::InvalidFullName(
-                "Full name cannot be empty".to_string(),
-            ));
+            return Err("Full name cannot be empty".to_string());
         }
 
         if trimmed.len() > 100 {
-            return Err(CreateUserError::InvalidFullName(
-                "Full name cannot exceed 100 characters".to_string(),
-            ));
+            return Err("Full name cannot exceed 100 characters".to_string());
         }
 
         // Return normalized
         Ok(trimmed.to_string())
     }
 
+    fn validate_locale(locale: Option<&str>) -> Result<String, String> {
+        match locale {
+            None => Ok(DEFAULT_LOCALE.to_string()),
+            Some(locale) => normalize_locale(locale)
+                .ok_or_else(|| "Locale must look like 'en' or 'pt-BR'".to_string()),
+        }
+    }
+
+    /// Every field is checked, so the client learns about all problems at once
+    fn validate(input: &CreateUserInput) -> Result<ValidInput, CreateUserError> {
+        let mut errors = ValidationErrors::new();
+        let username = errors.check(
+            "username",
+            "INVALID_USERNAME",
+            Self::validate_username(&input.username),
+        );
+        let email = errors.check("email", "INVALID_EMAIL", Self::validate_email(&input.email));
+        errors.check(
+            "password",
+            "INVALID_PASSWORD",
+            Self::validate_password(&input.password),
+        );
+        let full_name = errors.check(
+            "full_name",
+            "INVALID_FULL_NAME",
+            Self::validate_full_name(&input.full_name),
+        );
+        let preferred_locale = errors.check(
+            "preferred_locale",
+            "INVALID_LOCALE",
+            Self::validate_locale(input.preferred_locale.as_deref()),
+        );
+
+        match (username, email, full_name, preferred_locale) {
+            (Some(username), Some(email), Some(full_name), Some(preferred_locale))
+                if errors.is_empty() =>
+            {
+                Ok(ValidInput {
+                    username,
+                    email,
+                    full_name,
+                    preferred_locale,
+                })
+            }
+            _ => Err(CreateUserError::Invalid(errors)),
+        }
+    }
+
     // ========================================================================
     // Soft-Delete Check - Business Rule
     // ========================================================================
@@ -192,6 +240,7 @@ where
                 email: restored.email,
                 username: restored.username,
                 full_name: restored.full_name,
+                preferred_locale: restored.preferred_locale,
             })
         } else {
             // User exists and is active — genuine duplicate
@@ -212,10 +261,12 @@ where
 {
     async fn execute(&self, input: CreateUserInput) -> Result<CreateUserOutput, CreateUserError> {
         // 1. Validate and normalize inputs
-        let username = self.validate_username(&input.username)?;
-        let email = self.validate_email(&input.email)?;
-        self.validate_password(&input.password)?;
-        let full_name = self.validate_full_name(&input.full_name)?;
+        let ValidInput {
+            username,
+            email,
+            full_name,
+            preferred_locale,
+        } = Self::validate(&input)?;
 
         // 2. Hash password
         let password_hash = self
@@ -242,10 +293,11 @@ where
                 username: username.clone(),
                 password_hash,
                 full_name: full_name.clone(),
+                preferred_locale,
             })
             .await;
 
-        match create_result {
+        let output = match create_result {
             Ok(created_user) => {
                 // Happy path: user created successfully
                 Ok(CreateUserOutput {
@@ -253,6 +305,7 @@ where
                     email: created_user.email,
                     username: created_user.username,
                     full_name: created_user.full_name,
+                    preferred_locale: created_user.preferred_locale,
                 })
             }
             Err(UserRepositoryError::UserAlreadyExists) => {
@@ -260,7 +313,12 @@ where
                 self.try_restore_soft_deleted(&email).await
             }
             Err(other) => Err(CreateUserError::RepositoryError(other)),
-        }
+        }?;
+
+        telemetry::record(BusinessEvent::UserRegistered {
+            user_id: output.user_id,
+        });
+        Ok(output)
     }
 }
 
@@ -288,10 +346,13 @@ mod tests {
             username: user.username.clone(),
             password_hash: "hashed".into(),
             full_name: user.full_name.clone(),
+            preferred_locale: "en".to_string(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
             is_verified: true,
             is_deleted: false,
+            token_version: 0,
+            is_active: true,
         }
     }
 
@@ -308,6 +369,7 @@ mod tests {
             username: "testuser".to_string(),
             password: "securepassword123".to_string(),
             full_name: "Test User".to_string(),
+            preferred_locale: None,
         }
     }
 
@@ -408,10 +470,11 @@ mod tests {
             unimplemented!()
         }
 
-        async fn set_full_name(
+        async fn update_profile(
             &self,
             _: Uuid,
             _: String,
+            _: Option<String>,
         ) -> Result<UserResult, UserRepositoryError> {
             unimplemented!()
         }
@@ -474,6 +537,7 @@ mod tests {
             email: "deleted@example.com".into(),
             username: "deleteduser".into(),
             full_name: "Deleted User".into(),
+            preferred_locale: "en".to_string(),
         };
 
         let restored_user = deleted_user.clone();
@@ -496,6 +560,7 @@ mod tests {
             email: "active@example.com".into(),
             username: "activeuser".into(),
             full_name: "Active User".into(),
+            preferred_locale: "en".to_string(),
         };
 
         let created_user = UserResult {
@@ -503,6 +568,7 @@ mod tests {
             email: "test@example.com".into(),
             username: "testuser".into(),
             full_name: "Test User".into(),
+            preferred_locale: "en".to_string(),
         };
 
         let use_case = CreateUserUseCase::new(
@@ -540,6 +606,7 @@ mod tests {
                 email: "x".into(),
                 username: "x".into(),
                 full_name: "x".into(),
+                preferred_locale: "en".to_string(),
             }),
             Arc::new(MockPasswordHasher::fail()),
         );
@@ -547,4 +614,52 @@ mod tests {
         let err = use_case.execute(valid_input()).await.unwrap_err();
         assert!(matches!(err, CreateUserError::HashingFailed(_)));
     }
+
+    #[tokio::test]
+    async fn rejects_malformed_locale() {
+        let use_case = CreateUserUseCase::new(
+            MockUserQuery::empty(),
+            MockUserRepository::create_error(UserRepositoryError::DatabaseError(
+                "not reached".to_string(),
+            )),
+            Arc::new(MockPasswordHasher::success()),
+        );
+
+        let input = CreateUserInput {
+            preferred_locale: Some("english".to_string()),
+            ..valid_input()
+        };
+        let err = use_case.execute(input).await.unwrap_err();
+
+        let CreateUserError::Invalid(errors) = err else {
+            panic!("expected a validation error, got {err:?}");
+        };
+        assert_eq!(errors.fields()[0].field, "preferred_locale");
+        assert_eq!(errors.fields()[0].code, "INVALID_LOCALE");
+    }
+
+    #[tokio::test]
+    async fn test_reports_every_invalid_field() {
+        let use_case = CreateUserUseCase::new(
+            MockUserQuery::empty(),
+            MockUserRepository::create_error(UserRepositoryError::DatabaseError(
+                "not reached".to_string(),
+            )),
+            Arc::new(MockPasswordHasher::success()),
+        );
+
+        let input = CreateUserInput {
+            username: "a b".to_string(),
+            email: "not-an-email".to_string(),
+            password: "short".to_string(),
+            ..valid_input()
+        };
+        let err = use_case.execute(input).await.unwrap_err();
+
+        let CreateUserError::Invalid(errors) = err else {
+            panic!("expected a validation error, got {err:?}");
+        };
+        let fields: Vec<&str> = errors.fields().iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["username", "email", "password"]);
+    }
 }
@@ -0,0 +1,127 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::auth::application::helpers::TokenVersionGuard;
+use crate::auth::application::ports::outgoing::{AccountStatusRepository, UserRepositoryError};
+use crate::shared::metrics::metered_use_case;
+
+// ====================== Deactivate Account Errors ======================
+#[derive(Debug, Clone)]
+pub enum DeactivateAccountError {
+    UserNotFound,
+    DatabaseError(String),
+}
+
+impl std::fmt::Display for DeactivateAccountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeactivateAccountError::UserNotFound => write!(f, "User not found"),
+            DeactivateAccountError::DatabaseError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for DeactivateAccountError {}
+
+impl From<UserRepositoryError> for DeactivateAccountError {
+    fn from(error: UserRepositoryError) -> Self {
+        match error {
+            UserRepositoryError::UserNotFound => DeactivateAccountError::UserNotFound,
+            other => DeactivateAccountError::DatabaseError(other.to_string()),
+        }
+    }
+}
+
+// ==================== Deactivate Account Use Case ======================
+/// Takes an account offline without deleting anything: its content leaves
+/// the public endpoints, every session ends, and logging in is refused until
+/// the owner reactivates it
+#[async_trait]
+pub trait IDeactivateAccountUseCase: Send + Sync {
+    async fn execute(&self, user_id: Uuid) -> Result<(), DeactivateAccountError>;
+}
+
+metered_use_case!(
+    "auth",
+    "deactivate_account",
+    IDeactivateAccountUseCase,
+    fn execute(&self, user_id: Uuid) -> Result<(), DeactivateAccountError>
+);
+
+#[derive(Clone)]
+pub struct DeactivateAccountUseCase {
+    accounts: Arc<dyn AccountStatusRepository>,
+    guard: TokenVersionGuard,
+}
+
+impl DeactivateAccountUseCase {
+    pub fn new(accounts: Arc<dyn AccountStatusRepository>, guard: TokenVersionGuard) -> Self {
+        Self { accounts, guard }
+    }
+}
+
+#[async_trait]
+impl IDeactivateAccountUseCase for DeactivateAccountUseCase {
+    async fn execute(&self, user_id: Uuid) -> Result<(), DeactivateAccountError> {
+        self.accounts.set_active(user_id, false).await?;
+        self.guard.revoke_all(user_id).await?;
+
+        info!("Deactivated account of user {}", user_id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::auth::adapter::outgoing::in_memory::InMemoryUserStore;
+    use crate::auth::application::ports::outgoing::user_repository::{
+        CreateUserData, UserRepository,
+    };
+    use crate::auth::application::ports::outgoing::UserQuery;
+    use crate::tests::support::stubs::InMemoryCache;
+
+    fn use_case(store: &InMemoryUserStore) -> (DeactivateAccountUseCase, TokenVersionGuard) {
+        let guard =
+            TokenVersionGuard::new(Arc::new(store.clone()), Arc::new(InMemoryCache::default()));
+        (
+            DeactivateAccountUseCase::new(Arc::new(store.clone()), guard.clone()),
+            guard,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_deactivate_hides_account_and_ends_sessions() {
+        let store = InMemoryUserStore::default();
+        let user = store
+            .create_user(CreateUserData {
+                email: "jane@example.com".to_string(),
+                username: "jane".to_string(),
+                password_hash: "hash".to_string(),
+                full_name: "Jane Doe".to_string(),
+                preferred_locale: "en".to_string(),
+            })
+            .await
+            .unwrap();
+        let (use_case, guard) = use_case(&store);
+
+        use_case.execute(user.id).await.unwrap();
+
+        let found = store.find_by_id(user.id).await.unwrap().unwrap();
+        assert!(!found.is_active);
+        assert!(!found.is_deleted);
+        assert!(!guard.is_current(user.id, 0).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_deactivate_unknown_user() {
+        let (use_case, _) = use_case(&InMemoryUserStore::default());
+
+        let result = use_case.execute(Uuid::new_v4()).await;
+
+        assert!(matches!(result, Err(DeactivateAccountError::UserNotFound)));
+    }
+}
@@ -3,6 +3,7 @@ use async_trait::async_trait;
 use crate::auth::application::{
     domain::entities::UserId, ports::outgoing::user_query::UserQueryError,
 };
+use crate::shared::metrics::metered_use_case;
 
 #[derive(Clone, Debug)]
 pub struct FetchUserOutput {
@@ -10,6 +11,7 @@ pub struct FetchUserOutput {
     pub email: String,
     pub username: String,
     pub full_name: String,
+    pub preferred_locale: String,
 }
 
 #[derive(Debug, thiserror::Error, Clone)]
@@ -25,3 +27,10 @@ pub enum FetchUserError {
 pub trait FetchUserProfileUseCase: Send + Sync {
     async fn execute(&self, user_id: UserId) -> Result<FetchUserOutput, FetchUserError>;
 }
+
+metered_use_case!(
+    "auth",
+    "fetch_user_profile",
+    FetchUserProfileUseCase,
+    fn execute(&self, user_id: UserId) -> Result<FetchUserOutput, FetchUserError>
+);
@@ -0,0 +1,254 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+use crate::auth::application::helpers::TokenVersionGuard;
+use crate::auth::application::ports::outgoing::{
+    token_hasher::hash_token,
+    token_provider::{TokenClaims, TokenError, TokenProvider},
+    token_repository::TokenRepository,
+};
+use crate::shared::metrics::metered_use_case;
+
+// ==================== Introspection Result ======================
+/// Why a token would be refused
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InactiveReason {
+    /// Malformed, forged, or issued for another issuer or audience
+    Invalid,
+    Expired,
+    NotYetValid,
+    /// Refresh token given up at logout
+    Blacklisted,
+    /// Issued before the user's last logout from all devices
+    Revoked,
+}
+
+impl InactiveReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InactiveReason::Invalid => "invalid",
+            InactiveReason::Expired => "expired",
+            InactiveReason::NotYetValid => "not_yet_valid",
+            InactiveReason::Blacklisted => "blacklisted",
+            InactiveReason::Revoked => "revoked",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TokenIntrospection {
+    /// Set when the signature checks out, even if the token is refused
+    pub claims: Option<TokenClaims>,
+    /// `None` when the token would be accepted
+    pub inactive_reason: Option<InactiveReason>,
+    pub blacklisted: bool,
+}
+
+impl TokenIntrospection {
+    pub fn is_active(&self) -> bool {
+        self.inactive_reason.is_none()
+    }
+
+    /// Seconds until the token expires, if its claims could be read
+    pub fn expires_in(&self) -> Option<i64> {
+        self.claims
+            .as_ref()
+            .map(|claims| (claims.exp - Utc::now().timestamp()).max(0))
+    }
+
+    fn inactive(reason: InactiveReason) -> Self {
+        Self {
+            claims: None,
+            inactive_reason: Some(reason),
+            blacklisted: false,
+        }
+    }
+}
+
+// ====================== Introspection Errors ======================
+#[derive(Debug, Clone)]
+pub enum IntrospectTokenError {
+    /// The blacklist or the token version could not be read
+    LookupFailed(String),
+}
+
+impl std::fmt::Display for IntrospectTokenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntrospectTokenError::LookupFailed(msg) => write!(f, "Lookup failed: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for IntrospectTokenError {}
+
+// ==================== Introspect Token Use Case ======================
+/// Tells trusted services whether a token would be accepted here, and why not
+#[async_trait]
+pub trait IIntrospectTokenUseCase: Send + Sync {
+    async fn execute(&self, token: &str) -> Result<TokenIntrospection, IntrospectTokenError>;
+}
+
+metered_use_case!(
+    "auth",
+    "introspect_token",
+    IIntrospectTokenUseCase,
+    fn execute(&self, token: &str) -> Result<TokenIntrospection, IntrospectTokenError>
+);
+
+#[derive(Clone)]
+pub struct IntrospectTokenUseCase<R>
+where
+    R: TokenRepository + Send + Sync,
+{
+    token_repository: R,
+    token_provider: Arc<dyn TokenProvider>,
+    token_version_guard: TokenVersionGuard,
+}
+
+impl<R> IntrospectTokenUseCase<R>
+where
+    R: TokenRepository + Send + Sync,
+{
+    pub fn new(
+        token_repository: R,
+        token_provider: Arc<dyn TokenProvider>,
+        token_version_guard: TokenVersionGuard,
+    ) -> Self {
+        Self {
+            token_repository,
+            token_provider,
+            token_version_guard,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> IIntrospectTokenUseCase for IntrospectTokenUseCase<R>
+where
+    R: TokenRepository + Send + Sync,
+{
+    async fn execute(&self, token: &str) -> Result<TokenIntrospection, IntrospectTokenError> {
+        let claims = match self.token_provider.verify_token(token) {
+            Ok(claims) => claims,
+            Err(TokenError::TokenExpired) => {
+                return Ok(TokenIntrospection::inactive(InactiveReason::Expired))
+            }
+            Err(TokenError::TokenNotYetValid) => {
+                return Ok(TokenIntrospection::inactive(InactiveReason::NotYetValid))
+            }
+            Err(_) => return Ok(TokenIntrospection::inactive(InactiveReason::Invalid)),
+        };
+
+        let blacklisted = self
+            .token_repository
+            .is_token_blacklisted(&hash_token(token))
+            .await
+            .map_err(|e| IntrospectTokenError::LookupFailed(e.to_string()))?;
+
+        // Verification tokens predate any session, so versions don't apply
+        let current = claims.token_type == "verification"
+            || self
+                .token_version_guard
+                .is_current(claims.sub, claims.token_version)
+                .await
+                .map_err(|e| IntrospectTokenError::LookupFailed(e.to_string()))?;
+
+        let inactive_reason = if blacklisted {
+            Some(InactiveReason::Blacklisted)
+        } else if !current {
+            Some(InactiveReason::Revoked)
+        } else {
+            None
+        };
+
+        Ok(TokenIntrospection {
+            claims: Some(claims),
+            inactive_reason,
+            blacklisted,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::adapter::outgoing::in_memory::InMemoryTokenRepository;
+    use crate::shared::cache::NoopCache;
+    use crate::tests::support::auth_helper::test_helpers::create_test_jwt_service;
+    use crate::tests::support::stubs::StubTokenVersionRepository;
+    use uuid::Uuid;
+
+    fn use_case(
+        tokens: InMemoryTokenRepository,
+        version: i32,
+    ) -> IntrospectTokenUseCase<InMemoryTokenRepository> {
+        IntrospectTokenUseCase::new(
+            tokens,
+            Arc::new(create_test_jwt_service()),
+            TokenVersionGuard::new(
+                Arc::new(StubTokenVersionRepository::at(version)),
+                Arc::new(NoopCache),
+            ),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_valid_token_is_active_with_claims_and_ttl() {
+        let user_id = Uuid::new_v4();
+        let token = create_test_jwt_service()
+            .generate_access_token(user_id, true, 0)
+            .unwrap();
+
+        let result = use_case(InMemoryTokenRepository::default(), 0)
+            .execute(&token)
+            .await
+            .unwrap();
+
+        assert!(result.is_active());
+        assert!(!result.blacklisted);
+        assert_eq!(result.claims.as_ref().unwrap().sub, user_id);
+        let expires_in = result.expires_in().unwrap();
+        assert!(expires_in > 3500 && expires_in <= 3600);
+    }
+
+    #[tokio::test]
+    async fn test_blacklisted_and_revoked_tokens_are_inactive() {
+        let user_id = Uuid::new_v4();
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_refresh_token(user_id, true, 0, false).unwrap();
+
+        let tokens = InMemoryTokenRepository::default();
+        tokens
+            .blacklist_token(
+                hash_token(&token),
+                user_id,
+                Utc::now() + chrono::Duration::hours(1),
+            )
+            .await
+            .unwrap();
+        let result = use_case(tokens, 0).execute(&token).await.unwrap();
+        assert_eq!(result.inactive_reason, Some(InactiveReason::Blacklisted));
+        assert!(result.blacklisted);
+
+        let result = use_case(InMemoryTokenRepository::default(), 1)
+            .execute(&token)
+            .await
+            .unwrap();
+        assert_eq!(result.inactive_reason, Some(InactiveReason::Revoked));
+        assert!(result.claims.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_garbage_is_invalid_without_claims() {
+        let result = use_case(InMemoryTokenRepository::default(), 0)
+            .execute("not.a.token")
+            .await
+            .unwrap();
+
+        assert_eq!(result.inactive_reason, Some(InactiveReason::Invalid));
+        assert!(result.claims.is_none());
+        assert_eq!(result.expires_in(), None);
+    }
+}
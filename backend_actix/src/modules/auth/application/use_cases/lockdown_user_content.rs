@@ -0,0 +1,150 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::auth::application::helpers::TokenVersionGuard;
+use crate::auth::application::ports::outgoing::UserRepositoryError;
+use crate::shared::cache::{self, CachePort};
+use crate::shared::metrics::metered_use_case;
+use crate::{pages, project};
+
+// ==================== Lockdown User Content Errors =====================
+#[derive(Debug, Clone)]
+pub enum LockdownUserContentError {
+    UserNotFound,
+    DatabaseError(String),
+}
+
+impl std::fmt::Display for LockdownUserContentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LockdownUserContentError::UserNotFound => write!(f, "User not found"),
+            LockdownUserContentError::DatabaseError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for LockdownUserContentError {}
+
+impl From<UserRepositoryError> for LockdownUserContentError {
+    fn from(error: UserRepositoryError) -> Self {
+        match error {
+            UserRepositoryError::UserNotFound => LockdownUserContentError::UserNotFound,
+            other => LockdownUserContentError::DatabaseError(other.to_string()),
+        }
+    }
+}
+
+// ================== Lockdown User Content Use Case =====================
+/// Run right after an account is soft-deleted. Public queries already skip
+/// a deleted owner's projects, pages, CVs and media; this ends the owner's
+/// sessions, so no more signed URLs are issued to them, and evicts the
+/// public responses cached before the deletion.
+#[async_trait]
+pub trait ILockdownUserContentUseCase: Send + Sync {
+    async fn execute(&self, user_id: Uuid) -> Result<(), LockdownUserContentError>;
+}
+
+metered_use_case!(
+    "auth",
+    "lockdown_user_content",
+    ILockdownUserContentUseCase,
+    fn execute(&self, user_id: Uuid) -> Result<(), LockdownUserContentError>
+);
+
+#[derive(Clone)]
+pub struct LockdownUserContentUseCase {
+    guard: TokenVersionGuard,
+    cache: Arc<dyn CachePort>,
+}
+
+impl LockdownUserContentUseCase {
+    pub fn new(guard: TokenVersionGuard, cache: Arc<dyn CachePort>) -> Self {
+        Self { guard, cache }
+    }
+}
+
+#[async_trait]
+impl ILockdownUserContentUseCase for LockdownUserContentUseCase {
+    async fn execute(&self, user_id: Uuid) -> Result<(), LockdownUserContentError> {
+        self.guard.revoke_all(user_id).await?;
+
+        // Public CVs are cached by id, behind the owner lookup, so they
+        // need no eviction
+        cache::invalidate_prefix(
+            self.cache.as_ref(),
+            &project::application::cache_keys::owner_prefix(UserId::from(user_id)),
+        )
+        .await;
+        cache::invalidate_prefix(
+            self.cache.as_ref(),
+            &pages::application::cache_keys::owner_prefix(user_id),
+        )
+        .await;
+
+        info!("Locked down content of deleted user {}", user_id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::auth::adapter::outgoing::in_memory::InMemoryUserStore;
+    use crate::auth::application::ports::outgoing::user_repository::{
+        CreateUserData, UserRepository,
+    };
+    use crate::tests::support::stubs::InMemoryCache;
+
+    #[tokio::test]
+    async fn test_lockdown_ends_sessions_and_evicts_public_content() {
+        let store = InMemoryUserStore::default();
+        let user = store
+            .create_user(CreateUserData {
+                email: "jane@example.com".to_string(),
+                username: "jane".to_string(),
+                password_hash: "hash".to_string(),
+                full_name: "Jane Doe".to_string(),
+                preferred_locale: "en".to_string(),
+            })
+            .await
+            .unwrap();
+        let cache = InMemoryCache::default();
+        let project_key = format!(
+            "{}list",
+            project::application::cache_keys::owner_prefix(UserId::from(user.id))
+        );
+        let page_key = pages::application::cache_keys::public_by_slug(user.id, "about");
+        let other_key = pages::application::cache_keys::public_by_slug(Uuid::new_v4(), "about");
+        for key in [&project_key, &page_key, &other_key] {
+            cache.set(key, "{}".to_string()).await.unwrap();
+        }
+        let guard = TokenVersionGuard::new(Arc::new(store.clone()), Arc::new(cache.clone()));
+        let use_case = LockdownUserContentUseCase::new(guard.clone(), Arc::new(cache.clone()));
+
+        use_case.execute(user.id).await.unwrap();
+
+        assert_eq!(cache.keys(), vec![other_key]);
+        assert!(!guard.is_current(user.id, 0).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_lockdown_unknown_user() {
+        let store = InMemoryUserStore::default();
+        let cache: Arc<dyn CachePort> = Arc::new(InMemoryCache::default());
+        let use_case = LockdownUserContentUseCase::new(
+            TokenVersionGuard::new(Arc::new(store), Arc::clone(&cache)),
+            cache,
+        );
+
+        let result = use_case.execute(Uuid::new_v4()).await;
+
+        assert!(matches!(
+            result,
+            Err(LockdownUserContentError::UserNotFound)
+        ));
+    }
+}
@@ -7,6 +7,7 @@ use crate::auth::application::ports::outgoing::{
     token_provider::TokenProvider,
     UserQuery,
 };
+use crate::shared::metrics::metered_use_case;
 use email_address::EmailAddress;
 // ========================= Login Request =========================
 /// Validated login request - can be deserialized directly from JSON
@@ -14,6 +15,7 @@ use email_address::EmailAddress;
 pub struct LoginRequest {
     email: String,    // Private - guaranteed valid
     password: String, // Private - guaranteed valid
+    remember_me: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -41,7 +43,17 @@ impl LoginRequest {
         let email = Self::validate_email(email)?;
         let password = Self::validate_password(password)?;
 
-        Ok(Self { email, password })
+        Ok(Self {
+            email,
+            password,
+            remember_me: false,
+        })
+    }
+
+    /// Ask for a long-lived refresh token
+    pub fn with_remember_me(mut self, remember_me: bool) -> Self {
+        self.remember_me = remember_me;
+        self
     }
 
     /// Get email (guaranteed to be valid)
@@ -54,6 +66,10 @@ impl LoginRequest {
         &self.password
     }
 
+    pub fn remember_me(&self) -> bool {
+        self.remember_me
+    }
+
     // ------------------------
     // Validation helpers
     // ------------------------
@@ -93,10 +109,14 @@ impl<'de> Deserialize<'de> for LoginRequest {
         struct LoginRequestHelper {
             email: String,
             password: String,
+            #[serde(default)]
+            remember_me: bool,
         }
 
         let helper = LoginRequestHelper::deserialize(deserializer)?;
-        LoginRequest::new(helper.email, helper.password).map_err(serde::de::Error::custom)
+        LoginRequest::new(helper.email, helper.password)
+            .map(|request| request.with_remember_me(helper.remember_me))
+            .map_err(serde::de::Error::custom)
     }
 }
 
@@ -105,6 +125,9 @@ impl<'de> Deserialize<'de> for LoginRequest {
 pub enum LoginError {
     InvalidCredentials,
     UserDeleted,
+    /// Right password, but the owner deactivated the account; they can
+    /// reactivate it
+    AccountDeactivated,
     PasswordVerificationFailed(String),
     TokenGenerationFailed(String),
     QueryError(String),
@@ -115,6 +138,7 @@ impl std::fmt::Display for LoginError {
         match self {
             LoginError::InvalidCredentials => write!(f, "Invalid email or password"),
             LoginError::UserDeleted => write!(f, "User account has been deleted"),
+            LoginError::AccountDeactivated => write!(f, "User account is deactivated"),
             LoginError::PasswordVerificationFailed(msg) => {
                 write!(f, "Password verification failed: {}", msg)
             }
@@ -151,6 +175,13 @@ pub trait ILoginUserUseCase: Send + Sync {
     async fn execute(&self, request: LoginRequest) -> Result<LoginUserResponse, LoginError>;
 }
 
+metered_use_case!(
+    "auth",
+    "login_user",
+    ILoginUserUseCase,
+    fn execute(&self, request: LoginRequest) -> Result<LoginUserResponse, LoginError>
+);
+
 // Implementation of Login use case
 #[derive(Clone)]
 pub struct LoginUserUseCase<Q>
@@ -217,15 +248,26 @@ where
             return Err(LoginError::InvalidCredentials);
         }
 
+        // Only told after the password checks out, so it reveals nothing
+        // about the account to anyone else
+        if !user.is_active {
+            return Err(LoginError::AccountDeactivated);
+        }
+
         // 4️⃣ **Generate tokens**
         let access_token = self
             .token_provider
-            .generate_access_token(user.id, user.is_verified)
+            .generate_access_token(user.id, user.is_verified, user.token_version)
             .map_err(|e| LoginError::TokenGenerationFailed(e.to_string()))?;
 
         let refresh_token = self
             .token_provider
-            .generate_refresh_token(user.id, user.is_verified)
+            .generate_refresh_token(
+                user.id,
+                user.is_verified,
+                user.token_version,
+                request.remember_me(),
+            )
             .map_err(|e| LoginError::TokenGenerationFailed(e.to_string()))?;
 
         // 5️⃣ **Return response**
@@ -303,6 +345,19 @@ mod tests {
         let request: LoginRequest = serde_json::from_value(json).unwrap();
         assert_eq!(request.email(), "test@example.com");
         assert_eq!(request.password(), "password123");
+        assert!(!request.remember_me());
+    }
+
+    #[test]
+    fn test_login_request_deserialize_remember_me() {
+        let json = json!({
+            "email": "test@example.com",
+            "password": "password123",
+            "remember_me": true
+        });
+
+        let request: LoginRequest = serde_json::from_value(json).unwrap();
+        assert!(request.remember_me());
     }
 
     #[test]
@@ -409,9 +464,11 @@ mod tests {
         JwtTokenService::new(JwtConfig {
             secret_key: "test_secret_key_min_32_characters_long".to_string(),
             issuer: "testapp".to_string(),
+            audience: "test_audience".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         })
     }
 
@@ -423,10 +480,13 @@ mod tests {
             email: "test@example.com".to_string(),
             password_hash: "hashed_password".to_string(),
             full_name: "Alexander Gibraltar".to_string(),
+            preferred_locale: "en".to_string(),
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             is_verified,
             is_deleted,
+            token_version: 0,
+            is_active: true,
         }
     }
 
@@ -459,6 +519,32 @@ mod tests {
         assert_eq!(response.user.is_verified, true);
     }
 
+    #[tokio::test]
+    async fn test_login_remember_me_issues_long_lived_refresh_token() {
+        let user = create_test_user(true, false);
+        let query = MockUserQuery {
+            user: Some(user),
+            should_fail: false,
+        };
+        let jwt_service = create_jwt_service();
+        let use_case = LoginUserUseCase::new(
+            query,
+            Arc::new(MockPasswordHasher {
+                should_verify: true,
+            }),
+            Arc::new(jwt_service.clone()),
+        );
+
+        let request = LoginRequest::new("test@example.com".to_string(), "password123".to_string())
+            .unwrap()
+            .with_remember_me(true);
+        let response = use_case.execute(request).await.unwrap();
+
+        let claims = jwt_service.verify_token(&response.refresh_token).unwrap();
+        assert!(claims.remember_me);
+        assert_eq!(claims.exp - claims.iat, 2592000);
+    }
+
     #[tokio::test]
     async fn test_login_user_not_found() {
         let query = MockUserQuery::default();
@@ -539,6 +625,30 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_login_deactivated_account_needs_right_password_to_tell() {
+        let mut user = create_test_user(true, false);
+        user.is_active = false;
+        let login = |should_verify| {
+            LoginUserUseCase::new(
+                MockUserQuery {
+                    user: Some(user.clone()),
+                    should_fail: false,
+                },
+                Arc::new(MockPasswordHasher { should_verify }),
+                Arc::new(create_jwt_service()),
+            )
+        };
+        let request =
+            LoginRequest::new("test@example.com".to_string(), "password123".to_string()).unwrap();
+
+        let result = login(true).execute(request.clone()).await;
+        assert!(matches!(result, Err(LoginError::AccountDeactivated)));
+
+        let result = login(false).execute(request).await;
+        assert!(matches!(result, Err(LoginError::InvalidCredentials)));
+    }
+
     #[tokio::test]
     async fn test_login_query_error() {
         let query = MockUserQuery {
@@ -0,0 +1,115 @@
+use async_trait::async_trait;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::auth::application::helpers::TokenVersionGuard;
+use crate::auth::application::ports::outgoing::UserRepositoryError;
+use crate::shared::metrics::metered_use_case;
+
+// ====================== Logout All Errors ======================
+#[derive(Debug, Clone)]
+pub enum LogoutAllError {
+    UserNotFound,
+    DatabaseError(String),
+}
+
+impl std::fmt::Display for LogoutAllError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogoutAllError::UserNotFound => write!(f, "User not found"),
+            LogoutAllError::DatabaseError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for LogoutAllError {}
+
+impl From<UserRepositoryError> for LogoutAllError {
+    fn from(error: UserRepositoryError) -> Self {
+        match error {
+            UserRepositoryError::UserNotFound => LogoutAllError::UserNotFound,
+            other => LogoutAllError::DatabaseError(other.to_string()),
+        }
+    }
+}
+
+// ==================== Logout All Use Case ======================
+/// Ends every session of a user: all access and refresh tokens issued so far
+/// stop working, on every device
+#[async_trait]
+pub trait ILogoutAllUseCase: Send + Sync {
+    async fn execute(&self, user_id: Uuid) -> Result<(), LogoutAllError>;
+}
+
+metered_use_case!(
+    "auth",
+    "logout_all",
+    ILogoutAllUseCase,
+    fn execute(&self, user_id: Uuid) -> Result<(), LogoutAllError>
+);
+
+#[derive(Clone)]
+pub struct LogoutAllUseCase {
+    guard: TokenVersionGuard,
+}
+
+impl LogoutAllUseCase {
+    pub fn new(guard: TokenVersionGuard) -> Self {
+        Self { guard }
+    }
+}
+
+#[async_trait]
+impl ILogoutAllUseCase for LogoutAllUseCase {
+    async fn execute(&self, user_id: Uuid) -> Result<(), LogoutAllError> {
+        self.guard.revoke_all(user_id).await?;
+
+        info!("Revoked all tokens of user {}", user_id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use crate::auth::adapter::outgoing::in_memory::InMemoryUserStore;
+    use crate::auth::application::ports::outgoing::user_repository::{
+        CreateUserData, UserRepository,
+    };
+    use crate::tests::support::stubs::InMemoryCache;
+
+    #[tokio::test]
+    async fn test_logout_all_revokes_tokens_issued_so_far() {
+        let store = InMemoryUserStore::default();
+        let user = store
+            .create_user(CreateUserData {
+                email: "jane@example.com".to_string(),
+                username: "jane".to_string(),
+                password_hash: "hash".to_string(),
+                full_name: "Jane Doe".to_string(),
+                preferred_locale: "en".to_string(),
+            })
+            .await
+            .unwrap();
+        let guard = TokenVersionGuard::new(Arc::new(store), Arc::new(InMemoryCache::default()));
+        let use_case = LogoutAllUseCase::new(guard.clone());
+
+        use_case.execute(user.id).await.unwrap();
+
+        assert!(!guard.is_current(user.id, 0).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_logout_all_unknown_user() {
+        let guard = TokenVersionGuard::new(
+            Arc::new(InMemoryUserStore::default()),
+            Arc::new(InMemoryCache::default()),
+        );
+
+        let result = LogoutAllUseCase::new(guard).execute(Uuid::new_v4()).await;
+
+        assert!(matches!(result, Err(LogoutAllError::UserNotFound)));
+    }
+}
@@ -9,6 +9,7 @@ use crate::auth::application::ports::{
     outgoing::token_provider::TokenProvider,
     outgoing::token_repository::{TokenRepository, TokenRepositoryError},
 };
+use crate::shared::metrics::metered_use_case;
 
 // ========================= Logout Request =========================
 #[derive(Debug, Clone)]
@@ -98,6 +99,13 @@ pub trait ILogoutUseCase: Send + Sync {
     async fn execute(&self, request: LogoutRequest) -> Result<LogoutResponse, LogoutError>;
 }
 
+metered_use_case!(
+    "auth",
+    "logout",
+    ILogoutUseCase,
+    fn execute(&self, request: LogoutRequest) -> Result<LogoutResponse, LogoutError>
+);
+
 #[derive(Clone)]
 pub struct LogoutUseCase<R>
 where
@@ -236,9 +244,11 @@ mod tests {
         JwtTokenService::new(JwtConfig {
             secret_key: "test_secret_key_min_32_characters_long".to_string(),
             issuer: "testapp".to_string(),
+            audience: "test_audience".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         })
     }
 
@@ -249,7 +259,9 @@ mod tests {
         let user_id = Uuid::new_v4();
 
         // Generate a valid refresh token
-        let refresh_token = jwt_service.generate_refresh_token(user_id, true).unwrap();
+        let refresh_token = jwt_service
+            .generate_refresh_token(user_id, true, 0, false)
+            .unwrap();
 
         let use_case = LogoutUseCase::new(repository.clone(), Arc::new(jwt_service));
         let request = LogoutRequest::new(Some(refresh_token.clone())).unwrap();
@@ -295,7 +307,9 @@ mod tests {
         let jwt_service = create_jwt_service();
         let user_id = Uuid::new_v4();
 
-        let refresh_token = jwt_service.generate_refresh_token(user_id, true).unwrap();
+        let refresh_token = jwt_service
+            .generate_refresh_token(user_id, true, 0, false)
+            .unwrap();
 
         let use_case = LogoutUseCase::new(repository, Arc::new(jwt_service));
         let request = LogoutRequest::new(Some(refresh_token)).unwrap();
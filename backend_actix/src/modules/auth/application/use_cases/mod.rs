@@ -1,8 +1,15 @@
 pub mod create_user;
+pub mod deactivate_account;
 pub mod fetch_profile;
+pub mod introspect_token;
+pub mod lockdown_user_content;
 pub mod login_user;
+pub mod logout_all;
 pub mod logout_user;
+pub mod passkeys;
+pub mod reactivate_account;
 pub mod refresh_token;
 pub mod soft_delete_user;
 pub mod update_profile;
+pub mod user_profile;
 pub mod verify_user_email;
@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::PasskeyError;
+use crate::auth::application::ports::outgoing::PasskeyRepository;
+use crate::shared::metrics::metered_use_case;
+
+/// Removes one of the user's passkeys; the password keeps working
+#[async_trait]
+pub trait IDeletePasskeyUseCase: Send + Sync {
+    async fn execute(&self, user_id: Uuid, passkey_id: Uuid) -> Result<(), PasskeyError>;
+}
+
+metered_use_case!(
+    "auth",
+    "delete_passkey",
+    IDeletePasskeyUseCase,
+    fn execute(&self, user_id: Uuid, passkey_id: Uuid) -> Result<(), PasskeyError>
+);
+
+#[derive(Clone)]
+pub struct DeletePasskeyUseCase {
+    passkeys: Arc<dyn PasskeyRepository>,
+}
+
+impl DeletePasskeyUseCase {
+    pub fn new(passkeys: Arc<dyn PasskeyRepository>) -> Self {
+        Self { passkeys }
+    }
+}
+
+#[async_trait]
+impl IDeletePasskeyUseCase for DeletePasskeyUseCase {
+    async fn execute(&self, user_id: Uuid, passkey_id: Uuid) -> Result<(), PasskeyError> {
+        if self.passkeys.delete(user_id, passkey_id).await? {
+            Ok(())
+        } else {
+            Err(PasskeyError::PasskeyNotFound)
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::PasskeyError;
+use crate::auth::application::ports::outgoing::passkey_challenge_store::CeremonyKind;
+use crate::auth::application::ports::outgoing::{
+    token_provider::TokenProvider, PasskeyCeremony, PasskeyChallengeStore, PasskeyRepository,
+    UserQuery,
+};
+use crate::auth::application::use_cases::login_user::{LoginUserResponse, UserInfo};
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone)]
+pub struct FinishPasskeyLogin {
+    pub challenge_id: Uuid,
+    /// The `PublicKeyCredential` from `navigator.credentials.get()`
+    pub credential: serde_json::Value,
+    pub remember_me: bool,
+}
+
+/// Verifies the passkey's signature and issues the same tokens as a
+/// password login
+#[async_trait]
+pub trait IFinishPasskeyLoginUseCase: Send + Sync {
+    async fn execute(&self, request: FinishPasskeyLogin)
+        -> Result<LoginUserResponse, PasskeyError>;
+}
+
+metered_use_case!(
+    "auth",
+    "finish_passkey_login",
+    IFinishPasskeyLoginUseCase,
+    fn execute(&self, request: FinishPasskeyLogin) -> Result<LoginUserResponse, PasskeyError>
+);
+
+#[derive(Clone)]
+pub struct FinishPasskeyLoginUseCase {
+    users: Arc<dyn UserQuery>,
+    passkeys: Arc<dyn PasskeyRepository>,
+    challenges: Arc<dyn PasskeyChallengeStore>,
+    ceremony: Arc<dyn PasskeyCeremony>,
+    token_provider: Arc<dyn TokenProvider>,
+}
+
+impl FinishPasskeyLoginUseCase {
+    pub fn new(
+        users: Arc<dyn UserQuery>,
+        passkeys: Arc<dyn PasskeyRepository>,
+        challenges: Arc<dyn PasskeyChallengeStore>,
+        ceremony: Arc<dyn PasskeyCeremony>,
+        token_provider: Arc<dyn TokenProvider>,
+    ) -> Self {
+        Self {
+            users,
+            passkeys,
+            challenges,
+            ceremony,
+            token_provider,
+        }
+    }
+}
+
+#[async_trait]
+impl IFinishPasskeyLoginUseCase for FinishPasskeyLoginUseCase {
+    async fn execute(
+        &self,
+        request: FinishPasskeyLogin,
+    ) -> Result<LoginUserResponse, PasskeyError> {
+        let ceremony = self
+            .challenges
+            .take(request.challenge_id)
+            .await?
+            .filter(|c| c.kind == CeremonyKind::Login)
+            .ok_or(PasskeyError::ChallengeNotFound)?;
+
+        let passkeys = self.passkeys.list_for_user(ceremony.user_id).await?;
+        let verified =
+            self.ceremony
+                .finish_login(&request.credential, &ceremony.state, &passkeys)?;
+        self.passkeys
+            .record_use(verified.passkey_id, verified.updated_credential)
+            .await?;
+
+        let user = self
+            .users
+            .find_by_id(ceremony.user_id)
+            .await?
+            .ok_or(PasskeyError::UserNotFound)?;
+
+        // As with the password, only told once the passkey has checked out
+        if user.is_deleted {
+            return Err(PasskeyError::UserDeleted);
+        }
+        if !user.is_active {
+            return Err(PasskeyError::AccountDeactivated);
+        }
+
+        let access_token = self
+            .token_provider
+            .generate_access_token(user.id, user.is_verified, user.token_version)
+            .map_err(|e| PasskeyError::TokenGenerationFailed(e.to_string()))?;
+        let refresh_token = self
+            .token_provider
+            .generate_refresh_token(
+                user.id,
+                user.is_verified,
+                user.token_version,
+                request.remember_me,
+            )
+            .map_err(|e| PasskeyError::TokenGenerationFailed(e.to_string()))?;
+
+        Ok(LoginUserResponse {
+            access_token,
+            refresh_token,
+            user: UserInfo {
+                id: user.id,
+                username: user.username,
+                email: user.email,
+                is_verified: user.is_verified,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::adapter::outgoing::in_memory::{
+        InMemoryPasskeyChallenges, InMemoryPasskeyStore, InMemoryUserStore,
+    };
+    use crate::auth::adapter::outgoing::jwt::{JwtConfig, JwtTokenService};
+    use crate::auth::application::ports::outgoing::passkey_repository::NewPasskey;
+    use crate::auth::application::ports::outgoing::user_query::UserQueryResult;
+    use crate::auth::application::use_cases::passkeys::start_passkey_login::{
+        IStartPasskeyLoginUseCase, StartPasskeyLoginUseCase,
+    };
+    use crate::auth::application::use_cases::passkeys::test_support::FakeCeremony;
+
+    struct Fixture {
+        users: InMemoryUserStore,
+        passkeys: InMemoryPasskeyStore,
+        start: StartPasskeyLoginUseCase,
+        finish: FinishPasskeyLoginUseCase,
+        jwt: JwtTokenService,
+    }
+
+    fn fixture() -> Fixture {
+        let users = InMemoryUserStore::default();
+        let passkeys = InMemoryPasskeyStore::default();
+        let challenges = Arc::new(InMemoryPasskeyChallenges::default());
+        let ceremony = Arc::new(FakeCeremony);
+        let jwt = JwtTokenService::new(JwtConfig {
+            secret_key: "test_secret_key_min_32_characters_long".to_string(),
+            issuer: "testapp".to_string(),
+            audience: "test_audience".to_string(),
+            access_token_expiry: 3600,
+            refresh_token_expiry: 86400,
+            verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
+        });
+
+        Fixture {
+            start: StartPasskeyLoginUseCase::new(
+                Arc::new(users.clone()),
+                Arc::new(passkeys.clone()),
+                challenges.clone(),
+                ceremony.clone(),
+            ),
+            finish: FinishPasskeyLoginUseCase::new(
+                Arc::new(users.clone()),
+                Arc::new(passkeys.clone()),
+                challenges,
+                ceremony,
+                Arc::new(jwt.clone()),
+            ),
+            users,
+            passkeys,
+            jwt,
+        }
+    }
+
+    async fn user_with_passkey(fixture: &Fixture, is_active: bool) -> Uuid {
+        let id = Uuid::new_v4();
+        fixture.users.users.write(|users| {
+            users.push(UserQueryResult {
+                id,
+                username: "passkeyuser".to_string(),
+                email: "passkey@example.com".to_string(),
+                password_hash: "hash".to_string(),
+                full_name: "Passkey User".to_string(),
+                preferred_locale: "en".to_string(),
+                created_at: chrono::Utc::now(),
+                updated_at: chrono::Utc::now(),
+                is_verified: true,
+                is_deleted: false,
+                token_version: 0,
+                is_active,
+            })
+        });
+        fixture
+            .passkeys
+            .add(NewPasskey {
+                user_id: id,
+                name: "Phone".to_string(),
+                credential_id: "cred-1".to_string(),
+                credential: "{}".to_string(),
+            })
+            .await
+            .unwrap();
+        id
+    }
+
+    fn answer(challenge_id: Uuid, credential_id: &str) -> FinishPasskeyLogin {
+        FinishPasskeyLogin {
+            challenge_id,
+            credential: serde_json::json!({ "id": credential_id }),
+            remember_me: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_signed_challenge_issues_tokens_and_stamps_the_passkey() {
+        let fixture = fixture();
+        let user_id = user_with_passkey(&fixture, true).await;
+
+        let challenge = fixture
+            .start
+            .execute(" Passkey@Example.com".to_string())
+            .await
+            .unwrap();
+        let response = fixture
+            .finish
+            .execute(answer(challenge.challenge_id, "cred-1"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.user.id, user_id);
+        let claims = fixture.jwt.verify_token(&response.refresh_token).unwrap();
+        assert!(claims.remember_me);
+        let passkeys = fixture.passkeys.list_for_user(user_id).await.unwrap();
+        assert!(passkeys[0].last_used_at.is_some());
+
+        // Each challenge signs in once
+        let replay = fixture
+            .finish
+            .execute(answer(challenge.challenge_id, "cred-1"))
+            .await;
+        assert!(matches!(replay, Err(PasskeyError::ChallengeNotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_credential_is_rejected() {
+        let fixture = fixture();
+        user_with_passkey(&fixture, true).await;
+
+        let challenge = fixture
+            .start
+            .execute("passkey@example.com".to_string())
+            .await
+            .unwrap();
+        let result = fixture
+            .finish
+            .execute(answer(challenge.challenge_id, "someone-elses"))
+            .await;
+
+        assert!(matches!(result, Err(PasskeyError::VerificationFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_account_without_passkeys_looks_like_unknown_email() {
+        let fixture = fixture();
+
+        let result = fixture
+            .start
+            .execute("nobody@example.com".to_string())
+            .await;
+
+        assert!(matches!(result, Err(PasskeyError::NoPasskeys)));
+    }
+
+    #[tokio::test]
+    async fn test_deactivated_account_is_told_after_the_passkey_checks_out() {
+        let fixture = fixture();
+        user_with_passkey(&fixture, false).await;
+
+        let challenge = fixture
+            .start
+            .execute("passkey@example.com".to_string())
+            .await
+            .unwrap();
+        let result = fixture
+            .finish
+            .execute(answer(challenge.challenge_id, "cred-1"))
+            .await;
+
+        assert!(matches!(result, Err(PasskeyError::AccountDeactivated)));
+    }
+}
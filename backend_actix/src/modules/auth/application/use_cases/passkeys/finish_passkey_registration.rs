@@ -0,0 +1,208 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{PasskeyError, PasskeyInfo, MAX_PASSKEY_NAME_LEN};
+use crate::auth::application::ports::outgoing::passkey_challenge_store::CeremonyKind;
+use crate::auth::application::ports::outgoing::passkey_repository::NewPasskey;
+use crate::auth::application::ports::outgoing::{
+    PasskeyCeremony, PasskeyChallengeStore, PasskeyRepository,
+};
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone)]
+pub struct FinishPasskeyRegistration {
+    pub challenge_id: Uuid,
+    /// Label for the passkey, e.g. "Work laptop"
+    pub name: String,
+    /// The `PublicKeyCredential` from `navigator.credentials.create()`
+    pub credential: serde_json::Value,
+}
+
+/// Verifies the authenticator's answer and stores the new passkey
+#[async_trait]
+pub trait IFinishPasskeyRegistrationUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        user_id: Uuid,
+        request: FinishPasskeyRegistration,
+    ) -> Result<PasskeyInfo, PasskeyError>;
+}
+
+metered_use_case!(
+    "auth",
+    "finish_passkey_registration",
+    IFinishPasskeyRegistrationUseCase,
+    fn execute(
+        &self,
+        user_id: Uuid,
+        request: FinishPasskeyRegistration,
+    ) -> Result<PasskeyInfo, PasskeyError>
+);
+
+#[derive(Clone)]
+pub struct FinishPasskeyRegistrationUseCase {
+    passkeys: Arc<dyn PasskeyRepository>,
+    challenges: Arc<dyn PasskeyChallengeStore>,
+    ceremony: Arc<dyn PasskeyCeremony>,
+}
+
+impl FinishPasskeyRegistrationUseCase {
+    pub fn new(
+        passkeys: Arc<dyn PasskeyRepository>,
+        challenges: Arc<dyn PasskeyChallengeStore>,
+        ceremony: Arc<dyn PasskeyCeremony>,
+    ) -> Self {
+        Self {
+            passkeys,
+            challenges,
+            ceremony,
+        }
+    }
+}
+
+#[async_trait]
+impl IFinishPasskeyRegistrationUseCase for FinishPasskeyRegistrationUseCase {
+    async fn execute(
+        &self,
+        user_id: Uuid,
+        request: FinishPasskeyRegistration,
+    ) -> Result<PasskeyInfo, PasskeyError> {
+        // Checked before the challenge is consumed, so a bad name can be
+        // corrected and the same answer sent again
+        let name = request.name.trim();
+        if name.is_empty() {
+            return Err(PasskeyError::InvalidName("name is required".to_string()));
+        }
+        if name.chars().count() > MAX_PASSKEY_NAME_LEN {
+            return Err(PasskeyError::InvalidName(format!(
+                "at most {MAX_PASSKEY_NAME_LEN} characters"
+            )));
+        }
+
+        let ceremony = self
+            .challenges
+            .take(request.challenge_id)
+            .await?
+            .filter(|c| c.kind == CeremonyKind::Registration && c.user_id == user_id)
+            .ok_or(PasskeyError::ChallengeNotFound)?;
+
+        let verified = self
+            .ceremony
+            .finish_registration(&request.credential, &ceremony.state)?;
+
+        let stored = self
+            .passkeys
+            .add(NewPasskey {
+                user_id,
+                name: name.to_string(),
+                credential_id: verified.credential_id,
+                credential: verified.credential,
+            })
+            .await?;
+
+        Ok(stored.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::adapter::outgoing::in_memory::{
+        InMemoryPasskeyChallenges, InMemoryPasskeyStore,
+    };
+    use crate::auth::application::ports::outgoing::passkey_challenge_store::PendingCeremony;
+    use crate::auth::application::use_cases::passkeys::test_support::FakeCeremony;
+    use crate::auth::application::use_cases::passkeys::CHALLENGE_TTL;
+
+    async fn started(challenges: &InMemoryPasskeyChallenges, user_id: Uuid) -> Uuid {
+        let challenge_id = Uuid::new_v4();
+        challenges
+            .put(
+                challenge_id,
+                PendingCeremony {
+                    kind: CeremonyKind::Registration,
+                    user_id,
+                    state: "registration-state".to_string(),
+                },
+                CHALLENGE_TTL,
+            )
+            .await
+            .unwrap();
+        challenge_id
+    }
+
+    fn request(challenge_id: Uuid, name: &str) -> FinishPasskeyRegistration {
+        FinishPasskeyRegistration {
+            challenge_id,
+            name: name.to_string(),
+            credential: serde_json::json!({ "id": "cred-1" }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verified_answer_stores_the_passkey_once() {
+        let store = InMemoryPasskeyStore::default();
+        let challenges = InMemoryPasskeyChallenges::default();
+        let user_id = Uuid::new_v4();
+        let challenge_id = started(&challenges, user_id).await;
+        let use_case = FinishPasskeyRegistrationUseCase::new(
+            Arc::new(store.clone()),
+            Arc::new(challenges),
+            Arc::new(FakeCeremony),
+        );
+
+        let info = use_case
+            .execute(user_id, request(challenge_id, "  Work laptop "))
+            .await
+            .unwrap();
+
+        assert_eq!(info.name, "Work laptop");
+        let stored = store.list_for_user(user_id).await.unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].credential_id, "cred-1");
+
+        // The challenge is spent
+        let replay = use_case
+            .execute(user_id, request(challenge_id, "Work laptop"))
+            .await;
+        assert!(matches!(replay, Err(PasskeyError::ChallengeNotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_challenge_of_another_user_is_not_found() {
+        let challenges = InMemoryPasskeyChallenges::default();
+        let challenge_id = started(&challenges, Uuid::new_v4()).await;
+        let use_case = FinishPasskeyRegistrationUseCase::new(
+            Arc::new(InMemoryPasskeyStore::default()),
+            Arc::new(challenges),
+            Arc::new(FakeCeremony),
+        );
+
+        let result = use_case
+            .execute(Uuid::new_v4(), request(challenge_id, "Phone"))
+            .await;
+
+        assert!(matches!(result, Err(PasskeyError::ChallengeNotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_blank_name_keeps_the_challenge() {
+        let challenges = InMemoryPasskeyChallenges::default();
+        let user_id = Uuid::new_v4();
+        let challenge_id = started(&challenges, user_id).await;
+        let use_case = FinishPasskeyRegistrationUseCase::new(
+            Arc::new(InMemoryPasskeyStore::default()),
+            Arc::new(challenges),
+            Arc::new(FakeCeremony),
+        );
+
+        let result = use_case.execute(user_id, request(challenge_id, "  ")).await;
+        assert!(matches!(result, Err(PasskeyError::InvalidName(_))));
+
+        assert!(use_case
+            .execute(user_id, request(challenge_id, "Phone"))
+            .await
+            .is_ok());
+    }
+}
@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{PasskeyError, PasskeyInfo};
+use crate::auth::application::ports::outgoing::PasskeyRepository;
+use crate::shared::metrics::metered_use_case;
+
+#[async_trait]
+pub trait IListPasskeysUseCase: Send + Sync {
+    async fn execute(&self, user_id: Uuid) -> Result<Vec<PasskeyInfo>, PasskeyError>;
+}
+
+metered_use_case!(
+    "auth",
+    "list_passkeys",
+    IListPasskeysUseCase,
+    fn execute(&self, user_id: Uuid) -> Result<Vec<PasskeyInfo>, PasskeyError>
+);
+
+#[derive(Clone)]
+pub struct ListPasskeysUseCase {
+    passkeys: Arc<dyn PasskeyRepository>,
+}
+
+impl ListPasskeysUseCase {
+    pub fn new(passkeys: Arc<dyn PasskeyRepository>) -> Self {
+        Self { passkeys }
+    }
+}
+
+#[async_trait]
+impl IListPasskeysUseCase for ListPasskeysUseCase {
+    async fn execute(&self, user_id: Uuid) -> Result<Vec<PasskeyInfo>, PasskeyError> {
+        let passkeys = self.passkeys.list_for_user(user_id).await?;
+        Ok(passkeys.into_iter().map(PasskeyInfo::from).collect())
+    }
+}
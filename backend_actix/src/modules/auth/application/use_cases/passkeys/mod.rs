@@ -0,0 +1,195 @@
+//! Passkey (WebAuthn) sign-in, an alternative to the password.
+//!
+//! Both ceremonies take two requests: `start` issues a challenge and keeps
+//! its state in the `PasskeyChallengeStore`; `finish` takes the browser's
+//! signed answer, verifies it against that state, and consumes it.
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::auth::application::ports::outgoing::passkey_ceremony::PasskeyCeremonyError;
+use crate::auth::application::ports::outgoing::passkey_repository::{
+    PasskeyStoreError, StoredPasskey,
+};
+use crate::auth::application::ports::outgoing::user_query::UserQueryError;
+
+pub mod delete_passkey;
+pub mod finish_passkey_login;
+pub mod finish_passkey_registration;
+pub mod list_passkeys;
+pub mod start_passkey_login;
+pub mod start_passkey_registration;
+
+/// How long the browser has to answer a challenge
+pub const CHALLENGE_TTL: Duration = Duration::from_secs(300);
+
+pub const MAX_PASSKEY_NAME_LEN: usize = 64;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum PasskeyError {
+    /// No relying party configured
+    #[error("Passkeys are not enabled")]
+    Disabled,
+
+    /// Unknown, expired, already answered, or started by someone else
+    #[error("Passkey challenge not found or expired")]
+    ChallengeNotFound,
+
+    #[error("Passkey verification failed: {0}")]
+    VerificationFailed(String),
+
+    #[error("Passkey already registered")]
+    AlreadyRegistered,
+
+    #[error("Passkey not found")]
+    PasskeyNotFound,
+
+    /// Also for unknown and deleted accounts, so the two can't be told apart
+    #[error("No passkey is registered for this account")]
+    NoPasskeys,
+
+    #[error("Invalid passkey name: {0}")]
+    InvalidName(String),
+
+    #[error("User not found")]
+    UserNotFound,
+
+    #[error("User account has been deleted")]
+    UserDeleted,
+
+    /// The passkey checked out, but the owner deactivated the account
+    #[error("User account is deactivated")]
+    AccountDeactivated,
+
+    #[error("Token generation failed: {0}")]
+    TokenGenerationFailed(String),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<PasskeyStoreError> for PasskeyError {
+    fn from(err: PasskeyStoreError) -> Self {
+        match err {
+            PasskeyStoreError::AlreadyRegistered => Self::AlreadyRegistered,
+            PasskeyStoreError::DatabaseError(e) => Self::RepositoryError(e),
+        }
+    }
+}
+
+impl From<PasskeyCeremonyError> for PasskeyError {
+    fn from(err: PasskeyCeremonyError) -> Self {
+        match err {
+            PasskeyCeremonyError::Disabled => Self::Disabled,
+            PasskeyCeremonyError::Rejected(e) => Self::VerificationFailed(e),
+            PasskeyCeremonyError::Internal(e) => Self::RepositoryError(e),
+        }
+    }
+}
+
+impl From<UserQueryError> for PasskeyError {
+    fn from(err: UserQueryError) -> Self {
+        Self::RepositoryError(err.to_string())
+    }
+}
+
+/// A started ceremony, for the browser
+#[derive(Debug, Clone, Serialize)]
+pub struct PasskeyChallenge {
+    /// Sent back with the answer
+    pub challenge_id: Uuid,
+    /// For `navigator.credentials.create()` or `.get()`
+    pub options: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PasskeyInfo {
+    pub id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<StoredPasskey> for PasskeyInfo {
+    fn from(passkey: StoredPasskey) -> Self {
+        Self {
+            id: passkey.id,
+            name: passkey.name,
+            created_at: passkey.created_at,
+            last_used_at: passkey.last_used_at,
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use uuid::Uuid;
+
+    use crate::auth::application::ports::outgoing::passkey_ceremony::{
+        Challenge, PasskeyCeremony, PasskeyCeremonyError, VerifiedLogin, VerifiedRegistration,
+    };
+    use crate::auth::application::ports::outgoing::passkey_repository::StoredPasskey;
+
+    /// Takes an answer's `id` as the credential it was signed with: a
+    /// registration stores it, a login accepts it if it is one of the
+    /// user's passkeys
+    #[derive(Default)]
+    pub struct FakeCeremony;
+
+    impl PasskeyCeremony for FakeCeremony {
+        fn start_registration(
+            &self,
+            _user_id: Uuid,
+            _username: &str,
+            _display_name: &str,
+            _existing: &[StoredPasskey],
+        ) -> Result<Challenge, PasskeyCeremonyError> {
+            Ok(Challenge {
+                options: serde_json::json!({ "publicKey": {} }),
+                state: "registration-state".to_string(),
+            })
+        }
+
+        fn finish_registration(
+            &self,
+            response: &serde_json::Value,
+            _state: &str,
+        ) -> Result<VerifiedRegistration, PasskeyCeremonyError> {
+            let id = response["id"]
+                .as_str()
+                .ok_or_else(|| PasskeyCeremonyError::Rejected("no id".to_string()))?;
+            Ok(VerifiedRegistration {
+                credential_id: id.to_string(),
+                credential: response.to_string(),
+            })
+        }
+
+        fn start_login(
+            &self,
+            _passkeys: &[StoredPasskey],
+        ) -> Result<Challenge, PasskeyCeremonyError> {
+            Ok(Challenge {
+                options: serde_json::json!({ "publicKey": {} }),
+                state: "login-state".to_string(),
+            })
+        }
+
+        fn finish_login(
+            &self,
+            response: &serde_json::Value,
+            _state: &str,
+            passkeys: &[StoredPasskey],
+        ) -> Result<VerifiedLogin, PasskeyCeremonyError> {
+            passkeys
+                .iter()
+                .find(|passkey| response["id"] == passkey.credential_id.as_str())
+                .map(|passkey| VerifiedLogin {
+                    passkey_id: passkey.id,
+                    updated_credential: None,
+                })
+                .ok_or_else(|| PasskeyCeremonyError::Rejected("unknown credential".to_string()))
+        }
+    }
+}
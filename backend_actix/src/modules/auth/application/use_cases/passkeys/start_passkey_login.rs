@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{PasskeyChallenge, PasskeyError, CHALLENGE_TTL};
+use crate::auth::application::ports::outgoing::passkey_challenge_store::{
+    CeremonyKind, PendingCeremony,
+};
+use crate::auth::application::ports::outgoing::{
+    PasskeyCeremony, PasskeyChallengeStore, PasskeyRepository, UserQuery,
+};
+use crate::shared::metrics::metered_use_case;
+
+/// First half of signing in with a passkey instead of the password
+#[async_trait]
+pub trait IStartPasskeyLoginUseCase: Send + Sync {
+    async fn execute(&self, email: String) -> Result<PasskeyChallenge, PasskeyError>;
+}
+
+metered_use_case!(
+    "auth",
+    "start_passkey_login",
+    IStartPasskeyLoginUseCase,
+    fn execute(&self, email: String) -> Result<PasskeyChallenge, PasskeyError>
+);
+
+#[derive(Clone)]
+pub struct StartPasskeyLoginUseCase {
+    users: Arc<dyn UserQuery>,
+    passkeys: Arc<dyn PasskeyRepository>,
+    challenges: Arc<dyn PasskeyChallengeStore>,
+    ceremony: Arc<dyn PasskeyCeremony>,
+}
+
+impl StartPasskeyLoginUseCase {
+    pub fn new(
+        users: Arc<dyn UserQuery>,
+        passkeys: Arc<dyn PasskeyRepository>,
+        challenges: Arc<dyn PasskeyChallengeStore>,
+        ceremony: Arc<dyn PasskeyCeremony>,
+    ) -> Self {
+        Self {
+            users,
+            passkeys,
+            challenges,
+            ceremony,
+        }
+    }
+}
+
+#[async_trait]
+impl IStartPasskeyLoginUseCase for StartPasskeyLoginUseCase {
+    async fn execute(&self, email: String) -> Result<PasskeyChallenge, PasskeyError> {
+        if !self.ceremony.enabled() {
+            return Err(PasskeyError::Disabled);
+        }
+
+        let email = email.trim().to_lowercase();
+        let user = self
+            .users
+            .find_by_email(&email)
+            .await?
+            .filter(|user| !user.is_deleted)
+            .ok_or(PasskeyError::NoPasskeys)?;
+
+        let passkeys = self.passkeys.list_for_user(user.id).await?;
+        if passkeys.is_empty() {
+            return Err(PasskeyError::NoPasskeys);
+        }
+        let challenge = self.ceremony.start_login(&passkeys)?;
+
+        let challenge_id = Uuid::new_v4();
+        self.challenges
+            .put(
+                challenge_id,
+                PendingCeremony {
+                    kind: CeremonyKind::Login,
+                    user_id: user.id,
+                    state: challenge.state,
+                },
+                CHALLENGE_TTL,
+            )
+            .await?;
+
+        Ok(PasskeyChallenge {
+            challenge_id,
+            options: challenge.options,
+        })
+    }
+}
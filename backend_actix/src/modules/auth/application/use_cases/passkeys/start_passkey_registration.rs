@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{PasskeyChallenge, PasskeyError, CHALLENGE_TTL};
+use crate::auth::application::ports::outgoing::passkey_challenge_store::{
+    CeremonyKind, PendingCeremony,
+};
+use crate::auth::application::ports::outgoing::{
+    PasskeyCeremony, PasskeyChallengeStore, PasskeyRepository, UserQuery,
+};
+use crate::shared::metrics::metered_use_case;
+
+/// First half of adding a passkey to the signed-in user's account
+#[async_trait]
+pub trait IStartPasskeyRegistrationUseCase: Send + Sync {
+    async fn execute(&self, user_id: Uuid) -> Result<PasskeyChallenge, PasskeyError>;
+}
+
+metered_use_case!(
+    "auth",
+    "start_passkey_registration",
+    IStartPasskeyRegistrationUseCase,
+    fn execute(&self, user_id: Uuid) -> Result<PasskeyChallenge, PasskeyError>
+);
+
+#[derive(Clone)]
+pub struct StartPasskeyRegistrationUseCase {
+    users: Arc<dyn UserQuery>,
+    passkeys: Arc<dyn PasskeyRepository>,
+    challenges: Arc<dyn PasskeyChallengeStore>,
+    ceremony: Arc<dyn PasskeyCeremony>,
+}
+
+impl StartPasskeyRegistrationUseCase {
+    pub fn new(
+        users: Arc<dyn UserQuery>,
+        passkeys: Arc<dyn PasskeyRepository>,
+        challenges: Arc<dyn PasskeyChallengeStore>,
+        ceremony: Arc<dyn PasskeyCeremony>,
+    ) -> Self {
+        Self {
+            users,
+            passkeys,
+            challenges,
+            ceremony,
+        }
+    }
+}
+
+#[async_trait]
+impl IStartPasskeyRegistrationUseCase for StartPasskeyRegistrationUseCase {
+    async fn execute(&self, user_id: Uuid) -> Result<PasskeyChallenge, PasskeyError> {
+        if !self.ceremony.enabled() {
+            return Err(PasskeyError::Disabled);
+        }
+
+        let user = self
+            .users
+            .find_by_id(user_id)
+            .await?
+            .filter(|user| !user.is_deleted)
+            .ok_or(PasskeyError::UserNotFound)?;
+
+        // The authenticator refuses to register a second credential for
+        // an account it already holds one of
+        let existing = self.passkeys.list_for_user(user_id).await?;
+        let challenge =
+            self.ceremony
+                .start_registration(user.id, &user.email, &user.full_name, &existing)?;
+
+        let challenge_id = Uuid::new_v4();
+        self.challenges
+            .put(
+                challenge_id,
+                PendingCeremony {
+                    kind: CeremonyKind::Registration,
+                    user_id,
+                    state: challenge.state,
+                },
+                CHALLENGE_TTL,
+            )
+            .await?;
+
+        Ok(PasskeyChallenge {
+            challenge_id,
+            options: challenge.options,
+        })
+    }
+}
@@ -0,0 +1,208 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::info;
+
+use crate::auth::application::ports::outgoing::{
+    password_hasher::PasswordHasher, AccountStatusRepository, UserQuery,
+};
+use crate::auth::application::use_cases::login_user::LoginRequest;
+use crate::shared::metrics::metered_use_case;
+
+// ====================== Reactivate Account Errors ======================
+#[derive(Debug, Clone)]
+pub enum ReactivateAccountError {
+    InvalidCredentials,
+    /// Deletion is final; only deactivation can be undone
+    UserDeleted,
+    PasswordVerificationFailed(String),
+    QueryError(String),
+    DatabaseError(String),
+}
+
+impl std::fmt::Display for ReactivateAccountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReactivateAccountError::InvalidCredentials => write!(f, "Invalid email or password"),
+            ReactivateAccountError::UserDeleted => write!(f, "User account has been deleted"),
+            ReactivateAccountError::PasswordVerificationFailed(msg) => {
+                write!(f, "Password verification failed: {}", msg)
+            }
+            ReactivateAccountError::QueryError(msg) => write!(f, "Query error: {}", msg),
+            ReactivateAccountError::DatabaseError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ReactivateAccountError {}
+
+// ==================== Reactivate Account Use Case ======================
+/// Brings a deactivated account back with its login credentials, since its
+/// owner holds no valid token anymore. Reactivating an active account is a
+/// no-op; the owner logs in afterwards as usual.
+#[async_trait]
+pub trait IReactivateAccountUseCase: Send + Sync {
+    async fn execute(&self, request: LoginRequest) -> Result<(), ReactivateAccountError>;
+}
+
+metered_use_case!(
+    "auth",
+    "reactivate_account",
+    IReactivateAccountUseCase,
+    fn execute(&self, request: LoginRequest) -> Result<(), ReactivateAccountError>
+);
+
+#[derive(Clone)]
+pub struct ReactivateAccountUseCase<Q>
+where
+    Q: UserQuery + Send + Sync,
+{
+    query: Q,
+    password_hasher: Arc<dyn PasswordHasher>,
+    accounts: Arc<dyn AccountStatusRepository>,
+}
+
+impl<Q> ReactivateAccountUseCase<Q>
+where
+    Q: UserQuery + Send + Sync,
+{
+    pub fn new(
+        query: Q,
+        password_hasher: Arc<dyn PasswordHasher>,
+        accounts: Arc<dyn AccountStatusRepository>,
+    ) -> Self {
+        Self {
+            query,
+            password_hasher,
+            accounts,
+        }
+    }
+}
+
+#[async_trait]
+impl<Q> IReactivateAccountUseCase for ReactivateAccountUseCase<Q>
+where
+    Q: UserQuery + Send + Sync,
+{
+    async fn execute(&self, request: LoginRequest) -> Result<(), ReactivateAccountError> {
+        let user = self
+            .query
+            .find_by_email(request.email())
+            .await
+            .map_err(|e| ReactivateAccountError::QueryError(e.to_string()))?
+            .ok_or(ReactivateAccountError::InvalidCredentials)?;
+
+        if user.is_deleted {
+            return Err(ReactivateAccountError::UserDeleted);
+        }
+
+        let is_valid = self
+            .password_hasher
+            .verify_password(request.password(), &user.password_hash)
+            .await
+            .map_err(|e| ReactivateAccountError::PasswordVerificationFailed(e.to_string()))?;
+        if !is_valid {
+            return Err(ReactivateAccountError::InvalidCredentials);
+        }
+
+        if !user.is_active {
+            self.accounts
+                .set_active(user.id, true)
+                .await
+                .map_err(|e| ReactivateAccountError::DatabaseError(e.to_string()))?;
+            info!("Reactivated account of user {}", user.id);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    use crate::auth::adapter::outgoing::in_memory::InMemoryUserStore;
+    use crate::auth::application::ports::outgoing::password_hasher::HashError;
+    use crate::auth::application::ports::outgoing::user_repository::{
+        CreateUserData, UserRepository,
+    };
+
+    /// Accepts exactly the password "right"
+    struct PlainHasher;
+
+    #[async_trait]
+    impl PasswordHasher for PlainHasher {
+        async fn hash_password(&self, password: &str) -> Result<String, HashError> {
+            Ok(password.to_string())
+        }
+
+        async fn verify_password(&self, password: &str, hash: &str) -> Result<bool, HashError> {
+            Ok(password == hash)
+        }
+    }
+
+    async fn deactivated_jane(store: &InMemoryUserStore) -> Uuid {
+        let user = store
+            .create_user(CreateUserData {
+                email: "jane@example.com".to_string(),
+                username: "jane".to_string(),
+                password_hash: "right".to_string(),
+                full_name: "Jane Doe".to_string(),
+                preferred_locale: "en".to_string(),
+            })
+            .await
+            .unwrap();
+        store.set_active(user.id, false).await.unwrap();
+        user.id
+    }
+
+    fn use_case(store: &InMemoryUserStore) -> ReactivateAccountUseCase<InMemoryUserStore> {
+        ReactivateAccountUseCase::new(
+            store.clone(),
+            Arc::new(PlainHasher),
+            Arc::new(store.clone()),
+        )
+    }
+
+    fn credentials(password: &str) -> LoginRequest {
+        LoginRequest::new("jane@example.com".to_string(), password.to_string()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_reactivate_with_right_password() {
+        let store = InMemoryUserStore::default();
+        let user_id = deactivated_jane(&store).await;
+
+        use_case(&store)
+            .execute(credentials("right"))
+            .await
+            .unwrap();
+
+        assert!(store.find_by_id(user_id).await.unwrap().unwrap().is_active);
+    }
+
+    #[tokio::test]
+    async fn test_reactivate_with_wrong_password_keeps_account_inactive() {
+        let store = InMemoryUserStore::default();
+        let user_id = deactivated_jane(&store).await;
+
+        let result = use_case(&store).execute(credentials("wrong")).await;
+
+        assert!(matches!(
+            result,
+            Err(ReactivateAccountError::InvalidCredentials)
+        ));
+        assert!(!store.find_by_id(user_id).await.unwrap().unwrap().is_active);
+    }
+
+    #[tokio::test]
+    async fn test_deleted_account_cannot_be_reactivated() {
+        let store = InMemoryUserStore::default();
+        let user_id = deactivated_jane(&store).await;
+        store.soft_delete_user(user_id).await.unwrap();
+
+        let result = use_case(&store).execute(credentials("right")).await;
+
+        assert!(matches!(result, Err(ReactivateAccountError::UserDeleted)));
+    }
+}
@@ -3,7 +3,9 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use serde::{Deserialize, Deserializer, Serialize};
 
+use crate::auth::application::helpers::TokenVersionGuard;
 use crate::auth::application::ports::outgoing::token_provider::{TokenError, TokenProvider};
+use crate::shared::metrics::metered_use_case;
 
 // ========================= Refresh Token Request =========================
 /// Validated refresh token request
@@ -70,7 +72,10 @@ pub enum RefreshTokenError {
     TokenNotYetValid,
     InvalidTokenType,
     InvalidSignature,
+    /// Issued before the user logged out from all devices
+    TokenRevoked,
     TokenGenerationFailed(String),
+    RevocationCheckFailed(String),
 }
 
 impl std::fmt::Display for RefreshTokenError {
@@ -81,9 +86,13 @@ impl std::fmt::Display for RefreshTokenError {
             RefreshTokenError::TokenNotYetValid => write!(f, "Token is not yet valid"),
             RefreshTokenError::InvalidTokenType => write!(f, "Invalid token type"),
             RefreshTokenError::InvalidSignature => write!(f, "Invalid token signature"),
+            RefreshTokenError::TokenRevoked => write!(f, "Refresh token has been revoked"),
             RefreshTokenError::TokenGenerationFailed(msg) => {
                 write!(f, "Token generation failed: {}", msg)
             }
+            RefreshTokenError::RevocationCheckFailed(msg) => {
+                write!(f, "Revocation check failed: {}", msg)
+            }
         }
     }
 }
@@ -98,7 +107,9 @@ impl From<TokenError> for RefreshTokenError {
             TokenError::TokenNotYetValid => RefreshTokenError::TokenNotYetValid,
             TokenError::InvalidTokenType(_) => RefreshTokenError::InvalidTokenType,
             TokenError::InvalidSignature => RefreshTokenError::InvalidSignature,
-            TokenError::MalformedToken => RefreshTokenError::TokenInvalid,
+            TokenError::WrongIssuerOrAudience | TokenError::MalformedToken => {
+                RefreshTokenError::TokenInvalid
+            }
             TokenError::EncodingError(msg) => RefreshTokenError::TokenGenerationFailed(msg),
         }
     }
@@ -109,6 +120,8 @@ impl From<TokenError> for RefreshTokenError {
 pub struct RefreshTokenResponse {
     pub access_token: String,
     pub refresh_token: String, // Optional: return new refresh token (token rotation)
+    /// Whether the refresh token is a long-lived "remember me" one
+    pub remember_me: bool,
 }
 
 // ============================ Refresh Token Use Case =============================
@@ -121,11 +134,22 @@ pub trait IRefreshTokenUseCase: Send + Sync {
     ) -> Result<RefreshTokenResponse, RefreshTokenError>;
 }
 
+metered_use_case!(
+    "auth",
+    "refresh_token",
+    IRefreshTokenUseCase,
+    fn execute(
+        &self,
+        request: RefreshTokenRequest,
+    ) -> Result<RefreshTokenResponse, RefreshTokenError>
+);
+
 /// Implementation of Refresh Token use case
 #[derive(Clone)]
 pub struct RefreshTokenUseCase {
     token_provider: Arc<dyn TokenProvider>,
     enable_token_rotation: bool, // Feature flag for token rotation
+    token_version_guard: Option<TokenVersionGuard>,
 }
 
 impl RefreshTokenUseCase {
@@ -133,6 +157,7 @@ impl RefreshTokenUseCase {
         Self {
             token_provider,
             enable_token_rotation: true, // Enable token rotation by default
+            token_version_guard: None,
         }
     }
 
@@ -140,6 +165,12 @@ impl RefreshTokenUseCase {
         self.enable_token_rotation = enable;
         self
     }
+
+    /// Refuse refresh tokens revoked by "logout from all devices"
+    pub fn with_token_version_guard(mut self, guard: TokenVersionGuard) -> Self {
+        self.token_version_guard = Some(guard);
+        self
+    }
 }
 
 #[async_trait]
@@ -159,16 +190,31 @@ impl IRefreshTokenUseCase for RefreshTokenUseCase {
             return Err(RefreshTokenError::InvalidTokenType);
         }
 
+        if let Some(guard) = &self.token_version_guard {
+            let is_current = guard
+                .is_current(claims.sub, claims.token_version)
+                .await
+                .map_err(|e| RefreshTokenError::RevocationCheckFailed(e.to_string()))?;
+            if !is_current {
+                return Err(RefreshTokenError::TokenRevoked);
+            }
+        }
+
         // 3️⃣ Generate new access token
         let access_token = self
             .token_provider
-            .generate_access_token(claims.sub, claims.is_verified)
+            .generate_access_token(claims.sub, claims.is_verified, claims.token_version)
             .map_err(|e| RefreshTokenError::TokenGenerationFailed(e.to_string()))?;
 
         // 4️⃣ Optionally generate new refresh token (token rotation)
         let refresh_token = if self.enable_token_rotation {
             self.token_provider
-                .generate_refresh_token(claims.sub, claims.is_verified)
+                .generate_refresh_token(
+                    claims.sub,
+                    claims.is_verified,
+                    claims.token_version,
+                    claims.remember_me,
+                )
                 .map_err(|e| RefreshTokenError::TokenGenerationFailed(e.to_string()))?
         } else {
             // Return the same refresh token
@@ -179,6 +225,7 @@ impl IRefreshTokenUseCase for RefreshTokenUseCase {
         Ok(RefreshTokenResponse {
             access_token,
             refresh_token,
+            remember_me: claims.remember_me,
         })
     }
 }
@@ -195,9 +242,11 @@ mod tests {
         JwtTokenService::new(JwtConfig {
             secret_key: "test_secret_key_min_32_characters_long".to_string(),
             issuer: "testapp".to_string(),
+            audience: "test_audience".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         })
     }
 
@@ -286,7 +335,9 @@ mod tests {
         let user_id = Uuid::new_v4();
 
         // Generate a valid refresh token
-        let refresh_token = jwt_service.generate_refresh_token(user_id, true).unwrap();
+        let refresh_token = jwt_service
+            .generate_refresh_token(user_id, true, 0, false)
+            .unwrap();
 
         let use_case = RefreshTokenUseCase::new(Arc::new(jwt_service));
         let request = RefreshTokenRequest::new(refresh_token).unwrap();
@@ -304,7 +355,9 @@ mod tests {
         let jwt_service = create_jwt_service();
         let user_id = Uuid::new_v4();
 
-        let original_refresh_token = jwt_service.generate_refresh_token(user_id, true).unwrap();
+        let original_refresh_token = jwt_service
+            .generate_refresh_token(user_id, true, 0, false)
+            .unwrap();
 
         // Add a 32 second delay to ensure different timestamps follow the `validation.leeway = 30;` in JWT Service
         tokio::time::sleep(tokio::time::Duration::from_millis(1920)).await;
@@ -326,7 +379,9 @@ mod tests {
         let jwt_service = create_jwt_service();
         let user_id = Uuid::new_v4();
 
-        let original_refresh_token = jwt_service.generate_refresh_token(user_id, true).unwrap();
+        let original_refresh_token = jwt_service
+            .generate_refresh_token(user_id, true, 0, false)
+            .unwrap();
 
         let use_case = RefreshTokenUseCase::new(Arc::new(jwt_service)).with_token_rotation(false);
 
@@ -345,13 +400,17 @@ mod tests {
         let jwt_service = JwtTokenService::new(JwtConfig {
             secret_key: "test_secret_key_min_32_characters_long".to_string(),
             issuer: "testapp".to_string(),
+            audience: "test_audience".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: -60, // Expired token
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         });
 
         let user_id = Uuid::new_v4();
-        let expired_token = jwt_service.generate_refresh_token(user_id, true).unwrap();
+        let expired_token = jwt_service
+            .generate_refresh_token(user_id, true, 0, false)
+            .unwrap();
 
         let use_case = RefreshTokenUseCase::new(Arc::new(create_jwt_service()));
         let request = RefreshTokenRequest::new(expired_token).unwrap();
@@ -384,7 +443,7 @@ mod tests {
         let user_id = Uuid::new_v4();
 
         // Generate an access token instead of refresh token
-        let access_token = jwt_service.generate_access_token(user_id, true).unwrap();
+        let access_token = jwt_service.generate_access_token(user_id, true, 0).unwrap();
 
         let use_case = RefreshTokenUseCase::new(Arc::new(jwt_service));
         let request = RefreshTokenRequest::new(access_token).unwrap();
@@ -421,15 +480,19 @@ mod tests {
         let jwt_service1 = JwtTokenService::new(JwtConfig {
             secret_key: "secret_one_min_32_characters_long_key".to_string(),
             issuer: "testapp".to_string(),
+            audience: "test_audience".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         });
 
         let jwt_service2 = create_jwt_service(); // Different secret
 
         let user_id = Uuid::new_v4();
-        let token = jwt_service1.generate_refresh_token(user_id, true).unwrap();
+        let token = jwt_service1
+            .generate_refresh_token(user_id, true, 0, false)
+            .unwrap();
 
         let use_case = RefreshTokenUseCase::new(Arc::new(jwt_service2));
         let request = RefreshTokenRequest::new(token).unwrap();
@@ -442,13 +505,41 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_refresh_token_revoked_by_logout_all() {
+        use crate::tests::support::stubs::{InMemoryCache, StubTokenVersionRepository};
+
+        let jwt_service = create_jwt_service();
+        let refresh_token = jwt_service
+            .generate_refresh_token(Uuid::new_v4(), true, 0, false)
+            .unwrap();
+        let guard = TokenVersionGuard::new(
+            Arc::new(StubTokenVersionRepository::at(1)),
+            Arc::new(InMemoryCache::default()),
+        );
+
+        let use_case =
+            RefreshTokenUseCase::new(Arc::new(jwt_service)).with_token_version_guard(guard);
+        let result = use_case
+            .execute(RefreshTokenRequest::new(refresh_token).unwrap())
+            .await;
+
+        assert!(
+            matches!(result, Err(RefreshTokenError::TokenRevoked)),
+            "Expected TokenRevoked, got {:?}",
+            result
+        );
+    }
+
     #[tokio::test]
     async fn test_refresh_token_preserves_user_verification_status() {
         let jwt_service = create_jwt_service();
         let user_id = Uuid::new_v4();
 
         // Test with verified user
-        let refresh_token_verified = jwt_service.generate_refresh_token(user_id, true).unwrap();
+        let refresh_token_verified = jwt_service
+            .generate_refresh_token(user_id, true, 0, false)
+            .unwrap();
 
         let use_case = RefreshTokenUseCase::new(Arc::new(jwt_service.clone()));
         let request = RefreshTokenRequest::new(refresh_token_verified).unwrap();
@@ -460,7 +551,9 @@ mod tests {
         assert_eq!(claims.is_verified, true);
 
         // Test with unverified user
-        let refresh_token_unverified = jwt_service.generate_refresh_token(user_id, false).unwrap();
+        let refresh_token_unverified = jwt_service
+            .generate_refresh_token(user_id, false, 0, false)
+            .unwrap();
 
         let request = RefreshTokenRequest::new(refresh_token_unverified).unwrap();
         let result = use_case.execute(request).await;
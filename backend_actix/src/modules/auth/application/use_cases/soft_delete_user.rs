@@ -5,6 +5,7 @@ use uuid::Uuid;
 use crate::auth::application::ports::outgoing::{
     token_repository::TokenRepository, UserRepository,
 };
+use crate::shared::metrics::metered_use_case;
 
 // ====================== Soft Delete Request ======================
 #[derive(Debug, Clone)]
@@ -44,6 +45,13 @@ pub trait ISoftDeleteUserUseCase: Send + Sync {
     async fn execute(&self, request: SoftDeleteUserRequest) -> Result<(), SoftDeleteUserError>;
 }
 
+metered_use_case!(
+    "auth",
+    "soft_delete_user",
+    ISoftDeleteUserUseCase,
+    fn execute(&self, request: SoftDeleteUserRequest) -> Result<(), SoftDeleteUserError>
+);
+
 pub struct SoftDeleteUserUseCase<U, T>
 where
     U: UserRepository + Send + Sync,
@@ -258,10 +266,11 @@ mod tests {
             unimplemented!()
         }
 
-        async fn set_full_name(
+        async fn update_profile(
             &self,
             _user_id: Uuid,
             _full_name: String,
+            _preferred_locale: Option<String>,
         ) -> Result<UserResult, UserRepositoryError> {
             unimplemented!()
         }
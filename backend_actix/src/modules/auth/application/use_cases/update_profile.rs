@@ -4,6 +4,7 @@ use crate::auth::application::{
     domain::entities::UserId,
     ports::outgoing::{user_query::UserQueryError, UserRepositoryError},
 };
+use crate::shared::metrics::metered_use_case;
 
 #[derive(Clone, Debug)]
 pub struct UpdateUserOutput {
@@ -11,12 +12,15 @@ pub struct UpdateUserOutput {
     pub username: String,
     pub email: String,
     pub full_name: String,
+    pub preferred_locale: String,
 }
 
 #[derive(Clone, Debug)]
 pub struct UpdateUserInput {
     pub user_id: UserId,
     pub full_name: String,
+    /// Keeps the current locale when `None`
+    pub preferred_locale: Option<String>,
 }
 
 #[derive(Debug, thiserror::Error, Clone)]
@@ -24,6 +28,9 @@ pub enum UpdateUserError {
     #[error("Invalid full name: {0}")]
     InvalidFullName(String),
 
+    #[error("Invalid locale: {0}")]
+    InvalidLocale(String),
+
     #[error("Repository error: {0}")]
     RepositoryError(#[from] UserRepositoryError),
 
@@ -35,3 +42,10 @@ pub enum UpdateUserError {
 pub trait UpdateUserProfileUseCase: Send + Sync {
     async fn execute(&self, data: UpdateUserInput) -> Result<UpdateUserOutput, UpdateUserError>;
 }
+
+metered_use_case!(
+    "auth",
+    "update_user_profile",
+    UpdateUserProfileUseCase,
+    fn execute(&self, data: UpdateUserInput) -> Result<UpdateUserOutput, UpdateUserError>
+);
@@ -0,0 +1,109 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::auth::application::ports::outgoing::user_profile_repository::{
+    StoredUserProfile, UserProfileRepositoryError,
+};
+use crate::project::application::ports::outgoing::project_repository::PatchField;
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserProfile {
+    pub user_id: UserId,
+    pub email: String,
+    pub username: String,
+    pub full_name: String,
+    pub preferred_locale: String,
+    pub timezone: String,
+    pub avatar_media_id: Option<Uuid>,
+}
+
+impl From<StoredUserProfile> for UserProfile {
+    fn from(profile: StoredUserProfile) -> Self {
+        Self {
+            user_id: profile.user_id.into(),
+            email: profile.email,
+            username: profile.username,
+            full_name: profile.full_name,
+            preferred_locale: profile.preferred_locale,
+            timezone: profile.timezone,
+            avatar_media_id: profile.avatar_media_id,
+        }
+    }
+}
+
+/// Changes to the editable profile fields; `None` and `Unset` keep the
+/// current value, and only the avatar can be cleared.
+#[derive(Clone, Debug, Default)]
+pub struct UserProfilePatch {
+    pub full_name: Option<String>,
+    pub preferred_locale: Option<String>,
+    pub timezone: Option<String>,
+    pub avatar_media_id: PatchField<Uuid>,
+}
+
+#[derive(Debug, thiserror::Error, Clone)]
+pub enum UserProfileError {
+    #[error("User not found")]
+    UserNotFound,
+
+    #[error("Invalid full name: {0}")]
+    InvalidFullName(String),
+
+    #[error("Invalid locale: {0}")]
+    InvalidLocale(String),
+
+    #[error("Invalid timezone: {0}")]
+    InvalidTimezone(String),
+
+    /// Not one of the user's own media, or trashed
+    #[error("Avatar media not found")]
+    AvatarNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<UserProfileRepositoryError> for UserProfileError {
+    fn from(error: UserProfileRepositoryError) -> Self {
+        match error {
+            UserProfileRepositoryError::UserNotFound => Self::UserNotFound,
+            UserProfileRepositoryError::AvatarNotFound => Self::AvatarNotFound,
+            UserProfileRepositoryError::DatabaseError(e) => Self::RepositoryError(e),
+        }
+    }
+}
+
+/// The signed-in user's own profile, with the settings only they see
+#[async_trait]
+pub trait GetUserProfileUseCase: Send + Sync {
+    async fn execute(&self, user_id: UserId) -> Result<UserProfile, UserProfileError>;
+}
+
+metered_use_case!(
+    "auth",
+    "get_user_profile",
+    GetUserProfileUseCase,
+    fn execute(&self, user_id: UserId) -> Result<UserProfile, UserProfileError>
+);
+
+#[async_trait]
+pub trait PatchUserProfileUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        user_id: UserId,
+        patch: UserProfilePatch,
+    ) -> Result<UserProfile, UserProfileError>;
+}
+
+metered_use_case!(
+    "auth",
+    "patch_user_profile",
+    PatchUserProfileUseCase,
+    fn execute(
+        &self,
+        user_id: UserId,
+        patch: UserProfilePatch,
+    ) -> Result<UserProfile, UserProfileError>
+);
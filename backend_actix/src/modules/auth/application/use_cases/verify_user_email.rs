@@ -1,69 +1,135 @@
+use chrono::{TimeZone, Utc};
 use std::sync::Arc;
 
 use crate::auth::application::ports::outgoing::token_provider::{TokenError, TokenProvider};
+use crate::auth::application::ports::outgoing::ConsumedTokenRepository;
 use crate::modules::auth::application::ports::outgoing::{
     user_repository::UserRepository, UserRepositoryError,
 };
+use crate::shared::metrics::metered_use_case;
+use crate::shared::unit_of_work::{UnitOfWork, UnitOfWorkError};
 use async_trait::async_trait;
 
 #[derive(Debug, Clone)]
 pub enum VerifyUserEmailError {
     TokenExpired,
     TokenInvalid,
+    /// The link was already followed once
+    TokenAlreadyUsed,
     UserNotFound,
     DatabaseError,
 }
 
+impl From<UnitOfWorkError> for VerifyUserEmailError {
+    fn from(e: UnitOfWorkError) -> Self {
+        tracing::error!("Email verification transaction failed: {}", e);
+        VerifyUserEmailError::DatabaseError
+    }
+}
+
 #[async_trait]
 pub trait IVerifyUserEmailUseCase: Send + Sync {
     async fn execute(&self, token: &str) -> Result<(), VerifyUserEmailError>;
 }
 
+metered_use_case!(
+    "auth",
+    "verify_user_email",
+    IVerifyUserEmailUseCase,
+    fn execute(&self, token: &str) -> Result<(), VerifyUserEmailError>
+);
+
 #[derive(Clone)]
-pub struct VerifyUserEmailUseCase<R>
+pub struct VerifyUserEmailUseCase<R, U>
 where
     R: UserRepository + Send + Sync,
+    U: UnitOfWork,
 {
     repository: R,
     token_provider: Arc<dyn TokenProvider>,
+    /// Makes tokens single-use: each `jti` is recorded on first use and
+    /// refused afterwards
+    consumed_tokens: Arc<dyn ConsumedTokenRepository>,
+    /// Records the `jti` and activates together, so a failed activation
+    /// doesn't use up the link
+    unit_of_work: U,
 }
 
-impl<R> VerifyUserEmailUseCase<R>
+impl<R, U> VerifyUserEmailUseCase<R, U>
 where
     R: UserRepository + Send + Sync,
+    U: UnitOfWork,
 {
-    pub fn new(repository: R, token_provider: Arc<dyn TokenProvider>) -> Self {
+    pub fn new(
+        repository: R,
+        token_provider: Arc<dyn TokenProvider>,
+        consumed_tokens: Arc<dyn ConsumedTokenRepository>,
+        unit_of_work: U,
+    ) -> Self {
         Self {
             repository,
             token_provider,
+            consumed_tokens,
+            unit_of_work,
         }
     }
 }
 
 #[async_trait]
-impl<R> IVerifyUserEmailUseCase for VerifyUserEmailUseCase<R>
+impl<R, U> IVerifyUserEmailUseCase for VerifyUserEmailUseCase<R, U>
 where
     R: UserRepository + Send + Sync,
+    U: UnitOfWork,
 {
     async fn execute(&self, token: &str) -> Result<(), VerifyUserEmailError> {
         // Decode and validate token (logging handled in JWT service)
-        let user_id = self
+        let claims = self
             .token_provider
             .verify_verification_token(token)
             .map_err(|e| match e {
                 TokenError::TokenExpired => VerifyUserEmailError::TokenExpired,
                 _ => VerifyUserEmailError::TokenInvalid,
             })?;
+        let user_id = claims.sub;
 
-        // Activate user through user repository
-        self.repository
-            .activate_user(user_id)
-            .await
-            .map_err(|e| match e {
-                UserRepositoryError::UserNotFound => VerifyUserEmailError::UserNotFound,
-                UserRepositoryError::DatabaseError(_) => VerifyUserEmailError::DatabaseError,
-                _ => VerifyUserEmailError::DatabaseError,
-            })?;
+        // Tokens issued before jti existed can't be tracked, so they are
+        // refused; the user asks for a new link
+        let Some(jti) = &claims.jti else {
+            tracing::warn!("Verification token without jti for user {}", user_id);
+            return Err(VerifyUserEmailError::TokenInvalid);
+        };
+        let expires_at = Utc
+            .timestamp_opt(claims.exp, 0)
+            .single()
+            .unwrap_or_else(Utc::now);
+        self.unit_of_work
+            .run(async {
+                let first_use = self
+                    .consumed_tokens
+                    .consume(jti, user_id, expires_at)
+                    .await
+                    .map_err(|e| {
+                        tracing::error!("Failed to record consumed token: {}", e);
+                        VerifyUserEmailError::DatabaseError
+                    })?;
+                if !first_use {
+                    tracing::warn!("Replayed verification token for user {}", user_id);
+                    return Err(VerifyUserEmailError::TokenAlreadyUsed);
+                }
+
+                // Activate user through user repository
+                self.repository
+                    .activate_user(user_id)
+                    .await
+                    .map_err(|e| match e {
+                        UserRepositoryError::UserNotFound => VerifyUserEmailError::UserNotFound,
+                        UserRepositoryError::DatabaseError(_) => {
+                            VerifyUserEmailError::DatabaseError
+                        }
+                        _ => VerifyUserEmailError::DatabaseError,
+                    })
+            })
+            .await?;
 
         tracing::info!("User email verified successfully: {}", user_id);
         Ok(())
@@ -73,12 +139,14 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::auth::adapter::outgoing::in_memory::InMemoryConsumedTokens;
     use crate::auth::adapter::outgoing::jwt::{JwtConfig, JwtTokenService};
     use crate::auth::application::ports::outgoing::token_provider::TokenClaims;
     use crate::auth::application::ports::outgoing::user_repository::{CreateUserData, UserResult};
     use crate::modules::auth::application::ports::outgoing::{
         user_repository::UserRepository, UserRepositoryError,
     };
+    use crate::shared::unit_of_work::InMemoryUnitOfWork;
     use async_trait::async_trait;
     use mockall::{mock, predicate::*};
     use uuid::Uuid;
@@ -91,10 +159,11 @@ mod tests {
             async fn create_user(&self, data: CreateUserData) -> Result<UserResult, UserRepositoryError>;
             async fn restore_user(&self, user_id: Uuid) -> Result<UserResult, UserRepositoryError>;
             async fn activate_user(&self, user_id: Uuid) -> Result<UserResult, UserRepositoryError>;
-            async fn set_full_name(
+            async fn update_profile(
                 &self,
                 user_id: Uuid,
                 full_name: String,
+                preferred_locale: Option<String>,
             ) -> Result<UserResult, UserRepositoryError>;
 
             // Operations that don't need to return user data (pure commands)
@@ -108,14 +177,20 @@ mod tests {
         }
     }
 
+    fn consumed_tokens() -> Arc<dyn ConsumedTokenRepository> {
+        Arc::new(InMemoryConsumedTokens::default())
+    }
+
     // Helper function to create JWT service
     fn create_jwt_service() -> JwtTokenService {
         let config = JwtConfig {
             secret_key: "testsecretkey_min_32_characters_long".to_string(),
             issuer: "testapp".to_string(),
+            audience: "test_audience".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         };
         JwtTokenService::new(config)
     }
@@ -142,11 +217,17 @@ mod tests {
                     email: "test@example.com".to_string(),
                     username: "testuser".to_string(),
                     full_name: "Test User".to_string(),
+                    preferred_locale: "en".to_string(),
                 })
             });
 
         // Create use case with the configured repository
-        let use_case = VerifyUserEmailUseCase::new(repository, Arc::new(jwt_service));
+        let use_case = VerifyUserEmailUseCase::new(
+            repository,
+            Arc::new(jwt_service),
+            consumed_tokens(),
+            InMemoryUnitOfWork,
+        );
 
         // Execute use case
         let result = use_case.execute(&valid_token).await;
@@ -159,6 +240,40 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_verification_token_is_single_use() {
+        let mut repository = MockUserRepositoryMock::new();
+        let user_id = Uuid::new_v4();
+        let jwt_service = create_jwt_service();
+        let token = jwt_service.generate_verification_token(user_id).unwrap();
+
+        repository
+            .expect_activate_user()
+            .times(1)
+            .returning(move |_| {
+                Ok(UserResult {
+                    id: user_id,
+                    email: "test@example.com".to_string(),
+                    username: "testuser".to_string(),
+                    full_name: "Test User".to_string(),
+                    preferred_locale: "en".to_string(),
+                })
+            });
+
+        let use_case = VerifyUserEmailUseCase::new(
+            repository,
+            Arc::new(jwt_service),
+            consumed_tokens(),
+            InMemoryUnitOfWork,
+        );
+
+        assert!(use_case.execute(&token).await.is_ok());
+        assert!(matches!(
+            use_case.execute(&token).await,
+            Err(VerifyUserEmailError::TokenAlreadyUsed)
+        ));
+    }
+
     #[tokio::test]
     async fn test_verify_user_email_token_expired() {
         use chrono::{Duration, Utc};
@@ -176,6 +291,11 @@ mod tests {
             nbf: (now - Duration::hours(25)).timestamp(),   // Not before 25 hours ago
             token_type: "verification".to_string(),
             is_verified: false,
+            token_version: 0,
+            remember_me: false,
+            iss: "testapp".to_string(),
+            aud: "test_audience".to_string(),
+            jti: None,
         };
 
         let expired_token = encode(
@@ -186,7 +306,12 @@ mod tests {
         .expect("Should encode expired token");
 
         // Create use case
-        let use_case = VerifyUserEmailUseCase::new(repository, Arc::new(create_jwt_service()));
+        let use_case = VerifyUserEmailUseCase::new(
+            repository,
+            Arc::new(create_jwt_service()),
+            consumed_tokens(),
+            InMemoryUnitOfWork,
+        );
 
         // Execute use case
         let result = use_case.execute(&expired_token).await;
@@ -199,13 +324,59 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_verification_token_without_jti_is_refused() {
+        use chrono::{Duration, Utc};
+        use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+
+        // No activate_user expectation: the mock panics if it is reached
+        let repository = MockUserRepositoryMock::new();
+        let now = Utc::now();
+        let claims = TokenClaims {
+            sub: Uuid::new_v4(),
+            exp: (now + Duration::hours(1)).timestamp(),
+            iat: now.timestamp(),
+            nbf: now.timestamp(),
+            token_type: "verification".to_string(),
+            is_verified: false,
+            token_version: 0,
+            remember_me: false,
+            iss: "testapp".to_string(),
+            aud: "test_audience".to_string(),
+            jti: None,
+        };
+        let token = encode(
+            &Header::new(Algorithm::HS256),
+            &claims,
+            &EncodingKey::from_secret("testsecretkey_min_32_characters_long".as_bytes()),
+        )
+        .unwrap();
+
+        let use_case = VerifyUserEmailUseCase::new(
+            repository,
+            Arc::new(create_jwt_service()),
+            consumed_tokens(),
+            InMemoryUnitOfWork,
+        );
+
+        assert!(matches!(
+            use_case.execute(&token).await,
+            Err(VerifyUserEmailError::TokenInvalid)
+        ));
+    }
+
     #[tokio::test]
     async fn test_verify_user_email_token_invalid() {
         let repository = MockUserRepositoryMock::new();
         let invalid_token = "invalid.jwt.token";
 
         // Create use case (no repository expectations needed since token validation fails first)
-        let use_case = VerifyUserEmailUseCase::new(repository, Arc::new(create_jwt_service()));
+        let use_case = VerifyUserEmailUseCase::new(
+            repository,
+            Arc::new(create_jwt_service()),
+            consumed_tokens(),
+            InMemoryUnitOfWork,
+        );
 
         // Execute use case
         let result = use_case.execute(invalid_token).await;
@@ -225,11 +396,16 @@ mod tests {
         let jwt_service = create_jwt_service();
 
         let access_token = jwt_service
-            .generate_access_token(user_id, true)
+            .generate_access_token(user_id, true, 0)
             .expect("Should generate access token");
 
         // Create use case (no repository expectations needed since token validation fails first)
-        let use_case = VerifyUserEmailUseCase::new(repository, Arc::new(jwt_service));
+        let use_case = VerifyUserEmailUseCase::new(
+            repository,
+            Arc::new(jwt_service),
+            consumed_tokens(),
+            InMemoryUnitOfWork,
+        );
 
         // Execute use case
         let result = use_case.execute(&access_token).await;
@@ -249,11 +425,16 @@ mod tests {
         let jwt_service = create_jwt_service();
 
         let refresh_token = jwt_service
-            .generate_refresh_token(user_id, true)
+            .generate_refresh_token(user_id, true, 0, false)
             .expect("Should generate refresh token");
 
         // Create use case
-        let use_case = VerifyUserEmailUseCase::new(repository, Arc::new(jwt_service));
+        let use_case = VerifyUserEmailUseCase::new(
+            repository,
+            Arc::new(jwt_service),
+            consumed_tokens(),
+            InMemoryUnitOfWork,
+        );
 
         // Execute use case
         let result = use_case.execute(&refresh_token).await;
@@ -275,9 +456,11 @@ mod tests {
         let config1 = JwtConfig {
             secret_key: "first_secret_key_min_32_characters_long".to_string(),
             issuer: "testapp".to_string(),
+            audience: "test_audience".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         };
         let jwt_service1 = JwtTokenService::new(config1);
 
@@ -287,7 +470,12 @@ mod tests {
 
         // Try to verify with different secret
         let jwt_service2 = create_jwt_service();
-        let use_case = VerifyUserEmailUseCase::new(repository, Arc::new(jwt_service2));
+        let use_case = VerifyUserEmailUseCase::new(
+            repository,
+            Arc::new(jwt_service2),
+            consumed_tokens(),
+            InMemoryUnitOfWork,
+        );
 
         // Execute use case
         let result = use_case.execute(&token).await;
@@ -305,7 +493,12 @@ mod tests {
         let repository = MockUserRepositoryMock::new();
         let malformed_token = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.bm90X3ZhbGlkX2pzb24.fakesig";
 
-        let use_case = VerifyUserEmailUseCase::new(repository, Arc::new(create_jwt_service()));
+        let use_case = VerifyUserEmailUseCase::new(
+            repository,
+            Arc::new(create_jwt_service()),
+            consumed_tokens(),
+            InMemoryUnitOfWork,
+        );
 
         // Execute use case
         let result = use_case.execute(malformed_token).await;
@@ -336,7 +529,12 @@ mod tests {
             .returning(|_| Err(UserRepositoryError::UserNotFound));
 
         // Create use case with the configured repository
-        let use_case = VerifyUserEmailUseCase::new(repository, Arc::new(jwt_service));
+        let use_case = VerifyUserEmailUseCase::new(
+            repository,
+            Arc::new(jwt_service),
+            consumed_tokens(),
+            InMemoryUnitOfWork,
+        );
 
         // Execute use case
         let result = use_case.execute(&valid_token).await;
@@ -367,7 +565,12 @@ mod tests {
             .returning(|_| Err(UserRepositoryError::DatabaseError("DB error".to_string())));
 
         // Create use case with the configured repository
-        let use_case = VerifyUserEmailUseCase::new(repository, Arc::new(jwt_service));
+        let use_case = VerifyUserEmailUseCase::new(
+            repository,
+            Arc::new(jwt_service),
+            consumed_tokens(),
+            InMemoryUnitOfWork,
+        );
 
         // Execute use case
         let result = use_case.execute(&valid_token).await;
@@ -398,7 +601,12 @@ mod tests {
             .returning(|_| Err(UserRepositoryError::UserAlreadyExists));
 
         // Create use case with the configured repository
-        let use_case = VerifyUserEmailUseCase::new(repository, Arc::new(jwt_service));
+        let use_case = VerifyUserEmailUseCase::new(
+            repository,
+            Arc::new(jwt_service),
+            consumed_tokens(),
+            InMemoryUnitOfWork,
+        );
 
         // Execute use case
         let result = use_case.execute(&valid_token).await;
@@ -424,7 +632,12 @@ mod tests {
         // Tamper with the token
         valid_token.push('x');
 
-        let use_case = VerifyUserEmailUseCase::new(repository, Arc::new(jwt_service));
+        let use_case = VerifyUserEmailUseCase::new(
+            repository,
+            Arc::new(jwt_service),
+            consumed_tokens(),
+            InMemoryUnitOfWork,
+        );
 
         // Execute use case
         let result = use_case.execute(&valid_token).await;
@@ -436,4 +649,48 @@ mod tests {
             result
         );
     }
+
+    #[tokio::test]
+    async fn test_failed_activation_keeps_the_link_usable() {
+        use crate::auth::adapter::outgoing::consumed_token_postgres::ConsumedTokenRepositoryPostgres;
+        use crate::auth::adapter::outgoing::user_repository_postgres::UserRepositoryPostgres;
+        use crate::shared::unit_of_work::UnitOfWorkPostgres;
+        use sea_orm::{DatabaseBackend, DbErr, MockDatabase, MockExecResult};
+
+        let exec = |rows_affected| MockExecResult {
+            last_insert_id: 0,
+            rows_affected,
+        };
+        let db = Arc::new(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_exec_results(vec![exec(0), exec(1)])
+                .append_query_errors(vec![DbErr::Custom("connection lost".into())])
+                .into_connection(),
+        );
+        let jwt_service = create_jwt_service();
+        let token = jwt_service
+            .generate_verification_token(Uuid::new_v4())
+            .unwrap();
+        let use_case = VerifyUserEmailUseCase::new(
+            UserRepositoryPostgres::new(Arc::clone(&db)),
+            Arc::new(jwt_service),
+            Arc::new(ConsumedTokenRepositoryPostgres::new(Arc::clone(&db))),
+            UnitOfWorkPostgres::new(Arc::clone(&db)),
+        );
+
+        let result = use_case.execute(&token).await;
+        drop(use_case);
+
+        assert!(matches!(result, Err(VerifyUserEmailError::DatabaseError)));
+        // The recorded jti goes with the failed activation
+        let log = format!(
+            "{:?}",
+            Arc::try_unwrap(db)
+                .expect("no other connection handles")
+                .into_transaction_log()
+        );
+        assert!(log.contains("INSERT INTO consumed_tokens"));
+        assert!(log.contains("ROLLBACK"));
+        assert!(!log.contains("COMMIT"));
+    }
 }
@@ -0,0 +1,6 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    EMPTY_BATCH = (BAD_REQUEST, "A batch needs at least one operation");
+    TOO_MANY_OPERATIONS = (BAD_REQUEST, "The batch has too many operations");
+}
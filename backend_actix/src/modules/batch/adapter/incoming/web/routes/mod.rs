@@ -0,0 +1,3 @@
+mod run_batch;
+
+pub use run_batch::{__path_run_batch_handler, run_batch_handler, RunBatchRequest};
@@ -0,0 +1,187 @@
+use actix_web::{post, web, Responder};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::batch::adapter::incoming::web::error_codes::{EMPTY_BATCH, TOO_MANY_OPERATIONS};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::VerifiedUser,
+    batch::application::{
+        domain::entities::{BatchMode, BatchOperation, BatchReport},
+        ports::incoming::use_cases::RunBatchError,
+    },
+    shared::api::ApiResponse,
+    AppState,
+};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct RunBatchRequest {
+    /// `independent` (default) or `atomic`
+    #[serde(default)]
+    pub mode: BatchMode,
+
+    /// Run in this order; at most 50
+    pub operations: Vec<BatchOperation>,
+}
+
+/// Run several content changes in one request
+///
+/// Each operation is named by `op` and takes the fields of its own endpoint
+/// plus the id of what it changes: `patch_project`, `add_project_topic`,
+/// `remove_project_topic` and `update_page`. Only the caller's own content
+/// can be changed.
+///
+/// In `independent` mode every operation is tried and reports its own
+/// result. In `atomic` mode the first failure skips the rest and the
+/// operations before it are undone (`rolled_back`); an undo that fails
+/// leaves its change in place and is reported as `rollback_failed`. Undoing
+/// is best effort: a concurrent edit of the same content may be overwritten.
+///
+/// The response is 200 whenever the batch ran; `succeeded` says whether
+/// every operation did, and each item carries the error code its endpoint
+/// would have returned.
+#[utoipa::path(
+    post,
+    path = "/api/batch",
+    tag = "batch",
+    request_body = RunBatchRequest,
+    responses(
+        (status = 200, description = "Result of every operation, in request order", body = inline(SuccessResponse<BatchReport>)),
+        (status = 400, description = "No operations, too many, or malformed request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/batch")]
+pub async fn run_batch_handler(
+    user: VerifiedUser,
+    payload: web::Json<RunBatchRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let RunBatchRequest { mode, operations } = payload.into_inner();
+
+    match data
+        .run_batch_use_case
+        .execute(user.user_id, mode, operations)
+        .await
+    {
+        Ok(report) => ApiResponse::success(report),
+        Err(err @ RunBatchError::Empty) => EMPTY_BATCH.with_message(&err.to_string()),
+        Err(err @ RunBatchError::TooManyOperations(_)) => {
+            TOO_MANY_OPERATIONS.with_message(&err.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::json;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        shared::api::custom_json_config,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubRunBatchUseCase,
+        },
+    };
+
+    async fn call(
+        state: web::Data<AppState>,
+        user_id: Uuid,
+        body: serde_json::Value,
+    ) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(user_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .app_data(custom_json_config())
+                .service(run_batch_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/batch")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(body)
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_runs_the_operations_for_the_caller() {
+        let user_id = Uuid::new_v4();
+        let project_id = Uuid::new_v4();
+        let topic_id = Uuid::new_v4();
+        let stub = StubRunBatchUseCase::success();
+        let state = TestAppStateBuilder::default()
+            .with_run_batch(stub.clone())
+            .build();
+
+        let resp = call(
+            state,
+            user_id,
+            json!({
+                "mode": "atomic",
+                "operations": [
+                    { "op": "patch_project", "project_id": project_id, "title": "New", "repo_url": null },
+                    { "op": "add_project_topic", "project_id": project_id, "topic_id": topic_id }
+                ]
+            }),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["mode"], "atomic");
+        assert_eq!(body["data"]["items"][0]["status"], "ok");
+
+        let (owner_id, mode, operations) = stub.received().unwrap();
+        assert_eq!(owner_id, user_id);
+        assert_eq!(mode, BatchMode::Atomic);
+        assert_eq!(operations.len(), 2);
+        assert!(matches!(
+            &operations[0],
+            BatchOperation::PatchProject(patch) if patch.repo_url.is_null() && patch.description.is_unset()
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_operation_is_bad_request() {
+        let state = TestAppStateBuilder::default().build();
+
+        let resp = call(
+            state,
+            Uuid::new_v4(),
+            json!({ "operations": [{ "op": "delete_everything" }] }),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_empty_batch_is_bad_request() {
+        let state = TestAppStateBuilder::default()
+            .with_run_batch(StubRunBatchUseCase::failure(RunBatchError::Empty))
+            .build();
+
+        let resp = call(state, Uuid::new_v4(), json!({ "operations": [] })).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "EMPTY_BATCH");
+    }
+}
@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::pages::application::domain::entities::PageStatus;
+use crate::project::application::ports::outgoing::project_repository::PatchField;
+
+/// One change in a batch, made through the same use case as its own endpoint
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum BatchOperation {
+    /// As `PATCH /api/projects/{project_id}`
+    PatchProject(ProjectPatch),
+    /// As `POST /api/projects/{project_id}/topics`
+    AddProjectTopic(ProjectTopicLink),
+    /// As `DELETE /api/projects/{project_id}/topics/{topic_id}`
+    RemoveProjectTopic(ProjectTopicLink),
+    /// As `PATCH /api/pages/{page_id}`
+    UpdatePage(PagePatch),
+}
+
+impl BatchOperation {
+    /// The `op` tag, for reports and logs
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::PatchProject(_) => "patch_project",
+            Self::AddProjectTopic(_) => "add_project_topic",
+            Self::RemoveProjectTopic(_) => "remove_project_topic",
+            Self::UpdatePage(_) => "update_page",
+        }
+    }
+}
+
+/// Omitted fields are kept; `null` clears the URLs
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct ProjectPatch {
+    pub project_id: Uuid,
+    #[serde(default, skip_serializing_if = "PatchField::is_unset")]
+    #[schema(value_type = Option<String>)]
+    pub title: PatchField<String>,
+    #[serde(default, skip_serializing_if = "PatchField::is_unset")]
+    #[schema(value_type = Option<String>)]
+    pub description: PatchField<String>,
+    #[serde(default, skip_serializing_if = "PatchField::is_unset")]
+    #[schema(value_type = Option<Vec<String>>)]
+    pub tech_stack: PatchField<Vec<String>>,
+    #[serde(default, skip_serializing_if = "PatchField::is_unset")]
+    #[schema(value_type = Option<String>)]
+    pub repo_url: PatchField<String>,
+    #[serde(default, skip_serializing_if = "PatchField::is_unset")]
+    #[schema(value_type = Option<String>)]
+    pub live_demo_url: PatchField<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct ProjectTopicLink {
+    pub project_id: Uuid,
+    pub topic_id: Uuid,
+}
+
+/// Omitted fields are kept; `null` clears the SEO fields and empties the body
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct PagePatch {
+    pub page_id: Uuid,
+    #[serde(default, skip_serializing_if = "PatchField::is_unset")]
+    #[schema(value_type = Option<String>)]
+    pub title: PatchField<String>,
+    #[serde(default, skip_serializing_if = "PatchField::is_unset")]
+    #[schema(value_type = Option<String>)]
+    pub body: PatchField<String>,
+    #[serde(default, skip_serializing_if = "PatchField::is_unset")]
+    #[schema(value_type = Option<PageStatus>)]
+    pub status: PatchField<PageStatus>,
+    #[serde(default, skip_serializing_if = "PatchField::is_unset")]
+    #[schema(value_type = Option<String>)]
+    pub seo_title: PatchField<String>,
+    #[serde(default, skip_serializing_if = "PatchField::is_unset")]
+    #[schema(value_type = Option<String>)]
+    pub seo_description: PatchField<String>,
+}
+
+/// How a failed operation affects the others
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchMode {
+    /// Every operation is tried; failures don't affect the rest
+    #[default]
+    Independent,
+    /// The first failure skips the rest and undoes the operations before it
+    Atomic,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchItemStatus {
+    Ok,
+    Failed,
+    /// Not tried, because an earlier operation of an atomic batch failed
+    Skipped,
+    /// Applied, then undone because a later operation failed
+    RolledBack,
+    /// Applied, and undoing it failed; the change is still in place
+    RollbackFailed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct BatchItemResult {
+    /// Position in the request
+    pub index: usize,
+    pub op: String,
+    pub status: BatchItemStatus,
+    /// Same codes as the operation's own endpoint, e.g. `PROJECT_NOT_FOUND`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct BatchReport {
+    pub mode: BatchMode,
+    /// Every operation is `ok`
+    pub succeeded: bool,
+    /// One per operation, in request order
+    pub items: Vec<BatchItemResult>,
+}
+
+/// Why an operation failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperationError {
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl OperationError {
+    pub fn new(code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
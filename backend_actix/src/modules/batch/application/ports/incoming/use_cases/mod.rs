@@ -0,0 +1,3 @@
+mod run_batch_use_case;
+
+pub use run_batch_use_case::{RunBatchError, RunBatchUseCase, MAX_BATCH_OPERATIONS};
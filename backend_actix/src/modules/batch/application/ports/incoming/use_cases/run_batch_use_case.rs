@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::batch::application::domain::entities::{BatchMode, BatchOperation, BatchReport};
+use crate::shared::metrics::metered_use_case;
+
+/// Operations accepted in one batch
+pub const MAX_BATCH_OPERATIONS: usize = 50;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum RunBatchError {
+    #[error("A batch needs at least one operation")]
+    Empty,
+
+    #[error("A batch can have at most {0} operations")]
+    TooManyOperations(usize),
+}
+
+/// Several content changes in one request, e.g. the edits of one admin form
+#[async_trait]
+pub trait RunBatchUseCase: Send + Sync {
+    /// Operations run in order, each on content owned by `owner_id`. A
+    /// failed operation is reported in its item rather than failing the
+    /// batch; only an empty or oversized batch does.
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        mode: BatchMode,
+        operations: Vec<BatchOperation>,
+    ) -> Result<BatchReport, RunBatchError>;
+}
+
+metered_use_case!(
+    "batch",
+    "run_batch",
+    RunBatchUseCase,
+    fn execute(
+        &self,
+        owner_id: Uuid,
+        mode: BatchMode,
+        operations: Vec<BatchOperation>,
+    ) -> Result<BatchReport, RunBatchError>
+);
@@ -0,0 +1,3 @@
+mod run_batch_service;
+
+pub use run_batch_service::{BatchTargets, RunBatchService};
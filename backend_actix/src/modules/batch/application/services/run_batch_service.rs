@@ -0,0 +1,630 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::{error, warn};
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::batch::application::domain::entities::{
+    BatchItemResult, BatchItemStatus, BatchMode, BatchOperation, BatchReport, OperationError,
+    PagePatch, ProjectPatch, ProjectTopicLink,
+};
+use crate::batch::application::ports::incoming::use_cases::{
+    RunBatchError, RunBatchUseCase, MAX_BATCH_OPERATIONS,
+};
+use crate::pages::application::ports::incoming::use_cases::{
+    GetPageError, GetPageUseCase, UpdatePageCommand, UpdatePageError, UpdatePageUseCase,
+};
+use crate::project::application::ports::incoming::use_cases::{
+    AddProjectTopicError, AddProjectTopicUseCase, GetSingleProjectError, GetSingleProjectUseCase,
+    PatchProjectError, PatchProjectUseCase, RemoveProjectTopicError, RemoveProjectTopicUseCase,
+};
+use crate::project::application::ports::outgoing::project_query::ProjectView;
+use crate::project::application::ports::outgoing::project_repository::{
+    PatchField, PatchProjectData,
+};
+
+/// The use cases batch operations are run through, so each one gets the same
+/// checks and cache invalidation as its own endpoint. The getters read the
+/// state an atomic batch restores on failure.
+#[derive(Clone)]
+pub struct BatchTargets {
+    pub patch_project: Arc<dyn PatchProjectUseCase + Send + Sync>,
+    pub get_project: Arc<dyn GetSingleProjectUseCase + Send + Sync>,
+    pub add_project_topic: Arc<dyn AddProjectTopicUseCase + Send + Sync>,
+    pub remove_project_topic: Arc<dyn RemoveProjectTopicUseCase + Send + Sync>,
+    pub get_page: Arc<dyn GetPageUseCase + Send + Sync>,
+    pub update_page: Arc<dyn UpdatePageUseCase + Send + Sync>,
+}
+
+/// Atomic batches are undone by compensation: before each operation the
+/// service reads what it is about to change and, if a later one fails,
+/// applies the inverse operations in reverse order. The use cases don't
+/// share a transaction, so this is not isolation: a concurrent edit of the
+/// same content between the two steps is overwritten, `updated_at` moves
+/// forward, and webhooks fire for both the change and its undo.
+pub struct RunBatchService {
+    targets: BatchTargets,
+}
+
+impl RunBatchService {
+    pub fn new(targets: BatchTargets) -> Self {
+        Self { targets }
+    }
+}
+
+#[async_trait]
+impl RunBatchUseCase for RunBatchService {
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        mode: BatchMode,
+        operations: Vec<BatchOperation>,
+    ) -> Result<BatchReport, RunBatchError> {
+        if operations.is_empty() {
+            return Err(RunBatchError::Empty);
+        }
+        if operations.len() > MAX_BATCH_OPERATIONS {
+            return Err(RunBatchError::TooManyOperations(MAX_BATCH_OPERATIONS));
+        }
+
+        let atomic = mode == BatchMode::Atomic;
+        let mut items = Vec::with_capacity(operations.len());
+        // Applied operations of an atomic batch, with what undoes them;
+        // `None` when they changed nothing
+        let mut applied: Vec<(usize, Option<BatchOperation>)> = vec![];
+        let mut aborted = false;
+
+        for (index, operation) in operations.into_iter().enumerate() {
+            let mut item = BatchItemResult {
+                index,
+                op: operation.name().to_string(),
+                status: BatchItemStatus::Ok,
+                code: None,
+                message: None,
+            };
+            if aborted {
+                item.status = BatchItemStatus::Skipped;
+                items.push(item);
+                continue;
+            }
+
+            let result = if atomic {
+                match self.undo_for(owner_id, &operation).await {
+                    Ok(undo) => self
+                        .apply(owner_id, operation)
+                        .await
+                        .map(|()| applied.push((index, undo))),
+                    Err(err) => Err(err),
+                }
+            } else {
+                self.apply(owner_id, operation).await
+            };
+
+            if let Err(err) = result {
+                item.status = BatchItemStatus::Failed;
+                item.code = Some(err.code.to_string());
+                item.message = Some(err.message);
+                aborted = atomic;
+            }
+            items.push(item);
+        }
+
+        if aborted {
+            for (index, undo) in applied.into_iter().rev() {
+                let item = &mut items[index];
+                let Some(undo) = undo else {
+                    item.status = BatchItemStatus::RolledBack;
+                    continue;
+                };
+                match self.apply(owner_id, undo).await {
+                    Ok(()) => item.status = BatchItemStatus::RolledBack,
+                    Err(err) => {
+                        warn!(
+                            "Could not undo batch operation {} ({}): {}",
+                            index, item.op, err.message
+                        );
+                        item.status = BatchItemStatus::RollbackFailed;
+                        item.code = Some(err.code.to_string());
+                        item.message = Some(err.message);
+                    }
+                }
+            }
+        }
+
+        Ok(BatchReport {
+            mode,
+            succeeded: items.iter().all(|item| item.status == BatchItemStatus::Ok),
+            items,
+        })
+    }
+}
+
+impl RunBatchService {
+    async fn apply(&self, owner_id: Uuid, operation: BatchOperation) -> Result<(), OperationError> {
+        let owner = UserId::from(owner_id);
+        match operation {
+            BatchOperation::PatchProject(patch) => {
+                let data = PatchProjectData {
+                    title: patch.title,
+                    description: patch.description,
+                    tech_stack: patch.tech_stack,
+                    screenshots: PatchField::Unset,
+                    repo_url: patch.repo_url,
+                    live_demo_url: patch.live_demo_url,
+                    seo_title: PatchField::Unset,
+                    seo_description: PatchField::Unset,
+                    canonical_url: PatchField::Unset,
+                    noindex: None,
+                    expected_updated_at: None,
+                };
+                match self
+                    .targets
+                    .patch_project
+                    .execute(owner, patch.project_id, data)
+                    .await
+                {
+                    Ok(_) => Ok(()),
+                    Err(PatchProjectError::NotFound) => Err(project_not_found()),
+                    // Batch patches carry no expectation
+                    Err(PatchProjectError::Stale) => Err(internal("unexpected stale write".into())),
+                    // Batch patches leave the SEO fields alone
+                    Err(PatchProjectError::Invalid(errors)) => Err(internal(errors.to_string())),
+                    Err(PatchProjectError::RepositoryError(msg)) => Err(internal(msg)),
+                }
+            }
+            BatchOperation::AddProjectTopic(link) => match self
+                .targets
+                .add_project_topic
+                .execute(owner, link.project_id, link.topic_id)
+                .await
+            {
+                Ok(()) => Ok(()),
+                Err(AddProjectTopicError::ProjectNotFound) => Err(project_not_found()),
+                Err(AddProjectTopicError::TopicNotFound) => {
+                    Err(OperationError::new("TOPIC_NOT_FOUND", "Topic not found"))
+                }
+                Err(AddProjectTopicError::RepositoryError(msg)) => Err(internal(msg)),
+            },
+            // Removing a topic that isn't attached succeeds, as on its endpoint
+            BatchOperation::RemoveProjectTopic(link) => match self
+                .targets
+                .remove_project_topic
+                .execute(owner, link.project_id, link.topic_id)
+                .await
+            {
+                Ok(()) | Err(RemoveProjectTopicError::TopicNotFound) => Ok(()),
+                Err(RemoveProjectTopicError::ProjectNotFound) => Err(project_not_found()),
+                Err(RemoveProjectTopicError::RepositoryError(msg)) => Err(internal(msg)),
+            },
+            BatchOperation::UpdatePage(patch) => {
+                let command = UpdatePageCommand::new(
+                    patch.title,
+                    patch.body,
+                    patch.status,
+                    patch.seo_title,
+                    patch.seo_description,
+                )
+                .map_err(|err| OperationError::new(err.code(), err.to_string()))?;
+                match self
+                    .targets
+                    .update_page
+                    .execute(owner_id, patch.page_id, command)
+                    .await
+                {
+                    Ok(_) => Ok(()),
+                    Err(UpdatePageError::NotFound) => Err(page_not_found()),
+                    Err(UpdatePageError::Stale) => Err(internal("unexpected stale write".into())),
+                    Err(UpdatePageError::Locked(_)) => Err(OperationError::new(
+                        "PAGE_LOCKED",
+                        "Page is being edited in another session",
+                    )),
+                    Err(UpdatePageError::RepositoryError(msg)) => Err(internal(msg)),
+                }
+            }
+        }
+    }
+
+    /// The operation that puts back what `operation` is about to change
+    async fn undo_for(
+        &self,
+        owner_id: Uuid,
+        operation: &BatchOperation,
+    ) -> Result<Option<BatchOperation>, OperationError> {
+        match operation {
+            BatchOperation::PatchProject(patch) => {
+                let project = self.project(owner_id, patch.project_id).await?;
+                Ok(Some(BatchOperation::PatchProject(ProjectPatch {
+                    project_id: patch.project_id,
+                    title: previous(&patch.title, || PatchField::Value(project.title.clone())),
+                    description: previous(&patch.description, || {
+                        PatchField::Value(project.description.clone())
+                    }),
+                    tech_stack: previous(&patch.tech_stack, || {
+                        PatchField::Value(project.tech_stack.clone())
+                    }),
+                    repo_url: previous(&patch.repo_url, || nullable(&project.repo_url)),
+                    live_demo_url: previous(&patch.live_demo_url, || {
+                        nullable(&project.live_demo_url)
+                    }),
+                })))
+            }
+            BatchOperation::AddProjectTopic(link) => {
+                let project = self.project(owner_id, link.project_id).await?;
+                Ok((!has_topic(&project, link))
+                    .then(|| BatchOperation::RemoveProjectTopic(link.clone())))
+            }
+            BatchOperation::RemoveProjectTopic(link) => {
+                let project = self.project(owner_id, link.project_id).await?;
+                Ok(
+                    has_topic(&project, link)
+                        .then(|| BatchOperation::AddProjectTopic(link.clone())),
+                )
+            }
+            BatchOperation::UpdatePage(patch) => {
+                let page = match self.targets.get_page.execute(owner_id, patch.page_id).await {
+                    Ok(page) => page,
+                    Err(GetPageError::NotFound) => return Err(page_not_found()),
+                    Err(GetPageError::RepositoryError(msg)) => return Err(internal(msg)),
+                };
+                // `published_at` of a first publication stays set
+                Ok(Some(BatchOperation::UpdatePage(PagePatch {
+                    page_id: patch.page_id,
+                    title: previous(&patch.title, || PatchField::Value(page.title.clone())),
+                    body: previous(&patch.body, || PatchField::Value(page.body.clone())),
+                    status: previous(&patch.status, || PatchField::Value(page.status)),
+                    seo_title: previous(&patch.seo_title, || nullable(&page.seo_title)),
+                    seo_description: previous(&patch.seo_description, || {
+                        nullable(&page.seo_description)
+                    }),
+                })))
+            }
+        }
+    }
+
+    async fn project(
+        &self,
+        owner_id: Uuid,
+        project_id: Uuid,
+    ) -> Result<ProjectView, OperationError> {
+        match self
+            .targets
+            .get_project
+            .execute(UserId::from(owner_id), project_id)
+            .await
+        {
+            Ok(project) => Ok(project),
+            Err(GetSingleProjectError::NotFound) => Err(project_not_found()),
+            Err(GetSingleProjectError::RepositoryError(msg)) => Err(internal(msg)),
+        }
+    }
+}
+
+/// The current value of a field the operation sets; unset fields stay unset
+fn previous<T, U>(field: &PatchField<T>, current: impl FnOnce() -> PatchField<U>) -> PatchField<U> {
+    if field.is_unset() {
+        PatchField::Unset
+    } else {
+        current()
+    }
+}
+
+fn nullable(value: &Option<String>) -> PatchField<String> {
+    value.clone().map_or(PatchField::Null, PatchField::Value)
+}
+
+fn has_topic(project: &ProjectView, link: &ProjectTopicLink) -> bool {
+    project.topics.iter().any(|topic| topic.id == link.topic_id)
+}
+
+fn project_not_found() -> OperationError {
+    OperationError::new("PROJECT_NOT_FOUND", "Project not found")
+}
+
+fn page_not_found() -> OperationError {
+    OperationError::new("PAGE_NOT_FOUND", "Page not found")
+}
+
+/// Details are logged, not returned
+fn internal(msg: String) -> OperationError {
+    error!("Batch operation failed: {}", msg);
+    OperationError::new("INTERNAL_ERROR", "An unexpected error occurred")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::pages::adapter::outgoing::InMemoryPageStore;
+    use crate::pages::application::domain::entities::{Page, PageStatus};
+    use crate::pages::application::ports::incoming::use_cases::CreatePageCommand;
+    use crate::pages::application::ports::outgoing::PageRepository;
+    use crate::pages::application::services::{GetPageService, UpdatePageService};
+    use crate::project::adapter::outgoing::InMemoryProjectStore;
+    use crate::project::application::ports::outgoing::project_repository::{
+        CreateProjectData, ProjectRepository, ProjectResult,
+    };
+    use crate::project::application::service::{
+        AddProjectTopicService, GetSingleProjectService, PatchProjectService,
+        RemoveProjectTopicService,
+    };
+    use crate::shared::cache::{CachePort, NoopCache};
+    use crate::topic::adapter::outgoing::InMemoryTopicStore;
+    use crate::topic::application::ports::incoming::use_cases::{
+        CreateTopicCommand, CreateTopicUseCase,
+    };
+    use crate::topic::application::services::CreateTopicService;
+
+    struct Fixture {
+        service: RunBatchService,
+        owner_id: Uuid,
+        project: ProjectResult,
+        page: Page,
+        topic_id: Uuid,
+        pages: InMemoryPageStore,
+        projects: InMemoryProjectStore,
+    }
+
+    async fn fixture() -> Fixture {
+        let owner_id = Uuid::new_v4();
+        let pages = InMemoryPageStore::default();
+        let topics = InMemoryTopicStore::default();
+        let projects = InMemoryProjectStore::new(topics.clone());
+        let cache: Arc<dyn CachePort> = Arc::new(NoopCache);
+
+        let project = projects
+            .create_project(CreateProjectData {
+                owner: UserId::from(owner_id),
+                title: "Port CMS".to_string(),
+                slug: "port-cms".to_string(),
+                description: "A CMS".to_string(),
+                tech_stack: vec!["Rust".to_string()],
+                screenshots: vec![],
+                repo_url: None,
+                live_demo_url: None,
+                seo_title: None,
+                seo_description: None,
+                canonical_url: None,
+                noindex: false,
+            })
+            .await
+            .unwrap();
+        let page = pages
+            .create(
+                owner_id,
+                &CreatePageCommand::new(
+                    "about".to_string(),
+                    "About".to_string(),
+                    "Hi".to_string(),
+                    PageStatus::Draft,
+                    None,
+                    None,
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let topic_id = CreateTopicService::new(topics)
+            .execute(
+                CreateTopicCommand::new(UserId::from(owner_id), "Rust".to_string(), None).unwrap(),
+            )
+            .await
+            .unwrap()
+            .id;
+
+        let service = RunBatchService::new(BatchTargets {
+            patch_project: Arc::new(PatchProjectService::new(projects.clone(), cache.clone())),
+            get_project: Arc::new(GetSingleProjectService::new(projects.clone())),
+            add_project_topic: Arc::new(AddProjectTopicService::new(
+                projects.clone(),
+                cache.clone(),
+            )),
+            remove_project_topic: Arc::new(RemoveProjectTopicService::new(
+                projects.clone(),
+                cache.clone(),
+            )),
+            get_page: Arc::new(GetPageService::new(pages.clone())),
+            update_page: Arc::new(UpdatePageService::new(pages.clone(), cache)),
+        });
+
+        Fixture {
+            service,
+            owner_id,
+            project,
+            page,
+            topic_id,
+            pages,
+            projects,
+        }
+    }
+
+    impl Fixture {
+        fn operations(&self, last: BatchOperation) -> Vec<BatchOperation> {
+            vec![
+                BatchOperation::PatchProject(ProjectPatch {
+                    project_id: self.project.id,
+                    title: PatchField::Value("Port CMS 2".to_string()),
+                    repo_url: PatchField::Value("https://example.com/cms".to_string()),
+                    ..ProjectPatch::default()
+                }),
+                BatchOperation::AddProjectTopic(ProjectTopicLink {
+                    project_id: self.project.id,
+                    topic_id: self.topic_id,
+                }),
+                BatchOperation::UpdatePage(PagePatch {
+                    page_id: self.page.id,
+                    title: PatchField::Value("About me".to_string()),
+                    seo_title: PatchField::Value("Who I am".to_string()),
+                    ..PagePatch::default()
+                }),
+                last,
+            ]
+        }
+
+        fn project_title(&self) -> String {
+            self.projects
+                .projects
+                .read(|rows| rows[0].project.title.clone())
+        }
+
+        fn linked_topics(&self) -> usize {
+            self.projects.links.read(|links| links.len())
+        }
+
+        fn stored_page(&self) -> Page {
+            self.pages.pages.read(|pages| pages[0].clone())
+        }
+    }
+
+    fn statuses(report: &BatchReport) -> Vec<BatchItemStatus> {
+        report.items.iter().map(|item| item.status).collect()
+    }
+
+    fn missing_page() -> BatchOperation {
+        BatchOperation::UpdatePage(PagePatch {
+            page_id: Uuid::new_v4(),
+            title: PatchField::Value("Gone".to_string()),
+            ..PagePatch::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_independent_batch_reports_each_operation() {
+        let fx = fixture().await;
+        let operations = fx.operations(missing_page());
+
+        let report = fx
+            .service
+            .execute(fx.owner_id, BatchMode::Independent, operations)
+            .await
+            .unwrap();
+
+        use BatchItemStatus::*;
+        assert_eq!(statuses(&report), vec![Ok, Ok, Ok, Failed]);
+        assert!(!report.succeeded);
+        assert_eq!(report.items[3].code.as_deref(), Some("PAGE_NOT_FOUND"));
+        assert_eq!(fx.project_title(), "Port CMS 2");
+        assert_eq!(fx.linked_topics(), 1);
+        assert_eq!(fx.stored_page().seo_title.as_deref(), Some("Who I am"));
+    }
+
+    #[tokio::test]
+    async fn test_atomic_batch_undoes_applied_operations_on_failure() {
+        let fx = fixture().await;
+        let mut operations = fx.operations(missing_page());
+        operations.push(BatchOperation::RemoveProjectTopic(ProjectTopicLink {
+            project_id: fx.project.id,
+            topic_id: fx.topic_id,
+        }));
+
+        let report = fx
+            .service
+            .execute(fx.owner_id, BatchMode::Atomic, operations)
+            .await
+            .unwrap();
+
+        use BatchItemStatus::*;
+        assert_eq!(
+            statuses(&report),
+            vec![RolledBack, RolledBack, RolledBack, Failed, Skipped]
+        );
+        assert!(!report.succeeded);
+        assert_eq!(fx.project_title(), "Port CMS");
+        let repo_url = fx
+            .projects
+            .projects
+            .read(|rows| rows[0].project.repo_url.clone());
+        assert_eq!(repo_url, None);
+        assert_eq!(fx.linked_topics(), 0);
+        let page = fx.stored_page();
+        assert_eq!(page.title, "About");
+        assert_eq!(page.seo_title, None);
+    }
+
+    #[tokio::test]
+    async fn test_atomic_batch_applies_everything_when_all_succeed() {
+        let fx = fixture().await;
+        let operations = fx.operations(BatchOperation::UpdatePage(PagePatch {
+            page_id: fx.page.id,
+            status: PatchField::Value(PageStatus::Published),
+            ..PagePatch::default()
+        }));
+
+        let report = fx
+            .service
+            .execute(fx.owner_id, BatchMode::Atomic, operations)
+            .await
+            .unwrap();
+
+        assert!(report.succeeded);
+        assert_eq!(fx.linked_topics(), 1);
+        assert_eq!(fx.stored_page().status, PageStatus::Published);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_page_fields_fail_with_the_endpoint_code() {
+        let fx = fixture().await;
+
+        let report = fx
+            .service
+            .execute(
+                fx.owner_id,
+                BatchMode::Independent,
+                vec![BatchOperation::UpdatePage(PagePatch {
+                    page_id: fx.page.id,
+                    title: PatchField::Null,
+                    ..PagePatch::default()
+                })],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(report.items[0].code.as_deref(), Some("INVALID_TITLE"));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_empty_and_oversized_batches() {
+        let fx = fixture().await;
+        let link = BatchOperation::AddProjectTopic(ProjectTopicLink {
+            project_id: fx.project.id,
+            topic_id: fx.topic_id,
+        });
+
+        assert!(matches!(
+            fx.service
+                .execute(fx.owner_id, BatchMode::Atomic, vec![])
+                .await,
+            Err(RunBatchError::Empty)
+        ));
+        assert!(matches!(
+            fx.service
+                .execute(
+                    fx.owner_id,
+                    BatchMode::Atomic,
+                    vec![link; MAX_BATCH_OPERATIONS + 1]
+                )
+                .await,
+            Err(RunBatchError::TooManyOperations(MAX_BATCH_OPERATIONS))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_other_owners_content_is_not_found() {
+        let fx = fixture().await;
+
+        let report = fx
+            .service
+            .execute(
+                Uuid::new_v4(),
+                BatchMode::Atomic,
+                vec![BatchOperation::AddProjectTopic(ProjectTopicLink {
+                    project_id: fx.project.id,
+                    topic_id: fx.topic_id,
+                })],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(report.items[0].code.as_deref(), Some("PROJECT_NOT_FOUND"));
+        assert_eq!(fx.linked_topics(), 0);
+    }
+}
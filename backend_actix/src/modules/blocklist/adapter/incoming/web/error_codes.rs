@@ -0,0 +1,10 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    INVALID_IP_RANGE = (BAD_REQUEST, "Invalid IP address or CIDR range");
+    INVALID_USER_AGENT_PATTERN = (BAD_REQUEST, "Invalid user agent pattern");
+    NOTE_TOO_LONG = (BAD_REQUEST, "Note is too long");
+    BLOCK_RULE_NOT_FOUND = (NOT_FOUND, "Block rule not found");
+    BLOCK_RULE_ALREADY_EXISTS = (CONFLICT, "The same block rule already exists");
+    REQUEST_BLOCKED = (FORBIDDEN, "Request blocked");
+}
@@ -0,0 +1,180 @@
+use actix_web::{
+    body::{EitherBody, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    http::{header, Method},
+    middleware::Next,
+    web, Error,
+};
+
+use crate::blocklist::adapter::incoming::web::error_codes::REQUEST_BLOCKED;
+use crate::blocklist::application::domain::entities::Requester;
+use crate::shared::client_ip::client_ip;
+use crate::AppState;
+
+/// Public writes outside `/api/public/`
+const PUBLIC_WRITE_PATHS: &[&str] = &["/api/auth/register"];
+
+/// Whether the request writes through an endpoint anyone can call: the
+/// contact form, page views, unsubscribe links and sign-up
+fn is_public_write(method: &Method, path: &str) -> bool {
+    let safe_method = matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS);
+    !safe_method && (path.starts_with("/api/public/") || PUBLIC_WRITE_PATHS.contains(&path))
+}
+
+fn requester_of(req: &ServiceRequest) -> Requester {
+    let ip = client_ip(req.request());
+    let user_agent = req
+        .headers()
+        .get(header::USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+
+    Requester { ip, user_agent }
+}
+
+/// Turns away public writes from blocked addresses and user agents with 403
+/// `REQUEST_BLOCKED`. Reads are never blocked. If the rules can't be
+/// loaded the request goes through: a database hiccup shouldn't take the
+/// contact form down with it.
+pub async fn blocklist_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    if is_public_write(req.method(), req.path()) {
+        if let Some(state) = req.app_data::<web::Data<AppState>>() {
+            let requester = requester_of(&req);
+            match state.blocklist.check.execute(&requester).await {
+                Ok(Some(rule)) => {
+                    tracing::warn!(
+                        path = %req.path(),
+                        rule_id = %rule.id,
+                        kind = rule.kind.as_str(),
+                        "Blocked public write"
+                    );
+                    return Ok(req
+                        .into_response(REQUEST_BLOCKED.response())
+                        .map_into_right_body());
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::warn!(
+                        path = %req.path(),
+                        "Blocklist check failed, letting request through: {}",
+                        e
+                    );
+                }
+            }
+        }
+    }
+
+    next.call(req)
+        .await
+        .map(ServiceResponse::map_into_left_body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{middleware::from_fn, test, App, HttpResponse};
+
+    use crate::blocklist::adapter::outgoing::InMemoryBlockRuleStore;
+    use crate::blocklist::application::blocklist_use_cases::BlocklistUseCases;
+    use crate::blocklist::application::domain::entities::BlockRuleKind;
+    use crate::blocklist::application::ports::incoming::use_cases::CreateBlockRuleCommand;
+    use crate::tests::support::app_state_builder::TestAppStateBuilder;
+
+    async fn ok() -> HttpResponse {
+        HttpResponse::Ok().finish()
+    }
+
+    async fn blocklist_with(kind: BlockRuleKind, pattern: &str) -> BlocklistUseCases {
+        let blocklist = BlocklistUseCases::build(InMemoryBlockRuleStore::default());
+        let command = CreateBlockRuleCommand::new(kind, pattern.to_string(), None).unwrap();
+        blocklist.create.execute(command).await.unwrap();
+        blocklist
+    }
+
+    macro_rules! app {
+        ($blocklist:expr) => {
+            test::init_service(
+                App::new()
+                    .app_data(
+                        TestAppStateBuilder::default()
+                            .with_blocklist($blocklist)
+                            .build(),
+                    )
+                    .wrap(from_fn(blocklist_middleware))
+                    .route("/api/public/contact", web::post().to(ok))
+                    .route("/api/public/pages/about", web::get().to(ok))
+                    .route("/api/projects", web::post().to(ok)),
+            )
+            .await
+        };
+    }
+
+    #[actix_web::test]
+    async fn test_only_public_writes_are_checked() {
+        assert!(is_public_write(&Method::POST, "/api/public/contact"));
+        assert!(is_public_write(&Method::POST, "/api/auth/register"));
+        assert!(!is_public_write(&Method::GET, "/api/public/pages/about"));
+        assert!(!is_public_write(&Method::POST, "/api/projects"));
+        assert!(!is_public_write(&Method::POST, "/api/auth/login"));
+    }
+
+    #[actix_web::test]
+    async fn test_blocked_address_cannot_write_but_can_read() {
+        let blocklist = blocklist_with(BlockRuleKind::Ip, "203.0.113.0/24").await;
+        let app = app!(blocklist.clone());
+
+        let contact = test::TestRequest::post()
+            .uri("/api/public/contact")
+            .peer_addr("203.0.113.7:4000".parse().unwrap())
+            .to_request();
+        let resp = test::call_service(&app, contact).await;
+        assert_eq!(resp.status(), 403);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "REQUEST_BLOCKED");
+
+        let read = test::TestRequest::get()
+            .uri("/api/public/pages/about")
+            .peer_addr("203.0.113.7:4000".parse().unwrap())
+            .to_request();
+        assert_eq!(test::call_service(&app, read).await.status(), 200);
+
+        let other = test::TestRequest::post()
+            .uri("/api/public/contact")
+            .peer_addr("198.51.100.7:4000".parse().unwrap())
+            .to_request();
+        assert_eq!(test::call_service(&app, other).await.status(), 200);
+
+        // No proxy is trusted, so the header can't stand in for the address
+        let spoofed = test::TestRequest::post()
+            .uri("/api/public/contact")
+            .peer_addr("203.0.113.7:4000".parse().unwrap())
+            .insert_header(("X-Forwarded-For", "198.51.100.7"))
+            .to_request();
+        assert_eq!(test::call_service(&app, spoofed).await.status(), 403);
+
+        let rules = blocklist.list.execute(0, 10).await.unwrap();
+        assert_eq!(rules.items[0].hits, 2);
+        assert!(rules.items[0].last_hit_at.is_some());
+    }
+
+    #[actix_web::test]
+    async fn test_blocked_user_agent_cannot_write() {
+        let app = app!(blocklist_with(BlockRuleKind::UserAgent, "spambot").await);
+
+        let blocked = test::TestRequest::post()
+            .uri("/api/public/contact")
+            .insert_header((header::USER_AGENT, "Mozilla/5.0 (compatible; SpamBot/2.1)"))
+            .to_request();
+        assert_eq!(test::call_service(&app, blocked).await.status(), 403);
+
+        let authenticated = test::TestRequest::post()
+            .uri("/api/projects")
+            .insert_header((header::USER_AGENT, "SpamBot/2.1"))
+            .to_request();
+        assert_eq!(test::call_service(&app, authenticated).await.status(), 200);
+    }
+}
@@ -0,0 +1,12 @@
+use actix_web::web;
+
+pub mod error_codes;
+pub mod middleware;
+pub mod routes;
+
+/// Routes for signed-in users with a verified email
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::create_block_rule_handler)
+        .service(routes::list_block_rules_handler)
+        .service(routes::delete_block_rule_handler);
+}
@@ -0,0 +1,168 @@
+use actix_web::{post, web, HttpResponse, Responder};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use utoipa::ToSchema;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    blocklist::{
+        adapter::incoming::web::error_codes::{
+            BLOCK_RULE_ALREADY_EXISTS, INVALID_IP_RANGE, INVALID_USER_AGENT_PATTERN, NOTE_TOO_LONG,
+        },
+        application::{
+            domain::entities::{BlockRule, BlockRuleKind},
+            ports::incoming::use_cases::{
+                BlockRuleCommandError, CreateBlockRuleCommand, CreateBlockRuleError,
+            },
+        },
+    },
+    shared::api::ApiResponse,
+    AppState,
+};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct CreateBlockRuleRequest {
+    pub kind: BlockRuleKind,
+    /// CIDR range or single address for `ip`, e.g. `203.0.113.0/24`;
+    /// substring of the `User-Agent` header for `user_agent`
+    pub pattern: String,
+    /// Why the rule was added
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+fn map_command_error(err: BlockRuleCommandError) -> HttpResponse {
+    let code = match err {
+        BlockRuleCommandError::InvalidIpRange => INVALID_IP_RANGE,
+        BlockRuleCommandError::InvalidUserAgentPattern => INVALID_USER_AGENT_PATTERN,
+        BlockRuleCommandError::NoteTooLong => NOTE_TOO_LONG,
+    };
+    code.with_message(&err.to_string())
+}
+
+/// Block an address range or user agent from the public write endpoints
+///
+/// Matching requests to the contact form, page view tracking, unsubscribe
+/// links and sign-up get 403 `REQUEST_BLOCKED`. Reads are not affected.
+#[utoipa::path(
+    post,
+    path = "/api/admin/blocklist",
+    tag = "blocklist",
+    request_body = CreateBlockRuleRequest,
+    responses(
+        (status = 201, description = "Rule created", body = inline(SuccessResponse<BlockRule>)),
+        (status = 400, description = "Invalid pattern or note, or malformed request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 409, description = "The same rule already exists", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/admin/blocklist")]
+pub async fn create_block_rule_handler(
+    admin: AdminUser,
+    payload: web::Json<CreateBlockRuleRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let payload = payload.into_inner();
+    let command = match CreateBlockRuleCommand::new(payload.kind, payload.pattern, payload.note) {
+        Ok(cmd) => cmd,
+        Err(err) => return map_command_error(err),
+    };
+
+    match data.blocklist.create.execute(command).await {
+        Ok(rule) => ApiResponse::created(rule),
+        Err(CreateBlockRuleError::AlreadyExists) => BLOCK_RULE_ALREADY_EXISTS.response(),
+        Err(CreateBlockRuleError::RepositoryError(msg)) => {
+            error!(admin = %admin.user_id, "Failed to create block rule: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::json;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service,
+        },
+    };
+
+    async fn call(
+        state: web::Data<AppState>,
+        user_id: Uuid,
+        body: serde_json::Value,
+    ) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(user_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(create_block_rule_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/admin/blocklist")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(body)
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_rule_is_created_once() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .build();
+        let body = json!({ "kind": "ip", "pattern": "203.0.113.77/24", "note": "Contact spam" });
+
+        let resp = call(state.clone(), admin, body.clone()).await;
+
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["pattern"], "203.0.113.0/24");
+        assert_eq!(json["data"]["hits"], 0);
+
+        let resp = call(state, admin, body).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "BLOCK_RULE_ALREADY_EXISTS");
+    }
+
+    #[actix_web::test]
+    async fn test_invalid_range_is_rejected() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .build();
+
+        let resp = call(
+            state,
+            admin,
+            json!({ "kind": "ip", "pattern": "203.0.113.0/33" }),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "INVALID_IP_RANGE");
+    }
+}
@@ -0,0 +1,93 @@
+use actix_web::{delete, web, Responder};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::api::schemas::ErrorResponse;
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    blocklist::{
+        adapter::incoming::web::error_codes::BLOCK_RULE_NOT_FOUND,
+        application::ports::incoming::use_cases::DeleteBlockRuleError,
+    },
+    shared::api::ApiResponse,
+    AppState,
+};
+
+/// Delete a block rule. Matching requests go through again.
+#[utoipa::path(
+    delete,
+    path = "/api/admin/blocklist/{rule_id}",
+    tag = "blocklist",
+    params(
+        ("rule_id" = Uuid, Path, description = "Block rule id"),
+    ),
+    responses(
+        (status = 204, description = "Rule deleted"),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 404, description = "Block rule not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[delete("/api/admin/blocklist/{rule_id}")]
+pub async fn delete_block_rule_handler(
+    admin: AdminUser,
+    path: web::Path<Uuid>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let rule_id = path.into_inner();
+
+    match data.blocklist.delete.execute(rule_id).await {
+        Ok(()) => ApiResponse::no_content(),
+        Err(DeleteBlockRuleError::NotFound) => BLOCK_RULE_NOT_FOUND.response(),
+        Err(DeleteBlockRuleError::RepositoryError(msg)) => {
+            error!(admin = %admin.user_id, "Failed to delete block rule {}: {}", rule_id, msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service,
+        },
+    };
+
+    #[actix_web::test]
+    async fn test_unknown_rule_is_not_found() {
+        let admin = Uuid::new_v4();
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(admin, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(delete_block_rule_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/admin/blocklist/{}", Uuid::new_v4()))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,98 @@
+use actix_web::{get, web, Responder};
+use tracing::error;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    blocklist::application::{
+        domain::entities::BlockRule, ports::incoming::use_cases::ListBlockRulesError,
+    },
+    shared::api::{
+        pagination::{PageParams, PagedResponse},
+        ApiResponse,
+    },
+    AppState,
+};
+
+/// Every block rule with its hit count, newest first
+#[utoipa::path(
+    get,
+    path = "/api/admin/blocklist",
+    tag = "blocklist",
+    params(
+        ("limit" = Option<u32>, Query, description = "Page size, 1-100 (default 20)"),
+        ("cursor" = Option<String>, Query, description = "`next_cursor` from the previous page"),
+    ),
+    responses(
+        (status = 200, description = "One page of block rules", body = inline(SuccessResponse<PagedResponse<BlockRule>>)),
+        (status = 400, description = "Invalid pagination parameters", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/admin/blocklist")]
+pub async fn list_block_rules_handler(
+    admin: AdminUser,
+    page: PageParams,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data.blocklist.list.execute(page.offset, page.limit).await {
+        Ok(result) => ApiResponse::success(PagedResponse::new(result.items, result.total, &page)),
+        Err(ListBlockRulesError::QueryFailed(msg)) => {
+            error!(admin = %admin.user_id, "Failed to list block rules: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service,
+        },
+    };
+
+    async fn call(state: web::Data<AppState>, user_id: Uuid) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(user_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(list_block_rules_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/api/admin/blocklist")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_non_admin_is_forbidden() {
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![Uuid::new_v4()])
+            .build();
+
+        let resp = call(state, Uuid::new_v4()).await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+}
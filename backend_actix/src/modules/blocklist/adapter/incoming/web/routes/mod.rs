@@ -0,0 +1,7 @@
+mod create_block_rule;
+mod delete_block_rule;
+mod list_block_rules;
+
+pub use create_block_rule::{__path_create_block_rule_handler, create_block_rule_handler};
+pub use delete_block_rule::{__path_delete_block_rule_handler, delete_block_rule_handler};
+pub use list_block_rules::{__path_list_block_rules_handler, list_block_rules_handler};
@@ -0,0 +1,278 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::blocklist::application::domain::entities::{BlockRule, BlockRuleKind};
+use crate::blocklist::application::ports::incoming::use_cases::CreateBlockRuleCommand;
+use crate::blocklist::application::ports::outgoing::{
+    BlockRulePage, BlockRuleRepository, BlockRuleRepositoryError,
+};
+use crate::shared::sql;
+
+const BLOCK_RULE_COLUMNS: &str = "id, kind, pattern, note, hits, last_hit_at, created_at";
+
+#[derive(Clone)]
+pub struct BlockRuleRepositoryPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl BlockRuleRepositoryPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    fn insert_stmt(backend: DatabaseBackend, command: &CreateBlockRuleCommand) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                INSERT INTO block_rules (id, kind, pattern, note)
+                VALUES ($1, $2, $3, $4)
+                RETURNING {BLOCK_RULE_COLUMNS}
+                "#
+            ),
+            vec![
+                Uuid::new_v4().into(),
+                command.kind().as_str().into(),
+                command.pattern().into(),
+                command.note().map(str::to_string).into(),
+            ],
+        )
+    }
+
+    fn list_stmt(backend: DatabaseBackend, offset: u64, limit: u32) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                SELECT {BLOCK_RULE_COLUMNS}
+                FROM block_rules
+                ORDER BY created_at DESC, id
+                LIMIT $2
+                OFFSET $1
+                "#
+            ),
+            vec![(offset as i64).into(), (limit as i64).into()],
+        )
+    }
+
+    fn count_stmt(backend: DatabaseBackend) -> Statement {
+        Statement::from_string(backend, "SELECT COUNT(*) AS total FROM block_rules")
+    }
+
+    fn all_stmt(backend: DatabaseBackend) -> Statement {
+        Statement::from_string(
+            backend,
+            format!("SELECT {BLOCK_RULE_COLUMNS} FROM block_rules ORDER BY created_at, id"),
+        )
+    }
+
+    fn delete_stmt(backend: DatabaseBackend, id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            "DELETE FROM block_rules WHERE id = $1",
+            vec![id.into()],
+        )
+    }
+
+    fn record_hit_stmt(backend: DatabaseBackend, id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                "UPDATE block_rules SET hits = hits + 1, last_hit_at = {now} WHERE id = $1",
+                now = sql::now(backend),
+            ),
+            vec![id.into()],
+        )
+    }
+
+    // =====================================================
+    // Mapping
+    // =====================================================
+
+    fn to_block_rule(row: &QueryResult) -> Result<BlockRule, BlockRuleRepositoryError> {
+        let kind: String = row.try_get("", "kind").map_err(Self::map_db_err)?;
+        let hits: i64 = row.try_get("", "hits").map_err(Self::map_db_err)?;
+
+        Ok(BlockRule {
+            id: row.try_get("", "id").map_err(Self::map_db_err)?,
+            kind: kind
+                .parse::<BlockRuleKind>()
+                .map_err(BlockRuleRepositoryError::DatabaseError)?,
+            pattern: row.try_get("", "pattern").map_err(Self::map_db_err)?,
+            note: row.try_get("", "note").map_err(Self::map_db_err)?,
+            hits: hits.max(0) as u64,
+            last_hit_at: row.try_get("", "last_hit_at").map_err(Self::map_db_err)?,
+            created_at: row.try_get("", "created_at").map_err(Self::map_db_err)?,
+        })
+    }
+
+    /// Inserts hit the unique kind and pattern index
+    fn map_insert_err(e: DbErr) -> BlockRuleRepositoryError {
+        let msg = e.to_string().to_lowercase();
+
+        if msg.contains("duplicate") || msg.contains("unique") || msg.contains("23505") {
+            BlockRuleRepositoryError::AlreadyExists
+        } else {
+            BlockRuleRepositoryError::DatabaseError(e.to_string())
+        }
+    }
+
+    fn map_db_err(e: DbErr) -> BlockRuleRepositoryError {
+        BlockRuleRepositoryError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl BlockRuleRepository for BlockRuleRepositoryPostgres {
+    async fn create(
+        &self,
+        command: &CreateBlockRuleCommand,
+    ) -> Result<BlockRule, BlockRuleRepositoryError> {
+        let row = self
+            .db
+            .query_one(Self::insert_stmt(self.db.get_database_backend(), command))
+            .await
+            .map_err(Self::map_insert_err)?
+            .ok_or_else(|| {
+                BlockRuleRepositoryError::DatabaseError("insert returned no row".into())
+            })?;
+
+        Self::to_block_rule(&row)
+    }
+
+    async fn list(
+        &self,
+        offset: u64,
+        limit: u32,
+    ) -> Result<BlockRulePage, BlockRuleRepositoryError> {
+        let backend = self.db.get_database_backend();
+        let items = self
+            .db
+            .query_all(Self::list_stmt(backend, offset, limit))
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(Self::to_block_rule)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let total: i64 = self
+            .db
+            .query_one(Self::count_stmt(backend))
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or_else(|| BlockRuleRepositoryError::DatabaseError("count returned no row".into()))?
+            .try_get("", "total")
+            .map_err(Self::map_db_err)?;
+
+        Ok(BlockRulePage {
+            items,
+            total: total.max(0) as u64,
+        })
+    }
+
+    async fn all(&self) -> Result<Vec<BlockRule>, BlockRuleRepositoryError> {
+        self.db
+            .query_all(Self::all_stmt(self.db.get_database_backend()))
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(Self::to_block_rule)
+            .collect()
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), BlockRuleRepositoryError> {
+        let res = self
+            .db
+            .execute(Self::delete_stmt(self.db.get_database_backend(), id))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        if res.rows_affected() == 0 {
+            return Err(BlockRuleRepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn record_hit(&self, id: Uuid) -> Result<(), BlockRuleRepositoryError> {
+        self.db
+            .execute(Self::record_hit_stmt(self.db.get_database_backend(), id))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use sea_orm::{MockDatabase, Value};
+    use std::collections::BTreeMap;
+
+    fn rule_row(kind: &str) -> BTreeMap<String, Value> {
+        BTreeMap::from([
+            ("id".to_string(), Value::from(Uuid::new_v4())),
+            ("kind".to_string(), Value::from(kind)),
+            ("pattern".to_string(), Value::from("203.0.113.0/24")),
+            ("note".to_string(), Value::from(None::<String>)),
+            ("hits".to_string(), Value::from(3i64)),
+            ("last_hit_at".to_string(), Value::from(Utc::now())),
+            ("created_at".to_string(), Value::from(Utc::now())),
+        ])
+    }
+
+    #[tokio::test]
+    async fn test_create_maps_duplicate_rule() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_errors([DbErr::Custom(
+                "duplicate key value violates unique constraint \"idx_block_rules_kind_pattern\""
+                    .to_string(),
+            )])
+            .into_connection();
+        let command =
+            CreateBlockRuleCommand::new(BlockRuleKind::Ip, "203.0.113.0/24".to_string(), None)
+                .unwrap();
+
+        let repo = BlockRuleRepositoryPostgres::new(Arc::new(db));
+        let result = repo.create(&command).await;
+
+        assert!(matches!(
+            result,
+            Err(BlockRuleRepositoryError::AlreadyExists)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_all_reads_every_rule() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![rule_row("ip"), rule_row("user_agent")]])
+            .into_connection();
+
+        let repo = BlockRuleRepositoryPostgres::new(Arc::new(db));
+        let rules = repo.all().await.unwrap();
+
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].kind, BlockRuleKind::Ip);
+        assert_eq!(rules[1].kind, BlockRuleKind::UserAgent);
+        assert_eq!(rules[0].hits, 3);
+    }
+
+    #[test]
+    fn test_hit_is_counted_in_place() {
+        let stmt =
+            BlockRuleRepositoryPostgres::record_hit_stmt(DatabaseBackend::Postgres, Uuid::new_v4());
+
+        assert!(stmt.sql.contains("hits = hits + 1"));
+        assert!(stmt.sql.contains("WHERE id = $1"));
+    }
+}
@@ -0,0 +1,88 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::blocklist::application::domain::entities::BlockRule;
+use crate::blocklist::application::ports::incoming::use_cases::CreateBlockRuleCommand;
+use crate::blocklist::application::ports::outgoing::{
+    BlockRulePage, BlockRuleRepository, BlockRuleRepositoryError,
+};
+use crate::shared::in_memory::Table;
+
+/// Process-local `BlockRuleRepository`
+#[derive(Clone, Default)]
+pub struct InMemoryBlockRuleStore {
+    rules: Table<BlockRule>,
+}
+
+#[async_trait]
+impl BlockRuleRepository for InMemoryBlockRuleStore {
+    async fn create(
+        &self,
+        command: &CreateBlockRuleCommand,
+    ) -> Result<BlockRule, BlockRuleRepositoryError> {
+        self.rules.write(|rules| {
+            if rules
+                .iter()
+                .any(|rule| rule.kind == command.kind() && rule.pattern == command.pattern())
+            {
+                return Err(BlockRuleRepositoryError::AlreadyExists);
+            }
+
+            let rule = BlockRule {
+                id: Uuid::new_v4(),
+                kind: command.kind(),
+                pattern: command.pattern().to_string(),
+                note: command.note().map(str::to_string),
+                hits: 0,
+                last_hit_at: None,
+                created_at: Utc::now(),
+            };
+            rules.push(rule.clone());
+            Ok(rule)
+        })
+    }
+
+    async fn list(
+        &self,
+        offset: u64,
+        limit: u32,
+    ) -> Result<BlockRulePage, BlockRuleRepositoryError> {
+        let rules = self.rules.read(|rules| rules.to_vec());
+
+        Ok(BlockRulePage {
+            total: rules.len() as u64,
+            items: rules
+                .into_iter()
+                .rev()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .collect(),
+        })
+    }
+
+    async fn all(&self) -> Result<Vec<BlockRule>, BlockRuleRepositoryError> {
+        Ok(self.rules.read(|rules| rules.to_vec()))
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), BlockRuleRepositoryError> {
+        self.rules.write(|rules| {
+            let before = rules.len();
+            rules.retain(|rule| rule.id != id);
+            if rules.len() == before {
+                return Err(BlockRuleRepositoryError::NotFound);
+            }
+            Ok(())
+        })
+    }
+
+    async fn record_hit(&self, id: Uuid) -> Result<(), BlockRuleRepositoryError> {
+        self.rules.write(|rules| {
+            if let Some(rule) = rules.iter_mut().find(|rule| rule.id == id) {
+                rule.hits += 1;
+                rule.last_hit_at = Some(Utc::now());
+            }
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+mod block_rule_repository_postgres;
+mod in_memory;
+
+pub use block_rule_repository_postgres::BlockRuleRepositoryPostgres;
+pub use in_memory::InMemoryBlockRuleStore;
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use crate::blocklist::application::ports::incoming::use_cases::{
+    CheckBlocklistUseCase, CreateBlockRuleUseCase, DeleteBlockRuleUseCase, ListBlockRulesUseCase,
+};
+use crate::blocklist::application::ports::outgoing::BlockRuleRepository;
+use crate::blocklist::application::services::{
+    CheckBlocklistService, CreateBlockRuleService, DeleteBlockRuleService, ListBlockRulesService,
+};
+use crate::shared::metrics::Metered;
+
+#[derive(Clone)]
+pub struct BlocklistUseCases {
+    pub create: Arc<dyn CreateBlockRuleUseCase + Send + Sync>,
+    pub list: Arc<dyn ListBlockRulesUseCase + Send + Sync>,
+    pub delete: Arc<dyn DeleteBlockRuleUseCase + Send + Sync>,
+    pub check: Arc<dyn CheckBlocklistUseCase + Send + Sync>,
+}
+
+impl BlocklistUseCases {
+    pub fn build<R>(repository: R) -> Self
+    where
+        R: BlockRuleRepository + Clone + 'static,
+    {
+        Self {
+            create: Arc::new(Metered(CreateBlockRuleService::new(repository.clone()))),
+            list: Arc::new(Metered(ListBlockRulesService::new(repository.clone()))),
+            delete: Arc::new(Metered(DeleteBlockRuleService::new(repository.clone()))),
+            check: Arc::new(Metered(CheckBlocklistService::new(repository))),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use std::str::FromStr;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::ip_range::IpRange;
+
+/// What a block rule is matched against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BlockRuleKind {
+    /// The client address, against a CIDR range
+    Ip,
+    /// The `User-Agent` header, against a substring
+    UserAgent,
+}
+
+impl BlockRuleKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BlockRuleKind::Ip => "ip",
+            BlockRuleKind::UserAgent => "user_agent",
+        }
+    }
+}
+
+impl FromStr for BlockRuleKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ip" => Ok(BlockRuleKind::Ip),
+            "user_agent" => Ok(BlockRuleKind::UserAgent),
+            other => Err(format!("unknown block rule kind '{other}'")),
+        }
+    }
+}
+
+/// Who sent a request, as far as the block rules care
+#[derive(Debug, Clone, Default)]
+pub struct Requester {
+    pub ip: Option<IpAddr>,
+    pub user_agent: String,
+}
+
+/// Keeps matching requests out of the public write endpoints
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BlockRule {
+    pub id: Uuid,
+    pub kind: BlockRuleKind,
+    /// CIDR range for `ip` (e.g. `203.0.113.0/24`), substring for
+    /// `user_agent` (matched ignoring case)
+    pub pattern: String,
+    /// Why the rule was added
+    pub note: Option<String>,
+    /// Requests the rule has blocked
+    pub hits: u64,
+    /// Absent until the rule first blocks a request
+    pub last_hit_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl BlockRule {
+    pub fn matches(&self, requester: &Requester) -> bool {
+        match self.kind {
+            BlockRuleKind::Ip => match (requester.ip, self.pattern.parse::<IpRange>()) {
+                (Some(ip), Ok(range)) => range.contains(ip),
+                _ => false,
+            },
+            BlockRuleKind::UserAgent => requester
+                .user_agent
+                .to_lowercase()
+                .contains(&self.pattern.to_lowercase()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(kind: BlockRuleKind, pattern: &str) -> BlockRule {
+        BlockRule {
+            id: Uuid::new_v4(),
+            kind,
+            pattern: pattern.to_string(),
+            note: None,
+            hits: 0,
+            last_hit_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_rules_match_address_range_or_user_agent_substring() {
+        let requester = Requester {
+            ip: Some("203.0.113.7".parse().unwrap()),
+            user_agent: "Mozilla/5.0 (compatible; SpamBot/2.1)".to_string(),
+        };
+
+        assert!(rule(BlockRuleKind::Ip, "203.0.113.0/24").matches(&requester));
+        assert!(!rule(BlockRuleKind::Ip, "198.51.100.0/24").matches(&requester));
+        assert!(rule(BlockRuleKind::UserAgent, "spambot").matches(&requester));
+        assert!(!rule(BlockRuleKind::UserAgent, "curl/").matches(&requester));
+
+        // No known address: address rules can't match
+        let anonymous = Requester::default();
+        assert!(!rule(BlockRuleKind::Ip, "0.0.0.0/0").matches(&anonymous));
+    }
+}
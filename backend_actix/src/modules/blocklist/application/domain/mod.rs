@@ -0,0 +1,2 @@
+pub mod entities;
+pub use crate::shared::ip_range;
@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+
+use crate::blocklist::application::domain::entities::{BlockRule, Requester};
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum CheckBlocklistError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait CheckBlocklistUseCase: Send + Sync {
+    /// The oldest rule matching `requester`, with the hit counted; `None`
+    /// lets the request through.
+    async fn execute(
+        &self,
+        requester: &Requester,
+    ) -> Result<Option<BlockRule>, CheckBlocklistError>;
+}
+
+metered_use_case!(
+    "blocklist",
+    "check_blocklist",
+    CheckBlocklistUseCase,
+    fn execute(&self, requester: &Requester) -> Result<Option<BlockRule>, CheckBlocklistError>
+);
@@ -0,0 +1,172 @@
+use async_trait::async_trait;
+
+use crate::blocklist::application::domain::entities::{BlockRule, BlockRuleKind};
+use crate::blocklist::application::domain::ip_range::IpRange;
+use crate::shared::metrics::metered_use_case;
+
+pub const MIN_USER_AGENT_PATTERN_LENGTH: usize = 3;
+pub const MAX_PATTERN_LENGTH: usize = 200;
+pub const MAX_NOTE_LENGTH: usize = 200;
+
+//
+// ──────────────────────────────────────────────────────────
+// Create Block Rule Command
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, thiserror::Error)]
+pub enum BlockRuleCommandError {
+    #[error("Pattern must be an IP address or CIDR range, e.g. '203.0.113.0/24'")]
+    InvalidIpRange,
+
+    #[error(
+        "User agent pattern must be {MIN_USER_AGENT_PATTERN_LENGTH}-{MAX_PATTERN_LENGTH} characters, so it can't match every browser by accident"
+    )]
+    InvalidUserAgentPattern,
+
+    #[error("Note must be at most {MAX_NOTE_LENGTH} characters")]
+    NoteTooLong,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateBlockRuleCommand {
+    kind: BlockRuleKind,
+    pattern: String,
+    note: Option<String>,
+}
+
+impl CreateBlockRuleCommand {
+    /// Ranges are stored in canonical form (`203.0.113.77/24` becomes
+    /// `203.0.113.0/24`); user agent patterns and notes are trimmed, and a
+    /// blank note counts as absent.
+    pub fn new(
+        kind: BlockRuleKind,
+        pattern: String,
+        note: Option<String>,
+    ) -> Result<Self, BlockRuleCommandError> {
+        let pattern = match kind {
+            BlockRuleKind::Ip => pattern
+                .parse::<IpRange>()
+                .map_err(|_| BlockRuleCommandError::InvalidIpRange)?
+                .to_string(),
+            BlockRuleKind::UserAgent => {
+                let pattern = pattern.trim();
+                let length = pattern.chars().count();
+                if !(MIN_USER_AGENT_PATTERN_LENGTH..=MAX_PATTERN_LENGTH).contains(&length) {
+                    return Err(BlockRuleCommandError::InvalidUserAgentPattern);
+                }
+                pattern.to_string()
+            }
+        };
+
+        let note = note
+            .map(|note| note.trim().to_string())
+            .filter(|note| !note.is_empty());
+        if note
+            .as_ref()
+            .is_some_and(|note| note.chars().count() > MAX_NOTE_LENGTH)
+        {
+            return Err(BlockRuleCommandError::NoteTooLong);
+        }
+
+        Ok(Self {
+            kind,
+            pattern,
+            note,
+        })
+    }
+
+    pub fn kind(&self) -> BlockRuleKind {
+        self.kind
+    }
+
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Use Case Error
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum CreateBlockRuleError {
+    #[error("The same rule already exists")]
+    AlreadyExists,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Incoming Port (Use Case)
+// ──────────────────────────────────────────────────────────
+//
+
+#[async_trait]
+pub trait CreateBlockRuleUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        command: CreateBlockRuleCommand,
+    ) -> Result<BlockRule, CreateBlockRuleError>;
+}
+
+metered_use_case!(
+    "blocklist",
+    "create_block_rule",
+    CreateBlockRuleUseCase,
+    fn execute(&self, command: CreateBlockRuleCommand) -> Result<BlockRule, CreateBlockRuleError>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_normalizes_patterns() {
+        let command = CreateBlockRuleCommand::new(
+            BlockRuleKind::Ip,
+            " 203.0.113.77/24 ".to_string(),
+            Some("  ".to_string()),
+        )
+        .unwrap();
+        assert_eq!(command.pattern(), "203.0.113.0/24");
+        assert_eq!(command.note(), None);
+
+        let command = CreateBlockRuleCommand::new(
+            BlockRuleKind::UserAgent,
+            " SpamBot ".to_string(),
+            Some(" March wave ".to_string()),
+        )
+        .unwrap();
+        assert_eq!(command.pattern(), "SpamBot");
+        assert_eq!(command.note(), Some("March wave"));
+    }
+
+    #[test]
+    fn test_command_rejects_unusable_patterns() {
+        assert!(matches!(
+            CreateBlockRuleCommand::new(BlockRuleKind::Ip, "spam.example".to_string(), None),
+            Err(BlockRuleCommandError::InvalidIpRange)
+        ));
+        assert!(matches!(
+            CreateBlockRuleCommand::new(BlockRuleKind::UserAgent, " a ".to_string(), None),
+            Err(BlockRuleCommandError::InvalidUserAgentPattern)
+        ));
+        assert!(matches!(
+            CreateBlockRuleCommand::new(
+                BlockRuleKind::UserAgent,
+                "curl/".to_string(),
+                Some("n".repeat(MAX_NOTE_LENGTH + 1))
+            ),
+            Err(BlockRuleCommandError::NoteTooLong)
+        ));
+    }
+}
@@ -0,0 +1,24 @@
+use crate::shared::metrics::metered_use_case;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DeleteBlockRuleError {
+    #[error("Block rule not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait DeleteBlockRuleUseCase: Send + Sync {
+    async fn execute(&self, id: Uuid) -> Result<(), DeleteBlockRuleError>;
+}
+
+metered_use_case!(
+    "blocklist",
+    "delete_block_rule",
+    DeleteBlockRuleUseCase,
+    fn execute(&self, id: Uuid) -> Result<(), DeleteBlockRuleError>
+);
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::blocklist::application::ports::outgoing::BlockRulePage;
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ListBlockRulesError {
+    #[error("Failed to list block rules: {0}")]
+    QueryFailed(String),
+}
+
+#[async_trait]
+pub trait ListBlockRulesUseCase: Send + Sync {
+    /// Newest first
+    async fn execute(&self, offset: u64, limit: u32) -> Result<BlockRulePage, ListBlockRulesError>;
+}
+
+metered_use_case!(
+    "blocklist",
+    "list_block_rules",
+    ListBlockRulesUseCase,
+    fn execute(&self, offset: u64, limit: u32) -> Result<BlockRulePage, ListBlockRulesError>
+);
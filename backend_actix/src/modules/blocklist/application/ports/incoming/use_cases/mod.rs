@@ -0,0 +1,12 @@
+mod check_blocklist_use_case;
+mod create_block_rule_use_case;
+mod delete_block_rule_use_case;
+mod list_block_rules_use_case;
+
+pub use check_blocklist_use_case::{CheckBlocklistError, CheckBlocklistUseCase};
+pub use create_block_rule_use_case::{
+    BlockRuleCommandError, CreateBlockRuleCommand, CreateBlockRuleError, CreateBlockRuleUseCase,
+    MAX_NOTE_LENGTH, MAX_PATTERN_LENGTH, MIN_USER_AGENT_PATTERN_LENGTH,
+};
+pub use delete_block_rule_use_case::{DeleteBlockRuleError, DeleteBlockRuleUseCase};
+pub use list_block_rules_use_case::{ListBlockRulesError, ListBlockRulesUseCase};
@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::blocklist::application::domain::entities::BlockRule;
+use crate::blocklist::application::ports::incoming::use_cases::CreateBlockRuleCommand;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum BlockRuleRepositoryError {
+    #[error("Block rule not found")]
+    NotFound,
+
+    #[error("The same rule already exists")]
+    AlreadyExists,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BlockRulePage {
+    pub items: Vec<BlockRule>,
+    pub total: u64,
+}
+
+#[async_trait]
+pub trait BlockRuleRepository: Send + Sync {
+    async fn create(
+        &self,
+        command: &CreateBlockRuleCommand,
+    ) -> Result<BlockRule, BlockRuleRepositoryError>;
+
+    /// Newest first
+    async fn list(
+        &self,
+        offset: u64,
+        limit: u32,
+    ) -> Result<BlockRulePage, BlockRuleRepositoryError>;
+
+    /// Every rule, oldest first, for matching requests against
+    async fn all(&self) -> Result<Vec<BlockRule>, BlockRuleRepositoryError>;
+
+    async fn delete(&self, id: Uuid) -> Result<(), BlockRuleRepositoryError>;
+
+    /// Counts a blocked request in one statement, so concurrent hits are
+    /// never lost. A rule deleted meanwhile is ignored.
+    async fn record_hit(&self, id: Uuid) -> Result<(), BlockRuleRepositoryError>;
+}
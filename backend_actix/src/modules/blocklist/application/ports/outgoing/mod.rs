@@ -0,0 +1,3 @@
+pub mod block_rule_repository;
+
+pub use block_rule_repository::{BlockRulePage, BlockRuleRepository, BlockRuleRepositoryError};
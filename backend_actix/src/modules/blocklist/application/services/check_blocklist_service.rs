@@ -0,0 +1,88 @@
+use async_trait::async_trait;
+use tracing::warn;
+
+use crate::blocklist::application::domain::entities::{BlockRule, Requester};
+use crate::blocklist::application::ports::{
+    incoming::use_cases::{CheckBlocklistError, CheckBlocklistUseCase},
+    outgoing::BlockRuleRepository,
+};
+
+pub struct CheckBlocklistService<R>
+where
+    R: BlockRuleRepository,
+{
+    repository: R,
+}
+
+impl<R> CheckBlocklistService<R>
+where
+    R: BlockRuleRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> CheckBlocklistUseCase for CheckBlocklistService<R>
+where
+    R: BlockRuleRepository,
+{
+    async fn execute(
+        &self,
+        requester: &Requester,
+    ) -> Result<Option<BlockRule>, CheckBlocklistError> {
+        let rules = self
+            .repository
+            .all()
+            .await
+            .map_err(|e| CheckBlocklistError::RepositoryError(e.to_string()))?;
+
+        let Some(rule) = rules.into_iter().find(|rule| rule.matches(requester)) else {
+            return Ok(None);
+        };
+        // The request is blocked either way; a lost count is only logged
+        if let Err(e) = self.repository.record_hit(rule.id).await {
+            warn!(rule_id = %rule.id, error = %e, "Failed to count block rule hit");
+        }
+        Ok(Some(rule))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocklist::adapter::outgoing::InMemoryBlockRuleStore;
+    use crate::blocklist::application::domain::entities::BlockRuleKind;
+    use crate::blocklist::application::ports::incoming::use_cases::CreateBlockRuleCommand;
+
+    #[tokio::test]
+    async fn test_matching_requests_are_counted() {
+        let store = InMemoryBlockRuleStore::default();
+        let command =
+            CreateBlockRuleCommand::new(BlockRuleKind::UserAgent, "SpamBot".to_string(), None)
+                .unwrap();
+        let rule = store.create(&command).await.unwrap();
+        let service = CheckBlocklistService::new(store.clone());
+
+        let spammer = Requester {
+            ip: None,
+            user_agent: "spambot/2.1".to_string(),
+        };
+        let visitor = Requester {
+            ip: Some("198.51.100.7".parse().unwrap()),
+            user_agent: "Mozilla/5.0".to_string(),
+        };
+
+        assert_eq!(
+            service.execute(&spammer).await.unwrap().unwrap().id,
+            rule.id
+        );
+        assert!(service.execute(&visitor).await.unwrap().is_none());
+        service.execute(&spammer).await.unwrap();
+
+        let listed = store.list(0, 10).await.unwrap();
+        assert_eq!(listed.items[0].hits, 2);
+        assert!(listed.items[0].last_hit_at.is_some());
+    }
+}
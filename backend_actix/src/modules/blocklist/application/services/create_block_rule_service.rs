@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+
+use crate::blocklist::application::domain::entities::BlockRule;
+use crate::blocklist::application::ports::{
+    incoming::use_cases::{CreateBlockRuleCommand, CreateBlockRuleError, CreateBlockRuleUseCase},
+    outgoing::{BlockRuleRepository, BlockRuleRepositoryError},
+};
+
+pub struct CreateBlockRuleService<R>
+where
+    R: BlockRuleRepository,
+{
+    repository: R,
+}
+
+impl<R> CreateBlockRuleService<R>
+where
+    R: BlockRuleRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> CreateBlockRuleUseCase for CreateBlockRuleService<R>
+where
+    R: BlockRuleRepository,
+{
+    async fn execute(
+        &self,
+        command: CreateBlockRuleCommand,
+    ) -> Result<BlockRule, CreateBlockRuleError> {
+        self.repository.create(&command).await.map_err(|e| match e {
+            BlockRuleRepositoryError::AlreadyExists => CreateBlockRuleError::AlreadyExists,
+            other => CreateBlockRuleError::RepositoryError(other.to_string()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocklist::adapter::outgoing::InMemoryBlockRuleStore;
+    use crate::blocklist::application::domain::entities::BlockRuleKind;
+
+    #[tokio::test]
+    async fn test_same_range_is_rejected_however_written() {
+        let service = CreateBlockRuleService::new(InMemoryBlockRuleStore::default());
+        let command = |pattern: &str| {
+            CreateBlockRuleCommand::new(BlockRuleKind::Ip, pattern.to_string(), None).unwrap()
+        };
+
+        let created = service.execute(command("203.0.113.0/24")).await.unwrap();
+        assert_eq!(created.hits, 0);
+
+        let result = service.execute(command("203.0.113.99/24")).await;
+
+        assert!(matches!(result, Err(CreateBlockRuleError::AlreadyExists)));
+    }
+}
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::blocklist::application::ports::{
+    incoming::use_cases::{DeleteBlockRuleError, DeleteBlockRuleUseCase},
+    outgoing::{BlockRuleRepository, BlockRuleRepositoryError},
+};
+
+pub struct DeleteBlockRuleService<R>
+where
+    R: BlockRuleRepository,
+{
+    repository: R,
+}
+
+impl<R> DeleteBlockRuleService<R>
+where
+    R: BlockRuleRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> DeleteBlockRuleUseCase for DeleteBlockRuleService<R>
+where
+    R: BlockRuleRepository,
+{
+    async fn execute(&self, id: Uuid) -> Result<(), DeleteBlockRuleError> {
+        self.repository.delete(id).await.map_err(|e| match e {
+            BlockRuleRepositoryError::NotFound => DeleteBlockRuleError::NotFound,
+            other => DeleteBlockRuleError::RepositoryError(other.to_string()),
+        })
+    }
+}
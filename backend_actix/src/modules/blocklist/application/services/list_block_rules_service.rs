@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+
+use crate::blocklist::application::ports::{
+    incoming::use_cases::{ListBlockRulesError, ListBlockRulesUseCase},
+    outgoing::{BlockRulePage, BlockRuleRepository},
+};
+
+pub struct ListBlockRulesService<R>
+where
+    R: BlockRuleRepository,
+{
+    repository: R,
+}
+
+impl<R> ListBlockRulesService<R>
+where
+    R: BlockRuleRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> ListBlockRulesUseCase for ListBlockRulesService<R>
+where
+    R: BlockRuleRepository,
+{
+    async fn execute(&self, offset: u64, limit: u32) -> Result<BlockRulePage, ListBlockRulesError> {
+        self.repository
+            .list(offset, limit)
+            .await
+            .map_err(|e| ListBlockRulesError::QueryFailed(e.to_string()))
+    }
+}
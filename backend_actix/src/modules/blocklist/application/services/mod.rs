@@ -0,0 +1,9 @@
+mod check_blocklist_service;
+mod create_block_rule_service;
+mod delete_block_rule_service;
+mod list_block_rules_service;
+
+pub use check_blocklist_service::CheckBlocklistService;
+pub use create_block_rule_service::CreateBlockRuleService;
+pub use delete_block_rule_service::DeleteBlockRuleService;
+pub use list_block_rules_service::ListBlockRulesService;
@@ -0,0 +1,8 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    INVALID_NAME = (BAD_REQUEST, "Name is empty or too long");
+    SUBJECT_TOO_LONG = (BAD_REQUEST, "Subject is too long");
+    INVALID_MESSAGE = (BAD_REQUEST, "Message is empty or too long");
+    CONTACT_MESSAGE_NOT_FOUND = (NOT_FOUND, "Contact message not found");
+}
@@ -0,0 +1,16 @@
+use actix_web::web;
+
+pub mod error_codes;
+pub mod routes;
+
+/// Routes anyone can call
+pub fn configure_public(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::submit_contact_message_handler);
+}
+
+/// Routes for signed-in users with a verified email
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::list_contact_messages_handler)
+        .service(routes::mark_contact_message_read_handler)
+        .service(routes::delete_contact_message_handler);
+}
@@ -0,0 +1,125 @@
+use actix_web::{delete, web, HttpResponse, Responder};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::api::schemas::ErrorResponse;
+use crate::contact::adapter::incoming::web::error_codes::CONTACT_MESSAGE_NOT_FOUND;
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    contact::application::ports::incoming::use_cases::DeleteContactMessageError,
+    shared::api::ApiResponse, AppState,
+};
+
+/// Delete a contact message. Notification emails already queued are still sent.
+#[utoipa::path(
+    delete,
+    path = "/api/admin/contact-messages/{message_id}",
+    tag = "contact",
+    params(
+        ("message_id" = Uuid, Path, description = "Message id"),
+    ),
+    responses(
+        (status = 204, description = "Message deleted"),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 404, description = "Message not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[delete("/api/admin/contact-messages/{message_id}")]
+pub async fn delete_contact_message_handler(
+    _admin: AdminUser,
+    path: web::Path<Uuid>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data.contact.delete.execute(path.into_inner()).await {
+        Ok(()) => ApiResponse::no_content(),
+        Err(err) => map_delete_error(err),
+    }
+}
+
+fn map_delete_error(err: DeleteContactMessageError) -> HttpResponse {
+    match err {
+        DeleteContactMessageError::NotFound => CONTACT_MESSAGE_NOT_FOUND.response(),
+        DeleteContactMessageError::RepositoryError(msg) => {
+            error!("Failed to delete contact message: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service,
+            stubs::StubDeleteContactMessageUseCase,
+        },
+    };
+
+    async fn call(state: web::Data<AppState>, user_id: Uuid) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(user_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(delete_contact_message_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/admin/contact-messages/{}", Uuid::new_v4()))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_delete_returns_no_content() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .build();
+
+        let resp = call(state, admin).await;
+
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_message_is_not_found() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .with_delete_contact_message(StubDeleteContactMessageUseCase::not_found())
+            .build();
+
+        let resp = call(state, admin).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_non_admin_is_forbidden() {
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![Uuid::new_v4()])
+            .build();
+
+        let resp = call(state, Uuid::new_v4()).await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+}
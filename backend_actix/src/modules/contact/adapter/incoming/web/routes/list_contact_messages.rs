@@ -0,0 +1,134 @@
+use actix_web::{get, web, Responder};
+use serde::Deserialize;
+use tracing::error;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    contact::application::{
+        domain::entities::ContactMessage, ports::incoming::use_cases::ListContactMessagesError,
+    },
+    shared::api::{
+        pagination::{PageParams, PagedResponse},
+        ApiResponse,
+    },
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct ListContactMessagesQuery {
+    #[serde(default)]
+    pub unread: bool,
+}
+
+/// Contact form messages, newest first
+#[utoipa::path(
+    get,
+    path = "/api/admin/contact-messages",
+    tag = "contact",
+    params(
+        ("unread" = Option<bool>, Query, description = "Only messages not yet marked read"),
+        ("limit" = Option<u32>, Query, description = "Page size, 1-100 (default 20)"),
+        ("cursor" = Option<String>, Query, description = "`next_cursor` from the previous page"),
+    ),
+    responses(
+        (status = 200, description = "One page of messages", body = inline(SuccessResponse<PagedResponse<ContactMessage>>)),
+        (status = 400, description = "Invalid pagination parameters", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/admin/contact-messages")]
+pub async fn list_contact_messages_handler(
+    admin: AdminUser,
+    query: web::Query<ListContactMessagesQuery>,
+    page: PageParams,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data
+        .contact
+        .list
+        .execute(query.unread, page.offset, page.limit)
+        .await
+    {
+        Ok(result) => ApiResponse::success(PagedResponse::new(result.items, result.total, &page)),
+        Err(ListContactMessagesError::QueryFailed(msg)) => {
+            error!(admin = %admin.user_id, "Failed to list contact messages: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service,
+            stubs::StubListContactMessagesUseCase,
+        },
+    };
+
+    async fn call(
+        state: web::Data<AppState>,
+        user_id: Uuid,
+        uri: &str,
+    ) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(user_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(list_contact_messages_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_admin_sees_messages() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .with_list_contact_messages(StubListContactMessagesUseCase::one_unread())
+            .build();
+
+        let resp = call(state, admin, "/api/admin/contact-messages?unread=true").await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["total"], 1);
+        assert_eq!(json["data"]["items"][0]["email"], "jane@example.com");
+        assert!(json["data"]["items"][0]["read_at"].is_null());
+    }
+
+    #[actix_web::test]
+    async fn test_non_admin_is_forbidden() {
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![Uuid::new_v4()])
+            .build();
+
+        let resp = call(state, Uuid::new_v4(), "/api/admin/contact-messages").await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+}
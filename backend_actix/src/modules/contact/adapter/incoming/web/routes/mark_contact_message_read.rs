@@ -0,0 +1,124 @@
+use actix_web::{post, web, HttpResponse, Responder};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::contact::adapter::incoming::web::error_codes::CONTACT_MESSAGE_NOT_FOUND;
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    contact::application::{
+        domain::entities::ContactMessage, ports::incoming::use_cases::MarkContactMessageReadError,
+    },
+    shared::api::ApiResponse,
+    AppState,
+};
+
+/// Mark a contact message read. Marking it again keeps the first `read_at`.
+#[utoipa::path(
+    post,
+    path = "/api/admin/contact-messages/{message_id}/read",
+    tag = "contact",
+    params(
+        ("message_id" = Uuid, Path, description = "Message id"),
+    ),
+    responses(
+        (status = 200, description = "Message marked read", body = inline(SuccessResponse<ContactMessage>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 404, description = "Message not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/admin/contact-messages/{message_id}/read")]
+pub async fn mark_contact_message_read_handler(
+    _admin: AdminUser,
+    path: web::Path<Uuid>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data.contact.mark_read.execute(path.into_inner()).await {
+        Ok(message) => ApiResponse::success(message),
+        Err(err) => map_mark_read_error(err),
+    }
+}
+
+fn map_mark_read_error(err: MarkContactMessageReadError) -> HttpResponse {
+    match err {
+        MarkContactMessageReadError::NotFound => CONTACT_MESSAGE_NOT_FOUND.response(),
+        MarkContactMessageReadError::RepositoryError(msg) => {
+            error!("Failed to mark contact message read: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service,
+            stubs::StubMarkContactMessageReadUseCase,
+        },
+    };
+
+    async fn call(state: web::Data<AppState>, user_id: Uuid) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(user_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(mark_contact_message_read_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri(&format!(
+                "/api/admin/contact-messages/{}/read",
+                Uuid::new_v4()
+            ))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_returns_read_message() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .build();
+
+        let resp = call(state, admin).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert!(json["data"]["read_at"].is_string());
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_message_is_not_found() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .with_mark_contact_message_read(StubMarkContactMessageReadUseCase::not_found())
+            .build();
+
+        let resp = call(state, admin).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "CONTACT_MESSAGE_NOT_FOUND");
+    }
+}
@@ -0,0 +1,17 @@
+mod delete_contact_message;
+mod list_contact_messages;
+mod mark_contact_message_read;
+mod submit_contact_message;
+
+pub use delete_contact_message::{
+    __path_delete_contact_message_handler, delete_contact_message_handler,
+};
+pub use list_contact_messages::{
+    __path_list_contact_messages_handler, list_contact_messages_handler,
+};
+pub use mark_contact_message_read::{
+    __path_mark_contact_message_read_handler, mark_contact_message_read_handler,
+};
+pub use submit_contact_message::{
+    __path_submit_contact_message_handler, submit_contact_message_handler,
+};
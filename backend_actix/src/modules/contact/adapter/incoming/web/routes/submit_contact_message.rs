@@ -0,0 +1,232 @@
+use actix_web::{post, web, HttpRequest, HttpResponse, Responder};
+use serde::{Deserialize, Serialize};
+use tracing::{error, warn};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::contact::adapter::incoming::web::error_codes::{
+    INVALID_MESSAGE, INVALID_NAME, SUBJECT_TOO_LONG,
+};
+use crate::shared::api::error_codes::INVALID_EMAIL;
+use crate::shared::client_ip::client_ip;
+use crate::{
+    contact::application::ports::incoming::use_cases::{
+        SubmitContactMessageCommand, SubmitContactMessageCommandError, SubmitContactMessageError,
+    },
+    shared::{api::ApiResponse, bot_check::BotCheckedEndpoint},
+    AppState,
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SubmitContactMessageRequest {
+    /// 1-100 characters
+    pub name: String,
+    /// Where the owner can reply
+    pub email: String,
+    /// Up to 200 characters
+    pub subject: Option<String>,
+    /// 1-5000 characters
+    pub message: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SubmittedContactMessage {
+    pub id: Uuid,
+}
+
+/// Send a message to the site owners
+///
+/// The message is kept for the owners and forwarded to them by email.
+/// Limited per client address (`CONTACT_RATE_LIMIT` per hour) and, when
+/// `contact` is in `BOT_CHECK_ENDPOINTS`, gated by the bot check.
+#[utoipa::path(
+    post,
+    path = "/api/public/contact",
+    tag = "contact",
+    request_body = SubmitContactMessageRequest,
+    responses(
+        (status = 201, description = "Message received", body = inline(SuccessResponse<SubmittedContactMessage>)),
+        (status = 400, description = "Invalid field or missing bot check token", body = ErrorResponse),
+        (status = 403, description = "Bot check failed", body = ErrorResponse),
+        (status = 429, description = "Too many messages from this address", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+        (status = 503, description = "Bot check provider unreachable", body = ErrorResponse),
+    )
+)]
+#[post("/api/public/contact")]
+pub async fn submit_contact_message_handler(
+    req: HttpRequest,
+    payload: web::Json<SubmitContactMessageRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    if let Err(response) = data.contact_rate_limiter.check(&req) {
+        return response;
+    }
+    if let Err(e) = data.bot_gate.check(BotCheckedEndpoint::Contact, &req).await {
+        warn!(error = %e, "Contact message blocked by bot check");
+        return e.to_response();
+    }
+
+    let ip_address = client_ip(&req).map(|ip| ip.to_string());
+    let payload = payload.into_inner();
+    let command = match SubmitContactMessageCommand::new(
+        payload.name,
+        payload.email,
+        payload.subject,
+        payload.message,
+        ip_address,
+    ) {
+        Ok(cmd) => cmd,
+        Err(err) => return map_command_error(err),
+    };
+
+    match data.contact.submit.execute(command).await {
+        Ok(message) => ApiResponse::created(SubmittedContactMessage { id: message.id }),
+        Err(SubmitContactMessageError::RepositoryError(msg)) => {
+            error!("Failed to store contact message: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+fn map_command_error(err: SubmitContactMessageCommandError) -> HttpResponse {
+    let code = match err {
+        SubmitContactMessageCommandError::InvalidName => INVALID_NAME,
+        SubmitContactMessageCommandError::InvalidEmail => INVALID_EMAIL,
+        SubmitContactMessageCommandError::SubjectTooLong => SUBJECT_TOO_LONG,
+        SubmitContactMessageCommandError::InvalidMessage => INVALID_MESSAGE,
+    };
+    code.with_message(&err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::json;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::shared::bot_check::{BotGate, NoopBotCheck, BOT_CHECK_HEADER};
+    use crate::shared::rate_limit::RateLimiter;
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, stubs::StubSubmitContactMessageUseCase,
+    };
+
+    fn body() -> serde_json::Value {
+        json!({
+            "name": "Jane",
+            "email": "jane@example.com",
+            "subject": "Hello",
+            "message": "Are you available for hire?"
+        })
+    }
+
+    async fn call(
+        state: web::Data<AppState>,
+        requests: Vec<test::TestRequest>,
+    ) -> Vec<actix_web::dev::ServiceResponse> {
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .service(submit_contact_message_handler),
+        )
+        .await;
+
+        let mut responses = Vec::new();
+        for req in requests {
+            let req = req
+                .uri("/api/public/contact")
+                .peer_addr("203.0.113.7:5000".parse().unwrap())
+                .to_request();
+            responses.push(test::call_service(&app, req).await);
+        }
+        responses
+    }
+
+    #[actix_web::test]
+    async fn test_submit_returns_message_id() {
+        let state = TestAppStateBuilder::default().build();
+
+        let resp = call(state, vec![test::TestRequest::post().set_json(body())])
+            .await
+            .remove(0);
+
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert!(json["data"]["id"].is_string());
+    }
+
+    #[actix_web::test]
+    async fn test_invalid_email_is_rejected() {
+        let state = TestAppStateBuilder::default().build();
+        let mut body = body();
+        body["email"] = json!("not-an-email");
+
+        let resp = call(state, vec![test::TestRequest::post().set_json(body)])
+            .await
+            .remove(0);
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "INVALID_EMAIL");
+    }
+
+    #[actix_web::test]
+    async fn test_rate_limited_per_address() {
+        let state = TestAppStateBuilder::default()
+            .with_contact_rate_limiter(RateLimiter::new(1, Duration::from_secs(3600)))
+            .build();
+
+        let responses = call(
+            state,
+            vec![
+                test::TestRequest::post().set_json(body()),
+                test::TestRequest::post().set_json(body()),
+            ],
+        )
+        .await;
+
+        assert_eq!(responses[0].status(), StatusCode::CREATED);
+        assert_eq!(responses[1].status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[actix_web::test]
+    async fn test_requires_bot_check_token_when_enabled() {
+        let state = TestAppStateBuilder::default()
+            .with_bot_gate(BotGate::new(
+                Arc::new(NoopBotCheck),
+                vec![BotCheckedEndpoint::Contact],
+                0,
+            ))
+            .build();
+
+        let responses = call(
+            state,
+            vec![
+                test::TestRequest::post().set_json(body()),
+                test::TestRequest::post()
+                    .insert_header((BOT_CHECK_HEADER, "solved"))
+                    .set_json(body()),
+            ],
+        )
+        .await;
+
+        assert_eq!(responses[0].status(), StatusCode::BAD_REQUEST);
+        assert_eq!(responses[1].status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn test_store_failure_returns_internal_error() {
+        let state = TestAppStateBuilder::default()
+            .with_submit_contact_message(StubSubmitContactMessageUseCase::failure("db down"))
+            .build();
+
+        let resp = call(state, vec![test::TestRequest::post().set_json(body())])
+            .await
+            .remove(0);
+
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
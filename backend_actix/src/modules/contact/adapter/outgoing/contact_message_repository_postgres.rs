@@ -0,0 +1,449 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement,
+    TransactionTrait, Value,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::contact::application::domain::entities::ContactMessage;
+use crate::contact::application::ports::incoming::use_cases::SubmitContactMessageCommand;
+use crate::contact::application::ports::outgoing::{
+    ContactMessagePage, ContactMessageRepository, ContactMessageRepositoryError,
+};
+use crate::email::adapter::outgoing::email_outbox_postgres::EmailOutboxPostgres;
+use crate::email::application::ports::outgoing::email_outbox::{ContactNotification, OutboxEmail};
+use crate::shared::field_cipher::FieldCipher;
+use crate::shared::sql;
+
+const MESSAGE_COLUMNS: &str = "id, name, email, subject, message, ip_address, read_at, created_at";
+
+#[derive(Clone)]
+pub struct ContactMessageRepositoryPostgres {
+    db: Arc<DatabaseConnection>,
+    cipher: FieldCipher,
+}
+
+impl ContactMessageRepositoryPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            db,
+            cipher: FieldCipher::default(),
+        }
+    }
+
+    /// Encrypts message bodies on write and decrypts them on read
+    pub fn with_cipher(mut self, cipher: FieldCipher) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    /// `message` is the body as stored
+    fn insert_stmt(
+        backend: DatabaseBackend,
+        command: &SubmitContactMessageCommand,
+        message: String,
+    ) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                INSERT INTO contact_messages (id, name, email, subject, message, ip_address)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                RETURNING {MESSAGE_COLUMNS}
+                "#
+            ),
+            vec![
+                Uuid::new_v4().into(),
+                command.name().into(),
+                command.email().into(),
+                command.subject().map(str::to_string).into(),
+                message.into(),
+                command.ip_address().map(str::to_string).into(),
+            ],
+        )
+    }
+
+    /// Active accounts among `ids`; `ids` must not be empty
+    fn owners_stmt(backend: DatabaseBackend, ids: &[Uuid]) -> Statement {
+        let placeholders = (1..=ids.len())
+            .map(|n| format!("${n}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                SELECT id, email, username, preferred_locale
+                FROM users
+                WHERE id IN ({placeholders}) AND is_deleted = false
+                "#
+            ),
+            ids.iter().copied().map(Value::from),
+        )
+    }
+
+    fn unread_filter(unread_only: bool) -> &'static str {
+        if unread_only {
+            "WHERE read_at IS NULL"
+        } else {
+            ""
+        }
+    }
+
+    fn list_stmt(
+        backend: DatabaseBackend,
+        unread_only: bool,
+        offset: u64,
+        limit: u32,
+    ) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                SELECT {MESSAGE_COLUMNS}
+                FROM contact_messages
+                {filter}
+                ORDER BY created_at DESC, id
+                LIMIT $2
+                OFFSET $1
+                "#,
+                filter = Self::unread_filter(unread_only),
+            ),
+            vec![(offset as i64).into(), (limit as i64).into()],
+        )
+    }
+
+    fn count_stmt(backend: DatabaseBackend, unread_only: bool) -> Statement {
+        Statement::from_string(
+            backend,
+            format!(
+                "SELECT COUNT(*) AS total FROM contact_messages {}",
+                Self::unread_filter(unread_only)
+            ),
+        )
+    }
+
+    fn mark_read_stmt(backend: DatabaseBackend, id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                UPDATE contact_messages
+                SET read_at = COALESCE(read_at, {now})
+                WHERE id = $1
+                RETURNING {MESSAGE_COLUMNS}
+                "#,
+                now = sql::now(backend),
+            ),
+            vec![id.into()],
+        )
+    }
+
+    fn delete_stmt(backend: DatabaseBackend, id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            "DELETE FROM contact_messages WHERE id = $1",
+            vec![id.into()],
+        )
+    }
+
+    // =====================================================
+    // Mapping
+    // =====================================================
+
+    fn to_message(
+        &self,
+        row: &QueryResult,
+    ) -> Result<ContactMessage, ContactMessageRepositoryError> {
+        let message: String = row.try_get("", "message").map_err(Self::map_db_err)?;
+
+        Ok(ContactMessage {
+            id: row.try_get("", "id").map_err(Self::map_db_err)?,
+            name: row.try_get("", "name").map_err(Self::map_db_err)?,
+            email: row.try_get("", "email").map_err(Self::map_db_err)?,
+            subject: row.try_get("", "subject").map_err(Self::map_db_err)?,
+            message: self
+                .cipher
+                .decrypt(FieldCipher::SITE, &message)
+                .map_err(|e| ContactMessageRepositoryError::DatabaseError(e.to_string()))?,
+            ip_address: row.try_get("", "ip_address").map_err(Self::map_db_err)?,
+            read_at: row.try_get("", "read_at").map_err(Self::map_db_err)?,
+            created_at: row.try_get("", "created_at").map_err(Self::map_db_err)?,
+        })
+    }
+
+    fn to_notification(
+        row: &QueryResult,
+        message: &ContactMessage,
+    ) -> Result<ContactNotification, ContactMessageRepositoryError> {
+        Ok(ContactNotification {
+            owner_id: row.try_get("", "id").map_err(Self::map_db_err)?,
+            owner_email: row.try_get("", "email").map_err(Self::map_db_err)?,
+            owner_username: row.try_get("", "username").map_err(Self::map_db_err)?,
+            preferred_locale: row
+                .try_get("", "preferred_locale")
+                .map_err(Self::map_db_err)?,
+            message_id: message.id,
+            sender_name: message.name.clone(),
+            sender_email: message.email.clone(),
+            subject: message.subject.clone(),
+            message: message.message.clone(),
+        })
+    }
+
+    // =====================================================
+    // Helpers
+    // =====================================================
+
+    async fn count(&self, stmt: Statement) -> Result<u64, ContactMessageRepositoryError> {
+        let total: i64 = self
+            .db
+            .query_one(stmt)
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or_else(|| {
+                ContactMessageRepositoryError::DatabaseError("count returned no row".into())
+            })?
+            .try_get("", "total")
+            .map_err(Self::map_db_err)?;
+
+        Ok(total.max(0) as u64)
+    }
+
+    fn map_db_err(e: DbErr) -> ContactMessageRepositoryError {
+        ContactMessageRepositoryError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl ContactMessageRepository for ContactMessageRepositoryPostgres {
+    async fn create(
+        &self,
+        command: &SubmitContactMessageCommand,
+        notify: &[Uuid],
+    ) -> Result<ContactMessage, ContactMessageRepositoryError> {
+        let backend = self.db.get_database_backend();
+        let txn = self.db.begin().await.map_err(Self::map_db_err)?;
+
+        let stored = self.cipher.encrypt(FieldCipher::SITE, command.message());
+        let row = txn
+            .query_one(Self::insert_stmt(backend, command, stored))
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or_else(|| {
+                ContactMessageRepositoryError::DatabaseError("insert returned no row".into())
+            })?;
+        let message = self.to_message(&row)?;
+
+        // Queued in the same transaction: no message without its notification
+        if !notify.is_empty() {
+            let owners = txn
+                .query_all(Self::owners_stmt(backend, notify))
+                .await
+                .map_err(Self::map_db_err)?;
+            for owner in &owners {
+                let notification = Self::to_notification(owner, &message)?;
+                EmailOutboxPostgres::enqueue(&txn, &OutboxEmail::ContactMessage(notification))
+                    .await
+                    .map_err(Self::map_db_err)?;
+            }
+        }
+
+        txn.commit().await.map_err(Self::map_db_err)?;
+
+        Ok(message)
+    }
+
+    async fn list(
+        &self,
+        unread_only: bool,
+        offset: u64,
+        limit: u32,
+    ) -> Result<ContactMessagePage, ContactMessageRepositoryError> {
+        let backend = self.db.get_database_backend();
+        let items = self
+            .db
+            .query_all(Self::list_stmt(backend, unread_only, offset, limit))
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(|row| self.to_message(row))
+            .collect::<Result<Vec<_>, _>>()?;
+        let total = self.count(Self::count_stmt(backend, unread_only)).await?;
+
+        Ok(ContactMessagePage { items, total })
+    }
+
+    async fn mark_read(&self, id: Uuid) -> Result<ContactMessage, ContactMessageRepositoryError> {
+        let row = self
+            .db
+            .query_one(Self::mark_read_stmt(self.db.get_database_backend(), id))
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or(ContactMessageRepositoryError::NotFound)?;
+
+        self.to_message(&row)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), ContactMessageRepositoryError> {
+        let res = self
+            .db
+            .execute(Self::delete_stmt(self.db.get_database_backend(), id))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        if res.rows_affected() == 0 {
+            return Err(ContactMessageRepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::field_cipher::FieldKey;
+    use chrono::Utc;
+    use sea_orm::{MockDatabase, MockExecResult};
+    use std::collections::BTreeMap;
+
+    fn message_row(id: Uuid) -> BTreeMap<String, Value> {
+        BTreeMap::from([
+            ("id".to_string(), Value::from(id)),
+            ("name".to_string(), Value::from("Jane")),
+            ("email".to_string(), Value::from("jane@example.com")),
+            ("subject".to_string(), Value::String(None)),
+            ("message".to_string(), Value::from("Hello")),
+            ("ip_address".to_string(), Value::from("203.0.113.7")),
+            ("read_at".to_string(), Value::ChronoDateTimeUtc(None)),
+            ("created_at".to_string(), Value::from(Utc::now())),
+        ])
+    }
+
+    fn command() -> SubmitContactMessageCommand {
+        SubmitContactMessageCommand::new(
+            "Jane".to_string(),
+            "jane@example.com".to_string(),
+            None,
+            "Hello".to_string(),
+            Some("203.0.113.7".to_string()),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_queues_a_notification_per_owner() {
+        let id = Uuid::new_v4();
+        let owner_row = BTreeMap::from([
+            ("id".to_string(), Value::from(Uuid::new_v4())),
+            ("email".to_string(), Value::from("owner@example.com")),
+            ("username".to_string(), Value::from("owner")),
+            ("preferred_locale".to_string(), Value::from("en")),
+        ]);
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![message_row(id)]])
+            .append_query_results(vec![vec![owner_row]])
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        // The mock fails the call if the outbox insert does not run
+        let repo = ContactMessageRepositoryPostgres::new(Arc::new(db));
+        let message = repo.create(&command(), &[Uuid::new_v4()]).await.unwrap();
+
+        assert_eq!(message.id, id);
+        assert_eq!(message.ip_address.as_deref(), Some("203.0.113.7"));
+    }
+
+    #[tokio::test]
+    async fn test_message_bodies_are_encrypted_at_rest() {
+        let cipher = FieldCipher::new(vec![FieldKey::new("k1", [7; 32]).unwrap()]);
+        let id = Uuid::new_v4();
+        let mut row = message_row(id);
+        row.insert(
+            "message".to_string(),
+            Value::from(cipher.encrypt(FieldCipher::SITE, "Hello")),
+        );
+        let owner_row = BTreeMap::from([
+            ("id".to_string(), Value::from(Uuid::new_v4())),
+            ("email".to_string(), Value::from("owner@example.com")),
+            ("username".to_string(), Value::from("owner")),
+            ("preferred_locale".to_string(), Value::from("en")),
+        ]);
+        let db = Arc::new(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_query_results(vec![vec![row]])
+                .append_query_results(vec![vec![owner_row]])
+                .append_exec_results([MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                }])
+                .into_connection(),
+        );
+
+        let repo = ContactMessageRepositoryPostgres::new(Arc::clone(&db)).with_cipher(cipher);
+        let message = repo.create(&command(), &[Uuid::new_v4()]).await.unwrap();
+        assert_eq!(message.message, "Hello");
+        drop(repo);
+
+        let log = Arc::try_unwrap(db)
+            .expect("no other connection handles")
+            .into_transaction_log();
+        let log = format!("{log:?}");
+        assert!(log.contains("enc:v1:k1:"));
+        assert!(!log.contains("\"Hello\""));
+    }
+
+    #[tokio::test]
+    async fn test_mark_read_missing_message_is_not_found() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![Vec::<BTreeMap<String, Value>>::new()])
+            .into_connection();
+
+        let repo = ContactMessageRepositoryPostgres::new(Arc::new(db));
+        let result = repo.mark_read(Uuid::new_v4()).await;
+
+        assert!(matches!(
+            result,
+            Err(ContactMessageRepositoryError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_delete_missing_message_is_not_found() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }])
+            .into_connection();
+
+        let repo = ContactMessageRepositoryPostgres::new(Arc::new(db));
+        let result = repo.delete(Uuid::new_v4()).await;
+
+        assert!(matches!(
+            result,
+            Err(ContactMessageRepositoryError::NotFound)
+        ));
+    }
+
+    #[test]
+    fn test_list_filters_unread_only_when_asked() {
+        let unread =
+            ContactMessageRepositoryPostgres::list_stmt(DatabaseBackend::Postgres, true, 0, 20);
+        let all =
+            ContactMessageRepositoryPostgres::list_stmt(DatabaseBackend::Postgres, false, 0, 20);
+
+        assert!(unread.sql.contains("WHERE read_at IS NULL"));
+        assert!(!all.sql.contains("read_at IS NULL"));
+    }
+}
@@ -0,0 +1,209 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::auth::adapter::outgoing::in_memory::InMemoryUserStore;
+use crate::contact::application::domain::entities::ContactMessage;
+use crate::contact::application::ports::incoming::use_cases::SubmitContactMessageCommand;
+use crate::contact::application::ports::outgoing::{
+    ContactMessagePage, ContactMessageRepository, ContactMessageRepositoryError,
+};
+use crate::email::adapter::outgoing::in_memory::InMemoryEmailOutbox;
+use crate::email::application::ports::outgoing::email_outbox::{ContactNotification, OutboxEmail};
+use crate::shared::in_memory::Table;
+
+/// Process-local `ContactMessageRepository`. With users and an outbox to
+/// notify through, owners get an email per message like the Postgres
+/// repository queues; without them no email is queued.
+#[derive(Clone, Default)]
+pub struct InMemoryContactStore {
+    messages: Table<ContactMessage>,
+    notifications: Option<(InMemoryUserStore, InMemoryEmailOutbox)>,
+}
+
+impl InMemoryContactStore {
+    pub fn with_notifications(users: InMemoryUserStore, outbox: InMemoryEmailOutbox) -> Self {
+        Self {
+            messages: Table::default(),
+            notifications: Some((users, outbox)),
+        }
+    }
+
+    fn notify(&self, message: &ContactMessage, owners: &[Uuid]) {
+        let Some((users, outbox)) = &self.notifications else {
+            return;
+        };
+
+        let notifications: Vec<ContactNotification> = users.users.read(|users| {
+            users
+                .iter()
+                .filter(|user| owners.contains(&user.id) && !user.is_deleted)
+                .map(|owner| ContactNotification {
+                    owner_id: owner.id,
+                    owner_email: owner.email.clone(),
+                    owner_username: owner.username.clone(),
+                    preferred_locale: owner.preferred_locale.clone(),
+                    message_id: message.id,
+                    sender_name: message.name.clone(),
+                    sender_email: message.email.clone(),
+                    subject: message.subject.clone(),
+                    message: message.message.clone(),
+                })
+                .collect()
+        });
+        for notification in notifications {
+            outbox.enqueue(OutboxEmail::ContactMessage(notification));
+        }
+    }
+}
+
+#[async_trait]
+impl ContactMessageRepository for InMemoryContactStore {
+    async fn create(
+        &self,
+        message: &SubmitContactMessageCommand,
+        notify: &[Uuid],
+    ) -> Result<ContactMessage, ContactMessageRepositoryError> {
+        let message = ContactMessage {
+            id: Uuid::new_v4(),
+            name: message.name().to_string(),
+            email: message.email().to_string(),
+            subject: message.subject().map(str::to_string),
+            message: message.message().to_string(),
+            ip_address: message.ip_address().map(str::to_string),
+            read_at: None,
+            created_at: Utc::now(),
+        };
+        self.messages
+            .write(|messages| messages.push(message.clone()));
+        self.notify(&message, notify);
+        Ok(message)
+    }
+
+    async fn list(
+        &self,
+        unread_only: bool,
+        offset: u64,
+        limit: u32,
+    ) -> Result<ContactMessagePage, ContactMessageRepositoryError> {
+        Ok(self.messages.read(|messages| {
+            let matching: Vec<&ContactMessage> = messages
+                .iter()
+                .rev()
+                .filter(|message| !unread_only || message.read_at.is_none())
+                .collect();
+
+            ContactMessagePage {
+                total: matching.len() as u64,
+                items: matching
+                    .into_iter()
+                    .skip(offset as usize)
+                    .take(limit as usize)
+                    .cloned()
+                    .collect(),
+            }
+        }))
+    }
+
+    async fn mark_read(&self, id: Uuid) -> Result<ContactMessage, ContactMessageRepositoryError> {
+        self.messages.write(|messages| {
+            let message = messages
+                .iter_mut()
+                .find(|message| message.id == id)
+                .ok_or(ContactMessageRepositoryError::NotFound)?;
+            message.read_at.get_or_insert_with(Utc::now);
+            Ok(message.clone())
+        })
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), ContactMessageRepositoryError> {
+        self.messages.write(|messages| {
+            let before = messages.len();
+            messages.retain(|message| message.id != id);
+            if messages.len() == before {
+                return Err(ContactMessageRepositoryError::NotFound);
+            }
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::application::ports::outgoing::user_query::UserQueryResult;
+    use crate::email::application::ports::outgoing::email_outbox::EmailOutbox;
+
+    fn command() -> SubmitContactMessageCommand {
+        SubmitContactMessageCommand::new(
+            "Jane".to_string(),
+            "jane@example.com".to_string(),
+            Some("Hello".to_string()),
+            "Are you available for hire?".to_string(),
+            None,
+        )
+        .unwrap()
+    }
+
+    fn owner(users: &InMemoryUserStore) -> Uuid {
+        let id = Uuid::new_v4();
+        users.users.write(|users| {
+            users.push(UserQueryResult {
+                id,
+                email: "owner@example.com".to_string(),
+                username: "owner".to_string(),
+                password_hash: String::new(),
+                full_name: "Site Owner".to_string(),
+                preferred_locale: "id".to_string(),
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                is_verified: true,
+                is_deleted: false,
+                token_version: 0,
+                is_active: true,
+            })
+        });
+        id
+    }
+
+    #[tokio::test]
+    async fn test_create_queues_notification_for_known_owners() {
+        let users = InMemoryUserStore::default();
+        let outbox = InMemoryEmailOutbox::default();
+        let owner_id = owner(&users);
+        let store = InMemoryContactStore::with_notifications(users, outbox.clone());
+
+        let message = store
+            .create(&command(), &[owner_id, Uuid::new_v4()])
+            .await
+            .unwrap();
+
+        let claimed = outbox.claim_due(10, Utc::now()).await.unwrap();
+        assert_eq!(claimed.len(), 1);
+        let OutboxEmail::ContactMessage(notification) = &claimed[0].email else {
+            panic!("expected a contact notification");
+        };
+        assert_eq!(notification.owner_email, "owner@example.com");
+        assert_eq!(notification.preferred_locale, "id");
+        assert_eq!(notification.message_id, message.id);
+    }
+
+    #[tokio::test]
+    async fn test_list_filters_unread_and_mark_read_keeps_first_time() {
+        let store = InMemoryContactStore::default();
+        let first = store.create(&command(), &[]).await.unwrap();
+        let second = store.create(&command(), &[]).await.unwrap();
+
+        let read = store.mark_read(first.id).await.unwrap();
+        let again = store.mark_read(first.id).await.unwrap();
+        assert_eq!(read.read_at, again.read_at);
+
+        let all = store.list(false, 0, 10).await.unwrap();
+        assert_eq!(all.total, 2);
+        assert_eq!(all.items[0].id, second.id);
+
+        let unread = store.list(true, 0, 10).await.unwrap();
+        assert_eq!(unread.total, 1);
+        assert_eq!(unread.items[0].id, second.id);
+    }
+}
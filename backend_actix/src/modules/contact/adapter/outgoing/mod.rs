@@ -0,0 +1,5 @@
+mod contact_message_repository_postgres;
+mod in_memory;
+
+pub use contact_message_repository_postgres::ContactMessageRepositoryPostgres;
+pub use in_memory::InMemoryContactStore;
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::contact::application::ports::incoming::use_cases::{
+    DeleteContactMessageUseCase, ListContactMessagesUseCase, MarkContactMessageReadUseCase,
+    SubmitContactMessageUseCase,
+};
+use crate::contact::application::ports::outgoing::ContactMessageRepository;
+use crate::contact::application::services::{
+    DeleteContactMessageService, ListContactMessagesService, MarkContactMessageReadService,
+    SubmitContactMessageService,
+};
+use crate::shared::metrics::Metered;
+
+#[derive(Clone)]
+pub struct ContactUseCases {
+    pub submit: Arc<dyn SubmitContactMessageUseCase + Send + Sync>,
+    pub list: Arc<dyn ListContactMessagesUseCase + Send + Sync>,
+    pub mark_read: Arc<dyn MarkContactMessageReadUseCase + Send + Sync>,
+    pub delete: Arc<dyn DeleteContactMessageUseCase + Send + Sync>,
+}
+
+impl ContactUseCases {
+    /// `owners` are notified of every submitted message
+    pub fn build<R>(repository: R, owners: Vec<Uuid>) -> Self
+    where
+        R: ContactMessageRepository + Clone + 'static,
+    {
+        Self {
+            submit: Arc::new(Metered(SubmitContactMessageService::new(
+                repository.clone(),
+                owners,
+            ))),
+            list: Arc::new(Metered(ListContactMessagesService::new(repository.clone()))),
+            mark_read: Arc::new(Metered(MarkContactMessageReadService::new(
+                repository.clone(),
+            ))),
+            delete: Arc::new(Metered(DeleteContactMessageService::new(repository))),
+        }
+    }
+}
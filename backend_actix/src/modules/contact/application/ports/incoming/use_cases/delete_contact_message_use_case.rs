@@ -0,0 +1,24 @@
+use crate::shared::metrics::metered_use_case;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DeleteContactMessageError {
+    #[error("Contact message not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait DeleteContactMessageUseCase: Send + Sync {
+    async fn execute(&self, id: Uuid) -> Result<(), DeleteContactMessageError>;
+}
+
+metered_use_case!(
+    "contact",
+    "delete_contact_message",
+    DeleteContactMessageUseCase,
+    fn execute(&self, id: Uuid) -> Result<(), DeleteContactMessageError>
+);
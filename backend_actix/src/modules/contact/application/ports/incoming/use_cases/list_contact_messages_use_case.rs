@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+
+use crate::contact::application::ports::outgoing::ContactMessagePage;
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ListContactMessagesError {
+    #[error("Failed to list contact messages: {0}")]
+    QueryFailed(String),
+}
+
+#[async_trait]
+pub trait ListContactMessagesUseCase: Send + Sync {
+    /// Newest first
+    async fn execute(
+        &self,
+        unread_only: bool,
+        offset: u64,
+        limit: u32,
+    ) -> Result<ContactMessagePage, ListContactMessagesError>;
+}
+
+metered_use_case!(
+    "contact",
+    "list_contact_messages",
+    ListContactMessagesUseCase,
+    fn execute(
+        &self,
+        unread_only: bool,
+        offset: u64,
+        limit: u32,
+    ) -> Result<ContactMessagePage, ListContactMessagesError>
+);
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::contact::application::domain::entities::ContactMessage;
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum MarkContactMessageReadError {
+    #[error("Contact message not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait MarkContactMessageReadUseCase: Send + Sync {
+    async fn execute(&self, id: Uuid) -> Result<ContactMessage, MarkContactMessageReadError>;
+}
+
+metered_use_case!(
+    "contact",
+    "mark_contact_message_read",
+    MarkContactMessageReadUseCase,
+    fn execute(&self, id: Uuid) -> Result<ContactMessage, MarkContactMessageReadError>
+);
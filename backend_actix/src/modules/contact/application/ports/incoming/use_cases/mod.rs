@@ -0,0 +1,14 @@
+mod delete_contact_message_use_case;
+mod list_contact_messages_use_case;
+mod mark_contact_message_read_use_case;
+mod submit_contact_message_use_case;
+
+pub use delete_contact_message_use_case::{DeleteContactMessageError, DeleteContactMessageUseCase};
+pub use list_contact_messages_use_case::{ListContactMessagesError, ListContactMessagesUseCase};
+pub use mark_contact_message_read_use_case::{
+    MarkContactMessageReadError, MarkContactMessageReadUseCase,
+};
+pub use submit_contact_message_use_case::{
+    SubmitContactMessageCommand, SubmitContactMessageCommandError, SubmitContactMessageError,
+    SubmitContactMessageUseCase,
+};
@@ -0,0 +1,196 @@
+use async_trait::async_trait;
+use email_address::EmailAddress;
+
+use crate::contact::application::domain::entities::ContactMessage;
+use crate::shared::metrics::metered_use_case;
+
+pub const MAX_NAME_LENGTH: usize = 100;
+pub const MAX_EMAIL_LENGTH: usize = 254;
+pub const MAX_SUBJECT_LENGTH: usize = 200;
+pub const MAX_MESSAGE_LENGTH: usize = 5000;
+
+//
+// ──────────────────────────────────────────────────────────
+// Submit Contact Message Command
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone)]
+pub struct SubmitContactMessageCommand {
+    name: String,
+    email: String,
+    subject: Option<String>,
+    message: String,
+    ip_address: Option<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SubmitContactMessageCommandError {
+    #[error("Name is required and must be at most {MAX_NAME_LENGTH} characters")]
+    InvalidName,
+
+    #[error("A valid email address is required")]
+    InvalidEmail,
+
+    #[error("Subject must be at most {MAX_SUBJECT_LENGTH} characters")]
+    SubjectTooLong,
+
+    #[error("Message is required and must be at most {MAX_MESSAGE_LENGTH} characters")]
+    InvalidMessage,
+}
+
+impl SubmitContactMessageCommand {
+    pub fn new(
+        name: String,
+        email: String,
+        subject: Option<String>,
+        message: String,
+        ip_address: Option<String>,
+    ) -> Result<Self, SubmitContactMessageCommandError> {
+        let name = name.trim();
+        if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+            return Err(SubmitContactMessageCommandError::InvalidName);
+        }
+
+        let email = email.trim();
+        if email.len() > MAX_EMAIL_LENGTH || !EmailAddress::is_valid(email) {
+            return Err(SubmitContactMessageCommandError::InvalidEmail);
+        }
+
+        let subject = subject
+            .map(|subject| subject.trim().to_string())
+            .filter(|subject| !subject.is_empty());
+        if subject
+            .as_ref()
+            .is_some_and(|subject| subject.chars().count() > MAX_SUBJECT_LENGTH)
+        {
+            return Err(SubmitContactMessageCommandError::SubjectTooLong);
+        }
+
+        let message = message.trim();
+        if message.is_empty() || message.chars().count() > MAX_MESSAGE_LENGTH {
+            return Err(SubmitContactMessageCommandError::InvalidMessage);
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            email: email.to_string(),
+            subject,
+            message: message.to_string(),
+            ip_address,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+
+    pub fn subject(&self) -> Option<&str> {
+        self.subject.as_deref()
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn ip_address(&self) -> Option<&str> {
+        self.ip_address.as_deref()
+    }
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Use Case Error
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SubmitContactMessageError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Incoming Port (Use Case)
+// ──────────────────────────────────────────────────────────
+//
+
+#[async_trait]
+pub trait SubmitContactMessageUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        command: SubmitContactMessageCommand,
+    ) -> Result<ContactMessage, SubmitContactMessageError>;
+}
+
+metered_use_case!(
+    "contact",
+    "submit_contact_message",
+    SubmitContactMessageUseCase,
+    fn execute(
+        &self,
+        command: SubmitContactMessageCommand,
+    ) -> Result<ContactMessage, SubmitContactMessageError>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(
+        name: &str,
+        email: &str,
+        subject: Option<&str>,
+        message: &str,
+    ) -> Result<SubmitContactMessageCommand, SubmitContactMessageCommandError> {
+        SubmitContactMessageCommand::new(
+            name.to_string(),
+            email.to_string(),
+            subject.map(str::to_string),
+            message.to_string(),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_command_trims_fields_and_drops_blank_subject() {
+        let command = command(" Jane ", " jane@example.com ", Some("  "), " Hello ").unwrap();
+
+        assert_eq!(command.name(), "Jane");
+        assert_eq!(command.email(), "jane@example.com");
+        assert_eq!(command.subject(), None);
+        assert_eq!(command.message(), "Hello");
+    }
+
+    #[test]
+    fn test_command_rejects_invalid_fields() {
+        let long_subject = "s".repeat(MAX_SUBJECT_LENGTH + 1);
+        let long_message = "m".repeat(MAX_MESSAGE_LENGTH + 1);
+
+        assert!(matches!(
+            command("  ", "jane@example.com", None, "Hi"),
+            Err(SubmitContactMessageCommandError::InvalidName)
+        ));
+        assert!(matches!(
+            command("Jane", "not-an-email", None, "Hi"),
+            Err(SubmitContactMessageCommandError::InvalidEmail)
+        ));
+        assert!(matches!(
+            command("Jane", "jane@example.com", Some(&long_subject), "Hi"),
+            Err(SubmitContactMessageCommandError::SubjectTooLong)
+        ));
+        assert!(matches!(
+            command("Jane", "jane@example.com", None, " "),
+            Err(SubmitContactMessageCommandError::InvalidMessage)
+        ));
+        assert!(matches!(
+            command("Jane", "jane@example.com", None, &long_message),
+            Err(SubmitContactMessageCommandError::InvalidMessage)
+        ));
+    }
+}
@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::contact::application::domain::entities::ContactMessage;
+use crate::contact::application::ports::incoming::use_cases::SubmitContactMessageCommand;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContactMessagePage {
+    pub items: Vec<ContactMessage>,
+    pub total: u64,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ContactMessageRepositoryError {
+    #[error("Contact message not found")]
+    NotFound,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait ContactMessageRepository: Send + Sync {
+    /// Store the message and queue a notification email to each of `notify`
+    /// (user ids) in the same transaction. Unknown ids are skipped.
+    async fn create(
+        &self,
+        message: &SubmitContactMessageCommand,
+        notify: &[Uuid],
+    ) -> Result<ContactMessage, ContactMessageRepositoryError>;
+
+    /// Newest first
+    async fn list(
+        &self,
+        unread_only: bool,
+        offset: u64,
+        limit: u32,
+    ) -> Result<ContactMessagePage, ContactMessageRepositoryError>;
+
+    /// Keeps the first `read_at` if already read
+    async fn mark_read(&self, id: Uuid) -> Result<ContactMessage, ContactMessageRepositoryError>;
+
+    async fn delete(&self, id: Uuid) -> Result<(), ContactMessageRepositoryError>;
+}
@@ -0,0 +1,5 @@
+pub mod contact_message_repository;
+
+pub use contact_message_repository::{
+    ContactMessagePage, ContactMessageRepository, ContactMessageRepositoryError,
+};
@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::contact::application::ports::{
+    incoming::use_cases::{DeleteContactMessageError, DeleteContactMessageUseCase},
+    outgoing::{ContactMessageRepository, ContactMessageRepositoryError},
+};
+
+pub struct DeleteContactMessageService<R>
+where
+    R: ContactMessageRepository,
+{
+    repository: R,
+}
+
+impl<R> DeleteContactMessageService<R>
+where
+    R: ContactMessageRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> DeleteContactMessageUseCase for DeleteContactMessageService<R>
+where
+    R: ContactMessageRepository,
+{
+    async fn execute(&self, id: Uuid) -> Result<(), DeleteContactMessageError> {
+        self.repository.delete(id).await.map_err(|e| match e {
+            ContactMessageRepositoryError::NotFound => DeleteContactMessageError::NotFound,
+            ContactMessageRepositoryError::DatabaseError(msg) => {
+                DeleteContactMessageError::RepositoryError(msg)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contact::adapter::outgoing::InMemoryContactStore;
+
+    #[tokio::test]
+    async fn test_unknown_message_is_not_found() {
+        let service = DeleteContactMessageService::new(InMemoryContactStore::default());
+
+        let result = service.execute(Uuid::new_v4()).await;
+
+        assert!(matches!(result, Err(DeleteContactMessageError::NotFound)));
+    }
+}
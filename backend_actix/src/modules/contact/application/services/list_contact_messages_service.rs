@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+
+use crate::contact::application::ports::{
+    incoming::use_cases::{ListContactMessagesError, ListContactMessagesUseCase},
+    outgoing::{ContactMessagePage, ContactMessageRepository},
+};
+
+pub struct ListContactMessagesService<R>
+where
+    R: ContactMessageRepository,
+{
+    repository: R,
+}
+
+impl<R> ListContactMessagesService<R>
+where
+    R: ContactMessageRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> ListContactMessagesUseCase for ListContactMessagesService<R>
+where
+    R: ContactMessageRepository,
+{
+    async fn execute(
+        &self,
+        unread_only: bool,
+        offset: u64,
+        limit: u32,
+    ) -> Result<ContactMessagePage, ListContactMessagesError> {
+        self.repository
+            .list(unread_only, offset, limit)
+            .await
+            .map_err(|e| ListContactMessagesError::QueryFailed(e.to_string()))
+    }
+}
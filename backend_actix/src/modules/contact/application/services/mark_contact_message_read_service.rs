@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::contact::application::domain::entities::ContactMessage;
+use crate::contact::application::ports::{
+    incoming::use_cases::{MarkContactMessageReadError, MarkContactMessageReadUseCase},
+    outgoing::{ContactMessageRepository, ContactMessageRepositoryError},
+};
+
+pub struct MarkContactMessageReadService<R>
+where
+    R: ContactMessageRepository,
+{
+    repository: R,
+}
+
+impl<R> MarkContactMessageReadService<R>
+where
+    R: ContactMessageRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> MarkContactMessageReadUseCase for MarkContactMessageReadService<R>
+where
+    R: ContactMessageRepository,
+{
+    async fn execute(&self, id: Uuid) -> Result<ContactMessage, MarkContactMessageReadError> {
+        self.repository.mark_read(id).await.map_err(|e| match e {
+            ContactMessageRepositoryError::NotFound => MarkContactMessageReadError::NotFound,
+            ContactMessageRepositoryError::DatabaseError(msg) => {
+                MarkContactMessageReadError::RepositoryError(msg)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contact::adapter::outgoing::InMemoryContactStore;
+
+    #[tokio::test]
+    async fn test_unknown_message_is_not_found() {
+        let service = MarkContactMessageReadService::new(InMemoryContactStore::default());
+
+        let result = service.execute(Uuid::new_v4()).await;
+
+        assert!(matches!(result, Err(MarkContactMessageReadError::NotFound)));
+    }
+}
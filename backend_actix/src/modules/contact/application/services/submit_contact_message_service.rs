@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::contact::application::domain::entities::ContactMessage;
+use crate::contact::application::ports::{
+    incoming::use_cases::{
+        SubmitContactMessageCommand, SubmitContactMessageError, SubmitContactMessageUseCase,
+    },
+    outgoing::ContactMessageRepository,
+};
+
+pub struct SubmitContactMessageService<R>
+where
+    R: ContactMessageRepository,
+{
+    repository: R,
+    /// Users notified of each message
+    owners: Vec<Uuid>,
+}
+
+impl<R> SubmitContactMessageService<R>
+where
+    R: ContactMessageRepository,
+{
+    pub fn new(repository: R, owners: Vec<Uuid>) -> Self {
+        Self { repository, owners }
+    }
+}
+
+#[async_trait]
+impl<R> SubmitContactMessageUseCase for SubmitContactMessageService<R>
+where
+    R: ContactMessageRepository,
+{
+    async fn execute(
+        &self,
+        command: SubmitContactMessageCommand,
+    ) -> Result<ContactMessage, SubmitContactMessageError> {
+        self.repository
+            .create(&command, &self.owners)
+            .await
+            .map_err(|e| SubmitContactMessageError::RepositoryError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contact::adapter::outgoing::InMemoryContactStore;
+
+    #[tokio::test]
+    async fn test_submit_stores_message() {
+        let store = InMemoryContactStore::default();
+        let service = SubmitContactMessageService::new(store.clone(), vec![Uuid::new_v4()]);
+        let command = SubmitContactMessageCommand::new(
+            "Jane".to_string(),
+            "jane@example.com".to_string(),
+            None,
+            "Hello".to_string(),
+            None,
+        )
+        .unwrap();
+
+        let message = service.execute(command).await.unwrap();
+
+        let page = store.list(false, 0, 10).await.unwrap();
+        assert_eq!(page.items, vec![message]);
+    }
+}
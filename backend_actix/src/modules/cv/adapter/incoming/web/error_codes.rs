@@ -0,0 +1,6 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    CV_NOT_FOUND = (NOT_FOUND, "CV not found");
+    CV_UNAUTHORIZED = (FORBIDDEN, "You are not authorized to delete this CV");
+}
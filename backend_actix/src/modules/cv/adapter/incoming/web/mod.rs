@@ -1 +1,21 @@
+use actix_web::web;
+
+pub mod error_codes;
 pub mod routes;
+
+/// Routes anyone can call
+pub fn configure_public(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::get_public_cv_by_id_handler);
+}
+
+/// Routes for signed-in users with a verified email
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::get_cvs_handler)
+        .service(routes::get_cv_by_id_handler)
+        .service(routes::get_cv_stats_handler)
+        .service(routes::export_cv_text_handler)
+        .service(routes::create_cv_handler)
+        .service(routes::update_cv_handler)
+        .service(routes::patch_cv_handler)
+        .service(routes::hard_delete_cv_handler);
+}
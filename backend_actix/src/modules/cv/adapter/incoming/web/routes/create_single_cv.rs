@@ -1,16 +1,18 @@
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
 use crate::auth::adapter::incoming::web::extractors::auth::VerifiedUser;
 use crate::cv::application::ports::outgoing::CreateCVData;
 use crate::cv::application::use_cases::create_cv::CreateCVError;
 use crate::cv::domain::entities::{
-    ContactDetail, CoreSkill, Education, Experience, HighlightedProject,
+    CVInfo, ContactDetail, CoreSkill, Education, Experience, HighlightedProject,
 };
 use crate::shared::api::ApiResponse;
 use crate::AppState;
 use actix_web::{post, web, Responder};
 use serde::{Deserialize, Serialize};
 use tracing::error;
+use utoipa::ToSchema;
 
-#[derive(Deserialize, Serialize)]
+#[derive(Deserialize, Serialize, ToSchema)]
 pub struct CreateCVRequest {
     pub role: String,
     pub bio: String,
@@ -23,14 +25,14 @@ pub struct CreateCVRequest {
     pub contact_info: Vec<ContactDetail>,
 }
 
-#[derive(serde::Deserialize, serde::Serialize)]
+#[derive(serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct EducationRequest {
     pub degree: String,
     pub institution: String,
     pub graduation_year: i32,
 }
 
-#[derive(serde::Deserialize, serde::Serialize)]
+#[derive(serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct ExperienceRequest {
     pub company: String,
     pub position: String,
@@ -42,7 +44,7 @@ pub struct ExperienceRequest {
     pub achievements: Vec<String>,
 }
 
-#[derive(serde::Deserialize, serde::Serialize)]
+#[derive(serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct HighlightedProjectRequest {
     pub id: String,
     pub title: String,
@@ -50,6 +52,23 @@ pub struct HighlightedProjectRequest {
     pub short_description: String,
 }
 
+/// Create a CV for the current user
+#[utoipa::path(
+    post,
+    path = "/api/cvs",
+    tag = "cvs",
+    request_body = CreateCVRequest,
+    responses(
+        (status = 201, description = "CV created", body = inline(SuccessResponse<CVInfo>)),
+        (status = 400, description = "Malformed request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[post("/api/cvs")]
 pub async fn create_cv_handler(
     user: VerifiedUser,
@@ -115,7 +134,7 @@ pub async fn create_cv_handler(
             .collect(),
     };
 
-    match data.create_cv_use_case.execute(user.user_id, cv_data).await {
+    match data.cv.create.execute(user.user_id, cv_data).await {
         Ok(created) => ApiResponse::created(created),
 
         Err(CreateCVError::RepositoryError(e)) => {
@@ -179,16 +198,18 @@ mod tests {
     fn jwt_service() -> JwtTokenService {
         JwtTokenService::new(JwtConfig {
             issuer: "Lotion".to_string(),
+            audience: "test_audience".to_string(),
             secret_key: "test_secret_key_for_testing_purposes_only".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         })
     }
 
     fn token(user_id: Uuid, verified: bool) -> String {
         jwt_service()
-            .generate_access_token(user_id, verified)
+            .generate_access_token(user_id, verified, 0)
             .unwrap()
     }
 
@@ -286,6 +307,7 @@ mod tests {
                 short_description: "Full-stack e-commerce solution".to_string(),
             }],
             contact_info: full_request().contact_info,
+            updated_at: chrono::Utc::now(),
         }
     }
 
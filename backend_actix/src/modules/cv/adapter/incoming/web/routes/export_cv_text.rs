@@ -0,0 +1,162 @@
+use actix_web::{get, http::header, web, HttpResponse, Responder};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::api::schemas::ErrorResponse;
+use crate::cv::adapter::incoming::web::error_codes::CV_NOT_FOUND;
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::VerifiedUser,
+    cv::application::use_cases::fetch_cv_by_id::FetchCVByIdError, shared::api::ApiResponse,
+    AppState,
+};
+
+/// Download one of the current user's CVs as plain text
+///
+/// For applicant tracking systems, which often mangle PDFs: headed sections
+/// (contact, summary, skills, experience, education, projects) with `-`
+/// bullets and no tables. Empty sections are left out.
+#[utoipa::path(
+    get,
+    path = "/api/cvs/{cv_id}/export.txt",
+    tag = "cvs",
+    params(
+        ("cv_id" = Uuid, Path, description = "CV id"),
+    ),
+    responses(
+        (status = 200, description = "Plain-text CV, as an attachment", content_type = "text/plain"),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "CV not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/cvs/{cv_id}/export.txt")]
+pub async fn export_cv_text_handler(
+    user: VerifiedUser,
+    path: web::Path<Uuid>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let cv_id = path.into_inner();
+
+    match data.cv.export_text.execute(user.user_id, cv_id).await {
+        Ok(exported) => HttpResponse::Ok()
+            .content_type(exported.content_type)
+            .insert_header((
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", exported.file_name),
+            ))
+            .body(exported.body),
+
+        Err(FetchCVByIdError::CVNotFound) => CV_NOT_FOUND.response(),
+
+        Err(FetchCVByIdError::RepositoryError(err)) => {
+            error!("Repository error exporting CV {}: {}", cv_id, err);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use async_trait::async_trait;
+    use std::sync::Arc;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        cv::{
+            adapter::outgoing::PlainTextCvRenderer,
+            application::use_cases::{
+                export_cv::ExportCvUseCase, fetch_cv_by_id::IFetchCVByIdUseCase,
+            },
+            domain::CVInfo,
+        },
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service,
+        },
+    };
+
+    /// Finds any CV for its owner, or none
+    struct MockFetchCVByIdUseCase {
+        found: bool,
+    }
+
+    #[async_trait]
+    impl IFetchCVByIdUseCase for MockFetchCVByIdUseCase {
+        async fn execute(&self, user_id: Uuid, cv_id: Uuid) -> Result<CVInfo, FetchCVByIdError> {
+            if !self.found {
+                return Err(FetchCVByIdError::CVNotFound);
+            }
+            Ok(CVInfo {
+                id: cv_id,
+                user_id,
+                display_name: "Jane Doe".to_string(),
+                role: "Engineer".to_string(),
+                bio: "Builds APIs.".to_string(),
+                photo_url: String::new(),
+                core_skills: vec![],
+                educations: vec![],
+                experiences: vec![],
+                highlighted_projects: vec![],
+                contact_info: vec![],
+                updated_at: chrono::Utc::now(),
+            })
+        }
+    }
+
+    async fn call(found: bool) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_export_cv_text(ExportCvUseCase::new(
+                Arc::new(MockFetchCVByIdUseCase { found }),
+                Arc::new(PlainTextCvRenderer),
+            ))
+            .build();
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(export_cv_text_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/api/cvs/{}/export.txt", Uuid::new_v4()))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_owner_downloads_plain_text_attachment() {
+        let resp = call(true).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers().get(header::CONTENT_TYPE).unwrap(),
+            "text/plain; charset=utf-8"
+        );
+        assert_eq!(
+            resp.headers().get(header::CONTENT_DISPOSITION).unwrap(),
+            "attachment; filename=\"jane-doe-cv.txt\""
+        );
+        let body = test::read_body(resp).await;
+        assert_eq!(body, "JANE DOE\nEngineer\n\nSUMMARY\nBuilds APIs.\n");
+    }
+
+    #[actix_web::test]
+    async fn test_someone_elses_cv_is_not_found() {
+        let resp = call(false).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "CV_NOT_FOUND");
+    }
+}
@@ -0,0 +1,178 @@
+use actix_web::{get, web, HttpRequest, Responder};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::cv::adapter::incoming::web::error_codes::CV_NOT_FOUND;
+use crate::{
+    analytics::{
+        adapter::incoming::web::routes::ReportQuery,
+        application::{
+            domain::entities::{cv_view_path, PathReport},
+            ports::incoming::use_cases::AnalyticsReportError,
+        },
+    },
+    auth::adapter::incoming::web::extractors::auth::VerifiedUser,
+    cv::application::use_cases::fetch_cv_by_id::FetchCVByIdError,
+    shared::api::ApiResponse,
+    AppState,
+};
+
+/// Views of one of the current user's CVs
+///
+/// Counts the views of its public link (`GET /api/public/cvs/{username}/{cv_id}`)
+/// per day, with the hosts that referred them. The owner's own views and
+/// crawlers are not counted, and views show up after a few seconds.
+#[utoipa::path(
+    get,
+    path = "/api/cvs/{cv_id}/stats",
+    tag = "cvs",
+    params(
+        ("cv_id" = Uuid, Path, description = "CV id"),
+        ("from" = Option<String>, Query, description = "First UTC day, YYYY-MM-DD (default: 29 days before `to`)"),
+        ("to" = Option<String>, Query, description = "Last UTC day, YYYY-MM-DD (default: today); at most 366 days in all"),
+        ("limit" = Option<u32>, Query, description = "Referrers to list, 1-100 (default 10)"),
+    ),
+    responses(
+        (status = 200, description = "Totals, every day of the range and the top referrers", body = inline(SuccessResponse<PathReport>)),
+        (status = 400, description = "Invalid range or limit", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "CV not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/cvs/{cv_id}/stats")]
+pub async fn get_cv_stats_handler(
+    user: VerifiedUser,
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let cv_id = path.into_inner();
+    let query = match ReportQuery::parse(req.query_string()) {
+        Ok(query) => query,
+        Err(response) => return response,
+    };
+
+    // Only the owner may see who reads their CV
+    match data.cv.fetch_by_id.execute(user.user_id, cv_id).await {
+        Ok(_) => {}
+        Err(FetchCVByIdError::CVNotFound) => return CV_NOT_FOUND.response(),
+        Err(FetchCVByIdError::RepositoryError(err)) => {
+            error!("Repository error fetching CV {} for stats: {}", cv_id, err);
+            return ApiResponse::internal_error();
+        }
+    }
+
+    match data
+        .analytics
+        .path_report
+        .execute(&cv_view_path(cv_id), query.range, query.limit)
+        .await
+    {
+        Ok(report) => ApiResponse::success(report),
+        Err(AnalyticsReportError::QueryFailed(msg)) => {
+            error!("Failed to report views of CV {}: {}", cv_id, msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use async_trait::async_trait;
+    use std::sync::Arc;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        cv::{application::use_cases::fetch_cv_by_id::IFetchCVByIdUseCase, domain::CVInfo},
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service,
+        },
+    };
+
+    /// Finds any CV for its owner, or none
+    struct MockFetchCVByIdUseCase {
+        found: bool,
+    }
+
+    #[async_trait]
+    impl IFetchCVByIdUseCase for MockFetchCVByIdUseCase {
+        async fn execute(&self, user_id: Uuid, cv_id: Uuid) -> Result<CVInfo, FetchCVByIdError> {
+            if !self.found {
+                return Err(FetchCVByIdError::CVNotFound);
+            }
+            Ok(CVInfo {
+                id: cv_id,
+                user_id,
+                display_name: "CV".to_string(),
+                role: "Engineer".to_string(),
+                bio: String::new(),
+                photo_url: String::new(),
+                core_skills: vec![],
+                educations: vec![],
+                experiences: vec![],
+                highlighted_projects: vec![],
+                contact_info: vec![],
+                updated_at: chrono::Utc::now(),
+            })
+        }
+    }
+
+    async fn call(found: bool, query: &str) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_fetch_cv_by_id(MockFetchCVByIdUseCase { found })
+            .build();
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(get_cv_stats_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/api/cvs/{}/stats{}", Uuid::new_v4(), query))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_owner_sees_daily_views_and_referrers() {
+        let resp = call(true, "?from=2026-10-15&to=2026-10-16").await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["views"], 4);
+        assert_eq!(json["data"]["daily"].as_array().unwrap().len(), 2);
+        assert_eq!(json["data"]["daily"][0]["day"], "2026-10-15");
+        assert_eq!(json["data"]["referrers"][0]["referrer"], "www.linkedin.com");
+    }
+
+    #[actix_web::test]
+    async fn test_someone_elses_cv_is_not_found() {
+        let resp = call(false, "").await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "CV_NOT_FOUND");
+    }
+
+    #[actix_web::test]
+    async fn test_invalid_range_is_rejected() {
+        let resp = call(true, "?from=2026-10-16&to=2026-10-01").await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -2,9 +2,11 @@ use actix_web::{get, web, Responder};
 use serde::Deserialize;
 use tracing::error;
 
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
 use crate::auth::adapter::incoming::web::extractors::auth::VerifiedUser;
-use crate::cv::application::ports::outgoing::{CVListFilter, CVPageRequest, CVSort};
+use crate::cv::application::ports::outgoing::{CVListFilter, CVPageRequest, CVPageResult, CVSort};
 use crate::cv::application::use_cases::fetch_user_cvs::FetchCVError;
+use crate::cv::domain::entities::CVInfo;
 use crate::shared::api::ApiResponse;
 use crate::AppState;
 
@@ -47,6 +49,27 @@ impl From<GetCVsQuery> for (CVListFilter, CVPageRequest, CVSort) {
 // ──────────────────────────────────────────────────────────
 //
 
+/// List the current user's CVs
+#[utoipa::path(
+    get,
+    path = "/api/cvs",
+    tag = "cvs",
+    params(
+        ("search" = Option<String>, Query, description = "Match against role and display name"),
+        ("sort" = Option<String>, Query, description = "`Newest`, `Oldest`, `UpdatedNewest` (default) or `UpdatedOldest`"),
+        ("page" = Option<u32>, Query, description = "1-based page number"),
+        ("per_page" = Option<u32>, Query, description = "Page size (default 10)"),
+    ),
+    responses(
+        (status = 200, description = "CVs of the current user", body = inline(SuccessResponse<CVPageResult<CVInfo>>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[get("/api/cvs")]
 pub async fn get_cvs_handler(
     user: VerifiedUser,
@@ -56,7 +79,8 @@ pub async fn get_cvs_handler(
     let (filter, page, sort) = query.into_inner().into();
 
     match data
-        .fetch_cv_use_case
+        .cv
+        .fetch_list
         .execute(user.user_id, filter, sort, page)
         .await
     {
@@ -138,6 +162,7 @@ mod tests {
                 experiences: vec![],
                 highlighted_projects: vec![],
                 contact_info: vec![],
+                updated_at: chrono::Utc::now(),
             }],
             page: 1,
             per_page: 10,
@@ -158,7 +183,7 @@ mod tests {
             .build();
 
         let jwt_service = create_test_jwt_service();
-        let token = jwt_service.generate_access_token(user_id, true).unwrap();
+        let token = jwt_service.generate_access_token(user_id, true, 0).unwrap();
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service);
 
         let app = test::init_service(
@@ -202,7 +227,7 @@ mod tests {
             .build();
 
         let jwt_service = create_test_jwt_service();
-        let token = jwt_service.generate_access_token(user_id, true).unwrap();
+        let token = jwt_service.generate_access_token(user_id, true, 0).unwrap();
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service);
 
         let app = test::init_service(
@@ -238,7 +263,7 @@ mod tests {
             .build();
 
         let jwt_service = create_test_jwt_service();
-        let token = jwt_service.generate_access_token(user_id, true).unwrap();
+        let token = jwt_service.generate_access_token(user_id, true, 0).unwrap();
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service);
 
         let app = test::init_service(
@@ -267,7 +292,9 @@ mod tests {
             .build();
 
         let jwt_service = create_test_jwt_service();
-        let token = jwt_service.generate_access_token(user_id, false).unwrap();
+        let token = jwt_service
+            .generate_access_token(user_id, false, 0)
+            .unwrap();
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service);
 
         let app = test::init_service(
@@ -1,16 +1,44 @@
-use actix_web::{get, web, Responder};
+use actix_web::{get, http::header, web, HttpRequest, Responder};
 use tracing::error;
 use uuid::Uuid;
 
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::cv::adapter::incoming::web::error_codes::CV_NOT_FOUND;
+use crate::cv::domain::entities::CVInfo;
 use crate::{
-    auth::adapter::incoming::web::extractors::auth::resolve_owner_id_or_response,
+    analytics::{
+        adapter::incoming::web::routes::page_view_of, application::domain::entities::cv_view_path,
+    },
+    auth::adapter::incoming::web::extractors::auth::{resolve_owner_id_or_response, MaybeUser},
     cv::application::use_cases::get_public_single_cv::GetPublicSingleCvError,
-    shared::api::ApiResponse, AppState,
+    shared::api::{etag::ETag, ApiResponse},
+    AppState,
 };
 
+/// Get a published CV by its owner's username
+///
+/// Each view is counted for the owner's `GET /api/cvs/{cv_id}/stats`, except
+/// the owner's own.
+#[utoipa::path(
+    get,
+    path = "/api/public/cvs/{username}/{cv_id}",
+    tag = "cvs",
+    params(
+        ("username" = String, Path, description = "Owner's username"),
+        ("cv_id" = Uuid, Path, description = "CV id"),
+    ),
+    responses(
+        (status = 200, description = "CV found (carries an `ETag`)", body = inline(SuccessResponse<CVInfo>)),
+        (status = 304, description = "Not modified since the `If-None-Match` ETag"),
+        (status = 404, description = "User or CV not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+)]
 #[get("/api/public/cvs/{username}/{cv_id}")]
 pub async fn get_public_cv_by_id_handler(
+    req: HttpRequest,
     path: web::Path<(String, Uuid)>,
+    viewer: MaybeUser,
     data: web::Data<AppState>,
 ) -> impl Responder {
     let (username, cv_id) = path.into_inner();
@@ -23,16 +51,21 @@ pub async fn get_public_cv_by_id_handler(
 
     // Fetch the CV publicly (still owner-scoped)
     match data
-        .get_public_single_cv_use_case
+        .cv
+        .get_public_single
         .execute(owner_id.into(), cv_id)
         .await
     {
-        Ok(cv) => ApiResponse::success(cv),
-
-        Err(GetPublicSingleCvError::NotFound) => {
-            ApiResponse::not_found("CV_NOT_FOUND", "CV not found")
+        Ok(cv) => {
+            if !viewer.is(owner_id) {
+                record_view(&req, &data, cv_id).await;
+            }
+            // CVInfo carries no timestamp, so the validator is a digest of the payload
+            ETag::from_body(&cv).respond(&req, cv)
         }
 
+        Err(GetPublicSingleCvError::NotFound) => CV_NOT_FOUND.response(),
+
         Err(GetPublicSingleCvError::RepositoryError(msg)) => {
             error!(
                 "Repository error fetching public CV {} for username {}: {}",
@@ -43,6 +76,19 @@ pub async fn get_public_cv_by_id_handler(
     }
 }
 
+async fn record_view(req: &HttpRequest, data: &AppState, cv_id: Uuid) {
+    let referrer = req
+        .headers()
+        .get(header::REFERER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    match page_view_of(req, cv_view_path(cv_id), referrer) {
+        Ok(view) => data.analytics.record.execute(view).await,
+        Err(e) => error!("Failed to record view of CV {}: {}", cv_id, e),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -64,6 +110,7 @@ mod tests {
     use crate::cv::domain::entities::CVInfo;
 
     use crate::tests::support::app_state_builder::TestAppStateBuilder;
+    use crate::tests::support::stubs::RecordingPageViewUseCase;
 
     /* --------------------------------------------------
      * Mock UserQuery (drives UserIdentityResolver)
@@ -121,10 +168,13 @@ mod tests {
             username: username.to_string(),
             password_hash: "hashed".to_string(),
             full_name: "Test User".to_string(),
+            preferred_locale: "en".to_string(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
             is_verified: true,
             is_deleted,
+            token_version: 0,
+            is_active: true,
         }
     }
 
@@ -179,6 +229,7 @@ mod tests {
             experiences: vec![],
             highlighted_projects: vec![],
             contact_info: vec![],
+            updated_at: chrono::Utc::now(),
         }
     }
 
@@ -231,6 +282,39 @@ mod tests {
         assert!(body["data"]["experiences"].is_array());
     }
 
+    #[actix_web::test]
+    async fn test_get_public_cv_by_id_counts_the_view() {
+        let owner_uuid = Uuid::new_v4();
+        let cv_id = Uuid::new_v4();
+        let resolver = UserIdentityResolver::new(Arc::new(MockUserQuery::found(
+            sample_user_query_result(owner_uuid, "someone", false),
+        )));
+        let views = RecordingPageViewUseCase::default();
+
+        let app_state = TestAppStateBuilder::default()
+            .with_user_identity_resolver(resolver)
+            .with_get_public_single_cv(Arc::new(MockGetPublicSingleCvUseCase::success(sample_cv(
+                owner_uuid, cv_id,
+            ))))
+            .with_record_page_view(views.clone())
+            .build();
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .service(get_public_cv_by_id_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/api/public/cvs/someone/{}", cv_id))
+            .insert_header((header::REFERER, "https://www.linkedin.com/in/someone"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(views.paths(), vec![format!("/api/public/cvs/{cv_id}")]);
+    }
+
     #[actix_web::test]
     async fn test_get_public_cv_by_id_user_not_found() {
         let cv_id = Uuid::new_v4();
@@ -2,12 +2,34 @@ use actix_web::{get, web, Responder};
 use tracing::error;
 use uuid::Uuid;
 
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::cv::adapter::incoming::web::error_codes::CV_NOT_FOUND;
+use crate::cv::domain::entities::CVInfo;
 use crate::{
     auth::adapter::incoming::web::extractors::auth::VerifiedUser,
     cv::application::use_cases::fetch_cv_by_id::FetchCVByIdError, shared::api::ApiResponse,
     AppState,
 };
 
+/// Get one of the current user's CVs
+#[utoipa::path(
+    get,
+    path = "/api/cvs/{cv_id}",
+    tag = "cvs",
+    params(
+        ("cv_id" = Uuid, Path, description = "CV id"),
+    ),
+    responses(
+        (status = 200, description = "CV found", body = inline(SuccessResponse<CVInfo>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "CV not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[get("/api/cvs/{cv_id}")]
 pub async fn get_cv_by_id_handler(
     user: VerifiedUser,
@@ -16,14 +38,10 @@ pub async fn get_cv_by_id_handler(
 ) -> impl Responder {
     let cv_id = path.into_inner();
 
-    match data
-        .fetch_cv_by_id_use_case
-        .execute(user.user_id, cv_id)
-        .await
-    {
+    match data.cv.fetch_by_id.execute(user.user_id, cv_id).await {
         Ok(cv) => ApiResponse::success(cv),
 
-        Err(FetchCVByIdError::CVNotFound) => ApiResponse::not_found("CV_NOT_FOUND", "CV not found"),
+        Err(FetchCVByIdError::CVNotFound) => CV_NOT_FOUND.response(),
 
         Err(FetchCVByIdError::RepositoryError(err)) => {
             error!("Repository error fetching CV by id: {}", err);
@@ -121,6 +139,7 @@ mod tests {
             experiences: vec![],
             highlighted_projects: vec![],
             contact_info: vec![],
+            updated_at: chrono::Utc::now(),
         };
 
         let fetch_uc = MockFetchCVByIdUseCase::new();
@@ -131,7 +150,7 @@ mod tests {
             .build();
 
         let jwt_service = create_test_jwt_service();
-        let token = jwt_service.generate_access_token(user_id, true).unwrap();
+        let token = jwt_service.generate_access_token(user_id, true, 0).unwrap();
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service.clone());
 
         let app = test::init_service(
@@ -170,7 +189,7 @@ mod tests {
             .build();
 
         let jwt_service = create_test_jwt_service();
-        let token = jwt_service.generate_access_token(user_id, true).unwrap();
+        let token = jwt_service.generate_access_token(user_id, true, 0).unwrap();
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service);
 
         let app = test::init_service(
@@ -208,7 +227,7 @@ mod tests {
             .build();
 
         let jwt_service = create_test_jwt_service();
-        let token = jwt_service.generate_access_token(user_id, true).unwrap();
+        let token = jwt_service.generate_access_token(user_id, true, 0).unwrap();
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service);
 
         let app = test::init_service(
@@ -301,7 +320,9 @@ mod tests {
             .build();
 
         let jwt_service = create_test_jwt_service();
-        let token = jwt_service.generate_access_token(user_id, false).unwrap();
+        let token = jwt_service
+            .generate_access_token(user_id, false, 0)
+            .unwrap();
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service.clone());
 
         let app = test::init_service(
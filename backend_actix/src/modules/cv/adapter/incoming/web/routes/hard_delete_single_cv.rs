@@ -2,6 +2,8 @@ use actix_web::{delete, web, Responder};
 use tracing::error;
 use uuid::Uuid;
 
+use crate::api::schemas::ErrorResponse;
+use crate::cv::adapter::incoming::web::error_codes::{CV_NOT_FOUND, CV_UNAUTHORIZED};
 use crate::{
     auth::{
         adapter::incoming::web::extractors::auth::VerifiedUser,
@@ -12,6 +14,25 @@ use crate::{
     AppState,
 };
 
+/// Permanently delete a CV
+#[utoipa::path(
+    delete,
+    path = "/api/cvs/{cv_id}",
+    tag = "cvs",
+    params(
+        ("cv_id" = Uuid, Path, description = "CV id"),
+    ),
+    responses(
+        (status = 204, description = "CV deleted"),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified, or the CV belongs to someone else", body = ErrorResponse),
+        (status = 404, description = "CV not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[delete("/api/cvs/{cv_id}")]
 pub async fn hard_delete_cv_handler(
     user: VerifiedUser,
@@ -21,18 +42,14 @@ pub async fn hard_delete_cv_handler(
     let cv_id = path.into_inner();
 
     match app_data
-        .hard_delete_cv_use_case
+        .cv
+        .hard_delete
         .execute(UserId::from(user.user_id), cv_id)
         .await
     {
         Ok(()) => ApiResponse::no_content(),
-        Err(HardDeleteCVError::CVNotFound) => {
-            ApiResponse::not_found("CV_NOT_FOUND", "CV not found")
-        }
-        Err(HardDeleteCVError::Unauthorized) => ApiResponse::forbidden(
-            "CV_UNAUTHORIZED",
-            "You are not authorized to delete this CV",
-        ),
+        Err(HardDeleteCVError::CVNotFound) => CV_NOT_FOUND.response(),
+        Err(HardDeleteCVError::Unauthorized) => CV_UNAUTHORIZED.response(),
         Err(HardDeleteCVError::RepositoryError(e)) => {
             error!("Repository error deleting CV: {}", e);
             ApiResponse::internal_error()
@@ -79,6 +96,7 @@ mod tests {
             &self,
             _user_id: Uuid,
             _is_verified: bool,
+            _token_version: i32,
         ) -> Result<String, TokenError> {
             unimplemented!()
         }
@@ -87,6 +105,8 @@ mod tests {
             &self,
             _user_id: Uuid,
             _is_verified: bool,
+            _token_version: i32,
+            _remember_me: bool,
         ) -> Result<String, TokenError> {
             unimplemented!()
         }
@@ -99,6 +119,11 @@ mod tests {
                 exp: 9999999999,
                 iat: 0,
                 nbf: 0,
+                token_version: 0,
+                remember_me: false,
+                iss: String::new(),
+                aud: String::new(),
+                jti: None,
             })
         }
 
@@ -110,7 +135,7 @@ mod tests {
             unimplemented!()
         }
 
-        fn verify_verification_token(&self, _token: &str) -> Result<Uuid, TokenError> {
+        fn verify_verification_token(&self, _token: &str) -> Result<TokenClaims, TokenError> {
             unimplemented!()
         }
     }
@@ -1,4 +1,6 @@
 mod create_single_cv;
+mod export_cv_text;
+mod get_cv_stats;
 mod get_cvs;
 mod get_public_single_cv;
 mod get_single_cv;
@@ -6,10 +8,12 @@ mod hard_delete_single_cv;
 mod patch_single_cv;
 mod update_single_cv;
 
-pub use create_single_cv::create_cv_handler;
-pub use get_cvs::get_cvs_handler;
-pub use get_public_single_cv::get_public_cv_by_id_handler;
-pub use get_single_cv::get_cv_by_id_handler;
-pub use hard_delete_single_cv::hard_delete_cv_handler;
-pub use patch_single_cv::patch_cv_handler;
-pub use update_single_cv::update_cv_handler;
+pub use create_single_cv::{__path_create_cv_handler, create_cv_handler};
+pub use export_cv_text::{__path_export_cv_text_handler, export_cv_text_handler};
+pub use get_cv_stats::{__path_get_cv_stats_handler, get_cv_stats_handler};
+pub use get_cvs::{__path_get_cvs_handler, get_cvs_handler};
+pub use get_public_single_cv::{__path_get_public_cv_by_id_handler, get_public_cv_by_id_handler};
+pub use get_single_cv::{__path_get_cv_by_id_handler, get_cv_by_id_handler};
+pub use hard_delete_single_cv::{__path_hard_delete_cv_handler, hard_delete_cv_handler};
+pub use patch_single_cv::{__path_patch_cv_handler, patch_cv_handler};
+pub use update_single_cv::{__path_update_cv_handler, update_cv_handler};
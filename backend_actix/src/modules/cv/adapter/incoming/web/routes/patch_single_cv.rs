@@ -1,23 +1,27 @@
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
 use crate::auth::adapter::incoming::web::extractors::auth::VerifiedUser;
+use crate::cv::adapter::incoming::web::error_codes::CV_NOT_FOUND;
 use crate::cv::application::ports::outgoing::PatchCVData;
 use crate::cv::application::use_cases::patch_cv::PatchCVError;
 use crate::cv::domain::entities::{
-    ContactDetail, CoreSkill, Education, Experience, HighlightedProject,
+    CVInfo, ContactDetail, CoreSkill, Education, Experience, HighlightedProject,
 };
-use crate::shared::api::ApiResponse;
+use crate::shared::api::{error_codes::STALE_WRITE, precondition, ApiResponse};
 use crate::AppState;
 use actix_web::patch;
-use actix_web::{web, Responder};
+use actix_web::{web, HttpRequest, Responder};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use tracing::error;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub struct ReplaceOp<T> {
     pub replace: Vec<T>,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub struct PatchCVRequest {
     pub bio: Option<String>,
     pub role: Option<String>,
@@ -29,16 +33,45 @@ pub struct PatchCVRequest {
     pub experiences: Option<ReplaceOp<Experience>>,
     pub highlighted_projects: Option<ReplaceOp<HighlightedProject>>,
     pub contact_info: Option<ReplaceOp<ContactDetail>>,
+
+    /// The CV's `updated_at` as last read; the write fails with
+    /// `STALE_WRITE` if it changed since. `If-Unmodified-Since` works too.
+    #[serde(default)]
+    pub expected_updated_at: Option<DateTime<Utc>>,
 }
 
+/// Partially update a CV; list fields are replaced as a whole
+#[utoipa::path(
+    patch,
+    path = "/api/cvs/{cv_id}",
+    tag = "cvs",
+    params(
+        ("cv_id" = Uuid, Path, description = "CV id"),
+    ),
+    request_body = PatchCVRequest,
+    responses(
+        (status = 200, description = "CV updated", body = inline(SuccessResponse<CVInfo>)),
+        (status = 400, description = "Malformed request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "CV not found", body = ErrorResponse),
+        (status = 409, description = "CV changed since `expected_updated_at`", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[patch("/api/cvs/{cv_id}")]
 pub async fn patch_cv_handler(
     user: VerifiedUser,
+    http_req: HttpRequest,
     path: web::Path<Uuid>,
     req: web::Json<PatchCVRequest>,
     data: web::Data<AppState>,
 ) -> impl Responder {
     let cv_id = path.into_inner();
+    let expected_updated_at = precondition::expected_updated_at(&http_req, req.expected_updated_at);
 
     let patch_data = PatchCVData {
         bio: req.bio.clone(),
@@ -56,12 +89,14 @@ pub async fn patch_cv_handler(
     };
 
     match data
-        .patch_cv_use_case
-        .execute(user.user_id, cv_id, patch_data)
+        .cv
+        .patch
+        .execute(user.user_id, cv_id, patch_data, expected_updated_at)
         .await
     {
         Ok(cv) => ApiResponse::success(cv),
-        Err(PatchCVError::CVNotFound) => ApiResponse::not_found("CV_NOT_FOUND", "CV not found"),
+        Err(PatchCVError::CVNotFound) => CV_NOT_FOUND.response(),
+        Err(PatchCVError::Stale) => STALE_WRITE.response(),
         Err(PatchCVError::RepositoryError(e)) => {
             error!("Repository error patching CV: {}", e);
             ApiResponse::internal_error()
@@ -100,6 +135,7 @@ mod tests {
             experiences: None,
             highlighted_projects: None,
             contact_info: None,
+            expected_updated_at: None,
         }
     }
 
@@ -138,6 +174,7 @@ mod tests {
             _user_id: Uuid,
             cv_id: Uuid,
             data: PatchCVData,
+            _expected_updated_at: Option<DateTime<Utc>>,
         ) -> Result<CVInfo, PatchCVError> {
             if let Some(err) = self.should_fail.lock().await.clone() {
                 return Err(err);
@@ -164,6 +201,7 @@ mod tests {
                     .highlighted_projects
                     .unwrap_or(existing.highlighted_projects),
                 contact_info: data.contact_info.unwrap_or(existing.contact_info),
+                updated_at: chrono::Utc::now(),
             })
         }
     }
@@ -199,6 +237,7 @@ mod tests {
                 contact_type: ContactType::WebPage,
                 content: "www.nonexist.blog.com".to_string(),
             }],
+            updated_at: chrono::Utc::now(),
         };
 
         patch_uc.set_success(expected_cv).await;
@@ -208,7 +247,7 @@ mod tests {
             .build();
 
         let jwt_service = create_test_jwt_service();
-        let token = jwt_service.generate_access_token(user_id, true).unwrap();
+        let token = jwt_service.generate_access_token(user_id, true, 0).unwrap();
 
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service.clone());
 
@@ -272,6 +311,7 @@ mod tests {
             experiences: vec![],
             highlighted_projects: vec![],
             contact_info: vec![],
+            updated_at: chrono::Utc::now(),
         };
 
         patch_uc.set_success(expected_cv).await;
@@ -281,7 +321,7 @@ mod tests {
             .build();
 
         let jwt_service = create_test_jwt_service();
-        let token = jwt_service.generate_access_token(user_id, true).unwrap();
+        let token = jwt_service.generate_access_token(user_id, true, 0).unwrap();
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service.clone());
 
         let app = test::init_service(
@@ -326,7 +366,7 @@ mod tests {
             .build();
 
         let jwt_service = create_test_jwt_service();
-        let token = jwt_service.generate_access_token(user_id, true).unwrap();
+        let token = jwt_service.generate_access_token(user_id, true, 0).unwrap();
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service.clone());
 
         let app = test::init_service(
@@ -354,6 +394,47 @@ mod tests {
         assert!(body.get("data").is_none());
     }
 
+    #[actix_web::test]
+    async fn test_patch_cv_handler_stale_write() {
+        let user_id = Uuid::new_v4();
+        let cv_id = Uuid::new_v4();
+
+        let patch_uc = MockPatchCvUseCase::new();
+        patch_uc.set_error(PatchCVError::Stale).await;
+
+        let app_state = TestAppStateBuilder::default()
+            .with_patch_cv(patch_uc)
+            .build();
+
+        let jwt_service = create_test_jwt_service();
+        let token = jwt_service.generate_access_token(user_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service.clone());
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .app_data(web::Data::new(token_provider))
+                .service(patch_cv_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::patch()
+            .uri(&format!("/api/cvs/{}", cv_id))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(serde_json::json!({
+                "bio": "test",
+                "expected_updated_at": "2026-10-16T09:00:00Z"
+            }))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), 409);
+
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "STALE_WRITE");
+    }
+
     #[actix_web::test]
     async fn test_patch_cv_handler_repository_error() {
         let user_id = Uuid::new_v4();
@@ -371,7 +452,7 @@ mod tests {
             .build();
 
         let jwt_service = create_test_jwt_service();
-        let token = jwt_service.generate_access_token(user_id, true).unwrap();
+        let token = jwt_service.generate_access_token(user_id, true, 0).unwrap();
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service.clone());
 
         let app = test::init_service(
@@ -474,7 +555,9 @@ mod tests {
             .build();
 
         let jwt_service = create_test_jwt_service();
-        let token = jwt_service.generate_access_token(user_id, false).unwrap();
+        let token = jwt_service
+            .generate_access_token(user_id, false, 0)
+            .unwrap();
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service.clone());
 
         let app = test::init_service(
@@ -1,24 +1,28 @@
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
 use crate::auth::adapter::incoming::web::extractors::auth::VerifiedUser;
+use crate::cv::adapter::incoming::web::error_codes::CV_NOT_FOUND;
 use crate::cv::application::ports::outgoing::UpdateCVData;
 use crate::cv::application::use_cases::update_cv::UpdateCVError;
 use crate::cv::domain::entities::{
-    ContactDetail, ContactType, CoreSkill, Education, Experience, HighlightedProject,
+    CVInfo, ContactDetail, ContactType, CoreSkill, Education, Experience, HighlightedProject,
 };
-use crate::shared::api::ApiResponse;
+use crate::shared::api::{error_codes::STALE_WRITE, precondition, ApiResponse};
 use crate::AppState;
-use actix_web::{put, web, Responder};
+use actix_web::{put, web, HttpRequest, Responder};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use tracing::error;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub struct EducationRequest {
     pub degree: String,
     pub institution: String,
     pub graduation_year: i32,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub struct ExperienceRequest {
     pub company: String,
     pub position: String,
@@ -30,7 +34,7 @@ pub struct ExperienceRequest {
     pub achievements: Vec<String>,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub struct HighlightedProjectRequest {
     pub id: String,
     pub title: String,
@@ -40,14 +44,14 @@ pub struct HighlightedProjectRequest {
 
 type ContactTypeRequest = ContactType;
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub struct ContactDetailRequest {
     pub title: String,
     pub contact_type: ContactTypeRequest,
     pub content: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub struct UpdateCVRequest {
     pub bio: String,
     pub role: String,
@@ -58,16 +62,44 @@ pub struct UpdateCVRequest {
     pub experiences: Vec<ExperienceRequest>,
     pub highlighted_projects: Vec<HighlightedProjectRequest>,
     pub contact_info: Vec<ContactDetailRequest>,
+    /// The CV's `updated_at` as last read; the write fails with
+    /// `STALE_WRITE` if it changed since. `If-Unmodified-Since` works too.
+    #[serde(default)]
+    pub expected_updated_at: Option<DateTime<Utc>>,
 }
 
+/// Replace a CV
+#[utoipa::path(
+    put,
+    path = "/api/cvs/{cv_id}",
+    tag = "cvs",
+    params(
+        ("cv_id" = Uuid, Path, description = "CV id"),
+    ),
+    request_body = UpdateCVRequest,
+    responses(
+        (status = 200, description = "CV replaced", body = inline(SuccessResponse<CVInfo>)),
+        (status = 400, description = "Malformed request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "CV not found", body = ErrorResponse),
+        (status = 409, description = "CV changed since `expected_updated_at`", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[put("/api/cvs/{cv_id}")]
 pub async fn update_cv_handler(
     user: VerifiedUser,
+    http_req: HttpRequest,
     path: web::Path<Uuid>,
     req: web::Json<UpdateCVRequest>,
     data: web::Data<AppState>,
 ) -> impl Responder {
     let cv_id = path.into_inner();
+    let expected_updated_at = precondition::expected_updated_at(&http_req, req.expected_updated_at);
 
     let cv_data = UpdateCVData {
         bio: req.bio.clone(),
@@ -127,12 +159,14 @@ pub async fn update_cv_handler(
     };
 
     match data
-        .update_cv_use_case
-        .execute(user.user_id, cv_id, cv_data)
+        .cv
+        .update
+        .execute(user.user_id, cv_id, cv_data, expected_updated_at)
         .await
     {
         Ok(updated) => ApiResponse::success(updated),
-        Err(UpdateCVError::CVNotFound) => ApiResponse::not_found("CV_NOT_FOUND", "CV not found"),
+        Err(UpdateCVError::CVNotFound) => CV_NOT_FOUND.response(),
+        Err(UpdateCVError::Stale) => STALE_WRITE.response(),
         Err(UpdateCVError::RepositoryError(e)) => {
             error!("Repository error updating CV: {}", e);
             ApiResponse::internal_error()
@@ -173,6 +207,7 @@ mod tests {
             experiences: vec![],
             highlighted_projects: vec![],
             contact_info: vec![],
+            expected_updated_at: None,
         }
     }
 
@@ -190,6 +225,7 @@ mod tests {
                 experiences: data.experiences,
                 highlighted_projects: data.highlighted_projects,
                 contact_info: data.contact_info,
+                updated_at: chrono::Utc::now(),
             }
         }
     }
@@ -229,6 +265,7 @@ mod tests {
             user_id: Uuid,
             cv_id: Uuid,
             cv_data: UpdateCVData,
+            _expected_updated_at: Option<DateTime<Utc>>,
         ) -> Result<CVInfo, UpdateCVError> {
             if let Some(err) = self.should_fail.lock().await.clone() {
                 return Err(err);
@@ -267,6 +304,7 @@ mod tests {
             experiences: vec![],
             highlighted_projects: vec![],
             contact_info: vec![],
+            updated_at: chrono::Utc::now(),
         };
 
         let update_uc = Arc::new(MockUpdateCVUseCase::new());
@@ -278,7 +316,7 @@ mod tests {
 
         let jwt_service = create_test_jwt_service();
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service.clone());
-        let token = jwt_service.generate_access_token(user_id, true).unwrap();
+        let token = jwt_service.generate_access_token(user_id, true, 0).unwrap();
 
         let app = test::init_service(
             App::new()
@@ -359,6 +397,7 @@ mod tests {
                 title: "Portfolio".to_string(),
                 content: "https://qa-portfolio.com".to_string(),
             }],
+            updated_at: chrono::Utc::now(),
         };
 
         let update_uc = Arc::new(MockUpdateCVUseCase::new());
@@ -370,7 +409,7 @@ mod tests {
 
         let jwt_service = create_test_jwt_service();
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service.clone());
-        let token = jwt_service.generate_access_token(user_id, true).unwrap();
+        let token = jwt_service.generate_access_token(user_id, true, 0).unwrap();
 
         let app = test::init_service(
             App::new()
@@ -418,6 +457,7 @@ mod tests {
                     title: "Portfolio".to_string(),
                     content: "https://qa-portfolio.com".to_string(),
                 }],
+                expected_updated_at: None,
             })
             .to_request();
 
@@ -455,7 +495,7 @@ mod tests {
 
         let jwt_service = create_test_jwt_service();
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service.clone());
-        let token = jwt_service.generate_access_token(user_id, true).unwrap();
+        let token = jwt_service.generate_access_token(user_id, true, 0).unwrap();
 
         let app = test::init_service(
             App::new()
@@ -482,6 +522,45 @@ mod tests {
         assert!(body.get("data").is_none());
     }
 
+    #[actix_web::test]
+    async fn test_update_cv_handler_stale_write() {
+        let user_id = Uuid::new_v4();
+        let cv_id = Uuid::new_v4();
+
+        let update_uc = Arc::new(MockUpdateCVUseCase::new());
+        update_uc.set_error(UpdateCVError::Stale).await;
+
+        let app_state = TestAppStateBuilder::default()
+            .with_update_cv(update_uc.clone())
+            .build();
+
+        let jwt_service = create_test_jwt_service();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service.clone());
+        let token = jwt_service.generate_access_token(user_id, true, 0).unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .app_data(web::Data::new(token_provider))
+                .service(update_cv_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/cvs/{}", cv_id))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .insert_header(("If-Unmodified-Since", "Fri, 16 Oct 2026 09:00:00 GMT"))
+            .set_json(base_update_request())
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), 409);
+
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "STALE_WRITE");
+    }
+
     #[actix_web::test]
     async fn test_update_cv_handler_repository_error() {
         let user_id = Uuid::new_v4();
@@ -500,7 +579,7 @@ mod tests {
 
         let jwt_service = create_test_jwt_service();
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service.clone());
-        let token = jwt_service.generate_access_token(user_id, true).unwrap();
+        let token = jwt_service.generate_access_token(user_id, true, 0).unwrap();
 
         let app = test::init_service(
             App::new()
@@ -603,7 +682,9 @@ mod tests {
 
         let jwt_service = create_test_jwt_service();
         let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service.clone());
-        let token = jwt_service.generate_access_token(user_id, false).unwrap();
+        let token = jwt_service
+            .generate_access_token(user_id, false, 0)
+            .unwrap();
 
         let app = test::init_service(
             App::new()
@@ -1,8 +1,10 @@
 // cv_archiver_postgres.rs
 use crate::cv::application::ports::outgoing::{CVArchiver, CVArchiverError};
 use crate::cv::domain::entities::CVInfo;
+use crate::shared::field_cipher::FieldCipher;
+use crate::shared::sql;
 use async_trait::async_trait;
-use sea_orm::{DatabaseBackend, DatabaseConnection, FromQueryResult, Statement};
+use sea_orm::{ConnectionTrait, DatabaseBackend, DatabaseConnection, FromQueryResult, Statement};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -11,11 +13,21 @@ use super::sea_orm_entity::Model as CvModel;
 #[derive(Debug, Clone)]
 pub struct CVArchiverPostgres {
     db: Arc<DatabaseConnection>,
+    cipher: FieldCipher,
 }
 
 impl CVArchiverPostgres {
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
-        Self { db }
+        Self {
+            db,
+            cipher: FieldCipher::default(),
+        }
+    }
+
+    /// Decrypts the contact details of restored CVs
+    pub fn with_cipher(mut self, cipher: FieldCipher) -> Self {
+        self.cipher = cipher;
+        self
     }
 }
 
@@ -27,9 +39,13 @@ impl CVArchiver for CVArchiverPostgres {
             id: Uuid,
         }
 
+        let backend = self.db.get_database_backend();
         let result = IdResult::find_by_statement(Statement::from_sql_and_values(
-            DatabaseBackend::Postgres,
-            r#"UPDATE resumes SET is_deleted = true, updated_at = NOW() WHERE id = $1 AND is_deleted = false RETURNING id"#,
+            backend,
+            format!(
+                r#"UPDATE resumes SET is_deleted = true, updated_at = {} WHERE id = $1 AND is_deleted = false RETURNING id"#,
+                sql::now(backend)
+            ),
             [cv_id.into()],
         ))
         .one(&*self.db)
@@ -73,9 +89,13 @@ impl CVArchiver for CVArchiverPostgres {
     }
 
     async fn restore(&self, cv_id: Uuid) -> Result<CVInfo, CVArchiverError> {
+        let backend = self.db.get_database_backend();
         let result = CvModel::find_by_statement(Statement::from_sql_and_values(
-            DatabaseBackend::Postgres,
-            r#"UPDATE resumes SET is_deleted = false, updated_at = NOW() WHERE id = $1 AND is_deleted = true RETURNING *"#,
+            backend,
+            format!(
+                r#"UPDATE resumes SET is_deleted = false, updated_at = {} WHERE id = $1 AND is_deleted = true RETURNING *"#,
+                sql::now(backend)
+            ),
             [cv_id.into()],
         ))
         .one(&*self.db)
@@ -83,7 +103,9 @@ impl CVArchiver for CVArchiverPostgres {
         .map_err(|e| CVArchiverError::DatabaseError(e.to_string()))?;
 
         match result {
-            Some(model) => Ok(model.to_domain()),
+            Some(model) => model
+                .to_domain_decrypted(&self.cipher)
+                .map_err(|e| CVArchiverError::DatabaseError(e.to_string())),
             None => {
                 // Check if CV exists but is not archived
                 let exists = self.cv_exists(cv_id).await?;
@@ -2,15 +2,18 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use sea_orm::prelude::Expr;
-use sea_orm::sea_query::extension::postgres::PgExpr;
 use sea_orm::QuerySelect;
-use sea_orm::{ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder};
+use sea_orm::{
+    ColumnTrait, Condition, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+};
 use uuid::Uuid;
 
 use crate::cv::application::ports::outgoing::{
     CVListFilter, CVPageRequest, CVPageResult, CVQuery, CVQueryError, CVSort,
 };
 use crate::cv::domain::entities::CVInfo;
+use crate::shared::field_cipher::FieldCipher;
+use crate::shared::sql;
 
 // Adjust these to your actual generated entity path
 use crate::modules::cv::adapter::outgoing::sea_orm_entity::{
@@ -20,11 +23,27 @@ use crate::modules::cv::adapter::outgoing::sea_orm_entity::{
 #[derive(Debug, Clone)]
 pub struct CVQueryPostgres {
     db: Arc<sea_orm::DatabaseConnection>,
+    cipher: FieldCipher,
 }
 
 impl CVQueryPostgres {
     pub fn new(db: Arc<sea_orm::DatabaseConnection>) -> Self {
-        Self { db }
+        Self {
+            db,
+            cipher: FieldCipher::default(),
+        }
+    }
+
+    /// Decrypts contact details on read
+    pub fn with_cipher(mut self, cipher: FieldCipher) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
+    fn to_domain(&self, model: &ResumeModel) -> Result<CVInfo, CVQueryError> {
+        model
+            .to_domain_decrypted(&self.cipher)
+            .map_err(|e| CVQueryError::DatabaseError(e.to_string()))
     }
 }
 
@@ -47,26 +66,39 @@ impl CVQuery for CVQueryPostgres {
             let term = search.trim();
             if !term.is_empty() {
                 let pattern = format!("%{}%", term);
+                let backend = self.db.get_database_backend();
+                let ilike = sql::ilike(backend);
 
                 // For JSONB array-of-objects fields, cast the whole column to text
-                // and do ILIKE. This searches all nested string values.
-                let core_skills_expr =
-                    Expr::cust_with_values("CAST(core_skills AS TEXT) ILIKE $1", [pattern.clone()]);
+                // and do ILIKE. This searches all nested string values, except
+                // contact contents while they are encrypted.
+                let core_skills_expr = Expr::cust_with_values(
+                    format!("CAST(core_skills AS TEXT) {ilike} $1"),
+                    [pattern.clone()],
+                );
 
-                let educations_expr =
-                    Expr::cust_with_values("CAST(educations AS TEXT) ILIKE $1", [pattern.clone()]);
+                let educations_expr = Expr::cust_with_values(
+                    format!("CAST(educations AS TEXT) {ilike} $1"),
+                    [pattern.clone()],
+                );
 
-                let experiences_expr =
-                    Expr::cust_with_values("CAST(experiences AS TEXT) ILIKE $1", [pattern.clone()]);
+                let experiences_expr = Expr::cust_with_values(
+                    format!("CAST(experiences AS TEXT) {ilike} $1"),
+                    [pattern.clone()],
+                );
 
                 let contact_info_expr = Expr::cust_with_values(
-                    "CAST(contact_info AS TEXT) ILIKE $1",
+                    format!("CAST(contact_info AS TEXT) {ilike} $1"),
                     [pattern.clone()],
                 );
                 query = query.filter(
                     Condition::any()
-                        .add(Expr::col(ResumeColumn::DisplayName).ilike(&pattern))
-                        .add(Expr::col(ResumeColumn::Role).ilike(&pattern))
+                        .add(sql::ilike_expr(
+                            backend,
+                            ResumeColumn::DisplayName,
+                            &pattern,
+                        ))
+                        .add(sql::ilike_expr(backend, ResumeColumn::Role, &pattern))
                         .add(core_skills_expr)
                         .add(educations_expr)
                         .add(experiences_expr)
@@ -101,7 +133,10 @@ impl CVQuery for CVQueryPostgres {
             .map_err(|e| CVQueryError::DatabaseError(e.to_string()))?;
 
         Ok(CVPageResult {
-            items: models.into_iter().map(|m| m.to_domain()).collect(),
+            items: models
+                .iter()
+                .map(|m| self.to_domain(m))
+                .collect::<Result<_, _>>()?,
             page: page.page,
             per_page: page.per_page,
             total,
@@ -111,11 +146,12 @@ impl CVQuery for CVQueryPostgres {
     async fn fetch_cv_by_id(&self, cv_id: Uuid) -> Result<Option<CVInfo>, CVQueryError> {
         let model: Option<ResumeModel> = ResumeEntity::find_by_id(cv_id)
             .filter(ResumeColumn::IsDeleted.eq(false))
+            .filter(Expr::cust(sql::owner_is_live("resumes.user_id")))
             .one(&*self.db)
             .await
             .map_err(|err| CVQueryError::DatabaseError(err.to_string()))?;
 
-        Ok(model.map(|m| m.to_domain()))
+        model.map(|m| self.to_domain(&m)).transpose()
     }
 }
 
@@ -2,24 +2,58 @@ use crate::cv::application::ports::outgoing::{
     CVRepository, CVRepositoryError, CreateCVData, UpdateCVData,
 };
 use crate::cv::domain::entities::CVInfo;
+use crate::shared::field_cipher::{FieldCipher, FieldCipherError};
 use async_trait::async_trait;
-use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
+use chrono::{DateTime, Utc};
+use sea_orm::{ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QuerySelect, Set};
 use std::sync::Arc;
 use uuid::Uuid;
 
 // Bring in the entity we just defined above:
 use super::sea_orm_entity::{
-    ActiveModel as CvActiveModel, Column as CvColumn, Entity as CvEntity, Model as CvModel,
+    seal_contacts, ActiveModel as CvActiveModel, Column as CvColumn, Entity as CvEntity,
+    Model as CvModel,
 };
 
 #[derive(Debug, Clone)]
 pub struct CVRepoPostgres {
     db: Arc<DatabaseConnection>,
+    cipher: FieldCipher,
 }
 
 impl CVRepoPostgres {
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
-        Self { db }
+        Self {
+            db,
+            cipher: FieldCipher::default(),
+        }
+    }
+
+    /// Encrypts contact details on write and decrypts them on read
+    pub fn with_cipher(mut self, cipher: FieldCipher) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
+    fn to_domain(&self, model: &CvModel) -> Result<CVInfo, CVRepositoryError> {
+        model
+            .to_domain_decrypted(&self.cipher)
+            .map_err(Self::map_cipher_err)
+    }
+
+    fn map_cipher_err(e: FieldCipherError) -> CVRepositoryError {
+        CVRepositoryError::DatabaseError(e.to_string())
+    }
+
+    /// Owner of a CV, whose key its contact details are encrypted with
+    async fn owner_of(&self, cv_id: Uuid) -> Result<Option<Uuid>, CVRepositoryError> {
+        CvEntity::find_by_id(cv_id)
+            .select_only()
+            .column(CvColumn::UserId)
+            .into_tuple()
+            .one(&*self.db)
+            .await
+            .map_err(|err| CVRepositoryError::DatabaseError(err.to_string()))
     }
 }
 
@@ -32,7 +66,7 @@ impl CVRepository for CVRepoPostgres {
             .await
             .map_err(|err| CVRepositoryError::DatabaseError(err.to_string()))?;
 
-        Ok(models.into_iter().map(|m| m.to_domain()).collect())
+        models.iter().map(|m| self.to_domain(m)).collect()
     }
 
     async fn fetch_cv_by_id(&self, cv_id: Uuid) -> Result<Option<CVInfo>, CVRepositoryError> {
@@ -41,7 +75,7 @@ impl CVRepository for CVRepoPostgres {
             .await
             .map_err(|err| CVRepositoryError::DatabaseError(err.to_string()))?;
 
-        Ok(model.map(|m| m.to_domain()))
+        model.map(|m| self.to_domain(&m)).transpose()
     }
 
     async fn create_cv(
@@ -49,6 +83,10 @@ impl CVRepository for CVRepoPostgres {
         user_id: Uuid,
         cv_data: CreateCVData,
     ) -> Result<CVInfo, CVRepositoryError> {
+        let cv_data = CreateCVData {
+            contact_info: seal_contacts(&self.cipher, user_id, &cv_data.contact_info),
+            ..cv_data
+        };
         let model = CvModel::from_create_data(user_id, &cv_data);
 
         let active_model: CvActiveModel = model.into();
@@ -58,14 +96,26 @@ impl CVRepository for CVRepoPostgres {
             .await
             .map_err(|err| CVRepositoryError::DatabaseError(err.to_string()))?;
 
-        Ok(inserted.to_domain())
+        self.to_domain(&inserted)
     }
 
     async fn update_cv(
         &self,
         cv_id: Uuid,
         cv_data: UpdateCVData,
+        expected_updated_at: Option<DateTime<Utc>>,
     ) -> Result<CVInfo, CVRepositoryError> {
+        // Only looked up when there is something to encrypt
+        let contact_info = if self.cipher.is_enabled() && !cv_data.contact_info.is_empty() {
+            let owner = self
+                .owner_of(cv_id)
+                .await?
+                .ok_or(CVRepositoryError::NotFound)?;
+            seal_contacts(&self.cipher, owner, &cv_data.contact_info)
+        } else {
+            cv_data.contact_info
+        };
+
         let active_model = CvActiveModel {
             id: Set(cv_id),
             role: Set(cv_data.role),
@@ -76,24 +126,28 @@ impl CVRepository for CVRepoPostgres {
             educations: Set(serde_json::to_value(&cv_data.educations).unwrap()),
             experiences: Set(serde_json::to_value(&cv_data.experiences).unwrap()),
             highlighted_projects: Set(serde_json::to_value(&cv_data.highlighted_projects).unwrap()),
-            contact_info: Set(serde_json::to_value(&cv_data.contact_info).unwrap()), // ← Add this
+            contact_info: Set(serde_json::to_value(&contact_info).unwrap()),
             updated_at: Set(chrono::Utc::now().into()),
             ..Default::default()
         };
 
-        let updated = active_model.update(&*self.db).await.map_err(|err| {
-            let err_msg = err.to_string();
-            // Check if it's a "record not found" error
-            if err_msg.contains("None of the records are updated")
-                || err_msg.contains("RecordNotUpdated")
-            {
-                CVRepositoryError::NotFound
-            } else {
-                CVRepositoryError::DatabaseError(err_msg)
-            }
-        })?;
+        let mut update = CvEntity::update(active_model);
+        if let Some(expected) = expected_updated_at {
+            update = update.filter(CvColumn::UpdatedAt.lte(expected));
+        }
 
-        Ok(updated.to_domain())
+        match update.exec(&*self.db).await {
+            Ok(updated) => self.to_domain(&updated),
+            // Nothing matched: either no such CV, or it moved past `expected`
+            Err(DbErr::RecordNotUpdated) if expected_updated_at.is_some() => {
+                match self.fetch_cv_by_id(cv_id).await? {
+                    Some(_) => Err(CVRepositoryError::Stale),
+                    None => Err(CVRepositoryError::NotFound),
+                }
+            }
+            Err(DbErr::RecordNotUpdated) => Err(CVRepositoryError::NotFound),
+            Err(err) => Err(CVRepositoryError::DatabaseError(err.to_string())),
+        }
     }
 }
 
@@ -103,6 +157,7 @@ mod tests {
     use crate::cv::domain::entities::{
         ContactDetail, ContactType, CoreSkill, Education, Experience, HighlightedProject,
     };
+    use crate::shared::field_cipher::FieldKey;
     use chrono::Utc;
     use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
 
@@ -438,7 +493,7 @@ mod tests {
         let repo = CVRepoPostgres::new(Arc::new(db));
 
         // Act
-        let result = repo.update_cv(cv_id, updated_cv_data).await;
+        let result = repo.update_cv(cv_id, updated_cv_data, None).await;
 
         // Assert
         assert!(
@@ -485,7 +540,7 @@ mod tests {
         let repo = CVRepoPostgres::new(Arc::new(db));
 
         // Act
-        let result = repo.update_cv(cv_id, cv_data).await;
+        let result = repo.update_cv(cv_id, cv_data, None).await;
 
         // Assert
         assert!(
@@ -495,6 +550,85 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_update_cv_stale_when_changed_since_expected() {
+        let user_id = Uuid::new_v4();
+        let current = create_test_cv_model(user_id);
+        let cv_data = UpdateCVData {
+            bio: "Updated bio".to_string(),
+            role: "Updated role".to_string(),
+            display_name: "Robin Hood".to_string(),
+            photo_url: "https://example.com/updated.jpg".to_string(),
+            core_skills: vec![],
+            educations: vec![],
+            experiences: vec![],
+            highlighted_projects: vec![],
+            contact_info: vec![],
+        };
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            // The guarded UPDATE matches nothing; the CV is still there
+            .append_query_results(vec![Vec::<CvModel>::new()])
+            .append_query_results(vec![vec![current.clone()]])
+            .into_connection();
+
+        let repo = CVRepoPostgres::new(Arc::new(db));
+
+        let result = repo
+            .update_cv(
+                current.id,
+                cv_data,
+                Some(current.updated_at.with_timezone(&Utc) - chrono::Duration::seconds(5)),
+            )
+            .await;
+
+        assert!(
+            matches!(result, Err(CVRepositoryError::Stale)),
+            "Expected Stale error, got {:?}",
+            result
+        );
+    }
+
+    #[tokio::test]
+    async fn test_contact_details_are_encrypted_at_rest() {
+        let user_id = Uuid::new_v4();
+        let cipher = FieldCipher::new(vec![FieldKey::new("k1", [7; 32]).unwrap()]);
+        let mut stored = create_test_cv_model(user_id);
+        let contacts: Vec<ContactDetail> =
+            serde_json::from_value(stored.contact_info.clone()).unwrap();
+        stored.contact_info =
+            serde_json::to_value(seal_contacts(&cipher, user_id, &contacts)).unwrap();
+
+        let db = Arc::new(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_query_results(vec![vec![stored]])
+                .into_connection(),
+        );
+        let repo = CVRepoPostgres::new(Arc::clone(&db)).with_cipher(cipher);
+
+        let cv_data = CreateCVData {
+            bio: "Test bio".to_string(),
+            role: "Test role".to_string(),
+            display_name: "Robin Hood".to_string(),
+            photo_url: "https://example.com/photo.jpg".to_string(),
+            core_skills: vec![],
+            educations: vec![],
+            experiences: vec![],
+            highlighted_projects: vec![],
+            contact_info: contacts,
+        };
+        let created = repo.create_cv(user_id, cv_data).await.unwrap();
+        assert_eq!(created.contact_info[0].content, "0876352718");
+        drop(repo);
+
+        let log = Arc::try_unwrap(db)
+            .expect("no other connection handles")
+            .into_transaction_log();
+        let log = format!("{log:?}");
+        assert!(log.contains("enc:v1:k1:"));
+        assert!(!log.contains("0876352718"));
+    }
+
     #[test]
     fn test_instance_can_be_cloned() {
         // Arrange
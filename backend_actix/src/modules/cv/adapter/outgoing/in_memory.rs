@@ -0,0 +1,336 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::cv::application::ports::outgoing::{
+    CVArchiver, CVArchiverError, CVListFilter, CVPageRequest, CVPageResult, CVQuery, CVQueryError,
+    CVRepository, CVRepositoryError, CVSort, CreateCVData, UpdateCVData,
+};
+use crate::cv::domain::entities::CVInfo;
+use crate::shared::in_memory::Table;
+
+pub(crate) struct CvRow {
+    pub(crate) cv: CVInfo,
+    pub(crate) created_at: DateTime<Utc>,
+    /// Set while the CV is in the trash
+    pub(crate) deleted_at: Option<DateTime<Utc>>,
+}
+
+impl CvRow {
+    fn is_live(&self) -> bool {
+        self.deleted_at.is_none()
+    }
+
+    /// Case-insensitive substring match over the fields the Postgres search
+    /// covers; the JSON sections are matched on their serialized text, like
+    /// the `CAST(... AS TEXT) ILIKE` there
+    fn matches(&self, term: &str) -> bool {
+        let term = term.to_lowercase();
+        let contains = |text: String| text.to_lowercase().contains(&term);
+
+        contains(self.cv.display_name.clone())
+            || contains(self.cv.role.clone())
+            || contains(json_text(&self.cv.core_skills))
+            || contains(json_text(&self.cv.educations))
+            || contains(json_text(&self.cv.experiences))
+            || contains(json_text(&self.cv.contact_info))
+    }
+}
+
+fn json_text<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+/// Process-local CVs, implementing `CVRepository`, `CVQuery` and
+/// `CVArchiver` over the same rows.
+#[derive(Clone, Default)]
+pub struct InMemoryCvStore {
+    pub(crate) cvs: Table<CvRow>,
+}
+
+fn build_cv(id: Uuid, user_id: Uuid, data: CreateCVData, updated_at: DateTime<Utc>) -> CVInfo {
+    CVInfo {
+        id,
+        user_id,
+        role: data.role,
+        display_name: data.display_name,
+        bio: data.bio,
+        photo_url: data.photo_url,
+        core_skills: data.core_skills,
+        educations: data.educations,
+        experiences: data.experiences,
+        highlighted_projects: data.highlighted_projects,
+        contact_info: data.contact_info,
+        updated_at,
+    }
+}
+
+#[async_trait]
+impl CVRepository for InMemoryCvStore {
+    /// Trashed CVs included, as in Postgres
+    async fn fetch_cv_by_user_id(&self, user_id: Uuid) -> Result<Vec<CVInfo>, CVRepositoryError> {
+        Ok(self.cvs.read(|cvs| {
+            cvs.iter()
+                .filter(|row| row.cv.user_id == user_id)
+                .map(|row| row.cv.clone())
+                .collect()
+        }))
+    }
+
+    async fn fetch_cv_by_id(&self, cv_id: Uuid) -> Result<Option<CVInfo>, CVRepositoryError> {
+        Ok(self.cvs.read(|cvs| {
+            cvs.iter()
+                .find(|row| row.cv.id == cv_id)
+                .map(|row| row.cv.clone())
+        }))
+    }
+
+    async fn create_cv(
+        &self,
+        user_id: Uuid,
+        cv_data: CreateCVData,
+    ) -> Result<CVInfo, CVRepositoryError> {
+        let now = Utc::now();
+        let cv = build_cv(Uuid::new_v4(), user_id, cv_data, now);
+
+        self.cvs.write(|cvs| {
+            cvs.push(CvRow {
+                cv: cv.clone(),
+                created_at: now,
+                deleted_at: None,
+            })
+        });
+        Ok(cv)
+    }
+
+    async fn update_cv(
+        &self,
+        cv_id: Uuid,
+        cv_data: UpdateCVData,
+        expected_updated_at: Option<DateTime<Utc>>,
+    ) -> Result<CVInfo, CVRepositoryError> {
+        self.cvs.write(|cvs| {
+            let row = cvs
+                .iter_mut()
+                .find(|row| row.cv.id == cv_id)
+                .ok_or(CVRepositoryError::NotFound)?;
+            if expected_updated_at.is_some_and(|expected| row.cv.updated_at > expected) {
+                return Err(CVRepositoryError::Stale);
+            }
+            row.cv = build_cv(cv_id, row.cv.user_id, cv_data, Utc::now());
+            Ok(row.cv.clone())
+        })
+    }
+}
+
+#[async_trait]
+impl CVQuery for InMemoryCvStore {
+    async fn list(
+        &self,
+        user_id: Uuid,
+        filter: CVListFilter,
+        sort: CVSort,
+        page: CVPageRequest,
+    ) -> Result<CVPageResult<CVInfo>, CVQueryError> {
+        let term = filter
+            .search
+            .as_deref()
+            .map(str::trim)
+            .filter(|term| !term.is_empty());
+
+        let (items, total) = self.cvs.read(|cvs| {
+            let mut matching: Vec<&CvRow> = cvs
+                .iter()
+                .filter(|row| row.cv.user_id == user_id && row.is_live())
+                .filter(|row| term.is_none_or(|term| row.matches(term)))
+                .collect();
+
+            match sort {
+                CVSort::Newest => matching.sort_by_key(|row| std::cmp::Reverse(row.created_at)),
+                CVSort::Oldest => matching.sort_by_key(|row| row.created_at),
+                CVSort::UpdatedNewest => {
+                    matching.sort_by_key(|row| std::cmp::Reverse(row.cv.updated_at))
+                }
+                CVSort::UpdatedOldest => matching.sort_by_key(|row| row.cv.updated_at),
+            }
+
+            let offset = (page.page.saturating_sub(1) * page.per_page) as usize;
+            let items: Vec<CVInfo> = matching
+                .iter()
+                .skip(offset)
+                .take(page.per_page as usize)
+                .map(|row| row.cv.clone())
+                .collect();
+            (items, matching.len() as u64)
+        });
+
+        Ok(CVPageResult {
+            items,
+            page: page.page,
+            per_page: page.per_page,
+            total,
+        })
+    }
+
+    async fn fetch_cv_by_id(&self, cv_id: Uuid) -> Result<Option<CVInfo>, CVQueryError> {
+        Ok(self.cvs.read(|cvs| {
+            cvs.iter()
+                .find(|row| row.cv.id == cv_id && row.is_live())
+                .map(|row| row.cv.clone())
+        }))
+    }
+}
+
+#[async_trait]
+impl CVArchiver for InMemoryCvStore {
+    async fn soft_delete(&self, cv_id: Uuid) -> Result<(), CVArchiverError> {
+        self.cvs.write(|cvs| {
+            let row = cvs
+                .iter_mut()
+                .find(|row| row.cv.id == cv_id)
+                .ok_or(CVArchiverError::NotFound)?;
+            if !row.is_live() {
+                return Err(CVArchiverError::AlreadyArchived);
+            }
+            let now = Utc::now();
+            row.deleted_at = Some(now);
+            row.cv.updated_at = now;
+            Ok(())
+        })
+    }
+
+    async fn hard_delete(&self, cv_id: Uuid) -> Result<(), CVArchiverError> {
+        self.cvs.write(|cvs| {
+            let before = cvs.len();
+            cvs.retain(|row| row.cv.id != cv_id);
+            if cvs.len() == before {
+                return Err(CVArchiverError::NotFound);
+            }
+            Ok(())
+        })
+    }
+
+    async fn restore(&self, cv_id: Uuid) -> Result<CVInfo, CVArchiverError> {
+        self.cvs.write(|cvs| {
+            let row = cvs
+                .iter_mut()
+                .find(|row| row.cv.id == cv_id)
+                .ok_or(CVArchiverError::NotFound)?;
+            if row.is_live() {
+                return Err(CVArchiverError::NotArchived);
+            }
+            row.deleted_at = None;
+            row.cv.updated_at = Utc::now();
+            Ok(row.cv.clone())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cv::domain::entities::CoreSkill;
+
+    fn cv(display_name: &str, skill: &str) -> CreateCVData {
+        CreateCVData {
+            role: "Engineer".to_string(),
+            bio: "Bio".to_string(),
+            display_name: display_name.to_string(),
+            photo_url: "https://example.com/me.png".to_string(),
+            core_skills: vec![CoreSkill {
+                title: skill.to_string(),
+                description: String::new(),
+            }],
+            educations: vec![],
+            experiences: vec![],
+            highlighted_projects: vec![],
+            contact_info: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_searches_sections_and_hides_trashed() {
+        let store = InMemoryCvStore::default();
+        let user_id = Uuid::new_v4();
+        let rust = store.create_cv(user_id, cv("Jane", "Rust")).await.unwrap();
+        let go = store.create_cv(user_id, cv("Jane", "Go")).await.unwrap();
+
+        let search = CVListFilter {
+            search: Some("rust".to_string()),
+        };
+        let page = store
+            .list(user_id, search, CVSort::default(), CVPageRequest::default())
+            .await
+            .unwrap();
+        assert_eq!(page.total, 1);
+        assert_eq!(page.items[0].id, rust.id);
+
+        store.soft_delete(go.id).await.unwrap();
+        let page = store
+            .list(
+                user_id,
+                CVListFilter::default(),
+                CVSort::default(),
+                CVPageRequest::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(page.total, 1);
+    }
+
+    #[tokio::test]
+    async fn test_archiver_reports_state_errors() {
+        let store = InMemoryCvStore::default();
+        let created = store
+            .create_cv(Uuid::new_v4(), cv("Jane", "Rust"))
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            store.restore(created.id).await,
+            Err(CVArchiverError::NotArchived)
+        ));
+        store.soft_delete(created.id).await.unwrap();
+        assert!(matches!(
+            store.soft_delete(created.id).await,
+            Err(CVArchiverError::AlreadyArchived)
+        ));
+        assert!(CVQuery::fetch_cv_by_id(&store, created.id)
+            .await
+            .unwrap()
+            .is_none());
+
+        store.restore(created.id).await.unwrap();
+        store.hard_delete(created.id).await.unwrap();
+        assert!(matches!(
+            store.hard_delete(created.id).await,
+            Err(CVArchiverError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_rejects_writes_made_against_an_old_version() {
+        let store = InMemoryCvStore::default();
+        let created = store
+            .create_cv(Uuid::new_v4(), cv("Jane", "Rust"))
+            .await
+            .unwrap();
+
+        // Another device saves first; its write was made against `created`
+        let first = store
+            .update_cv(created.id, cv("Jane", "Go"), Some(created.updated_at))
+            .await
+            .unwrap();
+        assert!(matches!(
+            store
+                .update_cv(created.id, cv("Jane", "Zig"), Some(created.updated_at))
+                .await,
+            Err(CVRepositoryError::Stale)
+        ));
+
+        store
+            .update_cv(created.id, cv("Jane", "Zig"), Some(first.updated_at))
+            .await
+            .unwrap();
+    }
+}
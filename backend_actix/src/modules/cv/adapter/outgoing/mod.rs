@@ -1,9 +1,16 @@
 // pub(in crate::modules::cv::adapter::outgoing) mod datasource;
 pub(crate) mod cv_repo_postgres;
 mod sea_orm_entity;
+pub(crate) use sea_orm_entity::open_contacts;
 
 mod cv_query_postgres;
 pub use cv_query_postgres::CVQueryPostgres;
 
 mod cv_archiver_postgres;
 pub use cv_archiver_postgres::CVArchiverPostgres;
+
+mod in_memory;
+pub use in_memory::InMemoryCvStore;
+
+mod plain_text_renderer;
+pub use plain_text_renderer::PlainTextCvRenderer;
@@ -0,0 +1,244 @@
+use crate::cv::application::ports::outgoing::CvRenderer;
+use crate::cv::domain::entities::CVInfo;
+
+/// Renders a CV as plain text for applicant tracking systems
+///
+/// One section per heading, entries separated by blank lines and `-` bullets;
+/// no tables, columns or non-ASCII decoration that parsers tend to mangle.
+/// Empty sections are left out.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlainTextCvRenderer;
+
+impl CvRenderer for PlainTextCvRenderer {
+    fn content_type(&self) -> &'static str {
+        "text/plain; charset=utf-8"
+    }
+
+    fn extension(&self) -> &'static str {
+        "txt"
+    }
+
+    fn render(&self, cv: &CVInfo) -> String {
+        let mut sections = Vec::new();
+
+        let mut header = vec![one_line(&cv.display_name).to_uppercase()];
+        if !cv.role.trim().is_empty() {
+            header.push(one_line(&cv.role));
+        }
+        sections.push(header.join("\n"));
+
+        let contacts: Vec<String> = cv
+            .contact_info
+            .iter()
+            .filter(|contact| !contact.content.trim().is_empty())
+            .map(|contact| bullet(&labelled(&contact.title, &contact.content)))
+            .collect();
+        push_section(&mut sections, "CONTACT", contacts);
+
+        if !cv.bio.trim().is_empty() {
+            push_section(&mut sections, "SUMMARY", vec![paragraph(&cv.bio)]);
+        }
+
+        let skills: Vec<String> = cv
+            .core_skills
+            .iter()
+            .map(|skill| bullet(&labelled(&skill.title, &skill.description)))
+            .collect();
+        push_section(&mut sections, "CORE SKILLS", skills);
+
+        let experiences: Vec<String> = cv
+            .experiences
+            .iter()
+            .map(|exp| {
+                let mut lines = vec![join_non_empty(&[&exp.position, &exp.company], ", ")];
+                let end = exp.end_date.as_deref().unwrap_or("Present");
+                let period = format!("{} - {}", one_line(&exp.start_date), one_line(end));
+                lines.push(join_non_empty(&[&exp.location, &period], " | "));
+                if !exp.description.trim().is_empty() {
+                    lines.push(paragraph(&exp.description));
+                }
+                lines.extend(
+                    exp.tasks
+                        .iter()
+                        .chain(&exp.achievements)
+                        .filter(|item| !item.trim().is_empty())
+                        .map(|item| bullet(item)),
+                );
+                lines.join("\n")
+            })
+            .collect();
+        push_entries(&mut sections, "EXPERIENCE", experiences);
+
+        let educations: Vec<String> = cv
+            .educations
+            .iter()
+            .map(|edu| {
+                let year = edu.graduation_year.to_string();
+                bullet(&join_non_empty(
+                    &[&edu.degree, &edu.institution, &year],
+                    ", ",
+                ))
+            })
+            .collect();
+        push_section(&mut sections, "EDUCATION", educations);
+
+        let projects: Vec<String> = cv
+            .highlighted_projects
+            .iter()
+            .map(|project| bullet(&labelled(&project.title, &project.short_description)))
+            .collect();
+        push_section(&mut sections, "PROJECTS", projects);
+
+        let mut text = sections.join("\n\n");
+        text.push('\n');
+        text
+    }
+}
+
+/// Heading followed by one line per item
+fn push_section(sections: &mut Vec<String>, heading: &str, lines: Vec<String>) {
+    if !lines.is_empty() {
+        sections.push(format!("{}\n{}", heading, lines.join("\n")));
+    }
+}
+
+/// Heading followed by multi-line entries, a blank line between them
+fn push_entries(sections: &mut Vec<String>, heading: &str, entries: Vec<String>) {
+    if !entries.is_empty() {
+        sections.push(format!("{}\n\n{}", heading, entries.join("\n\n")));
+    }
+}
+
+fn bullet(text: &str) -> String {
+    format!("- {}", one_line(text))
+}
+
+/// `Title: text`, or whichever of the two is set
+fn labelled(title: &str, text: &str) -> String {
+    join_non_empty(&[title, text], ": ")
+}
+
+fn join_non_empty(parts: &[&str], separator: &str) -> String {
+    parts
+        .iter()
+        .map(|part| one_line(part))
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Collapses line breaks and runs of whitespace, so entries stay on one line
+fn one_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Keeps the author's line breaks but drops blank and trailing-space lines
+fn paragraph(text: &str) -> String {
+    text.lines()
+        .map(one_line)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cv::domain::entities::{
+        ContactDetail, ContactType, CoreSkill, Education, Experience, HighlightedProject,
+    };
+    use uuid::Uuid;
+
+    fn cv() -> CVInfo {
+        CVInfo {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            role: "Backend Engineer".to_string(),
+            display_name: "Jane Doe".to_string(),
+            bio: "Builds APIs.\r\n\r\nLikes   Rust.".to_string(),
+            photo_url: "https://example.com/jane.png".to_string(),
+            core_skills: vec![CoreSkill {
+                title: "Rust".to_string(),
+                description: "Actix, SeaORM".to_string(),
+            }],
+            educations: vec![Education {
+                degree: "BSc Computer Science".to_string(),
+                institution: "State University".to_string(),
+                graduation_year: 2015,
+            }],
+            experiences: vec![Experience {
+                company: "Acme".to_string(),
+                position: "Senior Engineer".to_string(),
+                location: "Remote".to_string(),
+                start_date: "2020-01".to_string(),
+                end_date: None,
+                description: "Payments team.".to_string(),
+                tasks: vec!["Designed the ledger".to_string()],
+                achievements: vec!["Cut p99 latency\nby 40%".to_string()],
+            }],
+            highlighted_projects: vec![HighlightedProject {
+                id: "p1".to_string(),
+                title: "Blog CMS".to_string(),
+                slug: "blog-cms".to_string(),
+                short_description: "Headless CMS".to_string(),
+            }],
+            contact_info: vec![ContactDetail {
+                contact_type: ContactType::WebPage,
+                title: "Website".to_string(),
+                content: "https://jane.dev".to_string(),
+            }],
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_renders_sections_in_order_with_bullets() {
+        let text = PlainTextCvRenderer.render(&cv());
+
+        let expected = "\
+JANE DOE
+Backend Engineer
+
+CONTACT
+- Website: https://jane.dev
+
+SUMMARY
+Builds APIs.
+Likes Rust.
+
+CORE SKILLS
+- Rust: Actix, SeaORM
+
+EXPERIENCE
+
+Senior Engineer, Acme
+Remote | 2020-01 - Present
+Payments team.
+- Designed the ledger
+- Cut p99 latency by 40%
+
+EDUCATION
+- BSc Computer Science, State University, 2015
+
+PROJECTS
+- Blog CMS: Headless CMS
+";
+        assert_eq!(text, expected);
+    }
+
+    #[test]
+    fn test_empty_sections_are_left_out() {
+        let mut cv = cv();
+        cv.bio = "  ".to_string();
+        cv.core_skills.clear();
+        cv.experiences.clear();
+        cv.educations.clear();
+        cv.highlighted_projects.clear();
+        cv.contact_info.clear();
+
+        let text = PlainTextCvRenderer.render(&cv);
+
+        assert_eq!(text, "JANE DOE\nBackend Engineer\n");
+        assert!(!text.contains('\r'));
+    }
+}
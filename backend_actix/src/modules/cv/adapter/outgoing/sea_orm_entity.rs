@@ -1,5 +1,6 @@
 use crate::cv::application::ports::outgoing::CreateCVData;
-use crate::cv::domain::entities::CVInfo;
+use crate::cv::domain::entities::{CVInfo, ContactDetail};
+use crate::shared::field_cipher::{FieldCipher, FieldCipherError};
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 use serde_json;
@@ -52,8 +53,17 @@ impl Model {
             highlighted_projects: serde_json::from_value(self.highlighted_projects.clone())
                 .unwrap_or_default(),
             contact_info: serde_json::from_value(self.contact_info.clone()).unwrap_or_default(),
+            updated_at: self.updated_at.with_timezone(&chrono::Utc),
         }
     }
+
+    /// `to_domain`, with the contact details decrypted
+    pub fn to_domain_decrypted(&self, cipher: &FieldCipher) -> Result<CVInfo, FieldCipherError> {
+        let mut cv = self.to_domain();
+        cv.contact_info = open_contacts(cipher, self.user_id, cv.contact_info)?;
+        Ok(cv)
+    }
+
     pub fn from_create_data(user_id: Uuid, cv: &CreateCVData) -> Self {
         Self {
             id: Uuid::new_v4(),
@@ -74,6 +84,38 @@ impl Model {
     }
 }
 
+/// Contact details as stored: the content of each encrypted for its owner
+pub fn seal_contacts(
+    cipher: &FieldCipher,
+    user_id: Uuid,
+    contacts: &[ContactDetail],
+) -> Vec<ContactDetail> {
+    contacts
+        .iter()
+        .map(|contact| ContactDetail {
+            content: cipher.encrypt(user_id, &contact.content),
+            ..contact.clone()
+        })
+        .collect()
+}
+
+/// Stored contact details back as they were written
+pub fn open_contacts(
+    cipher: &FieldCipher,
+    user_id: Uuid,
+    contacts: Vec<ContactDetail>,
+) -> Result<Vec<ContactDetail>, FieldCipherError> {
+    contacts
+        .into_iter()
+        .map(|contact| {
+            Ok(ContactDetail {
+                content: cipher.decrypt(user_id, &contact.content)?,
+                ..contact
+            })
+        })
+        .collect()
+}
+
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {}
 
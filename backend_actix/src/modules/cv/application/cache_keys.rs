@@ -0,0 +1,8 @@
+//! Cache keys for public CV reads (see `shared::cache`).
+
+use uuid::Uuid;
+
+/// A single CV as served by the public route
+pub fn public_cv(cv_id: Uuid) -> String {
+    format!("cache:cv:{cv_id}")
+}
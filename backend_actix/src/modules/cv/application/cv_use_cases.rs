@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use crate::cv::application::ports::outgoing::{CVArchiver, CVQuery, CVRepository, CvRenderer};
+use crate::cv::application::services::{GetPublicSingleCvService, HardDeleteCvService};
+use crate::cv::application::use_cases::{
+    create_cv::{CreateCVUseCase, ICreateCVUseCase},
+    export_cv::{ExportCvUseCase, IExportCvUseCase},
+    fetch_cv_by_id::{FetchCVByIdUseCase, IFetchCVByIdUseCase},
+    fetch_user_cvs::{FetchCVService, IFetchCVUseCase},
+    get_public_single_cv::GetPublicSingleCvUseCase,
+    hard_delete_cv::HardDeleteCvUseCase,
+    patch_cv::{IPatchCVUseCase, PatchCVUseCase},
+    update_cv::{IUpdateCVUseCase, UpdateCVUseCase},
+};
+use crate::shared::cache::CachePort;
+use crate::shared::metrics::Metered;
+
+#[derive(Clone)]
+pub struct CvUseCases {
+    pub fetch_list: Arc<dyn IFetchCVUseCase + Send + Sync>,
+    pub fetch_by_id: Arc<dyn IFetchCVByIdUseCase + Send + Sync>,
+    pub export_text: Arc<dyn IExportCvUseCase + Send + Sync>,
+    pub get_public_single: Arc<dyn GetPublicSingleCvUseCase + Send + Sync>,
+    pub create: Arc<dyn ICreateCVUseCase + Send + Sync>,
+    pub update: Arc<dyn IUpdateCVUseCase + Send + Sync>,
+    pub patch: Arc<dyn IPatchCVUseCase + Send + Sync>,
+    pub hard_delete: Arc<dyn HardDeleteCvUseCase + Send + Sync>,
+}
+
+impl CvUseCases {
+    pub fn build<R, Q, A>(
+        repository: R,
+        query: Q,
+        archiver: A,
+        renderer: Arc<dyn CvRenderer>,
+        cache: Arc<dyn CachePort>,
+    ) -> Self
+    where
+        R: CVRepository + Clone + 'static,
+        Q: CVQuery + Clone + 'static,
+        A: CVArchiver + 'static,
+    {
+        let fetch_by_id: Arc<dyn IFetchCVByIdUseCase + Send + Sync> =
+            Arc::new(Metered(FetchCVByIdUseCase::new(repository.clone())));
+
+        Self {
+            fetch_list: Arc::new(Metered(FetchCVService::new(query.clone()))),
+            export_text: Arc::new(Metered(ExportCvUseCase::new(
+                Arc::clone(&fetch_by_id),
+                renderer,
+            ))),
+            fetch_by_id,
+            get_public_single: Arc::new(Metered(GetPublicSingleCvService::new(
+                query,
+                Arc::clone(&cache),
+            ))),
+            create: Arc::new(Metered(CreateCVUseCase::new(repository.clone()))),
+            update: Arc::new(Metered(UpdateCVUseCase::new(
+                repository.clone(),
+                Arc::clone(&cache),
+            ))),
+            patch: Arc::new(Metered(PatchCVUseCase::new(
+                repository.clone(),
+                Arc::clone(&cache),
+            ))),
+            hard_delete: Arc::new(Metered(HardDeleteCvService::new(
+                archiver, repository, cache,
+            ))),
+        }
+    }
+}
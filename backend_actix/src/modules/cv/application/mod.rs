@@ -1,3 +1,5 @@
+pub mod cache_keys;
+pub mod cv_use_cases;
 pub mod ports;
 pub mod services;
 pub mod use_cases;
@@ -1,8 +1,10 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::cv::domain::entities::CVInfo;
+use crate::shared::faults::faulty_repository;
 
 //
 // ──────────────────────────────────────────────────────────
@@ -44,7 +46,7 @@ impl Default for CVPageRequest {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CVPageResult<T> {
     pub items: Vec<T>,
     pub page: u32,
@@ -85,3 +87,16 @@ pub trait CVQuery: Send + Sync {
 
     async fn fetch_cv_by_id(&self, cv_id: Uuid) -> Result<Option<CVInfo>, CVQueryError>;
 }
+
+faulty_repository! {
+    CVQuery,
+    CVQueryError::DatabaseError,
+    fn list(
+        &self,
+        user_id: Uuid,
+        filter: CVListFilter,
+        sort: CVSort,
+        page: CVPageRequest,
+    ) -> Result<CVPageResult<CVInfo>, CVQueryError>;
+    fn fetch_cv_by_id(&self, cv_id: Uuid) -> Result<Option<CVInfo>, CVQueryError>;
+}
@@ -0,0 +1,12 @@
+use crate::cv::domain::entities::CVInfo;
+
+/// Turns a CV into a document to download
+pub trait CvRenderer: Send + Sync {
+    /// `Content-Type` of the rendered documents
+    fn content_type(&self) -> &'static str;
+
+    /// File name extension, without the dot
+    fn extension(&self) -> &'static str;
+
+    fn render(&self, cv: &CVInfo) -> String;
+}
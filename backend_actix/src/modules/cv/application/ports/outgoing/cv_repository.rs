@@ -2,13 +2,17 @@
 use crate::cv::domain::entities::{
     CVInfo, ContactDetail, CoreSkill, Education, Experience, HighlightedProject,
 };
+use crate::shared::faults::faulty_repository;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 #[derive(Debug, Clone)]
 pub enum CVRepositoryError {
     NotFound,
+    /// Changed since the `expected_updated_at` the write was made against
+    Stale,
     DatabaseError(String),
 }
 
@@ -21,10 +25,27 @@ pub trait CVRepository: Send + Sync {
         user_id: Uuid,
         cv_data: CreateCVData,
     ) -> Result<CVInfo, CVRepositoryError>;
+    /// With `expected_updated_at`, fails with `Stale` when the CV was
+    /// updated after it (see `shared::api::precondition`).
     async fn update_cv(
         &self,
         cv_id: Uuid,
         cv_data: UpdateCVData,
+        expected_updated_at: Option<DateTime<Utc>>,
+    ) -> Result<CVInfo, CVRepositoryError>;
+}
+
+faulty_repository! {
+    CVRepository,
+    CVRepositoryError::DatabaseError,
+    fn fetch_cv_by_user_id(&self, user_id: Uuid) -> Result<Vec<CVInfo>, CVRepositoryError>;
+    fn fetch_cv_by_id(&self, cv_id: Uuid) -> Result<Option<CVInfo>, CVRepositoryError>;
+    fn create_cv(&self, user_id: Uuid, cv_data: CreateCVData) -> Result<CVInfo, CVRepositoryError>;
+    fn update_cv(
+        &self,
+        cv_id: Uuid,
+        cv_data: UpdateCVData,
+        expected_updated_at: Option<DateTime<Utc>>,
     ) -> Result<CVInfo, CVRepositoryError>;
 }
 
@@ -7,3 +7,6 @@ pub use cv_query::*;
 
 mod cv_archiver;
 pub use cv_archiver::{CVArchiver, CVArchiverError};
+
+mod cv_renderer;
+pub use cv_renderer::CvRenderer;
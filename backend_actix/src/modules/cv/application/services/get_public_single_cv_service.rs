@@ -1,11 +1,14 @@
 use async_trait::async_trait;
+use std::sync::Arc;
 use uuid::Uuid;
 
+use crate::cv::application::cache_keys;
 use crate::cv::application::ports::outgoing::{CVQuery, CVQueryError};
 use crate::cv::application::use_cases::get_public_single_cv::{
     GetPublicSingleCvError, GetPublicSingleCvUseCase,
 };
 use crate::cv::domain::entities::CVInfo;
+use crate::shared::cache::{self, CachePort};
 
 //
 // ──────────────────────────────────────────────────────────
@@ -18,14 +21,15 @@ where
     Q: CVQuery,
 {
     query: Q,
+    cache: Arc<dyn CachePort>,
 }
 
 impl<Q> GetPublicSingleCvService<Q>
 where
     Q: CVQuery,
 {
-    pub fn new(query: Q) -> Self {
-        Self { query }
+    pub fn new(query: Q, cache: Arc<dyn CachePort>) -> Self {
+        Self { query, cache }
     }
 }
 
@@ -35,20 +39,33 @@ where
     Q: CVQuery + Send + Sync,
 {
     async fn execute(&self, owner_id: Uuid, cv_id: Uuid) -> Result<CVInfo, GetPublicSingleCvError> {
-        let cv = self
-            .query
-            .fetch_cv_by_id(cv_id)
-            .await
-            .map_err(|e| match e {
-                CVQueryError::DatabaseError(msg) => GetPublicSingleCvError::RepositoryError(msg),
-                CVQueryError::QueryFailed(msg) => GetPublicSingleCvError::RepositoryError(msg),
-            })?;
-
-        match cv {
-            None => Err(GetPublicSingleCvError::NotFound),
-            Some(cv) if cv.user_id != owner_id => Err(GetPublicSingleCvError::NotFound),
-            Some(cv) => Ok(cv),
+        // Cached by id alone, so the owner check runs on every hit. Misses are
+        // not cached: random ids must not fill the cache.
+        let cv: CVInfo = cache::read_through(
+            self.cache.as_ref(),
+            &cache_keys::public_cv(cv_id),
+            || async {
+                self.query
+                    .fetch_cv_by_id(cv_id)
+                    .await
+                    .map_err(|e| match e {
+                        CVQueryError::DatabaseError(msg) => {
+                            GetPublicSingleCvError::RepositoryError(msg)
+                        }
+                        CVQueryError::QueryFailed(msg) => {
+                            GetPublicSingleCvError::RepositoryError(msg)
+                        }
+                    })?
+                    .ok_or(GetPublicSingleCvError::NotFound)
+            },
+        )
+        .await?;
+
+        if cv.user_id != owner_id {
+            return Err(GetPublicSingleCvError::NotFound);
         }
+
+        Ok(cv)
     }
 }
 
@@ -63,6 +80,8 @@ mod tests {
     use super::*;
     use crate::cv::application::ports::outgoing::{CVListFilter, CVSort};
     use crate::cv::application::ports::outgoing::{CVPageRequest, CVPageResult};
+    use crate::shared::cache::NoopCache;
+    use crate::tests::support::stubs::InMemoryCache;
     use async_trait::async_trait;
 
     #[derive(Clone)]
@@ -116,6 +135,7 @@ mod tests {
             experiences: vec![],
             highlighted_projects: vec![],
             contact_info: vec![],
+            updated_at: chrono::Utc::now(),
         }
     }
 
@@ -129,7 +149,7 @@ mod tests {
         let cv = sample_cv(owner_id);
 
         let query = MockCVQuery::found(cv.clone());
-        let service = GetPublicSingleCvService::new(query);
+        let service = GetPublicSingleCvService::new(query, Arc::new(NoopCache));
 
         let result = service.execute(owner_id, cv.id).await;
 
@@ -147,7 +167,7 @@ mod tests {
         let cv_id = Uuid::new_v4();
 
         let query = MockCVQuery::not_found();
-        let service = GetPublicSingleCvService::new(query);
+        let service = GetPublicSingleCvService::new(query, Arc::new(NoopCache));
 
         let result = service.execute(owner_id, cv_id).await;
 
@@ -161,7 +181,7 @@ mod tests {
         let cv = sample_cv(other_user_id);
 
         let query = MockCVQuery::found(cv);
-        let service = GetPublicSingleCvService::new(query);
+        let service = GetPublicSingleCvService::new(query, Arc::new(NoopCache));
 
         let result = service.execute(owner_id, Uuid::new_v4()).await;
 
@@ -178,7 +198,7 @@ mod tests {
         let cv_id = Uuid::new_v4();
 
         let query = MockCVQuery::error(CVQueryError::DatabaseError("db down".to_string()));
-        let service = GetPublicSingleCvService::new(query);
+        let service = GetPublicSingleCvService::new(query, Arc::new(NoopCache));
 
         let result = service.execute(owner_id, cv_id).await;
 
@@ -194,7 +214,7 @@ mod tests {
         let cv_id = Uuid::new_v4();
 
         let query = MockCVQuery::error(CVQueryError::QueryFailed("query failed".to_string()));
-        let service = GetPublicSingleCvService::new(query);
+        let service = GetPublicSingleCvService::new(query, Arc::new(NoopCache));
 
         let result = service.execute(owner_id, cv_id).await;
 
@@ -203,4 +223,24 @@ mod tests {
             GetPublicSingleCvError::RepositoryError(msg) if msg == "query failed"
         ));
     }
+
+    // =====================================================
+    // Cache
+    // =====================================================
+
+    #[tokio::test]
+    async fn execute_checks_owner_on_cache_hits() {
+        let owner_id = Uuid::new_v4();
+        let cv = sample_cv(owner_id);
+        let cache = InMemoryCache::default();
+
+        let service =
+            GetPublicSingleCvService::new(MockCVQuery::found(cv.clone()), Arc::new(cache.clone()));
+        service.execute(owner_id, cv.id).await.unwrap();
+        assert_eq!(cache.keys(), vec![cache_keys::public_cv(cv.id)]);
+
+        let result = service.execute(Uuid::new_v4(), cv.id).await;
+
+        assert!(matches!(result, Err(GetPublicSingleCvError::NotFound)));
+    }
 }
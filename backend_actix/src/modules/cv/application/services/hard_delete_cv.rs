@@ -1,9 +1,12 @@
 use crate::auth::application::domain::entities::UserId;
+use crate::cv::application::cache_keys;
 use crate::cv::application::ports::outgoing::{
     CVArchiver, CVArchiverError, CVRepository, CVRepositoryError,
 };
 use crate::cv::application::use_cases::hard_delete_cv::{HardDeleteCVError, HardDeleteCvUseCase};
+use crate::shared::cache::{self, CachePort};
 use async_trait::async_trait;
+use std::sync::Arc;
 use uuid::Uuid;
 
 pub struct HardDeleteCvService<A, R>
@@ -13,6 +16,7 @@ where
 {
     cv_archiver: A,
     cv_repository: R,
+    cache: Arc<dyn CachePort>,
 }
 
 impl<A, R> HardDeleteCvService<A, R>
@@ -20,10 +24,11 @@ where
     A: CVArchiver + Send + Sync,
     R: CVRepository + Send + Sync,
 {
-    pub fn new(cv_archiver: A, cv_repository: R) -> Self {
+    pub fn new(cv_archiver: A, cv_repository: R, cache: Arc<dyn CachePort>) -> Self {
         Self {
             cv_archiver,
             cv_repository,
+            cache,
         }
     }
 }
@@ -42,6 +47,9 @@ where
             .await
             .map_err(|e| match e {
                 CVRepositoryError::NotFound => HardDeleteCVError::CVNotFound,
+                CVRepositoryError::Stale => {
+                    HardDeleteCVError::RepositoryError("Unknown repository error".to_string())
+                }
                 CVRepositoryError::DatabaseError(msg) => HardDeleteCVError::RepositoryError(msg),
             })?
             .ok_or(HardDeleteCVError::CVNotFound)?;
@@ -66,6 +74,8 @@ where
                 CVArchiverError::DatabaseError(msg) => HardDeleteCVError::RepositoryError(msg),
             })?;
 
+        cache::invalidate(self.cache.as_ref(), &cache_keys::public_cv(cv_id)).await;
+
         Ok(())
     }
 }
@@ -77,6 +87,7 @@ mod tests {
         CVArchiver, CVArchiverError, CVRepository, CVRepositoryError, CreateCVData, UpdateCVData,
     };
     use crate::cv::domain::entities::CVInfo;
+    use crate::shared::cache::NoopCache;
     use async_trait::async_trait;
     use uuid::Uuid;
 
@@ -128,6 +139,7 @@ mod tests {
             &self,
             _cv_id: Uuid,
             _cv_data: UpdateCVData,
+            _expected_updated_at: Option<chrono::DateTime<chrono::Utc>>,
         ) -> Result<CVInfo, CVRepositoryError> {
             unimplemented!()
         }
@@ -146,6 +158,7 @@ mod tests {
             experiences: vec![],
             highlighted_projects: vec![],
             contact_info: vec![],
+            updated_at: chrono::Utc::now(),
         }
     }
 
@@ -160,7 +173,7 @@ mod tests {
 
         let mock_archiver = MockCVArchiver { result: Ok(()) };
 
-        let service = HardDeleteCvService::new(mock_archiver, mock_repository);
+        let service = HardDeleteCvService::new(mock_archiver, mock_repository, Arc::new(NoopCache));
 
         let result = service.execute(UserId::from(user_id), cv_id).await;
 
@@ -176,7 +189,7 @@ mod tests {
 
         let mock_archiver = MockCVArchiver { result: Ok(()) };
 
-        let service = HardDeleteCvService::new(mock_archiver, mock_repository);
+        let service = HardDeleteCvService::new(mock_archiver, mock_repository, Arc::new(NoopCache));
 
         let result = service.execute(UserId::from(user_id), cv_id).await;
 
@@ -196,7 +209,7 @@ mod tests {
 
         let mock_archiver = MockCVArchiver { result: Ok(()) };
 
-        let service = HardDeleteCvService::new(mock_archiver, mock_repository);
+        let service = HardDeleteCvService::new(mock_archiver, mock_repository, Arc::new(NoopCache));
 
         let result = service.execute(UserId::from(other_user_id), cv_id).await;
 
@@ -218,7 +231,7 @@ mod tests {
 
         let mock_archiver = MockCVArchiver { result: Ok(()) };
 
-        let service = HardDeleteCvService::new(mock_archiver, mock_repository);
+        let service = HardDeleteCvService::new(mock_archiver, mock_repository, Arc::new(NoopCache));
 
         let result = service.execute(UserId::from(user_id), cv_id).await;
 
@@ -239,7 +252,7 @@ mod tests {
 
         let mock_archiver = MockCVArchiver { result: Ok(()) };
 
-        let service = HardDeleteCvService::new(mock_archiver, mock_repository);
+        let service = HardDeleteCvService::new(mock_archiver, mock_repository, Arc::new(NoopCache));
 
         let result = service.execute(UserId::from(user_id), cv_id).await;
 
@@ -265,7 +278,7 @@ mod tests {
             result: Err(CVArchiverError::NotFound),
         };
 
-        let service = HardDeleteCvService::new(mock_archiver, mock_repository);
+        let service = HardDeleteCvService::new(mock_archiver, mock_repository, Arc::new(NoopCache));
 
         let result = service.execute(UserId::from(user_id), cv_id).await;
 
@@ -286,7 +299,7 @@ mod tests {
             result: Err(CVArchiverError::AlreadyArchived),
         };
 
-        let service = HardDeleteCvService::new(mock_archiver, mock_repository);
+        let service = HardDeleteCvService::new(mock_archiver, mock_repository, Arc::new(NoopCache));
 
         let result = service.execute(UserId::from(user_id), cv_id).await;
 
@@ -312,7 +325,7 @@ mod tests {
             result: Err(CVArchiverError::NotArchived),
         };
 
-        let service = HardDeleteCvService::new(mock_archiver, mock_repository);
+        let service = HardDeleteCvService::new(mock_archiver, mock_repository, Arc::new(NoopCache));
 
         let result = service.execute(UserId::from(user_id), cv_id).await;
 
@@ -338,7 +351,7 @@ mod tests {
             result: Err(CVArchiverError::DatabaseError("Delete failed".to_string())),
         };
 
-        let service = HardDeleteCvService::new(mock_archiver, mock_repository);
+        let service = HardDeleteCvService::new(mock_archiver, mock_repository, Arc::new(NoopCache));
 
         let result = service.execute(UserId::from(user_id), cv_id).await;
 
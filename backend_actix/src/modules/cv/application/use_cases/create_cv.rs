@@ -1,5 +1,6 @@
 use crate::cv::application::ports::outgoing::{CVRepository, CVRepositoryError, CreateCVData};
 use crate::cv::domain::entities::CVInfo;
+use crate::shared::metrics::metered_use_case;
 use async_trait::async_trait;
 use std::fmt;
 use uuid::Uuid;
@@ -25,6 +26,13 @@ pub trait ICreateCVUseCase: Send + Sync {
     async fn execute(&self, user_id: Uuid, cv_data: CreateCVData) -> Result<CVInfo, CreateCVError>;
 }
 
+metered_use_case!(
+    "cv",
+    "create_cv",
+    ICreateCVUseCase,
+    fn execute(&self, user_id: Uuid, cv_data: CreateCVData) -> Result<CVInfo, CreateCVError>
+);
+
 /// Concrete implementation of the create CV use case
 pub struct CreateCVUseCase<R>
 where
@@ -102,6 +110,7 @@ mod tests {
                 experiences: vec![],
                 highlighted_projects: vec![],
                 contact_info: vec![],
+                updated_at: chrono::Utc::now(),
             }
         }
     }
@@ -137,6 +146,7 @@ mod tests {
                     experiences: cv_data.experiences,
                     highlighted_projects: cv_data.highlighted_projects,
                     contact_info: cv_data.contact_info,
+                    updated_at: chrono::Utc::now(),
                 }),
                 Err(e) => Err(e.clone()),
             }
@@ -146,6 +156,7 @@ mod tests {
             &self,
             _user_id: Uuid,
             _cv_data: UpdateCVData,
+            _expected_updated_at: Option<chrono::DateTime<chrono::Utc>>,
         ) -> Result<CVInfo, CVRepositoryError> {
             unimplemented!()
         }
@@ -0,0 +1,151 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::cv::application::ports::outgoing::CvRenderer;
+use crate::cv::application::use_cases::fetch_cv_by_id::{FetchCVByIdError, IFetchCVByIdUseCase};
+use crate::shared::metrics::metered_use_case;
+
+/// A rendered CV, ready to download
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportedCv {
+    /// e.g. `jane-doe-cv.txt`
+    pub file_name: String,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+/// Renders one of the user's CVs as a document
+#[async_trait]
+pub trait IExportCvUseCase: Send + Sync {
+    async fn execute(&self, user_id: Uuid, cv_id: Uuid) -> Result<ExportedCv, FetchCVByIdError>;
+}
+
+metered_use_case!(
+    "cv",
+    "export_cv",
+    IExportCvUseCase,
+    fn execute(&self, user_id: Uuid, cv_id: Uuid) -> Result<ExportedCv, FetchCVByIdError>
+);
+
+#[derive(Clone)]
+pub struct ExportCvUseCase {
+    fetch_cv: Arc<dyn IFetchCVByIdUseCase + Send + Sync>,
+    renderer: Arc<dyn CvRenderer>,
+}
+
+impl ExportCvUseCase {
+    pub fn new(
+        fetch_cv: Arc<dyn IFetchCVByIdUseCase + Send + Sync>,
+        renderer: Arc<dyn CvRenderer>,
+    ) -> Self {
+        Self { fetch_cv, renderer }
+    }
+}
+
+#[async_trait]
+impl IExportCvUseCase for ExportCvUseCase {
+    async fn execute(&self, user_id: Uuid, cv_id: Uuid) -> Result<ExportedCv, FetchCVByIdError> {
+        // Ownership is enforced by the fetch
+        let cv = self.fetch_cv.execute(user_id, cv_id).await?;
+
+        Ok(ExportedCv {
+            file_name: format!(
+                "{}.{}",
+                file_stem(&cv.display_name),
+                self.renderer.extension()
+            ),
+            content_type: self.renderer.content_type(),
+            body: self.renderer.render(&cv),
+        })
+    }
+}
+
+/// `Jane Doe` -> `jane-doe-cv`; plain ASCII so the name is safe in a header
+fn file_stem(display_name: &str) -> String {
+    let slug = display_name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join("-");
+
+    if slug.is_empty() {
+        "cv".to_string()
+    } else {
+        format!("{}-cv", slug)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cv::adapter::outgoing::PlainTextCvRenderer;
+    use crate::cv::domain::entities::CVInfo;
+
+    struct MockFetch {
+        result: Result<CVInfo, FetchCVByIdError>,
+    }
+
+    #[async_trait]
+    impl IFetchCVByIdUseCase for MockFetch {
+        async fn execute(&self, _user_id: Uuid, _cv_id: Uuid) -> Result<CVInfo, FetchCVByIdError> {
+            self.result.clone()
+        }
+    }
+
+    fn cv(display_name: &str) -> CVInfo {
+        CVInfo {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            role: "Engineer".to_string(),
+            display_name: display_name.to_string(),
+            bio: String::new(),
+            photo_url: String::new(),
+            core_skills: vec![],
+            educations: vec![],
+            experiences: vec![],
+            highlighted_projects: vec![],
+            contact_info: vec![],
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    fn use_case(result: Result<CVInfo, FetchCVByIdError>) -> ExportCvUseCase {
+        ExportCvUseCase::new(
+            Arc::new(MockFetch { result }),
+            Arc::new(PlainTextCvRenderer),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_export_renders_with_file_name_from_display_name() {
+        let exported = use_case(Ok(cv("Jane Doe")))
+            .execute(Uuid::new_v4(), Uuid::new_v4())
+            .await
+            .unwrap();
+
+        assert_eq!(exported.file_name, "jane-doe-cv.txt");
+        assert_eq!(exported.content_type, "text/plain; charset=utf-8");
+        assert_eq!(exported.body, "JANE DOE\nEngineer\n");
+    }
+
+    #[tokio::test]
+    async fn test_export_passes_not_found_through() {
+        let result = use_case(Err(FetchCVByIdError::CVNotFound))
+            .execute(Uuid::new_v4(), Uuid::new_v4())
+            .await;
+
+        assert!(matches!(result, Err(FetchCVByIdError::CVNotFound)));
+    }
+
+    #[test]
+    fn test_file_stem_drops_unsafe_characters() {
+        assert_eq!(file_stem("Zoë \"O'Neil\" / CV"), "zo-o-neil-cv-cv");
+        assert_eq!(
+            file_stem("Backend   Engineer 2026"),
+            "backend-engineer-2026-cv"
+        );
+        assert_eq!(file_stem("简历"), "cv");
+    }
+}
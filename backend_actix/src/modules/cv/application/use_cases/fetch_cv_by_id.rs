@@ -1,5 +1,6 @@
 use crate::cv::application::ports::outgoing::{CVRepository, CVRepositoryError};
 use crate::cv::domain::entities::CVInfo;
+use crate::shared::metrics::metered_use_case;
 use uuid::Uuid;
 
 #[derive(Debug, Clone)]
@@ -31,6 +32,13 @@ pub trait IFetchCVByIdUseCase: Send + Sync {
     async fn execute(&self, user_id: Uuid, cv_id: Uuid) -> Result<CVInfo, FetchCVByIdError>;
 }
 
+metered_use_case!(
+    "cv",
+    "fetch_cv_by_id",
+    IFetchCVByIdUseCase,
+    fn execute(&self, user_id: Uuid, cv_id: Uuid) -> Result<CVInfo, FetchCVByIdError>
+);
+
 #[async_trait::async_trait]
 impl<R> IFetchCVByIdUseCase for FetchCVByIdUseCase<R>
 where
@@ -104,6 +112,7 @@ mod tests {
             &self,
             _user_id: Uuid,
             _cv_data: crate::cv::application::ports::outgoing::UpdateCVData,
+            _expected_updated_at: Option<chrono::DateTime<chrono::Utc>>,
         ) -> Result<CVInfo, CVRepositoryError> {
             unimplemented!()
         }
@@ -122,6 +131,7 @@ mod tests {
             experiences: vec![],
             highlighted_projects: vec![],
             contact_info: vec![],
+            updated_at: chrono::Utc::now(),
         }
     }
 
@@ -5,6 +5,7 @@ use crate::cv::application::ports::outgoing::{
     CVListFilter, CVPageRequest, CVPageResult, CVQuery, CVQueryError, CVSort,
 };
 use crate::cv::domain::entities::CVInfo;
+use crate::shared::metrics::metered_use_case;
 
 // ============================================================================
 // Errors
@@ -55,6 +56,19 @@ pub trait IFetchCVUseCase: Send + Sync {
     ) -> Result<CVPageResult<CVInfo>, FetchCVError>;
 }
 
+metered_use_case!(
+    "cv",
+    "fetch_cv",
+    IFetchCVUseCase,
+    fn execute(
+        &self,
+        user_id: Uuid,
+        filter: CVListFilter,
+        sort: CVSort,
+        page: CVPageRequest,
+    ) -> Result<CVPageResult<CVInfo>, FetchCVError>
+);
+
 #[async_trait]
 impl<Q> IFetchCVUseCase for FetchCVService<Q>
 where
@@ -134,6 +148,7 @@ mod tests {
                 experiences: vec![],
                 highlighted_projects: vec![],
                 contact_info: vec![],
+                updated_at: chrono::Utc::now(),
             }],
             page: 1,
             per_page: 10,
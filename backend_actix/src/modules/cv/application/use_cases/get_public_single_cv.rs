@@ -3,6 +3,7 @@ use std::fmt;
 use uuid::Uuid;
 
 use crate::cv::domain::entities::CVInfo;
+use crate::shared::metrics::metered_use_case;
 
 //
 // ──────────────────────────────────────────────────────────
@@ -35,3 +36,10 @@ impl fmt::Display for GetPublicSingleCvError {
 pub trait GetPublicSingleCvUseCase: Send + Sync {
     async fn execute(&self, owner_id: Uuid, cv_id: Uuid) -> Result<CVInfo, GetPublicSingleCvError>;
 }
+
+metered_use_case!(
+    "cv",
+    "get_public_single_cv",
+    GetPublicSingleCvUseCase,
+    fn execute(&self, owner_id: Uuid, cv_id: Uuid) -> Result<CVInfo, GetPublicSingleCvError>
+);
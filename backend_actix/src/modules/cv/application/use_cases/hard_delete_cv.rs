@@ -1,6 +1,7 @@
 use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
+use crate::shared::metrics::metered_use_case;
 
 // Unimplemented
 #[derive(Debug, Clone)]
@@ -14,3 +15,10 @@ pub enum HardDeleteCVError {
 pub trait HardDeleteCvUseCase: Send + Sync {
     async fn execute(&self, user_id: UserId, cv_id: Uuid) -> Result<(), HardDeleteCVError>;
 }
+
+metered_use_case!(
+    "cv",
+    "hard_delete_cv",
+    HardDeleteCvUseCase,
+    fn execute(&self, user_id: UserId, cv_id: Uuid) -> Result<(), HardDeleteCVError>
+);
@@ -1,4 +1,5 @@
 pub mod create_cv;
+pub mod export_cv;
 pub mod fetch_cv_by_id;
 pub mod fetch_user_cvs;
 pub mod get_public_single_cv;
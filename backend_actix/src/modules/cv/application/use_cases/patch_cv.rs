@@ -1,12 +1,19 @@
+use crate::cv::application::cache_keys;
 use crate::cv::application::ports::outgoing::{
     CVRepository, CVRepositoryError, PatchCVData, UpdateCVData,
 };
 use crate::cv::domain::entities::CVInfo;
+use crate::shared::cache::{self, CachePort};
+use crate::shared::metrics::metered_use_case;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
 use uuid::Uuid;
 
 #[derive(Debug, Clone)]
 pub enum PatchCVError {
     CVNotFound,
+    /// Updated since `expected_updated_at`
+    Stale,
     RepositoryError(String),
 }
 
@@ -17,17 +24,32 @@ pub trait IPatchCVUseCase: Send + Sync {
         user_id: Uuid,
         cv_id: Uuid,
         data: PatchCVData,
+        expected_updated_at: Option<DateTime<Utc>>,
     ) -> Result<CVInfo, PatchCVError>;
 }
 
-#[derive(Debug, Clone)]
+metered_use_case!(
+    "cv",
+    "patch_cv",
+    IPatchCVUseCase,
+    fn execute(
+        &self,
+        user_id: Uuid,
+        cv_id: Uuid,
+        data: PatchCVData,
+        expected_updated_at: Option<DateTime<Utc>>,
+    ) -> Result<CVInfo, PatchCVError>
+);
+
+#[derive(Clone)]
 pub struct PatchCVUseCase<R: CVRepository> {
     repository: R,
+    cache: Arc<dyn CachePort>,
 }
 
 impl<R: CVRepository> PatchCVUseCase<R> {
-    pub fn new(repository: R) -> Self {
-        Self { repository }
+    pub fn new(repository: R, cache: Arc<dyn CachePort>) -> Self {
+        Self { repository, cache }
     }
 }
 
@@ -41,6 +63,7 @@ where
         user_id: Uuid,
         cv_id: Uuid,
         data: PatchCVData,
+        expected_updated_at: Option<DateTime<Utc>>,
     ) -> Result<CVInfo, PatchCVError> {
         // 1️⃣ Fetch CV by ID
         let cv = self
@@ -78,13 +101,19 @@ where
         };
 
         // 4️⃣ Delegate to existing update logic
-        self.repository
-            .update_cv(cv_id, merged)
+        let updated = self
+            .repository
+            .update_cv(cv_id, merged, expected_updated_at)
             .await
             .map_err(|err| match err {
                 CVRepositoryError::NotFound => PatchCVError::CVNotFound,
+                CVRepositoryError::Stale => PatchCVError::Stale,
                 CVRepositoryError::DatabaseError(msg) => PatchCVError::RepositoryError(msg),
-            })
+            })?;
+
+        cache::invalidate(self.cache.as_ref(), &cache_keys::public_cv(cv_id)).await;
+
+        Ok(updated)
     }
 }
 
@@ -97,6 +126,7 @@ mod patch_tests {
         CVRepository, CVRepositoryError, CreateCVData, PatchCVData,
     };
     use crate::cv::domain::entities::CVInfo;
+    use crate::shared::cache::NoopCache;
     use async_trait::async_trait;
     use tokio;
     use uuid::Uuid;
@@ -130,6 +160,7 @@ mod patch_tests {
             &self,
             cv_id: Uuid,
             cv_data: UpdateCVData,
+            _expected_updated_at: Option<chrono::DateTime<chrono::Utc>>,
         ) -> Result<CVInfo, CVRepositoryError> {
             if self.should_fail_update {
                 return Err(CVRepositoryError::DatabaseError(
@@ -156,6 +187,7 @@ mod patch_tests {
                 experiences: cv_data.experiences,
                 highlighted_projects: cv_data.highlighted_projects,
                 contact_info: cv_data.contact_info,
+                updated_at: chrono::Utc::now(),
             })
         }
 
@@ -188,6 +220,7 @@ mod patch_tests {
             experiences: vec![],
             highlighted_projects: vec![],
             contact_info: vec![],
+            updated_at: chrono::Utc::now(),
         };
 
         let mock_repo = MockCVRepository {
@@ -195,7 +228,7 @@ mod patch_tests {
             should_fail_update: false,
         };
 
-        let use_case = PatchCVUseCase::new(mock_repo);
+        let use_case = PatchCVUseCase::new(mock_repo, Arc::new(NoopCache));
 
         let patch_data = PatchCVData {
             bio: Some("Patched bio".to_string()),
@@ -209,7 +242,7 @@ mod patch_tests {
             contact_info: None,
         };
 
-        let result = use_case.execute(user_id, cv_id, patch_data).await;
+        let result = use_case.execute(user_id, cv_id, patch_data, None).await;
 
         assert!(result.is_ok());
         let updated = result.unwrap();
@@ -236,7 +269,7 @@ mod patch_tests {
             should_fail_update: false,
         };
 
-        let use_case = PatchCVUseCase::new(mock_repo);
+        let use_case = PatchCVUseCase::new(mock_repo, Arc::new(NoopCache));
 
         let patch_data = PatchCVData {
             bio: Some("Patched bio".to_string()),
@@ -250,7 +283,7 @@ mod patch_tests {
             contact_info: None,
         };
 
-        let result = use_case.execute(user_id, cv_id, patch_data).await;
+        let result = use_case.execute(user_id, cv_id, patch_data, None).await;
 
         match result {
             Err(PatchCVError::CVNotFound) => (),
@@ -277,6 +310,7 @@ mod patch_tests {
             experiences: vec![],
             highlighted_projects: vec![],
             contact_info: vec![],
+            updated_at: chrono::Utc::now(),
         };
 
         let mock_repo = MockCVRepository {
@@ -284,7 +318,7 @@ mod patch_tests {
             should_fail_update: false,
         };
 
-        let use_case = PatchCVUseCase::new(mock_repo);
+        let use_case = PatchCVUseCase::new(mock_repo, Arc::new(NoopCache));
 
         let patch_data = PatchCVData {
             bio: Some("Hacked bio".to_string()),
@@ -298,7 +332,9 @@ mod patch_tests {
             contact_info: None,
         };
 
-        let result = use_case.execute(Uuid::new_v4(), cv_id, patch_data).await;
+        let result = use_case
+            .execute(Uuid::new_v4(), cv_id, patch_data, None)
+            .await;
 
         // IMPORTANT: do not leak existence
         match result {
@@ -327,6 +363,7 @@ mod patch_tests {
             experiences: vec![],
             highlighted_projects: vec![],
             contact_info: vec![],
+            updated_at: chrono::Utc::now(),
         };
 
         let mock_repo = MockCVRepository {
@@ -334,7 +371,7 @@ mod patch_tests {
             should_fail_update: true,
         };
 
-        let use_case = PatchCVUseCase::new(mock_repo);
+        let use_case = PatchCVUseCase::new(mock_repo, Arc::new(NoopCache));
 
         let patch_data = PatchCVData {
             bio: Some("Hacked bio".to_string()),
@@ -348,7 +385,7 @@ mod patch_tests {
             contact_info: None,
         };
 
-        let result = use_case.execute(user_id, cv_id, patch_data).await;
+        let result = use_case.execute(user_id, cv_id, patch_data, None).await;
 
         match result {
             Err(PatchCVError::RepositoryError(msg)) => {
@@ -2,6 +2,7 @@
 use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
+use crate::shared::metrics::metered_use_case;
 
 // Unimplemented
 #[derive(Debug, Clone)]
@@ -15,3 +16,10 @@ pub enum SoftDeleteCVError {
 pub trait SoftDeleteCvUseCase: Send + Sync {
     async fn execute(&self, user_id: UserId, cv_id: Uuid) -> Result<(), SoftDeleteCVError>;
 }
+
+metered_use_case!(
+    "cv",
+    "soft_delete_cv",
+    SoftDeleteCvUseCase,
+    fn execute(&self, user_id: UserId, cv_id: Uuid) -> Result<(), SoftDeleteCVError>
+);
@@ -1,11 +1,18 @@
+use crate::cv::application::cache_keys;
 use crate::cv::application::ports::outgoing::{CVRepository, CVRepositoryError, UpdateCVData};
 use crate::cv::domain::entities::CVInfo;
+use crate::shared::cache::{self, CachePort};
+use crate::shared::metrics::metered_use_case;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
 use uuid::Uuid;
 
 #[derive(Debug, Clone)]
 pub enum UpdateCVError {
     CVNotFound,
+    /// Updated since `expected_updated_at`
+    Stale,
     RepositoryError(String),
 }
 
@@ -16,17 +23,32 @@ pub trait IUpdateCVUseCase: Send + Sync {
         user_id: Uuid,
         cv_id: Uuid,
         data: UpdateCVData,
+        expected_updated_at: Option<DateTime<Utc>>,
     ) -> Result<CVInfo, UpdateCVError>;
 }
 
-#[derive(Debug, Clone)]
+metered_use_case!(
+    "cv",
+    "update_cv",
+    IUpdateCVUseCase,
+    fn execute(
+        &self,
+        user_id: Uuid,
+        cv_id: Uuid,
+        data: UpdateCVData,
+        expected_updated_at: Option<DateTime<Utc>>,
+    ) -> Result<CVInfo, UpdateCVError>
+);
+
+#[derive(Clone)]
 pub struct UpdateCVUseCase<R: CVRepository> {
     repository: R,
+    cache: Arc<dyn CachePort>,
 }
 
 impl<R: CVRepository> UpdateCVUseCase<R> {
-    pub fn new(repository: R) -> Self {
-        Self { repository }
+    pub fn new(repository: R, cache: Arc<dyn CachePort>) -> Self {
+        Self { repository, cache }
     }
 }
 
@@ -40,6 +62,7 @@ where
         user_id: Uuid,
         cv_id: Uuid,
         cv_data: UpdateCVData,
+        expected_updated_at: Option<DateTime<Utc>>,
     ) -> Result<CVInfo, UpdateCVError> {
         // 1️⃣ Fetch CV by ID
         let cv = self
@@ -63,13 +86,19 @@ where
         }
 
         // 3️⃣ Perform update
-        self.repository
-            .update_cv(cv_id, cv_data)
+        let updated = self
+            .repository
+            .update_cv(cv_id, cv_data, expected_updated_at)
             .await
             .map_err(|err| match err {
                 CVRepositoryError::NotFound => UpdateCVError::CVNotFound,
+                CVRepositoryError::Stale => UpdateCVError::Stale,
                 CVRepositoryError::DatabaseError(msg) => UpdateCVError::RepositoryError(msg),
-            })
+            })?;
+
+        cache::invalidate(self.cache.as_ref(), &cache_keys::public_cv(cv_id)).await;
+
+        Ok(updated)
     }
 }
 
@@ -78,6 +107,7 @@ mod tests {
     use super::*;
     use crate::cv::application::ports::outgoing::{CVRepository, CVRepositoryError, CreateCVData};
     use crate::cv::domain::entities::CVInfo;
+    use crate::shared::cache::NoopCache;
     use async_trait::async_trait;
     use tokio;
     use uuid::Uuid;
@@ -112,6 +142,7 @@ mod tests {
             &self,
             cv_id: Uuid,
             cv_data: UpdateCVData,
+            _expected_updated_at: Option<chrono::DateTime<chrono::Utc>>,
         ) -> Result<CVInfo, CVRepositoryError> {
             if self.should_fail_update {
                 return Err(CVRepositoryError::DatabaseError(
@@ -138,6 +169,7 @@ mod tests {
                 experiences: cv_data.experiences,
                 highlighted_projects: cv_data.highlighted_projects,
                 contact_info: cv_data.contact_info,
+                updated_at: chrono::Utc::now(),
             })
         }
 
@@ -173,6 +205,7 @@ mod tests {
             experiences: vec![],
             highlighted_projects: vec![],
             contact_info: vec![],
+            updated_at: chrono::Utc::now(),
         };
 
         let mock_repo = MockCVRepository {
@@ -180,7 +213,7 @@ mod tests {
             should_fail_update: false,
             should_fail_create: false,
         };
-        let use_case = UpdateCVUseCase::new(mock_repo);
+        let use_case = UpdateCVUseCase::new(mock_repo, Arc::new(NoopCache));
 
         // Create UpdateCVData (no id field)
         let update_data = UpdateCVData {
@@ -196,7 +229,7 @@ mod tests {
         };
 
         // Act - pass the CV ID (not user ID) and UpdateCVData
-        let result = use_case.execute(user_id, cv_id, update_data).await;
+        let result = use_case.execute(user_id, cv_id, update_data, None).await;
 
         // Assert
         assert!(result.is_ok());
@@ -220,7 +253,7 @@ mod tests {
             should_fail_create: false,
         };
 
-        let use_case = UpdateCVUseCase::new(mock_repo);
+        let use_case = UpdateCVUseCase::new(mock_repo, Arc::new(NoopCache));
 
         let update_data = UpdateCVData {
             role: "Senior Software Engineer".to_string(), // Can also update role
@@ -235,7 +268,7 @@ mod tests {
         };
 
         // Act
-        let result = use_case.execute(user_id, cv_id, update_data).await;
+        let result = use_case.execute(user_id, cv_id, update_data, None).await;
 
         // Assert
         match result {
@@ -263,6 +296,7 @@ mod tests {
             experiences: vec![],
             highlighted_projects: vec![],
             contact_info: vec![],
+            updated_at: chrono::Utc::now(),
         };
 
         let mock_repo = MockCVRepository {
@@ -271,7 +305,7 @@ mod tests {
             should_fail_create: false,
         };
 
-        let use_case = UpdateCVUseCase::new(mock_repo);
+        let use_case = UpdateCVUseCase::new(mock_repo, Arc::new(NoopCache));
 
         let update_data = UpdateCVData {
             role: "Senior Software Engineer".to_string(), // Can also update role
@@ -286,7 +320,7 @@ mod tests {
         };
 
         // Act
-        let result = use_case.execute(user_id, cv_id, update_data).await;
+        let result = use_case.execute(user_id, cv_id, update_data, None).await;
 
         // Assert
         match result {
@@ -1,4 +1,6 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::{
@@ -6,7 +8,7 @@ use crate::{
     modules::topic::application::domain::entities::Topic,
 };
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct CVInfo {
     pub id: Uuid,
     pub user_id: Uuid,
@@ -19,21 +21,24 @@ pub struct CVInfo {
     pub experiences: Vec<Experience>,
     pub highlighted_projects: Vec<HighlightedProject>, // INTENTION NOT CLEAR
     pub contact_info: Vec<ContactDetail>,
+    /// Last write; sent back in `If-Match` or `expected_updated_at` to
+    /// refuse stale writes
+    pub updated_at: DateTime<Utc>,
 }
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct CoreSkill {
     pub title: String,
     pub description: String,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct Education {
     pub degree: String,
     pub institution: String,
     pub graduation_year: i32,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct Experience {
     pub company: String,
     pub position: String,
@@ -46,7 +51,7 @@ pub struct Experience {
 }
 
 // INTENTION NOT CLEAR
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct HighlightedProject {
     pub id: String,
     pub title: String,
@@ -77,13 +82,13 @@ pub struct Screenshot {
     pub featured: bool, //shows first
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
 pub enum ContactType {
     PhoneNumber,
     WebPage,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct ContactDetail {
     pub contact_type: ContactType,
     pub title: String,
@@ -0,0 +1,6 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    INVALID_EMAIL_EVENTS = (BAD_REQUEST, "Unrecognized email event payload");
+    INVALID_UNSUBSCRIBE_TOKEN = (BAD_REQUEST, "Invalid unsubscribe token");
+}
@@ -0,0 +1,18 @@
+use actix_web::web;
+
+pub mod error_codes;
+pub mod provider_events;
+pub mod routes;
+
+/// Routes anyone can call: provider callbacks carry their own secret,
+/// unsubscribe links a token
+pub fn configure_public(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::ingest_email_events_handler)
+        .service(routes::unsubscribe_handler)
+        .service(routes::one_click_unsubscribe_handler);
+}
+
+/// Routes for signed-in users with a verified email
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::list_outbox_emails_handler);
+}
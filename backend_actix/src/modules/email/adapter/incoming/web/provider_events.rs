@@ -0,0 +1,258 @@
+//! Delivery event payloads from the email providers, mapped to [`EmailEvent`]s.
+//!
+//! - SendGrid's Event Webhook posts a JSON array of events
+//! - SES publishes to an SNS topic whose HTTPS subscription posts an SNS
+//!   envelope with the SES notification as a JSON string in `Message`. A raw
+//!   SES notification (e.g. relayed by EventBridge) is accepted as well.
+//!
+//! Event types that say nothing about the address (opens, clicks, ...) are
+//! dropped.
+
+use serde_json::Value;
+
+use crate::email::application::ports::incoming::use_cases::{EmailEvent, EmailEventKind};
+
+#[derive(Debug, PartialEq)]
+pub enum ProviderPayload {
+    Events(Vec<EmailEvent>),
+    /// SNS asks to confirm the subscription by visiting `subscribe_url`
+    SubscriptionConfirmation {
+        subscribe_url: String,
+    },
+}
+
+pub fn parse(body: &[u8]) -> Result<ProviderPayload, String> {
+    let value: Value = serde_json::from_slice(body).map_err(|e| e.to_string())?;
+
+    match &value {
+        Value::Array(events) => Ok(ProviderPayload::Events(
+            events.iter().filter_map(sendgrid_event).collect(),
+        )),
+        Value::Object(object) => match object.get("Type").and_then(Value::as_str) {
+            Some("SubscriptionConfirmation") => {
+                let subscribe_url = str_field(&value, "SubscribeURL")
+                    .ok_or("SubscriptionConfirmation without SubscribeURL")?;
+                Ok(ProviderPayload::SubscriptionConfirmation {
+                    subscribe_url: subscribe_url.to_string(),
+                })
+            }
+            Some("Notification") => {
+                let message = str_field(&value, "Message").ok_or("Notification without Message")?;
+                let notification: Value =
+                    serde_json::from_str(message).map_err(|e| format!("Message: {e}"))?;
+                ses_events(&notification).map(ProviderPayload::Events)
+            }
+            Some(other) => Err(format!("unsupported SNS message type '{other}'")),
+            None => ses_events(&value).map(ProviderPayload::Events),
+        },
+        _ => Err("expected a JSON array or object".to_string()),
+    }
+}
+
+fn str_field<'a>(value: &'a Value, key: &str) -> Option<&'a str> {
+    value.get(key).and_then(Value::as_str)
+}
+
+fn sendgrid_event(event: &Value) -> Option<EmailEvent> {
+    let kind = match (str_field(event, "event")?, str_field(event, "type")) {
+        ("bounce", Some("blocked")) | ("deferred", _) => EmailEventKind::SoftBounce,
+        ("bounce", _) => EmailEventKind::HardBounce,
+        // SendGrid also drops mail to addresses on its other suppression
+        // lists; only the bounce list means the address is bad
+        ("dropped", _) if str_field(event, "reason") == Some("Bounced Address") => {
+            EmailEventKind::HardBounce
+        }
+        ("spamreport", _) => EmailEventKind::Complaint,
+        ("delivered", _) => EmailEventKind::Delivered,
+        _ => return None,
+    };
+
+    Some(EmailEvent {
+        recipient: str_field(event, "email")?.to_string(),
+        kind,
+        detail: str_field(event, "reason").map(str::to_string),
+    })
+}
+
+fn ses_events(notification: &Value) -> Result<Vec<EmailEvent>, String> {
+    // `notificationType` for identity notifications, `eventType` for
+    // configuration set event publishing
+    let event_type = str_field(notification, "notificationType")
+        .or_else(|| str_field(notification, "eventType"))
+        .ok_or("SES notification without notificationType")?;
+
+    let events: Vec<EmailEvent> = match event_type {
+        "Bounce" => {
+            let bounce = &notification["bounce"];
+            let kind = if str_field(bounce, "bounceType") == Some("Permanent") {
+                EmailEventKind::HardBounce
+            } else {
+                EmailEventKind::SoftBounce
+            };
+            recipients(&bounce["bouncedRecipients"])
+                .map(|recipient| EmailEvent {
+                    recipient: str_field(recipient, "emailAddress")
+                        .unwrap_or_default()
+                        .to_string(),
+                    kind,
+                    detail: str_field(recipient, "diagnosticCode")
+                        .or_else(|| str_field(bounce, "bounceSubType"))
+                        .map(str::to_string),
+                })
+                .collect()
+        }
+        "Complaint" => {
+            let complaint = &notification["complaint"];
+            recipients(&complaint["complainedRecipients"])
+                .map(|recipient| EmailEvent {
+                    recipient: str_field(recipient, "emailAddress")
+                        .unwrap_or_default()
+                        .to_string(),
+                    kind: EmailEventKind::Complaint,
+                    detail: str_field(complaint, "complaintFeedbackType").map(str::to_string),
+                })
+                .collect()
+        }
+        "Delivery" => recipients(&notification["delivery"]["recipients"])
+            .filter_map(Value::as_str)
+            .map(|recipient| EmailEvent {
+                recipient: recipient.to_string(),
+                kind: EmailEventKind::Delivered,
+                detail: None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    Ok(events
+        .into_iter()
+        .filter(|event| !event.recipient.is_empty())
+        .collect())
+}
+
+fn recipients(value: &Value) -> impl Iterator<Item = &Value> {
+    value.as_array().into_iter().flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn events(body: Value) -> Vec<EmailEvent> {
+        match parse(body.to_string().as_bytes()).unwrap() {
+            ProviderPayload::Events(events) => events,
+            other => panic!("expected events, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_sendgrid_events() {
+        let parsed = events(json!([
+            { "email": "a@example.com", "event": "bounce", "type": "bounce", "reason": "550 5.1.1 unknown user" },
+            { "email": "b@example.com", "event": "bounce", "type": "blocked" },
+            { "email": "c@example.com", "event": "spamreport" },
+            { "email": "d@example.com", "event": "delivered" },
+            { "email": "e@example.com", "event": "open" },
+            { "email": "f@example.com", "event": "dropped", "reason": "Bounced Address" },
+            { "email": "g@example.com", "event": "dropped", "reason": "Unsubscribed Address" },
+        ]));
+
+        let kinds: Vec<_> = parsed
+            .iter()
+            .map(|event| (event.recipient.as_str(), event.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("a@example.com", EmailEventKind::HardBounce),
+                ("b@example.com", EmailEventKind::SoftBounce),
+                ("c@example.com", EmailEventKind::Complaint),
+                ("d@example.com", EmailEventKind::Delivered),
+                ("f@example.com", EmailEventKind::HardBounce),
+            ]
+        );
+        assert_eq!(parsed[0].detail.as_deref(), Some("550 5.1.1 unknown user"));
+    }
+
+    #[test]
+    fn test_ses_bounce_in_sns_envelope() {
+        let message = json!({
+            "notificationType": "Bounce",
+            "bounce": {
+                "bounceType": "Permanent",
+                "bounceSubType": "General",
+                "bouncedRecipients": [
+                    { "emailAddress": "jane@example.com", "diagnosticCode": "smtp; 550 5.1.1 user unknown" },
+                    { "emailAddress": "john@example.com" }
+                ]
+            }
+        });
+
+        let parsed = events(json!({
+            "Type": "Notification",
+            "Message": message.to_string(),
+        }));
+
+        assert_eq!(parsed.len(), 2);
+        assert!(parsed
+            .iter()
+            .all(|event| event.kind == EmailEventKind::HardBounce));
+        assert_eq!(
+            parsed[0].detail.as_deref(),
+            Some("smtp; 550 5.1.1 user unknown")
+        );
+        assert_eq!(parsed[1].detail.as_deref(), Some("General"));
+    }
+
+    #[test]
+    fn test_raw_ses_events() {
+        let transient = events(json!({
+            "eventType": "Bounce",
+            "bounce": {
+                "bounceType": "Transient",
+                "bouncedRecipients": [{ "emailAddress": "jane@example.com" }]
+            }
+        }));
+        let complaint = events(json!({
+            "notificationType": "Complaint",
+            "complaint": {
+                "complaintFeedbackType": "abuse",
+                "complainedRecipients": [{ "emailAddress": "jane@example.com" }]
+            }
+        }));
+        let delivery = events(json!({
+            "notificationType": "Delivery",
+            "delivery": { "recipients": ["jane@example.com"] }
+        }));
+
+        assert_eq!(transient[0].kind, EmailEventKind::SoftBounce);
+        assert_eq!(complaint[0].kind, EmailEventKind::Complaint);
+        assert_eq!(complaint[0].detail.as_deref(), Some("abuse"));
+        assert_eq!(delivery[0].kind, EmailEventKind::Delivered);
+    }
+
+    #[test]
+    fn test_sns_subscription_confirmation() {
+        let body = json!({
+            "Type": "SubscriptionConfirmation",
+            "SubscribeURL": "https://sns.us-east-1.amazonaws.com/?Action=ConfirmSubscription",
+        });
+
+        assert_eq!(
+            parse(body.to_string().as_bytes()).unwrap(),
+            ProviderPayload::SubscriptionConfirmation {
+                subscribe_url: "https://sns.us-east-1.amazonaws.com/?Action=ConfirmSubscription"
+                    .to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_rejects_unrecognized_bodies() {
+        assert!(parse(b"not json").is_err());
+        assert!(parse(b"42").is_err());
+        assert!(parse(br#"{"hello":"world"}"#).is_err());
+        assert!(parse(br#"{"Type":"Notification","Message":"nope"}"#).is_err());
+    }
+}
@@ -0,0 +1,219 @@
+use actix_web::{http::header::AUTHORIZATION, post, web, HttpRequest, Responder};
+use serde::Deserialize;
+use tracing::{error, warn};
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::email::adapter::incoming::web::error_codes::INVALID_EMAIL_EVENTS;
+use crate::shared::api::error_codes::{NOT_FOUND, UNAUTHORIZED};
+use crate::shared::constant_time::constant_time_eq;
+use crate::{
+    email::{
+        adapter::incoming::web::provider_events::{self, ProviderPayload},
+        application::ports::incoming::use_cases::{EmailEventsSummary, RecordEmailEventsError},
+    },
+    shared::api::ApiResponse,
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct EmailEventsQuery {
+    pub token: Option<String>,
+}
+
+/// Token from `?token=` (SES/SNS and SendGrid can only be given a URL) or
+/// an `Authorization: Bearer` header
+fn presented_token<'a>(req: &'a HttpRequest, query: &'a EmailEventsQuery) -> Option<&'a str> {
+    query.token.as_deref().or_else(|| {
+        req.headers()
+            .get(AUTHORIZATION)?
+            .to_str()
+            .ok()?
+            .strip_prefix("Bearer ")
+    })
+}
+
+/// Bounce, complaint and delivery events from SendGrid's Event Webhook or
+/// SES via an SNS HTTPS subscription. Hard-bounced and complaining addresses
+/// are marked on the user and get no further mail.
+#[utoipa::path(
+    post,
+    path = "/api/internal/email-events",
+    tag = "email",
+    params(
+        ("token" = Option<String>, Query, description = "`EMAIL_EVENTS_TOKEN`, unless sent as a Bearer token"),
+    ),
+    request_body(content = String, description = "SendGrid event array, SNS envelope or SES notification", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Events recorded", body = inline(SuccessResponse<EmailEventsSummary>)),
+        (status = 400, description = "Unrecognized payload", body = ErrorResponse),
+        (status = 401, description = "Missing or wrong token", body = ErrorResponse),
+        (status = 404, description = "`EMAIL_EVENTS_TOKEN` is not configured", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    )
+)]
+#[post("/api/internal/email-events")]
+pub async fn ingest_email_events_handler(
+    req: HttpRequest,
+    query: web::Query<EmailEventsQuery>,
+    body: web::Bytes,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let Some(expected) = data.email_events_token.as_deref() else {
+        return NOT_FOUND.response();
+    };
+    if !presented_token(&req, &query)
+        .is_some_and(|token| constant_time_eq(expected.as_bytes(), token.as_bytes()))
+    {
+        return UNAUTHORIZED.with_message("Missing or invalid email events token");
+    }
+
+    // SNS posts as text/plain, so the body is parsed here rather than by `web::Json`
+    let events = match provider_events::parse(&body) {
+        Ok(ProviderPayload::Events(events)) => events,
+        Ok(ProviderPayload::SubscriptionConfirmation { subscribe_url }) => {
+            warn!(%subscribe_url, "SNS subscription awaiting confirmation; open the URL to confirm");
+            return ApiResponse::success(EmailEventsSummary::default());
+        }
+        Err(msg) => {
+            return INVALID_EMAIL_EVENTS
+                .with_message(&format!("Unrecognized email event payload: {msg}"))
+        }
+    };
+
+    match data.email.record_events.execute(events).await {
+        Ok(summary) => ApiResponse::success(summary),
+        Err(RecordEmailEventsError::StoreFailed(msg)) => {
+            error!("Failed to record email events: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::json;
+
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, stubs::StubRecordEmailEventsUseCase,
+    };
+
+    const TOKEN: &str = "0123456789abcdef";
+
+    async fn call(
+        state: web::Data<AppState>,
+        req: test::TestRequest,
+    ) -> actix_web::dev::ServiceResponse {
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .service(ingest_email_events_handler),
+        )
+        .await;
+        test::call_service(&app, req.to_request()).await
+    }
+
+    fn sendgrid_bounce() -> String {
+        json!([
+            { "email": "jane@example.com", "event": "bounce", "type": "bounce" },
+            { "email": "john@example.com", "event": "delivered" },
+        ])
+        .to_string()
+    }
+
+    #[actix_web::test]
+    async fn test_records_events_with_query_token() {
+        let state = TestAppStateBuilder::default()
+            .with_email_events_token(TOKEN)
+            .build();
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/internal/email-events?token={TOKEN}"))
+            .insert_header(("content-type", "application/json"))
+            .set_payload(sendgrid_bounce());
+        let resp = call(state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["received"], 2);
+        assert_eq!(json["data"]["bounced"], 1);
+    }
+
+    #[actix_web::test]
+    async fn test_accepts_bearer_token_and_plain_text_body() {
+        let state = TestAppStateBuilder::default()
+            .with_email_events_token(TOKEN)
+            .build();
+        let body = json!({
+            "Type": "SubscriptionConfirmation",
+            "SubscribeURL": "https://sns.example.com/confirm",
+        });
+
+        let req = test::TestRequest::post()
+            .uri("/api/internal/email-events")
+            .insert_header(("authorization", format!("Bearer {TOKEN}")))
+            .insert_header(("content-type", "text/plain; charset=UTF-8"))
+            .set_payload(body.to_string());
+        let resp = call(state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_wrong_token_is_unauthorized() {
+        let state = TestAppStateBuilder::default()
+            .with_email_events_token(TOKEN)
+            .build();
+
+        let req = test::TestRequest::post()
+            .uri("/api/internal/email-events?token=fedcba9876543210")
+            .set_payload(sendgrid_bounce());
+        let resp = call(state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn test_disabled_without_configured_token() {
+        let state = TestAppStateBuilder::default().build();
+
+        let req = test::TestRequest::post()
+            .uri("/api/internal/email-events?token=anything")
+            .set_payload(sendgrid_bounce());
+        let resp = call(state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_unrecognized_payload_is_rejected() {
+        let state = TestAppStateBuilder::default()
+            .with_email_events_token(TOKEN)
+            .build();
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/internal/email-events?token={TOKEN}"))
+            .set_payload("<xml/>");
+        let resp = call(state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "INVALID_EMAIL_EVENTS");
+    }
+
+    #[actix_web::test]
+    async fn test_store_failure_returns_internal_error() {
+        let state = TestAppStateBuilder::default()
+            .with_email_events_token(TOKEN)
+            .with_record_email_events(StubRecordEmailEventsUseCase::failure("db down"))
+            .build();
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/internal/email-events?token={TOKEN}"))
+            .set_payload(sendgrid_bounce());
+        let resp = call(state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
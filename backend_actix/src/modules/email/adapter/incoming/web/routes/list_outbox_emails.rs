@@ -0,0 +1,163 @@
+use actix_web::{get, web, Responder};
+use serde::Deserialize;
+use tracing::error;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    email::application::ports::{
+        incoming::use_cases::ListOutboxEmailsError,
+        outgoing::{OutboxEmailStatus, OutboxEmailSummary},
+    },
+    shared::api::{
+        pagination::{PageParams, PagedResponse},
+        ApiResponse,
+    },
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct ListOutboxEmailsQuery {
+    pub status: Option<OutboxEmailStatus>,
+}
+
+/// Outgoing emails, newest first, so failed sends (e.g. bad SMTP credentials)
+/// are visible with their last error
+#[utoipa::path(
+    get,
+    path = "/api/admin/emails",
+    tag = "admin",
+    params(
+        ("status" = Option<OutboxEmailStatus>, Query, description = "Only emails in this state"),
+        ("limit" = Option<u32>, Query, description = "Page size, 1-100 (default 20)"),
+        ("cursor" = Option<String>, Query, description = "`next_cursor` from the previous page"),
+    ),
+    responses(
+        (status = 200, description = "One page of emails", body = inline(SuccessResponse<PagedResponse<OutboxEmailSummary>>)),
+        (status = 400, description = "Invalid status or pagination parameters", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/admin/emails")]
+pub async fn list_outbox_emails_handler(
+    admin: AdminUser,
+    query: web::Query<ListOutboxEmailsQuery>,
+    page: PageParams,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data
+        .email
+        .list_outbox
+        .execute(query.status, page.offset, page.limit)
+        .await
+    {
+        Ok(result) => ApiResponse::success(PagedResponse::new(result.items, result.total, &page)),
+        Err(ListOutboxEmailsError::QueryFailed(msg)) => {
+            error!(admin = %admin.user_id, "Failed to list outbox emails: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, web, App};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubListOutboxEmailsUseCase,
+        },
+    };
+
+    async fn call(
+        state: web::Data<AppState>,
+        user_id: Uuid,
+        uri: &str,
+    ) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(user_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(list_outbox_emails_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_admin_sees_failed_emails() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .with_list_outbox_emails(StubListOutboxEmailsUseCase::one_failed())
+            .build();
+
+        let resp = call(state, admin, "/api/admin/emails?status=failed").await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["total"], 1);
+        assert_eq!(json["data"]["items"][0]["status"], "failed");
+        assert_eq!(json["data"]["items"][0]["recipient"], "jane@example.com");
+        assert!(json["data"]["items"][0]["last_error"]
+            .as_str()
+            .unwrap()
+            .contains("535"));
+    }
+
+    #[actix_web::test]
+    async fn test_non_admin_is_forbidden() {
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![Uuid::new_v4()])
+            .build();
+
+        let resp = call(state, Uuid::new_v4(), "/api/admin/emails").await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_status_is_rejected() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .build();
+
+        let resp = call(state, admin, "/api/admin/emails?status=bounced").await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_query_failure_returns_internal_error() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .with_list_outbox_emails(StubListOutboxEmailsUseCase::failure("db down"))
+            .build();
+
+        let resp = call(state, admin, "/api/admin/emails").await;
+
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,10 @@
+mod ingest_email_events;
+mod list_outbox_emails;
+mod unsubscribe;
+
+pub use ingest_email_events::{__path_ingest_email_events_handler, ingest_email_events_handler};
+pub use list_outbox_emails::{__path_list_outbox_emails_handler, list_outbox_emails_handler};
+pub use unsubscribe::{
+    __path_one_click_unsubscribe_handler, __path_unsubscribe_handler,
+    one_click_unsubscribe_handler, unsubscribe_handler,
+};
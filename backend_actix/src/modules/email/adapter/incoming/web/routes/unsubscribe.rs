@@ -0,0 +1,161 @@
+use actix_web::{get, post, web, HttpResponse, Responder};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use utoipa::ToSchema;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::email::adapter::incoming::web::error_codes::INVALID_UNSUBSCRIBE_TOKEN;
+use crate::{
+    email::application::ports::incoming::use_cases::UnsubscribeError, shared::api::ApiResponse,
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct UnsubscribeQuery {
+    pub token: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct UnsubscribeResponse {
+    #[schema(example = "You will no longer receive these emails")]
+    message: String,
+}
+
+async fn unsubscribe(data: &AppState, query: &UnsubscribeQuery) -> HttpResponse {
+    let token = query.token.as_deref().unwrap_or_default();
+
+    match data.email.unsubscribe.execute(token).await {
+        Ok(()) => ApiResponse::success(UnsubscribeResponse {
+            message: "You will no longer receive these emails".to_string(),
+        }),
+        Err(UnsubscribeError::InvalidToken) => INVALID_UNSUBSCRIBE_TOKEN.response(),
+        Err(UnsubscribeError::StoreFailed(msg)) => {
+            error!("Failed to unsubscribe: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+/// Unsubscribe from non-transactional email
+///
+/// The link at the bottom of such emails. No login needed: the token is
+/// signed for one user. Account emails (e.g. verification) are still sent.
+#[utoipa::path(
+    get,
+    path = "/api/public/unsubscribe",
+    tag = "email",
+    params(
+        ("token" = String, Query, description = "Token from the unsubscribe link"),
+    ),
+    responses(
+        (status = 200, description = "Unsubscribed", body = inline(SuccessResponse<UnsubscribeResponse>)),
+        (status = 400, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    )
+)]
+#[get("/api/public/unsubscribe")]
+pub async fn unsubscribe_handler(
+    query: web::Query<UnsubscribeQuery>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    unsubscribe(&data, &query).await
+}
+
+/// One-click unsubscribe (RFC 8058)
+///
+/// What mail clients send for the `List-Unsubscribe` header, with the body
+/// `List-Unsubscribe=One-Click`, which is ignored.
+#[utoipa::path(
+    post,
+    path = "/api/public/unsubscribe",
+    tag = "email",
+    params(
+        ("token" = String, Query, description = "Token from the `List-Unsubscribe` header"),
+    ),
+    responses(
+        (status = 200, description = "Unsubscribed", body = inline(SuccessResponse<UnsubscribeResponse>)),
+        (status = 400, description = "Missing or invalid token", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    )
+)]
+#[post("/api/public/unsubscribe")]
+pub async fn one_click_unsubscribe_handler(
+    query: web::Query<UnsubscribeQuery>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    unsubscribe(&data, &query).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, stubs::StubUnsubscribeUseCase,
+    };
+
+    async fn call(
+        state: web::Data<AppState>,
+        req: test::TestRequest,
+    ) -> actix_web::dev::ServiceResponse {
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .service(unsubscribe_handler)
+                .service(one_click_unsubscribe_handler),
+        )
+        .await;
+        test::call_service(&app, req.to_request()).await
+    }
+
+    #[actix_web::test]
+    async fn test_link_and_one_click_both_unsubscribe() {
+        for req in [
+            test::TestRequest::get().uri("/api/public/unsubscribe?token=valid"),
+            test::TestRequest::post()
+                .uri("/api/public/unsubscribe?token=valid")
+                .insert_header(("content-type", "application/x-www-form-urlencoded"))
+                .set_payload("List-Unsubscribe=One-Click"),
+        ] {
+            let state = TestAppStateBuilder::default().build();
+
+            let resp = call(state, req).await;
+
+            assert_eq!(resp.status(), StatusCode::OK);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_invalid_or_missing_token_is_rejected() {
+        for uri in [
+            "/api/public/unsubscribe?token=forged",
+            "/api/public/unsubscribe",
+        ] {
+            let state = TestAppStateBuilder::default()
+                .with_unsubscribe(StubUnsubscribeUseCase::invalid_token())
+                .build();
+
+            let resp = call(state, test::TestRequest::get().uri(uri)).await;
+
+            assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+            let json: serde_json::Value = test::read_body_json(resp).await;
+            assert_eq!(json["error"]["code"], "INVALID_UNSUBSCRIBE_TOKEN");
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_store_failure_returns_internal_error() {
+        let state = TestAppStateBuilder::default()
+            .with_unsubscribe(StubUnsubscribeUseCase::failure("db down"))
+            .build();
+
+        let resp = call(
+            state,
+            test::TestRequest::get().uri("/api/public/unsubscribe?token=valid"),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,828 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement,
+    TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::DEFAULT_LOCALE;
+use crate::auth::application::use_cases::create_user::CreateUserOutput;
+use crate::email::application::ports::outgoing::{
+    email_outbox::{
+        ContactNotification, EmailOutbox, EmailOutboxError, OutboxEmail, OutboxMessage,
+    },
+    EmailBounceList, EmailOutboxQuery, EmailUnsubscribeList, OutboxEmailPage, OutboxEmailStatus,
+    OutboxEmailSummary,
+};
+use crate::shared::field_cipher::FieldCipher;
+use crate::shared::sql;
+
+const KIND_VERIFICATION: &str = "verification";
+const KIND_CONTACT_MESSAGE: &str = "contact_message";
+
+/// `payload` of a `verification` row
+#[derive(Serialize, Deserialize)]
+struct VerificationPayload {
+    user_id: Uuid,
+    email: String,
+    username: String,
+    full_name: String,
+    /// Missing on rows queued before locales existed
+    #[serde(default = "default_locale")]
+    preferred_locale: String,
+}
+
+/// `payload` of a `contact_message` row. `email` is the recipient, as in
+/// every other kind, so the admin listing can show it. The sender and the
+/// message are read from `contact_messages` when the row is claimed, so the
+/// body only exists there, encrypted.
+#[derive(Serialize, Deserialize)]
+struct ContactMessagePayload {
+    owner_id: Uuid,
+    email: String,
+    username: String,
+    preferred_locale: String,
+    message_id: Uuid,
+}
+
+fn default_locale() -> String {
+    DEFAULT_LOCALE.to_string()
+}
+
+#[derive(Clone)]
+pub struct EmailOutboxPostgres {
+    db: Arc<DatabaseConnection>,
+    cipher: FieldCipher,
+}
+
+impl EmailOutboxPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            db,
+            cipher: FieldCipher::default(),
+        }
+    }
+
+    /// Decrypts the contact message bodies read at send time
+    pub fn with_cipher(mut self, cipher: FieldCipher) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
+    /// Queue `email` on `conn`. Pass the caller's transaction so the row is only
+    /// committed together with the change that triggered it. Nothing is queued
+    /// for a user whose address has bounced, nor non-transactional mail for a
+    /// user who unsubscribed.
+    pub async fn enqueue<C: ConnectionTrait>(conn: &C, email: &OutboxEmail) -> Result<(), DbErr> {
+        let (kind, payload) = match email {
+            OutboxEmail::Verification(user) => (
+                KIND_VERIFICATION,
+                serde_json::to_value(VerificationPayload {
+                    user_id: user.user_id,
+                    email: user.email.clone(),
+                    username: user.username.clone(),
+                    full_name: user.full_name.clone(),
+                    preferred_locale: user.preferred_locale.clone(),
+                }),
+            ),
+            OutboxEmail::ContactMessage(notification) => (
+                KIND_CONTACT_MESSAGE,
+                serde_json::to_value(ContactMessagePayload {
+                    owner_id: notification.owner_id,
+                    email: notification.owner_email.clone(),
+                    username: notification.owner_username.clone(),
+                    preferred_locale: notification.preferred_locale.clone(),
+                    message_id: notification.message_id,
+                }),
+            ),
+        };
+        let payload = payload.map_err(|e| DbErr::Custom(e.to_string()))?;
+        let user_id = email.user_id();
+
+        conn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"
+            INSERT INTO email_outbox (user_id, kind, payload)
+            SELECT $1, $2, $3
+            WHERE NOT EXISTS (
+                SELECT 1 FROM users
+                WHERE id = $1
+                  AND (email_bounced_at IS NOT NULL
+                       OR (NOT $4 AND email_unsubscribed_at IS NOT NULL))
+            )
+            "#,
+            vec![
+                user_id.into(),
+                kind.into(),
+                payload.into(),
+                email.is_transactional().into(),
+            ],
+        ))
+        .await?;
+
+        Ok(())
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    // SKIP LOCKED + the lease keep concurrent dispatchers off each other's rows
+    fn claim_due_stmt(
+        backend: DatabaseBackend,
+        limit: u32,
+        lease_until: DateTime<Utc>,
+    ) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+            UPDATE email_outbox
+            SET next_attempt_at = $2,
+                attempts = attempts + 1
+            WHERE id IN (
+                SELECT id
+                FROM email_outbox
+                WHERE next_attempt_at IS NOT NULL
+                  AND next_attempt_at <= {now}
+                ORDER BY next_attempt_at
+                LIMIT $1
+                {skip_locked}
+            )
+            RETURNING id, kind, payload, attempts
+            "#,
+                now = sql::now(backend),
+                skip_locked = sql::skip_locked(backend),
+            ),
+            vec![(limit as i64).into(), lease_until.into()],
+        )
+    }
+
+    fn contact_message_stmt(backend: DatabaseBackend, id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT name, email, subject, message
+            FROM contact_messages
+            WHERE id = $1
+            "#,
+            vec![id.into()],
+        )
+    }
+
+    fn mark_sent_stmt(backend: DatabaseBackend, id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+            UPDATE email_outbox
+            SET sent_at = {},
+                next_attempt_at = NULL,
+                last_error = NULL
+            WHERE id = $1
+            "#,
+                sql::now(backend)
+            ),
+            vec![id.into()],
+        )
+    }
+
+    fn mark_failed_stmt(id: Uuid, error: &str, retry_at: Option<DateTime<Utc>>) -> Statement {
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"
+            UPDATE email_outbox
+            SET next_attempt_at = $2,
+                last_error = $3
+            WHERE id = $1
+            "#,
+            vec![id.into(), retry_at.into(), error.into()],
+        )
+    }
+
+    fn mark_user_bounced_stmt(backend: DatabaseBackend, email: &str) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+            UPDATE users
+            SET email_bounced_at = COALESCE(email_bounced_at, {})
+            WHERE email = $1
+            "#,
+                sql::now(backend)
+            ),
+            vec![email.into()],
+        )
+    }
+
+    /// Give up on unsent mail to the users with `email`
+    fn abandon_queued_stmt(backend: DatabaseBackend, email: &str, reason: &str) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            UPDATE email_outbox
+            SET next_attempt_at = NULL,
+                last_error = $2
+            WHERE sent_at IS NULL
+              AND next_attempt_at IS NOT NULL
+              AND user_id IN (SELECT id FROM users WHERE email = $1)
+            "#,
+            vec![email.into(), reason.into()],
+        )
+    }
+
+    fn mark_user_unsubscribed_stmt(backend: DatabaseBackend, user_id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+            UPDATE users
+            SET email_unsubscribed_at = COALESCE(email_unsubscribed_at, {})
+            WHERE id = $1
+            "#,
+                sql::now(backend)
+            ),
+            vec![user_id.into()],
+        )
+    }
+
+    /// Give up on unsent non-transactional mail to `user_id`
+    fn abandon_unsubscribed_stmt(backend: DatabaseBackend, user_id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            UPDATE email_outbox
+            SET next_attempt_at = NULL,
+                last_error = 'unsubscribed'
+            WHERE sent_at IS NULL
+              AND next_attempt_at IS NOT NULL
+              AND user_id = $1
+              AND kind <> $2
+            "#,
+            vec![user_id.into(), KIND_VERIFICATION.into()],
+        )
+    }
+
+    /// `WHERE` clause selecting rows in `status`
+    fn status_filter(status: Option<OutboxEmailStatus>) -> &'static str {
+        match status {
+            None => "",
+            Some(OutboxEmailStatus::Sent) => "WHERE sent_at IS NOT NULL",
+            Some(OutboxEmailStatus::Queued) => {
+                "WHERE sent_at IS NULL AND next_attempt_at IS NOT NULL"
+            }
+            Some(OutboxEmailStatus::Failed) => "WHERE sent_at IS NULL AND next_attempt_at IS NULL",
+        }
+    }
+
+    fn list_stmt(
+        backend: DatabaseBackend,
+        status: Option<OutboxEmailStatus>,
+        offset: u64,
+        limit: u32,
+    ) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+            SELECT id, user_id, kind, payload, attempts, last_error,
+                   next_attempt_at, sent_at, created_at
+            FROM email_outbox
+            {}
+            ORDER BY created_at DESC, id
+            LIMIT $2
+            OFFSET $1
+            "#,
+                Self::status_filter(status)
+            ),
+            vec![(offset as i64).into(), (limit as i64).into()],
+        )
+    }
+
+    fn count_stmt(backend: DatabaseBackend, status: Option<OutboxEmailStatus>) -> Statement {
+        Statement::from_string(
+            backend,
+            format!(
+                r#"
+            SELECT COUNT(*) AS total
+            FROM email_outbox
+            {}
+            "#,
+                Self::status_filter(status)
+            ),
+        )
+    }
+
+    // =====================================================
+    // Mapping
+    // =====================================================
+
+    async fn to_message(&self, row: QueryResult) -> Result<OutboxMessage, EmailOutboxError> {
+        let id: Uuid = row.try_get("", "id").map_err(Self::map_db_err)?;
+        let kind: String = row.try_get("", "kind").map_err(Self::map_db_err)?;
+        let payload: serde_json::Value = row.try_get("", "payload").map_err(Self::map_db_err)?;
+        let attempts: i32 = row.try_get("", "attempts").map_err(Self::map_db_err)?;
+
+        let email = match kind.as_str() {
+            KIND_VERIFICATION => {
+                let p: VerificationPayload = serde_json::from_value(payload)
+                    .map_err(|e| EmailOutboxError::InvalidMessage(id, e.to_string()))?;
+                OutboxEmail::Verification(CreateUserOutput {
+                    user_id: p.user_id,
+                    email: p.email,
+                    username: p.username,
+                    full_name: p.full_name,
+                    preferred_locale: p.preferred_locale,
+                })
+            }
+            KIND_CONTACT_MESSAGE => {
+                let p: ContactMessagePayload = serde_json::from_value(payload)
+                    .map_err(|e| EmailOutboxError::InvalidMessage(id, e.to_string()))?;
+                let sent = self
+                    .db
+                    .query_one(Self::contact_message_stmt(
+                        self.db.get_database_backend(),
+                        p.message_id,
+                    ))
+                    .await
+                    .map_err(Self::map_db_err)?
+                    // Deleted from the inbox before it went out
+                    .ok_or_else(|| {
+                        EmailOutboxError::InvalidMessage(id, "contact message deleted".into())
+                    })?;
+                let message: String = sent.try_get("", "message").map_err(Self::map_db_err)?;
+
+                OutboxEmail::ContactMessage(ContactNotification {
+                    owner_id: p.owner_id,
+                    owner_email: p.email,
+                    owner_username: p.username,
+                    preferred_locale: p.preferred_locale,
+                    message_id: p.message_id,
+                    sender_name: sent.try_get("", "name").map_err(Self::map_db_err)?,
+                    sender_email: sent.try_get("", "email").map_err(Self::map_db_err)?,
+                    subject: sent.try_get("", "subject").map_err(Self::map_db_err)?,
+                    message: self
+                        .cipher
+                        .decrypt(FieldCipher::SITE, &message)
+                        .map_err(|e| EmailOutboxError::InvalidMessage(id, e.to_string()))?,
+                })
+            }
+            other => {
+                return Err(EmailOutboxError::InvalidMessage(
+                    id,
+                    format!("unknown kind '{}'", other),
+                ))
+            }
+        };
+
+        Ok(OutboxMessage {
+            id,
+            attempts: attempts.max(0) as u32,
+            email,
+        })
+    }
+
+    fn to_summary(row: &QueryResult) -> Result<OutboxEmailSummary, EmailOutboxError> {
+        let payload: serde_json::Value = row.try_get("", "payload").map_err(Self::map_db_err)?;
+        let attempts: i32 = row.try_get("", "attempts").map_err(Self::map_db_err)?;
+        let next_attempt_at: Option<DateTime<Utc>> = row
+            .try_get("", "next_attempt_at")
+            .map_err(Self::map_db_err)?;
+        let sent_at: Option<DateTime<Utc>> =
+            row.try_get("", "sent_at").map_err(Self::map_db_err)?;
+
+        let status = match (sent_at, next_attempt_at) {
+            (Some(_), _) => OutboxEmailStatus::Sent,
+            (None, Some(_)) => OutboxEmailStatus::Queued,
+            (None, None) => OutboxEmailStatus::Failed,
+        };
+
+        Ok(OutboxEmailSummary {
+            id: row.try_get("", "id").map_err(Self::map_db_err)?,
+            user_id: row.try_get("", "user_id").map_err(Self::map_db_err)?,
+            recipient: payload["email"].as_str().unwrap_or_default().to_string(),
+            kind: row.try_get("", "kind").map_err(Self::map_db_err)?,
+            status,
+            attempts: attempts.max(0) as u32,
+            last_error: row.try_get("", "last_error").map_err(Self::map_db_err)?,
+            next_attempt_at,
+            sent_at,
+            created_at: row.try_get("", "created_at").map_err(Self::map_db_err)?,
+        })
+    }
+
+    fn map_db_err(e: DbErr) -> EmailOutboxError {
+        EmailOutboxError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl EmailOutbox for EmailOutboxPostgres {
+    async fn claim_due(
+        &self,
+        limit: u32,
+        lease_until: DateTime<Utc>,
+    ) -> Result<Vec<OutboxMessage>, EmailOutboxError> {
+        let rows = self
+            .db
+            .query_all(Self::claim_due_stmt(
+                self.db.get_database_backend(),
+                limit,
+                lease_until,
+            ))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        let mut messages = Vec::with_capacity(rows.len());
+        for row in rows {
+            match self.to_message(row).await {
+                Ok(message) => messages.push(message),
+                // Park unreadable rows so they don't come back every poll
+                Err(EmailOutboxError::InvalidMessage(id, reason)) => {
+                    tracing::error!(message_id = %id, error = %reason, "Unreadable outbox row");
+                    self.mark_failed(id, &reason, None).await?;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(messages)
+    }
+
+    async fn mark_sent(&self, id: Uuid) -> Result<(), EmailOutboxError> {
+        self.db
+            .execute(Self::mark_sent_stmt(self.db.get_database_backend(), id))
+            .await
+            .map_err(Self::map_db_err)?;
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), EmailOutboxError> {
+        self.db
+            .execute(Self::mark_failed_stmt(id, error, retry_at))
+            .await
+            .map_err(Self::map_db_err)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EmailOutboxQuery for EmailOutboxPostgres {
+    async fn list(
+        &self,
+        status: Option<OutboxEmailStatus>,
+        offset: u64,
+        limit: u32,
+    ) -> Result<OutboxEmailPage, EmailOutboxError> {
+        let backend = self.db.get_database_backend();
+
+        let items = self
+            .db
+            .query_all(Self::list_stmt(backend, status, offset, limit))
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(Self::to_summary)
+            .collect::<Result<Vec<_>, _>>()?;
+        let total: i64 = self
+            .db
+            .query_one(Self::count_stmt(backend, status))
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or_else(|| EmailOutboxError::DatabaseError("count returned no row".into()))?
+            .try_get("", "total")
+            .map_err(Self::map_db_err)?;
+
+        Ok(OutboxEmailPage {
+            items,
+            total: total.max(0) as u64,
+        })
+    }
+}
+
+/// Bounces are recorded on `users.email_bounced_at`; the outbox is where
+/// they take effect.
+#[async_trait]
+impl EmailBounceList for EmailOutboxPostgres {
+    async fn mark_bounced(&self, email: &str, reason: &str) -> Result<bool, EmailOutboxError> {
+        // Stored addresses are lowercase (registration normalizes them)
+        let email = email.trim().to_lowercase();
+        let backend = self.db.get_database_backend();
+
+        let txn = self.db.begin().await.map_err(Self::map_db_err)?;
+        let marked = txn
+            .execute(Self::mark_user_bounced_stmt(backend, &email))
+            .await
+            .map_err(Self::map_db_err)?
+            .rows_affected();
+        txn.execute(Self::abandon_queued_stmt(backend, &email, reason))
+            .await
+            .map_err(Self::map_db_err)?;
+        txn.commit().await.map_err(Self::map_db_err)?;
+
+        Ok(marked > 0)
+    }
+}
+
+/// Opt-outs are recorded on `users.email_unsubscribed_at`, like bounces
+#[async_trait]
+impl EmailUnsubscribeList for EmailOutboxPostgres {
+    async fn unsubscribe(&self, user_id: Uuid) -> Result<bool, EmailOutboxError> {
+        let backend = self.db.get_database_backend();
+
+        let txn = self.db.begin().await.map_err(Self::map_db_err)?;
+        let marked = txn
+            .execute(Self::mark_user_unsubscribed_stmt(backend, user_id))
+            .await
+            .map_err(Self::map_db_err)?
+            .rows_affected();
+        txn.execute(Self::abandon_unsubscribed_stmt(backend, user_id))
+            .await
+            .map_err(Self::map_db_err)?;
+        txn.commit().await.map_err(Self::map_db_err)?;
+
+        Ok(marked > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::field_cipher::FieldKey;
+    use sea_orm::{MockDatabase, MockExecResult, Value};
+    use std::collections::BTreeMap;
+
+    fn outbox_row(
+        kind: &str,
+        payload: serde_json::Value,
+        attempts: i32,
+    ) -> BTreeMap<String, Value> {
+        BTreeMap::from([
+            (
+                "id".to_string(),
+                Value::Uuid(Some(Box::new(Uuid::new_v4()))),
+            ),
+            (
+                "kind".to_string(),
+                Value::String(Some(Box::new(kind.to_string()))),
+            ),
+            ("payload".to_string(), Value::Json(Some(Box::new(payload)))),
+            ("attempts".to_string(), Value::Int(Some(attempts))),
+        ])
+    }
+
+    #[tokio::test]
+    async fn test_claim_due_reads_verification_messages() {
+        let user_id = Uuid::new_v4();
+        let payload = serde_json::json!({
+            "user_id": user_id,
+            "email": "valid@example.com",
+            "username": "validuser",
+            "full_name": "Valid User",
+        });
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![outbox_row(KIND_VERIFICATION, payload, 1)]])
+            .into_connection();
+
+        let outbox = EmailOutboxPostgres::new(Arc::new(db));
+        let messages = outbox.claim_due(10, Utc::now()).await.unwrap();
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].attempts, 1);
+        match &messages[0].email {
+            OutboxEmail::Verification(user) => {
+                assert_eq!(user.user_id, user_id);
+                assert_eq!(user.username, "validuser");
+                // Payload predates locales
+                assert_eq!(user.preferred_locale, DEFAULT_LOCALE);
+            }
+            other => panic!("expected a verification email, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_claim_due_reads_contact_messages() {
+        let message_id = Uuid::new_v4();
+        let payload = serde_json::json!({
+            "owner_id": Uuid::new_v4(),
+            "email": "owner@example.com",
+            "username": "owner",
+            "preferred_locale": "id",
+            "message_id": message_id,
+        });
+        let cipher = FieldCipher::new(vec![FieldKey::new("k1", [7; 32]).unwrap()]);
+        let sent = BTreeMap::from([
+            ("name".to_string(), Value::from("Jane Doe")),
+            ("email".to_string(), Value::from("jane@example.com")),
+            ("subject".to_string(), Value::String(None)),
+            (
+                "message".to_string(),
+                Value::from(cipher.encrypt(FieldCipher::SITE, "Are you available?")),
+            ),
+        ]);
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![outbox_row(KIND_CONTACT_MESSAGE, payload, 1)]])
+            .append_query_results(vec![vec![sent]])
+            .into_connection();
+
+        let outbox = EmailOutboxPostgres::new(Arc::new(db)).with_cipher(cipher);
+        let messages = outbox.claim_due(10, Utc::now()).await.unwrap();
+
+        match &messages[0].email {
+            OutboxEmail::ContactMessage(notification) => {
+                assert_eq!(notification.message_id, message_id);
+                assert_eq!(notification.owner_email, "owner@example.com");
+                assert_eq!(notification.sender_email, "jane@example.com");
+                assert_eq!(notification.subject, None);
+                assert_eq!(notification.message, "Are you available?");
+            }
+            other => panic!("expected a contact message, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_claim_due_parks_unreadable_rows() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![outbox_row(
+                "newsletter",
+                serde_json::json!({}),
+                1,
+            )]])
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let outbox = EmailOutboxPostgres::new(Arc::new(db));
+        let messages = outbox.claim_due(10, Utc::now()).await.unwrap();
+
+        assert!(messages.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_database_error_is_mapped() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_errors(vec![DbErr::Custom("connection lost".into())])
+            .into_connection();
+
+        let outbox = EmailOutboxPostgres::new(Arc::new(db));
+        let err = outbox.mark_sent(Uuid::new_v4()).await.unwrap_err();
+
+        assert!(
+            matches!(err, EmailOutboxError::DatabaseError(msg) if msg.contains("connection lost"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_derives_status_and_recipient() {
+        let row = |sent_at: Option<DateTime<Utc>>, next_attempt_at: Option<DateTime<Utc>>| {
+            BTreeMap::from([
+                ("id".to_string(), Value::from(Uuid::new_v4())),
+                ("user_id".to_string(), Value::from(Uuid::new_v4())),
+                ("kind".to_string(), Value::from(KIND_VERIFICATION)),
+                (
+                    "payload".to_string(),
+                    Value::Json(Some(Box::new(serde_json::json!({
+                        "email": "jane@example.com",
+                    })))),
+                ),
+                ("attempts".to_string(), Value::Int(Some(8))),
+                (
+                    "last_error".to_string(),
+                    Value::from("535 authentication failed"),
+                ),
+                (
+                    "next_attempt_at".to_string(),
+                    Value::ChronoDateTimeUtc(next_attempt_at.map(Box::new)),
+                ),
+                (
+                    "sent_at".to_string(),
+                    Value::ChronoDateTimeUtc(sent_at.map(Box::new)),
+                ),
+                ("created_at".to_string(), Value::from(Utc::now())),
+            ])
+        };
+        let total = BTreeMap::from([("total".to_string(), Value::BigInt(Some(3)))]);
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![
+                row(Some(Utc::now()), None),
+                row(None, Some(Utc::now())),
+                row(None, None),
+            ]])
+            .append_query_results(vec![vec![total]])
+            .into_connection();
+
+        let outbox = EmailOutboxPostgres::new(Arc::new(db));
+        let page = outbox.list(None, 0, 20).await.unwrap();
+
+        assert_eq!(page.total, 3);
+        let statuses: Vec<_> = page.items.iter().map(|item| item.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                OutboxEmailStatus::Sent,
+                OutboxEmailStatus::Queued,
+                OutboxEmailStatus::Failed
+            ]
+        );
+        assert_eq!(page.items[2].recipient, "jane@example.com");
+        assert_eq!(page.items[2].attempts, 8);
+    }
+
+    #[test]
+    fn test_status_filter_matches_derived_status() {
+        let stmt = EmailOutboxPostgres::list_stmt(
+            DatabaseBackend::Postgres,
+            Some(OutboxEmailStatus::Failed),
+            0,
+            20,
+        );
+
+        assert!(stmt
+            .sql
+            .contains("WHERE sent_at IS NULL AND next_attempt_at IS NULL"));
+    }
+
+    #[tokio::test]
+    async fn test_mark_bounced_reports_unknown_addresses() {
+        let exec = |rows_affected| MockExecResult {
+            last_insert_id: 0,
+            rows_affected,
+        };
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results(vec![exec(1), exec(2), exec(0), exec(0)])
+            .into_connection();
+
+        let outbox = EmailOutboxPostgres::new(Arc::new(db));
+
+        assert!(outbox
+            .mark_bounced(" Jane@Example.com", "hard bounce")
+            .await
+            .unwrap());
+        assert!(!outbox
+            .mark_bounced("nobody@example.com", "hard bounce")
+            .await
+            .unwrap());
+    }
+
+    #[test]
+    fn test_bounce_statements() {
+        let mark = EmailOutboxPostgres::mark_user_bounced_stmt(
+            DatabaseBackend::Postgres,
+            "jane@example.com",
+        );
+        let abandon = EmailOutboxPostgres::abandon_queued_stmt(
+            DatabaseBackend::Postgres,
+            "jane@example.com",
+            "hard bounce",
+        );
+
+        assert!(mark.sql.contains("COALESCE(email_bounced_at, NOW())"));
+        assert!(abandon.sql.contains("SET next_attempt_at = NULL"));
+        assert_eq!(
+            abandon.values.unwrap().0[0],
+            Value::from("jane@example.com")
+        );
+    }
+
+    #[test]
+    fn test_unsubscribe_keeps_transactional_mail() {
+        let user_id = Uuid::new_v4();
+        let abandon =
+            EmailOutboxPostgres::abandon_unsubscribed_stmt(DatabaseBackend::Postgres, user_id);
+
+        assert!(abandon.sql.contains("kind <> $2"));
+        assert_eq!(
+            abandon.values.unwrap().0,
+            vec![Value::from(user_id), Value::from(KIND_VERIFICATION)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_reports_unknown_users() {
+        let exec = |rows_affected| MockExecResult {
+            last_insert_id: 0,
+            rows_affected,
+        };
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results(vec![exec(1), exec(1), exec(0), exec(0)])
+            .into_connection();
+
+        let outbox = EmailOutboxPostgres::new(Arc::new(db));
+
+        assert!(outbox.unsubscribe(Uuid::new_v4()).await.unwrap());
+        assert!(!outbox.unsubscribe(Uuid::new_v4()).await.unwrap());
+    }
+}
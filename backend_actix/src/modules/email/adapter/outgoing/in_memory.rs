@@ -0,0 +1,361 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::email::application::ports::outgoing::{
+    email_outbox::{EmailOutbox, EmailOutboxError, OutboxEmail, OutboxMessage},
+    EmailBounceList, EmailOutboxQuery, EmailUnsubscribeList, OutboxEmailPage, OutboxEmailStatus,
+    OutboxEmailSummary,
+};
+use crate::shared::in_memory::Table;
+
+struct QueuedEmail {
+    id: Uuid,
+    email: OutboxEmail,
+    attempts: u32,
+    last_error: Option<String>,
+    /// `None` once sent or given up on
+    next_attempt_at: Option<DateTime<Utc>>,
+    sent_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl QueuedEmail {
+    fn status(&self) -> OutboxEmailStatus {
+        match (self.sent_at, self.next_attempt_at) {
+            (Some(_), _) => OutboxEmailStatus::Sent,
+            (None, Some(_)) => OutboxEmailStatus::Queued,
+            (None, None) => OutboxEmailStatus::Failed,
+        }
+    }
+
+    fn summary(&self) -> OutboxEmailSummary {
+        let kind = match &self.email {
+            OutboxEmail::Verification(_) => "verification",
+            OutboxEmail::ContactMessage(_) => "contact_message",
+        };
+
+        OutboxEmailSummary {
+            id: self.id,
+            user_id: self.email.user_id(),
+            recipient: self.email.recipient().to_string(),
+            kind: kind.to_string(),
+            status: self.status(),
+            attempts: self.attempts,
+            last_error: self.last_error.clone(),
+            next_attempt_at: self.next_attempt_at,
+            sent_at: self.sent_at,
+            created_at: self.created_at,
+        }
+    }
+}
+
+/// Process-local `EmailOutbox`. Sent messages and messages given up on stay
+/// in the table, like rows in `email_outbox`. There is no users table to
+/// mark, so bounced addresses and unsubscribed users are kept here.
+#[derive(Clone, Default)]
+pub struct InMemoryEmailOutbox {
+    queue: Table<QueuedEmail>,
+    bounced: Table<String>,
+    unsubscribed: Table<Uuid>,
+}
+
+impl InMemoryEmailOutbox {
+    /// Queue `email` for the dispatcher; the in-memory counterpart of
+    /// `EmailOutboxPostgres::enqueue`
+    pub fn enqueue(&self, email: OutboxEmail) {
+        let recipient = email.recipient().to_lowercase();
+        if self.bounced.read(|bounced| bounced.contains(&recipient)) {
+            return;
+        }
+        if !email.is_transactional()
+            && self
+                .unsubscribed
+                .read(|unsubscribed| unsubscribed.contains(&email.user_id()))
+        {
+            return;
+        }
+        self.queue.write(|queue| {
+            queue.push(QueuedEmail {
+                id: Uuid::new_v4(),
+                email,
+                attempts: 0,
+                last_error: None,
+                next_attempt_at: Some(Utc::now()),
+                sent_at: None,
+                created_at: Utc::now(),
+            })
+        });
+    }
+}
+
+#[async_trait]
+impl EmailOutbox for InMemoryEmailOutbox {
+    async fn claim_due(
+        &self,
+        limit: u32,
+        lease_until: DateTime<Utc>,
+    ) -> Result<Vec<OutboxMessage>, EmailOutboxError> {
+        let now = Utc::now();
+
+        Ok(self.queue.write(|queue| {
+            queue
+                .iter_mut()
+                .filter(|queued| queued.next_attempt_at.is_some_and(|at| at <= now))
+                .take(limit as usize)
+                .map(|queued| {
+                    queued.attempts += 1;
+                    queued.next_attempt_at = Some(lease_until);
+                    OutboxMessage {
+                        id: queued.id,
+                        attempts: queued.attempts,
+                        email: queued.email.clone(),
+                    }
+                })
+                .collect()
+        }))
+    }
+
+    async fn mark_sent(&self, id: Uuid) -> Result<(), EmailOutboxError> {
+        self.queue.write(|queue| {
+            if let Some(queued) = queue.iter_mut().find(|queued| queued.id == id) {
+                queued.sent_at = Some(Utc::now());
+                queued.next_attempt_at = None;
+                queued.last_error = None;
+            }
+        });
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), EmailOutboxError> {
+        self.queue.write(|queue| {
+            if let Some(queued) = queue.iter_mut().find(|queued| queued.id == id) {
+                queued.next_attempt_at = retry_at;
+                queued.last_error = Some(error.to_string());
+            }
+        });
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EmailOutboxQuery for InMemoryEmailOutbox {
+    async fn list(
+        &self,
+        status: Option<OutboxEmailStatus>,
+        offset: u64,
+        limit: u32,
+    ) -> Result<OutboxEmailPage, EmailOutboxError> {
+        Ok(self.queue.read(|queue| {
+            let matching: Vec<&QueuedEmail> = queue
+                .iter()
+                .rev()
+                .filter(|queued| status.is_none() || status == Some(queued.status()))
+                .collect();
+
+            OutboxEmailPage {
+                total: matching.len() as u64,
+                items: matching
+                    .into_iter()
+                    .skip(offset as usize)
+                    .take(limit as usize)
+                    .map(QueuedEmail::summary)
+                    .collect(),
+            }
+        }))
+    }
+}
+
+#[async_trait]
+impl EmailBounceList for InMemoryEmailOutbox {
+    async fn mark_bounced(&self, email: &str, reason: &str) -> Result<bool, EmailOutboxError> {
+        let email = email.trim().to_lowercase();
+        self.bounced.write(|bounced| {
+            if !bounced.contains(&email) {
+                bounced.push(email.clone());
+            }
+        });
+
+        Ok(self.queue.write(|queue| {
+            let mut known = false;
+            for queued in queue
+                .iter_mut()
+                .filter(|queued| queued.email.recipient().eq_ignore_ascii_case(&email))
+            {
+                known = true;
+                if queued.sent_at.is_none() && queued.next_attempt_at.is_some() {
+                    queued.next_attempt_at = None;
+                    queued.last_error = Some(reason.to_string());
+                }
+            }
+            known
+        }))
+    }
+}
+
+/// Every signed user id is accepted: there is no users table to check
+#[async_trait]
+impl EmailUnsubscribeList for InMemoryEmailOutbox {
+    async fn unsubscribe(&self, user_id: Uuid) -> Result<bool, EmailOutboxError> {
+        self.unsubscribed.write(|unsubscribed| {
+            if !unsubscribed.contains(&user_id) {
+                unsubscribed.push(user_id);
+            }
+        });
+        self.queue.write(|queue| {
+            for queued in queue.iter_mut().filter(|queued| {
+                queued.email.user_id() == user_id
+                    && !queued.email.is_transactional()
+                    && queued.sent_at.is_none()
+                    && queued.next_attempt_at.is_some()
+            }) {
+                queued.next_attempt_at = None;
+                queued.last_error = Some("unsubscribed".to_string());
+            }
+        });
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::application::use_cases::create_user::CreateUserOutput;
+    use crate::email::application::ports::outgoing::email_outbox::ContactNotification;
+
+    fn verification() -> OutboxEmail {
+        OutboxEmail::Verification(CreateUserOutput {
+            user_id: Uuid::new_v4(),
+            email: "jane@example.com".to_string(),
+            username: "jane".to_string(),
+            full_name: "Jane Doe".to_string(),
+            preferred_locale: "en".to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_claimed_message_is_leased_until_retry() {
+        let outbox = InMemoryEmailOutbox::default();
+        outbox.enqueue(verification());
+
+        let claimed = outbox
+            .claim_due(10, Utc::now() + chrono::Duration::minutes(5))
+            .await
+            .unwrap();
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(claimed[0].attempts, 1);
+
+        // Leased: a second dispatcher pass finds nothing
+        assert!(outbox.claim_due(10, Utc::now()).await.unwrap().is_empty());
+
+        outbox
+            .mark_failed(claimed[0].id, "smtp down", Some(Utc::now()))
+            .await
+            .unwrap();
+        let retried = outbox.claim_due(10, Utc::now()).await.unwrap();
+        assert_eq!(retried[0].attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn test_sent_and_abandoned_messages_are_kept_but_not_claimed() {
+        let outbox = InMemoryEmailOutbox::default();
+        outbox.enqueue(verification());
+        outbox.enqueue(verification());
+        outbox.enqueue(verification());
+
+        let claimed = outbox.claim_due(2, Utc::now()).await.unwrap();
+        outbox.mark_sent(claimed[0].id).await.unwrap();
+        outbox
+            .mark_failed(claimed[1].id, "bad address", None)
+            .await
+            .unwrap();
+
+        assert!(outbox
+            .claim_due(10, Utc::now() + chrono::Duration::hours(1))
+            .await
+            .unwrap()
+            .iter()
+            .all(|message| message.id != claimed[0].id && message.id != claimed[1].id));
+
+        let all = outbox.list(None, 0, 20).await.unwrap();
+        assert_eq!(all.total, 3);
+        let failed = outbox
+            .list(Some(OutboxEmailStatus::Failed), 0, 20)
+            .await
+            .unwrap();
+        assert_eq!(failed.total, 1);
+        assert_eq!(failed.items[0].id, claimed[1].id);
+        assert_eq!(failed.items[0].last_error.as_deref(), Some("bad address"));
+        assert_eq!(failed.items[0].recipient, "jane@example.com");
+        let sent = outbox
+            .list(Some(OutboxEmailStatus::Sent), 0, 20)
+            .await
+            .unwrap();
+        assert_eq!(sent.items[0].id, claimed[0].id);
+    }
+
+    #[tokio::test]
+    async fn test_bounced_address_gets_no_more_mail() {
+        let outbox = InMemoryEmailOutbox::default();
+        outbox.enqueue(verification());
+
+        assert!(outbox
+            .mark_bounced("Jane@Example.com", "hard bounce")
+            .await
+            .unwrap());
+        outbox.enqueue(verification());
+
+        assert!(outbox.claim_due(10, Utc::now()).await.unwrap().is_empty());
+        let all = outbox.list(None, 0, 20).await.unwrap();
+        assert_eq!(all.total, 1);
+        assert_eq!(all.items[0].status, OutboxEmailStatus::Failed);
+        assert_eq!(all.items[0].last_error.as_deref(), Some("hard bounce"));
+        assert!(!outbox
+            .mark_bounced("nobody@example.com", "hard bounce")
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribed_user_still_gets_account_mail() {
+        let outbox = InMemoryEmailOutbox::default();
+        let user_id = Uuid::new_v4();
+        let contact_message = || {
+            OutboxEmail::ContactMessage(ContactNotification {
+                owner_id: user_id,
+                owner_email: "owner@example.com".to_string(),
+                owner_username: "owner".to_string(),
+                preferred_locale: "en".to_string(),
+                message_id: Uuid::new_v4(),
+                sender_name: "Jane".to_string(),
+                sender_email: "jane@example.com".to_string(),
+                subject: None,
+                message: "Hi".to_string(),
+            })
+        };
+        let OutboxEmail::Verification(mut user) = verification() else {
+            unreachable!()
+        };
+        user.user_id = user_id;
+        outbox.enqueue(contact_message());
+
+        assert!(outbox.unsubscribe(user_id).await.unwrap());
+        outbox.enqueue(contact_message());
+        outbox.enqueue(OutboxEmail::Verification(user));
+
+        let claimed = outbox.claim_due(10, Utc::now()).await.unwrap();
+        assert_eq!(claimed.len(), 1);
+        assert!(matches!(claimed[0].email, OutboxEmail::Verification(_)));
+        let failed = outbox
+            .list(Some(OutboxEmailStatus::Failed), 0, 20)
+            .await
+            .unwrap();
+        assert_eq!(failed.total, 1);
+        assert_eq!(failed.items[0].last_error.as_deref(), Some("unsubscribed"));
+    }
+}
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use tracing::info;
+
+use crate::email::application::ports::outgoing::email_sender::{EmailContent, EmailSender};
+
+/// Writes each email to the log instead of sending it. Used without SMTP
+/// (`RUN_MODE=standalone`), so verification links can be copied from the
+/// server output.
+#[derive(Clone, Default)]
+pub struct LogEmailSender;
+
+#[async_trait]
+impl EmailSender for LogEmailSender {
+    async fn send_email(&self, to: &str, content: &EmailContent) -> Result<(), String> {
+        info!(
+            to,
+            subject = content.subject,
+            body = content.text,
+            unsubscribe_url = content.unsubscribe_url,
+            "Email not sent (standalone mode)"
+        );
+        Ok(())
+    }
+}
@@ -1 +1,6 @@
+pub mod email_outbox_postgres;
+pub mod in_memory;
+pub mod log_sender;
+pub mod sendgrid_sender;
+pub mod ses_sender;
 pub mod smtp_sender;
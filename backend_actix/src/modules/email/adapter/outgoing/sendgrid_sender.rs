@@ -0,0 +1,140 @@
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::time::Duration;
+
+use crate::email::application::ports::outgoing::email_sender::{
+    EmailContent, EmailSender, LIST_UNSUBSCRIBE_POST,
+};
+
+const SENDGRID_SEND_URL: &str = "https://api.sendgrid.com/v3/mail/send";
+const SEND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Sends through the SendGrid v3 mail API over HTTPS, for hosts that block
+/// outbound SMTP.
+#[derive(Clone)]
+pub struct SendGridEmailSender {
+    client: reqwest::Client,
+    api_key: String,
+    from_email: String,
+}
+
+impl SendGridEmailSender {
+    pub fn new(api_key: &str, from_email: &str) -> Result<Self, String> {
+        let client = reqwest::Client::builder()
+            .timeout(SEND_TIMEOUT)
+            .build()
+            .map_err(|e| e.to_string())?;
+
+        Ok(Self {
+            client,
+            api_key: api_key.to_string(),
+            from_email: from_email.to_string(),
+        })
+    }
+
+    /// `POST /v3/mail/send` body. SendGrid wants `text/plain` before `text/html`.
+    fn request_body(&self, to: &str, content: &EmailContent) -> Value {
+        let mut body = json!({
+            "personalizations": [{ "to": [{ "email": to }] }],
+            "from": { "email": self.from_email },
+            "subject": content.subject,
+            "content": [
+                { "type": "text/plain", "value": content.text },
+                { "type": "text/html", "value": content.html },
+            ],
+        });
+        if let Some(url) = &content.unsubscribe_url {
+            body["headers"] = json!({
+                "List-Unsubscribe": format!("<{url}>"),
+                "List-Unsubscribe-Post": LIST_UNSUBSCRIBE_POST,
+            });
+        }
+        body
+    }
+}
+
+#[async_trait]
+impl EmailSender for SendGridEmailSender {
+    async fn send_email(&self, to: &str, content: &EmailContent) -> Result<(), String> {
+        let body =
+            serde_json::to_vec(&self.request_body(to, content)).map_err(|e| e.to_string())?;
+
+        let response = self
+            .client
+            .post(SENDGRID_SEND_URL)
+            .bearer_auth(&self.api_key)
+            .header("content-type", "application/json")
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        // The body lists what was wrong, e.g. an unverified sender identity
+        let body = response.text().await.unwrap_or_default();
+        Err(format!(
+            "SendGrid answered HTTP {}: {}",
+            status.as_u16(),
+            body
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_body_has_both_parts() {
+        let sender = SendGridEmailSender::new("SG.key", "noreply@example.com").unwrap();
+
+        let body = sender.request_body(
+            "jane@example.com",
+            &EmailContent {
+                subject: "Verify Your Email".to_string(),
+                html: "<p>Hi</p>".to_string(),
+                text: "Hi".to_string(),
+                unsubscribe_url: None,
+            },
+        );
+
+        assert_eq!(
+            body["personalizations"][0]["to"][0]["email"],
+            "jane@example.com"
+        );
+        assert_eq!(body["from"]["email"], "noreply@example.com");
+        assert_eq!(body["subject"], "Verify Your Email");
+        assert_eq!(body["content"][0]["type"], "text/plain");
+        assert_eq!(body["content"][0]["value"], "Hi");
+        assert_eq!(body["content"][1]["type"], "text/html");
+        assert_eq!(body["content"][1]["value"], "<p>Hi</p>");
+        assert!(body.get("headers").is_none());
+    }
+
+    #[test]
+    fn test_request_body_has_unsubscribe_headers() {
+        let sender = SendGridEmailSender::new("SG.key", "noreply@example.com").unwrap();
+
+        let body = sender.request_body(
+            "jane@example.com",
+            &EmailContent {
+                subject: "New Message from Your Contact Form".to_string(),
+                html: "<p>Hi</p>".to_string(),
+                text: "Hi".to_string(),
+                unsubscribe_url: Some("https://app.test/unsubscribe?token=t".to_string()),
+            },
+        );
+
+        assert_eq!(
+            body["headers"]["List-Unsubscribe"],
+            "<https://app.test/unsubscribe?token=t>"
+        );
+        assert_eq!(
+            body["headers"]["List-Unsubscribe-Post"],
+            "List-Unsubscribe=One-Click"
+        );
+    }
+}
@@ -1,17 +1,25 @@
-use crate::email::application::ports::outgoing::email_sender::EmailSender;
+use crate::email::application::ports::outgoing::email_sender::{
+    EmailContent, EmailSender, LIST_UNSUBSCRIBE_POST,
+};
 use async_trait::async_trait;
+use lettre::message::header::{HeaderName, HeaderValue};
 use lettre::transport::smtp::authentication::Credentials;
-use lettre::{
-    message::header::ContentType, AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
-};
+use lettre::{message::MultiPart, AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use std::sync::Arc;
 
 #[async_trait]
 pub trait Mailer: Send + Sync {
     async fn send(&self, email: Message) -> Result<(), String>;
+
+    /// Open a connection to the server without sending anything.
+    /// Used by the readiness probe; mailers without a server are always reachable.
+    async fn test_connection(&self) -> Result<(), String> {
+        Ok(())
+    }
 }
 
 pub struct SmtpEmailSender {
-    mailer: Box<dyn Mailer>,
+    mailer: Arc<dyn Mailer>,
     from_email: String,
 }
 
@@ -23,12 +31,20 @@ impl Mailer for AsyncSmtpTransport<Tokio1Executor> {
             .map(|_resp| ())
             .map_err(|e| e.to_string())
     }
+
+    async fn test_connection(&self) -> Result<(), String> {
+        match AsyncSmtpTransport::test_connection(self).await {
+            Ok(true) => Ok(()),
+            Ok(false) => Err("SMTP server did not accept the connection".to_string()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
 }
 
 impl SmtpEmailSender {
     pub fn new_with_mailer(mailer: Box<dyn Mailer>, from_email: &str) -> Self {
         Self {
-            mailer,
+            mailer: Arc::from(mailer),
             from_email: from_email.to_string(),
         }
     }
@@ -45,7 +61,7 @@ impl SmtpEmailSender {
             .credentials(creds)
             .build();
 
-        let mailer: Box<dyn Mailer> = Box::new(transport);
+        let mailer: Arc<dyn Mailer> = Arc::new(transport);
 
         Self {
             mailer,
@@ -59,21 +75,40 @@ impl SmtpEmailSender {
             .build();
 
         Self {
-            mailer: Box::new(transport),
+            mailer: Arc::new(transport),
             from_email: from_email.to_string(),
         }
     }
+
+    /// Shared handle to the transport, for connectivity checks.
+    pub fn mailer(&self) -> Arc<dyn Mailer> {
+        Arc::clone(&self.mailer)
+    }
 }
 
 #[async_trait]
 impl EmailSender for SmtpEmailSender {
-    async fn send_email(&self, to: &str, subject: &str, body: &str) -> Result<(), String> {
-        let email = Message::builder()
+    async fn send_email(&self, to: &str, content: &EmailContent) -> Result<(), String> {
+        let mut builder = Message::builder()
             .from(self.from_email.parse().map_err(|e| format!("{:?}", e))?)
             .to(to.parse().map_err(|e| format!("{:?}", e))?)
-            .subject(subject)
-            .header(ContentType::TEXT_HTML)
-            .body(body.to_string())
+            .subject(&content.subject);
+        if let Some(url) = &content.unsubscribe_url {
+            builder = builder
+                .raw_header(HeaderValue::new(
+                    HeaderName::new_from_ascii_str("List-Unsubscribe"),
+                    format!("<{url}>"),
+                ))
+                .raw_header(HeaderValue::new(
+                    HeaderName::new_from_ascii_str("List-Unsubscribe-Post"),
+                    LIST_UNSUBSCRIBE_POST.to_string(),
+                ));
+        }
+        let email = builder
+            .multipart(MultiPart::alternative_plain_html(
+                content.text.clone(),
+                content.html.clone(),
+            ))
             .map_err(|e| e.to_string())?;
 
         self.mailer.send(email).await.map_err(|e| e.to_string())?;
@@ -86,6 +121,15 @@ mod tests {
     use super::*;
     use tokio;
 
+    fn content(subject: &str, html: &str) -> EmailContent {
+        EmailContent {
+            subject: subject.to_string(),
+            html: html.to_string(),
+            text: "Body".to_string(),
+            unsubscribe_url: None,
+        }
+    }
+
     struct MockMailer;
     #[async_trait]
     impl Mailer for MockMailer {
@@ -99,12 +143,46 @@ mod tests {
         let sender = SmtpEmailSender::new_with_mailer(Box::new(MockMailer), "sender@example.com");
 
         let result = sender
-            .send_email("recipient@example.com", "Test", "<p>Unit test</p>")
+            .send_email(
+                "recipient@example.com",
+                &content("Test", "<p>Unit test</p>"),
+            )
             .await;
 
         assert!(result.is_ok(), "Expected Ok, got {:?}", result);
     }
 
+    #[tokio::test]
+    async fn test_send_email_adds_list_unsubscribe_headers() {
+        struct CapturingMailer(Arc<std::sync::Mutex<String>>);
+        #[async_trait]
+        impl Mailer for CapturingMailer {
+            async fn send(&self, email: Message) -> Result<(), String> {
+                *self.0.lock().unwrap() = String::from_utf8_lossy(&email.formatted()).into_owned();
+                Ok(())
+            }
+        }
+
+        let sent = Arc::new(std::sync::Mutex::new(String::new()));
+        let sender = SmtpEmailSender::new_with_mailer(
+            Box::new(CapturingMailer(Arc::clone(&sent))),
+            "sender@example.com",
+        );
+        let mut content = content("Test", "<p>Unit test</p>");
+        content.unsubscribe_url = Some("https://app.test/api/public/unsubscribe?token=t".into());
+
+        sender
+            .send_email("recipient@example.com", &content)
+            .await
+            .unwrap();
+
+        let sent = sent.lock().unwrap();
+        assert!(
+            sent.contains("List-Unsubscribe: <https://app.test/api/public/unsubscribe?token=t>")
+        );
+        assert!(sent.contains("List-Unsubscribe-Post: List-Unsubscribe=One-Click"));
+    }
+
     #[tokio::test]
     async fn test_send_email_invalid_from_address() {
         struct DummyMailer;
@@ -121,7 +199,7 @@ mod tests {
         );
 
         let result = sender
-            .send_email("recipient@example.com", "Subject", "<p>Test</p>")
+            .send_email("recipient@example.com", &content("Subject", "<p>Test</p>"))
             .await;
 
         assert!(
@@ -150,7 +228,7 @@ mod tests {
         let sender = SmtpEmailSender::new_with_mailer(Box::new(DummyMailer), "sender@example.com");
 
         let result = sender
-            .send_email("not-an-email", "Subject", "<p>Test</p>")
+            .send_email("not-an-email", &content("Subject", "<p>Test</p>"))
             .await;
 
         assert!(result.is_err(), "Expected error from invalid 'to' address");
@@ -170,7 +248,7 @@ mod tests {
         let sender = SmtpEmailSender::new("smtp.invalid.local", "user", "pass", "bad-from-email");
 
         let result = sender
-            .send_email("not-an-email", "Subject", "<p>Body</p>")
+            .send_email("not-an-email", &content("Subject", "<p>Body</p>"))
             .await;
 
         assert!(
@@ -200,7 +278,7 @@ mod tests {
         // This will panic at `.unwrap()` in `relay()` if domain is invalid DNS
         // So we make sure to use a *syntactically valid* domain that doesn't resolve
         let result = sender
-            .send_email("to@example.com", "Subject", "<p>Body</p>")
+            .send_email("to@example.com", &content("Subject", "<p>Body</p>"))
             .await;
 
         assert!(
@@ -222,12 +300,11 @@ mod tests {
 
         // "" is a valid parseable address (parsed as empty local part), but invalid for email sending
         let result = sender
-            .send_email("receiver@xample.com", "", "<p>Body</p>")
+            .send_email("receiver@xample.com", &content("", "<p>Body</p>"))
             .await;
 
         match result {
             Err(e) => {
-                println!("Got error: {}", e); // Add this
                 assert!(
                     e.contains("InvalidInput"),
                     "Unexpected error message: {}",
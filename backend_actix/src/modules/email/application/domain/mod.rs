@@ -0,0 +1 @@
+pub mod unsubscribe_token;
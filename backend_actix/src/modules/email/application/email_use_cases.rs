@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use crate::email::application::domain::unsubscribe_token::UnsubscribeTokens;
+use crate::email::application::ports::incoming::use_cases::{
+    ListOutboxEmailsUseCase, RecordEmailEventsUseCase, UnsubscribeUseCase,
+};
+use crate::email::application::ports::outgoing::{
+    EmailBounceList, EmailOutboxQuery, EmailUnsubscribeList,
+};
+use crate::email::application::services::{
+    ListOutboxEmailsService, RecordEmailEventsService, UnsubscribeService,
+};
+use crate::shared::metrics::Metered;
+
+#[derive(Clone)]
+pub struct EmailUseCases {
+    pub list_outbox: Arc<dyn ListOutboxEmailsUseCase + Send + Sync>,
+    pub record_events: Arc<dyn RecordEmailEventsUseCase + Send + Sync>,
+    pub unsubscribe: Arc<dyn UnsubscribeUseCase + Send + Sync>,
+}
+
+impl EmailUseCases {
+    /// `outbox` is the email outbox, which also keeps bounces and unsubscribes
+    pub fn build<O>(outbox: O, unsubscribe_tokens: UnsubscribeTokens) -> Self
+    where
+        O: EmailOutboxQuery + EmailBounceList + EmailUnsubscribeList + Clone + 'static,
+    {
+        Self {
+            list_outbox: Arc::new(Metered(ListOutboxEmailsService::new(outbox.clone()))),
+            record_events: Arc::new(Metered(RecordEmailEventsService::new(outbox.clone()))),
+            unsubscribe: Arc::new(Metered(UnsubscribeService::new(outbox, unsubscribe_tokens))),
+        }
+    }
+}
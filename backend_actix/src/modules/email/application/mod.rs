@@ -1,2 +1,5 @@
+pub mod domain;
+pub mod email_use_cases;
 pub mod ports;
 pub mod services;
+pub mod templates;
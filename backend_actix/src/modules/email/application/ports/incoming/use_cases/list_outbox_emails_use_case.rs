@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+
+use crate::email::application::ports::outgoing::{OutboxEmailPage, OutboxEmailStatus};
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ListOutboxEmailsError {
+    #[error("Failed to list outbox emails: {0}")]
+    QueryFailed(String),
+}
+
+#[async_trait]
+pub trait ListOutboxEmailsUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        status: Option<OutboxEmailStatus>,
+        offset: u64,
+        limit: u32,
+    ) -> Result<OutboxEmailPage, ListOutboxEmailsError>;
+}
+
+metered_use_case!(
+    "email",
+    "list_outbox_emails",
+    ListOutboxEmailsUseCase,
+    fn execute(
+        &self,
+        status: Option<OutboxEmailStatus>,
+        offset: u64,
+        limit: u32,
+    ) -> Result<OutboxEmailPage, ListOutboxEmailsError>
+);
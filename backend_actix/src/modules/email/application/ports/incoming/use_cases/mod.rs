@@ -0,0 +1,10 @@
+mod list_outbox_emails_use_case;
+mod record_email_events_use_case;
+mod unsubscribe_use_case;
+
+pub use list_outbox_emails_use_case::{ListOutboxEmailsError, ListOutboxEmailsUseCase};
+pub use record_email_events_use_case::{
+    EmailEvent, EmailEventKind, EmailEventsSummary, RecordEmailEventsError,
+    RecordEmailEventsUseCase,
+};
+pub use unsubscribe_use_case::{UnsubscribeError, UnsubscribeUseCase};
@@ -0,0 +1,53 @@
+use crate::shared::metrics::metered_use_case;
+use async_trait::async_trait;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// What the email provider reported for one recipient
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmailEventKind {
+    Delivered,
+    /// Temporary failure (full mailbox, greylisting); the provider retries
+    SoftBounce,
+    /// The address does not exist or refuses mail for good
+    HardBounce,
+    /// The recipient marked the email as spam
+    Complaint,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailEvent {
+    pub recipient: String,
+    pub kind: EmailEventKind,
+    /// Provider's reason, e.g. an SMTP diagnostic
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, ToSchema)]
+pub struct EmailEventsSummary {
+    /// Events in the delivery
+    pub received: usize,
+    /// Addresses of known users marked as bounced
+    pub bounced: usize,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum RecordEmailEventsError {
+    #[error("Failed to record email events: {0}")]
+    StoreFailed(String),
+}
+
+#[async_trait]
+pub trait RecordEmailEventsUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        events: Vec<EmailEvent>,
+    ) -> Result<EmailEventsSummary, RecordEmailEventsError>;
+}
+
+metered_use_case!(
+    "email",
+    "record_email_events",
+    RecordEmailEventsUseCase,
+    fn execute(&self, events: Vec<EmailEvent>) -> Result<EmailEventsSummary, RecordEmailEventsError>
+);
@@ -0,0 +1,25 @@
+use crate::shared::metrics::metered_use_case;
+use async_trait::async_trait;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum UnsubscribeError {
+    #[error("Invalid unsubscribe token")]
+    InvalidToken,
+
+    #[error("Failed to unsubscribe: {0}")]
+    StoreFailed(String),
+}
+
+/// Opt a user out of non-transactional mail with the signed token from an
+/// unsubscribe link; no login needed.
+#[async_trait]
+pub trait UnsubscribeUseCase: Send + Sync {
+    async fn execute(&self, token: &str) -> Result<(), UnsubscribeError>;
+}
+
+metered_use_case!(
+    "email",
+    "unsubscribe",
+    UnsubscribeUseCase,
+    fn execute(&self, token: &str) -> Result<(), UnsubscribeError>
+);
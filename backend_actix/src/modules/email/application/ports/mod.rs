@@ -1 +1,2 @@
+pub mod incoming;
 pub mod outgoing;
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+
+use super::email_outbox::EmailOutboxError;
+
+/// Addresses the email provider reported as undeliverable. Nothing more is
+/// queued for them, and mail already queued is given up on.
+#[async_trait]
+pub trait EmailBounceList: Send + Sync {
+    /// Mark `email` as bounced and give up on its queued mail with `reason`.
+    /// Returns `false` when no user has the address.
+    async fn mark_bounced(&self, email: &str, reason: &str) -> Result<bool, EmailOutboxError>;
+}
@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::auth::application::use_cases::create_user::CreateUserOutput;
+
+/// An email waiting in the outbox, with what is needed to render it
+#[derive(Debug, Clone)]
+pub enum OutboxEmail {
+    Verification(CreateUserOutput),
+    ContactMessage(ContactNotification),
+}
+
+impl OutboxEmail {
+    /// User the email is addressed to
+    pub fn user_id(&self) -> Uuid {
+        match self {
+            OutboxEmail::Verification(user) => user.user_id,
+            OutboxEmail::ContactMessage(notification) => notification.owner_id,
+        }
+    }
+
+    /// Account mail, sent even to users who unsubscribed
+    pub fn is_transactional(&self) -> bool {
+        matches!(self, OutboxEmail::Verification(_))
+    }
+
+    pub fn recipient(&self) -> &str {
+        match self {
+            OutboxEmail::Verification(user) => &user.email,
+            OutboxEmail::ContactMessage(notification) => &notification.owner_email,
+        }
+    }
+}
+
+/// A contact form message, forwarded to one of the site owners
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContactNotification {
+    pub owner_id: Uuid,
+    pub owner_email: String,
+    pub owner_username: String,
+    pub preferred_locale: String,
+    pub message_id: Uuid,
+    pub sender_name: String,
+    pub sender_email: String,
+    pub subject: Option<String>,
+    pub message: String,
+}
+
+/// A message claimed for delivery
+#[derive(Debug, Clone)]
+pub struct OutboxMessage {
+    pub id: Uuid,
+    /// Including the attempt this claim is for
+    pub attempts: u32,
+    pub email: OutboxEmail,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum EmailOutboxError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+
+    #[error("Invalid outbox message {0}: {1}")]
+    InvalidMessage(Uuid, String),
+}
+
+/// Delivery side of the email outbox. Rows are written by the repositories that
+/// trigger them, inside their own transaction.
+#[async_trait]
+pub trait EmailOutbox: Send + Sync {
+    /// Take up to `limit` due messages and lease them until `lease_until`, so
+    /// another dispatcher skips them while this one is sending.
+    async fn claim_due(
+        &self,
+        limit: u32,
+        lease_until: DateTime<Utc>,
+    ) -> Result<Vec<OutboxMessage>, EmailOutboxError>;
+
+    async fn mark_sent(&self, id: Uuid) -> Result<(), EmailOutboxError>;
+
+    /// Record a failed attempt. `retry_at: None` gives up on the message.
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), EmailOutboxError>;
+}
@@ -0,0 +1,55 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::email_outbox::EmailOutboxError;
+
+/// Where an outbox message stands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum OutboxEmailStatus {
+    /// Waiting for its first attempt or a retry
+    Queued,
+    Sent,
+    /// Given up on after the last allowed attempt
+    Failed,
+}
+
+/// One outbox message as shown to administrators. The rendered body is not
+/// stored; `kind` says which template it is sent with.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct OutboxEmailSummary {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub recipient: String,
+    /// Template, e.g. `verification`
+    pub kind: String,
+    pub status: OutboxEmailStatus,
+    pub attempts: u32,
+    /// Error of the latest failed attempt
+    pub last_error: Option<String>,
+    /// Next attempt; absent once sent or given up on
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub sent_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutboxEmailPage {
+    pub items: Vec<OutboxEmailSummary>,
+    pub total: u64,
+}
+
+/// Read side of the email outbox, for the admin listing
+#[async_trait]
+pub trait EmailOutboxQuery: Send + Sync {
+    /// Newest first
+    async fn list(
+        &self,
+        status: Option<OutboxEmailStatus>,
+        offset: u64,
+        limit: u32,
+    ) -> Result<OutboxEmailPage, EmailOutboxError>;
+}
@@ -1,6 +1,46 @@
 use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::shared::faults::{faults, Faulty};
+
+/// A rendered email: HTML body with a plain-text alternative
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailContent {
+    pub subject: String,
+    pub html: String,
+    pub text: String,
+    /// One-click unsubscribe link for mail the recipient can opt out of.
+    /// Senders put it in the `List-Unsubscribe` headers (RFC 8058).
+    pub unsubscribe_url: Option<String>,
+}
+
+/// `List-Unsubscribe-Post` value announcing one-click unsubscribe
+pub const LIST_UNSUBSCRIBE_POST: &str = "List-Unsubscribe=One-Click";
 
 #[async_trait]
 pub trait EmailSender: Send + Sync {
-    async fn send_email(&self, to: &str, subject: &str, body: &str) -> Result<(), String>;
+    async fn send_email(&self, to: &str, content: &EmailContent) -> Result<(), String>;
+}
+
+/// Lets the provider be picked at startup and shared as `Arc<dyn EmailSender>`
+#[async_trait]
+impl<S> EmailSender for Arc<S>
+where
+    S: EmailSender + ?Sized,
+{
+    async fn send_email(&self, to: &str, content: &EmailContent) -> Result<(), String> {
+        (**self).send_email(to, content).await
+    }
+}
+
+/// Sends late while an SMTP delay is injected (see `shared::faults`)
+#[async_trait]
+impl<S: EmailSender> EmailSender for Faulty<S> {
+    async fn send_email(&self, to: &str, content: &EmailContent) -> Result<(), String> {
+        let delay = faults().smtp_delay();
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+        self.0.send_email(to, content).await
+    }
 }
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::email_outbox::EmailOutboxError;
+
+/// Users who opted out of non-transactional mail. Nothing but account mail
+/// (e.g. verification) is queued for them, and other queued mail is given up on.
+#[async_trait]
+pub trait EmailUnsubscribeList: Send + Sync {
+    /// Unsubscribe `user_id`. Returns `false` when there is no such user.
+    async fn unsubscribe(&self, user_id: Uuid) -> Result<bool, EmailOutboxError>;
+}
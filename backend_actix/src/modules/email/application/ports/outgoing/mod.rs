@@ -1,3 +1,12 @@
+pub mod email_bounce_list;
+pub mod email_outbox;
+pub mod email_outbox_query;
 pub mod email_sender;
+pub mod email_unsubscribe_list;
 pub mod user_email_notifier;
-pub use email_sender::EmailSender;
+pub use email_bounce_list::EmailBounceList;
+pub use email_outbox_query::{
+    EmailOutboxQuery, OutboxEmailPage, OutboxEmailStatus, OutboxEmailSummary,
+};
+pub use email_sender::{EmailContent, EmailSender};
+pub use email_unsubscribe_list::EmailUnsubscribeList;
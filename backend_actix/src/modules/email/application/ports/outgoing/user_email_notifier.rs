@@ -1,10 +1,14 @@
 use crate::auth::application::use_cases::create_user::CreateUserOutput;
+use crate::email::application::ports::outgoing::email_outbox::ContactNotification;
 
 #[derive(Debug, thiserror::Error)]
 pub enum UserEmailNotificationError {
     #[error("Token generation failed: {0}")]
     TokenGenerationFailed(String),
 
+    #[error("Email rendering failed: {0}")]
+    RenderFailed(String),
+
     #[error("Email sending failed: {0}")]
     EmailSendingFailed(String),
 }
@@ -15,4 +19,9 @@ pub trait UserEmailNotifier: Send + Sync {
         &self,
         user: CreateUserOutput,
     ) -> Result<(), UserEmailNotificationError>;
+
+    async fn send_contact_notification(
+        &self,
+        notification: ContactNotification,
+    ) -> Result<(), UserEmailNotificationError>;
 }
@@ -1,9 +1,13 @@
 use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
 use crate::auth::application::use_cases::create_user::CreateUserOutput;
+use crate::email::application::domain::unsubscribe_token::UnsubscribeTokens;
+use crate::email::application::ports::outgoing::email_outbox::ContactNotification;
 use crate::email::application::ports::outgoing::email_sender::EmailSender;
 use crate::email::application::ports::outgoing::user_email_notifier::{
     UserEmailNotificationError, UserEmailNotifier,
 };
+use crate::email::application::templates::{EmailTemplate, EmailTemplates};
+use uuid::Uuid;
 
 #[derive(Clone, Debug)]
 pub struct UserEmailService<T, E>
@@ -14,6 +18,8 @@ where
     token_provider: T,
     email_sender: E,
     app_url: String,
+    templates: EmailTemplates,
+    unsubscribe_tokens: UnsubscribeTokens,
 }
 
 impl<T, E> UserEmailService<T, E>
@@ -21,42 +27,35 @@ where
     T: TokenProvider + Send + Sync,
     E: EmailSender + Send + Sync,
 {
-    pub fn new(token_provider: T, email_sender: E, app_url: String) -> Self {
+    pub fn new(
+        token_provider: T,
+        email_sender: E,
+        app_url: String,
+        templates: EmailTemplates,
+        unsubscribe_tokens: UnsubscribeTokens,
+    ) -> Self {
         Self {
             token_provider,
             email_sender,
             app_url,
+            templates,
+            unsubscribe_tokens,
         }
     }
 
-    fn create_verification_email(
-        &self,
-        username: &str,
-        verification_token: &str,
-    ) -> (String, String) {
-        let verification_link = format!(
+    fn verification_link(&self, verification_token: &str) -> String {
+        format!(
             "{}/api/auth/email-verification/{}",
             self.app_url, verification_token
-        );
-
-        let subject = "Verify Your Email".to_string();
-        let html_body = format!(
-            r#"
-            <p>Hi {},</p>
-            <p>Welcome to Ekstion! We're excited to have you on board.</p>
-            <p>To complete your registration, click the button below:</p>
-            <p>
-                <a href="{}" style="display: inline-block; padding: 10px 20px; background-color: #007BFF; color: white; text-decoration: none; border-radius: 5px;">
-                    Verify Your Email
-                </a>
-            </p>
-            <p><strong>Note:</strong> This link is valid for 24 hours.</p>
-            <p>Thanks,<br>The Ekstion Team</p>
-            "#,
-            username, verification_link
-        );
+        )
+    }
 
-        (subject, html_body)
+    fn unsubscribe_link(&self, user_id: Uuid) -> String {
+        format!(
+            "{}/api/public/unsubscribe?token={}",
+            self.app_url,
+            self.unsubscribe_tokens.sign(user_id)
+        )
     }
 }
 
@@ -75,10 +74,46 @@ where
             .generate_verification_token(user.user_id)
             .map_err(|e| UserEmailNotificationError::TokenGenerationFailed(e.to_string()))?;
 
-        let (subject, body) = self.create_verification_email(&user.username, &token);
+        let content = self
+            .templates
+            .render(
+                &EmailTemplate::Verification {
+                    username: user.username,
+                    verification_link: self.verification_link(&token),
+                },
+                &user.preferred_locale,
+            )
+            .map_err(|e| UserEmailNotificationError::RenderFailed(e.to_string()))?;
+
+        self.email_sender
+            .send_email(&user.email, &content)
+            .await
+            .map_err(UserEmailNotificationError::EmailSendingFailed)?;
+
+        Ok(())
+    }
+    async fn send_contact_notification(
+        &self,
+        notification: ContactNotification,
+    ) -> Result<(), UserEmailNotificationError> {
+        let unsubscribe_link = self.unsubscribe_link(notification.owner_id);
+        let content = self
+            .templates
+            .render_with_unsubscribe(
+                &EmailTemplate::ContactMessage {
+                    username: notification.owner_username,
+                    sender_name: notification.sender_name,
+                    sender_email: notification.sender_email,
+                    subject: notification.subject,
+                    message: notification.message,
+                },
+                &notification.preferred_locale,
+                Some(&unsubscribe_link),
+            )
+            .map_err(|e| UserEmailNotificationError::RenderFailed(e.to_string()))?;
 
         self.email_sender
-            .send_email(&user.email, &subject, &body)
+            .send_email(&notification.owner_email, &content)
             .await
             .map_err(UserEmailNotificationError::EmailSendingFailed)?;
 
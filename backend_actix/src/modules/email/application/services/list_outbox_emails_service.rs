@@ -0,0 +1,106 @@
+use async_trait::async_trait;
+
+use crate::email::application::ports::{
+    incoming::use_cases::{ListOutboxEmailsError, ListOutboxEmailsUseCase},
+    outgoing::{EmailOutboxQuery, OutboxEmailPage, OutboxEmailStatus},
+};
+
+pub struct ListOutboxEmailsService<Q>
+where
+    Q: EmailOutboxQuery,
+{
+    query: Q,
+}
+
+impl<Q> ListOutboxEmailsService<Q>
+where
+    Q: EmailOutboxQuery,
+{
+    pub fn new(query: Q) -> Self {
+        Self { query }
+    }
+}
+
+#[async_trait]
+impl<Q> ListOutboxEmailsUseCase for ListOutboxEmailsService<Q>
+where
+    Q: EmailOutboxQuery,
+{
+    async fn execute(
+        &self,
+        status: Option<OutboxEmailStatus>,
+        offset: u64,
+        limit: u32,
+    ) -> Result<OutboxEmailPage, ListOutboxEmailsError> {
+        self.query
+            .list(status, offset, limit)
+            .await
+            .map_err(|e| ListOutboxEmailsError::QueryFailed(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    use crate::email::application::ports::outgoing::email_outbox::EmailOutboxError;
+
+    /// Status filter, offset and limit of one listing
+    type ListCall = (Option<OutboxEmailStatus>, u64, u32);
+
+    #[derive(Clone, Default)]
+    struct MockOutboxQuery {
+        fail: bool,
+        calls: Arc<Mutex<Vec<ListCall>>>,
+    }
+
+    #[async_trait]
+    impl EmailOutboxQuery for MockOutboxQuery {
+        async fn list(
+            &self,
+            status: Option<OutboxEmailStatus>,
+            offset: u64,
+            limit: u32,
+        ) -> Result<OutboxEmailPage, EmailOutboxError> {
+            self.calls.lock().unwrap().push((status, offset, limit));
+            if self.fail {
+                return Err(EmailOutboxError::DatabaseError("db down".to_string()));
+            }
+            Ok(OutboxEmailPage {
+                items: Vec::new(),
+                total: 0,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_passes_filter_and_page() {
+        let query = MockOutboxQuery::default();
+        let service = ListOutboxEmailsService::new(query.clone());
+
+        service
+            .execute(Some(OutboxEmailStatus::Failed), 40, 20)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *query.calls.lock().unwrap(),
+            vec![(Some(OutboxEmailStatus::Failed), 40, 20)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_query_error_is_mapped() {
+        let service = ListOutboxEmailsService::new(MockOutboxQuery {
+            fail: true,
+            ..Default::default()
+        });
+
+        let result = service.execute(None, 0, 20).await;
+
+        assert!(
+            matches!(result, Err(ListOutboxEmailsError::QueryFailed(msg)) if msg.contains("db down"))
+        );
+    }
+}
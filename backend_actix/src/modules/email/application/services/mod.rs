@@ -1,2 +1,10 @@
 mod email_service;
+mod list_outbox_emails_service;
+mod outbox_dispatcher;
+mod record_email_events_service;
+mod unsubscribe_service;
 pub use email_service::UserEmailService;
+pub use list_outbox_emails_service::ListOutboxEmailsService;
+pub use outbox_dispatcher::{DispatchPolicy, OutboxDispatcher};
+pub use record_email_events_service::RecordEmailEventsService;
+pub use unsubscribe_service::UnsubscribeService;
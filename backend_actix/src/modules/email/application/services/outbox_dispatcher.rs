@@ -0,0 +1,342 @@
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+use crate::email::application::ports::outgoing::{
+    email_outbox::{EmailOutbox, EmailOutboxError, OutboxEmail, OutboxMessage},
+    user_email_notifier::UserEmailNotifier,
+};
+use crate::shared::lifecycle::ShutdownSignal;
+
+/// How the dispatcher polls and backs off.
+#[derive(Debug, Clone)]
+pub struct DispatchPolicy {
+    pub poll_interval: Duration,
+    pub batch_size: u32,
+    /// How long a claimed message stays invisible to other dispatchers
+    pub lease: Duration,
+    /// Attempts before a message is given up on
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for DispatchPolicy {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(5),
+            batch_size: 20,
+            lease: Duration::from_secs(300),
+            max_attempts: 8,
+            base_delay: Duration::from_secs(30),
+            max_delay: Duration::from_secs(3600),
+        }
+    }
+}
+
+impl DispatchPolicy {
+    /// Delay after the `attempt`-th failure: base, 2x base, 4x base, ... capped at `max_delay`
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.base_delay.saturating_mul(factor).min(self.max_delay)
+    }
+}
+
+/// Delivers queued emails from the outbox with retries and exponential backoff.
+///
+/// Runs as a background job (`BackgroundJobs`); a batch in flight at shutdown is
+/// finished before the job returns.
+pub struct OutboxDispatcher<O, N>
+where
+    O: EmailOutbox,
+    N: UserEmailNotifier,
+{
+    outbox: O,
+    notifier: N,
+    policy: DispatchPolicy,
+}
+
+impl<O, N> OutboxDispatcher<O, N>
+where
+    O: EmailOutbox,
+    N: UserEmailNotifier,
+{
+    pub fn new(outbox: O, notifier: N, policy: DispatchPolicy) -> Self {
+        Self {
+            outbox,
+            notifier,
+            policy,
+        }
+    }
+
+    pub async fn run(self, mut signal: ShutdownSignal) {
+        info!("Email outbox dispatcher started");
+
+        loop {
+            tokio::select! {
+                _ = signal.wait() => break,
+                _ = tokio::time::sleep(self.policy.poll_interval) => {}
+            }
+
+            if let Err(e) = self.dispatch_due(Utc::now()).await {
+                warn!(error = %e, "Email outbox poll failed");
+            }
+        }
+    }
+
+    /// Deliver one batch of due messages. Returns how many were sent.
+    pub async fn dispatch_due(&self, now: DateTime<Utc>) -> Result<usize, EmailOutboxError> {
+        let messages = self
+            .outbox
+            .claim_due(self.policy.batch_size, now + self.policy.lease)
+            .await?;
+
+        let mut sent = 0;
+        for message in messages {
+            match self.deliver(&message).await {
+                Ok(()) => {
+                    self.outbox.mark_sent(message.id).await?;
+                    sent += 1;
+                }
+                Err(reason) => self.record_failure(&message, &reason, now).await?,
+            }
+        }
+
+        Ok(sent)
+    }
+
+    async fn deliver(&self, message: &OutboxMessage) -> Result<(), String> {
+        match &message.email {
+            OutboxEmail::Verification(user) => self
+                .notifier
+                .send_verification_email(user.clone())
+                .await
+                .map_err(|e| e.to_string()),
+            OutboxEmail::ContactMessage(notification) => self
+                .notifier
+                .send_contact_notification(notification.clone())
+                .await
+                .map_err(|e| e.to_string()),
+        }
+    }
+
+    async fn record_failure(
+        &self,
+        message: &OutboxMessage,
+        reason: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(), EmailOutboxError> {
+        let retry_at = if message.attempts >= self.policy.max_attempts {
+            error!(
+                message_id = %message.id,
+                attempts = message.attempts,
+                error = reason,
+                "Giving up on outbox email"
+            );
+            None
+        } else {
+            let delay = self.policy.backoff(message.attempts);
+            warn!(
+                message_id = %message.id,
+                attempts = message.attempts,
+                retry_in_secs = delay.as_secs(),
+                error = reason,
+                "Outbox email delivery failed, will retry"
+            );
+            Some(now + delay)
+        };
+
+        self.outbox.mark_failed(message.id, reason, retry_at).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+    use uuid::Uuid;
+
+    use crate::auth::application::use_cases::create_user::CreateUserOutput;
+    use crate::email::application::ports::outgoing::{
+        email_outbox::ContactNotification, user_email_notifier::UserEmailNotificationError,
+    };
+
+    // =====================================================
+    // Mocks
+    // =====================================================
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Outcome {
+        Sent(Uuid),
+        Failed(Uuid, Option<DateTime<Utc>>),
+    }
+
+    #[derive(Clone, Default)]
+    struct MockOutbox {
+        due: Vec<OutboxMessage>,
+        outcomes: Arc<Mutex<Vec<Outcome>>>,
+    }
+
+    #[async_trait]
+    impl EmailOutbox for MockOutbox {
+        async fn claim_due(
+            &self,
+            limit: u32,
+            _lease_until: DateTime<Utc>,
+        ) -> Result<Vec<OutboxMessage>, EmailOutboxError> {
+            Ok(self.due.iter().take(limit as usize).cloned().collect())
+        }
+
+        async fn mark_sent(&self, id: Uuid) -> Result<(), EmailOutboxError> {
+            self.outcomes.lock().unwrap().push(Outcome::Sent(id));
+            Ok(())
+        }
+
+        async fn mark_failed(
+            &self,
+            id: Uuid,
+            _error: &str,
+            retry_at: Option<DateTime<Utc>>,
+        ) -> Result<(), EmailOutboxError> {
+            self.outcomes
+                .lock()
+                .unwrap()
+                .push(Outcome::Failed(id, retry_at));
+            Ok(())
+        }
+    }
+
+    struct MockNotifier {
+        fail: bool,
+    }
+
+    impl MockNotifier {
+        fn outcome(&self) -> Result<(), UserEmailNotificationError> {
+            if self.fail {
+                Err(UserEmailNotificationError::EmailSendingFailed(
+                    "SMTP down".to_string(),
+                ))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[async_trait]
+    impl UserEmailNotifier for MockNotifier {
+        async fn send_verification_email(
+            &self,
+            _user: CreateUserOutput,
+        ) -> Result<(), UserEmailNotificationError> {
+            self.outcome()
+        }
+
+        async fn send_contact_notification(
+            &self,
+            _notification: ContactNotification,
+        ) -> Result<(), UserEmailNotificationError> {
+            self.outcome()
+        }
+    }
+
+    // =====================================================
+    // Helpers
+    // =====================================================
+
+    fn message(attempts: u32) -> OutboxMessage {
+        OutboxMessage {
+            id: Uuid::new_v4(),
+            attempts,
+            email: OutboxEmail::Verification(CreateUserOutput {
+                user_id: Uuid::new_v4(),
+                email: "valid@example.com".to_string(),
+                username: "validuser".to_string(),
+                full_name: "Valid User".to_string(),
+                preferred_locale: "en".to_string(),
+            }),
+        }
+    }
+
+    fn dispatcher(outbox: MockOutbox, fail: bool) -> OutboxDispatcher<MockOutbox, MockNotifier> {
+        OutboxDispatcher::new(outbox, MockNotifier { fail }, DispatchPolicy::default())
+    }
+
+    // =====================================================
+    // Tests
+    // =====================================================
+
+    #[test]
+    fn test_backoff_doubles_and_is_capped() {
+        let policy = DispatchPolicy::default();
+
+        assert_eq!(policy.backoff(1), Duration::from_secs(30));
+        assert_eq!(policy.backoff(2), Duration::from_secs(60));
+        assert_eq!(policy.backoff(3), Duration::from_secs(120));
+        assert_eq!(policy.backoff(20), Duration::from_secs(3600));
+    }
+
+    #[tokio::test]
+    async fn test_delivered_messages_are_marked_sent() {
+        let first = message(1);
+        let second = message(1);
+        let outbox = MockOutbox {
+            due: vec![first.clone(), second.clone()],
+            ..Default::default()
+        };
+
+        let sent = dispatcher(outbox.clone(), false)
+            .dispatch_due(Utc::now())
+            .await
+            .unwrap();
+
+        assert_eq!(sent, 2);
+        assert_eq!(
+            *outbox.outcomes.lock().unwrap(),
+            vec![Outcome::Sent(first.id), Outcome::Sent(second.id)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_delivery_is_retried_with_backoff() {
+        let now = Utc::now();
+        let queued = message(2);
+        let outbox = MockOutbox {
+            due: vec![queued.clone()],
+            ..Default::default()
+        };
+
+        let sent = dispatcher(outbox.clone(), true)
+            .dispatch_due(now)
+            .await
+            .unwrap();
+
+        assert_eq!(sent, 0);
+        assert_eq!(
+            *outbox.outcomes.lock().unwrap(),
+            vec![Outcome::Failed(
+                queued.id,
+                Some(now + Duration::from_secs(60))
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_attempts() {
+        let queued = message(DispatchPolicy::default().max_attempts);
+        let outbox = MockOutbox {
+            due: vec![queued.clone()],
+            ..Default::default()
+        };
+
+        dispatcher(outbox.clone(), true)
+            .dispatch_due(Utc::now())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *outbox.outcomes.lock().unwrap(),
+            vec![Outcome::Failed(queued.id, None)]
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use async_trait::async_trait;
+use tracing::{debug, info, warn};
+
+use crate::email::application::ports::{
+    incoming::use_cases::{
+        EmailEvent, EmailEventKind, EmailEventsSummary, RecordEmailEventsError,
+        RecordEmailEventsUseCase,
+    },
+    outgoing::EmailBounceList,
+};
+
+/// Applies provider delivery events: hard bounces and spam complaints put the
+/// address on the bounce list, everything else is only logged.
+pub struct RecordEmailEventsService<B>
+where
+    B: EmailBounceList,
+{
+    bounces: B,
+}
+
+impl<B> RecordEmailEventsService<B>
+where
+    B: EmailBounceList,
+{
+    pub fn new(bounces: B) -> Self {
+        Self { bounces }
+    }
+
+    /// `last_error` of the outbox rows given up on because of `event`
+    fn reason(event: &EmailEvent) -> String {
+        let kind = match event.kind {
+            EmailEventKind::Complaint => "spam complaint",
+            _ => "hard bounce",
+        };
+        match &event.detail {
+            Some(detail) => format!("{kind}: {detail}"),
+            None => kind.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl<B> RecordEmailEventsUseCase for RecordEmailEventsService<B>
+where
+    B: EmailBounceList,
+{
+    async fn execute(
+        &self,
+        events: Vec<EmailEvent>,
+    ) -> Result<EmailEventsSummary, RecordEmailEventsError> {
+        let mut summary = EmailEventsSummary {
+            received: events.len(),
+            bounced: 0,
+        };
+
+        for event in &events {
+            match event.kind {
+                EmailEventKind::HardBounce | EmailEventKind::Complaint => {
+                    let known = self
+                        .bounces
+                        .mark_bounced(&event.recipient, &Self::reason(event))
+                        .await
+                        .map_err(|e| RecordEmailEventsError::StoreFailed(e.to_string()))?;
+                    if known {
+                        summary.bounced += 1;
+                        warn!(
+                            recipient = %event.recipient,
+                            kind = ?event.kind,
+                            detail = event.detail.as_deref(),
+                            "Email address marked as bounced"
+                        );
+                    } else {
+                        debug!(recipient = %event.recipient, "Bounce for an unknown address");
+                    }
+                }
+                EmailEventKind::SoftBounce => info!(
+                    recipient = %event.recipient,
+                    detail = event.detail.as_deref(),
+                    "Email soft-bounced"
+                ),
+                EmailEventKind::Delivered => {
+                    debug!(recipient = %event.recipient, "Email delivered")
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    use crate::email::application::ports::outgoing::email_outbox::EmailOutboxError;
+
+    #[derive(Clone, Default)]
+    struct MockBounceList {
+        known: Vec<&'static str>,
+        fail: bool,
+        marked: Arc<Mutex<Vec<(String, String)>>>,
+    }
+
+    #[async_trait]
+    impl EmailBounceList for MockBounceList {
+        async fn mark_bounced(&self, email: &str, reason: &str) -> Result<bool, EmailOutboxError> {
+            if self.fail {
+                return Err(EmailOutboxError::DatabaseError("db down".to_string()));
+            }
+            self.marked
+                .lock()
+                .unwrap()
+                .push((email.to_string(), reason.to_string()));
+            Ok(self.known.contains(&email))
+        }
+    }
+
+    fn event(recipient: &str, kind: EmailEventKind, detail: Option<&str>) -> EmailEvent {
+        EmailEvent {
+            recipient: recipient.to_string(),
+            kind,
+            detail: detail.map(str::to_string),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_only_hard_bounces_and_complaints_are_marked() {
+        let bounces = MockBounceList {
+            known: vec!["gone@example.com", "angry@example.com"],
+            ..Default::default()
+        };
+        let service = RecordEmailEventsService::new(bounces.clone());
+
+        let summary = service
+            .execute(vec![
+                event("ok@example.com", EmailEventKind::Delivered, None),
+                event("full@example.com", EmailEventKind::SoftBounce, Some("452")),
+                event(
+                    "gone@example.com",
+                    EmailEventKind::HardBounce,
+                    Some("550 no such user"),
+                ),
+                event("angry@example.com", EmailEventKind::Complaint, None),
+                event("stranger@example.com", EmailEventKind::HardBounce, None),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            summary,
+            EmailEventsSummary {
+                received: 5,
+                bounced: 2
+            }
+        );
+        assert_eq!(
+            *bounces.marked.lock().unwrap(),
+            vec![
+                (
+                    "gone@example.com".to_string(),
+                    "hard bounce: 550 no such user".to_string()
+                ),
+                (
+                    "angry@example.com".to_string(),
+                    "spam complaint".to_string()
+                ),
+                (
+                    "stranger@example.com".to_string(),
+                    "hard bounce".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_store_error_is_mapped() {
+        let service = RecordEmailEventsService::new(MockBounceList {
+            fail: true,
+            ..Default::default()
+        });
+
+        let result = service
+            .execute(vec![event(
+                "gone@example.com",
+                EmailEventKind::HardBounce,
+                None,
+            )])
+            .await;
+
+        assert!(
+            matches!(result, Err(RecordEmailEventsError::StoreFailed(msg)) if msg.contains("db down"))
+        );
+    }
+}
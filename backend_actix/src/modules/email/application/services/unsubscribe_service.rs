@@ -0,0 +1,126 @@
+use async_trait::async_trait;
+use tracing::{debug, info};
+
+use crate::email::application::domain::unsubscribe_token::UnsubscribeTokens;
+use crate::email::application::ports::{
+    incoming::use_cases::{UnsubscribeError, UnsubscribeUseCase},
+    outgoing::EmailUnsubscribeList,
+};
+
+pub struct UnsubscribeService<U>
+where
+    U: EmailUnsubscribeList,
+{
+    unsubscribes: U,
+    tokens: UnsubscribeTokens,
+}
+
+impl<U> UnsubscribeService<U>
+where
+    U: EmailUnsubscribeList,
+{
+    pub fn new(unsubscribes: U, tokens: UnsubscribeTokens) -> Self {
+        Self {
+            unsubscribes,
+            tokens,
+        }
+    }
+}
+
+#[async_trait]
+impl<U> UnsubscribeUseCase for UnsubscribeService<U>
+where
+    U: EmailUnsubscribeList,
+{
+    async fn execute(&self, token: &str) -> Result<(), UnsubscribeError> {
+        let user_id = self
+            .tokens
+            .verify(token)
+            .ok_or(UnsubscribeError::InvalidToken)?;
+
+        let known = self
+            .unsubscribes
+            .unsubscribe(user_id)
+            .await
+            .map_err(|e| UnsubscribeError::StoreFailed(e.to_string()))?;
+        // A deleted account gets no mail either, so the link still "works"
+        if known {
+            info!(user_id = %user_id, "User unsubscribed from email");
+        } else {
+            debug!(user_id = %user_id, "Unsubscribe for an unknown user");
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use uuid::Uuid;
+
+    use crate::email::application::ports::outgoing::email_outbox::EmailOutboxError;
+
+    const SECRET: &str = "0123456789abcdef0123456789abcdef";
+
+    #[derive(Clone, Default)]
+    struct MockUnsubscribeList {
+        fail: bool,
+        unsubscribed: Arc<Mutex<Vec<Uuid>>>,
+    }
+
+    #[async_trait]
+    impl EmailUnsubscribeList for MockUnsubscribeList {
+        async fn unsubscribe(&self, user_id: Uuid) -> Result<bool, EmailOutboxError> {
+            if self.fail {
+                return Err(EmailOutboxError::DatabaseError("db down".to_string()));
+            }
+            self.unsubscribed.lock().unwrap().push(user_id);
+            Ok(true)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_signed_token_unsubscribes_its_user() {
+        let list = MockUnsubscribeList::default();
+        let tokens = UnsubscribeTokens::new(SECRET);
+        let service = UnsubscribeService::new(list.clone(), tokens.clone());
+        let user_id = Uuid::new_v4();
+
+        service.execute(&tokens.sign(user_id)).await.unwrap();
+
+        assert_eq!(*list.unsubscribed.lock().unwrap(), vec![user_id]);
+    }
+
+    #[tokio::test]
+    async fn test_forged_token_is_rejected() {
+        let list = MockUnsubscribeList::default();
+        let service = UnsubscribeService::new(list.clone(), UnsubscribeTokens::new(SECRET));
+        let forged =
+            UnsubscribeTokens::new("not-the-server-secret-not-the-se").sign(Uuid::new_v4());
+
+        let result = service.execute(&forged).await;
+
+        assert!(matches!(result, Err(UnsubscribeError::InvalidToken)));
+        assert!(list.unsubscribed.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_store_error_is_mapped() {
+        let tokens = UnsubscribeTokens::new(SECRET);
+        let service = UnsubscribeService::new(
+            MockUnsubscribeList {
+                fail: true,
+                ..Default::default()
+            },
+            tokens.clone(),
+        );
+
+        let result = service.execute(&tokens.sign(Uuid::new_v4())).await;
+
+        assert!(
+            matches!(result, Err(UnsubscribeError::StoreFailed(msg)) if msg.contains("db down"))
+        );
+    }
+}
@@ -0,0 +1,462 @@
+//! Email bodies, rendered from Handlebars templates compiled into the binary.
+//!
+//! Every email has an HTML template, wrapped in the `layout` partial, and a
+//! plain-text alternative (`<name>.html.hbs`, `<name>.txt.hbs`). Translations
+//! live in a directory per locale (`id/<name>.html.hbs`) and must cover every
+//! template. Templates run in strict mode and are rendered once per locale
+//! with sample values when [`EmailTemplates`] is built, so a misspelled or
+//! missing variable stops the server at startup instead of failing a send.
+//!
+//! Mail the recipient can opt out of ([`EmailTemplate::is_transactional`]
+//! is `false`) carries an unsubscribe link: the layout shows it under the
+//! sign-off, and the plain-text templates end with it.
+
+use handlebars::Handlebars;
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::auth::application::domain::entities::DEFAULT_LOCALE;
+use crate::email::application::ports::outgoing::email_sender::EmailContent;
+
+const LAYOUT: &str = include_str!("layout.html.hbs");
+
+/// The templates of one locale
+struct LocaleSources {
+    locale: &'static str,
+    /// Sign-off under every HTML email: closing line, then signature
+    sign_off: (&'static str, &'static str),
+    /// Text of the unsubscribe link
+    unsubscribe: &'static str,
+    /// (name, subject, HTML, plain text)
+    templates: [(&'static str, &'static str, &'static str, &'static str); 4],
+}
+
+/// English first: it is the fallback for every other locale
+static LOCALES: [LocaleSources; 2] = [
+    LocaleSources {
+        locale: DEFAULT_LOCALE,
+        sign_off: ("Thanks,", "The Ekstion Team"),
+        unsubscribe: "Unsubscribe from these emails",
+        templates: [
+            (
+                "verification",
+                "Verify Your Email",
+                include_str!("verification.html.hbs"),
+                include_str!("verification.txt.hbs"),
+            ),
+            (
+                "password_reset",
+                "Reset Your Password",
+                include_str!("password_reset.html.hbs"),
+                include_str!("password_reset.txt.hbs"),
+            ),
+            (
+                "new_login",
+                "New Sign-in to Your Account",
+                include_str!("new_login.html.hbs"),
+                include_str!("new_login.txt.hbs"),
+            ),
+            (
+                "contact_message",
+                "New Message from Your Contact Form",
+                include_str!("contact_message.html.hbs"),
+                include_str!("contact_message.txt.hbs"),
+            ),
+        ],
+    },
+    LocaleSources {
+        locale: "id",
+        sign_off: ("Terima kasih,", "Tim Ekstion"),
+        unsubscribe: "Berhenti berlangganan email ini",
+        templates: [
+            (
+                "verification",
+                "Verifikasi Email Anda",
+                include_str!("id/verification.html.hbs"),
+                include_str!("id/verification.txt.hbs"),
+            ),
+            (
+                "password_reset",
+                "Atur Ulang Kata Sandi Anda",
+                include_str!("id/password_reset.html.hbs"),
+                include_str!("id/password_reset.txt.hbs"),
+            ),
+            (
+                "new_login",
+                "Aktivitas Masuk Baru di Akun Anda",
+                include_str!("id/new_login.html.hbs"),
+                include_str!("id/new_login.txt.hbs"),
+            ),
+            (
+                "contact_message",
+                "Pesan Baru dari Formulir Kontak",
+                include_str!("id/contact_message.html.hbs"),
+                include_str!("id/contact_message.txt.hbs"),
+            ),
+        ],
+    },
+];
+
+/// A named template with the variables it renders
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum EmailTemplate {
+    Verification {
+        username: String,
+        verification_link: String,
+    },
+    PasswordReset {
+        username: String,
+        reset_link: String,
+        valid_for_minutes: u32,
+    },
+    NewLogin {
+        username: String,
+        logged_in_at: String,
+        ip_address: String,
+        user_agent: String,
+    },
+    ContactMessage {
+        username: String,
+        sender_name: String,
+        sender_email: String,
+        subject: Option<String>,
+        message: String,
+    },
+}
+
+impl EmailTemplate {
+    pub fn name(&self) -> &'static str {
+        match self {
+            EmailTemplate::Verification { .. } => "verification",
+            EmailTemplate::PasswordReset { .. } => "password_reset",
+            EmailTemplate::NewLogin { .. } => "new_login",
+            EmailTemplate::ContactMessage { .. } => "contact_message",
+        }
+    }
+
+    /// Account mail the user cannot opt out of. Everything else gets an
+    /// unsubscribe link and is not sent once the user has unsubscribed.
+    pub fn is_transactional(&self) -> bool {
+        !matches!(self, EmailTemplate::ContactMessage { .. })
+    }
+
+    /// One of each, for the startup check
+    fn samples() -> Vec<EmailTemplate> {
+        vec![
+            EmailTemplate::Verification {
+                username: "sample".to_string(),
+                verification_link: "https://example.com/verify".to_string(),
+            },
+            EmailTemplate::PasswordReset {
+                username: "sample".to_string(),
+                reset_link: "https://example.com/reset".to_string(),
+                valid_for_minutes: 30,
+            },
+            EmailTemplate::NewLogin {
+                username: "sample".to_string(),
+                logged_in_at: "2026-01-01 00:00 UTC".to_string(),
+                ip_address: "203.0.113.7".to_string(),
+                user_agent: "Firefox on Linux".to_string(),
+            },
+            EmailTemplate::ContactMessage {
+                username: "sample".to_string(),
+                sender_name: "Jane Doe".to_string(),
+                sender_email: "jane@example.com".to_string(),
+                subject: Some("Hello".to_string()),
+                message: "Hi there".to_string(),
+            },
+        ]
+    }
+}
+
+/// Template variables plus what the layout needs
+#[derive(Serialize)]
+struct RenderContext<'a> {
+    locale: &'a str,
+    subject: &'a str,
+    closing: &'a str,
+    signature: &'a str,
+    unsubscribe: &'a str,
+    /// Always present, `null` for transactional mail, so strict mode accepts
+    /// `{{#if unsubscribe_url}}`
+    unsubscribe_url: Option<&'a str>,
+    #[serde(flatten)]
+    template: &'a EmailTemplate,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum EmailTemplateError {
+    #[error("Invalid email template '{0}': {1}")]
+    Invalid(String, String),
+
+    #[error("Failed to render email template '{0}': {1}")]
+    Render(String, String),
+}
+
+#[derive(Debug, Clone)]
+pub struct EmailTemplates {
+    registry: Arc<Handlebars<'static>>,
+}
+
+impl EmailTemplates {
+    /// Compile every template and render each with sample values, in every locale.
+    pub fn new() -> Result<Self, EmailTemplateError> {
+        let mut registry = Handlebars::new();
+        registry.set_strict_mode(true);
+        registry
+            .register_partial("layout", LAYOUT)
+            .map_err(|e| EmailTemplateError::Invalid("layout".to_string(), e.to_string()))?;
+
+        for sources in &LOCALES {
+            for (name, _, html, text) in sources.templates {
+                for (key, source) in [
+                    (format!("{}/{name}.html", sources.locale), html),
+                    (format!("{}/{name}.txt", sources.locale), text),
+                ] {
+                    registry
+                        .register_template_string(&key, source)
+                        .map_err(|e| EmailTemplateError::Invalid(key.clone(), e.to_string()))?;
+                }
+            }
+        }
+
+        let templates = Self {
+            registry: Arc::new(registry),
+        };
+        for sources in &LOCALES {
+            for sample in EmailTemplate::samples() {
+                let unsubscribe_url =
+                    (!sample.is_transactional()).then_some("https://example.com/unsubscribe");
+                templates.render_with_unsubscribe(&sample, sources.locale, unsubscribe_url)?;
+            }
+        }
+
+        Ok(templates)
+    }
+
+    /// Templates for `locale`: an exact match, then its language (`pt-BR`
+    /// falls back to `pt`), then English.
+    fn resolve(locale: &str) -> &'static LocaleSources {
+        let language = locale.split('-').next().unwrap_or(locale);
+        [locale, language]
+            .into_iter()
+            .find_map(|candidate| {
+                LOCALES
+                    .iter()
+                    .find(|sources| sources.locale.eq_ignore_ascii_case(candidate))
+            })
+            .unwrap_or(&LOCALES[0])
+    }
+
+    pub fn render(
+        &self,
+        template: &EmailTemplate,
+        locale: &str,
+    ) -> Result<EmailContent, EmailTemplateError> {
+        self.render_with_unsubscribe(template, locale, None)
+    }
+
+    /// [`render`](Self::render), linking to `unsubscribe_url` in the body and
+    /// the headers
+    pub fn render_with_unsubscribe(
+        &self,
+        template: &EmailTemplate,
+        locale: &str,
+        unsubscribe_url: Option<&str>,
+    ) -> Result<EmailContent, EmailTemplateError> {
+        let sources = Self::resolve(locale);
+        let subject = sources
+            .templates
+            .iter()
+            .find(|(name, ..)| *name == template.name())
+            .map(|(_, subject, ..)| *subject)
+            .ok_or_else(|| {
+                EmailTemplateError::Render(
+                    template.name().to_string(),
+                    format!("missing from locale '{}'", sources.locale),
+                )
+            })?;
+        let context = RenderContext {
+            locale: sources.locale,
+            subject,
+            closing: sources.sign_off.0,
+            signature: sources.sign_off.1,
+            unsubscribe: sources.unsubscribe,
+            unsubscribe_url,
+            template,
+        };
+        let render = |format: &str| {
+            let key = format!("{}/{}.{format}", sources.locale, template.name());
+            self.registry
+                .render(&key, &context)
+                .map_err(|e| EmailTemplateError::Render(key, e.to_string()))
+        };
+
+        Ok(EmailContent {
+            subject: subject.to_string(),
+            html: render("html")?,
+            text: render("txt")?,
+            unsubscribe_url: unsubscribe_url.map(str::to_string),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_templates_compile_and_render() {
+        assert!(EmailTemplates::new().is_ok());
+    }
+
+    #[test]
+    fn test_verification_email_has_html_and_text() {
+        let templates = EmailTemplates::new().unwrap();
+
+        let email = templates
+            .render(
+                &EmailTemplate::Verification {
+                    username: "alice".to_string(),
+                    verification_link: "https://app.test/verify?a=1&b=2".to_string(),
+                },
+                "en",
+            )
+            .unwrap();
+
+        assert_eq!(email.subject, "Verify Your Email");
+        assert!(email.html.contains("<title>Verify Your Email</title>"));
+        assert!(email.html.contains("Hi alice,"));
+        // HTML-escaped in the markup, verbatim in the plain text
+        assert!(email
+            .html
+            .contains("https://app.test/verify?a&#x3D;1&amp;b&#x3D;2"));
+        assert!(email.text.contains("https://app.test/verify?a=1&b=2"));
+        assert!(email.html.contains("The Ekstion Team"));
+        assert!(!email.text.contains('<'));
+    }
+
+    #[test]
+    fn test_renders_in_the_preferred_locale() {
+        let templates = EmailTemplates::new().unwrap();
+        let reset = EmailTemplate::PasswordReset {
+            username: "budi".to_string(),
+            reset_link: "https://app.test/reset".to_string(),
+            valid_for_minutes: 30,
+        };
+
+        let email = templates.render(&reset, "id").unwrap();
+
+        assert_eq!(email.subject, "Atur Ulang Kata Sandi Anda");
+        assert!(email.html.contains("<html lang=\"id\">"));
+        assert!(email.html.contains("Terima kasih,<br>Tim Ekstion"));
+        assert!(email.text.contains("berlaku selama 30 menit"));
+    }
+
+    #[test]
+    fn test_falls_back_to_language_then_english() {
+        let templates = EmailTemplates::new().unwrap();
+        let verification = EmailTemplate::Verification {
+            username: "alice".to_string(),
+            verification_link: "https://app.test/verify".to_string(),
+        };
+
+        let regional = templates.render(&verification, "id-ID").unwrap();
+        let unknown = templates.render(&verification, "fr").unwrap();
+
+        assert_eq!(regional.subject, "Verifikasi Email Anda");
+        assert_eq!(unknown.subject, "Verify Your Email");
+        assert!(unknown.html.contains("<html lang=\"en\">"));
+    }
+
+    #[test]
+    fn test_html_variables_are_escaped() {
+        let templates = EmailTemplates::new().unwrap();
+
+        let email = templates
+            .render(
+                &EmailTemplate::NewLogin {
+                    username: "<script>".to_string(),
+                    logged_in_at: "now".to_string(),
+                    ip_address: "127.0.0.1".to_string(),
+                    user_agent: "curl".to_string(),
+                },
+                "en",
+            )
+            .unwrap();
+
+        assert!(!email.html.contains("<script>"));
+        assert!(email.html.contains("&lt;script&gt;"));
+    }
+
+    #[test]
+    fn test_missing_variable_is_rejected_in_strict_mode() {
+        let mut registry = Handlebars::new();
+        registry.set_strict_mode(true);
+        registry
+            .register_template_string("t", "Hi {{usrname}}")
+            .unwrap();
+
+        let result = registry.render(
+            "t",
+            &EmailTemplate::Verification {
+                username: "alice".to_string(),
+                verification_link: String::new(),
+            },
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_contact_message_without_subject() {
+        let templates = EmailTemplates::new().unwrap();
+
+        let email = templates
+            .render(
+                &EmailTemplate::ContactMessage {
+                    username: "owner".to_string(),
+                    sender_name: "Jane <Doe>".to_string(),
+                    sender_email: "jane@example.com".to_string(),
+                    subject: None,
+                    message: "Line one\nLine two".to_string(),
+                },
+                "en",
+            )
+            .unwrap();
+
+        assert_eq!(email.subject, "New Message from Your Contact Form");
+        assert!(!email.html.contains("Subject:"));
+        assert!(email.html.contains("Jane &lt;Doe&gt;"));
+        assert!(email.text.contains("Jane <Doe> (jane@example.com)"));
+        assert!(email.text.contains("Line one\nLine two"));
+        assert!(!email.html.contains("Unsubscribe"));
+    }
+
+    #[test]
+    fn test_unsubscribe_link_in_body_and_content() {
+        let templates = EmailTemplates::new().unwrap();
+        let url = "https://app.test/api/public/unsubscribe?token=t";
+
+        let email = templates
+            .render_with_unsubscribe(
+                &EmailTemplate::ContactMessage {
+                    username: "owner".to_string(),
+                    sender_name: "Jane".to_string(),
+                    sender_email: "jane@example.com".to_string(),
+                    subject: None,
+                    message: "Hi".to_string(),
+                },
+                "id",
+                Some(url),
+            )
+            .unwrap();
+
+        assert_eq!(email.unsubscribe_url.as_deref(), Some(url));
+        assert!(email.html.contains(
+            "<a href=\"https://app.test/api/public/unsubscribe?token&#x3D;t\">Berhenti berlangganan email ini</a>"
+        ));
+        assert!(email.text.contains(url));
+    }
+}
@@ -0,0 +1,8 @@
+use actix_web::web;
+
+pub mod routes;
+
+/// Routes for signed-in users with a verified email
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::export_content_handler);
+}
@@ -0,0 +1,128 @@
+use actix_web::{get, http::header, web, HttpResponse, Responder};
+use chrono::Utc;
+use futures::StreamExt;
+use tracing::error;
+
+use crate::api::schemas::ErrorResponse;
+use crate::{auth::adapter::incoming::web::extractors::auth::AdminUser, AppState};
+
+/// Download all content as a zip
+///
+/// Every owner's CVs, projects and pages as Markdown files with YAML front
+/// matter under `cvs/`, `projects/` and `pages/`, one folder per owner,
+/// plus `media.json` listing each media file's storage location and where
+/// it's used. Trashed content is left out, and the media files themselves
+/// are not included. The archive is streamed as it's built; if reading
+/// fails midway the download is cut short.
+#[utoipa::path(
+    get,
+    path = "/api/admin/export",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Zip archive", content_type = "application/zip"),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/admin/export")]
+pub async fn export_content_handler(admin: AdminUser, data: web::Data<AppState>) -> impl Responder {
+    let admin_id = admin.user_id;
+    let archive = data.export_content_use_case.execute().map(move |chunk| {
+        chunk.map(web::Bytes::from).inspect_err(|err| {
+            error!(admin = %admin_id, "Content export failed: {}", err);
+        })
+    });
+
+    HttpResponse::Ok()
+        .content_type("application/zip")
+        .insert_header((
+            header::CONTENT_DISPOSITION,
+            format!(
+                "attachment; filename=\"export-{}.zip\"",
+                Utc::now().format("%Y-%m-%d")
+            ),
+        ))
+        .streaming(archive)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubExportContentUseCase,
+        },
+    };
+
+    async fn call(state: web::Data<AppState>, user_id: Uuid) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(user_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(export_content_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/api/admin/export")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_admin_downloads_the_archive() {
+        let admin_id = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin_id])
+            .with_export_content(StubExportContentUseCase::with_chunks(vec![
+                b"PK".to_vec(),
+                b"rest".to_vec(),
+            ]))
+            .build();
+
+        let resp = call(state, admin_id).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers().get(header::CONTENT_TYPE).unwrap(),
+            "application/zip"
+        );
+        assert!(resp
+            .headers()
+            .get(header::CONTENT_DISPOSITION)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .starts_with("attachment; filename=\"export-"));
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(b"PKrest")
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_non_admin_is_forbidden() {
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![Uuid::new_v4()])
+            .build();
+
+        let resp = call(state, Uuid::new_v4()).await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,443 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement, Value,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::cv::adapter::outgoing::open_contacts;
+use crate::cv::domain::entities::CVInfo;
+use crate::export::application::domain::entities::{
+    DocumentKind, ExportDocument, MediaReference, MediaUsage,
+};
+use crate::export::application::ports::outgoing::{ExportSource, ExportSourceError};
+use crate::pages::application::domain::entities::Page;
+use crate::project::application::ports::outgoing::project_repository::ProjectResult;
+use crate::shared::field_cipher::FieldCipher;
+
+const CV_COLUMNS: &str = "id, user_id, display_name, role, bio, photo_url, core_skills, \
+                          educations, experiences, highlighted_projects, contact_info, \
+                          created_at, updated_at";
+const PROJECT_COLUMNS: &str = "id, user_id, title, slug, description, tech_stack, screenshots, \
+                               repo_url, live_demo_url, seo_title, seo_description, \
+                               canonical_url, noindex, created_at, updated_at";
+const PAGE_COLUMNS: &str = "id, user_id, slug, title, body, status, seo_title, seo_description, \
+                            canonical_url, noindex, published_at, created_at, updated_at";
+const MEDIA_COLUMNS: &str = "id, user_id, original_filename, file_size_bytes, \
+                             CAST(status AS TEXT) AS status, bucket_name, object_key";
+
+#[derive(Clone)]
+pub struct ExportSourcePostgres {
+    db: Arc<DatabaseConnection>,
+    cipher: FieldCipher,
+}
+
+impl ExportSourcePostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            db,
+            cipher: FieldCipher::default(),
+        }
+    }
+
+    /// Decrypts CV contact details, which exports carry in plaintext
+    pub fn with_cipher(mut self, cipher: FieldCipher) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    /// Up to `limit` rows of `table` with ids after `after`, in id order
+    fn batch_stmt(
+        backend: DatabaseBackend,
+        table: &str,
+        columns: &str,
+        live: Option<&str>,
+        after: Option<Uuid>,
+        limit: u32,
+    ) -> Statement {
+        let mut conditions: Vec<String> = live.map(str::to_string).into_iter().collect();
+        let mut values: Vec<Value> = Vec::new();
+        if let Some(after) = after {
+            values.push(after.into());
+            conditions.push(format!("id > ${}", values.len()));
+        }
+        values.push((limit as i64).into());
+
+        let filter = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                "SELECT {columns} FROM {table} {filter} ORDER BY id LIMIT ${}",
+                values.len()
+            ),
+            values,
+        )
+    }
+
+    fn documents_stmt(
+        backend: DatabaseBackend,
+        kind: DocumentKind,
+        after: Option<Uuid>,
+        limit: u32,
+    ) -> Statement {
+        match kind {
+            DocumentKind::Cv => Self::batch_stmt(
+                backend,
+                "resumes",
+                CV_COLUMNS,
+                Some("is_deleted = false"),
+                after,
+                limit,
+            ),
+            DocumentKind::Project => Self::batch_stmt(
+                backend,
+                "projects",
+                PROJECT_COLUMNS,
+                Some("is_deleted = false"),
+                after,
+                limit,
+            ),
+            DocumentKind::Page => {
+                Self::batch_stmt(backend, "pages", PAGE_COLUMNS, None, after, limit)
+            }
+        }
+    }
+
+    fn media_stmt(backend: DatabaseBackend, after: Option<Uuid>, limit: u32) -> Statement {
+        Self::batch_stmt(
+            backend,
+            "media",
+            MEDIA_COLUMNS,
+            Some("deleted_at IS NULL"),
+            after,
+            limit,
+        )
+    }
+
+    /// Attachments of a batch of media; `media_ids` is never empty
+    fn attachments_stmt(backend: DatabaseBackend, media_ids: &[Uuid]) -> Statement {
+        let placeholders = (1..=media_ids.len())
+            .map(|i| format!("${i}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                SELECT media_id, attachable_type, attachable_id, role
+                FROM media_attachments
+                WHERE media_id IN ({placeholders})
+                ORDER BY attachable_type, position
+                "#
+            ),
+            media_ids.iter().map(|id| Value::from(*id)),
+        )
+    }
+
+    // =====================================================
+    // Mapping
+    // =====================================================
+
+    fn json<T: serde::de::DeserializeOwned>(
+        row: &QueryResult,
+        column: &str,
+    ) -> Result<T, ExportSourceError> {
+        let value: serde_json::Value = row.try_get("", column).map_err(Self::map_db_err)?;
+        serde_json::from_value(value)
+            .map_err(|e| ExportSourceError::DatabaseError(format!("{column}: {e}")))
+    }
+
+    fn to_document(
+        &self,
+        kind: DocumentKind,
+        row: &QueryResult,
+    ) -> Result<ExportDocument, ExportSourceError> {
+        let id: Uuid = row.try_get("", "id").map_err(Self::map_db_err)?;
+        let owner_id: Uuid = row.try_get("", "user_id").map_err(Self::map_db_err)?;
+        let created_at: DateTime<Utc> = row.try_get("", "created_at").map_err(Self::map_db_err)?;
+        let updated_at: DateTime<Utc> = row.try_get("", "updated_at").map_err(Self::map_db_err)?;
+
+        Ok(match kind {
+            DocumentKind::Cv => ExportDocument::from_cv(
+                &CVInfo {
+                    id,
+                    user_id: owner_id,
+                    role: row.try_get("", "role").map_err(Self::map_db_err)?,
+                    display_name: row.try_get("", "display_name").map_err(Self::map_db_err)?,
+                    bio: row.try_get("", "bio").map_err(Self::map_db_err)?,
+                    photo_url: row.try_get("", "photo_url").map_err(Self::map_db_err)?,
+                    core_skills: Self::json(row, "core_skills")?,
+                    educations: Self::json(row, "educations")?,
+                    experiences: Self::json(row, "experiences")?,
+                    highlighted_projects: Self::json(row, "highlighted_projects")?,
+                    contact_info: open_contacts(
+                        &self.cipher,
+                        owner_id,
+                        Self::json(row, "contact_info")?,
+                    )
+                    .map_err(|e| ExportSourceError::DatabaseError(e.to_string()))?,
+                    updated_at,
+                },
+                created_at,
+                updated_at,
+            ),
+            DocumentKind::Project => ExportDocument::from_project(&ProjectResult {
+                id,
+                owner: UserId::from(owner_id),
+                title: row.try_get("", "title").map_err(Self::map_db_err)?,
+                slug: row.try_get("", "slug").map_err(Self::map_db_err)?,
+                description: row.try_get("", "description").map_err(Self::map_db_err)?,
+                tech_stack: Self::json(row, "tech_stack")?,
+                screenshots: Self::json(row, "screenshots")?,
+                repo_url: row.try_get("", "repo_url").map_err(Self::map_db_err)?,
+                live_demo_url: row.try_get("", "live_demo_url").map_err(Self::map_db_err)?,
+                seo_title: row.try_get("", "seo_title").map_err(Self::map_db_err)?,
+                seo_description: row
+                    .try_get("", "seo_description")
+                    .map_err(Self::map_db_err)?,
+                canonical_url: row.try_get("", "canonical_url").map_err(Self::map_db_err)?,
+                noindex: row.try_get("", "noindex").map_err(Self::map_db_err)?,
+                created_at,
+                updated_at,
+            }),
+            DocumentKind::Page => {
+                let status: String = row.try_get("", "status").map_err(Self::map_db_err)?;
+                ExportDocument::from_page(&Page {
+                    id,
+                    owner_id,
+                    slug: row.try_get("", "slug").map_err(Self::map_db_err)?,
+                    title: row.try_get("", "title").map_err(Self::map_db_err)?,
+                    body: row.try_get("", "body").map_err(Self::map_db_err)?,
+                    status: status.parse().map_err(ExportSourceError::DatabaseError)?,
+                    seo_title: row.try_get("", "seo_title").map_err(Self::map_db_err)?,
+                    seo_description: row
+                        .try_get("", "seo_description")
+                        .map_err(Self::map_db_err)?,
+                    canonical_url: row.try_get("", "canonical_url").map_err(Self::map_db_err)?,
+                    noindex: row.try_get("", "noindex").map_err(Self::map_db_err)?,
+                    published_at: row.try_get("", "published_at").map_err(Self::map_db_err)?,
+                    created_at,
+                    updated_at,
+                })
+            }
+        })
+    }
+
+    fn to_media(row: &QueryResult) -> Result<MediaReference, ExportSourceError> {
+        let file_size_bytes: i64 = row
+            .try_get("", "file_size_bytes")
+            .map_err(Self::map_db_err)?;
+
+        Ok(MediaReference {
+            id: row.try_get("", "id").map_err(Self::map_db_err)?,
+            owner_id: row.try_get("", "user_id").map_err(Self::map_db_err)?,
+            original_filename: row
+                .try_get("", "original_filename")
+                .map_err(Self::map_db_err)?,
+            file_size_bytes: file_size_bytes.max(0) as u64,
+            status: row.try_get("", "status").map_err(Self::map_db_err)?,
+            bucket: Some(row.try_get("", "bucket_name").map_err(Self::map_db_err)?),
+            object_key: Some(row.try_get("", "object_key").map_err(Self::map_db_err)?),
+            used_by: Vec::new(),
+        })
+    }
+
+    fn to_usage(row: &QueryResult) -> Result<(Uuid, MediaUsage), ExportSourceError> {
+        Ok((
+            row.try_get("", "media_id").map_err(Self::map_db_err)?,
+            MediaUsage {
+                target: row
+                    .try_get("", "attachable_type")
+                    .map_err(Self::map_db_err)?,
+                target_id: row.try_get("", "attachable_id").map_err(Self::map_db_err)?,
+                role: row.try_get("", "role").map_err(Self::map_db_err)?,
+            },
+        ))
+    }
+
+    fn map_db_err(e: DbErr) -> ExportSourceError {
+        ExportSourceError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl ExportSource for ExportSourcePostgres {
+    async fn documents(
+        &self,
+        kind: DocumentKind,
+        after: Option<Uuid>,
+        limit: u32,
+    ) -> Result<Vec<ExportDocument>, ExportSourceError> {
+        self.db
+            .query_all(Self::documents_stmt(
+                self.db.get_database_backend(),
+                kind,
+                after,
+                limit,
+            ))
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(|row| self.to_document(kind, row))
+            .collect()
+    }
+
+    async fn media(
+        &self,
+        after: Option<Uuid>,
+        limit: u32,
+    ) -> Result<Vec<MediaReference>, ExportSourceError> {
+        let backend = self.db.get_database_backend();
+        let mut media = self
+            .db
+            .query_all(Self::media_stmt(backend, after, limit))
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(Self::to_media)
+            .collect::<Result<Vec<_>, _>>()?;
+        if media.is_empty() {
+            return Ok(media);
+        }
+
+        let ids: Vec<Uuid> = media.iter().map(|m| m.id).collect();
+        let mut usages: HashMap<Uuid, Vec<MediaUsage>> = HashMap::new();
+        for row in self
+            .db
+            .query_all(Self::attachments_stmt(backend, &ids))
+            .await
+            .map_err(Self::map_db_err)?
+        {
+            let (media_id, usage) = Self::to_usage(&row)?;
+            usages.entry(media_id).or_default().push(usage);
+        }
+        for reference in &mut media {
+            reference.used_by = usages.remove(&reference.id).unwrap_or_default();
+        }
+
+        Ok(media)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::MockDatabase;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_first_batch_has_no_cursor() {
+        let stmt = ExportSourcePostgres::documents_stmt(
+            DatabaseBackend::Postgres,
+            DocumentKind::Cv,
+            None,
+            50,
+        );
+
+        assert!(stmt
+            .sql
+            .contains("FROM resumes WHERE is_deleted = false ORDER BY id LIMIT $1"));
+        assert_eq!(stmt.values.unwrap().0, vec![Value::from(50i64)]);
+    }
+
+    #[test]
+    fn test_later_batches_start_after_the_cursor() {
+        let after = Uuid::new_v4();
+        let stmt = ExportSourcePostgres::documents_stmt(
+            DatabaseBackend::Postgres,
+            DocumentKind::Page,
+            Some(after),
+            50,
+        );
+
+        assert!(stmt
+            .sql
+            .contains("FROM pages WHERE id > $1 ORDER BY id LIMIT $2"));
+        assert_eq!(
+            stmt.values.unwrap().0,
+            vec![Value::from(after), Value::from(50i64)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pages_are_mapped_to_documents() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![BTreeMap::from([
+                ("id".to_string(), Value::from(Uuid::new_v4())),
+                ("user_id".to_string(), Value::from(Uuid::new_v4())),
+                ("slug".to_string(), Value::from("about")),
+                ("title".to_string(), Value::from("About")),
+                ("body".to_string(), Value::from("# Hello")),
+                ("status".to_string(), Value::from("published")),
+                ("seo_title".to_string(), Value::String(None)),
+                ("seo_description".to_string(), Value::String(None)),
+                ("canonical_url".to_string(), Value::String(None)),
+                ("noindex".to_string(), Value::from(false)),
+                ("published_at".to_string(), Value::from(Utc::now())),
+                ("created_at".to_string(), Value::from(Utc::now())),
+                ("updated_at".to_string(), Value::from(Utc::now())),
+            ])]])
+            .into_connection();
+
+        let source = ExportSourcePostgres::new(Arc::new(db));
+        let docs = source
+            .documents(DocumentKind::Page, None, 10)
+            .await
+            .unwrap();
+
+        assert_eq!(docs.len(), 1);
+        assert_eq!(docs[0].name, "about");
+        assert_eq!(docs[0].body, "# Hello");
+        assert!(docs[0].to_markdown().contains("status: \"published\"\n"));
+    }
+
+    #[tokio::test]
+    async fn test_media_carry_their_attachments() {
+        let media_id = Uuid::new_v4();
+        let project_id = Uuid::new_v4();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![BTreeMap::from([
+                ("id".to_string(), Value::from(media_id)),
+                ("user_id".to_string(), Value::from(Uuid::new_v4())),
+                ("original_filename".to_string(), Value::from("shot.png")),
+                ("file_size_bytes".to_string(), Value::from(2048i64)),
+                ("status".to_string(), Value::from("ready")),
+                ("bucket_name".to_string(), Value::from("uploads")),
+                ("object_key".to_string(), Value::from("u/1/shot.png")),
+            ])]])
+            .append_query_results(vec![vec![BTreeMap::from([
+                ("media_id".to_string(), Value::from(media_id)),
+                ("attachable_type".to_string(), Value::from("project")),
+                ("attachable_id".to_string(), Value::from(project_id)),
+                ("role".to_string(), Value::from("screenshoot")),
+            ])]])
+            .into_connection();
+
+        let source = ExportSourcePostgres::new(Arc::new(db));
+        let media = source.media(None, 10).await.unwrap();
+
+        assert_eq!(media.len(), 1);
+        assert_eq!(media[0].object_key.as_deref(), Some("u/1/shot.png"));
+        assert_eq!(
+            media[0].used_by,
+            vec![MediaUsage {
+                target: "project".to_string(),
+                target_id: project_id,
+                role: "screenshoot".to_string(),
+            }]
+        );
+    }
+}
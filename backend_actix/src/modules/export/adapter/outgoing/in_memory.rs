@@ -0,0 +1,170 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::cv::adapter::outgoing::InMemoryCvStore;
+use crate::export::application::domain::entities::{
+    DocumentKind, ExportDocument, MediaReference, MediaUsage,
+};
+use crate::export::application::ports::outgoing::{ExportSource, ExportSourceError};
+use crate::multimedia::adapter::outgoing::db::InMemoryMediaStore;
+use crate::pages::adapter::outgoing::InMemoryPageStore;
+use crate::project::adapter::outgoing::InMemoryProjectStore;
+
+/// `ExportSource` over the other modules' in-memory stores
+#[derive(Clone)]
+pub struct InMemoryExportSource {
+    cvs: InMemoryCvStore,
+    projects: InMemoryProjectStore,
+    pages: InMemoryPageStore,
+    media: InMemoryMediaStore,
+}
+
+impl InMemoryExportSource {
+    pub fn new(
+        cvs: InMemoryCvStore,
+        projects: InMemoryProjectStore,
+        pages: InMemoryPageStore,
+        media: InMemoryMediaStore,
+    ) -> Self {
+        Self {
+            cvs,
+            projects,
+            pages,
+            media,
+        }
+    }
+}
+
+/// The `limit` rows after `after`, in id order
+fn batch<T>(mut rows: Vec<(Uuid, T)>, after: Option<Uuid>, limit: u32) -> Vec<T> {
+    rows.sort_by_key(|(id, _)| *id);
+    rows.into_iter()
+        .filter(|(id, _)| after.is_none_or(|after| *id > after))
+        .take(limit as usize)
+        .map(|(_, row)| row)
+        .collect()
+}
+
+#[async_trait]
+impl ExportSource for InMemoryExportSource {
+    async fn documents(
+        &self,
+        kind: DocumentKind,
+        after: Option<Uuid>,
+        limit: u32,
+    ) -> Result<Vec<ExportDocument>, ExportSourceError> {
+        let docs: Vec<(Uuid, ExportDocument)> = match kind {
+            DocumentKind::Cv => self.cvs.cvs.read(|rows| {
+                rows.iter()
+                    .filter(|row| row.deleted_at.is_none())
+                    .map(|row| {
+                        let doc =
+                            ExportDocument::from_cv(&row.cv, row.created_at, row.cv.updated_at);
+                        (doc.id, doc)
+                    })
+                    .collect()
+            }),
+            DocumentKind::Project => self.projects.projects.read(|rows| {
+                rows.iter()
+                    .filter(|row| row.deleted_at.is_none())
+                    .map(|row| (row.project.id, ExportDocument::from_project(&row.project)))
+                    .collect()
+            }),
+            DocumentKind::Page => self.pages.pages.read(|pages| {
+                pages
+                    .iter()
+                    .map(|page| (page.id, ExportDocument::from_page(page)))
+                    .collect()
+            }),
+        };
+
+        Ok(batch(docs, after, limit))
+    }
+
+    async fn media(
+        &self,
+        after: Option<Uuid>,
+        limit: u32,
+    ) -> Result<Vec<MediaReference>, ExportSourceError> {
+        let media: Vec<(Uuid, MediaReference)> = self.media.media.read(|rows| {
+            rows.iter()
+                .filter(|row| row.deleted_at.is_none())
+                .map(|row| {
+                    let reference = MediaReference {
+                        id: row.media_id,
+                        owner_id: row.owner.value(),
+                        original_filename: row.original_name.clone(),
+                        file_size_bytes: row.file_size_bytes,
+                        status: serde_json::to_value(&row.state)
+                            .ok()
+                            .and_then(|state| state.as_str().map(str::to_string))
+                            .unwrap_or_default(),
+                        bucket: None,
+                        object_key: None,
+                        used_by: vec![MediaUsage {
+                            target: row.attachment.attachment_target.to_string(),
+                            target_id: row.attachment.attachment_target_id,
+                            role: row.attachment.role.to_string(),
+                        }],
+                    };
+                    (reference.id, reference)
+                })
+                .collect()
+        });
+
+        Ok(batch(media, after, limit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::pages::application::domain::entities::PageStatus;
+    use crate::pages::application::ports::incoming::use_cases::CreatePageCommand;
+    use crate::pages::application::ports::outgoing::PageRepository;
+
+    #[tokio::test]
+    async fn test_documents_come_in_id_batches() {
+        let pages = InMemoryPageStore::default();
+        for slug in ["about", "now", "uses"] {
+            pages
+                .create(
+                    Uuid::new_v4(),
+                    &CreatePageCommand::new(
+                        slug.to_string(),
+                        slug.to_string(),
+                        String::new(),
+                        PageStatus::Draft,
+                        None,
+                        None,
+                    )
+                    .unwrap(),
+                )
+                .await
+                .unwrap();
+        }
+        let source = InMemoryExportSource::new(
+            InMemoryCvStore::default(),
+            InMemoryProjectStore::default(),
+            pages,
+            InMemoryMediaStore::default(),
+        );
+
+        let first = source.documents(DocumentKind::Page, None, 2).await.unwrap();
+        let rest = source
+            .documents(DocumentKind::Page, Some(first[1].id), 2)
+            .await
+            .unwrap();
+
+        assert_eq!(first.len(), 2);
+        assert!(first[0].id < first[1].id);
+        assert_eq!(rest.len(), 1);
+        assert!(rest[0].id > first[1].id);
+        assert!(source
+            .documents(DocumentKind::Cv, None, 2)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,5 @@
+mod export_source_postgres;
+mod in_memory;
+
+pub use export_source_postgres::ExportSourcePostgres;
+pub use in_memory::InMemoryExportSource;
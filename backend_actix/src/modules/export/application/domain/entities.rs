@@ -0,0 +1,196 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use crate::cv::domain::entities::CVInfo;
+use crate::pages::application::domain::entities::Page;
+use crate::project::application::ports::outgoing::project_repository::ProjectResult;
+
+/// Content exported as one Markdown file each
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentKind {
+    Cv,
+    Project,
+    Page,
+}
+
+impl DocumentKind {
+    /// In archive order
+    pub const ALL: [DocumentKind; 3] =
+        [DocumentKind::Cv, DocumentKind::Project, DocumentKind::Page];
+
+    /// Top-level folder in the archive
+    pub fn dir(self) -> &'static str {
+        match self {
+            DocumentKind::Cv => "cvs",
+            DocumentKind::Project => "projects",
+            DocumentKind::Page => "pages",
+        }
+    }
+}
+
+/// One Markdown file: front matter fields in order, then the body
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportDocument {
+    pub kind: DocumentKind,
+    pub id: Uuid,
+    pub owner_id: Uuid,
+    /// File name without the extension: the slug, or the id for CVs
+    pub name: String,
+    pub front_matter: Vec<(&'static str, Value)>,
+    pub body: String,
+}
+
+impl ExportDocument {
+    /// The bio is the body; the CV sections go in the front matter
+    pub fn from_cv(cv: &CVInfo, created_at: DateTime<Utc>, updated_at: DateTime<Utc>) -> Self {
+        Self {
+            kind: DocumentKind::Cv,
+            id: cv.id,
+            owner_id: cv.user_id,
+            name: cv.id.to_string(),
+            front_matter: vec![
+                ("id", json!(cv.id)),
+                ("display_name", json!(cv.display_name)),
+                ("role", json!(cv.role)),
+                ("photo_url", json!(cv.photo_url)),
+                ("core_skills", json!(cv.core_skills)),
+                ("educations", json!(cv.educations)),
+                ("experiences", json!(cv.experiences)),
+                ("highlighted_projects", json!(cv.highlighted_projects)),
+                ("contact_info", json!(cv.contact_info)),
+                ("created_at", json!(created_at)),
+                ("updated_at", json!(updated_at)),
+            ],
+            body: cv.bio.clone(),
+        }
+    }
+
+    /// The description is the body
+    pub fn from_project(project: &ProjectResult) -> Self {
+        Self {
+            kind: DocumentKind::Project,
+            id: project.id,
+            owner_id: project.owner.value(),
+            name: project.slug.clone(),
+            front_matter: vec![
+                ("id", json!(project.id)),
+                ("title", json!(project.title)),
+                ("slug", json!(project.slug)),
+                ("tech_stack", json!(project.tech_stack)),
+                ("screenshots", json!(project.screenshots)),
+                ("repo_url", json!(project.repo_url)),
+                ("live_demo_url", json!(project.live_demo_url)),
+                ("seo_title", json!(project.seo_title)),
+                ("seo_description", json!(project.seo_description)),
+                ("canonical_url", json!(project.canonical_url)),
+                ("noindex", json!(project.noindex)),
+                ("created_at", json!(project.created_at)),
+                ("updated_at", json!(project.updated_at)),
+            ],
+            body: project.description.clone(),
+        }
+    }
+
+    pub fn from_page(page: &Page) -> Self {
+        Self {
+            kind: DocumentKind::Page,
+            id: page.id,
+            owner_id: page.owner_id,
+            name: page.slug.clone(),
+            front_matter: vec![
+                ("id", json!(page.id)),
+                ("title", json!(page.title)),
+                ("slug", json!(page.slug)),
+                ("status", json!(page.status.as_str())),
+                ("seo_title", json!(page.seo_title)),
+                ("seo_description", json!(page.seo_description)),
+                ("canonical_url", json!(page.canonical_url)),
+                ("noindex", json!(page.noindex)),
+                ("published_at", json!(page.published_at)),
+                ("created_at", json!(page.created_at)),
+                ("updated_at", json!(page.updated_at)),
+            ],
+            body: page.body.clone(),
+        }
+    }
+
+    /// `<folder>/<owner id>/<name>.md`
+    pub fn path(&self) -> String {
+        format!("{}/{}/{}.md", self.kind.dir(), self.owner_id, self.name)
+    }
+
+    /// YAML front matter, then the body. Values are written as JSON, which
+    /// YAML reads as flow scalars, sequences and mappings.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("---\n");
+        for (key, value) in &self.front_matter {
+            out.push_str(key);
+            out.push_str(": ");
+            out.push_str(&value.to_string());
+            out.push('\n');
+        }
+        out.push_str("---\n\n");
+        out.push_str(&self.body);
+        if !self.body.ends_with('\n') {
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Where a media file is used
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MediaUsage {
+    /// `resume`, `project`, `user`, ...
+    pub target: String,
+    pub target_id: Uuid,
+    pub role: String,
+}
+
+/// One entry of the media manifest. Only the reference is exported, not the
+/// file itself.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MediaReference {
+    pub id: Uuid,
+    pub owner_id: Uuid,
+    pub original_filename: String,
+    pub file_size_bytes: u64,
+    /// Processing state: `pending`, `processing`, `ready` or `failed`
+    pub status: String,
+    /// Storage location of the original; absent in standalone mode
+    pub bucket: Option<String>,
+    pub object_key: Option<String>,
+    pub used_by: Vec<MediaUsage>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_has_json_front_matter_then_body() {
+        let doc = ExportDocument {
+            kind: DocumentKind::Page,
+            id: Uuid::nil(),
+            owner_id: Uuid::nil(),
+            name: "about".to_string(),
+            front_matter: vec![
+                ("title", json!("About: \"me\"")),
+                ("seo_title", Value::Null),
+                ("tags", json!(["a", "b"])),
+            ],
+            body: "# Hi".to_string(),
+        };
+
+        assert_eq!(
+            doc.to_markdown(),
+            "---\ntitle: \"About: \\\"me\\\"\"\nseo_title: null\ntags: [\"a\",\"b\"]\n---\n\n# Hi\n"
+        );
+        assert_eq!(
+            doc.path(),
+            "pages/00000000-0000-0000-0000-000000000000/about.md"
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use futures::stream::BoxStream;
+
+/// Chunks of a zip file, produced as the stream is polled
+pub type ContentArchive = BoxStream<'static, Result<Vec<u8>, ExportContentError>>;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ExportContentError {
+    #[error("Failed to read content: {0}")]
+    ReadFailed(String),
+    #[error("Archive is too big for a zip without ZIP64: {0}")]
+    TooLarge(String),
+}
+
+/// Every owner's CVs, projects and pages as Markdown files with YAML front
+/// matter, plus `media.json` listing the media files and where they're used
+pub trait ExportContentUseCase: Send + Sync {
+    /// Nothing is read until the archive is polled; a read error, or content
+    /// past the zip limits, ends it early with an `Err` chunk
+    fn execute(&self) -> ContentArchive;
+}
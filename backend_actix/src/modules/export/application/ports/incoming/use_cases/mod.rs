@@ -0,0 +1,3 @@
+mod export_content_use_case;
+
+pub use export_content_use_case::{ContentArchive, ExportContentError, ExportContentUseCase};
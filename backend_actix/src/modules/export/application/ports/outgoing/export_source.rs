@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::export::application::domain::entities::{DocumentKind, ExportDocument, MediaReference};
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ExportSourceError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Live (not trashed) content of every owner, read in id order a batch at
+/// a time so the export never holds more than one batch
+#[async_trait]
+pub trait ExportSource: Send + Sync {
+    /// Up to `limit` documents of `kind` with ids after `after`
+    async fn documents(
+        &self,
+        kind: DocumentKind,
+        after: Option<Uuid>,
+        limit: u32,
+    ) -> Result<Vec<ExportDocument>, ExportSourceError>;
+
+    /// Up to `limit` media with ids after `after`
+    async fn media(
+        &self,
+        after: Option<Uuid>,
+        limit: u32,
+    ) -> Result<Vec<MediaReference>, ExportSourceError>;
+}
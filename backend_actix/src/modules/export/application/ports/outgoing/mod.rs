@@ -0,0 +1,3 @@
+mod export_source;
+
+pub use export_source::{ExportSource, ExportSourceError};
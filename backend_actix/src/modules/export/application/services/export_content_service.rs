@@ -0,0 +1,354 @@
+use chrono::Utc;
+use futures::{stream, StreamExt};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::export::application::domain::entities::DocumentKind;
+use crate::export::application::ports::{
+    incoming::use_cases::{ContentArchive, ExportContentError, ExportContentUseCase},
+    outgoing::{ExportSource, ExportSourceError},
+};
+use crate::shared::zip::{ZipLimitError, ZipStream};
+
+/// Rows read per query, and so roughly the content per chunk
+const BATCH_SIZE: u32 = 100;
+const MEDIA_MANIFEST: &str = "media.json";
+
+pub struct ExportContentService<S>
+where
+    S: ExportSource + 'static,
+{
+    source: Arc<S>,
+    batch_size: u32,
+}
+
+impl<S> ExportContentService<S>
+where
+    S: ExportSource + 'static,
+{
+    pub fn new(source: S) -> Self {
+        Self {
+            source: Arc::new(source),
+            batch_size: BATCH_SIZE,
+        }
+    }
+
+    #[cfg(test)]
+    fn with_batch_size(mut self, batch_size: u32) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+}
+
+impl From<ExportSourceError> for ExportContentError {
+    fn from(err: ExportSourceError) -> Self {
+        ExportContentError::ReadFailed(err.to_string())
+    }
+}
+
+impl From<ZipLimitError> for ExportContentError {
+    fn from(err: ZipLimitError) -> Self {
+        ExportContentError::TooLarge(err.to_string())
+    }
+}
+
+impl<S> ExportContentUseCase for ExportContentService<S>
+where
+    S: ExportSource + 'static,
+{
+    fn execute(&self) -> ContentArchive {
+        let exporter = Exporter {
+            source: Arc::clone(&self.source),
+            batch_size: self.batch_size,
+            zip: ZipStream::new(Utc::now()),
+            phase: Phase::Documents {
+                kind: 0,
+                after: None,
+            },
+        };
+
+        stream::unfold(exporter, |mut exporter| async move {
+            match exporter.next_chunk().await {
+                Ok(Some(chunk)) => Some((Ok(chunk), exporter)),
+                Ok(None) => None,
+                Err(err) => {
+                    exporter.phase = Phase::Done;
+                    Some((Err(err), exporter))
+                }
+            }
+        })
+        .boxed()
+    }
+}
+
+enum Phase {
+    /// Index into `DocumentKind::ALL`
+    Documents {
+        kind: usize,
+        after: Option<Uuid>,
+    },
+    /// `media.json` is open; `after` is `None` until an entry is written
+    Media {
+        after: Option<Uuid>,
+    },
+    Done,
+}
+
+/// Writes the archive one batch at a time: documents kind by kind, then
+/// the media manifest, then the zip directory
+struct Exporter<S> {
+    source: Arc<S>,
+    batch_size: u32,
+    zip: ZipStream,
+    phase: Phase,
+}
+
+impl<S> Exporter<S>
+where
+    S: ExportSource,
+{
+    async fn next_chunk(&mut self) -> Result<Option<Vec<u8>>, ExportContentError> {
+        loop {
+            match self.phase {
+                Phase::Documents { kind, after } => {
+                    let Some(&doc_kind) = DocumentKind::ALL.get(kind) else {
+                        self.zip.start_file(MEDIA_MANIFEST)?;
+                        self.zip.write(b"[")?;
+                        self.phase = Phase::Media { after: None };
+                        continue;
+                    };
+
+                    let docs = self
+                        .source
+                        .documents(doc_kind, after, self.batch_size)
+                        .await?;
+                    self.phase = match docs.last() {
+                        Some(last) if docs.len() as u32 == self.batch_size => Phase::Documents {
+                            kind,
+                            after: Some(last.id),
+                        },
+                        _ => Phase::Documents {
+                            kind: kind + 1,
+                            after: None,
+                        },
+                    };
+                    if docs.is_empty() {
+                        continue;
+                    }
+
+                    for doc in &docs {
+                        self.zip.start_file(&doc.path())?;
+                        self.zip.write(doc.to_markdown().as_bytes())?;
+                    }
+                    self.zip.finish_file()?;
+                    return Ok(Some(self.zip.take_output()));
+                }
+                Phase::Media { after } => {
+                    let media = self.source.media(after, self.batch_size).await?;
+
+                    for (i, reference) in media.iter().enumerate() {
+                        let separator = if after.is_none() && i == 0 {
+                            "\n"
+                        } else {
+                            ",\n"
+                        };
+                        self.zip.write(separator.as_bytes())?;
+                        let json = serde_json::to_vec(reference)
+                            .expect("MediaReference always serializes");
+                        self.zip.write(&json)?;
+                    }
+
+                    match media.last() {
+                        Some(last) if media.len() as u32 == self.batch_size => {
+                            self.phase = Phase::Media {
+                                after: Some(last.id),
+                            };
+                            return Ok(Some(self.zip.take_output()));
+                        }
+                        _ => {
+                            self.zip.write(b"\n]\n")?;
+                            let mut chunk = self.zip.take_output();
+                            chunk.extend(self.zip.finish()?);
+                            self.phase = Phase::Done;
+                            return Ok(Some(chunk));
+                        }
+                    }
+                }
+                Phase::Done => return Ok(None),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use futures::TryStreamExt;
+
+    use crate::export::application::domain::entities::{ExportDocument, MediaReference};
+
+    /// Serves `docs` and `media` in id order, like the real adapters
+    struct FakeSource {
+        docs: Vec<ExportDocument>,
+        media: Vec<MediaReference>,
+        fail_media: bool,
+    }
+
+    fn page(slug: &str) -> ExportDocument {
+        ExportDocument {
+            kind: DocumentKind::Page,
+            id: Uuid::new_v4(),
+            owner_id: Uuid::nil(),
+            name: slug.to_string(),
+            front_matter: vec![("slug", serde_json::json!(slug))],
+            body: format!("# {slug}"),
+        }
+    }
+
+    fn media(name: &str) -> MediaReference {
+        MediaReference {
+            id: Uuid::new_v4(),
+            owner_id: Uuid::nil(),
+            original_filename: name.to_string(),
+            file_size_bytes: 10,
+            status: "ready".to_string(),
+            bucket: None,
+            object_key: None,
+            used_by: vec![],
+        }
+    }
+
+    fn batch_after<T>(
+        rows: Vec<&T>,
+        id: impl Fn(&T) -> Uuid,
+        after: Option<Uuid>,
+        limit: u32,
+    ) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut rows = rows;
+        rows.sort_by_key(|row| id(row));
+        rows.into_iter()
+            .filter(|row| after.is_none_or(|after| id(row) > after))
+            .take(limit as usize)
+            .cloned()
+            .collect()
+    }
+
+    #[async_trait]
+    impl ExportSource for FakeSource {
+        async fn documents(
+            &self,
+            kind: DocumentKind,
+            after: Option<Uuid>,
+            limit: u32,
+        ) -> Result<Vec<ExportDocument>, ExportSourceError> {
+            let rows = self.docs.iter().filter(|doc| doc.kind == kind).collect();
+            Ok(batch_after(rows, |doc| doc.id, after, limit))
+        }
+
+        async fn media(
+            &self,
+            after: Option<Uuid>,
+            limit: u32,
+        ) -> Result<Vec<MediaReference>, ExportSourceError> {
+            if self.fail_media {
+                return Err(ExportSourceError::DatabaseError("boom".to_string()));
+            }
+            Ok(batch_after(
+                self.media.iter().collect(),
+                |m| m.id,
+                after,
+                limit,
+            ))
+        }
+    }
+
+    fn count(haystack: &[u8], needle: &[u8]) -> usize {
+        haystack
+            .windows(needle.len())
+            .filter(|window| *window == needle)
+            .count()
+    }
+
+    #[tokio::test]
+    async fn test_archive_holds_every_document_and_the_manifest() {
+        let service = ExportContentService::new(FakeSource {
+            docs: vec![page("about"), page("now"), page("uses")],
+            media: vec![media("a.png"), media("b.png"), media("c.png")],
+            fail_media: false,
+        })
+        .with_batch_size(2);
+
+        let chunks: Vec<Vec<u8>> = service.execute().try_collect().await.unwrap();
+        let archive = chunks.concat();
+
+        // Two document batches, two media batches
+        assert_eq!(chunks.len(), 4);
+        assert!(
+            count(
+                &archive,
+                b"pages/00000000-0000-0000-0000-000000000000/now.md"
+            ) > 0
+        );
+        assert_eq!(count(&archive, b"---\nslug:"), 3);
+        assert_eq!(count(&archive, b"\"original_filename\""), 3);
+        assert_eq!(count(&archive, b"PK\x05\x06"), 1);
+        // 3 pages + media.json in the directory
+        assert_eq!(count(&archive, b"PK\x01\x02"), 4);
+    }
+
+    #[tokio::test]
+    async fn test_empty_site_still_gets_a_manifest() {
+        let service = ExportContentService::new(FakeSource {
+            docs: vec![],
+            media: vec![],
+            fail_media: false,
+        });
+
+        let archive = service
+            .execute()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap()
+            .concat();
+
+        assert_eq!(count(&archive, b"media.json[\n]\n"), 1);
+        assert_eq!(count(&archive, b"PK\x01\x02"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_read_error_ends_the_archive() {
+        let service = ExportContentService::new(FakeSource {
+            docs: vec![page("about")],
+            media: vec![],
+            fail_media: true,
+        });
+
+        let chunks: Vec<_> = service.execute().collect().await;
+
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks[0].is_ok());
+        assert!(matches!(chunks[1], Err(ExportContentError::ReadFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_too_many_entries_fail_instead_of_a_broken_directory() {
+        // 65534 pages fill the zip, leaving no room for media.json
+        let service = ExportContentService::new(FakeSource {
+            docs: (0..u16::MAX - 1).map(|_| page("p")).collect(),
+            media: vec![],
+            fail_media: false,
+        })
+        .with_batch_size(10_000);
+
+        let chunks: Vec<_> = service.execute().collect().await;
+
+        let (last, written) = chunks.split_last().unwrap();
+        assert!(matches!(last, Err(ExportContentError::TooLarge(_))));
+        let archive: Vec<u8> = written.iter().flat_map(|c| c.clone().unwrap()).collect();
+        assert_eq!(count(&archive, b"PK\x05\x06"), 0);
+    }
+}
@@ -0,0 +1,3 @@
+mod export_content_service;
+
+pub use export_content_service::ExportContentService;
@@ -0,0 +1,5 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    INVALID_ARCHIVE = (BAD_REQUEST, "The archive could not be read");
+}
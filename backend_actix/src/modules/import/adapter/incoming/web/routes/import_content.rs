@@ -0,0 +1,191 @@
+use actix_web::{error::PayloadError, post, web, HttpRequest, Responder};
+use futures::StreamExt;
+use serde::Deserialize;
+use tracing::info;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::import::adapter::incoming::web::error_codes::INVALID_ARCHIVE;
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    import::application::domain::entities::ImportReport,
+    shared::api::{
+        body_limit::{limit_for, payload_too_large},
+        ApiResponse,
+    },
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct ImportContentParams {
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// Import pages and projects from a zip of Markdown files
+///
+/// The body is a zip archive (up to 10 MiB) of `.md` files with Hugo or
+/// Jekyll-style YAML front matter; an archive from `GET /api/admin/export`
+/// works too. A file is a project when its `type` or `layout` says so or
+/// it sits in a `projects/` folder, and a page otherwise. Posts are not
+/// supported and are skipped, as are CVs.
+///
+/// Everything is created for the calling administrator. Pages are published
+/// unless marked `draft: true` (or `published: false`). A project's
+/// `topics`, `tags` or `categories` are matched to the administrator's
+/// topics by title, and created when missing. A slug already in use gets a
+/// `-2`, `-3`, ... suffix.
+///
+/// Files that can't be imported are listed under `skipped` with the reason;
+/// the rest are still imported. With `dry_run=true` nothing is written and
+/// the report shows what would be.
+#[utoipa::path(
+    post,
+    path = "/api/admin/import",
+    tag = "admin",
+    params(
+        ("dry_run" = Option<bool>, Query, description = "Only report what would be created (default false)"),
+    ),
+    request_body(content = Vec<u8>, content_type = "application/zip"),
+    responses(
+        (status = 200, description = "What was (or would be) imported and skipped", body = inline(SuccessResponse<ImportReport>)),
+        (status = 400, description = "Body is not a readable zip, or has too many or too large files", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 413, description = "Archive larger than 10 MiB", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/admin/import")]
+pub async fn import_content_handler(
+    admin: AdminUser,
+    req: HttpRequest,
+    params: web::Query<ImportContentParams>,
+    mut payload: web::Payload,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    // `web::Bytes` would apply the 256 KiB `PayloadConfig` default; the
+    // route's limit is enforced by `body_limit_middleware`
+    let mut archive = Vec::new();
+    while let Some(chunk) = payload.next().await {
+        match chunk {
+            Ok(chunk) => archive.extend_from_slice(&chunk),
+            Err(PayloadError::Overflow) => return payload_too_large(limit_for(req.path())),
+            Err(err) => {
+                return INVALID_ARCHIVE
+                    .with_message(&format!("Failed to read the request body: {err}"))
+            }
+        }
+    }
+
+    let dry_run = params.dry_run;
+    match data
+        .import_content_use_case
+        .execute(admin.user_id, archive, dry_run)
+        .await
+    {
+        Ok(report) => {
+            if !dry_run {
+                info!(
+                    admin = %admin.user_id,
+                    imported = report.items.len(),
+                    skipped = report.skipped.len(),
+                    "Content imported"
+                );
+            }
+            ApiResponse::success(report)
+        }
+        Err(err) => INVALID_ARCHIVE.with_message(&err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        import::application::ports::incoming::use_cases::ImportContentError,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubImportContentUseCase,
+        },
+    };
+
+    async fn call(
+        state: web::Data<AppState>,
+        user_id: Uuid,
+        uri: &str,
+    ) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(user_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(import_content_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .insert_header(("Content-Type", "application/zip"))
+            .set_payload(vec![b'x'; 300 * 1024])
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_dry_run_returns_the_report() {
+        let admin_id = Uuid::new_v4();
+        let stub = StubImportContentUseCase::success();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin_id])
+            .with_import_content(stub.clone())
+            .build();
+
+        let resp = call(state, admin_id, "/api/admin/import?dry_run=true").await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["dry_run"], true);
+        // Bodies over the default payload limit still get through
+        assert_eq!(stub.received(), Some((admin_id, 300 * 1024, true)));
+    }
+
+    #[actix_web::test]
+    async fn test_unreadable_archive_is_bad_request() {
+        let admin_id = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin_id])
+            .with_import_content(StubImportContentUseCase::failure(
+                ImportContentError::InvalidArchive("invalid Zip archive".to_string()),
+            ))
+            .build();
+
+        let resp = call(state, admin_id, "/api/admin/import").await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "INVALID_ARCHIVE");
+    }
+
+    #[actix_web::test]
+    async fn test_non_admin_is_forbidden() {
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![Uuid::new_v4()])
+            .build();
+
+        let resp = call(state, Uuid::new_v4(), "/api/admin/import").await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,3 @@
+mod import_content;
+
+pub use import_content::{__path_import_content_handler, import_content_handler};
@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::import::application::ports::outgoing::{SlugLookup, SlugLookupError};
+use crate::pages::adapter::outgoing::InMemoryPageStore;
+use crate::project::adapter::outgoing::InMemoryProjectStore;
+
+/// `SlugLookup` over the pages and projects modules' in-memory stores
+#[derive(Clone)]
+pub struct InMemorySlugLookup {
+    pages: InMemoryPageStore,
+    projects: InMemoryProjectStore,
+}
+
+impl InMemorySlugLookup {
+    pub fn new(pages: InMemoryPageStore, projects: InMemoryProjectStore) -> Self {
+        Self { pages, projects }
+    }
+}
+
+#[async_trait]
+impl SlugLookup for InMemorySlugLookup {
+    async fn page_slug_taken(&self, owner_id: Uuid, slug: &str) -> Result<bool, SlugLookupError> {
+        Ok(self.pages.pages.read(|pages| {
+            pages
+                .iter()
+                .any(|page| page.owner_id == owner_id && page.slug == slug)
+        }))
+    }
+
+    async fn project_slug_taken(&self, slug: &str) -> Result<bool, SlugLookupError> {
+        let slug = slug.to_lowercase();
+        Ok(self
+            .projects
+            .projects
+            .read(|rows| rows.iter().any(|row| row.project.slug == slug)))
+    }
+}
@@ -0,0 +1,5 @@
+mod in_memory;
+mod slug_lookup_postgres;
+
+pub use in_memory::InMemorySlugLookup;
+pub use slug_lookup_postgres::SlugLookupPostgres;
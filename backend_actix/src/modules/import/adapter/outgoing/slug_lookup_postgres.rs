@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use sea_orm::{ConnectionTrait, DatabaseBackend, DatabaseConnection, Statement};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::import::application::ports::outgoing::{SlugLookup, SlugLookupError};
+
+#[derive(Clone)]
+pub struct SlugLookupPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl SlugLookupPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn page_stmt(backend: DatabaseBackend, owner_id: Uuid, slug: &str) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            "SELECT 1 AS taken FROM pages WHERE user_id = $1 AND slug = $2 LIMIT 1",
+            vec![owner_id.into(), slug.into()],
+        )
+    }
+
+    /// Trashed projects included, like `idx_projects_slug_unique`
+    fn project_stmt(backend: DatabaseBackend, slug: &str) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            "SELECT 1 AS taken FROM projects WHERE lower(slug) = lower($1) LIMIT 1",
+            vec![slug.into()],
+        )
+    }
+
+    async fn exists(&self, stmt: Statement) -> Result<bool, SlugLookupError> {
+        self.db
+            .query_one(stmt)
+            .await
+            .map(|row| row.is_some())
+            .map_err(|e| SlugLookupError::DatabaseError(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl SlugLookup for SlugLookupPostgres {
+    async fn page_slug_taken(&self, owner_id: Uuid, slug: &str) -> Result<bool, SlugLookupError> {
+        self.exists(Self::page_stmt(
+            self.db.get_database_backend(),
+            owner_id,
+            slug,
+        ))
+        .await
+    }
+
+    async fn project_slug_taken(&self, slug: &str) -> Result<bool, SlugLookupError> {
+        self.exists(Self::project_stmt(self.db.get_database_backend(), slug))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DbErr, MockDatabase, Value};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_project_lookup_ignores_case() {
+        let stmt = SlugLookupPostgres::project_stmt(DatabaseBackend::Postgres, "CMS");
+
+        assert!(stmt.sql.contains("lower(slug) = lower($1)"));
+        assert!(!stmt.sql.contains("is_deleted"));
+    }
+
+    #[tokio::test]
+    async fn test_page_slug_taken_when_a_row_comes_back() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![
+                vec![BTreeMap::from([("taken".to_string(), Value::from(1i32))])],
+                vec![],
+            ])
+            .into_connection();
+        let lookup = SlugLookupPostgres::new(Arc::new(db));
+        let owner_id = Uuid::new_v4();
+
+        assert!(lookup.page_slug_taken(owner_id, "about").await.unwrap());
+        assert!(!lookup.page_slug_taken(owner_id, "now").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_database_error_is_mapped() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_errors(vec![DbErr::Custom("boom".to_string())])
+            .into_connection();
+        let lookup = SlugLookupPostgres::new(Arc::new(db));
+
+        assert!(matches!(
+            lookup.project_slug_taken("cms").await,
+            Err(SlugLookupError::DatabaseError(_))
+        ));
+    }
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// What a Markdown file becomes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportKind {
+    Page,
+    Project,
+}
+
+/// A file that was (or in a dry run, would be) imported
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ImportedItem {
+    /// Path inside the archive
+    pub path: String,
+    pub kind: ImportKind,
+    /// Absent in a dry run
+    pub id: Option<Uuid>,
+    pub title: String,
+    pub slug: String,
+    /// The slug the file asked for, when it was taken and a suffix was added
+    pub requested_slug: Option<String>,
+    /// `draft` or `published`; pages only
+    pub status: Option<String>,
+    /// Topic titles linked to the project
+    pub topics: Vec<String>,
+    /// Parts of the file that were left out, e.g. a topic that couldn't be
+    /// created
+    pub warnings: Vec<String>,
+}
+
+/// A file that was not imported, and why
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct SkippedFile {
+    pub path: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, ToSchema)]
+pub struct ImportReport {
+    /// Nothing was written
+    pub dry_run: bool,
+    pub items: Vec<ImportedItem>,
+    /// Titles of the topics that were (or would be) created
+    pub topics_created: Vec<String>,
+    pub skipped: Vec<SkippedFile>,
+}
+
+impl ImportReport {
+    pub fn skip(&mut self, path: &str, reason: impl Into<String>) {
+        self.skipped.push(SkippedFile {
+            path: path.to_string(),
+            reason: reason.into(),
+        });
+    }
+}
@@ -0,0 +1,286 @@
+//! Front matter as Hugo and Jekyll write it: a YAML block between `---`
+//! lines at the top of the file.
+//!
+//! Only the flat subset those generators use for content is understood:
+//! `key: value` scalars (plain, quoted, numbers, booleans), flow lists
+//! (`[a, b]` or JSON) and block lists (`- item` lines under a key). Nested
+//! mappings are ignored, since nothing imported reads them.
+
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FrontMatterError {
+    #[error("Front matter is not closed with '---'")]
+    Unclosed,
+
+    #[error("TOML front matter ('+++') is not supported, use YAML ('---')")]
+    Toml,
+
+    #[error("Front matter line {0} is not 'key: value'")]
+    InvalidLine(usize),
+}
+
+/// The parsed fields, by key
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FrontMatter(Map<String, Value>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkdownFile {
+    pub front_matter: FrontMatter,
+    pub body: String,
+}
+
+/// Splits `text` into front matter and body. A file without front matter
+/// is all body.
+pub fn parse(text: &str) -> Result<MarkdownFile, FrontMatterError> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut lines = text.split_inclusive('\n');
+
+    match lines.next().map(|line| line.trim_end()) {
+        Some("---") => {}
+        Some("+++") => return Err(FrontMatterError::Toml),
+        _ => {
+            return Ok(MarkdownFile {
+                front_matter: FrontMatter::default(),
+                body: text.to_string(),
+            })
+        }
+    }
+
+    let mut header = Vec::new();
+    let mut closed = false;
+    for line in lines.by_ref() {
+        let line = line.trim_end();
+        if line == "---" || line == "..." {
+            closed = true;
+            break;
+        }
+        header.push(line);
+    }
+    if !closed {
+        return Err(FrontMatterError::Unclosed);
+    }
+
+    let body: String = lines.collect();
+    Ok(MarkdownFile {
+        front_matter: parse_fields(&header)?,
+        body: body.trim_start_matches(['\r', '\n']).to_string(),
+    })
+}
+
+fn parse_fields(lines: &[&str]) -> Result<FrontMatter, FrontMatterError> {
+    let mut fields = Map::new();
+    // Key that a following `- item` line belongs to
+    let mut list_key: Option<String> = None;
+
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if let Some(item) = trimmed
+            .strip_prefix("- ")
+            .or((trimmed == "-").then_some(""))
+        {
+            if let Some(key) = &list_key {
+                let list = fields
+                    .entry(key.clone())
+                    .or_insert_with(|| Value::Array(vec![]));
+                if let Value::Null = list {
+                    *list = Value::Array(vec![]);
+                }
+                if let Value::Array(items) = list {
+                    items.push(scalar(item));
+                }
+            }
+            continue;
+        }
+        if line.starts_with([' ', '\t']) {
+            // Nested mapping
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once(':') else {
+            return Err(FrontMatterError::InvalidLine(i + 2));
+        };
+        let key = key.trim();
+        if key.is_empty() {
+            return Err(FrontMatterError::InvalidLine(i + 2));
+        }
+        let value = value.trim();
+        list_key = value.is_empty().then(|| key.to_string());
+        fields.insert(key.to_string(), scalar(value));
+    }
+
+    Ok(FrontMatter(fields))
+}
+
+fn scalar(raw: &str) -> Value {
+    let raw = raw.trim();
+    if raw.starts_with('"') {
+        return serde_json::from_str::<String>(raw)
+            .map(Value::String)
+            .unwrap_or_else(|_| Value::String(raw.trim_matches('"').to_string()));
+    }
+    if let Some(inner) = raw
+        .strip_prefix('\'')
+        .and_then(|raw| raw.strip_suffix('\''))
+    {
+        return Value::String(inner.replace("''", "'"));
+    }
+    if raw.starts_with('[') || raw.starts_with('{') {
+        if let Ok(value) = serde_json::from_str(raw) {
+            return value;
+        }
+        if let Some(inner) = raw.strip_prefix('[').and_then(|raw| raw.strip_suffix(']')) {
+            return Value::Array(
+                inner
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(scalar)
+                    .collect(),
+            );
+        }
+    }
+
+    // A comment ends a plain scalar
+    let raw = raw
+        .split_once(" #")
+        .map_or(raw, |(value, _)| value)
+        .trim_end();
+    match raw {
+        "" | "~" | "null" | "Null" | "NULL" => Value::Null,
+        "true" | "True" | "TRUE" => Value::Bool(true),
+        "false" | "False" | "FALSE" => Value::Bool(false),
+        // Not `inf` or `NaN`, which Rust would parse but a title may well be
+        _ if raw.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '.') => raw
+            .parse::<i64>()
+            .map(Value::from)
+            .or_else(|_| raw.parse::<f64>().map(Value::from))
+            .unwrap_or_else(|_| Value::String(raw.to_string())),
+        _ => Value::String(raw.to_string()),
+    }
+}
+
+impl FrontMatter {
+    /// The first of `keys` that holds a non-blank scalar, as text
+    pub fn text(&self, keys: &[&str]) -> Option<String> {
+        keys.iter().find_map(|key| match self.0.get(*key)? {
+            Value::String(text) => Some(text.trim().to_string()).filter(|text| !text.is_empty()),
+            Value::Number(number) => Some(number.to_string()),
+            Value::Bool(flag) => Some(flag.to_string()),
+            _ => None,
+        })
+    }
+
+    /// A boolean, also accepting `"true"` and `"false"` strings
+    pub fn flag(&self, key: &str) -> Option<bool> {
+        match self.0.get(key)? {
+            Value::Bool(flag) => Some(*flag),
+            Value::String(text) => text.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// The items of the first of `keys` that is set. A string counts as a
+    /// comma-separated list.
+    pub fn list(&self, keys: &[&str]) -> Vec<String> {
+        let Some(value) = keys.iter().find_map(|key| self.0.get(*key)) else {
+            return vec![];
+        };
+        let items: Vec<String> = match value {
+            Value::Array(items) => items
+                .iter()
+                .filter_map(|item| match item {
+                    Value::String(text) => Some(text.clone()),
+                    Value::Number(number) => Some(number.to_string()),
+                    _ => None,
+                })
+                .collect(),
+            Value::String(text) => text.split(',').map(str::to_string).collect(),
+            _ => vec![],
+        };
+        items
+            .into_iter()
+            .map(|item| item.trim().to_string())
+            .filter(|item| !item.is_empty())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_hugo_style_front_matter() {
+        let file = parse(
+            "---\n\
+             title: \"Hello: world\"\n\
+             draft: true\n\
+             weight: 3\n\
+             tags: [rust, 'web dev']\n\
+             categories:\n  - Notes\n  - \"Tips\"\n\
+             params:\n  hidden: yes\n\
+             description: Plain text # with a comment\n\
+             ---\n\n# Body\n",
+        )
+        .unwrap();
+        let fm = &file.front_matter;
+
+        assert_eq!(fm.text(&["title"]).as_deref(), Some("Hello: world"));
+        assert_eq!(fm.flag("draft"), Some(true));
+        assert_eq!(fm.text(&["weight"]).as_deref(), Some("3"));
+        assert_eq!(fm.list(&["tags"]), vec!["rust", "web dev"]);
+        assert_eq!(fm.list(&["topics", "categories"]), vec!["Notes", "Tips"]);
+        assert_eq!(fm.text(&["description"]).as_deref(), Some("Plain text"));
+        assert_eq!(fm.text(&["params"]), None);
+        assert_eq!(file.body, "# Body\n");
+    }
+
+    #[test]
+    fn test_reads_exported_json_values() {
+        let file = parse(
+            "---\r\ntitle: \"About \\\"me\\\"\"\r\nseo_title: null\r\ntech_stack: [\"Rust\",\"SQL\"]\r\n---\r\n\r\nHi",
+        )
+        .unwrap();
+
+        assert_eq!(
+            file.front_matter.text(&["title"]).as_deref(),
+            Some("About \"me\"")
+        );
+        assert_eq!(file.front_matter.text(&["seo_title"]), None);
+        assert_eq!(file.front_matter.list(&["tech_stack"]), vec!["Rust", "SQL"]);
+        assert_eq!(file.body, "Hi");
+    }
+
+    #[test]
+    fn test_file_without_front_matter_is_all_body() {
+        let file = parse("# Just text\n---\n").unwrap();
+
+        assert_eq!(file.front_matter, FrontMatter::default());
+        assert_eq!(file.body, "# Just text\n---\n");
+    }
+
+    #[test]
+    fn test_rejects_unclosed_toml_and_malformed_front_matter() {
+        assert_eq!(parse("---\ntitle: x\n"), Err(FrontMatterError::Unclosed));
+        assert_eq!(
+            parse("+++\ntitle = 'x'\n+++\n"),
+            Err(FrontMatterError::Toml)
+        );
+        assert_eq!(
+            parse("---\ntitle: x\njust words\n---\n"),
+            Err(FrontMatterError::InvalidLine(3))
+        );
+    }
+
+    #[test]
+    fn test_string_list_is_comma_separated() {
+        let file = parse("---\ntags: rust, actix ,\n---\n").unwrap();
+
+        assert_eq!(file.front_matter.list(&["tags"]), vec!["rust", "actix"]);
+    }
+}
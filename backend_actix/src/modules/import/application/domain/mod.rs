@@ -0,0 +1,3 @@
+pub mod entities;
+pub mod front_matter;
+pub mod slug;
@@ -0,0 +1,50 @@
+//! Slugs for imported content. Page slugs are the strictest (lowercase
+//! letters, digits and single hyphens, at most 100 characters), so every
+//! kind gets one that would pass as a page slug.
+
+use crate::pages::application::ports::incoming::use_cases::MAX_SLUG_LENGTH;
+
+/// `text` as a slug; empty when it has no ASCII letters or digits
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.truncate(MAX_SLUG_LENGTH);
+    slug.trim_end_matches('-').to_string()
+}
+
+/// `base-n`, shortening `base` so the result still fits
+pub fn with_suffix(base: &str, n: u32) -> String {
+    let suffix = format!("-{n}");
+    let keep = MAX_SLUG_LENGTH.saturating_sub(suffix.len()).min(base.len());
+    format!("{}{suffix}", base[..keep].trim_end_matches('-'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Hello, World!"), "hello-world");
+        assert_eq!(slugify("  --Rust & Actix 2026-- "), "rust-actix-2026");
+        assert_eq!(slugify("Über uns"), "ber-uns");
+        assert_eq!(slugify("日本"), "");
+        assert_eq!(slugify(&"a".repeat(150)).len(), MAX_SLUG_LENGTH);
+    }
+
+    #[test]
+    fn test_suffix_keeps_slug_within_limit() {
+        assert_eq!(with_suffix("about", 2), "about-2");
+
+        let long = format!("{}-b", "a".repeat(97));
+        let slug = with_suffix(&long, 12);
+        assert_eq!(slug, format!("{}-12", "a".repeat(97)));
+        assert!(slug.len() <= MAX_SLUG_LENGTH);
+    }
+}
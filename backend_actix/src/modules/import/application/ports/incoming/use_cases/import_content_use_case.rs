@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::import::application::domain::entities::ImportReport;
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ImportContentError {
+    #[error("Not a readable zip archive: {0}")]
+    InvalidArchive(String),
+
+    #[error("Archive has more than {0} entries")]
+    TooManyFiles(usize),
+
+    #[error("Archive unpacks to more than {0} MiB")]
+    TooLarge(u64),
+}
+
+/// Pages and projects from a zip of Markdown files with Hugo or
+/// Jekyll-style front matter
+#[async_trait]
+pub trait ImportContentUseCase: Send + Sync {
+    /// Everything is created for `owner_id`. Files that can't be imported
+    /// are listed in the report rather than failing the import; only an
+    /// unreadable archive does. With `dry_run`, nothing is written and the
+    /// report says what would be.
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        archive: Vec<u8>,
+        dry_run: bool,
+    ) -> Result<ImportReport, ImportContentError>;
+}
+
+metered_use_case!(
+    "import",
+    "import_content",
+    ImportContentUseCase,
+    fn execute(
+        &self,
+        owner_id: Uuid,
+        archive: Vec<u8>,
+        dry_run: bool,
+    ) -> Result<ImportReport, ImportContentError>
+);
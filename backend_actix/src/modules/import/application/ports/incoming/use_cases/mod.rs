@@ -0,0 +1,3 @@
+mod import_content_use_case;
+
+pub use import_content_use_case::{ImportContentError, ImportContentUseCase};
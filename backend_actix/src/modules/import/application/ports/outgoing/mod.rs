@@ -0,0 +1,3 @@
+mod slug_lookup;
+
+pub use slug_lookup::{SlugLookup, SlugLookupError};
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SlugLookupError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Whether a slug is already used. Trashed projects keep their slug, as
+/// the unique index covers them too.
+#[async_trait]
+pub trait SlugLookup: Send + Sync {
+    /// Page slugs are unique per owner
+    async fn page_slug_taken(&self, owner_id: Uuid, slug: &str) -> Result<bool, SlugLookupError>;
+
+    /// Project slugs are unique across all owners, ignoring case
+    async fn project_slug_taken(&self, slug: &str) -> Result<bool, SlugLookupError>;
+}
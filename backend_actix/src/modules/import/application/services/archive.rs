@@ -0,0 +1,128 @@
+//! Reading the uploaded zip into Markdown texts.
+
+use std::io::{Cursor, Read};
+use zip::ZipArchive;
+
+use crate::import::application::ports::incoming::use_cases::ImportContentError;
+
+/// Entries read from one archive, directories included
+pub(super) const MAX_ENTRIES: usize = 1000;
+/// Unpacked size of one Markdown file
+const MAX_FILE_BYTES: u64 = 1024 * 1024;
+/// Unpacked size of all Markdown files together, against zip bombs
+const MAX_TOTAL_MIB: u64 = 50;
+
+pub(super) struct ArchiveFile {
+    pub path: String,
+    /// The text, or why it couldn't be read
+    pub content: Result<String, &'static str>,
+}
+
+/// Markdown (`.md`, `.markdown`) files in path order. Other files are
+/// returned with an error; directories, dotfiles and macOS `__MACOSX`
+/// metadata are left out.
+pub(super) fn read(bytes: Vec<u8>) -> Result<Vec<ArchiveFile>, ImportContentError> {
+    let mut archive = ZipArchive::new(Cursor::new(bytes))
+        .map_err(|err| ImportContentError::InvalidArchive(err.to_string()))?;
+    if archive.len() > MAX_ENTRIES {
+        return Err(ImportContentError::TooManyFiles(MAX_ENTRIES));
+    }
+
+    let mut files = Vec::new();
+    let mut total = 0;
+    for i in 0..archive.len() {
+        let mut entry = archive
+            .by_index(i)
+            .map_err(|err| ImportContentError::InvalidArchive(err.to_string()))?;
+        let path = entry.name().to_string();
+        if entry.is_dir() || is_hidden(&path) {
+            continue;
+        }
+        if !is_markdown(&path) {
+            files.push(ArchiveFile {
+                path,
+                content: Err("Not a Markdown file"),
+            });
+            continue;
+        }
+
+        let mut text = String::new();
+        let content = match (&mut entry)
+            .take(MAX_FILE_BYTES + 1)
+            .read_to_string(&mut text)
+        {
+            Ok(read) if read as u64 > MAX_FILE_BYTES => Err("Larger than 1 MiB"),
+            Ok(read) => {
+                total += read as u64;
+                Ok(text)
+            }
+            Err(_) => Err("Not UTF-8 text"),
+        };
+        if total > MAX_TOTAL_MIB * 1024 * 1024 {
+            return Err(ImportContentError::TooLarge(MAX_TOTAL_MIB));
+        }
+        files.push(ArchiveFile { path, content });
+    }
+
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(files)
+}
+
+fn is_hidden(path: &str) -> bool {
+    path.split('/')
+        .any(|segment| segment.starts_with('.') || segment == "__MACOSX")
+}
+
+fn is_markdown(path: &str) -> bool {
+    let lower = path.to_ascii_lowercase();
+    lower.ends_with(".md") || lower.ends_with(".markdown")
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    /// A deflated zip of `(path, content)` pairs; paths ending in `/` are
+    /// directories
+    pub fn zip_of(files: &[(&str, &str)]) -> Vec<u8> {
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for (path, content) in files {
+            if path.ends_with('/') {
+                zip.add_directory(*path, SimpleFileOptions::default())
+                    .unwrap();
+            } else {
+                zip.start_file(*path, SimpleFileOptions::default()).unwrap();
+                zip.write_all(content.as_bytes()).unwrap();
+            }
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_reads_markdown_in_path_order() {
+        let files = read(zip_of(&[
+            ("site/", ""),
+            ("site/b.md", "B"),
+            ("site/A.Markdown", "A"),
+            ("site/logo.png", "png"),
+            ("__MACOSX/site/._b.md", "junk"),
+            ("site/.draft.md", "hidden"),
+        ]))
+        .unwrap();
+
+        let paths: Vec<_> = files.iter().map(|file| file.path.as_str()).collect();
+        assert_eq!(paths, vec!["site/A.Markdown", "site/b.md", "site/logo.png"]);
+        assert_eq!(files[1].content, Ok("B".to_string()));
+        assert!(files[2].content.is_err());
+    }
+
+    #[test]
+    fn test_rejects_non_zip_bytes() {
+        assert!(matches!(
+            read(b"not a zip".to_vec()),
+            Err(ImportContentError::InvalidArchive(_))
+        ));
+    }
+}
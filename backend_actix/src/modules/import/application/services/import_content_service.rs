@@ -0,0 +1,669 @@
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::archive;
+use crate::auth::application::domain::entities::UserId;
+use crate::import::application::domain::entities::{ImportKind, ImportReport, ImportedItem};
+use crate::import::application::domain::front_matter::{self, FrontMatter};
+use crate::import::application::domain::slug::{slugify, with_suffix};
+use crate::import::application::ports::{
+    incoming::use_cases::{ImportContentError, ImportContentUseCase},
+    outgoing::SlugLookup,
+};
+use crate::pages::application::domain::entities::PageStatus;
+use crate::pages::application::ports::incoming::use_cases::{
+    CreatePageCommand, CreatePageError, CreatePageUseCase,
+};
+use crate::project::application::ports::incoming::use_cases::{
+    AddProjectTopicUseCase, CreateProjectError, CreateProjectUseCase,
+};
+use crate::project::application::ports::outgoing::project_repository::CreateProjectData;
+use crate::project::application::seo_fields;
+use crate::shared::unit_of_work::{UnitOfWork, UnitOfWorkError};
+use crate::topic::application::ports::incoming::use_cases::{
+    CreateTopicCommand, CreateTopicError, CreateTopicUseCase, GetTopicsUseCase,
+};
+
+/// Numbered variants tried before a file is skipped for want of a slug
+const MAX_SLUG_SUFFIX: u32 = 100;
+/// Creates retried when another request takes the slug in between
+const MAX_CREATE_ATTEMPTS: usize = 3;
+
+const POSTS_UNSUPPORTED: &str = "Posts can't be imported: there is no blog to import them into";
+const CVS_UNSUPPORTED: &str = "CVs can't be imported; create them with the CV API";
+
+/// The use cases imported content is written through, so it gets the same
+/// checks and cache invalidation as content created one at a time
+#[derive(Clone)]
+pub struct ImportTargets {
+    pub create_page: Arc<dyn CreatePageUseCase + Send + Sync>,
+    pub create_project: Arc<dyn CreateProjectUseCase + Send + Sync>,
+    pub add_project_topic: Arc<dyn AddProjectTopicUseCase + Send + Sync>,
+    pub get_topics: Arc<dyn GetTopicsUseCase + Send + Sync>,
+    pub create_topic: Arc<dyn CreateTopicUseCase + Send + Sync>,
+}
+
+pub struct ImportContentService<S, U>
+where
+    S: SlugLookup,
+    U: UnitOfWork,
+{
+    slugs: S,
+    targets: ImportTargets,
+    unit_of_work: U,
+}
+
+impl<S, U> ImportContentService<S, U>
+where
+    S: SlugLookup,
+    U: UnitOfWork,
+{
+    pub fn new(slugs: S, targets: ImportTargets, unit_of_work: U) -> Self {
+        Self {
+            slugs,
+            targets,
+            unit_of_work,
+        }
+    }
+}
+
+/// State of one import
+struct ImportRun {
+    owner_id: Uuid,
+    dry_run: bool,
+    report: ImportReport,
+    /// Slugs used by earlier files, or found taken while creating
+    claimed: HashSet<(ImportKind, String)>,
+    /// The owner's topics by lowercased title, loaded on first use. `None`
+    /// ids are topics a dry run would create.
+    topics: Option<HashMap<String, Option<Uuid>>>,
+}
+
+/// Why a project and its topic links were not kept
+enum Undone {
+    SlugTaken,
+    Failed(String),
+}
+
+impl From<UnitOfWorkError> for Undone {
+    fn from(err: UnitOfWorkError) -> Self {
+        Undone::Failed(err.to_string())
+    }
+}
+
+#[async_trait]
+impl<S, U> ImportContentUseCase for ImportContentService<S, U>
+where
+    S: SlugLookup,
+    U: UnitOfWork,
+{
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        archive: Vec<u8>,
+        dry_run: bool,
+    ) -> Result<ImportReport, ImportContentError> {
+        let files = archive::read(archive)?;
+        let mut run = ImportRun {
+            owner_id,
+            dry_run,
+            report: ImportReport {
+                dry_run,
+                ..ImportReport::default()
+            },
+            claimed: HashSet::new(),
+            topics: None,
+        };
+
+        for file in files {
+            let result = match file.content {
+                Ok(text) => self.import_file(&mut run, &file.path, &text).await,
+                Err(reason) => Err(reason.to_string()),
+            };
+            if let Err(reason) = result {
+                run.report.skip(&file.path, reason);
+            }
+        }
+
+        Ok(run.report)
+    }
+}
+
+impl<S, U> ImportContentService<S, U>
+where
+    S: SlugLookup,
+    U: UnitOfWork,
+{
+    async fn import_file(&self, run: &mut ImportRun, path: &str, text: &str) -> Result<(), String> {
+        let markdown = front_matter::parse(text).map_err(|err| err.to_string())?;
+        let fm = &markdown.front_matter;
+        let kind = detect_kind(path, fm)?;
+        let name = file_name(path)?;
+        let title = fm.text(&["title"]).unwrap_or_else(|| name.clone());
+        let requested = [fm.text(&["slug"]), Some(name), Some(title.clone())]
+            .into_iter()
+            .flatten()
+            .map(|text| slugify(&text))
+            .find(|slug| !slug.is_empty())
+            .ok_or("No slug can be made from the front matter, file name or title")?;
+
+        let mut item = ImportedItem {
+            path: path.to_string(),
+            kind,
+            id: None,
+            title,
+            slug: requested.clone(),
+            requested_slug: None,
+            status: None,
+            topics: vec![],
+            warnings: vec![],
+        };
+
+        for _ in 0..MAX_CREATE_ATTEMPTS {
+            item.slug = self.free_slug(run, kind, &requested).await?;
+            let created = match kind {
+                ImportKind::Page => self.create_page(run, fm, &markdown.body, &mut item).await?,
+                ImportKind::Project => {
+                    self.create_project(run, fm, &markdown.body, &mut item)
+                        .await?
+                }
+            };
+            run.claimed.insert((kind, item.slug.clone()));
+            if created {
+                item.requested_slug = (item.slug != requested).then_some(requested);
+                run.report.items.push(item);
+                return Ok(());
+            }
+        }
+
+        Err(format!(
+            "Slug '{}' was taken by other content during the import",
+            item.slug
+        ))
+    }
+
+    /// `base`, or the first `base-n` not already taken
+    async fn free_slug(
+        &self,
+        run: &ImportRun,
+        kind: ImportKind,
+        base: &str,
+    ) -> Result<String, String> {
+        for n in 1..=MAX_SLUG_SUFFIX {
+            let slug = if n == 1 {
+                base.to_string()
+            } else {
+                with_suffix(base, n)
+            };
+            if run.claimed.contains(&(kind, slug.clone())) {
+                continue;
+            }
+
+            let taken = match kind {
+                ImportKind::Page => self.slugs.page_slug_taken(run.owner_id, &slug).await,
+                ImportKind::Project => self.slugs.project_slug_taken(&slug).await,
+            }
+            .map_err(|err| format!("Failed to check the slug: {err}"))?;
+            if !taken {
+                return Ok(slug);
+            }
+        }
+
+        Err(format!(
+            "Slug '{base}' and its numbered variants up to -{MAX_SLUG_SUFFIX} are taken"
+        ))
+    }
+
+    /// `false` when the slug turned out to be taken
+    async fn create_page(
+        &self,
+        run: &ImportRun,
+        fm: &FrontMatter,
+        body: &str,
+        item: &mut ImportedItem,
+    ) -> Result<bool, String> {
+        let command = CreatePageCommand::new(
+            item.slug.clone(),
+            item.title.clone(),
+            body.to_string(),
+            page_status(fm),
+            fm.text(&["seo_title"]),
+            fm.text(&["seo_description", "description", "summary"]),
+        )
+        .and_then(|command| {
+            command.with_indexing(
+                fm.text(&["canonical_url"]),
+                fm.flag("noindex") == Some(true),
+            )
+        })
+        .map_err(|err| err.to_string())?;
+        item.title = command.title().to_string();
+        item.status = Some(command.status().to_string());
+
+        if run.dry_run {
+            return Ok(true);
+        }
+        match self
+            .targets
+            .create_page
+            .execute(run.owner_id, command)
+            .await
+        {
+            Ok(page) => {
+                item.id = Some(page.id);
+                Ok(true)
+            }
+            Err(CreatePageError::SlugAlreadyExists) => Ok(false),
+            Err(CreatePageError::RepositoryError(msg)) => {
+                Err(format!("Failed to create the page: {msg}"))
+            }
+        }
+    }
+
+    /// Creates the project and links its topics in one unit of work, so a
+    /// file is imported whole or not at all. `false` when the slug turned
+    /// out to be taken.
+    async fn create_project(
+        &self,
+        run: &mut ImportRun,
+        fm: &FrontMatter,
+        body: &str,
+        item: &mut ImportedItem,
+    ) -> Result<bool, String> {
+        let description = if body.trim().is_empty() {
+            fm.text(&["description", "summary"]).unwrap_or_default()
+        } else {
+            body.to_string()
+        };
+        let mut data = CreateProjectData {
+            owner: UserId::from(run.owner_id),
+            title: item.title.trim().to_string(),
+            slug: item.slug.clone(),
+            description,
+            tech_stack: fm.list(&["tech_stack", "tech", "stack"]),
+            screenshots: fm.list(&["screenshots", "images"]),
+            repo_url: fm.text(&["repo_url", "repo", "github"]),
+            live_demo_url: fm.text(&["live_demo_url", "demo", "website"]),
+            seo_title: fm.text(&["seo_title"]),
+            seo_description: fm.text(&["seo_description"]),
+            canonical_url: fm.text(&["canonical_url"]),
+            noindex: fm.flag("noindex") == Some(true),
+        };
+        seo_fields::check_create(&mut data).map_err(|errors| errors.to_string())?;
+        item.title = data.title.clone();
+
+        if run.dry_run {
+            self.link_topics(run, fm, item).await?;
+            return Ok(true);
+        }
+
+        // Topics created in an attempt that is undone are gone again
+        let topics = run.topics.clone();
+        let topics_created = run.report.topics_created.len();
+        let outcome = self
+            .unit_of_work
+            .run(async {
+                let project = match self.targets.create_project.execute(data).await {
+                    Ok(project) => project,
+                    Err(CreateProjectError::SlugAlreadyExists) => return Err(Undone::SlugTaken),
+                    Err(CreateProjectError::Invalid(errors)) => {
+                        return Err(Undone::Failed(errors.to_string()))
+                    }
+                    Err(CreateProjectError::RepositoryError(msg)) => {
+                        return Err(Undone::Failed(format!(
+                            "Failed to create the project: {msg}"
+                        )))
+                    }
+                };
+                item.id = Some(project.id);
+                self.link_topics(run, fm, item)
+                    .await
+                    .map_err(Undone::Failed)
+            })
+            .await;
+
+        match outcome {
+            Ok(()) => Ok(true),
+            Err(undone) => {
+                item.id = None;
+                item.topics.clear();
+                item.warnings.clear();
+                run.topics = topics;
+                run.report.topics_created.truncate(topics_created);
+                match undone {
+                    Undone::SlugTaken => Ok(false),
+                    Undone::Failed(reason) => Err(reason),
+                }
+            }
+        }
+    }
+
+    /// Finds or creates the file's topics and links them to the project.
+    /// A topic with an invalid title becomes a warning; one that fails to be
+    /// created or linked fails the file, and with it the project.
+    async fn link_topics(
+        &self,
+        run: &mut ImportRun,
+        fm: &FrontMatter,
+        item: &mut ImportedItem,
+    ) -> Result<(), String> {
+        let mut seen = HashSet::new();
+        for title in fm.list(&["topics", "tags", "categories"]) {
+            if !seen.insert(title.to_lowercase()) {
+                continue;
+            }
+
+            let topic_id = match self.topic_id(run, &title).await? {
+                Ok(topic_id) => topic_id,
+                Err(reason) => {
+                    item.warnings
+                        .push(format!("Topic '{title}' was left out: {reason}"));
+                    continue;
+                }
+            };
+            if let (Some(project_id), Some(topic_id)) = (item.id, topic_id) {
+                self.targets
+                    .add_project_topic
+                    .execute(UserId::from(run.owner_id), project_id, topic_id)
+                    .await
+                    .map_err(|err| format!("Topic '{title}' could not be linked: {err}"))?;
+            }
+            item.topics.push(title);
+        }
+        Ok(())
+    }
+
+    /// The owner's topic with this title, created if there's none. `None`
+    /// in a dry run for a topic that would be created. The inner error is a
+    /// title that can't be a topic; the outer one, a failed read or write.
+    async fn topic_id(
+        &self,
+        run: &mut ImportRun,
+        title: &str,
+    ) -> Result<Result<Option<Uuid>, String>, String> {
+        let key = title.to_lowercase();
+        if run.topics.is_none() {
+            run.topics = Some(self.load_topics(run.owner_id).await?);
+        }
+        if let Some(topic_id) = run.topics.as_ref().and_then(|topics| topics.get(&key)) {
+            return Ok(Ok(*topic_id));
+        }
+
+        let command =
+            match CreateTopicCommand::new(UserId::from(run.owner_id), title.to_string(), None) {
+                Ok(command) => command,
+                Err(err) => return Ok(Err(err.to_string())),
+            };
+        let title = command.title().to_string();
+        let topic_id = if run.dry_run {
+            None
+        } else {
+            match self.targets.create_topic.execute(command).await {
+                Ok(topic) => Some(topic.id),
+                Err(CreateTopicError::TopicAlreadyExists) => {
+                    // Created since the topics were loaded
+                    let topics = self.load_topics(run.owner_id).await?;
+                    let topic_id = topics.get(&key).copied().flatten();
+                    run.topics = Some(topics);
+                    return topic_id.map(|topic_id| Ok(Some(topic_id))).ok_or_else(|| {
+                        format!("Topic '{title}' already exists but could not be found")
+                    });
+                }
+                Err(err) => return Err(format!("Topic '{title}' could not be created: {err}")),
+            }
+        };
+
+        if let Some(topics) = run.topics.as_mut() {
+            topics.insert(key, topic_id);
+        }
+        run.report.topics_created.push(title);
+        Ok(Ok(topic_id))
+    }
+
+    async fn load_topics(&self, owner_id: Uuid) -> Result<HashMap<String, Option<Uuid>>, String> {
+        let topics = self
+            .targets
+            .get_topics
+            .execute(UserId::from(owner_id))
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(topics
+            .into_iter()
+            .map(|topic| (topic.title.to_lowercase(), Some(topic.id)))
+            .collect())
+    }
+}
+
+/// From a `type` or `layout` front matter field, else the innermost folder
+/// that names a kind, else a page
+fn detect_kind(path: &str, fm: &FrontMatter) -> Result<ImportKind, String> {
+    let named = |name: &str| match name.to_lowercase().as_str() {
+        "page" | "pages" => Some(Ok(ImportKind::Page)),
+        "project" | "projects" => Some(Ok(ImportKind::Project)),
+        "post" | "posts" | "_posts" | "blog" => Some(Err(POSTS_UNSUPPORTED.to_string())),
+        "cv" | "cvs" => Some(Err(CVS_UNSUPPORTED.to_string())),
+        _ => None,
+    };
+
+    fm.text(&["type", "layout"])
+        .and_then(|name| named(&name))
+        .or_else(|| path.rsplit('/').skip(1).find_map(named))
+        .unwrap_or(Ok(ImportKind::Page))
+}
+
+/// The file name without its extension; for `index.md` in a page bundle,
+/// the folder name
+fn file_name(path: &str) -> Result<String, String> {
+    let mut segments = path.rsplit('/');
+    let file = segments.next().unwrap_or(path);
+    let stem = file.rsplit_once('.').map_or(file, |(stem, _)| stem);
+
+    match stem {
+        "_index" => Err("Section index files are not imported".to_string()),
+        "index" => Ok(segments
+            .next()
+            .filter(|dir| !dir.is_empty())
+            .unwrap_or(stem)
+            .to_string()),
+        _ => Ok(stem.to_string()),
+    }
+}
+
+/// Published unless marked as a draft, as in Hugo and Jekyll
+fn page_status(fm: &FrontMatter) -> PageStatus {
+    let draft = fm.flag("draft") == Some(true)
+        || fm.flag("published") == Some(false)
+        || fm
+            .text(&["status"])
+            .is_some_and(|status| status.eq_ignore_ascii_case("draft"));
+
+    if draft {
+        PageStatus::Draft
+    } else {
+        PageStatus::Published
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::archive::tests::zip_of;
+    use super::*;
+
+    use crate::import::adapter::outgoing::InMemorySlugLookup;
+    use crate::pages::adapter::outgoing::InMemoryPageStore;
+    use crate::pages::application::ports::outgoing::PageRepository;
+    use crate::pages::application::services::CreatePageService;
+    use crate::project::adapter::outgoing::InMemoryProjectStore;
+    use crate::project::application::service::{AddProjectTopicService, CreateProjectService};
+    use crate::shared::cache::{CachePort, NoopCache};
+    use crate::shared::unit_of_work::InMemoryUnitOfWork;
+    use crate::topic::adapter::outgoing::InMemoryTopicStore;
+    use crate::topic::application::services::{CreateTopicService, GetTopicsService};
+
+    struct Fixture {
+        service: ImportContentService<InMemorySlugLookup, InMemoryUnitOfWork>,
+        pages: InMemoryPageStore,
+        projects: InMemoryProjectStore,
+        topics: InMemoryTopicStore,
+    }
+
+    fn fixture() -> Fixture {
+        let pages = InMemoryPageStore::default();
+        let topics = InMemoryTopicStore::default();
+        let projects = InMemoryProjectStore::new(topics.clone());
+        let cache: Arc<dyn CachePort> = Arc::new(NoopCache);
+
+        let service = ImportContentService::new(
+            InMemorySlugLookup::new(pages.clone(), projects.clone()),
+            ImportTargets {
+                create_page: Arc::new(CreatePageService::new(pages.clone(), cache.clone())),
+                create_project: Arc::new(CreateProjectService::new(
+                    projects.clone(),
+                    cache.clone(),
+                )),
+                add_project_topic: Arc::new(AddProjectTopicService::new(projects.clone(), cache)),
+                get_topics: Arc::new(GetTopicsService::new(topics.clone())),
+                create_topic: Arc::new(CreateTopicService::new(topics.clone())),
+            },
+            InMemoryUnitOfWork,
+        );
+
+        Fixture {
+            service,
+            pages,
+            projects,
+            topics,
+        }
+    }
+
+    fn archive() -> Vec<u8> {
+        zip_of(&[
+            (
+                "content/about.md",
+                "---\ntitle: About me\ndescription: Who I am\n---\n\n# Hi\n",
+            ),
+            ("content/about/index.md", "---\ndraft: true\n---\nAgain\n"),
+            (
+                "content/projects/cms.md",
+                "---\ntitle: Port CMS\ntags: [Rust, rust, Web]\nrepo: https://example.com/cms\n---\nA CMS\n",
+            ),
+            ("content/posts/hello.md", "---\ntitle: Hello\n---\n"),
+            ("content/_index.md", "---\ntitle: Home\n---\n"),
+            ("content/broken.md", "---\ntitle: x\n"),
+        ])
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_reports_without_writing() {
+        let fx = fixture();
+
+        let report = fx
+            .service
+            .execute(Uuid::new_v4(), archive(), true)
+            .await
+            .unwrap();
+
+        assert!(report.dry_run);
+        let slugs: Vec<_> = report.items.iter().map(|item| item.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["about", "about-2", "cms"]);
+        assert_eq!(report.items[1].requested_slug.as_deref(), Some("about"));
+        assert_eq!(report.items[1].status.as_deref(), Some("draft"));
+        assert_eq!(report.items[2].kind, ImportKind::Project);
+        assert_eq!(report.items[2].topics, vec!["Rust", "Web"]);
+        assert!(report.items.iter().all(|item| item.id.is_none()));
+        assert_eq!(report.topics_created, vec!["Rust", "Web"]);
+
+        let skipped: Vec<_> = report
+            .skipped
+            .iter()
+            .map(|file| file.path.as_str())
+            .collect();
+        assert_eq!(
+            skipped,
+            vec![
+                "content/_index.md",
+                "content/broken.md",
+                "content/posts/hello.md"
+            ]
+        );
+
+        assert!(fx.pages.pages.read(|pages| pages.is_empty()));
+        assert!(fx.projects.projects.read(|rows| rows.is_empty()));
+        assert!(fx.topics.topics.read(|rows| rows.is_empty()));
+    }
+
+    #[tokio::test]
+    async fn test_import_creates_content_and_resolves_existing_slugs() {
+        let fx = fixture();
+        let owner_id = Uuid::new_v4();
+        fx.pages
+            .create(
+                owner_id,
+                &CreatePageCommand::new(
+                    "about".to_string(),
+                    "Old".to_string(),
+                    String::new(),
+                    PageStatus::Draft,
+                    None,
+                    None,
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        fx.service
+            .targets
+            .create_topic
+            .execute(
+                CreateTopicCommand::new(UserId::from(owner_id), "web".to_string(), None).unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let report = fx
+            .service
+            .execute(owner_id, archive(), false)
+            .await
+            .unwrap();
+
+        let slugs: Vec<_> = report.items.iter().map(|item| item.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["about-2", "about-3", "cms"]);
+        assert!(report.items.iter().all(|item| item.id.is_some()));
+        assert_eq!(report.topics_created, vec!["Rust"]);
+        assert!(report.items[2].warnings.is_empty());
+
+        let page = fx.pages.pages.read(|pages| {
+            pages
+                .iter()
+                .find(|page| page.slug == "about-2")
+                .cloned()
+                .unwrap()
+        });
+        assert_eq!(page.status, PageStatus::Published);
+        assert_eq!(page.seo_description.as_deref(), Some("Who I am"));
+        assert_eq!(page.body, "# Hi\n");
+
+        let project = fx.projects.projects.read(|rows| rows[0].project.clone());
+        assert_eq!(project.title, "Port CMS");
+        assert_eq!(project.repo_url.as_deref(), Some("https://example.com/cms"));
+        assert_eq!(fx.topics.topics.read(|rows| rows.len()), 2);
+    }
+
+    #[test]
+    fn test_kind_from_front_matter_then_folder() {
+        let fm = |text: &str| front_matter::parse(text).unwrap().front_matter;
+
+        assert_eq!(
+            detect_kind("projects/a.md", &fm("---\ntype: page\n---\n")),
+            Ok(ImportKind::Page)
+        );
+        assert_eq!(
+            detect_kind("site/projects/2026/a.md", &fm("")),
+            Ok(ImportKind::Project)
+        );
+        assert!(detect_kind("_posts/a.md", &fm("")).is_err());
+        assert_eq!(detect_kind("a.md", &fm("")), Ok(ImportKind::Page));
+    }
+}
@@ -0,0 +1,4 @@
+mod archive;
+mod import_content_service;
+
+pub use import_content_service::{ImportContentService, ImportTargets};
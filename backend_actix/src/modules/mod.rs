@@ -1,6 +1,22 @@
+pub mod activity;
+pub mod admin;
+pub mod analytics;
 pub mod auth;
+pub mod batch;
+pub mod blocklist;
+pub mod contact;
 pub mod cv;
 pub mod email;
+pub mod export;
+pub mod import;
 pub mod multimedia;
+pub mod pages;
 pub mod project;
+pub mod redirects;
+pub mod search;
+pub mod site;
+pub mod skills;
 pub mod topic;
+pub mod translations;
+pub mod trash;
+pub mod webhooks;
@@ -0,0 +1,38 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    INVALID_FILE_NAME = (BAD_REQUEST, "Invalid file name");
+    INVALID_EXTENSION = (BAD_REQUEST, "Invalid file extension");
+    INVALID_MIME_TYPE = (BAD_REQUEST, "Invalid mime type");
+    MIME_EXTENSION_MISMATCH = (BAD_REQUEST, "Mime type does not match the file extension");
+    FILE_TOO_LARGE = (BAD_REQUEST, "File too large");
+    INVALID_DIMENSIONS = (BAD_REQUEST, "Invalid dimensions");
+    TARGET_NOT_FOUND = (BAD_REQUEST, "Target Attachment Is Not Exist");
+    INVALID_CHECKSUM = (BAD_REQUEST, "Checksum must be a hex SHA-256 digest");
+    DUPLICATE_MEDIA = (CONFLICT, "An identical file is already stored");
+    UPLOAD_RATE_LIMITED = (TOO_MANY_REQUESTS, "Upload URL limit reached");
+    INVALID_UPLOAD_BATCH = (BAD_REQUEST, "Send between 1 and 20 files");
+    UPLOAD_BATCH_NOT_FOUND = (NOT_FOUND, "Upload batch not found");
+    MEDIA_NOT_FOUND = (NOT_FOUND, "Media not found");
+    MEDIA_NOT_UPLOADED = (CONFLICT, "The file has not been uploaded yet");
+    MEDIA_PENDING = (CONFLICT, "Media is pending upload");
+    MEDIA_PROCESSING = (CONFLICT, "Media is still being processed");
+    MEDIA_FAILED = (CONFLICT, "Media processing failed");
+    VARIANT_NOT_FOUND = (NOT_FOUND, "Variant not found for this media");
+    STORAGE_ERROR = (BAD_GATEWAY, "Storage could not be reached");
+    INVALID_VARIANT_WIDTHS = (BAD_REQUEST, "Invalid variant widths");
+    INVALID_VARIANT_BATCH = (BAD_REQUEST, "Ask for between 1 and 50 variants");
+    PROCESSOR_NOT_CONFIGURED = (SERVICE_UNAVAILABLE, "No image processor is configured");
+    PROCESSOR_ERROR = (BAD_GATEWAY, "The image processor could not be reached");
+    INVALID_MANIFEST = (BAD_REQUEST, "Invalid processing manifest");
+    INVALID_IMAGE_URL = (BAD_REQUEST, "Not an absolute http(s) URL");
+    IMAGE_ORIGIN_NOT_ALLOWED = (FORBIDDEN, "Images from this origin are not proxied");
+    IMAGE_NOT_FOUND = (NOT_FOUND, "Image not found at the origin");
+    IMAGE_TOO_LARGE = (UNPROCESSABLE_ENTITY, "Image too large");
+    UNSUPPORTED_IMAGE_TYPE = (UNPROCESSABLE_ENTITY, "Unsupported image type");
+    IMAGE_FETCH_FAILED = (BAD_GATEWAY, "The image could not be fetched");
+    INVALID_STORAGE_OVERRIDE = (BAD_REQUEST, "Invalid storage override");
+    BUCKET_TAKEN = (CONFLICT, "Bucket already belongs to another account");
+    STORAGE_OVERRIDE_NOT_FOUND = (NOT_FOUND, "The user uses the server's buckets");
+    ENCRYPTION_NOT_CONFIGURED = (SERVICE_UNAVAILABLE, "Field encryption is not configured");
+}
@@ -1 +1,29 @@
+use actix_web::web;
+
+pub mod callback_signature;
+pub mod error_codes;
 pub mod routes;
+
+/// Routes anyone can call: the processor's callback carries its own signature
+pub fn configure_public(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::proxy_image_handler)
+        .service(routes::ingest_media_manifest_handler);
+}
+
+/// Routes for signed-in users with a verified email
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::init_upload_handler)
+        // Before `list_media_handler`, whose `{attachment_target}` would match it
+        .service(routes::upload_policy_handler)
+        .service(routes::create_upload_batch_handler)
+        // Before `get_variant_read_url_handler`, whose `{media_id}/{media_size}` would match it
+        .service(routes::get_upload_batch_handler)
+        .service(routes::finalize_upload_handler)
+        .service(routes::get_variant_read_urls_handler)
+        .service(routes::get_variant_read_url_handler)
+        .service(routes::list_media_handler)
+        .service(routes::regenerate_media_handler)
+        .service(routes::get_storage_override_handler)
+        .service(routes::set_storage_override_handler)
+        .service(routes::delete_storage_override_handler);
+}
@@ -0,0 +1,164 @@
+use actix_web::{post, web, Responder};
+use serde::Serialize;
+use tracing::error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::auth::adapter::incoming::web::extractors::auth::VerifiedUser;
+use crate::multimedia::adapter::incoming::web::error_codes::{
+    MEDIA_NOT_FOUND, MEDIA_NOT_UPLOADED, STORAGE_ERROR,
+};
+use crate::multimedia::application::domain::entities::MediaState;
+use crate::multimedia::application::ports::incoming::use_cases::FinalizeUploadError;
+use crate::shared::api::ApiResponse;
+use crate::AppState;
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FinalizeUploadResponse {
+    pub media_id: Uuid,
+    /// `processing` once finalized; `ready` or `failed` if the image
+    /// processor has already finished
+    pub status: MediaState,
+}
+
+/// Tell the server a direct upload has completed
+///
+/// Call once the PUT to the URL from `POST /api/media/upload-url` has
+/// succeeded. The file must be in storage; the media then moves from
+/// `pending` to `processing`. Calling again is harmless and reports the
+/// current state.
+#[utoipa::path(
+    post,
+    path = "/api/media/{media_id}/finalize",
+    tag = "media",
+    params(
+        ("media_id" = Uuid, Path, description = "Media id from the upload URL response"),
+    ),
+    responses(
+        (status = 200, description = "Upload confirmed", body = inline(SuccessResponse<FinalizeUploadResponse>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Media not found", body = ErrorResponse),
+        (status = 409, description = "The file was never uploaded (`MEDIA_NOT_UPLOADED`)", body = ErrorResponse),
+        (status = 502, description = "Storage could not be checked", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/media/{media_id}/finalize")]
+pub async fn finalize_upload_handler(
+    user: VerifiedUser,
+    path: web::Path<Uuid>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let media_id = path.into_inner();
+
+    match data
+        .multimedia
+        .finalize_upload
+        .execute(user.user_id.into(), media_id)
+        .await
+    {
+        Ok(result) => ApiResponse::success(FinalizeUploadResponse {
+            media_id: result.media_id,
+            status: result.status,
+        }),
+
+        Err(FinalizeUploadError::MediaNotFound) => MEDIA_NOT_FOUND.response(),
+
+        Err(FinalizeUploadError::NotUploaded) => MEDIA_NOT_UPLOADED.response(),
+
+        Err(FinalizeUploadError::StorageError(e)) => {
+            error!("Storage error finalizing upload: {}", e);
+            STORAGE_ERROR.with_message("Failed to check the upload")
+        }
+
+        Err(FinalizeUploadError::RepositoryError(e)) => {
+            error!("Repository error finalizing upload: {}", e);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::Value;
+    use std::sync::Arc;
+
+    use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, auth_helper::test_helpers::create_test_jwt_service,
+        stubs::StubFinalizeUploadUseCase,
+    };
+
+    async fn call(stub: StubFinalizeUploadUseCase, media_id: Uuid) -> (StatusCode, Value) {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_finalize_upload(stub)
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(finalize_upload_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/media/{media_id}/finalize"))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        let status = resp.status();
+        (status, test::read_body_json(resp).await)
+    }
+
+    #[actix_web::test]
+    async fn test_finalize_upload_reports_processing() {
+        let media_id = Uuid::new_v4();
+
+        let (status, body) = call(
+            StubFinalizeUploadUseCase::success(MediaState::Processing),
+            media_id,
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["mediaId"], media_id.to_string());
+        assert_eq!(body["data"]["status"], "processing");
+    }
+
+    #[actix_web::test]
+    async fn test_finalize_upload_never_uploaded_is_conflict() {
+        let (status, body) = call(
+            StubFinalizeUploadUseCase::failure(FinalizeUploadError::NotUploaded),
+            Uuid::new_v4(),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["error"]["code"], "MEDIA_NOT_UPLOADED");
+    }
+
+    #[actix_web::test]
+    async fn test_finalize_upload_unknown_media_is_not_found() {
+        let (status, body) = call(
+            StubFinalizeUploadUseCase::failure(FinalizeUploadError::MediaNotFound),
+            Uuid::new_v4(),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["error"]["code"], "MEDIA_NOT_FOUND");
+    }
+}
@@ -1,14 +1,23 @@
 use actix_web::{get, web, Responder};
 use serde::{Deserialize, Serialize};
 use tracing::error;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
 use crate::auth::adapter::incoming::web::extractors::auth::VerifiedUser;
+use crate::multimedia::adapter::incoming::web::error_codes::{
+    MEDIA_FAILED, MEDIA_NOT_FOUND, MEDIA_PENDING, MEDIA_PROCESSING, STORAGE_ERROR,
+    VARIANT_NOT_FOUND,
+};
 use crate::multimedia::application::domain::entities::MediaSize;
 use crate::multimedia::application::ports::incoming::use_cases::{GetReadUrlError, GetUrlCommand};
-use crate::shared::api::ApiResponse;
+use crate::shared::api::{cache::CachePolicy, ApiResponse};
 use crate::AppState;
 
+/// Stop caching a signed URL this long before it expires
+const SIGNED_URL_CACHE_MARGIN_SECS: i64 = 60;
+
 //
 // ──────────────────────────────────────────────────────────
 // Path DTO
@@ -27,7 +36,7 @@ pub struct GetVariantPath {
 // ──────────────────────────────────────────────────────────
 //
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub struct GetVariantUrlResponse {
     pub media_id: Uuid,
@@ -42,6 +51,28 @@ pub struct GetVariantUrlResponse {
 // ──────────────────────────────────────────────────────────
 //
 
+/// Get a short-lived read URL for one size of a processed image
+#[utoipa::path(
+    get,
+    path = "/api/media/{media_id}/{media_size}",
+    tag = "media",
+    params(
+        ("media_id" = Uuid, Path, description = "Media id"),
+        ("media_size" = MediaSize, Path, description = "Variant size"),
+    ),
+    responses(
+        (status = 200, description = "Signed URL (cacheable until shortly before it expires)", body = inline(SuccessResponse<GetVariantUrlResponse>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Media or variant not found", body = ErrorResponse),
+        (status = 409, description = "Media still pending, processing, or failed", body = ErrorResponse),
+        (status = 502, description = "Storage could not sign the URL", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[get("/api/media/{media_id}/{media_size}")]
 pub async fn get_variant_read_url_handler(
     user: VerifiedUser,
@@ -55,7 +86,7 @@ pub async fn get_variant_read_url_handler(
         "small" => MediaSize::Small,
         "medium" => MediaSize::Medium,
         "large" => MediaSize::Large,
-        _ => return ApiResponse::not_found("VARIANT_NOT_FOUND", "Invalid media size"),
+        _ => return VARIANT_NOT_FOUND.with_message("Invalid media size"),
     };
 
     let command = GetUrlCommand {
@@ -65,12 +96,24 @@ pub async fn get_variant_read_url_handler(
     };
 
     match data.multimedia.create_signed_get_url.execute(command).await {
-        Ok(result) => ApiResponse::success(GetVariantUrlResponse {
-            media_id: result.media_id,
-            size: result.size,
-            url: result.url,
-            expires_at: result.expires_at,
-        }),
+        Ok(result) => {
+            // The browser may reuse the signed URL until shortly before it expires
+            let max_age = (result.expires_at - chrono::Utc::now()).num_seconds()
+                - SIGNED_URL_CACHE_MARGIN_SECS;
+            let policy = match u32::try_from(max_age) {
+                Ok(max_age) if max_age > 0 => CachePolicy::Private { max_age },
+                _ => CachePolicy::NoStore,
+            };
+
+            let mut resp = ApiResponse::success(GetVariantUrlResponse {
+                media_id: result.media_id,
+                size: result.size,
+                url: result.url,
+                expires_at: result.expires_at,
+            });
+            policy.apply(&mut resp);
+            resp
+        }
 
         Err(e) => map_get_read_url_error(e),
     }
@@ -78,39 +121,19 @@ pub async fn get_variant_read_url_handler(
 
 fn map_get_read_url_error(e: GetReadUrlError) -> actix_web::HttpResponse {
     match e {
-        GetReadUrlError::MediaNotFound => {
-            ApiResponse::not_found("MEDIA_NOT_FOUND", "Media not found")
-        }
-        GetReadUrlError::VariantNotFound(size) => ApiResponse::not_found(
-            "VARIANT_NOT_FOUND",
-            &format!("Variant '{:?}' not found for this media", size),
-        ),
+        GetReadUrlError::MediaNotFound => MEDIA_NOT_FOUND.response(),
+        GetReadUrlError::VariantNotFound(size) => VARIANT_NOT_FOUND
+            .with_message(&format!("Variant '{:?}' not found for this media", size)),
 
         // state-related errors: 409 Conflict makes sense
-        GetReadUrlError::MediaProcessing => ApiResponse::error(
-            actix_web::http::StatusCode::CONFLICT,
-            "MEDIA_PROCESSING",
-            "Media is still being processed",
-        ),
-        GetReadUrlError::MediaPending => ApiResponse::error(
-            actix_web::http::StatusCode::CONFLICT,
-            "MEDIA_PENDING",
-            "Media is pending upload",
-        ),
-        GetReadUrlError::MediaFailed => ApiResponse::error(
-            actix_web::http::StatusCode::CONFLICT,
-            "MEDIA_FAILED",
-            "Media processing failed",
-        ),
+        GetReadUrlError::MediaProcessing => MEDIA_PROCESSING.response(),
+        GetReadUrlError::MediaPending => MEDIA_PENDING.response(),
+        GetReadUrlError::MediaFailed => MEDIA_FAILED.response(),
 
         // infra errors
         GetReadUrlError::StorageError(msg) => {
             error!("Storage error creating read URL: {}", msg);
-            ApiResponse::error(
-                actix_web::http::StatusCode::BAD_GATEWAY,
-                "STORAGE_ERROR",
-                "Failed to generate read URL",
-            )
+            STORAGE_ERROR.with_message("Failed to generate read URL")
         }
         GetReadUrlError::QueryError(msg) => {
             error!("Query error creating read URL: {}", msg);
@@ -156,6 +179,17 @@ mod tests {
             }
         }
 
+        fn expiring_in(media_id: Uuid, minutes: i64) -> Self {
+            Self {
+                result: Ok(GetUrlResult {
+                    media_id,
+                    size: MediaSize::Medium,
+                    url: "https://example.com/read-url".to_string(),
+                    expires_at: chrono::Utc::now() + chrono::Duration::minutes(minutes),
+                }),
+            }
+        }
+
         fn err(err: GetReadUrlError) -> Self {
             Self { result: Err(err) }
         }
@@ -175,16 +209,18 @@ mod tests {
     fn jwt_service() -> JwtTokenService {
         JwtTokenService::new(JwtConfig {
             issuer: "Lotion".to_string(),
+            audience: "test_audience".to_string(),
             secret_key: "test_secret_key_for_testing_purposes_only".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         })
     }
 
     fn token(user_id: Uuid, verified: bool) -> String {
         jwt_service()
-            .generate_access_token(user_id, verified)
+            .generate_access_token(user_id, verified, 0)
             .unwrap()
     }
 
@@ -231,6 +267,48 @@ mod tests {
         assert!(body["data"]["expires_at"].is_string());
     }
 
+    #[actix_web::test]
+    async fn test_get_variant_read_url_is_cached_privately_until_expiry() {
+        let user_id = Uuid::new_v4();
+        let media_id = Uuid::new_v4();
+
+        let app_state = TestAppStateBuilder::default()
+            .with_create_signed_get_url(MockCreateSignedGetUrlUseCase::expiring_in(media_id, 60))
+            .build();
+
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service());
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .app_data(web::Data::new(token_provider))
+                .service(get_variant_read_url_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/api/media/{}/medium", media_id))
+            .insert_header(("Authorization", format!("Bearer {}", token(user_id, true))))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let cache_control = resp
+            .headers()
+            .get("Cache-Control")
+            .unwrap()
+            .to_str()
+            .unwrap();
+        let max_age: i64 = cache_control
+            .strip_prefix("private, max-age=")
+            .expect("private policy")
+            .parse()
+            .unwrap();
+        // 60 minutes minus the 60s margin, allowing for test run time
+        assert!((3530..=3540).contains(&max_age), "max-age {}", max_age);
+    }
+
     // -----------------------
     // Error arms in map_get_read_url_error
     // -----------------------
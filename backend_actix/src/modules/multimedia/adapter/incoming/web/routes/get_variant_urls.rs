@@ -0,0 +1,318 @@
+use actix_web::{post, web, Responder};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::auth::adapter::incoming::web::extractors::auth::VerifiedUser;
+use crate::multimedia::adapter::incoming::web::error_codes::{
+    INVALID_VARIANT_BATCH, MEDIA_FAILED, MEDIA_NOT_FOUND, MEDIA_PENDING, MEDIA_PROCESSING,
+    STORAGE_ERROR, VARIANT_NOT_FOUND,
+};
+use crate::multimedia::application::domain::entities::MediaSize;
+use crate::multimedia::application::ports::incoming::use_cases::{
+    GetReadUrlError, GetUrlsCommand, VariantUrlOutcome, MAX_VARIANT_URLS,
+};
+use crate::shared::api::{cache::CachePolicy, error_codes::INTERNAL_ERROR, ApiResponse, ErrorCode};
+use crate::AppState;
+
+//
+// ──────────────────────────────────────────────────────────
+// Request DTO
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct VariantRef {
+    pub media_id: Uuid,
+    pub size: MediaSize,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct GetVariantUrlsRequest {
+    /// At most 50; the same media may be asked for in several sizes
+    pub variants: Vec<VariantRef>,
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Response DTO
+// ──────────────────────────────────────────────────────────
+//
+
+/// A signed URL, or why this variant can't be read
+#[derive(Debug, Serialize, ToSchema)]
+pub struct VariantUrlItem {
+    pub media_id: Uuid,
+    pub size: MediaSize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Same codes as `GET /api/media/{media_id}/{media_size}`, e.g.
+    /// `MEDIA_PROCESSING`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GetVariantUrlsResponse {
+    /// One per requested variant, in request order
+    pub items: Vec<VariantUrlItem>,
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Handler
+// ──────────────────────────────────────────────────────────
+//
+
+/// Get short-lived read URLs for several image variants at once
+///
+/// Meant for galleries: the media are looked up together and the URLs
+/// signed side by side. The response is 200 whenever the batch ran; a
+/// variant that can't be read carries the error code the single-variant
+/// endpoint would have returned instead of a URL.
+#[utoipa::path(
+    post,
+    path = "/api/media/variant-urls",
+    tag = "media",
+    request_body = GetVariantUrlsRequest,
+    responses(
+        (status = 200, description = "A URL or an error per variant, in request order", body = inline(SuccessResponse<GetVariantUrlsResponse>)),
+        (status = 400, description = "No variants, too many, or malformed request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/media/variant-urls")]
+pub async fn get_variant_read_urls_handler(
+    user: VerifiedUser,
+    payload: web::Json<GetVariantUrlsRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let variants = payload.into_inner().variants;
+    if variants.is_empty() || variants.len() > MAX_VARIANT_URLS {
+        return INVALID_VARIANT_BATCH.with_message(&format!(
+            "Ask for between 1 and {MAX_VARIANT_URLS} variants, got {}",
+            variants.len()
+        ));
+    }
+
+    let requested: Vec<(Uuid, MediaSize)> = variants
+        .into_iter()
+        .map(|variant| (variant.media_id, variant.size))
+        .collect();
+    let command = GetUrlsCommand {
+        owner: user.user_id.into(),
+        variants: requested.clone(),
+    };
+
+    match data
+        .multimedia
+        .create_signed_get_urls
+        .execute(command)
+        .await
+    {
+        Ok(outcomes) => {
+            let items = requested
+                .into_iter()
+                .zip(outcomes)
+                .map(|((media_id, size), outcome)| to_item(media_id, size, outcome))
+                .collect();
+
+            // Each URL expires on its own schedule; the set isn't worth caching
+            let mut resp = ApiResponse::success(GetVariantUrlsResponse { items });
+            CachePolicy::NoStore.apply(&mut resp);
+            resp
+        }
+        Err(e) => {
+            error!("Query error creating read URLs: {}", e);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+fn to_item(media_id: Uuid, size: MediaSize, outcome: VariantUrlOutcome) -> VariantUrlItem {
+    match outcome {
+        Ok(result) => VariantUrlItem {
+            media_id,
+            size,
+            url: Some(result.url),
+            expires_at: Some(result.expires_at),
+            code: None,
+            message: None,
+        },
+        Err(e) => {
+            let (code, message) = item_error(e);
+            VariantUrlItem {
+                media_id,
+                size,
+                url: None,
+                expires_at: None,
+                code: Some(code.code.to_string()),
+                message: Some(message),
+            }
+        }
+    }
+}
+
+fn item_error(e: GetReadUrlError) -> (ErrorCode, String) {
+    match e {
+        GetReadUrlError::MediaNotFound => (MEDIA_NOT_FOUND, MEDIA_NOT_FOUND.message.to_string()),
+        GetReadUrlError::VariantNotFound(size) => (
+            VARIANT_NOT_FOUND,
+            format!("Variant '{:?}' not found for this media", size),
+        ),
+        GetReadUrlError::MediaProcessing => {
+            (MEDIA_PROCESSING, MEDIA_PROCESSING.message.to_string())
+        }
+        GetReadUrlError::MediaPending => (MEDIA_PENDING, MEDIA_PENDING.message.to_string()),
+        GetReadUrlError::MediaFailed => (MEDIA_FAILED, MEDIA_FAILED.message.to_string()),
+        GetReadUrlError::StorageError(msg) => {
+            error!("Storage error creating read URL: {}", msg);
+            (STORAGE_ERROR, "Failed to generate read URL".to_string())
+        }
+        GetReadUrlError::QueryError(msg) => {
+            error!("Query error creating read URL: {}", msg);
+            (INTERNAL_ERROR, INTERNAL_ERROR.message.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+
+    use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, auth_helper::test_helpers::create_test_jwt_service,
+        stubs::StubGetVariantReadUrlsUseCase,
+    };
+
+    async fn call(
+        state: web::Data<AppState>,
+        body: Value,
+        verified: bool,
+    ) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt
+            .generate_access_token(Uuid::new_v4(), verified, 0)
+            .unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(get_variant_read_urls_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/media/variant-urls")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(body)
+            .to_request();
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_signs_every_variant_in_request_order() {
+        let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+        let state = TestAppStateBuilder::default()
+            .with_create_signed_get_urls(StubGetVariantReadUrlsUseCase::signing_except(
+                second,
+                GetReadUrlError::MediaProcessing,
+            ))
+            .build();
+
+        let resp = call(
+            state,
+            json!({ "variants": [
+                { "media_id": first, "size": "thumbnail" },
+                { "media_id": second, "size": "thumbnail" },
+                { "media_id": first, "size": "large" },
+            ]}),
+            true,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers().get("Cache-Control").unwrap(), "no-store");
+
+        let body: Value = test::read_body_json(resp).await;
+        let items = body["data"]["items"].as_array().unwrap();
+        assert_eq!(items.len(), 3);
+        assert_eq!(
+            items[0]["url"],
+            format!("https://signed.example/{first}/thumbnail")
+        );
+        assert_eq!(items[1]["media_id"], second.to_string());
+        assert_eq!(items[1]["code"], "MEDIA_PROCESSING");
+        assert!(items[1].get("url").is_none());
+        assert_eq!(items[2]["size"], "large");
+        assert!(items[2]["expires_at"].is_string());
+    }
+
+    #[actix_web::test]
+    async fn test_rejects_empty_and_oversized_batches() {
+        let empty = call(
+            TestAppStateBuilder::default().build(),
+            json!({ "variants": [] }),
+            true,
+        )
+        .await;
+        assert_eq!(empty.status(), StatusCode::BAD_REQUEST);
+        let body: Value = test::read_body_json(empty).await;
+        assert_eq!(body["error"]["code"], "INVALID_VARIANT_BATCH");
+
+        let variants: Vec<Value> = (0..=MAX_VARIANT_URLS)
+            .map(|_| json!({ "media_id": Uuid::new_v4(), "size": "small" }))
+            .collect();
+        let oversized = call(
+            TestAppStateBuilder::default().build(),
+            json!({ "variants": variants }),
+            true,
+        )
+        .await;
+        assert_eq!(oversized.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_failed_lookup_fails_the_batch() {
+        let state = TestAppStateBuilder::default()
+            .with_create_signed_get_urls(StubGetVariantReadUrlsUseCase::failure(
+                GetReadUrlError::QueryError("db down".to_string()),
+            ))
+            .build();
+
+        let resp = call(
+            state,
+            json!({ "variants": [{ "media_id": Uuid::new_v4(), "size": "small" }] }),
+            true,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[actix_web::test]
+    async fn test_unverified_user_is_forbidden() {
+        let resp = call(
+            TestAppStateBuilder::default().build(),
+            json!({ "variants": [{ "media_id": Uuid::new_v4(), "size": "small" }] }),
+            false,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+}
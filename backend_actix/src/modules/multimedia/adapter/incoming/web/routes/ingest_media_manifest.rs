@@ -0,0 +1,234 @@
+use actix_web::{post, web, HttpRequest, Responder};
+use shared_domain::manifest::Manifest;
+use tracing::error;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::multimedia::adapter::incoming::web::callback_signature::{
+    self, CallbackSignatureError, SIGNATURE_HEADER, TIMESTAMP_HEADER,
+};
+use crate::multimedia::adapter::incoming::web::error_codes::{INVALID_MANIFEST, MEDIA_NOT_FOUND};
+use crate::multimedia::application::ports::incoming::use_cases::{
+    IngestMediaManifestError, IngestMediaManifestResult,
+};
+use crate::shared::api::error_codes::{NOT_FOUND, UNAUTHORIZED};
+use crate::shared::api::ApiResponse;
+use crate::AppState;
+
+fn header<'a>(req: &'a HttpRequest, name: &str) -> Option<&'a str> {
+    req.headers().get(name)?.to_str().ok()
+}
+
+/// Completion callback of the image processor: its final manifest, ready or
+/// failed, signed with `MEDIA_CALLBACK_SECRET`. A ready manifest settles the
+/// media and records its variants; a failed one goes to the retry queue.
+#[utoipa::path(
+    post,
+    path = "/api/internal/media/manifest",
+    tag = "media",
+    params(
+        ("X-Callback-Timestamp" = String, Header, description = "Unix seconds the callback was sent at"),
+        ("X-Callback-Signature" = String, Header, description = "`sha256=` + hex HMAC-SHA256 of `\"{timestamp}.{body}\"`"),
+    ),
+    request_body(content = String, description = "The manifest written to `{media_id}/manifest.json`", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Manifest applied", body = inline(SuccessResponse<IngestMediaManifestResult>)),
+        (status = 400, description = "Not a manifest", body = ErrorResponse),
+        (status = 401, description = "Missing, wrong or stale signature", body = ErrorResponse),
+        (status = 404, description = "Unknown media, or `MEDIA_CALLBACK_SECRET` is not configured", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    )
+)]
+#[post("/api/internal/media/manifest")]
+pub async fn ingest_media_manifest_handler(
+    req: HttpRequest,
+    body: web::Bytes,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let Some(secret) = data.media_callback_secret.as_deref() else {
+        return NOT_FOUND.response();
+    };
+    match callback_signature::verify(
+        secret,
+        header(&req, TIMESTAMP_HEADER),
+        header(&req, SIGNATURE_HEADER),
+        &body,
+        chrono::Utc::now().timestamp(),
+    ) {
+        Ok(()) => {}
+        Err(CallbackSignatureError::Stale) => {
+            return UNAUTHORIZED
+                .with_message("Callback timestamp is too far from the server's clock")
+        }
+        Err(CallbackSignatureError::Malformed | CallbackSignatureError::Mismatch) => {
+            return UNAUTHORIZED.with_message("Missing or invalid callback signature")
+        }
+    }
+
+    let manifest: Manifest = match serde_json::from_slice(&body) {
+        Ok(manifest) => manifest,
+        Err(e) => return INVALID_MANIFEST.with_message(&format!("Unrecognized manifest: {e}")),
+    };
+
+    match data.multimedia.ingest_manifest.execute(manifest).await {
+        Ok(result) => ApiResponse::success(result),
+        Err(IngestMediaManifestError::InvalidManifest(msg)) => INVALID_MANIFEST.with_message(&msg),
+        Err(IngestMediaManifestError::MediaNotFound) => MEDIA_NOT_FOUND.response(),
+        Err(IngestMediaManifestError::RepositoryError(msg)) => {
+            error!("Failed to apply media manifest: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::json;
+
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, stubs::StubIngestMediaManifestUseCase,
+    };
+
+    const SECRET: &str = "0123456789abcdef";
+    const MEDIA_ID: &str = "7b0f6f1e-3c1a-4d5e-9a3b-2f6c8d9e0a1b";
+
+    fn manifest() -> String {
+        json!({
+            "state": "failed",
+            "media_id": MEDIA_ID,
+            "pipeline_version": "v1",
+            "updated_at": "2026-10-16T10:00:00Z",
+            "error": {
+                "code": "DOWNLOAD_ERROR",
+                "message": "Could not download",
+                "stage": "download",
+            },
+        })
+        .to_string()
+    }
+
+    fn signed(body: &str, timestamp: i64, secret: &str) -> test::TestRequest {
+        let timestamp = timestamp.to_string();
+        test::TestRequest::post()
+            .uri("/api/internal/media/manifest")
+            .insert_header((TIMESTAMP_HEADER, timestamp.as_str()))
+            .insert_header((
+                SIGNATURE_HEADER,
+                callback_signature::sign(secret, &timestamp, body.as_bytes()),
+            ))
+            .insert_header(("content-type", "application/json"))
+            .set_payload(body.to_string())
+    }
+
+    fn now() -> i64 {
+        chrono::Utc::now().timestamp()
+    }
+
+    async fn call(
+        state: web::Data<AppState>,
+        req: test::TestRequest,
+    ) -> actix_web::dev::ServiceResponse {
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .service(ingest_media_manifest_handler),
+        )
+        .await;
+        test::call_service(&app, req.to_request()).await
+    }
+
+    #[actix_web::test]
+    async fn test_applies_a_signed_manifest() {
+        let state = TestAppStateBuilder::default()
+            .with_media_callback_secret(SECRET)
+            .build();
+
+        let resp = call(state, signed(&manifest(), now(), SECRET)).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["mediaId"], MEDIA_ID);
+        assert_eq!(json["data"]["outcome"], "queued_for_retry");
+    }
+
+    #[actix_web::test]
+    async fn test_bad_signature_is_unauthorized() {
+        let state = TestAppStateBuilder::default()
+            .with_media_callback_secret(SECRET)
+            .build();
+
+        let resp = call(state, signed(&manifest(), now(), "fedcba9876543210")).await;
+
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn test_stale_timestamp_is_unauthorized() {
+        let state = TestAppStateBuilder::default()
+            .with_media_callback_secret(SECRET)
+            .build();
+
+        // Correctly signed, but captured an hour ago
+        let resp = call(state, signed(&manifest(), now() - 3600, SECRET)).await;
+
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert!(json["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("timestamp"));
+    }
+
+    #[actix_web::test]
+    async fn test_unsigned_callback_is_unauthorized() {
+        let state = TestAppStateBuilder::default()
+            .with_media_callback_secret(SECRET)
+            .build();
+
+        let req = test::TestRequest::post()
+            .uri("/api/internal/media/manifest")
+            .set_payload(manifest());
+        let resp = call(state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn test_disabled_without_configured_secret() {
+        let state = TestAppStateBuilder::default().build();
+
+        let resp = call(state, signed(&manifest(), now(), SECRET)).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_signed_garbage_is_rejected() {
+        let state = TestAppStateBuilder::default()
+            .with_media_callback_secret(SECRET)
+            .build();
+
+        let resp = call(state, signed(r#"{"state":"done"}"#, now(), SECRET)).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "INVALID_MANIFEST");
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_media_is_not_found() {
+        let state = TestAppStateBuilder::default()
+            .with_media_callback_secret(SECRET)
+            .with_ingest_media_manifest(StubIngestMediaManifestUseCase::failure(
+                IngestMediaManifestError::MediaNotFound,
+            ))
+            .build();
+
+        let resp = call(state, signed(&manifest(), now(), SECRET)).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "MEDIA_NOT_FOUND");
+    }
+}
@@ -1,8 +1,20 @@
 // src/modules/multimedia/adapter/incoming/web/routes/init_upload.rs
 
-use actix_web::{post, web, Responder};
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::multimedia::adapter::incoming::web::error_codes::{
+    DUPLICATE_MEDIA, FILE_TOO_LARGE, INVALID_CHECKSUM, INVALID_DIMENSIONS, INVALID_EXTENSION,
+    INVALID_FILE_NAME, INVALID_MIME_TYPE, MIME_EXTENSION_MISMATCH, STORAGE_ERROR,
+    UPLOAD_RATE_LIMITED,
+};
+use crate::shared::api::error_codes::MISSING_FIELD;
+use crate::shared::api::ErrorCode;
+use actix_web::{post, web, HttpResponse, Responder};
+use chrono::{SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Duration;
 use tracing::error;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::auth::adapter::incoming::web::extractors::auth::VerifiedUser;
@@ -11,6 +23,7 @@ use crate::multimedia::application::ports::incoming::use_cases::{
     CreateAttachmentCommand, CreateMediaCommand, CreateUrlError, UploadUrlCommandError,
 };
 use crate::shared::api::ApiResponse;
+use crate::shared::rate_limit::{rate_limited, retry_after_secs};
 use crate::AppState;
 
 //
@@ -19,7 +32,7 @@ use crate::AppState;
 // ──────────────────────────────────────────────────────────
 //
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InitUploadRequest {
     // File metadata
@@ -46,6 +59,16 @@ pub struct InitUploadRequest {
 
     #[serde(default)]
     pub caption: Option<String>,
+
+    /// Hex SHA-256 of the file. When the owner already stored an identical
+    /// file, the answer is 409 `DUPLICATE_MEDIA` naming that media instead
+    /// of a new upload URL.
+    #[serde(default)]
+    pub checksum_sha256: Option<String>,
+
+    /// Register the upload even when an identical file is already stored
+    #[serde(default)]
+    pub allow_duplicate: bool,
 }
 
 //
@@ -54,10 +77,14 @@ pub struct InitUploadRequest {
 // ──────────────────────────────────────────────────────────
 //
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InitUploadResponse {
     pub upload_url: String,
+    /// Headers the PUT must send as given, e.g. `content-type`. They are part
+    /// of the signature: storage rejects an upload without them, or one
+    /// larger than `fileSizeBytes` or of another type.
+    pub upload_headers: BTreeMap<String, String>,
     pub media_id: Uuid,
 }
 
@@ -67,6 +94,26 @@ pub struct InitUploadResponse {
 // ──────────────────────────────────────────────────────────
 //
 
+/// Register an upload and get a signed URL to PUT the file to
+#[utoipa::path(
+    post,
+    path = "/api/media/upload-url",
+    tag = "media",
+    request_body = InitUploadRequest,
+    responses(
+        (status = 201, description = "Upload registered", body = inline(SuccessResponse<InitUploadResponse>)),
+        (status = 400, description = "File metadata rejected by the upload policy", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 409, description = "An identical file is already stored (`DUPLICATE_MEDIA`); the message names its media id", body = ErrorResponse),
+        (status = 429, description = "Too many upload URLs this hour; `Retry-After` says when the limit resets", body = ErrorResponse),
+        (status = 502, description = "Storage could not sign the URL", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[post("/api/media/upload-url")]
 pub async fn init_upload_handler(
     user: VerifiedUser,
@@ -84,6 +131,8 @@ pub async fn init_upload_handler(
         .file_size_bytes(req.file_size_bytes)
         .width_px(req.width_px)
         .height_px(req.height_px)
+        .checksum_sha256(req.checksum_sha256)
+        .allow_duplicate(req.allow_duplicate)
         .build(policy)
     {
         Ok(cmd) => cmd,
@@ -114,40 +163,62 @@ pub async fn init_upload_handler(
     {
         Ok(result) => ApiResponse::created(InitUploadResponse {
             upload_url: result.url,
+            upload_headers: result.upload_headers,
             media_id: result.media_id,
         }),
 
         Err(CreateUrlError::StorageError(e)) => {
             error!("Storage error creating upload URL: {}", e);
             error!("Full error details: {:?}", e);
-            ApiResponse::error(
-                actix_web::http::StatusCode::BAD_GATEWAY,
-                "STORAGE_ERROR",
-                "Failed to generate upload URL",
-            )
+            STORAGE_ERROR.with_message("Failed to generate upload URL")
         }
 
         Err(CreateUrlError::RepositoryError(e)) => {
             error!("Repository error creating upload URL: {}", e);
             ApiResponse::internal_error()
         }
+
+        Err(CreateUrlError::Duplicate { media_id }) => DUPLICATE_MEDIA.with_message(&format!(
+            "An identical file is already stored as media {media_id}; reuse it, or send allowDuplicate to upload anyway"
+        )),
+
+        Err(CreateUrlError::RateLimited { retry_after }) => upload_rate_limited(retry_after),
     }
 }
 
-fn map_command_error(e: UploadUrlCommandError) -> actix_web::HttpResponse {
+/// 429 `UPLOAD_RATE_LIMITED`, naming when the owner's limit resets
+pub(super) fn upload_rate_limited(retry_after: Duration) -> HttpResponse {
+    let reset_at = Utc::now() + chrono::Duration::seconds(retry_after_secs(retry_after) as i64);
+    rate_limited(
+        UPLOAD_RATE_LIMITED,
+        &format!(
+            "Upload URL limit reached; try again after {}",
+            reset_at.to_rfc3339_opts(SecondsFormat::Secs, true)
+        ),
+        retry_after,
+    )
+}
+
+fn map_command_error(e: UploadUrlCommandError) -> HttpResponse {
+    let (code, message) = command_error(e);
+    code.with_message(&message)
+}
+
+/// The error code of a rejected command and its message
+pub(super) fn command_error(e: UploadUrlCommandError) -> (ErrorCode, String) {
     match e {
         UploadUrlCommandError::MissingField(field) => {
-            ApiResponse::bad_request("MISSING_FIELD", &format!("Missing field: {}", field))
+            (MISSING_FIELD, format!("Missing field: {}", field))
         }
         UploadUrlCommandError::InvalidFileName => {
-            ApiResponse::bad_request("INVALID_FILE_NAME", "Invalid file name")
+            (INVALID_FILE_NAME, INVALID_FILE_NAME.message.to_string())
         }
         UploadUrlCommandError::FileTooLarge {
             max_bytes,
             actual_bytes,
-        } => ApiResponse::bad_request(
-            "FILE_TOO_LARGE",
-            &format!(
+        } => (
+            FILE_TOO_LARGE,
+            format!(
                 "File too large (max {} bytes, got {} bytes)",
                 max_bytes, actual_bytes
             ),
@@ -156,25 +227,26 @@ fn map_command_error(e: UploadUrlCommandError) -> actix_web::HttpResponse {
             max_px,
             width_px,
             height_px,
-        } => ApiResponse::bad_request(
-            "INVALID_DIMENSIONS",
-            &format!(
+        } => (
+            INVALID_DIMENSIONS,
+            format!(
                 "Invalid dimensions (max {}px, got {}x{})",
                 max_px, width_px, height_px
             ),
         ),
         UploadUrlCommandError::InvalidMimeType(mime) => {
-            ApiResponse::bad_request("INVALID_MIME_TYPE", &format!("Invalid mime type: {}", mime))
+            (INVALID_MIME_TYPE, format!("Invalid mime type: {}", mime))
         }
-        UploadUrlCommandError::InvalidExtension(ext) => ApiResponse::bad_request(
-            "INVALID_EXTENSION",
-            &format!("Invalid file extension: {}", ext),
+        UploadUrlCommandError::InvalidExtension(ext) => (
+            INVALID_EXTENSION,
+            format!("Invalid file extension: {}", ext),
         ),
-        UploadUrlCommandError::MimeExtensionMismatch { mime_type, ext } => {
-            ApiResponse::bad_request(
-                "MIME_EXTENSION_MISMATCH",
-                &format!("Mime type {} does not match extension {}", mime_type, ext),
-            )
+        UploadUrlCommandError::MimeExtensionMismatch { mime_type, ext } => (
+            MIME_EXTENSION_MISMATCH,
+            format!("Mime type {} does not match extension {}", mime_type, ext),
+        ),
+        UploadUrlCommandError::InvalidChecksum => {
+            (INVALID_CHECKSUM, INVALID_CHECKSUM.message.to_string())
         }
     }
 }
@@ -209,6 +281,7 @@ mod tests {
             Self {
                 result: Ok(CreateMediaResult {
                     url,
+                    upload_headers: [("content-type".to_string(), "image/png".to_string())].into(),
                     media_id: Uuid::new_v4(),
                 }),
             }
@@ -225,6 +298,18 @@ mod tests {
                 result: Err(CreateUrlError::RepositoryError(msg.to_string())),
             }
         }
+
+        fn duplicate(media_id: Uuid) -> Self {
+            Self {
+                result: Err(CreateUrlError::Duplicate { media_id }),
+            }
+        }
+
+        fn rate_limited(retry_after: std::time::Duration) -> Self {
+            Self {
+                result: Err(CreateUrlError::RateLimited { retry_after }),
+            }
+        }
     }
 
     #[async_trait]
@@ -245,16 +330,18 @@ mod tests {
     fn jwt_service() -> JwtTokenService {
         JwtTokenService::new(JwtConfig {
             issuer: "Lotion".to_string(),
+            audience: "test_audience".to_string(),
             secret_key: "test_secret_key_for_testing_purposes_only".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         })
     }
 
     fn token(user_id: Uuid, verified: bool) -> String {
         jwt_service()
-            .generate_access_token(user_id, verified)
+            .generate_access_token(user_id, verified, 0)
             .unwrap()
     }
 
@@ -271,6 +358,8 @@ mod tests {
             position: 0,
             alt_text: None,
             caption: None,
+            checksum_sha256: None,
+            allow_duplicate: false,
         }
     }
 
@@ -312,6 +401,50 @@ mod tests {
 
         let data = body["data"].clone();
         assert_eq!(data["uploadUrl"], upload_url);
+        assert_eq!(data["uploadHeaders"]["content-type"], "image/png");
+    }
+
+    #[actix_web::test]
+    async fn test_init_upload_pdf_document_success() {
+        let user_id = Uuid::new_v4();
+        let upload_url = "https://storage.googleapis.com/signed-url".to_string();
+
+        let app_state = TestAppStateBuilder::default()
+            .with_create_upload_media_url(MockCreateUploadUrlUseCase::success(upload_url.clone()))
+            .build();
+
+        let jwt = jwt_service();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .app_data(web::Data::new(token_provider))
+                .service(init_upload_handler),
+        )
+        .await;
+
+        // Larger than the image cap, within the document cap; no dimensions.
+        let mut request = base_upload_request();
+        request.file_name = "talk-slides.pdf".to_string();
+        request.mime_type = "application/pdf".to_string();
+        request.file_size_bytes = 12 * 1024 * 1024;
+        request.width_px = None;
+        request.height_px = None;
+        request.attachment_target = AttachmentTarget::Project;
+        request.role = MediaRole::Inline;
+
+        let req = test::TestRequest::post()
+            .uri("/api/media/upload-url")
+            .insert_header(("Authorization", format!("Bearer {}", token(user_id, true))))
+            .set_json(&request)
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["uploadUrl"], upload_url);
     }
 
     /* --------------------------------------------------
@@ -442,7 +575,7 @@ mod tests {
         .await;
 
         let mut request = base_upload_request();
-        request.mime_type = "application/pdf".to_string();
+        request.mime_type = "application/zip".to_string();
 
         let req = test::TestRequest::post()
             .uri("/api/media/upload-url")
@@ -476,7 +609,7 @@ mod tests {
         .await;
 
         let mut request = base_upload_request();
-        request.file_name = "avatar.pdf".to_string();
+        request.file_name = "avatar.gif".to_string();
 
         let req = test::TestRequest::post()
             .uri("/api/media/upload-url")
@@ -526,4 +659,85 @@ mod tests {
         let resp = test::call_service(&app, req).await;
         assert_eq!(resp.status(), StatusCode::FORBIDDEN);
     }
+
+    #[actix_web::test]
+    async fn test_init_upload_rate_limited() {
+        let user_id = Uuid::new_v4();
+
+        let app_state = TestAppStateBuilder::default()
+            .with_create_upload_media_url(MockCreateUploadUrlUseCase::rate_limited(
+                std::time::Duration::from_millis(90_500),
+            ))
+            .build();
+
+        let jwt = jwt_service();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .app_data(web::Data::new(token_provider))
+                .service(init_upload_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/media/upload-url")
+            .insert_header(("Authorization", format!("Bearer {}", token(user_id, true))))
+            .set_json(&base_upload_request())
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(resp.headers().get("retry-after").unwrap(), "91");
+
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "UPLOAD_RATE_LIMITED");
+        assert!(body["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("try again after"));
+    }
+
+    #[actix_web::test]
+    async fn test_init_upload_duplicate_names_existing_media() {
+        let existing = Uuid::new_v4();
+
+        let app_state = TestAppStateBuilder::default()
+            .with_create_upload_media_url(MockCreateUploadUrlUseCase::duplicate(existing))
+            .build();
+
+        let jwt = jwt_service();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .app_data(web::Data::new(token_provider))
+                .service(init_upload_handler),
+        )
+        .await;
+
+        let mut request = base_upload_request();
+        request.checksum_sha256 = Some("ab".repeat(32));
+
+        let req = test::TestRequest::post()
+            .uri("/api/media/upload-url")
+            .insert_header((
+                "Authorization",
+                format!("Bearer {}", token(Uuid::new_v4(), true)),
+            ))
+            .set_json(&request)
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "DUPLICATE_MEDIA");
+        assert!(body["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains(&existing.to_string()));
+    }
 }
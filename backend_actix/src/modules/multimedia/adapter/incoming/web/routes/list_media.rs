@@ -1,16 +1,22 @@
-use actix_web::{get, web, HttpResponse, Responder};
-use serde::{Deserialize, Serialize};
+use actix_web::{get, web, HttpRequest, HttpResponse, Responder};
+use serde::Deserialize;
+use tracing::error;
 
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::multimedia::adapter::incoming::web::error_codes::TARGET_NOT_FOUND;
+use crate::multimedia::application::ports::incoming::use_cases::MediaItem;
 use crate::{
     auth::{
         adapter::incoming::web::extractors::auth::VerifiedUser,
         application::domain::entities::UserId,
     },
     multimedia::application::{
-        domain::entities::AttachmentTarget,
-        ports::incoming::use_cases::{ListMediaCommand, MediaItem},
+        domain::entities::AttachmentTarget, ports::incoming::use_cases::ListMediaCommand,
+    },
+    shared::api::{
+        pagination::{PageParams, PagedResponse},
+        ApiResponse,
     },
-    shared::api::ApiResponse,
     AppState,
 };
 
@@ -19,29 +25,43 @@ pub struct ListMediaPath {
     attachment_target: String,
 }
 
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "lowercase")]
-pub struct ListMediaResponse {
-    rows: Vec<MediaItem>,
-}
-
 fn parse_attachment_target(s: &str) -> Result<AttachmentTarget, HttpResponse> {
     match s {
         "user" => Ok(AttachmentTarget::User),
         "resume" => Ok(AttachmentTarget::Resume),
         "project" => Ok(AttachmentTarget::Project),
         "blog_post" => Ok(AttachmentTarget::BlogPost),
-        _ => Err(ApiResponse::bad_request(
-            "TARGET_NOT_FOUND",
-            "Target Attachment Is Not Exist",
-        )),
+        _ => Err(TARGET_NOT_FOUND.response()),
     }
 }
 
+/// List the current user's media attached to one kind of target
+#[utoipa::path(
+    get,
+    path = "/api/media/{attachment_target}",
+    tag = "media",
+    params(
+        ("attachment_target" = String, Path, description = "`user`, `resume`, `project` or `blog_post`"),
+        ("limit" = Option<u32>, Query, description = "Page size, 1-100 (default 20)"),
+        ("cursor" = Option<String>, Query, description = "`next_cursor` from the previous page"),
+    ),
+    responses(
+        (status = 200, description = "One page of media (paging also in `X-Total-Count` and `Link`)", body = inline(SuccessResponse<PagedResponse<MediaItem>>)),
+        (status = 400, description = "Unknown attachment target or invalid pagination", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[get("/api/media/{attachment_target}")]
 pub async fn list_media_handler(
+    req: HttpRequest,
     user: VerifiedUser,
     path: web::Path<ListMediaPath>,
+    page: PageParams,
     data: web::Data<AppState>,
 ) -> impl Responder {
     let attachment_target = match parse_attachment_target(&path.attachment_target) {
@@ -54,10 +74,10 @@ pub async fn list_media_handler(
         attachment_target,
     };
     match data.multimedia.list_media.execute(command).await {
-        Ok(items) => ApiResponse::success(ListMediaResponse { rows: items }),
+        Ok(items) => PagedResponse::from_all(items, &page).respond(&req, &page),
         Err(err) => {
-            println!("Error from the server: {}", err);
-            return ApiResponse::internal_error();
+            error!(error = %err, "Failed to list media");
+            ApiResponse::internal_error()
         }
     }
 }
@@ -75,7 +95,7 @@ mod tests {
     use crate::auth::adapter::outgoing::jwt::{JwtConfig, JwtTokenService};
     use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
     use crate::multimedia::application::ports::incoming::use_cases::{
-        ListMediaError, ListMediaUseCase,
+        ListMediaError, ListMediaUseCase, MediaItem,
     };
     use crate::tests::support::app_state_builder::TestAppStateBuilder;
 
@@ -115,16 +135,18 @@ mod tests {
     fn jwt_service() -> JwtTokenService {
         JwtTokenService::new(JwtConfig {
             issuer: "Lotion".to_string(),
+            audience: "test_audience".to_string(),
             secret_key: "test_secret_key_for_testing_purposes_only".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         })
     }
 
     fn token(user_id: Uuid, verified: bool) -> String {
         jwt_service()
-            .generate_access_token(user_id, verified)
+            .generate_access_token(user_id, verified, 0)
             .unwrap()
     }
 
@@ -184,8 +206,9 @@ mod tests {
 
         let body: Value = test::read_body_json(resp).await;
         assert_eq!(body["success"], true);
-        assert!(body["data"]["rows"].is_array());
-        assert_eq!(body["data"]["rows"].as_array().unwrap().len(), 1);
+        assert!(body["data"]["items"].is_array());
+        assert_eq!(body["data"]["items"].as_array().unwrap().len(), 1);
+        assert_eq!(body["data"]["total"], 1);
     }
 
     // -----------------------
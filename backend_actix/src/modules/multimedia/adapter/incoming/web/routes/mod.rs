@@ -1,6 +1,31 @@
+mod finalize_upload;
 mod get_variant_url;
+mod get_variant_urls;
+mod ingest_media_manifest;
 mod init_upload;
 mod list_media;
-pub use get_variant_url::get_variant_read_url_handler;
-pub use init_upload::init_upload_handler;
-pub use list_media::list_media_handler;
+mod proxy_image;
+mod regenerate_media;
+mod storage_override;
+mod upload_batch;
+mod upload_policy;
+pub use finalize_upload::{__path_finalize_upload_handler, finalize_upload_handler};
+pub use get_variant_url::{__path_get_variant_read_url_handler, get_variant_read_url_handler};
+pub use get_variant_urls::{__path_get_variant_read_urls_handler, get_variant_read_urls_handler};
+pub use ingest_media_manifest::{
+    __path_ingest_media_manifest_handler, ingest_media_manifest_handler,
+};
+pub use init_upload::{__path_init_upload_handler, init_upload_handler};
+pub use list_media::{__path_list_media_handler, list_media_handler};
+pub use proxy_image::{__path_proxy_image_handler, proxy_image_handler};
+pub use regenerate_media::{__path_regenerate_media_handler, regenerate_media_handler};
+pub use storage_override::{
+    __path_delete_storage_override_handler, __path_get_storage_override_handler,
+    __path_set_storage_override_handler, delete_storage_override_handler,
+    get_storage_override_handler, set_storage_override_handler,
+};
+pub use upload_batch::{
+    __path_create_upload_batch_handler, __path_get_upload_batch_handler,
+    create_upload_batch_handler, get_upload_batch_handler,
+};
+pub use upload_policy::{__path_upload_policy_handler, upload_policy_handler};
@@ -0,0 +1,181 @@
+use actix_web::{get, http::header, web, HttpResponse, Responder};
+use serde::Deserialize;
+use tracing::{error, warn};
+
+use crate::api::schemas::ErrorResponse;
+use crate::multimedia::adapter::incoming::web::error_codes::{
+    IMAGE_FETCH_FAILED, IMAGE_NOT_FOUND, IMAGE_ORIGIN_NOT_ALLOWED, IMAGE_TOO_LARGE,
+    INVALID_IMAGE_URL, STORAGE_ERROR, UNSUPPORTED_IMAGE_TYPE,
+};
+use crate::multimedia::application::ports::incoming::use_cases::ProxyImageError;
+use crate::shared::api::cache::CachePolicy;
+use crate::AppState;
+
+/// Signed read URLs last 15 minutes; the redirect is reused for less than that
+const REDIRECT_MAX_AGE_SECS: u32 = 600;
+
+//
+// ──────────────────────────────────────────────────────────
+// Query DTO
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Deserialize)]
+pub struct ProxyImageQuery {
+    pub url: String,
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Handler
+// ──────────────────────────────────────────────────────────
+//
+
+/// Load an external image through the output bucket
+///
+/// The first request fetches the image and checks it like an upload; later
+/// ones are served from the stored copy. Answers with a redirect to a
+/// short-lived signed URL, so it can be used directly as an `<img>` source.
+#[utoipa::path(
+    get,
+    path = "/api/proxy/image",
+    tag = "media",
+    params(
+        ("url" = String, Query, description = "Absolute URL of the image, on an origin in `IMAGE_PROXY_ORIGINS`"),
+    ),
+    responses(
+        (status = 302, description = "Redirect to a signed URL of the stored copy"),
+        (status = 400, description = "Not an absolute http(s) URL", body = ErrorResponse),
+        (status = 403, description = "Origin not in the allowlist", body = ErrorResponse),
+        (status = 404, description = "The origin has no such image", body = ErrorResponse),
+        (status = 422, description = "Image too large or of an unsupported type", body = ErrorResponse),
+        (status = 502, description = "The origin or storage could not be reached", body = ErrorResponse),
+    )
+)]
+#[get("/api/proxy/image")]
+pub async fn proxy_image_handler(
+    query: web::Query<ProxyImageQuery>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data.multimedia.proxy_image.execute(&query.url).await {
+        Ok(signed_url) => {
+            let mut resp = HttpResponse::Found()
+                .insert_header((header::LOCATION, signed_url))
+                .finish();
+            CachePolicy::Public {
+                max_age: REDIRECT_MAX_AGE_SECS,
+            }
+            .apply(&mut resp);
+            resp
+        }
+        Err(e) => map_proxy_image_error(e),
+    }
+}
+
+fn map_proxy_image_error(e: ProxyImageError) -> HttpResponse {
+    match e {
+        ProxyImageError::InvalidUrl => INVALID_IMAGE_URL.response(),
+        ProxyImageError::OriginNotAllowed => IMAGE_ORIGIN_NOT_ALLOWED.response(),
+        ProxyImageError::ImageNotFound => IMAGE_NOT_FOUND.response(),
+        ProxyImageError::TooLarge(max) => {
+            IMAGE_TOO_LARGE.with_message(&format!("Image larger than {max} bytes"))
+        }
+        ProxyImageError::UnsupportedType(content_type) => {
+            UNSUPPORTED_IMAGE_TYPE.with_message(&format!("Unsupported image type '{content_type}'"))
+        }
+        ProxyImageError::FetchFailed(msg) => {
+            warn!("Fetching proxied image failed: {}", msg);
+            IMAGE_FETCH_FAILED.response()
+        }
+        ProxyImageError::StorageError(msg) => {
+            error!("Storage error proxying image: {}", msg);
+            STORAGE_ERROR.response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::Value;
+
+    use crate::tests::support::app_state_builder::TestAppStateBuilder;
+    use crate::tests::support::stubs::StubProxyImageUseCase;
+
+    async fn get(uc: StubProxyImageUseCase) -> actix_web::dev::ServiceResponse {
+        let app_state = TestAppStateBuilder::default().with_proxy_image(uc).build();
+        let app =
+            test::init_service(App::new().app_data(app_state).service(proxy_image_handler)).await;
+
+        let req = test::TestRequest::get()
+            .uri("/api/proxy/image?url=https%3A%2F%2Fi.imgur.com%2Fcat.png")
+            .to_request();
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_redirects_to_the_signed_url() {
+        let resp = get(StubProxyImageUseCase::success(
+            "https://storage.example/ready/proxy/abc?sig=1",
+        ))
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::FOUND);
+        assert_eq!(
+            resp.headers().get("Location").unwrap(),
+            "https://storage.example/ready/proxy/abc?sig=1"
+        );
+        assert_eq!(
+            resp.headers().get("Cache-Control").unwrap(),
+            "public, max-age=600"
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_maps_errors_to_status_and_code() {
+        for (err, status, code) in [
+            (
+                ProxyImageError::InvalidUrl,
+                StatusCode::BAD_REQUEST,
+                "INVALID_IMAGE_URL",
+            ),
+            (
+                ProxyImageError::OriginNotAllowed,
+                StatusCode::FORBIDDEN,
+                "IMAGE_ORIGIN_NOT_ALLOWED",
+            ),
+            (
+                ProxyImageError::ImageNotFound,
+                StatusCode::NOT_FOUND,
+                "IMAGE_NOT_FOUND",
+            ),
+            (
+                ProxyImageError::TooLarge(5),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "IMAGE_TOO_LARGE",
+            ),
+            (
+                ProxyImageError::UnsupportedType("image/svg+xml".to_string()),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "UNSUPPORTED_IMAGE_TYPE",
+            ),
+            (
+                ProxyImageError::FetchFailed("timeout".to_string()),
+                StatusCode::BAD_GATEWAY,
+                "IMAGE_FETCH_FAILED",
+            ),
+            (
+                ProxyImageError::StorageError("gcs down".to_string()),
+                StatusCode::BAD_GATEWAY,
+                "STORAGE_ERROR",
+            ),
+        ] {
+            let resp = get(StubProxyImageUseCase::failure(err)).await;
+            assert_eq!(resp.status(), status, "{code}");
+
+            let body: Value = test::read_body_json(resp).await;
+            assert_eq!(body["error"]["code"], code);
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use actix_web::{post, web, Responder};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::auth::adapter::incoming::web::extractors::auth::VerifiedUser;
+use crate::multimedia::adapter::incoming::web::error_codes::{
+    INVALID_VARIANT_WIDTHS, MEDIA_NOT_FOUND, MEDIA_PENDING, MEDIA_PROCESSING, PROCESSOR_ERROR,
+    PROCESSOR_NOT_CONFIGURED,
+};
+use crate::multimedia::application::domain::entities::MediaState;
+use crate::multimedia::application::ports::incoming::use_cases::{
+    RegenerateMediaCommand, RegenerateMediaError,
+};
+use crate::shared::api::ApiResponse;
+use crate::AppState;
+
+#[derive(Debug, Deserialize)]
+pub struct RegenerateMediaQuery {
+    /// Comma-separated pixel widths, e.g. `320,640`
+    pub widths: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RegenerateMediaResponse {
+    pub media_id: Uuid,
+    /// `processing` until the image processor reports back
+    pub status: MediaState,
+    /// Widths being rebuilt; `null` when every variant is
+    pub widths: Option<Vec<u32>>,
+}
+
+/// `320, 640` -> `[320, 640]`; `None` when a part is not a number
+fn parse_widths(widths: &str) -> Option<Vec<u32>> {
+    widths
+        .split(',')
+        .map(|width| width.trim().parse().ok())
+        .collect()
+}
+
+/// Rebuild a media's variants
+///
+/// Sends a `ready` or `failed` media back to the image processor, e.g.
+/// after its quality settings changed or a bad crop was fixed. The media is
+/// `processing` until the processor reports back; its current variants are
+/// served meanwhile. `widths` limits the work to the variants of those
+/// widths.
+#[utoipa::path(
+    post,
+    path = "/api/media/{media_id}/regenerate",
+    tag = "media",
+    params(
+        ("media_id" = Uuid, Path, description = "Media id"),
+        ("widths" = Option<String>, Query, description = "Comma-separated pixel widths to rebuild, e.g. `320,640`; omitted rebuilds every variant"),
+    ),
+    responses(
+        (status = 200, description = "Regeneration queued", body = inline(SuccessResponse<RegenerateMediaResponse>)),
+        (status = 400, description = "Invalid widths", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Media not found", body = ErrorResponse),
+        (status = 409, description = "Media not uploaded yet (`MEDIA_PENDING`) or already processing (`MEDIA_PROCESSING`)", body = ErrorResponse),
+        (status = 502, description = "The image processor could not be reached", body = ErrorResponse),
+        (status = 503, description = "No image processor is configured", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/media/{media_id}/regenerate")]
+pub async fn regenerate_media_handler(
+    user: VerifiedUser,
+    path: web::Path<Uuid>,
+    query: web::Query<RegenerateMediaQuery>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let widths = match query.widths.as_deref().map(parse_widths) {
+        None => None,
+        Some(Some(widths)) => Some(widths),
+        Some(None) => {
+            return INVALID_VARIANT_WIDTHS.with_message("widths must be comma-separated numbers")
+        }
+    };
+    let command = RegenerateMediaCommand {
+        owner: user.user_id.into(),
+        media_id: path.into_inner(),
+        widths,
+    };
+
+    match data.multimedia.regenerate_media.execute(command).await {
+        Ok(result) => ApiResponse::success(RegenerateMediaResponse {
+            media_id: result.media_id,
+            status: result.status,
+            widths: result.widths,
+        }),
+
+        Err(RegenerateMediaError::MediaNotFound) => MEDIA_NOT_FOUND.response(),
+
+        Err(RegenerateMediaError::MediaPending) => MEDIA_PENDING.response(),
+
+        Err(RegenerateMediaError::MediaProcessing) => MEDIA_PROCESSING.response(),
+
+        Err(RegenerateMediaError::InvalidWidths(msg)) => INVALID_VARIANT_WIDTHS.with_message(&msg),
+
+        Err(RegenerateMediaError::ProcessorNotConfigured) => PROCESSOR_NOT_CONFIGURED.response(),
+
+        Err(RegenerateMediaError::ProcessorError(e)) => {
+            error!("Image processor error regenerating media: {}", e);
+            PROCESSOR_ERROR.response()
+        }
+
+        Err(RegenerateMediaError::RepositoryError(e)) => {
+            error!("Repository error regenerating media: {}", e);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::Value;
+    use std::sync::Arc;
+
+    use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, auth_helper::test_helpers::create_test_jwt_service,
+        stubs::StubRegenerateMediaUseCase,
+    };
+
+    async fn call(stub: StubRegenerateMediaUseCase, uri: &str) -> (StatusCode, Value) {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_regenerate_media(stub)
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(regenerate_media_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        let status = resp.status();
+        (status, test::read_body_json(resp).await)
+    }
+
+    #[actix_web::test]
+    async fn test_regenerate_passes_widths() {
+        let media_id = Uuid::new_v4();
+
+        let (status, body) = call(
+            StubRegenerateMediaUseCase::success(),
+            &format!("/api/media/{media_id}/regenerate?widths=640,%20320"),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["mediaId"], media_id.to_string());
+        assert_eq!(body["data"]["status"], "processing");
+        assert_eq!(body["data"]["widths"], serde_json::json!([640, 320]));
+    }
+
+    #[actix_web::test]
+    async fn test_regenerate_rejects_malformed_widths() {
+        let (status, body) = call(
+            StubRegenerateMediaUseCase::success(),
+            &format!("/api/media/{}/regenerate?widths=wide", Uuid::new_v4()),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"]["code"], "INVALID_VARIANT_WIDTHS");
+    }
+
+    #[actix_web::test]
+    async fn test_regenerate_without_processor_is_unavailable() {
+        let (status, body) = call(
+            StubRegenerateMediaUseCase::failure(RegenerateMediaError::ProcessorNotConfigured),
+            &format!("/api/media/{}/regenerate", Uuid::new_v4()),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["error"]["code"], "PROCESSOR_NOT_CONFIGURED");
+    }
+}
@@ -0,0 +1,410 @@
+use actix_web::{get, post, web, Responder};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::init_upload::{command_error, upload_rate_limited, InitUploadResponse};
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::auth::adapter::incoming::web::extractors::auth::VerifiedUser;
+use crate::multimedia::adapter::incoming::web::error_codes::{
+    DUPLICATE_MEDIA, INVALID_UPLOAD_BATCH, STORAGE_ERROR, UPLOAD_BATCH_NOT_FOUND,
+};
+use crate::multimedia::application::domain::entities::{AttachmentTarget, MediaRole};
+use crate::multimedia::application::ports::incoming::use_cases::{
+    CreateAttachmentCommand, CreateMediaCommand, CreateUploadBatchCommand, CreateUploadBatchError,
+    CreateUrlError, GetUploadBatchError, UploadBatchStatus, MAX_UPLOAD_BATCH,
+};
+use crate::shared::api::ApiResponse;
+use crate::AppState;
+
+//
+// ──────────────────────────────────────────────────────────
+// Request DTO
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadBatchFile {
+    pub file_name: String,
+    pub mime_type: String,
+    pub file_size_bytes: u64,
+
+    #[serde(default)]
+    pub width_px: Option<u32>,
+
+    #[serde(default)]
+    pub height_px: Option<u32>,
+
+    #[serde(default)]
+    pub alt_text: Option<String>,
+
+    #[serde(default)]
+    pub caption: Option<String>,
+
+    /// Hex SHA-256 of the file, checked against the owner's stored files
+    #[serde(default)]
+    pub checksum_sha256: Option<String>,
+
+    /// Register the upload even when an identical file is already stored
+    #[serde(default)]
+    pub allow_duplicate: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadBatchRequest {
+    // Shared by every file
+    pub attachment_target: AttachmentTarget,
+    pub attachment_target_id: Uuid,
+    pub role: MediaRole,
+
+    /// Position of the first file; the others follow in order
+    #[serde(default)]
+    pub start_position: u8,
+
+    /// 1 to 20 files
+    pub files: Vec<UploadBatchFile>,
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Response DTO
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadBatchResponse {
+    /// For `GET /api/media/upload-batch/{batch_id}`
+    pub batch_id: Uuid,
+    /// One per file, in request order
+    pub uploads: Vec<InitUploadResponse>,
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Handlers
+// ──────────────────────────────────────────────────────────
+//
+
+/// Register several uploads and get a signed URL for each
+///
+/// Does what `POST /api/media/upload-url` does for every file, in one
+/// request: the files share an attachment target and role, and take
+/// consecutive positions from `startPosition`. Either every file is
+/// registered or none is; an error names the file as `files[i]`. Each URL
+/// counts towards the upload URL limit, and a batch that does not fit in
+/// what is left of it is refused whole.
+#[utoipa::path(
+    post,
+    path = "/api/media/upload-batch",
+    tag = "media",
+    request_body = UploadBatchRequest,
+    responses(
+        (status = 201, description = "Uploads registered", body = inline(SuccessResponse<UploadBatchResponse>)),
+        (status = 400, description = "Too few or many files (`INVALID_UPLOAD_BATCH`), or a file rejected by the upload policy", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 409, description = "A file is already stored (`DUPLICATE_MEDIA`); the message names the file and its media id", body = ErrorResponse),
+        (status = 429, description = "Not enough upload URLs left this hour for the batch; `Retry-After` says when the limit resets", body = ErrorResponse),
+        (status = 502, description = "Storage could not sign a URL", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/media/upload-batch")]
+pub async fn create_upload_batch_handler(
+    user: VerifiedUser,
+    req: web::Json<UploadBatchRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let req = req.into_inner();
+    let policy = &data.multimedia_upload_policy;
+
+    if req.files.is_empty() || req.files.len() > MAX_UPLOAD_BATCH {
+        return INVALID_UPLOAD_BATCH.response();
+    }
+    if usize::from(req.start_position) + req.files.len() - 1 > usize::from(u8::MAX) {
+        return INVALID_UPLOAD_BATCH.with_message(&format!(
+            "Positions would run past {} from startPosition {}",
+            u8::MAX,
+            req.start_position
+        ));
+    }
+
+    let mut uploads = Vec::with_capacity(req.files.len());
+    for (index, file) in req.files.into_iter().enumerate() {
+        let media_command = CreateMediaCommand::builder()
+            .owner(user.user_id.into())
+            .file_name(file.file_name)
+            .mime_type(file.mime_type)
+            .file_size_bytes(file.file_size_bytes)
+            .width_px(file.width_px)
+            .height_px(file.height_px)
+            .checksum_sha256(file.checksum_sha256)
+            .allow_duplicate(file.allow_duplicate)
+            .build(policy);
+        let attachment_command = CreateAttachmentCommand::builder()
+            .owner(user.user_id.into())
+            .attachment_target(req.attachment_target.clone())
+            .attachment_target_id(req.attachment_target_id)
+            .role(req.role.clone())
+            .position(req.start_position + index as u8)
+            .alt_text(file.alt_text.unwrap_or_default())
+            .caption(file.caption.unwrap_or_default())
+            .build();
+
+        match media_command.and_then(|media| Ok((media, attachment_command?))) {
+            Ok(upload) => uploads.push(upload),
+            Err(e) => {
+                let (code, message) = command_error(e);
+                return code.with_message(&format!("files[{index}]: {message}"));
+            }
+        }
+    }
+
+    let command = match CreateUploadBatchCommand::new(user.user_id.into(), uploads) {
+        Ok(command) => command,
+        Err(e) => return INVALID_UPLOAD_BATCH.with_message(&e.to_string()),
+    };
+
+    match data.multimedia.create_upload_batch.execute(command).await {
+        Ok(batch) => ApiResponse::created(UploadBatchResponse {
+            batch_id: batch.batch_id,
+            uploads: batch
+                .uploads
+                .into_iter()
+                .map(|upload| InitUploadResponse {
+                    upload_url: upload.url,
+                    upload_headers: upload.upload_headers,
+                    media_id: upload.media_id,
+                })
+                .collect(),
+        }),
+
+        Err(CreateUploadBatchError::InvalidBatch(msg)) => INVALID_UPLOAD_BATCH.with_message(&msg),
+
+        Err(CreateUploadBatchError::Upload { index, error }) => match error {
+            CreateUrlError::Duplicate { media_id } => DUPLICATE_MEDIA.with_message(&format!(
+                "files[{index}]: an identical file is already stored as media {media_id}; reuse it, or send allowDuplicate to upload anyway"
+            )),
+            CreateUrlError::RateLimited { retry_after } => upload_rate_limited(retry_after),
+            CreateUrlError::StorageError(e) => {
+                error!("Storage error creating upload URL of files[{}]: {}", index, e);
+                STORAGE_ERROR.with_message(&format!(
+                    "Failed to generate upload URL for files[{index}]"
+                ))
+            }
+            CreateUrlError::RepositoryError(e) => {
+                error!("Repository error registering files[{}]: {}", index, e);
+                ApiResponse::internal_error()
+            }
+        },
+
+        Err(CreateUploadBatchError::RateLimited { retry_after }) => {
+            upload_rate_limited(retry_after)
+        }
+
+        Err(CreateUploadBatchError::RepositoryError(e)) => {
+            error!("Repository error creating upload batch: {}", e);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+/// Where each media of an upload batch stands
+///
+/// Poll until `settled`, when every media is `ready` or `failed`. Trashed
+/// media are left out.
+#[utoipa::path(
+    get,
+    path = "/api/media/upload-batch/{batch_id}",
+    tag = "media",
+    params(
+        ("batch_id" = Uuid, Path, description = "Batch id from `POST /api/media/upload-batch`"),
+    ),
+    responses(
+        (status = 200, description = "Batch status", body = inline(SuccessResponse<UploadBatchStatus>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Unknown batch, another user's, or all of it trashed", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/media/upload-batch/{batch_id}")]
+pub async fn get_upload_batch_handler(
+    user: VerifiedUser,
+    path: web::Path<Uuid>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data
+        .multimedia
+        .get_upload_batch
+        .execute(user.user_id.into(), path.into_inner())
+        .await
+    {
+        Ok(status) => ApiResponse::success(status),
+
+        Err(GetUploadBatchError::NotFound) => UPLOAD_BATCH_NOT_FOUND.response(),
+
+        Err(GetUploadBatchError::RepositoryError(e)) => {
+            error!("Repository error reading upload batch: {}", e);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+
+    use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+    use crate::multimedia::application::domain::entities::MediaState;
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder,
+        auth_helper::test_helpers::create_test_jwt_service,
+        stubs::{StubCreateUploadBatchUseCase, StubGetUploadBatchUseCase},
+    };
+
+    async fn call(state: web::Data<AppState>, req: test::TestRequest) -> (StatusCode, Value) {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(create_upload_batch_handler)
+                .service(get_upload_batch_handler),
+        )
+        .await;
+
+        let req = req
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        let status = resp.status();
+        (status, test::read_body_json(resp).await)
+    }
+
+    fn file(name: &str) -> Value {
+        json!({ "fileName": name, "mimeType": "image/png", "fileSizeBytes": 1024 })
+    }
+
+    fn batch(start_position: u8, files: Vec<Value>) -> test::TestRequest {
+        test::TestRequest::post()
+            .uri("/api/media/upload-batch")
+            .set_json(json!({
+                "attachmentTarget": "Project",
+                "attachmentTargetId": Uuid::new_v4(),
+                "role": "Screenshoot",
+                "startPosition": start_position,
+                "files": files,
+            }))
+    }
+
+    #[actix_web::test]
+    async fn test_batch_takes_consecutive_positions() {
+        let state = TestAppStateBuilder::default()
+            .with_create_upload_batch(StubCreateUploadBatchUseCase::success())
+            .build();
+
+        let (status, body) = call(
+            state,
+            batch(4, vec![file("a.png"), file("b.png"), file("c.png")]),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::CREATED);
+        let urls: Vec<_> = body["data"]["uploads"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|upload| upload["uploadUrl"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(
+            urls,
+            [
+                "https://storage.test/upload/4",
+                "https://storage.test/upload/5",
+                "https://storage.test/upload/6"
+            ]
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_rejected_file_is_named_by_index() {
+        let state = TestAppStateBuilder::default()
+            .with_create_upload_batch(StubCreateUploadBatchUseCase::success())
+            .build();
+
+        let (status, body) = call(state, batch(0, vec![file("a.png"), file("b.exe")])).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"]["code"], "INVALID_EXTENSION");
+        assert!(body["error"]["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("files[1]: "));
+    }
+
+    #[actix_web::test]
+    async fn test_batch_size_and_positions_are_bounded() {
+        let state = TestAppStateBuilder::default()
+            .with_create_upload_batch(StubCreateUploadBatchUseCase::success())
+            .build();
+
+        let (empty, body) = call(state.clone(), batch(0, vec![])).await;
+        assert_eq!(empty, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"]["code"], "INVALID_UPLOAD_BATCH");
+
+        let (past_the_end, _) = call(state, batch(255, vec![file("a.png"), file("b.png")])).await;
+        assert_eq!(past_the_end, StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_batch_status_reports_settled() {
+        let state = TestAppStateBuilder::default()
+            .with_get_upload_batch(StubGetUploadBatchUseCase::success(vec![
+                MediaState::Ready,
+                MediaState::Failed,
+            ]))
+            .build();
+        let batch_id = Uuid::new_v4();
+
+        let (status, body) = call(
+            state,
+            test::TestRequest::get().uri(&format!("/api/media/upload-batch/{batch_id}")),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["batch_id"], batch_id.to_string());
+        assert_eq!(body["data"]["items"][1]["status"], "failed");
+        assert_eq!(body["data"]["settled"], true);
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_batch_is_not_found() {
+        let state = TestAppStateBuilder::default().build();
+
+        let (status, body) = call(
+            state,
+            test::TestRequest::get().uri(&format!("/api/media/upload-batch/{}", Uuid::new_v4())),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["error"]["code"], "UPLOAD_BATCH_NOT_FOUND");
+    }
+}
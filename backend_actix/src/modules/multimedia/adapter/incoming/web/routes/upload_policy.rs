@@ -0,0 +1,153 @@
+use actix_web::{get, web, Responder};
+use serde::Serialize;
+use shared_domain::variants::VariantSize;
+use utoipa::ToSchema;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::auth::adapter::incoming::web::extractors::auth::VerifiedUser;
+use crate::shared::api::ApiResponse;
+use crate::AppState;
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadPolicyResponse {
+    /// Largest image, in bytes
+    pub max_file_size_bytes: u64,
+    /// Largest document (`application/pdf`), in bytes
+    pub max_document_size_bytes: u64,
+    /// Longest side of an image, in pixels
+    pub max_width_height_px: u32,
+    /// Most pixels an image may have, whatever its sides
+    pub max_total_pixels: u64,
+    pub max_file_name_length: usize,
+    pub allowed_mime_types: Vec<String>,
+    /// Widths of the variants made of every image, smallest first; the
+    /// smallest is a square thumbnail
+    pub generated_widths: Vec<u32>,
+    /// Upload URLs each user may request per hour; `null` when unlimited
+    pub upload_urls_per_hour: Option<u32>,
+    /// Upload URLs left to this user this hour; `null` when unlimited
+    pub upload_urls_remaining: Option<u32>,
+}
+
+/// The limits uploads are checked against, to validate files before sending them
+///
+/// The same limits the server applies to `POST /api/media/upload-url` and the
+/// image processor to the uploaded bytes, so a file passing them is only
+/// rejected for what can't be known up front, e.g. an undecodable image.
+#[utoipa::path(
+    get,
+    path = "/api/media/upload-policy",
+    tag = "media",
+    responses(
+        (status = 200, description = "Current upload limits", body = inline(SuccessResponse<UploadPolicyResponse>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/media/upload-policy")]
+pub async fn upload_policy_handler(
+    user: VerifiedUser,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let policy = &data.multimedia_upload_policy;
+    let limiter = &data.upload_url_rate_limiter;
+
+    ApiResponse::success(UploadPolicyResponse {
+        max_file_size_bytes: policy.max_file_size_bytes,
+        max_document_size_bytes: policy.max_document_size_bytes,
+        max_width_height_px: policy.max_width_height_px,
+        max_total_pixels: policy.max_total_pixels,
+        max_file_name_length: policy.max_file_name_len,
+        allowed_mime_types: policy
+            .allowed_mime_types
+            .iter()
+            .map(|mime| mime.to_string())
+            .collect(),
+        generated_widths: VariantSize::ALL.iter().map(|size| size.width()).collect(),
+        upload_urls_per_hour: Some(limiter.limit()).filter(|limit| *limit > 0),
+        // Keyed like the upload use case counts
+        upload_urls_remaining: limiter.remaining(&user.user_id.to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::Value;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+    use crate::multimedia::adapter::incoming::web::configure;
+    use crate::shared::rate_limit::RateLimiter;
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, auth_helper::test_helpers::create_test_jwt_service,
+    };
+
+    async fn call(limiter: RateLimiter, user_id: Uuid) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(user_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_upload_url_rate_limiter(limiter)
+            .build();
+
+        // All media routes, so `/api/media/{attachment_target}` gets a chance to match
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .configure(configure),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/api/media/upload-policy")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_reports_the_shared_limits() {
+        let resp = call(RateLimiter::disabled(), Uuid::new_v4()).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: Value = test::read_body_json(resp).await;
+        let data = &body["data"];
+        assert_eq!(
+            data["maxFileSizeBytes"],
+            shared_domain::limits::MAX_IMAGE_BYTES
+        );
+        assert_eq!(
+            data["maxTotalPixels"],
+            shared_domain::limits::MAX_TOTAL_PIXELS
+        );
+        assert_eq!(data["allowedMimeTypes"][0], "image/jpeg");
+        assert_eq!(
+            data["generatedWidths"],
+            serde_json::json!([150, 320, 768, 1200])
+        );
+        assert!(data["uploadUrlsRemaining"].is_null());
+    }
+
+    #[actix_web::test]
+    async fn test_remaining_uploads_are_the_users_own() {
+        let user_id = Uuid::new_v4();
+        let limiter = RateLimiter::new(5, Duration::from_secs(3600));
+        limiter.hit(&user_id.to_string()).unwrap();
+        limiter.hit(&Uuid::new_v4().to_string()).unwrap();
+
+        let resp = call(limiter, user_id).await;
+
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["uploadUrlsPerHour"], 5);
+        assert_eq!(body["data"]["uploadUrlsRemaining"], 4);
+    }
+}
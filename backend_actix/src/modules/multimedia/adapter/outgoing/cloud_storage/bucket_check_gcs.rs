@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use tokio::sync::OnceCell;
+
+use google_cloud_storage::client::StorageControl;
+
+/// Whether a bucket can be reached with the configured credentials.
+/// Used by the health checks; uploads themselves go through signed URLs,
+/// so bad credentials would otherwise only show on the first upload.
+#[async_trait]
+pub trait BucketCheck: Send + Sync {
+    fn bucket(&self) -> &str;
+
+    async fn check_bucket(&self) -> Result<(), String>;
+}
+
+/// Reads the bucket's metadata, which needs `storage.buckets.get`
+pub struct GcsBucketCheck {
+    bucket: String,
+    client: OnceCell<StorageControl>,
+}
+
+impl GcsBucketCheck {
+    /// The client is built on the first check, like `GcsStorageQuery`'s
+    pub fn new(bucket: impl Into<String>) -> Self {
+        Self {
+            bucket: bucket.into(),
+            client: OnceCell::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl BucketCheck for GcsBucketCheck {
+    fn bucket(&self) -> &str {
+        &self.bucket
+    }
+
+    async fn check_bucket(&self) -> Result<(), String> {
+        let client = self
+            .client
+            .get_or_try_init(|| async { StorageControl::builder().build().await })
+            .await
+            .map_err(|e| format!("failed to build GCS client: {e}"))?;
+
+        client
+            .get_bucket()
+            .set_name(format!("projects/_/buckets/{}", self.bucket))
+            .send()
+            .await
+            .map(|_bucket| ())
+            .map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,169 @@
+use async_trait::async_trait;
+use std::time::Duration;
+
+use crate::multimedia::application::ports::outgoing::cloud_storage::{
+    FetchedFile, MediaTransfer, SignedUpload, TransferError,
+};
+use crate::shared::image_proxy::AllowedOrigins;
+
+/// Generous enough for a few megabytes over a slow link
+const TRANSFER_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// reqwest's own default
+const MAX_REDIRECTS: usize = 10;
+
+/// Plain HTTP transfers, for server-side imports
+pub struct HttpMediaTransfer {
+    client: reqwest::Client,
+}
+
+impl HttpMediaTransfer {
+    pub fn new() -> Result<Self, TransferError> {
+        Self::with_redirect_policy(reqwest::redirect::Policy::limited(MAX_REDIRECTS))
+    }
+
+    /// Follows a redirect only to a URL on one of `origins`, so an allowed
+    /// host can't bounce a download to an internal address. For the image
+    /// proxy, whose callers pick the URL.
+    pub fn allowing_only(origins: AllowedOrigins) -> Result<Self, TransferError> {
+        Self::with_redirect_policy(reqwest::redirect::Policy::custom(move |attempt| {
+            if attempt.previous().len() >= MAX_REDIRECTS {
+                attempt.error("too many redirects")
+            } else if origins.allows(attempt.url().as_str()) {
+                attempt.follow()
+            } else {
+                attempt.error("redirect to an origin that is not allowed")
+            }
+        }))
+    }
+
+    fn with_redirect_policy(policy: reqwest::redirect::Policy) -> Result<Self, TransferError> {
+        let client = reqwest::Client::builder()
+            .timeout(TRANSFER_TIMEOUT)
+            .user_agent(concat!("port-blog-cms/", env!("CARGO_PKG_VERSION")))
+            .redirect(policy)
+            .build()
+            .map_err(|e| TransferError::Failed(e.to_string()))?;
+
+        Ok(Self { client })
+    }
+}
+
+/// `image/PNG; charset=binary` -> `image/png`
+fn media_type(header: Option<&str>) -> String {
+    header
+        .and_then(|value| value.split(';').next())
+        .map(|value| value.trim().to_ascii_lowercase())
+        .unwrap_or_default()
+}
+
+#[async_trait]
+impl MediaTransfer for HttpMediaTransfer {
+    async fn download(&self, url: &str, max_bytes: u64) -> Result<FetchedFile, TransferError> {
+        let mut response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| TransferError::Failed(e.to_string()))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(TransferError::NotFound);
+        }
+        response = response
+            .error_for_status()
+            .map_err(|e| TransferError::Failed(e.to_string()))?;
+        if response.content_length().is_some_and(|len| len > max_bytes) {
+            return Err(TransferError::TooLarge(max_bytes));
+        }
+
+        let content_type = media_type(
+            response
+                .headers()
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok()),
+        );
+
+        // Content-Length may be missing or wrong, so count while reading
+        let mut bytes = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| TransferError::Failed(e.to_string()))?
+        {
+            if (bytes.len() + chunk.len()) as u64 > max_bytes {
+                return Err(TransferError::TooLarge(max_bytes));
+            }
+            bytes.extend_from_slice(&chunk);
+        }
+
+        Ok(FetchedFile {
+            bytes,
+            content_type,
+        })
+    }
+
+    async fn upload(&self, upload: &SignedUpload, file: &FetchedFile) -> Result<(), TransferError> {
+        let mut request = self.client.put(&upload.url);
+        for (name, value) in &upload.headers {
+            request = request.header(name.as_str(), value.as_str());
+        }
+        request
+            .body(file.bytes.clone())
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| TransferError::Failed(e.to_string()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Answers every request with `response`; returns its origin and a hit count
+    async fn serve(response: String) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let origin = format!("http://{}", listener.local_addr().unwrap());
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&hits);
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut request = [0u8; 1024];
+                let _ = socket.read(&mut request).await;
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+        (origin, hits)
+    }
+
+    #[test]
+    fn test_media_type_drops_parameters() {
+        assert_eq!(media_type(Some("image/PNG; charset=binary")), "image/png");
+        assert_eq!(media_type(Some(" image/webp ")), "image/webp");
+        assert_eq!(media_type(None), "");
+    }
+
+    #[tokio::test]
+    async fn test_redirect_off_the_allowlist_is_refused() {
+        let (internal, internal_hits) =
+            serve("HTTP/1.1 200 OK\r\nContent-Length: 6\r\n\r\nsecret".to_string()).await;
+        let (allowed, _) = serve(format!(
+            "HTTP/1.1 302 Found\r\nLocation: {internal}/latest/meta-data\r\nContent-Length: 0\r\n\r\n"
+        ))
+        .await;
+
+        let transfer =
+            HttpMediaTransfer::allowing_only(AllowedOrigins::new(vec![allowed.parse().unwrap()]))
+                .unwrap();
+        let result = transfer.download(&format!("{allowed}/cat.png"), 1024).await;
+
+        assert!(matches!(result, Err(TransferError::Failed(_))));
+        assert_eq!(internal_hits.load(Ordering::SeqCst), 0);
+    }
+}
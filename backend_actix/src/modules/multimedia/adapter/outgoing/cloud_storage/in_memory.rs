@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+
+use crate::multimedia::application::ports::outgoing::cloud_storage::{
+    ManifestInfo, MediaInfo, ObjectInventory, ObjectInventoryError, SignUrlError, SignedUpload,
+    StorageQuery, StorageQueryError, StoredObject, UploadConstraints,
+};
+
+/// Stand-in `StorageQuery` and `ObjectInventory` for running without GCS.
+/// Signed URLs use a `memory://` scheme that nothing serves, so the upload
+/// flow can be walked through but no bytes are stored, and no manifest is
+/// ever found because no image processor runs.
+#[derive(Clone, Default)]
+pub struct InMemoryStorage;
+
+fn memory_url(media_info: &MediaInfo) -> String {
+    format!(
+        "memory://{}/{}",
+        media_info.bucket_name(),
+        media_info.object_name()
+    )
+}
+
+#[async_trait]
+impl StorageQuery for InMemoryStorage {
+    async fn get_signed_upload_url(
+        &self,
+        media_info: MediaInfo,
+        constraints: UploadConstraints,
+    ) -> Result<SignedUpload, SignUrlError> {
+        Ok(SignedUpload {
+            url: memory_url(&media_info),
+            headers: BTreeMap::from([("content-type".to_string(), constraints.content_type)]),
+        })
+    }
+
+    async fn get_signed_read_url(&self, media_info: MediaInfo) -> Result<String, SignUrlError> {
+        Ok(memory_url(&media_info))
+    }
+
+    /// Always true, so the flow can be walked through to `processing`
+    async fn object_exists(&self, _media_info: MediaInfo) -> Result<bool, StorageQueryError> {
+        Ok(true)
+    }
+
+    async fn get_latest_manifest(&self, media_id: &str) -> Result<ManifestInfo, StorageQueryError> {
+        if media_id.trim().is_empty() {
+            return Err(StorageQueryError::MediaIdNotFound);
+        }
+        Err(StorageQueryError::ManifestNotFound)
+    }
+}
+
+/// Buckets are always empty, as no bytes are stored
+#[async_trait]
+impl ObjectInventory for InMemoryStorage {
+    async fn list_objects(&self, _bucket: &str) -> Result<Vec<StoredObject>, ObjectInventoryError> {
+        Ok(vec![])
+    }
+
+    async fn delete_object(&self, _bucket: &str, _name: &str) -> Result<(), ObjectInventoryError> {
+        Ok(())
+    }
+}
@@ -1,3 +1,13 @@
+mod bucket_check_gcs;
+mod http_media_transfer;
+mod in_memory;
+mod object_inventory_gcs;
 mod storage_query_gcs;
+mod storage_query_local;
 
+pub use bucket_check_gcs::{BucketCheck, GcsBucketCheck};
+pub use http_media_transfer::HttpMediaTransfer;
+pub use in_memory::InMemoryStorage;
+pub use object_inventory_gcs::GcsObjectInventory;
 pub use storage_query_gcs::GcsStorageQuery;
+pub use storage_query_local::LocalStorageQuery;
@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::OnceCell;
+
+use google_cloud_storage::client::StorageControl;
+
+use crate::multimedia::application::ports::outgoing::cloud_storage::{
+    ObjectInventory, ObjectInventoryError, StoredObject,
+};
+
+/// Objects fetched per list request
+const PAGE_SIZE: i32 = 1000;
+
+/// Lists and deletes objects through the storage control API, which needs
+/// `storage.objects.list` and `storage.objects.delete` on the buckets
+pub struct GcsObjectInventory {
+    client: OnceCell<StorageControl>,
+}
+
+impl GcsObjectInventory {
+    /// The client is built on first use, like `GcsStorageQuery`'s
+    pub fn new() -> Self {
+        Self {
+            client: OnceCell::new(),
+        }
+    }
+
+    async fn client(&self) -> Result<&StorageControl, ObjectInventoryError> {
+        self.client
+            .get_or_try_init(|| async { StorageControl::builder().build().await })
+            .await
+            .map_err(|e| ObjectInventoryError::Failed(format!("failed to build GCS client: {e}")))
+    }
+}
+
+impl Default for GcsObjectInventory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Missing objects come back as a gRPC status rather than an HTTP code
+fn is_not_found(e: &google_cloud_storage::Error) -> bool {
+    e.http_status_code() == Some(404) || e.status().is_some_and(|s| s.code.name() == "NOT_FOUND")
+}
+
+#[async_trait]
+impl ObjectInventory for GcsObjectInventory {
+    async fn list_objects(&self, bucket: &str) -> Result<Vec<StoredObject>, ObjectInventoryError> {
+        let client = self.client().await?;
+
+        let mut objects = Vec::new();
+        let mut page_token = String::new();
+        loop {
+            let page = client
+                .list_objects()
+                .set_parent(format!("projects/_/buckets/{bucket}"))
+                .set_page_size(PAGE_SIZE)
+                .set_page_token(page_token)
+                .send()
+                .await
+                .map_err(|e| ObjectInventoryError::Failed(e.to_string()))?;
+
+            for object in page.objects {
+                // Objects always carry one; treat a missing time as brand new
+                let created_at = object
+                    .create_time
+                    .and_then(|t| DateTime::from_timestamp(t.seconds(), t.nanos() as u32))
+                    .unwrap_or_else(Utc::now);
+                objects.push(StoredObject {
+                    name: object.name,
+                    created_at,
+                });
+            }
+
+            if page.next_page_token.is_empty() {
+                return Ok(objects);
+            }
+            page_token = page.next_page_token;
+        }
+    }
+
+    async fn delete_object(&self, bucket: &str, name: &str) -> Result<(), ObjectInventoryError> {
+        let client = self.client().await?;
+
+        match client
+            .delete_object()
+            .set_bucket(format!("projects/_/buckets/{bucket}"))
+            .set_object(name)
+            .send()
+            .await
+        {
+            Ok(()) => Ok(()),
+            Err(e) if is_not_found(&e) => Ok(()),
+            Err(e) => Err(ObjectInventoryError::Failed(e.to_string())),
+        }
+    }
+}
@@ -1,28 +1,42 @@
 use async_trait::async_trait;
-use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use shared_domain::buckets::{bucket_resource, manifest_object_key, DEFAULT_MANIFEST_BUCKET};
+use shared_domain::manifest::{ManifestHeader, ManifestState};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ops::Deref;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::sync::OnceCell;
+use uuid::Uuid;
 
+use crate::multimedia::application::domain::entities::MediaState;
 use crate::multimedia::application::ports::outgoing::cloud_storage::{
-    ManifestInfo, MediaInfo, SignUrlError, StorageQuery, StorageQueryError,
+    ManifestInfo, MediaInfo, SignUrlError, SignedUpload, StorageQuery, StorageQueryError,
+    UploadConstraints,
 };
+use crate::multimedia::application::ports::outgoing::db::{StorageOverride, StorageOverrideStore};
 
-/// Bucket where manifests are written by your image resizer service.
-const MANIFEST_BUCKET: &str = "blogport-cms-manifests";
+/// Bucket where manifests are written by the image processor.
+const MANIFEST_BUCKET: &str = DEFAULT_MANIFEST_BUCKET;
 
 /// TTL for signed upload URLs.
 const SIGNED_URL_TTL: Duration = Duration::from_secs(15 * 60);
 
-fn manifest_object_key(media_id: &str) -> String {
-    format!("{}/manifest.json", media_id)
-}
-
-/// google-cloud-storage uses a bucket resource name format:
-/// `projects/_/buckets/{bucket}`
-///
-/// Keeping this here makes it hard to accidentally pass a raw bucket name.
-fn bucket_resource(bucket: &str) -> String {
-    format!("projects/_/buckets/{}", bucket)
+/// Signed into the upload URL, so GCS refuses a PUT without them or with a
+/// body outside the range
+fn upload_headers(constraints: &UploadConstraints) -> BTreeMap<String, String> {
+    let mut headers = BTreeMap::from([
+        ("content-type".to_string(), constraints.content_type.clone()),
+        (
+            "x-goog-content-length-range".to_string(),
+            format!("0,{}", constraints.max_bytes),
+        ),
+    ]);
+    // The image processor routes the upload to the tenant's output bucket
+    if let Some(tenant) = &constraints.tenant {
+        headers.insert("x-goog-meta-tenant".to_string(), tenant.clone());
+    }
+    headers
 }
 
 fn map_sign_error(msg: &str) -> SignUrlError {
@@ -66,6 +80,7 @@ trait GcsClient: Send + Sync {
         bucket_resource: &str,
         object_name: &str,
         ttl: Duration,
+        headers: &BTreeMap<String, String>,
     ) -> Result<String, String>;
 
     async fn sign_get_url(
@@ -80,6 +95,10 @@ trait GcsClient: Send + Sync {
         bucket_resource: &str,
         object_name: &str,
     ) -> Result<Vec<u8>, String>;
+
+    /// `Ok(false)` when the object doesn't exist
+    async fn object_exists(&self, bucket_resource: &str, object_name: &str)
+        -> Result<bool, String>;
 }
 
 #[cfg(test)]
@@ -93,8 +112,11 @@ impl GcsClient for ArcGcsClient {
         bucket_resource: &str,
         object_name: &str,
         ttl: Duration,
+        headers: &BTreeMap<String, String>,
     ) -> Result<String, String> {
-        self.0.sign_put_url(bucket_resource, object_name, ttl).await
+        self.0
+            .sign_put_url(bucket_resource, object_name, ttl, headers)
+            .await
     }
 
     async fn sign_get_url(
@@ -115,6 +137,73 @@ impl GcsClient for ArcGcsClient {
             .download_object_bytes(bucket_resource, object_name)
             .await
     }
+
+    async fn object_exists(
+        &self,
+        bucket_resource: &str,
+        object_name: &str,
+    ) -> Result<bool, String> {
+        self.0.object_exists(bucket_resource, object_name).await
+    }
+}
+
+type GcsResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+/// A client and the `updated_at` of the key it was built from
+type CachedClient = (DateTime<Utc>, Arc<dyn GcsClient>);
+
+/// Clients for buckets of accounts with their own storage, signing with the
+/// account's service account key
+struct OverrideClients {
+    store: Arc<dyn StorageOverrideStore>,
+    /// The server's buckets, which are never looked up
+    default_buckets: HashSet<String>,
+    /// By owner
+    clients: Mutex<HashMap<Uuid, CachedClient>>,
+}
+
+impl OverrideClients {
+    async fn client(&self, value: &StorageOverride) -> GcsResult<Arc<dyn GcsClient>> {
+        let owner = value.owner.value();
+        if let Some((updated_at, client)) = self.cached(owner) {
+            if updated_at == value.updated_at {
+                return Ok(client);
+            }
+        }
+
+        let client: Arc<dyn GcsClient> =
+            Arc::new(RealGcsClient::from_key(&value.credentials).await?);
+        self.clients
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(owner, (value.updated_at, Arc::clone(&client)));
+        Ok(client)
+    }
+
+    fn cached(&self, owner: Uuid) -> Option<CachedClient> {
+        self.clients
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(&owner)
+            .map(|(updated_at, client)| (*updated_at, Arc::clone(client)))
+    }
+}
+
+/// The client for one bucket
+enum ClientRef<'a> {
+    Default(&'a dyn GcsClient),
+    Override(Arc<dyn GcsClient>),
+}
+
+impl<'a> Deref for ClientRef<'a> {
+    type Target = dyn GcsClient + 'a;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            ClientRef::Default(client) => *client,
+            ClientRef::Override(client) => client.as_ref(),
+        }
+    }
 }
 
 /// Production adapter: implements your StorageQuery port.
@@ -122,6 +211,7 @@ impl GcsClient for ArcGcsClient {
 pub struct GcsStorageQuery {
     client: Arc<OnceCell<Box<dyn GcsClient>>>,
     signed_url_ttl: Duration,
+    overrides: Option<Arc<OverrideClients>>,
 }
 
 impl GcsStorageQuery {
@@ -130,11 +220,27 @@ impl GcsStorageQuery {
         Self {
             client: Arc::new(OnceCell::new()),
             signed_url_ttl: SIGNED_URL_TTL,
+            overrides: None,
         }
     }
 
+    /// Buckets other than `default_buckets` are signed and read with the key
+    /// of the account they belong to, if any
+    pub fn with_overrides(
+        mut self,
+        store: Arc<dyn StorageOverrideStore>,
+        default_buckets: impl IntoIterator<Item = String>,
+    ) -> Self {
+        self.overrides = Some(Arc::new(OverrideClients {
+            store,
+            default_buckets: default_buckets.into_iter().collect(),
+            clients: Mutex::new(HashMap::new()),
+        }));
+        self
+    }
+
     /// Get or initialize the GCS client.
-    async fn get_client(&self) -> Result<&dyn GcsClient, Box<dyn std::error::Error + Send + Sync>> {
+    async fn get_client(&self) -> GcsResult<&dyn GcsClient> {
         self.client
             .get_or_try_init(|| async {
                 let real_client = RealGcsClient::new().await?;
@@ -144,6 +250,18 @@ impl GcsStorageQuery {
             .map(|boxed| &**boxed)
     }
 
+    /// The client of the account owning `bucket`, or the default one
+    async fn client_for(&self, bucket: &str) -> GcsResult<ClientRef<'_>> {
+        if let Some(overrides) = &self.overrides {
+            if !overrides.default_buckets.contains(bucket) {
+                if let Some(value) = overrides.store.find_by_bucket(bucket).await? {
+                    return overrides.client(&value).await.map(ClientRef::Override);
+                }
+            }
+        }
+        self.get_client().await.map(ClientRef::Default)
+    }
+
     /// Test-friendly constructor with pre-initialized client.
     #[cfg(test)]
     fn with_client(client: Arc<dyn GcsClient>, signed_url_ttl: Duration) -> Self {
@@ -155,30 +273,38 @@ impl GcsStorageQuery {
         Self {
             client: Arc::new(once),
             signed_url_ttl,
+            overrides: None,
         }
     }
 }
 
 #[async_trait]
 impl StorageQuery for GcsStorageQuery {
-    async fn get_signed_upload_url(&self, media_info: MediaInfo) -> Result<String, SignUrlError> {
+    async fn get_signed_upload_url(
+        &self,
+        media_info: MediaInfo,
+        constraints: UploadConstraints,
+    ) -> Result<SignedUpload, SignUrlError> {
         let client = self
-            .get_client()
+            .client_for(media_info.bucket_name())
             .await
             .map_err(|_| SignUrlError::Infrastructure)?;
 
         let bucket = bucket_resource(media_info.bucket_name());
         let object = media_info.object_name().to_string();
+        let headers = upload_headers(&constraints);
 
-        client
-            .sign_put_url(&bucket, &object, self.signed_url_ttl)
+        let url = client
+            .sign_put_url(&bucket, &object, self.signed_url_ttl, &headers)
             .await
-            .map_err(|e| map_sign_error(&e))
+            .map_err(|e| map_sign_error(&e))?;
+
+        Ok(SignedUpload { url, headers })
     }
 
     async fn get_signed_read_url(&self, media_info: MediaInfo) -> Result<String, SignUrlError> {
         let client = self
-            .get_client()
+            .client_for(media_info.bucket_name())
             .await
             .map_err(|_| SignUrlError::Infrastructure)?;
 
@@ -210,10 +336,32 @@ impl StorageQuery for GcsStorageQuery {
             .await
             .map_err(|e| map_read_error(&e))?;
 
-        let manifest: ManifestInfo =
+        // Only the header: it parses manifests of every pipeline version
+        let manifest: ManifestHeader =
             serde_json::from_slice(&bytes).map_err(|_| StorageQueryError::NetworkInterrupted)?;
 
-        Ok(manifest)
+        Ok(ManifestInfo {
+            media_id: manifest.media_id,
+            updated_at: manifest.updated_at,
+            status: match manifest.state {
+                ManifestState::Ready => MediaState::Ready,
+                ManifestState::Failed => MediaState::Failed,
+            },
+        })
+    }
+
+    async fn object_exists(&self, media_info: MediaInfo) -> Result<bool, StorageQueryError> {
+        let client = self
+            .client_for(media_info.bucket_name())
+            .await
+            .map_err(|_| StorageQueryError::NetworkInterrupted)?;
+
+        let bucket = bucket_resource(media_info.bucket_name());
+
+        client
+            .object_exists(&bucket, media_info.object_name())
+            .await
+            .map_err(|_| StorageQueryError::NetworkInterrupted)
     }
 }
 
@@ -260,6 +408,23 @@ impl RealGcsClient {
 
         Ok(Self { storage, signer })
     }
+
+    /// Signs and reads with a service account key instead of the default
+    /// credentials
+    async fn from_key(key_json: &str) -> GcsResult<Self> {
+        let key: serde_json::Value = serde_json::from_str(key_json)?;
+
+        let credentials =
+            google_cloud_auth::credentials::service_account::Builder::new(key.clone()).build()?;
+        let storage = google_cloud_storage::client::Storage::builder()
+            .with_credentials(credentials)
+            .build()
+            .await?;
+        let signer =
+            google_cloud_auth::credentials::service_account::Builder::new(key).build_signer()?;
+
+        Ok(Self { storage, signer })
+    }
 }
 
 #[async_trait]
@@ -269,16 +434,22 @@ impl GcsClient for RealGcsClient {
         bucket_resource: &str,
         object_name: &str,
         ttl: Duration,
+        headers: &BTreeMap<String, String>,
     ) -> Result<String, String> {
-        let url = google_cloud_storage::builder::storage::SignedUrlBuilder::for_object(
+        let mut builder = google_cloud_storage::builder::storage::SignedUrlBuilder::for_object(
             bucket_resource.to_string(),
             object_name.to_string(),
         )
         .with_method(google_cloud_storage::http::Method::PUT)
-        .with_expiration(ttl)
-        .sign_with(&self.signer)
-        .await
-        .map_err(|e| e.to_string())?;
+        .with_expiration(ttl);
+        for (name, value) in headers {
+            builder = builder.with_header(name.as_str(), value.as_str());
+        }
+
+        let url = builder
+            .sign_with(&self.signer)
+            .await
+            .map_err(|e| e.to_string())?;
 
         Ok(url)
     }
@@ -322,6 +493,24 @@ impl GcsClient for RealGcsClient {
 
         Ok(out)
     }
+
+    async fn object_exists(
+        &self,
+        bucket_resource: &str,
+        object_name: &str,
+    ) -> Result<bool, String> {
+        // Only the response headers are read; dropping the stream skips the body
+        match self
+            .storage
+            .read_object(bucket_resource.to_string(), object_name.to_string())
+            .send()
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(e) if e.http_status_code() == Some(404) => Ok(false),
+            Err(e) => Err(e.to_string()),
+        }
+    }
 }
 
 // ============================================================================
@@ -333,16 +522,21 @@ mod tests {
     use super::*;
     use std::sync::Mutex;
 
-    use crate::multimedia::application::domain::entities::{AttachmentTarget, MediaState};
+    use crate::multimedia::application::domain::entities::AttachmentTarget;
     use crate::multimedia::application::ports::outgoing::cloud_storage::MediaInfo;
 
+    /// Bucket, object key, expiry and signed headers of a PUT signing
+    type SignPutCall = (String, String, Duration, BTreeMap<String, String>);
+
     struct FakeGcsClient {
-        last_sign_put_call: Mutex<Option<(String, String, Duration)>>,
+        last_sign_put_call: Mutex<Option<SignPutCall>>,
         last_sign_get_call: Mutex<Option<(String, String, Duration)>>,
         last_download_call: Mutex<Option<(String, String)>>,
+        last_exists_call: Mutex<Option<(String, String)>>,
         sign_put_result: Mutex<Result<String, String>>,
         sign_get_result: Mutex<Result<String, String>>,
         download_result: Mutex<Result<Vec<u8>, String>>,
+        exists_result: Mutex<Result<bool, String>>,
     }
 
     impl Default for FakeGcsClient {
@@ -351,9 +545,11 @@ mod tests {
                 last_sign_put_call: Mutex::new(None),
                 last_sign_get_call: Mutex::new(None),
                 last_download_call: Mutex::new(None),
+                last_exists_call: Mutex::new(None),
                 sign_put_result: Mutex::new(Ok("ok".to_string())),
                 sign_get_result: Mutex::new(Ok("ok".to_string())),
                 download_result: Mutex::new(Ok(Vec::new())),
+                exists_result: Mutex::new(Ok(true)),
             }
         }
     }
@@ -374,6 +570,10 @@ mod tests {
         fn set_download_result(&self, r: Result<Vec<u8>, String>) {
             *self.download_result.lock().unwrap() = r;
         }
+
+        fn set_exists_result(&self, r: Result<bool, String>) {
+            *self.exists_result.lock().unwrap() = r;
+        }
     }
 
     #[async_trait]
@@ -383,9 +583,14 @@ mod tests {
             bucket_resource: &str,
             object_name: &str,
             ttl: Duration,
+            headers: &BTreeMap<String, String>,
         ) -> Result<String, String> {
-            *self.last_sign_put_call.lock().unwrap() =
-                Some((bucket_resource.to_string(), object_name.to_string(), ttl));
+            *self.last_sign_put_call.lock().unwrap() = Some((
+                bucket_resource.to_string(),
+                object_name.to_string(),
+                ttl,
+                headers.clone(),
+            ));
 
             self.sign_put_result.lock().unwrap().clone()
         }
@@ -412,6 +617,25 @@ mod tests {
 
             self.download_result.lock().unwrap().clone()
         }
+
+        async fn object_exists(
+            &self,
+            bucket_resource: &str,
+            object_name: &str,
+        ) -> Result<bool, String> {
+            *self.last_exists_call.lock().unwrap() =
+                Some((bucket_resource.to_string(), object_name.to_string()));
+
+            self.exists_result.lock().unwrap().clone()
+        }
+    }
+
+    fn sample_constraints() -> UploadConstraints {
+        UploadConstraints {
+            content_type: "image/webp".to_string(),
+            max_bytes: 1024,
+            tenant: None,
+        }
     }
 
     fn sample_media_info() -> MediaInfo {
@@ -435,15 +659,19 @@ mod tests {
         let svc = GcsStorageQuery::with_client(fake.clone(), Duration::from_secs(123));
 
         let url = svc
-            .get_signed_upload_url(sample_media_info())
+            .get_signed_upload_url(sample_media_info(), sample_constraints())
             .await
             .unwrap();
-        assert_eq!(url, "https://signed.example");
+        assert_eq!(url.url, "https://signed.example");
 
         let call = fake.last_sign_put_call.lock().unwrap().clone().unwrap();
         assert_eq!(call.0, "projects/_/buckets/blogport-cms-upload");
         assert_eq!(call.1, "abc.webp");
         assert_eq!(call.2, Duration::from_secs(123));
+        // Size and type are bound into the signature and handed to the client
+        assert_eq!(call.3, url.headers);
+        assert_eq!(url.headers["content-type"], "image/webp");
+        assert_eq!(url.headers["x-goog-content-length-range"], "0,1024");
     }
 
     #[tokio::test]
@@ -453,7 +681,7 @@ mod tests {
 
         let svc = GcsStorageQuery::with_client(fake, SIGNED_URL_TTL);
         let err = svc
-            .get_signed_upload_url(sample_media_info())
+            .get_signed_upload_url(sample_media_info(), sample_constraints())
             .await
             .unwrap_err();
 
@@ -467,7 +695,7 @@ mod tests {
 
         let svc = GcsStorageQuery::with_client(fake, SIGNED_URL_TTL);
         let err = svc
-            .get_signed_upload_url(sample_media_info())
+            .get_signed_upload_url(sample_media_info(), sample_constraints())
             .await
             .unwrap_err();
 
@@ -481,7 +709,7 @@ mod tests {
 
         let svc = GcsStorageQuery::with_client(fake, SIGNED_URL_TTL);
         let err = svc
-            .get_signed_upload_url(sample_media_info())
+            .get_signed_upload_url(sample_media_info(), sample_constraints())
             .await
             .unwrap_err();
 
@@ -495,7 +723,7 @@ mod tests {
 
         let svc = GcsStorageQuery::with_client(fake, SIGNED_URL_TTL);
         let err = svc
-            .get_signed_upload_url(sample_media_info())
+            .get_signed_upload_url(sample_media_info(), sample_constraints())
             .await
             .unwrap_err();
 
@@ -594,7 +822,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_latest_manifest_success_and_uses_correct_object_key() {
         let fake = Arc::new(FakeGcsClient::new());
-        let json = br#"{"media_id":"m1","updated_at":"2026-02-08T00:00:00Z","status":"ready"}"#;
+        let json = br#"{"state":"ready","media_id":"m1","pipeline_version":"v1","updated_at":"2026-02-08T00:00:00Z","kind":"image","variants":[]}"#;
         fake.set_download_result(Ok(json.to_vec()));
 
         let svc = GcsStorageQuery::with_client(fake.clone(), SIGNED_URL_TTL);
@@ -608,6 +836,31 @@ mod tests {
         assert_eq!(call.1, "m1/manifest.json");
     }
 
+    #[tokio::test]
+    async fn test_get_latest_manifest_reads_what_the_processor_writes() {
+        use shared_domain::manifest::{Manifest, ManifestError, PIPELINE_VERSION};
+
+        let written = Manifest::Failed {
+            media_id: "m1".to_string(),
+            pipeline_version: PIPELINE_VERSION.to_string(),
+            updated_at: "2026-02-08T00:00:00Z".to_string(),
+            error: ManifestError {
+                code: shared_domain::error_codes::DECODE_FAILED.to_string(),
+                message: "Truncated file".to_string(),
+                stage: "validation".to_string(),
+            },
+        };
+        let fake = Arc::new(FakeGcsClient::new());
+        fake.set_download_result(Ok(serde_json::to_vec(&written).unwrap()));
+
+        let svc = GcsStorageQuery::with_client(fake, SIGNED_URL_TTL);
+
+        let manifest = svc.get_latest_manifest("m1").await.unwrap();
+        assert_eq!(manifest.media_id, "m1");
+        assert_eq!(manifest.updated_at, "2026-02-08T00:00:00Z");
+        assert_eq!(manifest.status, MediaState::Failed);
+    }
+
     #[tokio::test]
     async fn test_get_latest_manifest_maps_manifest_not_found() {
         let fake = Arc::new(FakeGcsClient::new());
@@ -640,4 +893,112 @@ mod tests {
         let err = svc.get_latest_manifest("m4").await.unwrap_err();
         assert!(matches!(err, StorageQueryError::NetworkInterrupted));
     }
+
+    // -----------------------
+    // object_exists
+    // -----------------------
+
+    #[tokio::test]
+    async fn test_object_exists_checks_the_upload_object() {
+        let fake = Arc::new(FakeGcsClient::new());
+        let svc = GcsStorageQuery::with_client(fake.clone(), SIGNED_URL_TTL);
+
+        assert!(svc.object_exists(sample_media_info()).await.unwrap());
+
+        let call = fake.last_exists_call.lock().unwrap().clone().unwrap();
+        assert_eq!(call.0, "projects/_/buckets/blogport-cms-upload");
+        assert_eq!(call.1, "abc.webp");
+
+        fake.set_exists_result(Ok(false));
+        assert!(!svc.object_exists(sample_media_info()).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_object_exists_maps_errors_to_network_interrupted() {
+        let fake = Arc::new(FakeGcsClient::new());
+        fake.set_exists_result(Err("connection reset".to_string()));
+
+        let svc = GcsStorageQuery::with_client(fake, SIGNED_URL_TTL);
+
+        let err = svc.object_exists(sample_media_info()).await.unwrap_err();
+        assert!(matches!(err, StorageQueryError::NetworkInterrupted));
+    }
+
+    // -----------------------
+    // storage overrides
+    // -----------------------
+
+    #[tokio::test]
+    async fn test_upload_for_a_tenant_signs_its_metadata() {
+        let fake = Arc::new(FakeGcsClient::new());
+        let svc = GcsStorageQuery::with_client(fake, SIGNED_URL_TTL);
+        let constraints = UploadConstraints {
+            tenant: Some("jane".to_string()),
+            ..sample_constraints()
+        };
+
+        let url = svc
+            .get_signed_upload_url(sample_media_info(), constraints)
+            .await
+            .unwrap();
+
+        assert_eq!(url.headers["x-goog-meta-tenant"], "jane");
+    }
+
+    #[tokio::test]
+    async fn test_overridden_buckets_use_the_owners_client() {
+        use crate::multimedia::adapter::outgoing::db::InMemoryStorageOverrides;
+
+        let store = InMemoryStorageOverrides::default();
+        let saved = store
+            .save(StorageOverride {
+                owner: Uuid::new_v4().into(),
+                upload_bucket: "jane-uploads".to_string(),
+                ready_bucket: "jane-ready".to_string(),
+                tenant: "jane".to_string(),
+                credentials: "{}".to_string(),
+                updated_at: Utc::now(),
+            })
+            .await
+            .unwrap();
+        let default_client = Arc::new(FakeGcsClient::new());
+        let owner_client = Arc::new(FakeGcsClient::new());
+        let svc = GcsStorageQuery::with_client(default_client.clone(), SIGNED_URL_TTL)
+            .with_overrides(Arc::new(store), ["blogport-cms-upload".to_string()]);
+        // Stands in for the client built from the key
+        svc.overrides
+            .as_ref()
+            .unwrap()
+            .clients
+            .lock()
+            .unwrap()
+            .insert(
+                saved.owner.value(),
+                (saved.updated_at, owner_client.clone() as Arc<dyn GcsClient>),
+            );
+
+        let ready = MediaInfo::try_new(
+            "jane-ready".to_string(),
+            "abc/thumbnail.webp".to_string(),
+            AttachmentTarget::Resume,
+        )
+        .unwrap();
+        svc.get_signed_read_url(ready).await.unwrap();
+        svc.get_signed_read_url(sample_media_info()).await.unwrap();
+
+        let call = owner_client
+            .last_sign_get_call
+            .lock()
+            .unwrap()
+            .clone()
+            .unwrap();
+        assert_eq!(call.0, "projects/_/buckets/jane-ready");
+        let call = default_client
+            .last_sign_get_call
+            .lock()
+            .unwrap()
+            .clone()
+            .unwrap();
+        assert_eq!(call.0, "projects/_/buckets/blogport-cms-upload");
+    }
 }
@@ -0,0 +1,231 @@
+use async_trait::async_trait;
+use shared_domain::buckets::{manifest_object_key, DEFAULT_MANIFEST_BUCKET};
+use shared_domain::manifest::{ManifestHeader, ManifestState};
+use std::collections::BTreeMap;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use crate::multimedia::application::domain::entities::MediaState;
+use crate::multimedia::application::ports::outgoing::cloud_storage::{
+    ManifestInfo, MediaInfo, SignUrlError, SignedUpload, StorageQuery, StorageQueryError,
+    UploadConstraints,
+};
+
+/// `StorageQuery` over the folders of an image processor running with
+/// `PROCESSOR_MODE=local`, for standalone runs with a real pipeline. Every
+/// bucket is a folder under `root` (the processor's `LOCAL_STORAGE_DIR`), and
+/// URLs point at the processor's `/local/{bucket}/{name}` routes, which store
+/// an upload, process it and serve the results. Nothing is signed: anyone who
+/// can reach the processor can read and write.
+#[derive(Clone)]
+pub struct LocalStorageQuery {
+    root: PathBuf,
+    processor_url: String,
+}
+
+impl LocalStorageQuery {
+    pub fn new(root: impl Into<PathBuf>, processor_url: &str) -> Self {
+        Self {
+            root: root.into(),
+            processor_url: processor_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    fn url(&self, media_info: &MediaInfo) -> String {
+        format!(
+            "{}/local/{}/{}",
+            self.processor_url,
+            media_info.bucket_name(),
+            media_info.object_name()
+        )
+    }
+
+    /// File of an object, or `None` when the name would step out of its
+    /// bucket folder; the processor refuses those names too
+    fn path(&self, bucket: &str, name: &str) -> Option<PathBuf> {
+        let safe = |segment: &str| !matches!(segment, "" | "." | "..") && !segment.contains('\\');
+        if !safe(bucket) || !name.split('/').all(safe) {
+            return None;
+        }
+        Some(self.root.join(bucket).join(name))
+    }
+}
+
+#[async_trait]
+impl StorageQuery for LocalStorageQuery {
+    async fn get_signed_upload_url(
+        &self,
+        media_info: MediaInfo,
+        constraints: UploadConstraints,
+    ) -> Result<SignedUpload, SignUrlError> {
+        let mut headers = BTreeMap::from([("content-type".to_string(), constraints.content_type)]);
+        // The processor turns `x-goog-meta-*` headers into object metadata
+        if let Some(tenant) = constraints.tenant {
+            headers.insert("x-goog-meta-tenant".to_string(), tenant);
+        }
+
+        Ok(SignedUpload {
+            url: self.url(&media_info),
+            headers,
+        })
+    }
+
+    async fn get_signed_read_url(&self, media_info: MediaInfo) -> Result<String, SignUrlError> {
+        Ok(self.url(&media_info))
+    }
+
+    async fn object_exists(&self, media_info: MediaInfo) -> Result<bool, StorageQueryError> {
+        let Some(path) = self.path(media_info.bucket_name(), media_info.object_name()) else {
+            return Ok(false);
+        };
+
+        tokio::fs::try_exists(path)
+            .await
+            .map_err(|_| StorageQueryError::NetworkInterrupted)
+    }
+
+    async fn get_latest_manifest(&self, media_id: &str) -> Result<ManifestInfo, StorageQueryError> {
+        let media_id = media_id.trim();
+        let Some(path) = self
+            .path(DEFAULT_MANIFEST_BUCKET, &manifest_object_key(media_id))
+            .filter(|_| !media_id.is_empty())
+        else {
+            return Err(StorageQueryError::MediaIdNotFound);
+        };
+
+        let bytes = tokio::fs::read(path).await.map_err(|e| match e.kind() {
+            ErrorKind::NotFound => StorageQueryError::ManifestNotFound,
+            _ => StorageQueryError::NetworkInterrupted,
+        })?;
+
+        // Only the header: it parses manifests of every pipeline version
+        let manifest: ManifestHeader =
+            serde_json::from_slice(&bytes).map_err(|_| StorageQueryError::NetworkInterrupted)?;
+
+        Ok(ManifestInfo {
+            media_id: manifest.media_id,
+            updated_at: manifest.updated_at,
+            status: match manifest.state {
+                ManifestState::Ready => MediaState::Ready,
+                ManifestState::Failed => MediaState::Failed,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared_domain::manifest::{Manifest, ManifestError, PIPELINE_VERSION};
+    use uuid::Uuid;
+
+    use crate::multimedia::application::domain::entities::AttachmentTarget;
+
+    /// A fresh storage folder, removed on drop
+    struct Folder(PathBuf);
+
+    impl Folder {
+        fn new() -> Self {
+            let path = std::env::temp_dir().join(format!("local-storage-{}", Uuid::new_v4()));
+            std::fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+
+        fn write(&self, bucket: &str, name: &str, bytes: &[u8]) {
+            let file = self.0.join(bucket).join(name);
+            std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+            std::fs::write(file, bytes).unwrap();
+        }
+    }
+
+    impl Drop for Folder {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn media_info(bucket: &str, name: &str) -> MediaInfo {
+        MediaInfo::try_new(
+            bucket.to_string(),
+            name.to_string(),
+            AttachmentTarget::Project,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_urls_point_at_the_processor_local_routes() {
+        let folder = Folder::new();
+        let storage = LocalStorageQuery::new(&folder.0, "http://localhost:8081/");
+
+        let upload = storage
+            .get_signed_upload_url(
+                media_info("uploads", "m1/cat.png"),
+                UploadConstraints {
+                    content_type: "image/png".to_string(),
+                    max_bytes: 1024,
+                    tenant: Some("acme".to_string()),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(upload.url, "http://localhost:8081/local/uploads/m1/cat.png");
+        assert_eq!(upload.headers["content-type"], "image/png");
+        assert_eq!(upload.headers["x-goog-meta-tenant"], "acme");
+
+        let read = storage
+            .get_signed_read_url(media_info("ready", "variants/m1/cat_320.webp"))
+            .await
+            .unwrap();
+        assert_eq!(
+            read,
+            "http://localhost:8081/local/ready/variants/m1/cat_320.webp"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_object_exists_looks_in_the_bucket_folder() {
+        let folder = Folder::new();
+        folder.write("uploads", "m1/cat.png", b"png");
+        let storage = LocalStorageQuery::new(&folder.0, "http://localhost:8081");
+
+        let exists = |name: &'static str| storage.object_exists(media_info("uploads", name));
+        assert!(exists("m1/cat.png").await.unwrap());
+        assert!(!exists("m1/dog.png").await.unwrap());
+        assert!(!exists("../uploads/m1/cat.png").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_get_latest_manifest_reads_what_the_processor_writes() {
+        let folder = Folder::new();
+        let written = Manifest::Failed {
+            media_id: "m1".to_string(),
+            pipeline_version: PIPELINE_VERSION.to_string(),
+            updated_at: "2026-10-16T10:00:00Z".to_string(),
+            error: ManifestError {
+                code: "DOWNLOAD_ERROR".to_string(),
+                message: "Could not download".to_string(),
+                stage: "download".to_string(),
+            },
+        };
+        folder.write(
+            DEFAULT_MANIFEST_BUCKET,
+            &manifest_object_key("m1"),
+            &serde_json::to_vec(&written).unwrap(),
+        );
+        let storage = LocalStorageQuery::new(&folder.0, "http://localhost:8081");
+
+        let manifest = storage.get_latest_manifest("m1").await.unwrap();
+        assert_eq!(manifest.status, MediaState::Failed);
+        assert_eq!(manifest.updated_at, "2026-10-16T10:00:00Z");
+
+        assert_eq!(
+            storage.get_latest_manifest("m2").await.unwrap_err(),
+            StorageQueryError::ManifestNotFound
+        );
+        assert_eq!(
+            storage.get_latest_manifest(" ").await.unwrap_err(),
+            StorageQueryError::MediaIdNotFound
+        );
+    }
+}
@@ -0,0 +1,577 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shared_domain::buckets::upload_object_key;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::multimedia::application::domain::entities::{
+    AttachmentTarget, MediaFailure, MediaSize, MediaState, MediaStateInfo, MediaVariant,
+};
+use crate::multimedia::application::ports::outgoing::db::{
+    MediaAttachment, MediaQuery, MediaQueryError, MediaRepository, MediaRepositoryError,
+    MediaRetryQueue, MediaUploadBatchRepository, MediaVariantRecord, NewMediaAttachment,
+    QueuedFailure, RecordMediaError, RecordMediaTx, RecordedMedia, StorageOverride,
+    StorageOverrideStore, StorageOverrideStoreError, StoredVariant, UpdateMediaStateData,
+    UploadBatchItem, UploadObject,
+};
+use crate::shared::in_memory::Table;
+
+/// Extensions the `media` table's check constraint accepts
+const ALLOWED_EXTENSIONS: [&str; 6] = ["jpg", "jpeg", "png", "webp", "svg", "pdf"];
+
+pub(crate) struct MediaRow {
+    pub(crate) owner: UserId,
+    pub(crate) media_id: Uuid,
+    pub(crate) bucket_name: String,
+    pub(crate) object_key: String,
+    pub(crate) original_name: String,
+    pub(crate) file_size_bytes: u64,
+    pub(crate) state: MediaState,
+    pub(crate) checksum_sha256: Option<String>,
+    pub(crate) updated_at: DateTime<Utc>,
+    /// Set while the media is in the trash
+    pub(crate) deleted_at: Option<DateTime<Utc>>,
+    pub(crate) attachment: NewMediaAttachment,
+    pub(crate) variants: Vec<StoredVariant>,
+}
+
+impl MediaRow {
+    fn is_live(&self) -> bool {
+        self.deleted_at.is_none()
+    }
+
+    fn state_info(&self) -> MediaStateInfo {
+        MediaStateInfo {
+            owner: self.owner,
+            media_id: self.media_id,
+            updated_at: self.updated_at.to_rfc3339(),
+            status: self.state.clone(),
+        }
+    }
+
+    fn attachment(&self) -> MediaAttachment {
+        MediaAttachment {
+            media_id: self.media_id,
+            owner: self.owner,
+            attachment_target: self.attachment.attachment_target.clone(),
+            attachment_target_id: self.attachment.attachment_target_id,
+            status: self.state.clone(),
+            role: self.attachment.role.clone(),
+            position: self.attachment.position,
+            alt_text: self.attachment.alt_text.clone().unwrap_or_default(),
+            caption: self.attachment.caption.clone().unwrap_or_default(),
+            original_filename: self.original_name.clone(),
+            variants: self.variants.clone(),
+        }
+    }
+}
+
+/// A `media_retries` row
+struct RetryRow {
+    media_id: Uuid,
+    failure_code: String,
+    attempts: u32,
+    /// `None` while a retry is out
+    failed_at: Option<DateTime<Utc>>,
+}
+
+/// Display order of variants, as in the Postgres variant query
+fn size_rank(size: &MediaSize) -> u8 {
+    match size {
+        MediaSize::Thumbnail => 1,
+        MediaSize::Small => 2,
+        MediaSize::Medium => 3,
+        MediaSize::Large => 4,
+    }
+}
+
+/// Process-local media, implementing both `MediaRepository` and `MediaQuery`
+/// over the same rows. Trashed media is invisible to both, as in Postgres,
+/// except to `existing_media_ids`.
+#[derive(Clone, Default)]
+pub struct InMemoryMediaStore {
+    pub(crate) media: Table<MediaRow>,
+    /// `(batch_id, media_id)` in request order
+    batches: Table<(Uuid, Uuid)>,
+    retries: Table<RetryRow>,
+}
+
+#[async_trait]
+impl MediaRepository for InMemoryMediaStore {
+    async fn record_media_tx(&self, tx: RecordMediaTx) -> Result<RecordedMedia, RecordMediaError> {
+        let original_name = tx.media.original_name.trim().to_string();
+        let ext = std::path::Path::new(&original_name)
+            .extension()
+            .and_then(|s| s.to_str())
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        if !ALLOWED_EXTENSIONS.contains(&ext.as_str()) {
+            return Err(RecordMediaError::DatabaseError(format!(
+                "unsupported extension '{ext}'"
+            )));
+        }
+
+        let media_id = Uuid::new_v4();
+        let row = MediaRow {
+            owner: tx.media.owner,
+            media_id,
+            bucket_name: tx.media.bucket_name.trim().to_string(),
+            object_key: upload_object_key(&media_id.to_string(), &original_name),
+            original_name,
+            file_size_bytes: tx.media.file_size_bytes,
+            state: tx.media.state,
+            checksum_sha256: tx.media.checksum_sha256.map(|c| c.to_ascii_lowercase()),
+            updated_at: Utc::now(),
+            deleted_at: None,
+            attachment: tx.attachment,
+            variants: Vec::new(),
+        };
+        let recorded = RecordedMedia {
+            owner: row.owner,
+            media_id: row.media_id,
+            bucket_name: row.bucket_name.clone(),
+            original_name: row.original_name.clone(),
+            attachment_target: row.attachment.attachment_target.clone(),
+            state: row.state.clone(),
+        };
+        self.media.write(|media| media.push(row));
+        Ok(recorded)
+    }
+
+    async fn set_media_state(
+        &self,
+        data: UpdateMediaStateData,
+    ) -> Result<MediaStateInfo, MediaRepositoryError> {
+        self.media.write(|media| {
+            let row = media
+                .iter_mut()
+                .find(|m| m.media_id == data.media_id && m.owner == data.owner && m.is_live())
+                .ok_or(MediaRepositoryError::NotFound)?;
+            row.state = data.status;
+            row.updated_at = Utc::now();
+            Ok(row.state_info())
+        })
+    }
+
+    async fn transition_media_state(
+        &self,
+        data: UpdateMediaStateData,
+        from: MediaState,
+    ) -> Result<Option<MediaStateInfo>, MediaRepositoryError> {
+        self.media.write(|media| {
+            let Some(row) = media
+                .iter_mut()
+                .find(|m| m.media_id == data.media_id && m.owner == data.owner && m.is_live())
+            else {
+                return Ok(None);
+            };
+            if row.state != from {
+                return Ok(None);
+            }
+            row.state = data.status;
+            row.updated_at = Utc::now();
+            Ok(Some(row.state_info()))
+        })
+    }
+
+    async fn fail_media(
+        &self,
+        media: &MediaStateInfo,
+        _failure: MediaFailure,
+    ) -> Result<Option<MediaStateInfo>, MediaRepositoryError> {
+        // The code only feeds the `media.failed` webhook, which has no
+        // in-memory counterpart
+        self.media.write(|rows| {
+            let Some(row) = rows
+                .iter_mut()
+                .find(|m| m.media_id == media.media_id && m.owner == media.owner && m.is_live())
+            else {
+                return Ok(None);
+            };
+            if row.state != media.status {
+                return Ok(None);
+            }
+            row.state = MediaState::Failed;
+            row.updated_at = Utc::now();
+            Ok(Some(row.state_info()))
+        })
+    }
+
+    async fn record_single_variant(
+        &self,
+        data: MediaVariantRecord,
+    ) -> Result<MediaVariant, MediaRepositoryError> {
+        self.record_variants(vec![data])
+            .await?
+            .pop()
+            .ok_or(MediaRepositoryError::NotFound)
+    }
+
+    async fn record_variants(
+        &self,
+        data: Vec<MediaVariantRecord>,
+    ) -> Result<Vec<MediaVariant>, MediaRepositoryError> {
+        self.media.write(|media| {
+            // All or nothing, like the Postgres transaction
+            if !data.iter().all(|v| {
+                media
+                    .iter()
+                    .any(|m| m.media_id == v.media_id && m.owner == v.owner && m.is_live())
+            }) {
+                return Err(MediaRepositoryError::NotFound);
+            }
+
+            let mut recorded = Vec::with_capacity(data.len());
+            for variant in data {
+                let row = media
+                    .iter_mut()
+                    .find(|m| m.media_id == variant.media_id)
+                    .expect("checked above");
+                row.variants.retain(|v| v.size != variant.size);
+                row.variants.push(StoredVariant {
+                    size: variant.size.clone(),
+                    bucket_name: variant.bucket_name.trim().to_string(),
+                    object_name: variant.object_key.trim().to_string(),
+                    width: variant.width_px.unwrap_or(0),
+                    height: variant.height_px.unwrap_or(0),
+                    file_size_bytes: variant.file_size_bytes,
+                    mime_type: variant.mime_type.trim().to_string(),
+                });
+                row.variants.sort_by_key(|v| size_rank(&v.size));
+                recorded.push(MediaVariant {
+                    path: format!("/api/media/{}/{}", variant.media_id, variant.size),
+                    size: variant.size,
+                });
+            }
+            Ok(recorded)
+        })
+    }
+}
+
+#[async_trait]
+impl MediaUploadBatchRepository for InMemoryMediaStore {
+    async fn record_batch(
+        &self,
+        batch_id: Uuid,
+        media_ids: &[Uuid],
+    ) -> Result<(), MediaRepositoryError> {
+        self.batches
+            .write(|batches| batches.extend(media_ids.iter().map(|id| (batch_id, *id))));
+        Ok(())
+    }
+
+    async fn batch_items(
+        &self,
+        owner: UserId,
+        batch_id: Uuid,
+    ) -> Result<Vec<UploadBatchItem>, MediaRepositoryError> {
+        let media_ids: Vec<Uuid> = self.batches.read(|batches| {
+            batches
+                .iter()
+                .filter(|(batch, _)| *batch == batch_id)
+                .map(|(_, media_id)| *media_id)
+                .collect()
+        });
+
+        Ok(self.media.read(|media| {
+            media_ids
+                .iter()
+                .filter_map(|id| {
+                    media
+                        .iter()
+                        .find(|m| m.media_id == *id && m.owner == owner && m.is_live())
+                })
+                .map(|m| UploadBatchItem {
+                    media_id: m.media_id,
+                    original_filename: m.original_name.clone(),
+                    status: m.state.clone(),
+                    // Failure codes are not kept here, see `fail_media`
+                    failure_code: None,
+                })
+                .collect()
+        }))
+    }
+}
+
+#[async_trait]
+impl MediaQuery for InMemoryMediaStore {
+    async fn get_state(&self, media_id: Uuid) -> Result<MediaStateInfo, MediaQueryError> {
+        self.media.read(|media| {
+            media
+                .iter()
+                .find(|m| m.media_id == media_id && m.is_live())
+                .map(MediaRow::state_info)
+                .ok_or(MediaQueryError::MediaNotFound)
+        })
+    }
+
+    async fn list_by_target(
+        &self,
+        owner: UserId,
+        target: AttachmentTarget,
+    ) -> Result<Vec<MediaAttachment>, MediaQueryError> {
+        self.media.read(|media| {
+            let mut matching: Vec<&MediaRow> = media
+                .iter()
+                .filter(|m| m.owner == owner && m.is_live())
+                .filter(|m| m.attachment.attachment_target == target)
+                .collect();
+            matching.sort_by_key(|m| {
+                (
+                    m.attachment.attachment_target_id,
+                    m.attachment.position,
+                    m.updated_at,
+                )
+            });
+            Ok(matching.into_iter().map(MediaRow::attachment).collect())
+        })
+    }
+
+    async fn get_attachment_info(
+        &self,
+        media_id: Uuid,
+    ) -> Result<MediaAttachment, MediaQueryError> {
+        self.media.read(|media| {
+            media
+                .iter()
+                .find(|m| m.media_id == media_id && m.is_live())
+                .map(MediaRow::attachment)
+                .ok_or(MediaQueryError::MediaNotFound)
+        })
+    }
+
+    async fn get_attachment_infos(
+        &self,
+        media_ids: &[Uuid],
+    ) -> Result<Vec<MediaAttachment>, MediaQueryError> {
+        self.media.read(|media| {
+            Ok(media
+                .iter()
+                .filter(|m| media_ids.contains(&m.media_id) && m.is_live())
+                .map(MediaRow::attachment)
+                .collect())
+        })
+    }
+
+    async fn get_upload_object(&self, media_id: Uuid) -> Result<UploadObject, MediaQueryError> {
+        self.media.read(|media| {
+            media
+                .iter()
+                .find(|m| m.media_id == media_id && m.is_live())
+                .map(|m| UploadObject {
+                    media_id: m.media_id,
+                    owner: m.owner,
+                    status: m.state.clone(),
+                    attachment_target: m.attachment.attachment_target.clone(),
+                    bucket_name: m.bucket_name.clone(),
+                    object_key: m.object_key.clone(),
+                })
+                .ok_or(MediaQueryError::MediaNotFound)
+        })
+    }
+
+    async fn list_unsettled(
+        &self,
+        updated_before: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<MediaStateInfo>, MediaQueryError> {
+        self.media.read(|media| {
+            let mut unsettled: Vec<&MediaRow> = media
+                .iter()
+                .filter(|m| matches!(m.state, MediaState::Pending | MediaState::Processing))
+                .filter(|m| m.updated_at < updated_before && m.is_live())
+                .collect();
+            unsettled.sort_by_key(|m| m.updated_at);
+            Ok(unsettled
+                .into_iter()
+                .take(limit as usize)
+                .map(MediaRow::state_info)
+                .collect())
+        })
+    }
+
+    async fn find_duplicate(
+        &self,
+        owner: UserId,
+        checksum_sha256: &str,
+    ) -> Result<Option<MediaStateInfo>, MediaQueryError> {
+        self.media.read(|media| {
+            Ok(media
+                .iter()
+                .filter(|m| m.owner == owner && m.is_live() && m.state != MediaState::Failed)
+                .filter(|m| m.checksum_sha256.as_deref() == Some(checksum_sha256))
+                .max_by_key(|m| m.updated_at)
+                .map(MediaRow::state_info))
+        })
+    }
+
+    async fn existing_media_ids(
+        &self,
+        media_ids: &[Uuid],
+    ) -> Result<HashSet<Uuid>, MediaQueryError> {
+        self.media.read(|media| {
+            Ok(media
+                .iter()
+                .map(|m| m.media_id)
+                .filter(|id| media_ids.contains(id))
+                .collect())
+        })
+    }
+}
+
+#[async_trait]
+impl MediaRetryQueue for InMemoryMediaStore {
+    async fn file(
+        &self,
+        media_id: Uuid,
+        failure_code: &str,
+        failed_at: DateTime<Utc>,
+    ) -> Result<bool, MediaRepositoryError> {
+        let qualifies = self.media.read(|media| {
+            media.iter().any(|m| {
+                m.media_id == media_id
+                    && m.is_live()
+                    && matches!(m.state, MediaState::Pending | MediaState::Processing)
+                    && m.updated_at <= failed_at
+            })
+        });
+        if !qualifies {
+            return Ok(false);
+        }
+
+        self.retries.write(
+            |retries| match retries.iter_mut().find(|r| r.media_id == media_id) {
+                Some(row) => {
+                    row.failure_code = failure_code.to_string();
+                    row.failed_at = Some(failed_at);
+                }
+                None => retries.push(RetryRow {
+                    media_id,
+                    failure_code: failure_code.to_string(),
+                    attempts: 0,
+                    failed_at: Some(failed_at),
+                }),
+            },
+        );
+        Ok(true)
+    }
+
+    async fn waiting(&self, limit: u64) -> Result<Vec<QueuedFailure>, MediaRepositoryError> {
+        let queued: Vec<(Uuid, String, u32, DateTime<Utc>)> = self.retries.read(|retries| {
+            retries
+                .iter()
+                .filter_map(|r| {
+                    r.failed_at
+                        .map(|at| (r.media_id, r.failure_code.clone(), r.attempts, at))
+                })
+                .collect()
+        });
+
+        let mut waiting: Vec<QueuedFailure> = self.media.read(|media| {
+            queued
+                .into_iter()
+                .filter_map(|(media_id, failure_code, attempts, failed_at)| {
+                    let row = media.iter().find(|m| {
+                        m.media_id == media_id
+                            && m.is_live()
+                            && matches!(m.state, MediaState::Pending | MediaState::Processing)
+                    })?;
+                    Some(QueuedFailure {
+                        media: row.state_info(),
+                        bucket_name: row.bucket_name.clone(),
+                        object_key: row.object_key.clone(),
+                        failure_code,
+                        attempts,
+                        failed_at,
+                    })
+                })
+                .collect()
+        });
+        waiting.sort_by_key(|f| f.failed_at);
+        waiting.truncate(limit as usize);
+        Ok(waiting)
+    }
+
+    async fn mark_sent(&self, media_id: Uuid, attempts: u32) -> Result<(), MediaRepositoryError> {
+        self.retries.write(|retries| {
+            if let Some(row) = retries.iter_mut().find(|r| r.media_id == media_id) {
+                row.attempts = attempts;
+                row.failed_at = None;
+            }
+        });
+        Ok(())
+    }
+
+    async fn postpone(&self, media_id: Uuid, attempts: u32) -> Result<(), MediaRepositoryError> {
+        self.retries.write(|retries| {
+            if let Some(row) = retries.iter_mut().find(|r| r.media_id == media_id) {
+                row.attempts = attempts;
+                row.failed_at = Some(Utc::now());
+            }
+        });
+        Ok(())
+    }
+
+    async fn remove(&self, media_id: Uuid) -> Result<(), MediaRepositoryError> {
+        self.retries
+            .write(|retries| retries.retain(|r| r.media_id != media_id));
+        Ok(())
+    }
+}
+
+/// Process-local `storage_overrides`. Credentials are kept as given; nothing
+/// here outlives the process.
+#[derive(Clone, Default)]
+pub struct InMemoryStorageOverrides {
+    rows: Table<StorageOverride>,
+}
+
+#[async_trait]
+impl StorageOverrideStore for InMemoryStorageOverrides {
+    async fn find_by_owner(
+        &self,
+        owner: UserId,
+    ) -> Result<Option<StorageOverride>, StorageOverrideStoreError> {
+        Ok(self
+            .rows
+            .read(|rows| rows.iter().find(|row| row.owner == owner).cloned()))
+    }
+
+    async fn find_by_bucket(
+        &self,
+        bucket: &str,
+    ) -> Result<Option<StorageOverride>, StorageOverrideStoreError> {
+        Ok(self
+            .rows
+            .read(|rows| rows.iter().find(|row| row.owns_bucket(bucket)).cloned()))
+    }
+
+    async fn save(
+        &self,
+        mut value: StorageOverride,
+    ) -> Result<StorageOverride, StorageOverrideStoreError> {
+        value.updated_at = Utc::now();
+        self.rows.write(|rows| {
+            let taken = rows.iter().any(|row| {
+                row.owner != value.owner
+                    && (row.owns_bucket(&value.upload_bucket)
+                        || row.owns_bucket(&value.ready_bucket))
+            });
+            if taken {
+                return Err(StorageOverrideStoreError::BucketTaken);
+            }
+            rows.retain(|row| row.owner != value.owner);
+            rows.push(value.clone());
+            Ok(value)
+        })
+    }
+
+    async fn delete(&self, owner: UserId) -> Result<bool, StorageOverrideStoreError> {
+        Ok(self.rows.write(|rows| {
+            let before = rows.len();
+            rows.retain(|row| row.owner != owner);
+            rows.len() < before
+        }))
+    }
+}
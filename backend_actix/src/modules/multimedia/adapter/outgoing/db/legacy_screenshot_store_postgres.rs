@@ -0,0 +1,191 @@
+use async_trait::async_trait;
+use sea_orm::{ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, Statement};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    auth::application::domain::entities::UserId,
+    multimedia::application::ports::outgoing::db::{
+        LegacyScreenshot, LegacyScreenshotStore, MediaRepositoryError, ScreenshotMigration,
+    },
+};
+
+#[derive(Clone)]
+pub struct LegacyScreenshotStorePostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl LegacyScreenshotStorePostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    /// Each URL of `projects.screenshots` with its index, minus those already
+    /// recorded (imported ones only, with `retry_failed`)
+    fn pending_stmt(limit: u64, retry_failed: bool) -> Statement {
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"
+            SELECT
+                p.id AS project_id,
+                p.user_id,
+                s.url,
+                s.position - 1 AS position
+            FROM projects p
+            CROSS JOIN LATERAL jsonb_array_elements_text(p.screenshots)
+                WITH ORDINALITY AS s(url, position)
+            WHERE p.is_deleted = false
+              AND NOT EXISTS (
+                  SELECT 1
+                  FROM project_screenshot_migrations m
+                  WHERE m.project_id = p.id
+                    AND m.source_url = s.url
+                    AND (m.media_id IS NOT NULL OR NOT $1)
+              )
+            ORDER BY p.id, s.position
+            LIMIT $2
+            "#,
+            vec![retry_failed.into(), (limit as i64).into()],
+        )
+    }
+
+    fn record_stmt(project_id: Uuid, url: &str, outcome: ScreenshotMigration) -> Statement {
+        let (media_id, error) = match outcome {
+            ScreenshotMigration::Migrated { media_id } => (Some(media_id), None),
+            ScreenshotMigration::Failed { reason } => (None, Some(reason)),
+        };
+
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"
+            INSERT INTO project_screenshot_migrations (project_id, source_url, media_id, error)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (project_id, source_url) DO UPDATE
+            SET media_id = excluded.media_id,
+                error = excluded.error,
+                migrated_at = now()
+            "#,
+            vec![project_id.into(), url.into(), media_id.into(), error.into()],
+        )
+    }
+
+    fn map_db_err(e: DbErr) -> MediaRepositoryError {
+        MediaRepositoryError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl LegacyScreenshotStore for LegacyScreenshotStorePostgres {
+    async fn pending(
+        &self,
+        limit: u64,
+        retry_failed: bool,
+    ) -> Result<Vec<LegacyScreenshot>, MediaRepositoryError> {
+        let rows = self
+            .db
+            .query_all(Self::pending_stmt(limit, retry_failed))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        rows.iter()
+            .map(|row| {
+                let owner: Uuid = row.try_get("", "user_id").map_err(Self::map_db_err)?;
+                let position: i64 = row.try_get("", "position").map_err(Self::map_db_err)?;
+                Ok(LegacyScreenshot {
+                    owner: UserId::from(owner),
+                    project_id: row.try_get("", "project_id").map_err(Self::map_db_err)?,
+                    // Attachment positions are a u8; later ones share the last slot
+                    position: u8::try_from(position).unwrap_or(u8::MAX),
+                    url: row.try_get("", "url").map_err(Self::map_db_err)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn record(
+        &self,
+        project_id: Uuid,
+        url: &str,
+        outcome: ScreenshotMigration,
+    ) -> Result<(), MediaRepositoryError> {
+        self.db
+            .execute(Self::record_stmt(project_id, url, outcome))
+            .await
+            .map_err(Self::map_db_err)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{MockDatabase, Value};
+    use std::collections::BTreeMap;
+
+    #[tokio::test]
+    async fn test_pending_maps_rows() {
+        let owner = Uuid::new_v4();
+        let project_id = Uuid::new_v4();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![BTreeMap::from([
+                ("project_id".to_string(), Value::from(project_id)),
+                ("user_id".to_string(), Value::from(owner)),
+                (
+                    "url".to_string(),
+                    Value::from("https://cdn.example.com/a.png"),
+                ),
+                ("position".to_string(), Value::BigInt(Some(300))),
+            ])]])
+            .into_connection();
+
+        let pending = LegacyScreenshotStorePostgres::new(Arc::new(db))
+            .pending(10, false)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            pending,
+            vec![LegacyScreenshot {
+                owner: UserId::from(owner),
+                project_id,
+                position: u8::MAX,
+                url: "https://cdn.example.com/a.png".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_pending_skips_recorded_urls_of_live_projects() {
+        let stmt = LegacyScreenshotStorePostgres::pending_stmt(10, true);
+
+        assert!(stmt.sql.contains("p.is_deleted = false"));
+        assert!(stmt.sql.contains("m.media_id IS NOT NULL OR NOT $1"));
+        assert_eq!(
+            stmt.values.unwrap().0[0],
+            Value::Bool(Some(true)),
+            "retry_failed is bound first"
+        );
+    }
+
+    #[test]
+    fn test_record_failure_keeps_reason() {
+        let stmt = LegacyScreenshotStorePostgres::record_stmt(
+            Uuid::new_v4(),
+            "https://cdn.example.com/a.png",
+            ScreenshotMigration::Failed {
+                reason: "File not found".to_string(),
+            },
+        );
+
+        assert!(stmt
+            .sql
+            .contains("ON CONFLICT (project_id, source_url) DO UPDATE"));
+        let values = stmt.values.unwrap().0;
+        assert_eq!(values[2], Value::Uuid(None));
+        assert_eq!(values[3], Value::from("File not found"));
+    }
+}
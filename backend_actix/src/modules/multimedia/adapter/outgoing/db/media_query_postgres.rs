@@ -1,5 +1,9 @@
 use async_trait::async_trait;
-use sea_orm::{ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, Statement};
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement, Value,
+};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -7,7 +11,9 @@ use crate::{
     auth::application::domain::entities::UserId,
     multimedia::application::{
         domain::entities::{AttachmentTarget, MediaRole, MediaSize, MediaState, MediaStateInfo},
-        ports::outgoing::db::{MediaAttachment, MediaQuery, MediaQueryError, StoredVariant},
+        ports::outgoing::db::{
+            MediaAttachment, MediaQuery, MediaQueryError, StoredVariant, UploadObject,
+        },
     },
 };
 
@@ -37,7 +43,7 @@ impl MediaQueryPostgres {
                 m.user_id,
                 m.id as media_id,
                 m.updated_at,
-                m.status::text as status
+                CAST(m.status AS TEXT) as status
             FROM media m
             WHERE m.id = $1
               AND m.deleted_at IS NULL
@@ -46,6 +52,8 @@ impl MediaQueryPostgres {
         )
     }
 
+    // Grouped per entity in display order: that is the order of
+    // idx_media_attachments_target_position, so no sort step is needed
     fn list_by_target_stmt(owner: Uuid, target: &str) -> Statement {
         Statement::from_sql_and_values(
             DatabaseBackend::Postgres,
@@ -55,8 +63,8 @@ impl MediaQueryPostgres {
                 m.id as media_id,
                 ma.attachable_type,
                 ma.attachable_id,
-                m.status::text as status,
-                ma.role::text as role,
+                CAST(m.status AS TEXT) as status,
+                CAST(ma.role AS TEXT) as role,
                 ma.position,
                 COALESCE(ma.alt_text, '') as alt_text,
                 COALESCE(ma.caption, '') as caption,
@@ -66,7 +74,7 @@ impl MediaQueryPostgres {
             WHERE m.user_id = $1
               AND ma.attachable_type = $2
               AND m.deleted_at IS NULL
-            ORDER BY ma.position ASC, ma.created_at ASC
+            ORDER BY ma.attachable_id, ma.position ASC, ma.created_at ASC
             "#,
             vec![owner.into(), target.into()],
         )
@@ -81,7 +89,7 @@ impl MediaQueryPostgres {
                 m.id as media_id,
                 ma.attachable_type,
                 ma.attachable_id,
-                m.status::text as status,
+                CAST(m.status AS TEXT) as status,
                 ma.role,
                 ma.position,
                 COALESCE(ma.alt_text, '') as alt_text,
@@ -89,45 +97,219 @@ impl MediaQueryPostgres {
                 m.original_filename
             FROM media m
             INNER JOIN media_attachments ma ON m.id = ma.media_id
+            INNER JOIN users u ON u.id = m.user_id
             WHERE m.id = $1
               AND m.deleted_at IS NULL
+              AND u.is_deleted = false
+              AND u.is_active = true
             LIMIT 1
             "#,
             vec![media_id.into()],
         )
     }
 
-    fn get_variants_stmt(media_id: Uuid) -> Statement {
+    /// Same rows as [`get_attachment_info_stmt`](Self::get_attachment_info_stmt)
+    /// for a batch of media; `media_ids` is never empty
+    fn get_attachment_infos_stmt(media_ids: &[Uuid]) -> Statement {
+        let placeholders = (1..=media_ids.len())
+            .map(|i| format!("${i}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!(
+                r#"
+                SELECT DISTINCT ON (m.id)
+                    m.user_id,
+                    m.id as media_id,
+                    ma.attachable_type,
+                    ma.attachable_id,
+                    CAST(m.status AS TEXT) as status,
+                    CAST(ma.role AS TEXT) as role,
+                    ma.position,
+                    COALESCE(ma.alt_text, '') as alt_text,
+                    COALESCE(ma.caption, '') as caption,
+                    m.original_filename
+                FROM media m
+                INNER JOIN media_attachments ma ON m.id = ma.media_id
+                INNER JOIN users u ON u.id = m.user_id
+                WHERE m.id IN ({placeholders})
+                  AND m.deleted_at IS NULL
+                  AND u.is_deleted = false
+                  AND u.is_active = true
+                ORDER BY m.id
+                "#
+            ),
+            media_ids.iter().map(|id| Value::from(*id)),
+        )
+    }
+
+    fn get_upload_object_stmt(media_id: Uuid) -> Statement {
         Statement::from_sql_and_values(
             DatabaseBackend::Postgres,
             r#"
             SELECT
-            variant_type::text as variant_type,
-                bucket_name,
-                object_key,
-                width,
-                height,
-                file_size_bytes,
-                mime_type
-            FROM media_variants
-            WHERE media_id = $1
-            ORDER BY
-                CASE variant_type
-                    WHEN 'thumbnail' THEN 1
-                    WHEN 'small' THEN 2
-                    WHEN 'medium' THEN 3
-                    WHEN 'large' THEN 4
-                    ELSE 5
-                END
+                m.user_id,
+                m.id as media_id,
+                CAST(m.status AS TEXT) as status,
+                ma.attachable_type,
+                m.bucket_name,
+                m.object_key
+            FROM media m
+            INNER JOIN media_attachments ma ON m.id = ma.media_id
+            WHERE m.id = $1
+              AND m.deleted_at IS NULL
+            LIMIT 1
             "#,
             vec![media_id.into()],
         )
     }
 
+    /// Variants of a batch of media, in size order per media; `media_ids` is
+    /// never empty
+    fn get_variants_stmt(media_ids: &[Uuid]) -> Statement {
+        let placeholders = (1..=media_ids.len())
+            .map(|i| format!("${i}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!(
+                r#"
+                SELECT
+                    media_id,
+                    CAST(variant_type AS TEXT) as variant_type,
+                    bucket_name,
+                    object_key,
+                    width,
+                    height,
+                    file_size_bytes,
+                    mime_type
+                FROM media_variants
+                WHERE media_id IN ({placeholders})
+                ORDER BY
+                    media_id,
+                    CASE variant_type
+                        WHEN 'thumbnail' THEN 1
+                        WHEN 'small' THEN 2
+                        WHEN 'medium' THEN 3
+                        WHEN 'large' THEN 4
+                        ELSE 5
+                    END
+                "#
+            ),
+            media_ids.iter().map(|id| Value::from(*id)),
+        )
+    }
+
+    fn list_unsettled_stmt(updated_before: DateTime<Utc>, limit: u64) -> Statement {
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"
+            SELECT
+                m.user_id,
+                m.id as media_id,
+                m.updated_at,
+                CAST(m.status AS TEXT) as status
+            FROM media m
+            WHERE m.status IN ('pending', 'processing')
+              AND m.updated_at < $1
+              AND m.deleted_at IS NULL
+              AND NOT EXISTS (
+                  SELECT 1
+                  FROM media_retries r
+                  WHERE r.media_id = m.id AND r.failed_at IS NOT NULL
+              )
+            ORDER BY m.updated_at ASC
+            LIMIT $2
+            "#,
+            vec![updated_before.into(), (limit as i64).into()],
+        )
+    }
+
+    fn find_duplicate_stmt(owner: Uuid, checksum_sha256: &str) -> Statement {
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"
+            SELECT
+                m.user_id,
+                m.id as media_id,
+                m.updated_at,
+                CAST(m.status AS TEXT) as status
+            FROM media m
+            WHERE m.user_id = $1
+              AND m.checksum_sha256 = $2
+              AND m.status <> 'failed'
+              AND m.deleted_at IS NULL
+            ORDER BY m.updated_at DESC
+            LIMIT 1
+            "#,
+            vec![owner.into(), checksum_sha256.into()],
+        )
+    }
+
+    /// `media_ids` is never empty
+    fn existing_media_ids_stmt(media_ids: &[Uuid]) -> Statement {
+        let placeholders = (1..=media_ids.len())
+            .map(|i| format!("${i}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        // No deleted_at filter: trashed media keeps its objects
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!("SELECT id FROM media WHERE id IN ({placeholders})"),
+            media_ids.iter().map(|id| Value::from(*id)),
+        )
+    }
+
     // =====================================================
     // Mapping helpers
     // =====================================================
 
+    fn to_state_info(row: QueryResult) -> Result<MediaStateInfo, MediaQueryError> {
+        let user_id: Uuid = row.try_get("", "user_id").map_err(Self::map_db_err)?;
+        let media_id: Uuid = row.try_get("", "media_id").map_err(Self::map_db_err)?;
+        let updated_at: chrono::DateTime<chrono::FixedOffset> =
+            row.try_get("", "updated_at").map_err(Self::map_db_err)?;
+        let status: String = row.try_get("", "status").map_err(Self::map_db_err)?;
+
+        Ok(MediaStateInfo {
+            owner: UserId::from(user_id),
+            media_id,
+            updated_at: updated_at.to_rfc3339(),
+            status: Self::parse_media_state(&status)?,
+        })
+    }
+
+    /// An attachment row without its variants
+    fn to_attachment(row: &QueryResult) -> Result<MediaAttachment, MediaQueryError> {
+        let user_id: Uuid = row.try_get("", "user_id").map_err(Self::map_db_err)?;
+        let attachable_type: String = row
+            .try_get("", "attachable_type")
+            .map_err(Self::map_db_err)?;
+        let status: String = row.try_get("", "status").map_err(Self::map_db_err)?;
+        let role: String = row.try_get("", "role").map_err(Self::map_db_err)?;
+
+        Ok(MediaAttachment {
+            media_id: row.try_get("", "media_id").map_err(Self::map_db_err)?,
+            owner: UserId::from(user_id),
+            attachment_target: Self::parse_attachment_target(&attachable_type)?,
+            attachment_target_id: row.try_get("", "attachable_id").map_err(Self::map_db_err)?,
+            status: Self::parse_media_state(&status)?,
+            role: Self::parse_media_role(&role)?,
+            position: row.try_get("", "position").map_err(Self::map_db_err)?,
+            alt_text: row.try_get("", "alt_text").map_err(Self::map_db_err)?,
+            caption: row.try_get("", "caption").map_err(Self::map_db_err)?,
+            original_filename: row
+                .try_get("", "original_filename")
+                .map_err(Self::map_db_err)?,
+            variants: Vec::new(),
+        })
+    }
+
     fn map_db_err(e: DbErr) -> MediaQueryError {
         MediaQueryError::DatabaseError(e.to_string())
     }
@@ -186,17 +368,22 @@ impl MediaQueryPostgres {
         }
     }
 
+    /// Variants of every media in `media_ids`, grouped by media, in one query
     async fn get_variants(
         db: &DatabaseConnection,
-        media_id: Uuid,
-    ) -> Result<Vec<StoredVariant>, MediaQueryError> {
-        let stmt = Self::get_variants_stmt(media_id);
+        media_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<StoredVariant>>, MediaQueryError> {
+        let mut variants: HashMap<Uuid, Vec<StoredVariant>> = HashMap::new();
+        if media_ids.is_empty() {
+            return Ok(variants);
+        }
 
-        let results = db.query_all(stmt).await.map_err(Self::map_db_err)?;
+        let stmt = Self::get_variants_stmt(media_ids);
 
-        let mut variants = Vec::new();
+        let results = db.query_all(stmt).await.map_err(Self::map_db_err)?;
 
         for row in results {
+            let media_id: Uuid = row.try_get("", "media_id").map_err(Self::map_db_err)?;
             let variant_type: String = row.try_get("", "variant_type").map_err(Self::map_db_err)?;
             let bucket_name: String = row.try_get("", "bucket_name").map_err(Self::map_db_err)?;
             let object_key: String = row.try_get("", "object_key").map_err(Self::map_db_err)?;
@@ -209,7 +396,7 @@ impl MediaQueryPostgres {
 
             let size = Self::parse_media_size(&variant_type)?;
 
-            variants.push(StoredVariant {
+            variants.entry(media_id).or_default().push(StoredVariant {
                 size,
                 bucket_name,
                 object_name: object_key,
@@ -233,18 +420,7 @@ impl MediaQuery for MediaQueryPostgres {
 
         let row = result.ok_or(MediaQueryError::MediaNotFound)?;
 
-        let user_id: Uuid = row.try_get("", "user_id").map_err(Self::map_db_err)?;
-        let media_id: Uuid = row.try_get("", "media_id").map_err(Self::map_db_err)?;
-        let updated_at: chrono::DateTime<chrono::FixedOffset> =
-            row.try_get("", "updated_at").map_err(Self::map_db_err)?;
-        let status: String = row.try_get("", "status").map_err(Self::map_db_err)?;
-
-        Ok(MediaStateInfo {
-            owner: UserId::from(user_id),
-            media_id,
-            updated_at: updated_at.to_rfc3339(),
-            status: Self::parse_media_state(&status)?,
-        })
+        Self::to_state_info(row)
     }
 
     async fn list_by_target(
@@ -257,42 +433,20 @@ impl MediaQuery for MediaQueryPostgres {
 
         let stmt = Self::list_by_target_stmt(owner_uuid, &target_str);
 
-        let results = self.db.query_all(stmt).await.map_err(Self::map_db_err)?;
-
-        let mut media_list = Vec::new();
-
-        for row in results {
-            let user_id: Uuid = row.try_get("", "user_id").map_err(Self::map_db_err)?;
-            let media_id: Uuid = row.try_get("", "media_id").map_err(Self::map_db_err)?;
-            let attachable_type: String = row
-                .try_get("", "attachable_type")
-                .map_err(Self::map_db_err)?;
-            let attachable_id: Uuid = row.try_get("", "attachable_id").map_err(Self::map_db_err)?;
-            let status: String = row.try_get("", "status").map_err(Self::map_db_err)?;
-            let role: String = row.try_get("", "role").map_err(Self::map_db_err)?;
-            let position: u8 = row.try_get("", "position").map_err(Self::map_db_err)?;
-            let alt_text: String = row.try_get("", "alt_text").map_err(Self::map_db_err)?;
-            let caption: String = row.try_get("", "caption").map_err(Self::map_db_err)?;
-            let original_filename: String = row
-                .try_get("", "original_filename")
-                .map_err(Self::map_db_err)?;
-
-            // Fetch variants for this media
-            let variants = Self::get_variants(&self.db, media_id).await?;
-
-            media_list.push(MediaAttachment {
-                media_id,
-                owner: UserId::from(user_id),
-                attachment_target: Self::parse_attachment_target(&attachable_type)?,
-                attachment_target_id: attachable_id,
-                status: Self::parse_media_state(&status)?,
-                role: Self::parse_media_role(&role)?,
-                position: position,
-                alt_text,
-                caption,
-                original_filename,
-                variants,
-            });
+        let mut media_list = self
+            .db
+            .query_all(stmt)
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(Self::to_attachment)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // One query for the variants of the whole list, not one per media
+        let media_ids: Vec<Uuid> = media_list.iter().map(|media| media.media_id).collect();
+        let mut variants = Self::get_variants(&self.db, &media_ids).await?;
+        for media in &mut media_list {
+            media.variants = variants.remove(&media.media_id).unwrap_or_default();
         }
 
         Ok(media_list)
@@ -307,39 +461,117 @@ impl MediaQuery for MediaQueryPostgres {
         let result = self.db.query_one(stmt).await.map_err(Self::map_db_err)?;
 
         let row = result.ok_or(MediaQueryError::MediaNotFound)?;
+        let mut media = Self::to_attachment(&row)?;
+
+        media.variants = Self::get_variants(&self.db, &[media.media_id])
+            .await?
+            .remove(&media.media_id)
+            .unwrap_or_default();
+
+        Ok(media)
+    }
+
+    async fn get_upload_object(&self, media_id: Uuid) -> Result<UploadObject, MediaQueryError> {
+        let stmt = Self::get_upload_object_stmt(media_id);
+
+        let row = self
+            .db
+            .query_one(stmt)
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or(MediaQueryError::MediaNotFound)?;
 
         let user_id: Uuid = row.try_get("", "user_id").map_err(Self::map_db_err)?;
         let media_id: Uuid = row.try_get("", "media_id").map_err(Self::map_db_err)?;
+        let status: String = row.try_get("", "status").map_err(Self::map_db_err)?;
         let attachable_type: String = row
             .try_get("", "attachable_type")
             .map_err(Self::map_db_err)?;
-        let attachable_id: Uuid = row.try_get("", "attachable_id").map_err(Self::map_db_err)?;
-        let status: String = row.try_get("", "status").map_err(Self::map_db_err)?;
-        let role: String = row.try_get("", "role").map_err(Self::map_db_err)?;
-        let position: u8 = row.try_get("", "position").map_err(Self::map_db_err)?;
-        let alt_text: String = row.try_get("", "alt_text").map_err(Self::map_db_err)?;
-        let caption: String = row.try_get("", "caption").map_err(Self::map_db_err)?;
-        let original_filename: String = row
-            .try_get("", "original_filename")
-            .map_err(Self::map_db_err)?;
+        let bucket_name: String = row.try_get("", "bucket_name").map_err(Self::map_db_err)?;
+        let object_key: String = row.try_get("", "object_key").map_err(Self::map_db_err)?;
 
-        // Fetch variants for this media
-        let variants = Self::get_variants(&self.db, media_id).await?;
-
-        Ok(MediaAttachment {
+        Ok(UploadObject {
             media_id,
             owner: UserId::from(user_id),
-            attachment_target: Self::parse_attachment_target(&attachable_type)?,
-            attachment_target_id: attachable_id,
             status: Self::parse_media_state(&status)?,
-            role: Self::parse_media_role(&role)?,
-            position: position,
-            alt_text,
-            caption,
-            original_filename,
-            variants,
+            attachment_target: Self::parse_attachment_target(&attachable_type)?,
+            bucket_name,
+            object_key,
         })
     }
+
+    async fn list_unsettled(
+        &self,
+        updated_before: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<MediaStateInfo>, MediaQueryError> {
+        let stmt = Self::list_unsettled_stmt(updated_before, limit);
+
+        let results = self.db.query_all(stmt).await.map_err(Self::map_db_err)?;
+
+        results.into_iter().map(Self::to_state_info).collect()
+    }
+
+    async fn find_duplicate(
+        &self,
+        owner: UserId,
+        checksum_sha256: &str,
+    ) -> Result<Option<MediaStateInfo>, MediaQueryError> {
+        let stmt = Self::find_duplicate_stmt(owner.into(), checksum_sha256);
+
+        self.db
+            .query_one(stmt)
+            .await
+            .map_err(Self::map_db_err)?
+            .map(Self::to_state_info)
+            .transpose()
+    }
+
+    async fn get_attachment_infos(
+        &self,
+        media_ids: &[Uuid],
+    ) -> Result<Vec<MediaAttachment>, MediaQueryError> {
+        if media_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let stmt = Self::get_attachment_infos_stmt(media_ids);
+
+        let mut media_list = self
+            .db
+            .query_all(stmt)
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(Self::to_attachment)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let found: Vec<Uuid> = media_list.iter().map(|media| media.media_id).collect();
+        let mut variants = Self::get_variants(&self.db, &found).await?;
+        for media in &mut media_list {
+            media.variants = variants.remove(&media.media_id).unwrap_or_default();
+        }
+
+        Ok(media_list)
+    }
+
+    async fn existing_media_ids(
+        &self,
+        media_ids: &[Uuid],
+    ) -> Result<HashSet<Uuid>, MediaQueryError> {
+        if media_ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let stmt = Self::existing_media_ids_stmt(media_ids);
+
+        let results = self.db.query_all(stmt).await.map_err(Self::map_db_err)?;
+
+        results
+            .into_iter()
+            .map(|row| row.try_get::<Uuid>("", "id").map_err(Self::map_db_err))
+            .collect()
+    }
 }
 
 // ============================================================================
@@ -390,6 +622,58 @@ mod tests {
         assert_eq!(info.status, MediaState::Ready);
     }
 
+    #[tokio::test]
+    async fn test_list_unsettled_maps_rows() {
+        let media_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let now = Utc::now().fixed_offset();
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![make_row(vec![
+                ("user_id", Value::Uuid(Some(Box::new(user_id)))),
+                ("media_id", Value::Uuid(Some(Box::new(media_id)))),
+                (
+                    "updated_at",
+                    Value::ChronoDateTimeWithTimeZone(Some(Box::new(now))),
+                ),
+                (
+                    "status",
+                    Value::String(Some(Box::new("processing".to_string()))),
+                ),
+            ])]])
+            .into_connection();
+
+        let query = MediaQueryPostgres::new(Arc::new(db));
+        let stuck = query.list_unsettled(Utc::now(), 50).await.unwrap();
+
+        assert_eq!(stuck.len(), 1);
+        assert_eq!(stuck[0].media_id, media_id);
+        assert_eq!(stuck[0].status, MediaState::Processing);
+    }
+
+    #[tokio::test]
+    async fn test_find_duplicate_without_match_is_none() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![Vec::<BTreeMap<String, Value>>::new()])
+            .into_connection();
+
+        let query = MediaQueryPostgres::new(Arc::new(db));
+        let found = query
+            .find_duplicate(UserId::from(Uuid::new_v4()), &"a".repeat(64))
+            .await
+            .unwrap();
+
+        assert!(found.is_none());
+    }
+
+    #[test]
+    fn test_find_duplicate_stmt_skips_failed_and_trashed() {
+        let stmt = MediaQueryPostgres::find_duplicate_stmt(Uuid::new_v4(), &"a".repeat(64));
+
+        assert!(stmt.sql.contains("m.status <> 'failed'"));
+        assert!(stmt.sql.contains("m.deleted_at IS NULL"));
+    }
+
     #[tokio::test]
     async fn test_get_state_not_found() {
         let media_id = Uuid::new_v4();
@@ -487,6 +771,7 @@ mod tests {
                 ])],
                 // Second query: get variants for media
                 vec![make_row(vec![
+                    ("media_id", Value::Uuid(Some(Box::new(media_id)))),
                     (
                         "variant_type",
                         Value::String(Some(Box::new("thumbnail".to_string()))),
@@ -773,6 +1058,7 @@ mod tests {
                 // Second query: get variants (all sizes)
                 vec![
                     make_row(vec![
+                        ("media_id", Value::Uuid(Some(Box::new(media_id)))),
                         (
                             "variant_type",
                             Value::String(Some(Box::new("thumbnail".to_string()))),
@@ -794,6 +1080,7 @@ mod tests {
                         ),
                     ]),
                     make_row(vec![
+                        ("media_id", Value::Uuid(Some(Box::new(media_id)))),
                         (
                             "variant_type",
                             Value::String(Some(Box::new("small".to_string()))),
@@ -815,6 +1102,7 @@ mod tests {
                         ),
                     ]),
                     make_row(vec![
+                        ("media_id", Value::Uuid(Some(Box::new(media_id)))),
                         (
                             "variant_type",
                             Value::String(Some(Box::new("medium".to_string()))),
@@ -836,6 +1124,7 @@ mod tests {
                         ),
                     ]),
                     make_row(vec![
+                        ("media_id", Value::Uuid(Some(Box::new(media_id)))),
                         (
                             "variant_type",
                             Value::String(Some(Box::new("large".to_string()))),
@@ -908,6 +1197,7 @@ mod tests {
                 ])],
                 // Second query: invalid variant size
                 vec![make_row(vec![
+                    ("media_id", Value::Uuid(Some(Box::new(media_id)))),
                     (
                         "variant_type",
                         Value::String(Some(Box::new("invalid_size".to_string()))),
@@ -943,6 +1233,121 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_list_by_target_fetches_variants_in_one_query() {
+        let user_id = Uuid::new_v4();
+        let media_ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+        let attachable_id = Uuid::new_v4();
+
+        let attachment = |media_id: Uuid| {
+            make_row(vec![
+                ("user_id", Value::Uuid(Some(Box::new(user_id)))),
+                ("media_id", Value::Uuid(Some(Box::new(media_id)))),
+                (
+                    "attachable_type",
+                    Value::String(Some(Box::new("project".to_string()))),
+                ),
+                ("attachable_id", Value::Uuid(Some(Box::new(attachable_id)))),
+                ("status", Value::String(Some(Box::new("ready".to_string())))),
+                (
+                    "role",
+                    Value::String(Some(Box::new("screenshoot".to_string()))),
+                ),
+                ("position", Value::TinyUnsigned(Some(0))),
+                ("alt_text", Value::String(Some(Box::new("".to_string())))),
+                ("caption", Value::String(Some(Box::new("".to_string())))),
+                (
+                    "original_filename",
+                    Value::String(Some(Box::new("shot.png".to_string()))),
+                ),
+            ])
+        };
+        let variant = |media_id: Uuid, size: &str| {
+            make_row(vec![
+                ("media_id", Value::Uuid(Some(Box::new(media_id)))),
+                (
+                    "variant_type",
+                    Value::String(Some(Box::new(size.to_string()))),
+                ),
+                (
+                    "bucket_name",
+                    Value::String(Some(Box::new("bucket".to_string()))),
+                ),
+                (
+                    "object_key",
+                    Value::String(Some(Box::new(format!("{media_id}/{size}.webp")))),
+                ),
+                ("width", Value::Int(Some(150))),
+                ("height", Value::Int(Some(150))),
+                ("file_size_bytes", Value::BigInt(Some(3000))),
+                (
+                    "mime_type",
+                    Value::String(Some(Box::new("image/webp".to_string()))),
+                ),
+            ])
+        };
+
+        let db = Arc::new(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_query_results(vec![
+                    media_ids.iter().map(|id| attachment(*id)).collect(),
+                    // The last media has no variants yet
+                    vec![
+                        variant(media_ids[0], "thumbnail"),
+                        variant(media_ids[0], "large"),
+                        variant(media_ids[1], "thumbnail"),
+                    ],
+                ])
+                .into_connection(),
+        );
+
+        let query = MediaQueryPostgres::new(Arc::clone(&db));
+        let list = query
+            .list_by_target(UserId::from(user_id), AttachmentTarget::Project)
+            .await
+            .unwrap();
+        drop(query);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list[0].variants.len(), 2);
+        assert_eq!(list[0].variants[1].size, MediaSize::Large);
+        assert_eq!(list[1].variants.len(), 1);
+        assert_eq!(
+            list[1].variants[0].object_name,
+            format!("{}/thumbnail.webp", media_ids[1])
+        );
+        assert!(list[2].variants.is_empty());
+
+        // The attachments, then one variants query for all three media
+        let log = Arc::try_unwrap(db)
+            .expect("no other connection handles")
+            .into_transaction_log();
+        assert_eq!(log.len(), 2);
+        let variants_sql = log[1].statements()[0].sql.clone();
+        assert!(variants_sql.contains("WHERE media_id IN ($1, $2, $3)"));
+    }
+
+    #[tokio::test]
+    async fn test_list_by_target_empty_skips_variants_query() {
+        let db = Arc::new(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_query_results(vec![Vec::<BTreeMap<String, Value>>::new()])
+                .into_connection(),
+        );
+
+        let query = MediaQueryPostgres::new(Arc::clone(&db));
+        query
+            .list_by_target(UserId::from(Uuid::new_v4()), AttachmentTarget::Project)
+            .await
+            .unwrap();
+        drop(query);
+
+        let log = Arc::try_unwrap(db)
+            .expect("no other connection handles")
+            .into_transaction_log();
+        assert_eq!(log.len(), 1);
+    }
+
     // -----------------------
     // Edge cases - parse functions
     // -----------------------
@@ -1034,4 +1439,109 @@ mod tests {
             MediaSize::Large
         ));
     }
+
+    #[tokio::test]
+    async fn test_get_attachment_infos_reads_the_batch_in_two_queries() {
+        let user_id = Uuid::new_v4();
+        let found = Uuid::new_v4();
+        let db = Arc::new(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_query_results(vec![
+                    vec![make_row(vec![
+                        ("user_id", Value::Uuid(Some(Box::new(user_id)))),
+                        ("media_id", Value::Uuid(Some(Box::new(found)))),
+                        (
+                            "attachable_type",
+                            Value::String(Some(Box::new("blog_post".to_string()))),
+                        ),
+                        ("attachable_id", Value::Uuid(Some(Box::new(Uuid::new_v4())))),
+                        ("status", Value::String(Some(Box::new("ready".to_string())))),
+                        ("role", Value::String(Some(Box::new("gallery".to_string())))),
+                        ("position", Value::TinyUnsigned(Some(0))),
+                        ("alt_text", Value::String(Some(Box::new("".to_string())))),
+                        ("caption", Value::String(Some(Box::new("".to_string())))),
+                        (
+                            "original_filename",
+                            Value::String(Some(Box::new("a.jpg".to_string()))),
+                        ),
+                    ])],
+                    Vec::<BTreeMap<String, Value>>::new(),
+                ])
+                .into_connection(),
+        );
+
+        let query = MediaQueryPostgres::new(Arc::clone(&db));
+        let media = query
+            .get_attachment_infos(&[found, Uuid::new_v4()])
+            .await
+            .unwrap();
+        drop(query);
+
+        assert_eq!(media.len(), 1);
+        assert_eq!(media[0].media_id, found);
+
+        let log = Arc::try_unwrap(db)
+            .expect("no other connection handles")
+            .into_transaction_log();
+        assert_eq!(log.len(), 2);
+        assert!(log[0].statements()[0]
+            .sql
+            .contains("WHERE m.id IN ($1, $2)"));
+        assert!(log[1].statements()[0]
+            .sql
+            .contains("WHERE media_id IN ($1)"));
+    }
+
+    #[tokio::test]
+    async fn test_get_attachment_infos_skips_the_query_when_empty() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+        let query = MediaQueryPostgres::new(Arc::new(db));
+
+        assert!(query.get_attachment_infos(&[]).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_existing_media_ids_skips_the_query_when_empty() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+        let query = MediaQueryPostgres::new(Arc::new(db));
+
+        assert!(query.existing_media_ids(&[]).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_existing_media_ids_returns_found_ids() {
+        let known = Uuid::new_v4();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![make_row(vec![(
+                "id",
+                Value::Uuid(Some(Box::new(known))),
+            )])]])
+            .into_connection();
+        let query = MediaQueryPostgres::new(Arc::new(db));
+
+        let found = query
+            .existing_media_ids(&[known, Uuid::new_v4()])
+            .await
+            .unwrap();
+
+        assert_eq!(found, HashSet::from([known]));
+    }
+
+    #[tokio::test]
+    async fn test_explain_list_by_target_uses_position_index() {
+        let Some(db) = crate::tests::support::database::test_postgres_db().await else {
+            return;
+        };
+
+        let plan = crate::tests::support::explain::explain(
+            &db,
+            MediaQueryPostgres::list_by_target_stmt(Uuid::new_v4(), "project"),
+        )
+        .await;
+
+        assert!(
+            plan.contains("idx_media_attachments_target_position"),
+            "{plan}"
+        );
+    }
 }
@@ -2,16 +2,19 @@ use async_trait::async_trait;
 use chrono::Utc;
 use sea_orm::ConnectionTrait;
 use sea_orm::{DatabaseBackend, DatabaseConnection, DbErr, Statement, TransactionTrait};
+use shared_domain::buckets::upload_object_key;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::multimedia::application::{
-    domain::entities::{MediaState, MediaStateInfo, MediaVariant},
+    domain::entities::{MediaFailure, MediaState, MediaStateInfo, MediaVariant},
     ports::outgoing::db::{
         MediaRepository, MediaRepositoryError, MediaVariantRecord, RecordMediaError, RecordMediaTx,
         RecordedMedia, UpdateMediaStateData,
     },
 };
+use crate::shared::sql;
+use crate::shared::unit_of_work::connection;
 
 // ============================================================================
 // Repository Implementation (Production)
@@ -32,6 +35,7 @@ impl MediaRepositoryPostgres {
     // =====================================================
 
     fn insert_media_stmt(
+        backend: DatabaseBackend,
         media_id: Uuid,
         owner: Uuid,
         bucket_name: &str,
@@ -43,17 +47,19 @@ impl MediaRepositoryPostgres {
         height: Option<i32>,
         duration_seconds: Option<i64>,
         status: &str,
+        checksum_sha256: Option<&str>,
         now: chrono::DateTime<chrono::FixedOffset>,
     ) -> Statement {
         Statement::from_sql_and_values(
-            DatabaseBackend::Postgres,
-            r#"
+            backend,
+            format!(
+                r#"
             INSERT INTO media (
               id, user_id,
               bucket_name, object_key,
               original_filename, mime_type, file_size_bytes,
               width, height, duration_seconds,
-              status, metadata,
+              status, metadata, checksum_sha256,
               created_at, updated_at, deleted_at
             )
             VALUES (
@@ -61,10 +67,12 @@ impl MediaRepositoryPostgres {
               $3, $4,
               $5, $6, $7,
               $8, $9, $10,
-              $11::media_status, '{}'::jsonb,
+              {status}, '{{}}', $13,
               $12, $12, NULL
             )
             "#,
+                status = sql::pg_cast(backend, "$11", "media_status"),
+            ),
             vec![
                 media_id.into(),
                 owner.into(),
@@ -79,6 +87,7 @@ impl MediaRepositoryPostgres {
                 duration_seconds.map(|v| v as f64).into(),
                 status.into(),
                 now.into(),
+                checksum_sha256.map(str::to_string).into(),
             ],
         )
     }
@@ -104,7 +113,7 @@ impl MediaRepositoryPostgres {
               created_at
             )
             VALUES (
-              gen_random_uuid(), $1,
+              $9, $1,
               $2, $3,
               $4, $5,
               $6, $7,
@@ -120,10 +129,165 @@ impl MediaRepositoryPostgres {
                 alt_text.into(),
                 caption.into(),
                 now.into(),
+                Uuid::new_v4().into(),
+            ],
+        )
+    }
+
+    /// Upsert on (media_id, variant_type): reprocessing replaces the previous
+    /// variant. The SELECT only yields a row when the media belongs to `owner`.
+    #[allow(clippy::too_many_arguments)]
+    fn upsert_variant_stmt(
+        media_id: Uuid,
+        owner: Uuid,
+        variant_type: &str,
+        bucket_name: &str,
+        object_key: &str,
+        mime_type: &str,
+        file_size_bytes: i64,
+        width: Option<i32>,
+        height: Option<i32>,
+        checksum_sha256: Option<String>,
+        now: chrono::DateTime<chrono::FixedOffset>,
+    ) -> Statement {
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"
+            INSERT INTO media_variants (
+              id, media_id, variant_type,
+              bucket_name, object_key,
+              mime_type, file_size_bytes,
+              width, height,
+              checksum_sha256, created_at
+            )
+            SELECT
+              $12, m.id, $3,
+              $4, $5,
+              $6, $7,
+              $8, $9,
+              $10, $11
+            FROM media m
+            WHERE m.id = $1 AND m.user_id = $2 AND m.deleted_at IS NULL
+            ON CONFLICT (media_id, variant_type) DO UPDATE SET
+              bucket_name = EXCLUDED.bucket_name,
+              object_key = EXCLUDED.object_key,
+              mime_type = EXCLUDED.mime_type,
+              file_size_bytes = EXCLUDED.file_size_bytes,
+              width = EXCLUDED.width,
+              height = EXCLUDED.height,
+              checksum_sha256 = EXCLUDED.checksum_sha256
+            "#,
+            vec![
+                media_id.into(),
+                owner.into(),
+                variant_type.into(),
+                bucket_name.into(),
+                object_key.into(),
+                mime_type.into(),
+                file_size_bytes.into(),
+                width.into(),
+                height.into(),
+                checksum_sha256.into(),
+                now.into(),
+                Uuid::new_v4().into(),
             ],
         )
     }
 
+    /// With `from`, only a media still in that state is updated. The failure
+    /// code is replaced on every change, so it only survives on a `failed`
+    /// media the server failed itself.
+    fn set_media_state_stmt(
+        backend: DatabaseBackend,
+        media_id: Uuid,
+        owner: Uuid,
+        status: &str,
+        failure_code: Option<&str>,
+        from: Option<&str>,
+    ) -> Statement {
+        let mut values = vec![
+            media_id.into(),
+            owner.into(),
+            status.into(),
+            failure_code.map(str::to_string).into(),
+        ];
+        let guard = match from {
+            Some(from) => {
+                values.push(from.into());
+                format!(
+                    "AND status = {}",
+                    sql::pg_cast(backend, "$5", "media_status")
+                )
+            }
+            None => String::new(),
+        };
+
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+            UPDATE media
+            SET status = {status},
+                failure_code = $4,
+                updated_at = {now}
+            WHERE id = $1 AND user_id = $2 AND deleted_at IS NULL {guard}
+            RETURNING updated_at
+            "#,
+                status = sql::pg_cast(backend, "$3", "media_status"),
+                now = sql::now(backend),
+            ),
+            values,
+        )
+    }
+
+    /// `None` when no row matched
+    async fn update_media_state(
+        &self,
+        data: UpdateMediaStateData,
+        failure: Option<MediaFailure>,
+        from: Option<&MediaState>,
+    ) -> Result<Option<MediaStateInfo>, MediaRepositoryError> {
+        let stmt = Self::set_media_state_stmt(
+            self.db.get_database_backend(),
+            data.media_id,
+            data.owner.into(),
+            Self::media_state_to_db_str(&data.status),
+            failure.as_ref().map(MediaFailure::code),
+            from.map(Self::media_state_to_db_str),
+        );
+
+        let Some(row) = self
+            .db
+            .query_one(stmt)
+            .await
+            .map_err(|e| MediaRepositoryError::DatabaseError(e.to_string()))?
+        else {
+            return Ok(None);
+        };
+
+        let updated_at: chrono::DateTime<chrono::FixedOffset> = row
+            .try_get("", "updated_at")
+            .map_err(|e| MediaRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(Some(MediaStateInfo {
+            owner: data.owner,
+            media_id: data.media_id,
+            updated_at: updated_at.to_rfc3339(),
+            status: data.status,
+        }))
+    }
+
+    fn normalize_checksum(checksum: Option<&str>) -> Result<Option<String>, MediaRepositoryError> {
+        match checksum.map(|c| c.trim().to_ascii_lowercase()) {
+            None => Ok(None),
+            Some(c) if c.len() == 64 && c.bytes().all(|b| b.is_ascii_hexdigit()) => Ok(Some(c)),
+            Some(c) => Err(MediaRepositoryError::DatabaseError(format!(
+                "invalid sha256 checksum: {}",
+                c
+            ))),
+        }
+    }
+
     fn map_db_err(e: DbErr) -> RecordMediaError {
         RecordMediaError::DatabaseError(e.to_string())
     }
@@ -152,7 +316,9 @@ impl MediaRepositoryPostgres {
         }
 
         match ext.as_str() {
-            "jpg" | "jpeg" | "png" | "webp" => Ok(format!("{}/{}", media_id, original_name)),
+            "jpg" | "jpeg" | "png" | "webp" | "svg" | "pdf" => {
+                Ok(upload_object_key(&media_id.to_string(), original_name))
+            }
             _ => Err(RecordMediaError::DatabaseError(format!(
                 "invalid extension: {}",
                 ext
@@ -164,6 +330,61 @@ impl MediaRepositoryPostgres {
     // Facade hook: lets tests supply a fake DB/txn
     // =====================================================
 
+    async fn record_variants_with_db<D: MediaDb>(
+        db: &D,
+        data: Vec<MediaVariantRecord>,
+    ) -> Result<Vec<MediaVariant>, MediaRepositoryError> {
+        let map_err = |e: DbErr| MediaRepositoryError::DatabaseError(e.to_string());
+        let now = Utc::now().fixed_offset();
+
+        let mut txn = db.begin().await.map_err(map_err)?;
+        let mut recorded = Vec::with_capacity(data.len());
+
+        for variant in data {
+            let checksum = match Self::normalize_checksum(variant.checksum_sha256.as_deref()) {
+                Ok(c) => c,
+                Err(e) => {
+                    let _ = txn.rollback().await;
+                    return Err(e);
+                }
+            };
+
+            let stmt = Self::upsert_variant_stmt(
+                variant.media_id,
+                variant.owner.into(),
+                &variant.size.to_string(),
+                variant.bucket_name.trim(),
+                variant.object_key.trim(),
+                variant.mime_type.trim(),
+                variant.file_size_bytes as i64,
+                variant.width_px.map(|v| v as i32),
+                variant.height_px.map(|v| v as i32),
+                checksum,
+                now,
+            );
+
+            match txn.execute(stmt).await {
+                Ok(0) => {
+                    let _ = txn.rollback().await;
+                    return Err(MediaRepositoryError::NotFound);
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    let _ = txn.rollback().await;
+                    return Err(map_err(e));
+                }
+            }
+
+            recorded.push(MediaVariant {
+                path: format!("/api/media/{}/{}", variant.media_id, variant.size),
+                size: variant.size,
+            });
+        }
+
+        txn.commit().await.map_err(map_err)?;
+        Ok(recorded)
+    }
+
     async fn record_media_tx_with_db<D: MediaDb>(
         db: &D,
         tx: RecordMediaTx,
@@ -185,6 +406,8 @@ impl MediaRepositoryPostgres {
         let duration_seconds_i64 = tx.media.duration_seconds.map(|v| v as i64);
 
         let status_str = Self::media_state_to_db_str(&tx.media.state);
+        let checksum_sha256 = Self::normalize_checksum(tx.media.checksum_sha256.as_deref())
+            .map_err(|e| RecordMediaError::DatabaseError(e.to_string()))?;
 
         let attachable_type = tx.attachment.attachment_target.to_string();
         let role = tx.attachment.role.to_string();
@@ -199,6 +422,7 @@ impl MediaRepositoryPostgres {
         // insert media
         if let Err(e) = txn
             .execute(Self::insert_media_stmt(
+                db.backend(),
                 media_id,
                 owner_uuid,
                 &bucket_name,
@@ -210,6 +434,7 @@ impl MediaRepositoryPostgres {
                 height_i32,
                 duration_seconds_i64,
                 status_str,
+                checksum_sha256.as_deref(),
                 now,
             ))
             .await
@@ -262,23 +487,53 @@ impl MediaRepository for MediaRepositoryPostgres {
 
     async fn set_media_state(
         &self,
-        _data: UpdateMediaStateData,
+        data: UpdateMediaStateData,
     ) -> Result<MediaStateInfo, MediaRepositoryError> {
-        todo!()
+        self.update_media_state(data, None, None)
+            .await?
+            .ok_or(MediaRepositoryError::NotFound)
+    }
+
+    async fn transition_media_state(
+        &self,
+        data: UpdateMediaStateData,
+        from: MediaState,
+    ) -> Result<Option<MediaStateInfo>, MediaRepositoryError> {
+        self.update_media_state(data, None, Some(&from)).await
+    }
+
+    async fn fail_media(
+        &self,
+        media: &MediaStateInfo,
+        failure: MediaFailure,
+    ) -> Result<Option<MediaStateInfo>, MediaRepositoryError> {
+        let data = UpdateMediaStateData {
+            owner: media.owner,
+            media_id: media.media_id,
+            status: MediaState::Failed,
+        };
+        self.update_media_state(data, Some(failure), Some(&media.status))
+            .await
     }
 
     async fn record_single_variant(
         &self,
-        _data: MediaVariantRecord,
+        data: MediaVariantRecord,
     ) -> Result<MediaVariant, MediaRepositoryError> {
-        todo!()
+        self.record_variants(vec![data])
+            .await?
+            .pop()
+            .ok_or(MediaRepositoryError::NotFound)
     }
 
     async fn record_variants(
         &self,
-        _data: Vec<MediaVariantRecord>,
+        data: Vec<MediaVariantRecord>,
     ) -> Result<Vec<MediaVariant>, MediaRepositoryError> {
-        todo!()
+        let db = SeaOrmDb {
+            db: self.db.clone(),
+        };
+        Self::record_variants_with_db(&db, data).await
     }
 }
 
@@ -289,12 +544,14 @@ impl MediaRepository for MediaRepositoryPostgres {
 #[async_trait]
 trait MediaDb: Send + Sync {
     type Txn: MediaTxn;
+    fn backend(&self) -> DatabaseBackend;
     async fn begin(&self) -> Result<Self::Txn, DbErr>;
 }
 
 #[async_trait]
 trait MediaTxn: Send {
-    async fn execute(&mut self, stmt: Statement) -> Result<(), DbErr>;
+    /// Returns the number of rows affected.
+    async fn execute(&mut self, stmt: Statement) -> Result<u64, DbErr>;
     async fn commit(self) -> Result<(), DbErr>;
     async fn rollback(self) -> Result<(), DbErr>;
 }
@@ -311,17 +568,21 @@ struct SeaOrmTxn {
 impl MediaDb for SeaOrmDb {
     type Txn = SeaOrmTxn;
 
+    fn backend(&self) -> DatabaseBackend {
+        self.db.get_database_backend()
+    }
+
     async fn begin(&self) -> Result<Self::Txn, DbErr> {
-        let txn = self.db.begin().await?;
+        // A savepoint when recording inside a unit of work
+        let txn = connection(&self.db).begin().await?;
         Ok(SeaOrmTxn { txn })
     }
 }
 
 #[async_trait]
 impl MediaTxn for SeaOrmTxn {
-    async fn execute(&mut self, stmt: Statement) -> Result<(), DbErr> {
-        self.txn.execute(stmt).await?;
-        Ok(())
+    async fn execute(&mut self, stmt: Statement) -> Result<u64, DbErr> {
+        Ok(self.txn.execute(stmt).await?.rows_affected())
     }
 
     async fn commit(self) -> Result<(), DbErr> {
@@ -342,14 +603,18 @@ mod tests {
     use super::*;
     use crate::auth::application::domain::entities::UserId;
     use crate::multimedia::application::domain::entities::{
-        AttachmentTarget, MediaRole, MediaState,
+        AttachmentTarget, MediaRole, MediaSize, MediaState,
     };
     use crate::multimedia::application::ports::outgoing::db::{NewMedia, NewMediaAttachment};
+    use sea_orm::{MockDatabase, Value};
+    use std::collections::BTreeMap;
 
     #[derive(Debug)]
     enum Step {
         Begin(Result<(), DbErr>),
         Exec(Result<(), DbErr>),
+        /// Successful execute affecting this many rows
+        ExecRows(u64),
         Commit(Result<(), DbErr>),
         Rollback(Result<(), DbErr>),
     }
@@ -379,6 +644,10 @@ mod tests {
     impl MediaDb for FakeDb {
         type Txn = FakeTxn;
 
+        fn backend(&self) -> DatabaseBackend {
+            DatabaseBackend::Postgres
+        }
+
         async fn begin(&self) -> Result<Self::Txn, DbErr> {
             match self.pop() {
                 Step::Begin(Ok(())) => Ok(FakeTxn {
@@ -392,10 +661,11 @@ mod tests {
 
     #[async_trait]
     impl MediaTxn for FakeTxn {
-        async fn execute(&mut self, _stmt: Statement) -> Result<(), DbErr> {
+        async fn execute(&mut self, _stmt: Statement) -> Result<u64, DbErr> {
             let step = self.steps.lock().unwrap().remove(0);
             match step {
-                Step::Exec(res) => res,
+                Step::Exec(res) => res.map(|_| 1),
+                Step::ExecRows(rows) => Ok(rows),
                 other => panic!("Expected Step::Exec, got: {:?}", other),
             }
         }
@@ -431,6 +701,7 @@ mod tests {
                 width_px: Some(400),
                 height_px: Some(300),
                 duration_seconds: None,
+                checksum_sha256: None,
             },
             attachment: NewMediaAttachment {
                 owner: UserId::from(Uuid::new_v4()),
@@ -553,4 +824,182 @@ mod tests {
             RecordMediaError::DatabaseError(msg) => assert!(msg.contains("commit failed")),
         }
     }
+
+    fn make_variant(media_id: Uuid, size: MediaSize, checksum: Option<&str>) -> MediaVariantRecord {
+        MediaVariantRecord {
+            owner: UserId::from(Uuid::new_v4()),
+            media_id,
+            size,
+            bucket_name: "blogport-cms-ready".to_string(),
+            object_key: format!("variants/{}/cat_150.webp", media_id),
+            mime_type: "image/webp".to_string(),
+            file_size_bytes: 2048,
+            width_px: Some(150),
+            height_px: Some(150),
+            checksum_sha256: checksum.map(String::from),
+        }
+    }
+
+    const CHECKSUM: &str = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+    #[tokio::test]
+    async fn test_record_variants_success() {
+        let db = FakeDb::new(vec![
+            Step::Begin(Ok(())),
+            Step::Exec(Ok(())), // thumbnail
+            Step::Exec(Ok(())), // small
+            Step::Commit(Ok(())),
+        ]);
+
+        let media_id = Uuid::new_v4();
+        let data = vec![
+            make_variant(media_id, MediaSize::Thumbnail, Some(CHECKSUM)),
+            make_variant(media_id, MediaSize::Small, None),
+        ];
+
+        let recorded = MediaRepositoryPostgres::record_variants_with_db(&db, data)
+            .await
+            .unwrap();
+
+        assert_eq!(recorded.len(), 2);
+        assert_eq!(
+            recorded[0].path,
+            format!("/api/media/{}/thumbnail", media_id)
+        );
+        assert_eq!(recorded[1].path, format!("/api/media/{}/small", media_id));
+    }
+
+    #[tokio::test]
+    async fn test_record_variants_not_owned_rolls_back() {
+        let db = FakeDb::new(vec![
+            Step::Begin(Ok(())),
+            Step::ExecRows(0), // media missing or owned by someone else
+            Step::Rollback(Ok(())),
+        ]);
+
+        let data = vec![make_variant(
+            Uuid::new_v4(),
+            MediaSize::Large,
+            Some(CHECKSUM),
+        )];
+        let err = MediaRepositoryPostgres::record_variants_with_db(&db, data)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, MediaRepositoryError::NotFound));
+    }
+
+    #[tokio::test]
+    async fn test_record_variants_invalid_checksum_rolls_back() {
+        let db = FakeDb::new(vec![Step::Begin(Ok(())), Step::Rollback(Ok(()))]);
+
+        let data = vec![make_variant(
+            Uuid::new_v4(),
+            MediaSize::Medium,
+            Some("abc123"),
+        )];
+        let err = MediaRepositoryPostgres::record_variants_with_db(&db, data)
+            .await
+            .unwrap_err();
+
+        match err {
+            MediaRepositoryError::DatabaseError(msg) => assert!(msg.contains("checksum")),
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_set_media_state_returns_new_state() {
+        let updated_at = Utc::now().fixed_offset();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![BTreeMap::from([(
+                "updated_at".to_string(),
+                Value::ChronoDateTimeWithTimeZone(Some(Box::new(updated_at))),
+            )])]])
+            .into_connection();
+
+        let repo = MediaRepositoryPostgres::new(Arc::new(db));
+        let media_id = Uuid::new_v4();
+        let info = repo
+            .set_media_state(UpdateMediaStateData {
+                owner: UserId::from(Uuid::new_v4()),
+                media_id,
+                status: MediaState::Ready,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(info.media_id, media_id);
+        assert_eq!(info.status, MediaState::Ready);
+        assert_eq!(info.updated_at, updated_at.to_rfc3339());
+    }
+
+    #[tokio::test]
+    async fn test_set_media_state_not_found() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![Vec::<BTreeMap<String, Value>>::new()])
+            .into_connection();
+
+        let repo = MediaRepositoryPostgres::new(Arc::new(db));
+        let err = repo
+            .set_media_state(UpdateMediaStateData {
+                owner: UserId::from(Uuid::new_v4()),
+                media_id: Uuid::new_v4(),
+                status: MediaState::Failed,
+            })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, MediaRepositoryError::NotFound));
+    }
+
+    #[tokio::test]
+    async fn test_transition_media_state_in_another_state_is_none() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![Vec::<BTreeMap<String, Value>>::new()])
+            .into_connection();
+
+        let repo = MediaRepositoryPostgres::new(Arc::new(db));
+        let info = repo
+            .transition_media_state(
+                UpdateMediaStateData {
+                    owner: UserId::from(Uuid::new_v4()),
+                    media_id: Uuid::new_v4(),
+                    status: MediaState::Processing,
+                },
+                MediaState::Pending,
+            )
+            .await
+            .unwrap();
+
+        assert!(info.is_none());
+    }
+
+    #[test]
+    fn test_fail_stmt_binds_the_failure_code() {
+        let stmt = MediaRepositoryPostgres::set_media_state_stmt(
+            DatabaseBackend::Postgres,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "failed",
+            Some(MediaFailure::ProcessingTimeout.code()),
+            Some("processing"),
+        );
+
+        assert!(stmt.sql.contains("failure_code = $4"));
+        assert!(stmt.sql.contains("AND status = $5::media_status"));
+        let values = stmt.values.unwrap().0;
+        assert_eq!(values[3], Value::from("PROCESSING_TIMEOUT".to_string()));
+        assert_eq!(values[4], Value::from("processing"));
+    }
+
+    #[test]
+    fn test_normalize_checksum_lowercases() {
+        let upper = CHECKSUM.to_ascii_uppercase();
+        let normalized = MediaRepositoryPostgres::normalize_checksum(Some(&upper)).unwrap();
+        assert_eq!(normalized.as_deref(), Some(CHECKSUM));
+        assert!(MediaRepositoryPostgres::normalize_checksum(None)
+            .unwrap()
+            .is_none());
+    }
 }
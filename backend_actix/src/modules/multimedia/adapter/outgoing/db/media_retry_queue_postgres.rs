@@ -0,0 +1,310 @@
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, Utc};
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::multimedia::application::{
+    domain::entities::{MediaState, MediaStateInfo},
+    ports::outgoing::db::{MediaRepositoryError, MediaRetryQueue, QueuedFailure},
+};
+use crate::shared::sql;
+
+#[derive(Clone)]
+pub struct MediaRetryQueuePostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl MediaRetryQueuePostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    /// Same rules as the status updater's insert
+    fn file_stmt(
+        backend: DatabaseBackend,
+        media_id: Uuid,
+        failure_code: &str,
+        failed_at: DateTime<Utc>,
+    ) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            INSERT INTO media_retries (media_id, failure_code, failed_at)
+            SELECT id, $2, $3
+            FROM media
+            WHERE id = $1
+              AND deleted_at IS NULL
+              AND status NOT IN ('ready', 'failed')
+              AND updated_at <= $3
+            ON CONFLICT (media_id)
+            DO UPDATE SET
+                failure_code = EXCLUDED.failure_code,
+                failed_at = EXCLUDED.failed_at
+            "#,
+            vec![
+                media_id.into(),
+                failure_code.into(),
+                failed_at.fixed_offset().into(),
+            ],
+        )
+    }
+
+    fn waiting_stmt(backend: DatabaseBackend, limit: u64) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT
+                m.user_id,
+                m.id AS media_id,
+                m.updated_at,
+                CAST(m.status AS TEXT) AS status,
+                m.bucket_name,
+                m.object_key,
+                r.failure_code,
+                r.attempts,
+                r.failed_at
+            FROM media_retries r
+            INNER JOIN media m ON m.id = r.media_id
+            WHERE r.failed_at IS NOT NULL
+              AND m.status IN ('pending', 'processing')
+              AND m.deleted_at IS NULL
+            ORDER BY r.failed_at ASC
+            LIMIT $1
+            "#,
+            vec![(limit as i64).into()],
+        )
+    }
+
+    /// `$2` attempts; `failed_at` cleared once a retry is out, reset to now
+    /// when it could not be sent
+    fn attempt_stmt(
+        backend: DatabaseBackend,
+        media_id: Uuid,
+        attempts: u32,
+        sent: bool,
+    ) -> Statement {
+        let now = sql::now(backend);
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                UPDATE media_retries
+                SET attempts = $2,
+                    last_attempt_at = {now},
+                    failed_at = {failed_at}
+                WHERE media_id = $1
+                "#,
+                failed_at = if sent { "NULL" } else { now },
+            ),
+            vec![media_id.into(), (attempts as i32).into()],
+        )
+    }
+
+    fn remove_stmt(backend: DatabaseBackend, media_id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            "DELETE FROM media_retries WHERE media_id = $1",
+            vec![media_id.into()],
+        )
+    }
+
+    // =====================================================
+    // Mapping
+    // =====================================================
+
+    fn to_failure(row: &QueryResult) -> Result<QueuedFailure, DbErr> {
+        let user_id: Uuid = row.try_get("", "user_id")?;
+        let updated_at: DateTime<FixedOffset> = row.try_get("", "updated_at")?;
+        let status: String = row.try_get("", "status")?;
+        let attempts: i32 = row.try_get("", "attempts")?;
+        let failed_at: DateTime<FixedOffset> = row.try_get("", "failed_at")?;
+
+        Ok(QueuedFailure {
+            media: MediaStateInfo {
+                owner: UserId::from(user_id),
+                media_id: row.try_get("", "media_id")?,
+                updated_at: updated_at.to_rfc3339(),
+                status: match status.as_str() {
+                    "pending" => MediaState::Pending,
+                    "processing" => MediaState::Processing,
+                    other => {
+                        return Err(DbErr::Custom(format!(
+                            "unexpected media state in retry queue: {other}"
+                        )))
+                    }
+                },
+            },
+            bucket_name: row.try_get("", "bucket_name")?,
+            object_key: row.try_get("", "object_key")?,
+            failure_code: row.try_get("", "failure_code")?,
+            attempts: attempts.max(0) as u32,
+            failed_at: failed_at.with_timezone(&Utc),
+        })
+    }
+
+    fn map_db_err(e: DbErr) -> MediaRepositoryError {
+        MediaRepositoryError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl MediaRetryQueue for MediaRetryQueuePostgres {
+    async fn file(
+        &self,
+        media_id: Uuid,
+        failure_code: &str,
+        failed_at: DateTime<Utc>,
+    ) -> Result<bool, MediaRepositoryError> {
+        let backend = self.db.get_database_backend();
+        let result = self
+            .db
+            .execute(Self::file_stmt(backend, media_id, failure_code, failed_at))
+            .await
+            .map_err(Self::map_db_err)?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn waiting(&self, limit: u64) -> Result<Vec<QueuedFailure>, MediaRepositoryError> {
+        let backend = self.db.get_database_backend();
+        self.db
+            .query_all(Self::waiting_stmt(backend, limit))
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(|row| Self::to_failure(row).map_err(Self::map_db_err))
+            .collect()
+    }
+
+    async fn mark_sent(&self, media_id: Uuid, attempts: u32) -> Result<(), MediaRepositoryError> {
+        let backend = self.db.get_database_backend();
+        self.db
+            .execute(Self::attempt_stmt(backend, media_id, attempts, true))
+            .await
+            .map_err(Self::map_db_err)?;
+        Ok(())
+    }
+
+    async fn postpone(&self, media_id: Uuid, attempts: u32) -> Result<(), MediaRepositoryError> {
+        let backend = self.db.get_database_backend();
+        self.db
+            .execute(Self::attempt_stmt(backend, media_id, attempts, false))
+            .await
+            .map_err(Self::map_db_err)?;
+        Ok(())
+    }
+
+    async fn remove(&self, media_id: Uuid) -> Result<(), MediaRepositoryError> {
+        let backend = self.db.get_database_backend();
+        self.db
+            .execute(Self::remove_stmt(backend, media_id))
+            .await
+            .map_err(Self::map_db_err)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{MockDatabase, MockExecResult, Value};
+    use std::collections::BTreeMap;
+
+    fn row(media_id: Uuid, status: &str) -> BTreeMap<String, Value> {
+        let at = DateTime::parse_from_rfc3339("2026-10-16T10:00:00+00:00").unwrap();
+        BTreeMap::from([
+            ("user_id".to_string(), Value::from(Uuid::new_v4())),
+            ("media_id".to_string(), Value::from(media_id)),
+            ("updated_at".to_string(), Value::from(at)),
+            ("status".to_string(), Value::from(status)),
+            ("bucket_name".to_string(), Value::from("uploads")),
+            (
+                "object_key".to_string(),
+                Value::from(format!("{media_id}.png")),
+            ),
+            ("failure_code".to_string(), Value::from("DOWNLOAD_ERROR")),
+            ("attempts".to_string(), Value::Int(Some(2))),
+            ("failed_at".to_string(), Value::from(at)),
+        ])
+    }
+
+    #[tokio::test]
+    async fn test_waiting_rows_become_failures() {
+        let media_id = Uuid::new_v4();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![row(media_id, "processing")]])
+            .into_connection();
+        let queue = MediaRetryQueuePostgres::new(Arc::new(db));
+
+        let waiting = queue.waiting(10).await.unwrap();
+
+        assert_eq!(waiting.len(), 1);
+        assert_eq!(waiting[0].media.media_id, media_id);
+        assert_eq!(waiting[0].media.status, MediaState::Processing);
+        assert_eq!(waiting[0].failure_code, "DOWNLOAD_ERROR");
+        assert_eq!(waiting[0].attempts, 2);
+        assert_eq!(waiting[0].object_key, format!("{media_id}.png"));
+    }
+
+    #[test]
+    fn test_sent_retry_stops_waiting() {
+        let media_id = Uuid::new_v4();
+
+        let sent =
+            MediaRetryQueuePostgres::attempt_stmt(DatabaseBackend::Postgres, media_id, 1, true);
+        let postponed =
+            MediaRetryQueuePostgres::attempt_stmt(DatabaseBackend::Postgres, media_id, 1, false);
+
+        assert!(sent.sql.contains("failed_at = NULL"));
+        assert!(postponed.sql.contains("failed_at = NOW()"));
+        assert_eq!(sent.values.unwrap().0[1], Value::Int(Some(1)));
+    }
+
+    #[tokio::test]
+    async fn test_filing_reports_whether_the_media_qualified() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results(vec![
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 0,
+                },
+            ])
+            .into_connection();
+        let queue = MediaRetryQueuePostgres::new(Arc::new(db));
+
+        let media_id = Uuid::new_v4();
+        assert!(queue
+            .file(media_id, "DOWNLOAD_ERROR", Utc::now())
+            .await
+            .unwrap());
+        assert!(!queue
+            .file(media_id, "DOWNLOAD_ERROR", Utc::now())
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_database_error_is_mapped() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_errors(vec![DbErr::Custom("connection lost".into())])
+            .into_connection();
+        let queue = MediaRetryQueuePostgres::new(Arc::new(db));
+
+        let err = queue.remove(Uuid::new_v4()).await.unwrap_err();
+
+        assert!(
+            matches!(err, MediaRepositoryError::DatabaseError(msg) if msg.contains("connection lost"))
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement, Value,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::multimedia::application::{
+    domain::entities::MediaState,
+    ports::outgoing::db::{MediaRepositoryError, MediaUploadBatchRepository, UploadBatchItem},
+};
+use crate::shared::unit_of_work::connection;
+
+#[derive(Clone)]
+pub struct MediaUploadBatchPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl MediaUploadBatchPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    /// One row per media; `$1` is the batch, the media ids follow in order
+    fn insert_stmt(backend: DatabaseBackend, batch_id: Uuid, media_ids: &[Uuid]) -> Statement {
+        let rows = (0..media_ids.len())
+            .map(|i| format!("($1, ${}, {i})", i + 2))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let mut values: Vec<Value> = vec![batch_id.into()];
+        values.extend(media_ids.iter().map(|id| Value::from(*id)));
+
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                "INSERT INTO media_upload_batches (batch_id, media_id, position) VALUES {rows}"
+            ),
+            values,
+        )
+    }
+
+    fn items_stmt(backend: DatabaseBackend, owner: Uuid, batch_id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT
+                m.id AS media_id,
+                m.original_filename,
+                CAST(m.status AS TEXT) AS status,
+                m.failure_code
+            FROM media_upload_batches b
+            INNER JOIN media m ON m.id = b.media_id
+            WHERE b.batch_id = $1
+              AND m.user_id = $2
+              AND m.deleted_at IS NULL
+            ORDER BY b.position ASC
+            "#,
+            vec![batch_id.into(), owner.into()],
+        )
+    }
+
+    // =====================================================
+    // Mapping
+    // =====================================================
+
+    fn to_item(row: &QueryResult) -> Result<UploadBatchItem, DbErr> {
+        let status: String = row.try_get("", "status")?;
+
+        Ok(UploadBatchItem {
+            media_id: row.try_get("", "media_id")?,
+            original_filename: row.try_get("", "original_filename")?,
+            status: match status.as_str() {
+                "pending" => MediaState::Pending,
+                "processing" => MediaState::Processing,
+                "ready" => MediaState::Ready,
+                "failed" => MediaState::Failed,
+                other => return Err(DbErr::Custom(format!("invalid media state: {other}"))),
+            },
+            failure_code: row.try_get("", "failure_code")?,
+        })
+    }
+
+    fn map_db_err(e: DbErr) -> MediaRepositoryError {
+        MediaRepositoryError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl MediaUploadBatchRepository for MediaUploadBatchPostgres {
+    async fn record_batch(
+        &self,
+        batch_id: Uuid,
+        media_ids: &[Uuid],
+    ) -> Result<(), MediaRepositoryError> {
+        if media_ids.is_empty() {
+            return Ok(());
+        }
+
+        // Joins the unit of work the media rows were written in
+        let db = connection(&self.db);
+        db.execute(Self::insert_stmt(
+            db.get_database_backend(),
+            batch_id,
+            media_ids,
+        ))
+        .await
+        .map_err(Self::map_db_err)?;
+        Ok(())
+    }
+
+    async fn batch_items(
+        &self,
+        owner: UserId,
+        batch_id: Uuid,
+    ) -> Result<Vec<UploadBatchItem>, MediaRepositoryError> {
+        let backend = self.db.get_database_backend();
+        self.db
+            .query_all(Self::items_stmt(backend, owner.value(), batch_id))
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(|row| Self::to_item(row).map_err(Self::map_db_err))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::MockDatabase;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_insert_keeps_request_order() {
+        let batch_id = Uuid::new_v4();
+        let media_ids = [Uuid::new_v4(), Uuid::new_v4()];
+
+        let stmt =
+            MediaUploadBatchPostgres::insert_stmt(DatabaseBackend::Postgres, batch_id, &media_ids);
+
+        assert!(stmt.sql.ends_with("VALUES ($1, $2, 0), ($1, $3, 1)"));
+        let values = stmt.values.unwrap().0;
+        assert_eq!(values[0], Value::from(batch_id));
+        assert_eq!(values[2], Value::from(media_ids[1]));
+    }
+
+    #[tokio::test]
+    async fn test_rows_become_items() {
+        let media_id = Uuid::new_v4();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![BTreeMap::from([
+                ("media_id".to_string(), Value::from(media_id)),
+                ("original_filename".to_string(), Value::from("shot-1.png")),
+                ("status".to_string(), Value::from("failed")),
+                ("failure_code".to_string(), Value::from("DECODE_FAILED")),
+            ])]])
+            .into_connection();
+        let batches = MediaUploadBatchPostgres::new(Arc::new(db));
+
+        let items = batches
+            .batch_items(UserId::from(Uuid::new_v4()), Uuid::new_v4())
+            .await
+            .unwrap();
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].media_id, media_id);
+        assert_eq!(items[0].status, MediaState::Failed);
+        assert_eq!(items[0].failure_code.as_deref(), Some("DECODE_FAILED"));
+    }
+}
@@ -1,6 +1,16 @@
+mod in_memory;
+mod legacy_screenshot_store_postgres;
 mod media_query_postgres;
 mod media_repository_postgres;
+mod media_retry_queue_postgres;
+mod media_upload_batch_postgres;
 pub mod sea_orm_entity;
+mod storage_override_store_postgres;
 
+pub use in_memory::{InMemoryMediaStore, InMemoryStorageOverrides};
+pub use legacy_screenshot_store_postgres::LegacyScreenshotStorePostgres;
 pub use media_query_postgres::MediaQueryPostgres;
 pub use media_repository_postgres::MediaRepositoryPostgres;
+pub use media_retry_queue_postgres::MediaRetryQueuePostgres;
+pub use media_upload_batch_postgres::MediaUploadBatchPostgres;
+pub use storage_override_store_postgres::StorageOverrideStorePostgres;
@@ -21,6 +21,10 @@ pub struct Model {
     pub duration_seconds: Option<Decimal>,
 
     pub status: MediaStatus,
+    /// Why the server failed the media, e.g. `PROCESSING_TIMEOUT`
+    pub failure_code: Option<String>,
+    /// Lowercase hex SHA-256 of the original, as declared by the client
+    pub checksum_sha256: Option<String>,
 
     pub metadata: Json,
 
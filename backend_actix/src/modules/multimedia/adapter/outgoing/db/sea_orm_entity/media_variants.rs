@@ -20,6 +20,9 @@ pub struct Model {
     pub width: Option<i32>,
     pub height: Option<i32>,
 
+    /// Lowercase hex SHA-256 reported by the image processor
+    pub checksum_sha256: Option<String>,
+
     pub created_at: DateTimeWithTimeZone,
 }
 
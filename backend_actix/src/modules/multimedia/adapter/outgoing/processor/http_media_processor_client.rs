@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use std::time::Duration;
+
+use crate::multimedia::application::ports::outgoing::processor::{
+    MediaProcessorClient, MediaProcessorError, ReprocessRequest,
+};
+
+/// The processor only queues the work, so it should answer quickly
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Calls `POST {base_url}/reprocess` on the image processor
+pub struct HttpMediaProcessorClient {
+    client: reqwest::Client,
+    reprocess_url: String,
+    token: Option<String>,
+}
+
+impl HttpMediaProcessorClient {
+    pub fn new(base_url: &str, token: Option<String>) -> Result<Self, MediaProcessorError> {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .user_agent(concat!("port-blog-cms/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| MediaProcessorError::Failed(e.to_string()))?;
+
+        Ok(Self {
+            client,
+            reprocess_url: reprocess_url(base_url),
+            token,
+        })
+    }
+}
+
+/// `https://processor.internal/` -> `https://processor.internal/reprocess`
+fn reprocess_url(base_url: &str) -> String {
+    format!("{}/reprocess", base_url.trim_end_matches('/'))
+}
+
+#[async_trait]
+impl MediaProcessorClient for HttpMediaProcessorClient {
+    async fn reprocess(&self, request: &ReprocessRequest) -> Result<(), MediaProcessorError> {
+        let body =
+            serde_json::to_vec(request).map_err(|e| MediaProcessorError::Failed(e.to_string()))?;
+
+        let mut call = self
+            .client
+            .post(&self.reprocess_url)
+            .header("content-type", "application/json")
+            .body(body);
+        if let Some(token) = &self.token {
+            call = call.bearer_auth(token);
+        }
+        let response = call
+            .send()
+            .await
+            .map_err(|e| MediaProcessorError::Failed(e.to_string()))?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let body = response.text().await.unwrap_or_default();
+        Err(MediaProcessorError::Failed(format!(
+            "processor answered HTTP {}: {}",
+            status.as_u16(),
+            body
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_reprocess_url_joins_base() {
+        assert_eq!(
+            reprocess_url("https://processor.internal/"),
+            "https://processor.internal/reprocess"
+        );
+        assert_eq!(
+            reprocess_url("http://localhost:9000/api"),
+            "http://localhost:9000/api/reprocess"
+        );
+    }
+
+    #[test]
+    fn test_request_body_omits_missing_widths() {
+        let request = ReprocessRequest {
+            media_id: Uuid::nil(),
+            bucket_name: "blogport-cms-upload".to_string(),
+            object_key: "media/abc.png".to_string(),
+            widths: None,
+        };
+
+        let body = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(body["bucket_name"], "blogport-cms-upload");
+        assert!(body.get("widths").is_none());
+    }
+}
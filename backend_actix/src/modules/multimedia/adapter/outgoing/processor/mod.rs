@@ -0,0 +1,5 @@
+mod http_media_processor_client;
+mod unconfigured;
+
+pub use http_media_processor_client::HttpMediaProcessorClient;
+pub use unconfigured::UnconfiguredMediaProcessor;
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::multimedia::application::ports::outgoing::processor::{
+    MediaProcessorClient, MediaProcessorError, ReprocessRequest,
+};
+
+/// Stands in when `IMAGE_PROCESSOR_URL` is unset, and in standalone mode,
+/// where no image processor runs
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnconfiguredMediaProcessor;
+
+#[async_trait]
+impl MediaProcessorClient for UnconfiguredMediaProcessor {
+    async fn reprocess(&self, _request: &ReprocessRequest) -> Result<(), MediaProcessorError> {
+        Err(MediaProcessorError::NotConfigured)
+    }
+}
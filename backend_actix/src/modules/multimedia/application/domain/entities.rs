@@ -1,10 +1,16 @@
 use serde::{Deserialize, Serialize};
+use shared_domain::error_codes;
+use shared_domain::variants::VariantSize;
 use std::fmt;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// Manifest layout this server reads, the image processor's `PIPELINE_VERSION`
+pub const EXPECTED_PIPELINE_VERSION: &str = shared_domain::manifest::PIPELINE_VERSION;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum MediaState {
     Pending,
@@ -21,7 +27,33 @@ pub struct MediaStateInfo {
     pub status: MediaState,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// Why the server marked a media `failed`, kept as its `failure_code`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaFailure {
+    /// `processing` for longer than the deadline with no result
+    ProcessingTimeout,
+    /// The image processor's error code, once retries were ruled out or used up
+    Processor(&'static str),
+}
+impl MediaFailure {
+    /// Unknown codes become `INTERNAL_ERROR`
+    pub fn from_processor_code(code: &str) -> Self {
+        let code = error_codes::ALL
+            .into_iter()
+            .find(|known| *known == code)
+            .unwrap_or(error_codes::INTERNAL_ERROR);
+        MediaFailure::Processor(code)
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            MediaFailure::ProcessingTimeout => error_codes::PROCESSING_TIMEOUT,
+            MediaFailure::Processor(code) => code,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum MediaSize {
     Thumbnail,
@@ -31,13 +63,27 @@ pub enum MediaSize {
 }
 impl fmt::Display for MediaSize {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let s = match self {
-            MediaSize::Thumbnail => "thumbnail",
-            MediaSize::Small => "small",
-            MediaSize::Medium => "medium",
-            MediaSize::Large => "large",
-        };
-        write!(f, "{}", s)
+        write!(f, "{}", VariantSize::from(self.clone()).as_str())
+    }
+}
+impl From<MediaSize> for VariantSize {
+    fn from(size: MediaSize) -> Self {
+        match size {
+            MediaSize::Thumbnail => VariantSize::Thumbnail,
+            MediaSize::Small => VariantSize::Small,
+            MediaSize::Medium => VariantSize::Medium,
+            MediaSize::Large => VariantSize::Large,
+        }
+    }
+}
+impl From<VariantSize> for MediaSize {
+    fn from(size: VariantSize) -> Self {
+        match size {
+            VariantSize::Thumbnail => MediaSize::Thumbnail,
+            VariantSize::Small => MediaSize::Small,
+            VariantSize::Medium => MediaSize::Medium,
+            VariantSize::Large => MediaSize::Large,
+        }
     }
 }
 
@@ -48,7 +94,7 @@ pub struct MediaVariant {
     pub path: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub enum MediaRole {
     Avatar,
     #[default]
@@ -72,7 +118,7 @@ impl fmt::Display for MediaRole {
     }
 }
 
-#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
 pub enum AttachmentTarget {
     User,
     #[default]
@@ -92,3 +138,20 @@ impl fmt::Display for AttachmentTarget {
         write!(f, "{s}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_media_size_names_match_the_processor_variants() {
+        for size in VariantSize::ALL {
+            let media_size = MediaSize::from(size);
+            assert_eq!(media_size.to_string(), size.as_str());
+            assert_eq!(
+                serde_json::to_value(&media_size).unwrap(),
+                serde_json::to_value(size).unwrap()
+            );
+        }
+    }
+}
@@ -1 +1,2 @@
+pub mod retry_policy;
 pub mod upload_policy;
@@ -0,0 +1,84 @@
+use chrono::Duration;
+use shared_domain::error_codes;
+
+/// Longest wait between two retries, however many came before
+const MAX_DELAY_HOURS: i64 = 6;
+
+/// How many times, and how soon, a failure of the image processor is retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    /// Wait before the first retry; it doubles for each one after
+    pub base_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Policy for a failed manifest's `error.code`, or `None` when another
+    /// run cannot help: the upload is invalid, too large or was rejected.
+    /// No policy allows more than `max_attempts`.
+    pub fn for_code(code: &str, max_attempts: u32) -> Option<Self> {
+        let (attempts, base_delay) = match code {
+            // Storage hiccups
+            error_codes::DOWNLOAD_ERROR | error_codes::UPLOAD_ERROR => {
+                (max_attempts, Duration::minutes(1))
+            }
+            // The moderation service was unreachable; give it time to recover
+            error_codes::MODERATION_ERROR => (max_attempts, Duration::minutes(5)),
+            // Possibly a bug; one more run rules out a fluke
+            error_codes::INTERNAL_ERROR => (max_attempts.min(1), Duration::minutes(1)),
+            _ => return None,
+        };
+
+        (attempts > 0).then_some(Self {
+            max_attempts: attempts,
+            base_delay,
+        })
+    }
+
+    /// Whether another retry is due after `attempts` of them
+    pub fn allows(&self, attempts: u32) -> bool {
+        attempts < self.max_attempts
+    }
+
+    /// Wait after a failure before the retry following `attempts` of them
+    pub fn delay(&self, attempts: u32) -> Duration {
+        (self.base_delay * 2i32.pow(attempts.min(16))).min(Duration::hours(MAX_DELAY_HOURS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_uploads_are_not_retried() {
+        for code in [
+            error_codes::INVALID_TYPE,
+            error_codes::MAX_SIZE_EXCEEDED,
+            error_codes::DECODE_FAILED,
+            error_codes::CONTENT_REJECTED,
+            "SOMETHING_NEW",
+        ] {
+            assert_eq!(RetryPolicy::for_code(code, 3), None, "{code}");
+        }
+    }
+
+    #[test]
+    fn test_transient_failures_back_off() {
+        let policy = RetryPolicy::for_code(error_codes::DOWNLOAD_ERROR, 3).unwrap();
+
+        assert!(policy.allows(2));
+        assert!(!policy.allows(3));
+        assert_eq!(policy.delay(0), Duration::minutes(1));
+        assert_eq!(policy.delay(2), Duration::minutes(4));
+        assert_eq!(policy.delay(30), Duration::hours(MAX_DELAY_HOURS));
+    }
+
+    #[test]
+    fn test_internal_errors_are_retried_once() {
+        let policy = RetryPolicy::for_code(error_codes::INTERNAL_ERROR, 3).unwrap();
+        assert_eq!(policy.max_attempts, 1);
+
+        assert_eq!(RetryPolicy::for_code(error_codes::UPLOAD_ERROR, 0), None);
+    }
+}
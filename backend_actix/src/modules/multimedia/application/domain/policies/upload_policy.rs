@@ -1,16 +1,22 @@
+use shared_domain::buckets::DEFAULT_UPLOAD_BUCKET;
+use shared_domain::limits;
+
 #[derive(Debug, Clone)]
 pub struct UploadPolicy {
     pub max_file_size_bytes: u64,
+    /// Separate cap for documents (PDF), which are routinely larger than images.
+    pub max_document_size_bytes: u64,
     pub max_width_height_px: u32,
+    /// Checked by the processor only; the declared size may be missing
+    pub max_total_pixels: u64,
     pub max_file_name_len: usize,
     pub allowed_mime_types: &'static [&'static str],
     pub bucket_name: String,
 }
 
 impl UploadPolicy {
-    pub const DEFAULT_BUCKET_NAME: &'static str = "blogport-cms-upload";
-    pub const DEFAULT_ALLOWED_MIME_TYPES: &'static [&'static str] =
-        &["image/jpeg", "image/png", "image/webp"];
+    pub const DEFAULT_BUCKET_NAME: &'static str = DEFAULT_UPLOAD_BUCKET;
+    pub const DEFAULT_ALLOWED_MIME_TYPES: &'static [&'static str] = limits::ALLOWED_MIME_TYPES;
 
     /// Load policy with `bucket_name` from env var, fallback to "blogport-cms-upload".
     ///
@@ -22,14 +28,25 @@ impl UploadPolicy {
             .unwrap_or_else(|| Self::DEFAULT_BUCKET_NAME.to_string());
 
         Self {
-            max_file_size_bytes: 5 * 1024 * 1024, // 5MB
-            max_width_height_px: 6000,
+            max_file_size_bytes: limits::MAX_IMAGE_BYTES,
+            max_document_size_bytes: limits::MAX_DOCUMENT_BYTES,
+            max_width_height_px: limits::MAX_DIMENSION,
+            max_total_pixels: limits::MAX_TOTAL_PIXELS,
             max_file_name_len: 255,
             allowed_mime_types: Self::DEFAULT_ALLOWED_MIME_TYPES,
             bucket_name,
         }
     }
 
+    /// Size cap for the given mime type.
+    pub fn max_size_for(&self, mime_type: &str) -> u64 {
+        if mime_type == "application/pdf" {
+            self.max_document_size_bytes
+        } else {
+            self.max_file_size_bytes
+        }
+    }
+
     /// Handy for unit tests or custom wiring (no env reads).
     pub fn new(bucket_name: String) -> Self {
         Self {
@@ -41,8 +58,10 @@ impl UploadPolicy {
     /// Internal helper to keep construction consistent without repeating values.
     fn from_env_with_bucket_fallback(fallback: &str) -> Self {
         Self {
-            max_file_size_bytes: 5 * 1024 * 1024,
-            max_width_height_px: 6000,
+            max_file_size_bytes: limits::MAX_IMAGE_BYTES,
+            max_document_size_bytes: limits::MAX_DOCUMENT_BYTES,
+            max_width_height_px: limits::MAX_DIMENSION,
+            max_total_pixels: limits::MAX_TOTAL_PIXELS,
             max_file_name_len: 255,
             allowed_mime_types: Self::DEFAULT_ALLOWED_MIME_TYPES,
             bucket_name: fallback.to_string(),
@@ -1,12 +1,126 @@
 use std::sync::Arc;
 
+use crate::multimedia::application::ports::incoming::services::{
+    CreateUploadBatchService, CreateUploadMediaUrlService, DeleteStorageOverrideService,
+    FinalizeUploadService, GetPreviewImageService, GetStorageOverrideService,
+    GetUploadBatchService, GetVariantReadUrlService, GetVariantReadUrlsService, ListMediaService,
+    RegenerateMediaService, SetStorageOverrideService,
+};
 use crate::multimedia::application::ports::incoming::use_cases::{
-    CreateUploadMediaUrlUseCase, GetVariantReadUrlUseCase, ListMediaUseCase,
+    CreateUploadBatchUseCase, CreateUploadMediaUrlUseCase, DeleteStorageOverrideUseCase,
+    FinalizeUploadUseCase, GetPreviewImageUseCase, GetStorageOverrideUseCase,
+    GetUploadBatchUseCase, GetVariantReadUrlUseCase, GetVariantReadUrlsUseCase,
+    IngestMediaManifestUseCase, ListMediaUseCase, ProxyImageUseCase, RegenerateMediaUseCase,
+    SetStorageOverrideUseCase,
+};
+use crate::multimedia::application::ports::outgoing::{
+    cloud_storage::StorageQuery,
+    db::{MediaQuery, MediaRepository, MediaUploadBatchRepository, StorageOverrideStore},
+    processor::MediaProcessorClient,
 };
+use crate::shared::metrics::Metered;
+use crate::shared::rate_limit::RateLimiter;
+use crate::shared::unit_of_work::UnitOfWork;
 
 #[derive(Clone)]
 pub struct MultimediaUseCases {
     pub create_signed_post_url: Arc<dyn CreateUploadMediaUrlUseCase + Send + Sync>,
+    pub create_upload_batch: Arc<dyn CreateUploadBatchUseCase + Send + Sync>,
+    pub get_upload_batch: Arc<dyn GetUploadBatchUseCase + Send + Sync>,
+    pub finalize_upload: Arc<dyn FinalizeUploadUseCase + Send + Sync>,
     pub create_signed_get_url: Arc<dyn GetVariantReadUrlUseCase + Send + Sync>,
+    pub create_signed_get_urls: Arc<dyn GetVariantReadUrlsUseCase + Send + Sync>,
+    /// Public: link previews of projects
+    pub get_preview_image: Arc<dyn GetPreviewImageUseCase + Send + Sync>,
     pub list_media: Arc<dyn ListMediaUseCase + Send + Sync>,
+    pub regenerate_media: Arc<dyn RegenerateMediaUseCase + Send + Sync>,
+    pub proxy_image: Arc<dyn ProxyImageUseCase + Send + Sync>,
+    /// Internal: manifests pushed by the image processor's completion callback
+    pub ingest_manifest: Arc<dyn IngestMediaManifestUseCase + Send + Sync>,
+    /// Admin: accounts storing their media in their own buckets
+    pub get_storage_override: Arc<dyn GetStorageOverrideUseCase + Send + Sync>,
+    pub set_storage_override: Arc<dyn SetStorageOverrideUseCase + Send + Sync>,
+    pub delete_storage_override: Arc<dyn DeleteStorageOverrideUseCase + Send + Sync>,
+}
+
+impl MultimediaUseCases {
+    /// `upload_rate_limiter` counts the upload URLs issued to each owner,
+    /// single or batched. `unit_of_work` records a batch's media together.
+    /// `proxy_image` writes to the output bucket rather than the upload one,
+    /// so it is built by the caller, as is `ingest_manifest`, which files
+    /// failures in the retry queue. Uploads of owners with a storage
+    /// override go to their own bucket; `default_buckets` are the server's,
+    /// which no override may name.
+    #[allow(clippy::too_many_arguments)]
+    pub fn build<S, R, Q, B, U, O>(
+        storage: S,
+        repository: R,
+        query: Q,
+        batches: B,
+        unit_of_work: U,
+        processor: Arc<dyn MediaProcessorClient>,
+        upload_rate_limiter: RateLimiter,
+        proxy_image: Arc<dyn ProxyImageUseCase + Send + Sync>,
+        ingest_manifest: Arc<dyn IngestMediaManifestUseCase + Send + Sync>,
+        storage_overrides: O,
+        default_buckets: Vec<String>,
+    ) -> Self
+    where
+        S: StorageQuery + Clone + 'static,
+        R: MediaRepository + Clone + 'static,
+        Q: MediaQuery + Clone + 'static,
+        B: MediaUploadBatchRepository + Clone + 'static,
+        U: UnitOfWork + 'static,
+        O: StorageOverrideStore + Clone + 'static,
+    {
+        let uploads = || {
+            CreateUploadMediaUrlService::new(storage.clone(), repository.clone())
+                .with_rate_limiter(upload_rate_limiter.clone())
+                .with_duplicate_check(Arc::new(query.clone()))
+                .with_storage_overrides(Arc::new(storage_overrides.clone()))
+        };
+
+        Self {
+            create_signed_post_url: Arc::new(Metered(uploads())),
+            create_upload_batch: Arc::new(Metered(CreateUploadBatchService::new(
+                uploads(),
+                batches.clone(),
+                unit_of_work,
+            ))),
+            get_upload_batch: Arc::new(Metered(GetUploadBatchService::new(batches))),
+            finalize_upload: Arc::new(Metered(FinalizeUploadService::new(
+                query.clone(),
+                storage.clone(),
+                repository.clone(),
+            ))),
+            create_signed_get_url: Arc::new(Metered(GetVariantReadUrlService::new(
+                storage.clone(),
+                query.clone(),
+            ))),
+            create_signed_get_urls: Arc::new(Metered(GetVariantReadUrlsService::new(
+                storage.clone(),
+                query.clone(),
+            ))),
+            get_preview_image: Arc::new(Metered(GetPreviewImageService::new(
+                storage,
+                query.clone(),
+            ))),
+            list_media: Arc::new(Metered(ListMediaService::new(query.clone()))),
+            regenerate_media: Arc::new(Metered(RegenerateMediaService::new(
+                query, repository, processor,
+            ))),
+            proxy_image,
+            ingest_manifest,
+            get_storage_override: Arc::new(Metered(GetStorageOverrideService::new(
+                storage_overrides.clone(),
+            ))),
+            set_storage_override: Arc::new(Metered(SetStorageOverrideService::new(
+                storage_overrides.clone(),
+                default_buckets,
+            ))),
+            delete_storage_override: Arc::new(Metered(DeleteStorageOverrideService::new(
+                storage_overrides,
+            ))),
+        }
+    }
 }
@@ -0,0 +1,561 @@
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::multimedia::application::{
+    domain::{
+        entities::{AttachmentTarget, MediaRole, MediaState},
+        policies::upload_policy::UploadPolicy,
+    },
+    ports::{
+        incoming::use_cases::{
+            make_object_key, BackfillScreenshotsCommand, BackfillScreenshotsError,
+            BackfillScreenshotsReport, BackfillScreenshotsUseCase,
+        },
+        outgoing::{
+            cloud_storage::{MediaInfo, MediaTransfer, StorageQuery, UploadConstraints},
+            db::{
+                LegacyScreenshot, LegacyScreenshotStore, MediaRepository, NewMedia,
+                NewMediaAttachment, RecordMediaTx, ScreenshotMigration, UpdateMediaStateData,
+            },
+        },
+    },
+};
+
+pub struct BackfillScreenshotsService<L, R, S, T>
+where
+    L: LegacyScreenshotStore,
+    R: MediaRepository,
+    S: StorageQuery,
+    T: MediaTransfer,
+{
+    legacy: L,
+    repository: R,
+    storage: S,
+    transfer: T,
+    policy: UploadPolicy,
+}
+
+impl<L, R, S, T> BackfillScreenshotsService<L, R, S, T>
+where
+    L: LegacyScreenshotStore,
+    R: MediaRepository,
+    S: StorageQuery,
+    T: MediaTransfer,
+{
+    pub fn new(legacy: L, repository: R, storage: S, transfer: T, policy: UploadPolicy) -> Self {
+        Self {
+            legacy,
+            repository,
+            storage,
+            transfer,
+            policy,
+        }
+    }
+
+    /// Download, record and upload one screenshot; the error is kept as the
+    /// reason the URL failed
+    async fn import(&self, shot: &LegacyScreenshot) -> Result<Uuid, String> {
+        if !(shot.url.starts_with("https://") || shot.url.starts_with("http://")) {
+            return Err("not an http(s) URL".to_string());
+        }
+
+        let file = self
+            .transfer
+            .download(&shot.url, self.policy.max_file_size_bytes)
+            .await
+            .map_err(|e| e.to_string())?;
+        let ext = raster_extension(&file.content_type)
+            .ok_or_else(|| format!("not a supported image: {}", file.content_type))?;
+        let original_name = file_name(&shot.url, ext, shot.position);
+
+        let recorded = self
+            .repository
+            .record_media_tx(RecordMediaTx {
+                media: NewMedia {
+                    owner: shot.owner,
+                    state: MediaState::Pending,
+                    bucket_name: self.policy.bucket_name.clone(),
+                    original_name,
+                    mime_type: file.content_type.clone(),
+                    file_size_bytes: file.bytes.len() as u64,
+                    width_px: None,
+                    height_px: None,
+                    duration_seconds: None,
+                    // Downloaded here, so the digest is known for certain
+                    checksum_sha256: Some(hex::encode(Sha256::digest(&file.bytes))),
+                },
+                attachment: NewMediaAttachment {
+                    owner: shot.owner,
+                    attachment_target: AttachmentTarget::Project,
+                    attachment_target_id: shot.project_id,
+                    role: MediaRole::Screenshoot,
+                    position: shot.position,
+                    alt_text: None,
+                    caption: None,
+                },
+            })
+            .await
+            .map_err(|e| e.to_string())?;
+
+        // From here on a media row exists; a failed upload marks it failed
+        // rather than leaving it pending forever
+        let uploaded = async {
+            let object_key = make_object_key(recorded.media_id, &recorded.original_name)
+                .map_err(|e| e.to_string())?;
+            let media_info =
+                MediaInfo::try_new(recorded.bucket_name, object_key, AttachmentTarget::Project)
+                    .map_err(|e| e.to_string())?;
+            let constraints = UploadConstraints {
+                content_type: file.content_type.clone(),
+                max_bytes: file.bytes.len() as u64,
+                tenant: None,
+            };
+            let signed = self
+                .storage
+                .get_signed_upload_url(media_info, constraints)
+                .await
+                .map_err(|e| e.to_string())?;
+            self.transfer
+                .upload(&signed, &file)
+                .await
+                .map_err(|e| e.to_string())
+        }
+        .await;
+
+        if let Err(reason) = uploaded {
+            let marked = self
+                .repository
+                .set_media_state(UpdateMediaStateData {
+                    owner: shot.owner,
+                    media_id: recorded.media_id,
+                    status: MediaState::Failed,
+                })
+                .await;
+            if let Err(e) = marked {
+                warn!(media_id = %recorded.media_id, error = %e, "Failed to mark media failed");
+            }
+            return Err(reason);
+        }
+
+        Ok(recorded.media_id)
+    }
+}
+
+/// Extension for the image types the processor resizes
+fn raster_extension(content_type: &str) -> Option<&'static str> {
+    match content_type {
+        "image/jpeg" => Some("jpg"),
+        "image/png" => Some("png"),
+        "image/webp" => Some("webp"),
+        _ => None,
+    }
+}
+
+/// The URL's own file name when it's a plain one of the right type,
+/// otherwise `screenshot-<n>.<ext>`
+fn file_name(url: &str, ext: &str, position: u8) -> String {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    let last = path.rsplit('/').next().unwrap_or("");
+    let plain = !last.is_empty()
+        && last.len() <= 100
+        && last
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'));
+    let matches_type = last
+        .rsplit_once('.')
+        .map(|(stem, e)| {
+            let e = e.to_ascii_lowercase();
+            !stem.is_empty() && (e == ext || (ext == "jpg" && e == "jpeg"))
+        })
+        .unwrap_or(false);
+
+    if plain && matches_type {
+        last.to_string()
+    } else {
+        format!("screenshot-{}.{ext}", u16::from(position) + 1)
+    }
+}
+
+#[async_trait]
+impl<L, R, S, T> BackfillScreenshotsUseCase for BackfillScreenshotsService<L, R, S, T>
+where
+    L: LegacyScreenshotStore,
+    R: MediaRepository,
+    S: StorageQuery,
+    T: MediaTransfer,
+{
+    async fn execute(
+        &self,
+        command: BackfillScreenshotsCommand,
+    ) -> Result<BackfillScreenshotsReport, BackfillScreenshotsError> {
+        let pending = self
+            .legacy
+            .pending(command.limit, command.retry_failed)
+            .await?;
+
+        let mut report = BackfillScreenshotsReport::default();
+
+        for shot in pending {
+            report.checked += 1;
+            if command.dry_run {
+                continue;
+            }
+
+            let outcome = match self.import(&shot).await {
+                Ok(media_id) => {
+                    report.migrated += 1;
+                    ScreenshotMigration::Migrated { media_id }
+                }
+                Err(reason) => {
+                    warn!(project_id = %shot.project_id, url = %shot.url, %reason, "Screenshot import failed");
+                    report.failed += 1;
+                    ScreenshotMigration::Failed { reason }
+                }
+            };
+
+            // Stop rather than import the same URL again on the next run
+            self.legacy
+                .record(shot.project_id, &shot.url, outcome)
+                .await?;
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    use crate::auth::application::domain::entities::UserId;
+    use crate::multimedia::application::domain::entities::{
+        MediaFailure, MediaStateInfo, MediaVariant,
+    };
+    use crate::multimedia::application::ports::outgoing::cloud_storage::{
+        FetchedFile, ManifestInfo, SignUrlError, SignedUpload, StorageQueryError, TransferError,
+    };
+    use crate::multimedia::application::ports::outgoing::db::{
+        MediaRepositoryError, MediaVariantRecord, RecordMediaError, RecordedMedia,
+    };
+
+    #[derive(Clone, Default)]
+    struct MockLegacyStore {
+        pending: Vec<LegacyScreenshot>,
+        recorded: Arc<Mutex<Vec<(String, ScreenshotMigration)>>>,
+    }
+
+    #[async_trait]
+    impl LegacyScreenshotStore for MockLegacyStore {
+        async fn pending(
+            &self,
+            _limit: u64,
+            _retry_failed: bool,
+        ) -> Result<Vec<LegacyScreenshot>, MediaRepositoryError> {
+            Ok(self.pending.clone())
+        }
+
+        async fn record(
+            &self,
+            _project_id: Uuid,
+            url: &str,
+            outcome: ScreenshotMigration,
+        ) -> Result<(), MediaRepositoryError> {
+            self.recorded
+                .lock()
+                .unwrap()
+                .push((url.to_string(), outcome));
+            Ok(())
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct MockMediaRepository {
+        recorded: Arc<Mutex<Vec<RecordMediaTx>>>,
+        failed: Arc<Mutex<Vec<Uuid>>>,
+    }
+
+    #[async_trait]
+    impl MediaRepository for MockMediaRepository {
+        async fn record_media_tx(
+            &self,
+            tx: RecordMediaTx,
+        ) -> Result<RecordedMedia, RecordMediaError> {
+            self.recorded.lock().unwrap().push(tx.clone());
+            Ok(RecordedMedia {
+                owner: tx.media.owner,
+                media_id: Uuid::new_v4(),
+                bucket_name: tx.media.bucket_name,
+                original_name: tx.media.original_name,
+                attachment_target: tx.attachment.attachment_target,
+                state: tx.media.state,
+            })
+        }
+
+        async fn set_media_state(
+            &self,
+            data: UpdateMediaStateData,
+        ) -> Result<MediaStateInfo, MediaRepositoryError> {
+            self.failed.lock().unwrap().push(data.media_id);
+            Ok(MediaStateInfo {
+                owner: data.owner,
+                media_id: data.media_id,
+                updated_at: String::new(),
+                status: data.status,
+            })
+        }
+
+        async fn transition_media_state(
+            &self,
+            _data: UpdateMediaStateData,
+            _from: MediaState,
+        ) -> Result<Option<MediaStateInfo>, MediaRepositoryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn fail_media(
+            &self,
+            _media: &MediaStateInfo,
+            _failure: MediaFailure,
+        ) -> Result<Option<MediaStateInfo>, MediaRepositoryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn record_single_variant(
+            &self,
+            _data: MediaVariantRecord,
+        ) -> Result<MediaVariant, MediaRepositoryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn record_variants(
+            &self,
+            _data: Vec<MediaVariantRecord>,
+        ) -> Result<Vec<MediaVariant>, MediaRepositoryError> {
+            unimplemented!("not needed for these tests")
+        }
+    }
+
+    struct MockStorage;
+
+    #[async_trait]
+    impl StorageQuery for MockStorage {
+        async fn get_signed_upload_url(
+            &self,
+            media_info: MediaInfo,
+            constraints: UploadConstraints,
+        ) -> Result<SignedUpload, SignUrlError> {
+            Ok(SignedUpload {
+                url: format!("https://signed.example/{}", media_info.object_name()),
+                headers: [("content-type".to_string(), constraints.content_type)].into(),
+            })
+        }
+
+        async fn get_signed_read_url(
+            &self,
+            _media_info: MediaInfo,
+        ) -> Result<String, SignUrlError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn object_exists(&self, _media_info: MediaInfo) -> Result<bool, StorageQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+        async fn get_latest_manifest(
+            &self,
+            _media_id: &str,
+        ) -> Result<ManifestInfo, StorageQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+    }
+
+    /// Serves a PNG for every URL but `missing`; uploads fail when `upload_fails`
+    #[derive(Clone, Default)]
+    struct MockTransfer {
+        missing: String,
+        content_type: String,
+        upload_fails: bool,
+        uploads: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl MediaTransfer for MockTransfer {
+        async fn download(&self, url: &str, _max_bytes: u64) -> Result<FetchedFile, TransferError> {
+            if url == self.missing {
+                return Err(TransferError::NotFound);
+            }
+            Ok(FetchedFile {
+                bytes: vec![0; 10],
+                content_type: self.content_type.clone(),
+            })
+        }
+
+        async fn upload(
+            &self,
+            upload: &SignedUpload,
+            _file: &FetchedFile,
+        ) -> Result<(), TransferError> {
+            if self.upload_fails {
+                return Err(TransferError::Failed("503".to_string()));
+            }
+            self.uploads.lock().unwrap().push(upload.url.clone());
+            Ok(())
+        }
+    }
+
+    fn shot(url: &str, position: u8) -> LegacyScreenshot {
+        LegacyScreenshot {
+            owner: UserId::from(Uuid::new_v4()),
+            project_id: Uuid::new_v4(),
+            position,
+            url: url.to_string(),
+        }
+    }
+
+    fn command(dry_run: bool) -> BackfillScreenshotsCommand {
+        BackfillScreenshotsCommand {
+            limit: 100,
+            retry_failed: false,
+            dry_run,
+        }
+    }
+
+    fn service(
+        legacy: MockLegacyStore,
+        repository: MockMediaRepository,
+        transfer: MockTransfer,
+    ) -> BackfillScreenshotsService<MockLegacyStore, MockMediaRepository, MockStorage, MockTransfer>
+    {
+        BackfillScreenshotsService::new(
+            legacy,
+            repository,
+            MockStorage,
+            transfer,
+            UploadPolicy::new("uploads".to_string()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_backfill_imports_and_records_each_url() {
+        let legacy = MockLegacyStore {
+            pending: vec![
+                shot("https://cdn.example.com/shots/home.png", 0),
+                shot("https://cdn.example.com/gone.png", 1),
+                shot("ftp://old.example.com/x.png", 2),
+            ],
+            ..Default::default()
+        };
+        let repository = MockMediaRepository::default();
+        let transfer = MockTransfer {
+            missing: "https://cdn.example.com/gone.png".to_string(),
+            content_type: "image/png".to_string(),
+            ..Default::default()
+        };
+
+        let report = service(legacy.clone(), repository.clone(), transfer.clone())
+            .execute(command(false))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            report,
+            BackfillScreenshotsReport {
+                checked: 3,
+                migrated: 1,
+                failed: 2,
+            }
+        );
+
+        let recorded = repository.recorded.lock().unwrap();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].media.original_name, "home.png");
+        assert_eq!(recorded[0].media.bucket_name, "uploads");
+        assert_eq!(recorded[0].attachment.role, MediaRole::Screenshoot);
+        assert_eq!(recorded[0].attachment.position, 0);
+
+        let uploads = transfer.uploads.lock().unwrap();
+        assert_eq!(uploads.len(), 1);
+        assert!(uploads[0].ends_with("/home.png"));
+
+        let outcomes = legacy.recorded.lock().unwrap();
+        assert!(matches!(
+            outcomes[0].1,
+            ScreenshotMigration::Migrated { .. }
+        ));
+        assert_eq!(
+            outcomes[1].1,
+            ScreenshotMigration::Failed {
+                reason: "File not found".to_string()
+            }
+        );
+        assert!(matches!(outcomes[2].1, ScreenshotMigration::Failed { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_backfill_marks_media_failed_when_upload_fails() {
+        let legacy = MockLegacyStore {
+            pending: vec![shot("https://cdn.example.com/a.png", 0)],
+            ..Default::default()
+        };
+        let repository = MockMediaRepository::default();
+        let transfer = MockTransfer {
+            content_type: "image/png".to_string(),
+            upload_fails: true,
+            ..Default::default()
+        };
+
+        let report = service(legacy, repository.clone(), transfer)
+            .execute(command(false))
+            .await
+            .unwrap();
+
+        assert_eq!(report.failed, 1);
+        assert_eq!(repository.failed.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_backfill_rejects_non_images_and_dry_run_writes_nothing() {
+        let legacy = MockLegacyStore {
+            pending: vec![shot("https://example.com/page", 0)],
+            ..Default::default()
+        };
+        let repository = MockMediaRepository::default();
+        let transfer = MockTransfer {
+            content_type: "text/html".to_string(),
+            ..Default::default()
+        };
+
+        let report = service(legacy.clone(), repository.clone(), transfer.clone())
+            .execute(command(true))
+            .await
+            .unwrap();
+        assert_eq!(report.checked, 1);
+        assert!(legacy.recorded.lock().unwrap().is_empty());
+
+        let report = service(legacy.clone(), repository.clone(), transfer)
+            .execute(command(false))
+            .await
+            .unwrap();
+        assert_eq!(report.failed, 1);
+        assert!(repository.recorded.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_file_name_falls_back_for_unusable_names() {
+        assert_eq!(
+            file_name("https://x.io/a/shot.JPEG?v=2", "jpg", 0),
+            "shot.JPEG"
+        );
+        assert_eq!(
+            file_name("https://x.io/a/shot.png", "webp", 1),
+            "screenshot-2.webp"
+        );
+        assert_eq!(
+            file_name("https://x.io/a/my%20shot.png", "png", 2),
+            "screenshot-3.png"
+        );
+        assert_eq!(file_name("https://x.io/", "png", 255), "screenshot-256.png");
+    }
+}
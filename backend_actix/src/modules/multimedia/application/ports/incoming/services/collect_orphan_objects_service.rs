@@ -0,0 +1,359 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use std::collections::HashSet;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::multimedia::application::ports::{
+    incoming::use_cases::{
+        CollectOrphanObjectsCommand, CollectOrphanObjectsError, CollectOrphanObjectsReport,
+        CollectOrphanObjectsUseCase,
+    },
+    outgoing::{cloud_storage::ObjectInventory, db::MediaQuery},
+};
+
+/// Media ids looked up per query
+const LOOKUP_BATCH: usize = 500;
+
+/// The media an object belongs to: the first path segment that is a UUID.
+/// Uploads are stored as `{media_id}/{name}` and variants as
+/// `variants/{media_id}/{name}`.
+fn media_id_of(object_name: &str) -> Option<Uuid> {
+    object_name
+        .split('/')
+        .find_map(|segment| Uuid::parse_str(segment).ok())
+}
+
+pub struct CollectOrphanObjectsService<Q, I>
+where
+    Q: MediaQuery,
+    I: ObjectInventory,
+{
+    query: Q,
+    inventory: I,
+    buckets: Vec<String>,
+}
+
+impl<Q, I> CollectOrphanObjectsService<Q, I>
+where
+    Q: MediaQuery,
+    I: ObjectInventory,
+{
+    /// `buckets` are the ones media objects are written to: uploads and variants
+    pub fn new(query: Q, inventory: I, buckets: Vec<String>) -> Self {
+        Self {
+            query,
+            inventory,
+            buckets,
+        }
+    }
+
+    async fn existing(
+        &self,
+        media_ids: &[Uuid],
+    ) -> Result<HashSet<Uuid>, CollectOrphanObjectsError> {
+        let mut existing = HashSet::new();
+        for batch in media_ids.chunks(LOOKUP_BATCH) {
+            existing.extend(self.query.existing_media_ids(batch).await?);
+        }
+        Ok(existing)
+    }
+}
+
+#[async_trait]
+impl<Q, I> CollectOrphanObjectsUseCase for CollectOrphanObjectsService<Q, I>
+where
+    Q: MediaQuery,
+    I: ObjectInventory,
+{
+    async fn execute(
+        &self,
+        command: CollectOrphanObjectsCommand,
+    ) -> Result<CollectOrphanObjectsReport, CollectOrphanObjectsError> {
+        let created_before = Utc::now() - command.grace;
+        let mut report = CollectOrphanObjectsReport::default();
+
+        for bucket in &self.buckets {
+            let objects = self.inventory.list_objects(bucket).await?;
+            report.scanned += objects.len();
+
+            let mut candidates = Vec::new();
+            for object in objects {
+                if object.created_at >= created_before {
+                    continue;
+                }
+                match media_id_of(&object.name) {
+                    Some(media_id) => candidates.push((object.name, media_id)),
+                    None => report.unrecognized += 1,
+                }
+            }
+
+            let mut media_ids: Vec<Uuid> = candidates.iter().map(|(_, id)| *id).collect();
+            media_ids.sort_unstable();
+            media_ids.dedup();
+            let existing = self.existing(&media_ids).await?;
+
+            for (name, media_id) in candidates {
+                if existing.contains(&media_id) {
+                    continue;
+                }
+                report.orphaned += 1;
+                if command.dry_run {
+                    continue;
+                }
+
+                match self.inventory.delete_object(bucket, &name).await {
+                    Ok(()) => report.deleted += 1,
+                    Err(e) => {
+                        warn!(bucket = %bucket, object = %name, error = %e, "Failed to delete orphaned object");
+                        report.errors += 1;
+                    }
+                }
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Duration};
+    use std::sync::{Arc, Mutex};
+
+    use crate::auth::application::domain::entities::UserId;
+    use crate::multimedia::adapter::outgoing::db::InMemoryMediaStore;
+    use crate::multimedia::application::domain::entities::{
+        AttachmentTarget, MediaRole, MediaState,
+    };
+    use crate::multimedia::application::ports::outgoing::cloud_storage::{
+        ObjectInventoryError, StoredObject,
+    };
+    use crate::multimedia::application::ports::outgoing::db::{
+        MediaRepository, NewMedia, NewMediaAttachment, RecordMediaTx,
+    };
+
+    /// Buckets of objects; `undeletable` names fail to delete
+    #[derive(Clone, Default)]
+    struct MockInventory {
+        objects: Arc<Mutex<Vec<(String, StoredObject)>>>,
+        undeletable: Vec<String>,
+    }
+
+    impl MockInventory {
+        fn put(&self, bucket: &str, name: &str, created_at: DateTime<Utc>) {
+            self.objects.lock().unwrap().push((
+                bucket.to_string(),
+                StoredObject {
+                    name: name.to_string(),
+                    created_at,
+                },
+            ));
+        }
+
+        fn names(&self, bucket: &str) -> Vec<String> {
+            self.objects
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(b, _)| b == bucket)
+                .map(|(_, o)| o.name.clone())
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl ObjectInventory for MockInventory {
+        async fn list_objects(
+            &self,
+            bucket: &str,
+        ) -> Result<Vec<StoredObject>, ObjectInventoryError> {
+            Ok(self
+                .objects
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(b, _)| b == bucket)
+                .map(|(_, o)| o.clone())
+                .collect())
+        }
+
+        async fn delete_object(
+            &self,
+            bucket: &str,
+            name: &str,
+        ) -> Result<(), ObjectInventoryError> {
+            if self.undeletable.iter().any(|n| n == name) {
+                return Err(ObjectInventoryError::Failed(
+                    "permission denied".to_string(),
+                ));
+            }
+            self.objects
+                .lock()
+                .unwrap()
+                .retain(|(b, o)| !(b == bucket && o.name == name));
+            Ok(())
+        }
+    }
+
+    async fn recorded_media(store: &InMemoryMediaStore) -> Uuid {
+        let owner = UserId::from(Uuid::new_v4());
+        store
+            .record_media_tx(RecordMediaTx {
+                media: NewMedia {
+                    owner,
+                    state: MediaState::Ready,
+                    bucket_name: "uploads".to_string(),
+                    original_name: "cat.png".to_string(),
+                    mime_type: "image/png".to_string(),
+                    file_size_bytes: 1024,
+                    width_px: None,
+                    height_px: None,
+                    duration_seconds: None,
+                    checksum_sha256: None,
+                },
+                attachment: NewMediaAttachment {
+                    owner,
+                    attachment_target: AttachmentTarget::Project,
+                    attachment_target_id: Uuid::new_v4(),
+                    role: MediaRole::Gallery,
+                    position: 0,
+                    alt_text: None,
+                    caption: None,
+                },
+            })
+            .await
+            .unwrap()
+            .media_id
+    }
+
+    fn service(
+        store: &InMemoryMediaStore,
+        inventory: &MockInventory,
+    ) -> CollectOrphanObjectsService<InMemoryMediaStore, MockInventory> {
+        CollectOrphanObjectsService::new(
+            store.clone(),
+            inventory.clone(),
+            vec!["uploads".to_string(), "ready".to_string()],
+        )
+    }
+
+    fn command(dry_run: bool) -> CollectOrphanObjectsCommand {
+        CollectOrphanObjectsCommand {
+            grace: Duration::hours(24),
+            dry_run,
+        }
+    }
+
+    #[tokio::test]
+    async fn deletes_old_objects_without_a_media_row() {
+        let store = InMemoryMediaStore::default();
+        let inventory = MockInventory::default();
+        let known = recorded_media(&store).await;
+        let gone = Uuid::new_v4();
+        let old = Utc::now() - Duration::days(3);
+        inventory.put("uploads", &format!("{known}/cat.png"), old);
+        inventory.put("uploads", &format!("{gone}/dog.png"), old);
+        inventory.put("ready", &format!("variants/{known}/thumbnail.webp"), old);
+        inventory.put("ready", &format!("variants/{gone}/thumbnail.webp"), old);
+        inventory.put("ready", &format!("variants/{gone}/thumbnail.png"), old);
+
+        let report = service(&store, &inventory)
+            .execute(command(false))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            report,
+            CollectOrphanObjectsReport {
+                scanned: 5,
+                orphaned: 3,
+                deleted: 3,
+                unrecognized: 0,
+                errors: 0,
+            }
+        );
+        assert_eq!(inventory.names("uploads"), vec![format!("{known}/cat.png")]);
+        assert_eq!(
+            inventory.names("ready"),
+            vec![format!("variants/{known}/thumbnail.webp")]
+        );
+    }
+
+    #[tokio::test]
+    async fn dry_run_only_counts() {
+        let store = InMemoryMediaStore::default();
+        let inventory = MockInventory::default();
+        inventory.put(
+            "uploads",
+            &format!("{}/dog.png", Uuid::new_v4()),
+            Utc::now() - Duration::days(3),
+        );
+
+        let report = service(&store, &inventory)
+            .execute(command(true))
+            .await
+            .unwrap();
+
+        assert_eq!(report.orphaned, 1);
+        assert_eq!(report.deleted, 0);
+        assert_eq!(inventory.names("uploads").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn recent_and_unrecognized_objects_are_kept() {
+        let store = InMemoryMediaStore::default();
+        let inventory = MockInventory::default();
+        // Its media row may not be committed yet
+        inventory.put(
+            "uploads",
+            &format!("{}/new.png", Uuid::new_v4()),
+            Utc::now() - Duration::minutes(5),
+        );
+        inventory.put("ready", "robots.txt", Utc::now() - Duration::days(30));
+
+        let report = service(&store, &inventory)
+            .execute(command(false))
+            .await
+            .unwrap();
+
+        assert_eq!(report.scanned, 2);
+        assert_eq!(report.orphaned, 0);
+        assert_eq!(report.unrecognized, 1);
+        assert_eq!(inventory.names("uploads").len(), 1);
+        assert_eq!(inventory.names("ready").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn failed_deletes_are_counted_and_the_rest_continue() {
+        let store = InMemoryMediaStore::default();
+        let stuck = format!("{}/stuck.png", Uuid::new_v4());
+        let inventory = MockInventory {
+            undeletable: vec![stuck.clone()],
+            ..MockInventory::default()
+        };
+        let old = Utc::now() - Duration::days(3);
+        inventory.put("uploads", &stuck, old);
+        inventory.put("uploads", &format!("{}/dog.png", Uuid::new_v4()), old);
+
+        let report = service(&store, &inventory)
+            .execute(command(false))
+            .await
+            .unwrap();
+
+        assert_eq!(report.orphaned, 2);
+        assert_eq!(report.deleted, 1);
+        assert_eq!(report.errors, 1);
+        assert_eq!(inventory.names("uploads"), vec![stuck]);
+    }
+
+    #[test]
+    fn media_id_is_found_in_upload_and_variant_names() {
+        let id = Uuid::new_v4();
+
+        assert_eq!(media_id_of(&format!("{id}/cat.png")), Some(id));
+        assert_eq!(media_id_of(&format!("variants/{id}/small.webp")), Some(id));
+        assert_eq!(media_id_of("variants/cat.png"), None);
+    }
+}
@@ -1,15 +1,16 @@
 use async_trait::async_trait;
 use chrono::{Duration, Utc};
 
+use crate::auth::application::domain::entities::UserId;
 use crate::multimedia::application::{
-    domain::entities::MediaState,
+    domain::entities::{MediaSize, MediaState},
     ports::{
         incoming::use_cases::{
             GetReadUrlError, GetUrlCommand, GetUrlResult, GetVariantReadUrlUseCase,
         },
         outgoing::{
             cloud_storage::{MediaInfo, StorageQuery},
-            db::MediaQuery,
+            db::{MediaAttachment, MediaQuery},
         },
     },
 };
@@ -37,40 +38,83 @@ where
             media_query,
         }
     }
+}
 
-    fn map_query_error(
-        err: crate::multimedia::application::ports::outgoing::db::MediaQueryError,
-    ) -> GetReadUrlError {
-        use crate::multimedia::application::ports::outgoing::db::MediaQueryError;
+pub(super) fn map_query_error(
+    err: crate::multimedia::application::ports::outgoing::db::MediaQueryError,
+) -> GetReadUrlError {
+    use crate::multimedia::application::ports::outgoing::db::MediaQueryError;
 
-        match err {
-            MediaQueryError::MediaNotFound => GetReadUrlError::MediaNotFound,
-            MediaQueryError::DatabaseError(e) => GetReadUrlError::QueryError(e),
-        }
+    match err {
+        MediaQueryError::MediaNotFound => GetReadUrlError::MediaNotFound,
+        MediaQueryError::DatabaseError(e) => GetReadUrlError::QueryError(e),
     }
+}
 
-    fn map_storage_error(
-        err: crate::multimedia::application::ports::outgoing::cloud_storage::SignUrlError,
-    ) -> GetReadUrlError {
-        use crate::multimedia::application::ports::outgoing::cloud_storage::SignUrlError;
-
-        match err {
-            SignUrlError::AccessDenied => {
-                GetReadUrlError::StorageError("Access denied".to_string())
-            }
-            SignUrlError::BucketNotFound => {
-                GetReadUrlError::StorageError("Bucket not found".to_string())
-            }
-            SignUrlError::Configuration => {
-                GetReadUrlError::StorageError("Storage configuration error".to_string())
-            }
-            SignUrlError::Infrastructure => {
-                GetReadUrlError::StorageError("Storage infrastructure error".to_string())
-            }
+fn map_storage_error(
+    err: crate::multimedia::application::ports::outgoing::cloud_storage::SignUrlError,
+) -> GetReadUrlError {
+    use crate::multimedia::application::ports::outgoing::cloud_storage::SignUrlError;
+
+    match err {
+        SignUrlError::AccessDenied => GetReadUrlError::StorageError("Access denied".to_string()),
+        SignUrlError::BucketNotFound => {
+            GetReadUrlError::StorageError("Bucket not found".to_string())
+        }
+        SignUrlError::Configuration => {
+            GetReadUrlError::StorageError("Storage configuration error".to_string())
+        }
+        SignUrlError::Infrastructure => {
+            GetReadUrlError::StorageError("Storage infrastructure error".to_string())
         }
     }
 }
 
+/// A signed URL for `size` of `media`, if `owner` may read it
+pub(super) async fn sign_variant<S: StorageQuery>(
+    storage_query: &S,
+    media: &MediaAttachment,
+    owner: UserId,
+    size: MediaSize,
+) -> Result<GetUrlResult, GetReadUrlError> {
+    // Someone else's media is not found, not forbidden
+    if media.owner != owner {
+        return Err(GetReadUrlError::MediaNotFound);
+    }
+
+    match media.status {
+        MediaState::Pending => return Err(GetReadUrlError::MediaPending),
+        MediaState::Processing => return Err(GetReadUrlError::MediaProcessing),
+        MediaState::Failed => return Err(GetReadUrlError::MediaFailed),
+        MediaState::Ready => {}
+    }
+
+    let variant = media
+        .variants
+        .iter()
+        .find(|v| v.size == size)
+        .ok_or_else(|| GetReadUrlError::VariantNotFound(size.clone()))?;
+
+    let media_info = MediaInfo::try_new(
+        variant.bucket_name.clone(),
+        variant.object_name.clone(),
+        media.attachment_target.clone(),
+    )
+    .map_err(|e| GetReadUrlError::StorageError(e.to_string()))?;
+
+    let url = storage_query
+        .get_signed_read_url(media_info)
+        .await
+        .map_err(map_storage_error)?;
+
+    Ok(GetUrlResult {
+        media_id: media.media_id,
+        size,
+        url,
+        expires_at: Utc::now() + Duration::minutes(SIGNED_URL_TTL_MINUTES),
+    })
+}
+
 #[async_trait]
 impl<S, M> GetVariantReadUrlUseCase for GetVariantReadUrlService<S, M>
 where
@@ -78,57 +122,13 @@ where
     M: MediaQuery,
 {
     async fn execute(&self, command: GetUrlCommand) -> Result<GetUrlResult, GetReadUrlError> {
-        // 1. Get media attachment info
         let media = self
             .media_query
             .get_attachment_info(command.media_id)
             .await
-            .map_err(Self::map_query_error)?;
+            .map_err(map_query_error)?;
 
-        // 2. Verify ownership
-        if media.owner != command.owner {
-            return Err(GetReadUrlError::MediaNotFound);
-        }
-
-        // 3. Check media state
-        match media.status {
-            MediaState::Pending => return Err(GetReadUrlError::MediaPending),
-            MediaState::Processing => return Err(GetReadUrlError::MediaProcessing),
-            MediaState::Failed => return Err(GetReadUrlError::MediaFailed),
-            MediaState::Ready => {}
-        }
-
-        // 4. Find the requested variant
-        let variant = media
-            .variants
-            .iter()
-            .find(|v| v.size == command.size)
-            .ok_or_else(|| GetReadUrlError::VariantNotFound(command.size.clone()))?;
-
-        // 5. Create MediaInfo for signing
-        let media_info = MediaInfo::try_new(
-            variant.bucket_name.clone(),
-            variant.object_name.clone(),
-            media.attachment_target,
-        )
-        .map_err(|e| GetReadUrlError::StorageError(e.to_string()))?;
-
-        // 6. Get signed URL
-        let url = self
-            .storage_query
-            .get_signed_read_url(media_info)
-            .await
-            .map_err(Self::map_storage_error)?;
-
-        // 7. Calculate expiration time
-        let expires_at = Utc::now() + Duration::minutes(SIGNED_URL_TTL_MINUTES);
-
-        Ok(GetUrlResult {
-            media_id: command.media_id,
-            size: command.size,
-            url,
-            expires_at,
-        })
+        sign_variant(&self.storage_query, &media, command.owner, command.size).await
     }
 }
 
@@ -146,8 +146,10 @@ mod tests {
                 AttachmentTarget, MediaRole, MediaSize, MediaState, MediaStateInfo,
             },
             ports::outgoing::{
-                cloud_storage::{ManifestInfo, SignUrlError, StorageQueryError},
-                db::{MediaAttachment, MediaQueryError, StoredVariant},
+                cloud_storage::{
+                    ManifestInfo, SignUrlError, SignedUpload, StorageQueryError, UploadConstraints,
+                },
+                db::{MediaAttachment, MediaQueryError, StoredVariant, UploadObject},
             },
         },
     };
@@ -177,6 +179,43 @@ mod tests {
         ) -> Result<MediaAttachment, MediaQueryError> {
             self.result.clone()
         }
+
+        async fn get_upload_object(
+            &self,
+            _media_id: Uuid,
+        ) -> Result<UploadObject, MediaQueryError> {
+            unimplemented!()
+        }
+
+        async fn list_unsettled(
+            &self,
+            _updated_before: chrono::DateTime<Utc>,
+            _limit: u64,
+        ) -> Result<Vec<MediaStateInfo>, MediaQueryError> {
+            unimplemented!()
+        }
+
+        async fn find_duplicate(
+            &self,
+            _owner: UserId,
+            _checksum_sha256: &str,
+        ) -> Result<Option<MediaStateInfo>, MediaQueryError> {
+            unimplemented!()
+        }
+
+        async fn get_attachment_infos(
+            &self,
+            _media_ids: &[Uuid],
+        ) -> Result<Vec<MediaAttachment>, MediaQueryError> {
+            unimplemented!()
+        }
+
+        async fn existing_media_ids(
+            &self,
+            _media_ids: &[Uuid],
+        ) -> Result<std::collections::HashSet<Uuid>, MediaQueryError> {
+            unimplemented!()
+        }
     }
 
     // Mock StorageQuery
@@ -189,7 +228,8 @@ mod tests {
         async fn get_signed_upload_url(
             &self,
             _media_info: MediaInfo,
-        ) -> Result<String, SignUrlError> {
+            _constraints: UploadConstraints,
+        ) -> Result<SignedUpload, SignUrlError> {
             unimplemented!()
         }
 
@@ -200,6 +240,9 @@ mod tests {
             self.result.clone()
         }
 
+        async fn object_exists(&self, _media_info: MediaInfo) -> Result<bool, StorageQueryError> {
+            unimplemented!()
+        }
         async fn get_latest_manifest(
             &self,
             _media_id: &str,
@@ -244,11 +287,11 @@ mod tests {
     async fn execute_success() {
         // Arrange
         let owner = UserId::from(Uuid::new_v4());
-        let media_id = Uuid::new_v4();
         let size = MediaSize::Medium;
 
         let variant = create_test_variant(size.clone());
         let media = create_test_media_attachment(owner, MediaState::Ready, vec![variant]);
+        let media_id = media.media_id;
 
         let media_query = MockMediaQuery { result: Ok(media) };
         let storage_query = MockStorageQuery {
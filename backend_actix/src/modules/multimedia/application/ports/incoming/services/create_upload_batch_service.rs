@@ -0,0 +1,261 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::CreateUploadMediaUrlService;
+use crate::multimedia::application::ports::{
+    incoming::use_cases::{
+        CreateUploadBatchCommand, CreateUploadBatchError, CreateUploadBatchUseCase, UploadBatch,
+    },
+    outgoing::{
+        cloud_storage::StorageQuery,
+        db::{MediaRepository, MediaUploadBatchRepository},
+    },
+};
+use crate::shared::unit_of_work::UnitOfWork;
+
+pub struct CreateUploadBatchService<Q, R, B, U>
+where
+    Q: StorageQuery,
+    R: MediaRepository,
+    B: MediaUploadBatchRepository,
+    U: UnitOfWork,
+{
+    /// Registers each file, with the rate limit and duplicate check of
+    /// single uploads
+    uploads: CreateUploadMediaUrlService<Q, R>,
+    batches: B,
+    unit_of_work: U,
+}
+
+impl<Q, R, B, U> CreateUploadBatchService<Q, R, B, U>
+where
+    Q: StorageQuery,
+    R: MediaRepository,
+    B: MediaUploadBatchRepository,
+    U: UnitOfWork,
+{
+    pub fn new(uploads: CreateUploadMediaUrlService<Q, R>, batches: B, unit_of_work: U) -> Self {
+        Self {
+            uploads,
+            batches,
+            unit_of_work,
+        }
+    }
+}
+
+#[async_trait]
+impl<Q, R, B, U> CreateUploadBatchUseCase for CreateUploadBatchService<Q, R, B, U>
+where
+    Q: StorageQuery + Send + Sync,
+    R: MediaRepository + Send + Sync,
+    B: MediaUploadBatchRepository,
+    U: UnitOfWork,
+{
+    async fn execute(
+        &self,
+        command: CreateUploadBatchCommand,
+    ) -> Result<UploadBatch, CreateUploadBatchError> {
+        let owner = *command.owner();
+        let uploads = command.into_uploads();
+
+        // A known file fails the batch before anything is counted or reserved
+        for (index, (media_command, _)) in uploads.iter().enumerate() {
+            self.uploads
+                .reject_duplicate(media_command)
+                .await
+                .map_err(|error| CreateUploadBatchError::Upload { index, error })?;
+        }
+
+        // Every URL of the batch is counted, or none
+        self.uploads
+            .rate_limiter()
+            .hit_many(&owner.value().to_string(), uploads.len() as u32)
+            .map_err(|retry_after| CreateUploadBatchError::RateLimited { retry_after })?;
+
+        let batch_id = Uuid::new_v4();
+        self.unit_of_work
+            .run(async {
+                let mut signed = Vec::with_capacity(uploads.len());
+                for (index, (media_command, attachment_command)) in uploads.into_iter().enumerate()
+                {
+                    let upload = self
+                        .uploads
+                        .register(media_command, attachment_command)
+                        .await
+                        .map_err(|error| CreateUploadBatchError::Upload { index, error })?;
+                    signed.push(upload);
+                }
+
+                let media_ids: Vec<Uuid> = signed.iter().map(|upload| upload.media_id).collect();
+                self.batches.record_batch(batch_id, &media_ids).await?;
+
+                Ok(UploadBatch {
+                    batch_id,
+                    uploads: signed,
+                })
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::auth::application::domain::entities::UserId;
+    use crate::multimedia::adapter::outgoing::{
+        cloud_storage::InMemoryStorage, db::InMemoryMediaStore,
+    };
+    use crate::multimedia::application::{
+        domain::{
+            entities::{AttachmentTarget, MediaRole, MediaState},
+            policies::upload_policy::UploadPolicy,
+        },
+        ports::incoming::use_cases::{
+            CreateAttachmentCommand, CreateMediaCommand, CreateUploadMediaUrlUseCase,
+            CreateUrlError, MAX_UPLOAD_BATCH,
+        },
+    };
+    use crate::shared::rate_limit::RateLimiter;
+    use crate::shared::unit_of_work::InMemoryUnitOfWork;
+
+    fn upload(
+        owner: UserId,
+        target_id: Uuid,
+        file_name: &str,
+        position: u8,
+    ) -> (CreateMediaCommand, CreateAttachmentCommand) {
+        let media = CreateMediaCommand::builder()
+            .owner(owner)
+            .file_name(file_name.to_string())
+            .mime_type("image/png".to_string())
+            .file_size_bytes(1024)
+            .checksum_sha256(Some(format!("{:0>64}", position)))
+            .build(&UploadPolicy::new("media-bucket".to_string()))
+            .expect("valid media command");
+        let attachment = CreateAttachmentCommand::builder()
+            .owner(owner)
+            .attachment_target(AttachmentTarget::Project)
+            .attachment_target_id(target_id)
+            .role(MediaRole::Gallery)
+            .position(position)
+            .build()
+            .expect("valid attachment command");
+
+        (media, attachment)
+    }
+
+    fn service(
+        store: &InMemoryMediaStore,
+        limiter: RateLimiter,
+    ) -> CreateUploadBatchService<
+        InMemoryStorage,
+        InMemoryMediaStore,
+        InMemoryMediaStore,
+        InMemoryUnitOfWork,
+    > {
+        let uploads = CreateUploadMediaUrlService::new(InMemoryStorage, store.clone())
+            .with_rate_limiter(limiter)
+            .with_duplicate_check(Arc::new(store.clone()));
+        CreateUploadBatchService::new(uploads, store.clone(), InMemoryUnitOfWork)
+    }
+
+    #[tokio::test]
+    async fn test_batch_registers_every_file_in_order() {
+        let store = InMemoryMediaStore::default();
+        let owner: UserId = Uuid::new_v4().into();
+        let target_id = Uuid::new_v4();
+        let svc = service(&store, RateLimiter::disabled());
+        let command = CreateUploadBatchCommand::new(
+            owner,
+            vec![
+                upload(owner, target_id, "one.png", 3),
+                upload(owner, target_id, "two.png", 4),
+            ],
+        )
+        .unwrap();
+
+        let batch = svc.execute(command).await.unwrap();
+
+        assert_eq!(batch.uploads.len(), 2);
+        let items = store.batch_items(owner, batch.batch_id).await.unwrap();
+        let names: Vec<_> = items.iter().map(|i| i.original_filename.as_str()).collect();
+        assert_eq!(names, ["one.png", "two.png"]);
+        assert_eq!(items[1].media_id, batch.uploads[1].media_id);
+        assert!(items.iter().all(|i| i.status == MediaState::Pending));
+        assert!(store
+            .batch_items(Uuid::new_v4().into(), batch.batch_id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_batch_over_the_limit_counts_nothing() {
+        let store = InMemoryMediaStore::default();
+        let owner: UserId = Uuid::new_v4().into();
+        let target_id = Uuid::new_v4();
+        let limiter = RateLimiter::new(3, Duration::from_secs(3600));
+        let svc = service(&store, limiter.clone());
+        let uploads = (0..4)
+            .map(|i| upload(owner, target_id, &format!("{i}.png"), i))
+            .collect();
+
+        let err = svc
+            .execute(CreateUploadBatchCommand::new(owner, uploads).unwrap())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, CreateUploadBatchError::RateLimited { .. }));
+        assert_eq!(limiter.remaining(&owner.value().to_string()), Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_known_file_fails_the_batch_with_its_index() {
+        let store = InMemoryMediaStore::default();
+        let owner: UserId = Uuid::new_v4().into();
+        let target_id = Uuid::new_v4();
+        let limiter = RateLimiter::new(10, Duration::from_secs(3600));
+        let svc = service(&store, limiter.clone());
+        let (media, attachment) = upload(owner, target_id, "known.png", 1);
+        let uploads = CreateUploadMediaUrlService::new(InMemoryStorage, store.clone());
+        let known = uploads.execute(media, attachment).await.unwrap();
+
+        let err = svc
+            .execute(
+                CreateUploadBatchCommand::new(
+                    owner,
+                    vec![
+                        upload(owner, target_id, "new.png", 0),
+                        upload(owner, target_id, "again.png", 1),
+                    ],
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap_err();
+
+        match err {
+            CreateUploadBatchError::Upload {
+                index: 1,
+                error: CreateUrlError::Duplicate { media_id },
+            } => assert_eq!(media_id, known.media_id),
+            other => panic!("expected a duplicate at files[1], got {other:?}"),
+        }
+        assert_eq!(limiter.remaining(&owner.value().to_string()), Some(10));
+    }
+
+    #[test]
+    fn test_command_bounds_the_batch_size() {
+        let owner: UserId = Uuid::new_v4().into();
+        let target_id = Uuid::new_v4();
+        let too_many = (0..=MAX_UPLOAD_BATCH as u8)
+            .map(|i| upload(owner, target_id, "a.png", i))
+            .collect();
+
+        assert!(CreateUploadBatchCommand::new(owner, vec![]).is_err());
+        assert!(CreateUploadBatchCommand::new(owner, too_many).is_err());
+    }
+}
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use std::sync::Arc;
 
 use crate::multimedia::application::ports::{
     incoming::use_cases::{
@@ -6,10 +7,11 @@ use crate::multimedia::application::ports::{
         CreateUploadMediaUrlUseCase, CreateUrlError,
     },
     outgoing::{
-        cloud_storage::{MediaInfo, StorageQuery},
-        db::{MediaRepository, RecordMediaError, RecordMediaTx},
+        cloud_storage::{MediaInfo, StorageQuery, UploadConstraints},
+        db::{MediaQuery, MediaRepository, RecordMediaError, RecordMediaTx, StorageOverrideStore},
     },
 };
+use crate::shared::rate_limit::RateLimiter;
 
 pub struct CreateUploadMediaUrlService<Q, R>
 where
@@ -18,6 +20,14 @@ where
 {
     storage_query: Q,
     repository: R,
+    /// Keyed by owner; every issued URL holds a pending media row and storage
+    /// until the upload is processed or expires
+    rate_limiter: RateLimiter,
+    /// Looks up earlier uploads of a declared checksum; none skips the check
+    duplicates: Option<Arc<dyn MediaQuery>>,
+    /// Owners whose uploads go to their own bucket; none sends every upload
+    /// to the policy's
+    overrides: Option<Arc<dyn StorageOverrideStore>>,
 }
 
 impl<Q, R> CreateUploadMediaUrlService<Q, R>
@@ -29,24 +39,91 @@ where
         Self {
             storage_query,
             repository,
+            rate_limiter: RateLimiter::disabled(),
+            duplicates: None,
+            overrides: None,
         }
     }
+
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    pub fn with_duplicate_check(mut self, query: Arc<dyn MediaQuery>) -> Self {
+        self.duplicates = Some(query);
+        self
+    }
+
+    pub fn with_storage_overrides(mut self, overrides: Arc<dyn StorageOverrideStore>) -> Self {
+        self.overrides = Some(overrides);
+        self
+    }
 }
 
-#[async_trait]
-impl<Q, R> CreateUploadMediaUrlUseCase for CreateUploadMediaUrlService<Q, R>
+impl<Q, R> CreateUploadMediaUrlService<Q, R>
 where
-    Q: StorageQuery + Send + Sync,
-    R: MediaRepository + Send + Sync,
+    Q: StorageQuery,
+    R: MediaRepository,
 {
-    async fn execute(
+    pub(super) fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    /// An identical file the owner already stored can be reused instead;
+    /// answering so reserves nothing, so it is checked before counting
+    pub(super) async fn reject_duplicate(
+        &self,
+        media_command: &CreateMediaCommand,
+    ) -> Result<(), CreateUrlError> {
+        if let (Some(query), Some(checksum), false) = (
+            &self.duplicates,
+            media_command.checksum_sha256(),
+            media_command.allow_duplicate(),
+        ) {
+            let existing = query
+                .find_duplicate(*media_command.owner(), checksum)
+                .await
+                .map_err(|e| CreateUrlError::RepositoryError(e.to_string()))?;
+            if let Some(existing) = existing {
+                return Err(CreateUrlError::Duplicate {
+                    media_id: existing.media_id,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Records the media and its attachment and signs the URL to upload it
+    /// to; the URL has been counted already
+    pub(super) async fn register(
         &self,
         media_command: CreateMediaCommand,
         attachment_command: CreateAttachmentCommand,
     ) -> Result<CreateMediaResult, CreateUrlError> {
+        let mut media = media_command.to_new_media();
+        let mut tenant = None;
+        if let Some(overrides) = &self.overrides {
+            let found = overrides
+                .find_by_owner(*media_command.owner())
+                .await
+                .map_err(|e| CreateUrlError::RepositoryError(e.to_string()))?;
+            if let Some(found) = found {
+                media.bucket_name = found.upload_bucket;
+                tenant = Some(found.tenant);
+            }
+        }
+
+        // The URL only accepts the declared type, at most the declared size
+        let constraints = UploadConstraints {
+            content_type: media_command.mime_type().to_string(),
+            max_bytes: media_command.file_size_bytes(),
+            tenant,
+        };
+
         // 1) Persist media + attachment atomically.
         let tx = RecordMediaTx {
-            media: media_command.to_new_media(),
+            media,
             attachment: attachment_command.to_new_attachment(),
         };
 
@@ -69,19 +146,42 @@ where
 
         // 4) Ask storage adapter for signed upload URL (async).
         // IMPORTANT: Avoid `map_err(Into::into)` ambiguity by mapping explicitly.
-        let url = self
+        let signed = self
             .storage_query
-            .get_signed_upload_url(media_info)
+            .get_signed_upload_url(media_info, constraints)
             .await
             .map_err(CreateUrlError::from)?;
 
         Ok(CreateMediaResult {
-            url,
+            url: signed.url,
+            upload_headers: signed.headers,
             media_id: recorded.media_id,
         })
     }
 }
 
+#[async_trait]
+impl<Q, R> CreateUploadMediaUrlUseCase for CreateUploadMediaUrlService<Q, R>
+where
+    Q: StorageQuery + Send + Sync,
+    R: MediaRepository + Send + Sync,
+{
+    async fn execute(
+        &self,
+        media_command: CreateMediaCommand,
+        attachment_command: CreateAttachmentCommand,
+    ) -> Result<CreateMediaResult, CreateUrlError> {
+        self.reject_duplicate(&media_command).await?;
+
+        // 0) Count the URL before anything is reserved for it.
+        self.rate_limiter
+            .hit(&media_command.owner().value().to_string())
+            .map_err(|retry_after| CreateUrlError::RateLimited { retry_after })?;
+
+        self.register(media_command, attachment_command).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -90,12 +190,15 @@ mod tests {
 
     use crate::multimedia::application::{
         domain::{
-            entities::{AttachmentTarget, MediaRole, MediaState, MediaStateInfo, MediaVariant},
+            entities::{
+                AttachmentTarget, MediaFailure, MediaRole, MediaState, MediaStateInfo, MediaVariant,
+            },
             policies::upload_policy::UploadPolicy,
         },
         ports::outgoing::{
             cloud_storage::{
-                ManifestInfo, MediaInfo, SignUrlError, StorageQuery, StorageQueryError,
+                ManifestInfo, MediaInfo, SignUrlError, SignedUpload, StorageQuery,
+                StorageQueryError,
             },
             db::{
                 MediaRepository, MediaRepositoryError, MediaVariantRecord, RecordMediaError,
@@ -144,6 +247,22 @@ mod tests {
             Err(MediaRepositoryError::DatabaseError("not used".into()))
         }
 
+        async fn transition_media_state(
+            &self,
+            _data: UpdateMediaStateData,
+            _from: MediaState,
+        ) -> Result<Option<MediaStateInfo>, MediaRepositoryError> {
+            Err(MediaRepositoryError::DatabaseError("not used".into()))
+        }
+
+        async fn fail_media(
+            &self,
+            _media: &MediaStateInfo,
+            _failure: MediaFailure,
+        ) -> Result<Option<MediaStateInfo>, MediaRepositoryError> {
+            Err(MediaRepositoryError::DatabaseError("not used".into()))
+        }
+
         async fn record_single_variant(
             &self,
             _data: MediaVariantRecord,
@@ -163,6 +282,7 @@ mod tests {
     struct MockStorage {
         result: Arc<Mutex<Result<String, SignUrlError>>>,
         captured_info: Arc<Mutex<Option<MediaInfo>>>,
+        captured_constraints: Arc<Mutex<Option<UploadConstraints>>>,
     }
 
     impl MockStorage {
@@ -170,12 +290,17 @@ mod tests {
             Self {
                 result: Arc::new(Mutex::new(result)),
                 captured_info: Arc::new(Mutex::new(None)),
+                captured_constraints: Arc::new(Mutex::new(None)),
             }
         }
 
         fn captured(&self) -> Option<MediaInfo> {
             self.captured_info.lock().unwrap().clone()
         }
+
+        fn captured_constraints(&self) -> Option<UploadConstraints> {
+            self.captured_constraints.lock().unwrap().clone()
+        }
     }
 
     #[async_trait::async_trait]
@@ -183,9 +308,13 @@ mod tests {
         async fn get_signed_upload_url(
             &self,
             media_info: MediaInfo,
-        ) -> Result<String, SignUrlError> {
+            constraints: UploadConstraints,
+        ) -> Result<SignedUpload, SignUrlError> {
             *self.captured_info.lock().unwrap() = Some(media_info);
-            self.result.lock().unwrap().clone()
+            let headers = [("content-type".to_string(), constraints.content_type.clone())].into();
+            *self.captured_constraints.lock().unwrap() = Some(constraints);
+            let url = self.result.lock().unwrap().clone()?;
+            Ok(SignedUpload { url, headers })
         }
         async fn get_signed_read_url(
             &self,
@@ -193,6 +322,9 @@ mod tests {
         ) -> Result<String, SignUrlError> {
             unimplemented!()
         }
+        async fn object_exists(&self, _media_info: MediaInfo) -> Result<bool, StorageQueryError> {
+            unimplemented!()
+        }
         async fn get_latest_manifest(
             &self,
             _media_id: &str,
@@ -209,7 +341,9 @@ mod tests {
         // If your UploadPolicy doesn’t allow struct literal construction, adjust here.
         UploadPolicy {
             max_file_size_bytes: 5 * 1024 * 1024,
+            max_document_size_bytes: 20 * 1024 * 1024,
             max_width_height_px: 6000,
+            max_total_pixels: 20_000_000,
             max_file_name_len: 255,
             allowed_mime_types: &["image/jpeg", "image/png", "image/webp"],
             bucket_name: bucket.to_string(),
@@ -312,6 +446,14 @@ mod tests {
         // object_name should be "<uuid>.png" (generated from recorded media_id + ext)
         // We can’t know the uuid here easily, but we can ensure it ends with ".png"
         assert!(info.object_name().ends_with(".png"));
+
+        // The declared type and size are bound into the URL
+        let constraints = storage
+            .captured_constraints()
+            .expect("constraints should be passed");
+        assert_eq!(constraints.content_type, "image/png");
+        assert_eq!(constraints.max_bytes, 1024);
+        assert_eq!(media_result.upload_headers["content-type"], "image/png");
     }
 
     #[tokio::test]
@@ -377,6 +519,132 @@ mod tests {
         assert!(storage.captured().is_none());
     }
 
+    #[tokio::test]
+    async fn execute_over_the_rate_limit_is_rejected_before_recording() {
+        let target = dummy_attachment_target();
+        let repo = MockRepo::new(Ok(recorded_media("bucket-a", "cat.png", target)));
+        let storage = MockStorage::new(Ok("https://signed.example/upload".into()));
+
+        let svc = CreateUploadMediaUrlService::new(storage, repo.clone())
+            .with_rate_limiter(RateLimiter::new(1, std::time::Duration::from_secs(3600)));
+
+        let (media_cmd, attachment_cmd) = build_valid_commands("bucket-a");
+        svc.execute(media_cmd.clone(), attachment_cmd.clone())
+            .await
+            .expect("first URL is within the limit");
+        *repo.captured_tx.lock().unwrap() = None;
+
+        let err = svc.execute(media_cmd, attachment_cmd).await.unwrap_err();
+
+        match err {
+            CreateUrlError::RateLimited { retry_after } => {
+                assert!(retry_after <= std::time::Duration::from_secs(3600))
+            }
+            other => panic!("expected RateLimited, got: {other:?}"),
+        }
+        assert!(repo.captured().is_none());
+    }
+
+    #[tokio::test]
+    async fn execute_declared_duplicate_is_reported_instead_of_recorded() {
+        use crate::multimedia::adapter::outgoing::db::InMemoryMediaStore;
+
+        let policy = policy_with_bucket("bucket-a");
+        let owner = dummy_user_id();
+        let checksum = "AB".repeat(32);
+        let store = InMemoryMediaStore::default();
+        let svc =
+            CreateUploadMediaUrlService::new(MockStorage::new(Ok("url".into())), store.clone())
+                .with_duplicate_check(Arc::new(store.clone()));
+        let command = |allow_duplicate| {
+            CreateMediaCommand::builder()
+                .owner(owner)
+                .file_name("cat.png".to_string())
+                .mime_type("image/png".to_string())
+                .file_size_bytes(1024)
+                .checksum_sha256(Some(checksum.clone()))
+                .allow_duplicate(allow_duplicate)
+                .build(&policy)
+                .expect("valid media command")
+        };
+        let (_, attachment) = build_valid_commands("bucket-a");
+        let stored = svc
+            .execute(command(false), attachment.clone())
+            .await
+            .expect("first upload is new");
+
+        let err = svc
+            .execute(command(false), attachment.clone())
+            .await
+            .unwrap_err();
+        match err {
+            CreateUrlError::Duplicate { media_id } => assert_eq!(media_id, stored.media_id),
+            other => panic!("expected Duplicate, got: {other:?}"),
+        }
+
+        let again = svc
+            .execute(command(true), attachment)
+            .await
+            .expect("duplicates are allowed on request");
+        assert_ne!(again.media_id, stored.media_id);
+    }
+
+    #[tokio::test]
+    async fn execute_uploads_to_the_owners_bucket_when_overridden() {
+        use crate::multimedia::adapter::outgoing::db::{
+            InMemoryMediaStore, InMemoryStorageOverrides,
+        };
+        use crate::multimedia::application::ports::outgoing::db::StorageOverride;
+
+        let overrides = InMemoryStorageOverrides::default();
+        let storage = MockStorage::new(Ok("url".into()));
+        let svc = CreateUploadMediaUrlService::new(storage.clone(), InMemoryMediaStore::default())
+            .with_storage_overrides(Arc::new(overrides.clone()));
+        let (media_cmd, attachment_cmd) = build_valid_commands("bucket-a");
+        overrides
+            .save(StorageOverride {
+                owner: *media_cmd.owner(),
+                upload_bucket: "jane-uploads".to_string(),
+                ready_bucket: "jane-ready".to_string(),
+                tenant: "jane".to_string(),
+                credentials: "{}".to_string(),
+                updated_at: chrono::Utc::now(),
+            })
+            .await
+            .unwrap();
+
+        svc.execute(media_cmd, attachment_cmd.clone())
+            .await
+            .unwrap();
+        assert_eq!(storage.captured().unwrap().bucket_name(), "jane-uploads");
+        assert_eq!(
+            storage.captured_constraints().unwrap().tenant.as_deref(),
+            Some("jane")
+        );
+
+        let (others, _) = build_valid_commands("bucket-a");
+        svc.execute(others, attachment_cmd).await.unwrap();
+        assert_eq!(storage.captured().unwrap().bucket_name(), "bucket-a");
+        assert_eq!(storage.captured_constraints().unwrap().tenant, None);
+    }
+
+    #[test]
+    fn build_rejects_a_malformed_checksum() {
+        let err = CreateMediaCommand::builder()
+            .owner(dummy_user_id())
+            .file_name("cat.png".to_string())
+            .mime_type("image/png".to_string())
+            .file_size_bytes(1024)
+            .checksum_sha256(Some("not-a-digest".to_string()))
+            .build(&policy_with_bucket("bucket-a"))
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            crate::multimedia::application::ports::incoming::use_cases::UploadUrlCommandError::InvalidChecksum
+        ));
+    }
+
     #[tokio::test]
     async fn execute_storage_error_maps_to_create_url_storage_error() {
         let target = dummy_attachment_target();
@@ -0,0 +1,299 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::multimedia::application::{
+    domain::entities::MediaState,
+    ports::{
+        incoming::use_cases::{FinalizeUploadError, FinalizeUploadResult, FinalizeUploadUseCase},
+        outgoing::{
+            cloud_storage::{MediaInfo, StorageQuery},
+            db::{MediaQuery, MediaRepository, UpdateMediaStateData},
+        },
+    },
+};
+
+pub struct FinalizeUploadService<Q, S, R>
+where
+    Q: MediaQuery,
+    S: StorageQuery,
+    R: MediaRepository,
+{
+    query: Q,
+    storage: S,
+    repository: R,
+}
+
+impl<Q, S, R> FinalizeUploadService<Q, S, R>
+where
+    Q: MediaQuery,
+    S: StorageQuery,
+    R: MediaRepository,
+{
+    pub fn new(query: Q, storage: S, repository: R) -> Self {
+        Self {
+            query,
+            storage,
+            repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<Q, S, R> FinalizeUploadUseCase for FinalizeUploadService<Q, S, R>
+where
+    Q: MediaQuery,
+    S: StorageQuery,
+    R: MediaRepository,
+{
+    async fn execute(
+        &self,
+        owner: UserId,
+        media_id: Uuid,
+    ) -> Result<FinalizeUploadResult, FinalizeUploadError> {
+        let object = self.query.get_upload_object(media_id).await?;
+        if object.owner != owner {
+            return Err(FinalizeUploadError::MediaNotFound);
+        }
+
+        // Finalized before, or the processor got there first
+        if object.status != MediaState::Pending {
+            return Ok(FinalizeUploadResult {
+                media_id,
+                status: object.status,
+            });
+        }
+
+        let media_info = MediaInfo::try_new(
+            object.bucket_name,
+            object.object_key,
+            object.attachment_target,
+        )
+        .map_err(|e| FinalizeUploadError::StorageError(e.to_string()))?;
+        let uploaded = self
+            .storage
+            .object_exists(media_info)
+            .await
+            .map_err(|e| FinalizeUploadError::StorageError(e.to_string()))?;
+        if !uploaded {
+            return Err(FinalizeUploadError::NotUploaded);
+        }
+
+        let moved = self
+            .repository
+            .transition_media_state(
+                UpdateMediaStateData {
+                    owner,
+                    media_id,
+                    status: MediaState::Processing,
+                },
+                MediaState::Pending,
+            )
+            .await?;
+
+        let status = match moved {
+            Some(info) => info.status,
+            // Changed since it was read; report where it is now
+            None => self.query.get_upload_object(media_id).await?.status,
+        };
+
+        Ok(FinalizeUploadResult { media_id, status })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    use crate::multimedia::adapter::outgoing::db::InMemoryMediaStore;
+    use crate::multimedia::application::domain::entities::{AttachmentTarget, MediaRole};
+    use crate::multimedia::application::ports::outgoing::cloud_storage::{
+        ManifestInfo, SignUrlError, SignedUpload, StorageQueryError, UploadConstraints,
+    };
+    use crate::multimedia::application::ports::outgoing::db::{
+        NewMedia, NewMediaAttachment, RecordMediaTx,
+    };
+
+    /// Has the objects in `uploaded`
+    #[derive(Clone, Default)]
+    struct MockStorage {
+        uploaded: Arc<Mutex<Vec<String>>>,
+        fails: bool,
+    }
+
+    #[async_trait]
+    impl StorageQuery for MockStorage {
+        async fn get_signed_upload_url(
+            &self,
+            _media_info: MediaInfo,
+            _constraints: UploadConstraints,
+        ) -> Result<SignedUpload, SignUrlError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn get_signed_read_url(
+            &self,
+            _media_info: MediaInfo,
+        ) -> Result<String, SignUrlError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn object_exists(&self, media_info: MediaInfo) -> Result<bool, StorageQueryError> {
+            if self.fails {
+                return Err(StorageQueryError::NetworkInterrupted);
+            }
+            Ok(self
+                .uploaded
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|key| key == media_info.object_name()))
+        }
+
+        async fn get_latest_manifest(
+            &self,
+            _media_id: &str,
+        ) -> Result<ManifestInfo, StorageQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+    }
+
+    async fn pending_media(store: &InMemoryMediaStore, owner: UserId) -> Uuid {
+        store
+            .record_media_tx(RecordMediaTx {
+                media: NewMedia {
+                    owner,
+                    state: MediaState::Pending,
+                    bucket_name: "uploads".to_string(),
+                    original_name: "cat.png".to_string(),
+                    mime_type: "image/png".to_string(),
+                    file_size_bytes: 1024,
+                    width_px: None,
+                    height_px: None,
+                    duration_seconds: None,
+                    checksum_sha256: None,
+                },
+                attachment: NewMediaAttachment {
+                    owner,
+                    attachment_target: AttachmentTarget::Project,
+                    attachment_target_id: Uuid::new_v4(),
+                    role: MediaRole::Gallery,
+                    position: 0,
+                    alt_text: None,
+                    caption: None,
+                },
+            })
+            .await
+            .unwrap()
+            .media_id
+    }
+
+    fn service(
+        store: &InMemoryMediaStore,
+        storage: MockStorage,
+    ) -> FinalizeUploadService<InMemoryMediaStore, MockStorage, InMemoryMediaStore> {
+        FinalizeUploadService::new(store.clone(), storage, store.clone())
+    }
+
+    #[tokio::test]
+    async fn uploaded_media_moves_to_processing() {
+        let store = InMemoryMediaStore::default();
+        let owner = UserId::from(Uuid::new_v4());
+        let media_id = pending_media(&store, owner).await;
+        let storage = MockStorage::default();
+        storage
+            .uploaded
+            .lock()
+            .unwrap()
+            .push(format!("{media_id}/cat.png"));
+        let before = store.get_state(media_id).await.unwrap().updated_at;
+
+        let result = service(&store, storage)
+            .execute(owner, media_id)
+            .await
+            .unwrap();
+
+        assert_eq!(result.status, MediaState::Processing);
+        let state = store.get_state(media_id).await.unwrap();
+        assert_eq!(state.status, MediaState::Processing);
+        // The stuck-media clock starts over
+        assert!(state.updated_at >= before);
+    }
+
+    #[tokio::test]
+    async fn media_never_uploaded_stays_pending() {
+        let store = InMemoryMediaStore::default();
+        let owner = UserId::from(Uuid::new_v4());
+        let media_id = pending_media(&store, owner).await;
+
+        let err = service(&store, MockStorage::default())
+            .execute(owner, media_id)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, FinalizeUploadError::NotUploaded));
+        assert_eq!(
+            store.get_state(media_id).await.unwrap().status,
+            MediaState::Pending
+        );
+    }
+
+    #[tokio::test]
+    async fn finalizing_again_reports_the_current_state() {
+        let store = InMemoryMediaStore::default();
+        let owner = UserId::from(Uuid::new_v4());
+        let media_id = pending_media(&store, owner).await;
+        store
+            .set_media_state(UpdateMediaStateData {
+                owner,
+                media_id,
+                status: MediaState::Ready,
+            })
+            .await
+            .unwrap();
+
+        // Storage is not asked again
+        let storage = MockStorage {
+            fails: true,
+            ..MockStorage::default()
+        };
+        let result = service(&store, storage)
+            .execute(owner, media_id)
+            .await
+            .unwrap();
+
+        assert_eq!(result.status, MediaState::Ready);
+    }
+
+    #[tokio::test]
+    async fn another_owners_media_is_not_found() {
+        let store = InMemoryMediaStore::default();
+        let media_id = pending_media(&store, UserId::from(Uuid::new_v4())).await;
+
+        let err = service(&store, MockStorage::default())
+            .execute(UserId::from(Uuid::new_v4()), media_id)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, FinalizeUploadError::MediaNotFound));
+    }
+
+    #[tokio::test]
+    async fn storage_failure_is_a_storage_error() {
+        let store = InMemoryMediaStore::default();
+        let owner = UserId::from(Uuid::new_v4());
+        let media_id = pending_media(&store, owner).await;
+        let storage = MockStorage {
+            fails: true,
+            ..MockStorage::default()
+        };
+
+        let err = service(&store, storage)
+            .execute(owner, media_id)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, FinalizeUploadError::StorageError(_)));
+    }
+}
@@ -0,0 +1,275 @@
+use async_trait::async_trait;
+
+use super::create_get_variant_url_service::{map_query_error, sign_variant};
+use crate::multimedia::application::{
+    domain::entities::{MediaRole, MediaSize, MediaState},
+    ports::{
+        incoming::use_cases::{
+            GetPreviewImageCommand, GetPreviewImageUseCase, GetReadUrlError, PreviewImage,
+        },
+        outgoing::{cloud_storage::StorageQuery, db::MediaQuery},
+    },
+};
+
+/// Roles an image may stand for its target with, best first
+const PREVIEW_ROLES: [MediaRole; 3] =
+    [MediaRole::Cover, MediaRole::Screenshoot, MediaRole::Gallery];
+
+/// Variants a preview may use, best first: previews render around 500px wide
+const PREVIEW_SIZES: [MediaSize; 3] = [MediaSize::Medium, MediaSize::Large, MediaSize::Small];
+
+pub struct GetPreviewImageService<S, M>
+where
+    S: StorageQuery,
+    M: MediaQuery,
+{
+    storage_query: S,
+    media_query: M,
+}
+
+impl<S, M> GetPreviewImageService<S, M>
+where
+    S: StorageQuery,
+    M: MediaQuery,
+{
+    pub fn new(storage_query: S, media_query: M) -> Self {
+        Self {
+            storage_query,
+            media_query,
+        }
+    }
+}
+
+#[async_trait]
+impl<S, M> GetPreviewImageUseCase for GetPreviewImageService<S, M>
+where
+    S: StorageQuery,
+    M: MediaQuery,
+{
+    async fn execute(
+        &self,
+        command: GetPreviewImageCommand,
+    ) -> Result<Option<PreviewImage>, GetReadUrlError> {
+        let attachments = self
+            .media_query
+            .list_by_target(command.owner, command.attachment_target)
+            .await
+            .map_err(map_query_error)?;
+
+        let best = attachments
+            .into_iter()
+            .filter(|media| {
+                media.attachment_target_id == command.attachment_target_id
+                    && media.status == MediaState::Ready
+            })
+            .filter_map(|media| {
+                let rank = PREVIEW_ROLES.iter().position(|role| *role == media.role)?;
+                let variant = PREVIEW_SIZES
+                    .iter()
+                    .find_map(|size| media.variants.iter().find(|v| v.size == *size))?
+                    .clone();
+                Some((rank, media.position, media, variant))
+            })
+            .min_by_key(|(rank, position, ..)| (*rank, *position));
+
+        let Some((_, _, media, variant)) = best else {
+            return Ok(None);
+        };
+
+        let signed = sign_variant(&self.storage_query, &media, command.owner, variant.size).await?;
+        Ok(Some(PreviewImage {
+            media_id: media.media_id,
+            url: signed.url,
+            width: variant.width,
+            height: variant.height,
+            alt_text: media.alt_text,
+            expires_at: signed.expires_at,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    use crate::auth::application::domain::entities::UserId;
+    use crate::multimedia::adapter::outgoing::{
+        cloud_storage::InMemoryStorage, db::InMemoryMediaStore,
+    };
+    use crate::multimedia::application::{
+        domain::entities::AttachmentTarget,
+        ports::outgoing::db::{
+            MediaRepository, MediaVariantRecord, NewMedia, NewMediaAttachment, RecordMediaTx,
+            UpdateMediaStateData,
+        },
+    };
+
+    struct Image {
+        role: MediaRole,
+        position: u8,
+        state: MediaState,
+        sizes: Vec<MediaSize>,
+    }
+
+    fn image(role: MediaRole, position: u8) -> Image {
+        Image {
+            role,
+            position,
+            state: MediaState::Ready,
+            sizes: vec![MediaSize::Thumbnail, MediaSize::Medium, MediaSize::Large],
+        }
+    }
+
+    /// Attaches `image` to the project `target_id`; returns its id
+    async fn attach(
+        store: &InMemoryMediaStore,
+        owner: UserId,
+        target_id: Uuid,
+        image: Image,
+    ) -> Uuid {
+        let media_id = store
+            .record_media_tx(RecordMediaTx {
+                media: NewMedia {
+                    owner,
+                    state: MediaState::Pending,
+                    bucket_name: "media-upload".to_string(),
+                    original_name: "shot.png".to_string(),
+                    mime_type: "image/png".to_string(),
+                    file_size_bytes: 4096,
+                    width_px: None,
+                    height_px: None,
+                    duration_seconds: None,
+                    checksum_sha256: None,
+                },
+                attachment: NewMediaAttachment {
+                    owner,
+                    attachment_target: AttachmentTarget::Project,
+                    attachment_target_id: target_id,
+                    role: image.role,
+                    position: image.position,
+                    alt_text: Some("The dashboard".to_string()),
+                    caption: None,
+                },
+            })
+            .await
+            .unwrap()
+            .media_id;
+        store
+            .record_variants(
+                image
+                    .sizes
+                    .into_iter()
+                    .map(|size| MediaVariantRecord {
+                        owner,
+                        media_id,
+                        object_key: format!("{media_id}/{size}.webp"),
+                        size,
+                        bucket_name: "media-ready".to_string(),
+                        mime_type: "image/webp".to_string(),
+                        file_size_bytes: 2048,
+                        width_px: Some(800),
+                        height_px: Some(450),
+                        checksum_sha256: None,
+                    })
+                    .collect(),
+            )
+            .await
+            .unwrap();
+        store
+            .set_media_state(UpdateMediaStateData {
+                owner,
+                media_id,
+                status: image.state,
+            })
+            .await
+            .unwrap();
+        media_id
+    }
+
+    async fn preview(
+        store: &InMemoryMediaStore,
+        owner: UserId,
+        target_id: Uuid,
+    ) -> Option<PreviewImage> {
+        GetPreviewImageService::new(InMemoryStorage, store.clone())
+            .execute(GetPreviewImageCommand {
+                owner,
+                attachment_target: AttachmentTarget::Project,
+                attachment_target_id: target_id,
+            })
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_cover_wins_over_earlier_screenshots() {
+        let store = InMemoryMediaStore::default();
+        let owner: UserId = Uuid::new_v4().into();
+        let target_id = Uuid::new_v4();
+        attach(&store, owner, target_id, image(MediaRole::Screenshoot, 0)).await;
+        let cover = attach(&store, owner, target_id, image(MediaRole::Cover, 3)).await;
+
+        let preview = preview(&store, owner, target_id).await.unwrap();
+
+        assert_eq!(preview.media_id, cover);
+        assert_eq!(
+            preview.url,
+            format!("memory://media-ready/{cover}/medium.webp")
+        );
+        assert_eq!((preview.width, preview.height), (800, 450));
+        assert_eq!(preview.alt_text, "The dashboard");
+    }
+
+    #[tokio::test]
+    async fn test_first_ready_screenshot_with_a_usable_variant() {
+        let store = InMemoryMediaStore::default();
+        let owner: UserId = Uuid::new_v4().into();
+        let target_id = Uuid::new_v4();
+        attach(
+            &store,
+            owner,
+            target_id,
+            Image {
+                state: MediaState::Processing,
+                ..image(MediaRole::Screenshoot, 0)
+            },
+        )
+        .await;
+        attach(
+            &store,
+            owner,
+            target_id,
+            Image {
+                sizes: vec![MediaSize::Thumbnail],
+                ..image(MediaRole::Screenshoot, 1)
+            },
+        )
+        .await;
+        let third = attach(
+            &store,
+            owner,
+            target_id,
+            Image {
+                sizes: vec![MediaSize::Large],
+                ..image(MediaRole::Screenshoot, 2)
+            },
+        )
+        .await;
+        attach(&store, owner, target_id, image(MediaRole::Inline, 0)).await;
+
+        let preview = preview(&store, owner, target_id).await.unwrap();
+
+        assert_eq!(preview.media_id, third);
+        assert!(preview.url.ends_with("/large.webp"));
+    }
+
+    #[tokio::test]
+    async fn test_no_preview_without_an_image_of_the_target() {
+        let store = InMemoryMediaStore::default();
+        let owner: UserId = Uuid::new_v4().into();
+        attach(&store, owner, Uuid::new_v4(), image(MediaRole::Cover, 0)).await;
+
+        assert!(preview(&store, owner, Uuid::new_v4()).await.is_none());
+    }
+}
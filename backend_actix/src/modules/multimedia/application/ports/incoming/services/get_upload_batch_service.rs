@@ -0,0 +1,115 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::multimedia::application::ports::{
+    incoming::use_cases::{GetUploadBatchError, GetUploadBatchUseCase, UploadBatchStatus},
+    outgoing::db::MediaUploadBatchRepository,
+};
+
+#[derive(Debug, Clone)]
+pub struct GetUploadBatchService<B>
+where
+    B: MediaUploadBatchRepository,
+{
+    batches: B,
+}
+
+impl<B> GetUploadBatchService<B>
+where
+    B: MediaUploadBatchRepository,
+{
+    pub fn new(batches: B) -> Self {
+        Self { batches }
+    }
+}
+
+#[async_trait]
+impl<B> GetUploadBatchUseCase for GetUploadBatchService<B>
+where
+    B: MediaUploadBatchRepository,
+{
+    async fn execute(
+        &self,
+        owner: UserId,
+        batch_id: Uuid,
+    ) -> Result<UploadBatchStatus, GetUploadBatchError> {
+        let items = self.batches.batch_items(owner, batch_id).await?;
+        if items.is_empty() {
+            return Err(GetUploadBatchError::NotFound);
+        }
+
+        Ok(UploadBatchStatus::new(batch_id, items))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::multimedia::application::{
+        domain::entities::MediaState,
+        ports::outgoing::db::{MediaRepositoryError, UploadBatchItem},
+    };
+
+    struct MockBatches(Vec<MediaState>);
+
+    #[async_trait]
+    impl MediaUploadBatchRepository for MockBatches {
+        async fn record_batch(
+            &self,
+            _batch_id: Uuid,
+            _media_ids: &[Uuid],
+        ) -> Result<(), MediaRepositoryError> {
+            Ok(())
+        }
+
+        async fn batch_items(
+            &self,
+            _owner: UserId,
+            _batch_id: Uuid,
+        ) -> Result<Vec<UploadBatchItem>, MediaRepositoryError> {
+            Ok(self
+                .0
+                .iter()
+                .map(|status| UploadBatchItem {
+                    media_id: Uuid::new_v4(),
+                    original_filename: "shot.png".to_string(),
+                    status: status.clone(),
+                    failure_code: None,
+                })
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_settles_once_nothing_is_in_flight() {
+        let owner: UserId = Uuid::new_v4().into();
+        let in_flight = GetUploadBatchService::new(MockBatches(vec![
+            MediaState::Ready,
+            MediaState::Processing,
+        ]));
+        let settled =
+            GetUploadBatchService::new(MockBatches(vec![MediaState::Ready, MediaState::Failed]));
+
+        let status = in_flight.execute(owner, Uuid::new_v4()).await.unwrap();
+        assert_eq!(status.items.len(), 2);
+        assert!(!status.settled);
+        assert!(
+            settled
+                .execute(owner, Uuid::new_v4())
+                .await
+                .unwrap()
+                .settled
+        );
+    }
+
+    #[tokio::test]
+    async fn test_empty_batch_is_not_found() {
+        let service = GetUploadBatchService::new(MockBatches(vec![]));
+
+        let result = service.execute(Uuid::new_v4().into(), Uuid::new_v4()).await;
+
+        assert!(matches!(result, Err(GetUploadBatchError::NotFound)));
+    }
+}
@@ -0,0 +1,300 @@
+use async_trait::async_trait;
+use futures::future::join_all;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::create_get_variant_url_service::{map_query_error, sign_variant};
+use crate::multimedia::application::ports::{
+    incoming::use_cases::{
+        GetReadUrlError, GetUrlsCommand, GetVariantReadUrlsUseCase, VariantUrlOutcome,
+    },
+    outgoing::{
+        cloud_storage::StorageQuery,
+        db::{MediaAttachment, MediaQuery},
+    },
+};
+
+pub struct GetVariantReadUrlsService<S, M>
+where
+    S: StorageQuery,
+    M: MediaQuery,
+{
+    storage_query: S,
+    media_query: M,
+}
+
+impl<S, M> GetVariantReadUrlsService<S, M>
+where
+    S: StorageQuery,
+    M: MediaQuery,
+{
+    pub fn new(storage_query: S, media_query: M) -> Self {
+        Self {
+            storage_query,
+            media_query,
+        }
+    }
+}
+
+#[async_trait]
+impl<S, M> GetVariantReadUrlsUseCase for GetVariantReadUrlsService<S, M>
+where
+    S: StorageQuery,
+    M: MediaQuery,
+{
+    async fn execute(
+        &self,
+        command: GetUrlsCommand,
+    ) -> Result<Vec<VariantUrlOutcome>, GetReadUrlError> {
+        let owner = command.owner;
+        let mut media_ids: Vec<Uuid> = command.variants.iter().map(|(id, _)| *id).collect();
+        media_ids.sort_unstable();
+        media_ids.dedup();
+
+        // One lookup for the whole batch, however many sizes of each media
+        let found: HashMap<Uuid, MediaAttachment> = self
+            .media_query
+            .get_attachment_infos(&media_ids)
+            .await
+            .map_err(map_query_error)?
+            .into_iter()
+            .map(|media| (media.media_id, media))
+            .collect();
+
+        // Signing may call out to the storage provider, so the URLs are
+        // signed side by side
+        Ok(
+            join_all(command.variants.into_iter().map(|(media_id, size)| {
+                let media = found.get(&media_id);
+                async move {
+                    match media {
+                        Some(media) => sign_variant(&self.storage_query, media, owner, size).await,
+                        None => Err(GetReadUrlError::MediaNotFound),
+                    }
+                }
+            }))
+            .await,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+
+    use crate::auth::application::domain::entities::UserId;
+    use crate::multimedia::application::domain::entities::{
+        AttachmentTarget, MediaRole, MediaSize, MediaState, MediaStateInfo,
+    };
+    use crate::multimedia::application::ports::outgoing::{
+        cloud_storage::{
+            ManifestInfo, MediaInfo, SignUrlError, SignedUpload, StorageQueryError,
+            UploadConstraints,
+        },
+        db::{MediaQueryError, StoredVariant, UploadObject},
+    };
+
+    /// Serves `media` and records the ids of every batch lookup
+    struct MockMediaQuery {
+        media: Vec<MediaAttachment>,
+        lookups: Mutex<Vec<Vec<Uuid>>>,
+    }
+
+    #[async_trait]
+    impl MediaQuery for MockMediaQuery {
+        async fn get_state(&self, _media_id: Uuid) -> Result<MediaStateInfo, MediaQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn list_by_target(
+            &self,
+            _owner: UserId,
+            _target: AttachmentTarget,
+        ) -> Result<Vec<MediaAttachment>, MediaQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn get_attachment_info(
+            &self,
+            _media_id: Uuid,
+        ) -> Result<MediaAttachment, MediaQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn get_attachment_infos(
+            &self,
+            media_ids: &[Uuid],
+        ) -> Result<Vec<MediaAttachment>, MediaQueryError> {
+            self.lookups.lock().unwrap().push(media_ids.to_vec());
+            Ok(self
+                .media
+                .iter()
+                .filter(|media| media_ids.contains(&media.media_id))
+                .cloned()
+                .collect())
+        }
+
+        async fn get_upload_object(
+            &self,
+            _media_id: Uuid,
+        ) -> Result<UploadObject, MediaQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn list_unsettled(
+            &self,
+            _updated_before: chrono::DateTime<Utc>,
+            _limit: u64,
+        ) -> Result<Vec<MediaStateInfo>, MediaQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn find_duplicate(
+            &self,
+            _owner: UserId,
+            _checksum_sha256: &str,
+        ) -> Result<Option<MediaStateInfo>, MediaQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn existing_media_ids(
+            &self,
+            _media_ids: &[Uuid],
+        ) -> Result<HashSet<Uuid>, MediaQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+    }
+
+    /// Signs every object as `signed:<object name>`
+    struct MockStorageQuery;
+
+    #[async_trait]
+    impl StorageQuery for MockStorageQuery {
+        async fn get_signed_upload_url(
+            &self,
+            _media_info: MediaInfo,
+            _constraints: UploadConstraints,
+        ) -> Result<SignedUpload, SignUrlError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn get_signed_read_url(&self, media_info: MediaInfo) -> Result<String, SignUrlError> {
+            Ok(format!("signed:{}", media_info.object_name()))
+        }
+
+        async fn object_exists(&self, _media_info: MediaInfo) -> Result<bool, StorageQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn get_latest_manifest(
+            &self,
+            _media_id: &str,
+        ) -> Result<ManifestInfo, StorageQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+    }
+
+    fn media(owner: UserId, status: MediaState) -> MediaAttachment {
+        let media_id = Uuid::new_v4();
+        MediaAttachment {
+            media_id,
+            owner,
+            attachment_target: AttachmentTarget::BlogPost,
+            attachment_target_id: Uuid::new_v4(),
+            status,
+            role: MediaRole::Gallery,
+            position: 0,
+            alt_text: String::new(),
+            caption: String::new(),
+            original_filename: "photo.jpg".to_string(),
+            variants: [MediaSize::Thumbnail, MediaSize::Large]
+                .into_iter()
+                .map(|size| StoredVariant {
+                    object_name: format!("{media_id}/{size}.webp"),
+                    size,
+                    bucket_name: "ready".to_string(),
+                    width: 800,
+                    height: 600,
+                    file_size_bytes: 1024,
+                    mime_type: "image/webp".to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_signs_a_gallery_with_one_lookup() {
+        let owner = UserId::from(Uuid::new_v4());
+        let first = media(owner, MediaState::Ready);
+        let second = media(owner, MediaState::Ready);
+        let query = MockMediaQuery {
+            media: vec![first.clone(), second.clone()],
+            lookups: Mutex::default(),
+        };
+        let service = GetVariantReadUrlsService::new(MockStorageQuery, query);
+
+        let outcomes = service
+            .execute(GetUrlsCommand {
+                owner,
+                variants: vec![
+                    (second.media_id, MediaSize::Thumbnail),
+                    (first.media_id, MediaSize::Thumbnail),
+                    (second.media_id, MediaSize::Large),
+                ],
+            })
+            .await
+            .unwrap();
+
+        let urls: Vec<String> = outcomes.into_iter().map(|o| o.unwrap().url).collect();
+        assert_eq!(
+            urls,
+            vec![
+                format!("signed:{}/thumbnail.webp", second.media_id),
+                format!("signed:{}/thumbnail.webp", first.media_id),
+                format!("signed:{}/large.webp", second.media_id),
+            ]
+        );
+        let lookups = service.media_query.lookups.lock().unwrap();
+        assert_eq!(lookups.len(), 1);
+        assert_eq!(lookups[0].len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_unreadable_variants_fail_on_their_own() {
+        let owner = UserId::from(Uuid::new_v4());
+        let ready = media(owner, MediaState::Ready);
+        let processing = media(owner, MediaState::Processing);
+        let someone_elses = media(UserId::from(Uuid::new_v4()), MediaState::Ready);
+        let query = MockMediaQuery {
+            media: vec![ready.clone(), processing.clone(), someone_elses.clone()],
+            lookups: Mutex::default(),
+        };
+        let service = GetVariantReadUrlsService::new(MockStorageQuery, query);
+
+        let outcomes = service
+            .execute(GetUrlsCommand {
+                owner,
+                variants: vec![
+                    (ready.media_id, MediaSize::Medium),
+                    (processing.media_id, MediaSize::Large),
+                    (someone_elses.media_id, MediaSize::Large),
+                    (Uuid::new_v4(), MediaSize::Large),
+                    (ready.media_id, MediaSize::Large),
+                ],
+            })
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            outcomes[0],
+            Err(GetReadUrlError::VariantNotFound(MediaSize::Medium))
+        ));
+        assert!(matches!(outcomes[1], Err(GetReadUrlError::MediaProcessing)));
+        assert!(matches!(outcomes[2], Err(GetReadUrlError::MediaNotFound)));
+        assert!(matches!(outcomes[3], Err(GetReadUrlError::MediaNotFound)));
+        assert!(outcomes[4].is_ok());
+    }
+}
@@ -0,0 +1,460 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shared_domain::manifest::{Manifest, ReadyManifest};
+use shared_domain::variants::VariantSize;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::multimedia::application::{
+    domain::entities::{MediaState, MediaStateInfo},
+    ports::{
+        incoming::use_cases::{
+            IngestMediaManifestError, IngestMediaManifestResult, IngestMediaManifestUseCase,
+            ManifestOutcome,
+        },
+        outgoing::db::{
+            MediaQuery, MediaRepository, MediaRetryQueue, MediaVariantRecord, UpdateMediaStateData,
+        },
+    },
+};
+
+/// Content type of a variant, from the extension the processor gave it
+fn variant_mime_type(path: &str) -> &'static str {
+    let ext = path
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase());
+    match ext.as_deref() {
+        Some("webp") => "image/webp",
+        Some("avif") => "image/avif",
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        _ => "application/octet-stream",
+    }
+}
+
+fn parse_time(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|at| at.with_timezone(&Utc))
+}
+
+pub struct IngestMediaManifestService<Q, R, F>
+where
+    Q: MediaQuery,
+    R: MediaRepository,
+    F: MediaRetryQueue,
+{
+    query: Q,
+    repository: R,
+    queue: F,
+    /// Where the processor writes variants
+    ready_bucket: String,
+}
+
+impl<Q, R, F> IngestMediaManifestService<Q, R, F>
+where
+    Q: MediaQuery,
+    R: MediaRepository,
+    F: MediaRetryQueue,
+{
+    pub fn new(query: Q, repository: R, queue: F, ready_bucket: String) -> Self {
+        Self {
+            query,
+            repository,
+            queue,
+            ready_bucket,
+        }
+    }
+
+    /// Variants of the sizes this server serves; the others are left out.
+    /// They are where the manifest says, else in the server's ready bucket.
+    fn variant_records(
+        &self,
+        media: &MediaStateInfo,
+        manifest: &ReadyManifest,
+    ) -> Vec<MediaVariantRecord> {
+        let bucket_name = manifest
+            .output_bucket
+            .clone()
+            .unwrap_or_else(|| self.ready_bucket.clone());
+        manifest
+            .variants
+            .iter()
+            .filter_map(|variant| {
+                let size = VariantSize::from_width(variant.size)?;
+                Some(MediaVariantRecord {
+                    owner: media.owner,
+                    media_id: media.media_id,
+                    size: size.into(),
+                    bucket_name: bucket_name.clone(),
+                    object_key: variant.path.clone(),
+                    mime_type: variant_mime_type(&variant.path).to_string(),
+                    file_size_bytes: variant.file_size_bytes as u64,
+                    width_px: Some(variant.width),
+                    height_px: Some(variant.height),
+                    checksum_sha256: Some(variant.sha256.clone()),
+                })
+            })
+            .collect()
+    }
+
+    /// Touched after the manifest was written, e.g. by a regeneration
+    fn changed_since(media: &MediaStateInfo, written_at: DateTime<Utc>) -> bool {
+        parse_time(&media.updated_at).is_some_and(|updated_at| updated_at > written_at)
+    }
+
+    async fn ingest_ready(
+        &self,
+        media: MediaStateInfo,
+        manifest: &ReadyManifest,
+        written_at: DateTime<Utc>,
+    ) -> Result<ManifestOutcome, IngestMediaManifestError> {
+        let unsettled = matches!(media.status, MediaState::Pending | MediaState::Processing);
+        if media.status == MediaState::Failed
+            || (unsettled && Self::changed_since(&media, written_at))
+        {
+            return Ok(ManifestOutcome::Ignored);
+        }
+
+        // Variants first, so a `ready` media always has them
+        self.repository
+            .record_variants(self.variant_records(&media, manifest))
+            .await?;
+        if !unsettled {
+            return Ok(ManifestOutcome::VariantsReplaced);
+        }
+
+        let moved = self
+            .repository
+            .transition_media_state(
+                UpdateMediaStateData {
+                    owner: media.owner,
+                    media_id: media.media_id,
+                    status: MediaState::Ready,
+                },
+                media.status.clone(),
+            )
+            .await?;
+        if moved.is_none() {
+            return Ok(ManifestOutcome::Ignored);
+        }
+
+        // A retry that succeeded no longer needs its queue entry
+        if let Err(e) = self.queue.remove(media.media_id).await {
+            warn!(media_id = %media.media_id, error = %e, "Removing media from retry queue failed");
+        }
+        Ok(ManifestOutcome::Ready)
+    }
+}
+
+#[async_trait]
+impl<Q, R, F> IngestMediaManifestUseCase for IngestMediaManifestService<Q, R, F>
+where
+    Q: MediaQuery,
+    R: MediaRepository,
+    F: MediaRetryQueue,
+{
+    async fn execute(
+        &self,
+        manifest: Manifest,
+    ) -> Result<IngestMediaManifestResult, IngestMediaManifestError> {
+        let (media_id, updated_at) = match &manifest {
+            Manifest::Ready(ready) => (&ready.media_id, &ready.updated_at),
+            Manifest::Failed {
+                media_id,
+                updated_at,
+                ..
+            } => (media_id, updated_at),
+        };
+        let media_id = Uuid::parse_str(media_id).map_err(|_| {
+            IngestMediaManifestError::InvalidManifest(format!("'{media_id}' is not a media id"))
+        })?;
+        let written_at = parse_time(updated_at).ok_or_else(|| {
+            IngestMediaManifestError::InvalidManifest(format!(
+                "'{updated_at}' is not an RFC 3339 time"
+            ))
+        })?;
+
+        let media = self.query.get_state(media_id).await?;
+        let outcome = match &manifest {
+            Manifest::Ready(ready) => self.ingest_ready(media, ready, written_at).await?,
+            Manifest::Failed { error, .. } => {
+                // Not final yet: the retry job decides
+                if self.queue.file(media_id, &error.code, written_at).await? {
+                    ManifestOutcome::QueuedForRetry
+                } else {
+                    ManifestOutcome::Ignored
+                }
+            }
+        };
+
+        Ok(IngestMediaManifestResult { media_id, outcome })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use shared_domain::error_codes;
+    use shared_domain::manifest::{
+        ManifestError, ManifestMetrics, ManifestOriginal, ManifestVariant, PIPELINE_VERSION,
+    };
+
+    use crate::auth::application::domain::entities::UserId;
+    use crate::multimedia::adapter::outgoing::db::InMemoryMediaStore;
+    use crate::multimedia::application::domain::entities::{
+        AttachmentTarget, MediaRole, MediaSize,
+    };
+    use crate::multimedia::application::ports::outgoing::db::{
+        NewMedia, NewMediaAttachment, RecordMediaTx,
+    };
+
+    async fn media_in(store: &InMemoryMediaStore, status: MediaState) -> MediaStateInfo {
+        let owner = UserId::from(Uuid::new_v4());
+        let media_id = store
+            .record_media_tx(RecordMediaTx {
+                media: NewMedia {
+                    owner,
+                    state: MediaState::Pending,
+                    bucket_name: "uploads".to_string(),
+                    original_name: "cat.png".to_string(),
+                    mime_type: "image/png".to_string(),
+                    file_size_bytes: 1024,
+                    width_px: None,
+                    height_px: None,
+                    duration_seconds: None,
+                    checksum_sha256: None,
+                },
+                attachment: NewMediaAttachment {
+                    owner,
+                    attachment_target: AttachmentTarget::Project,
+                    attachment_target_id: Uuid::new_v4(),
+                    role: MediaRole::Gallery,
+                    position: 0,
+                    alt_text: None,
+                    caption: None,
+                },
+            })
+            .await
+            .unwrap()
+            .media_id;
+        store
+            .set_media_state(UpdateMediaStateData {
+                owner,
+                media_id,
+                status,
+            })
+            .await
+            .unwrap()
+    }
+
+    fn ready(media_id: Uuid, written_at: DateTime<Utc>) -> Manifest {
+        let variant = |size: u32, height: u32| ManifestVariant {
+            size,
+            path: format!("variants/{media_id}/cat_{size}.webp"),
+            width: size,
+            height,
+            file_size_bytes: 2048,
+            sha256: "ab".repeat(32),
+            quality: 80,
+            has_alpha: false,
+            flattened_onto: None,
+            png_path: None,
+            png_sha256: None,
+        };
+        Manifest::Ready(Box::new(ReadyManifest {
+            media_id: media_id.to_string(),
+            pipeline_version: PIPELINE_VERSION.to_string(),
+            updated_at: written_at.to_rfc3339(),
+            kind: "image".to_string(),
+            tenant: None,
+            output_bucket: None,
+            original: ManifestOriginal {
+                bucket: "uploads".to_string(),
+                path: format!("{media_id}/cat.png"),
+                sha256: "cd".repeat(32),
+                width: Some(1600),
+                height: Some(1200),
+            },
+            svg: None,
+            passthrough: None,
+            // 640 is not a size this server serves
+            variants: vec![variant(150, 150), variant(768, 576), variant(640, 480)],
+            metrics: ManifestMetrics {
+                total_ms: 10,
+                download_ms: 2,
+                processing_ms: 5,
+                upload_ms: 3,
+                encoder: "webp".to_string(),
+                quality: 80,
+                resize_filter: "lanczos3".to_string(),
+            },
+        }))
+    }
+
+    fn failed(media_id: Uuid, code: &str, written_at: DateTime<Utc>) -> Manifest {
+        Manifest::Failed {
+            media_id: media_id.to_string(),
+            pipeline_version: PIPELINE_VERSION.to_string(),
+            updated_at: written_at.to_rfc3339(),
+            error: ManifestError {
+                code: code.to_string(),
+                message: "Could not download".to_string(),
+                stage: "download".to_string(),
+            },
+        }
+    }
+
+    fn service(
+        store: &InMemoryMediaStore,
+    ) -> IngestMediaManifestService<InMemoryMediaStore, InMemoryMediaStore, InMemoryMediaStore>
+    {
+        IngestMediaManifestService::new(
+            store.clone(),
+            store.clone(),
+            store.clone(),
+            "media-ready".to_string(),
+        )
+    }
+
+    fn later() -> DateTime<Utc> {
+        Utc::now() + Duration::seconds(5)
+    }
+
+    #[tokio::test]
+    async fn ready_manifest_settles_the_media_with_its_variants() {
+        let store = InMemoryMediaStore::default();
+        let media = media_in(&store, MediaState::Processing).await;
+
+        let result = service(&store)
+            .execute(ready(media.media_id, later()))
+            .await
+            .unwrap();
+
+        assert_eq!(result.outcome, ManifestOutcome::Ready);
+        let attachment = store.get_attachment_info(media.media_id).await.unwrap();
+        assert_eq!(attachment.status, MediaState::Ready);
+        let sizes: Vec<_> = attachment.variants.iter().map(|v| v.size.clone()).collect();
+        assert_eq!(sizes, vec![MediaSize::Thumbnail, MediaSize::Medium]);
+        assert_eq!(attachment.variants[1].bucket_name, "media-ready");
+        assert_eq!(attachment.variants[1].mime_type, "image/webp");
+        assert_eq!(attachment.variants[1].height, 576);
+    }
+
+    #[tokio::test]
+    async fn ready_manifest_of_a_ready_media_replaces_its_variants() {
+        let store = InMemoryMediaStore::default();
+        let media = media_in(&store, MediaState::Ready).await;
+
+        let result = service(&store)
+            .execute(ready(media.media_id, later()))
+            .await
+            .unwrap();
+
+        assert_eq!(result.outcome, ManifestOutcome::VariantsReplaced);
+        let attachment = store.get_attachment_info(media.media_id).await.unwrap();
+        assert_eq!(attachment.variants.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn variants_are_recorded_in_the_bucket_they_were_written_to() {
+        let store = InMemoryMediaStore::default();
+        let media = media_in(&store, MediaState::Processing).await;
+        let Manifest::Ready(mut manifest) = ready(media.media_id, later()) else {
+            unreachable!()
+        };
+        manifest.output_bucket = Some("acme-ready".to_string());
+
+        service(&store)
+            .execute(Manifest::Ready(manifest))
+            .await
+            .unwrap();
+
+        let attachment = store.get_attachment_info(media.media_id).await.unwrap();
+        assert!(attachment
+            .variants
+            .iter()
+            .all(|v| v.bucket_name == "acme-ready"));
+    }
+
+    #[tokio::test]
+    async fn manifest_older_than_the_media_is_ignored() {
+        let store = InMemoryMediaStore::default();
+        let media = media_in(&store, MediaState::Processing).await;
+
+        let result = service(&store)
+            .execute(ready(media.media_id, Utc::now() - Duration::hours(1)))
+            .await
+            .unwrap();
+
+        assert_eq!(result.outcome, ManifestOutcome::Ignored);
+        let attachment = store.get_attachment_info(media.media_id).await.unwrap();
+        assert_eq!(attachment.status, MediaState::Processing);
+        assert!(attachment.variants.is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_manifest_is_queued_for_the_retry_job() {
+        let store = InMemoryMediaStore::default();
+        let media = media_in(&store, MediaState::Processing).await;
+
+        let result = service(&store)
+            .execute(failed(media.media_id, error_codes::DOWNLOAD_ERROR, later()))
+            .await
+            .unwrap();
+
+        assert_eq!(result.outcome, ManifestOutcome::QueuedForRetry);
+        let waiting = store.waiting(10).await.unwrap();
+        assert_eq!(waiting.len(), 1);
+        assert_eq!(waiting[0].failure_code, error_codes::DOWNLOAD_ERROR);
+        assert_eq!(waiting[0].object_key, format!("{}/cat.png", media.media_id));
+
+        // Its retry came back ready
+        service(&store)
+            .execute(ready(media.media_id, later() + Duration::seconds(1)))
+            .await
+            .unwrap();
+        assert!(store.waiting(10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_media_stays_failed() {
+        let store = InMemoryMediaStore::default();
+        let media = media_in(&store, MediaState::Failed).await;
+
+        for manifest in [
+            ready(media.media_id, later()),
+            failed(media.media_id, error_codes::DOWNLOAD_ERROR, later()),
+        ] {
+            let result = service(&store).execute(manifest).await.unwrap();
+            assert_eq!(result.outcome, ManifestOutcome::Ignored);
+        }
+        assert_eq!(
+            store.get_state(media.media_id).await.unwrap().status,
+            MediaState::Failed
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_media_and_bad_ids_are_errors() {
+        let store = InMemoryMediaStore::default();
+
+        let err = service(&store)
+            .execute(ready(Uuid::new_v4(), later()))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, IngestMediaManifestError::MediaNotFound));
+
+        let Manifest::Ready(mut manifest) = ready(Uuid::new_v4(), later()) else {
+            unreachable!()
+        };
+        manifest.media_id = "not-a-uuid".to_string();
+        let err = service(&store)
+            .execute(Manifest::Ready(manifest))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, IngestMediaManifestError::InvalidManifest(_)));
+    }
+}
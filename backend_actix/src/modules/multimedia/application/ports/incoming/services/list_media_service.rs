@@ -53,7 +53,7 @@ mod tests {
     };
     use crate::multimedia::application::ports::incoming::use_cases::ListMediaCommand;
     use crate::multimedia::application::ports::outgoing::db::{
-        MediaAttachment, MediaQueryError, StoredVariant,
+        MediaAttachment, MediaQueryError, StoredVariant, UploadObject,
     };
 
     /// Simple mock query that:
@@ -107,6 +107,43 @@ mod tests {
         ) -> Result<MediaAttachment, MediaQueryError> {
             unimplemented!("not needed for these tests")
         }
+
+        async fn get_attachment_infos(
+            &self,
+            _media_ids: &[Uuid],
+        ) -> Result<Vec<MediaAttachment>, MediaQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn get_upload_object(
+            &self,
+            _media_id: Uuid,
+        ) -> Result<UploadObject, MediaQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn list_unsettled(
+            &self,
+            _updated_before: chrono::DateTime<chrono::Utc>,
+            _limit: u64,
+        ) -> Result<Vec<MediaStateInfo>, MediaQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn find_duplicate(
+            &self,
+            _owner: UserId,
+            _checksum_sha256: &str,
+        ) -> Result<Option<MediaStateInfo>, MediaQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn existing_media_ids(
+            &self,
+            _media_ids: &[Uuid],
+        ) -> Result<std::collections::HashSet<Uuid>, MediaQueryError> {
+            unimplemented!("not needed for these tests")
+        }
     }
 
     fn sample_attachment(owner: UserId, target: AttachmentTarget) -> MediaAttachment {
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::multimedia::application::ports::incoming::use_cases::{
+    ReconcileMediaCommand, ReconcileMediaUseCase,
+};
+use crate::shared::lifecycle::ShutdownSignal;
+
+/// Media updated more recently than this is left to the normal flow
+const SETTLE_AFTER_MINS: i64 = 30;
+
+/// Media checked per run
+const BATCH_SIZE: u64 = 500;
+
+/// Runs `ReconcileMediaUseCase` on a schedule, so stuck uploads settle and
+/// media past the processing deadline fails without anyone running the CLI.
+///
+/// Runs as a background job (`BackgroundJobs`) and is only started when an
+/// interval is configured.
+pub struct MediaReconciler {
+    reconcile: Arc<dyn ReconcileMediaUseCase>,
+    processing_timeout: chrono::Duration,
+    interval: Duration,
+}
+
+impl MediaReconciler {
+    pub fn new(
+        reconcile: Arc<dyn ReconcileMediaUseCase>,
+        processing_timeout: chrono::Duration,
+        interval: Duration,
+    ) -> Self {
+        Self {
+            reconcile,
+            processing_timeout,
+            interval,
+        }
+    }
+
+    pub async fn run(self, mut signal: ShutdownSignal) {
+        info!(
+            interval_secs = self.interval.as_secs(),
+            processing_timeout_mins = self.processing_timeout.num_minutes(),
+            "Media reconciler started"
+        );
+
+        loop {
+            tokio::select! {
+                _ = signal.wait() => break,
+                _ = tokio::time::sleep(self.interval) => {}
+            }
+
+            let command = ReconcileMediaCommand {
+                older_than: chrono::Duration::minutes(SETTLE_AFTER_MINS),
+                limit: BATCH_SIZE,
+                processing_timeout: self.processing_timeout,
+            };
+            match self.reconcile.execute(command).await {
+                Ok(report) if report.updated == 0 && report.timed_out == 0 => {}
+                Ok(report) => info!(
+                    checked = report.checked,
+                    updated = report.updated,
+                    timed_out = report.timed_out,
+                    errors = report.errors,
+                    "Reconciled media"
+                ),
+                Err(e) => warn!(error = %e, "Media reconcile failed"),
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::multimedia::application::ports::incoming::use_cases::{
+    RetryFailedMediaCommand, RetryFailedMediaUseCase,
+};
+use crate::shared::lifecycle::ShutdownSignal;
+
+/// How often the retry queue is read; the shortest backoff is a minute
+const POLL_INTERVAL_SECS: u64 = 30;
+
+/// Queued failures handled per run
+const BATCH_SIZE: u64 = 100;
+
+/// Runs `RetryFailedMediaUseCase` on a schedule, so a transient processor
+/// failure is sent back once its backoff is over and a permanent one reaches
+/// the owner as `failed`.
+///
+/// Runs as a background job (`BackgroundJobs`). It always runs, even with
+/// retries disabled, since queued failures are only surfaced from here.
+pub struct MediaRetrier {
+    retry: Arc<dyn RetryFailedMediaUseCase>,
+}
+
+impl MediaRetrier {
+    pub fn new(retry: Arc<dyn RetryFailedMediaUseCase>) -> Self {
+        Self { retry }
+    }
+
+    pub async fn run(self, mut signal: ShutdownSignal) {
+        info!(interval_secs = POLL_INTERVAL_SECS, "Media retrier started");
+
+        loop {
+            tokio::select! {
+                _ = signal.wait() => break,
+                _ = tokio::time::sleep(Duration::from_secs(POLL_INTERVAL_SECS)) => {}
+            }
+
+            let command = RetryFailedMediaCommand { limit: BATCH_SIZE };
+            match self.retry.execute(command).await {
+                Ok(report) if report.retried == 0 && report.failed == 0 && report.errors == 0 => {}
+                Ok(report) => info!(
+                    checked = report.checked,
+                    retried = report.retried,
+                    waiting = report.waiting,
+                    failed = report.failed,
+                    errors = report.errors,
+                    "Retried failed media"
+                ),
+                Err(e) => warn!(error = %e, "Media retry failed"),
+            }
+        }
+    }
+}
@@ -1,6 +1,40 @@
+mod backfill_screenshots_service;
+mod collect_orphan_objects_service;
 mod create_get_variant_url_service;
+mod create_upload_batch_service;
 mod create_upload_url_service;
+mod finalize_upload_service;
+mod get_preview_image_service;
+mod get_upload_batch_service;
+mod get_variant_read_urls_service;
+mod ingest_media_manifest_service;
 mod list_media_service;
+mod media_reconciler;
+mod media_retrier;
+mod orphan_object_collector;
+mod proxy_image_service;
+mod reconcile_media_service;
+mod regenerate_media_service;
+mod retry_failed_media_service;
+mod storage_override_service;
+pub use backfill_screenshots_service::BackfillScreenshotsService;
+pub use collect_orphan_objects_service::CollectOrphanObjectsService;
 pub use create_get_variant_url_service::GetVariantReadUrlService;
+pub use create_upload_batch_service::CreateUploadBatchService;
 pub use create_upload_url_service::CreateUploadMediaUrlService;
+pub use finalize_upload_service::FinalizeUploadService;
+pub use get_preview_image_service::GetPreviewImageService;
+pub use get_upload_batch_service::GetUploadBatchService;
+pub use get_variant_read_urls_service::GetVariantReadUrlsService;
+pub use ingest_media_manifest_service::IngestMediaManifestService;
 pub use list_media_service::ListMediaService;
+pub use media_reconciler::MediaReconciler;
+pub use media_retrier::MediaRetrier;
+pub use orphan_object_collector::OrphanObjectCollector;
+pub use proxy_image_service::ProxyImageService;
+pub use reconcile_media_service::ReconcileMediaService;
+pub use regenerate_media_service::RegenerateMediaService;
+pub use retry_failed_media_service::RetryFailedMediaService;
+pub use storage_override_service::{
+    DeleteStorageOverrideService, GetStorageOverrideService, SetStorageOverrideService,
+};
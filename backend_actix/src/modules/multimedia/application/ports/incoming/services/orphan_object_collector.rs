@@ -0,0 +1,68 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::multimedia::application::ports::incoming::use_cases::{
+    CollectOrphanObjectsCommand, CollectOrphanObjectsUseCase,
+};
+use crate::shared::lifecycle::ShutdownSignal;
+
+/// Runs `CollectOrphanObjectsUseCase` on a schedule.
+///
+/// Runs as a background job (`BackgroundJobs`) and is only started when an
+/// interval is configured. In dry-run mode it only logs what it would delete.
+pub struct OrphanObjectCollector {
+    collect: Arc<dyn CollectOrphanObjectsUseCase>,
+    grace: chrono::Duration,
+    dry_run: bool,
+    interval: Duration,
+}
+
+impl OrphanObjectCollector {
+    pub fn new(
+        collect: Arc<dyn CollectOrphanObjectsUseCase>,
+        grace: chrono::Duration,
+        dry_run: bool,
+        interval: Duration,
+    ) -> Self {
+        Self {
+            collect,
+            grace,
+            dry_run,
+            interval,
+        }
+    }
+
+    pub async fn run(self, mut signal: ShutdownSignal) {
+        info!(
+            interval_secs = self.interval.as_secs(),
+            grace_hours = self.grace.num_hours(),
+            dry_run = self.dry_run,
+            "Orphaned object collector started"
+        );
+
+        loop {
+            tokio::select! {
+                _ = signal.wait() => break,
+                _ = tokio::time::sleep(self.interval) => {}
+            }
+
+            let command = CollectOrphanObjectsCommand {
+                grace: self.grace,
+                dry_run: self.dry_run,
+            };
+            match self.collect.execute(command).await {
+                Ok(report) if report.orphaned == 0 && report.errors == 0 => {}
+                Ok(report) => info!(
+                    scanned = report.scanned,
+                    orphaned = report.orphaned,
+                    deleted = report.deleted,
+                    errors = report.errors,
+                    dry_run = self.dry_run,
+                    "Collected orphaned objects"
+                ),
+                Err(e) => warn!(error = %e, "Orphaned object collection failed"),
+            }
+        }
+    }
+}
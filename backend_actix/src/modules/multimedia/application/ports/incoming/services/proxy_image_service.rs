@@ -0,0 +1,318 @@
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+
+use crate::multimedia::application::{
+    domain::{entities::AttachmentTarget, policies::upload_policy::UploadPolicy},
+    ports::{
+        incoming::use_cases::{ProxyImageError, ProxyImageUseCase},
+        outgoing::cloud_storage::{
+            FetchedFile, MediaInfo, MediaTransfer, StorageQuery, TransferError, UploadConstraints,
+        },
+    },
+};
+use crate::shared::image_proxy::{self, AllowedOrigins};
+
+/// Proxied images live under this prefix of the output bucket. The name has
+/// no media id in it, so the orphan collector leaves them alone.
+const OBJECT_PREFIX: &str = "proxy";
+
+pub struct ProxyImageService<S, T>
+where
+    S: StorageQuery,
+    T: MediaTransfer,
+{
+    storage: S,
+    transfer: T,
+    policy: UploadPolicy,
+    /// The output bucket, where the image processor writes variants
+    bucket: String,
+    origins: AllowedOrigins,
+}
+
+impl<S, T> ProxyImageService<S, T>
+where
+    S: StorageQuery,
+    T: MediaTransfer,
+{
+    pub fn new(
+        storage: S,
+        transfer: T,
+        policy: UploadPolicy,
+        bucket: String,
+        origins: AllowedOrigins,
+    ) -> Self {
+        Self {
+            storage,
+            transfer,
+            policy,
+            bucket,
+            origins,
+        }
+    }
+
+    /// Download `url` and check it as an upload would be
+    async fn fetch(&self, url: &str) -> Result<FetchedFile, ProxyImageError> {
+        let file = self
+            .transfer
+            .download(url, self.policy.max_file_size_bytes)
+            .await
+            .map_err(|e| match e {
+                TransferError::NotFound => ProxyImageError::ImageNotFound,
+                TransferError::TooLarge(max) => ProxyImageError::TooLarge(max),
+                TransferError::Failed(msg) => ProxyImageError::FetchFailed(msg),
+            })?;
+
+        let accepted = self
+            .policy
+            .allowed_mime_types
+            .contains(&file.content_type.as_str())
+            && sniff(&file.bytes) == Some(file.content_type.as_str());
+        if !accepted {
+            return Err(ProxyImageError::UnsupportedType(file.content_type));
+        }
+        Ok(file)
+    }
+}
+
+/// The raster type the bytes start like. SVG is not proxied: it can carry
+/// script, and nothing in the bytes proves what it is.
+fn sniff(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP" {
+        Some("image/webp")
+    } else {
+        None
+    }
+}
+
+fn storage_error(e: impl std::fmt::Display) -> ProxyImageError {
+    ProxyImageError::StorageError(e.to_string())
+}
+
+/// One object per URL, so a changed image needs a new URL
+fn object_name(url: &str) -> String {
+    format!(
+        "{OBJECT_PREFIX}/{}",
+        hex::encode(Sha256::digest(url.as_bytes()))
+    )
+}
+
+#[async_trait]
+impl<S, T> ProxyImageUseCase for ProxyImageService<S, T>
+where
+    S: StorageQuery,
+    T: MediaTransfer,
+{
+    async fn execute(&self, url: &str) -> Result<String, ProxyImageError> {
+        let url = url.trim();
+        if image_proxy::origin_of(url).is_none() {
+            return Err(ProxyImageError::InvalidUrl);
+        }
+        if !self.origins.allows(url) {
+            return Err(ProxyImageError::OriginNotAllowed);
+        }
+
+        let media_info = MediaInfo::try_new(
+            self.bucket.clone(),
+            object_name(url),
+            AttachmentTarget::BlogPost,
+        )
+        .map_err(storage_error)?;
+
+        let cached = self
+            .storage
+            .object_exists(media_info.clone())
+            .await
+            .map_err(storage_error)?;
+        if !cached {
+            let file = self.fetch(url).await?;
+            let constraints = UploadConstraints {
+                content_type: file.content_type.clone(),
+                max_bytes: file.bytes.len() as u64,
+                tenant: None,
+            };
+            let signed = self
+                .storage
+                .get_signed_upload_url(media_info.clone(), constraints)
+                .await
+                .map_err(storage_error)?;
+            self.transfer
+                .upload(&signed, &file)
+                .await
+                .map_err(storage_error)?;
+        }
+
+        self.storage
+            .get_signed_read_url(media_info)
+            .await
+            .map_err(storage_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    use crate::multimedia::application::ports::outgoing::cloud_storage::{
+        ManifestInfo, SignUrlError, SignedUpload, StorageQueryError,
+    };
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+
+    /// A bucket of object names
+    #[derive(Clone, Default)]
+    struct MockStorage {
+        objects: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl StorageQuery for MockStorage {
+        async fn get_signed_upload_url(
+            &self,
+            media_info: MediaInfo,
+            _constraints: UploadConstraints,
+        ) -> Result<SignedUpload, SignUrlError> {
+            Ok(SignedUpload {
+                url: media_info.object_name().to_string(),
+                headers: Default::default(),
+            })
+        }
+
+        async fn get_signed_read_url(&self, media_info: MediaInfo) -> Result<String, SignUrlError> {
+            Ok(format!(
+                "https://signed.example/{}/{}",
+                media_info.bucket_name(),
+                media_info.object_name()
+            ))
+        }
+
+        async fn object_exists(&self, media_info: MediaInfo) -> Result<bool, StorageQueryError> {
+            let objects = self.objects.lock().unwrap();
+            Ok(objects.iter().any(|name| name == media_info.object_name()))
+        }
+
+        async fn get_latest_manifest(
+            &self,
+            _media_id: &str,
+        ) -> Result<ManifestInfo, StorageQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+    }
+
+    /// Serves `file` for every URL and counts downloads; uploads land in `storage`
+    #[derive(Clone)]
+    struct MockTransfer {
+        file: FetchedFile,
+        downloads: Arc<Mutex<usize>>,
+        storage: MockStorage,
+    }
+
+    #[async_trait]
+    impl MediaTransfer for MockTransfer {
+        async fn download(
+            &self,
+            _url: &str,
+            _max_bytes: u64,
+        ) -> Result<FetchedFile, TransferError> {
+            *self.downloads.lock().unwrap() += 1;
+            Ok(self.file.clone())
+        }
+
+        async fn upload(
+            &self,
+            upload: &SignedUpload,
+            _file: &FetchedFile,
+        ) -> Result<(), TransferError> {
+            self.storage
+                .objects
+                .lock()
+                .unwrap()
+                .push(upload.url.clone());
+            Ok(())
+        }
+    }
+
+    fn service_serving(
+        bytes: &[u8],
+        content_type: &str,
+    ) -> (
+        ProxyImageService<MockStorage, MockTransfer>,
+        Arc<Mutex<usize>>,
+    ) {
+        let storage = MockStorage::default();
+        let transfer = MockTransfer {
+            file: FetchedFile {
+                bytes: bytes.to_vec(),
+                content_type: content_type.to_string(),
+            },
+            downloads: Arc::default(),
+            storage: storage.clone(),
+        };
+        let downloads = Arc::clone(&transfer.downloads);
+        let origins = AllowedOrigins::new(vec!["https://i.imgur.com".parse().unwrap()]);
+        let service = ProxyImageService::new(
+            storage,
+            transfer,
+            UploadPolicy::new("uploads".to_string()),
+            "ready".to_string(),
+            origins,
+        );
+        (service, downloads)
+    }
+
+    #[tokio::test]
+    async fn test_fetches_once_then_serves_from_the_bucket() {
+        let (service, downloads) = service_serving(PNG, "image/png");
+
+        let first = service
+            .execute("https://i.imgur.com/cat.png")
+            .await
+            .unwrap();
+        let second = service
+            .execute("https://i.imgur.com/cat.png")
+            .await
+            .unwrap();
+
+        assert_eq!(first, second);
+        assert!(first.starts_with("https://signed.example/ready/proxy/"));
+        assert_eq!(*downloads.lock().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_refuses_other_origins_without_fetching() {
+        let (service, downloads) = service_serving(PNG, "image/png");
+
+        assert_eq!(
+            service.execute("https://example.com/cat.png").await,
+            Err(ProxyImageError::OriginNotAllowed)
+        );
+        assert_eq!(
+            service.execute("/cat.png").await,
+            Err(ProxyImageError::InvalidUrl)
+        );
+        assert_eq!(*downloads.lock().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_refuses_files_that_are_not_what_they_claim() {
+        for (bytes, content_type) in [
+            (
+                b"<svg xmlns=\"http://www.w3.org/2000/svg\"/>".as_slice(),
+                "image/svg+xml",
+            ),
+            (b"<html></html>".as_slice(), "image/png"),
+            (PNG, "text/html"),
+        ] {
+            let (service, _) = service_serving(bytes, content_type);
+
+            assert!(matches!(
+                service.execute("https://i.imgur.com/x").await,
+                Err(ProxyImageError::UnsupportedType(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,491 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tracing::warn;
+
+use crate::multimedia::application::{
+    domain::entities::{MediaFailure, MediaState, MediaStateInfo},
+    ports::{
+        incoming::use_cases::{
+            ReconcileMediaCommand, ReconcileMediaError, ReconcileMediaReport, ReconcileMediaUseCase,
+        },
+        outgoing::{
+            cloud_storage::{StorageQuery, StorageQueryError},
+            db::{MediaQuery, MediaRepository, UpdateMediaStateData},
+        },
+    },
+};
+use crate::shared::telemetry::{self, BusinessEvent};
+
+pub struct ReconcileMediaService<Q, S, R>
+where
+    Q: MediaQuery,
+    S: StorageQuery,
+    R: MediaRepository,
+{
+    query: Q,
+    storage: S,
+    repository: R,
+}
+
+impl<Q, S, R> ReconcileMediaService<Q, S, R>
+where
+    Q: MediaQuery,
+    S: StorageQuery,
+    R: MediaRepository,
+{
+    pub fn new(query: Q, storage: S, repository: R) -> Self {
+        Self {
+            query,
+            storage,
+            repository,
+        }
+    }
+
+    /// State to write for `media`, if the manifest moved it forward
+    fn settled_state(media: &MediaStateInfo, manifest_state: MediaState) -> Option<MediaState> {
+        match manifest_state {
+            // The processor never writes `pending`; nothing to learn from it
+            MediaState::Pending => None,
+            state if state == media.status => None,
+            state => Some(state),
+        }
+    }
+
+    /// Still `processing` and last touched before `deadline`
+    fn timed_out(media: &MediaStateInfo, deadline: DateTime<Utc>) -> bool {
+        media.status == MediaState::Processing
+            && DateTime::parse_from_rfc3339(&media.updated_at)
+                .is_ok_and(|updated_at| updated_at < deadline)
+    }
+}
+
+#[async_trait]
+impl<Q, S, R> ReconcileMediaUseCase for ReconcileMediaService<Q, S, R>
+where
+    Q: MediaQuery,
+    S: StorageQuery,
+    R: MediaRepository,
+{
+    async fn execute(
+        &self,
+        command: ReconcileMediaCommand,
+    ) -> Result<ReconcileMediaReport, ReconcileMediaError> {
+        let now = Utc::now();
+        let unsettled = self
+            .query
+            .list_unsettled(now - command.older_than, command.limit)
+            .await?;
+
+        let mut report = ReconcileMediaReport::default();
+
+        for media in unsettled {
+            report.checked += 1;
+
+            let manifest_state = match self
+                .storage
+                .get_latest_manifest(&media.media_id.to_string())
+                .await
+            {
+                Ok(manifest) => Some(manifest.status),
+                Err(StorageQueryError::ManifestNotFound | StorageQueryError::MediaIdNotFound) => {
+                    report.missing_manifest += 1;
+                    None
+                }
+                Err(e) => {
+                    warn!(media_id = %media.media_id, error = %e, "Manifest lookup failed");
+                    report.errors += 1;
+                    continue;
+                }
+            };
+
+            let Some(status) = manifest_state.and_then(|state| Self::settled_state(&media, state))
+            else {
+                if Self::timed_out(&media, now - command.processing_timeout) {
+                    match self
+                        .repository
+                        .fail_media(&media, MediaFailure::ProcessingTimeout)
+                        .await
+                    {
+                        Ok(Some(_)) => {
+                            telemetry::record(BusinessEvent::MediaFailed {
+                                user_id: media.owner.into(),
+                                media_id: media.media_id,
+                                reason: Some(MediaFailure::ProcessingTimeout.code()),
+                            });
+                            report.timed_out += 1;
+                        }
+                        // Settled meanwhile
+                        Ok(None) => {}
+                        Err(e) => {
+                            warn!(media_id = %media.media_id, error = %e, "Failing timed out media failed");
+                            report.errors += 1;
+                        }
+                    }
+                }
+                continue;
+            };
+
+            let failed = status == MediaState::Failed;
+            match self
+                .repository
+                .set_media_state(UpdateMediaStateData {
+                    owner: media.owner,
+                    media_id: media.media_id,
+                    status,
+                })
+                .await
+            {
+                Ok(_) => {
+                    if failed {
+                        telemetry::record(BusinessEvent::MediaFailed {
+                            user_id: media.owner.into(),
+                            media_id: media.media_id,
+                            reason: None,
+                        });
+                    }
+                    report.updated += 1;
+                }
+                Err(e) => {
+                    warn!(media_id = %media.media_id, error = %e, "Media state update failed");
+                    report.errors += 1;
+                }
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Duration};
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use uuid::Uuid;
+
+    use crate::auth::application::domain::entities::UserId;
+    use crate::multimedia::application::domain::entities::{AttachmentTarget, MediaVariant};
+    use crate::multimedia::application::ports::outgoing::cloud_storage::{
+        ManifestInfo, MediaInfo, SignUrlError, SignedUpload, UploadConstraints,
+    };
+    use crate::multimedia::application::ports::outgoing::db::{
+        MediaAttachment, MediaQueryError, MediaRepositoryError, MediaVariantRecord,
+        RecordMediaError, RecordMediaTx, RecordedMedia, UploadObject,
+    };
+
+    struct MockMediaQuery {
+        unsettled: Vec<MediaStateInfo>,
+    }
+
+    #[async_trait]
+    impl MediaQuery for MockMediaQuery {
+        async fn get_state(&self, _media_id: Uuid) -> Result<MediaStateInfo, MediaQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn list_by_target(
+            &self,
+            _owner: UserId,
+            _attachment_target: AttachmentTarget,
+        ) -> Result<Vec<MediaAttachment>, MediaQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn get_attachment_info(
+            &self,
+            _media_id: Uuid,
+        ) -> Result<MediaAttachment, MediaQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn get_attachment_infos(
+            &self,
+            _media_ids: &[Uuid],
+        ) -> Result<Vec<MediaAttachment>, MediaQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn get_upload_object(
+            &self,
+            _media_id: Uuid,
+        ) -> Result<UploadObject, MediaQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn list_unsettled(
+            &self,
+            _updated_before: DateTime<Utc>,
+            _limit: u64,
+        ) -> Result<Vec<MediaStateInfo>, MediaQueryError> {
+            Ok(self.unsettled.clone())
+        }
+
+        async fn find_duplicate(
+            &self,
+            _owner: UserId,
+            _checksum_sha256: &str,
+        ) -> Result<Option<MediaStateInfo>, MediaQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn existing_media_ids(
+            &self,
+            _media_ids: &[Uuid],
+        ) -> Result<std::collections::HashSet<Uuid>, MediaQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+    }
+
+    /// Manifests by media id; missing ids answer `ManifestNotFound`
+    struct MockStorageQuery {
+        manifests: HashMap<Uuid, Result<MediaState, StorageQueryError>>,
+    }
+
+    #[async_trait]
+    impl StorageQuery for MockStorageQuery {
+        async fn get_signed_upload_url(
+            &self,
+            _media_info: MediaInfo,
+            _constraints: UploadConstraints,
+        ) -> Result<SignedUpload, SignUrlError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn get_signed_read_url(
+            &self,
+            _media_info: MediaInfo,
+        ) -> Result<String, SignUrlError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn object_exists(&self, _media_info: MediaInfo) -> Result<bool, StorageQueryError> {
+            unimplemented!("not needed for these tests")
+        }
+        async fn get_latest_manifest(
+            &self,
+            media_id: &str,
+        ) -> Result<ManifestInfo, StorageQueryError> {
+            let id = Uuid::parse_str(media_id).unwrap();
+            match self.manifests.get(&id) {
+                Some(Ok(status)) => Ok(ManifestInfo {
+                    media_id: media_id.to_string(),
+                    updated_at: Utc::now().to_rfc3339(),
+                    status: status.clone(),
+                }),
+                Some(Err(e)) => Err(e.clone()),
+                None => Err(StorageQueryError::ManifestNotFound),
+            }
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct MockMediaRepository {
+        updates: Arc<Mutex<Vec<(Uuid, MediaState)>>>,
+        failures: Arc<Mutex<Vec<(Uuid, MediaFailure)>>>,
+    }
+
+    #[async_trait]
+    impl MediaRepository for MockMediaRepository {
+        async fn record_media_tx(
+            &self,
+            _tx: RecordMediaTx,
+        ) -> Result<RecordedMedia, RecordMediaError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn set_media_state(
+            &self,
+            data: UpdateMediaStateData,
+        ) -> Result<MediaStateInfo, MediaRepositoryError> {
+            self.updates
+                .lock()
+                .unwrap()
+                .push((data.media_id, data.status.clone()));
+            Ok(MediaStateInfo {
+                owner: data.owner,
+                media_id: data.media_id,
+                updated_at: Utc::now().to_rfc3339(),
+                status: data.status,
+            })
+        }
+
+        async fn transition_media_state(
+            &self,
+            _data: UpdateMediaStateData,
+            _from: MediaState,
+        ) -> Result<Option<MediaStateInfo>, MediaRepositoryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn fail_media(
+            &self,
+            media: &MediaStateInfo,
+            failure: MediaFailure,
+        ) -> Result<Option<MediaStateInfo>, MediaRepositoryError> {
+            self.failures
+                .lock()
+                .unwrap()
+                .push((media.media_id, failure));
+            Ok(Some(MediaStateInfo {
+                status: MediaState::Failed,
+                ..media.clone()
+            }))
+        }
+
+        async fn record_single_variant(
+            &self,
+            _data: MediaVariantRecord,
+        ) -> Result<MediaVariant, MediaRepositoryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn record_variants(
+            &self,
+            _data: Vec<MediaVariantRecord>,
+        ) -> Result<Vec<MediaVariant>, MediaRepositoryError> {
+            unimplemented!("not needed for these tests")
+        }
+    }
+
+    fn unsettled(status: MediaState) -> MediaStateInfo {
+        MediaStateInfo {
+            owner: UserId::from(Uuid::new_v4()),
+            media_id: Uuid::new_v4(),
+            updated_at: (Utc::now() - Duration::hours(2)).to_rfc3339(),
+            status,
+        }
+    }
+
+    fn command() -> ReconcileMediaCommand {
+        ReconcileMediaCommand {
+            older_than: Duration::minutes(30),
+            limit: 100,
+            processing_timeout: Duration::hours(3),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_applies_settled_manifests() {
+        let ready = unsettled(MediaState::Processing);
+        let failed = unsettled(MediaState::Pending);
+        let still_processing = unsettled(MediaState::Processing);
+        let no_manifest = unsettled(MediaState::Pending);
+
+        let storage = MockStorageQuery {
+            manifests: HashMap::from([
+                (ready.media_id, Ok(MediaState::Ready)),
+                (failed.media_id, Ok(MediaState::Failed)),
+                (still_processing.media_id, Ok(MediaState::Processing)),
+            ]),
+        };
+        let repository = MockMediaRepository::default();
+        let service = ReconcileMediaService::new(
+            MockMediaQuery {
+                unsettled: vec![ready.clone(), failed.clone(), still_processing, no_manifest],
+            },
+            storage,
+            repository.clone(),
+        );
+
+        let report = service.execute(command()).await.unwrap();
+
+        assert_eq!(
+            report,
+            ReconcileMediaReport {
+                checked: 4,
+                updated: 2,
+                missing_manifest: 1,
+                timed_out: 0,
+                errors: 0,
+            }
+        );
+        assert_eq!(
+            *repository.updates.lock().unwrap(),
+            vec![
+                (ready.media_id, MediaState::Ready),
+                (failed.media_id, MediaState::Failed),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_counts_storage_errors_and_continues() {
+        let broken = unsettled(MediaState::Processing);
+        let ready = unsettled(MediaState::Processing);
+
+        let storage = MockStorageQuery {
+            manifests: HashMap::from([
+                (broken.media_id, Err(StorageQueryError::NetworkInterrupted)),
+                (ready.media_id, Ok(MediaState::Ready)),
+            ]),
+        };
+        let service = ReconcileMediaService::new(
+            MockMediaQuery {
+                unsettled: vec![broken, ready],
+            },
+            storage,
+            MockMediaRepository::default(),
+        );
+
+        let report = service.execute(command()).await.unwrap();
+
+        assert_eq!(report.checked, 2);
+        assert_eq!(report.updated, 1);
+        assert_eq!(report.errors, 1);
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_fails_media_processing_past_the_deadline() {
+        let stuck = unsettled(MediaState::Processing);
+        let stuck_with_manifest = unsettled(MediaState::Processing);
+        let pending = unsettled(MediaState::Pending);
+        let ready = unsettled(MediaState::Processing);
+
+        let storage = MockStorageQuery {
+            manifests: HashMap::from([
+                (stuck_with_manifest.media_id, Ok(MediaState::Processing)),
+                (ready.media_id, Ok(MediaState::Ready)),
+            ]),
+        };
+        let repository = MockMediaRepository::default();
+        let service = ReconcileMediaService::new(
+            MockMediaQuery {
+                unsettled: vec![
+                    stuck.clone(),
+                    stuck_with_manifest.clone(),
+                    pending,
+                    ready.clone(),
+                ],
+            },
+            storage,
+            repository.clone(),
+        );
+
+        let report = service
+            .execute(ReconcileMediaCommand {
+                processing_timeout: Duration::hours(1),
+                ..command()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(report.timed_out, 2);
+        assert_eq!(report.updated, 1);
+        assert_eq!(
+            *repository.failures.lock().unwrap(),
+            vec![
+                (stuck.media_id, MediaFailure::ProcessingTimeout),
+                (
+                    stuck_with_manifest.media_id,
+                    MediaFailure::ProcessingTimeout
+                ),
+            ]
+        );
+        // A result in the manifest wins over the deadline
+        assert_eq!(
+            *repository.updates.lock().unwrap(),
+            vec![(ready.media_id, MediaState::Ready)]
+        );
+    }
+}
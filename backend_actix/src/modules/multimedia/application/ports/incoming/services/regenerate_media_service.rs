@@ -0,0 +1,324 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::warn;
+
+use crate::multimedia::application::{
+    domain::entities::MediaState,
+    ports::{
+        incoming::use_cases::{
+            RegenerateMediaCommand, RegenerateMediaError, RegenerateMediaResult,
+            RegenerateMediaUseCase, MAX_REGENERATE_WIDTHS, MAX_VARIANT_WIDTH,
+        },
+        outgoing::{
+            db::{MediaQuery, MediaRepository, UpdateMediaStateData},
+            processor::{MediaProcessorClient, MediaProcessorError, ReprocessRequest},
+        },
+    },
+};
+
+pub struct RegenerateMediaService<Q, R>
+where
+    Q: MediaQuery,
+    R: MediaRepository,
+{
+    query: Q,
+    repository: R,
+    processor: Arc<dyn MediaProcessorClient>,
+}
+
+impl<Q, R> RegenerateMediaService<Q, R>
+where
+    Q: MediaQuery,
+    R: MediaRepository,
+{
+    pub fn new(query: Q, repository: R, processor: Arc<dyn MediaProcessorClient>) -> Self {
+        Self {
+            query,
+            repository,
+            processor,
+        }
+    }
+}
+
+/// Sorted and deduplicated; empty, zero or too wide is refused
+fn check_widths(mut widths: Vec<u32>) -> Result<Vec<u32>, RegenerateMediaError> {
+    widths.sort_unstable();
+    widths.dedup();
+    if widths.is_empty() || widths.len() > MAX_REGENERATE_WIDTHS {
+        return Err(RegenerateMediaError::InvalidWidths(format!(
+            "give between 1 and {MAX_REGENERATE_WIDTHS} widths, or leave them out to rebuild every variant"
+        )));
+    }
+    if widths.iter().any(|&w| w == 0 || w > MAX_VARIANT_WIDTH) {
+        return Err(RegenerateMediaError::InvalidWidths(format!(
+            "widths must be between 1 and {MAX_VARIANT_WIDTH} pixels"
+        )));
+    }
+    Ok(widths)
+}
+
+#[async_trait]
+impl<Q, R> RegenerateMediaUseCase for RegenerateMediaService<Q, R>
+where
+    Q: MediaQuery,
+    R: MediaRepository,
+{
+    async fn execute(
+        &self,
+        command: RegenerateMediaCommand,
+    ) -> Result<RegenerateMediaResult, RegenerateMediaError> {
+        let RegenerateMediaCommand {
+            owner,
+            media_id,
+            widths,
+        } = command;
+        let widths = widths.map(check_widths).transpose()?;
+
+        let object = self.query.get_upload_object(media_id).await?;
+        if object.owner != owner {
+            return Err(RegenerateMediaError::MediaNotFound);
+        }
+        match object.status {
+            MediaState::Pending => return Err(RegenerateMediaError::MediaPending),
+            MediaState::Processing => return Err(RegenerateMediaError::MediaProcessing),
+            MediaState::Ready | MediaState::Failed => {}
+        }
+
+        // Back to `processing` first, so the processor's callback is not
+        // ignored as arriving for a settled media
+        let previous = object.status;
+        let moved = self
+            .repository
+            .transition_media_state(
+                UpdateMediaStateData {
+                    owner,
+                    media_id,
+                    status: MediaState::Processing,
+                },
+                previous.clone(),
+            )
+            .await?;
+        if moved.is_none() {
+            // Another request got there first
+            return Err(RegenerateMediaError::MediaProcessing);
+        }
+
+        let request = ReprocessRequest {
+            media_id,
+            bucket_name: object.bucket_name,
+            object_key: object.object_key,
+            widths: widths.clone(),
+        };
+        if let Err(err) = self.processor.reprocess(&request).await {
+            // Nothing was queued; put the media back where it was
+            let restored = self
+                .repository
+                .transition_media_state(
+                    UpdateMediaStateData {
+                        owner,
+                        media_id,
+                        status: previous,
+                    },
+                    MediaState::Processing,
+                )
+                .await;
+            if let Err(e) = restored {
+                warn!(
+                    "Could not restore media {} after a failed regeneration: {}",
+                    media_id, e
+                );
+            }
+            return Err(match err {
+                MediaProcessorError::NotConfigured => RegenerateMediaError::ProcessorNotConfigured,
+                MediaProcessorError::Failed(e) => RegenerateMediaError::ProcessorError(e),
+            });
+        }
+
+        Ok(RegenerateMediaResult {
+            media_id,
+            status: MediaState::Processing,
+            widths,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    use crate::auth::application::domain::entities::UserId;
+    use crate::multimedia::adapter::outgoing::db::InMemoryMediaStore;
+    use crate::multimedia::adapter::outgoing::processor::UnconfiguredMediaProcessor;
+    use crate::multimedia::application::domain::entities::{AttachmentTarget, MediaRole};
+    use crate::multimedia::application::ports::outgoing::db::{
+        NewMedia, NewMediaAttachment, RecordMediaTx,
+    };
+
+    /// Records every request it is sent
+    #[derive(Default)]
+    struct RecordingProcessor {
+        requests: Mutex<Vec<ReprocessRequest>>,
+    }
+
+    #[async_trait]
+    impl MediaProcessorClient for RecordingProcessor {
+        async fn reprocess(&self, request: &ReprocessRequest) -> Result<(), MediaProcessorError> {
+            self.requests.lock().unwrap().push(request.clone());
+            Ok(())
+        }
+    }
+
+    async fn media_in(store: &InMemoryMediaStore, owner: UserId, state: MediaState) -> Uuid {
+        let media_id = store
+            .record_media_tx(RecordMediaTx {
+                media: NewMedia {
+                    owner,
+                    state: MediaState::Pending,
+                    bucket_name: "uploads".to_string(),
+                    original_name: "cat.png".to_string(),
+                    mime_type: "image/png".to_string(),
+                    file_size_bytes: 1024,
+                    width_px: None,
+                    height_px: None,
+                    duration_seconds: None,
+                    checksum_sha256: None,
+                },
+                attachment: NewMediaAttachment {
+                    owner,
+                    attachment_target: AttachmentTarget::Project,
+                    attachment_target_id: Uuid::new_v4(),
+                    role: MediaRole::Gallery,
+                    position: 0,
+                    alt_text: None,
+                    caption: None,
+                },
+            })
+            .await
+            .unwrap()
+            .media_id;
+        store
+            .set_media_state(UpdateMediaStateData {
+                owner,
+                media_id,
+                status: state,
+            })
+            .await
+            .unwrap();
+        media_id
+    }
+
+    fn command(owner: UserId, media_id: Uuid, widths: Option<Vec<u32>>) -> RegenerateMediaCommand {
+        RegenerateMediaCommand {
+            owner,
+            media_id,
+            widths,
+        }
+    }
+
+    #[tokio::test]
+    async fn ready_media_is_sent_back_to_the_processor() {
+        let store = InMemoryMediaStore::default();
+        let owner = UserId::from(Uuid::new_v4());
+        let media_id = media_in(&store, owner, MediaState::Ready).await;
+        let processor = Arc::new(RecordingProcessor::default());
+        let service = RegenerateMediaService::new(store.clone(), store.clone(), processor.clone());
+
+        let result = service
+            .execute(command(owner, media_id, Some(vec![800, 320, 800])))
+            .await
+            .unwrap();
+
+        assert_eq!(result.status, MediaState::Processing);
+        assert_eq!(result.widths, Some(vec![320, 800]));
+        assert_eq!(
+            store.get_state(media_id).await.unwrap().status,
+            MediaState::Processing
+        );
+        let requests = processor.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].object_key, format!("{media_id}/cat.png"));
+        assert_eq!(requests[0].widths, Some(vec![320, 800]));
+    }
+
+    #[tokio::test]
+    async fn processor_failure_restores_the_state() {
+        let store = InMemoryMediaStore::default();
+        let owner = UserId::from(Uuid::new_v4());
+        let media_id = media_in(&store, owner, MediaState::Failed).await;
+        let service = RegenerateMediaService::new(
+            store.clone(),
+            store.clone(),
+            Arc::new(UnconfiguredMediaProcessor),
+        );
+
+        let err = service
+            .execute(command(owner, media_id, None))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, RegenerateMediaError::ProcessorNotConfigured));
+        assert_eq!(
+            store.get_state(media_id).await.unwrap().status,
+            MediaState::Failed
+        );
+    }
+
+    #[tokio::test]
+    async fn unsettled_media_is_refused() {
+        let store = InMemoryMediaStore::default();
+        let owner = UserId::from(Uuid::new_v4());
+        let pending = media_in(&store, owner, MediaState::Pending).await;
+        let processing = media_in(&store, owner, MediaState::Processing).await;
+        let processor = Arc::new(RecordingProcessor::default());
+        let service = RegenerateMediaService::new(store.clone(), store.clone(), processor.clone());
+
+        let pending_err = service
+            .execute(command(owner, pending, None))
+            .await
+            .unwrap_err();
+        let processing_err = service
+            .execute(command(owner, processing, None))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(pending_err, RegenerateMediaError::MediaPending));
+        assert!(matches!(
+            processing_err,
+            RegenerateMediaError::MediaProcessing
+        ));
+        assert!(processor.requests.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn another_owners_media_is_not_found() {
+        let store = InMemoryMediaStore::default();
+        let media_id = media_in(&store, UserId::from(Uuid::new_v4()), MediaState::Ready).await;
+        let service = RegenerateMediaService::new(
+            store.clone(),
+            store.clone(),
+            Arc::new(RecordingProcessor::default()),
+        );
+
+        let err = service
+            .execute(command(UserId::from(Uuid::new_v4()), media_id, None))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, RegenerateMediaError::MediaNotFound));
+    }
+
+    #[test]
+    fn widths_are_checked() {
+        assert_eq!(check_widths(vec![640, 320, 640]).unwrap(), vec![320, 640]);
+        for bad in [
+            vec![],
+            vec![0],
+            vec![MAX_VARIANT_WIDTH + 1],
+            (1..=MAX_REGENERATE_WIDTHS as u32 + 1).collect(),
+        ] {
+            assert!(check_widths(bad).is_err());
+        }
+    }
+}
@@ -0,0 +1,465 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use tracing::warn;
+
+use crate::multimedia::application::{
+    domain::{
+        entities::{MediaFailure, MediaState, MediaStateInfo},
+        policies::retry_policy::RetryPolicy,
+    },
+    ports::{
+        incoming::use_cases::{
+            RetryFailedMediaCommand, RetryFailedMediaError, RetryFailedMediaReport,
+            RetryFailedMediaUseCase,
+        },
+        outgoing::{
+            db::{MediaRepository, MediaRetryQueue, QueuedFailure, UpdateMediaStateData},
+            processor::{MediaProcessorClient, MediaProcessorError, ReprocessRequest},
+        },
+    },
+};
+use crate::shared::telemetry::{self, BusinessEvent};
+
+pub struct RetryFailedMediaService<Q, R>
+where
+    Q: MediaRetryQueue,
+    R: MediaRepository,
+{
+    queue: Q,
+    repository: R,
+    processor: Arc<dyn MediaProcessorClient>,
+    /// Upper bound of every code's retries; 0 fails everything at once
+    max_attempts: u32,
+}
+
+impl<Q, R> RetryFailedMediaService<Q, R>
+where
+    Q: MediaRetryQueue,
+    R: MediaRepository,
+{
+    pub fn new(
+        queue: Q,
+        repository: R,
+        processor: Arc<dyn MediaProcessorClient>,
+        max_attempts: u32,
+    ) -> Self {
+        Self {
+            queue,
+            repository,
+            processor,
+            max_attempts,
+        }
+    }
+
+    /// Marks the media `failed` with the processor's code and drops it from
+    /// the queue; the owner hears of it from here on
+    async fn give_up(
+        &self,
+        media: &MediaStateInfo,
+        failure_code: &str,
+        report: &mut RetryFailedMediaReport,
+    ) {
+        let failure = MediaFailure::from_processor_code(failure_code);
+        match self.repository.fail_media(media, failure).await {
+            Ok(Some(_)) => {
+                telemetry::record(BusinessEvent::MediaFailed {
+                    user_id: media.owner.into(),
+                    media_id: media.media_id,
+                    reason: Some(failure.code()),
+                });
+                report.failed += 1;
+            }
+            // Settled meanwhile
+            Ok(None) => {}
+            Err(e) => {
+                warn!(media_id = %media.media_id, error = %e, "Failing media failed");
+                report.errors += 1;
+                return;
+            }
+        }
+        self.remove(media, report).await;
+    }
+
+    async fn retry(&self, failure: &QueuedFailure, report: &mut RetryFailedMediaReport) {
+        let media = &failure.media;
+
+        // Touch the row, so the processing deadline counts from this run
+        let moved = self
+            .repository
+            .transition_media_state(
+                UpdateMediaStateData {
+                    owner: media.owner,
+                    media_id: media.media_id,
+                    status: MediaState::Processing,
+                },
+                media.status.clone(),
+            )
+            .await;
+        let media = match moved {
+            Ok(Some(media)) => media,
+            Ok(None) => return self.remove(media, report).await,
+            Err(e) => {
+                warn!(media_id = %media.media_id, error = %e, "Media state update failed");
+                report.errors += 1;
+                return;
+            }
+        };
+
+        let request = ReprocessRequest {
+            media_id: media.media_id,
+            bucket_name: failure.bucket_name.clone(),
+            object_key: failure.object_key.clone(),
+            widths: None,
+        };
+        let attempts = failure.attempts + 1;
+        let recorded = match self.processor.reprocess(&request).await {
+            Ok(()) => {
+                report.retried += 1;
+                self.queue.mark_sent(media.media_id, attempts).await
+            }
+            // Nothing can run it again
+            Err(MediaProcessorError::NotConfigured) => {
+                return self.give_up(&media, &failure.failure_code, report).await;
+            }
+            Err(MediaProcessorError::Failed(e)) => {
+                warn!(media_id = %media.media_id, error = %e, "Media retry could not be sent");
+                report.errors += 1;
+                self.queue.postpone(media.media_id, attempts).await
+            }
+        };
+        if let Err(e) = recorded {
+            warn!(media_id = %media.media_id, error = %e, "Recording media retry failed");
+            report.errors += 1;
+        }
+    }
+
+    async fn remove(&self, media: &MediaStateInfo, report: &mut RetryFailedMediaReport) {
+        if let Err(e) = self.queue.remove(media.media_id).await {
+            warn!(media_id = %media.media_id, error = %e, "Removing media from retry queue failed");
+            report.errors += 1;
+        }
+    }
+}
+
+#[async_trait]
+impl<Q, R> RetryFailedMediaUseCase for RetryFailedMediaService<Q, R>
+where
+    Q: MediaRetryQueue,
+    R: MediaRepository,
+{
+    async fn execute(
+        &self,
+        command: RetryFailedMediaCommand,
+    ) -> Result<RetryFailedMediaReport, RetryFailedMediaError> {
+        let now = Utc::now();
+        let waiting = self.queue.waiting(command.limit).await?;
+
+        let mut report = RetryFailedMediaReport::default();
+
+        for failure in waiting {
+            report.checked += 1;
+
+            let policy = RetryPolicy::for_code(&failure.failure_code, self.max_attempts)
+                .filter(|policy| policy.allows(failure.attempts));
+            let Some(policy) = policy else {
+                self.give_up(&failure.media, &failure.failure_code, &mut report)
+                    .await;
+                continue;
+            };
+
+            if now < failure.failed_at + policy.delay(failure.attempts) {
+                report.waiting += 1;
+                continue;
+            }
+            self.retry(&failure, &mut report).await;
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Duration};
+    use shared_domain::error_codes;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    use crate::auth::application::domain::entities::UserId;
+    use crate::multimedia::adapter::outgoing::db::InMemoryMediaStore;
+    use crate::multimedia::adapter::outgoing::processor::UnconfiguredMediaProcessor;
+    use crate::multimedia::application::domain::entities::{AttachmentTarget, MediaRole};
+    use crate::multimedia::application::ports::outgoing::db::{
+        MediaQuery, MediaRepositoryError, NewMedia, NewMediaAttachment, RecordMediaTx,
+    };
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Call {
+        Sent(Uuid, u32),
+        Postponed(Uuid, u32),
+        Removed(Uuid),
+    }
+
+    #[derive(Default)]
+    struct MockQueue {
+        waiting: Vec<QueuedFailure>,
+        calls: Mutex<Vec<Call>>,
+    }
+
+    #[async_trait]
+    impl MediaRetryQueue for MockQueue {
+        async fn file(
+            &self,
+            _media_id: Uuid,
+            _failure_code: &str,
+            _failed_at: DateTime<Utc>,
+        ) -> Result<bool, MediaRepositoryError> {
+            Ok(true)
+        }
+
+        async fn waiting(&self, _limit: u64) -> Result<Vec<QueuedFailure>, MediaRepositoryError> {
+            Ok(self.waiting.clone())
+        }
+
+        async fn mark_sent(
+            &self,
+            media_id: Uuid,
+            attempts: u32,
+        ) -> Result<(), MediaRepositoryError> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(Call::Sent(media_id, attempts));
+            Ok(())
+        }
+
+        async fn postpone(
+            &self,
+            media_id: Uuid,
+            attempts: u32,
+        ) -> Result<(), MediaRepositoryError> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(Call::Postponed(media_id, attempts));
+            Ok(())
+        }
+
+        async fn remove(&self, media_id: Uuid) -> Result<(), MediaRepositoryError> {
+            self.calls.lock().unwrap().push(Call::Removed(media_id));
+            Ok(())
+        }
+    }
+
+    /// Records every request it is sent
+    #[derive(Default)]
+    struct RecordingProcessor {
+        requests: Mutex<Vec<ReprocessRequest>>,
+    }
+
+    #[async_trait]
+    impl MediaProcessorClient for RecordingProcessor {
+        async fn reprocess(&self, request: &ReprocessRequest) -> Result<(), MediaProcessorError> {
+            self.requests.lock().unwrap().push(request.clone());
+            Ok(())
+        }
+    }
+
+    async fn processing_media(store: &InMemoryMediaStore) -> MediaStateInfo {
+        let owner = UserId::from(Uuid::new_v4());
+        let media_id = store
+            .record_media_tx(RecordMediaTx {
+                media: NewMedia {
+                    owner,
+                    state: MediaState::Pending,
+                    bucket_name: "uploads".to_string(),
+                    original_name: "cat.png".to_string(),
+                    mime_type: "image/png".to_string(),
+                    file_size_bytes: 1024,
+                    width_px: None,
+                    height_px: None,
+                    duration_seconds: None,
+                    checksum_sha256: None,
+                },
+                attachment: NewMediaAttachment {
+                    owner,
+                    attachment_target: AttachmentTarget::Project,
+                    attachment_target_id: Uuid::new_v4(),
+                    role: MediaRole::Gallery,
+                    position: 0,
+                    alt_text: None,
+                    caption: None,
+                },
+            })
+            .await
+            .unwrap()
+            .media_id;
+        store
+            .set_media_state(UpdateMediaStateData {
+                owner,
+                media_id,
+                status: MediaState::Processing,
+            })
+            .await
+            .unwrap()
+    }
+
+    fn queued(
+        media: &MediaStateInfo,
+        code: &str,
+        attempts: u32,
+        failed_at: DateTime<Utc>,
+    ) -> QueuedFailure {
+        QueuedFailure {
+            media: media.clone(),
+            bucket_name: "uploads".to_string(),
+            object_key: format!("{}/cat.png", media.media_id),
+            failure_code: code.to_string(),
+            attempts,
+            failed_at,
+        }
+    }
+
+    fn command() -> RetryFailedMediaCommand {
+        RetryFailedMediaCommand { limit: 100 }
+    }
+
+    #[tokio::test]
+    async fn transient_failure_is_sent_back_to_the_processor() {
+        let store = InMemoryMediaStore::default();
+        let media = processing_media(&store).await;
+        let queue = MockQueue {
+            waiting: vec![queued(
+                &media,
+                error_codes::DOWNLOAD_ERROR,
+                1,
+                Utc::now() - Duration::minutes(5),
+            )],
+            ..Default::default()
+        };
+        let processor = Arc::new(RecordingProcessor::default());
+        let service = RetryFailedMediaService::new(queue, store.clone(), processor.clone(), 3);
+
+        let report = service.execute(command()).await.unwrap();
+
+        assert_eq!(report.retried, 1);
+        assert_eq!(
+            *service.queue.calls.lock().unwrap(),
+            vec![Call::Sent(media.media_id, 2)]
+        );
+        {
+            let requests = processor.requests.lock().unwrap();
+            assert_eq!(
+                requests[0].object_key,
+                format!("{}/cat.png", media.media_id)
+            );
+            assert_eq!(requests[0].widths, None);
+        }
+        assert_eq!(
+            store.get_state(media.media_id).await.unwrap().status,
+            MediaState::Processing
+        );
+    }
+
+    #[tokio::test]
+    async fn retry_waits_out_its_backoff() {
+        let store = InMemoryMediaStore::default();
+        let media = processing_media(&store).await;
+        // Third retry of a moderation outage: 20 minutes after the failure
+        let queue = MockQueue {
+            waiting: vec![queued(
+                &media,
+                error_codes::MODERATION_ERROR,
+                2,
+                Utc::now() - Duration::minutes(10),
+            )],
+            ..Default::default()
+        };
+        let processor = Arc::new(RecordingProcessor::default());
+        let service = RetryFailedMediaService::new(queue, store, processor.clone(), 3);
+
+        let report = service.execute(command()).await.unwrap();
+
+        assert_eq!(report.waiting, 1);
+        assert!(processor.requests.lock().unwrap().is_empty());
+        assert!(service.queue.calls.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn permanent_and_exhausted_failures_are_surfaced() {
+        let store = InMemoryMediaStore::default();
+        let rejected = processing_media(&store).await;
+        let exhausted = processing_media(&store).await;
+        let long_ago = Utc::now() - Duration::days(1);
+        let queue = MockQueue {
+            waiting: vec![
+                queued(&rejected, error_codes::DECODE_FAILED, 0, long_ago),
+                queued(&exhausted, error_codes::UPLOAD_ERROR, 3, long_ago),
+            ],
+            ..Default::default()
+        };
+        let processor = Arc::new(RecordingProcessor::default());
+        let service = RetryFailedMediaService::new(queue, store.clone(), processor.clone(), 3);
+
+        let report = service.execute(command()).await.unwrap();
+
+        assert_eq!(report.failed, 2);
+        assert!(processor.requests.lock().unwrap().is_empty());
+        for media in [&rejected, &exhausted] {
+            assert_eq!(
+                store.get_state(media.media_id).await.unwrap().status,
+                MediaState::Failed
+            );
+        }
+        assert_eq!(
+            *service.queue.calls.lock().unwrap(),
+            vec![
+                Call::Removed(rejected.media_id),
+                Call::Removed(exhausted.media_id)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn without_a_processor_nothing_is_retried() {
+        let store = InMemoryMediaStore::default();
+        let media = processing_media(&store).await;
+        let queue = MockQueue {
+            waiting: vec![queued(
+                &media,
+                error_codes::UPLOAD_ERROR,
+                0,
+                Utc::now() - Duration::hours(1),
+            )],
+            ..Default::default()
+        };
+        let service = RetryFailedMediaService::new(
+            queue,
+            store.clone(),
+            Arc::new(UnconfiguredMediaProcessor),
+            3,
+        );
+
+        let report = service.execute(command()).await.unwrap();
+
+        assert_eq!(report.failed, 1);
+        assert_eq!(
+            store.get_state(media.media_id).await.unwrap().status,
+            MediaState::Failed
+        );
+    }
+
+    #[test]
+    fn unknown_codes_fail_as_internal_errors() {
+        assert_eq!(
+            MediaFailure::from_processor_code("SOMETHING_NEW").code(),
+            error_codes::INTERNAL_ERROR
+        );
+        assert_eq!(
+            MediaFailure::from_processor_code(error_codes::DECODE_FAILED).code(),
+            error_codes::DECODE_FAILED
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::multimedia::application::ports::outgoing::db::MediaRepositoryError;
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum BackfillScreenshotsError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+impl From<MediaRepositoryError> for BackfillScreenshotsError {
+    fn from(err: MediaRepositoryError) -> Self {
+        Self::RepositoryError(err.to_string())
+    }
+}
+
+pub struct BackfillScreenshotsCommand {
+    pub limit: u64,
+    /// Try again URLs whose import failed on an earlier run
+    pub retry_failed: bool,
+    /// Only count what would be imported
+    pub dry_run: bool,
+}
+
+/// Outcome of one backfill pass
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct BackfillScreenshotsReport {
+    pub checked: usize,
+    pub migrated: usize,
+    pub failed: usize,
+}
+
+/// Imports the URLs of the legacy `projects.screenshots` column as media
+/// attached to the project, downloading each file and uploading it to the
+/// upload bucket so the image processor builds its variants as for any
+/// upload. The column itself is left untouched for clients still reading it.
+#[async_trait]
+pub trait BackfillScreenshotsUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        command: BackfillScreenshotsCommand,
+    ) -> Result<BackfillScreenshotsReport, BackfillScreenshotsError>;
+}
+
+metered_use_case!(
+    "multimedia",
+    "backfill_screenshots",
+    BackfillScreenshotsUseCase,
+    fn execute(
+        &self,
+        command: BackfillScreenshotsCommand,
+    ) -> Result<BackfillScreenshotsReport, BackfillScreenshotsError>
+);
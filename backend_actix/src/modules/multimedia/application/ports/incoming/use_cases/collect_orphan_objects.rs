@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use chrono::Duration;
+use serde::Serialize;
+
+use crate::multimedia::application::ports::outgoing::{
+    cloud_storage::ObjectInventoryError, db::MediaQueryError,
+};
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum CollectOrphanObjectsError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+
+    #[error("Storage error: {0}")]
+    StorageError(String),
+}
+impl From<MediaQueryError> for CollectOrphanObjectsError {
+    fn from(err: MediaQueryError) -> Self {
+        Self::RepositoryError(err.to_string())
+    }
+}
+impl From<ObjectInventoryError> for CollectOrphanObjectsError {
+    fn from(err: ObjectInventoryError) -> Self {
+        Self::StorageError(err.to_string())
+    }
+}
+
+pub struct CollectOrphanObjectsCommand {
+    /// Objects younger than this are left alone, so an upload whose media
+    /// row is still being written isn't mistaken for an orphan
+    pub grace: Duration,
+    /// Count orphans without deleting them
+    pub dry_run: bool,
+}
+
+/// Outcome of one collection pass, over every bucket
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct CollectOrphanObjectsReport {
+    pub scanned: usize,
+    /// Older than the grace period and belonging to no media row
+    pub orphaned: usize,
+    pub deleted: usize,
+    /// Names with no media id in them; never deleted
+    pub unrecognized: usize,
+    pub errors: usize,
+}
+
+/// Removes objects in the upload and variant buckets whose media row is
+/// gone or was never written, e.g. left behind by a failed pipeline run.
+#[async_trait]
+pub trait CollectOrphanObjectsUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        command: CollectOrphanObjectsCommand,
+    ) -> Result<CollectOrphanObjectsReport, CollectOrphanObjectsError>;
+}
+
+metered_use_case!(
+    "multimedia",
+    "collect_orphan_objects",
+    CollectOrphanObjectsUseCase,
+    fn execute(
+        &self,
+        command: CollectOrphanObjectsCommand,
+    ) -> Result<CollectOrphanObjectsReport, CollectOrphanObjectsError>
+);
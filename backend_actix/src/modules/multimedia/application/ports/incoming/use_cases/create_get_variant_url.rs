@@ -1,11 +1,15 @@
 use async_trait::async_trait;
 use uuid::Uuid;
 
+use crate::shared::metrics::metered_use_case;
 use crate::{
     auth::application::domain::entities::UserId,
     multimedia::application::domain::entities::MediaSize,
 };
 
+/// Most variants one batch may ask for
+pub const MAX_VARIANT_URLS: usize = 50;
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum GetReadUrlError {
     #[error("Media not found")]
@@ -47,3 +51,37 @@ pub struct GetUrlResult {
 pub trait GetVariantReadUrlUseCase: Send + Sync {
     async fn execute(&self, command: GetUrlCommand) -> Result<GetUrlResult, GetReadUrlError>;
 }
+
+metered_use_case!(
+    "multimedia",
+    "get_variant_read_url",
+    GetVariantReadUrlUseCase,
+    fn execute(&self, command: GetUrlCommand) -> Result<GetUrlResult, GetReadUrlError>
+);
+
+/// Several variants of the owner's media, e.g. every image of a gallery
+pub struct GetUrlsCommand {
+    pub owner: UserId,
+    pub variants: Vec<(Uuid, MediaSize)>,
+}
+
+/// A variant's signed URL, or why it has none
+pub type VariantUrlOutcome = Result<GetUrlResult, GetReadUrlError>;
+
+#[async_trait]
+pub trait GetVariantReadUrlsUseCase: Send + Sync {
+    /// One outcome per requested variant, in request order. A variant that
+    /// can't be read doesn't fail the others; only a failed lookup fails the
+    /// whole batch.
+    async fn execute(
+        &self,
+        command: GetUrlsCommand,
+    ) -> Result<Vec<VariantUrlOutcome>, GetReadUrlError>;
+}
+
+metered_use_case!(
+    "multimedia",
+    "get_variant_read_urls",
+    GetVariantReadUrlsUseCase,
+    fn execute(&self, command: GetUrlsCommand) -> Result<Vec<VariantUrlOutcome>, GetReadUrlError>
+);
@@ -1,8 +1,12 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use shared_domain::buckets::upload_object_key;
+use std::collections::BTreeMap;
 use std::path::Path;
+use std::time::Duration;
 use uuid::Uuid;
 
+use crate::shared::metrics::metered_use_case;
 use crate::{
     auth::application::domain::entities::UserId,
     multimedia::application::{
@@ -43,6 +47,9 @@ pub enum UploadUrlCommandError {
 
     #[error("Mime type does not match file extension (mime={mime_type}, ext={ext})")]
     MimeExtensionMismatch { mime_type: String, ext: String },
+
+    #[error("Checksum must be a hex SHA-256 digest (64 characters)")]
+    InvalidChecksum,
 }
 
 fn sanitize_basename(file_name: &str, max_len: usize) -> Result<String, UploadUrlCommandError> {
@@ -95,7 +102,7 @@ fn validate_mime(mime_type: &str, allowed: &[&str]) -> Result<(), UploadUrlComma
 
 fn validate_ext(ext: &str) -> Result<(), UploadUrlCommandError> {
     match ext {
-        "jpg" | "jpeg" | "png" | "webp" => Ok(()),
+        "jpg" | "jpeg" | "png" | "webp" | "svg" | "pdf" => Ok(()),
         other => Err(UploadUrlCommandError::InvalidExtension(other.to_string())),
     }
 }
@@ -106,6 +113,8 @@ fn validate_mime_ext_match(mime: &str, ext: &str) -> Result<(), UploadUrlCommand
         "image/jpeg" => matches!(ext, "jpg" | "jpeg"),
         "image/png" => ext == "png",
         "image/webp" => ext == "webp",
+        "image/svg+xml" => ext == "svg",
+        "application/pdf" => ext == "pdf",
         _ => false,
     };
 
@@ -126,7 +135,7 @@ pub fn make_object_key(
 ) -> Result<String, UploadUrlCommandError> {
     let ext = ext_lower(original_name)?;
     validate_ext(&ext)?;
-    Ok(format!("{}/{}", media_id, original_name))
+    Ok(upload_object_key(&media_id.to_string(), original_name))
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -140,6 +149,8 @@ pub struct CreateMediaCommand {
     width_px: Option<u32>,
     height_px: Option<u32>,
     duration_seconds: Option<u64>,
+    checksum_sha256: Option<String>,
+    allow_duplicate: bool,
 }
 
 impl CreateMediaCommand {
@@ -168,6 +179,14 @@ impl CreateMediaCommand {
     pub fn duration_seconds(&self) -> Option<u64> {
         self.duration_seconds
     }
+    /// Lowercase hex SHA-256 of the file, as declared by the client
+    pub fn checksum_sha256(&self) -> Option<&str> {
+        self.checksum_sha256.as_deref()
+    }
+    /// Register the upload even when an identical file is already stored
+    pub fn allow_duplicate(&self) -> bool {
+        self.allow_duplicate
+    }
 
     pub fn to_new_media(&self) -> NewMedia {
         NewMedia {
@@ -180,6 +199,7 @@ impl CreateMediaCommand {
             width_px: self.width_px,
             height_px: self.height_px,
             duration_seconds: self.duration_seconds,
+            checksum_sha256: self.checksum_sha256.clone(),
         }
     }
 }
@@ -193,6 +213,8 @@ pub struct CreateMediaCommandBuilder {
     width_px: Option<u32>,
     height_px: Option<u32>,
     duration_seconds: Option<u64>,
+    checksum_sha256: Option<String>,
+    allow_duplicate: bool,
 }
 
 impl CreateMediaCommandBuilder {
@@ -231,6 +253,16 @@ impl CreateMediaCommandBuilder {
         self
     }
 
+    pub fn checksum_sha256(mut self, checksum_sha256: Option<String>) -> Self {
+        self.checksum_sha256 = checksum_sha256;
+        self
+    }
+
+    pub fn allow_duplicate(mut self, allow_duplicate: bool) -> Self {
+        self.allow_duplicate = allow_duplicate;
+        self
+    }
+
     /// Build a validated command using injected policy (no hardcoded constants).
     pub fn build(self, policy: &UploadPolicy) -> Result<CreateMediaCommand, UploadUrlCommandError> {
         let owner = self
@@ -255,10 +287,11 @@ impl CreateMediaCommandBuilder {
         validate_mime(&mime_type, policy.allowed_mime_types)?;
         validate_mime_ext_match(&mime_type, &ext)?;
 
-        // 3) File size rule
-        if file_size_bytes > policy.max_file_size_bytes {
+        // 3) File size rule (documents have their own cap)
+        let max_bytes = policy.max_size_for(&mime_type);
+        if file_size_bytes > max_bytes {
             return Err(UploadUrlCommandError::FileTooLarge {
-                max_bytes: policy.max_file_size_bytes,
+                max_bytes,
                 actual_bytes: file_size_bytes,
             });
         }
@@ -279,6 +312,14 @@ impl CreateMediaCommandBuilder {
             return Err(UploadUrlCommandError::MissingField("width_px/height_px"));
         }
 
+        // 5) Checksum, when declared: 64 hex digits, kept lowercase
+        let checksum_sha256 = match self.checksum_sha256.map(|c| c.trim().to_ascii_lowercase()) {
+            Some(c) if c.len() != 64 || !c.bytes().all(|b| b.is_ascii_hexdigit()) => {
+                return Err(UploadUrlCommandError::InvalidChecksum);
+            }
+            checksum => checksum,
+        };
+
         Ok(CreateMediaCommand {
             owner,
             state: MediaState::Pending,
@@ -289,6 +330,8 @@ impl CreateMediaCommandBuilder {
             width_px: self.width_px,
             height_px: self.height_px,
             duration_seconds: self.duration_seconds,
+            checksum_sha256,
+            allow_duplicate: self.allow_duplicate,
         })
     }
 }
@@ -421,6 +464,14 @@ pub enum CreateUrlError {
 
     #[error("Storage service error: {0}")]
     StorageError(String),
+
+    /// The owner has used up their upload URLs for now
+    #[error("Upload URL limit reached")]
+    RateLimited { retry_after: Duration },
+
+    /// The owner already has media with the declared checksum
+    #[error("An identical file is already stored as media {media_id}")]
+    Duplicate { media_id: Uuid },
 }
 
 impl From<SignUrlError> for CreateUrlError {
@@ -431,6 +482,8 @@ impl From<SignUrlError> for CreateUrlError {
 #[derive(Debug, Clone)]
 pub struct CreateMediaResult {
     pub url: String,
+    /// Signed into `url`; the upload must send them as given
+    pub upload_headers: BTreeMap<String, String>,
     pub media_id: Uuid,
 }
 #[async_trait]
@@ -441,3 +494,14 @@ pub trait CreateUploadMediaUrlUseCase: Send + Sync {
         attachment_command: CreateAttachmentCommand,
     ) -> Result<CreateMediaResult, CreateUrlError>;
 }
+
+metered_use_case!(
+    "multimedia",
+    "create_upload_media_url",
+    CreateUploadMediaUrlUseCase,
+    fn execute(
+        &self,
+        media_command: CreateMediaCommand,
+        attachment_command: CreateAttachmentCommand,
+    ) -> Result<CreateMediaResult, CreateUrlError>
+);
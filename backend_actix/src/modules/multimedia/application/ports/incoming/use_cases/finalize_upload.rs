@@ -0,0 +1,75 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::shared::metrics::metered_use_case;
+use crate::{
+    auth::application::domain::entities::UserId,
+    multimedia::application::{
+        domain::entities::MediaState,
+        ports::outgoing::db::{MediaQueryError, MediaRepositoryError},
+    },
+};
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum FinalizeUploadError {
+    /// Unknown, trashed, or another owner's media
+    #[error("Media not found")]
+    MediaNotFound,
+
+    /// Still `pending` and storage has no object for it
+    #[error("The file has not been uploaded yet")]
+    NotUploaded,
+
+    #[error("Storage error: {0}")]
+    StorageError(String),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+impl From<MediaQueryError> for FinalizeUploadError {
+    fn from(err: MediaQueryError) -> Self {
+        match err {
+            MediaQueryError::MediaNotFound => Self::MediaNotFound,
+            MediaQueryError::DatabaseError(e) => Self::RepositoryError(e),
+        }
+    }
+}
+impl From<MediaRepositoryError> for FinalizeUploadError {
+    fn from(err: MediaRepositoryError) -> Self {
+        match err {
+            MediaRepositoryError::NotFound => Self::MediaNotFound,
+            MediaRepositoryError::DatabaseError(e) => Self::RepositoryError(e),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FinalizeUploadResult {
+    pub media_id: Uuid,
+    /// `processing` right after finalizing; a media finalized before, or
+    /// moved on by the image processor, keeps its state
+    pub status: MediaState,
+}
+
+/// Called by the client once its direct upload has completed. Moves the
+/// media from `pending` to `processing`, which restarts the clock the
+/// stuck-media checks measure from.
+#[async_trait]
+pub trait FinalizeUploadUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        owner: UserId,
+        media_id: Uuid,
+    ) -> Result<FinalizeUploadResult, FinalizeUploadError>;
+}
+
+metered_use_case!(
+    "multimedia",
+    "finalize_upload",
+    FinalizeUploadUseCase,
+    fn execute(
+        &self,
+        owner: UserId,
+        media_id: Uuid,
+    ) -> Result<FinalizeUploadResult, FinalizeUploadError>
+);
@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::shared::metrics::metered_use_case;
+use crate::{
+    auth::application::domain::entities::UserId,
+    multimedia::application::{
+        domain::entities::AttachmentTarget, ports::incoming::use_cases::GetReadUrlError,
+    },
+};
+
+pub struct GetPreviewImageCommand {
+    pub owner: UserId,
+    pub attachment_target: AttachmentTarget,
+    pub attachment_target_id: Uuid,
+}
+
+/// A signed variant to show in link previews
+#[derive(Debug, Clone)]
+pub struct PreviewImage {
+    pub media_id: Uuid,
+    pub url: String,
+    pub width: u32,
+    pub height: u32,
+    pub alt_text: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// The image that stands for a target in link previews: its cover, else its
+/// first screenshot, else its first gallery image, among the `ready` ones.
+/// The `medium` variant is preferred. `None` when the target has no such
+/// image.
+#[async_trait]
+pub trait GetPreviewImageUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        command: GetPreviewImageCommand,
+    ) -> Result<Option<PreviewImage>, GetReadUrlError>;
+}
+
+metered_use_case!(
+    "multimedia",
+    "get_preview_image",
+    GetPreviewImageUseCase,
+    fn execute(
+        &self,
+        command: GetPreviewImageCommand,
+    ) -> Result<Option<PreviewImage>, GetReadUrlError>
+);
@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use serde::Serialize;
+use shared_domain::manifest::Manifest;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::multimedia::application::ports::outgoing::db::{MediaQueryError, MediaRepositoryError};
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum IngestMediaManifestError {
+    /// `media_id` of the manifest is not a media id
+    #[error("Invalid manifest: {0}")]
+    InvalidManifest(String),
+
+    /// Unknown or trashed media
+    #[error("Media not found")]
+    MediaNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+impl From<MediaQueryError> for IngestMediaManifestError {
+    fn from(err: MediaQueryError) -> Self {
+        match err {
+            MediaQueryError::MediaNotFound => Self::MediaNotFound,
+            MediaQueryError::DatabaseError(e) => Self::RepositoryError(e),
+        }
+    }
+}
+impl From<MediaRepositoryError> for IngestMediaManifestError {
+    fn from(err: MediaRepositoryError) -> Self {
+        match err {
+            MediaRepositoryError::NotFound => Self::MediaNotFound,
+            MediaRepositoryError::DatabaseError(e) => Self::RepositoryError(e),
+        }
+    }
+}
+
+/// What a manifest did to its media
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ManifestOutcome {
+    /// Now `ready`, with its variants recorded
+    Ready,
+    /// Already `ready`; the variants of a regeneration replaced the old ones
+    VariantsReplaced,
+    /// Filed for the retry job, which decides whether it is retried
+    QueuedForRetry,
+    /// `failed` already, or changed after the manifest was written
+    Ignored,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IngestMediaManifestResult {
+    pub media_id: Uuid,
+    pub outcome: ManifestOutcome,
+}
+
+/// Applies a manifest the image processor pushed to the completion callback,
+/// as the status updater does with the manifests it reads from the bucket: a
+/// ready one settles the media and records its variants, a failed one is
+/// queued for the retry job.
+#[async_trait]
+pub trait IngestMediaManifestUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        manifest: Manifest,
+    ) -> Result<IngestMediaManifestResult, IngestMediaManifestError>;
+}
+
+metered_use_case!(
+    "multimedia",
+    "ingest_media_manifest",
+    IngestMediaManifestUseCase,
+    fn execute(
+        &self,
+        manifest: Manifest,
+    ) -> Result<IngestMediaManifestResult, IngestMediaManifestError>
+);
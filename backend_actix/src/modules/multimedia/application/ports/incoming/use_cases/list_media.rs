@@ -1,7 +1,9 @@
 use async_trait::async_trait;
 use serde::Serialize;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::shared::metrics::metered_use_case;
 use crate::{
     auth::application::domain::entities::UserId,
     multimedia::application::{
@@ -26,7 +28,7 @@ pub struct ListMediaCommand {
     pub attachment_target: AttachmentTarget,
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, ToSchema)]
 pub struct MediaItem {
     pub media_id: Uuid,
     pub original_filename: String,
@@ -58,3 +60,10 @@ impl MediaItem {
 pub trait ListMediaUseCase: Send + Sync {
     async fn execute(&self, command: ListMediaCommand) -> Result<Vec<MediaItem>, ListMediaError>;
 }
+
+metered_use_case!(
+    "multimedia",
+    "list_media",
+    ListMediaUseCase,
+    fn execute(&self, command: ListMediaCommand) -> Result<Vec<MediaItem>, ListMediaError>
+);
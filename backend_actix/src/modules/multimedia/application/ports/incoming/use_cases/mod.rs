@@ -1,13 +1,70 @@
+mod backfill_screenshots;
+mod collect_orphan_objects;
 mod create_get_variant_url;
 mod create_upload_url;
+mod finalize_upload;
+mod get_preview_image;
+mod ingest_media_manifest;
 mod list_media;
+mod proxy_image;
+mod reconcile_media;
+mod regenerate_media;
+mod retry_failed_media;
+mod storage_override;
+mod upload_batch;
+pub use backfill_screenshots::{
+    BackfillScreenshotsCommand, BackfillScreenshotsError, BackfillScreenshotsReport,
+    BackfillScreenshotsUseCase,
+};
+
+pub use collect_orphan_objects::{
+    CollectOrphanObjectsCommand, CollectOrphanObjectsError, CollectOrphanObjectsReport,
+    CollectOrphanObjectsUseCase,
+};
+
 pub use create_upload_url::{
     make_object_key, CreateAttachmentCommand, CreateMediaCommand, CreateMediaResult,
     CreateUploadMediaUrlUseCase, CreateUrlError, UploadUrlCommandError,
 };
 
 pub use create_get_variant_url::{
-    GetReadUrlError, GetUrlCommand, GetUrlResult, GetVariantReadUrlUseCase,
+    GetReadUrlError, GetUrlCommand, GetUrlResult, GetUrlsCommand, GetVariantReadUrlUseCase,
+    GetVariantReadUrlsUseCase, VariantUrlOutcome, MAX_VARIANT_URLS,
+};
+
+pub use finalize_upload::{FinalizeUploadError, FinalizeUploadResult, FinalizeUploadUseCase};
+
+pub use get_preview_image::{GetPreviewImageCommand, GetPreviewImageUseCase, PreviewImage};
+
+pub use ingest_media_manifest::{
+    IngestMediaManifestError, IngestMediaManifestResult, IngestMediaManifestUseCase,
+    ManifestOutcome,
 };
 
 pub use list_media::{ListMediaCommand, ListMediaError, ListMediaUseCase, MediaItem};
+
+pub use proxy_image::{ProxyImageError, ProxyImageUseCase};
+
+pub use reconcile_media::{
+    ReconcileMediaCommand, ReconcileMediaError, ReconcileMediaReport, ReconcileMediaUseCase,
+};
+
+pub use regenerate_media::{
+    RegenerateMediaCommand, RegenerateMediaError, RegenerateMediaResult, RegenerateMediaUseCase,
+    MAX_REGENERATE_WIDTHS, MAX_VARIANT_WIDTH,
+};
+
+pub use retry_failed_media::{
+    RetryFailedMediaCommand, RetryFailedMediaError, RetryFailedMediaReport, RetryFailedMediaUseCase,
+};
+
+pub use storage_override::{
+    DeleteStorageOverrideUseCase, GetStorageOverrideUseCase, SetStorageOverrideCommand,
+    SetStorageOverrideError, SetStorageOverrideUseCase, StorageOverrideCommandError,
+    StorageOverrideError, StorageOverrideView,
+};
+
+pub use upload_batch::{
+    CreateUploadBatchCommand, CreateUploadBatchError, CreateUploadBatchUseCase,
+    GetUploadBatchError, GetUploadBatchUseCase, UploadBatch, UploadBatchStatus, MAX_UPLOAD_BATCH,
+};
@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum ProxyImageError {
+    #[error("Not an absolute http(s) URL")]
+    InvalidUrl,
+
+    /// The URL's origin is not in `IMAGE_PROXY_ORIGINS`
+    #[error("Images from this origin are not proxied")]
+    OriginNotAllowed,
+
+    /// The origin answered 404
+    #[error("Image not found at the origin")]
+    ImageNotFound,
+
+    #[error("Image larger than {0} bytes")]
+    TooLarge(u64),
+
+    /// Not one of the raster types uploads accept, or the bytes don't match
+    /// the type the origin declared
+    #[error("Unsupported image type: {0}")]
+    UnsupportedType(String),
+
+    #[error("Fetching the image failed: {0}")]
+    FetchFailed(String),
+
+    #[error("Storage error: {0}")]
+    StorageError(String),
+}
+
+/// Serves an external image from the output bucket: fetched from its origin
+/// and checked like an upload the first time, stored under a name derived
+/// from the URL, and read from there afterwards. Returns a signed read URL.
+#[async_trait]
+pub trait ProxyImageUseCase: Send + Sync {
+    async fn execute(&self, url: &str) -> Result<String, ProxyImageError>;
+}
+
+metered_use_case!(
+    "multimedia",
+    "proxy_image",
+    ProxyImageUseCase,
+    fn execute(&self, url: &str) -> Result<String, ProxyImageError>
+);
@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use chrono::Duration;
+use serde::Serialize;
+
+use crate::multimedia::application::ports::outgoing::db::MediaQueryError;
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ReconcileMediaError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+impl From<MediaQueryError> for ReconcileMediaError {
+    fn from(err: MediaQueryError) -> Self {
+        Self::RepositoryError(err.to_string())
+    }
+}
+
+pub struct ReconcileMediaCommand {
+    /// Only media untouched for at least this long are checked
+    pub older_than: Duration,
+    pub limit: u64,
+    /// Media still `processing` this long after its last update, with no
+    /// result in its manifest, is failed with `PROCESSING_TIMEOUT`
+    pub processing_timeout: Duration,
+}
+
+/// Outcome of one reconcile pass
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct ReconcileMediaReport {
+    pub checked: usize,
+    pub updated: usize,
+    pub missing_manifest: usize,
+    pub timed_out: usize,
+    pub errors: usize,
+}
+
+/// Brings `pending`/`processing` rows in line with the manifests written by
+/// the image processor, for uploads whose status update never arrived, and
+/// gives up on media stuck in `processing`.
+#[async_trait]
+pub trait ReconcileMediaUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        command: ReconcileMediaCommand,
+    ) -> Result<ReconcileMediaReport, ReconcileMediaError>;
+}
+
+metered_use_case!(
+    "multimedia",
+    "reconcile_media",
+    ReconcileMediaUseCase,
+    fn execute(
+        &self,
+        command: ReconcileMediaCommand,
+    ) -> Result<ReconcileMediaReport, ReconcileMediaError>
+);
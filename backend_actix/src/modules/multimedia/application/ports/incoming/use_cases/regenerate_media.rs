@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::shared::metrics::metered_use_case;
+use crate::{
+    auth::application::domain::entities::UserId,
+    multimedia::application::{
+        domain::entities::MediaState,
+        ports::outgoing::db::{MediaQueryError, MediaRepositoryError},
+    },
+};
+
+/// Widths one request may ask for
+pub const MAX_REGENERATE_WIDTHS: usize = 10;
+/// Widest variant the processor is asked to build, in pixels
+pub const MAX_VARIANT_WIDTH: u32 = 8192;
+
+#[derive(Debug, Clone)]
+pub struct RegenerateMediaCommand {
+    pub owner: UserId,
+    pub media_id: Uuid,
+    /// Only rebuild the variants of these widths; `None` rebuilds all
+    pub widths: Option<Vec<u32>>,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum RegenerateMediaError {
+    /// Unknown, trashed, or another owner's media
+    #[error("Media not found")]
+    MediaNotFound,
+
+    /// Still `pending`: there is no finalized upload to build from
+    #[error("Media is pending upload")]
+    MediaPending,
+
+    /// Already `processing`, perhaps from an earlier regeneration
+    #[error("Media is still being processed")]
+    MediaProcessing,
+
+    #[error("Invalid widths: {0}")]
+    InvalidWidths(String),
+
+    /// No image processor to call
+    #[error("Image processor is not configured")]
+    ProcessorNotConfigured,
+
+    #[error("Image processor error: {0}")]
+    ProcessorError(String),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+impl From<MediaQueryError> for RegenerateMediaError {
+    fn from(err: MediaQueryError) -> Self {
+        match err {
+            MediaQueryError::MediaNotFound => Self::MediaNotFound,
+            MediaQueryError::DatabaseError(e) => Self::RepositoryError(e),
+        }
+    }
+}
+impl From<MediaRepositoryError> for RegenerateMediaError {
+    fn from(err: MediaRepositoryError) -> Self {
+        match err {
+            MediaRepositoryError::NotFound => Self::MediaNotFound,
+            MediaRepositoryError::DatabaseError(e) => Self::RepositoryError(e),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegenerateMediaResult {
+    pub media_id: Uuid,
+    /// Always `processing`; the processor's callback moves it on
+    pub status: MediaState,
+    /// The widths asked for, sorted and without duplicates; `None` for all
+    pub widths: Option<Vec<u32>>,
+}
+
+/// Builds a `ready` or `failed` media's variants again from its original
+/// upload, e.g. after the quality settings changed or a crop was fixed. The
+/// media goes back to `processing` until the image processor reports in.
+#[async_trait]
+pub trait RegenerateMediaUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        command: RegenerateMediaCommand,
+    ) -> Result<RegenerateMediaResult, RegenerateMediaError>;
+}
+
+metered_use_case!(
+    "multimedia",
+    "regenerate_media",
+    RegenerateMediaUseCase,
+    fn execute(
+        &self,
+        command: RegenerateMediaCommand,
+    ) -> Result<RegenerateMediaResult, RegenerateMediaError>
+);
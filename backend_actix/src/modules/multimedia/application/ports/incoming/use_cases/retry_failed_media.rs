@@ -0,0 +1,55 @@
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::multimedia::application::ports::outgoing::db::MediaRepositoryError;
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum RetryFailedMediaError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+impl From<MediaRepositoryError> for RetryFailedMediaError {
+    fn from(err: MediaRepositoryError) -> Self {
+        Self::RepositoryError(err.to_string())
+    }
+}
+
+pub struct RetryFailedMediaCommand {
+    /// Queued failures looked at in one pass
+    pub limit: u64,
+}
+
+/// Outcome of one pass over the retry queue
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct RetryFailedMediaReport {
+    pub checked: usize,
+    /// Sent back to the image processor
+    pub retried: usize,
+    /// Still backing off
+    pub waiting: usize,
+    /// Permanent or out of retries, and now `failed`
+    pub failed: usize,
+    pub errors: usize,
+}
+
+/// Decides on the processing failures the status updater queued: transient
+/// ones (download, upload, moderation outage) go back to the image processor
+/// with a backoff, the rest are marked `failed` with their error code.
+#[async_trait]
+pub trait RetryFailedMediaUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        command: RetryFailedMediaCommand,
+    ) -> Result<RetryFailedMediaReport, RetryFailedMediaError>;
+}
+
+metered_use_case!(
+    "multimedia",
+    "retry_failed_media",
+    RetryFailedMediaUseCase,
+    fn execute(
+        &self,
+        command: RetryFailedMediaCommand,
+    ) -> Result<RetryFailedMediaReport, RetryFailedMediaError>
+);
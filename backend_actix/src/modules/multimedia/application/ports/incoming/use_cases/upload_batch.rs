@@ -0,0 +1,173 @@
+use async_trait::async_trait;
+use serde::Serialize;
+use std::time::Duration;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::shared::metrics::metered_use_case;
+use crate::shared::unit_of_work::UnitOfWorkError;
+use crate::{
+    auth::application::domain::entities::UserId,
+    multimedia::application::{
+        domain::entities::MediaState,
+        ports::{
+            incoming::use_cases::{
+                CreateAttachmentCommand, CreateMediaCommand, CreateMediaResult, CreateUrlError,
+            },
+            outgoing::db::{MediaRepositoryError, UploadBatchItem},
+        },
+    },
+};
+
+/// Files one batch may register
+pub const MAX_UPLOAD_BATCH: usize = 20;
+
+/// Several uploads of one owner, registered together or not at all
+#[derive(Debug, Clone)]
+pub struct CreateUploadBatchCommand {
+    owner: UserId,
+    uploads: Vec<(CreateMediaCommand, CreateAttachmentCommand)>,
+}
+
+impl CreateUploadBatchCommand {
+    pub fn new(
+        owner: UserId,
+        uploads: Vec<(CreateMediaCommand, CreateAttachmentCommand)>,
+    ) -> Result<Self, CreateUploadBatchError> {
+        if uploads.is_empty() || uploads.len() > MAX_UPLOAD_BATCH {
+            return Err(CreateUploadBatchError::InvalidBatch(format!(
+                "Send between 1 and {MAX_UPLOAD_BATCH} files"
+            )));
+        }
+
+        Ok(Self { owner, uploads })
+    }
+
+    pub fn owner(&self) -> &UserId {
+        &self.owner
+    }
+
+    pub fn into_uploads(self) -> Vec<(CreateMediaCommand, CreateAttachmentCommand)> {
+        self.uploads
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UploadBatch {
+    pub batch_id: Uuid,
+    /// In request order
+    pub uploads: Vec<CreateMediaResult>,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum CreateUploadBatchError {
+    #[error("{0}")]
+    InvalidBatch(String),
+
+    /// One file could not be registered, so none was
+    #[error("files[{index}]: {error}")]
+    Upload { index: usize, error: CreateUrlError },
+
+    /// The whole batch does not fit in what is left of the owner's upload
+    /// URLs; none is counted
+    #[error("Upload URL limit reached")]
+    RateLimited { retry_after: Duration },
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<MediaRepositoryError> for CreateUploadBatchError {
+    fn from(err: MediaRepositoryError) -> Self {
+        Self::RepositoryError(err.to_string())
+    }
+}
+
+impl From<UnitOfWorkError> for CreateUploadBatchError {
+    fn from(err: UnitOfWorkError) -> Self {
+        Self::RepositoryError(err.to_string())
+    }
+}
+
+/// Registers every file of the batch as `pending` and signs its upload URL,
+/// as `POST /api/media/upload-url` would one by one. The media and the batch
+/// are recorded in one transaction, so a failing file leaves nothing behind.
+#[async_trait]
+pub trait CreateUploadBatchUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        command: CreateUploadBatchCommand,
+    ) -> Result<UploadBatch, CreateUploadBatchError>;
+}
+
+metered_use_case!(
+    "multimedia",
+    "create_upload_batch",
+    CreateUploadBatchUseCase,
+    fn execute(
+        &self,
+        command: CreateUploadBatchCommand,
+    ) -> Result<UploadBatch, CreateUploadBatchError>
+);
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct UploadBatchStatus {
+    pub batch_id: Uuid,
+    /// In request order; trashed media are left out
+    pub items: Vec<UploadBatchItem>,
+    /// Every item is `ready` or `failed`, so polling can stop
+    pub settled: bool,
+}
+
+impl UploadBatchStatus {
+    pub fn new(batch_id: Uuid, items: Vec<UploadBatchItem>) -> Self {
+        let settled = items
+            .iter()
+            .all(|item| matches!(item.status, MediaState::Ready | MediaState::Failed));
+
+        Self {
+            batch_id,
+            items,
+            settled,
+        }
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum GetUploadBatchError {
+    /// Unknown, another owner's, or every media of it trashed
+    #[error("Upload batch not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<MediaRepositoryError> for GetUploadBatchError {
+    fn from(err: MediaRepositoryError) -> Self {
+        match err {
+            MediaRepositoryError::NotFound => Self::NotFound,
+            MediaRepositoryError::DatabaseError(e) => Self::RepositoryError(e),
+        }
+    }
+}
+
+#[async_trait]
+pub trait GetUploadBatchUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        owner: UserId,
+        batch_id: Uuid,
+    ) -> Result<UploadBatchStatus, GetUploadBatchError>;
+}
+
+metered_use_case!(
+    "multimedia",
+    "get_upload_batch",
+    GetUploadBatchUseCase,
+    fn execute(
+        &self,
+        owner: UserId,
+        batch_id: Uuid,
+    ) -> Result<UploadBatchStatus, GetUploadBatchError>
+);
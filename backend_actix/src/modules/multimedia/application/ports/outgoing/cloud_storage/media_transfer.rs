@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+
+use super::SignedUpload;
+
+/// A file downloaded from an arbitrary URL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchedFile {
+    pub bytes: Vec<u8>,
+    /// `Content-Type` the server sent, without parameters
+    pub content_type: String,
+}
+
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum TransferError {
+    #[error("File not found")]
+    NotFound,
+
+    #[error("File larger than {0} bytes")]
+    TooLarge(u64),
+
+    #[error("Transfer failed: {0}")]
+    Failed(String),
+}
+
+/// Moves bytes server-side, for imports that can't go through a browser
+/// upload: pulls a file from a URL and pushes it to a signed upload URL.
+#[async_trait]
+pub trait MediaTransfer: Send + Sync {
+    async fn download(&self, url: &str, max_bytes: u64) -> Result<FetchedFile, TransferError>;
+
+    /// PUT `file` to a URL from `StorageQuery::get_signed_upload_url`, with
+    /// the headers it was signed with
+    async fn upload(&self, upload: &SignedUpload, file: &FetchedFile) -> Result<(), TransferError>;
+}
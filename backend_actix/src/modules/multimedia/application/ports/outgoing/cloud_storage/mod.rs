@@ -1,4 +1,9 @@
+mod media_transfer;
+mod object_inventory;
 mod storage_query;
+pub use media_transfer::{FetchedFile, MediaTransfer, TransferError};
+pub use object_inventory::{ObjectInventory, ObjectInventoryError, StoredObject};
 pub use storage_query::{
-    ManifestInfo, MediaInfo, MediaInfoError, SignUrlError, StorageQuery, StorageQueryError,
+    ManifestInfo, MediaInfo, MediaInfoError, SignUrlError, SignedUpload, StorageQuery,
+    StorageQueryError, UploadConstraints,
 };
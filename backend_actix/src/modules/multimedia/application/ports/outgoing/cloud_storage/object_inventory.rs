@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// An object as listed from a bucket
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredObject {
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum ObjectInventoryError {
+    #[error("Storage request failed: {0}")]
+    Failed(String),
+}
+
+/// Raw access to bucket contents, for cleaning up objects no media row
+/// points to. Everything else reaches storage through signed URLs.
+#[async_trait]
+pub trait ObjectInventory: Send + Sync {
+    /// Every object in `bucket`
+    async fn list_objects(&self, bucket: &str) -> Result<Vec<StoredObject>, ObjectInventoryError>;
+
+    /// Deleting an object that is already gone succeeds
+    async fn delete_object(&self, bucket: &str, name: &str) -> Result<(), ObjectInventoryError>;
+}
@@ -1,7 +1,10 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
 
 use crate::multimedia::application::domain::entities::{AttachmentTarget, MediaState};
+use crate::shared::faults::{faults, Faulty};
 
 // ============================================================================
 // Domain Types
@@ -72,6 +75,25 @@ impl MediaInfo {
     }
 }
 
+/// What a signed upload accepts. Storage enforces it, so a client can't put
+/// a larger or different file than it registered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadConstraints {
+    pub content_type: String,
+    /// Largest body accepted, in bytes
+    pub max_bytes: u64,
+    /// Image processor tenant the upload is for; `None` for the default one
+    pub tenant: Option<String>,
+}
+
+/// A signed upload URL. The upload must send `headers` exactly as given;
+/// they are part of the signature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedUpload {
+    pub url: String,
+    pub headers: BTreeMap<String, String>,
+}
+
 // ============================================================================
 // Error Types
 // ============================================================================
@@ -126,8 +148,13 @@ pub trait StorageQuery: Send + Sync {
     /// Returns a signed URL for client-side direct uploads.
     ///
     /// The URL allows clients to upload directly to cloud storage
-    /// without routing through the backend.
-    async fn get_signed_upload_url(&self, media_info: MediaInfo) -> Result<String, SignUrlError>;
+    /// without routing through the backend, and only a body that meets
+    /// `constraints`.
+    async fn get_signed_upload_url(
+        &self,
+        media_info: MediaInfo,
+        constraints: UploadConstraints,
+    ) -> Result<SignedUpload, SignUrlError>;
 
     /// Returns a signed URL for client-side direct reads.
     ///
@@ -135,6 +162,10 @@ pub trait StorageQuery: Send + Sync {
     /// cloud storage with temporary access credentials.
     async fn get_signed_read_url(&self, media_info: MediaInfo) -> Result<String, SignUrlError>;
 
+    /// Whether the object has been written, e.g. a client's direct upload
+    /// has completed.
+    async fn object_exists(&self, media_info: MediaInfo) -> Result<bool, StorageQueryError>;
+
     /// Returns the latest manifest info for a media file.
     ///
     /// Manifests are written by the image processing service and contain
@@ -142,6 +173,65 @@ pub trait StorageQuery: Send + Sync {
     async fn get_latest_manifest(&self, media_id: &str) -> Result<ManifestInfo, StorageQueryError>;
 }
 
+/// Lets the adapter be picked at startup and shared as `Arc<dyn StorageQuery>`
+#[async_trait]
+impl<S> StorageQuery for Arc<S>
+where
+    S: StorageQuery + ?Sized,
+{
+    async fn get_signed_upload_url(
+        &self,
+        media_info: MediaInfo,
+        constraints: UploadConstraints,
+    ) -> Result<SignedUpload, SignUrlError> {
+        (**self)
+            .get_signed_upload_url(media_info, constraints)
+            .await
+    }
+
+    async fn get_signed_read_url(&self, media_info: MediaInfo) -> Result<String, SignUrlError> {
+        (**self).get_signed_read_url(media_info).await
+    }
+
+    async fn object_exists(&self, media_info: MediaInfo) -> Result<bool, StorageQueryError> {
+        (**self).object_exists(media_info).await
+    }
+
+    async fn get_latest_manifest(&self, media_id: &str) -> Result<ManifestInfo, StorageQueryError> {
+        (**self).get_latest_manifest(media_id).await
+    }
+}
+
+/// Refuses to sign while signing failures are injected (see `shared::faults`)
+#[async_trait]
+impl<S: StorageQuery> StorageQuery for Faulty<S> {
+    async fn get_signed_upload_url(
+        &self,
+        media_info: MediaInfo,
+        constraints: UploadConstraints,
+    ) -> Result<SignedUpload, SignUrlError> {
+        if faults().storage_signing_fails() {
+            return Err(SignUrlError::Infrastructure);
+        }
+        self.0.get_signed_upload_url(media_info, constraints).await
+    }
+
+    async fn get_signed_read_url(&self, media_info: MediaInfo) -> Result<String, SignUrlError> {
+        if faults().storage_signing_fails() {
+            return Err(SignUrlError::Infrastructure);
+        }
+        self.0.get_signed_read_url(media_info).await
+    }
+
+    async fn object_exists(&self, media_info: MediaInfo) -> Result<bool, StorageQueryError> {
+        self.0.object_exists(media_info).await
+    }
+
+    async fn get_latest_manifest(&self, media_id: &str) -> Result<ManifestInfo, StorageQueryError> {
+        self.0.get_latest_manifest(media_id).await
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    auth::application::domain::entities::UserId,
+    multimedia::application::ports::outgoing::db::MediaRepositoryError,
+};
+
+/// One URL of a project's legacy `screenshots` JSON column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegacyScreenshot {
+    pub owner: UserId,
+    pub project_id: Uuid,
+    /// Index in the JSON array, start from 0
+    pub position: u8,
+    pub url: String,
+}
+
+/// What became of a legacy screenshot URL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScreenshotMigration {
+    Migrated { media_id: Uuid },
+    Failed { reason: String },
+}
+
+/// Source of the screenshot backfill: the URLs still living only in
+/// `projects.screenshots`, and the record of which ones were imported.
+#[async_trait]
+pub trait LegacyScreenshotStore: Send + Sync {
+    /// URLs of live projects not imported yet, by project then position.
+    /// URLs that failed before are only returned with `retry_failed`.
+    async fn pending(
+        &self,
+        limit: u64,
+        retry_failed: bool,
+    ) -> Result<Vec<LegacyScreenshot>, MediaRepositoryError>;
+
+    async fn record(
+        &self,
+        project_id: Uuid,
+        url: &str,
+        outcome: ScreenshotMigration,
+    ) -> Result<(), MediaRepositoryError>;
+}
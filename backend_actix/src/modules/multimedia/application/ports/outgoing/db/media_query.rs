@@ -1,4 +1,6 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
 use uuid::Uuid;
 
 use crate::{
@@ -36,6 +38,17 @@ pub struct MediaAttachment {
     pub variants: Vec<StoredVariant>,
 }
 
+/// Where a media's original file is uploaded to
+#[derive(Debug, Clone)]
+pub struct UploadObject {
+    pub media_id: Uuid,
+    pub owner: UserId,
+    pub status: MediaState,
+    pub attachment_target: AttachmentTarget,
+    pub bucket_name: String,
+    pub object_key: String,
+}
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum MediaQueryError {
     #[error("Media not found")]
@@ -57,4 +70,36 @@ pub trait MediaQuery: Send + Sync {
 
     async fn get_attachment_info(&self, media_id: Uuid)
         -> Result<MediaAttachment, MediaQueryError>;
+
+    /// [`get_attachment_info`](Self::get_attachment_info) for several media
+    /// at once, in no particular order. Ids with no live media are left out.
+    async fn get_attachment_infos(
+        &self,
+        media_ids: &[Uuid],
+    ) -> Result<Vec<MediaAttachment>, MediaQueryError>;
+
+    async fn get_upload_object(&self, media_id: Uuid) -> Result<UploadObject, MediaQueryError>;
+
+    /// Media still `pending` or `processing` with no update since `updated_before`,
+    /// oldest first. Failures waiting in the retry queue are left to it.
+    async fn list_unsettled(
+        &self,
+        updated_before: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<MediaStateInfo>, MediaQueryError>;
+
+    /// The owner's most recently updated live media declared with
+    /// `checksum_sha256` (lowercase hex), leaving out `failed` ones
+    async fn find_duplicate(
+        &self,
+        owner: UserId,
+        checksum_sha256: &str,
+    ) -> Result<Option<MediaStateInfo>, MediaQueryError>;
+
+    /// Which of `media_ids` still have a row, trashed media included, so
+    /// their stored objects are kept
+    async fn existing_media_ids(
+        &self,
+        media_ids: &[Uuid],
+    ) -> Result<HashSet<Uuid>, MediaQueryError>;
 }
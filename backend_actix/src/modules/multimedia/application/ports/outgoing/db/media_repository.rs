@@ -5,7 +5,8 @@ use uuid::Uuid;
 use crate::{
     auth::application::domain::entities::UserId,
     multimedia::application::domain::entities::{
-        AttachmentTarget, MediaRole, MediaSize, MediaState, MediaStateInfo, MediaVariant,
+        AttachmentTarget, MediaFailure, MediaRole, MediaSize, MediaState, MediaStateInfo,
+        MediaVariant,
     },
 };
 
@@ -22,6 +23,8 @@ pub struct NewMedia {
     pub width_px: Option<u32>,
     pub height_px: Option<u32>,
     pub duration_seconds: Option<u64>,
+    /// Lowercase hex SHA-256 of the original, as declared by the client
+    pub checksum_sha256: Option<String>,
 }
 
 /// Represents a new attachment row to be recorded with a media.
@@ -83,6 +86,7 @@ pub struct UpdateMediaStateData {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MediaVariantRecord {
     pub owner: UserId,
+    pub media_id: Uuid,
     pub size: MediaSize,
     pub bucket_name: String,
     pub object_key: String,
@@ -90,6 +94,8 @@ pub struct MediaVariantRecord {
     pub file_size_bytes: u64,
     pub width_px: Option<u32>,
     pub height_px: Option<u32>,
+    /// Lowercase hex SHA-256 of the stored object, as reported in the manifest
+    pub checksum_sha256: Option<String>,
 }
 
 #[async_trait]
@@ -102,6 +108,22 @@ pub trait MediaRepository: Send + Sync {
         data: UpdateMediaStateData,
     ) -> Result<MediaStateInfo, MediaRepositoryError>;
 
+    /// Like `set_media_state`, but only while the media is still in `from`;
+    /// `None` when it isn't (anymore), so a concurrent update is never undone.
+    async fn transition_media_state(
+        &self,
+        data: UpdateMediaStateData,
+        from: MediaState,
+    ) -> Result<Option<MediaStateInfo>, MediaRepositoryError>;
+
+    /// Mark `media` `failed` for `failure`, only while it is still in the
+    /// state it was read in; `None` when it moved on meanwhile
+    async fn fail_media(
+        &self,
+        media: &MediaStateInfo,
+        failure: MediaFailure,
+    ) -> Result<Option<MediaStateInfo>, MediaRepositoryError>;
+
     async fn record_single_variant(
         &self,
         data: MediaVariantRecord,
@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::multimedia::application::{
+    domain::entities::MediaStateInfo, ports::outgoing::db::MediaRepositoryError,
+};
+
+/// A processing failure filed by the status updater, with what a retry needs
+#[derive(Debug, Clone)]
+pub struct QueuedFailure {
+    pub media: MediaStateInfo,
+    pub bucket_name: String,
+    pub object_key: String,
+    /// `error.code` of the failed manifest
+    pub failure_code: String,
+    /// Retries already sent for this media
+    pub attempts: u32,
+    pub failed_at: DateTime<Utc>,
+}
+
+/// `media_retries`: failed manifests waiting for a retry or to be given up
+/// on. The status updater or the completion callback files them; the
+/// backend drains them.
+#[async_trait]
+pub trait MediaRetryQueue: Send + Sync {
+    /// Files a failed manifest of a media still `pending` or `processing`,
+    /// not in the trash and not changed since `failed_at`; a later failure
+    /// replaces the code and time of an earlier one. `false` when the media
+    /// didn't qualify.
+    async fn file(
+        &self,
+        media_id: Uuid,
+        failure_code: &str,
+        failed_at: DateTime<Utc>,
+    ) -> Result<bool, MediaRepositoryError>;
+
+    /// Failures waiting for a decision, oldest first, of media that are
+    /// still `pending` or `processing` and not in the trash
+    async fn waiting(&self, limit: u64) -> Result<Vec<QueuedFailure>, MediaRepositoryError>;
+
+    /// A retry went to the processor; the row waits for its manifest
+    async fn mark_sent(&self, media_id: Uuid, attempts: u32) -> Result<(), MediaRepositoryError>;
+
+    /// The processor could not be reached; counts as a failed retry and
+    /// waits out its backoff again
+    async fn postpone(&self, media_id: Uuid, attempts: u32) -> Result<(), MediaRepositoryError>;
+
+    /// Given up on, or settled some other way
+    async fn remove(&self, media_id: Uuid) -> Result<(), MediaRepositoryError>;
+}
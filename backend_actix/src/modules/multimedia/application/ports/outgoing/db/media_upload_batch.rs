@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::multimedia::application::{
+    domain::entities::MediaState, ports::outgoing::db::MediaRepositoryError,
+};
+
+/// Where one media of an upload batch stands
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct UploadBatchItem {
+    pub media_id: Uuid,
+    pub original_filename: String,
+    pub status: MediaState,
+    /// Why the media failed, when it did and the code was kept
+    pub failure_code: Option<String>,
+}
+
+/// `media_upload_batches`: the media registered together by one request
+#[async_trait]
+pub trait MediaUploadBatchRepository: Send + Sync {
+    /// Records `media_ids`, in request order, as batch `batch_id`
+    async fn record_batch(
+        &self,
+        batch_id: Uuid,
+        media_ids: &[Uuid],
+    ) -> Result<(), MediaRepositoryError>;
+
+    /// The owner's media of the batch in request order, trashed ones left
+    /// out; empty when there is no such batch
+    async fn batch_items(
+        &self,
+        owner: UserId,
+        batch_id: Uuid,
+    ) -> Result<Vec<UploadBatchItem>, MediaRepositoryError>;
+}
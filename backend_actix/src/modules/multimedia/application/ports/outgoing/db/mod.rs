@@ -1,9 +1,23 @@
+mod legacy_screenshot_store;
 mod media_query;
 mod media_repository;
+mod media_retry_queue;
+mod media_upload_batch;
+mod storage_override_store;
+
+pub use legacy_screenshot_store::{LegacyScreenshot, LegacyScreenshotStore, ScreenshotMigration};
 
 pub use media_repository::{
     MediaRepository, MediaRepositoryError, MediaVariantRecord, NewMedia, NewMediaAttachment,
     RecordMediaError, RecordMediaTx, RecordedMedia, UpdateMediaStateData,
 };
 
-pub use media_query::{MediaAttachment, MediaQuery, MediaQueryError, StoredVariant};
+pub use media_retry_queue::{MediaRetryQueue, QueuedFailure};
+
+pub use media_upload_batch::{MediaUploadBatchRepository, UploadBatchItem};
+
+pub use media_query::{MediaAttachment, MediaQuery, MediaQueryError, StoredVariant, UploadObject};
+
+pub use storage_override_store::{
+    StorageOverride, StorageOverrideStore, StorageOverrideStoreError,
+};
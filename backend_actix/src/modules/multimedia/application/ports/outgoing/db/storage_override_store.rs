@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::fmt;
+
+use crate::auth::application::domain::entities::UserId;
+
+/// Buckets an account's media is stored in instead of the server's, and the
+/// service account key that reaches them
+#[derive(Clone, PartialEq, Eq)]
+pub struct StorageOverride {
+    pub owner: UserId,
+    pub upload_bucket: String,
+    pub ready_bucket: String,
+    /// The image processor's `TENANT_ROUTES` entry writing to `ready_bucket`
+    pub tenant: String,
+    /// Service account key JSON, as given; stores encrypt it
+    pub credentials: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl StorageOverride {
+    pub fn owns_bucket(&self, bucket: &str) -> bool {
+        self.upload_bucket == bucket || self.ready_bucket == bucket
+    }
+}
+
+/// Keeps the key out of logs
+impl fmt::Debug for StorageOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StorageOverride")
+            .field("owner", &self.owner)
+            .field("upload_bucket", &self.upload_bucket)
+            .field("ready_bucket", &self.ready_bucket)
+            .field("tenant", &self.tenant)
+            .field("updated_at", &self.updated_at)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum StorageOverrideStoreError {
+    /// One of the buckets is another account's
+    #[error("Bucket already belongs to another account")]
+    BucketTaken,
+
+    #[error("No such account")]
+    OwnerNotFound,
+
+    /// Credentials would be stored in plaintext
+    #[error("Field encryption is not configured")]
+    EncryptionDisabled,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// `storage_overrides`: at most one per account, and a bucket belongs to one
+/// account
+#[async_trait]
+pub trait StorageOverrideStore: Send + Sync {
+    async fn find_by_owner(
+        &self,
+        owner: UserId,
+    ) -> Result<Option<StorageOverride>, StorageOverrideStoreError>;
+
+    /// The override one of whose buckets is `bucket`
+    async fn find_by_bucket(
+        &self,
+        bucket: &str,
+    ) -> Result<Option<StorageOverride>, StorageOverrideStoreError>;
+
+    /// Creates or replaces the owner's override; `updated_at` is ignored
+    async fn save(
+        &self,
+        value: StorageOverride,
+    ) -> Result<StorageOverride, StorageOverrideStoreError>;
+
+    /// `false` when the owner had none
+    async fn delete(&self, owner: UserId) -> Result<bool, StorageOverrideStoreError>;
+}
@@ -1,2 +1,3 @@
 pub mod cloud_storage;
 pub mod db;
+pub mod processor;
@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Asks the image processor to build a media's variants again from its
+/// original upload
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReprocessRequest {
+    pub media_id: Uuid,
+    pub bucket_name: String,
+    pub object_key: String,
+    /// Only the variants of these widths, in pixels; `None` rebuilds all
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub widths: Option<Vec<u32>>,
+}
+
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum MediaProcessorError {
+    /// No processor URL is configured
+    #[error("Image processor is not configured")]
+    NotConfigured,
+
+    #[error("Image processor request failed: {0}")]
+    Failed(String),
+}
+
+/// Talks to the image processor. The processor reports back through the
+/// usual status callback and manifest, so a request only queues the work.
+#[async_trait]
+pub trait MediaProcessorClient: Send + Sync {
+    async fn reprocess(&self, request: &ReprocessRequest) -> Result<(), MediaProcessorError>;
+}
@@ -0,0 +1,2 @@
+mod media_processor_client;
+pub use media_processor_client::{MediaProcessorClient, MediaProcessorError, ReprocessRequest};
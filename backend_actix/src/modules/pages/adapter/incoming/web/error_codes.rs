@@ -0,0 +1,15 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    INVALID_SLUG = (BAD_REQUEST, "Invalid slug");
+    INVALID_TITLE = (BAD_REQUEST, "Title is empty or too long");
+    BODY_TOO_LONG = (BAD_REQUEST, "Body is too long");
+    INVALID_STATUS = (BAD_REQUEST, "Status must be 'draft' or 'published'");
+    INVALID_EXPIRY = (BAD_REQUEST, "Invalid preview expiry");
+    INVALID_LOCK_LABEL = (BAD_REQUEST, "Lock label is too long");
+    PAGE_NOT_FOUND = (NOT_FOUND, "Page not found");
+    PAGE_GONE = (GONE, "This page has been deleted");
+    PREVIEW_NOT_FOUND = (NOT_FOUND, "Preview not found");
+    PREVIEW_EXPIRED = (GONE, "This preview link has expired");
+    PAGE_LOCKED = (LOCKED, "Page is being edited in another session");
+}
@@ -0,0 +1,24 @@
+use actix_web::web;
+
+pub mod error_codes;
+pub mod routes;
+
+/// Routes anyone can call
+pub fn configure_public(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::get_public_page_handler)
+        .service(routes::get_page_preview_handler);
+}
+
+/// Routes for signed-in users with a verified email
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::create_page_handler)
+        .service(routes::list_pages_handler)
+        .service(routes::get_page_handler)
+        .service(routes::update_page_handler)
+        .service(routes::delete_page_handler)
+        .service(routes::create_preview_token_handler)
+        .service(routes::acquire_page_lock_handler)
+        .service(routes::renew_page_lock_handler)
+        .service(routes::release_page_lock_handler)
+        .service(routes::autosave_page_handler);
+}
@@ -0,0 +1,146 @@
+use actix_web::{post, web, Responder};
+use serde::Deserialize;
+use tracing::error;
+use uuid::Uuid;
+
+use super::{page_locked, page_not_found, PageLockResponse};
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::pages::adapter::incoming::web::error_codes::INVALID_LOCK_LABEL;
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::VerifiedUser,
+    pages::application::ports::incoming::use_cases::AcquirePageLockError, shared::api::ApiResponse,
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct AcquirePageLockQuery {
+    pub label: Option<String>,
+}
+
+/// Lock one of the caller's pages for editing in this session
+///
+/// While the lock lives, `PATCH /api/pages/{page_id}` only accepts edits
+/// sending its `lock_id` in `X-Edit-Lock`; other sessions get 423
+/// `PAGE_LOCKED`. The lock lapses two minutes after it was taken or last
+/// renewed through `heartbeat_url`.
+#[utoipa::path(
+    post,
+    path = "/api/pages/{page_id}/lock",
+    tag = "pages",
+    params(
+        ("page_id" = Uuid, Path, description = "Page id"),
+        ("label" = Option<String>, Query, description = "What this session calls itself, e.g. `Firefox on laptop`, shown to the sessions it keeps out (at most 100 characters)"),
+    ),
+    responses(
+        (status = 201, description = "Lock taken", body = inline(SuccessResponse<PageLockResponse>)),
+        (status = 400, description = "Label too long", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Page not found", body = ErrorResponse),
+        (status = 423, description = "Another session holds the lock; `Retry-After` says when it lapses", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/pages/{page_id}/lock")]
+pub async fn acquire_page_lock_handler(
+    user: VerifiedUser,
+    path: web::Path<Uuid>,
+    query: web::Query<AcquirePageLockQuery>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let page_id = path.into_inner();
+
+    match data
+        .pages
+        .acquire_lock
+        .execute(user.user_id, page_id, None, query.into_inner().label)
+        .await
+    {
+        Ok(lock) => ApiResponse::created(PageLockResponse::from(lock)),
+        Err(AcquirePageLockError::InvalidLabel(msg)) => INVALID_LOCK_LABEL.with_message(&msg),
+        Err(AcquirePageLockError::NotFound) => page_not_found(),
+        Err(AcquirePageLockError::Locked(holder)) => page_locked(&holder),
+        Err(AcquirePageLockError::RepositoryError(msg)) => {
+            error!("Failed to lock page {}: {}", page_id, msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{
+        http::{header::RETRY_AFTER, StatusCode},
+        test, App,
+    };
+    use std::sync::Arc;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubAcquirePageLockUseCase,
+        },
+    };
+
+    async fn call(state: web::Data<AppState>, uri: &str) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(acquire_page_lock_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_lock_comes_with_heartbeat_url() {
+        let state = TestAppStateBuilder::default().build();
+        let page_id = Uuid::new_v4();
+
+        let resp = call(state, &format!("/api/pages/{page_id}/lock?label=Firefox")).await;
+
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        let lock_id = json["data"]["lock_id"].as_str().unwrap();
+        assert_eq!(json["data"]["label"], "Firefox");
+        assert_eq!(
+            json["data"]["heartbeat_url"],
+            format!("/api/pages/{page_id}/lock/{lock_id}")
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_held_lock_names_holder_but_not_its_id() {
+        let stub = StubAcquirePageLockUseCase::locked();
+        let holder_id = stub.holder_lock_id();
+        let state = TestAppStateBuilder::default()
+            .with_acquire_page_lock(stub)
+            .build();
+
+        let resp = call(state, &format!("/api/pages/{}/lock", Uuid::new_v4())).await;
+
+        assert_eq!(resp.status(), StatusCode::LOCKED);
+        assert!(resp.headers().contains_key(RETRY_AFTER));
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "PAGE_LOCKED");
+        let message = json["error"]["message"].as_str().unwrap();
+        assert!(message.contains("Firefox on laptop"));
+        assert!(!message.contains(&holder_id.to_string()));
+    }
+}
@@ -0,0 +1,143 @@
+use actix_web::{put, web, Responder};
+use serde::Deserialize;
+use tracing::error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::page_not_found;
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::pages::adapter::incoming::web::error_codes::BODY_TOO_LONG;
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::VerifiedUser,
+    pages::application::{
+        domain::autosave::PageAutosave, ports::incoming::use_cases::AutosavePageError,
+    },
+    shared::api::ApiResponse,
+    AppState,
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AutosavePageRequest {
+    /// Markdown, as the editor holds it
+    pub body: String,
+}
+
+/// Keep the editor's unsaved body, to restore after a crash
+///
+/// Send it every few seconds while editing. Only the latest autosave of a
+/// page is kept, apart from the page: its body, status and `updated_at`
+/// stay as they are. `GET /api/pages/{page_id}` returns the autosave while
+/// it is newer than the page's last save; publishing drops it.
+#[utoipa::path(
+    put,
+    path = "/api/pages/{page_id}/autosave",
+    tag = "pages",
+    params(
+        ("page_id" = Uuid, Path, description = "Page id"),
+    ),
+    request_body = AutosavePageRequest,
+    responses(
+        (status = 200, description = "Autosave kept", body = inline(SuccessResponse<PageAutosave>)),
+        (status = 400, description = "Body too long or malformed request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Page not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[put("/api/pages/{page_id}/autosave")]
+pub async fn autosave_page_handler(
+    user: VerifiedUser,
+    path: web::Path<Uuid>,
+    payload: web::Json<AutosavePageRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let page_id = path.into_inner();
+
+    match data
+        .pages
+        .autosave
+        .execute(user.user_id, page_id, payload.into_inner().body)
+        .await
+    {
+        Ok(autosave) => ApiResponse::success(autosave),
+        Err(err @ AutosavePageError::BodyTooLong) => BODY_TOO_LONG.with_message(&err.to_string()),
+        Err(AutosavePageError::NotFound) => page_not_found(),
+        Err(AutosavePageError::RepositoryError(msg)) => {
+            error!("Failed to autosave page {}: {}", page_id, msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubAutosavePageUseCase,
+        },
+    };
+
+    async fn call(
+        state: web::Data<AppState>,
+        page_id: Uuid,
+        body: serde_json::Value,
+    ) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(autosave_page_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/pages/{page_id}/autosave"))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(body)
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_autosave_is_echoed_back() {
+        let state = TestAppStateBuilder::default().build();
+        let page_id = Uuid::new_v4();
+
+        let resp = call(state, page_id, json!({ "body": "# Draft" })).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["page_id"], page_id.to_string());
+        assert_eq!(json["data"]["body"], "# Draft");
+        assert!(json["data"]["saved_at"].is_string());
+    }
+
+    #[actix_web::test]
+    async fn test_overlong_body_is_rejected() {
+        let state = TestAppStateBuilder::default()
+            .with_autosave_page(StubAutosavePageUseCase::body_too_long())
+            .build();
+
+        let resp = call(state, Uuid::new_v4(), json!({ "body": "# Draft" })).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "BODY_TOO_LONG");
+    }
+}
@@ -0,0 +1,182 @@
+use actix_web::{post, web, Responder};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use utoipa::ToSchema;
+
+use super::map_command_error;
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::shared::api::error_codes::SLUG_ALREADY_EXISTS;
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::VerifiedUser,
+    pages::application::{
+        domain::entities::{Page, PageStatus},
+        ports::incoming::use_cases::{CreatePageCommand, CreatePageError},
+    },
+    shared::api::ApiResponse,
+    AppState,
+};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct CreatePageRequest {
+    /// Lowercase letters, digits and single hyphens; cannot be changed later
+    pub slug: String,
+    /// 1-200 characters
+    pub title: String,
+    /// Markdown, up to 100 000 characters
+    #[serde(default)]
+    pub body: String,
+    /// Defaults to `draft`
+    #[serde(default)]
+    pub status: PageStatus,
+    /// Up to 100 characters
+    pub seo_title: Option<String>,
+    /// Up to 300 characters
+    pub seo_description: Option<String>,
+    /// Absolute http(s) URL of the original, when this page is a copy
+    pub canonical_url: Option<String>,
+    /// Ask search engines not to index the page
+    #[serde(default)]
+    pub noindex: bool,
+}
+
+/// Create a page
+#[utoipa::path(
+    post,
+    path = "/api/pages",
+    tag = "pages",
+    request_body = CreatePageRequest,
+    responses(
+        (status = 201, description = "Page created", body = inline(SuccessResponse<Page>)),
+        (status = 400, description = "Invalid field or malformed request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 409, description = "Slug already in use by another of the caller's pages", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/pages")]
+pub async fn create_page_handler(
+    user: VerifiedUser,
+    payload: web::Json<CreatePageRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let payload = payload.into_inner();
+    let command = match CreatePageCommand::new(
+        payload.slug,
+        payload.title,
+        payload.body,
+        payload.status,
+        payload.seo_title,
+        payload.seo_description,
+    )
+    .and_then(|cmd| cmd.with_indexing(payload.canonical_url, payload.noindex))
+    {
+        Ok(cmd) => cmd,
+        Err(err) => return map_command_error(err),
+    };
+
+    match data.pages.create.execute(user.user_id, command).await {
+        Ok(page) => ApiResponse::created(page),
+        Err(CreatePageError::SlugAlreadyExists) => {
+            SLUG_ALREADY_EXISTS.with_message("Page slug already exists")
+        }
+        Err(CreatePageError::RepositoryError(msg)) => {
+            error!(user = %user.user_id, "Failed to create page: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::json;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubCreatePageUseCase,
+        },
+    };
+
+    async fn call(
+        state: web::Data<AppState>,
+        body: serde_json::Value,
+    ) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(create_page_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/pages")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(body)
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_create_defaults_to_draft() {
+        let state = TestAppStateBuilder::default().build();
+
+        let resp = call(state, json!({ "slug": "about", "title": "About" })).await;
+
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["slug"], "about");
+        assert_eq!(json["data"]["status"], "draft");
+    }
+
+    #[actix_web::test]
+    async fn test_invalid_slug_is_rejected() {
+        let state = TestAppStateBuilder::default().build();
+
+        let resp = call(state, json!({ "slug": "About Me", "title": "About" })).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "INVALID_SLUG");
+    }
+
+    #[actix_web::test]
+    async fn test_relative_canonical_url_is_rejected() {
+        let state = TestAppStateBuilder::default().build();
+
+        let resp = call(
+            state,
+            json!({ "slug": "about", "title": "About", "canonical_url": "/about" }),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "INVALID_CANONICAL_URL");
+    }
+
+    #[actix_web::test]
+    async fn test_duplicate_slug_is_conflict() {
+        let state = TestAppStateBuilder::default()
+            .with_create_page(StubCreatePageUseCase::slug_taken())
+            .build();
+
+        let resp = call(state, json!({ "slug": "about", "title": "About" })).await;
+
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+}
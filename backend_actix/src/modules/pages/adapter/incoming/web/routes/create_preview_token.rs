@@ -0,0 +1,168 @@
+use actix_web::{post, web, Responder};
+use serde::Deserialize;
+use tracing::error;
+use uuid::Uuid;
+
+use super::page_not_found;
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::pages::adapter::incoming::web::error_codes::INVALID_EXPIRY;
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::VerifiedUser,
+    pages::application::ports::incoming::use_cases::{CreatePreviewTokenError, PreviewToken},
+    shared::api::ApiResponse,
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct CreatePreviewTokenQuery {
+    pub expires_in_hours: Option<u32>,
+}
+
+/// Create a preview link for one of the caller's pages
+///
+/// Anyone holding the token can read the page, draft or not, through
+/// `GET /api/public/preview/{token}` until it expires. Links are not stored
+/// and can't be revoked one by one; changing `PREVIEW_SECRET` revokes them
+/// all.
+#[utoipa::path(
+    post,
+    path = "/api/pages/{page_id}/preview-token",
+    tag = "pages",
+    params(
+        ("page_id" = Uuid, Path, description = "Page id"),
+        ("expires_in_hours" = Option<u32>, Query, description = "Hours the link stays valid, 1-720 (default 168, a week)"),
+    ),
+    responses(
+        (status = 201, description = "Preview token created", body = inline(SuccessResponse<PreviewToken>)),
+        (status = 400, description = "Expiry out of range", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Page not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/pages/{page_id}/preview-token")]
+pub async fn create_preview_token_handler(
+    user: VerifiedUser,
+    path: web::Path<Uuid>,
+    query: web::Query<CreatePreviewTokenQuery>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let page_id = path.into_inner();
+
+    match data
+        .pages
+        .create_preview_token
+        .execute(user.user_id, page_id, query.expires_in_hours)
+        .await
+    {
+        Ok(token) => ApiResponse::created(token),
+        Err(err @ CreatePreviewTokenError::InvalidExpiry) => {
+            INVALID_EXPIRY.with_message(&err.to_string())
+        }
+        Err(CreatePreviewTokenError::NotFound) => page_not_found(),
+        Err(CreatePreviewTokenError::RepositoryError(msg)) => {
+            error!(
+                "Failed to create preview token for page {}: {}",
+                page_id, msg
+            );
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service,
+            stubs::StubCreatePreviewTokenUseCase,
+        },
+    };
+
+    async fn call(state: web::Data<AppState>, uri: &str) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(create_preview_token_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_token_is_created() {
+        let state = TestAppStateBuilder::default().build();
+
+        let resp = call(
+            state,
+            &format!(
+                "/api/pages/{}/preview-token?expires_in_hours=24",
+                Uuid::new_v4()
+            ),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert!(json["data"]["token"].is_string());
+        assert!(json["data"]["expires_at"].is_string());
+    }
+
+    #[actix_web::test]
+    async fn test_out_of_range_expiry_is_rejected() {
+        let state = TestAppStateBuilder::default()
+            .with_create_preview_token(StubCreatePreviewTokenUseCase::invalid_expiry())
+            .build();
+
+        let resp = call(
+            state,
+            &format!(
+                "/api/pages/{}/preview-token?expires_in_hours=0",
+                Uuid::new_v4()
+            ),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "INVALID_EXPIRY");
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_page_is_not_found() {
+        let state = TestAppStateBuilder::default()
+            .with_create_preview_token(StubCreatePreviewTokenUseCase::not_found())
+            .build();
+
+        let resp = call(
+            state,
+            &format!("/api/pages/{}/preview-token", Uuid::new_v4()),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "PAGE_NOT_FOUND");
+    }
+}
@@ -0,0 +1,48 @@
+use actix_web::{delete, web, Responder};
+use tracing::error;
+use uuid::Uuid;
+
+use super::page_not_found;
+use crate::api::schemas::ErrorResponse;
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::VerifiedUser,
+    pages::application::ports::incoming::use_cases::DeletePageError, shared::api::ApiResponse,
+    AppState,
+};
+
+/// Delete a page for good. Pages have no trash.
+#[utoipa::path(
+    delete,
+    path = "/api/pages/{page_id}",
+    tag = "pages",
+    params(
+        ("page_id" = Uuid, Path, description = "Page id"),
+    ),
+    responses(
+        (status = 204, description = "Page deleted"),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Page not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[delete("/api/pages/{page_id}")]
+pub async fn delete_page_handler(
+    user: VerifiedUser,
+    path: web::Path<Uuid>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let page_id = path.into_inner();
+
+    match data.pages.delete.execute(user.user_id, page_id).await {
+        Ok(()) => ApiResponse::no_content(),
+        Err(DeletePageError::NotFound) => page_not_found(),
+        Err(DeletePageError::RepositoryError(msg)) => {
+            error!("Failed to delete page {}: {}", page_id, msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
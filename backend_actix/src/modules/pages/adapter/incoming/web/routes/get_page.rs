@@ -0,0 +1,143 @@
+use actix_web::{get, web, Responder};
+use serde::Serialize;
+use tracing::{error, warn};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::page_not_found;
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::VerifiedUser,
+    pages::application::{
+        domain::{autosave::PageAutosave, entities::Page},
+        ports::incoming::use_cases::GetPageError,
+    },
+    shared::api::ApiResponse,
+    AppState,
+};
+
+/// A page as the editor opens it
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EditablePage {
+    #[serde(flatten)]
+    pub page: Page,
+    /// Unsaved work newer than the page's last save, to offer restoring
+    pub autosave: Option<PageAutosave>,
+}
+
+/// One of the caller's pages, draft or not
+///
+/// Comes with the editor's autosave (see `PUT /api/pages/{page_id}/autosave`)
+/// when it is newer than the page's last save.
+#[utoipa::path(
+    get,
+    path = "/api/pages/{page_id}",
+    tag = "pages",
+    params(
+        ("page_id" = Uuid, Path, description = "Page id"),
+    ),
+    responses(
+        (status = 200, description = "Page found", body = inline(SuccessResponse<EditablePage>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Page not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/pages/{page_id}")]
+pub async fn get_page_handler(
+    user: VerifiedUser,
+    path: web::Path<Uuid>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let page_id = path.into_inner();
+
+    let page = match data.pages.get.execute(user.user_id, page_id).await {
+        Ok(page) => page,
+        Err(GetPageError::NotFound) => return page_not_found(),
+        Err(GetPageError::RepositoryError(msg)) => {
+            error!("Failed to fetch page {}: {}", page_id, msg);
+            return ApiResponse::internal_error();
+        }
+    };
+
+    // The page opens without it rather than not at all
+    let autosave = data
+        .pages
+        .get_autosave
+        .execute(&page)
+        .await
+        .unwrap_or_else(|e| {
+            warn!("Failed to fetch autosave of page {}: {}", page_id, e);
+            None
+        });
+
+    ApiResponse::success(EditablePage { page, autosave })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubGetPageAutosaveUseCase,
+        },
+    };
+
+    async fn call(state: web::Data<AppState>) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(get_page_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/api/pages/{}", Uuid::new_v4()))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_page_comes_with_its_autosave() {
+        let state = TestAppStateBuilder::default()
+            .with_get_page_autosave(StubGetPageAutosaveUseCase::restorable())
+            .build();
+
+        let resp = call(state).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["body"], "# Hello");
+        assert_eq!(json["data"]["autosave"]["body"], "# Hello, unsaved");
+    }
+
+    #[actix_web::test]
+    async fn test_failed_autosave_lookup_still_opens_the_page() {
+        let state = TestAppStateBuilder::default()
+            .with_get_page_autosave(StubGetPageAutosaveUseCase::failing())
+            .build();
+
+        let resp = call(state).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["slug"], "about");
+        assert!(json["data"]["autosave"].is_null());
+    }
+}
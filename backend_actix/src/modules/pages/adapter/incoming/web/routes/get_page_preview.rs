@@ -0,0 +1,119 @@
+use actix_web::{get, web, Responder};
+use tracing::error;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::pages::adapter::incoming::web::error_codes::{PREVIEW_EXPIRED, PREVIEW_NOT_FOUND};
+use crate::{
+    pages::application::{domain::entities::Page, ports::incoming::use_cases::GetPagePreviewError},
+    shared::api::{cache::CachePolicy, ApiResponse},
+    shared::seo,
+    AppState,
+};
+
+/// Read a page through a preview link
+///
+/// Serves the page as it is now, draft or not, to whoever holds a token from
+/// `POST /api/pages/{page_id}/preview-token`. Never cached, and marked
+/// `X-Robots-Tag: noindex` so shared links stay out of search engines.
+#[utoipa::path(
+    get,
+    path = "/api/public/preview/{token}",
+    tag = "pages",
+    params(
+        ("token" = String, Path, description = "Preview token"),
+    ),
+    responses(
+        (status = 200, description = "Page found", body = inline(SuccessResponse<Page>)),
+        (status = 404, description = "Invalid token, or the page was deleted", body = ErrorResponse),
+        (status = 410, description = "Preview link has expired", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+)]
+#[get("/api/public/preview/{token}")]
+pub async fn get_page_preview_handler(
+    path: web::Path<String>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let mut resp = match data.pages.get_preview.execute(&path).await {
+        Ok(page) => ApiResponse::success(page),
+        // A forged token and a deleted page look the same to the visitor
+        Err(GetPagePreviewError::InvalidToken | GetPagePreviewError::NotFound) => {
+            PREVIEW_NOT_FOUND.response()
+        }
+        Err(err @ GetPagePreviewError::Expired) => PREVIEW_EXPIRED.with_message(&err.to_string()),
+        Err(GetPagePreviewError::RepositoryError(msg)) => {
+            error!("Failed to fetch page preview: {}", msg);
+            ApiResponse::internal_error()
+        }
+    };
+    CachePolicy::NoStore.apply(&mut resp);
+    seo::forbid_indexing(&mut resp);
+    resp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{
+        http::{header, StatusCode},
+        test, App,
+    };
+
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, stubs::StubGetPagePreviewUseCase,
+    };
+
+    async fn call(state: web::Data<AppState>) -> actix_web::dev::ServiceResponse {
+        let app =
+            test::init_service(App::new().app_data(state).service(get_page_preview_handler)).await;
+        let req = test::TestRequest::get()
+            .uri("/api/public/preview/some-token")
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_draft_is_served_uncached_and_unindexed() {
+        let state = TestAppStateBuilder::default()
+            .with_get_page_preview(StubGetPagePreviewUseCase::draft())
+            .build();
+
+        let resp = call(state).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers().get(header::CACHE_CONTROL).unwrap(),
+            "no-store"
+        );
+        assert_eq!(resp.headers().get("x-robots-tag").unwrap(), "noindex");
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["status"], "draft");
+    }
+
+    #[actix_web::test]
+    async fn test_invalid_token_is_not_found() {
+        let state = TestAppStateBuilder::default()
+            .with_get_page_preview(StubGetPagePreviewUseCase::invalid())
+            .build();
+
+        let resp = call(state).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "PREVIEW_NOT_FOUND");
+    }
+
+    #[actix_web::test]
+    async fn test_expired_token_is_gone() {
+        let state = TestAppStateBuilder::default()
+            .with_get_page_preview(StubGetPagePreviewUseCase::expired())
+            .build();
+
+        let resp = call(state).await;
+
+        assert_eq!(resp.status(), StatusCode::GONE);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "PREVIEW_EXPIRED");
+    }
+}
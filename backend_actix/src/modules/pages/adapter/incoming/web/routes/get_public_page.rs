@@ -0,0 +1,279 @@
+use actix_web::{get, web, HttpRequest, Responder};
+use serde::Deserialize;
+use tracing::error;
+
+use super::page_not_found;
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::pages::adapter::incoming::web::error_codes::PAGE_GONE;
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::{resolve_owner_id_or_response, MaybeUser},
+    pages::application::{domain::entities::Page, ports::incoming::use_cases::GetPublicPageError},
+    shared::api::{
+        owner_view::{OwnerControls, OwnerView},
+        ApiResponse,
+    },
+    shared::seo,
+    translations::{
+        adapter::incoming::web::localize::{respond_localized, respond_localized_to_owner},
+        application::domain::entities::TranslatableKind,
+    },
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct PublicPagePath {
+    pub username: String,
+    pub slug: String,
+}
+
+/// Get a published page by owner and slug
+///
+/// Served in the visitor's locale when the owner translated it; fields left
+/// untranslated keep the original. When the owner is signed in, the page
+/// also carries `owner_controls` with its edit link, and isn't cached. A
+/// `noindex` page is sent with `X-Robots-Tag: noindex`. A published page
+/// that was deleted answers 410, so crawlers drop it.
+#[utoipa::path(
+    get,
+    path = "/api/public/pages/{username}/{slug}",
+    tag = "pages",
+    params(
+        ("username" = String, Path, description = "Owner's username"),
+        ("slug" = String, Path, description = "Page slug"),
+        ("lang" = Option<String>, Query, description = "Locale to serve, e.g. `pt-BR`; defaults to `Accept-Language`"),
+    ),
+    responses(
+        (status = 200, description = "Page found (carries an `ETag`)", body = inline(SuccessResponse<Page>)),
+        (status = 304, description = "Not modified since the `If-None-Match` ETag"),
+        (status = 404, description = "User or page not found, or the page is a draft", body = ErrorResponse),
+        (status = 410, description = "The page was published, then deleted", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+)]
+#[get("/api/public/pages/{username}/{slug}")]
+pub async fn get_public_page_handler(
+    req: HttpRequest,
+    path: web::Path<PublicPagePath>,
+    viewer: MaybeUser,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let path = path.into_inner();
+
+    let owner_id = match resolve_owner_id_or_response(&data, &path.username).await {
+        Ok(id) => id,
+        Err(resp) => return resp,
+    };
+
+    match data.pages.get_public.execute(owner_id, &path.slug).await {
+        Ok(page) => {
+            let noindex = page.noindex;
+            let mut resp = if viewer.is(owner_id) {
+                let controls = OwnerControls::new(format!("/api/pages/{}", page.id));
+                respond_localized_to_owner(
+                    &req,
+                    &data,
+                    TranslatableKind::Page,
+                    page.id,
+                    page.updated_at,
+                    OwnerView::new(page, controls),
+                )
+                .await
+            } else {
+                respond_localized(
+                    &req,
+                    &data,
+                    TranslatableKind::Page,
+                    page.id,
+                    page.updated_at,
+                    page,
+                )
+                .await
+            };
+            if noindex {
+                seo::forbid_indexing(&mut resp);
+            }
+            resp
+        }
+        Err(GetPublicPageError::NotFound) => page_not_found(),
+        Err(GetPublicPageError::Gone(_)) => PAGE_GONE.response(),
+        Err(GetPublicPageError::RepositoryError(msg)) => {
+            error!(
+                "Failed to fetch public page slug={} for username={}: {}",
+                path.slug, path.username, msg
+            );
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use chrono::Utc;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::auth::adapter::outgoing::in_memory::InMemoryUserStore;
+    use crate::auth::application::helpers::UserIdentityResolver;
+    use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+    use crate::auth::application::ports::outgoing::user_query::UserQueryResult;
+    use crate::tests::support::auth_helper::test_helpers::create_test_jwt_service;
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, stubs::StubGetPublicPageUseCase,
+    };
+
+    fn resolver_with(username: &str) -> UserIdentityResolver {
+        resolver_for(username, Uuid::new_v4())
+    }
+
+    fn resolver_for(username: &str, user_id: Uuid) -> UserIdentityResolver {
+        let users = InMemoryUserStore::default();
+        users.users.write(|users| {
+            users.push(UserQueryResult {
+                id: user_id,
+                email: "jane@example.com".to_string(),
+                username: username.to_string(),
+                password_hash: String::new(),
+                full_name: "Jane".to_string(),
+                preferred_locale: "en".to_string(),
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                is_verified: true,
+                is_deleted: false,
+                token_version: 0,
+                is_active: true,
+            })
+        });
+        UserIdentityResolver::new(Arc::new(users))
+    }
+
+    async fn call(state: web::Data<AppState>, uri: &str) -> actix_web::dev::ServiceResponse {
+        let app =
+            test::init_service(App::new().app_data(state).service(get_public_page_handler)).await;
+        let req = test::TestRequest::get().uri(uri).to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_published_page_is_served_with_etag() {
+        let state = TestAppStateBuilder::default()
+            .with_user_identity_resolver(resolver_with("jane"))
+            .build();
+
+        let resp = call(state, "/api/public/pages/jane/about").await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert!(resp.headers().contains_key("etag"));
+        assert!(!resp.headers().contains_key("x-robots-tag"));
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["status"], "published");
+        assert_eq!(json["data"]["noindex"], false);
+    }
+
+    #[actix_web::test]
+    async fn test_noindex_page_asks_robots_to_stay_away() {
+        let state = TestAppStateBuilder::default()
+            .with_user_identity_resolver(resolver_with("jane"))
+            .with_get_public_page(StubGetPublicPageUseCase::noindex())
+            .build();
+
+        let resp = call(state, "/api/public/pages/jane/about").await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers().get("x-robots-tag").unwrap(), "noindex");
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["noindex"], true);
+        assert_eq!(json["data"]["canonical_url"], "https://dev.to/jane/about");
+    }
+
+    #[actix_web::test]
+    async fn test_owner_gets_edit_link_and_an_uncached_response() {
+        let owner_id = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_user_identity_resolver(resolver_for("jane", owner_id))
+            .build();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> =
+            Arc::new(create_test_jwt_service());
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(Arc::clone(&token_provider)))
+                .service(get_public_page_handler),
+        )
+        .await;
+        let get = |user_id: Uuid| {
+            let token = token_provider
+                .generate_access_token(user_id, true, 0)
+                .unwrap();
+            test::TestRequest::get()
+                .uri("/api/public/pages/jane/about")
+                .insert_header(("Authorization", format!("Bearer {token}")))
+                .to_request()
+        };
+
+        let resp = test::call_service(&app, get(owner_id)).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers().get("cache-control").unwrap(), "no-store");
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        let page_id = json["data"]["id"].as_str().unwrap();
+        assert_eq!(
+            json["data"]["owner_controls"]["edit_url"],
+            format!("/api/pages/{page_id}")
+        );
+
+        // Another user, or a token that doesn't verify, sees the public page
+        let resp = test::call_service(&app, get(Uuid::new_v4())).await;
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert!(json["data"].get("owner_controls").is_none());
+
+        let garbage = test::TestRequest::get()
+            .uri("/api/public/pages/jane/about")
+            .insert_header(("Authorization", "Bearer not.a.token"))
+            .to_request();
+        assert_eq!(
+            test::call_service(&app, garbage).await.status(),
+            StatusCode::OK
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_draft_or_missing_page_is_not_found() {
+        let state = TestAppStateBuilder::default()
+            .with_user_identity_resolver(resolver_with("jane"))
+            .with_get_public_page(StubGetPublicPageUseCase::not_found())
+            .build();
+
+        let resp = call(state, "/api/public/pages/jane/now").await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "PAGE_NOT_FOUND");
+    }
+
+    #[actix_web::test]
+    async fn test_deleted_page_is_gone() {
+        let state = TestAppStateBuilder::default()
+            .with_user_identity_resolver(resolver_with("jane"))
+            .with_get_public_page(StubGetPublicPageUseCase::gone())
+            .build();
+
+        let resp = call(state, "/api/public/pages/jane/about").await;
+
+        assert_eq!(resp.status(), StatusCode::GONE);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "PAGE_GONE");
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_user_is_not_found() {
+        let state = TestAppStateBuilder::default().build();
+
+        let resp = call(state, "/api/public/pages/nobody/about").await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "USER_NOT_FOUND");
+    }
+}
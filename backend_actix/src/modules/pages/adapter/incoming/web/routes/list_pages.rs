@@ -0,0 +1,49 @@
+use actix_web::{get, web, HttpRequest, Responder};
+use tracing::error;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::VerifiedUser,
+    pages::application::{domain::entities::Page, ports::incoming::use_cases::ListPagesError},
+    shared::api::{
+        pagination::{PageParams, PagedResponse},
+        ApiResponse,
+    },
+    AppState,
+};
+
+/// The caller's pages, drafts included, by slug
+#[utoipa::path(
+    get,
+    path = "/api/pages",
+    tag = "pages",
+    params(
+        ("limit" = Option<u32>, Query, description = "Page size, 1-100 (default 20)"),
+        ("cursor" = Option<String>, Query, description = "`next_cursor` from the previous page"),
+    ),
+    responses(
+        (status = 200, description = "One page of pages (paging also in `X-Total-Count` and `Link`)", body = inline(SuccessResponse<PagedResponse<Page>>)),
+        (status = 400, description = "Invalid pagination parameters", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/pages")]
+pub async fn list_pages_handler(
+    req: HttpRequest,
+    user: VerifiedUser,
+    page: PageParams,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data.pages.list.execute(user.user_id).await {
+        Ok(pages) => PagedResponse::from_all(pages, &page).respond(&req, &page),
+        Err(ListPagesError::RepositoryError(msg)) => {
+            error!(user = %user.user_id, "Failed to list pages: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
@@ -0,0 +1,104 @@
+mod acquire_page_lock;
+mod autosave_page;
+mod create_page;
+mod create_preview_token;
+mod delete_page;
+mod get_page;
+mod get_page_preview;
+mod get_public_page;
+mod list_pages;
+mod release_page_lock;
+mod renew_page_lock;
+mod update_page;
+
+pub use acquire_page_lock::{__path_acquire_page_lock_handler, acquire_page_lock_handler};
+pub use autosave_page::{__path_autosave_page_handler, autosave_page_handler};
+pub use create_page::{__path_create_page_handler, create_page_handler};
+pub use create_preview_token::{__path_create_preview_token_handler, create_preview_token_handler};
+pub use delete_page::{__path_delete_page_handler, delete_page_handler};
+pub use get_page::{__path_get_page_handler, get_page_handler};
+pub use get_page_preview::{__path_get_page_preview_handler, get_page_preview_handler};
+pub use get_public_page::{__path_get_public_page_handler, get_public_page_handler};
+pub use list_pages::{__path_list_pages_handler, list_pages_handler};
+pub use release_page_lock::{__path_release_page_lock_handler, release_page_lock_handler};
+pub use renew_page_lock::{__path_renew_page_lock_handler, renew_page_lock_handler};
+pub use update_page::{__path_update_page_handler, update_page_handler};
+
+use actix_web::{HttpRequest, HttpResponse};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::pages::adapter::incoming::web::error_codes::{PAGE_LOCKED, PAGE_NOT_FOUND};
+use crate::pages::application::domain::edit_lock::EditLock;
+use crate::pages::application::ports::incoming::use_cases::PageCommandError;
+use crate::shared::api::ApiResponse;
+use crate::shared::rate_limit;
+
+/// Carries the lock id of the session sending an edit
+pub const EDIT_LOCK_HEADER: &str = "X-Edit-Lock";
+
+/// The edit lock the caller's session holds
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PageLockResponse {
+    /// Send as `X-Edit-Lock` with every edit, and keep it to yourself
+    pub lock_id: Uuid,
+    pub page_id: Uuid,
+    pub label: Option<String>,
+    pub acquired_at: DateTime<Utc>,
+    /// Renew before then, e.g. every 30 seconds
+    pub expires_at: DateTime<Utc>,
+    /// `PUT` renews the lock, `DELETE` releases it
+    pub heartbeat_url: String,
+}
+
+impl From<EditLock> for PageLockResponse {
+    fn from(lock: EditLock) -> Self {
+        Self {
+            heartbeat_url: format!("/api/pages/{}/lock/{}", lock.page_id, lock.lock_id),
+            lock_id: lock.lock_id,
+            page_id: lock.page_id,
+            label: lock.label,
+            acquired_at: lock.acquired_at,
+            expires_at: lock.expires_at,
+        }
+    }
+}
+
+fn map_command_error(err: PageCommandError) -> HttpResponse {
+    ApiResponse::bad_request(err.code(), &err.to_string())
+}
+
+fn page_not_found() -> HttpResponse {
+    PAGE_NOT_FOUND.response()
+}
+
+/// 423 saying who holds the lock since when; `Retry-After` is when it
+/// lapses unless renewed. The lock id is never shown.
+fn page_locked(holder: &EditLock) -> HttpResponse {
+    let session = match &holder.label {
+        Some(label) => format!("another session ({label})"),
+        None => "another session".to_string(),
+    };
+    let message = format!(
+        "Page is being edited in {session} since {}, locked until {}",
+        holder.acquired_at.to_rfc3339(),
+        holder.expires_at.to_rfc3339()
+    );
+    let remaining = (holder.expires_at - Utc::now())
+        .to_std()
+        .unwrap_or_default();
+    rate_limit::rate_limited(PAGE_LOCKED, &message, remaining)
+}
+
+/// Lock id from `X-Edit-Lock`; a malformed one counts as none
+fn edit_lock_id(req: &HttpRequest) -> Option<Uuid> {
+    req.headers()
+        .get(EDIT_LOCK_HEADER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
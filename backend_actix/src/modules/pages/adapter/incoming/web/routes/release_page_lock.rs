@@ -0,0 +1,57 @@
+use actix_web::{delete, web, Responder};
+use tracing::error;
+use uuid::Uuid;
+
+use super::page_not_found;
+use crate::api::schemas::ErrorResponse;
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::VerifiedUser,
+    pages::application::ports::incoming::use_cases::ReleasePageLockError, shared::api::ApiResponse,
+    AppState,
+};
+
+/// Give up this session's edit lock, e.g. when the editor closes
+///
+/// Succeeds as well when the lock already lapsed or another session holds
+/// the page.
+#[utoipa::path(
+    delete,
+    path = "/api/pages/{page_id}/lock/{lock_id}",
+    tag = "pages",
+    params(
+        ("page_id" = Uuid, Path, description = "Page id"),
+        ("lock_id" = Uuid, Path, description = "Lock id the session was given"),
+    ),
+    responses(
+        (status = 204, description = "Lock released"),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Page not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[delete("/api/pages/{page_id}/lock/{lock_id}")]
+pub async fn release_page_lock_handler(
+    user: VerifiedUser,
+    path: web::Path<(Uuid, Uuid)>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let (page_id, lock_id) = path.into_inner();
+
+    match data
+        .pages
+        .release_lock
+        .execute(user.user_id, page_id, lock_id)
+        .await
+    {
+        Ok(()) => ApiResponse::no_content(),
+        Err(ReleasePageLockError::NotFound) => page_not_found(),
+        Err(ReleasePageLockError::RepositoryError(msg)) => {
+            error!("Failed to release lock of page {}: {}", page_id, msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use actix_web::{put, web, Responder};
+use tracing::error;
+use uuid::Uuid;
+
+use super::{page_locked, page_not_found, PageLockResponse};
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::VerifiedUser,
+    pages::application::ports::incoming::use_cases::AcquirePageLockError, shared::api::ApiResponse,
+    AppState,
+};
+
+/// Keep this session's edit lock alive
+///
+/// Extends the lock by two minutes from now. A lock that lapsed is taken
+/// again under the same id, unless another session got it first.
+#[utoipa::path(
+    put,
+    path = "/api/pages/{page_id}/lock/{lock_id}",
+    tag = "pages",
+    params(
+        ("page_id" = Uuid, Path, description = "Page id"),
+        ("lock_id" = Uuid, Path, description = "Lock id the session was given"),
+    ),
+    responses(
+        (status = 200, description = "Lock renewed", body = inline(SuccessResponse<PageLockResponse>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Page not found", body = ErrorResponse),
+        (status = 423, description = "The lock lapsed and another session holds it now", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[put("/api/pages/{page_id}/lock/{lock_id}")]
+pub async fn renew_page_lock_handler(
+    user: VerifiedUser,
+    path: web::Path<(Uuid, Uuid)>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let (page_id, lock_id) = path.into_inner();
+
+    match data
+        .pages
+        .acquire_lock
+        .execute(user.user_id, page_id, Some(lock_id), None)
+        .await
+    {
+        Ok(lock) => ApiResponse::success(PageLockResponse::from(lock)),
+        Err(AcquirePageLockError::NotFound) => page_not_found(),
+        Err(AcquirePageLockError::Locked(holder)) => page_locked(&holder),
+        // No label to check on a renewal
+        Err(AcquirePageLockError::InvalidLabel(msg))
+        | Err(AcquirePageLockError::RepositoryError(msg)) => {
+            error!("Failed to renew lock of page {}: {}", page_id, msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use actix_web::{patch, web, HttpRequest, Responder};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::{edit_lock_id, map_command_error, page_locked, page_not_found};
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::VerifiedUser,
+    pages::application::{
+        domain::entities::{Page, PageStatus},
+        ports::incoming::use_cases::{UpdatePageCommand, UpdatePageError},
+    },
+    project::application::ports::outgoing::project_repository::PatchField,
+    shared::api::{error_codes::STALE_WRITE, precondition, ApiResponse},
+    AppState,
+};
+
+/// Omitted fields are kept. `null` clears the SEO fields and `canonical_url`,
+/// and empties the body.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct UpdatePageRequest {
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub title: PatchField<String>,
+
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub body: PatchField<String>,
+
+    /// Publishing for the first time sets `published_at`
+    #[serde(default)]
+    #[schema(value_type = Option<PageStatus>)]
+    pub status: PatchField<PageStatus>,
+
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub seo_title: PatchField<String>,
+
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub seo_description: PatchField<String>,
+
+    /// Absolute http(s) URL of the original, when this page is a copy
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub canonical_url: PatchField<String>,
+
+    #[serde(default)]
+    pub noindex: Option<bool>,
+
+    /// The page's `updated_at` as last read; the write fails with
+    /// `STALE_WRITE` if it changed since. `If-Unmodified-Since` works too.
+    #[serde(default)]
+    pub expected_updated_at: Option<DateTime<Utc>>,
+}
+
+/// Edit, publish or unpublish a page. The slug cannot change.
+///
+/// While another session holds the page's edit lock (see
+/// `POST /api/pages/{page_id}/lock`) the edit is refused with 423
+/// `PAGE_LOCKED`; the holder sends its lock id in `X-Edit-Lock`.
+#[utoipa::path(
+    patch,
+    path = "/api/pages/{page_id}",
+    tag = "pages",
+    params(
+        ("page_id" = Uuid, Path, description = "Page id"),
+        ("X-Edit-Lock" = Option<Uuid>, Header, description = "Id of the edit lock this session holds"),
+    ),
+    request_body = UpdatePageRequest,
+    responses(
+        (status = 200, description = "Page updated", body = inline(SuccessResponse<Page>)),
+        (status = 400, description = "Invalid field or malformed request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Page not found", body = ErrorResponse),
+        (status = 409, description = "Page changed since `expected_updated_at`", body = ErrorResponse),
+        (status = 423, description = "Another session holds the page's edit lock", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[patch("/api/pages/{page_id}")]
+pub async fn update_page_handler(
+    user: VerifiedUser,
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    payload: web::Json<UpdatePageRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let page_id = path.into_inner();
+    let payload = payload.into_inner();
+    let command = match UpdatePageCommand::new(
+        payload.title,
+        payload.body,
+        payload.status,
+        payload.seo_title,
+        payload.seo_description,
+    )
+    .and_then(|cmd| cmd.with_indexing(payload.canonical_url, payload.noindex))
+    {
+        Ok(cmd) => cmd
+            .with_expected_updated_at(precondition::expected_updated_at(
+                &req,
+                payload.expected_updated_at,
+            ))
+            .with_lock(edit_lock_id(&req)),
+        Err(err) => return map_command_error(err),
+    };
+
+    match data
+        .pages
+        .update
+        .execute(user.user_id, page_id, command)
+        .await
+    {
+        Ok(page) => ApiResponse::success(page),
+        Err(UpdatePageError::NotFound) => page_not_found(),
+        Err(UpdatePageError::Stale) => STALE_WRITE.response(),
+        Err(UpdatePageError::Locked(holder)) => page_locked(&holder),
+        Err(UpdatePageError::RepositoryError(msg)) => {
+            error!("Failed to update page {}: {}", page_id, msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubUpdatePageUseCase,
+        },
+    };
+
+    async fn call(
+        state: web::Data<AppState>,
+        body: serde_json::Value,
+    ) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(update_page_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::patch()
+            .uri(&format!("/api/pages/{}", Uuid::new_v4()))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(body)
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_null_title_is_rejected() {
+        let state = TestAppStateBuilder::default().build();
+
+        let resp = call(state, json!({ "title": null })).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "INVALID_TITLE");
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_page_is_not_found() {
+        let state = TestAppStateBuilder::default()
+            .with_update_page(StubUpdatePageUseCase::not_found())
+            .build();
+
+        let resp = call(state, json!({ "status": "published" })).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "PAGE_NOT_FOUND");
+    }
+
+    #[actix_web::test]
+    async fn test_stale_write_is_conflict() {
+        let state = TestAppStateBuilder::default()
+            .with_update_page(StubUpdatePageUseCase::stale())
+            .build();
+
+        let resp = call(
+            state,
+            json!({ "title": "About", "expected_updated_at": "2026-10-16T09:00:00Z" }),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "STALE_WRITE");
+    }
+
+    #[actix_web::test]
+    async fn test_edit_from_another_session_is_locked() {
+        let state = TestAppStateBuilder::default()
+            .with_update_page(StubUpdatePageUseCase::locked())
+            .build();
+
+        let resp = call(state, json!({ "title": "About" })).await;
+
+        assert_eq!(resp.status(), StatusCode::LOCKED);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "PAGE_LOCKED");
+    }
+}
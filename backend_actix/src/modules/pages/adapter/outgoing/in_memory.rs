@@ -0,0 +1,308 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::pages::application::domain::autosave::PageAutosave;
+use crate::pages::application::domain::edit_lock::EditLock;
+use crate::pages::application::domain::entities::{Page, PageStatus};
+use crate::pages::application::ports::incoming::use_cases::{CreatePageCommand, UpdatePageCommand};
+use crate::pages::application::ports::outgoing::{PageRepository, PageRepositoryError};
+use crate::project::application::ports::outgoing::project_repository::PatchField;
+use crate::shared::in_memory::Table;
+
+/// Process-local `PageRepository`
+#[derive(Clone, Default)]
+pub struct InMemoryPageStore {
+    pub(crate) pages: Table<Page>,
+    pub(crate) locks: Table<EditLock>,
+    pub(crate) autosaves: Table<PageAutosave>,
+    pub(crate) tombstones: Table<Tombstone>,
+}
+
+/// Slug of a deleted page that had been published
+pub(crate) struct Tombstone {
+    owner_id: Uuid,
+    slug: String,
+    deleted_at: DateTime<Utc>,
+}
+
+fn patch(current: &mut Option<String>, field: &PatchField<String>) {
+    match field {
+        PatchField::Unset => {}
+        PatchField::Null => *current = None,
+        PatchField::Value(value) => *current = Some(value.clone()),
+    }
+}
+
+#[async_trait]
+impl PageRepository for InMemoryPageStore {
+    async fn create(
+        &self,
+        owner_id: Uuid,
+        command: &CreatePageCommand,
+    ) -> Result<Page, PageRepositoryError> {
+        self.pages.write(|pages| {
+            if pages
+                .iter()
+                .any(|page| page.owner_id == owner_id && page.slug == command.slug())
+            {
+                return Err(PageRepositoryError::SlugAlreadyExists);
+            }
+
+            let now = Utc::now();
+            let page = Page {
+                id: Uuid::new_v4(),
+                owner_id,
+                slug: command.slug().to_string(),
+                title: command.title().to_string(),
+                body: command.body().to_string(),
+                status: command.status(),
+                seo_title: command.seo_title().map(str::to_string),
+                seo_description: command.seo_description().map(str::to_string),
+                canonical_url: command.canonical_url().map(str::to_string),
+                noindex: command.noindex(),
+                published_at: (command.status() == PageStatus::Published).then_some(now),
+                created_at: now,
+                updated_at: now,
+            };
+            pages.push(page.clone());
+            Ok(page)
+        })
+    }
+
+    async fn list(&self, owner_id: Uuid) -> Result<Vec<Page>, PageRepositoryError> {
+        let mut pages: Vec<Page> = self.pages.read(|pages| {
+            pages
+                .iter()
+                .filter(|page| page.owner_id == owner_id)
+                .cloned()
+                .collect()
+        });
+        pages.sort_by(|a, b| a.slug.cmp(&b.slug));
+        Ok(pages)
+    }
+
+    async fn get(&self, owner_id: Uuid, id: Uuid) -> Result<Page, PageRepositoryError> {
+        self.pages.read(|pages| {
+            pages
+                .iter()
+                .find(|page| page.id == id && page.owner_id == owner_id)
+                .cloned()
+                .ok_or(PageRepositoryError::NotFound)
+        })
+    }
+
+    async fn update(
+        &self,
+        owner_id: Uuid,
+        id: Uuid,
+        command: &UpdatePageCommand,
+    ) -> Result<Page, PageRepositoryError> {
+        let page = self.pages.write(|pages| {
+            let page = pages
+                .iter_mut()
+                .find(|page| page.id == id && page.owner_id == owner_id)
+                .ok_or(PageRepositoryError::NotFound)?;
+            if command
+                .expected_updated_at()
+                .is_some_and(|expected| page.updated_at > expected)
+            {
+                return Err(PageRepositoryError::Stale);
+            }
+
+            let now = Utc::now();
+            if let Some(title) = command.title() {
+                page.title = title.to_string();
+            }
+            if let Some(body) = command.body() {
+                page.body = body.to_string();
+            }
+            if let Some(status) = command.status() {
+                page.status = status;
+                if status == PageStatus::Published {
+                    page.published_at.get_or_insert(now);
+                }
+            }
+            patch(&mut page.seo_title, command.seo_title());
+            patch(&mut page.seo_description, command.seo_description());
+            patch(&mut page.canonical_url, command.canonical_url());
+            if let Some(noindex) = command.noindex() {
+                page.noindex = noindex;
+            }
+            page.updated_at = now;
+
+            Ok(page.clone())
+        })?;
+
+        if command.status() == Some(PageStatus::Published) {
+            self.autosaves
+                .write(|autosaves| autosaves.retain(|autosave| autosave.page_id != id));
+        }
+        Ok(page)
+    }
+
+    async fn delete(&self, owner_id: Uuid, id: Uuid) -> Result<(), PageRepositoryError> {
+        let page = self.pages.write(|pages| {
+            let index = pages
+                .iter()
+                .position(|page| page.id == id && page.owner_id == owner_id)
+                .ok_or(PageRepositoryError::NotFound)?;
+            Ok(pages.remove(index))
+        })?;
+        self.locks
+            .write(|locks| locks.retain(|lock| lock.page_id != id));
+        self.autosaves
+            .write(|autosaves| autosaves.retain(|autosave| autosave.page_id != id));
+
+        if page.published_at.is_some() {
+            self.tombstones.write(|tombstones| {
+                tombstones.retain(|t| !(t.owner_id == owner_id && t.slug == page.slug));
+                tombstones.push(Tombstone {
+                    owner_id,
+                    slug: page.slug,
+                    deleted_at: Utc::now(),
+                });
+            });
+        }
+        Ok(())
+    }
+
+    async fn find_published(
+        &self,
+        owner_id: Uuid,
+        slug: &str,
+    ) -> Result<Option<Page>, PageRepositoryError> {
+        Ok(self.pages.read(|pages| {
+            pages
+                .iter()
+                .find(|page| page.owner_id == owner_id && page.slug == slug && page.is_published())
+                .cloned()
+        }))
+    }
+
+    async fn find_tombstone(
+        &self,
+        owner_id: Uuid,
+        slug: &str,
+    ) -> Result<Option<DateTime<Utc>>, PageRepositoryError> {
+        let taken = self.pages.read(|pages| {
+            pages
+                .iter()
+                .any(|page| page.owner_id == owner_id && page.slug == slug)
+        });
+        if taken {
+            return Ok(None);
+        }
+
+        Ok(self.tombstones.read(|tombstones| {
+            tombstones
+                .iter()
+                .find(|t| t.owner_id == owner_id && t.slug == slug)
+                .map(|t| t.deleted_at)
+        }))
+    }
+
+    async fn acquire_lock(
+        &self,
+        owner_id: Uuid,
+        lock: &EditLock,
+    ) -> Result<EditLock, PageRepositoryError> {
+        self.get(owner_id, lock.page_id).await?;
+
+        self.locks.write(|locks| {
+            let Some(current) = locks.iter_mut().find(|l| l.page_id == lock.page_id) else {
+                locks.push(lock.clone());
+                return Ok(lock.clone());
+            };
+
+            if current.lock_id == lock.lock_id {
+                current.expires_at = lock.expires_at;
+                if lock.label.is_some() {
+                    current.label = lock.label.clone();
+                }
+            } else if current.is_live(lock.acquired_at) {
+                return Err(PageRepositoryError::Locked(current.clone()));
+            } else {
+                *current = lock.clone();
+            }
+            Ok(current.clone())
+        })
+    }
+
+    async fn find_lock(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+    ) -> Result<Option<EditLock>, PageRepositoryError> {
+        let owned = self.pages.read(|pages| {
+            pages
+                .iter()
+                .any(|page| page.id == page_id && page.owner_id == owner_id)
+        });
+        if !owned {
+            return Ok(None);
+        }
+
+        let now = Utc::now();
+        Ok(self.locks.read(|locks| {
+            locks
+                .iter()
+                .find(|lock| lock.page_id == page_id && lock.is_live(now))
+                .cloned()
+        }))
+    }
+
+    async fn release_lock(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+        lock_id: Uuid,
+    ) -> Result<(), PageRepositoryError> {
+        self.get(owner_id, page_id).await?;
+
+        self.locks.write(|locks| {
+            locks.retain(|lock| !(lock.page_id == page_id && lock.lock_id == lock_id))
+        });
+        Ok(())
+    }
+
+    async fn save_autosave(
+        &self,
+        owner_id: Uuid,
+        autosave: &PageAutosave,
+    ) -> Result<bool, PageRepositoryError> {
+        let Ok(page) = self.get(owner_id, autosave.page_id).await else {
+            return Ok(false);
+        };
+        if !autosave.is_newer_than(page.updated_at) {
+            return Ok(false);
+        }
+
+        Ok(self.autosaves.write(|autosaves| {
+            match autosaves.iter_mut().find(|a| a.page_id == autosave.page_id) {
+                Some(current) if current.saved_at > autosave.saved_at => return false,
+                Some(current) => *current = autosave.clone(),
+                None => autosaves.push(autosave.clone()),
+            }
+            true
+        }))
+    }
+
+    async fn find_autosave(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+    ) -> Result<Option<PageAutosave>, PageRepositoryError> {
+        let Ok(page) = self.get(owner_id, page_id).await else {
+            return Ok(None);
+        };
+
+        Ok(self.autosaves.read(|autosaves| {
+            autosaves
+                .iter()
+                .find(|autosave| autosave.page_id == page_id)
+                .filter(|autosave| autosave.is_newer_than(page.updated_at))
+                .cloned()
+        }))
+    }
+}
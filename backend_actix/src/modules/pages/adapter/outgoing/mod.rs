@@ -0,0 +1,5 @@
+mod in_memory;
+mod page_repository_postgres;
+
+pub use in_memory::InMemoryPageStore;
+pub use page_repository_postgres::PageRepositoryPostgres;
@@ -0,0 +1,846 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement,
+    TransactionTrait, Value,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::pages::application::domain::autosave::PageAutosave;
+use crate::pages::application::domain::edit_lock::EditLock;
+use crate::pages::application::domain::entities::{Page, PageStatus};
+use crate::pages::application::ports::incoming::use_cases::{CreatePageCommand, UpdatePageCommand};
+use crate::pages::application::ports::outgoing::{PageRepository, PageRepositoryError};
+use crate::project::application::ports::outgoing::project_repository::PatchField;
+use crate::shared::sql;
+
+const PAGE_COLUMNS: &str = "id, user_id, slug, title, body, status, seo_title, seo_description, \
+                            canonical_url, noindex, published_at, created_at, updated_at";
+
+const LOCK_COLUMNS: &str = "page_id, lock_id, label, acquired_at, expires_at";
+
+#[derive(Clone)]
+pub struct PageRepositoryPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl PageRepositoryPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    fn insert_stmt(
+        backend: DatabaseBackend,
+        owner_id: Uuid,
+        command: &CreatePageCommand,
+    ) -> Statement {
+        let published_at = match command.status() {
+            PageStatus::Published => sql::now(backend),
+            PageStatus::Draft => "NULL",
+        };
+
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                INSERT INTO pages (id, user_id, slug, title, body, status, seo_title,
+                                   seo_description, canonical_url, noindex, published_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, {published_at})
+                RETURNING {PAGE_COLUMNS}
+                "#
+            ),
+            vec![
+                Uuid::new_v4().into(),
+                owner_id.into(),
+                command.slug().into(),
+                command.title().into(),
+                command.body().into(),
+                command.status().as_str().into(),
+                command.seo_title().map(str::to_string).into(),
+                command.seo_description().map(str::to_string).into(),
+                command.canonical_url().map(str::to_string).into(),
+                command.noindex().into(),
+            ],
+        )
+    }
+
+    fn list_stmt(backend: DatabaseBackend, owner_id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!("SELECT {PAGE_COLUMNS} FROM pages WHERE user_id = $1 ORDER BY slug"),
+            vec![owner_id.into()],
+        )
+    }
+
+    fn get_stmt(backend: DatabaseBackend, owner_id: Uuid, id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            // Also serves preview links, which skip the username lookup
+            format!(
+                "SELECT {PAGE_COLUMNS} FROM pages WHERE id = $1 AND user_id = $2 AND {}",
+                sql::owner_is_live("pages.user_id")
+            ),
+            vec![id.into(), owner_id.into()],
+        )
+    }
+
+    /// Only the fields the command changes are set
+    fn update_stmt(
+        backend: DatabaseBackend,
+        owner_id: Uuid,
+        id: Uuid,
+        command: &UpdatePageCommand,
+    ) -> Statement {
+        let now = sql::now(backend);
+        let mut sets = Vec::new();
+        let mut values: Vec<Value> = vec![id.into(), owner_id.into()];
+        let mut set = |column: &str, value: Value| {
+            values.push(value);
+            sets.push(format!("{column} = ${}", values.len()));
+        };
+
+        if let Some(title) = command.title() {
+            set("title", title.into());
+        }
+        if let Some(body) = command.body() {
+            set("body", body.into());
+        }
+        if let Some(status) = command.status() {
+            set("status", status.as_str().into());
+        }
+        for (column, field) in [
+            ("seo_title", command.seo_title()),
+            ("seo_description", command.seo_description()),
+            ("canonical_url", command.canonical_url()),
+        ] {
+            match field {
+                PatchField::Unset => {}
+                PatchField::Null => set(column, Value::String(None)),
+                PatchField::Value(text) => set(column, text.as_str().into()),
+            }
+        }
+        if let Some(noindex) = command.noindex() {
+            set("noindex", noindex.into());
+        }
+        if command.status() == Some(PageStatus::Published) {
+            sets.push(format!("published_at = COALESCE(published_at, {now})"));
+            sets.push("autosave_body = NULL, autosaved_at = NULL".to_string());
+        }
+        sets.push(format!("updated_at = {now}"));
+
+        let mut guard = String::new();
+        if let Some(expected) = command.expected_updated_at() {
+            values.push(expected.into());
+            guard = format!(" AND updated_at <= ${}", values.len());
+        }
+
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                UPDATE pages
+                SET {sets}
+                WHERE id = $1 AND user_id = $2{guard}
+                RETURNING {PAGE_COLUMNS}
+                "#,
+                sets = sets.join(", "),
+            ),
+            values,
+        )
+    }
+
+    fn delete_stmt(backend: DatabaseBackend, owner_id: Uuid, id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            "DELETE FROM pages WHERE id = $1 AND user_id = $2 RETURNING slug, published_at",
+            vec![id.into(), owner_id.into()],
+        )
+    }
+
+    fn tombstone_stmt(
+        backend: DatabaseBackend,
+        owner_id: Uuid,
+        slug: &str,
+        deleted_at: DateTime<Utc>,
+    ) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            INSERT INTO page_tombstones (user_id, slug, deleted_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (user_id, slug) DO UPDATE SET deleted_at = EXCLUDED.deleted_at
+            "#,
+            vec![owner_id.into(), slug.into(), deleted_at.into()],
+        )
+    }
+
+    /// A page at the slug again, draft or not, hides the tombstone
+    fn find_tombstone_stmt(backend: DatabaseBackend, owner_id: Uuid, slug: &str) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT deleted_at
+            FROM page_tombstones
+            WHERE user_id = $1 AND slug = $2
+              AND NOT EXISTS (SELECT 1 FROM pages WHERE user_id = $1 AND slug = $2)
+            "#,
+            vec![owner_id.into(), slug.into()],
+        )
+    }
+
+    fn find_published_stmt(backend: DatabaseBackend, owner_id: Uuid, slug: &str) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                SELECT {PAGE_COLUMNS}
+                FROM pages
+                WHERE user_id = $1 AND slug = $2 AND status = $3
+                  AND {owner_is_live}
+                "#,
+                owner_is_live = sql::owner_is_live("pages.user_id"),
+            ),
+            vec![
+                owner_id.into(),
+                slug.into(),
+                PageStatus::Published.as_str().into(),
+            ],
+        )
+    }
+
+    /// Inserts nothing for someone else's page; takes over an existing row
+    /// only from the same lock id or once it has lapsed
+    fn acquire_lock_stmt(backend: DatabaseBackend, owner_id: Uuid, lock: &EditLock) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                INSERT INTO page_edit_locks ({LOCK_COLUMNS})
+                SELECT id, $3, $4, $5, $6 FROM pages WHERE id = $1 AND user_id = $2
+                ON CONFLICT (page_id) DO UPDATE
+                SET label = CASE WHEN page_edit_locks.lock_id = EXCLUDED.lock_id
+                                 THEN COALESCE(EXCLUDED.label, page_edit_locks.label)
+                                 ELSE EXCLUDED.label END,
+                    acquired_at = CASE WHEN page_edit_locks.lock_id = EXCLUDED.lock_id
+                                       THEN page_edit_locks.acquired_at
+                                       ELSE EXCLUDED.acquired_at END,
+                    lock_id = EXCLUDED.lock_id,
+                    expires_at = EXCLUDED.expires_at
+                WHERE page_edit_locks.lock_id = EXCLUDED.lock_id
+                   OR page_edit_locks.expires_at <= EXCLUDED.acquired_at
+                RETURNING {LOCK_COLUMNS}
+                "#
+            ),
+            vec![
+                lock.page_id.into(),
+                owner_id.into(),
+                lock.lock_id.into(),
+                lock.label.clone().into(),
+                lock.acquired_at.into(),
+                lock.expires_at.into(),
+            ],
+        )
+    }
+
+    fn find_lock_stmt(backend: DatabaseBackend, owner_id: Uuid, page_id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT l.page_id, l.lock_id, l.label, l.acquired_at, l.expires_at
+            FROM page_edit_locks l
+            JOIN pages p ON p.id = l.page_id
+            WHERE l.page_id = $1 AND p.user_id = $2 AND l.expires_at > $3
+            "#,
+            vec![page_id.into(), owner_id.into(), Utc::now().into()],
+        )
+    }
+
+    fn release_lock_stmt(
+        backend: DatabaseBackend,
+        owner_id: Uuid,
+        page_id: Uuid,
+        lock_id: Uuid,
+    ) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            DELETE FROM page_edit_locks
+            WHERE page_id = $1 AND lock_id = $2
+              AND page_id IN (SELECT id FROM pages WHERE user_id = $3)
+            "#,
+            vec![page_id.into(), lock_id.into(), owner_id.into()],
+        )
+    }
+
+    /// Writes nothing once the page was saved after the autosave was taken,
+    /// or over a newer autosave
+    fn save_autosave_stmt(
+        backend: DatabaseBackend,
+        owner_id: Uuid,
+        autosave: &PageAutosave,
+    ) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            UPDATE pages
+            SET autosave_body = $1, autosaved_at = $2
+            WHERE id = $3 AND user_id = $4 AND updated_at < $2
+              AND (autosaved_at IS NULL OR autosaved_at <= $2)
+            "#,
+            vec![
+                autosave.body.clone().into(),
+                autosave.saved_at.into(),
+                autosave.page_id.into(),
+                owner_id.into(),
+            ],
+        )
+    }
+
+    fn find_autosave_stmt(backend: DatabaseBackend, owner_id: Uuid, page_id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT id, autosave_body, autosaved_at
+            FROM pages
+            WHERE id = $1 AND user_id = $2 AND autosaved_at > updated_at
+            "#,
+            vec![page_id.into(), owner_id.into()],
+        )
+    }
+
+    // =====================================================
+    // Mapping
+    // =====================================================
+
+    fn to_page(row: &QueryResult) -> Result<Page, PageRepositoryError> {
+        let status: String = row.try_get("", "status").map_err(Self::map_db_err)?;
+
+        Ok(Page {
+            id: row.try_get("", "id").map_err(Self::map_db_err)?,
+            owner_id: row.try_get("", "user_id").map_err(Self::map_db_err)?,
+            slug: row.try_get("", "slug").map_err(Self::map_db_err)?,
+            title: row.try_get("", "title").map_err(Self::map_db_err)?,
+            body: row.try_get("", "body").map_err(Self::map_db_err)?,
+            status: status.parse().map_err(PageRepositoryError::DatabaseError)?,
+            seo_title: row.try_get("", "seo_title").map_err(Self::map_db_err)?,
+            seo_description: row
+                .try_get("", "seo_description")
+                .map_err(Self::map_db_err)?,
+            canonical_url: row.try_get("", "canonical_url").map_err(Self::map_db_err)?,
+            noindex: row.try_get("", "noindex").map_err(Self::map_db_err)?,
+            published_at: row.try_get("", "published_at").map_err(Self::map_db_err)?,
+            created_at: row.try_get("", "created_at").map_err(Self::map_db_err)?,
+            updated_at: row.try_get("", "updated_at").map_err(Self::map_db_err)?,
+        })
+    }
+
+    fn to_lock(row: &QueryResult) -> Result<EditLock, PageRepositoryError> {
+        Ok(EditLock {
+            page_id: row.try_get("", "page_id").map_err(Self::map_db_err)?,
+            lock_id: row.try_get("", "lock_id").map_err(Self::map_db_err)?,
+            label: row.try_get("", "label").map_err(Self::map_db_err)?,
+            acquired_at: row.try_get("", "acquired_at").map_err(Self::map_db_err)?,
+            expires_at: row.try_get("", "expires_at").map_err(Self::map_db_err)?,
+        })
+    }
+
+    fn to_autosave(row: &QueryResult) -> Result<PageAutosave, PageRepositoryError> {
+        Ok(PageAutosave {
+            page_id: row.try_get("", "id").map_err(Self::map_db_err)?,
+            body: row.try_get("", "autosave_body").map_err(Self::map_db_err)?,
+            saved_at: row.try_get("", "autosaved_at").map_err(Self::map_db_err)?,
+        })
+    }
+
+    fn map_insert_err(e: DbErr) -> PageRepositoryError {
+        let msg = e.to_string().to_lowercase();
+
+        if (msg.contains("duplicate") || msg.contains("unique") || msg.contains("23505"))
+            && msg.contains("slug")
+        {
+            PageRepositoryError::SlugAlreadyExists
+        } else {
+            PageRepositoryError::DatabaseError(e.to_string())
+        }
+    }
+
+    fn map_db_err(e: DbErr) -> PageRepositoryError {
+        PageRepositoryError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl PageRepository for PageRepositoryPostgres {
+    async fn create(
+        &self,
+        owner_id: Uuid,
+        command: &CreatePageCommand,
+    ) -> Result<Page, PageRepositoryError> {
+        let row = self
+            .db
+            .query_one(Self::insert_stmt(
+                self.db.get_database_backend(),
+                owner_id,
+                command,
+            ))
+            .await
+            .map_err(Self::map_insert_err)?
+            .ok_or_else(|| PageRepositoryError::DatabaseError("insert returned no row".into()))?;
+
+        Self::to_page(&row)
+    }
+
+    async fn list(&self, owner_id: Uuid) -> Result<Vec<Page>, PageRepositoryError> {
+        self.db
+            .query_all(Self::list_stmt(self.db.get_database_backend(), owner_id))
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(Self::to_page)
+            .collect()
+    }
+
+    async fn get(&self, owner_id: Uuid, id: Uuid) -> Result<Page, PageRepositoryError> {
+        let row = self
+            .db
+            .query_one(Self::get_stmt(self.db.get_database_backend(), owner_id, id))
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or(PageRepositoryError::NotFound)?;
+
+        Self::to_page(&row)
+    }
+
+    async fn update(
+        &self,
+        owner_id: Uuid,
+        id: Uuid,
+        command: &UpdatePageCommand,
+    ) -> Result<Page, PageRepositoryError> {
+        let row = self
+            .db
+            .query_one(Self::update_stmt(
+                self.db.get_database_backend(),
+                owner_id,
+                id,
+                command,
+            ))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        match row {
+            Some(row) => Self::to_page(&row),
+            // Nothing matched: either no such page, or it moved past `expected`
+            None if command.expected_updated_at().is_some() => {
+                self.get(owner_id, id).await?;
+                Err(PageRepositoryError::Stale)
+            }
+            None => Err(PageRepositoryError::NotFound),
+        }
+    }
+
+    async fn delete(&self, owner_id: Uuid, id: Uuid) -> Result<(), PageRepositoryError> {
+        let backend = self.db.get_database_backend();
+        let txn = self.db.begin().await.map_err(Self::map_db_err)?;
+
+        let row = txn
+            .query_one(Self::delete_stmt(backend, owner_id, id))
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or(PageRepositoryError::NotFound)?;
+
+        let published_at: Option<DateTime<Utc>> =
+            row.try_get("", "published_at").map_err(Self::map_db_err)?;
+        if published_at.is_some() {
+            let slug: String = row.try_get("", "slug").map_err(Self::map_db_err)?;
+            txn.execute(Self::tombstone_stmt(backend, owner_id, &slug, Utc::now()))
+                .await
+                .map_err(Self::map_db_err)?;
+        }
+
+        txn.commit().await.map_err(Self::map_db_err)
+    }
+
+    async fn find_published(
+        &self,
+        owner_id: Uuid,
+        slug: &str,
+    ) -> Result<Option<Page>, PageRepositoryError> {
+        self.db
+            .query_one(Self::find_published_stmt(
+                self.db.get_database_backend(),
+                owner_id,
+                slug,
+            ))
+            .await
+            .map_err(Self::map_db_err)?
+            .as_ref()
+            .map(Self::to_page)
+            .transpose()
+    }
+
+    async fn find_tombstone(
+        &self,
+        owner_id: Uuid,
+        slug: &str,
+    ) -> Result<Option<DateTime<Utc>>, PageRepositoryError> {
+        self.db
+            .query_one(Self::find_tombstone_stmt(
+                self.db.get_database_backend(),
+                owner_id,
+                slug,
+            ))
+            .await
+            .map_err(Self::map_db_err)?
+            .map(|row| row.try_get("", "deleted_at").map_err(Self::map_db_err))
+            .transpose()
+    }
+
+    async fn acquire_lock(
+        &self,
+        owner_id: Uuid,
+        lock: &EditLock,
+    ) -> Result<EditLock, PageRepositoryError> {
+        let row = self
+            .db
+            .query_one(Self::acquire_lock_stmt(
+                self.db.get_database_backend(),
+                owner_id,
+                lock,
+            ))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        if let Some(row) = row {
+            return Self::to_lock(&row);
+        }
+
+        // Nothing written: either no such page, or another id holds it
+        self.get(owner_id, lock.page_id).await?;
+        match self.find_lock(owner_id, lock.page_id).await? {
+            Some(current) => Err(PageRepositoryError::Locked(current)),
+            None => Err(PageRepositoryError::DatabaseError(
+                "edit lock neither taken nor held".to_string(),
+            )),
+        }
+    }
+
+    async fn find_lock(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+    ) -> Result<Option<EditLock>, PageRepositoryError> {
+        self.db
+            .query_one(Self::find_lock_stmt(
+                self.db.get_database_backend(),
+                owner_id,
+                page_id,
+            ))
+            .await
+            .map_err(Self::map_db_err)?
+            .as_ref()
+            .map(Self::to_lock)
+            .transpose()
+    }
+
+    async fn release_lock(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+        lock_id: Uuid,
+    ) -> Result<(), PageRepositoryError> {
+        let res = self
+            .db
+            .execute(Self::release_lock_stmt(
+                self.db.get_database_backend(),
+                owner_id,
+                page_id,
+                lock_id,
+            ))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        if res.rows_affected() == 0 {
+            self.get(owner_id, page_id).await?;
+        }
+        Ok(())
+    }
+
+    async fn save_autosave(
+        &self,
+        owner_id: Uuid,
+        autosave: &PageAutosave,
+    ) -> Result<bool, PageRepositoryError> {
+        let res = self
+            .db
+            .execute(Self::save_autosave_stmt(
+                self.db.get_database_backend(),
+                owner_id,
+                autosave,
+            ))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn find_autosave(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+    ) -> Result<Option<PageAutosave>, PageRepositoryError> {
+        self.db
+            .query_one(Self::find_autosave_stmt(
+                self.db.get_database_backend(),
+                owner_id,
+                page_id,
+            ))
+            .await
+            .map_err(Self::map_db_err)?
+            .as_ref()
+            .map(Self::to_autosave)
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{MockDatabase, MockExecResult};
+    use std::collections::BTreeMap;
+
+    fn page_row(id: Uuid, status: &str) -> BTreeMap<String, Value> {
+        BTreeMap::from([
+            ("id".to_string(), Value::from(id)),
+            ("user_id".to_string(), Value::from(Uuid::new_v4())),
+            ("slug".to_string(), Value::from("about")),
+            ("title".to_string(), Value::from("About")),
+            ("body".to_string(), Value::from("# Hello")),
+            ("status".to_string(), Value::from(status)),
+            ("seo_title".to_string(), Value::String(None)),
+            ("seo_description".to_string(), Value::String(None)),
+            ("canonical_url".to_string(), Value::String(None)),
+            ("noindex".to_string(), Value::from(false)),
+            ("published_at".to_string(), Value::ChronoDateTimeUtc(None)),
+            ("created_at".to_string(), Value::from(Utc::now())),
+            ("updated_at".to_string(), Value::from(Utc::now())),
+        ])
+    }
+
+    fn update_command(
+        status: PatchField<PageStatus>,
+        seo_title: PatchField<String>,
+    ) -> UpdatePageCommand {
+        UpdatePageCommand::new(
+            PatchField::Unset,
+            PatchField::Unset,
+            status,
+            seo_title,
+            PatchField::Unset,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_maps_duplicate_slug() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_errors([DbErr::Custom(
+                "duplicate key value violates unique constraint \"idx_pages_user_id_slug\""
+                    .to_string(),
+            )])
+            .into_connection();
+        let command = CreatePageCommand::new(
+            "about".to_string(),
+            "About".to_string(),
+            String::new(),
+            PageStatus::Draft,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let repo = PageRepositoryPostgres::new(Arc::new(db));
+        let result = repo.create(Uuid::new_v4(), &command).await;
+
+        assert!(matches!(
+            result,
+            Err(PageRepositoryError::SlugAlreadyExists)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_find_published_reads_row() {
+        let id = Uuid::new_v4();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![page_row(id, "published")]])
+            .into_connection();
+
+        let repo = PageRepositoryPostgres::new(Arc::new(db));
+        let page = repo
+            .find_published(Uuid::new_v4(), "about")
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(page.id, id);
+        assert_eq!(page.status, PageStatus::Published);
+    }
+
+    #[tokio::test]
+    async fn test_delete_missing_page_is_not_found() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([Vec::<BTreeMap<String, Value>>::new()])
+            .into_connection();
+
+        let repo = PageRepositoryPostgres::new(Arc::new(db));
+        let result = repo.delete(Uuid::new_v4(), Uuid::new_v4()).await;
+
+        assert!(matches!(result, Err(PageRepositoryError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_deleting_a_published_page_leaves_a_tombstone() {
+        let db = Arc::new(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_query_results([vec![BTreeMap::from([
+                    ("slug".to_string(), Value::from("about")),
+                    ("published_at".to_string(), Value::from(Utc::now())),
+                ])]])
+                .append_exec_results([MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                }])
+                .into_connection(),
+        );
+
+        let repo = PageRepositoryPostgres::new(Arc::clone(&db));
+        repo.delete(Uuid::new_v4(), Uuid::new_v4()).await.unwrap();
+        drop(repo);
+
+        let log = Arc::try_unwrap(db)
+            .expect("no other connection handles")
+            .into_transaction_log();
+        assert!(log
+            .iter()
+            .flat_map(|txn| txn.statements())
+            .any(|stmt| stmt.sql.contains("INSERT INTO page_tombstones")));
+    }
+
+    #[test]
+    fn test_update_sets_only_changed_columns() {
+        let stmt = PageRepositoryPostgres::update_stmt(
+            DatabaseBackend::Postgres,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            &update_command(PatchField::Value(PageStatus::Published), PatchField::Null),
+        );
+
+        assert!(stmt.sql.contains("status = $3"));
+        assert!(stmt.sql.contains("seo_title = $4"));
+        assert!(stmt.sql.contains("published_at = COALESCE(published_at, "));
+        assert!(stmt.sql.contains("autosave_body = NULL"));
+        assert!(!stmt.sql.contains(" title = "));
+        assert!(!stmt.sql.contains(" body ="));
+        assert_eq!(stmt.values.unwrap().0.len(), 4);
+    }
+
+    #[test]
+    fn test_unpublishing_keeps_published_at() {
+        let stmt = PageRepositoryPostgres::update_stmt(
+            DatabaseBackend::Postgres,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            &update_command(PatchField::Value(PageStatus::Draft), PatchField::Unset),
+        );
+
+        assert!(!stmt.sql.contains("published_at ="));
+        assert!(!stmt.sql.contains("autosave_body"));
+    }
+
+    #[test]
+    fn test_save_autosave_skips_pages_saved_since() {
+        let autosave = PageAutosave {
+            page_id: Uuid::new_v4(),
+            body: "# Draft".to_string(),
+            saved_at: Utc::now(),
+        };
+
+        let stmt = PageRepositoryPostgres::save_autosave_stmt(
+            DatabaseBackend::Postgres,
+            Uuid::new_v4(),
+            &autosave,
+        );
+
+        assert!(stmt.sql.contains("updated_at < $2"));
+        assert!(stmt.sql.contains("autosaved_at <= $2"));
+        assert!(!stmt.sql.contains(" body ="));
+    }
+
+    #[test]
+    fn test_update_guards_on_expected_updated_at() {
+        let command = update_command(PatchField::Value(PageStatus::Draft), PatchField::Unset)
+            .with_expected_updated_at(Some(Utc::now()));
+
+        let stmt = PageRepositoryPostgres::update_stmt(
+            DatabaseBackend::Postgres,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            &command,
+        );
+
+        assert!(stmt.sql.contains("AND updated_at <= $4"));
+        assert_eq!(stmt.values.unwrap().0.len(), 4);
+    }
+
+    #[test]
+    fn test_update_sets_indexing_columns() {
+        let command = update_command(PatchField::Unset, PatchField::Unset)
+            .with_indexing(PatchField::Null, Some(true))
+            .unwrap();
+
+        let stmt = PageRepositoryPostgres::update_stmt(
+            DatabaseBackend::Postgres,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            &command,
+        );
+
+        assert!(stmt.sql.contains("canonical_url = $3"));
+        assert!(stmt.sql.contains("noindex = $4"));
+    }
+
+    #[tokio::test]
+    async fn test_acquire_lock_held_elsewhere_reports_holder() {
+        let page_id = Uuid::new_v4();
+        let holder = EditLock::lease(page_id, Uuid::new_v4(), Some("Firefox".into()), Utc::now());
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![Vec::<BTreeMap<String, Value>>::new()])
+            .append_query_results(vec![vec![page_row(page_id, "draft")]])
+            .append_query_results(vec![vec![BTreeMap::from([
+                ("page_id".to_string(), Value::from(page_id)),
+                ("lock_id".to_string(), Value::from(holder.lock_id)),
+                ("label".to_string(), Value::from("Firefox")),
+                ("acquired_at".to_string(), Value::from(holder.acquired_at)),
+                ("expires_at".to_string(), Value::from(holder.expires_at)),
+            ])]])
+            .into_connection();
+
+        let repo = PageRepositoryPostgres::new(Arc::new(db));
+        let result = repo
+            .acquire_lock(
+                Uuid::new_v4(),
+                &EditLock::lease(page_id, Uuid::new_v4(), None, Utc::now()),
+            )
+            .await;
+
+        match result {
+            Err(PageRepositoryError::Locked(current)) => assert_eq!(current, holder),
+            other => panic!("expected Locked, got {other:?}"),
+        }
+    }
+}
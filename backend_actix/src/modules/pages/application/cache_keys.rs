@@ -0,0 +1,13 @@
+//! Cache keys for public page reads (see `shared::cache`).
+//!
+//! Like projects, everything cached for an owner lives under `owner_prefix`.
+
+use uuid::Uuid;
+
+pub fn owner_prefix(owner_id: Uuid) -> String {
+    format!("cache:pages:{}:", owner_id)
+}
+
+pub fn public_by_slug(owner_id: Uuid, slug: &str) -> String {
+    format!("{}slug:{}", owner_prefix(owner_id), slug)
+}
@@ -0,0 +1,25 @@
+//! Autosaves of the editor's unsaved body. The editor sends the body it
+//! holds every few seconds; the latest one per page is kept apart from the
+//! page until the owner saves, so it can be restored after a crash. Only an
+//! autosave taken after the page's last save is offered back, and
+//! publishing drops it.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct PageAutosave {
+    pub page_id: Uuid,
+    /// Markdown, as the editor last held it
+    pub body: String,
+    pub saved_at: DateTime<Utc>,
+}
+
+impl PageAutosave {
+    /// Whether it holds work newer than the page's last save
+    pub fn is_newer_than(&self, updated_at: DateTime<Utc>) -> bool {
+        self.saved_at > updated_at
+    }
+}
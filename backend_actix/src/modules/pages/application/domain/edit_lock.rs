@@ -0,0 +1,84 @@
+//! Advisory edit locks. An editing session takes a page's lock and renews
+//! it while the editor is open; edits from any other session are refused
+//! until it is released or lapses. Locks only guard the page's edits:
+//! reading, previewing and deleting ignore them.
+
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+/// How long a lock lasts past its last renewal
+pub const EDIT_LOCK_TTL_SECS: i64 = 120;
+pub const MAX_LOCK_LABEL_LENGTH: usize = 100;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditLock {
+    pub page_id: Uuid,
+    /// Known only to the holding session, which sends it with its edits
+    pub lock_id: Uuid,
+    /// What the holding session calls itself, e.g. "Firefox on laptop"
+    pub label: Option<String>,
+    /// When the holder took the lock; renewals keep it
+    pub acquired_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl EditLock {
+    /// A lock for `lock_id` taken or renewed at `now`
+    pub fn lease(page_id: Uuid, lock_id: Uuid, label: Option<String>, now: DateTime<Utc>) -> Self {
+        Self {
+            page_id,
+            lock_id,
+            label,
+            acquired_at: now,
+            expires_at: now + Duration::seconds(EDIT_LOCK_TTL_SECS),
+        }
+    }
+
+    pub fn is_live(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at > now
+    }
+
+    /// Whether a session sending `lock_id` (or none) may edit the page
+    pub fn admits(&self, lock_id: Option<Uuid>) -> bool {
+        lock_id == Some(self.lock_id)
+    }
+}
+
+/// Trimmed; blank counts as absent
+pub fn label(label: Option<String>) -> Result<Option<String>, String> {
+    let Some(label) = label.map(|label| label.trim().to_string()) else {
+        return Ok(None);
+    };
+    if label.chars().count() > MAX_LOCK_LABEL_LENGTH {
+        return Err(format!(
+            "Lock label must be at most {MAX_LOCK_LABEL_LENGTH} characters"
+        ));
+    }
+    Ok(Some(label).filter(|label| !label.is_empty()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lease_lapses_after_ttl() {
+        let now = Utc::now();
+        let lock = EditLock::lease(Uuid::new_v4(), Uuid::new_v4(), None, now);
+
+        assert!(lock.is_live(now + Duration::seconds(EDIT_LOCK_TTL_SECS - 1)));
+        assert!(!lock.is_live(now + Duration::seconds(EDIT_LOCK_TTL_SECS)));
+        assert!(lock.admits(Some(lock.lock_id)));
+        assert!(!lock.admits(None));
+    }
+
+    #[test]
+    fn test_label_is_trimmed_and_bounded() {
+        assert_eq!(
+            label(Some(" Firefox ".to_string())).unwrap(),
+            Some("Firefox".to_string())
+        );
+        assert_eq!(label(Some("  ".to_string())).unwrap(), None);
+        assert!(label(Some("l".repeat(MAX_LOCK_LABEL_LENGTH + 1))).is_err());
+    }
+}
@@ -0,0 +1,73 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum PageStatus {
+    /// Only visible to the owner
+    #[default]
+    Draft,
+    Published,
+}
+
+impl PageStatus {
+    /// Value of the `status` column
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PageStatus::Draft => "draft",
+            PageStatus::Published => "published",
+        }
+    }
+}
+
+impl fmt::Display for PageStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PageStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "draft" => Ok(PageStatus::Draft),
+            "published" => Ok(PageStatus::Published),
+            other => Err(format!("unknown page status '{other}'")),
+        }
+    }
+}
+
+/// A standalone page such as About, Now or Uses
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Page {
+    pub id: Uuid,
+    pub owner_id: Uuid,
+    pub slug: String,
+    pub title: String,
+    /// Markdown
+    pub body: String,
+    pub status: PageStatus,
+    /// Overrides `title` in the document `<title>`
+    pub seo_title: Option<String>,
+    /// Meta description
+    pub seo_description: Option<String>,
+    /// Where the original lives, when this page is a copy
+    pub canonical_url: Option<String>,
+    /// Asks search engines not to index the page
+    pub noindex: bool,
+    /// First publication; absent if never published
+    pub published_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Page {
+    pub fn is_published(&self) -> bool {
+        self.status == PageStatus::Published
+    }
+}
@@ -0,0 +1,4 @@
+pub mod autosave;
+pub mod edit_lock;
+pub mod entities;
+pub mod preview_token;
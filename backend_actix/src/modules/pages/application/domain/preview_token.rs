@@ -0,0 +1,172 @@
+//! Draft preview links.
+//!
+//! A token is `"{page_id}.{owner_id}.{expires}.{hex}"`, where `expires` is a
+//! Unix timestamp and `hex` the HMAC-SHA256 of
+//! `"preview:{page_id}:{owner_id}:{expires}"` keyed with `PREVIEW_SECRET`.
+//! Nothing is stored: anyone holding a link can read the page until it
+//! expires, and changing the secret revokes every link at once.
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::fmt;
+use uuid::Uuid;
+
+/// What a valid token grants: reading one page until `expires_at`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PreviewGrant {
+    pub page_id: Uuid,
+    pub owner_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum PreviewTokenError {
+    #[error("Preview link is invalid")]
+    Invalid,
+
+    #[error("Preview link has expired")]
+    Expired,
+}
+
+#[derive(Clone)]
+pub struct PreviewTokens {
+    secret: Vec<u8>,
+}
+
+impl PreviewTokens {
+    pub fn new(secret: &str) -> Self {
+        Self {
+            secret: secret.as_bytes().to_vec(),
+        }
+    }
+
+    fn mac(&self, page_id: Uuid, owner_id: Uuid, expires: i64) -> Hmac<Sha256> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC accepts any key length");
+        mac.update(format!("preview:{page_id}:{owner_id}:{expires}").as_bytes());
+        mac
+    }
+
+    /// `expires_at` is kept to the second
+    pub fn sign(&self, grant: &PreviewGrant) -> String {
+        let expires = grant.expires_at.timestamp();
+        let signature = self
+            .mac(grant.page_id, grant.owner_id, expires)
+            .finalize()
+            .into_bytes();
+        format!(
+            "{}.{}.{expires}.{}",
+            grant.page_id,
+            grant.owner_id,
+            hex::encode(signature)
+        )
+    }
+
+    /// The grant `token` was signed for, if the signature holds and it is
+    /// still valid at `now`
+    pub fn verify(
+        &self,
+        token: &str,
+        now: DateTime<Utc>,
+    ) -> Result<PreviewGrant, PreviewTokenError> {
+        let mut parts = token.split('.');
+        let (Some(page_id), Some(owner_id), Some(expires), Some(signature), None) = (
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+        ) else {
+            return Err(PreviewTokenError::Invalid);
+        };
+        let page_id = Uuid::parse_str(page_id).map_err(|_| PreviewTokenError::Invalid)?;
+        let owner_id = Uuid::parse_str(owner_id).map_err(|_| PreviewTokenError::Invalid)?;
+        let expires: i64 = expires.parse().map_err(|_| PreviewTokenError::Invalid)?;
+        let signature = hex::decode(signature).map_err(|_| PreviewTokenError::Invalid)?;
+
+        // Constant-time comparison
+        self.mac(page_id, owner_id, expires)
+            .verify_slice(&signature)
+            .map_err(|_| PreviewTokenError::Invalid)?;
+
+        let expires_at = DateTime::from_timestamp(expires, 0).ok_or(PreviewTokenError::Invalid)?;
+        if expires_at <= now {
+            return Err(PreviewTokenError::Expired);
+        }
+
+        Ok(PreviewGrant {
+            page_id,
+            owner_id,
+            expires_at,
+        })
+    }
+}
+
+/// Keeps the secret out of logs
+impl fmt::Debug for PreviewTokens {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PreviewTokens").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    const SECRET: &str = "0123456789abcdef0123456789abcdef";
+
+    fn grant(expires_at: DateTime<Utc>) -> PreviewGrant {
+        PreviewGrant {
+            page_id: Uuid::new_v4(),
+            owner_id: Uuid::new_v4(),
+            expires_at: DateTime::from_timestamp(expires_at.timestamp(), 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_round_trip_until_expiry() {
+        let tokens = PreviewTokens::new(SECRET);
+        let now = Utc::now();
+        let grant = grant(now + Duration::hours(1));
+        let token = tokens.sign(&grant);
+
+        assert_eq!(tokens.verify(&token, now), Ok(grant));
+        assert_eq!(
+            tokens.verify(&token, now + Duration::hours(2)),
+            Err(PreviewTokenError::Expired)
+        );
+    }
+
+    #[test]
+    fn test_tampered_tokens_are_invalid() {
+        let tokens = PreviewTokens::new(SECRET);
+        let now = Utc::now();
+        let grant = grant(now + Duration::hours(1));
+        let token = tokens.sign(&grant);
+        let parts: Vec<&str> = token.split('.').collect();
+
+        // A later expiry or another page keeps the old signature
+        let extended = format!(
+            "{}.{}.{}.{}",
+            parts[0],
+            parts[1],
+            grant.expires_at.timestamp() + 3600,
+            parts[3]
+        );
+        let other_page = format!("{}.{}.{}.{}", Uuid::new_v4(), parts[1], parts[2], parts[3]);
+        for token in [extended, other_page, "not-a-token".to_string()] {
+            assert_eq!(tokens.verify(&token, now), Err(PreviewTokenError::Invalid));
+        }
+        assert_eq!(
+            PreviewTokens::new("another-secret-another-secret-12")
+                .verify(&tokens.sign(&grant), now),
+            Err(PreviewTokenError::Invalid)
+        );
+        assert_eq!(
+            tokens.verify(&format!("{token}.extra"), now),
+            Err(PreviewTokenError::Invalid)
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub mod cache_keys;
+pub mod domain;
+pub mod page_use_cases;
+pub mod ports;
+pub mod services;
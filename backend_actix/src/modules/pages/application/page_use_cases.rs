@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use crate::pages::application::domain::preview_token::PreviewTokens;
+use crate::pages::application::ports::incoming::use_cases::{
+    AcquirePageLockUseCase, AutosavePageUseCase, CreatePageUseCase, CreatePreviewTokenUseCase,
+    DeletePageUseCase, GetPageAutosaveUseCase, GetPagePreviewUseCase, GetPageUseCase,
+    GetPublicPageUseCase, ListPagesUseCase, ReleasePageLockUseCase, UpdatePageUseCase,
+};
+use crate::pages::application::ports::outgoing::PageRepository;
+use crate::pages::application::services::{
+    AcquirePageLockService, AutosaveBuffer, AutosavePageService, CreatePageService,
+    CreatePreviewTokenService, DeletePageService, GetPageAutosaveService, GetPagePreviewService,
+    GetPageService, GetPublicPageService, ListPagesService, ReleasePageLockService,
+    UpdatePageService,
+};
+use crate::shared::cache::CachePort;
+use crate::shared::metrics::Metered;
+
+#[derive(Clone)]
+pub struct PageUseCases {
+    pub create: Arc<dyn CreatePageUseCase + Send + Sync>,
+    pub list: Arc<dyn ListPagesUseCase + Send + Sync>,
+    pub get: Arc<dyn GetPageUseCase + Send + Sync>,
+    pub update: Arc<dyn UpdatePageUseCase + Send + Sync>,
+    pub delete: Arc<dyn DeletePageUseCase + Send + Sync>,
+    pub get_public: Arc<dyn GetPublicPageUseCase + Send + Sync>,
+    pub create_preview_token: Arc<dyn CreatePreviewTokenUseCase + Send + Sync>,
+    pub get_preview: Arc<dyn GetPagePreviewUseCase + Send + Sync>,
+    pub acquire_lock: Arc<dyn AcquirePageLockUseCase + Send + Sync>,
+    pub release_lock: Arc<dyn ReleasePageLockUseCase + Send + Sync>,
+    pub autosave: Arc<dyn AutosavePageUseCase + Send + Sync>,
+    pub get_autosave: Arc<dyn GetPageAutosaveUseCase + Send + Sync>,
+}
+
+impl PageUseCases {
+    /// Autosaves go to `autosaves`; the `AutosaveFlusher` draining it
+    /// writes them to `repository`
+    pub fn build<R>(
+        repository: R,
+        preview_tokens: PreviewTokens,
+        cache: Arc<dyn CachePort>,
+        autosaves: AutosaveBuffer,
+    ) -> Self
+    where
+        R: PageRepository + Clone + 'static,
+    {
+        Self {
+            create: Arc::new(Metered(CreatePageService::new(
+                repository.clone(),
+                Arc::clone(&cache),
+            ))),
+            list: Arc::new(Metered(ListPagesService::new(repository.clone()))),
+            get: Arc::new(Metered(GetPageService::new(repository.clone()))),
+            update: Arc::new(Metered(UpdatePageService::new(
+                repository.clone(),
+                Arc::clone(&cache),
+            ))),
+            delete: Arc::new(Metered(DeletePageService::new(
+                repository.clone(),
+                Arc::clone(&cache),
+            ))),
+            create_preview_token: Arc::new(Metered(CreatePreviewTokenService::new(
+                repository.clone(),
+                preview_tokens.clone(),
+            ))),
+            get_preview: Arc::new(Metered(GetPagePreviewService::new(
+                repository.clone(),
+                preview_tokens,
+            ))),
+            acquire_lock: Arc::new(Metered(AcquirePageLockService::new(repository.clone()))),
+            release_lock: Arc::new(Metered(ReleasePageLockService::new(repository.clone()))),
+            autosave: Arc::new(Metered(AutosavePageService::new(
+                repository.clone(),
+                autosaves.clone(),
+            ))),
+            get_autosave: Arc::new(Metered(GetPageAutosaveService::new(
+                repository.clone(),
+                autosaves,
+            ))),
+            get_public: Arc::new(Metered(GetPublicPageService::new(repository, cache))),
+        }
+    }
+}
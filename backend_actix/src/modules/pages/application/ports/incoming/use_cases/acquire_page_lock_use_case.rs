@@ -0,0 +1,48 @@
+use crate::pages::application::domain::edit_lock::EditLock;
+use crate::shared::metrics::metered_use_case;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum AcquirePageLockError {
+    #[error("{0}")]
+    InvalidLabel(String),
+
+    #[error("Page not found")]
+    NotFound,
+
+    /// Another session holds a live lock
+    #[error("Page is being edited in another session")]
+    Locked(EditLock),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait AcquirePageLockUseCase: Send + Sync {
+    /// Take the page's edit lock for a new session, or renew it for the
+    /// session holding `lock_id`. A renewal whose lock lapsed takes it
+    /// again unless another session got it first. `label` is kept when
+    /// omitted from a renewal.
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+        lock_id: Option<Uuid>,
+        label: Option<String>,
+    ) -> Result<EditLock, AcquirePageLockError>;
+}
+
+metered_use_case!(
+    "pages",
+    "acquire_page_lock",
+    AcquirePageLockUseCase,
+    fn execute(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+        lock_id: Option<Uuid>,
+        label: Option<String>,
+    ) -> Result<EditLock, AcquirePageLockError>
+);
@@ -0,0 +1,42 @@
+use crate::pages::application::domain::autosave::PageAutosave;
+use crate::shared::metrics::metered_use_case;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::MAX_BODY_LENGTH;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum AutosavePageError {
+    #[error("Body must be at most {MAX_BODY_LENGTH} characters")]
+    BodyTooLong,
+
+    #[error("Page not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait AutosavePageUseCase: Send + Sync {
+    /// Keep `body` as the page's autosave in place of the previous one. It
+    /// reaches the database within seconds; the page itself is untouched.
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+        body: String,
+    ) -> Result<PageAutosave, AutosavePageError>;
+}
+
+metered_use_case!(
+    "pages",
+    "autosave_page",
+    AutosavePageUseCase,
+    fn execute(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+        body: String,
+    ) -> Result<PageAutosave, AutosavePageError>
+);
@@ -0,0 +1,194 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::page_command::{self, PageCommandError};
+use super::{MAX_SEO_DESCRIPTION_LENGTH, MAX_SEO_TITLE_LENGTH};
+use crate::pages::application::domain::entities::{Page, PageStatus};
+use crate::shared::metrics::metered_use_case;
+
+//
+// ──────────────────────────────────────────────────────────
+// Create Page Command
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone)]
+pub struct CreatePageCommand {
+    slug: String,
+    title: String,
+    body: String,
+    status: PageStatus,
+    seo_title: Option<String>,
+    seo_description: Option<String>,
+    canonical_url: Option<String>,
+    noindex: bool,
+}
+
+impl CreatePageCommand {
+    pub fn new(
+        slug: String,
+        title: String,
+        body: String,
+        status: PageStatus,
+        seo_title: Option<String>,
+        seo_description: Option<String>,
+    ) -> Result<Self, PageCommandError> {
+        Ok(Self {
+            slug: page_command::slug(slug)?,
+            title: page_command::title(title)?,
+            body: page_command::body(body)?,
+            status,
+            seo_title: page_command::optional_text(
+                seo_title,
+                MAX_SEO_TITLE_LENGTH,
+                PageCommandError::SeoTitleTooLong,
+            )?,
+            seo_description: page_command::optional_text(
+                seo_description,
+                MAX_SEO_DESCRIPTION_LENGTH,
+                PageCommandError::SeoDescriptionTooLong,
+            )?,
+            canonical_url: None,
+            noindex: false,
+        })
+    }
+
+    /// How search engines should treat the page; by default it is indexed
+    /// under its own URL
+    pub fn with_indexing(
+        mut self,
+        canonical_url: Option<String>,
+        noindex: bool,
+    ) -> Result<Self, PageCommandError> {
+        self.canonical_url = match canonical_url {
+            Some(url) => page_command::canonical_url(url)?,
+            None => None,
+        };
+        self.noindex = noindex;
+        Ok(self)
+    }
+
+    pub fn slug(&self) -> &str {
+        &self.slug
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    pub fn status(&self) -> PageStatus {
+        self.status
+    }
+
+    pub fn seo_title(&self) -> Option<&str> {
+        self.seo_title.as_deref()
+    }
+
+    pub fn seo_description(&self) -> Option<&str> {
+        self.seo_description.as_deref()
+    }
+
+    pub fn canonical_url(&self) -> Option<&str> {
+        self.canonical_url.as_deref()
+    }
+
+    pub fn noindex(&self) -> bool {
+        self.noindex
+    }
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Use Case Error
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum CreatePageError {
+    #[error("Slug already exists")]
+    SlugAlreadyExists,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Incoming Port (Use Case)
+// ──────────────────────────────────────────────────────────
+//
+
+#[async_trait]
+pub trait CreatePageUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        command: CreatePageCommand,
+    ) -> Result<Page, CreatePageError>;
+}
+
+metered_use_case!(
+    "pages",
+    "create_page",
+    CreatePageUseCase,
+    fn execute(&self, owner_id: Uuid, command: CreatePageCommand) -> Result<Page, CreatePageError>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_trims_and_drops_blank_seo_fields() {
+        let command = CreatePageCommand::new(
+            " about ".to_string(),
+            " About me ".to_string(),
+            "# Hi\n".to_string(),
+            PageStatus::Draft,
+            Some("  ".to_string()),
+            Some(" Who I am ".to_string()),
+        )
+        .unwrap();
+
+        assert_eq!(command.slug(), "about");
+        assert_eq!(command.title(), "About me");
+        assert_eq!(command.body(), "# Hi\n");
+        assert_eq!(command.seo_title(), None);
+        assert_eq!(command.seo_description(), Some("Who I am"));
+        assert_eq!(command.canonical_url(), None);
+        assert!(!command.noindex());
+    }
+
+    #[test]
+    fn test_command_rejects_invalid_fields() {
+        let command = |title: &str, seo_title: &str| {
+            CreatePageCommand::new(
+                "about".to_string(),
+                title.to_string(),
+                String::new(),
+                PageStatus::Draft,
+                Some(seo_title.to_string()),
+                None,
+            )
+        };
+
+        assert!(matches!(
+            command(" ", ""),
+            Err(PageCommandError::InvalidTitle)
+        ));
+        assert!(matches!(
+            command("About", &"s".repeat(MAX_SEO_TITLE_LENGTH + 1)),
+            Err(PageCommandError::SeoTitleTooLong)
+        ));
+        assert!(matches!(
+            command("About", "").and_then(|command| {
+                command.with_indexing(Some("example.com/about".to_string()), false)
+            }),
+            Err(PageCommandError::InvalidCanonicalUrl)
+        ));
+    }
+}
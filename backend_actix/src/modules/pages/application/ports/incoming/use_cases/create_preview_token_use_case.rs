@@ -0,0 +1,53 @@
+use crate::shared::metrics::metered_use_case;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Lifetime of a preview link when none is asked for: a week
+pub const DEFAULT_PREVIEW_HOURS: u32 = 7 * 24;
+pub const MAX_PREVIEW_HOURS: u32 = 30 * 24;
+
+/// A signed link to one page, draft or not
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct PreviewToken {
+    /// Goes into `GET /api/public/preview/{token}`
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum CreatePreviewTokenError {
+    #[error("Expiry must be between 1 and {MAX_PREVIEW_HOURS} hours")]
+    InvalidExpiry,
+
+    #[error("Page not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait CreatePreviewTokenUseCase: Send + Sync {
+    /// `expires_in_hours` defaults to `DEFAULT_PREVIEW_HOURS`
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+        expires_in_hours: Option<u32>,
+    ) -> Result<PreviewToken, CreatePreviewTokenError>;
+}
+
+metered_use_case!(
+    "pages",
+    "create_preview_token",
+    CreatePreviewTokenUseCase,
+    fn execute(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+        expires_in_hours: Option<u32>,
+    ) -> Result<PreviewToken, CreatePreviewTokenError>
+);
@@ -0,0 +1,24 @@
+use crate::shared::metrics::metered_use_case;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DeletePageError {
+    #[error("Page not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait DeletePageUseCase: Send + Sync {
+    async fn execute(&self, owner_id: Uuid, id: Uuid) -> Result<(), DeletePageError>;
+}
+
+metered_use_case!(
+    "pages",
+    "delete_page",
+    DeletePageUseCase,
+    fn execute(&self, owner_id: Uuid, id: Uuid) -> Result<(), DeletePageError>
+);
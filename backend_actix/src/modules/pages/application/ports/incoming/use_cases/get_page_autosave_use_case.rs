@@ -0,0 +1,23 @@
+use crate::pages::application::domain::{autosave::PageAutosave, entities::Page};
+use crate::shared::metrics::metered_use_case;
+use async_trait::async_trait;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum GetPageAutosaveError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait GetPageAutosaveUseCase: Send + Sync {
+    /// The autosave to offer when `page` opens in the editor, pending or
+    /// stored; `None` unless it is newer than the page's last save
+    async fn execute(&self, page: &Page) -> Result<Option<PageAutosave>, GetPageAutosaveError>;
+}
+
+metered_use_case!(
+    "pages",
+    "get_page_autosave",
+    GetPageAutosaveUseCase,
+    fn execute(&self, page: &Page) -> Result<Option<PageAutosave>, GetPageAutosaveError>
+);
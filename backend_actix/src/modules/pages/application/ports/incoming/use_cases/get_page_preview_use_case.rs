@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+
+use crate::pages::application::domain::entities::Page;
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum GetPagePreviewError {
+    /// Malformed or forged token
+    #[error("Preview link is invalid")]
+    InvalidToken,
+
+    #[error("Preview link has expired")]
+    Expired,
+
+    /// The page was deleted after the link was made
+    #[error("Page not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait GetPagePreviewUseCase: Send + Sync {
+    async fn execute(&self, token: &str) -> Result<Page, GetPagePreviewError>;
+}
+
+metered_use_case!(
+    "pages",
+    "get_page_preview",
+    GetPagePreviewUseCase,
+    fn execute(&self, token: &str) -> Result<Page, GetPagePreviewError>
+);
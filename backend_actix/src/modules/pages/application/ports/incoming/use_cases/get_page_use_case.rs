@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::pages::application::domain::entities::Page;
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum GetPageError {
+    #[error("Page not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait GetPageUseCase: Send + Sync {
+    async fn execute(&self, owner_id: Uuid, id: Uuid) -> Result<Page, GetPageError>;
+}
+
+metered_use_case!(
+    "pages",
+    "get_page",
+    GetPageUseCase,
+    fn execute(&self, owner_id: Uuid, id: Uuid) -> Result<Page, GetPageError>
+);
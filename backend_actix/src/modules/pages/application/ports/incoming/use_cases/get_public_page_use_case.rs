@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::pages::application::domain::entities::Page;
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum GetPublicPageError {
+    /// No such slug, or the page is a draft
+    #[error("Page not found")]
+    NotFound,
+
+    /// A published page was deleted from this slug, at the given time
+    #[error("Page was deleted")]
+    Gone(DateTime<Utc>),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait GetPublicPageUseCase: Send + Sync {
+    async fn execute(&self, owner_id: Uuid, slug: &str) -> Result<Page, GetPublicPageError>;
+}
+
+metered_use_case!(
+    "pages",
+    "get_public_page",
+    GetPublicPageUseCase,
+    fn execute(&self, owner_id: Uuid, slug: &str) -> Result<Page, GetPublicPageError>
+);
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::pages::application::domain::entities::Page;
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ListPagesError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait ListPagesUseCase: Send + Sync {
+    /// Every page of the owner, drafts included, by slug
+    async fn execute(&self, owner_id: Uuid) -> Result<Vec<Page>, ListPagesError>;
+}
+
+metered_use_case!(
+    "pages",
+    "list_pages",
+    ListPagesUseCase,
+    fn execute(&self, owner_id: Uuid) -> Result<Vec<Page>, ListPagesError>
+);
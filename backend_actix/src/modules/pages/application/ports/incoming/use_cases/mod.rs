@@ -0,0 +1,33 @@
+mod acquire_page_lock_use_case;
+mod autosave_page_use_case;
+mod create_page_use_case;
+mod create_preview_token_use_case;
+mod delete_page_use_case;
+mod get_page_autosave_use_case;
+mod get_page_preview_use_case;
+mod get_page_use_case;
+mod get_public_page_use_case;
+mod list_pages_use_case;
+mod page_command;
+mod release_page_lock_use_case;
+mod update_page_use_case;
+
+pub use acquire_page_lock_use_case::{AcquirePageLockError, AcquirePageLockUseCase};
+pub use autosave_page_use_case::{AutosavePageError, AutosavePageUseCase};
+pub use create_page_use_case::{CreatePageCommand, CreatePageError, CreatePageUseCase};
+pub use create_preview_token_use_case::{
+    CreatePreviewTokenError, CreatePreviewTokenUseCase, PreviewToken, DEFAULT_PREVIEW_HOURS,
+    MAX_PREVIEW_HOURS,
+};
+pub use delete_page_use_case::{DeletePageError, DeletePageUseCase};
+pub use get_page_autosave_use_case::{GetPageAutosaveError, GetPageAutosaveUseCase};
+pub use get_page_preview_use_case::{GetPagePreviewError, GetPagePreviewUseCase};
+pub use get_page_use_case::{GetPageError, GetPageUseCase};
+pub use get_public_page_use_case::{GetPublicPageError, GetPublicPageUseCase};
+pub use list_pages_use_case::{ListPagesError, ListPagesUseCase};
+pub use page_command::{
+    PageCommandError, MAX_BODY_LENGTH, MAX_CANONICAL_URL_LENGTH, MAX_SEO_DESCRIPTION_LENGTH,
+    MAX_SEO_TITLE_LENGTH, MAX_SLUG_LENGTH, MAX_TITLE_LENGTH,
+};
+pub use release_page_lock_use_case::{ReleasePageLockError, ReleasePageLockUseCase};
+pub use update_page_use_case::{UpdatePageCommand, UpdatePageError, UpdatePageUseCase};
@@ -0,0 +1,121 @@
+//! Field rules shared by the create and update commands.
+
+pub const MAX_SLUG_LENGTH: usize = 100;
+pub const MAX_TITLE_LENGTH: usize = 200;
+pub const MAX_BODY_LENGTH: usize = 100_000;
+
+use crate::shared::image_proxy;
+pub use crate::shared::seo::{
+    MAX_CANONICAL_URL_LENGTH, MAX_SEO_DESCRIPTION_LENGTH, MAX_SEO_TITLE_LENGTH,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum PageCommandError {
+    #[error(
+        "Slug must be 1-{MAX_SLUG_LENGTH} lowercase letters, digits and single hyphens, e.g. 'about' or 'now-2026'"
+    )]
+    InvalidSlug,
+
+    #[error("Title is required and must be at most {MAX_TITLE_LENGTH} characters")]
+    InvalidTitle,
+
+    #[error("Body must be at most {MAX_BODY_LENGTH} characters")]
+    BodyTooLong,
+
+    #[error("SEO title must be at most {MAX_SEO_TITLE_LENGTH} characters")]
+    SeoTitleTooLong,
+
+    #[error("SEO description must be at most {MAX_SEO_DESCRIPTION_LENGTH} characters")]
+    SeoDescriptionTooLong,
+
+    #[error(
+        "Canonical URL must be an absolute http(s) URL of at most {MAX_CANONICAL_URL_LENGTH} characters"
+    )]
+    InvalidCanonicalUrl,
+
+    #[error("Status must be 'draft' or 'published'")]
+    InvalidStatus,
+}
+
+impl PageCommandError {
+    /// Machine-readable reason, as sent in API errors
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidSlug => "INVALID_SLUG",
+            Self::InvalidTitle => "INVALID_TITLE",
+            Self::BodyTooLong => "BODY_TOO_LONG",
+            Self::SeoTitleTooLong => "SEO_TITLE_TOO_LONG",
+            Self::SeoDescriptionTooLong => "SEO_DESCRIPTION_TOO_LONG",
+            Self::InvalidCanonicalUrl => "INVALID_CANONICAL_URL",
+            Self::InvalidStatus => "INVALID_STATUS",
+        }
+    }
+}
+
+pub(super) fn slug(slug: String) -> Result<String, PageCommandError> {
+    let slug = slug.trim();
+    let valid = !slug.is_empty()
+        && slug.len() <= MAX_SLUG_LENGTH
+        && slug.split('-').all(|part| {
+            !part.is_empty() && part.bytes().all(|b| matches!(b, b'a'..=b'z' | b'0'..=b'9'))
+        });
+
+    if !valid {
+        return Err(PageCommandError::InvalidSlug);
+    }
+    Ok(slug.to_string())
+}
+
+pub(super) fn title(title: String) -> Result<String, PageCommandError> {
+    let title = title.trim();
+    if title.is_empty() || title.chars().count() > MAX_TITLE_LENGTH {
+        return Err(PageCommandError::InvalidTitle);
+    }
+    Ok(title.to_string())
+}
+
+/// Images on an `IMAGE_PROXY_ORIGINS` origin are pointed at the image proxy
+pub(super) fn body(body: String) -> Result<String, PageCommandError> {
+    if body.chars().count() > MAX_BODY_LENGTH {
+        return Err(PageCommandError::BodyTooLong);
+    }
+    Ok(image_proxy::allowed_origins().rewrite_markdown(body))
+}
+
+/// Trimmed; blank counts as absent
+pub(super) fn optional_text(
+    text: Option<String>,
+    max: usize,
+    err: PageCommandError,
+) -> Result<Option<String>, PageCommandError> {
+    let text = text
+        .map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty());
+    if text.as_ref().is_some_and(|text| text.chars().count() > max) {
+        return Err(err);
+    }
+    Ok(text)
+}
+
+/// Absolute http(s) URL; blank counts as absent
+pub(super) fn canonical_url(url: String) -> Result<Option<String>, PageCommandError> {
+    crate::shared::seo::canonical_url(url).map_err(|_| PageCommandError::InvalidCanonicalUrl)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slug_accepts_lowercase_words_joined_by_single_hyphens() {
+        for ok in ["about", "now-2026", "uses"] {
+            assert_eq!(slug(ok.to_string()).unwrap(), ok);
+        }
+        for bad in ["", "About", "now--2026", "-now", "now-", "über", "a/b"] {
+            assert!(
+                matches!(slug(bad.to_string()), Err(PageCommandError::InvalidSlug)),
+                "{bad:?} should be rejected"
+            );
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::shared::metrics::metered_use_case;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ReleasePageLockError {
+    #[error("Page not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait ReleasePageLockUseCase: Send + Sync {
+    /// Free the page for other sessions. A lock `lock_id` no longer holds
+    /// is left alone.
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+        lock_id: Uuid,
+    ) -> Result<(), ReleasePageLockError>;
+}
+
+metered_use_case!(
+    "pages",
+    "release_page_lock",
+    ReleasePageLockUseCase,
+    fn execute(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+        lock_id: Uuid,
+    ) -> Result<(), ReleasePageLockError>
+);
@@ -0,0 +1,281 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::page_command::{self, PageCommandError};
+use super::{MAX_SEO_DESCRIPTION_LENGTH, MAX_SEO_TITLE_LENGTH};
+use crate::pages::application::domain::edit_lock::EditLock;
+use crate::pages::application::domain::entities::{Page, PageStatus};
+use crate::project::application::ports::outgoing::project_repository::PatchField;
+use crate::shared::metrics::metered_use_case;
+
+//
+// ──────────────────────────────────────────────────────────
+// Update Page Command
+// ──────────────────────────────────────────────────────────
+//
+
+/// Validated changes. `None` keeps the current value; for the SEO fields
+/// and `canonical_url` `PatchField::Null` clears it. The slug cannot change.
+#[derive(Debug, Clone, Default)]
+pub struct UpdatePageCommand {
+    title: Option<String>,
+    body: Option<String>,
+    status: Option<PageStatus>,
+    seo_title: PatchField<String>,
+    seo_description: PatchField<String>,
+    canonical_url: PatchField<String>,
+    noindex: Option<bool>,
+    expected_updated_at: Option<DateTime<Utc>>,
+    lock_id: Option<Uuid>,
+}
+
+/// Blank text counts as `null`
+fn optional_patch(
+    field: PatchField<String>,
+    max: usize,
+    err: PageCommandError,
+) -> Result<PatchField<String>, PageCommandError> {
+    match field {
+        PatchField::Value(text) => Ok(page_command::optional_text(Some(text), max, err)?
+            .map_or(PatchField::Null, PatchField::Value)),
+        other => Ok(other),
+    }
+}
+
+impl UpdatePageCommand {
+    pub fn new(
+        title: PatchField<String>,
+        body: PatchField<String>,
+        status: PatchField<PageStatus>,
+        seo_title: PatchField<String>,
+        seo_description: PatchField<String>,
+    ) -> Result<Self, PageCommandError> {
+        let title = match title {
+            PatchField::Unset => None,
+            PatchField::Null => return Err(PageCommandError::InvalidTitle),
+            PatchField::Value(title) => Some(page_command::title(title)?),
+        };
+        let body = match body {
+            PatchField::Unset => None,
+            PatchField::Null => Some(String::new()),
+            PatchField::Value(body) => Some(page_command::body(body)?),
+        };
+        let status = match status {
+            PatchField::Unset => None,
+            PatchField::Null => return Err(PageCommandError::InvalidStatus),
+            PatchField::Value(status) => Some(status),
+        };
+
+        Ok(Self {
+            title,
+            body,
+            status,
+            seo_title: optional_patch(
+                seo_title,
+                MAX_SEO_TITLE_LENGTH,
+                PageCommandError::SeoTitleTooLong,
+            )?,
+            seo_description: optional_patch(
+                seo_description,
+                MAX_SEO_DESCRIPTION_LENGTH,
+                PageCommandError::SeoDescriptionTooLong,
+            )?,
+            canonical_url: PatchField::Unset,
+            noindex: None,
+            expected_updated_at: None,
+            lock_id: None,
+        })
+    }
+
+    /// Also change how search engines treat the page
+    pub fn with_indexing(
+        mut self,
+        canonical_url: PatchField<String>,
+        noindex: Option<bool>,
+    ) -> Result<Self, PageCommandError> {
+        self.canonical_url = match canonical_url {
+            PatchField::Value(url) => {
+                page_command::canonical_url(url)?.map_or(PatchField::Null, PatchField::Value)
+            }
+            other => other,
+        };
+        self.noindex = noindex;
+        Ok(self)
+    }
+
+    /// Fail with `Stale` instead of overwriting a page updated after
+    /// `expected_updated_at` (see `shared::api::precondition`)
+    pub fn with_expected_updated_at(mut self, expected: Option<DateTime<Utc>>) -> Self {
+        self.expected_updated_at = expected;
+        self
+    }
+
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+
+    pub fn status(&self) -> Option<PageStatus> {
+        self.status
+    }
+
+    pub fn seo_title(&self) -> &PatchField<String> {
+        &self.seo_title
+    }
+
+    pub fn seo_description(&self) -> &PatchField<String> {
+        &self.seo_description
+    }
+
+    pub fn canonical_url(&self) -> &PatchField<String> {
+        &self.canonical_url
+    }
+
+    pub fn noindex(&self) -> Option<bool> {
+        self.noindex
+    }
+
+    pub fn expected_updated_at(&self) -> Option<DateTime<Utc>> {
+        self.expected_updated_at
+    }
+
+    /// Edit lock the caller's session holds, if any
+    pub fn with_lock(mut self, lock_id: Option<Uuid>) -> Self {
+        self.lock_id = lock_id;
+        self
+    }
+
+    pub fn lock_id(&self) -> Option<Uuid> {
+        self.lock_id
+    }
+
+    /// Nothing to change
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none()
+            && self.body.is_none()
+            && self.status.is_none()
+            && self.seo_title.is_unset()
+            && self.seo_description.is_unset()
+            && self.canonical_url.is_unset()
+            && self.noindex.is_none()
+    }
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Use Case Error
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum UpdatePageError {
+    #[error("Page not found")]
+    NotFound,
+
+    /// Updated since `expected_updated_at`
+    #[error("Page changed since it was read")]
+    Stale,
+
+    /// Another session holds the page's edit lock
+    #[error("Page is being edited in another session")]
+    Locked(EditLock),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Incoming Port (Use Case)
+// ──────────────────────────────────────────────────────────
+//
+
+#[async_trait]
+pub trait UpdatePageUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        id: Uuid,
+        command: UpdatePageCommand,
+    ) -> Result<Page, UpdatePageError>;
+}
+
+metered_use_case!(
+    "pages",
+    "update_page",
+    UpdatePageUseCase,
+    fn execute(
+        &self,
+        owner_id: Uuid,
+        id: Uuid,
+        command: UpdatePageCommand,
+    ) -> Result<Page, UpdatePageError>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_keeps_unset_fields_and_clears_blank_seo_title() {
+        let command = UpdatePageCommand::new(
+            PatchField::Unset,
+            PatchField::Null,
+            PatchField::Value(PageStatus::Published),
+            PatchField::Value(" ".to_string()),
+            PatchField::Unset,
+        )
+        .unwrap();
+
+        assert_eq!(command.title(), None);
+        assert_eq!(command.body(), Some(""));
+        assert_eq!(command.status(), Some(PageStatus::Published));
+        assert_eq!(command.seo_title(), &PatchField::Null);
+        assert!(command.seo_description().is_unset());
+        assert!(!command.is_empty());
+    }
+
+    #[test]
+    fn test_indexing_alone_is_a_change() {
+        let command = UpdatePageCommand::default()
+            .with_indexing(PatchField::Value(" ".to_string()), Some(true))
+            .unwrap();
+
+        assert_eq!(command.canonical_url(), &PatchField::Null);
+        assert_eq!(command.noindex(), Some(true));
+        assert!(!command.is_empty());
+        assert!(matches!(
+            UpdatePageCommand::default()
+                .with_indexing(PatchField::Value("/about".to_string()), None),
+            Err(PageCommandError::InvalidCanonicalUrl)
+        ));
+    }
+
+    #[test]
+    fn test_command_rejects_null_title_and_status() {
+        assert!(matches!(
+            UpdatePageCommand::new(
+                PatchField::Null,
+                PatchField::Unset,
+                PatchField::Unset,
+                PatchField::Unset,
+                PatchField::Unset,
+            ),
+            Err(PageCommandError::InvalidTitle)
+        ));
+        assert!(matches!(
+            UpdatePageCommand::new(
+                PatchField::Unset,
+                PatchField::Unset,
+                PatchField::Null,
+                PatchField::Unset,
+                PatchField::Unset,
+            ),
+            Err(PageCommandError::InvalidStatus)
+        ));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod page_repository;
+
+pub use page_repository::{PageRepository, PageRepositoryError};
@@ -0,0 +1,166 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::pages::application::domain::autosave::PageAutosave;
+use crate::pages::application::domain::edit_lock::EditLock;
+use crate::pages::application::domain::entities::Page;
+use crate::pages::application::ports::incoming::use_cases::{CreatePageCommand, UpdatePageCommand};
+use crate::shared::faults::faulty_repository;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum PageRepositoryError {
+    /// Page doesn't exist or belongs to someone else
+    #[error("Page not found")]
+    NotFound,
+
+    #[error("Slug already exists")]
+    SlugAlreadyExists,
+
+    /// Updated after the command's `expected_updated_at`
+    #[error("Page changed since it was read")]
+    Stale,
+
+    /// Another session holds the page's edit lock
+    #[error("Page is locked by another session")]
+    Locked(EditLock),
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait PageRepository: Send + Sync {
+    async fn create(
+        &self,
+        owner_id: Uuid,
+        command: &CreatePageCommand,
+    ) -> Result<Page, PageRepositoryError>;
+
+    /// Every page of the owner, drafts included, by slug
+    async fn list(&self, owner_id: Uuid) -> Result<Vec<Page>, PageRepositoryError>;
+
+    async fn get(&self, owner_id: Uuid, id: Uuid) -> Result<Page, PageRepositoryError>;
+
+    /// Sets `published_at` the first time the page is published, and drops
+    /// the page's autosave when publishing
+    async fn update(
+        &self,
+        owner_id: Uuid,
+        id: Uuid,
+        command: &UpdatePageCommand,
+    ) -> Result<Page, PageRepositoryError>;
+
+    /// Leaves a tombstone at the slug if the page was ever published
+    async fn delete(&self, owner_id: Uuid, id: Uuid) -> Result<(), PageRepositoryError>;
+
+    /// The owner's page at `slug`, if it is published
+    async fn find_published(
+        &self,
+        owner_id: Uuid,
+        slug: &str,
+    ) -> Result<Option<Page>, PageRepositoryError>;
+
+    /// When the owner's page at `slug` was deleted, if it had been
+    /// published; `None` once a page holds the slug again
+    async fn find_tombstone(
+        &self,
+        owner_id: Uuid,
+        slug: &str,
+    ) -> Result<Option<DateTime<Utc>>, PageRepositoryError>;
+
+    /// Take the page's edit lock for `lock.lock_id`, or renew it when that
+    /// id already holds it (keeping `acquired_at`, and the label unless a
+    /// new one is given). `Locked` while another id holds a live lock.
+    async fn acquire_lock(
+        &self,
+        owner_id: Uuid,
+        lock: &EditLock,
+    ) -> Result<EditLock, PageRepositoryError>;
+
+    /// The page's live edit lock; `None` as well for someone else's page
+    async fn find_lock(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+    ) -> Result<Option<EditLock>, PageRepositoryError>;
+
+    /// Drop the page's edit lock if `lock_id` holds it; otherwise nothing
+    /// happens
+    async fn release_lock(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+        lock_id: Uuid,
+    ) -> Result<(), PageRepositoryError>;
+
+    /// Replace the page's autosave, unless the page was saved after it was
+    /// taken. `false` when nothing was written, for someone else's page too.
+    async fn save_autosave(
+        &self,
+        owner_id: Uuid,
+        autosave: &PageAutosave,
+    ) -> Result<bool, PageRepositoryError>;
+
+    /// The page's autosave, if it is newer than the page's last save
+    async fn find_autosave(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+    ) -> Result<Option<PageAutosave>, PageRepositoryError>;
+}
+
+faulty_repository! {
+    PageRepository,
+    PageRepositoryError::DatabaseError,
+    fn create(
+        &self,
+        owner_id: Uuid,
+        command: &CreatePageCommand,
+    ) -> Result<Page, PageRepositoryError>;
+    fn list(&self, owner_id: Uuid) -> Result<Vec<Page>, PageRepositoryError>;
+    fn get(&self, owner_id: Uuid, id: Uuid) -> Result<Page, PageRepositoryError>;
+    fn update(
+        &self,
+        owner_id: Uuid,
+        id: Uuid,
+        command: &UpdatePageCommand,
+    ) -> Result<Page, PageRepositoryError>;
+    fn delete(&self, owner_id: Uuid, id: Uuid) -> Result<(), PageRepositoryError>;
+    fn find_published(
+        &self,
+        owner_id: Uuid,
+        slug: &str,
+    ) -> Result<Option<Page>, PageRepositoryError>;
+    fn find_tombstone(
+        &self,
+        owner_id: Uuid,
+        slug: &str,
+    ) -> Result<Option<DateTime<Utc>>, PageRepositoryError>;
+    fn acquire_lock(
+        &self,
+        owner_id: Uuid,
+        lock: &EditLock,
+    ) -> Result<EditLock, PageRepositoryError>;
+    fn find_lock(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+    ) -> Result<Option<EditLock>, PageRepositoryError>;
+    fn release_lock(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+        lock_id: Uuid,
+    ) -> Result<(), PageRepositoryError>;
+    fn save_autosave(
+        &self,
+        owner_id: Uuid,
+        autosave: &PageAutosave,
+    ) -> Result<bool, PageRepositoryError>;
+    fn find_autosave(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+    ) -> Result<Option<PageAutosave>, PageRepositoryError>;
+}
@@ -0,0 +1,157 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::pages::application::domain::edit_lock::{self, EditLock};
+use crate::pages::application::ports::{
+    incoming::use_cases::{AcquirePageLockError, AcquirePageLockUseCase},
+    outgoing::{PageRepository, PageRepositoryError},
+};
+
+pub struct AcquirePageLockService<R>
+where
+    R: PageRepository,
+{
+    repository: R,
+}
+
+impl<R> AcquirePageLockService<R>
+where
+    R: PageRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> AcquirePageLockUseCase for AcquirePageLockService<R>
+where
+    R: PageRepository,
+{
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+        lock_id: Option<Uuid>,
+        label: Option<String>,
+    ) -> Result<EditLock, AcquirePageLockError> {
+        let label = edit_lock::label(label).map_err(AcquirePageLockError::InvalidLabel)?;
+        let lease = EditLock::lease(
+            page_id,
+            lock_id.unwrap_or_else(Uuid::new_v4),
+            label,
+            Utc::now(),
+        );
+
+        self.repository
+            .acquire_lock(owner_id, &lease)
+            .await
+            .map_err(|e| match e {
+                PageRepositoryError::NotFound => AcquirePageLockError::NotFound,
+                PageRepositoryError::Locked(holder) => AcquirePageLockError::Locked(holder),
+                other => AcquirePageLockError::RepositoryError(other.to_string()),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::adapter::outgoing::InMemoryPageStore;
+    use crate::pages::application::domain::entities::PageStatus;
+    use crate::pages::application::ports::incoming::use_cases::CreatePageCommand;
+
+    async fn page(store: &InMemoryPageStore, owner: Uuid) -> Uuid {
+        let command = CreatePageCommand::new(
+            "now".to_string(),
+            "Now".to_string(),
+            String::new(),
+            PageStatus::Draft,
+            None,
+            None,
+        )
+        .unwrap();
+        store.create(owner, &command).await.unwrap().id
+    }
+
+    #[tokio::test]
+    async fn test_second_session_is_kept_out_until_release() {
+        let store = InMemoryPageStore::default();
+        let owner = Uuid::new_v4();
+        let page_id = page(&store, owner).await;
+        let service = AcquirePageLockService::new(store.clone());
+
+        let first = service
+            .execute(owner, page_id, None, Some("Firefox".to_string()))
+            .await
+            .unwrap();
+        let second = service.execute(owner, page_id, None, None).await;
+
+        match second {
+            Err(AcquirePageLockError::Locked(holder)) => {
+                assert_eq!(holder.label.as_deref(), Some("Firefox"));
+                assert_eq!(holder.lock_id, first.lock_id);
+            }
+            other => panic!("expected Locked, got {other:?}"),
+        }
+
+        store
+            .release_lock(owner, page_id, first.lock_id)
+            .await
+            .unwrap();
+        assert!(service.execute(owner, page_id, None, None).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_renewal_extends_and_keeps_holder() {
+        let store = InMemoryPageStore::default();
+        let owner = Uuid::new_v4();
+        let page_id = page(&store, owner).await;
+        let service = AcquirePageLockService::new(store);
+
+        let first = service
+            .execute(owner, page_id, None, Some("Firefox".to_string()))
+            .await
+            .unwrap();
+        let renewed = service
+            .execute(owner, page_id, Some(first.lock_id), None)
+            .await
+            .unwrap();
+
+        assert_eq!(renewed.lock_id, first.lock_id);
+        assert_eq!(renewed.acquired_at, first.acquired_at);
+        assert_eq!(renewed.label.as_deref(), Some("Firefox"));
+        assert!(renewed.expires_at >= first.expires_at);
+    }
+
+    #[tokio::test]
+    async fn test_lapsed_lock_is_taken_over() {
+        let store = InMemoryPageStore::default();
+        let owner = Uuid::new_v4();
+        let page_id = page(&store, owner).await;
+        let lapsed = EditLock::lease(
+            page_id,
+            Uuid::new_v4(),
+            None,
+            Utc::now() - chrono::Duration::seconds(edit_lock::EDIT_LOCK_TTL_SECS + 1),
+        );
+        store.acquire_lock(owner, &lapsed).await.unwrap();
+        let service = AcquirePageLockService::new(store);
+
+        let lock = service.execute(owner, page_id, None, None).await.unwrap();
+
+        assert_ne!(lock.lock_id, lapsed.lock_id);
+    }
+
+    #[tokio::test]
+    async fn test_someone_elses_page_is_not_found() {
+        let store = InMemoryPageStore::default();
+        let page_id = page(&store, Uuid::new_v4()).await;
+        let service = AcquirePageLockService::new(store);
+
+        let result = service.execute(Uuid::new_v4(), page_id, None, None).await;
+
+        assert!(matches!(result, Err(AcquirePageLockError::NotFound)));
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use uuid::Uuid;
+
+use crate::pages::application::domain::autosave::PageAutosave;
+
+/// Pages whose autosaves are held at once; past this they are written
+/// straight away
+pub const MAX_PENDING: usize = 1_000;
+
+/// An autosave waiting to be written, with the owner of its page
+#[derive(Debug, Clone)]
+pub struct PendingAutosave {
+    pub owner_id: Uuid,
+    pub autosave: PageAutosave,
+}
+
+/// The latest autosave of each page, waiting to be written. The editor
+/// sends one every few seconds; only the last of a burst reaches the
+/// database, through [`super::AutosaveFlusher`]. Clones share the same
+/// autosaves.
+#[derive(Clone, Default)]
+pub struct AutosaveBuffer {
+    pending: Arc<Mutex<HashMap<Uuid, PendingAutosave>>>,
+}
+
+impl AutosaveBuffer {
+    /// Holds `autosave` in place of the page's pending one. `false` when
+    /// the buffer is full and the caller has to write it itself.
+    pub fn put(&self, owner_id: Uuid, autosave: PageAutosave) -> bool {
+        let mut pending = self.lock();
+        if pending.len() >= MAX_PENDING && !pending.contains_key(&autosave.page_id) {
+            return false;
+        }
+        pending.insert(autosave.page_id, PendingAutosave { owner_id, autosave });
+        true
+    }
+
+    /// The page's pending autosave, if the owner's
+    pub fn get(&self, owner_id: Uuid, page_id: Uuid) -> Option<PageAutosave> {
+        self.lock()
+            .get(&page_id)
+            .filter(|pending| pending.owner_id == owner_id)
+            .map(|pending| pending.autosave.clone())
+    }
+
+    /// Whether the owner's page has an autosave pending
+    pub fn holds(&self, owner_id: Uuid, page_id: Uuid) -> bool {
+        self.lock()
+            .get(&page_id)
+            .is_some_and(|pending| pending.owner_id == owner_id)
+    }
+
+    /// Everything pending
+    pub fn take(&self) -> Vec<PendingAutosave> {
+        std::mem::take(&mut *self.lock()).into_values().collect()
+    }
+
+    /// Puts back autosaves that could not be written, except for pages
+    /// autosaved again since
+    pub fn requeue(&self, batch: Vec<PendingAutosave>) {
+        let mut pending = self.lock();
+        for autosave in batch {
+            pending.entry(autosave.autosave.page_id).or_insert(autosave);
+        }
+    }
+
+    pub fn pending(&self) -> usize {
+        self.lock().len()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<Uuid, PendingAutosave>> {
+        self.pending
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn autosave(page_id: Uuid, body: &str) -> PageAutosave {
+        PageAutosave {
+            page_id,
+            body: body.to_string(),
+            saved_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_latest_autosave_of_a_page_wins() {
+        let buffer = AutosaveBuffer::default();
+        let (owner, page) = (Uuid::new_v4(), Uuid::new_v4());
+
+        assert!(buffer.put(owner, autosave(page, "a")));
+        assert!(buffer.put(owner, autosave(page, "ab")));
+
+        assert_eq!(buffer.pending(), 1);
+        assert_eq!(buffer.get(owner, page).unwrap().body, "ab");
+        assert!(buffer.get(Uuid::new_v4(), page).is_none());
+    }
+
+    #[test]
+    fn test_requeue_keeps_newer_autosaves() {
+        let buffer = AutosaveBuffer::default();
+        let (owner, page) = (Uuid::new_v4(), Uuid::new_v4());
+        buffer.put(owner, autosave(page, "old"));
+        let batch = buffer.take();
+        buffer.put(owner, autosave(page, "new"));
+
+        buffer.requeue(batch);
+
+        assert_eq!(buffer.take()[0].autosave.body, "new");
+    }
+
+    #[test]
+    fn test_full_buffer_refuses_new_pages_only() {
+        let buffer = AutosaveBuffer::default();
+        let owner = Uuid::new_v4();
+        let first = Uuid::new_v4();
+        buffer.put(owner, autosave(first, "x"));
+        for _ in 1..MAX_PENDING {
+            buffer.put(owner, autosave(Uuid::new_v4(), "x"));
+        }
+
+        assert!(!buffer.put(owner, autosave(Uuid::new_v4(), "x")));
+        assert!(buffer.put(owner, autosave(first, "y")));
+        assert_eq!(buffer.pending(), MAX_PENDING);
+    }
+}
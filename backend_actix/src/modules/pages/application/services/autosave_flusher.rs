@@ -0,0 +1,159 @@
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::pages::application::ports::outgoing::{PageRepository, PageRepositoryError};
+use crate::pages::application::services::AutosaveBuffer;
+use crate::shared::lifecycle::ShutdownSignal;
+
+/// Writes pending autosaves every `interval`. Autosaves that fail to write
+/// are kept for the next round unless the page was autosaved again, and
+/// whatever is left is written once more on shutdown.
+pub struct AutosaveFlusher<R>
+where
+    R: PageRepository,
+{
+    buffer: AutosaveBuffer,
+    repository: R,
+    interval: Duration,
+}
+
+impl<R> AutosaveFlusher<R>
+where
+    R: PageRepository,
+{
+    pub fn new(buffer: AutosaveBuffer, repository: R, interval: Duration) -> Self {
+        Self {
+            buffer,
+            repository,
+            interval,
+        }
+    }
+
+    pub async fn run(self, mut signal: ShutdownSignal) {
+        info!(
+            interval_secs = self.interval.as_secs(),
+            "Autosave flusher started"
+        );
+
+        loop {
+            tokio::select! {
+                _ = signal.wait() => break,
+                _ = tokio::time::sleep(self.interval) => {}
+            }
+
+            if let Err(e) = self.flush().await {
+                warn!(error = %e, "Autosave flush failed, retrying next round");
+            }
+        }
+
+        match self.flush().await {
+            Ok(0) => {}
+            Ok(written) => info!(written, "Flushed autosaves on shutdown"),
+            Err(e) => warn!(error = %e, "Autosaves lost on shutdown"),
+        }
+    }
+
+    /// Writes everything pending; returns how many autosaves were written.
+    /// Those of pages saved since are dropped.
+    pub async fn flush(&self) -> Result<usize, PageRepositoryError> {
+        let mut written = 0;
+        let mut failed = Vec::new();
+        let mut error = None;
+
+        for pending in self.buffer.take() {
+            match self
+                .repository
+                .save_autosave(pending.owner_id, &pending.autosave)
+                .await
+            {
+                Ok(true) => written += 1,
+                Ok(false) => {}
+                Err(e) => {
+                    error.get_or_insert(e);
+                    failed.push(pending);
+                }
+            }
+        }
+
+        self.buffer.requeue(failed);
+        match error {
+            Some(e) => Err(e),
+            None => Ok(written),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use crate::pages::adapter::outgoing::InMemoryPageStore;
+    use crate::pages::application::domain::autosave::PageAutosave;
+    use crate::pages::application::domain::entities::PageStatus;
+    use crate::pages::application::ports::incoming::use_cases::CreatePageCommand;
+    use crate::shared::lifecycle::BackgroundJobs;
+
+    async fn page(store: &InMemoryPageStore, owner: Uuid) -> Uuid {
+        store
+            .create(
+                owner,
+                &CreatePageCommand::new(
+                    "now".to_string(),
+                    "Now".to_string(),
+                    String::new(),
+                    PageStatus::Draft,
+                    None,
+                    None,
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap()
+            .id
+    }
+
+    fn autosave(page_id: Uuid, body: &str) -> PageAutosave {
+        PageAutosave {
+            page_id,
+            body: body.to_string(),
+            saved_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_only_the_latest_autosave_is_written() {
+        let store = InMemoryPageStore::default();
+        let owner = Uuid::new_v4();
+        let page_id = page(&store, owner).await;
+        let buffer = AutosaveBuffer::default();
+        let flusher = AutosaveFlusher::new(buffer.clone(), store.clone(), Duration::from_secs(60));
+
+        buffer.put(owner, autosave(page_id, "# Dr"));
+        buffer.put(owner, autosave(page_id, "# Draft"));
+
+        assert_eq!(flusher.flush().await.unwrap(), 1);
+        assert_eq!(buffer.pending(), 0);
+        let stored = store.find_autosave(owner, page_id).await.unwrap().unwrap();
+        assert_eq!(stored.body, "# Draft");
+    }
+
+    #[tokio::test]
+    async fn test_pending_autosaves_are_written_on_shutdown() {
+        let store = InMemoryPageStore::default();
+        let owner = Uuid::new_v4();
+        let page_id = page(&store, owner).await;
+        let buffer = AutosaveBuffer::default();
+        let flusher =
+            AutosaveFlusher::new(buffer.clone(), store.clone(), Duration::from_secs(3600));
+        let mut jobs = BackgroundJobs::new();
+        jobs.spawn("page_autosaves", move |signal| flusher.run(signal));
+
+        buffer.put(owner, autosave(page_id, "# Draft"));
+        let aborted = jobs.shutdown(Duration::from_secs(1)).await;
+
+        assert!(aborted.is_empty());
+        assert!(store.find_autosave(owner, page_id).await.unwrap().is_some());
+    }
+}
@@ -0,0 +1,142 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::pages::application::domain::autosave::PageAutosave;
+use crate::pages::application::ports::{
+    incoming::use_cases::{AutosavePageError, AutosavePageUseCase, MAX_BODY_LENGTH},
+    outgoing::{PageRepository, PageRepositoryError},
+};
+use crate::pages::application::services::AutosaveBuffer;
+
+pub struct AutosavePageService<R>
+where
+    R: PageRepository,
+{
+    repository: R,
+    buffer: AutosaveBuffer,
+}
+
+impl<R> AutosavePageService<R>
+where
+    R: PageRepository,
+{
+    pub fn new(repository: R, buffer: AutosaveBuffer) -> Self {
+        Self { repository, buffer }
+    }
+}
+
+#[async_trait]
+impl<R> AutosavePageUseCase for AutosavePageService<R>
+where
+    R: PageRepository,
+{
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+        body: String,
+    ) -> Result<PageAutosave, AutosavePageError> {
+        let map_err = |e| match e {
+            PageRepositoryError::NotFound => AutosavePageError::NotFound,
+            other => AutosavePageError::RepositoryError(other.to_string()),
+        };
+
+        if body.chars().count() > MAX_BODY_LENGTH {
+            return Err(AutosavePageError::BodyTooLong);
+        }
+
+        // The first autosave of a burst checks the page is the owner's;
+        // the ones following it only touch the buffer
+        if !self.buffer.holds(owner_id, page_id) {
+            self.repository
+                .get(owner_id, page_id)
+                .await
+                .map_err(map_err)?;
+        }
+
+        let autosave = PageAutosave {
+            page_id,
+            body,
+            saved_at: Utc::now(),
+        };
+        if !self.buffer.put(owner_id, autosave.clone()) {
+            self.repository
+                .save_autosave(owner_id, &autosave)
+                .await
+                .map_err(map_err)?;
+        }
+        Ok(autosave)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::adapter::outgoing::InMemoryPageStore;
+    use crate::pages::application::domain::entities::PageStatus;
+    use crate::pages::application::ports::incoming::use_cases::CreatePageCommand;
+
+    #[tokio::test]
+    async fn test_autosave_waits_in_the_buffer() {
+        let store = InMemoryPageStore::default();
+        let owner = Uuid::new_v4();
+        let page = store
+            .create(
+                owner,
+                &CreatePageCommand::new(
+                    "now".to_string(),
+                    "Now".to_string(),
+                    "# Now".to_string(),
+                    PageStatus::Draft,
+                    None,
+                    None,
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let buffer = AutosaveBuffer::default();
+        let service = AutosavePageService::new(store.clone(), buffer.clone());
+
+        service
+            .execute(owner, page.id, "# Now, in progress".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            buffer.get(owner, page.id).unwrap().body,
+            "# Now, in progress"
+        );
+        assert!(store.find_autosave(owner, page.id).await.unwrap().is_none());
+        assert_eq!(store.get(owner, page.id).await.unwrap().body, "# Now");
+    }
+
+    #[tokio::test]
+    async fn test_autosave_of_someone_elses_page_is_not_found() {
+        let service =
+            AutosavePageService::new(InMemoryPageStore::default(), AutosaveBuffer::default());
+
+        let result = service
+            .execute(Uuid::new_v4(), Uuid::new_v4(), "x".to_string())
+            .await;
+
+        assert!(matches!(result, Err(AutosavePageError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_overlong_body_is_refused() {
+        let service =
+            AutosavePageService::new(InMemoryPageStore::default(), AutosaveBuffer::default());
+
+        let result = service
+            .execute(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                "x".repeat(MAX_BODY_LENGTH + 1),
+            )
+            .await;
+
+        assert!(matches!(result, Err(AutosavePageError::BodyTooLong)));
+    }
+}
@@ -0,0 +1,104 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::pages::application::cache_keys;
+use crate::pages::application::domain::entities::Page;
+use crate::pages::application::ports::{
+    incoming::use_cases::{CreatePageCommand, CreatePageError, CreatePageUseCase},
+    outgoing::{PageRepository, PageRepositoryError},
+};
+use crate::shared::cache::{self, CachePort};
+use crate::shared::telemetry::{self, BusinessEvent};
+
+pub struct CreatePageService<R>
+where
+    R: PageRepository,
+{
+    repository: R,
+    cache: Arc<dyn CachePort>,
+}
+
+impl<R> CreatePageService<R>
+where
+    R: PageRepository,
+{
+    pub fn new(repository: R, cache: Arc<dyn CachePort>) -> Self {
+        Self { repository, cache }
+    }
+}
+
+#[async_trait]
+impl<R> CreatePageUseCase for CreatePageService<R>
+where
+    R: PageRepository,
+{
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        command: CreatePageCommand,
+    ) -> Result<Page, CreatePageError> {
+        let page = self
+            .repository
+            .create(owner_id, &command)
+            .await
+            .map_err(|e| match e {
+                PageRepositoryError::SlugAlreadyExists => CreatePageError::SlugAlreadyExists,
+                PageRepositoryError::DatabaseError(msg) => CreatePageError::RepositoryError(msg),
+                PageRepositoryError::NotFound
+                | PageRepositoryError::Stale
+                | PageRepositoryError::Locked(_) => CreatePageError::RepositoryError(
+                    "unexpected not found while creating page".to_string(),
+                ),
+            })?;
+
+        // A cached miss for this slug would otherwise hide the new page
+        cache::invalidate_prefix(self.cache.as_ref(), &cache_keys::owner_prefix(owner_id)).await;
+
+        if page.is_published() {
+            telemetry::record(BusinessEvent::PagePublished {
+                user_id: owner_id,
+                page_id: page.id,
+            });
+        }
+
+        Ok(page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::adapter::outgoing::InMemoryPageStore;
+    use crate::pages::application::domain::entities::PageStatus;
+    use crate::shared::cache::NoopCache;
+
+    fn command(slug: &str) -> CreatePageCommand {
+        CreatePageCommand::new(
+            slug.to_string(),
+            "About".to_string(),
+            "Hello".to_string(),
+            PageStatus::Draft,
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_slug_is_unique_per_owner() {
+        let service = CreatePageService::new(InMemoryPageStore::default(), Arc::new(NoopCache));
+        let owner = Uuid::new_v4();
+
+        service.execute(owner, command("about")).await.unwrap();
+
+        assert!(matches!(
+            service.execute(owner, command("about")).await,
+            Err(CreatePageError::SlugAlreadyExists)
+        ));
+        assert!(service
+            .execute(Uuid::new_v4(), command("about"))
+            .await
+            .is_ok());
+    }
+}
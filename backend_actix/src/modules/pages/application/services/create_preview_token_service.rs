@@ -0,0 +1,138 @@
+use async_trait::async_trait;
+use chrono::{Duration, SubsecRound, Utc};
+use uuid::Uuid;
+
+use crate::pages::application::domain::preview_token::{PreviewGrant, PreviewTokens};
+use crate::pages::application::ports::{
+    incoming::use_cases::{
+        CreatePreviewTokenError, CreatePreviewTokenUseCase, PreviewToken, DEFAULT_PREVIEW_HOURS,
+        MAX_PREVIEW_HOURS,
+    },
+    outgoing::{PageRepository, PageRepositoryError},
+};
+
+pub struct CreatePreviewTokenService<R>
+where
+    R: PageRepository,
+{
+    repository: R,
+    tokens: PreviewTokens,
+}
+
+impl<R> CreatePreviewTokenService<R>
+where
+    R: PageRepository,
+{
+    pub fn new(repository: R, tokens: PreviewTokens) -> Self {
+        Self { repository, tokens }
+    }
+}
+
+#[async_trait]
+impl<R> CreatePreviewTokenUseCase for CreatePreviewTokenService<R>
+where
+    R: PageRepository,
+{
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+        expires_in_hours: Option<u32>,
+    ) -> Result<PreviewToken, CreatePreviewTokenError> {
+        let hours = expires_in_hours.unwrap_or(DEFAULT_PREVIEW_HOURS);
+        if !(1..=MAX_PREVIEW_HOURS).contains(&hours) {
+            return Err(CreatePreviewTokenError::InvalidExpiry);
+        }
+
+        // Only the owner may share a page
+        self.repository
+            .get(owner_id, page_id)
+            .await
+            .map_err(|e| match e {
+                PageRepositoryError::NotFound => CreatePreviewTokenError::NotFound,
+                other => CreatePreviewTokenError::RepositoryError(other.to_string()),
+            })?;
+
+        let grant = PreviewGrant {
+            page_id,
+            owner_id,
+            // Tokens carry whole seconds
+            expires_at: (Utc::now() + Duration::hours(hours.into())).trunc_subsecs(0),
+        };
+        let token = self.tokens.sign(&grant);
+
+        Ok(PreviewToken {
+            token,
+            expires_at: grant.expires_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::adapter::outgoing::InMemoryPageStore;
+    use crate::pages::application::domain::entities::PageStatus;
+    use crate::pages::application::ports::incoming::use_cases::CreatePageCommand;
+
+    const SECRET: &str = "0123456789abcdef0123456789abcdef";
+
+    async fn draft(store: &InMemoryPageStore, owner: Uuid) -> Uuid {
+        let command = CreatePageCommand::new(
+            "now".to_string(),
+            "Now".to_string(),
+            String::new(),
+            PageStatus::Draft,
+            None,
+            None,
+        )
+        .unwrap();
+        store.create(owner, &command).await.unwrap().id
+    }
+
+    #[tokio::test]
+    async fn test_token_grants_the_owners_page() {
+        let store = InMemoryPageStore::default();
+        let owner = Uuid::new_v4();
+        let page_id = draft(&store, owner).await;
+        let service = CreatePreviewTokenService::new(store, PreviewTokens::new(SECRET));
+
+        let preview = service.execute(owner, page_id, Some(2)).await.unwrap();
+
+        let grant = PreviewTokens::new(SECRET)
+            .verify(&preview.token, Utc::now())
+            .unwrap();
+        assert_eq!((grant.page_id, grant.owner_id), (page_id, owner));
+        assert!(preview.expires_at <= Utc::now() + Duration::hours(2));
+        assert!(preview.expires_at > Utc::now() + Duration::minutes(119));
+    }
+
+    #[tokio::test]
+    async fn test_someone_elses_page_is_not_found() {
+        let store = InMemoryPageStore::default();
+        let page_id = draft(&store, Uuid::new_v4()).await;
+        let service = CreatePreviewTokenService::new(store, PreviewTokens::new(SECRET));
+
+        let result = service.execute(Uuid::new_v4(), page_id, None).await;
+
+        assert!(matches!(result, Err(CreatePreviewTokenError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_expiry_is_bounded() {
+        let service = CreatePreviewTokenService::new(
+            InMemoryPageStore::default(),
+            PreviewTokens::new(SECRET),
+        );
+
+        for hours in [0, MAX_PREVIEW_HOURS + 1] {
+            let result = service
+                .execute(Uuid::new_v4(), Uuid::new_v4(), Some(hours))
+                .await;
+            assert!(matches!(
+                result,
+                Err(CreatePreviewTokenError::InvalidExpiry)
+            ));
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::pages::application::cache_keys;
+use crate::pages::application::ports::{
+    incoming::use_cases::{DeletePageError, DeletePageUseCase},
+    outgoing::{PageRepository, PageRepositoryError},
+};
+use crate::shared::cache::{self, CachePort};
+
+pub struct DeletePageService<R>
+where
+    R: PageRepository,
+{
+    repository: R,
+    cache: Arc<dyn CachePort>,
+}
+
+impl<R> DeletePageService<R>
+where
+    R: PageRepository,
+{
+    pub fn new(repository: R, cache: Arc<dyn CachePort>) -> Self {
+        Self { repository, cache }
+    }
+}
+
+#[async_trait]
+impl<R> DeletePageUseCase for DeletePageService<R>
+where
+    R: PageRepository,
+{
+    async fn execute(&self, owner_id: Uuid, id: Uuid) -> Result<(), DeletePageError> {
+        self.repository
+            .delete(owner_id, id)
+            .await
+            .map_err(|e| match e {
+                PageRepositoryError::NotFound => DeletePageError::NotFound,
+                other => DeletePageError::RepositoryError(other.to_string()),
+            })?;
+
+        cache::invalidate_prefix(self.cache.as_ref(), &cache_keys::owner_prefix(owner_id)).await;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::adapter::outgoing::InMemoryPageStore;
+    use crate::shared::cache::NoopCache;
+
+    #[tokio::test]
+    async fn test_unknown_page_is_not_found() {
+        let service = DeletePageService::new(InMemoryPageStore::default(), Arc::new(NoopCache));
+
+        let result = service.execute(Uuid::new_v4(), Uuid::new_v4()).await;
+
+        assert!(matches!(result, Err(DeletePageError::NotFound)));
+    }
+}
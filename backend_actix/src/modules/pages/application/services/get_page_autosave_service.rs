@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+
+use crate::pages::application::domain::{autosave::PageAutosave, entities::Page};
+use crate::pages::application::ports::{
+    incoming::use_cases::{GetPageAutosaveError, GetPageAutosaveUseCase},
+    outgoing::PageRepository,
+};
+use crate::pages::application::services::AutosaveBuffer;
+
+pub struct GetPageAutosaveService<R>
+where
+    R: PageRepository,
+{
+    repository: R,
+    buffer: AutosaveBuffer,
+}
+
+impl<R> GetPageAutosaveService<R>
+where
+    R: PageRepository,
+{
+    pub fn new(repository: R, buffer: AutosaveBuffer) -> Self {
+        Self { repository, buffer }
+    }
+}
+
+#[async_trait]
+impl<R> GetPageAutosaveUseCase for GetPageAutosaveService<R>
+where
+    R: PageRepository,
+{
+    async fn execute(&self, page: &Page) -> Result<Option<PageAutosave>, GetPageAutosaveError> {
+        // A pending autosave is always newer than the stored one
+        if let Some(pending) = self.buffer.get(page.owner_id, page.id) {
+            return Ok(Some(pending).filter(|a| a.is_newer_than(page.updated_at)));
+        }
+
+        self.repository
+            .find_autosave(page.owner_id, page.id)
+            .await
+            .map(|stored| stored.filter(|a| a.is_newer_than(page.updated_at)))
+            .map_err(|e| GetPageAutosaveError::RepositoryError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+
+    use crate::pages::adapter::outgoing::InMemoryPageStore;
+    use crate::pages::application::domain::entities::PageStatus;
+    use crate::pages::application::ports::incoming::use_cases::CreatePageCommand;
+
+    #[tokio::test]
+    async fn test_autosave_older_than_the_last_save_is_not_offered() {
+        let store = InMemoryPageStore::default();
+        let owner = Uuid::new_v4();
+        let page = store
+            .create(
+                owner,
+                &CreatePageCommand::new(
+                    "now".to_string(),
+                    "Now".to_string(),
+                    String::new(),
+                    PageStatus::Draft,
+                    None,
+                    None,
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let buffer = AutosaveBuffer::default();
+        let service = GetPageAutosaveService::new(store, buffer.clone());
+        let autosave = PageAutosave {
+            page_id: page.id,
+            body: "# Draft".to_string(),
+            saved_at: page.updated_at + Duration::seconds(5),
+        };
+        buffer.put(owner, autosave.clone());
+
+        assert_eq!(service.execute(&page).await.unwrap(), Some(autosave));
+
+        let saved_since = Page {
+            updated_at: Utc::now() + Duration::seconds(10),
+            ..page
+        };
+        assert_eq!(service.execute(&saved_since).await.unwrap(), None);
+    }
+}
@@ -0,0 +1,119 @@
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::pages::application::domain::entities::Page;
+use crate::pages::application::domain::preview_token::{PreviewTokenError, PreviewTokens};
+use crate::pages::application::ports::{
+    incoming::use_cases::{GetPagePreviewError, GetPagePreviewUseCase},
+    outgoing::{PageRepository, PageRepositoryError},
+};
+
+/// Reads the page behind a preview link. Not cached: the point of a preview
+/// is to show the latest draft.
+pub struct GetPagePreviewService<R>
+where
+    R: PageRepository,
+{
+    repository: R,
+    tokens: PreviewTokens,
+}
+
+impl<R> GetPagePreviewService<R>
+where
+    R: PageRepository,
+{
+    pub fn new(repository: R, tokens: PreviewTokens) -> Self {
+        Self { repository, tokens }
+    }
+}
+
+#[async_trait]
+impl<R> GetPagePreviewUseCase for GetPagePreviewService<R>
+where
+    R: PageRepository,
+{
+    async fn execute(&self, token: &str) -> Result<Page, GetPagePreviewError> {
+        let grant = self.tokens.verify(token, Utc::now()).map_err(|e| match e {
+            PreviewTokenError::Invalid => GetPagePreviewError::InvalidToken,
+            PreviewTokenError::Expired => GetPagePreviewError::Expired,
+        })?;
+
+        self.repository
+            .get(grant.owner_id, grant.page_id)
+            .await
+            .map_err(|e| match e {
+                PageRepositoryError::NotFound => GetPagePreviewError::NotFound,
+                other => GetPagePreviewError::RepositoryError(other.to_string()),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    use crate::pages::adapter::outgoing::InMemoryPageStore;
+    use crate::pages::application::domain::entities::PageStatus;
+    use crate::pages::application::domain::preview_token::PreviewGrant;
+    use crate::pages::application::ports::incoming::use_cases::CreatePageCommand;
+
+    const SECRET: &str = "0123456789abcdef0123456789abcdef";
+
+    fn token(page_id: Uuid, owner_id: Uuid, expires_in: Duration) -> String {
+        PreviewTokens::new(SECRET).sign(&PreviewGrant {
+            page_id,
+            owner_id,
+            expires_at: Utc::now() + expires_in,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_valid_token_serves_the_draft() {
+        let store = InMemoryPageStore::default();
+        let owner = Uuid::new_v4();
+        let command = CreatePageCommand::new(
+            "now".to_string(),
+            "Now".to_string(),
+            "Work in progress".to_string(),
+            PageStatus::Draft,
+            None,
+            None,
+        )
+        .unwrap();
+        let page = store.create(owner, &command).await.unwrap();
+        let service = GetPagePreviewService::new(store, PreviewTokens::new(SECRET));
+
+        let preview = service
+            .execute(&token(page.id, owner, Duration::hours(1)))
+            .await
+            .unwrap();
+
+        assert_eq!(preview.body, "Work in progress");
+        assert_eq!(preview.status, PageStatus::Draft);
+    }
+
+    #[tokio::test]
+    async fn test_bad_expired_and_dangling_tokens_are_refused() {
+        let service =
+            GetPagePreviewService::new(InMemoryPageStore::default(), PreviewTokens::new(SECRET));
+
+        assert!(matches!(
+            service.execute("forged").await,
+            Err(GetPagePreviewError::InvalidToken)
+        ));
+        assert!(matches!(
+            service
+                .execute(&token(Uuid::new_v4(), Uuid::new_v4(), Duration::hours(-1)))
+                .await,
+            Err(GetPagePreviewError::Expired)
+        ));
+        assert!(matches!(
+            service
+                .execute(&token(Uuid::new_v4(), Uuid::new_v4(), Duration::hours(1)))
+                .await,
+            Err(GetPagePreviewError::NotFound)
+        ));
+    }
+}
@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::pages::application::domain::entities::Page;
+use crate::pages::application::ports::{
+    incoming::use_cases::{GetPageError, GetPageUseCase},
+    outgoing::{PageRepository, PageRepositoryError},
+};
+
+pub struct GetPageService<R>
+where
+    R: PageRepository,
+{
+    repository: R,
+}
+
+impl<R> GetPageService<R>
+where
+    R: PageRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> GetPageUseCase for GetPageService<R>
+where
+    R: PageRepository,
+{
+    async fn execute(&self, owner_id: Uuid, id: Uuid) -> Result<Page, GetPageError> {
+        self.repository
+            .get(owner_id, id)
+            .await
+            .map_err(|e| match e {
+                PageRepositoryError::NotFound => GetPageError::NotFound,
+                other => GetPageError::RepositoryError(other.to_string()),
+            })
+    }
+}
@@ -0,0 +1,152 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::pages::application::cache_keys;
+use crate::pages::application::domain::entities::Page;
+use crate::pages::application::ports::{
+    incoming::use_cases::{GetPublicPageError, GetPublicPageUseCase},
+    outgoing::{PageRepository, PageRepositoryError},
+};
+use crate::shared::cache::{self, CachePort};
+
+pub struct GetPublicPageService<R>
+where
+    R: PageRepository,
+{
+    repository: R,
+    cache: Arc<dyn CachePort>,
+}
+
+impl<R> GetPublicPageService<R>
+where
+    R: PageRepository,
+{
+    pub fn new(repository: R, cache: Arc<dyn CachePort>) -> Self {
+        Self { repository, cache }
+    }
+}
+
+#[async_trait]
+impl<R> GetPublicPageUseCase for GetPublicPageService<R>
+where
+    R: PageRepository,
+{
+    async fn execute(&self, owner_id: Uuid, slug: &str) -> Result<Page, GetPublicPageError> {
+        // Misses are not cached: arbitrary slugs must not fill the cache
+        cache::read_through(
+            self.cache.as_ref(),
+            &cache_keys::public_by_slug(owner_id, slug),
+            || async {
+                let repository_error =
+                    |e: PageRepositoryError| GetPublicPageError::RepositoryError(e.to_string());
+
+                if let Some(page) = self
+                    .repository
+                    .find_published(owner_id, slug)
+                    .await
+                    .map_err(repository_error)?
+                {
+                    return Ok(page);
+                }
+
+                match self
+                    .repository
+                    .find_tombstone(owner_id, slug)
+                    .await
+                    .map_err(repository_error)?
+                {
+                    Some(deleted_at) => Err(GetPublicPageError::Gone(deleted_at)),
+                    None => Err(GetPublicPageError::NotFound),
+                }
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::adapter::outgoing::InMemoryPageStore;
+    use crate::pages::application::domain::entities::PageStatus;
+    use crate::pages::application::ports::incoming::use_cases::CreatePageCommand;
+    use crate::shared::cache::NoopCache;
+
+    #[tokio::test]
+    async fn test_drafts_are_not_public() {
+        let store = InMemoryPageStore::default();
+        let owner = Uuid::new_v4();
+        for (slug, status) in [("about", PageStatus::Published), ("now", PageStatus::Draft)] {
+            let command = CreatePageCommand::new(
+                slug.to_string(),
+                slug.to_string(),
+                String::new(),
+                status,
+                None,
+                None,
+            )
+            .unwrap();
+            store.create(owner, &command).await.unwrap();
+        }
+        let service = GetPublicPageService::new(store, Arc::new(NoopCache));
+
+        assert_eq!(service.execute(owner, "about").await.unwrap().slug, "about");
+        assert!(matches!(
+            service.execute(owner, "now").await,
+            Err(GetPublicPageError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_deleted_published_page_is_gone_until_the_slug_is_reused() {
+        let store = InMemoryPageStore::default();
+        let owner = Uuid::new_v4();
+        let command = |slug: &str, status| {
+            CreatePageCommand::new(
+                slug.to_string(),
+                slug.to_string(),
+                String::new(),
+                status,
+                None,
+                None,
+            )
+            .unwrap()
+        };
+        let about = store
+            .create(owner, &command("about", PageStatus::Published))
+            .await
+            .unwrap();
+        let now = store
+            .create(owner, &command("now", PageStatus::Draft))
+            .await
+            .unwrap();
+        store.delete(owner, about.id).await.unwrap();
+        store.delete(owner, now.id).await.unwrap();
+        let service = GetPublicPageService::new(store.clone(), Arc::new(NoopCache));
+
+        assert!(matches!(
+            service.execute(owner, "about").await,
+            Err(GetPublicPageError::Gone(_))
+        ));
+        // Never published, so never public
+        assert!(matches!(
+            service.execute(owner, "now").await,
+            Err(GetPublicPageError::NotFound)
+        ));
+        // Someone else's slug
+        assert!(matches!(
+            service.execute(Uuid::new_v4(), "about").await,
+            Err(GetPublicPageError::NotFound)
+        ));
+
+        store
+            .create(owner, &command("about", PageStatus::Draft))
+            .await
+            .unwrap();
+        assert!(matches!(
+            service.execute(owner, "about").await,
+            Err(GetPublicPageError::NotFound)
+        ));
+    }
+}
@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::pages::application::domain::entities::Page;
+use crate::pages::application::ports::{
+    incoming::use_cases::{ListPagesError, ListPagesUseCase},
+    outgoing::PageRepository,
+};
+
+pub struct ListPagesService<R>
+where
+    R: PageRepository,
+{
+    repository: R,
+}
+
+impl<R> ListPagesService<R>
+where
+    R: PageRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> ListPagesUseCase for ListPagesService<R>
+where
+    R: PageRepository,
+{
+    async fn execute(&self, owner_id: Uuid) -> Result<Vec<Page>, ListPagesError> {
+        self.repository
+            .list(owner_id)
+            .await
+            .map_err(|e| ListPagesError::RepositoryError(e.to_string()))
+    }
+}
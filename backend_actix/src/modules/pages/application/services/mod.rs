@@ -0,0 +1,29 @@
+mod acquire_page_lock_service;
+mod autosave_buffer;
+mod autosave_flusher;
+mod autosave_page_service;
+mod create_page_service;
+mod create_preview_token_service;
+mod delete_page_service;
+mod get_page_autosave_service;
+mod get_page_preview_service;
+mod get_page_service;
+mod get_public_page_service;
+mod list_pages_service;
+mod release_page_lock_service;
+mod update_page_service;
+
+pub use acquire_page_lock_service::AcquirePageLockService;
+pub use autosave_buffer::{AutosaveBuffer, PendingAutosave};
+pub use autosave_flusher::AutosaveFlusher;
+pub use autosave_page_service::AutosavePageService;
+pub use create_page_service::CreatePageService;
+pub use create_preview_token_service::CreatePreviewTokenService;
+pub use delete_page_service::DeletePageService;
+pub use get_page_autosave_service::GetPageAutosaveService;
+pub use get_page_preview_service::GetPagePreviewService;
+pub use get_page_service::GetPageService;
+pub use get_public_page_service::GetPublicPageService;
+pub use list_pages_service::ListPagesService;
+pub use release_page_lock_service::ReleasePageLockService;
+pub use update_page_service::UpdatePageService;
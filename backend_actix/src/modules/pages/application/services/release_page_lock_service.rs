@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::pages::application::ports::{
+    incoming::use_cases::{ReleasePageLockError, ReleasePageLockUseCase},
+    outgoing::{PageRepository, PageRepositoryError},
+};
+
+pub struct ReleasePageLockService<R>
+where
+    R: PageRepository,
+{
+    repository: R,
+}
+
+impl<R> ReleasePageLockService<R>
+where
+    R: PageRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> ReleasePageLockUseCase for ReleasePageLockService<R>
+where
+    R: PageRepository,
+{
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        page_id: Uuid,
+        lock_id: Uuid,
+    ) -> Result<(), ReleasePageLockError> {
+        self.repository
+            .release_lock(owner_id, page_id, lock_id)
+            .await
+            .map_err(|e| match e {
+                PageRepositoryError::NotFound => ReleasePageLockError::NotFound,
+                other => ReleasePageLockError::RepositoryError(other.to_string()),
+            })
+    }
+}
@@ -0,0 +1,253 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::pages::application::cache_keys;
+use crate::pages::application::domain::entities::{Page, PageStatus};
+use crate::pages::application::ports::{
+    incoming::use_cases::{UpdatePageCommand, UpdatePageError, UpdatePageUseCase},
+    outgoing::{PageRepository, PageRepositoryError},
+};
+use crate::shared::cache::{self, CachePort};
+use crate::shared::telemetry::{self, BusinessEvent};
+
+pub struct UpdatePageService<R>
+where
+    R: PageRepository,
+{
+    repository: R,
+    cache: Arc<dyn CachePort>,
+}
+
+impl<R> UpdatePageService<R>
+where
+    R: PageRepository,
+{
+    pub fn new(repository: R, cache: Arc<dyn CachePort>) -> Self {
+        Self { repository, cache }
+    }
+}
+
+#[async_trait]
+impl<R> UpdatePageUseCase for UpdatePageService<R>
+where
+    R: PageRepository,
+{
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        id: Uuid,
+        command: UpdatePageCommand,
+    ) -> Result<Page, UpdatePageError> {
+        let map_err = |e| match e {
+            PageRepositoryError::NotFound => UpdatePageError::NotFound,
+            PageRepositoryError::Stale => UpdatePageError::Stale,
+            PageRepositoryError::Locked(holder) => UpdatePageError::Locked(holder),
+            other => UpdatePageError::RepositoryError(other.to_string()),
+        };
+
+        // An empty patch must not bump `updated_at`
+        if command.is_empty() {
+            return self.repository.get(owner_id, id).await.map_err(map_err);
+        }
+
+        // Advisory: an edit racing the lock's creation still goes through
+        if let Some(holder) = self
+            .repository
+            .find_lock(owner_id, id)
+            .await
+            .map_err(map_err)?
+        {
+            if !holder.admits(command.lock_id()) {
+                return Err(UpdatePageError::Locked(holder));
+            }
+        }
+
+        let page = self
+            .repository
+            .update(owner_id, id, &command)
+            .await
+            .map_err(map_err)?;
+
+        cache::invalidate_prefix(self.cache.as_ref(), &cache_keys::owner_prefix(owner_id)).await;
+
+        if command.status() == Some(PageStatus::Published) {
+            telemetry::record(BusinessEvent::PagePublished {
+                user_id: owner_id,
+                page_id: page.id,
+            });
+        }
+
+        Ok(page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pages::adapter::outgoing::InMemoryPageStore;
+    use crate::pages::application::domain::edit_lock::EditLock;
+    use crate::pages::application::ports::incoming::use_cases::CreatePageCommand;
+    use crate::project::application::ports::outgoing::project_repository::PatchField;
+    use crate::shared::cache::NoopCache;
+
+    fn status(status: PageStatus) -> UpdatePageCommand {
+        UpdatePageCommand::new(
+            PatchField::Unset,
+            PatchField::Unset,
+            PatchField::Value(status),
+            PatchField::Unset,
+            PatchField::Unset,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_first_publication_date_is_kept() {
+        let store = InMemoryPageStore::default();
+        let owner = Uuid::new_v4();
+        let page = store
+            .create(
+                owner,
+                &CreatePageCommand::new(
+                    "now".to_string(),
+                    "Now".to_string(),
+                    String::new(),
+                    PageStatus::Draft,
+                    None,
+                    None,
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(page.published_at, None);
+        let service = UpdatePageService::new(store, Arc::new(NoopCache));
+
+        let published = service
+            .execute(owner, page.id, status(PageStatus::Published))
+            .await
+            .unwrap();
+        service
+            .execute(owner, page.id, status(PageStatus::Draft))
+            .await
+            .unwrap();
+        let republished = service
+            .execute(owner, page.id, status(PageStatus::Published))
+            .await
+            .unwrap();
+
+        assert!(published.published_at.is_some());
+        assert_eq!(republished.published_at, published.published_at);
+    }
+
+    #[tokio::test]
+    async fn test_other_owners_page_is_not_found() {
+        let store = InMemoryPageStore::default();
+        let page = store
+            .create(
+                Uuid::new_v4(),
+                &CreatePageCommand::new(
+                    "uses".to_string(),
+                    "Uses".to_string(),
+                    String::new(),
+                    PageStatus::Draft,
+                    None,
+                    None,
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let service = UpdatePageService::new(store, Arc::new(NoopCache));
+
+        let result = service
+            .execute(Uuid::new_v4(), page.id, status(PageStatus::Published))
+            .await;
+
+        assert!(matches!(result, Err(UpdatePageError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_write_against_an_old_version_is_stale() {
+        let store = InMemoryPageStore::default();
+        let owner = Uuid::new_v4();
+        let page = store
+            .create(
+                owner,
+                &CreatePageCommand::new(
+                    "about".to_string(),
+                    "About".to_string(),
+                    String::new(),
+                    PageStatus::Draft,
+                    None,
+                    None,
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let service = UpdatePageService::new(store, Arc::new(NoopCache));
+
+        // Published from another device after `page` was read
+        let published = service
+            .execute(owner, page.id, status(PageStatus::Published))
+            .await
+            .unwrap();
+        let stale = service
+            .execute(
+                owner,
+                page.id,
+                status(PageStatus::Draft).with_expected_updated_at(Some(page.updated_at)),
+            )
+            .await;
+        let current = service
+            .execute(
+                owner,
+                page.id,
+                status(PageStatus::Draft).with_expected_updated_at(Some(published.updated_at)),
+            )
+            .await;
+
+        assert!(matches!(stale, Err(UpdatePageError::Stale)));
+        assert_eq!(current.unwrap().status, PageStatus::Draft);
+    }
+
+    #[tokio::test]
+    async fn test_only_the_lock_holder_may_edit() {
+        let store = InMemoryPageStore::default();
+        let owner = Uuid::new_v4();
+        let page = store
+            .create(
+                owner,
+                &CreatePageCommand::new(
+                    "about".to_string(),
+                    "About".to_string(),
+                    String::new(),
+                    PageStatus::Draft,
+                    None,
+                    None,
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let lock = EditLock::lease(page.id, Uuid::new_v4(), None, chrono::Utc::now());
+        store.acquire_lock(owner, &lock).await.unwrap();
+        let service = UpdatePageService::new(store, Arc::new(NoopCache));
+
+        let other_tab = service
+            .execute(owner, page.id, status(PageStatus::Published))
+            .await;
+        let holder = service
+            .execute(
+                owner,
+                page.id,
+                status(PageStatus::Published).with_lock(Some(lock.lock_id)),
+            )
+            .await;
+
+        assert!(matches!(other_tab, Err(UpdatePageError::Locked(l)) if l == lock));
+        assert!(holder.unwrap().is_published());
+    }
+}
@@ -0,0 +1,13 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    PROJECT_NOT_FOUND = (NOT_FOUND, "Project not found");
+    PROJECT_GONE = (GONE, "This project has been deleted");
+    PROJECT_UPDATE_NOT_FOUND = (NOT_FOUND, "Project update not found");
+    NO_REPO_URL = (UNPROCESSABLE_ENTITY, "Project has no repository URL");
+    UNSUPPORTED_REPOSITORY = (UNPROCESSABLE_ENTITY, "Only public GitHub repositories are supported");
+    README_NOT_FOUND = (UNPROCESSABLE_ENTITY, "Repository has no README");
+    REPO_UNAVAILABLE = (BAD_GATEWAY, "Repository host unavailable");
+    OEMBED_URL_NOT_SUPPORTED = (NOT_FOUND, "No embed for this URL; expected a public project URL");
+    OEMBED_FORMAT_NOT_SUPPORTED = (NOT_IMPLEMENTED, "Only the json oEmbed format is served");
+}
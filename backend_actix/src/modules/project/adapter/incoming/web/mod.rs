@@ -1 +1,30 @@
+use actix_web::web;
+
+pub mod error_codes;
 pub mod routes;
+
+/// Routes anyone can call
+pub fn configure_public(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::get_public_projects_handler)
+        .service(routes::get_public_single_project_handler)
+        .service(routes::oembed_handler)
+        .service(routes::embed_project_handler);
+}
+
+/// Routes for signed-in users with a verified email
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::get_projects_handler)
+        .service(routes::create_project_handler)
+        .service(routes::hard_delete_project_handler)
+        .service(routes::sync_project_readme_handler)
+        .service(routes::get_project_by_id_handler)
+        .service(routes::patch_project_handler)
+        .service(routes::soft_delete_project_handler)
+        .service(routes::create_project_update_handler)
+        .service(routes::patch_project_update_handler)
+        .service(routes::delete_project_update_handler)
+        .service(routes::add_project_topic_handler)
+        .service(routes::get_project_topics_handler)
+        .service(routes::remove_project_topic_handler)
+        .service(routes::clear_project_topics_handler);
+}
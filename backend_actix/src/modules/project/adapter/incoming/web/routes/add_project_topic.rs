@@ -1,8 +1,12 @@
 use actix_web::{post, web, Responder};
 use serde::Deserialize;
 use tracing::error;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::api::schemas::ErrorResponse;
+use crate::project::adapter::incoming::web::error_codes::PROJECT_NOT_FOUND;
+use crate::topic::adapter::incoming::web::error_codes::TOPIC_NOT_FOUND;
 use crate::{
     auth::adapter::incoming::web::extractors::auth::VerifiedUser,
     auth::application::domain::entities::UserId,
@@ -10,11 +14,31 @@ use crate::{
     shared::api::ApiResponse, AppState,
 };
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct AddProjectTopicRequest {
     pub topic_id: Uuid,
 }
 
+/// Attach a topic to a project
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/topics",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+    ),
+    request_body = AddProjectTopicRequest,
+    responses(
+        (status = 200, description = "Topic attached"),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Project or topic not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[post("/api/projects/{project_id}/topics")]
 pub async fn add_project_topic_handler(
     user: VerifiedUser,
@@ -34,13 +58,9 @@ pub async fn add_project_topic_handler(
     {
         Ok(_) => ApiResponse::success(serde_json::json!({ "message": "OK" })),
 
-        Err(AddProjectTopicError::ProjectNotFound) => {
-            ApiResponse::not_found("PROJECT_NOT_FOUND", "Project not found")
-        }
+        Err(AddProjectTopicError::ProjectNotFound) => PROJECT_NOT_FOUND.response(),
 
-        Err(AddProjectTopicError::TopicNotFound) => {
-            ApiResponse::not_found("TOPIC_NOT_FOUND", "Topic not found")
-        }
+        Err(AddProjectTopicError::TopicNotFound) => TOPIC_NOT_FOUND.response(),
 
         Err(AddProjectTopicError::RepositoryError(msg)) => {
             error!("Failed to add topic to project: {}", msg);
@@ -105,16 +125,18 @@ mod tests {
     fn jwt_service() -> JwtTokenService {
         JwtTokenService::new(JwtConfig {
             issuer: "Lotion".to_string(),
+            audience: "test_audience".to_string(),
             secret_key: "test_secret_key_for_testing_purposes_only".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         })
     }
 
     fn token(user_id: Uuid, verified: bool) -> String {
         jwt_service()
-            .generate_access_token(user_id, verified)
+            .generate_access_token(user_id, verified, 0)
             .unwrap()
     }
 
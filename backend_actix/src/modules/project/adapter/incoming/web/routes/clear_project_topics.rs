@@ -2,6 +2,8 @@ use actix_web::{delete, web, Responder};
 use tracing::error;
 use uuid::Uuid;
 
+use crate::api::schemas::ErrorResponse;
+use crate::project::adapter::incoming::web::error_codes::PROJECT_NOT_FOUND;
 use crate::{
     auth::adapter::incoming::web::extractors::auth::VerifiedUser,
     auth::application::domain::entities::UserId,
@@ -9,6 +11,25 @@ use crate::{
     shared::api::ApiResponse, AppState,
 };
 
+/// Detach every topic from a project
+#[utoipa::path(
+    delete,
+    path = "/api/projects/{project_id}/topics/all",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+    ),
+    responses(
+        (status = 204, description = "Topics cleared"),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Project not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[delete("/api/projects/{project_id}/topics/all")]
 pub async fn clear_project_topics_handler(
     user: VerifiedUser,
@@ -21,9 +42,7 @@ pub async fn clear_project_topics_handler(
     match data.project.clear_topics.execute(owner, project_id).await {
         Ok(_) => ApiResponse::no_content(),
 
-        Err(ClearProjectTopicsError::ProjectNotFound) => {
-            ApiResponse::not_found("PROJECT_NOT_FOUND", "Project not found")
-        }
+        Err(ClearProjectTopicsError::ProjectNotFound) => PROJECT_NOT_FOUND.response(),
 
         Err(ClearProjectTopicsError::RepositoryError(msg)) => {
             error!("Failed to clear project topics: {}", msg);
@@ -85,16 +104,18 @@ mod tests {
     fn jwt_service() -> JwtTokenService {
         JwtTokenService::new(JwtConfig {
             issuer: "Lotion".to_string(),
+            audience: "test_audience".to_string(),
             secret_key: "test_secret_key_for_testing_purposes_only".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         })
     }
 
     fn token(user_id: Uuid, verified: bool) -> String {
         jwt_service()
-            .generate_access_token(user_id, verified)
+            .generate_access_token(user_id, verified, 0)
             .unwrap()
     }
 
@@ -1,11 +1,16 @@
 use actix_web::{post, web, Responder};
 use serde::{Deserialize, Serialize};
 use tracing::error;
+use utoipa::ToSchema;
 
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
 use crate::auth::adapter::incoming::web::extractors::auth::VerifiedUser;
 use crate::auth::application::domain::entities::UserId;
 use crate::modules::project::application::ports::incoming::use_cases::CreateProjectError;
-use crate::modules::project::application::ports::outgoing::project_repository::CreateProjectData;
+use crate::modules::project::application::ports::outgoing::project_repository::{
+    CreateProjectData, ProjectResult,
+};
+use crate::shared::api::error_codes::SLUG_ALREADY_EXISTS;
 use crate::shared::api::ApiResponse;
 use crate::AppState;
 
@@ -15,15 +20,26 @@ use crate::AppState;
 // ──────────────────────────────────────────────────────────
 //
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct CreateProjectRequest {
     pub title: String,
     pub slug: String,
     pub description: String,
     pub tech_stack: Vec<String>,
+    /// Deprecated: attach screenshots as project media with role `screenshoot`
+    #[schema(deprecated)]
     pub screenshots: Vec<String>,
     pub repo_url: Option<String>,
     pub live_demo_url: Option<String>,
+    /// Up to 100 characters
+    pub seo_title: Option<String>,
+    /// Up to 300 characters
+    pub seo_description: Option<String>,
+    /// Absolute http(s) URL of the original, when this project is a copy
+    pub canonical_url: Option<String>,
+    /// Ask search engines not to index the project
+    #[serde(default)]
+    pub noindex: bool,
 }
 
 //
@@ -32,6 +48,24 @@ pub struct CreateProjectRequest {
 // ──────────────────────────────────────────────────────────
 //
 
+/// Create a project
+#[utoipa::path(
+    post,
+    path = "/api/projects",
+    tag = "projects",
+    request_body = CreateProjectRequest,
+    responses(
+        (status = 201, description = "Project created", body = inline(SuccessResponse<ProjectResult>)),
+        (status = 400, description = "Invalid SEO field or malformed request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 409, description = "Slug already in use", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[post("/api/projects")]
 pub async fn create_project_handler(
     user: VerifiedUser,
@@ -49,13 +83,19 @@ pub async fn create_project_handler(
         screenshots: req.screenshots,
         repo_url: req.repo_url,
         live_demo_url: req.live_demo_url,
+        seo_title: req.seo_title,
+        seo_description: req.seo_description,
+        canonical_url: req.canonical_url,
+        noindex: req.noindex,
     };
 
     match data.project.create.execute(project_data).await {
         Ok(created) => ApiResponse::created(created),
 
+        Err(CreateProjectError::Invalid(errors)) => ApiResponse::validation_failed(&errors),
+
         Err(CreateProjectError::SlugAlreadyExists) => {
-            ApiResponse::conflict("SLUG_ALREADY_EXISTS", "Project slug already exists")
+            SLUG_ALREADY_EXISTS.with_message("Project slug already exists")
         }
 
         Err(CreateProjectError::RepositoryError(e)) => {
@@ -87,6 +127,7 @@ mod tests {
         CreateProjectData, ProjectResult,
     };
 
+    use crate::shared::validation::ValidationErrors;
     use crate::tests::support::app_state_builder::TestAppStateBuilder;
 
     /* --------------------------------------------------
@@ -125,16 +166,18 @@ mod tests {
     fn jwt_service() -> JwtTokenService {
         JwtTokenService::new(JwtConfig {
             issuer: "Lotion".to_string(),
+            audience: "test_audience".to_string(),
             secret_key: "test_secret_key_for_testing_purposes_only".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         })
     }
 
     fn token(user_id: Uuid, verified: bool) -> String {
         jwt_service()
-            .generate_access_token(user_id, verified)
+            .generate_access_token(user_id, verified, 0)
             .unwrap()
     }
 
@@ -147,6 +190,10 @@ mod tests {
             screenshots: vec!["img.png".to_string()],
             repo_url: Some("https://github.com/x/y".to_string()),
             live_demo_url: None,
+            seo_title: None,
+            seo_description: None,
+            canonical_url: None,
+            noindex: false,
         }
     }
 
@@ -161,6 +208,10 @@ mod tests {
             screenshots: vec!["img.png".to_string()],
             repo_url: Some("https://github.com/x/y".to_string()),
             live_demo_url: None,
+            seo_title: None,
+            seo_description: None,
+            canonical_url: None,
+            noindex: false,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -247,6 +298,44 @@ mod tests {
         assert_eq!(body["error"]["code"], "SLUG_ALREADY_EXISTS");
     }
 
+    #[actix_web::test]
+    async fn test_create_project_invalid_seo_field_bad_request() {
+        let user_id = Uuid::new_v4();
+
+        let app_state = TestAppStateBuilder::default()
+            .with_create_project_use_case(MockCreateProjectUseCase::error(
+                CreateProjectError::Invalid(ValidationErrors::single(
+                    "canonical_url",
+                    "INVALID_CANONICAL_URL",
+                    "Canonical URL must be an absolute http(s) URL",
+                )),
+            ))
+            .build();
+
+        let jwt = jwt_service();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .app_data(web::Data::new(token_provider))
+                .service(create_project_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/projects")
+            .insert_header(("Authorization", format!("Bearer {}", token(user_id, true))))
+            .set_json(&base_create_request())
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "INVALID_CANONICAL_URL");
+    }
+
     #[actix_web::test]
     async fn test_create_project_repository_error_internal_error() {
         let user_id = Uuid::new_v4();
@@ -0,0 +1,217 @@
+use actix_web::{post, web, Responder};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use tracing::error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::auth::adapter::incoming::web::extractors::auth::VerifiedUser;
+use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::ports::incoming::use_cases::CreateProjectUpdateError;
+use crate::modules::project::application::ports::outgoing::project_update_repository::{
+    CreateProjectUpdateData, ProjectUpdate,
+};
+use crate::project::adapter::incoming::web::error_codes::PROJECT_NOT_FOUND;
+use crate::shared::api::ApiResponse;
+use crate::AppState;
+
+//
+// ──────────────────────────────────────────────────────────
+// Request DTO
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateProjectUpdateRequest {
+    /// Up to 200 characters
+    pub title: String,
+
+    /// Markdown, up to 20000 characters
+    pub body: String,
+
+    /// Defaults to now; set it to backdate an entry
+    #[serde(default)]
+    pub posted_at: Option<DateTime<Utc>>,
+}
+
+impl From<CreateProjectUpdateRequest> for CreateProjectUpdateData {
+    fn from(req: CreateProjectUpdateRequest) -> Self {
+        CreateProjectUpdateData {
+            title: req.title,
+            body: req.body,
+            posted_at: req.posted_at,
+        }
+    }
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Handler
+// ──────────────────────────────────────────────────────────
+//
+
+/// Add a timeline entry to a project
+///
+/// The entry shows up in the project's `updates`, newest first, both in
+/// the owner's and the public project response.
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/updates",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+    ),
+    request_body = CreateProjectUpdateRequest,
+    responses(
+        (status = 201, description = "Entry added", body = inline(SuccessResponse<ProjectUpdate>)),
+        (status = 400, description = "Invalid title or body, or malformed request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Project not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/projects/{project_id}/updates")]
+pub async fn create_project_update_handler(
+    user: VerifiedUser,
+    path: web::Path<Uuid>,
+    req: web::Json<CreateProjectUpdateRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let owner = UserId::from(user.user_id);
+    let project_id = path.into_inner();
+
+    match data
+        .project
+        .create_update
+        .execute(owner, project_id, req.into_inner().into())
+        .await
+    {
+        Ok(update) => ApiResponse::created(update),
+
+        Err(CreateProjectUpdateError::Invalid(errors)) => ApiResponse::validation_failed(&errors),
+
+        Err(CreateProjectUpdateError::ProjectNotFound) => PROJECT_NOT_FOUND.response(),
+
+        Err(CreateProjectUpdateError::RepositoryError(msg)) => {
+            error!("Failed to add update to project {}: {}", project_id, msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use async_trait::async_trait;
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+
+    use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+    use crate::modules::project::application::ports::incoming::use_cases::CreateProjectUpdateUseCase;
+    use crate::shared::validation::ValidationErrors;
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, auth_helper::test_helpers::create_test_jwt_service,
+    };
+
+    #[derive(Clone)]
+    struct MockCreateProjectUpdateUseCase {
+        error: Option<CreateProjectUpdateError>,
+    }
+
+    #[async_trait]
+    impl CreateProjectUpdateUseCase for MockCreateProjectUpdateUseCase {
+        async fn execute(
+            &self,
+            _owner: UserId,
+            _project_id: Uuid,
+            data: CreateProjectUpdateData,
+        ) -> Result<ProjectUpdate, CreateProjectUpdateError> {
+            if let Some(err) = &self.error {
+                return Err(err.clone());
+            }
+            let now = Utc::now();
+            Ok(ProjectUpdate {
+                id: Uuid::new_v4(),
+                title: data.title,
+                body: data.body,
+                posted_at: data.posted_at.unwrap_or(now),
+                created_at: now,
+                updated_at: now,
+            })
+        }
+    }
+
+    async fn call(
+        error: Option<CreateProjectUpdateError>,
+        body: Value,
+    ) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_create_project_update(MockCreateProjectUpdateUseCase { error })
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(create_project_update_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/projects/{}/updates", Uuid::new_v4()))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(body)
+            .to_request();
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_create_project_update_created() {
+        let resp = call(
+            None,
+            json!({
+                "title": "v1.0",
+                "body": "First release",
+                "posted_at": "2026-01-02T03:04:05Z",
+            }),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["title"], "v1.0");
+        assert_eq!(body["data"]["posted_at"], "2026-01-02T03:04:05Z");
+    }
+
+    #[actix_web::test]
+    async fn test_create_project_update_errors() {
+        let invalid = call(
+            Some(CreateProjectUpdateError::Invalid(ValidationErrors::single(
+                "title",
+                "INVALID_UPDATE_TITLE",
+                "Title is required",
+            ))),
+            json!({ "title": "", "body": "x" }),
+        )
+        .await;
+        assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+        let body: Value = test::read_body_json(invalid).await;
+        assert_eq!(body["error"]["code"], "INVALID_UPDATE_TITLE");
+
+        let missing = call(
+            Some(CreateProjectUpdateError::ProjectNotFound),
+            json!({ "title": "v1.0", "body": "x" }),
+        )
+        .await;
+        assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,135 @@
+use actix_web::{delete, web, Responder};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::api::schemas::ErrorResponse;
+use crate::auth::adapter::incoming::web::extractors::auth::VerifiedUser;
+use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::ports::incoming::use_cases::DeleteProjectUpdateError;
+use crate::project::adapter::incoming::web::error_codes::{
+    PROJECT_NOT_FOUND, PROJECT_UPDATE_NOT_FOUND,
+};
+use crate::shared::api::ApiResponse;
+use crate::AppState;
+
+/// Delete a project timeline entry
+#[utoipa::path(
+    delete,
+    path = "/api/projects/{project_id}/updates/{update_id}",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("update_id" = Uuid, Path, description = "Timeline entry id"),
+    ),
+    responses(
+        (status = 204, description = "Entry deleted"),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Project or entry not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[delete("/api/projects/{project_id}/updates/{update_id}")]
+pub async fn delete_project_update_handler(
+    user: VerifiedUser,
+    path: web::Path<(Uuid, Uuid)>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let owner = UserId::from(user.user_id);
+    let (project_id, update_id) = path.into_inner();
+
+    match data
+        .project
+        .delete_update
+        .execute(owner, project_id, update_id)
+        .await
+    {
+        Ok(_) => ApiResponse::no_content(),
+
+        Err(DeleteProjectUpdateError::ProjectNotFound) => PROJECT_NOT_FOUND.response(),
+
+        Err(DeleteProjectUpdateError::UpdateNotFound) => PROJECT_UPDATE_NOT_FOUND.response(),
+
+        Err(DeleteProjectUpdateError::RepositoryError(msg)) => {
+            error!("Failed to delete update {}: {}", update_id, msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use async_trait::async_trait;
+    use std::sync::Arc;
+
+    use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+    use crate::modules::project::application::ports::incoming::use_cases::DeleteProjectUpdateUseCase;
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, auth_helper::test_helpers::create_test_jwt_service,
+    };
+
+    #[derive(Clone)]
+    struct MockDeleteProjectUpdateUseCase {
+        result: Result<(), DeleteProjectUpdateError>,
+    }
+
+    #[async_trait]
+    impl DeleteProjectUpdateUseCase for MockDeleteProjectUpdateUseCase {
+        async fn execute(
+            &self,
+            _owner: UserId,
+            _project_id: Uuid,
+            _update_id: Uuid,
+        ) -> Result<(), DeleteProjectUpdateError> {
+            self.result.clone()
+        }
+    }
+
+    async fn call(result: Result<(), DeleteProjectUpdateError>) -> StatusCode {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_delete_project_update(MockDeleteProjectUpdateUseCase { result })
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(delete_project_update_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::delete()
+            .uri(&format!(
+                "/api/projects/{}/updates/{}",
+                Uuid::new_v4(),
+                Uuid::new_v4()
+            ))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+        test::call_service(&app, req).await.status()
+    }
+
+    #[actix_web::test]
+    async fn test_delete_project_update() {
+        assert_eq!(call(Ok(())).await, StatusCode::NO_CONTENT);
+        assert_eq!(
+            call(Err(DeleteProjectUpdateError::ProjectNotFound)).await,
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            call(Err(DeleteProjectUpdateError::RepositoryError(
+                "db down".to_string()
+            )))
+            .await,
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
@@ -0,0 +1,376 @@
+use actix_web::{
+    get, http::header::CONTENT_SECURITY_POLICY, web, HttpRequest, HttpResponse, Responder,
+};
+use handlebars::html_escape;
+use tracing::{error, warn};
+
+use super::get_public_single_project::PublicProjectPath;
+use super::oembed::oembed_url;
+use crate::api::schemas::ErrorResponse;
+use crate::project::adapter::incoming::web::error_codes::{PROJECT_GONE, PROJECT_NOT_FOUND};
+use crate::{
+    auth::{
+        adapter::incoming::web::extractors::auth::resolve_owner_id_or_response,
+        application::domain::entities::UserId,
+    },
+    modules::project::application::ports::{
+        incoming::use_cases::GetPublicSingleProjectError, outgoing::project_query::ProjectView,
+    },
+    multimedia::application::{
+        domain::entities::AttachmentTarget,
+        ports::incoming::use_cases::{GetPreviewImageCommand, PreviewImage},
+    },
+    shared::{
+        api::{
+            cache::{CachePolicy, PUBLIC_MAX_AGE_SECS},
+            ApiResponse,
+        },
+        seo,
+    },
+    AppState,
+};
+
+/// Longest summary a card shows, in characters
+const MAX_SUMMARY_CHARS: usize = 200;
+
+/// Inline styles only, images from anywhere (signed URLs), no scripts
+const EMBED_CSP: &str = "default-src 'none'; img-src https: data:; style-src 'unsafe-inline'";
+
+/// What a card shows of a project
+pub(super) struct ProjectEmbed {
+    pub username: String,
+    pub project: ProjectView,
+    pub image: Option<PreviewImage>,
+}
+
+impl ProjectEmbed {
+    /// The SEO description if the owner wrote one, else the description, on
+    /// one line and cut to [`MAX_SUMMARY_CHARS`]
+    pub fn summary(&self) -> String {
+        let text = self
+            .project
+            .seo_description
+            .as_deref()
+            .unwrap_or(&self.project.description);
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.chars().count() <= MAX_SUMMARY_CHARS {
+            return text;
+        }
+
+        let cut: String = text.chars().take(MAX_SUMMARY_CHARS - 1).collect();
+        format!("{}…", cut.trim_end())
+    }
+}
+
+/// The public project of `username` with its preview image, or the response
+/// to answer instead. A preview image that can't be signed leaves the card
+/// without one.
+pub(super) async fn load_embed(
+    data: &web::Data<AppState>,
+    username: &str,
+    project_slug: &str,
+) -> Result<ProjectEmbed, HttpResponse> {
+    let owner_id = resolve_owner_id_or_response(data, username).await?;
+    let owner = UserId::from(owner_id);
+
+    let project = match data
+        .project
+        .get_public_single
+        .execute(owner, project_slug)
+        .await
+    {
+        Ok(project) => project,
+        Err(GetPublicSingleProjectError::NotFound) => return Err(PROJECT_NOT_FOUND.response()),
+        Err(GetPublicSingleProjectError::Gone(_)) => return Err(PROJECT_GONE.response()),
+        Err(GetPublicSingleProjectError::RepositoryError(msg)) => {
+            error!(
+                "Repository error embedding project slug={} for username={}: {}",
+                project_slug, username, msg
+            );
+            return Err(ApiResponse::internal_error());
+        }
+    };
+
+    let image = data
+        .multimedia
+        .get_preview_image
+        .execute(GetPreviewImageCommand {
+            owner,
+            attachment_target: AttachmentTarget::Project,
+            attachment_target_id: project.id,
+        })
+        .await
+        .unwrap_or_else(|e| {
+            warn!(project_id = %project.id, error = %e, "No preview image for embed");
+            None
+        });
+
+    Ok(ProjectEmbed {
+        username: username.to_string(),
+        project,
+        image,
+    })
+}
+
+/// `scheme://host` the request was addressed to, behind proxies included
+pub(super) fn request_origin(req: &HttpRequest) -> String {
+    let info = req.connection_info();
+    format!("{}://{}", info.scheme(), info.host())
+}
+
+/// Where the card of `username`'s project is served
+pub(super) fn embed_url(origin: &str, username: &str, project_slug: &str) -> String {
+    format!("{origin}/embed/projects/{username}/{project_slug}")
+}
+
+fn render(embed: &ProjectEmbed, page_url: &str, oembed: &str) -> String {
+    let project = &embed.project;
+    let title = html_escape(&project.title);
+    let summary = html_escape(&embed.summary());
+
+    let mut head = format!(
+        r#"<meta property="og:type" content="website">
+<meta property="og:title" content="{title}">
+<meta property="og:description" content="{summary}">
+<meta property="og:url" content="{url}">
+<link rel="alternate" type="application/json+oembed" href="{oembed}" title="{title}">"#,
+        url = html_escape(page_url),
+        oembed = html_escape(oembed),
+    );
+    let mut figure = String::new();
+    if let Some(image) = &embed.image {
+        let url = html_escape(&image.url);
+        head.push_str(&format!(
+            "\n<meta property=\"og:image\" content=\"{url}\">\n<meta name=\"twitter:card\" content=\"summary_large_image\">"
+        ));
+        figure = format!(
+            r#"<img src="{url}" alt="{alt}" width="{width}" height="{height}">"#,
+            alt = html_escape(&image.alt_text),
+            width = image.width,
+            height = image.height,
+        );
+    }
+
+    let byline = match project.tech_stack.as_slice() {
+        [] => embed.username.clone(),
+        stack => format!("{} · {}", embed.username, stack.join(", ")),
+    };
+    let links: Vec<String> = [
+        ("Live demo", &project.live_demo_url),
+        ("Source", &project.repo_url),
+    ]
+    .into_iter()
+    .filter_map(|(label, href)| {
+        href.as_deref().map(|href| {
+            format!(
+                r#"<a href="{}" target="_blank" rel="noopener">{label}</a>"#,
+                html_escape(href)
+            )
+        })
+    })
+    .collect();
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{title}</title>
+{head}
+<style>
+body {{ margin: 0; font-family: system-ui, sans-serif; color: #1f2328; }}
+article {{ border: 1px solid #d0d7de; border-radius: 8px; overflow: hidden; }}
+img {{ display: block; width: 100%; height: auto; }}
+div {{ padding: 12px 16px; }}
+h1 {{ margin: 0 0 6px; font-size: 18px; }}
+p {{ margin: 0 0 8px; font-size: 14px; line-height: 1.4; }}
+small, a {{ font-size: 13px; color: #57606a; margin-right: 12px; }}
+</style>
+</head>
+<body>
+<article>
+{figure}
+<div>
+<h1>{title}</h1>
+<p>{summary}</p>
+<small>{byline}</small>
+{links}
+</div>
+</article>
+</body>
+</html>
+"#,
+        byline = html_escape(&byline),
+        links = links.join(" "),
+    )
+}
+
+/// Embeddable card of a public project
+///
+/// A small HTML page with the project's title, summary and preview image,
+/// for iframes and for link unfurlers reading Open Graph tags. It points to
+/// its oEmbed description. Never indexed; cached like the public API.
+#[utoipa::path(
+    get,
+    path = "/embed/projects/{username}/{project_slug}",
+    tag = "projects",
+    params(
+        ("username" = String, Path, description = "Owner's username"),
+        ("project_slug" = String, Path, description = "Project slug"),
+    ),
+    responses(
+        (status = 200, description = "The card", content_type = "text/html", body = String),
+        (status = 404, description = "User or project not found", body = ErrorResponse),
+        (status = 410, description = "The project is in the owner's trash", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+)]
+#[get("/embed/projects/{username}/{project_slug}")]
+pub async fn embed_project_handler(
+    req: HttpRequest,
+    path: web::Path<PublicProjectPath>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let embed = match load_embed(&data, &path.username, &path.project_slug).await {
+        Ok(embed) => embed,
+        Err(resp) => return resp,
+    };
+
+    let origin = request_origin(&req);
+    let page_url = embed_url(&origin, &path.username, &path.project_slug);
+    let oembed = oembed_url(&origin, &page_url);
+
+    let mut resp = HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .insert_header((CONTENT_SECURITY_POLICY, EMBED_CSP))
+        .body(render(&embed, &page_url, &oembed));
+    // Cards duplicate the project page; only that one belongs in search results
+    seo::forbid_indexing(&mut resp);
+    CachePolicy::Public {
+        max_age: PUBLIC_MAX_AGE_SECS,
+    }
+    .apply(&mut resp);
+    resp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{
+        http::{header, StatusCode},
+        test, App,
+    };
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::auth::application::helpers::UserIdentityResolver;
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder,
+        project_test_fixtures::sample_project_view,
+        stubs::{StubGetPreviewImageUseCase, StubGetPublicSingleProjectUseCase, StubUserQuery},
+    };
+
+    async fn call(
+        project: StubGetPublicSingleProjectUseCase,
+        image: StubGetPreviewImageUseCase,
+    ) -> actix_web::dev::ServiceResponse {
+        let state = TestAppStateBuilder::default()
+            .with_user_identity_resolver(UserIdentityResolver::new(Arc::new(StubUserQuery::found(
+                Uuid::new_v4(),
+            ))))
+            .with_get_public_single_project(project)
+            .with_get_preview_image(image)
+            .build();
+        let app =
+            test::init_service(App::new().app_data(state).service(embed_project_handler)).await;
+        let req = test::TestRequest::get()
+            .uri("/embed/projects/jane/cms")
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_card_shows_the_project_escaped() {
+        let mut project = sample_project_view(Uuid::new_v4().into(), "cms");
+        project.title = "<Ferris> & co".to_string();
+        let project_id = project.id;
+
+        let resp = call(
+            StubGetPublicSingleProjectUseCase::success(project),
+            StubGetPreviewImageUseCase::image(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let headers = resp.headers();
+        assert_eq!(
+            headers.get(header::CONTENT_TYPE).unwrap(),
+            "text/html; charset=utf-8"
+        );
+        assert_eq!(headers.get("x-robots-tag").unwrap(), "noindex");
+        assert!(headers.contains_key(header::CONTENT_SECURITY_POLICY));
+        let html = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+        assert!(html.contains("<h1>&lt;Ferris&gt; &amp; co</h1>"), "{html}");
+        assert!(!html.contains("<Ferris>"));
+        assert!(
+            html.contains(&format!(
+                r#"<meta property="og:image" content="https://signed.example/{project_id}/medium">"#
+            )),
+            "{html}"
+        );
+        assert!(html.contains(r#"width="800" height="450""#));
+        assert!(html.contains("jane · Rust, Svelte"));
+        assert!(
+            html.contains(
+                "oembed?url&#x3D;http%3A%2F%2Flocalhost%3A8080%2Fembed%2Fprojects%2Fjane"
+            ),
+            "{html}"
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_card_without_preview_image() {
+        let project = sample_project_view(Uuid::new_v4().into(), "cms");
+
+        let resp = call(
+            StubGetPublicSingleProjectUseCase::success(project),
+            StubGetPreviewImageUseCase::none(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let html = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+        assert!(!html.contains("og:image"));
+        assert!(!html.contains("<img"));
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_project_is_not_found() {
+        let resp = call(
+            StubGetPublicSingleProjectUseCase::not_found(),
+            StubGetPreviewImageUseCase::image(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_summary_prefers_seo_description_and_is_cut() {
+        let mut project = sample_project_view(Uuid::new_v4().into(), "cms");
+        project.description = "A\n  portfolio   CMS".to_string();
+        let mut embed = ProjectEmbed {
+            username: "jane".to_string(),
+            project,
+            image: None,
+        };
+        assert_eq!(embed.summary(), "A portfolio CMS");
+
+        embed.project.seo_description = Some("word ".repeat(100));
+        let summary = embed.summary();
+        assert_eq!(summary.chars().count(), MAX_SUMMARY_CHARS);
+        assert!(summary.ends_with("word…"));
+    }
+}
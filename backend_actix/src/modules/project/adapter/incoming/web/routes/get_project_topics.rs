@@ -2,6 +2,9 @@ use actix_web::{get, web, Responder};
 use tracing::error;
 use uuid::Uuid;
 
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::modules::project::application::ports::outgoing::project_query::ProjectTopicItem;
+use crate::project::adapter::incoming::web::error_codes::PROJECT_NOT_FOUND;
 use crate::{
     auth::adapter::incoming::web::extractors::auth::VerifiedUser,
     auth::application::domain::entities::UserId,
@@ -9,6 +12,25 @@ use crate::{
     shared::api::ApiResponse, AppState,
 };
 
+/// List the topics attached to a project
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/topics",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+    ),
+    responses(
+        (status = 200, description = "Topics of the project", body = inline(SuccessResponse<Vec<ProjectTopicItem>>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Project not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[get("/api/projects/{project_id}/topics")]
 pub async fn get_project_topics_handler(
     user: VerifiedUser,
@@ -21,9 +43,7 @@ pub async fn get_project_topics_handler(
     match data.project.get_topics.execute(owner, project_id).await {
         Ok(topics) => ApiResponse::success(topics),
 
-        Err(GetProjectTopicsError::ProjectNotFound) => {
-            ApiResponse::not_found("PROJECT_NOT_FOUND", "Project not found")
-        }
+        Err(GetProjectTopicsError::ProjectNotFound) => PROJECT_NOT_FOUND.response(),
 
         Err(GetProjectTopicsError::QueryFailed(msg)) => {
             error!("Failed to get project topics: {}", msg);
@@ -87,16 +107,18 @@ mod tests {
     fn jwt_service() -> JwtTokenService {
         JwtTokenService::new(JwtConfig {
             issuer: "Lotion".to_string(),
+            audience: "test_audience".to_string(),
             secret_key: "test_secret_key_for_testing_purposes_only".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         })
     }
 
     fn token(user_id: Uuid, verified: bool) -> String {
         jwt_service()
-            .generate_access_token(user_id, verified)
+            .generate_access_token(user_id, verified, 0)
             .unwrap()
     }
 
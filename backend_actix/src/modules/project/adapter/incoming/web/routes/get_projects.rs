@@ -1,14 +1,18 @@
-use actix_web::{get, web, Responder};
+use actix_web::{get, web, HttpRequest, Responder};
 use serde::Deserialize;
 use tracing::error;
 
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
 use crate::auth::adapter::incoming::web::extractors::auth::VerifiedUser;
 use crate::auth::application::domain::entities::UserId;
 use crate::modules::project::application::ports::incoming::use_cases::GetProjectsError;
 use crate::modules::project::application::ports::outgoing::project_query::{
-    PageRequest, ProjectListFilter, ProjectSort,
+    PageRequest, ProjectCardView, ProjectListFilter, ProjectSort,
+};
+use crate::shared::api::{
+    pagination::{PageParams, PagedResponse},
+    ApiResponse,
 };
-use crate::shared::api::ApiResponse;
 use crate::AppState;
 
 //
@@ -21,30 +25,23 @@ use crate::AppState;
 pub struct GetProjectsQuery {
     pub search: Option<String>,
     pub topic_id: Option<uuid::Uuid>,
-
-    #[serde(default)]
-    pub sort: ProjectSort,
-
-    #[serde(default)]
-    pub page: u32,
-
-    #[serde(default)]
-    pub per_page: u32,
 }
 
-impl From<GetProjectsQuery> for (ProjectListFilter, PageRequest, ProjectSort) {
+impl From<GetProjectsQuery> for ProjectListFilter {
     fn from(q: GetProjectsQuery) -> Self {
-        let filter = ProjectListFilter {
+        ProjectListFilter {
             search: q.search,
             topic_id: q.topic_id,
-        };
-
-        let page = PageRequest {
-            page: if q.page == 0 { 1 } else { q.page },
-            per_page: if q.per_page == 0 { 10 } else { q.per_page },
-        };
+        }
+    }
+}
 
-        (filter, page, q.sort)
+impl From<&PageParams<ProjectSort>> for PageRequest {
+    fn from(p: &PageParams<ProjectSort>) -> Self {
+        PageRequest {
+            offset: p.offset,
+            limit: p.limit,
+        }
     }
 }
 
@@ -54,22 +51,48 @@ impl From<GetProjectsQuery> for (ProjectListFilter, PageRequest, ProjectSort) {
 // ──────────────────────────────────────────────────────────
 //
 
+/// List the current user's projects
+#[utoipa::path(
+    get,
+    path = "/api/projects",
+    tag = "projects",
+    params(
+        ("search" = Option<String>, Query, description = "Match against title"),
+        ("topic_id" = Option<uuid::Uuid>, Query, description = "Only projects tagged with this topic"),
+        ("limit" = Option<u32>, Query, description = "Page size, 1-100 (default 20)"),
+        ("cursor" = Option<String>, Query, description = "`next_cursor` from the previous page"),
+        ("sort" = Option<String>, Query, description = "`Newest`, `Oldest`, `UpdatedNewest` (default) or `UpdatedOldest`"),
+    ),
+    responses(
+        (status = 200, description = "One page of projects (paging also in `X-Total-Count` and `Link`)", body = inline(SuccessResponse<PagedResponse<ProjectCardView>>)),
+        (status = 400, description = "Invalid pagination parameters", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[get("/api/projects")]
 pub async fn get_projects_handler(
+    req: HttpRequest,
     user: VerifiedUser,
     query: web::Query<GetProjectsQuery>,
+    page: PageParams<ProjectSort>,
     data: web::Data<AppState>,
 ) -> impl Responder {
     let owner = UserId::from(user.user_id);
-    let (filter, page, sort) = query.into_inner().into();
+    let filter = query.into_inner().into();
+    let sort = page.sort.clone().unwrap_or_default();
 
     match data
         .project
         .get_list
-        .execute(owner, filter, sort, page)
+        .execute(owner, filter, sort, PageRequest::from(&page))
         .await
     {
-        Ok(result) => ApiResponse::success(result),
+        Ok(result) => PagedResponse::new(result.items, result.total, &page).respond(&req, &page),
 
         Err(GetProjectsError::QueryFailed(msg)) => {
             error!("Failed to list projects: {}", msg);
@@ -137,16 +160,18 @@ mod tests {
     fn jwt_service() -> JwtTokenService {
         JwtTokenService::new(JwtConfig {
             issuer: "Lotion".to_string(),
+            audience: "test_audience".to_string(),
             secret_key: "test_secret_key_for_testing_purposes_only".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         })
     }
 
     fn token(user_id: Uuid, verified: bool) -> String {
         jwt_service()
-            .generate_access_token(user_id, verified)
+            .generate_access_token(user_id, verified, 0)
             .unwrap()
     }
 
@@ -162,8 +187,6 @@ mod tests {
                 created_at: chrono::Utc::now(),
                 updated_at: chrono::Utc::now(),
             }],
-            page: 1,
-            per_page: 10,
             total: 1,
         }
     }
@@ -206,12 +229,77 @@ mod tests {
         assert!(body["error"].is_null());
 
         // Deserialize the data safely
-        let page: PageResult<ProjectCardView> =
+        let page: PagedResponse<ProjectCardView> =
             serde_json::from_value(body["data"].clone()).unwrap();
 
         assert_eq!(page.total, 1);
         assert_eq!(page.items.len(), 1);
         assert_eq!(page.items[0].slug, "test-project");
+        assert!(page.next_cursor.is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_get_projects_returns_next_cursor_when_more_remain() {
+        let user_id = Uuid::new_v4();
+
+        let mut result = sample_page_result();
+        result.total = 3;
+
+        let app_state = TestAppStateBuilder::default()
+            .with_get_projects(MockGetProjectsUseCase::success(result))
+            .build();
+
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service());
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .app_data(web::Data::new(token_provider))
+                .service(get_projects_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/api/projects?limit=1&sort=Newest")
+            .insert_header(("Authorization", format!("Bearer {}", token(user_id, true))))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body: JsonValue = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["total"], 3);
+        assert!(body["data"]["next_cursor"].is_string());
+    }
+
+    #[actix_web::test]
+    async fn test_get_projects_rejects_invalid_pagination() {
+        let user_id = Uuid::new_v4();
+
+        let app_state = TestAppStateBuilder::default()
+            .with_get_projects(MockGetProjectsUseCase::success(sample_page_result()))
+            .build();
+
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service());
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .app_data(web::Data::new(token_provider))
+                .service(get_projects_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/api/projects?limit=1000")
+            .insert_header(("Authorization", format!("Bearer {}", token(user_id, true))))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body: JsonValue = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "INVALID_PAGINATION");
     }
 
     #[actix_web::test]
@@ -1,11 +1,23 @@
-use actix_web::{get, web, Responder};
+use actix_web::{get, web, HttpRequest, Responder};
 use tracing::error;
 
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
 use crate::auth::adapter::incoming::web::extractors::auth::resolve_owner_id_or_response;
 use crate::auth::application::domain::entities::UserId;
 use crate::modules::project::adapter::incoming::web::routes::get_projects::GetProjectsQuery;
 use crate::modules::project::application::ports::incoming::use_cases::GetProjectsError;
-use crate::shared::api::ApiResponse;
+use crate::modules::project::application::ports::outgoing::project_query::{
+    PageRequest, ProjectCardView, ProjectSort,
+};
+use crate::shared::api::{
+    etag::ETag,
+    pagination::{PageHeaders, PageParams, PagedResponse},
+    ApiResponse,
+};
+use crate::translations::{
+    adapter::incoming::web::localize::{locale_preferences, mark_localized, translations_for},
+    application::domain::entities::{Translatable, TranslatableKind},
+};
 use crate::AppState;
 
 //
@@ -14,14 +26,41 @@ use crate::AppState;
 // ──────────────────────────────────────────────────────────
 //
 
+/// List a user's projects publicly
+///
+/// Titles are served in the visitor's locale when the owner translated them.
+#[utoipa::path(
+    get,
+    path = "/api/public/projects/{username}",
+    tag = "projects",
+    params(
+        ("username" = String, Path, description = "Owner's username"),
+        ("search" = Option<String>, Query, description = "Match against title"),
+        ("topic_id" = Option<uuid::Uuid>, Query, description = "Only projects tagged with this topic"),
+        ("limit" = Option<u32>, Query, description = "Page size, 1-100 (default 20)"),
+        ("cursor" = Option<String>, Query, description = "`next_cursor` from the previous page"),
+        ("sort" = Option<String>, Query, description = "`Newest`, `Oldest`, `UpdatedNewest` (default) or `UpdatedOldest`"),
+        ("lang" = Option<String>, Query, description = "Locale to serve, e.g. `pt-BR`; defaults to `Accept-Language`"),
+    ),
+    responses(
+        (status = 200, description = "One page of projects (carries an `ETag`; paging also in `X-Total-Count` and `Link`)", body = inline(SuccessResponse<PagedResponse<ProjectCardView>>)),
+        (status = 304, description = "Not modified since the `If-None-Match` ETag"),
+        (status = 400, description = "Invalid pagination parameters", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+)]
 #[get("/api/public/projects/{username}")]
 pub async fn get_public_projects_handler(
+    req: HttpRequest,
     path: web::Path<String>,
     query: web::Query<GetProjectsQuery>,
+    page: PageParams<ProjectSort>,
     data: web::Data<AppState>,
 ) -> impl Responder {
     let username = path.into_inner();
-    let (filter, page, sort) = query.into_inner().into();
+    let filter = query.into_inner().into();
+    let sort = page.sort.clone().unwrap_or_default();
 
     // 1. Resolve owner_id from username
     let owner_id = match resolve_owner_id_or_response(&data, &username).await {
@@ -33,10 +72,38 @@ pub async fn get_public_projects_handler(
     match data
         .project
         .get_list
-        .execute(UserId::from(owner_id), filter, sort, page)
+        .execute(
+            UserId::from(owner_id),
+            filter,
+            sort,
+            PageRequest::from(&page),
+        )
         .await
     {
-        Ok(result) => ApiResponse::success(result),
+        Ok(mut result) => {
+            let ids: Vec<_> = result.items.iter().map(|card| card.id).collect();
+            let translations = translations_for(
+                &data,
+                TranslatableKind::Project,
+                &ids,
+                &locale_preferences(&req),
+            )
+            .await;
+            for card in &mut result.items {
+                if let Some(translation) = translations.get(&card.id) {
+                    card.translate(translation);
+                }
+            }
+
+            let body = PagedResponse::new(result.items, result.total, &page);
+            let headers = PageHeaders::new(&req, &page, &body);
+            // Covers additions and deletions as well as edits on this page
+            let mut resp = ETag::from_body(&body).respond(&req, body);
+            headers.apply(&mut resp);
+            // Cards may come in several locales
+            mark_localized(&mut resp, None);
+            resp
+        }
 
         Err(GetProjectsError::QueryFailed(msg)) => {
             error!("Failed to list public projects: {}", msg);
@@ -125,10 +192,13 @@ mod tests {
             username: username.to_string(),
             password_hash: "hashed".to_string(),
             full_name: "Test User".to_string(),
+            preferred_locale: "en".to_string(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
             is_verified: true,
             is_deleted,
+            token_version: 0,
+            is_active: true,
         }
     }
 
@@ -176,8 +246,6 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
             }],
-            page: 1,
-            per_page: 10,
             total: 1,
         }
     }
@@ -223,8 +291,7 @@ mod tests {
         // Shape checks
         assert!(body["data"].is_object());
         assert!(body["data"]["items"].is_array());
-        assert!(body["data"]["page"].is_number());
-        assert!(body["data"]["per_page"].is_number());
+        assert!(body["data"]["next_cursor"].is_null());
         assert!(body["data"]["total"].is_number());
 
         // Item shape (minimal)
@@ -1,14 +1,25 @@
-use actix_web::{get, web, Responder};
+use actix_web::{get, web, HttpRequest, Responder};
 use serde::Deserialize;
 use tracing::error;
 
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::project::adapter::incoming::web::error_codes::{PROJECT_GONE, PROJECT_NOT_FOUND};
+use crate::project::application::ports::outgoing::project_query::ProjectView;
 use crate::{
     auth::{
-        adapter::incoming::web::extractors::auth::resolve_owner_id_or_response,
+        adapter::incoming::web::extractors::auth::{resolve_owner_id_or_response, MaybeUser},
         application::domain::entities::UserId,
     },
     modules::project::application::ports::incoming::use_cases::GetPublicSingleProjectError,
-    shared::api::ApiResponse,
+    shared::api::{
+        owner_view::{OwnerControls, OwnerView},
+        ApiResponse,
+    },
+    shared::seo,
+    translations::{
+        adapter::incoming::web::localize::{respond_localized, respond_localized_to_owner},
+        application::domain::entities::TranslatableKind,
+    },
     AppState,
 };
 
@@ -18,9 +29,35 @@ pub struct PublicProjectPath {
     pub project_slug: String,
 }
 
+/// Get a project publicly by owner and slug
+///
+/// Title and description are served in the visitor's locale when the owner
+/// translated them. When the owner is signed in, the project also carries
+/// `owner_controls` with its edit link, and isn't cached. A `noindex`
+/// project is sent with `X-Robots-Tag: noindex`. A project in the trash
+/// answers 410, so crawlers drop it.
+#[utoipa::path(
+    get,
+    path = "/api/public/projects/{username}/{project_slug}",
+    tag = "projects",
+    params(
+        ("username" = String, Path, description = "Owner's username"),
+        ("project_slug" = String, Path, description = "Project slug"),
+        ("lang" = Option<String>, Query, description = "Locale to serve, e.g. `pt-BR`; defaults to `Accept-Language`"),
+    ),
+    responses(
+        (status = 200, description = "Project found (carries an `ETag`)", body = inline(SuccessResponse<ProjectView>)),
+        (status = 304, description = "Not modified since the `If-None-Match` ETag"),
+        (status = 404, description = "User or project not found", body = ErrorResponse),
+        (status = 410, description = "The project is in the owner's trash", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+)]
 #[get("/api/public/projects/{username}/{project_slug}")]
 pub async fn get_public_single_project_handler(
+    req: HttpRequest,
     path: web::Path<PublicProjectPath>,
+    viewer: MaybeUser,
     data: web::Data<AppState>,
 ) -> impl Responder {
     let path = path.into_inner();
@@ -38,12 +75,40 @@ pub async fn get_public_single_project_handler(
         .execute(UserId::from(owner_id), &path.project_slug)
         .await
     {
-        Ok(project) => ApiResponse::success(project),
-
-        Err(GetPublicSingleProjectError::NotFound) => {
-            ApiResponse::not_found("PROJECT_NOT_FOUND", "Project not found")
+        Ok(project) => {
+            let noindex = project.noindex;
+            let mut resp = if viewer.is(owner_id) {
+                let controls = OwnerControls::new(format!("/api/projects/{}", project.id));
+                respond_localized_to_owner(
+                    &req,
+                    &data,
+                    TranslatableKind::Project,
+                    project.id,
+                    project.updated_at,
+                    OwnerView::new(project, controls),
+                )
+                .await
+            } else {
+                respond_localized(
+                    &req,
+                    &data,
+                    TranslatableKind::Project,
+                    project.id,
+                    project.updated_at,
+                    project,
+                )
+                .await
+            };
+            if noindex {
+                seo::forbid_indexing(&mut resp);
+            }
+            resp
         }
 
+        Err(GetPublicSingleProjectError::NotFound) => PROJECT_NOT_FOUND.response(),
+
+        Err(GetPublicSingleProjectError::Gone(_)) => PROJECT_GONE.response(),
+
         Err(GetPublicSingleProjectError::RepositoryError(msg)) => {
             error!(
                 "Repository error fetching public project slug={} for username={}: {}",
@@ -77,6 +142,8 @@ mod tests {
     use crate::modules::project::application::ports::outgoing::project_query::ProjectView;
 
     use crate::tests::support::app_state_builder::TestAppStateBuilder;
+    use crate::tests::support::stubs::StubLocalizeContentUseCase;
+    use crate::translations::application::domain::entities::Translation;
 
     /* --------------------------------------------------
      * Mock UserQuery (drives UserIdentityResolver)
@@ -134,10 +201,13 @@ mod tests {
             username: username.to_string(),
             password_hash: "hashed".to_string(),
             full_name: "Test User".to_string(),
+            preferred_locale: "en".to_string(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
             is_verified: true,
             is_deleted,
+            token_version: 0,
+            is_active: true,
         }
     }
 
@@ -183,6 +253,11 @@ mod tests {
             repo_url: None,
             live_demo_url: None,
             topics: vec![],
+            updates: vec![],
+            seo_title: None,
+            seo_description: None,
+            canonical_url: None,
+            noindex: false,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -247,6 +322,154 @@ mod tests {
         assert!(!body["data"]["owner"].is_null());
     }
 
+    #[actix_web::test]
+    async fn test_get_public_single_project_noindex_sets_robots_header() {
+        let owner_uuid = Uuid::new_v4();
+        let username = "someone";
+        let project_slug = "public-project";
+
+        let user_query =
+            MockUserQuery::found(sample_user_query_result(owner_uuid, username, false));
+        let resolver = UserIdentityResolver::new(Arc::new(user_query));
+
+        let view = ProjectView {
+            seo_title: Some("Public Project | Jane".to_string()),
+            noindex: true,
+            ..sample_project_view(UserId::from(owner_uuid), project_slug)
+        };
+
+        let app_state = TestAppStateBuilder::default()
+            .with_user_identity_resolver(resolver)
+            .with_get_public_single_project(MockGetPublicSingleProjectUseCase::success(view))
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .service(get_public_single_project_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/api/public/projects/{}/{}",
+                username, project_slug
+            ))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers().get("x-robots-tag").unwrap(), "noindex");
+
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["seo_title"], "Public Project | Jane");
+        assert_eq!(body["data"]["noindex"], true);
+    }
+
+    #[actix_web::test]
+    async fn test_get_public_single_project_not_modified_when_etag_matches() {
+        let owner_uuid = Uuid::new_v4();
+        let username = "someone";
+        let project_slug = "public-project";
+
+        let user_query =
+            MockUserQuery::found(sample_user_query_result(owner_uuid, username, false));
+        let resolver = UserIdentityResolver::new(Arc::new(user_query));
+
+        let view = sample_project_view(UserId::from(owner_uuid), project_slug);
+
+        let app_state = TestAppStateBuilder::default()
+            .with_user_identity_resolver(resolver)
+            .with_get_public_single_project(MockGetPublicSingleProjectUseCase::success(view))
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .service(get_public_single_project_handler),
+        )
+        .await;
+
+        let uri = format!("/api/public/projects/{}/{}", username, project_slug);
+
+        let first = test::call_service(&app, test::TestRequest::get().uri(&uri).to_request()).await;
+        assert_eq!(first.status(), StatusCode::OK);
+        let etag = first
+            .headers()
+            .get(actix_web::http::header::ETAG)
+            .expect("ETag header")
+            .clone();
+
+        let req = test::TestRequest::get()
+            .uri(&uri)
+            .insert_header((actix_web::http::header::IF_NONE_MATCH, etag.clone()))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(
+            resp.headers().get(actix_web::http::header::ETAG),
+            Some(&etag)
+        );
+
+        let body = test::read_body(resp).await;
+        assert!(body.is_empty());
+    }
+
+    #[actix_web::test]
+    async fn test_get_public_single_project_served_in_requested_locale() {
+        let owner_uuid = Uuid::new_v4();
+        let username = "someone";
+        let project_slug = "public-project";
+
+        let user_query =
+            MockUserQuery::found(sample_user_query_result(owner_uuid, username, false));
+        let resolver = UserIdentityResolver::new(Arc::new(user_query));
+
+        let view = sample_project_view(UserId::from(owner_uuid), project_slug);
+
+        let app_state = TestAppStateBuilder::default()
+            .with_user_identity_resolver(resolver)
+            .with_get_public_single_project(MockGetPublicSingleProjectUseCase::success(view))
+            .with_localize_content(StubLocalizeContentUseCase::with(Translation {
+                locale: "id".to_string(),
+                fields: [("title".to_string(), "Proyek Publik".to_string())].into(),
+                updated_at: Utc::now(),
+            }))
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .service(get_public_single_project_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/api/public/projects/{}/{}?lang=id",
+                username, project_slug
+            ))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers()
+                .get(actix_web::http::header::CONTENT_LANGUAGE)
+                .unwrap(),
+            "id"
+        );
+        assert_eq!(
+            resp.headers().get(actix_web::http::header::VARY).unwrap(),
+            "Accept-Language"
+        );
+
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["title"], "Proyek Publik");
+        assert_eq!(body["data"]["slug"], project_slug);
+    }
+
     #[actix_web::test]
     async fn test_get_public_single_project_user_not_found() {
         let username = "missing-user";
@@ -364,6 +587,40 @@ mod tests {
         assert_eq!(body["error"]["code"], "PROJECT_NOT_FOUND");
     }
 
+    #[actix_web::test]
+    async fn test_get_public_single_project_trashed_is_gone() {
+        let owner_uuid = Uuid::new_v4();
+        let username = "someone";
+
+        let user_query =
+            MockUserQuery::found(sample_user_query_result(owner_uuid, username, false));
+        let resolver = UserIdentityResolver::new(Arc::new(user_query));
+
+        let app_state = TestAppStateBuilder::default()
+            .with_user_identity_resolver(resolver)
+            .with_get_public_single_project(MockGetPublicSingleProjectUseCase::error(
+                GetPublicSingleProjectError::Gone(Utc::now()),
+            ))
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .service(get_public_single_project_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/api/public/projects/{}/old-project", username))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::GONE);
+
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "PROJECT_GONE");
+    }
+
     #[actix_web::test]
     async fn test_get_public_single_project_resolver_repository_error_internal_error() {
         let username = "someone";
@@ -2,6 +2,9 @@ use actix_web::{get, web, Responder};
 use tracing::error;
 use uuid::Uuid;
 
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::project::adapter::incoming::web::error_codes::PROJECT_NOT_FOUND;
+use crate::project::application::ports::outgoing::project_query::ProjectView;
 use crate::{
     auth::adapter::incoming::web::extractors::auth::VerifiedUser,
     auth::application::domain::entities::UserId,
@@ -9,6 +12,25 @@ use crate::{
     shared::api::ApiResponse, AppState,
 };
 
+/// Get one of the current user's projects
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+    ),
+    responses(
+        (status = 200, description = "Project found", body = inline(SuccessResponse<ProjectView>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Project not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[get("/api/projects/{project_id}")]
 pub async fn get_project_by_id_handler(
     user: VerifiedUser,
@@ -25,9 +47,7 @@ pub async fn get_project_by_id_handler(
     {
         Ok(project) => ApiResponse::success(project),
 
-        Err(GetSingleProjectError::NotFound) => {
-            ApiResponse::not_found("PROJECT_NOT_FOUND", "Project not found")
-        }
+        Err(GetSingleProjectError::NotFound) => PROJECT_NOT_FOUND.response(),
 
         Err(GetSingleProjectError::RepositoryError(e)) => {
             error!("Repository error fetching project {}: {}", project_id, e);
@@ -91,16 +111,18 @@ mod tests {
     fn jwt_service() -> JwtTokenService {
         JwtTokenService::new(JwtConfig {
             issuer: "Lotion".to_string(),
+            audience: "test_audience".to_string(),
             secret_key: "test_secret_key_for_testing_purposes_only".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         })
     }
 
     fn token(user_id: Uuid, verified: bool) -> String {
         jwt_service()
-            .generate_access_token(user_id, verified)
+            .generate_access_token(user_id, verified, 0)
             .unwrap()
     }
 
@@ -116,6 +138,11 @@ mod tests {
             repo_url: None,
             live_demo_url: None,
             topics: vec![],
+            updates: vec![],
+            seo_title: None,
+            seo_description: None,
+            canonical_url: None,
+            noindex: false,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         }
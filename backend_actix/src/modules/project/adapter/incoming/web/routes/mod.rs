@@ -1,25 +1,43 @@
 mod add_project_topic;
 mod clear_project_topics;
 mod create_project;
+mod create_project_update;
+mod delete_project_update;
+mod embed_project;
 mod get_project_topics;
 mod get_projects;
 mod get_public_projects;
 mod get_public_single_project;
 mod get_single_project;
 mod hard_delete_project;
+mod oembed;
 mod patch_project;
+mod patch_project_update;
 mod remove_project_topic;
 mod soft_delete_project;
+mod sync_project_readme;
 
-pub use add_project_topic::add_project_topic_handler;
-pub use clear_project_topics::clear_project_topics_handler;
-pub use create_project::create_project_handler;
-pub use get_project_topics::get_project_topics_handler;
-pub use get_projects::get_projects_handler;
-pub use get_public_projects::get_public_projects_handler;
-pub use get_public_single_project::get_public_single_project_handler;
-pub use get_single_project::get_project_by_id_handler;
-pub use hard_delete_project::hard_delete_project_handler;
-pub use patch_project::patch_project_handler;
-pub use remove_project_topic::remove_project_topic_handler;
-pub use soft_delete_project::soft_delete_project_handler;
+pub use add_project_topic::{__path_add_project_topic_handler, add_project_topic_handler};
+pub use clear_project_topics::{__path_clear_project_topics_handler, clear_project_topics_handler};
+pub use create_project::{__path_create_project_handler, create_project_handler};
+pub use create_project_update::{
+    __path_create_project_update_handler, create_project_update_handler,
+};
+pub use delete_project_update::{
+    __path_delete_project_update_handler, delete_project_update_handler,
+};
+pub use embed_project::{__path_embed_project_handler, embed_project_handler};
+pub use get_project_topics::{__path_get_project_topics_handler, get_project_topics_handler};
+pub use get_projects::{__path_get_projects_handler, get_projects_handler};
+pub use get_public_projects::{__path_get_public_projects_handler, get_public_projects_handler};
+pub use get_public_single_project::{
+    __path_get_public_single_project_handler, get_public_single_project_handler,
+};
+pub use get_single_project::{__path_get_project_by_id_handler, get_project_by_id_handler};
+pub use hard_delete_project::{__path_hard_delete_project_handler, hard_delete_project_handler};
+pub use oembed::{__path_oembed_handler, oembed_handler, OEmbedResponse};
+pub use patch_project::{__path_patch_project_handler, patch_project_handler};
+pub use patch_project_update::{__path_patch_project_update_handler, patch_project_update_handler};
+pub use remove_project_topic::{__path_remove_project_topic_handler, remove_project_topic_handler};
+pub use soft_delete_project::{__path_soft_delete_project_handler, soft_delete_project_handler};
+pub use sync_project_readme::{__path_sync_project_readme_handler, sync_project_readme_handler};
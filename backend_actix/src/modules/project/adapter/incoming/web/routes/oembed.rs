@@ -0,0 +1,277 @@
+use actix_web::{get, web, HttpRequest, HttpResponse, Responder};
+use chrono::Utc;
+use handlebars::html_escape;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use super::embed_project::{embed_url, load_embed, request_origin};
+use crate::api::schemas::ErrorResponse;
+use crate::project::adapter::incoming::web::error_codes::{
+    OEMBED_FORMAT_NOT_SUPPORTED, OEMBED_URL_NOT_SUPPORTED,
+};
+use crate::{shared::api::cache::PUBLIC_MAX_AGE_SECS, AppState};
+
+/// Width of the card's iframe when the consumer allows it
+const CARD_WIDTH: u32 = 480;
+
+/// Height of the card under its image: title, summary and links
+const CARD_TEXT_HEIGHT: u32 = 150;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct OEmbedQuery {
+    /// A project's public URL, ending in `/projects/{username}/{project_slug}`
+    pub url: String,
+    pub maxwidth: Option<u32>,
+    pub maxheight: Option<u32>,
+    /// Only `json` is served
+    pub format: Option<String>,
+}
+
+/// oEmbed 1.0 `rich` response. Sent as is, without the API envelope and in
+/// snake_case whatever `JSON_CASE` says, as consumers expect.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OEmbedResponse {
+    #[schema(example = "rich")]
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    #[schema(example = "1.0")]
+    pub version: &'static str,
+    pub title: String,
+    /// Owner's username
+    pub author_name: String,
+    /// Site title
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_name: Option<String>,
+    /// An iframe of `GET /embed/projects/{username}/{project_slug}`
+    pub html: String,
+    pub width: u32,
+    pub height: u32,
+    /// The project's preview image, when it fits `maxwidth` and `maxheight`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<u32>,
+    /// Seconds the response may be cached; no longer than the thumbnail's
+    /// signed URL lives
+    pub cache_age: u64,
+}
+
+/// `(username, project_slug)` of an http(s) URL whose path ends in
+/// `/projects/{username}/{project_slug}`: the site's project pages, the
+/// public API and the embed cards all do
+pub(super) fn project_path(url: &str) -> Option<(String, String)> {
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))?;
+    let rest = rest.split(['?', '#']).next()?;
+    let (host, path) = rest.split_once('/')?;
+    if host.is_empty() {
+        return None;
+    }
+
+    let mut segments = path.split('/').filter(|s| !s.is_empty()).rev();
+    let project_slug = segments.next()?;
+    let username = segments.next()?;
+    (segments.next()? == "projects").then(|| (username.to_string(), project_slug.to_string()))
+}
+
+/// `GET /api/public/oembed` on `origin` describing `page_url`
+pub(super) fn oembed_url(origin: &str, page_url: &str) -> String {
+    let mut encoded = String::with_capacity(page_url.len() * 3);
+    for byte in page_url.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    format!("{origin}/api/public/oembed?url={encoded}&format=json")
+}
+
+/// oEmbed description of a public project
+///
+/// Lets Notion, Slack and other consumers render a project link as a card:
+/// an iframe of the project's embed page, plus its preview image as the
+/// thumbnail. Only `json` is served; other formats answer 501.
+#[utoipa::path(
+    get,
+    path = "/api/public/oembed",
+    tag = "projects",
+    params(OEmbedQuery),
+    responses(
+        (status = 200, description = "oEmbed response", body = OEmbedResponse),
+        (status = 404, description = "Not a project URL, or no such user or project", body = ErrorResponse),
+        (status = 410, description = "The project is in the owner's trash", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+        (status = 501, description = "A format other than `json`", body = ErrorResponse),
+    ),
+)]
+#[get("/api/public/oembed")]
+pub async fn oembed_handler(
+    req: HttpRequest,
+    query: web::Query<OEmbedQuery>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    if query
+        .format
+        .as_deref()
+        .is_some_and(|format| format != "json")
+    {
+        return OEMBED_FORMAT_NOT_SUPPORTED.response();
+    }
+    let Some((username, project_slug)) = project_path(&query.url) else {
+        return OEMBED_URL_NOT_SUPPORTED.response();
+    };
+    let embed = match load_embed(&data, &username, &project_slug).await {
+        Ok(embed) => embed,
+        Err(resp) => return resp,
+    };
+
+    let width = query.maxwidth.map_or(CARD_WIDTH, |max| max.min(CARD_WIDTH));
+    let image_height = embed.image.as_ref().map_or(0, |image| {
+        (u64::from(image.height) * u64::from(width) / u64::from(image.width.max(1))) as u32
+    });
+    let height = (image_height + CARD_TEXT_HEIGHT).min(query.maxheight.unwrap_or(u32::MAX));
+
+    let src = embed_url(&request_origin(&req), &username, &project_slug);
+    let html = format!(
+        r#"<iframe src="{}" width="{width}" height="{height}" title="{}" frameborder="0" scrolling="no" loading="lazy"></iframe>"#,
+        html_escape(&src),
+        html_escape(&embed.project.title),
+    );
+
+    let thumbnail = embed.image.as_ref().filter(|image| {
+        query.maxwidth.is_none_or(|max| image.width <= max)
+            && query.maxheight.is_none_or(|max| image.height <= max)
+    });
+    let cache_age = match &embed.image {
+        Some(image) => (image.expires_at - Utc::now())
+            .num_seconds()
+            .clamp(0, i64::from(PUBLIC_MAX_AGE_SECS)) as u64,
+        None => u64::from(PUBLIC_MAX_AGE_SECS),
+    };
+
+    let provider_name = data.site.get.execute().await.ok().map(|site| site.title);
+
+    HttpResponse::Ok().json(OEmbedResponse {
+        kind: "rich",
+        version: "1.0",
+        title: embed.project.title.clone(),
+        author_name: embed.username.clone(),
+        provider_name,
+        html,
+        width,
+        height,
+        thumbnail_url: thumbnail.map(|image| image.url.clone()),
+        thumbnail_width: thumbnail.map(|image| image.width),
+        thumbnail_height: thumbnail.map(|image| image.height),
+        cache_age,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::Value;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::auth::application::helpers::UserIdentityResolver;
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder,
+        project_test_fixtures::sample_project_view,
+        stubs::{StubGetPreviewImageUseCase, StubGetPublicSingleProjectUseCase, StubUserQuery},
+    };
+
+    async fn call(query: &str) -> actix_web::dev::ServiceResponse {
+        let project = sample_project_view(Uuid::new_v4().into(), "cms");
+        let state = TestAppStateBuilder::default()
+            .with_user_identity_resolver(UserIdentityResolver::new(Arc::new(StubUserQuery::found(
+                Uuid::new_v4(),
+            ))))
+            .with_get_public_single_project(StubGetPublicSingleProjectUseCase::success(project))
+            .with_get_preview_image(StubGetPreviewImageUseCase::image())
+            .build();
+        let app = test::init_service(App::new().app_data(state).service(oembed_handler)).await;
+        let req = test::TestRequest::get()
+            .uri(&format!("/api/public/oembed?{query}"))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_project_path_takes_the_last_three_segments() {
+        let expected = Some(("jane".to_string(), "cms".to_string()));
+        for url in [
+            "https://jane.dev/projects/jane/cms",
+            "https://jane.dev/en/projects/jane/cms/?ref=slack#top",
+            "http://api.jane.dev/embed/projects/jane/cms",
+        ] {
+            assert_eq!(project_path(url), expected, "{url}");
+        }
+        for url in [
+            "https://jane.dev/pages/jane/cms",
+            "https://jane.dev/projects/cms",
+            "https:///projects/jane/cms",
+            "ftp://jane.dev/projects/jane/cms",
+        ] {
+            assert_eq!(project_path(url), None, "{url}");
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_describes_the_project_as_a_rich_embed() {
+        let resp = call("url=https%3A%2F%2Fjane.dev%2Fprojects%2Fjane%2Fcms").await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["type"], "rich");
+        assert_eq!(body["version"], "1.0");
+        assert_eq!(body["title"], "Public Project");
+        assert_eq!(body["author_name"], "jane");
+        assert_eq!(
+            (body["width"].as_u64(), body["height"].as_u64()),
+            (Some(480), Some(420))
+        );
+        assert_eq!(body["thumbnail_width"], 800);
+        assert!(body["thumbnail_url"]
+            .as_str()
+            .unwrap()
+            .starts_with("https://signed.example/"));
+        assert!(body["html"]
+            .as_str()
+            .unwrap()
+            .starts_with(r#"<iframe src="http://localhost:8080/embed/projects/jane/cms""#));
+        assert!(body["cache_age"].as_u64().unwrap() <= u64::from(PUBLIC_MAX_AGE_SECS));
+    }
+
+    #[actix_web::test]
+    async fn test_max_size_shrinks_the_card_and_drops_a_larger_thumbnail() {
+        let resp = call("url=https%3A%2F%2Fjane.dev%2Fprojects%2Fjane%2Fcms&maxwidth=320").await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(
+            (body["width"].as_u64(), body["height"].as_u64()),
+            (Some(320), Some(330))
+        );
+        assert!(body.get("thumbnail_url").is_none());
+        assert!(body.get("thumbnail_width").is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_rejects_other_formats_and_urls() {
+        let resp = call("url=https%3A%2F%2Fjane.dev%2Fprojects%2Fjane%2Fcms&format=xml").await;
+        assert_eq!(resp.status(), StatusCode::NOT_IMPLEMENTED);
+
+        let resp = call("url=https%3A%2F%2Fjane.dev%2Fabout").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "OEMBED_URL_NOT_SUPPORTED");
+    }
+}
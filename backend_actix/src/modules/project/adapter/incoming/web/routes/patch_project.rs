@@ -1,15 +1,20 @@
-use actix_web::{patch, web, Responder};
+use actix_web::{patch, web, HttpRequest, Responder};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use tracing::error;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
 use crate::auth::adapter::incoming::web::extractors::auth::VerifiedUser;
 use crate::auth::application::domain::entities::UserId;
 use crate::modules::project::application::ports::incoming::use_cases::PatchProjectError;
+use crate::modules::project::application::ports::outgoing::project_repository::ProjectResult;
 use crate::modules::project::application::ports::outgoing::project_repository::{
     PatchField, PatchProjectData,
 };
-use crate::shared::api::ApiResponse;
+use crate::project::adapter::incoming::web::error_codes::PROJECT_NOT_FOUND;
+use crate::shared::api::{error_codes::STALE_WRITE, precondition, ApiResponse};
 use crate::AppState;
 
 //
@@ -18,25 +23,55 @@ use crate::AppState;
 // ──────────────────────────────────────────────────────────
 //
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct PatchProjectRequest {
     #[serde(default)]
+    #[schema(value_type = Option<String>)]
     pub title: PatchField<String>,
 
     #[serde(default)]
+    #[schema(value_type = Option<String>)]
     pub description: PatchField<String>,
 
     #[serde(default)]
+    #[schema(value_type = Option<Vec<String>>)]
     pub tech_stack: PatchField<Vec<String>>,
 
+    /// Deprecated: attach screenshots as project media with role `screenshoot`
     #[serde(default)]
+    #[schema(value_type = Option<Vec<String>>, deprecated)]
     pub screenshots: PatchField<Vec<String>>,
 
     #[serde(default)]
+    #[schema(value_type = Option<String>)]
     pub repo_url: PatchField<String>,
 
     #[serde(default)]
+    #[schema(value_type = Option<String>)]
     pub live_demo_url: PatchField<String>,
+
+    /// Up to 100 characters; `null` clears it
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub seo_title: PatchField<String>,
+
+    /// Up to 300 characters; `null` clears it
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub seo_description: PatchField<String>,
+
+    /// Absolute http(s) URL of the original, when this project is a copy
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub canonical_url: PatchField<String>,
+
+    #[serde(default)]
+    pub noindex: Option<bool>,
+
+    /// The project's `updated_at` as last read; the write fails with
+    /// `STALE_WRITE` if it changed since. `If-Unmodified-Since` works too.
+    #[serde(default)]
+    pub expected_updated_at: Option<DateTime<Utc>>,
 }
 
 impl From<PatchProjectRequest> for PatchProjectData {
@@ -48,6 +83,11 @@ impl From<PatchProjectRequest> for PatchProjectData {
             screenshots: req.screenshots,
             repo_url: req.repo_url,
             live_demo_url: req.live_demo_url,
+            seo_title: req.seo_title,
+            seo_description: req.seo_description,
+            canonical_url: req.canonical_url,
+            noindex: req.noindex,
+            expected_updated_at: req.expected_updated_at,
         }
     }
 }
@@ -58,16 +98,41 @@ impl From<PatchProjectRequest> for PatchProjectData {
 // ──────────────────────────────────────────────────────────
 //
 
+/// Partially update a project
+#[utoipa::path(
+    patch,
+    path = "/api/projects/{project_id}",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+    ),
+    request_body = PatchProjectRequest,
+    responses(
+        (status = 200, description = "Project updated", body = inline(SuccessResponse<ProjectResult>)),
+        (status = 400, description = "Invalid SEO field or malformed request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Project not found", body = ErrorResponse),
+        (status = 409, description = "Project changed since `expected_updated_at`", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[patch("/api/projects/{project_id}")]
 pub async fn patch_project_handler(
     user: VerifiedUser,
+    http_req: HttpRequest,
     path: web::Path<Uuid>,
     req: web::Json<PatchProjectRequest>,
     data: web::Data<AppState>,
 ) -> impl Responder {
     let project_id = path.into_inner();
     let owner = UserId::from(user.user_id);
-    let patch_data: PatchProjectData = req.into_inner().into();
+    let mut patch_data: PatchProjectData = req.into_inner().into();
+    patch_data.expected_updated_at =
+        precondition::expected_updated_at(&http_req, patch_data.expected_updated_at);
 
     match data
         .project
@@ -77,9 +142,11 @@ pub async fn patch_project_handler(
     {
         Ok(updated) => ApiResponse::success(updated),
 
-        Err(PatchProjectError::NotFound) => {
-            ApiResponse::not_found("PROJECT_NOT_FOUND", "Project not found")
-        }
+        Err(PatchProjectError::Invalid(errors)) => ApiResponse::validation_failed(&errors),
+
+        Err(PatchProjectError::NotFound) => PROJECT_NOT_FOUND.response(),
+
+        Err(PatchProjectError::Stale) => STALE_WRITE.response(),
 
         Err(PatchProjectError::RepositoryError(e)) => {
             error!("Repository error patching project {}: {}", project_id, e);
@@ -149,16 +216,18 @@ mod tests {
     fn jwt_service() -> JwtTokenService {
         JwtTokenService::new(JwtConfig {
             issuer: "Lotion".to_string(),
+            audience: "test_audience".to_string(),
             secret_key: "test_secret_key_for_testing_purposes_only".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         })
     }
 
     fn token(user_id: Uuid, verified: bool) -> String {
         jwt_service()
-            .generate_access_token(user_id, verified)
+            .generate_access_token(user_id, verified, 0)
             .unwrap()
     }
 
@@ -180,6 +249,10 @@ mod tests {
             screenshots: vec!["img.png".to_string()],
             repo_url: Some("https://github.com/x/y".to_string()),
             live_demo_url: None,
+            seo_title: None,
+            seo_description: None,
+            canonical_url: None,
+            noindex: false,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -264,6 +337,41 @@ mod tests {
         assert_eq!(body["error"]["message"], "Project not found");
     }
 
+    #[actix_web::test]
+    async fn test_patch_project_stale_write() {
+        let user_id = Uuid::new_v4();
+        let project_id = Uuid::new_v4();
+
+        let app_state = TestAppStateBuilder::default()
+            .with_patch_project(MockPatchProjectUseCase::error(PatchProjectError::Stale))
+            .build();
+
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service());
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .app_data(web::Data::new(token_provider))
+                .service(patch_project_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::patch()
+            .uri(&format!("/api/projects/{}", project_id))
+            .insert_header(("Authorization", format!("Bearer {}", token(user_id, true))))
+            .set_json(json!({
+                "title": "Updated Title",
+                "expected_updated_at": "2026-10-16T09:00:00Z"
+            }))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "STALE_WRITE");
+    }
+
     #[actix_web::test]
     async fn test_patch_project_repository_error_internal_error() {
         let user_id = Uuid::new_v4();
@@ -0,0 +1,198 @@
+use actix_web::{patch, web, Responder};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use tracing::error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::auth::adapter::incoming::web::extractors::auth::VerifiedUser;
+use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::ports::incoming::use_cases::PatchProjectUpdateError;
+use crate::modules::project::application::ports::outgoing::project_update_repository::{
+    PatchProjectUpdateData, ProjectUpdate,
+};
+use crate::project::adapter::incoming::web::error_codes::{
+    PROJECT_NOT_FOUND, PROJECT_UPDATE_NOT_FOUND,
+};
+use crate::shared::api::ApiResponse;
+use crate::AppState;
+
+//
+// ──────────────────────────────────────────────────────────
+// Request DTO
+// ──────────────────────────────────────────────────────────
+//
+
+/// Absent fields are left as they are
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PatchProjectUpdateRequest {
+    #[serde(default)]
+    pub title: Option<String>,
+
+    #[serde(default)]
+    pub body: Option<String>,
+
+    #[serde(default)]
+    pub posted_at: Option<DateTime<Utc>>,
+}
+
+impl From<PatchProjectUpdateRequest> for PatchProjectUpdateData {
+    fn from(req: PatchProjectUpdateRequest) -> Self {
+        PatchProjectUpdateData {
+            title: req.title,
+            body: req.body,
+            posted_at: req.posted_at,
+        }
+    }
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Handler
+// ──────────────────────────────────────────────────────────
+//
+
+/// Edit a project timeline entry
+#[utoipa::path(
+    patch,
+    path = "/api/projects/{project_id}/updates/{update_id}",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("update_id" = Uuid, Path, description = "Timeline entry id"),
+    ),
+    request_body = PatchProjectUpdateRequest,
+    responses(
+        (status = 200, description = "Entry updated", body = inline(SuccessResponse<ProjectUpdate>)),
+        (status = 400, description = "Invalid title or body, or malformed request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Project or entry not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[patch("/api/projects/{project_id}/updates/{update_id}")]
+pub async fn patch_project_update_handler(
+    user: VerifiedUser,
+    path: web::Path<(Uuid, Uuid)>,
+    req: web::Json<PatchProjectUpdateRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let owner = UserId::from(user.user_id);
+    let (project_id, update_id) = path.into_inner();
+
+    match data
+        .project
+        .patch_update
+        .execute(owner, project_id, update_id, req.into_inner().into())
+        .await
+    {
+        Ok(update) => ApiResponse::success(update),
+
+        Err(PatchProjectUpdateError::Invalid(errors)) => ApiResponse::validation_failed(&errors),
+
+        Err(PatchProjectUpdateError::ProjectNotFound) => PROJECT_NOT_FOUND.response(),
+
+        Err(PatchProjectUpdateError::UpdateNotFound) => PROJECT_UPDATE_NOT_FOUND.response(),
+
+        Err(PatchProjectUpdateError::RepositoryError(msg)) => {
+            error!("Failed to patch update {}: {}", update_id, msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use async_trait::async_trait;
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+
+    use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+    use crate::modules::project::application::ports::incoming::use_cases::PatchProjectUpdateUseCase;
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, auth_helper::test_helpers::create_test_jwt_service,
+    };
+
+    #[derive(Clone)]
+    struct MockPatchProjectUpdateUseCase {
+        error: Option<PatchProjectUpdateError>,
+    }
+
+    #[async_trait]
+    impl PatchProjectUpdateUseCase for MockPatchProjectUpdateUseCase {
+        async fn execute(
+            &self,
+            _owner: UserId,
+            _project_id: Uuid,
+            update_id: Uuid,
+            data: PatchProjectUpdateData,
+        ) -> Result<ProjectUpdate, PatchProjectUpdateError> {
+            if let Some(err) = &self.error {
+                return Err(err.clone());
+            }
+            let now = Utc::now();
+            Ok(ProjectUpdate {
+                id: update_id,
+                title: data.title.unwrap_or_else(|| "v1.0".to_string()),
+                body: data.body.unwrap_or_else(|| "First release".to_string()),
+                posted_at: data.posted_at.unwrap_or(now),
+                created_at: now,
+                updated_at: now,
+            })
+        }
+    }
+
+    async fn call(error: Option<PatchProjectUpdateError>) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_patch_project_update(MockPatchProjectUpdateUseCase { error })
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(patch_project_update_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::patch()
+            .uri(&format!(
+                "/api/projects/{}/updates/{}",
+                Uuid::new_v4(),
+                Uuid::new_v4()
+            ))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({ "title": "v1.0.1" }))
+            .to_request();
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_patch_project_update_success() {
+        let resp = call(None).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["title"], "v1.0.1");
+        assert_eq!(body["data"]["body"], "First release");
+    }
+
+    #[actix_web::test]
+    async fn test_patch_project_update_not_found() {
+        let resp = call(Some(PatchProjectUpdateError::UpdateNotFound)).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "PROJECT_UPDATE_NOT_FOUND");
+    }
+}
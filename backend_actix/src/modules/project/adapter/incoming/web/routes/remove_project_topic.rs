@@ -1,8 +1,11 @@
 use actix_web::{delete, web, Responder};
 use serde::Deserialize;
 use tracing::error;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::api::schemas::ErrorResponse;
+use crate::project::adapter::incoming::web::error_codes::PROJECT_NOT_FOUND;
 use crate::{
     auth::adapter::incoming::web::extractors::auth::VerifiedUser,
     auth::application::domain::entities::UserId,
@@ -10,11 +13,31 @@ use crate::{
     shared::api::ApiResponse, AppState,
 };
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct RemoveProjectTopicRequest {
     pub topic_id: Uuid,
 }
 
+/// Detach a topic from a project; detaching an unattached topic is a no-op
+#[utoipa::path(
+    delete,
+    path = "/api/projects/{project_id}/topics",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+    ),
+    request_body = RemoveProjectTopicRequest,
+    responses(
+        (status = 204, description = "Topic detached"),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Project not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[delete("/api/projects/{project_id}/topics")]
 pub async fn remove_project_topic_handler(
     user: VerifiedUser,
@@ -34,9 +57,7 @@ pub async fn remove_project_topic_handler(
     {
         Ok(_) => ApiResponse::no_content(),
 
-        Err(RemoveProjectTopicError::ProjectNotFound) => {
-            ApiResponse::not_found("PROJECT_NOT_FOUND", "Project not found")
-        }
+        Err(RemoveProjectTopicError::ProjectNotFound) => PROJECT_NOT_FOUND.response(),
         // Intentionally not exposed for now (DELETE is idempotent at API level)
         Err(RemoveProjectTopicError::TopicNotFound) => ApiResponse::no_content(),
 
@@ -102,16 +123,18 @@ mod tests {
     fn jwt_service() -> JwtTokenService {
         JwtTokenService::new(JwtConfig {
             issuer: "Lotion".to_string(),
+            audience: "test_audience".to_string(),
             secret_key: "test_secret_key_for_testing_purposes_only".to_string(),
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
         })
     }
 
     fn token(user_id: Uuid, verified: bool) -> String {
         jwt_service()
-            .generate_access_token(user_id, verified)
+            .generate_access_token(user_id, verified, 0)
             .unwrap()
     }
 
@@ -1,10 +1,25 @@
 use actix_web::{delete, web, Responder};
 
+use crate::api::schemas::ErrorResponse;
 use crate::{
     auth::adapter::incoming::web::extractors::auth::VerifiedUser, shared::api::ApiResponse,
     AppState,
 };
 
+/// Soft delete a project (not implemented yet, always 204)
+#[utoipa::path(
+    delete,
+    path = "/api/projects",
+    tag = "projects",
+    responses(
+        (status = 204, description = "Accepted"),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[delete("/api/projects")]
 pub async fn soft_delete_project_handler(
     _user: VerifiedUser,
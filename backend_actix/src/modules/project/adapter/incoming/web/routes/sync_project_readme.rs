@@ -0,0 +1,263 @@
+use actix_web::{post, web, Responder};
+use serde::Deserialize;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::project::adapter::incoming::web::error_codes::{
+    NO_REPO_URL, PROJECT_NOT_FOUND, README_NOT_FOUND, REPO_UNAVAILABLE, UNSUPPORTED_REPOSITORY,
+};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::VerifiedUser,
+    auth::application::domain::entities::UserId,
+    modules::project::application::ports::incoming::use_cases::{
+        ReadmeSyncResult, SyncProjectReadmeError,
+    },
+    shared::api::ApiResponse,
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct SyncReadmeQuery {
+    pub keep_in_sync: Option<bool>,
+}
+
+/// Replace a project's description with its repository README
+///
+/// The README is fetched from the project's `repo_url` (public GitHub
+/// repositories only). Relative links and images are made absolute, and
+/// scripts, frames, forms and event handlers are stripped. With
+/// `keep_in_sync=true` the description is refreshed from the README
+/// periodically until the flag is turned off again.
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/sync-readme",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("keep_in_sync" = Option<bool>, Query, description = "Keep refreshing the description from the README; omitted keeps the current setting"),
+    ),
+    responses(
+        (status = 200, description = "Description synced", body = inline(SuccessResponse<ReadmeSyncResult>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Project not found", body = ErrorResponse),
+        (status = 422, description = "No repository URL, unsupported repository, or no README", body = ErrorResponse),
+        (status = 502, description = "Repository host unavailable", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/projects/{project_id}/sync-readme")]
+pub async fn sync_project_readme_handler(
+    user: VerifiedUser,
+    path: web::Path<Uuid>,
+    query: web::Query<SyncReadmeQuery>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let owner = UserId::from(user.user_id);
+    let project_id = path.into_inner();
+
+    match data
+        .project
+        .sync_readme
+        .execute(owner, project_id, query.keep_in_sync)
+        .await
+    {
+        Ok(result) => ApiResponse::success(result),
+
+        Err(SyncProjectReadmeError::NotFound) => PROJECT_NOT_FOUND.response(),
+
+        Err(SyncProjectReadmeError::NoRepoUrl) => NO_REPO_URL.response(),
+
+        Err(SyncProjectReadmeError::UnsupportedRepository) => UNSUPPORTED_REPOSITORY.response(),
+
+        Err(SyncProjectReadmeError::ReadmeNotFound) => README_NOT_FOUND.response(),
+
+        Err(SyncProjectReadmeError::RepoUnavailable(msg)) => {
+            error!("Failed to fetch README: {}", msg);
+            REPO_UNAVAILABLE.response()
+        }
+
+        Err(SyncProjectReadmeError::RepositoryError(msg)) => {
+            error!("Failed to sync README: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use serde_json::Value;
+    use std::sync::{Arc, Mutex};
+
+    use crate::auth::adapter::outgoing::jwt::{JwtConfig, JwtTokenService};
+    use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+    use crate::modules::project::application::ports::incoming::use_cases::SyncProjectReadmeUseCase;
+    use crate::modules::project::application::ports::outgoing::readme_sync_repository::ReadmeSync;
+    use crate::tests::support::app_state_builder::TestAppStateBuilder;
+
+    /* --------------------------------------------------
+     * Mock SyncProjectReadmeUseCase
+     * -------------------------------------------------- */
+
+    #[derive(Clone)]
+    struct MockSyncProjectReadmeUseCase {
+        result: Result<ReadmeSyncResult, SyncProjectReadmeError>,
+        keep_in_sync: Arc<Mutex<Option<Option<bool>>>>,
+    }
+
+    impl MockSyncProjectReadmeUseCase {
+        fn success() -> Self {
+            Self::with(Ok(ReadmeSyncResult {
+                description: "# CMS".to_string(),
+                changed: true,
+                sync: ReadmeSync {
+                    project_id: Uuid::new_v4(),
+                    keep_in_sync: true,
+                    readme_url: "https://github.com/jane/cms/blob/HEAD/README.md".to_string(),
+                    synced_at: Utc::now(),
+                },
+            }))
+        }
+
+        fn error(err: SyncProjectReadmeError) -> Self {
+            Self::with(Err(err))
+        }
+
+        fn with(result: Result<ReadmeSyncResult, SyncProjectReadmeError>) -> Self {
+            Self {
+                result,
+                keep_in_sync: Arc::new(Mutex::new(None)),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl SyncProjectReadmeUseCase for MockSyncProjectReadmeUseCase {
+        async fn execute(
+            &self,
+            _owner: UserId,
+            _project_id: Uuid,
+            keep_in_sync: Option<bool>,
+        ) -> Result<ReadmeSyncResult, SyncProjectReadmeError> {
+            *self.keep_in_sync.lock().unwrap() = Some(keep_in_sync);
+            self.result.clone()
+        }
+    }
+
+    /* --------------------------------------------------
+     * Helpers
+     * -------------------------------------------------- */
+
+    fn jwt_service() -> JwtTokenService {
+        JwtTokenService::new(JwtConfig {
+            issuer: "Lotion".to_string(),
+            audience: "test_audience".to_string(),
+            secret_key: "test_secret_key_for_testing_purposes_only".to_string(),
+            access_token_expiry: 3600,
+            refresh_token_expiry: 86400,
+            verification_token_expiry: 86400,
+            remember_me_refresh_token_expiry: 2592000,
+        })
+    }
+
+    async fn call(uc: MockSyncProjectReadmeUseCase, query: &str) -> (StatusCode, Value) {
+        let app_state = TestAppStateBuilder::default()
+            .with_sync_project_readme(uc)
+            .build();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service());
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_state)
+                .app_data(web::Data::new(token_provider))
+                .service(sync_project_readme_handler),
+        )
+        .await;
+
+        let token = jwt_service()
+            .generate_access_token(Uuid::new_v4(), true, 0)
+            .unwrap();
+        let req = test::TestRequest::post()
+            .uri(&format!(
+                "/api/projects/{}/sync-readme{}",
+                Uuid::new_v4(),
+                query
+            ))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        let status = resp.status();
+        (status, test::read_body_json(resp).await)
+    }
+
+    /* --------------------------------------------------
+     * Tests
+     * -------------------------------------------------- */
+
+    #[actix_web::test]
+    async fn test_sync_readme_success_passes_flag() {
+        let uc = MockSyncProjectReadmeUseCase::success();
+        let (status, body) = call(uc.clone(), "?keep_in_sync=true").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["description"], "# CMS");
+        assert_eq!(body["data"]["keep_in_sync"], true);
+        assert_eq!(*uc.keep_in_sync.lock().unwrap(), Some(Some(true)));
+    }
+
+    #[actix_web::test]
+    async fn test_sync_readme_without_flag_keeps_setting() {
+        let uc = MockSyncProjectReadmeUseCase::success();
+        let (status, _) = call(uc.clone(), "").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(*uc.keep_in_sync.lock().unwrap(), Some(None));
+    }
+
+    #[actix_web::test]
+    async fn test_sync_readme_errors() {
+        let cases = [
+            (
+                SyncProjectReadmeError::NotFound,
+                StatusCode::NOT_FOUND,
+                "PROJECT_NOT_FOUND",
+            ),
+            (
+                SyncProjectReadmeError::NoRepoUrl,
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "NO_REPO_URL",
+            ),
+            (
+                SyncProjectReadmeError::ReadmeNotFound,
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "README_NOT_FOUND",
+            ),
+            (
+                SyncProjectReadmeError::RepoUnavailable("timeout".to_string()),
+                StatusCode::BAD_GATEWAY,
+                "REPO_UNAVAILABLE",
+            ),
+            (
+                SyncProjectReadmeError::RepositoryError("db down".to_string()),
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "INTERNAL_ERROR",
+            ),
+        ];
+
+        for (err, expected_status, code) in cases {
+            let (status, body) = call(MockSyncProjectReadmeUseCase::error(err), "").await;
+
+            assert_eq!(status, expected_status);
+            assert_eq!(body["error"]["code"], code);
+        }
+    }
+}
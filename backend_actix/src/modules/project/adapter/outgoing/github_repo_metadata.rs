@@ -0,0 +1,189 @@
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use serde::Deserialize;
+use std::time::Duration;
+
+use crate::modules::project::application::ports::outgoing::repo_metadata_provider::{
+    RepoMetadataError, RepoMetadataProvider, RepoReadme,
+};
+
+const GITHUB_API_URL: &str = "https://api.github.com";
+
+/// Called from a request handler, so a slow API must not hold it long
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// `GET /repos/{owner}/{repo}/readme`
+#[derive(Deserialize)]
+struct ReadmeResponse {
+    content: String,
+    encoding: String,
+    html_url: String,
+    download_url: String,
+}
+
+/// READMEs of public GitHub repositories, through the REST API. An optional
+/// token raises the rate limit from 60 to 5000 requests an hour.
+pub struct GitHubRepoMetadataProvider {
+    client: reqwest::Client,
+    api_url: String,
+    token: Option<String>,
+}
+
+impl GitHubRepoMetadataProvider {
+    pub fn new(token: Option<String>) -> Result<Self, RepoMetadataError> {
+        let client = reqwest::Client::builder()
+            .timeout(FETCH_TIMEOUT)
+            .user_agent(concat!("port-blog-cms/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| RepoMetadataError::Unavailable(e.to_string()))?;
+
+        Ok(Self {
+            client,
+            api_url: GITHUB_API_URL.to_string(),
+            token,
+        })
+    }
+}
+
+/// `owner` and `repo` of `https://github.com/owner/repo[.git][/...]`
+fn parse_repo_url(repo_url: &str) -> Option<(&str, &str)> {
+    let rest = repo_url
+        .strip_prefix("https://")
+        .or_else(|| repo_url.strip_prefix("http://"))?;
+    let rest = rest.strip_prefix("www.").unwrap_or(rest);
+    let path = rest.strip_prefix("github.com/")?;
+
+    let mut parts = path.split(['/', '?', '#']);
+    let owner = parts.next()?;
+    let repo = parts.next()?;
+    let repo = repo.strip_suffix(".git").unwrap_or(repo);
+
+    // `.` and `..` would walk out of /repos/{owner}/{repo} on the API host
+    let valid = |part: &str| {
+        !part.is_empty()
+            && !part.chars().all(|c| c == '.')
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    };
+    (valid(owner) && valid(repo)).then_some((owner, repo))
+}
+
+/// `https://host/a/b/README.md` -> `https://host/a/b/`
+fn folder_of(url: &str) -> String {
+    match url.rfind('/') {
+        Some(slash) => url[..=slash].to_string(),
+        None => format!("{url}/"),
+    }
+}
+
+fn to_readme(response: ReadmeResponse) -> Result<RepoReadme, RepoMetadataError> {
+    if response.encoding != "base64" {
+        return Err(RepoMetadataError::Unavailable(format!(
+            "unexpected README encoding: {}",
+            response.encoding
+        )));
+    }
+
+    // GitHub wraps the base64 at 60 columns
+    let encoded: String = response
+        .content
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace())
+        .collect();
+    let bytes = STANDARD
+        .decode(encoded)
+        .map_err(|e| RepoMetadataError::Unavailable(format!("invalid README content: {e}")))?;
+
+    Ok(RepoReadme {
+        markdown: String::from_utf8_lossy(&bytes).into_owned(),
+        raw_base_url: folder_of(&response.download_url),
+        html_base_url: folder_of(&response.html_url),
+        html_url: response.html_url,
+    })
+}
+
+#[async_trait]
+impl RepoMetadataProvider for GitHubRepoMetadataProvider {
+    async fn fetch_readme(&self, repo_url: &str) -> Result<RepoReadme, RepoMetadataError> {
+        let (owner, repo) =
+            parse_repo_url(repo_url).ok_or(RepoMetadataError::UnsupportedRepository)?;
+
+        let mut request = self
+            .client
+            .get(format!("{}/repos/{owner}/{repo}/readme", self.api_url))
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28");
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| RepoMetadataError::Unavailable(e.to_string()))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(RepoMetadataError::ReadmeNotFound);
+        }
+        let body = response
+            .error_for_status()
+            .map_err(|e| RepoMetadataError::Unavailable(e.to_string()))?
+            .bytes()
+            .await
+            .map_err(|e| RepoMetadataError::Unavailable(e.to_string()))?;
+
+        let readme: ReadmeResponse = serde_json::from_slice(&body)
+            .map_err(|e| RepoMetadataError::Unavailable(format!("unexpected response: {e}")))?;
+        to_readme(readme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_repo_url() {
+        assert_eq!(
+            parse_repo_url("https://github.com/jane/cms"),
+            Some(("jane", "cms"))
+        );
+        assert_eq!(
+            parse_repo_url("https://www.github.com/jane/cms.git"),
+            Some(("jane", "cms"))
+        );
+        assert_eq!(
+            parse_repo_url("https://github.com/jane/cms/tree/main/docs"),
+            Some(("jane", "cms"))
+        );
+        assert_eq!(parse_repo_url("https://github.com/jane"), None);
+        assert_eq!(parse_repo_url("https://gitlab.com/jane/cms"), None);
+        assert_eq!(
+            parse_repo_url("https://github.com.evil.example/jane/cms"),
+            None
+        );
+        assert_eq!(parse_repo_url("https://github.com/../x"), None);
+    }
+
+    #[test]
+    fn test_to_readme_decodes_content_and_bases() {
+        let readme = to_readme(ReadmeResponse {
+            content: "IyBD\nTVM=\n".to_string(),
+            encoding: "base64".to_string(),
+            html_url: "https://github.com/jane/cms/blob/main/docs/README.md".to_string(),
+            download_url: "https://raw.githubusercontent.com/jane/cms/main/docs/README.md"
+                .to_string(),
+        })
+        .unwrap();
+
+        assert_eq!(readme.markdown, "# CMS");
+        assert_eq!(
+            readme.raw_base_url,
+            "https://raw.githubusercontent.com/jane/cms/main/docs/"
+        );
+        assert_eq!(
+            readme.html_base_url,
+            "https://github.com/jane/cms/blob/main/docs/"
+        );
+    }
+}
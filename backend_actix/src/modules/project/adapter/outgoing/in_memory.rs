@@ -0,0 +1,841 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::ports::outgoing::project_archiver::{
+    ProjectArchiver, ProjectArchiverError,
+};
+use crate::modules::project::application::ports::outgoing::project_query::{
+    PageRequest, PageResult, ProjectCardView, ProjectListFilter, ProjectQuery, ProjectQueryError,
+    ProjectSort, ProjectTopicItem, ProjectView,
+};
+use crate::modules::project::application::ports::outgoing::project_repository::{
+    CreateProjectData, PatchField, PatchProjectData, ProjectRepository, ProjectRepositoryError,
+    ProjectResult,
+};
+use crate::modules::project::application::ports::outgoing::project_topic_repository::{
+    ProjectTopicRepository, ProjectTopicRepositoryError,
+};
+use crate::modules::project::application::ports::outgoing::project_update_repository::{
+    CreateProjectUpdateData, PatchProjectUpdateData, ProjectUpdate, ProjectUpdateRepository,
+    ProjectUpdateRepositoryError,
+};
+use crate::modules::project::application::ports::outgoing::readme_sync_repository::{
+    ReadmeSync, ReadmeSyncRepository, ReadmeSyncTarget,
+};
+use crate::shared::in_memory::Table;
+use crate::topic::adapter::outgoing::InMemoryTopicStore;
+
+pub(crate) struct ProjectRow {
+    pub(crate) project: ProjectResult,
+    /// Set while the project is in the trash
+    pub(crate) deleted_at: Option<DateTime<Utc>>,
+}
+
+impl ProjectRow {
+    fn is_live(&self) -> bool {
+        self.deleted_at.is_none()
+    }
+
+    fn is_live_for(&self, owner: UserId, project_id: Uuid) -> bool {
+        self.project.id == project_id && self.project.owner == owner && self.is_live()
+    }
+
+    /// Case-insensitive substring match over title, description, slug and
+    /// tech stack, like the Postgres `ILIKE` search
+    fn matches(&self, term: &str) -> bool {
+        let term = term.to_lowercase();
+        let contains = |text: &str| text.to_lowercase().contains(&term);
+
+        contains(&self.project.title)
+            || contains(&self.project.description)
+            || contains(&self.project.slug)
+            || self.project.tech_stack.iter().any(|tech| contains(tech))
+    }
+
+    fn to_card_view(&self) -> ProjectCardView {
+        ProjectCardView {
+            id: self.project.id,
+            title: self.project.title.clone(),
+            slug: self.project.slug.clone(),
+            tech_stack: self.project.tech_stack.clone(),
+            repo_url: self.project.repo_url.clone(),
+            live_demo_url: self.project.live_demo_url.clone(),
+            created_at: self.project.created_at,
+            updated_at: self.project.updated_at,
+        }
+    }
+
+    fn to_view(&self, topics: Vec<ProjectTopicItem>, updates: Vec<ProjectUpdate>) -> ProjectView {
+        ProjectView {
+            id: self.project.id,
+            owner: self.project.owner,
+            title: self.project.title.clone(),
+            slug: self.project.slug.clone(),
+            description: self.project.description.clone(),
+            tech_stack: self.project.tech_stack.clone(),
+            screenshots: self.project.screenshots.clone(),
+            repo_url: self.project.repo_url.clone(),
+            live_demo_url: self.project.live_demo_url.clone(),
+            seo_title: self.project.seo_title.clone(),
+            seo_description: self.project.seo_description.clone(),
+            canonical_url: self.project.canonical_url.clone(),
+            noindex: self.project.noindex,
+            topics,
+            updates,
+            created_at: self.project.created_at,
+            updated_at: self.project.updated_at,
+        }
+    }
+}
+
+#[derive(PartialEq)]
+pub(crate) struct ProjectTopicLink {
+    pub(crate) project_id: Uuid,
+    pub(crate) topic_id: Uuid,
+}
+
+pub(crate) struct ProjectUpdateRow {
+    pub(crate) project_id: Uuid,
+    pub(crate) update: ProjectUpdate,
+}
+
+/// Process-local projects, implementing `ProjectRepository`, `ProjectQuery`,
+/// `ProjectTopicRepository`, `ProjectUpdateRepository`, `ProjectArchiver`
+/// and `ReadmeSyncRepository` over the same rows. Topic links are checked
+/// against the `InMemoryTopicStore` it was built with.
+#[derive(Clone, Default)]
+pub struct InMemoryProjectStore {
+    pub(crate) projects: Table<ProjectRow>,
+    pub(crate) links: Table<ProjectTopicLink>,
+    pub(crate) updates: Table<ProjectUpdateRow>,
+    readme_syncs: Table<ReadmeSync>,
+    topics: InMemoryTopicStore,
+}
+
+impl InMemoryProjectStore {
+    pub fn new(topics: InMemoryTopicStore) -> Self {
+        Self {
+            projects: Table::default(),
+            links: Table::default(),
+            updates: Table::default(),
+            readme_syncs: Table::default(),
+            topics,
+        }
+    }
+
+    /// Newest first, like the Postgres query
+    fn project_updates(&self, project_id: Uuid) -> Vec<ProjectUpdate> {
+        let mut updates: Vec<ProjectUpdate> = self.updates.read(|rows| {
+            rows.iter()
+                .filter(|row| row.project_id == project_id)
+                .map(|row| row.update.clone())
+                .collect()
+        });
+        updates.sort_by(|a, b| {
+            (b.posted_at, b.created_at)
+                .cmp(&(a.posted_at, a.created_at))
+                .then(a.id.cmp(&b.id))
+        });
+        updates
+    }
+
+    /// Moves the project's `updated_at`, if it is the owner's and live
+    fn touch_project(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+    ) -> Result<(), ProjectUpdateRepositoryError> {
+        self.projects.write(|projects| {
+            let row = projects
+                .iter_mut()
+                .find(|row| row.is_live_for(owner, project_id))
+                .ok_or(ProjectUpdateRepositoryError::ProjectNotFound)?;
+            row.project.updated_at = Utc::now();
+            Ok(())
+        })
+    }
+
+    /// The project is checked first, like the Postgres probe
+    fn ensure_update_exists(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        update_id: Uuid,
+    ) -> Result<(), ProjectUpdateRepositoryError> {
+        let project_ok = self.projects.read(|projects| {
+            projects
+                .iter()
+                .any(|row| row.is_live_for(owner, project_id))
+        });
+        if !project_ok {
+            return Err(ProjectUpdateRepositoryError::ProjectNotFound);
+        }
+        let update_ok = self.updates.read(|rows| {
+            rows.iter()
+                .any(|row| row.project_id == project_id && row.update.id == update_id)
+        });
+        if !update_ok {
+            return Err(ProjectUpdateRepositoryError::UpdateNotFound);
+        }
+        Ok(())
+    }
+
+    fn ensure_project_ok(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+    ) -> Result<(), ProjectTopicRepositoryError> {
+        if self.projects.read(|projects| {
+            projects
+                .iter()
+                .any(|row| row.is_live_for(owner, project_id))
+        }) {
+            Ok(())
+        } else {
+            Err(ProjectTopicRepositoryError::ProjectNotFound)
+        }
+    }
+
+    /// Topic first, then project, like the Postgres probe; an existing link
+    /// is left alone
+    fn link_topic(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        topic_id: Uuid,
+    ) -> Result<(), ProjectTopicRepositoryError> {
+        let topic_ok = self.topics.topics.read(|topics| {
+            topics
+                .iter()
+                .any(|row| row.topic.id == topic_id && row.topic.owner == owner)
+        });
+        if !topic_ok {
+            return Err(ProjectTopicRepositoryError::TopicNotFound);
+        }
+        self.ensure_project_ok(owner, project_id)?;
+
+        let link = ProjectTopicLink {
+            project_id,
+            topic_id,
+        };
+        self.links.write(|links| {
+            if !links.contains(&link) {
+                links.push(link);
+            }
+        });
+        Ok(())
+    }
+
+    fn set_deleted_at(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        deleted: bool,
+    ) -> Result<(), ProjectArchiverError> {
+        self.projects.write(|projects| {
+            let row = projects
+                .iter_mut()
+                .find(|row| {
+                    row.project.id == project_id
+                        && row.project.owner == owner
+                        && row.deleted_at.is_some() != deleted
+                })
+                .ok_or(ProjectArchiverError::NotFound)?;
+            row.deleted_at = deleted.then(Utc::now);
+            row.project.updated_at = Utc::now();
+            Ok(())
+        })
+    }
+}
+
+#[async_trait]
+impl ProjectRepository for InMemoryProjectStore {
+    /// Slugs are trimmed, lowercased and globally unique, deleted projects
+    /// included, as in Postgres
+    async fn create_project(
+        &self,
+        data: CreateProjectData,
+    ) -> Result<ProjectResult, ProjectRepositoryError> {
+        let slug = data.slug.trim().to_lowercase();
+
+        self.projects.write(|projects| {
+            if projects.iter().any(|row| row.project.slug == slug) {
+                return Err(ProjectRepositoryError::SlugAlreadyExists);
+            }
+
+            let now = Utc::now();
+            let project = ProjectResult {
+                id: Uuid::new_v4(),
+                owner: data.owner,
+                title: data.title.trim().to_string(),
+                slug,
+                description: data.description,
+                tech_stack: data.tech_stack,
+                screenshots: data.screenshots,
+                repo_url: data.repo_url,
+                live_demo_url: data.live_demo_url,
+                seo_title: data.seo_title,
+                seo_description: data.seo_description,
+                canonical_url: data.canonical_url,
+                noindex: data.noindex,
+                created_at: now,
+                updated_at: now,
+            };
+            projects.push(ProjectRow {
+                project: project.clone(),
+                deleted_at: None,
+            });
+            Ok(project)
+        })
+    }
+
+    async fn patch_project(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        data: PatchProjectData,
+    ) -> Result<ProjectResult, ProjectRepositoryError> {
+        fn patch_optional(field: PatchField<String>, current: &mut Option<String>) {
+            match field {
+                PatchField::Unset => {}
+                PatchField::Null => *current = None,
+                PatchField::Value(value) => *current = Some(value),
+            }
+        }
+
+        self.projects.write(|projects| {
+            let project = &mut projects
+                .iter_mut()
+                .find(|row| row.is_live_for(owner, project_id))
+                .ok_or(ProjectRepositoryError::NotFound)?
+                .project;
+
+            let has_changes = !(data.title.is_unset()
+                && data.description.is_unset()
+                && data.tech_stack.is_unset()
+                && data.screenshots.is_unset()
+                && data.repo_url.is_unset()
+                && data.live_demo_url.is_unset()
+                && data.seo_title.is_unset()
+                && data.seo_description.is_unset()
+                && data.canonical_url.is_unset()
+                && data.noindex.is_none());
+            if has_changes
+                && data
+                    .expected_updated_at
+                    .is_some_and(|expected| project.updated_at > expected)
+            {
+                return Err(ProjectRepositoryError::Stale);
+            }
+
+            if let PatchField::Value(title) = data.title {
+                project.title = title.trim().to_string();
+            }
+            if let PatchField::Value(description) = data.description {
+                project.description = description;
+            }
+            if let PatchField::Value(tech_stack) = data.tech_stack {
+                project.tech_stack = tech_stack;
+            }
+            if let PatchField::Value(screenshots) = data.screenshots {
+                project.screenshots = screenshots;
+            }
+            patch_optional(data.repo_url, &mut project.repo_url);
+            patch_optional(data.live_demo_url, &mut project.live_demo_url);
+            patch_optional(data.seo_title, &mut project.seo_title);
+            patch_optional(data.seo_description, &mut project.seo_description);
+            patch_optional(data.canonical_url, &mut project.canonical_url);
+            if let Some(noindex) = data.noindex {
+                project.noindex = noindex;
+            }
+
+            // Any written column bumps updated_at, as the Postgres trigger does
+            if has_changes {
+                project.updated_at = Utc::now();
+            }
+            Ok(project.clone())
+        })
+    }
+}
+
+#[async_trait]
+impl ProjectQuery for InMemoryProjectStore {
+    async fn get_by_id(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+    ) -> Result<ProjectView, ProjectQueryError> {
+        let topics = self.get_project_topics(project_id).await?;
+        let updates = self.project_updates(project_id);
+        self.projects.read(|projects| {
+            projects
+                .iter()
+                .find(|row| row.is_live_for(owner, project_id))
+                .map(|row| row.to_view(topics, updates))
+                .ok_or(ProjectQueryError::NotFound)
+        })
+    }
+
+    async fn get_by_slug(&self, slug: &str) -> Result<ProjectView, ProjectQueryError> {
+        let slug = slug.trim().to_lowercase();
+        let project_id = self
+            .projects
+            .read(|projects| {
+                projects
+                    .iter()
+                    .find(|row| row.project.slug == slug && row.is_live())
+                    .map(|row| row.project.id)
+            })
+            .ok_or(ProjectQueryError::NotFound)?;
+
+        let topics = self.get_project_topics(project_id).await?;
+        let updates = self.project_updates(project_id);
+        self.projects.read(|projects| {
+            projects
+                .iter()
+                .find(|row| row.project.id == project_id && row.is_live())
+                .map(|row| row.to_view(topics, updates))
+                .ok_or(ProjectQueryError::NotFound)
+        })
+    }
+
+    async fn find_tombstone(
+        &self,
+        owner: UserId,
+        slug: &str,
+    ) -> Result<Option<DateTime<Utc>>, ProjectQueryError> {
+        let slug = slug.trim().to_lowercase();
+        Ok(self.projects.read(|projects| {
+            projects
+                .iter()
+                .filter(|row| row.project.owner == owner && row.project.slug == slug)
+                .filter_map(|row| row.deleted_at)
+                .max()
+        }))
+    }
+
+    async fn list(
+        &self,
+        owner: UserId,
+        filter: ProjectListFilter,
+        sort: ProjectSort,
+        page: PageRequest,
+    ) -> Result<PageResult<ProjectCardView>, ProjectQueryError> {
+        let term = filter.search.as_deref().map(str::trim);
+        let with_topic: Option<Vec<Uuid>> = filter.topic_id.map(|topic_id| {
+            self.links.read(|links| {
+                links
+                    .iter()
+                    .filter(|link| link.topic_id == topic_id)
+                    .map(|link| link.project_id)
+                    .collect()
+            })
+        });
+
+        self.projects.read(|projects| {
+            let mut matching: Vec<&ProjectRow> = projects
+                .iter()
+                .filter(|row| row.project.owner == owner && row.is_live())
+                .filter(|row| term.is_none_or(|term| row.matches(term)))
+                .filter(|row| {
+                    with_topic
+                        .as_ref()
+                        .is_none_or(|ids| ids.contains(&row.project.id))
+                })
+                .collect();
+
+            match sort {
+                ProjectSort::Newest => {
+                    matching.sort_by_key(|row| std::cmp::Reverse(row.project.created_at))
+                }
+                ProjectSort::Oldest => matching.sort_by_key(|row| row.project.created_at),
+                ProjectSort::UpdatedNewest => {
+                    matching.sort_by_key(|row| std::cmp::Reverse(row.project.updated_at))
+                }
+                ProjectSort::UpdatedOldest => matching.sort_by_key(|row| row.project.updated_at),
+            }
+
+            Ok(PageResult {
+                total: matching.len() as u64,
+                items: matching
+                    .iter()
+                    .skip(page.offset as usize)
+                    .take(page.limit as usize)
+                    .map(|row| row.to_card_view())
+                    .collect(),
+            })
+        })
+    }
+
+    /// Linked topics by title; `NotFound` unless the project is live
+    async fn get_project_topics(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<ProjectTopicItem>, ProjectQueryError> {
+        let live = self.projects.read(|projects| {
+            projects
+                .iter()
+                .any(|row| row.project.id == project_id && row.is_live())
+        });
+        if !live {
+            return Err(ProjectQueryError::NotFound);
+        }
+
+        let topic_ids: Vec<Uuid> = self.links.read(|links| {
+            links
+                .iter()
+                .filter(|link| link.project_id == project_id)
+                .map(|link| link.topic_id)
+                .collect()
+        });
+        let mut topics: Vec<ProjectTopicItem> = self.topics.topics.read(|topics| {
+            topics
+                .iter()
+                .filter(|row| topic_ids.contains(&row.topic.id))
+                .map(|row| ProjectTopicItem {
+                    id: row.topic.id,
+                    title: row.topic.title.clone(),
+                    description: row.topic.description.clone(),
+                })
+                .collect()
+        });
+        topics.sort_by(|a, b| a.title.cmp(&b.title));
+        Ok(topics)
+    }
+
+    async fn slug_exists(&self, slug: &str) -> Result<bool, ProjectQueryError> {
+        let slug = slug.trim().to_lowercase();
+        Ok(self.projects.read(|projects| {
+            projects
+                .iter()
+                .any(|row| row.project.slug == slug && row.is_live())
+        }))
+    }
+}
+
+#[async_trait]
+impl ProjectTopicRepository for InMemoryProjectStore {
+    async fn add_project_topic(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        topic_id: Uuid,
+    ) -> Result<(), ProjectTopicRepositoryError> {
+        self.link_topic(owner, project_id, topic_id)
+    }
+
+    async fn remove_project_topic(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        topic_id: Uuid,
+    ) -> Result<(), ProjectTopicRepositoryError> {
+        let link = ProjectTopicLink {
+            project_id,
+            topic_id,
+        };
+        self.links
+            .write(|links| links.retain(|existing| *existing != link));
+        self.ensure_project_ok(owner, project_id)
+    }
+
+    async fn clear_project_topics(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+    ) -> Result<(), ProjectTopicRepositoryError> {
+        self.links
+            .write(|links| links.retain(|link| link.project_id != project_id));
+        self.ensure_project_ok(owner, project_id)
+    }
+
+    /// Validates every topic before touching the links, standing in for the
+    /// Postgres transaction rollback
+    async fn set_project_topics(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        topic_ids: Vec<Uuid>,
+    ) -> Result<(), ProjectTopicRepositoryError> {
+        self.ensure_project_ok(owner, project_id)?;
+
+        let all_owned = self.topics.topics.read(|topics| {
+            topic_ids.iter().all(|topic_id| {
+                topics
+                    .iter()
+                    .any(|row| row.topic.id == *topic_id && row.topic.owner == owner)
+            })
+        });
+        if !all_owned {
+            return Err(ProjectTopicRepositoryError::TopicNotFound);
+        }
+
+        self.links
+            .write(|links| links.retain(|link| link.project_id != project_id));
+        for topic_id in topic_ids {
+            self.link_topic(owner, project_id, topic_id)?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectUpdateRepository for InMemoryProjectStore {
+    async fn create_update(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        data: CreateProjectUpdateData,
+    ) -> Result<ProjectUpdate, ProjectUpdateRepositoryError> {
+        self.touch_project(owner, project_id)?;
+
+        let now = Utc::now();
+        let update = ProjectUpdate {
+            id: Uuid::new_v4(),
+            title: data.title,
+            body: data.body,
+            posted_at: data.posted_at.unwrap_or(now),
+            created_at: now,
+            updated_at: now,
+        };
+        self.updates.write(|rows| {
+            rows.push(ProjectUpdateRow {
+                project_id,
+                update: update.clone(),
+            })
+        });
+        Ok(update)
+    }
+
+    async fn patch_update(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        update_id: Uuid,
+        data: PatchProjectUpdateData,
+    ) -> Result<ProjectUpdate, ProjectUpdateRepositoryError> {
+        self.ensure_update_exists(owner, project_id, update_id)?;
+        self.touch_project(owner, project_id)?;
+
+        self.updates.write(|rows| {
+            let row = rows
+                .iter_mut()
+                .find(|row| row.project_id == project_id && row.update.id == update_id)
+                .ok_or(ProjectUpdateRepositoryError::UpdateNotFound)?;
+            if let Some(title) = data.title {
+                row.update.title = title;
+            }
+            if let Some(body) = data.body {
+                row.update.body = body;
+            }
+            if let Some(posted_at) = data.posted_at {
+                row.update.posted_at = posted_at;
+            }
+            row.update.updated_at = Utc::now();
+            Ok(row.update.clone())
+        })
+    }
+
+    async fn delete_update(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        update_id: Uuid,
+    ) -> Result<(), ProjectUpdateRepositoryError> {
+        self.ensure_update_exists(owner, project_id, update_id)?;
+        self.touch_project(owner, project_id)?;
+
+        self.updates.write(|rows| {
+            rows.retain(|row| !(row.project_id == project_id && row.update.id == update_id))
+        });
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectArchiver for InMemoryProjectStore {
+    async fn soft_delete(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+    ) -> Result<(), ProjectArchiverError> {
+        self.set_deleted_at(owner, project_id, true)
+    }
+
+    /// Drops the project's topic links, timeline and README sync with it,
+    /// like the FK cascades
+    async fn hard_delete(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+    ) -> Result<(), ProjectArchiverError> {
+        self.projects.write(|projects| {
+            let before = projects.len();
+            projects.retain(|row| !(row.project.id == project_id && row.project.owner == owner));
+            if projects.len() == before {
+                return Err(ProjectArchiverError::NotFound);
+            }
+            Ok(())
+        })?;
+        self.links
+            .write(|links| links.retain(|link| link.project_id != project_id));
+        self.updates
+            .write(|updates| updates.retain(|row| row.project_id != project_id));
+        self.readme_syncs
+            .write(|syncs| syncs.retain(|sync| sync.project_id != project_id));
+        Ok(())
+    }
+
+    async fn restore(&self, owner: UserId, project_id: Uuid) -> Result<(), ProjectArchiverError> {
+        self.set_deleted_at(owner, project_id, false)
+    }
+}
+
+#[async_trait]
+impl ReadmeSyncRepository for InMemoryProjectStore {
+    async fn find(&self, project_id: Uuid) -> Result<Option<ReadmeSync>, ProjectRepositoryError> {
+        Ok(self.readme_syncs.read(|syncs| {
+            syncs
+                .iter()
+                .find(|sync| sync.project_id == project_id)
+                .cloned()
+        }))
+    }
+
+    async fn save(&self, sync: ReadmeSync) -> Result<(), ProjectRepositoryError> {
+        self.readme_syncs.write(|syncs| {
+            syncs.retain(|existing| existing.project_id != sync.project_id);
+            syncs.push(sync);
+        });
+        Ok(())
+    }
+
+    async fn due(
+        &self,
+        synced_before: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<ReadmeSyncTarget>, ProjectRepositoryError> {
+        let mut due: Vec<ReadmeSync> = self.readme_syncs.read(|syncs| {
+            syncs
+                .iter()
+                .filter(|sync| sync.keep_in_sync && sync.synced_at < synced_before)
+                .cloned()
+                .collect()
+        });
+        due.sort_by_key(|sync| sync.synced_at);
+
+        Ok(self.projects.read(|projects| {
+            due.iter()
+                .filter_map(|sync| {
+                    projects
+                        .iter()
+                        .find(|row| row.project.id == sync.project_id && row.is_live())
+                        .map(|row| ReadmeSyncTarget {
+                            owner: row.project.owner,
+                            project_id: sync.project_id,
+                        })
+                })
+                .take(limit as usize)
+                .collect()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::topic::application::ports::outgoing::{CreateTopicData, TopicRepository};
+
+    fn new_project(owner: UserId, slug: &str) -> CreateProjectData {
+        CreateProjectData {
+            owner,
+            title: "Portfolio".to_string(),
+            slug: slug.to_string(),
+            description: "My site".to_string(),
+            tech_stack: vec!["Rust".to_string()],
+            screenshots: vec![],
+            repo_url: None,
+            live_demo_url: None,
+            seo_title: None,
+            seo_description: None,
+            canonical_url: None,
+            noindex: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_topic_links_check_the_topic_before_the_project() {
+        let topics = InMemoryTopicStore::default();
+        let store = InMemoryProjectStore::new(topics.clone());
+        let owner = UserId::from(Uuid::new_v4());
+        let project = store
+            .create_project(new_project(owner, "portfolio"))
+            .await
+            .unwrap();
+        let topic = topics
+            .create_topic(CreateTopicData {
+                owner,
+                title: "Rust".to_string(),
+                description: String::new(),
+            })
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            store
+                .add_project_topic(owner, Uuid::new_v4(), Uuid::new_v4())
+                .await,
+            Err(ProjectTopicRepositoryError::TopicNotFound)
+        ));
+        assert!(matches!(
+            store
+                .add_project_topic(owner, Uuid::new_v4(), topic.id)
+                .await,
+            Err(ProjectTopicRepositoryError::ProjectNotFound)
+        ));
+
+        // Idempotent
+        store
+            .add_project_topic(owner, project.id, topic.id)
+            .await
+            .unwrap();
+        store
+            .add_project_topic(owner, project.id, topic.id)
+            .await
+            .unwrap();
+
+        let view = store.get_by_slug(" PORTFOLIO ").await.unwrap();
+        assert_eq!(view.topics.len(), 1);
+        assert_eq!(view.topics[0].title, "Rust");
+    }
+
+    #[tokio::test]
+    async fn test_trashed_project_is_hidden_and_frees_nothing() {
+        let store = InMemoryProjectStore::default();
+        let owner = UserId::from(Uuid::new_v4());
+        let project = store
+            .create_project(new_project(owner, "portfolio"))
+            .await
+            .unwrap();
+
+        store.soft_delete(owner, project.id).await.unwrap();
+
+        assert!(matches!(
+            store.get_by_id(owner, project.id).await,
+            Err(ProjectQueryError::NotFound)
+        ));
+        assert!(!store.slug_exists("portfolio").await.unwrap());
+        // The unique index still covers the trashed row
+        assert!(matches!(
+            store.create_project(new_project(owner, "portfolio")).await,
+            Err(ProjectRepositoryError::SlugAlreadyExists)
+        ));
+        assert!(matches!(
+            store.soft_delete(owner, project.id).await,
+            Err(ProjectArchiverError::NotFound)
+        ));
+        store.restore(owner, project.id).await.unwrap();
+    }
+}
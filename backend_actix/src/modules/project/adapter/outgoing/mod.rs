@@ -1,10 +1,19 @@
+mod github_repo_metadata;
 mod project_archiver_postgres;
 mod project_query_postgres;
 mod project_repository_postgres;
 mod project_topic_repository_postgres;
+mod project_update_repository_postgres;
+mod readme_sync_repository_postgres;
 pub mod sea_orm_entity;
 
+pub use github_repo_metadata::GitHubRepoMetadataProvider;
 pub use project_archiver_postgres::ProjectArchiverPostgres;
 pub use project_query_postgres::ProjectQueryPostgres;
 pub use project_repository_postgres::ProjectRepositoryPostgres;
 pub use project_topic_repository_postgres::ProjectTopicRepositoryPostgres;
+pub use project_update_repository_postgres::ProjectUpdateRepositoryPostgres;
+pub use readme_sync_repository_postgres::ReadmeSyncRepositoryPostgres;
+
+mod in_memory;
+pub use in_memory::InMemoryProjectStore;
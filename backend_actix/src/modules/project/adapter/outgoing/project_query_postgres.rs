@@ -1,22 +1,28 @@
 // src/modules/project/adapter/outgoing/project_query_postgres.rs
 
 use async_trait::async_trait;
-use sea_orm::sea_query::extension::postgres::PgExpr;
+use chrono::{DateTime, Utc};
 use sea_orm::{
-    sea_query::Expr, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait,
-    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+    sea_query::Expr, ColumnTrait, Condition, ConnectionTrait, DatabaseBackend, DatabaseConnection,
+    DbErr, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait, Select,
+    Statement,
 };
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::adapter::outgoing::project_update_repository_postgres::{
+    to_project_update, PROJECT_UPDATE_COLUMNS, PROJECT_UPDATE_ORDER,
+};
 use crate::modules::project::adapter::outgoing::sea_orm_entity::project_topics;
 use crate::modules::project::adapter::outgoing::sea_orm_entity::projects::{self, Column, Entity};
 use crate::modules::project::application::ports::outgoing::project_query::{
     PageRequest, PageResult, ProjectCardView, ProjectListFilter, ProjectQuery, ProjectQueryError,
     ProjectSort, ProjectView,
 };
+use crate::modules::project::application::ports::outgoing::project_update_repository::ProjectUpdate;
 use crate::project::application::ports::outgoing::project_query::ProjectTopicItem;
+use crate::shared::sql;
 
 // ============================================================================
 // Repository Implementation
@@ -31,6 +37,130 @@ impl ProjectQueryPostgres {
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
         Self { db }
     }
+
+    /// Owner's live projects matching `filter`. Leads with the columns of
+    /// idx_projects_user_active_updated (user_id, is_deleted, updated_at).
+    fn list_query(
+        backend: DatabaseBackend,
+        owner: Uuid,
+        filter: &ProjectListFilter,
+    ) -> Select<Entity> {
+        // Base query
+        let mut query = Entity::find()
+            .filter(Column::UserId.eq(owner))
+            .filter(Column::IsDeleted.eq(false))
+            .filter(Expr::cust(sql::owner_is_live("projects.user_id")));
+
+        // Apply search filter with ILIKE
+        if let Some(ref search) = filter.search {
+            let term = search.trim();
+            let search_pattern = format!("%{}%", term);
+
+            // tech_stack element ILIKE pattern
+            // Uses EXISTS with jsonb_array_elements_text(tech_stack), or
+            // json_each(tech_stack) on SQLite
+            let (tech_stack_elements, elem) = match backend {
+                DatabaseBackend::Sqlite => ("json_each(tech_stack)", "value"),
+                _ => ("jsonb_array_elements_text(tech_stack) AS elem", "elem"),
+            };
+            let tech_stack_expr = Expr::cust_with_values(
+                format!(
+                    r#"
+                    EXISTS (
+                        SELECT 1
+                        FROM {tech_stack_elements}
+                        WHERE {elem} {ilike} $1
+                    )
+                    "#,
+                    ilike = sql::ilike(backend),
+                ),
+                [search_pattern.clone()],
+            );
+
+            query = query.filter(
+                Condition::any()
+                    .add(sql::ilike_expr(backend, Column::Title, &search_pattern))
+                    .add(sql::ilike_expr(
+                        backend,
+                        Column::Description,
+                        &search_pattern,
+                    ))
+                    .add(sql::ilike_expr(backend, Column::Slug, &search_pattern))
+                    .add(tech_stack_expr),
+            );
+        }
+
+        // Apply topic filter as a subquery, so the planner can join it
+        // instead of receiving a literal id list
+        if let Some(topic_id) = filter.topic_id {
+            let project_ids_with_topic = project_topics::Entity::find()
+                .select_only()
+                .column(project_topics::Column::ProjectId)
+                .filter(project_topics::Column::TopicId.eq(topic_id))
+                .into_query();
+
+            query = query.filter(Column::Id.in_subquery(project_ids_with_topic));
+        }
+
+        query
+    }
+
+    /// `deleted_at` is set by trigger; rows trashed before it existed fall
+    /// back to `updated_at`
+    fn find_tombstone_stmt(backend: DatabaseBackend, owner: Uuid, slug: &str) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT COALESCE(deleted_at, updated_at) AS deleted_at
+            FROM projects
+            WHERE user_id = $1 AND slug = $2 AND is_deleted = true
+            ORDER BY deleted_at DESC
+            LIMIT 1
+            "#,
+            vec![owner.into(), slug.into()],
+        )
+    }
+
+    fn updates_stmt(backend: DatabaseBackend, project_id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                SELECT {PROJECT_UPDATE_COLUMNS}
+                FROM project_updates
+                WHERE project_id = $1
+                ORDER BY {PROJECT_UPDATE_ORDER}
+                "#
+            ),
+            vec![project_id.into()],
+        )
+    }
+
+    /// Timeline of a project already found live, newest first
+    async fn get_project_updates(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<ProjectUpdate>, ProjectQueryError> {
+        self.db
+            .query_all(Self::updates_stmt(
+                self.db.get_database_backend(),
+                project_id,
+            ))
+            .await
+            .map_err(map_db_err)?
+            .iter()
+            .map(|row| to_project_update(row).map_err(map_db_err))
+            .collect()
+    }
+
+    fn apply_sort(query: Select<Entity>, sort: ProjectSort) -> Select<Entity> {
+        match sort {
+            ProjectSort::Newest => query.order_by_desc(Column::CreatedAt),
+            ProjectSort::Oldest => query.order_by_asc(Column::CreatedAt),
+            ProjectSort::UpdatedNewest => query.order_by_desc(Column::UpdatedAt),
+            ProjectSort::UpdatedOldest => query.order_by_asc(Column::UpdatedAt),
+        }
+    }
 }
 
 #[async_trait]
@@ -51,8 +181,9 @@ impl ProjectQuery for ProjectQueryPostgres {
             .ok_or(ProjectQueryError::NotFound)?;
 
         let topics = self.get_project_topics(project_id).await?;
+        let updates = self.get_project_updates(project_id).await?;
 
-        model_to_view(project, topics)
+        model_to_view(project, topics, updates)
     }
 
     async fn get_by_slug(&self, slug: &str) -> Result<ProjectView, ProjectQueryError> {
@@ -61,14 +192,16 @@ impl ProjectQuery for ProjectQueryPostgres {
         let project = Entity::find()
             .filter(Column::Slug.eq(&normalized_slug))
             .filter(Column::IsDeleted.eq(false))
+            .filter(Expr::cust(sql::owner_is_live("projects.user_id")))
             .one(&*self.db)
             .await
             .map_err(map_db_err)?
             .ok_or(ProjectQueryError::NotFound)?;
 
         let topics = self.get_project_topics(project.id).await?;
+        let updates = self.get_project_updates(project.id).await?;
 
-        model_to_view(project, topics)
+        model_to_view(project, topics, updates)
     }
 
     async fn list(
@@ -78,70 +211,15 @@ impl ProjectQuery for ProjectQueryPostgres {
         sort: ProjectSort,
         page: PageRequest,
     ) -> Result<PageResult<ProjectCardView>, ProjectQueryError> {
-        let owner_uuid: Uuid = owner.into();
+        let query = Self::list_query(self.db.get_database_backend(), owner.into(), &filter);
 
-        // Base query
-        let mut query = Entity::find()
-            .filter(Column::UserId.eq(owner_uuid))
-            .filter(Column::IsDeleted.eq(false));
-
-        // Apply search filter with ILIKE
-        if let Some(ref search) = filter.search {
-            let term = search.trim();
-            let search_pattern = format!("%{}%", term);
-
-            // tech_stack element ILIKE pattern
-            // Uses EXISTS with jsonb_array_elements_text(tech_stack)
-            let tech_stack_expr = Expr::cust_with_values(
-                r#"
-                    EXISTS (
-                        SELECT 1
-                        FROM jsonb_array_elements_text(tech_stack) AS elem
-                        WHERE elem ILIKE $1
-                    )
-                    "#,
-                [search_pattern.clone()],
-            );
-
-            query = query.filter(
-                Condition::any()
-                    .add(Expr::col(Column::Title).ilike(&search_pattern))
-                    .add(Expr::col(Column::Description).ilike(&search_pattern))
-                    .add(Expr::col(Column::Slug).ilike(&search_pattern))
-                    .add(tech_stack_expr),
-            );
-        }
-
-        // Apply topic filter via subquery
-        if let Some(topic_id) = filter.topic_id {
-            let project_ids_with_topic = project_topics::Entity::find()
-                .filter(project_topics::Column::TopicId.eq(topic_id))
-                .select_only()
-                .column(project_topics::Column::ProjectId)
-                .into_tuple::<Uuid>()
-                .all(&*self.db)
-                .await
-                .map_err(map_db_err)?;
-
-            query = query.filter(Column::Id.is_in(project_ids_with_topic));
-        }
-
-        // Apply sorting
-        query = match sort {
-            ProjectSort::Newest => query.order_by_desc(Column::CreatedAt),
-            ProjectSort::Oldest => query.order_by_asc(Column::CreatedAt),
-            ProjectSort::UpdatedNewest => query.order_by_desc(Column::UpdatedAt),
-            ProjectSort::UpdatedOldest => query.order_by_asc(Column::UpdatedAt),
-        };
-
-        // Get total count
+        // Get total count (before ordering; it doesn't change the count)
         let total = query.clone().count(&*self.db).await.map_err(map_db_err)?;
 
-        // Apply pagination
-        let offset = ((page.page.saturating_sub(1)) * page.per_page) as u64;
-        let projects = query
-            .offset(offset)
-            .limit(page.per_page as u64)
+        // Apply sorting and pagination
+        let projects = Self::apply_sort(query, sort)
+            .offset(page.offset)
+            .limit(page.limit as u64)
             .all(&*self.db)
             .await
             .map_err(map_db_err)?;
@@ -152,8 +230,6 @@ impl ProjectQuery for ProjectQueryPostgres {
 
         Ok(PageResult {
             items: items?,
-            page: page.page,
-            per_page: page.per_page,
             total,
         })
     }
@@ -162,7 +238,7 @@ impl ProjectQuery for ProjectQueryPostgres {
         &self,
         project_id: Uuid,
     ) -> Result<Vec<ProjectTopicItem>, ProjectQueryError> {
-        use sea_orm::{ConnectionTrait, DatabaseBackend, Statement};
+        use sea_orm::Statement;
 
         let stmt = Statement::from_sql_and_values(
             DatabaseBackend::Postgres,
@@ -228,6 +304,25 @@ impl ProjectQuery for ProjectQueryPostgres {
 
         Ok(count > 0)
     }
+
+    async fn find_tombstone(
+        &self,
+        owner: UserId,
+        slug: &str,
+    ) -> Result<Option<DateTime<Utc>>, ProjectQueryError> {
+        let normalized_slug = slug.trim().to_lowercase();
+
+        self.db
+            .query_one(Self::find_tombstone_stmt(
+                self.db.get_database_backend(),
+                owner.into(),
+                &normalized_slug,
+            ))
+            .await
+            .map_err(map_db_err)?
+            .map(|row| row.try_get("", "deleted_at").map_err(map_db_err))
+            .transpose()
+    }
 }
 
 // ============================================================================
@@ -237,6 +332,7 @@ impl ProjectQuery for ProjectQueryPostgres {
 fn model_to_view(
     model: projects::Model,
     topics: Vec<ProjectTopicItem>,
+    updates: Vec<ProjectUpdate>,
 ) -> Result<ProjectView, ProjectQueryError> {
     Ok(ProjectView {
         id: model.id,
@@ -248,7 +344,12 @@ fn model_to_view(
         screenshots: from_json(&model.screenshots)?,
         repo_url: model.repo_url,
         live_demo_url: model.live_demo_url,
+        seo_title: model.seo_title,
+        seo_description: model.seo_description,
+        canonical_url: model.canonical_url,
+        noindex: model.noindex,
         topics,
+        updates,
         created_at: model.created_at.into(),
         updated_at: model.updated_at.into(),
     })
@@ -287,7 +388,6 @@ mod tests {
     use std::collections::BTreeMap;
 
     use super::*;
-    use chrono::Utc;
     use sea_orm::{DatabaseBackend, MockDatabase, Value};
 
     fn create_mock_project_model(
@@ -308,6 +408,10 @@ mod tests {
             screenshots: serde_json::json!(["img1.png"]),
             repo_url: Some("https://github.com/test/repo".to_string()),
             live_demo_url: Some("https://demo.test.com".to_string()),
+            seo_title: None,
+            seo_description: None,
+            canonical_url: None,
+            noindex: false,
             is_deleted: false,
             created_at: now,
             updated_at: now,
@@ -350,6 +454,15 @@ mod tests {
                     Value::String(Some(Box::new("Systems".to_string()))),
                 ),
             ])]])
+            .append_query_results(vec![vec![BTreeMap::from([
+                ("id".to_string(), Value::from(Uuid::new_v4())),
+                ("project_id".to_string(), Value::from(project_id)),
+                ("title".to_string(), Value::from("v0.1")),
+                ("body".to_string(), Value::from("First release")),
+                ("posted_at".to_string(), Value::from(Utc::now())),
+                ("created_at".to_string(), Value::from(Utc::now())),
+                ("updated_at".to_string(), Value::from(Utc::now())),
+            ])]])
             .into_connection();
 
         let query = ProjectQueryPostgres::new(Arc::new(db));
@@ -361,6 +474,8 @@ mod tests {
         assert_eq!(view.title, "Test Project");
         assert_eq!(view.topics.len(), 1);
         assert_eq!(view.topics[0].id, topic_id);
+        assert_eq!(view.updates.len(), 1);
+        assert_eq!(view.updates[0].title, "v0.1");
     }
 
     #[tokio::test]
@@ -401,6 +516,7 @@ mod tests {
                 ("topic_title".to_string(), Value::String(None)),
                 ("topic_description".to_string(), Value::String(None)),
             ])]])
+            .append_query_results(vec![Vec::<BTreeMap<String, Value>>::new()])
             .into_connection();
 
         let query = ProjectQueryPostgres::new(Arc::new(db));
@@ -434,6 +550,7 @@ mod tests {
                 ("topic_title".to_string(), Value::String(None)),
                 ("topic_description".to_string(), Value::String(None)),
             ])]])
+            .append_query_results(vec![Vec::<BTreeMap<String, Value>>::new()])
             .into_connection();
 
         let query = ProjectQueryPostgres::new(Arc::new(db));
@@ -463,6 +580,7 @@ mod tests {
                 ("topic_title".to_string(), Value::String(None)),
                 ("topic_description".to_string(), Value::String(None)),
             ])]])
+            .append_query_results(vec![Vec::<BTreeMap<String, Value>>::new()])
             .into_connection();
 
         let query = ProjectQueryPostgres::new(Arc::new(db));
@@ -630,6 +748,44 @@ mod tests {
     // Note: list() uses count() which is difficult to mock.
     // Use integration tests for full list coverage.
 
+    #[test]
+    fn test_list_query_skips_deleted_owners() {
+        let sql = ProjectQueryPostgres::list_query(
+            DatabaseBackend::Postgres,
+            Uuid::new_v4(),
+            &ProjectListFilter::default(),
+        )
+        .build(DatabaseBackend::Postgres)
+        .to_string();
+
+        assert!(
+            sql.contains("u.id = projects.user_id AND u.is_deleted = false"),
+            "{sql}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_explain_list_uses_user_updated_index() {
+        let Some(db) = crate::tests::support::database::test_postgres_db().await else {
+            return;
+        };
+
+        let stmt = ProjectQueryPostgres::apply_sort(
+            ProjectQueryPostgres::list_query(
+                DatabaseBackend::Postgres,
+                Uuid::new_v4(),
+                &ProjectListFilter::default(),
+            ),
+            ProjectSort::UpdatedNewest,
+        )
+        .limit(20)
+        .build(DatabaseBackend::Postgres);
+        let plan = crate::tests::support::explain::explain(&db, stmt).await;
+
+        assert!(plan.contains("idx_projects_user_active_updated"), "{plan}");
+        assert!(!plan.contains("Sort"), "{plan}");
+    }
+
     // ========================================================================
     // Helper Function Tests
     // ========================================================================
@@ -681,7 +837,7 @@ mod tests {
             description: "Systems programming".to_string(),
         }];
 
-        let result = model_to_view(model, topics);
+        let result = model_to_view(model, topics, vec![]);
 
         assert!(result.is_ok());
         let view = result.unwrap();
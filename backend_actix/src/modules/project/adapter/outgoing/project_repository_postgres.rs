@@ -15,6 +15,7 @@ use crate::modules::project::application::ports::outgoing::project_repository::{
     CreateProjectData, PatchField, PatchProjectData, ProjectRepository, ProjectRepositoryError,
     ProjectResult,
 };
+use crate::shared::unit_of_work::connection;
 
 // ============================================================================
 // Repository Implementation
@@ -50,12 +51,19 @@ impl ProjectRepository for ProjectRepositoryPostgres {
             screenshots: Set(to_json(&data.screenshots)?),
             repo_url: Set(data.repo_url),
             live_demo_url: Set(data.live_demo_url),
+            seo_title: Set(data.seo_title),
+            seo_description: Set(data.seo_description),
+            canonical_url: Set(data.canonical_url),
+            noindex: Set(data.noindex),
             is_deleted: Set(false),
             created_at: Set(now),
             updated_at: Set(now),
         };
 
-        let result = model.insert(&*self.db).await.map_err(map_slug_error)?;
+        let result = model
+            .insert(&connection(&self.db))
+            .await
+            .map_err(map_slug_error)?;
 
         model_to_result(result)
     }
@@ -98,18 +106,38 @@ impl ProjectRepository for ProjectRepositoryPostgres {
             PatchField::Value(url) => model.live_demo_url = Set(Some(url)),
         }
 
+        for (field, column) in [
+            (data.seo_title, &mut model.seo_title),
+            (data.seo_description, &mut model.seo_description),
+            (data.canonical_url, &mut model.canonical_url),
+        ] {
+            match field {
+                PatchField::Unset => {}
+                PatchField::Null => *column = Set(None),
+                PatchField::Value(text) => *column = Set(Some(text)),
+            }
+        }
+
+        if let Some(noindex) = data.noindex {
+            model.noindex = Set(noindex);
+        }
+
         let has_changes = model.title.is_set()
             || model.description.is_set()
             || model.tech_stack.is_set()
             || model.screenshots.is_set()
             || model.repo_url.is_set()
-            || model.live_demo_url.is_set();
+            || model.live_demo_url.is_set()
+            || model.seo_title.is_set()
+            || model.seo_description.is_set()
+            || model.canonical_url.is_set()
+            || model.noindex.is_set();
 
         if !has_changes {
             let result = Entity::find_by_id(project_id)
                 .filter(Column::UserId.eq(owner_uuid))
                 .filter(Column::IsDeleted.eq(false))
-                .one(&*self.db)
+                .one(&connection(&self.db))
                 .await
                 .map_err(map_db_err)?
                 .ok_or(ProjectRepositoryError::NotFound)?;
@@ -117,21 +145,39 @@ impl ProjectRepository for ProjectRepositoryPostgres {
             return model_to_result(result);
         }
 
-        let results = Entity::update_many()
+        let mut update = Entity::update_many()
             .set(model)
             .filter(Column::Id.eq(project_id))
             .filter(Column::UserId.eq(owner_uuid))
-            .filter(Column::IsDeleted.eq(false))
-            .exec_with_returning(&*self.db)
+            .filter(Column::IsDeleted.eq(false));
+        if let Some(expected) = data.expected_updated_at {
+            update = update.filter(Column::UpdatedAt.lte(expected));
+        }
+
+        let results = update
+            .exec_with_returning(&connection(&self.db))
             .await
             .map_err(map_db_err)?;
 
-        let result = results
-            .into_iter()
-            .next()
-            .ok_or(ProjectRepositoryError::NotFound)?;
-
-        model_to_result(result)
+        match results.into_iter().next() {
+            Some(result) => model_to_result(result),
+            // Nothing matched: either no such project, or it moved past `expected`
+            None if data.expected_updated_at.is_some() => {
+                let exists = Entity::find_by_id(project_id)
+                    .filter(Column::UserId.eq(owner_uuid))
+                    .filter(Column::IsDeleted.eq(false))
+                    .one(&connection(&self.db))
+                    .await
+                    .map_err(map_db_err)?
+                    .is_some();
+                Err(if exists {
+                    ProjectRepositoryError::Stale
+                } else {
+                    ProjectRepositoryError::NotFound
+                })
+            }
+            None => Err(ProjectRepositoryError::NotFound),
+        }
     }
 }
 
@@ -150,6 +196,10 @@ fn model_to_result(model: projects::Model) -> Result<ProjectResult, ProjectRepos
         screenshots: from_json(&model.screenshots)?,
         repo_url: model.repo_url,
         live_demo_url: model.live_demo_url,
+        seo_title: model.seo_title,
+        seo_description: model.seo_description,
+        canonical_url: model.canonical_url,
+        noindex: model.noindex,
         created_at: model.created_at.into(),
         updated_at: model.updated_at.into(),
     })
@@ -203,6 +253,10 @@ mod tests {
             screenshots: vec!["screenshot1.png".to_string()],
             repo_url: Some("https://github.com/user/repo".to_string()),
             live_demo_url: Some("https://demo.example.com".to_string()),
+            seo_title: None,
+            seo_description: None,
+            canonical_url: None,
+            noindex: false,
         }
     }
 
@@ -224,6 +278,10 @@ mod tests {
             screenshots: serde_json::json!(["img1.png"]),
             repo_url: Some("https://github.com/test/repo".to_string()),
             live_demo_url: Some("https://demo.test.com".to_string()),
+            seo_title: None,
+            seo_description: None,
+            canonical_url: None,
+            noindex: false,
             is_deleted: false,
             created_at: now,
             updated_at: now,
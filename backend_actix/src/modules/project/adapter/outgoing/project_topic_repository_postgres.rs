@@ -9,6 +9,7 @@ use crate::auth::application::domain::entities::UserId;
 use crate::modules::project::application::ports::outgoing::project_topic_repository::{
     ProjectTopicRepository, ProjectTopicRepositoryError,
 };
+use crate::shared::unit_of_work::connection;
 
 #[derive(Clone)]
 pub struct ProjectTopicRepositoryPostgres {
@@ -179,8 +180,8 @@ impl ProjectTopicRepository for ProjectTopicRepositoryPostgres {
     ) -> Result<(), ProjectTopicRepositoryError> {
         let owner_uuid: Uuid = owner.into();
 
-        let result = self
-            .db
+        let db = connection(&self.db);
+        let result = db
             .execute(Self::guarded_insert_stmt(owner_uuid, project_id, topic_id))
             .await
             .map_err(Self::map_db_err)?;
@@ -190,7 +191,7 @@ impl ProjectTopicRepository for ProjectTopicRepositoryPostgres {
         }
 
         // 0 affected => either precondition failed or link already existed
-        Self::resolve_insert_failure(&*self.db, owner_uuid, project_id, topic_id).await
+        Self::resolve_insert_failure(&db, owner_uuid, project_id, topic_id).await
     }
 
     async fn remove_project_topic(
@@ -213,14 +214,12 @@ impl ProjectTopicRepository for ProjectTopicRepositoryPostgres {
             vec![owner_uuid.into(), project_id.into(), topic_id.into()],
         );
 
-        self.db
-            .execute(delete_stmt)
-            .await
-            .map_err(Self::map_db_err)?;
+        let db = connection(&self.db);
+        db.execute(delete_stmt).await.map_err(Self::map_db_err)?;
 
         // Enforce correct domain error if project invalid/not owned/deleted.
         // If project is OK, removal is idempotent success.
-        Self::ensure_project_ok(&*self.db, owner_uuid, project_id).await?;
+        Self::ensure_project_ok(&db, owner_uuid, project_id).await?;
 
         Ok(())
     }
@@ -241,14 +240,12 @@ impl ProjectTopicRepository for ProjectTopicRepositoryPostgres {
             vec![owner_uuid.into(), project_id.into()],
         );
 
-        self.db
-            .execute(delete_stmt)
-            .await
-            .map_err(Self::map_db_err)?;
+        let db = connection(&self.db);
+        db.execute(delete_stmt).await.map_err(Self::map_db_err)?;
 
         // If project is invalid/not owned/deleted => ProjectNotFound.
         // Otherwise idempotent success (even if there were no links).
-        Self::ensure_project_ok(&*self.db, owner_uuid, project_id).await?;
+        Self::ensure_project_ok(&db, owner_uuid, project_id).await?;
 
         Ok(())
     }
@@ -260,7 +257,10 @@ impl ProjectTopicRepository for ProjectTopicRepositoryPostgres {
         topic_ids: Vec<Uuid>,
     ) -> Result<(), ProjectTopicRepositoryError> {
         let owner_uuid: Uuid = owner.into();
-        let txn = self.db.begin().await.map_err(Self::map_db_err)?;
+        let txn = connection(&self.db)
+            .begin()
+            .await
+            .map_err(Self::map_db_err)?;
 
         // First validate the project (cheap and avoids ambiguous “empty” sets).
         if let Err(e) = Self::ensure_project_ok(&txn, owner_uuid, project_id).await {
@@ -0,0 +1,325 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement,
+    TransactionTrait,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::ports::outgoing::project_update_repository::{
+    CreateProjectUpdateData, PatchProjectUpdateData, ProjectUpdate, ProjectUpdateRepository,
+    ProjectUpdateRepositoryError,
+};
+use crate::shared::sql;
+
+pub(super) const PROJECT_UPDATE_COLUMNS: &str =
+    "id, project_id, title, body, posted_at, created_at, updated_at";
+
+/// Newest first; entries posted at the same time in the order they were written
+pub(super) const PROJECT_UPDATE_ORDER: &str = "posted_at DESC, created_at DESC, id";
+
+#[derive(Clone)]
+pub struct ProjectUpdateRepositoryPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl ProjectUpdateRepositoryPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    /// Moves `updated_at` of the owner's live project; no row when there is
+    /// no such project
+    fn touch_project_stmt(backend: DatabaseBackend, owner: Uuid, project_id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                UPDATE projects SET updated_at = {now}
+                WHERE id = $2 AND user_id = $1 AND is_deleted = false
+                "#,
+                now = sql::now(backend),
+            ),
+            vec![owner.into(), project_id.into()],
+        )
+    }
+
+    fn insert_stmt(
+        backend: DatabaseBackend,
+        project_id: Uuid,
+        data: &CreateProjectUpdateData,
+    ) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                INSERT INTO project_updates (id, project_id, title, body, posted_at)
+                VALUES ($1, $2, $3, $4, COALESCE($5, {now}))
+                RETURNING {PROJECT_UPDATE_COLUMNS}
+                "#,
+                now = sql::now(backend),
+            ),
+            vec![
+                Uuid::new_v4().into(),
+                project_id.into(),
+                data.title.clone().into(),
+                data.body.clone().into(),
+                data.posted_at.into(),
+            ],
+        )
+    }
+
+    fn patch_stmt(
+        backend: DatabaseBackend,
+        project_id: Uuid,
+        update_id: Uuid,
+        data: &PatchProjectUpdateData,
+    ) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                UPDATE project_updates
+                SET title = COALESCE($3, title),
+                    body = COALESCE($4, body),
+                    posted_at = COALESCE($5, posted_at),
+                    updated_at = {now}
+                WHERE id = $2 AND project_id = $1
+                RETURNING {PROJECT_UPDATE_COLUMNS}
+                "#,
+                now = sql::now(backend),
+            ),
+            vec![
+                project_id.into(),
+                update_id.into(),
+                data.title.clone().into(),
+                data.body.clone().into(),
+                data.posted_at.into(),
+            ],
+        )
+    }
+
+    fn delete_stmt(backend: DatabaseBackend, project_id: Uuid, update_id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            "DELETE FROM project_updates WHERE id = $2 AND project_id = $1",
+            vec![project_id.into(), update_id.into()],
+        )
+    }
+
+    /// Touches the project, so a missing project fails before the entry is
+    /// looked at
+    async fn touch_project<C: ConnectionTrait>(
+        conn: &C,
+        owner: UserId,
+        project_id: Uuid,
+    ) -> Result<(), ProjectUpdateRepositoryError> {
+        let res = conn
+            .execute(Self::touch_project_stmt(
+                conn.get_database_backend(),
+                owner.into(),
+                project_id,
+            ))
+            .await
+            .map_err(map_db_err)?;
+
+        if res.rows_affected() == 0 {
+            return Err(ProjectUpdateRepositoryError::ProjectNotFound);
+        }
+        Ok(())
+    }
+}
+
+/// The entry is written in the same transaction that touches its project
+#[async_trait]
+impl ProjectUpdateRepository for ProjectUpdateRepositoryPostgres {
+    async fn create_update(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        data: CreateProjectUpdateData,
+    ) -> Result<ProjectUpdate, ProjectUpdateRepositoryError> {
+        let txn = self.db.begin().await.map_err(map_db_err)?;
+        Self::touch_project(&txn, owner, project_id).await?;
+
+        let row = txn
+            .query_one(Self::insert_stmt(
+                txn.get_database_backend(),
+                project_id,
+                &data,
+            ))
+            .await
+            .map_err(map_db_err)?
+            .ok_or_else(|| {
+                ProjectUpdateRepositoryError::DatabaseError("insert returned no row".into())
+            })?;
+        let update = to_project_update(&row).map_err(map_db_err)?;
+
+        txn.commit().await.map_err(map_db_err)?;
+        Ok(update)
+    }
+
+    async fn patch_update(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        update_id: Uuid,
+        data: PatchProjectUpdateData,
+    ) -> Result<ProjectUpdate, ProjectUpdateRepositoryError> {
+        let txn = self.db.begin().await.map_err(map_db_err)?;
+        Self::touch_project(&txn, owner, project_id).await?;
+
+        // Dropping the transaction rolls the touch back
+        let row = txn
+            .query_one(Self::patch_stmt(
+                txn.get_database_backend(),
+                project_id,
+                update_id,
+                &data,
+            ))
+            .await
+            .map_err(map_db_err)?
+            .ok_or(ProjectUpdateRepositoryError::UpdateNotFound)?;
+        let update = to_project_update(&row).map_err(map_db_err)?;
+
+        txn.commit().await.map_err(map_db_err)?;
+        Ok(update)
+    }
+
+    async fn delete_update(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        update_id: Uuid,
+    ) -> Result<(), ProjectUpdateRepositoryError> {
+        let txn = self.db.begin().await.map_err(map_db_err)?;
+        Self::touch_project(&txn, owner, project_id).await?;
+
+        let res = txn
+            .execute(Self::delete_stmt(
+                txn.get_database_backend(),
+                project_id,
+                update_id,
+            ))
+            .await
+            .map_err(map_db_err)?;
+        if res.rows_affected() == 0 {
+            return Err(ProjectUpdateRepositoryError::UpdateNotFound);
+        }
+
+        txn.commit().await.map_err(map_db_err)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+pub(super) fn to_project_update(row: &QueryResult) -> Result<ProjectUpdate, DbErr> {
+    Ok(ProjectUpdate {
+        id: row.try_get("", "id")?,
+        title: row.try_get("", "title")?,
+        body: row.try_get("", "body")?,
+        posted_at: row.try_get("", "posted_at")?,
+        created_at: row.try_get("", "created_at")?,
+        updated_at: row.try_get("", "updated_at")?,
+    })
+}
+
+fn map_db_err(e: DbErr) -> ProjectUpdateRepositoryError {
+    ProjectUpdateRepositoryError::DatabaseError(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use sea_orm::{MockDatabase, MockExecResult, Value};
+    use std::collections::BTreeMap;
+
+    fn update_row(project_id: Uuid) -> BTreeMap<String, Value> {
+        BTreeMap::from([
+            ("id".to_string(), Value::from(Uuid::new_v4())),
+            ("project_id".to_string(), Value::from(project_id)),
+            ("title".to_string(), Value::from("v0.2")),
+            ("body".to_string(), Value::from("Added **search**")),
+            ("posted_at".to_string(), Value::from(Utc::now())),
+            ("created_at".to_string(), Value::from(Utc::now())),
+            ("updated_at".to_string(), Value::from(Utc::now())),
+        ])
+    }
+
+    #[tokio::test]
+    async fn test_create_touches_the_project_first() {
+        let project_id = Uuid::new_v4();
+        let db = Arc::new(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_exec_results([MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                }])
+                .append_query_results(vec![vec![update_row(project_id)]])
+                .into_connection(),
+        );
+        let repo = ProjectUpdateRepositoryPostgres::new(Arc::clone(&db));
+
+        let update = repo
+            .create_update(
+                UserId::from(Uuid::new_v4()),
+                project_id,
+                CreateProjectUpdateData {
+                    title: "v0.2".to_string(),
+                    body: "Added **search**".to_string(),
+                    posted_at: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(update.title, "v0.2");
+        drop(repo);
+
+        let log = Arc::try_unwrap(db)
+            .expect("no other connection handles")
+            .into_transaction_log();
+        let log = format!("{log:?}");
+        let touch = log.find("UPDATE projects SET updated_at").unwrap();
+        let insert = log.find("INSERT INTO project_updates").unwrap();
+        assert!(touch < insert);
+        assert!(log.contains("is_deleted = false"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_project_or_entry() {
+        let affected = |rows_affected| MockExecResult {
+            last_insert_id: 0,
+            rows_affected,
+        };
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([affected(0), affected(1), affected(0)])
+            .into_connection();
+        let repo = ProjectUpdateRepositoryPostgres::new(Arc::new(db));
+        let owner = UserId::from(Uuid::new_v4());
+
+        let missing_project = repo
+            .delete_update(owner, Uuid::new_v4(), Uuid::new_v4())
+            .await;
+        assert!(matches!(
+            missing_project,
+            Err(ProjectUpdateRepositoryError::ProjectNotFound)
+        ));
+
+        let missing_entry = repo
+            .delete_update(owner, Uuid::new_v4(), Uuid::new_v4())
+            .await;
+        assert!(matches!(
+            missing_entry,
+            Err(ProjectUpdateRepositoryError::UpdateNotFound)
+        ));
+    }
+}
@@ -0,0 +1,193 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::ports::outgoing::project_repository::ProjectRepositoryError;
+use crate::modules::project::application::ports::outgoing::readme_sync_repository::{
+    ReadmeSync, ReadmeSyncRepository, ReadmeSyncTarget,
+};
+
+#[derive(Clone)]
+pub struct ReadmeSyncRepositoryPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl ReadmeSyncRepositoryPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    fn upsert_stmt(backend: DatabaseBackend, sync: ReadmeSync) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            INSERT INTO project_readme_syncs (project_id, keep_in_sync, readme_url, synced_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (project_id) DO UPDATE
+            SET keep_in_sync = excluded.keep_in_sync,
+                readme_url = excluded.readme_url,
+                synced_at = excluded.synced_at
+            "#,
+            vec![
+                sync.project_id.into(),
+                sync.keep_in_sync.into(),
+                sync.readme_url.into(),
+                sync.synced_at.into(),
+            ],
+        )
+    }
+
+    fn due_stmt(backend: DatabaseBackend, synced_before: DateTime<Utc>, limit: u32) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT s.project_id, p.user_id
+            FROM project_readme_syncs s
+            JOIN projects p ON p.id = s.project_id
+            WHERE s.keep_in_sync = true
+              AND s.synced_at < $1
+              AND p.is_deleted = false
+            ORDER BY s.synced_at ASC
+            LIMIT $2
+            "#,
+            vec![synced_before.into(), i64::from(limit).into()],
+        )
+    }
+
+    // =====================================================
+    // Mapping
+    // =====================================================
+
+    fn to_sync(row: &QueryResult) -> Result<ReadmeSync, ProjectRepositoryError> {
+        Ok(ReadmeSync {
+            project_id: row.try_get("", "project_id").map_err(Self::map_db_err)?,
+            keep_in_sync: row.try_get("", "keep_in_sync").map_err(Self::map_db_err)?,
+            readme_url: row.try_get("", "readme_url").map_err(Self::map_db_err)?,
+            synced_at: row.try_get("", "synced_at").map_err(Self::map_db_err)?,
+        })
+    }
+
+    fn map_db_err(e: DbErr) -> ProjectRepositoryError {
+        ProjectRepositoryError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl ReadmeSyncRepository for ReadmeSyncRepositoryPostgres {
+    async fn find(&self, project_id: Uuid) -> Result<Option<ReadmeSync>, ProjectRepositoryError> {
+        let row = self
+            .db
+            .query_one(Statement::from_sql_and_values(
+                self.db.get_database_backend(),
+                r#"SELECT project_id, keep_in_sync, readme_url, synced_at
+                   FROM project_readme_syncs WHERE project_id = $1"#,
+                vec![project_id.into()],
+            ))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        row.as_ref().map(Self::to_sync).transpose()
+    }
+
+    async fn save(&self, sync: ReadmeSync) -> Result<(), ProjectRepositoryError> {
+        self.db
+            .execute(Self::upsert_stmt(self.db.get_database_backend(), sync))
+            .await
+            .map_err(Self::map_db_err)?;
+        Ok(())
+    }
+
+    async fn due(
+        &self,
+        synced_before: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<ReadmeSyncTarget>, ProjectRepositoryError> {
+        let rows = self
+            .db
+            .query_all(Self::due_stmt(
+                self.db.get_database_backend(),
+                synced_before,
+                limit,
+            ))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        rows.iter()
+            .map(|row| {
+                let owner: Uuid = row.try_get("", "user_id").map_err(Self::map_db_err)?;
+                Ok(ReadmeSyncTarget {
+                    owner: UserId::from(owner),
+                    project_id: row.try_get("", "project_id").map_err(Self::map_db_err)?,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{MockDatabase, Value};
+    use std::collections::BTreeMap;
+
+    #[tokio::test]
+    async fn test_find_maps_row() {
+        let project_id = Uuid::new_v4();
+        let synced_at = Utc::now();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![BTreeMap::from([
+                ("project_id".to_string(), Value::from(project_id)),
+                ("keep_in_sync".to_string(), Value::from(true)),
+                (
+                    "readme_url".to_string(),
+                    Value::from("https://github.com/jane/cms"),
+                ),
+                ("synced_at".to_string(), Value::from(synced_at)),
+            ])]])
+            .into_connection();
+
+        let sync = ReadmeSyncRepositoryPostgres::new(Arc::new(db))
+            .find(project_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(sync.project_id, project_id);
+        assert!(sync.keep_in_sync);
+        assert_eq!(sync.synced_at, synced_at);
+    }
+
+    #[test]
+    fn test_due_skips_trashed_projects_oldest_first() {
+        let stmt =
+            ReadmeSyncRepositoryPostgres::due_stmt(DatabaseBackend::Postgres, Utc::now(), 50);
+
+        assert!(stmt.sql.contains("s.keep_in_sync = true"));
+        assert!(stmt.sql.contains("p.is_deleted = false"));
+        assert!(stmt.sql.contains("ORDER BY s.synced_at ASC"));
+    }
+
+    #[test]
+    fn test_upsert_replaces_existing_row() {
+        let stmt = ReadmeSyncRepositoryPostgres::upsert_stmt(
+            DatabaseBackend::Postgres,
+            ReadmeSync {
+                project_id: Uuid::new_v4(),
+                keep_in_sync: false,
+                readme_url: String::new(),
+                synced_at: Utc::now(),
+            },
+        );
+
+        assert!(stmt.sql.contains("ON CONFLICT (project_id) DO UPDATE"));
+    }
+}
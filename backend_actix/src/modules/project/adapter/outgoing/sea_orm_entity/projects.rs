@@ -34,6 +34,17 @@ pub struct Model {
     #[sea_orm(column_type = "Text", nullable)]
     pub live_demo_url: Option<String>,
 
+    #[sea_orm(column_type = "Text", nullable)]
+    pub seo_title: Option<String>,
+
+    #[sea_orm(column_type = "Text", nullable)]
+    pub seo_description: Option<String>,
+
+    #[sea_orm(column_type = "Text", nullable)]
+    pub canonical_url: Option<String>,
+
+    pub noindex: bool,
+
     // Needed for soft_delete + restore
     pub is_deleted: bool,
 
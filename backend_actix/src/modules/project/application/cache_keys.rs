@@ -0,0 +1,36 @@
+//! Cache keys for project reads (see `shared::cache`).
+//!
+//! Everything cached for an owner lives under `owner_prefix`, so a mutation
+//! drops it all at once instead of working out which pages it touched.
+
+use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::ports::outgoing::project_query::{
+    PageRequest, ProjectListFilter, ProjectSort,
+};
+
+pub fn owner_prefix(owner: UserId) -> String {
+    format!("cache:projects:{}:", owner.value())
+}
+
+pub fn list(
+    owner: UserId,
+    filter: &ProjectListFilter,
+    sort: &ProjectSort,
+    page: &PageRequest,
+) -> String {
+    let topic = filter.topic_id.map(|id| id.to_string()).unwrap_or_default();
+    // Search text goes last: it is free-form and may contain ':'
+    format!(
+        "{}list:{:?}:{}:{}:{}:{}",
+        owner_prefix(owner),
+        sort,
+        page.offset,
+        page.limit,
+        topic,
+        filter.search.as_deref().unwrap_or_default()
+    )
+}
+
+pub fn by_slug(owner: UserId, slug: &str) -> String {
+    format!("{}slug:{}", owner_prefix(owner), slug)
+}
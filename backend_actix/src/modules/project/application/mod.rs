@@ -1,3 +1,7 @@
+pub mod cache_keys;
 pub mod ports;
 pub mod project_use_cases;
+pub mod readme;
+pub(crate) mod seo_fields;
 pub mod service;
+pub(crate) mod update_fields;
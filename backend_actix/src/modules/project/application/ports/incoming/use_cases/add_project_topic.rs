@@ -3,6 +3,7 @@ use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
 use crate::modules::project::application::ports::outgoing::project_topic_repository::ProjectTopicRepositoryError;
+use crate::shared::metrics::metered_use_case;
 
 //
 // ──────────────────────────────────────────────────────────
@@ -49,3 +50,15 @@ pub trait AddProjectTopicUseCase: Send + Sync {
         topic_id: Uuid,
     ) -> Result<(), AddProjectTopicError>;
 }
+
+metered_use_case!(
+    "project",
+    "add_project_topic",
+    AddProjectTopicUseCase,
+    fn execute(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        topic_id: Uuid,
+    ) -> Result<(), AddProjectTopicError>
+);
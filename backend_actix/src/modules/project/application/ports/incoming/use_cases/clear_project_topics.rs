@@ -3,6 +3,7 @@ use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
 use crate::modules::project::application::ports::outgoing::project_topic_repository::ProjectTopicRepositoryError;
+use crate::shared::metrics::metered_use_case;
 
 //
 // ──────────────────────────────────────────────────────────
@@ -47,3 +48,10 @@ pub trait ClearProjectTopicsUseCase: Send + Sync {
     async fn execute(&self, owner: UserId, project_id: Uuid)
         -> Result<(), ClearProjectTopicsError>;
 }
+
+metered_use_case!(
+    "project",
+    "clear_project_topics",
+    ClearProjectTopicsUseCase,
+    fn execute(&self, owner: UserId, project_id: Uuid) -> Result<(), ClearProjectTopicsError>
+);
@@ -4,6 +4,8 @@ use std::fmt;
 use crate::modules::project::application::ports::outgoing::project_repository::{
     CreateProjectData, ProjectResult,
 };
+use crate::shared::metrics::metered_use_case;
+use crate::shared::validation::ValidationErrors;
 
 //
 // ──────────────────────────────────────────────────────────
@@ -13,6 +15,8 @@ use crate::modules::project::application::ports::outgoing::project_repository::{
 
 #[derive(Debug, Clone)]
 pub enum CreateProjectError {
+    /// An SEO field is invalid
+    Invalid(ValidationErrors),
     SlugAlreadyExists,
     RepositoryError(String),
 }
@@ -20,6 +24,7 @@ pub enum CreateProjectError {
 impl fmt::Display for CreateProjectError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            CreateProjectError::Invalid(errors) => write!(f, "invalid input: {}", errors),
             CreateProjectError::SlugAlreadyExists => write!(f, "slug already exists"),
             CreateProjectError::RepositoryError(msg) => {
                 write!(f, "repository error: {}", msg)
@@ -38,3 +43,10 @@ impl fmt::Display for CreateProjectError {
 pub trait CreateProjectUseCase: Send + Sync {
     async fn execute(&self, data: CreateProjectData) -> Result<ProjectResult, CreateProjectError>;
 }
+
+metered_use_case!(
+    "project",
+    "create_project",
+    CreateProjectUseCase,
+    fn execute(&self, data: CreateProjectData) -> Result<ProjectResult, CreateProjectError>
+);
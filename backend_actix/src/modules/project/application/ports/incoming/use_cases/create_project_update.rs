@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::ports::outgoing::project_update_repository::{
+    CreateProjectUpdateData, ProjectUpdate, ProjectUpdateRepositoryError,
+};
+use crate::shared::metrics::metered_use_case;
+use crate::shared::validation::ValidationErrors;
+
+//
+// ──────────────────────────────────────────────────────────
+// Errors
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum CreateProjectUpdateError {
+    #[error("Invalid input: {0}")]
+    Invalid(ValidationErrors),
+
+    #[error("Project not found")]
+    ProjectNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ProjectUpdateRepositoryError> for CreateProjectUpdateError {
+    fn from(err: ProjectUpdateRepositoryError) -> Self {
+        match err {
+            ProjectUpdateRepositoryError::ProjectNotFound => {
+                CreateProjectUpdateError::ProjectNotFound
+            }
+            other => CreateProjectUpdateError::RepositoryError(other.to_string()),
+        }
+    }
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Incoming Port (Use Case)
+// ──────────────────────────────────────────────────────────
+//
+
+#[async_trait]
+pub trait CreateProjectUpdateUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        data: CreateProjectUpdateData,
+    ) -> Result<ProjectUpdate, CreateProjectUpdateError>;
+}
+
+metered_use_case!(
+    "project",
+    "create_project_update",
+    CreateProjectUpdateUseCase,
+    fn execute(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        data: CreateProjectUpdateData,
+    ) -> Result<ProjectUpdate, CreateProjectUpdateError>
+);
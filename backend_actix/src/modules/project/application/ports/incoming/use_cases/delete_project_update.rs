@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::ports::outgoing::project_update_repository::ProjectUpdateRepositoryError;
+use crate::shared::metrics::metered_use_case;
+
+//
+// ──────────────────────────────────────────────────────────
+// Errors
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DeleteProjectUpdateError {
+    #[error("Project not found")]
+    ProjectNotFound,
+
+    #[error("Project update not found")]
+    UpdateNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ProjectUpdateRepositoryError> for DeleteProjectUpdateError {
+    fn from(err: ProjectUpdateRepositoryError) -> Self {
+        match err {
+            ProjectUpdateRepositoryError::ProjectNotFound => {
+                DeleteProjectUpdateError::ProjectNotFound
+            }
+            ProjectUpdateRepositoryError::UpdateNotFound => {
+                DeleteProjectUpdateError::UpdateNotFound
+            }
+            ProjectUpdateRepositoryError::DatabaseError(msg) => {
+                DeleteProjectUpdateError::RepositoryError(msg)
+            }
+        }
+    }
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Incoming Port (Use Case)
+// ──────────────────────────────────────────────────────────
+//
+
+#[async_trait]
+pub trait DeleteProjectUpdateUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        update_id: Uuid,
+    ) -> Result<(), DeleteProjectUpdateError>;
+}
+
+metered_use_case!(
+    "project",
+    "delete_project_update",
+    DeleteProjectUpdateUseCase,
+    fn execute(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        update_id: Uuid,
+    ) -> Result<(), DeleteProjectUpdateError>
+);
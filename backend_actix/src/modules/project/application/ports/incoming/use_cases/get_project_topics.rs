@@ -5,6 +5,7 @@ use uuid::Uuid;
 use crate::auth::application::domain::entities::UserId;
 use crate::modules::project::application::ports::outgoing::project_query::ProjectQueryError;
 use crate::project::application::ports::outgoing::project_query::ProjectTopicItem;
+use crate::shared::metrics::metered_use_case;
 
 //
 // ──────────────────────────────────────────────────────────
@@ -58,3 +59,14 @@ pub trait GetProjectTopicsUseCase: Send + Sync {
         project_id: Uuid,
     ) -> Result<Vec<ProjectTopicItem>, GetProjectTopicsError>;
 }
+
+metered_use_case!(
+    "project",
+    "get_project_topics",
+    GetProjectTopicsUseCase,
+    fn execute(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+    ) -> Result<Vec<ProjectTopicItem>, GetProjectTopicsError>
+);
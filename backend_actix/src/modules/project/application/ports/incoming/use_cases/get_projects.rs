@@ -4,6 +4,7 @@ use crate::auth::application::domain::entities::UserId;
 use crate::modules::project::application::ports::outgoing::project_query::{
     PageRequest, PageResult, ProjectCardView, ProjectListFilter, ProjectQueryError, ProjectSort,
 };
+use crate::shared::metrics::metered_use_case;
 
 //
 // ──────────────────────────────────────────────────────────
@@ -47,3 +48,16 @@ pub trait GetProjectsUseCase: Send + Sync {
         page: PageRequest,
     ) -> Result<PageResult<ProjectCardView>, GetProjectsError>;
 }
+
+metered_use_case!(
+    "project",
+    "get_projects",
+    GetProjectsUseCase,
+    fn execute(
+        &self,
+        owner: UserId,
+        filter: ProjectListFilter,
+        sort: ProjectSort,
+        page: PageRequest,
+    ) -> Result<PageResult<ProjectCardView>, GetProjectsError>
+);
@@ -1,13 +1,19 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 use crate::auth::application::domain::entities::UserId;
 use crate::modules::project::application::ports::outgoing::project_query::ProjectView;
+use crate::shared::metrics::metered_use_case;
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum GetPublicSingleProjectError {
     #[error("Project not found")]
     NotFound,
 
+    /// The owner's project at this slug is in the trash, since the given time
+    #[error("Project was deleted")]
+    Gone(DateTime<Utc>),
+
     #[error("Repository error: {0}")]
     RepositoryError(String),
 }
@@ -20,3 +26,10 @@ pub trait GetPublicSingleProjectUseCase: Send + Sync {
         slug: &str,
     ) -> Result<ProjectView, GetPublicSingleProjectError>;
 }
+
+metered_use_case!(
+    "project",
+    "get_public_single_project",
+    GetPublicSingleProjectUseCase,
+    fn execute(&self, owner: UserId, slug: &str) -> Result<ProjectView, GetPublicSingleProjectError>
+);
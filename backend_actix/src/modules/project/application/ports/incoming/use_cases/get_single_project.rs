@@ -3,6 +3,7 @@ use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
 use crate::modules::project::application::ports::outgoing::project_query::ProjectView;
+use crate::shared::metrics::metered_use_case;
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum GetSingleProjectError {
@@ -21,3 +22,10 @@ pub trait GetSingleProjectUseCase: Send + Sync {
         project_id: Uuid,
     ) -> Result<ProjectView, GetSingleProjectError>;
 }
+
+metered_use_case!(
+    "project",
+    "get_single_project",
+    GetSingleProjectUseCase,
+    fn execute(&self, owner: UserId, project_id: Uuid) -> Result<ProjectView, GetSingleProjectError>
+);
@@ -3,6 +3,7 @@ use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
 use crate::modules::project::application::ports::outgoing::project_archiver::ProjectArchiverError;
+use crate::shared::metrics::metered_use_case;
 
 //
 // ──────────────────────────────────────────────────────────
@@ -40,3 +41,10 @@ impl From<ProjectArchiverError> for HardDeleteProjectError {
 pub trait HardDeleteProjectUseCase: Send + Sync {
     async fn execute(&self, owner: UserId, project_id: Uuid) -> Result<(), HardDeleteProjectError>;
 }
+
+metered_use_case!(
+    "project",
+    "hard_delete_project",
+    HardDeleteProjectUseCase,
+    fn execute(&self, owner: UserId, project_id: Uuid) -> Result<(), HardDeleteProjectError>
+);
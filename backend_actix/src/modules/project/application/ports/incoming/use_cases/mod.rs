@@ -1,21 +1,29 @@
 mod add_project_topic;
 mod clear_project_topics;
 mod create_project;
+mod create_project_update;
+mod delete_project_update;
 mod get_project_topics;
 mod get_projects;
 mod get_public_single_project;
 mod get_single_project;
 mod hard_delete_project;
 mod patch_project;
+mod patch_project_update;
 mod remove_project_topic;
+mod sync_project_readme;
 
 pub use add_project_topic::{AddProjectTopicError, AddProjectTopicUseCase};
 pub use clear_project_topics::{ClearProjectTopicsError, ClearProjectTopicsUseCase};
 pub use create_project::{CreateProjectError, CreateProjectUseCase};
+pub use create_project_update::{CreateProjectUpdateError, CreateProjectUpdateUseCase};
+pub use delete_project_update::{DeleteProjectUpdateError, DeleteProjectUpdateUseCase};
 pub use get_project_topics::{GetProjectTopicsError, GetProjectTopicsUseCase};
 pub use get_projects::{GetProjectsError, GetProjectsUseCase};
 pub use get_public_single_project::{GetPublicSingleProjectError, GetPublicSingleProjectUseCase};
 pub use get_single_project::{GetSingleProjectError, GetSingleProjectUseCase};
 pub use hard_delete_project::{HardDeleteProjectError, HardDeleteProjectUseCase};
 pub use patch_project::{PatchProjectError, PatchProjectUseCase};
+pub use patch_project_update::{PatchProjectUpdateError, PatchProjectUpdateUseCase};
 pub use remove_project_topic::{RemoveProjectTopicError, RemoveProjectTopicUseCase};
+pub use sync_project_readme::{ReadmeSyncResult, SyncProjectReadmeError, SyncProjectReadmeUseCase};
@@ -5,6 +5,8 @@ use crate::auth::application::domain::entities::UserId;
 use crate::modules::project::application::ports::outgoing::project_repository::{
     PatchProjectData, ProjectResult,
 };
+use crate::shared::metrics::metered_use_case;
+use crate::shared::validation::ValidationErrors;
 
 //
 // ──────────────────────────────────────────────────────────
@@ -14,9 +16,17 @@ use crate::modules::project::application::ports::outgoing::project_repository::{
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum PatchProjectError {
+    /// An SEO field is invalid
+    #[error("Invalid input: {0}")]
+    Invalid(ValidationErrors),
+
     #[error("Project not found")]
     NotFound,
 
+    /// Updated since `expected_updated_at`
+    #[error("Project changed since it was read")]
+    Stale,
+
     #[error("Repository error: {0}")]
     RepositoryError(String),
 }
@@ -36,3 +46,15 @@ pub trait PatchProjectUseCase: Send + Sync {
         data: PatchProjectData,
     ) -> Result<ProjectResult, PatchProjectError>;
 }
+
+metered_use_case!(
+    "project",
+    "patch_project",
+    PatchProjectUseCase,
+    fn execute(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        data: PatchProjectData,
+    ) -> Result<ProjectResult, PatchProjectError>
+);
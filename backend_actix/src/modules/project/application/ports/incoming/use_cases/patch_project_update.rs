@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::ports::outgoing::project_update_repository::{
+    PatchProjectUpdateData, ProjectUpdate, ProjectUpdateRepositoryError,
+};
+use crate::shared::metrics::metered_use_case;
+use crate::shared::validation::ValidationErrors;
+
+//
+// ──────────────────────────────────────────────────────────
+// Errors
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum PatchProjectUpdateError {
+    #[error("Invalid input: {0}")]
+    Invalid(ValidationErrors),
+
+    #[error("Project not found")]
+    ProjectNotFound,
+
+    #[error("Project update not found")]
+    UpdateNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<ProjectUpdateRepositoryError> for PatchProjectUpdateError {
+    fn from(err: ProjectUpdateRepositoryError) -> Self {
+        match err {
+            ProjectUpdateRepositoryError::ProjectNotFound => {
+                PatchProjectUpdateError::ProjectNotFound
+            }
+            ProjectUpdateRepositoryError::UpdateNotFound => PatchProjectUpdateError::UpdateNotFound,
+            ProjectUpdateRepositoryError::DatabaseError(msg) => {
+                PatchProjectUpdateError::RepositoryError(msg)
+            }
+        }
+    }
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Incoming Port (Use Case)
+// ──────────────────────────────────────────────────────────
+//
+
+#[async_trait]
+pub trait PatchProjectUpdateUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        update_id: Uuid,
+        data: PatchProjectUpdateData,
+    ) -> Result<ProjectUpdate, PatchProjectUpdateError>;
+}
+
+metered_use_case!(
+    "project",
+    "patch_project_update",
+    PatchProjectUpdateUseCase,
+    fn execute(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        update_id: Uuid,
+        data: PatchProjectUpdateData,
+    ) -> Result<ProjectUpdate, PatchProjectUpdateError>
+);
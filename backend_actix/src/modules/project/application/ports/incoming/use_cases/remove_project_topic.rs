@@ -3,6 +3,7 @@ use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
 use crate::modules::project::application::ports::outgoing::project_topic_repository::ProjectTopicRepositoryError;
+use crate::shared::metrics::metered_use_case;
 
 //
 // ──────────────────────────────────────────────────────────
@@ -51,3 +52,15 @@ pub trait RemoveProjectTopicUseCase: Send + Sync {
         topic_id: Uuid,
     ) -> Result<(), RemoveProjectTopicError>;
 }
+
+metered_use_case!(
+    "project",
+    "remove_project_topic",
+    RemoveProjectTopicUseCase,
+    fn execute(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        topic_id: Uuid,
+    ) -> Result<(), RemoveProjectTopicError>
+);
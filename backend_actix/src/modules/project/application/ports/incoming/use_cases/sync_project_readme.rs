@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::ports::outgoing::readme_sync_repository::ReadmeSync;
+use crate::shared::metrics::metered_use_case;
+
+//
+// ──────────────────────────────────────────────────────────
+// Result
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReadmeSyncResult {
+    /// The description now stored, taken from the README
+    pub description: String,
+
+    /// False when the README hadn't changed since the last sync
+    pub changed: bool,
+
+    #[serde(flatten)]
+    pub sync: ReadmeSync,
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Errors
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SyncProjectReadmeError {
+    #[error("Project not found")]
+    NotFound,
+
+    #[error("Project has no repository URL")]
+    NoRepoUrl,
+
+    #[error("Unsupported repository URL")]
+    UnsupportedRepository,
+
+    #[error("README not found")]
+    ReadmeNotFound,
+
+    #[error("Repository host unavailable: {0}")]
+    RepoUnavailable(String),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Use case trait
+// ──────────────────────────────────────────────────────────
+//
+
+#[async_trait]
+pub trait SyncProjectReadmeUseCase: Send + Sync {
+    /// Replaces the description with the README of the project's repository.
+    /// `keep_in_sync: None` keeps the current setting (off on a first sync).
+    async fn execute(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        keep_in_sync: Option<bool>,
+    ) -> Result<ReadmeSyncResult, SyncProjectReadmeError>;
+}
+
+metered_use_case!(
+    "project",
+    "sync_project_readme",
+    SyncProjectReadmeUseCase,
+    fn execute(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        keep_in_sync: Option<bool>,
+    ) -> Result<ReadmeSyncResult, SyncProjectReadmeError>
+);
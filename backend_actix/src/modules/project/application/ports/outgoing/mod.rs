@@ -2,3 +2,6 @@ pub mod project_archiver;
 pub mod project_query;
 pub mod project_repository;
 pub mod project_topic_repository;
+pub mod project_update_repository;
+pub mod readme_sync_repository;
+pub mod repo_metadata_provider;
@@ -3,9 +3,12 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::ports::outgoing::project_update_repository::ProjectUpdate;
+use crate::shared::faults::faulty_repository;
 
 //
 // ──────────────────────────────────────────────────────────
@@ -13,14 +16,14 @@ use crate::auth::application::domain::entities::UserId;
 // ──────────────────────────────────────────────────────────
 //
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ProjectTopicItem {
     pub id: Uuid,
     pub title: String,
     pub description: String,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ProjectView {
     pub id: Uuid,
     pub owner: UserId,
@@ -28,15 +31,28 @@ pub struct ProjectView {
     pub slug: String,
     pub description: String,
     pub tech_stack: Vec<String>,
+    /// Deprecated: attach screenshots as project media with role `screenshoot`
+    #[schema(deprecated)]
     pub screenshots: Vec<String>,
     pub repo_url: Option<String>,
     pub live_demo_url: Option<String>,
+    /// Overrides `title` in the document `<title>`
+    pub seo_title: Option<String>,
+    /// Meta description
+    pub seo_description: Option<String>,
+    /// Where the original lives, when this project is a copy
+    pub canonical_url: Option<String>,
+    /// Asks search engines not to index the project
+    pub noindex: bool,
     pub topics: Vec<ProjectTopicItem>,
+    /// Timeline entries, newest first
+    #[serde(default)]
+    pub updates: Vec<ProjectUpdate>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ProjectCardView {
     pub id: Uuid,
     pub title: String,
@@ -70,15 +86,15 @@ impl Default for ProjectSort {
 
 #[derive(Debug, Clone)]
 pub struct PageRequest {
-    pub page: u32,
-    pub per_page: u32,
+    pub offset: u64,
+    pub limit: u32,
 }
 
 impl Default for PageRequest {
     fn default() -> Self {
         Self {
-            page: 1,
-            per_page: 20,
+            offset: 0,
+            limit: 20,
         }
     }
 }
@@ -86,8 +102,6 @@ impl Default for PageRequest {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PageResult<T> {
     pub items: Vec<T>,
-    pub page: u32,
-    pub per_page: u32,
     pub total: u64,
 }
 
@@ -144,4 +158,36 @@ pub trait ProjectQuery: Send + Sync {
 
     /// Helper to support slug generator later
     async fn slug_exists(&self, slug: &str) -> Result<bool, ProjectQueryError>;
+
+    /// When the owner's project at `slug` was moved to the trash, if it is
+    /// still there
+    async fn find_tombstone(
+        &self,
+        owner: UserId,
+        slug: &str,
+    ) -> Result<Option<DateTime<Utc>>, ProjectQueryError>;
+}
+
+faulty_repository! {
+    ProjectQuery,
+    ProjectQueryError::DatabaseError,
+    fn get_by_id(&self, owner: UserId, project_id: Uuid) -> Result<ProjectView, ProjectQueryError>;
+    fn get_by_slug(&self, slug: &str) -> Result<ProjectView, ProjectQueryError>;
+    fn list(
+        &self,
+        owner: UserId,
+        filter: ProjectListFilter,
+        sort: ProjectSort,
+        page: PageRequest,
+    ) -> Result<PageResult<ProjectCardView>, ProjectQueryError>;
+    fn get_project_topics(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<ProjectTopicItem>, ProjectQueryError>;
+    fn slug_exists(&self, slug: &str) -> Result<bool, ProjectQueryError>;
+    fn find_tombstone(
+        &self,
+        owner: UserId,
+        slug: &str,
+    ) -> Result<Option<DateTime<Utc>>, ProjectQueryError>;
 }
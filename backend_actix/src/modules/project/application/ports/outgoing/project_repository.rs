@@ -3,9 +3,11 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
+use crate::shared::faults::faulty_repository;
 
 //
 // ──────────────────────────────────────────────────────────
@@ -84,12 +86,22 @@ pub struct CreateProjectData {
 
     pub repo_url: Option<String>,
     pub live_demo_url: Option<String>,
+
+    /// Search-engine fields, checked by the use case (see `shared::seo`)
+    pub seo_title: Option<String>,
+    pub seo_description: Option<String>,
+    pub canonical_url: Option<String>,
+    pub noindex: bool,
 }
 
 /// Patch semantics:
 /// - title/description: Unset => keep, Value => replace
 /// - tech_stack/screenshots: Value(vec) => replace whole array (no merge)
 /// - repo_url/live_demo_url: Unset => keep, Null => clear, Value => set
+/// - seo_title/seo_description/canonical_url: same as the URLs
+/// - noindex: None => keep
+/// - expected_updated_at: when set, a project updated after it fails with
+///   `Stale` (see `shared::api::precondition`)
 #[derive(Debug, Clone, Default)]
 pub struct PatchProjectData {
     pub title: PatchField<String>,
@@ -98,9 +110,14 @@ pub struct PatchProjectData {
     pub screenshots: PatchField<Vec<String>>,
     pub repo_url: PatchField<String>,
     pub live_demo_url: PatchField<String>,
+    pub seo_title: PatchField<String>,
+    pub seo_description: PatchField<String>,
+    pub canonical_url: PatchField<String>,
+    pub noindex: Option<bool>,
+    pub expected_updated_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct ProjectResult {
     pub id: Uuid,
     pub owner: UserId,
@@ -111,6 +128,14 @@ pub struct ProjectResult {
     pub screenshots: Vec<String>,
     pub repo_url: Option<String>,
     pub live_demo_url: Option<String>,
+    /// Overrides `title` in the document `<title>`
+    pub seo_title: Option<String>,
+    /// Meta description
+    pub seo_description: Option<String>,
+    /// Where the original lives, when this project is a copy
+    pub canonical_url: Option<String>,
+    /// Asks search engines not to index the project
+    pub noindex: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -131,6 +156,10 @@ pub enum ProjectRepositoryError {
     #[error("Slug already exists")]
     SlugAlreadyExists,
 
+    /// Updated after the write's `expected_updated_at`
+    #[error("Project changed since it was read")]
+    Stale,
+
     #[error("Database error: {0}")]
     DatabaseError(String),
 
@@ -160,3 +189,18 @@ pub trait ProjectRepository: Send + Sync {
         data: PatchProjectData,
     ) -> Result<ProjectResult, ProjectRepositoryError>;
 }
+
+faulty_repository! {
+    ProjectRepository,
+    ProjectRepositoryError::DatabaseError,
+    fn create_project(
+        &self,
+        data: CreateProjectData,
+    ) -> Result<ProjectResult, ProjectRepositoryError>;
+    fn patch_project(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        data: PatchProjectData,
+    ) -> Result<ProjectResult, ProjectRepositoryError>;
+}
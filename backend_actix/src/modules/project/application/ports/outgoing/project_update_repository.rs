@@ -0,0 +1,97 @@
+// src/modules/project/application/ports/outgoing/project_update_repository.rs
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+
+//
+// ──────────────────────────────────────────────────────────
+// DTOs
+// ──────────────────────────────────────────────────────────
+//
+
+/// A dated progress entry on a project's timeline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ProjectUpdate {
+    pub id: Uuid,
+    pub title: String,
+    /// Markdown
+    pub body: String,
+    /// The date the entry is shown under
+    pub posted_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateProjectUpdateData {
+    pub title: String,
+    pub body: String,
+    /// Now when absent
+    pub posted_at: Option<DateTime<Utc>>,
+}
+
+/// Absent fields are kept
+#[derive(Debug, Clone, Default)]
+pub struct PatchProjectUpdateData {
+    pub title: Option<String>,
+    pub body: Option<String>,
+    pub posted_at: Option<DateTime<Utc>>,
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Errors
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ProjectUpdateRepositoryError {
+    /// Project doesn't exist, isn't the owner's, or is in the trash
+    #[error("Project not found")]
+    ProjectNotFound,
+
+    /// No such entry on this project
+    #[error("Project update not found")]
+    UpdateNotFound,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Port (Command-side, project_updates table only)
+// ──────────────────────────────────────────────────────────
+//
+
+/// Every write also moves the project's `updated_at`, so its `ETag` and
+/// the recently-updated listing follow the timeline
+#[async_trait]
+pub trait ProjectUpdateRepository: Send + Sync {
+    async fn create_update(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        data: CreateProjectUpdateData,
+    ) -> Result<ProjectUpdate, ProjectUpdateRepositoryError>;
+
+    async fn patch_update(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        update_id: Uuid,
+        data: PatchProjectUpdateData,
+    ) -> Result<ProjectUpdate, ProjectUpdateRepositoryError>;
+
+    async fn delete_update(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        update_id: Uuid,
+    ) -> Result<(), ProjectUpdateRepositoryError>;
+}
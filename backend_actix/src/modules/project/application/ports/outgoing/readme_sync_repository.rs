@@ -0,0 +1,58 @@
+// src/modules/project/application/ports/outgoing/readme_sync_repository.rs
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::ports::outgoing::project_repository::ProjectRepositoryError;
+
+//
+// ──────────────────────────────────────────────────────────
+// DTOs
+// ──────────────────────────────────────────────────────────
+//
+
+/// How a project's description follows its repository README
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct ReadmeSync {
+    pub project_id: Uuid,
+
+    /// Refresh the description from the README in the background
+    pub keep_in_sync: bool,
+
+    pub readme_url: String,
+
+    pub synced_at: DateTime<Utc>,
+}
+
+/// A project due for a background refresh
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadmeSyncTarget {
+    pub owner: UserId,
+    pub project_id: Uuid,
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Port
+// ──────────────────────────────────────────────────────────
+//
+
+#[async_trait]
+pub trait ReadmeSyncRepository: Send + Sync {
+    async fn find(&self, project_id: Uuid) -> Result<Option<ReadmeSync>, ProjectRepositoryError>;
+
+    /// Insert or replace the project's sync state
+    async fn save(&self, sync: ReadmeSync) -> Result<(), ProjectRepositoryError>;
+
+    /// Live projects kept in sync and last synced before `synced_before`,
+    /// oldest sync first
+    async fn due(
+        &self,
+        synced_before: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<ReadmeSyncTarget>, ProjectRepositoryError>;
+}
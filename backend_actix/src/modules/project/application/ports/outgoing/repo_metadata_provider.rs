@@ -0,0 +1,58 @@
+// src/modules/project/application/ports/outgoing/repo_metadata_provider.rs
+
+use async_trait::async_trait;
+
+//
+// ──────────────────────────────────────────────────────────
+// DTOs
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoReadme {
+    /// README as written, usually Markdown
+    pub markdown: String,
+
+    /// Page of the README on the hosting site
+    pub html_url: String,
+
+    /// Relative image paths resolve against this (raw file contents),
+    /// with a trailing slash
+    pub raw_base_url: String,
+
+    /// Relative links resolve against this (rendered repository pages),
+    /// with a trailing slash
+    pub html_base_url: String,
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Errors
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum RepoMetadataError {
+    /// Not a repository URL of a host the provider knows
+    #[error("Unsupported repository URL")]
+    UnsupportedRepository,
+
+    /// The repository doesn't exist, is private, or has no README
+    #[error("README not found")]
+    ReadmeNotFound,
+
+    #[error("Repository host unavailable: {0}")]
+    Unavailable(String),
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Port
+// ──────────────────────────────────────────────────────────
+//
+
+#[async_trait]
+pub trait RepoMetadataProvider: Send + Sync {
+    /// README of the default branch of the repository at `repo_url`
+    async fn fetch_readme(&self, repo_url: &str) -> Result<RepoReadme, RepoMetadataError>;
+}
@@ -1,14 +1,29 @@
 use std::sync::Arc;
 
+use crate::shared::metrics::Metered;
 use crate::{
     modules::project::application::ports::incoming::use_cases::{
         CreateProjectUseCase, GetProjectsUseCase,
     },
     project::application::ports::incoming::use_cases::{
-        AddProjectTopicUseCase, ClearProjectTopicsUseCase, GetProjectTopicsUseCase,
-        GetPublicSingleProjectUseCase, GetSingleProjectUseCase, HardDeleteProjectUseCase,
-        PatchProjectUseCase, RemoveProjectTopicUseCase,
+        AddProjectTopicUseCase, ClearProjectTopicsUseCase, CreateProjectUpdateUseCase,
+        DeleteProjectUpdateUseCase, GetProjectTopicsUseCase, GetPublicSingleProjectUseCase,
+        GetSingleProjectUseCase, HardDeleteProjectUseCase, PatchProjectUpdateUseCase,
+        PatchProjectUseCase, RemoveProjectTopicUseCase, SyncProjectReadmeUseCase,
     },
+    project::application::ports::outgoing::{
+        project_archiver::ProjectArchiver, project_query::ProjectQuery,
+        project_repository::ProjectRepository, project_topic_repository::ProjectTopicRepository,
+        project_update_repository::ProjectUpdateRepository,
+    },
+    project::application::service::{
+        AddProjectTopicService, ClearProjectTopicsService, CreateProjectService,
+        CreateProjectUpdateService, DeleteProjectUpdateService, GetProjectTopicsService,
+        GetProjectsService, GetPublicSingleProjectService, GetSingleProjectService,
+        HardDeleteProjectService, PatchProjectService, PatchProjectUpdateService,
+        RemoveProjectTopicService,
+    },
+    shared::cache::CachePort,
 };
 
 #[derive(Clone)]
@@ -22,5 +37,77 @@ pub struct ProjectUseCases {
     pub add_topic: Arc<dyn AddProjectTopicUseCase + Send + Sync>,
     pub remove_topic: Arc<dyn RemoveProjectTopicUseCase + Send + Sync>,
     pub clear_topics: Arc<dyn ClearProjectTopicsUseCase + Send + Sync>,
+    pub create_update: Arc<dyn CreateProjectUpdateUseCase + Send + Sync>,
+    pub patch_update: Arc<dyn PatchProjectUpdateUseCase + Send + Sync>,
+    pub delete_update: Arc<dyn DeleteProjectUpdateUseCase + Send + Sync>,
     pub hard_delete: Arc<dyn HardDeleteProjectUseCase + Send + Sync>,
+    pub sync_readme: Arc<dyn SyncProjectReadmeUseCase + Send + Sync>,
+}
+
+impl ProjectUseCases {
+    /// `sync_readme` is built by the caller, which shares it with the
+    /// background `ReadmeSyncer`
+    pub fn build<R, Q, T, U, A>(
+        repository: R,
+        query: Q,
+        topics: T,
+        updates: U,
+        archiver: A,
+        sync_readme: Arc<dyn SyncProjectReadmeUseCase + Send + Sync>,
+        cache: Arc<dyn CachePort>,
+    ) -> Self
+    where
+        R: ProjectRepository + Clone + 'static,
+        Q: ProjectQuery + Clone + 'static,
+        T: ProjectTopicRepository + Clone + 'static,
+        U: ProjectUpdateRepository + Clone + 'static,
+        A: ProjectArchiver + 'static,
+    {
+        Self {
+            create: Arc::new(Metered(CreateProjectService::new(
+                repository.clone(),
+                Arc::clone(&cache),
+            ))),
+            get_list: Arc::new(Metered(GetProjectsService::new(
+                query.clone(),
+                Arc::clone(&cache),
+            ))),
+            get_single: Arc::new(Metered(GetSingleProjectService::new(query.clone()))),
+            get_public_single: Arc::new(Metered(GetPublicSingleProjectService::new(
+                query.clone(),
+                Arc::clone(&cache),
+            ))),
+            patch: Arc::new(Metered(PatchProjectService::new(
+                repository,
+                Arc::clone(&cache),
+            ))),
+            get_topics: Arc::new(Metered(GetProjectTopicsService::new(query))),
+            add_topic: Arc::new(Metered(AddProjectTopicService::new(
+                topics.clone(),
+                Arc::clone(&cache),
+            ))),
+            remove_topic: Arc::new(Metered(RemoveProjectTopicService::new(
+                topics.clone(),
+                Arc::clone(&cache),
+            ))),
+            clear_topics: Arc::new(Metered(ClearProjectTopicsService::new(
+                topics,
+                Arc::clone(&cache),
+            ))),
+            create_update: Arc::new(Metered(CreateProjectUpdateService::new(
+                updates.clone(),
+                Arc::clone(&cache),
+            ))),
+            patch_update: Arc::new(Metered(PatchProjectUpdateService::new(
+                updates.clone(),
+                Arc::clone(&cache),
+            ))),
+            delete_update: Arc::new(Metered(DeleteProjectUpdateService::new(
+                updates,
+                Arc::clone(&cache),
+            ))),
+            hard_delete: Arc::new(Metered(HardDeleteProjectService::new(archiver, cache))),
+            sync_readme,
+        }
+    }
 }
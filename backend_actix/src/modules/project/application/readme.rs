@@ -0,0 +1,277 @@
+//! Turns a repository README into a project description.
+//!
+//! Relative links only work next to the README, so they are made absolute:
+//! images against the raw file host, everything else against the rendered
+//! repository pages. READMEs may embed HTML, which the frontend renders, so
+//! active content (scripts, frames, forms, event handlers, `javascript:`
+//! URLs) is stripped first.
+
+use regex::{Captures, Regex};
+use std::sync::LazyLock;
+
+use crate::modules::project::application::ports::outgoing::repo_metadata_provider::RepoReadme;
+
+/// Elements removed along with their content
+const DROPPED_ELEMENTS: &[&str] = &[
+    "script", "style", "iframe", "frame", "frameset", "object", "embed", "applet", "form",
+    "textarea", "select", "noscript", "template", "svg", "math",
+];
+
+/// Void or stray tags of elements that are never kept
+static DROPPED_TAG: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)</?(?:{}|base|meta|link|input|button)\b[^>]*>",
+        DROPPED_ELEMENTS.join("|")
+    ))
+    .expect("valid regex")
+});
+
+static DROPPED_ELEMENT: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    DROPPED_ELEMENTS
+        .iter()
+        .map(|name| {
+            Regex::new(&format!(r"(?is)<{name}\b[^>]*>.*?</{name}\s*>")).expect("valid regex")
+        })
+        .collect()
+});
+
+static COMMENT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<!--.*?(?:-->|$)").expect("valid regex"));
+
+static HTML_TAG: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<[a-zA-Z][^>]*>").expect("valid regex"));
+
+/// `onclick=...`, `style=...` and `srcdoc=...` inside a tag
+static UNSAFE_ATTRIBUTE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?i)\s+(?:on[a-z]+|style|srcdoc)\s*=\s*(?:"[^"]*"|'[^']*'|[^\s>]+)"#)
+        .expect("valid regex")
+});
+
+/// `src`/`href` values inside a tag
+static URL_ATTRIBUTE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?i)(\s(?:src|href|poster)\s*=\s*)(?:"([^"]*)"|'([^']*)'|([^\s"'>]+))"#)
+        .expect("valid regex")
+});
+
+/// Target of an inline Markdown link or image: `](target`
+static INLINE_TARGET: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(\]\(\s*)(<[^>\n]*>|[^)\s]+)").expect("valid regex"));
+
+/// Reference definition: `[label]: target`
+static REFERENCE_TARGET: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?m)^( {0,3}\[[^\]\n]+\]:[ \t]*)(<[^>\n]*>|\S+)").expect("valid regex")
+});
+
+static NUMERIC_ENTITY: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)&#(x[0-9a-f]+|[0-9]+);?").expect("valid regex"));
+
+const IMAGE_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "gif", "svg", "webp", "avif", "bmp", "ico",
+];
+
+/// The README as a description: sanitized, with absolute links
+pub fn to_description(readme: &RepoReadme) -> String {
+    let text = readme.markdown.replace("\r\n", "\n");
+    let text = sanitize(&text);
+    absolutize(&text, &readme.raw_base_url, &readme.html_base_url)
+        .trim()
+        .to_string()
+}
+
+/// Drops active HTML; Markdown itself is left alone
+pub fn sanitize(text: &str) -> String {
+    let mut text = COMMENT.replace_all(text, "").into_owned();
+    for element in DROPPED_ELEMENT.iter() {
+        text = element.replace_all(&text, "").into_owned();
+    }
+    let text = DROPPED_TAG.replace_all(&text, "");
+
+    HTML_TAG
+        .replace_all(&text, |tag: &Captures| {
+            UNSAFE_ATTRIBUTE.replace_all(&tag[0], "").into_owned()
+        })
+        .into_owned()
+}
+
+/// Resolves relative Markdown and HTML link targets against the README's
+/// location. Unsafe URLs become `#`.
+pub fn absolutize(text: &str, raw_base: &str, html_base: &str) -> String {
+    let rewrite = |prefix: &str, target: &str| {
+        let (open, target, close) = match target.strip_prefix('<') {
+            Some(inner) => ("<", inner.trim_end_matches('>'), ">"),
+            None => ("", target, ""),
+        };
+        format!(
+            "{prefix}{open}{}{close}",
+            resolve(target, raw_base, html_base)
+        )
+    };
+
+    let text = INLINE_TARGET.replace_all(text, |c: &Captures| rewrite(&c[1], &c[2]));
+    let text = REFERENCE_TARGET.replace_all(&text, |c: &Captures| rewrite(&c[1], &c[2]));
+
+    HTML_TAG
+        .replace_all(&text, |tag: &Captures| {
+            URL_ATTRIBUTE
+                .replace_all(&tag[0], |c: &Captures| {
+                    let (quote, value) = match (c.get(2), c.get(3), c.get(4)) {
+                        (Some(value), _, _) => ("\"", value.as_str()),
+                        (_, Some(value), _) => ("'", value.as_str()),
+                        (_, _, value) => ("\"", value.map_or("", |v| v.as_str())),
+                    };
+                    format!(
+                        "{}{quote}{}{quote}",
+                        &c[1],
+                        resolve(value, raw_base, html_base)
+                    )
+                })
+                .into_owned()
+        })
+        .into_owned()
+}
+
+/// A link target made absolute, or `#` if it would run script
+fn resolve(target: &str, raw_base: &str, html_base: &str) -> String {
+    if is_unsafe_url(target) {
+        return "#".to_string();
+    }
+
+    let is_relative = !(target.is_empty()
+        || target.starts_with('#')
+        || target.starts_with("//")
+        || has_scheme(target));
+    if !is_relative {
+        return target.to_string();
+    }
+
+    // Paths are relative to the README's folder; a leading slash means the
+    // repository root, which is the same folder for a top-level README
+    let path = target.trim_start_matches('/');
+    let path = path.strip_prefix("./").unwrap_or(path);
+    let base = if is_image(path) { raw_base } else { html_base };
+    format!("{base}{path}")
+}
+
+fn has_scheme(url: &str) -> bool {
+    url.split_once(':').is_some_and(|(scheme, _)| {
+        !scheme.is_empty()
+            && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    })
+}
+
+fn is_image(path: &str) -> bool {
+    let path = path.split(['?', '#']).next().unwrap_or(path);
+    path.rsplit_once('.').is_some_and(|(_, extension)| {
+        IMAGE_EXTENSIONS.contains(&extension.to_ascii_lowercase().as_str())
+    })
+}
+
+/// `javascript:`, `vbscript:` and `data:` URLs (except raster images),
+/// seen through entity encoding and embedded whitespace as browsers do
+fn is_unsafe_url(url: &str) -> bool {
+    let decoded = NUMERIC_ENTITY.replace_all(url, |c: &Captures| {
+        let code = &c[1];
+        let value = match code.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok(),
+            None => code.parse().ok(),
+        };
+        value
+            .and_then(char::from_u32)
+            .map(String::from)
+            .unwrap_or_default()
+    });
+    let normalized = decoded
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .collect::<String>()
+        .to_ascii_lowercase()
+        .replace("&colon;", ":");
+
+    let raster_image =
+        normalized.starts_with("data:image/") && !normalized.starts_with("data:image/svg");
+    normalized.starts_with("javascript:")
+        || normalized.starts_with("vbscript:")
+        || (normalized.starts_with("data:") && !raster_image)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RAW: &str = "https://raw.githubusercontent.com/jane/cms/HEAD/";
+    const HTML: &str = "https://github.com/jane/cms/blob/HEAD/";
+
+    fn readme(markdown: &str) -> RepoReadme {
+        RepoReadme {
+            markdown: markdown.to_string(),
+            html_url: format!("{HTML}README.md"),
+            raw_base_url: RAW.to_string(),
+            html_base_url: HTML.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_relative_images_point_to_raw_files_and_links_to_pages() {
+        let text = absolutize(
+            "![Demo](./docs/demo.png \"Demo\") see [guide](docs/GUIDE.md#setup)\n\
+             [![CI](https://ci.example.com/badge.svg)](/actions)\n\
+             [logo]: assets/logo.SVG\n\
+             <img src=\"shot.webp\" width=\"400\"> <a href='LICENSE'>MIT</a>",
+            RAW,
+            HTML,
+        );
+
+        assert_eq!(
+            text,
+            format!(
+                "![Demo]({RAW}docs/demo.png \"Demo\") see [guide]({HTML}docs/GUIDE.md#setup)\n\
+                 [![CI](https://ci.example.com/badge.svg)]({HTML}actions)\n\
+                 [logo]: {RAW}assets/logo.SVG\n\
+                 <img src=\"{RAW}shot.webp\" width=\"400\"> <a href='{HTML}LICENSE'>MIT</a>"
+            )
+        );
+    }
+
+    #[test]
+    fn test_absolute_anchor_and_mail_links_are_kept() {
+        let text = "[a](https://example.com) [b](#usage) [c](mailto:jane@example.com) [d](//cdn.example.com/x.png)";
+
+        assert_eq!(absolutize(text, RAW, HTML), text);
+    }
+
+    #[test]
+    fn test_active_html_is_removed() {
+        let text = sanitize(
+            "# Title\n<script>alert(1)</script>\n<!-- note -->\
+             <img src=\"a.png\" onerror=\"alert(1)\" style=\"x\">\
+             <IFRAME src=\"https://evil.example\"></IFRAME><form><input name=q></form>ok",
+        );
+
+        assert_eq!(text, "# Title\n\n<img src=\"a.png\">ok");
+    }
+
+    #[test]
+    fn test_script_urls_are_neutralized() {
+        let text = absolutize(
+            "[x](javascript:void) <a href=\"jav&#x61;script:alert(1)\">y</a> \
+             [z](data:text/html;base64,AAAA) ![ok](data:image/png;base64,AAAA)",
+            RAW,
+            HTML,
+        );
+
+        assert_eq!(
+            text,
+            "[x](#) <a href=\"#\">y</a> [z](#) ![ok](data:image/png;base64,AAAA)"
+        );
+    }
+
+    #[test]
+    fn test_to_description_trims_and_normalizes_line_endings() {
+        let description = to_description(&readme("\r\n# CMS\r\n\r\n![shot](shot.png)\r\n"));
+
+        assert_eq!(description, format!("# CMS\n\n![shot]({RAW}shot.png)"));
+    }
+}
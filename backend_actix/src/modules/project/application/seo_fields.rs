@@ -0,0 +1,96 @@
+//! Checks of the search-engine fields of a project write (see
+//! `shared::seo`). Every field is checked, so the client learns about all
+//! problems at once; valid text is stored trimmed, and blank counts as
+//! absent.
+
+use crate::modules::project::application::ports::outgoing::project_repository::{
+    CreateProjectData, PatchField, PatchProjectData,
+};
+use crate::shared::seo;
+use crate::shared::validation::ValidationErrors;
+
+type Rule = fn(String) -> Result<Option<String>, String>;
+
+/// Field, code and rule of `seo_title`, `seo_description` and `canonical_url`
+const RULES: [(&str, &str, Rule); 3] = [
+    ("seo_title", "SEO_TITLE_TOO_LONG", seo::seo_title),
+    (
+        "seo_description",
+        "SEO_DESCRIPTION_TOO_LONG",
+        seo::seo_description,
+    ),
+    ("canonical_url", "INVALID_CANONICAL_URL", seo::canonical_url),
+];
+
+pub(crate) fn check_create(data: &mut CreateProjectData) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+    let fields = [
+        &mut data.seo_title,
+        &mut data.seo_description,
+        &mut data.canonical_url,
+    ];
+    for ((field, code, rule), value) in RULES.into_iter().zip(fields) {
+        if let Some(text) = value.take() {
+            *value = errors.check(field, code, rule(text)).flatten();
+        }
+    }
+    errors.into_result()
+}
+
+pub(crate) fn check_patch(data: &mut PatchProjectData) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+    let fields = [
+        &mut data.seo_title,
+        &mut data.seo_description,
+        &mut data.canonical_url,
+    ];
+    for ((field, code, rule), value) in RULES.into_iter().zip(fields) {
+        *value = match std::mem::take(value) {
+            PatchField::Value(text) => errors
+                .check(field, code, rule(text))
+                .flatten()
+                .map_or(PatchField::Null, PatchField::Value),
+            other => other,
+        };
+    }
+    errors.into_result()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_patch_reports_every_invalid_field() {
+        let mut data = PatchProjectData {
+            seo_title: PatchField::Value("t".repeat(seo::MAX_SEO_TITLE_LENGTH + 1)),
+            seo_description: PatchField::Value(" ".to_string()),
+            canonical_url: PatchField::Value("example.com/post".to_string()),
+            ..Default::default()
+        };
+
+        let errors = check_patch(&mut data).unwrap_err();
+
+        assert!(errors.has("seo_title") && errors.has("canonical_url"));
+        assert!(!errors.has("seo_description"));
+        assert_eq!(data.seo_description, PatchField::Null);
+    }
+
+    #[test]
+    fn test_patch_keeps_unset_and_null_fields() {
+        let mut data = PatchProjectData {
+            seo_title: PatchField::Null,
+            canonical_url: PatchField::Value(" https://dev.to/jane/post ".to_string()),
+            ..Default::default()
+        };
+
+        check_patch(&mut data).unwrap();
+
+        assert_eq!(data.seo_title, PatchField::Null);
+        assert!(data.seo_description.is_unset());
+        assert_eq!(
+            data.canonical_url,
+            PatchField::Value("https://dev.to/jane/post".to_string())
+        );
+    }
+}
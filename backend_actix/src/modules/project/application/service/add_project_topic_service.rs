@@ -1,25 +1,29 @@
 use async_trait::async_trait;
+use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::cache_keys;
 use crate::modules::project::application::ports::incoming::use_cases::{
     AddProjectTopicError, AddProjectTopicUseCase,
 };
 use crate::modules::project::application::ports::outgoing::project_topic_repository::ProjectTopicRepository;
+use crate::shared::cache::{self, CachePort};
 
 pub struct AddProjectTopicService<R>
 where
     R: ProjectTopicRepository,
 {
     repo: R,
+    cache: Arc<dyn CachePort>,
 }
 
 impl<R> AddProjectTopicService<R>
 where
     R: ProjectTopicRepository,
 {
-    pub fn new(repo: R) -> Self {
-        Self { repo }
+    pub fn new(repo: R, cache: Arc<dyn CachePort>) -> Self {
+        Self { repo, cache }
     }
 }
 
@@ -37,13 +41,18 @@ where
         self.repo
             .add_project_topic(owner, project_id, topic_id)
             .await
-            .map_err(AddProjectTopicError::from)
+            .map_err(AddProjectTopicError::from)?;
+
+        cache::invalidate_prefix(self.cache.as_ref(), &cache_keys::owner_prefix(owner)).await;
+
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shared::cache::NoopCache;
     use async_trait::async_trait;
     use uuid::Uuid;
 
@@ -111,7 +120,7 @@ mod tests {
         let topic_id = Uuid::new_v4();
 
         let repo = MockProjectTopicRepository::ok();
-        let service = AddProjectTopicService::new(repo);
+        let service = AddProjectTopicService::new(repo, Arc::new(NoopCache));
 
         let result = service.execute(owner, project_id, topic_id).await;
         assert!(result.is_ok());
@@ -122,7 +131,7 @@ mod tests {
         let owner = UserId::from(Uuid::new_v4());
 
         let repo = MockProjectTopicRepository::err(ProjectTopicRepositoryError::ProjectNotFound);
-        let service = AddProjectTopicService::new(repo);
+        let service = AddProjectTopicService::new(repo, Arc::new(NoopCache));
 
         let result = service.execute(owner, Uuid::new_v4(), Uuid::new_v4()).await;
 
@@ -134,7 +143,7 @@ mod tests {
         let owner = UserId::from(Uuid::new_v4());
 
         let repo = MockProjectTopicRepository::err(ProjectTopicRepositoryError::TopicNotFound);
-        let service = AddProjectTopicService::new(repo);
+        let service = AddProjectTopicService::new(repo, Arc::new(NoopCache));
 
         let result = service.execute(owner, Uuid::new_v4(), Uuid::new_v4()).await;
 
@@ -148,7 +157,7 @@ mod tests {
         let repo = MockProjectTopicRepository::err(ProjectTopicRepositoryError::DatabaseError(
             "db down".to_string(),
         ));
-        let service = AddProjectTopicService::new(repo);
+        let service = AddProjectTopicService::new(repo, Arc::new(NoopCache));
 
         let result = service.execute(owner, Uuid::new_v4(), Uuid::new_v4()).await;
 
@@ -1,25 +1,29 @@
 use async_trait::async_trait;
+use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::cache_keys;
 use crate::modules::project::application::ports::incoming::use_cases::{
     ClearProjectTopicsError, ClearProjectTopicsUseCase,
 };
 use crate::modules::project::application::ports::outgoing::project_topic_repository::ProjectTopicRepository;
+use crate::shared::cache::{self, CachePort};
 
 pub struct ClearProjectTopicsService<R>
 where
     R: ProjectTopicRepository,
 {
     repo: R,
+    cache: Arc<dyn CachePort>,
 }
 
 impl<R> ClearProjectTopicsService<R>
 where
     R: ProjectTopicRepository,
 {
-    pub fn new(repo: R) -> Self {
-        Self { repo }
+    pub fn new(repo: R, cache: Arc<dyn CachePort>) -> Self {
+        Self { repo, cache }
     }
 }
 
@@ -36,13 +40,18 @@ where
         self.repo
             .clear_project_topics(owner, project_id)
             .await
-            .map_err(ClearProjectTopicsError::from)
+            .map_err(ClearProjectTopicsError::from)?;
+
+        cache::invalidate_prefix(self.cache.as_ref(), &cache_keys::owner_prefix(owner)).await;
+
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shared::cache::NoopCache;
 
     use crate::modules::project::application::ports::outgoing::project_topic_repository::ProjectTopicRepositoryError;
 
@@ -94,7 +103,7 @@ mod tests {
         let repo = MockProjectTopicRepository {
             clear_result: Ok(()),
         };
-        let service = ClearProjectTopicsService::new(repo);
+        let service = ClearProjectTopicsService::new(repo, Arc::new(NoopCache));
 
         let owner = UserId::from(Uuid::new_v4());
         let project_id = Uuid::new_v4();
@@ -109,7 +118,7 @@ mod tests {
         let repo = MockProjectTopicRepository {
             clear_result: Err(ProjectTopicRepositoryError::ProjectNotFound),
         };
-        let service = ClearProjectTopicsService::new(repo);
+        let service = ClearProjectTopicsService::new(repo, Arc::new(NoopCache));
 
         let owner = UserId::from(Uuid::new_v4());
         let project_id = Uuid::new_v4();
@@ -129,7 +138,7 @@ mod tests {
                 "db is down".to_string(),
             )),
         };
-        let service = ClearProjectTopicsService::new(repo);
+        let service = ClearProjectTopicsService::new(repo, Arc::new(NoopCache));
 
         let owner = UserId::from(Uuid::new_v4());
         let project_id = Uuid::new_v4();
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use std::sync::Arc;
 
 use crate::modules::project::application::ports::incoming::use_cases::{
     CreateProjectError, CreateProjectUseCase,
@@ -6,6 +7,8 @@ use crate::modules::project::application::ports::incoming::use_cases::{
 use crate::modules::project::application::ports::outgoing::project_repository::{
     CreateProjectData, ProjectRepository, ProjectRepositoryError, ProjectResult,
 };
+use crate::modules::project::application::{cache_keys, seo_fields};
+use crate::shared::cache::{self, CachePort};
 
 //
 // ──────────────────────────────────────────────────────────
@@ -18,14 +21,18 @@ where
     R: ProjectRepository,
 {
     project_repository: R,
+    cache: Arc<dyn CachePort>,
 }
 
 impl<R> CreateProjectService<R>
 where
     R: ProjectRepository,
 {
-    pub fn new(project_repository: R) -> Self {
-        Self { project_repository }
+    pub fn new(project_repository: R, cache: Arc<dyn CachePort>) -> Self {
+        Self {
+            project_repository,
+            cache,
+        }
     }
 }
 
@@ -34,8 +41,15 @@ impl<R> CreateProjectUseCase for CreateProjectService<R>
 where
     R: ProjectRepository + Send + Sync,
 {
-    async fn execute(&self, data: CreateProjectData) -> Result<ProjectResult, CreateProjectError> {
-        self.project_repository
+    async fn execute(
+        &self,
+        mut data: CreateProjectData,
+    ) -> Result<ProjectResult, CreateProjectError> {
+        seo_fields::check_create(&mut data).map_err(CreateProjectError::Invalid)?;
+        let owner = data.owner;
+
+        let project = self
+            .project_repository
             .create_project(data)
             .await
             .map_err(|e| match e {
@@ -47,16 +61,24 @@ where
                     CreateProjectError::RepositoryError(msg)
                 }
                 // Defensive: should never happen on create
-                ProjectRepositoryError::NotFound => CreateProjectError::RepositoryError(
-                    "unexpected not found while creating project".to_string(),
-                ),
-            })
+                ProjectRepositoryError::NotFound | ProjectRepositoryError::Stale => {
+                    CreateProjectError::RepositoryError(
+                        "unexpected not found while creating project".to_string(),
+                    )
+                }
+            })?;
+
+        // Every cached listing page of this owner may be stale now
+        cache::invalidate_prefix(self.cache.as_ref(), &cache_keys::owner_prefix(owner)).await;
+
+        Ok(project)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shared::cache::NoopCache;
     use async_trait::async_trait;
     use uuid::Uuid;
 
@@ -100,6 +122,10 @@ mod tests {
             screenshots: vec!["img.png".to_string()],
             repo_url: None,
             live_demo_url: None,
+            seo_title: None,
+            seo_description: None,
+            canonical_url: None,
+            noindex: false,
         }
     }
 
@@ -114,6 +140,10 @@ mod tests {
             screenshots: vec!["img.png".to_string()],
             repo_url: None,
             live_demo_url: None,
+            seo_title: None,
+            seo_description: None,
+            canonical_url: None,
+            noindex: false,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -128,7 +158,7 @@ mod tests {
         let repo = MockProjectRepo {
             result: Ok(sample_project_result()),
         };
-        let service = CreateProjectService::new(repo);
+        let service = CreateProjectService::new(repo, Arc::new(NoopCache));
 
         let res = service.execute(sample_create_data()).await;
 
@@ -139,12 +169,32 @@ mod tests {
     // Error mapping
     // =====================================================
 
+    #[tokio::test]
+    async fn test_execute_rejects_invalid_seo_fields() {
+        let repo = MockProjectRepo {
+            result: Ok(sample_project_result()),
+        };
+        let service = CreateProjectService::new(repo, Arc::new(NoopCache));
+
+        let res = service
+            .execute(CreateProjectData {
+                canonical_url: Some("/elsewhere".to_string()),
+                ..sample_create_data()
+            })
+            .await;
+
+        assert!(matches!(
+            res.unwrap_err(),
+            CreateProjectError::Invalid(errors) if errors.has("canonical_url")
+        ));
+    }
+
     #[tokio::test]
     async fn test_execute_maps_slug_already_exists() {
         let repo = MockProjectRepo {
             result: Err(ProjectRepositoryError::SlugAlreadyExists),
         };
-        let service = CreateProjectService::new(repo);
+        let service = CreateProjectService::new(repo, Arc::new(NoopCache));
 
         let res = service.execute(sample_create_data()).await;
 
@@ -159,7 +209,7 @@ mod tests {
         let repo = MockProjectRepo {
             result: Err(ProjectRepositoryError::DatabaseError("db down".to_string())),
         };
-        let service = CreateProjectService::new(repo);
+        let service = CreateProjectService::new(repo, Arc::new(NoopCache));
 
         let res = service.execute(sample_create_data()).await;
 
@@ -176,7 +226,7 @@ mod tests {
                 "bad json".to_string(),
             )),
         };
-        let service = CreateProjectService::new(repo);
+        let service = CreateProjectService::new(repo, Arc::new(NoopCache));
 
         let res = service.execute(sample_create_data()).await;
 
@@ -191,7 +241,7 @@ mod tests {
         let repo = MockProjectRepo {
             result: Err(ProjectRepositoryError::NotFound),
         };
-        let service = CreateProjectService::new(repo);
+        let service = CreateProjectService::new(repo, Arc::new(NoopCache));
 
         let res = service.execute(sample_create_data()).await;
 
@@ -0,0 +1,143 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::cache_keys;
+use crate::modules::project::application::ports::incoming::use_cases::{
+    CreateProjectUpdateError, CreateProjectUpdateUseCase,
+};
+use crate::modules::project::application::ports::outgoing::project_update_repository::{
+    CreateProjectUpdateData, ProjectUpdate, ProjectUpdateRepository,
+};
+use crate::modules::project::application::update_fields;
+use crate::shared::cache::{self, CachePort};
+
+pub struct CreateProjectUpdateService<R>
+where
+    R: ProjectUpdateRepository,
+{
+    repo: R,
+    cache: Arc<dyn CachePort>,
+}
+
+impl<R> CreateProjectUpdateService<R>
+where
+    R: ProjectUpdateRepository,
+{
+    pub fn new(repo: R, cache: Arc<dyn CachePort>) -> Self {
+        Self { repo, cache }
+    }
+}
+
+#[async_trait]
+impl<R> CreateProjectUpdateUseCase for CreateProjectUpdateService<R>
+where
+    R: ProjectUpdateRepository + Send + Sync,
+{
+    async fn execute(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        mut data: CreateProjectUpdateData,
+    ) -> Result<ProjectUpdate, CreateProjectUpdateError> {
+        update_fields::check_create(&mut data).map_err(CreateProjectUpdateError::Invalid)?;
+
+        let update = self
+            .repo
+            .create_update(owner, project_id, data)
+            .await
+            .map_err(CreateProjectUpdateError::from)?;
+
+        // The entry shows up in the project's cached views
+        cache::invalidate_prefix(self.cache.as_ref(), &cache_keys::owner_prefix(owner)).await;
+
+        Ok(update)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::project::adapter::outgoing::InMemoryProjectStore;
+    use crate::modules::project::application::ports::outgoing::project_repository::{
+        CreateProjectData, ProjectRepository,
+    };
+    use crate::shared::cache::NoopCache;
+
+    async fn project_of(store: &InMemoryProjectStore, owner: UserId) -> Uuid {
+        store
+            .create_project(CreateProjectData {
+                owner,
+                title: "CMS".to_string(),
+                slug: "cms".to_string(),
+                description: String::new(),
+                tech_stack: vec![],
+                screenshots: vec![],
+                repo_url: None,
+                live_demo_url: None,
+                seo_title: None,
+                seo_description: None,
+                canonical_url: None,
+                noindex: false,
+            })
+            .await
+            .unwrap()
+            .id
+    }
+
+    fn entry(title: &str) -> CreateProjectUpdateData {
+        CreateProjectUpdateData {
+            title: title.to_string(),
+            body: " Added dark mode ".to_string(),
+            posted_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_stores_trimmed_entry() {
+        let store = InMemoryProjectStore::default();
+        let owner = UserId::from(Uuid::new_v4());
+        let project_id = project_of(&store, owner).await;
+        let service = CreateProjectUpdateService::new(store, Arc::new(NoopCache));
+
+        let update = service
+            .execute(owner, project_id, entry("v1.1"))
+            .await
+            .unwrap();
+
+        assert_eq!(update.title, "v1.1");
+        assert_eq!(update.body, "Added dark mode");
+    }
+
+    #[tokio::test]
+    async fn execute_rejects_invalid_entry_before_lookup() {
+        let service =
+            CreateProjectUpdateService::new(InMemoryProjectStore::default(), Arc::new(NoopCache));
+
+        let result = service
+            .execute(UserId::from(Uuid::new_v4()), Uuid::new_v4(), entry(""))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(CreateProjectUpdateError::Invalid(errors)) if errors.has("title")
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_maps_project_not_found() {
+        let store = InMemoryProjectStore::default();
+        let owner = UserId::from(Uuid::new_v4());
+        let project_id = project_of(&store, owner).await;
+        let service = CreateProjectUpdateService::new(store, Arc::new(NoopCache));
+
+        let stranger = UserId::from(Uuid::new_v4());
+        let result = service.execute(stranger, project_id, entry("v1.1")).await;
+
+        assert!(matches!(
+            result,
+            Err(CreateProjectUpdateError::ProjectNotFound)
+        ));
+    }
+}
@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::cache_keys;
+use crate::modules::project::application::ports::incoming::use_cases::{
+    DeleteProjectUpdateError, DeleteProjectUpdateUseCase,
+};
+use crate::modules::project::application::ports::outgoing::project_update_repository::ProjectUpdateRepository;
+use crate::shared::cache::{self, CachePort};
+
+pub struct DeleteProjectUpdateService<R>
+where
+    R: ProjectUpdateRepository,
+{
+    repo: R,
+    cache: Arc<dyn CachePort>,
+}
+
+impl<R> DeleteProjectUpdateService<R>
+where
+    R: ProjectUpdateRepository,
+{
+    pub fn new(repo: R, cache: Arc<dyn CachePort>) -> Self {
+        Self { repo, cache }
+    }
+}
+
+#[async_trait]
+impl<R> DeleteProjectUpdateUseCase for DeleteProjectUpdateService<R>
+where
+    R: ProjectUpdateRepository + Send + Sync,
+{
+    async fn execute(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        update_id: Uuid,
+    ) -> Result<(), DeleteProjectUpdateError> {
+        self.repo
+            .delete_update(owner, project_id, update_id)
+            .await
+            .map_err(DeleteProjectUpdateError::from)?;
+
+        cache::invalidate_prefix(self.cache.as_ref(), &cache_keys::owner_prefix(owner)).await;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::project::adapter::outgoing::InMemoryProjectStore;
+    use crate::shared::cache::NoopCache;
+
+    #[tokio::test]
+    async fn execute_maps_project_not_found() {
+        let service =
+            DeleteProjectUpdateService::new(InMemoryProjectStore::default(), Arc::new(NoopCache));
+
+        let result = service
+            .execute(UserId::from(Uuid::new_v4()), Uuid::new_v4(), Uuid::new_v4())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(DeleteProjectUpdateError::ProjectNotFound)
+        ));
+    }
+}
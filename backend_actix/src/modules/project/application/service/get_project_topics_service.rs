@@ -102,6 +102,14 @@ mod tests {
         async fn slug_exists(&self, _slug: &str) -> Result<bool, ProjectQueryError> {
             unimplemented!("not used")
         }
+
+        async fn find_tombstone(
+            &self,
+            _owner: UserId,
+            _slug: &str,
+        ) -> Result<Option<chrono::DateTime<chrono::Utc>>, ProjectQueryError> {
+            unimplemented!("not used")
+        }
     }
 
     #[actix_web::test]
@@ -1,12 +1,15 @@
 use async_trait::async_trait;
+use std::sync::Arc;
 
 use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::cache_keys;
 use crate::modules::project::application::ports::incoming::use_cases::{
     GetProjectsError, GetProjectsUseCase,
 };
 use crate::modules::project::application::ports::outgoing::project_query::{
     PageRequest, PageResult, ProjectCardView, ProjectListFilter, ProjectQuery, ProjectSort,
 };
+use crate::shared::cache::{self, CachePort};
 
 // ============================================================================
 // Service Implementation
@@ -17,14 +20,15 @@ where
     Q: ProjectQuery,
 {
     query: Q,
+    cache: Arc<dyn CachePort>,
 }
 
 impl<Q> GetProjectsService<Q>
 where
     Q: ProjectQuery,
 {
-    pub fn new(query: Q) -> Self {
-        Self { query }
+    pub fn new(query: Q, cache: Arc<dyn CachePort>) -> Self {
+        Self { query, cache }
     }
 }
 
@@ -40,10 +44,15 @@ where
         sort: ProjectSort,
         page: PageRequest,
     ) -> Result<PageResult<ProjectCardView>, GetProjectsError> {
-        self.query
-            .list(owner, filter, sort, page)
-            .await
-            .map_err(GetProjectsError::from)
+        let key = cache_keys::list(owner, &filter, &sort, &page);
+
+        cache::read_through(self.cache.as_ref(), &key, || async {
+            self.query
+                .list(owner, filter, sort, page)
+                .await
+                .map_err(GetProjectsError::from)
+        })
+        .await
     }
 }
 
@@ -54,6 +63,9 @@ mod tests {
     use async_trait::async_trait;
     use uuid::Uuid;
 
+    use crate::shared::cache::NoopCache;
+    use crate::tests::support::stubs::InMemoryCache;
+
     use crate::auth::application::domain::entities::UserId;
     use crate::modules::project::application::ports::outgoing::project_query::{
         PageRequest, PageResult, ProjectCardView, ProjectListFilter, ProjectQuery,
@@ -116,6 +128,14 @@ mod tests {
         async fn slug_exists(&self, _slug: &str) -> Result<bool, ProjectQueryError> {
             unimplemented!("not used in GetProjectsService tests")
         }
+
+        async fn find_tombstone(
+            &self,
+            _owner: UserId,
+            _slug: &str,
+        ) -> Result<Option<chrono::DateTime<chrono::Utc>>, ProjectQueryError> {
+            unimplemented!("not used in GetProjectsService tests")
+        }
     }
 
     /* --------------------------------------------------
@@ -134,8 +154,6 @@ mod tests {
                 created_at: chrono::Utc::now(),
                 updated_at: chrono::Utc::now(),
             }],
-            page: 1,
-            per_page: 10,
             total: 1,
         }
     }
@@ -149,7 +167,7 @@ mod tests {
         let owner = UserId::from(Uuid::new_v4());
 
         let query = MockProjectQuery::success(sample_page_result());
-        let service = GetProjectsService::new(query);
+        let service = GetProjectsService::new(query, Arc::new(NoopCache));
 
         let result = service
             .execute(
@@ -173,7 +191,7 @@ mod tests {
 
         let query =
             MockProjectQuery::error(ProjectQueryError::DatabaseError("db down".to_string()));
-        let service = GetProjectsService::new(query);
+        let service = GetProjectsService::new(query, Arc::new(NoopCache));
 
         let result = service
             .execute(
@@ -197,7 +215,7 @@ mod tests {
         let query = MockProjectQuery::error(ProjectQueryError::SerializationError(
             "bad json".to_string(),
         ));
-        let service = GetProjectsService::new(query);
+        let service = GetProjectsService::new(query, Arc::new(NoopCache));
 
         let result = service
             .execute(
@@ -213,4 +231,40 @@ mod tests {
         let err = result.unwrap_err();
         assert!(matches!(err, GetProjectsError::QueryFailed(_)));
     }
+
+    #[tokio::test]
+    async fn execute_serves_repeat_reads_from_cache() {
+        let owner = UserId::from(Uuid::new_v4());
+        let cache = InMemoryCache::default();
+
+        let warm = GetProjectsService::new(
+            MockProjectQuery::success(sample_page_result()),
+            Arc::new(cache.clone()),
+        );
+        warm.execute(
+            owner,
+            ProjectListFilter::default(),
+            ProjectSort::default(),
+            PageRequest::default(),
+        )
+        .await
+        .unwrap();
+
+        // Database is down now, the cached page still answers
+        let cold = GetProjectsService::new(
+            MockProjectQuery::error(ProjectQueryError::DatabaseError("db down".to_string())),
+            Arc::new(cache),
+        );
+        let page = cold
+            .execute(
+                owner,
+                ProjectListFilter::default(),
+                ProjectSort::default(),
+                PageRequest::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(page.total, 1);
+    }
 }
@@ -1,26 +1,30 @@
 use async_trait::async_trait;
+use std::sync::Arc;
 
 use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::cache_keys;
 use crate::modules::project::application::ports::incoming::use_cases::{
     GetPublicSingleProjectError, GetPublicSingleProjectUseCase,
 };
 use crate::modules::project::application::ports::outgoing::project_query::{
     ProjectQuery, ProjectQueryError, ProjectView,
 };
+use crate::shared::cache::{self, CachePort};
 
 pub struct GetPublicSingleProjectService<Q>
 where
     Q: ProjectQuery,
 {
     query: Q,
+    cache: Arc<dyn CachePort>,
 }
 
 impl<Q> GetPublicSingleProjectService<Q>
 where
     Q: ProjectQuery,
 {
-    pub fn new(query: Q) -> Self {
-        Self { query }
+    pub fn new(query: Q, cache: Arc<dyn CachePort>) -> Self {
+        Self { query, cache }
     }
 }
 
@@ -34,22 +38,42 @@ where
         owner: UserId,
         slug: &str,
     ) -> Result<ProjectView, GetPublicSingleProjectError> {
-        let project = self.query.get_by_slug(slug).await.map_err(|e| match e {
-            ProjectQueryError::NotFound => GetPublicSingleProjectError::NotFound,
-            ProjectQueryError::DatabaseError(msg) => {
-                GetPublicSingleProjectError::RepositoryError(msg)
-            }
-            ProjectQueryError::SerializationError(msg) => {
-                GetPublicSingleProjectError::RepositoryError(msg)
-            }
-        })?;
+        // Only views that passed the owner check below end up in the cache
+        cache::read_through(
+            self.cache.as_ref(),
+            &cache_keys::by_slug(owner, slug),
+            || async {
+                let project = match self.query.get_by_slug(slug).await {
+                    Ok(project) => Some(project),
+                    Err(ProjectQueryError::NotFound) => None,
+                    Err(e) => return Err(repository_error(e)),
+                };
+
+                // Public route is username-scoped, so we must not leak that a slug exists for another user.
+                match project {
+                    Some(project) if project.owner == owner => Ok(project),
+                    _ => match self
+                        .query
+                        .find_tombstone(owner, slug)
+                        .await
+                        .map_err(repository_error)?
+                    {
+                        Some(deleted_at) => Err(GetPublicSingleProjectError::Gone(deleted_at)),
+                        None => Err(GetPublicSingleProjectError::NotFound),
+                    },
+                }
+            },
+        )
+        .await
+    }
+}
 
-        // Public route is username-scoped, so we must not leak that a slug exists for another user.
-        if project.owner != owner {
-            return Err(GetPublicSingleProjectError::NotFound);
+fn repository_error(e: ProjectQueryError) -> GetPublicSingleProjectError {
+    match e {
+        ProjectQueryError::NotFound => GetPublicSingleProjectError::NotFound,
+        ProjectQueryError::DatabaseError(msg) | ProjectQueryError::SerializationError(msg) => {
+            GetPublicSingleProjectError::RepositoryError(msg)
         }
-
-        Ok(project)
     }
 }
 
@@ -59,6 +83,9 @@ mod tests {
     use async_trait::async_trait;
     use uuid::Uuid;
 
+    use crate::shared::cache::NoopCache;
+    use crate::tests::support::stubs::InMemoryCache;
+
     use crate::{
         modules::project::application::ports::outgoing::project_query::{
             PageRequest, PageResult, ProjectCardView, ProjectListFilter, ProjectQuery,
@@ -74,15 +101,29 @@ mod tests {
     #[derive(Clone)]
     struct MockProjectQuery {
         result: Result<ProjectView, ProjectQueryError>,
+        tombstone: Option<chrono::DateTime<chrono::Utc>>,
     }
 
     impl MockProjectQuery {
         fn success(view: ProjectView) -> Self {
-            Self { result: Ok(view) }
+            Self {
+                result: Ok(view),
+                tombstone: None,
+            }
         }
 
         fn error(err: ProjectQueryError) -> Self {
-            Self { result: Err(err) }
+            Self {
+                result: Err(err),
+                tombstone: None,
+            }
+        }
+
+        fn trashed() -> Self {
+            Self {
+                result: Err(ProjectQueryError::NotFound),
+                tombstone: Some(chrono::Utc::now()),
+            }
         }
     }
 
@@ -120,6 +161,14 @@ mod tests {
         async fn slug_exists(&self, _slug: &str) -> Result<bool, ProjectQueryError> {
             unimplemented!("not used in GetPublicSingleProjectService tests")
         }
+
+        async fn find_tombstone(
+            &self,
+            _owner: UserId,
+            _slug: &str,
+        ) -> Result<Option<chrono::DateTime<chrono::Utc>>, ProjectQueryError> {
+            Ok(self.tombstone)
+        }
     }
 
     /* --------------------------------------------------
@@ -138,6 +187,11 @@ mod tests {
             repo_url: None,
             live_demo_url: None,
             topics: vec![],
+            updates: vec![],
+            seo_title: None,
+            seo_description: None,
+            canonical_url: None,
+            noindex: false,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         }
@@ -153,7 +207,7 @@ mod tests {
         let view = sample_project_view(owner.clone());
 
         let query = MockProjectQuery::success(view.clone());
-        let service = GetPublicSingleProjectService::new(query);
+        let service = GetPublicSingleProjectService::new(query, Arc::new(NoopCache));
 
         let result = service.execute(owner, "public-project").await;
 
@@ -169,7 +223,7 @@ mod tests {
         let view = sample_project_view(actual_owner);
 
         let query = MockProjectQuery::success(view);
-        let service = GetPublicSingleProjectService::new(query);
+        let service = GetPublicSingleProjectService::new(query, Arc::new(NoopCache));
 
         let result = service.execute(requested_owner, "public-project").await;
 
@@ -185,7 +239,7 @@ mod tests {
         let owner = UserId::from(Uuid::new_v4());
 
         let query = MockProjectQuery::error(ProjectQueryError::NotFound);
-        let service = GetPublicSingleProjectService::new(query);
+        let service = GetPublicSingleProjectService::new(query, Arc::new(NoopCache));
 
         let result = service.execute(owner, "missing").await;
 
@@ -196,13 +250,24 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn execute_reports_trashed_project_as_gone() {
+        let owner = UserId::from(Uuid::new_v4());
+        let service =
+            GetPublicSingleProjectService::new(MockProjectQuery::trashed(), Arc::new(NoopCache));
+
+        let result = service.execute(owner, "public-project").await;
+
+        assert!(matches!(result, Err(GetPublicSingleProjectError::Gone(_))));
+    }
+
     #[tokio::test]
     async fn execute_maps_database_error_to_repository_error() {
         let owner = UserId::from(Uuid::new_v4());
 
         let query =
             MockProjectQuery::error(ProjectQueryError::DatabaseError("db down".to_string()));
-        let service = GetPublicSingleProjectService::new(query);
+        let service = GetPublicSingleProjectService::new(query, Arc::new(NoopCache));
 
         let result = service.execute(owner, "public-project").await;
 
@@ -220,7 +285,7 @@ mod tests {
         let query = MockProjectQuery::error(ProjectQueryError::SerializationError(
             "bad json".to_string(),
         ));
-        let service = GetPublicSingleProjectService::new(query);
+        let service = GetPublicSingleProjectService::new(query, Arc::new(NoopCache));
 
         let result = service.execute(owner, "public-project").await;
 
@@ -230,4 +295,25 @@ mod tests {
             GetPublicSingleProjectError::RepositoryError(msg) if msg == "bad json"
         ));
     }
+
+    #[tokio::test]
+    async fn execute_caches_only_views_of_the_requested_owner() {
+        let owner = UserId::from(Uuid::new_v4());
+        let stranger = UserId::from(Uuid::new_v4());
+        let cache = InMemoryCache::default();
+
+        let service = GetPublicSingleProjectService::new(
+            MockProjectQuery::success(sample_project_view(owner)),
+            Arc::new(cache.clone()),
+        );
+
+        assert!(service.execute(stranger, "public-project").await.is_err());
+        assert!(cache.keys().is_empty());
+
+        service.execute(owner, "public-project").await.unwrap();
+        assert_eq!(
+            cache.keys(),
+            vec![cache_keys::by_slug(owner, "public-project")]
+        );
+    }
 }
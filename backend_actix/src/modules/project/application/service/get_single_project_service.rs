@@ -116,6 +116,14 @@ mod tests {
         async fn slug_exists(&self, _slug: &str) -> Result<bool, ProjectQueryError> {
             unimplemented!("not used in GetSingleProjectService tests")
         }
+
+        async fn find_tombstone(
+            &self,
+            _owner: UserId,
+            _slug: &str,
+        ) -> Result<Option<chrono::DateTime<chrono::Utc>>, ProjectQueryError> {
+            unimplemented!("not used in GetSingleProjectService tests")
+        }
     }
 
     /* --------------------------------------------------
@@ -134,6 +142,11 @@ mod tests {
             repo_url: None,
             live_demo_url: None,
             topics: vec![],
+            updates: vec![],
+            seo_title: None,
+            seo_description: None,
+            canonical_url: None,
+            noindex: false,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         }
@@ -1,25 +1,29 @@
 use async_trait::async_trait;
+use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::cache_keys;
 use crate::modules::project::application::ports::incoming::use_cases::{
     HardDeleteProjectError, HardDeleteProjectUseCase,
 };
 use crate::modules::project::application::ports::outgoing::project_archiver::ProjectArchiver;
+use crate::shared::cache::{self, CachePort};
 
 pub struct HardDeleteProjectService<A>
 where
     A: ProjectArchiver,
 {
     archiver: A,
+    cache: Arc<dyn CachePort>,
 }
 
 impl<A> HardDeleteProjectService<A>
 where
     A: ProjectArchiver,
 {
-    pub fn new(archiver: A) -> Self {
-        Self { archiver }
+    pub fn new(archiver: A, cache: Arc<dyn CachePort>) -> Self {
+        Self { archiver, cache }
     }
 }
 
@@ -32,13 +36,18 @@ where
         self.archiver
             .hard_delete(owner, project_id)
             .await
-            .map_err(HardDeleteProjectError::from)
+            .map_err(HardDeleteProjectError::from)?;
+
+        cache::invalidate_prefix(self.cache.as_ref(), &cache_keys::owner_prefix(owner)).await;
+
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shared::cache::NoopCache;
 
     use crate::modules::project::application::ports::outgoing::project_archiver::{
         ProjectArchiver, ProjectArchiverError,
@@ -89,7 +98,7 @@ mod tests {
     #[actix_web::test]
     async fn execute_success() {
         let archiver = MockProjectArchiver::success();
-        let service = HardDeleteProjectService::new(archiver);
+        let service = HardDeleteProjectService::new(archiver, Arc::new(NoopCache));
 
         let owner = UserId::from(Uuid::new_v4());
         let project_id = Uuid::new_v4();
@@ -101,7 +110,7 @@ mod tests {
     #[actix_web::test]
     async fn execute_project_not_found() {
         let archiver = MockProjectArchiver::error(ProjectArchiverError::NotFound);
-        let service = HardDeleteProjectService::new(archiver);
+        let service = HardDeleteProjectService::new(archiver, Arc::new(NoopCache));
 
         let owner = UserId::from(Uuid::new_v4());
         let project_id = Uuid::new_v4();
@@ -118,7 +127,7 @@ mod tests {
     async fn execute_repository_error() {
         let archiver =
             MockProjectArchiver::error(ProjectArchiverError::DatabaseError("db down".into()));
-        let service = HardDeleteProjectService::new(archiver);
+        let service = HardDeleteProjectService::new(archiver, Arc::new(NoopCache));
 
         let owner = UserId::from(Uuid::new_v4());
         let project_id = Uuid::new_v4();
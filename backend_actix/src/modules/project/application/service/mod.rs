@@ -1,20 +1,30 @@
 mod add_project_topic_service;
 mod clear_project_topics_service;
 mod create_project_service;
+mod create_project_update_service;
+mod delete_project_update_service;
 mod get_project_topics_service;
 mod get_projects_service;
 mod get_public_single_project_service;
 mod get_single_project_service;
 mod hard_delete_project_service;
 mod patch_project_service;
+mod patch_project_update_service;
+mod readme_syncer;
 mod remove_project_topic_service;
+mod sync_project_readme_service;
 pub use add_project_topic_service::AddProjectTopicService;
 pub use clear_project_topics_service::ClearProjectTopicsService;
 pub use create_project_service::CreateProjectService;
+pub use create_project_update_service::CreateProjectUpdateService;
+pub use delete_project_update_service::DeleteProjectUpdateService;
 pub use get_project_topics_service::GetProjectTopicsService;
 pub use get_projects_service::GetProjectsService;
 pub use get_public_single_project_service::GetPublicSingleProjectService;
 pub use get_single_project_service::GetSingleProjectService;
 pub use hard_delete_project_service::HardDeleteProjectService;
 pub use patch_project_service::PatchProjectService;
+pub use patch_project_update_service::PatchProjectUpdateService;
+pub use readme_syncer::ReadmeSyncer;
 pub use remove_project_topic_service::RemoveProjectTopicService;
+pub use sync_project_readme_service::SyncProjectReadmeService;
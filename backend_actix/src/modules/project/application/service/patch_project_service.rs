@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
@@ -8,6 +9,8 @@ use crate::modules::project::application::ports::incoming::use_cases::{
 use crate::modules::project::application::ports::outgoing::project_repository::{
     PatchProjectData, ProjectRepository, ProjectRepositoryError, ProjectResult,
 };
+use crate::modules::project::application::{cache_keys, seo_fields};
+use crate::shared::cache::{self, CachePort};
 
 //
 // ──────────────────────────────────────────────────────────
@@ -20,14 +23,18 @@ where
     R: ProjectRepository,
 {
     project_repository: R,
+    cache: Arc<dyn CachePort>,
 }
 
 impl<R> PatchProjectService<R>
 where
     R: ProjectRepository,
 {
-    pub fn new(project_repository: R) -> Self {
-        Self { project_repository }
+    pub fn new(project_repository: R, cache: Arc<dyn CachePort>) -> Self {
+        Self {
+            project_repository,
+            cache,
+        }
     }
 }
 
@@ -40,14 +47,19 @@ where
         &self,
         owner: UserId,
         project_id: Uuid,
-        data: PatchProjectData,
+        mut data: PatchProjectData,
     ) -> Result<ProjectResult, PatchProjectError> {
-        self.project_repository
+        seo_fields::check_patch(&mut data).map_err(PatchProjectError::Invalid)?;
+
+        let project = self
+            .project_repository
             .patch_project(owner, project_id, data)
             .await
             .map_err(|e| match e {
                 ProjectRepositoryError::NotFound => PatchProjectError::NotFound,
 
+                ProjectRepositoryError::Stale => PatchProjectError::Stale,
+
                 ProjectRepositoryError::DatabaseError(msg) => {
                     PatchProjectError::RepositoryError(msg)
                 }
@@ -60,13 +72,19 @@ where
                 ProjectRepositoryError::SlugAlreadyExists => PatchProjectError::RepositoryError(
                     "unexpected slug conflict while patching project".to_string(),
                 ),
-            })
+            })?;
+
+        cache::invalidate_prefix(self.cache.as_ref(), &cache_keys::owner_prefix(owner)).await;
+
+        Ok(project)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shared::cache::NoopCache;
+    use crate::tests::support::stubs::InMemoryCache;
     use async_trait::async_trait;
     use chrono::Utc;
     use uuid::Uuid;
@@ -127,6 +145,10 @@ mod tests {
             screenshots: vec!["img.png".to_string()],
             repo_url: None,
             live_demo_url: None,
+            seo_title: None,
+            seo_description: None,
+            canonical_url: None,
+            noindex: false,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -144,7 +166,7 @@ mod tests {
         let repo = MockProjectRepo {
             result: Ok(sample_project_result(owner.clone(), project_id)),
         };
-        let service = PatchProjectService::new(repo);
+        let service = PatchProjectService::new(repo, Arc::new(NoopCache));
 
         let res = service
             .execute(owner, project_id, sample_patch_data())
@@ -153,6 +175,31 @@ mod tests {
         assert!(res.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_execute_invalidates_owner_cache() {
+        let owner = sample_owner();
+        let other = sample_owner();
+        let project_id = sample_project_id();
+
+        let cache = InMemoryCache::default();
+        let mine = cache_keys::by_slug(owner, "slug");
+        let theirs = cache_keys::by_slug(other, "slug");
+        cache.set(&mine, "{}".to_string()).await.unwrap();
+        cache.set(&theirs, "{}".to_string()).await.unwrap();
+
+        let repo = MockProjectRepo {
+            result: Ok(sample_project_result(owner, project_id)),
+        };
+        let service = PatchProjectService::new(repo, Arc::new(cache.clone()));
+
+        service
+            .execute(owner, project_id, sample_patch_data())
+            .await
+            .unwrap();
+
+        assert_eq!(cache.keys(), vec![theirs]);
+    }
+
     // =====================================================
     // Error mapping
     // =====================================================
@@ -165,7 +212,7 @@ mod tests {
         let repo = MockProjectRepo {
             result: Err(ProjectRepositoryError::NotFound),
         };
-        let service = PatchProjectService::new(repo);
+        let service = PatchProjectService::new(repo, Arc::new(NoopCache));
 
         let res = service
             .execute(owner, project_id, sample_patch_data())
@@ -182,7 +229,7 @@ mod tests {
         let repo = MockProjectRepo {
             result: Err(ProjectRepositoryError::DatabaseError("db down".to_string())),
         };
-        let service = PatchProjectService::new(repo);
+        let service = PatchProjectService::new(repo, Arc::new(NoopCache));
 
         let res = service
             .execute(owner, project_id, sample_patch_data())
@@ -204,7 +251,7 @@ mod tests {
                 "bad json".to_string(),
             )),
         };
-        let service = PatchProjectService::new(repo);
+        let service = PatchProjectService::new(repo, Arc::new(NoopCache));
 
         let res = service
             .execute(owner, project_id, sample_patch_data())
@@ -224,7 +271,7 @@ mod tests {
         let repo = MockProjectRepo {
             result: Err(ProjectRepositoryError::SlugAlreadyExists),
         };
-        let service = PatchProjectService::new(repo);
+        let service = PatchProjectService::new(repo, Arc::new(NoopCache));
 
         let res = service
             .execute(owner, project_id, sample_patch_data())
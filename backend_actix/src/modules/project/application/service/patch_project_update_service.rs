@@ -0,0 +1,170 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::cache_keys;
+use crate::modules::project::application::ports::incoming::use_cases::{
+    PatchProjectUpdateError, PatchProjectUpdateUseCase,
+};
+use crate::modules::project::application::ports::outgoing::project_update_repository::{
+    PatchProjectUpdateData, ProjectUpdate, ProjectUpdateRepository,
+};
+use crate::modules::project::application::update_fields;
+use crate::shared::cache::{self, CachePort};
+
+pub struct PatchProjectUpdateService<R>
+where
+    R: ProjectUpdateRepository,
+{
+    repo: R,
+    cache: Arc<dyn CachePort>,
+}
+
+impl<R> PatchProjectUpdateService<R>
+where
+    R: ProjectUpdateRepository,
+{
+    pub fn new(repo: R, cache: Arc<dyn CachePort>) -> Self {
+        Self { repo, cache }
+    }
+}
+
+#[async_trait]
+impl<R> PatchProjectUpdateUseCase for PatchProjectUpdateService<R>
+where
+    R: ProjectUpdateRepository + Send + Sync,
+{
+    async fn execute(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        update_id: Uuid,
+        mut data: PatchProjectUpdateData,
+    ) -> Result<ProjectUpdate, PatchProjectUpdateError> {
+        update_fields::check_patch(&mut data).map_err(PatchProjectUpdateError::Invalid)?;
+
+        let update = self
+            .repo
+            .patch_update(owner, project_id, update_id, data)
+            .await
+            .map_err(PatchProjectUpdateError::from)?;
+
+        cache::invalidate_prefix(self.cache.as_ref(), &cache_keys::owner_prefix(owner)).await;
+
+        Ok(update)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::project::adapter::outgoing::InMemoryProjectStore;
+    use crate::modules::project::application::ports::outgoing::project_repository::{
+        CreateProjectData, ProjectRepository,
+    };
+    use crate::modules::project::application::ports::outgoing::project_update_repository::CreateProjectUpdateData;
+    use crate::shared::cache::NoopCache;
+
+    async fn project_of(store: &InMemoryProjectStore, owner: UserId) -> Uuid {
+        store
+            .create_project(CreateProjectData {
+                owner,
+                title: "CMS".to_string(),
+                slug: "cms".to_string(),
+                description: String::new(),
+                tech_stack: vec![],
+                screenshots: vec![],
+                repo_url: None,
+                live_demo_url: None,
+                seo_title: None,
+                seo_description: None,
+                canonical_url: None,
+                noindex: false,
+            })
+            .await
+            .unwrap()
+            .id
+    }
+
+    #[tokio::test]
+    async fn execute_changes_only_given_fields() {
+        let store = InMemoryProjectStore::default();
+        let owner = UserId::from(Uuid::new_v4());
+        let project_id = project_of(&store, owner).await;
+        let created = store
+            .create_update(
+                owner,
+                project_id,
+                CreateProjectUpdateData {
+                    title: "v1.0".to_string(),
+                    body: "First release".to_string(),
+                    posted_at: None,
+                },
+            )
+            .await
+            .unwrap();
+        let service = PatchProjectUpdateService::new(store, Arc::new(NoopCache));
+
+        let patched = service
+            .execute(
+                owner,
+                project_id,
+                created.id,
+                PatchProjectUpdateData {
+                    title: Some(" v1.0.1 ".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(patched.title, "v1.0.1");
+        assert_eq!(patched.body, "First release");
+    }
+
+    #[tokio::test]
+    async fn execute_rejects_blank_body() {
+        let service =
+            PatchProjectUpdateService::new(InMemoryProjectStore::default(), Arc::new(NoopCache));
+
+        let result = service
+            .execute(
+                UserId::from(Uuid::new_v4()),
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                PatchProjectUpdateData {
+                    body: Some("   ".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PatchProjectUpdateError::Invalid(errors)) if errors.has("body")
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_maps_update_not_found() {
+        let store = InMemoryProjectStore::default();
+        let owner = UserId::from(Uuid::new_v4());
+        let project_id = project_of(&store, owner).await;
+        let service = PatchProjectUpdateService::new(store, Arc::new(NoopCache));
+
+        let result = service
+            .execute(
+                owner,
+                project_id,
+                Uuid::new_v4(),
+                PatchProjectUpdateData::default(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PatchProjectUpdateError::UpdateNotFound)
+        ));
+    }
+}
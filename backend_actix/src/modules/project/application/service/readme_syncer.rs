@@ -0,0 +1,220 @@
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::modules::project::application::ports::incoming::use_cases::{
+    SyncProjectReadmeError, SyncProjectReadmeUseCase,
+};
+use crate::modules::project::application::ports::outgoing::readme_sync_repository::{
+    ReadmeSync, ReadmeSyncRepository,
+};
+use crate::shared::lifecycle::ShutdownSignal;
+
+/// Projects refreshed per run
+const BATCH_SIZE: u32 = 50;
+
+/// Refreshes the descriptions of projects kept in sync with their README.
+///
+/// Runs as a background job (`BackgroundJobs`). A project whose README or
+/// repository is gone has its flag turned off, so it isn't retried forever.
+pub struct ReadmeSyncer<S>
+where
+    S: ReadmeSyncRepository,
+{
+    syncs: S,
+    sync_readme: Arc<dyn SyncProjectReadmeUseCase>,
+    interval: Duration,
+}
+
+impl<S> ReadmeSyncer<S>
+where
+    S: ReadmeSyncRepository,
+{
+    pub fn new(
+        syncs: S,
+        sync_readme: Arc<dyn SyncProjectReadmeUseCase>,
+        interval: Duration,
+    ) -> Self {
+        Self {
+            syncs,
+            sync_readme,
+            interval,
+        }
+    }
+
+    pub async fn run(self, mut signal: ShutdownSignal) {
+        info!(
+            interval_secs = self.interval.as_secs(),
+            "README syncer started"
+        );
+
+        loop {
+            tokio::select! {
+                _ = signal.wait() => break,
+                _ = tokio::time::sleep(self.interval) => {}
+            }
+
+            match self.sync_due(Utc::now()).await {
+                Ok(0) => {}
+                Ok(synced) => info!(synced, "Synced project READMEs"),
+                Err(e) => warn!(error = %e, "README sync failed"),
+            }
+        }
+    }
+
+    /// Sync the projects last synced more than one interval before `now`.
+    /// Returns how many succeeded.
+    pub async fn sync_due(&self, now: DateTime<Utc>) -> Result<usize, SyncProjectReadmeError> {
+        let since = chrono::Duration::from_std(self.interval).unwrap_or(chrono::Duration::zero());
+        let due = self
+            .syncs
+            .due(now - since, BATCH_SIZE)
+            .await
+            .map_err(|e| SyncProjectReadmeError::RepositoryError(e.to_string()))?;
+
+        let mut synced = 0;
+        for target in due {
+            match self
+                .sync_readme
+                .execute(target.owner, target.project_id, None)
+                .await
+            {
+                Ok(_) => synced += 1,
+                Err(
+                    e @ (SyncProjectReadmeError::NoRepoUrl
+                    | SyncProjectReadmeError::UnsupportedRepository
+                    | SyncProjectReadmeError::ReadmeNotFound),
+                ) => {
+                    warn!(project_id = %target.project_id, error = %e, "Stopped syncing README");
+                    self.stop(target.project_id).await;
+                }
+                Err(e) => {
+                    warn!(project_id = %target.project_id, error = %e, "README sync failed");
+                }
+            }
+        }
+
+        Ok(synced)
+    }
+
+    async fn stop(&self, project_id: Uuid) {
+        let result = match self.syncs.find(project_id).await {
+            Ok(Some(sync)) => {
+                self.syncs
+                    .save(ReadmeSync {
+                        keep_in_sync: false,
+                        ..sync
+                    })
+                    .await
+            }
+            Ok(None) => Ok(()),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            warn!(project_id = %project_id, error = %e, "Failed to turn off README sync");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    use crate::auth::application::domain::entities::UserId;
+    use crate::modules::project::adapter::outgoing::InMemoryProjectStore;
+    use crate::modules::project::application::ports::incoming::use_cases::ReadmeSyncResult;
+    use crate::modules::project::application::ports::outgoing::project_repository::{
+        CreateProjectData, ProjectRepository,
+    };
+
+    /// Succeeds for every project but `missing`, whose README is gone
+    struct StubSyncReadme {
+        missing: Uuid,
+        calls: Mutex<Vec<Uuid>>,
+    }
+
+    #[async_trait::async_trait]
+    impl SyncProjectReadmeUseCase for StubSyncReadme {
+        async fn execute(
+            &self,
+            _owner: UserId,
+            project_id: Uuid,
+            _keep_in_sync: Option<bool>,
+        ) -> Result<ReadmeSyncResult, SyncProjectReadmeError> {
+            self.calls.lock().unwrap().push(project_id);
+            if project_id == self.missing {
+                return Err(SyncProjectReadmeError::ReadmeNotFound);
+            }
+            Ok(ReadmeSyncResult {
+                description: String::new(),
+                changed: false,
+                sync: ReadmeSync {
+                    project_id,
+                    keep_in_sync: true,
+                    readme_url: String::new(),
+                    synced_at: Utc::now(),
+                },
+            })
+        }
+    }
+
+    async fn kept(store: &InMemoryProjectStore, synced_at: DateTime<Utc>) -> Uuid {
+        let project_id = store
+            .create_project(CreateProjectData {
+                owner: UserId::from(Uuid::new_v4()),
+                title: "CMS".to_string(),
+                slug: Uuid::new_v4().to_string(),
+                description: String::new(),
+                tech_stack: vec![],
+                screenshots: vec![],
+                repo_url: Some("https://github.com/jane/cms".to_string()),
+                live_demo_url: None,
+                seo_title: None,
+                seo_description: None,
+                canonical_url: None,
+                noindex: false,
+            })
+            .await
+            .unwrap()
+            .id;
+        store
+            .save(ReadmeSync {
+                project_id,
+                keep_in_sync: true,
+                readme_url: "https://github.com/jane/cms/blob/HEAD/README.md".to_string(),
+                synced_at,
+            })
+            .await
+            .unwrap();
+        project_id
+    }
+
+    #[tokio::test]
+    async fn test_syncs_due_projects_and_stops_those_without_readme() {
+        let store = InMemoryProjectStore::default();
+        let now = Utc::now();
+        let stale = kept(&store, now - chrono::Duration::hours(7)).await;
+        let missing = kept(&store, now - chrono::Duration::hours(8)).await;
+        let fresh = kept(&store, now - chrono::Duration::hours(1)).await;
+
+        let sync_readme = Arc::new(StubSyncReadme {
+            missing,
+            calls: Mutex::new(vec![]),
+        });
+        let syncer = ReadmeSyncer::new(
+            store.clone(),
+            sync_readme.clone(),
+            Duration::from_secs(6 * 3600),
+        );
+
+        let synced = syncer.sync_due(now).await.unwrap();
+
+        assert_eq!(synced, 1);
+        assert_eq!(*sync_readme.calls.lock().unwrap(), vec![missing, stale]);
+        assert!(!store.find(missing).await.unwrap().unwrap().keep_in_sync);
+        assert!(store.find(fresh).await.unwrap().unwrap().keep_in_sync);
+    }
+}
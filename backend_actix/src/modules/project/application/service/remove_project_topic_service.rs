@@ -1,25 +1,29 @@
 use async_trait::async_trait;
+use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::cache_keys;
 use crate::modules::project::application::ports::incoming::use_cases::{
     RemoveProjectTopicError, RemoveProjectTopicUseCase,
 };
 use crate::modules::project::application::ports::outgoing::project_topic_repository::ProjectTopicRepository;
+use crate::shared::cache::{self, CachePort};
 
 pub struct RemoveProjectTopicService<R>
 where
     R: ProjectTopicRepository,
 {
     repo: R,
+    cache: Arc<dyn CachePort>,
 }
 
 impl<R> RemoveProjectTopicService<R>
 where
     R: ProjectTopicRepository,
 {
-    pub fn new(repo: R) -> Self {
-        Self { repo }
+    pub fn new(repo: R, cache: Arc<dyn CachePort>) -> Self {
+        Self { repo, cache }
     }
 }
 
@@ -37,13 +41,18 @@ where
         self.repo
             .remove_project_topic(owner, project_id, topic_id)
             .await
-            .map_err(RemoveProjectTopicError::from)
+            .map_err(RemoveProjectTopicError::from)?;
+
+        cache::invalidate_prefix(self.cache.as_ref(), &cache_keys::owner_prefix(owner)).await;
+
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shared::cache::NoopCache;
 
     use crate::modules::project::application::ports::outgoing::project_topic_repository::ProjectTopicRepositoryError;
 
@@ -96,7 +105,7 @@ mod tests {
             remove_result: Ok(()),
         };
 
-        let service = RemoveProjectTopicService::new(repo);
+        let service = RemoveProjectTopicService::new(repo, Arc::new(NoopCache));
 
         let owner = UserId::from(Uuid::new_v4());
         let project_id = Uuid::new_v4();
@@ -113,7 +122,7 @@ mod tests {
             remove_result: Err(ProjectTopicRepositoryError::ProjectNotFound),
         };
 
-        let service = RemoveProjectTopicService::new(repo);
+        let service = RemoveProjectTopicService::new(repo, Arc::new(NoopCache));
 
         let owner = UserId::from(Uuid::new_v4());
         let project_id = Uuid::new_v4();
@@ -133,7 +142,7 @@ mod tests {
             remove_result: Err(ProjectTopicRepositoryError::TopicNotFound),
         };
 
-        let service = RemoveProjectTopicService::new(repo);
+        let service = RemoveProjectTopicService::new(repo, Arc::new(NoopCache));
 
         let owner = UserId::from(Uuid::new_v4());
         let project_id = Uuid::new_v4();
@@ -155,7 +164,7 @@ mod tests {
             )),
         };
 
-        let service = RemoveProjectTopicService::new(repo);
+        let service = RemoveProjectTopicService::new(repo, Arc::new(NoopCache));
 
         let owner = UserId::from(Uuid::new_v4());
         let project_id = Uuid::new_v4();
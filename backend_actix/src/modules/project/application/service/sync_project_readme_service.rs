@@ -0,0 +1,319 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::modules::project::application::cache_keys;
+use crate::modules::project::application::ports::incoming::use_cases::{
+    ReadmeSyncResult, SyncProjectReadmeError, SyncProjectReadmeUseCase,
+};
+use crate::modules::project::application::ports::outgoing::project_query::{
+    ProjectQuery, ProjectQueryError,
+};
+use crate::modules::project::application::ports::outgoing::project_repository::{
+    PatchField, PatchProjectData, ProjectRepository, ProjectRepositoryError,
+};
+use crate::modules::project::application::ports::outgoing::readme_sync_repository::{
+    ReadmeSync, ReadmeSyncRepository,
+};
+use crate::modules::project::application::ports::outgoing::repo_metadata_provider::{
+    RepoMetadataError, RepoMetadataProvider,
+};
+use crate::modules::project::application::readme;
+use crate::shared::cache::{self, CachePort};
+
+//
+// ──────────────────────────────────────────────────────────
+// Service
+// ──────────────────────────────────────────────────────────
+//
+
+pub struct SyncProjectReadmeService<Q, R, S>
+where
+    Q: ProjectQuery,
+    R: ProjectRepository,
+    S: ReadmeSyncRepository,
+{
+    query: Q,
+    project_repository: R,
+    syncs: S,
+    repo_metadata: Arc<dyn RepoMetadataProvider>,
+    cache: Arc<dyn CachePort>,
+}
+
+impl<Q, R, S> SyncProjectReadmeService<Q, R, S>
+where
+    Q: ProjectQuery,
+    R: ProjectRepository,
+    S: ReadmeSyncRepository,
+{
+    pub fn new(
+        query: Q,
+        project_repository: R,
+        syncs: S,
+        repo_metadata: Arc<dyn RepoMetadataProvider>,
+        cache: Arc<dyn CachePort>,
+    ) -> Self {
+        Self {
+            query,
+            project_repository,
+            syncs,
+            repo_metadata,
+            cache,
+        }
+    }
+}
+
+fn repository_error(e: ProjectRepositoryError) -> SyncProjectReadmeError {
+    match e {
+        ProjectRepositoryError::NotFound => SyncProjectReadmeError::NotFound,
+        other => SyncProjectReadmeError::RepositoryError(other.to_string()),
+    }
+}
+
+#[async_trait]
+impl<Q, R, S> SyncProjectReadmeUseCase for SyncProjectReadmeService<Q, R, S>
+where
+    Q: ProjectQuery + Send + Sync,
+    R: ProjectRepository + Send + Sync,
+    S: ReadmeSyncRepository + Send + Sync,
+{
+    async fn execute(
+        &self,
+        owner: UserId,
+        project_id: Uuid,
+        keep_in_sync: Option<bool>,
+    ) -> Result<ReadmeSyncResult, SyncProjectReadmeError> {
+        let project = self
+            .query
+            .get_by_id(owner, project_id)
+            .await
+            .map_err(|e| match e {
+                ProjectQueryError::NotFound => SyncProjectReadmeError::NotFound,
+                ProjectQueryError::DatabaseError(msg)
+                | ProjectQueryError::SerializationError(msg) => {
+                    SyncProjectReadmeError::RepositoryError(msg)
+                }
+            })?;
+
+        let repo_url = project
+            .repo_url
+            .filter(|url| !url.trim().is_empty())
+            .ok_or(SyncProjectReadmeError::NoRepoUrl)?;
+
+        let fetched = self
+            .repo_metadata
+            .fetch_readme(repo_url.trim())
+            .await
+            .map_err(|e| match e {
+                RepoMetadataError::UnsupportedRepository => {
+                    SyncProjectReadmeError::UnsupportedRepository
+                }
+                RepoMetadataError::ReadmeNotFound => SyncProjectReadmeError::ReadmeNotFound,
+                RepoMetadataError::Unavailable(msg) => SyncProjectReadmeError::RepoUnavailable(msg),
+            })?;
+
+        let keep_in_sync = match keep_in_sync {
+            Some(keep) => keep,
+            None => self
+                .syncs
+                .find(project_id)
+                .await
+                .map_err(repository_error)?
+                .is_some_and(|sync| sync.keep_in_sync),
+        };
+
+        // An unchanged README leaves the project (and its updated_at) alone
+        let description = readme::to_description(&fetched);
+        let changed = description != project.description;
+        if changed {
+            self.project_repository
+                .patch_project(
+                    owner,
+                    project_id,
+                    PatchProjectData {
+                        description: PatchField::Value(description.clone()),
+                        ..Default::default()
+                    },
+                )
+                .await
+                .map_err(repository_error)?;
+
+            cache::invalidate_prefix(self.cache.as_ref(), &cache_keys::owner_prefix(owner)).await;
+        }
+
+        let sync = ReadmeSync {
+            project_id,
+            keep_in_sync,
+            readme_url: fetched.html_url,
+            synced_at: Utc::now(),
+        };
+        self.syncs
+            .save(sync.clone())
+            .await
+            .map_err(repository_error)?;
+
+        Ok(ReadmeSyncResult {
+            description,
+            changed,
+            sync,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::project::adapter::outgoing::InMemoryProjectStore;
+    use crate::modules::project::application::ports::outgoing::project_repository::CreateProjectData;
+    use crate::modules::project::application::ports::outgoing::repo_metadata_provider::RepoReadme;
+    use crate::shared::cache::NoopCache;
+
+    struct StubRepoMetadata {
+        result: Result<RepoReadme, RepoMetadataError>,
+    }
+
+    #[async_trait]
+    impl RepoMetadataProvider for StubRepoMetadata {
+        async fn fetch_readme(&self, _repo_url: &str) -> Result<RepoReadme, RepoMetadataError> {
+            self.result.clone()
+        }
+    }
+
+    fn readme(markdown: &str) -> RepoReadme {
+        RepoReadme {
+            markdown: markdown.to_string(),
+            html_url: "https://github.com/jane/cms/blob/HEAD/README.md".to_string(),
+            raw_base_url: "https://raw.githubusercontent.com/jane/cms/HEAD/".to_string(),
+            html_base_url: "https://github.com/jane/cms/blob/HEAD/".to_string(),
+        }
+    }
+
+    fn service(
+        store: &InMemoryProjectStore,
+        result: Result<RepoReadme, RepoMetadataError>,
+    ) -> SyncProjectReadmeService<InMemoryProjectStore, InMemoryProjectStore, InMemoryProjectStore>
+    {
+        SyncProjectReadmeService::new(
+            store.clone(),
+            store.clone(),
+            store.clone(),
+            Arc::new(StubRepoMetadata { result }),
+            Arc::new(NoopCache),
+        )
+    }
+
+    async fn project(store: &InMemoryProjectStore, repo_url: Option<&str>) -> (UserId, Uuid) {
+        let owner = UserId::from(Uuid::new_v4());
+        let project = store
+            .create_project(CreateProjectData {
+                owner,
+                title: "CMS".to_string(),
+                slug: format!("cms-{}", Uuid::new_v4()),
+                description: "Hand-written".to_string(),
+                tech_stack: vec![],
+                screenshots: vec![],
+                repo_url: repo_url.map(str::to_string),
+                live_demo_url: None,
+                seo_title: None,
+                seo_description: None,
+                canonical_url: None,
+                noindex: false,
+            })
+            .await
+            .unwrap();
+        (owner, project.id)
+    }
+
+    #[tokio::test]
+    async fn test_sync_replaces_description_and_records_flag() {
+        let store = InMemoryProjectStore::default();
+        let (owner, project_id) = project(&store, Some("https://github.com/jane/cms")).await;
+        let service = service(&store, Ok(readme("# CMS\n![shot](shot.png)")));
+
+        let result = service
+            .execute(owner, project_id, Some(true))
+            .await
+            .unwrap();
+
+        assert!(result.changed);
+        assert_eq!(
+            result.description,
+            "# CMS\n![shot](https://raw.githubusercontent.com/jane/cms/HEAD/shot.png)"
+        );
+        let stored = store.get_by_id(owner, project_id).await.unwrap();
+        assert_eq!(stored.description, result.description);
+        let sync = store.find(project_id).await.unwrap().unwrap();
+        assert!(sync.keep_in_sync);
+        assert_eq!(sync.readme_url, result.sync.readme_url);
+
+        // Unchanged README, flag kept when not given
+        let again = service.execute(owner, project_id, None).await.unwrap();
+        assert!(!again.changed);
+        assert!(again.sync.keep_in_sync);
+    }
+
+    #[tokio::test]
+    async fn test_first_sync_without_flag_does_not_keep_in_sync() {
+        let store = InMemoryProjectStore::default();
+        let (owner, project_id) = project(&store, Some("https://github.com/jane/cms")).await;
+
+        let result = service(&store, Ok(readme("# CMS")))
+            .execute(owner, project_id, None)
+            .await
+            .unwrap();
+
+        assert!(!result.sync.keep_in_sync);
+    }
+
+    #[tokio::test]
+    async fn test_project_without_repo_url_is_refused() {
+        let store = InMemoryProjectStore::default();
+        let (owner, project_id) = project(&store, None).await;
+
+        let result = service(&store, Ok(readme("# CMS")))
+            .execute(owner, project_id, Some(true))
+            .await;
+
+        assert!(matches!(result, Err(SyncProjectReadmeError::NoRepoUrl)));
+        assert!(store.find(project_id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_provider_errors_are_mapped_and_description_kept() {
+        let store = InMemoryProjectStore::default();
+        let (owner, project_id) = project(&store, Some("https://gitlab.example/x")).await;
+
+        let result = service(&store, Err(RepoMetadataError::ReadmeNotFound))
+            .execute(owner, project_id, Some(true))
+            .await;
+        assert!(matches!(
+            result,
+            Err(SyncProjectReadmeError::ReadmeNotFound)
+        ));
+
+        let result = service(&store, Err(RepoMetadataError::UnsupportedRepository))
+            .execute(owner, project_id, Some(true))
+            .await;
+        assert!(matches!(
+            result,
+            Err(SyncProjectReadmeError::UnsupportedRepository)
+        ));
+
+        let stored = store.get_by_id(owner, project_id).await.unwrap();
+        assert_eq!(stored.description, "Hand-written");
+    }
+
+    #[tokio::test]
+    async fn test_someone_elses_project_is_not_found() {
+        let store = InMemoryProjectStore::default();
+        let (_, project_id) = project(&store, Some("https://github.com/jane/cms")).await;
+
+        let result = service(&store, Ok(readme("# CMS")))
+            .execute(UserId::from(Uuid::new_v4()), project_id, Some(true))
+            .await;
+
+        assert!(matches!(result, Err(SyncProjectReadmeError::NotFound)));
+    }
+}
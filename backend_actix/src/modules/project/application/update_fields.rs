@@ -0,0 +1,97 @@
+//! Checks of a timeline entry write. Title and body are stored trimmed;
+//! every field is checked, so the client learns about all problems at once.
+
+use crate::modules::project::application::ports::outgoing::project_update_repository::{
+    CreateProjectUpdateData, PatchProjectUpdateData,
+};
+use crate::shared::validation::ValidationErrors;
+
+const MAX_TITLE_CHARS: usize = 200;
+const MAX_BODY_CHARS: usize = 20_000;
+
+fn title(text: String) -> Result<String, String> {
+    let text = text.trim();
+    match text.chars().count() {
+        0 => Err("Title is required".to_string()),
+        n if n > MAX_TITLE_CHARS => Err(format!(
+            "Title must be at most {MAX_TITLE_CHARS} characters"
+        )),
+        _ => Ok(text.to_string()),
+    }
+}
+
+fn body(text: String) -> Result<String, String> {
+    let text = text.trim();
+    match text.chars().count() {
+        0 => Err("Body is required".to_string()),
+        n if n > MAX_BODY_CHARS => Err(format!("Body must be at most {MAX_BODY_CHARS} characters")),
+        _ => Ok(text.to_string()),
+    }
+}
+
+pub(crate) fn check_create(data: &mut CreateProjectUpdateData) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+    let checked = title(std::mem::take(&mut data.title));
+    data.title = errors
+        .check("title", "INVALID_UPDATE_TITLE", checked)
+        .unwrap_or_default();
+    let checked = body(std::mem::take(&mut data.body));
+    data.body = errors
+        .check("body", "INVALID_UPDATE_BODY", checked)
+        .unwrap_or_default();
+    errors.into_result()
+}
+
+pub(crate) fn check_patch(data: &mut PatchProjectUpdateData) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+    if let Some(text) = data.title.take() {
+        data.title = errors.check("title", "INVALID_UPDATE_TITLE", title(text));
+    }
+    if let Some(text) = data.body.take() {
+        data.body = errors.check("body", "INVALID_UPDATE_BODY", body(text));
+    }
+    errors.into_result()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_trims_and_reports_every_field() {
+        let mut data = CreateProjectUpdateData {
+            title: "  v1.0  ".to_string(),
+            body: "\n Released \n".to_string(),
+            posted_at: None,
+        };
+        check_create(&mut data).unwrap();
+        assert_eq!(data.title, "v1.0");
+        assert_eq!(data.body, "Released");
+
+        let mut blank = CreateProjectUpdateData {
+            title: " ".to_string(),
+            body: "x".repeat(MAX_BODY_CHARS + 1),
+            posted_at: None,
+        };
+        let errors = check_create(&mut blank).unwrap_err();
+        assert!(errors.has("title"));
+        assert!(errors.has("body"));
+    }
+
+    #[test]
+    fn test_patch_checks_only_given_fields() {
+        let mut data = PatchProjectUpdateData {
+            body: Some(" Fixed ".to_string()),
+            ..Default::default()
+        };
+        check_patch(&mut data).unwrap();
+        assert_eq!(data.title, None);
+        assert_eq!(data.body.as_deref(), Some("Fixed"));
+
+        let mut empty_title = PatchProjectUpdateData {
+            title: Some(String::new()),
+            ..Default::default()
+        };
+        assert!(check_patch(&mut empty_title).unwrap_err().has("title"));
+    }
+}
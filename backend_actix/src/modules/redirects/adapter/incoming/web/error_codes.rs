@@ -0,0 +1,9 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    INVALID_SOURCE_PATH = (BAD_REQUEST, "Invalid source path");
+    INVALID_TARGET_URL = (BAD_REQUEST, "Invalid target URL");
+    SELF_REDIRECT = (BAD_REQUEST, "A redirect cannot point to itself");
+    REDIRECT_NOT_FOUND = (NOT_FOUND, "Redirect not found");
+    SOURCE_ALREADY_EXISTS = (CONFLICT, "A redirect from this path already exists");
+}
@@ -0,0 +1,17 @@
+use actix_web::web;
+
+pub mod error_codes;
+pub mod routes;
+
+/// Routes anyone can call
+pub fn configure_public(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::resolve_redirect_handler);
+}
+
+/// Routes for signed-in users with a verified email
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::create_redirect_handler)
+        .service(routes::list_redirects_handler)
+        .service(routes::update_redirect_handler)
+        .service(routes::delete_redirect_handler);
+}
@@ -0,0 +1,193 @@
+use actix_web::{post, web, Responder};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use utoipa::ToSchema;
+
+use super::{map_command_error, source_already_exists};
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    redirects::application::{
+        domain::entities::{Redirect, RedirectStatus},
+        ports::incoming::use_cases::{CreateRedirectCommand, CreateRedirectError},
+    },
+    shared::api::ApiResponse,
+    AppState,
+};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct CreateRedirectRequest {
+    /// Site path to redirect, e.g. `/2019/05/old-post` or `/r/cv`. A trailing
+    /// slash is dropped.
+    pub source_path: String,
+    /// Absolute http(s) URL or a path on this site
+    pub target_url: String,
+    /// 301 (default) or 302
+    #[serde(default)]
+    #[schema(value_type = Option<u16>, example = 301)]
+    pub status_code: RedirectStatus,
+}
+
+/// Create a redirect
+#[utoipa::path(
+    post,
+    path = "/api/admin/redirects",
+    tag = "redirects",
+    request_body = CreateRedirectRequest,
+    responses(
+        (status = 201, description = "Redirect created", body = inline(SuccessResponse<Redirect>)),
+        (status = 400, description = "Invalid field or malformed request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 409, description = "A redirect from this path already exists", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/admin/redirects")]
+pub async fn create_redirect_handler(
+    admin: AdminUser,
+    payload: web::Json<CreateRedirectRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let payload = payload.into_inner();
+    let command = match CreateRedirectCommand::new(
+        payload.source_path,
+        payload.target_url,
+        payload.status_code,
+    ) {
+        Ok(cmd) => cmd,
+        Err(err) => return map_command_error(err),
+    };
+
+    match data.redirects.create.execute(command).await {
+        Ok(redirect) => ApiResponse::created(redirect),
+        Err(CreateRedirectError::SourceAlreadyExists) => source_already_exists(),
+        Err(CreateRedirectError::RepositoryError(msg)) => {
+            error!(admin = %admin.user_id, "Failed to create redirect: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::json;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubCreateRedirectUseCase,
+        },
+    };
+
+    async fn call(
+        state: web::Data<AppState>,
+        user_id: Uuid,
+        body: serde_json::Value,
+    ) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(user_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(create_redirect_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/admin/redirects")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(body)
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_redirect_is_created() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .build();
+
+        let resp = call(
+            state,
+            admin,
+            json!({ "source_path": "/r/cv/", "target_url": "https://example.com/cv.pdf", "status_code": 302 }),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["source_path"], "/r/cv");
+        assert_eq!(json["data"]["status_code"], 302);
+        assert_eq!(json["data"]["hits"], 0);
+    }
+
+    #[actix_web::test]
+    async fn test_other_status_codes_are_rejected() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .build();
+
+        let resp = call(
+            state,
+            admin,
+            json!({ "source_path": "/old", "target_url": "/new", "status_code": 307 }),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_duplicate_source_is_a_conflict() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .with_create_redirect(StubCreateRedirectUseCase::conflict())
+            .build();
+
+        let resp = call(
+            state,
+            admin,
+            json!({ "source_path": "/old", "target_url": "/new" }),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "SOURCE_ALREADY_EXISTS");
+    }
+
+    #[actix_web::test]
+    async fn test_reserved_source_is_rejected() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .build();
+
+        let resp = call(
+            state,
+            admin,
+            json!({ "source_path": "/api/pages", "target_url": "/new" }),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "INVALID_SOURCE_PATH");
+    }
+}
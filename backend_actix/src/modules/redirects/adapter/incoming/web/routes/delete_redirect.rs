@@ -0,0 +1,91 @@
+use actix_web::{delete, web, Responder};
+use tracing::error;
+use uuid::Uuid;
+
+use super::redirect_not_found;
+use crate::api::schemas::ErrorResponse;
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    redirects::application::ports::incoming::use_cases::DeleteRedirectError,
+    shared::api::ApiResponse, AppState,
+};
+
+/// Delete a redirect. Its source path answers 404 again.
+#[utoipa::path(
+    delete,
+    path = "/api/admin/redirects/{redirect_id}",
+    tag = "redirects",
+    params(
+        ("redirect_id" = Uuid, Path, description = "Redirect id"),
+    ),
+    responses(
+        (status = 204, description = "Redirect deleted"),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 404, description = "Redirect not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[delete("/api/admin/redirects/{redirect_id}")]
+pub async fn delete_redirect_handler(
+    admin: AdminUser,
+    path: web::Path<Uuid>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let redirect_id = path.into_inner();
+
+    match data.redirects.delete.execute(redirect_id).await {
+        Ok(()) => ApiResponse::no_content(),
+        Err(DeleteRedirectError::NotFound) => redirect_not_found(),
+        Err(DeleteRedirectError::RepositoryError(msg)) => {
+            error!(admin = %admin.user_id, "Failed to delete redirect {}: {}", redirect_id, msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubDeleteRedirectUseCase,
+        },
+    };
+
+    #[actix_web::test]
+    async fn test_unknown_redirect_is_not_found() {
+        let admin = Uuid::new_v4();
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(admin, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .with_delete_redirect(StubDeleteRedirectUseCase::not_found())
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(delete_redirect_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/admin/redirects/{}", Uuid::new_v4()))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,36 @@
+mod create_redirect;
+mod delete_redirect;
+mod list_redirects;
+mod resolve_redirect;
+mod update_redirect;
+
+pub use create_redirect::{__path_create_redirect_handler, create_redirect_handler};
+pub use delete_redirect::{__path_delete_redirect_handler, delete_redirect_handler};
+pub use list_redirects::{__path_list_redirects_handler, list_redirects_handler};
+pub use resolve_redirect::{__path_resolve_redirect_handler, resolve_redirect_handler};
+pub use update_redirect::{__path_update_redirect_handler, update_redirect_handler};
+
+use actix_web::HttpResponse;
+
+use crate::redirects::adapter::incoming::web::error_codes::{
+    INVALID_SOURCE_PATH, INVALID_TARGET_URL, REDIRECT_NOT_FOUND, SELF_REDIRECT,
+    SOURCE_ALREADY_EXISTS,
+};
+use crate::redirects::application::ports::incoming::use_cases::RedirectCommandError;
+
+fn map_command_error(err: RedirectCommandError) -> HttpResponse {
+    let code = match err {
+        RedirectCommandError::InvalidSourcePath => INVALID_SOURCE_PATH,
+        RedirectCommandError::InvalidTargetUrl => INVALID_TARGET_URL,
+        RedirectCommandError::SelfRedirect => SELF_REDIRECT,
+    };
+    code.with_message(&err.to_string())
+}
+
+fn redirect_not_found() -> HttpResponse {
+    REDIRECT_NOT_FOUND.response()
+}
+
+fn source_already_exists() -> HttpResponse {
+    SOURCE_ALREADY_EXISTS.response()
+}
@@ -0,0 +1,124 @@
+use actix_web::{get, web, Responder};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use utoipa::ToSchema;
+
+use super::redirect_not_found;
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    redirects::application::{
+        domain::entities::RedirectStatus, ports::incoming::use_cases::ResolveRedirectError,
+    },
+    shared::api::{cache::CachePolicy, ApiResponse},
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct ResolveRedirectQuery {
+    /// Requested site path, e.g. `/2019/05/old-post/`
+    pub path: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ResolvedRedirect {
+    pub target_url: String,
+    /// 301 or 302; answer the visitor with this status
+    #[schema(value_type = u16, example = 301)]
+    pub status_code: RedirectStatus,
+}
+
+/// Where a site path redirects to
+///
+/// Called by the frontend for paths it can't serve; each call counts as a
+/// hit. A trailing slash and the query string are ignored. Never cached, so
+/// every visit is counted and changes apply at once.
+#[utoipa::path(
+    get,
+    path = "/api/public/redirects/resolve",
+    tag = "redirects",
+    params(
+        ("path" = String, Query, description = "Requested site path"),
+    ),
+    responses(
+        (status = 200, description = "Redirect target", body = inline(SuccessResponse<ResolvedRedirect>)),
+        (status = 400, description = "Missing path", body = ErrorResponse),
+        (status = 404, description = "No redirect from this path", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    )
+)]
+#[get("/api/public/redirects/resolve")]
+pub async fn resolve_redirect_handler(
+    query: web::Query<ResolveRedirectQuery>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data.redirects.resolve.execute(&query.path).await {
+        Ok(redirect) => {
+            let mut resp = ApiResponse::success(ResolvedRedirect {
+                target_url: redirect.target_url,
+                status_code: redirect.status_code,
+            });
+            CachePolicy::NoStore.apply(&mut resp);
+            resp
+        }
+        Err(ResolveRedirectError::NotFound) => redirect_not_found(),
+        Err(ResolveRedirectError::RepositoryError(msg)) => {
+            error!("Failed to resolve redirect for {}: {}", query.path, msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{
+        http::{header, StatusCode},
+        test, App,
+    };
+
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, stubs::StubResolveRedirectUseCase,
+    };
+
+    #[actix_web::test]
+    async fn test_known_path_resolves_uncached() {
+        let state = TestAppStateBuilder::default()
+            .with_resolve_redirect(StubResolveRedirectUseCase::temporary("/r/cv", "/cv.pdf"))
+            .build();
+        let app =
+            test::init_service(App::new().app_data(state).service(resolve_redirect_handler)).await;
+
+        let req = test::TestRequest::get()
+            .uri("/api/public/redirects/resolve?path=/r/cv")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers().get(header::CACHE_CONTROL).unwrap(),
+            "no-store"
+        );
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["target_url"], "/cv.pdf");
+        assert_eq!(json["data"]["status_code"], 302);
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_path_is_not_found() {
+        let app = test::init_service(
+            App::new()
+                .app_data(TestAppStateBuilder::default().build())
+                .service(resolve_redirect_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/api/public/redirects/resolve?path=/missing")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "REDIRECT_NOT_FOUND");
+    }
+}
@@ -0,0 +1,159 @@
+use actix_web::{patch, web, Responder};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::{map_command_error, redirect_not_found, source_already_exists};
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    redirects::application::{
+        domain::entities::{Redirect, RedirectStatus},
+        ports::incoming::use_cases::{
+            RedirectCommandError, UpdateRedirectCommand, UpdateRedirectError,
+        },
+    },
+    shared::api::ApiResponse,
+    AppState,
+};
+
+/// Omitted fields are kept
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct UpdateRedirectRequest {
+    #[serde(default)]
+    pub source_path: Option<String>,
+
+    #[serde(default)]
+    pub target_url: Option<String>,
+
+    /// 301 or 302
+    #[serde(default)]
+    #[schema(value_type = Option<u16>, example = 302)]
+    pub status_code: Option<RedirectStatus>,
+}
+
+/// Change a redirect. The hit counter is kept.
+#[utoipa::path(
+    patch,
+    path = "/api/admin/redirects/{redirect_id}",
+    tag = "redirects",
+    params(
+        ("redirect_id" = Uuid, Path, description = "Redirect id"),
+    ),
+    request_body = UpdateRedirectRequest,
+    responses(
+        (status = 200, description = "Redirect updated", body = inline(SuccessResponse<Redirect>)),
+        (status = 400, description = "Invalid field or malformed request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 404, description = "Redirect not found", body = ErrorResponse),
+        (status = 409, description = "A redirect from this path already exists", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[patch("/api/admin/redirects/{redirect_id}")]
+pub async fn update_redirect_handler(
+    admin: AdminUser,
+    path: web::Path<Uuid>,
+    payload: web::Json<UpdateRedirectRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let redirect_id = path.into_inner();
+    let payload = payload.into_inner();
+    let command = match UpdateRedirectCommand::new(
+        payload.source_path,
+        payload.target_url,
+        payload.status_code,
+    ) {
+        Ok(cmd) => cmd,
+        Err(err) => return map_command_error(err),
+    };
+
+    match data.redirects.update.execute(redirect_id, command).await {
+        Ok(redirect) => ApiResponse::success(redirect),
+        Err(UpdateRedirectError::NotFound) => redirect_not_found(),
+        Err(UpdateRedirectError::SourceAlreadyExists) => source_already_exists(),
+        Err(UpdateRedirectError::SelfRedirect) => {
+            map_command_error(RedirectCommandError::SelfRedirect)
+        }
+        Err(UpdateRedirectError::RepositoryError(msg)) => {
+            error!(admin = %admin.user_id, "Failed to update redirect {}: {}", redirect_id, msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubUpdateRedirectUseCase,
+        },
+    };
+
+    async fn call(
+        state: web::Data<AppState>,
+        user_id: Uuid,
+        body: serde_json::Value,
+    ) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(user_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(update_redirect_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::patch()
+            .uri(&format!("/api/admin/redirects/{}", Uuid::new_v4()))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(body)
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_invalid_target_is_rejected() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .build();
+
+        let resp = call(state, admin, json!({ "target_url": "javascript:alert(1)" })).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "INVALID_TARGET_URL");
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_redirect_is_not_found() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .with_update_redirect(StubUpdateRedirectUseCase::not_found())
+            .build();
+
+        let resp = call(state, admin, json!({ "status_code": 302 })).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "REDIRECT_NOT_FOUND");
+    }
+}
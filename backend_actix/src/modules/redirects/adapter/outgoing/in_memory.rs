@@ -0,0 +1,133 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::redirects::application::domain::entities::Redirect;
+use crate::redirects::application::ports::incoming::use_cases::{
+    CreateRedirectCommand, UpdateRedirectCommand,
+};
+use crate::redirects::application::ports::outgoing::{
+    RedirectPage, RedirectRepository, RedirectRepositoryError,
+};
+use crate::shared::in_memory::Table;
+
+/// Process-local `RedirectRepository`
+#[derive(Clone, Default)]
+pub struct InMemoryRedirectStore {
+    redirects: Table<Redirect>,
+}
+
+#[async_trait]
+impl RedirectRepository for InMemoryRedirectStore {
+    async fn create(
+        &self,
+        command: &CreateRedirectCommand,
+    ) -> Result<Redirect, RedirectRepositoryError> {
+        self.redirects.write(|redirects| {
+            if redirects
+                .iter()
+                .any(|redirect| redirect.source_path == command.source_path())
+            {
+                return Err(RedirectRepositoryError::SourceAlreadyExists);
+            }
+
+            let now = Utc::now();
+            let redirect = Redirect {
+                id: Uuid::new_v4(),
+                source_path: command.source_path().to_string(),
+                target_url: command.target_url().to_string(),
+                status_code: command.status(),
+                hits: 0,
+                last_hit_at: None,
+                created_at: now,
+                updated_at: now,
+            };
+            redirects.push(redirect.clone());
+            Ok(redirect)
+        })
+    }
+
+    async fn list(&self, offset: u64, limit: u32) -> Result<RedirectPage, RedirectRepositoryError> {
+        let mut redirects = self.redirects.read(|redirects| redirects.to_vec());
+        redirects.sort_by(|a, b| a.source_path.cmp(&b.source_path));
+
+        Ok(RedirectPage {
+            total: redirects.len() as u64,
+            items: redirects
+                .into_iter()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .collect(),
+        })
+    }
+
+    async fn get(&self, id: Uuid) -> Result<Redirect, RedirectRepositoryError> {
+        self.redirects.read(|redirects| {
+            redirects
+                .iter()
+                .find(|redirect| redirect.id == id)
+                .cloned()
+                .ok_or(RedirectRepositoryError::NotFound)
+        })
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        command: &UpdateRedirectCommand,
+    ) -> Result<Redirect, RedirectRepositoryError> {
+        self.redirects.write(|redirects| {
+            if let Some(source_path) = command.source_path() {
+                if redirects
+                    .iter()
+                    .any(|redirect| redirect.id != id && redirect.source_path == source_path)
+                {
+                    return Err(RedirectRepositoryError::SourceAlreadyExists);
+                }
+            }
+
+            let redirect = redirects
+                .iter_mut()
+                .find(|redirect| redirect.id == id)
+                .ok_or(RedirectRepositoryError::NotFound)?;
+
+            if let Some(source_path) = command.source_path() {
+                redirect.source_path = source_path.to_string();
+            }
+            if let Some(target_url) = command.target_url() {
+                redirect.target_url = target_url.to_string();
+            }
+            if let Some(status) = command.status() {
+                redirect.status_code = status;
+            }
+            redirect.updated_at = Utc::now();
+
+            Ok(redirect.clone())
+        })
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), RedirectRepositoryError> {
+        self.redirects.write(|redirects| {
+            let before = redirects.len();
+            redirects.retain(|redirect| redirect.id != id);
+            if redirects.len() == before {
+                return Err(RedirectRepositoryError::NotFound);
+            }
+            Ok(())
+        })
+    }
+
+    async fn record_hit(
+        &self,
+        source_path: &str,
+    ) -> Result<Option<Redirect>, RedirectRepositoryError> {
+        Ok(self.redirects.write(|redirects| {
+            let redirect = redirects
+                .iter_mut()
+                .find(|redirect| redirect.source_path == source_path)?;
+            redirect.hits += 1;
+            redirect.last_hit_at = Some(Utc::now());
+            Some(redirect.clone())
+        }))
+    }
+}
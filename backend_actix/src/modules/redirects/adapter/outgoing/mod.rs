@@ -0,0 +1,5 @@
+mod in_memory;
+mod redirect_repository_postgres;
+
+pub use in_memory::InMemoryRedirectStore;
+pub use redirect_repository_postgres::RedirectRepositoryPostgres;
@@ -0,0 +1,367 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement, Value,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::redirects::application::domain::entities::{Redirect, RedirectStatus};
+use crate::redirects::application::ports::incoming::use_cases::{
+    CreateRedirectCommand, UpdateRedirectCommand,
+};
+use crate::redirects::application::ports::outgoing::{
+    RedirectPage, RedirectRepository, RedirectRepositoryError,
+};
+use crate::shared::sql;
+
+const REDIRECT_COLUMNS: &str =
+    "id, source_path, target_url, status_code, hits, last_hit_at, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct RedirectRepositoryPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl RedirectRepositoryPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    fn insert_stmt(backend: DatabaseBackend, command: &CreateRedirectCommand) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                INSERT INTO redirects (id, source_path, target_url, status_code)
+                VALUES ($1, $2, $3, $4)
+                RETURNING {REDIRECT_COLUMNS}
+                "#
+            ),
+            vec![
+                Uuid::new_v4().into(),
+                command.source_path().into(),
+                command.target_url().into(),
+                (command.status().code() as i16).into(),
+            ],
+        )
+    }
+
+    fn list_stmt(backend: DatabaseBackend, offset: u64, limit: u32) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                SELECT {REDIRECT_COLUMNS}
+                FROM redirects
+                ORDER BY source_path
+                LIMIT $2
+                OFFSET $1
+                "#
+            ),
+            vec![(offset as i64).into(), (limit as i64).into()],
+        )
+    }
+
+    fn count_stmt(backend: DatabaseBackend) -> Statement {
+        Statement::from_string(backend, "SELECT COUNT(*) AS total FROM redirects")
+    }
+
+    fn get_stmt(backend: DatabaseBackend, id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!("SELECT {REDIRECT_COLUMNS} FROM redirects WHERE id = $1"),
+            vec![id.into()],
+        )
+    }
+
+    /// Only the fields the command changes are set
+    fn update_stmt(
+        backend: DatabaseBackend,
+        id: Uuid,
+        command: &UpdateRedirectCommand,
+    ) -> Statement {
+        let mut sets = Vec::new();
+        let mut values: Vec<Value> = vec![id.into()];
+        let mut set = |column: &str, value: Value| {
+            values.push(value);
+            sets.push(format!("{column} = ${}", values.len()));
+        };
+
+        if let Some(source_path) = command.source_path() {
+            set("source_path", source_path.into());
+        }
+        if let Some(target_url) = command.target_url() {
+            set("target_url", target_url.into());
+        }
+        if let Some(status) = command.status() {
+            set("status_code", (status.code() as i16).into());
+        }
+        sets.push(format!("updated_at = {}", sql::now(backend)));
+
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                UPDATE redirects
+                SET {sets}
+                WHERE id = $1
+                RETURNING {REDIRECT_COLUMNS}
+                "#,
+                sets = sets.join(", "),
+            ),
+            values,
+        )
+    }
+
+    fn delete_stmt(backend: DatabaseBackend, id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            "DELETE FROM redirects WHERE id = $1",
+            vec![id.into()],
+        )
+    }
+
+    fn record_hit_stmt(backend: DatabaseBackend, source_path: &str) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                UPDATE redirects
+                SET hits = hits + 1, last_hit_at = {now}
+                WHERE source_path = $1
+                RETURNING {REDIRECT_COLUMNS}
+                "#,
+                now = sql::now(backend),
+            ),
+            vec![source_path.into()],
+        )
+    }
+
+    // =====================================================
+    // Mapping
+    // =====================================================
+
+    fn to_redirect(row: &QueryResult) -> Result<Redirect, RedirectRepositoryError> {
+        let status_code: i16 = row.try_get("", "status_code").map_err(Self::map_db_err)?;
+        let hits: i64 = row.try_get("", "hits").map_err(Self::map_db_err)?;
+
+        Ok(Redirect {
+            id: row.try_get("", "id").map_err(Self::map_db_err)?,
+            source_path: row.try_get("", "source_path").map_err(Self::map_db_err)?,
+            target_url: row.try_get("", "target_url").map_err(Self::map_db_err)?,
+            status_code: RedirectStatus::try_from(status_code.max(0) as u16)
+                .map_err(RedirectRepositoryError::DatabaseError)?,
+            hits: hits.max(0) as u64,
+            last_hit_at: row.try_get("", "last_hit_at").map_err(Self::map_db_err)?,
+            created_at: row.try_get("", "created_at").map_err(Self::map_db_err)?,
+            updated_at: row.try_get("", "updated_at").map_err(Self::map_db_err)?,
+        })
+    }
+
+    /// Insert and update both hit the unique source path index
+    fn map_write_err(e: DbErr) -> RedirectRepositoryError {
+        let msg = e.to_string().to_lowercase();
+
+        if (msg.contains("duplicate") || msg.contains("unique") || msg.contains("23505"))
+            && msg.contains("source_path")
+        {
+            RedirectRepositoryError::SourceAlreadyExists
+        } else {
+            RedirectRepositoryError::DatabaseError(e.to_string())
+        }
+    }
+
+    fn map_db_err(e: DbErr) -> RedirectRepositoryError {
+        RedirectRepositoryError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl RedirectRepository for RedirectRepositoryPostgres {
+    async fn create(
+        &self,
+        command: &CreateRedirectCommand,
+    ) -> Result<Redirect, RedirectRepositoryError> {
+        let row = self
+            .db
+            .query_one(Self::insert_stmt(self.db.get_database_backend(), command))
+            .await
+            .map_err(Self::map_write_err)?
+            .ok_or_else(|| {
+                RedirectRepositoryError::DatabaseError("insert returned no row".into())
+            })?;
+
+        Self::to_redirect(&row)
+    }
+
+    async fn list(&self, offset: u64, limit: u32) -> Result<RedirectPage, RedirectRepositoryError> {
+        let backend = self.db.get_database_backend();
+        let items = self
+            .db
+            .query_all(Self::list_stmt(backend, offset, limit))
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(Self::to_redirect)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let total: i64 = self
+            .db
+            .query_one(Self::count_stmt(backend))
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or_else(|| RedirectRepositoryError::DatabaseError("count returned no row".into()))?
+            .try_get("", "total")
+            .map_err(Self::map_db_err)?;
+
+        Ok(RedirectPage {
+            items,
+            total: total.max(0) as u64,
+        })
+    }
+
+    async fn get(&self, id: Uuid) -> Result<Redirect, RedirectRepositoryError> {
+        let row = self
+            .db
+            .query_one(Self::get_stmt(self.db.get_database_backend(), id))
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or(RedirectRepositoryError::NotFound)?;
+
+        Self::to_redirect(&row)
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        command: &UpdateRedirectCommand,
+    ) -> Result<Redirect, RedirectRepositoryError> {
+        let row = self
+            .db
+            .query_one(Self::update_stmt(
+                self.db.get_database_backend(),
+                id,
+                command,
+            ))
+            .await
+            .map_err(Self::map_write_err)?
+            .ok_or(RedirectRepositoryError::NotFound)?;
+
+        Self::to_redirect(&row)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), RedirectRepositoryError> {
+        let res = self
+            .db
+            .execute(Self::delete_stmt(self.db.get_database_backend(), id))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        if res.rows_affected() == 0 {
+            return Err(RedirectRepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn record_hit(
+        &self,
+        source_path: &str,
+    ) -> Result<Option<Redirect>, RedirectRepositoryError> {
+        self.db
+            .query_one(Self::record_hit_stmt(
+                self.db.get_database_backend(),
+                source_path,
+            ))
+            .await
+            .map_err(Self::map_db_err)?
+            .as_ref()
+            .map(Self::to_redirect)
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use sea_orm::MockDatabase;
+    use std::collections::BTreeMap;
+
+    fn redirect_row(status_code: i16, hits: i64) -> BTreeMap<String, Value> {
+        BTreeMap::from([
+            ("id".to_string(), Value::from(Uuid::new_v4())),
+            ("source_path".to_string(), Value::from("/r/cv")),
+            ("target_url".to_string(), Value::from("/cv.pdf")),
+            ("status_code".to_string(), Value::from(status_code)),
+            ("hits".to_string(), Value::from(hits)),
+            ("last_hit_at".to_string(), Value::from(Utc::now())),
+            ("created_at".to_string(), Value::from(Utc::now())),
+            ("updated_at".to_string(), Value::from(Utc::now())),
+        ])
+    }
+
+    #[tokio::test]
+    async fn test_create_maps_duplicate_source_path() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_errors([DbErr::Custom(
+                "duplicate key value violates unique constraint \"idx_redirects_source_path\""
+                    .to_string(),
+            )])
+            .into_connection();
+        let command = CreateRedirectCommand::new(
+            "/old".to_string(),
+            "/new".to_string(),
+            RedirectStatus::Permanent,
+        )
+        .unwrap();
+
+        let repo = RedirectRepositoryPostgres::new(Arc::new(db));
+        let result = repo.create(&command).await;
+
+        assert!(matches!(
+            result,
+            Err(RedirectRepositoryError::SourceAlreadyExists)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_record_hit_reads_counted_row() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![redirect_row(302, 7)]])
+            .into_connection();
+
+        let repo = RedirectRepositoryPostgres::new(Arc::new(db));
+        let redirect = repo.record_hit("/r/cv").await.unwrap().unwrap();
+
+        assert_eq!(redirect.status_code, RedirectStatus::Temporary);
+        assert_eq!(redirect.hits, 7);
+    }
+
+    #[test]
+    fn test_hit_is_counted_in_place() {
+        let stmt = RedirectRepositoryPostgres::record_hit_stmt(DatabaseBackend::Postgres, "/r/cv");
+
+        assert!(stmt.sql.contains("hits = hits + 1"));
+        assert!(stmt.sql.contains("WHERE source_path = $1"));
+    }
+
+    #[test]
+    fn test_update_sets_only_changed_columns() {
+        let stmt = RedirectRepositoryPostgres::update_stmt(
+            DatabaseBackend::Postgres,
+            Uuid::new_v4(),
+            &UpdateRedirectCommand::new(None, Some("/new".to_string()), None).unwrap(),
+        );
+
+        assert!(stmt.sql.contains("target_url = $2"));
+        assert!(!stmt.sql.contains("source_path ="));
+        assert!(!stmt.sql.contains("status_code ="));
+        assert!(!stmt.sql.contains("hits ="));
+        assert_eq!(stmt.values.unwrap().0.len(), 2);
+    }
+}
@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// How a redirect is answered. In JSON it is the status code itself.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "u16", into = "u16")]
+pub enum RedirectStatus {
+    /// 301: browsers and search engines remember the new address
+    #[default]
+    Permanent,
+    /// 302: asked again on every visit
+    Temporary,
+}
+
+impl RedirectStatus {
+    pub fn code(self) -> u16 {
+        match self {
+            RedirectStatus::Permanent => 301,
+            RedirectStatus::Temporary => 302,
+        }
+    }
+}
+
+impl TryFrom<u16> for RedirectStatus {
+    type Error = String;
+
+    fn try_from(code: u16) -> Result<Self, Self::Error> {
+        match code {
+            301 => Ok(RedirectStatus::Permanent),
+            302 => Ok(RedirectStatus::Temporary),
+            other => Err(format!("redirect status must be 301 or 302, not {other}")),
+        }
+    }
+}
+
+impl From<RedirectStatus> for u16 {
+    fn from(status: RedirectStatus) -> Self {
+        status.code()
+    }
+}
+
+/// Sends visitors of `source_path` to `target_url`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Redirect {
+    pub id: Uuid,
+    /// Site path that redirects, e.g. `/2019/05/old-post` or `/r/cv`
+    pub source_path: String,
+    /// Absolute http(s) URL or a path on this site
+    pub target_url: String,
+    /// 301 or 302
+    #[schema(value_type = u16, example = 301)]
+    pub status_code: RedirectStatus,
+    /// Times the redirect was followed
+    pub hits: u64,
+    /// Absent until first followed
+    pub last_hit_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -0,0 +1,4 @@
+pub mod domain;
+pub mod ports;
+pub mod redirect_use_cases;
+pub mod services;
@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+
+use super::redirect_command::{self, RedirectCommandError};
+use crate::redirects::application::domain::entities::{Redirect, RedirectStatus};
+use crate::shared::metrics::metered_use_case;
+
+//
+// ──────────────────────────────────────────────────────────
+// Create Redirect Command
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone)]
+pub struct CreateRedirectCommand {
+    source_path: String,
+    target_url: String,
+    status: RedirectStatus,
+}
+
+impl CreateRedirectCommand {
+    pub fn new(
+        source_path: String,
+        target_url: String,
+        status: RedirectStatus,
+    ) -> Result<Self, RedirectCommandError> {
+        let source_path = redirect_command::source_path(source_path)?;
+        let target_url = redirect_command::target_url(target_url)?;
+        redirect_command::ensure_no_loop(&source_path, &target_url)?;
+
+        Ok(Self {
+            source_path,
+            target_url,
+            status,
+        })
+    }
+
+    pub fn source_path(&self) -> &str {
+        &self.source_path
+    }
+
+    pub fn target_url(&self) -> &str {
+        &self.target_url
+    }
+
+    pub fn status(&self) -> RedirectStatus {
+        self.status
+    }
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Use Case Error
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum CreateRedirectError {
+    #[error("A redirect from this path already exists")]
+    SourceAlreadyExists,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Incoming Port (Use Case)
+// ──────────────────────────────────────────────────────────
+//
+
+#[async_trait]
+pub trait CreateRedirectUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        command: CreateRedirectCommand,
+    ) -> Result<Redirect, CreateRedirectError>;
+}
+
+metered_use_case!(
+    "redirects",
+    "create_redirect",
+    CreateRedirectUseCase,
+    fn execute(&self, command: CreateRedirectCommand) -> Result<Redirect, CreateRedirectError>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_normalizes_source_and_rejects_loops() {
+        let command = CreateRedirectCommand::new(
+            "/blog/old-post/".to_string(),
+            " https://example.com/posts/new ".to_string(),
+            RedirectStatus::Temporary,
+        )
+        .unwrap();
+
+        assert_eq!(command.source_path(), "/blog/old-post");
+        assert_eq!(command.target_url(), "https://example.com/posts/new");
+        assert_eq!(command.status(), RedirectStatus::Temporary);
+
+        assert!(matches!(
+            CreateRedirectCommand::new(
+                "/old".to_string(),
+                "/old/".to_string(),
+                RedirectStatus::Permanent
+            ),
+            Err(RedirectCommandError::SelfRedirect)
+        ));
+    }
+}
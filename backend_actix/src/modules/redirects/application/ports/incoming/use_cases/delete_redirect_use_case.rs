@@ -0,0 +1,24 @@
+use crate::shared::metrics::metered_use_case;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DeleteRedirectError {
+    #[error("Redirect not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait DeleteRedirectUseCase: Send + Sync {
+    async fn execute(&self, id: Uuid) -> Result<(), DeleteRedirectError>;
+}
+
+metered_use_case!(
+    "redirects",
+    "delete_redirect",
+    DeleteRedirectUseCase,
+    fn execute(&self, id: Uuid) -> Result<(), DeleteRedirectError>
+);
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::redirects::application::ports::outgoing::RedirectPage;
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ListRedirectsError {
+    #[error("Failed to list redirects: {0}")]
+    QueryFailed(String),
+}
+
+#[async_trait]
+pub trait ListRedirectsUseCase: Send + Sync {
+    /// By source path
+    async fn execute(&self, offset: u64, limit: u32) -> Result<RedirectPage, ListRedirectsError>;
+}
+
+metered_use_case!(
+    "redirects",
+    "list_redirects",
+    ListRedirectsUseCase,
+    fn execute(&self, offset: u64, limit: u32) -> Result<RedirectPage, ListRedirectsError>
+);
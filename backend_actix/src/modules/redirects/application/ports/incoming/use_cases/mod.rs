@@ -0,0 +1,20 @@
+mod create_redirect_use_case;
+mod delete_redirect_use_case;
+mod list_redirects_use_case;
+mod redirect_command;
+mod resolve_redirect_use_case;
+mod update_redirect_use_case;
+
+pub use create_redirect_use_case::{
+    CreateRedirectCommand, CreateRedirectError, CreateRedirectUseCase,
+};
+pub use delete_redirect_use_case::{DeleteRedirectError, DeleteRedirectUseCase};
+pub use list_redirects_use_case::{ListRedirectsError, ListRedirectsUseCase};
+pub use redirect_command::{
+    ensure_no_loop, normalize_source_path, RedirectCommandError, MAX_SOURCE_PATH_LENGTH,
+    MAX_TARGET_URL_LENGTH,
+};
+pub use resolve_redirect_use_case::{ResolveRedirectError, ResolveRedirectUseCase};
+pub use update_redirect_use_case::{
+    UpdateRedirectCommand, UpdateRedirectError, UpdateRedirectUseCase,
+};
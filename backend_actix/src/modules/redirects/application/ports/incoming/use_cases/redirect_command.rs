@@ -0,0 +1,145 @@
+//! Field rules shared by the create and update commands and by lookups.
+
+pub const MAX_SOURCE_PATH_LENGTH: usize = 500;
+pub const MAX_TARGET_URL_LENGTH: usize = 2000;
+
+/// Paths the API itself answers; a redirect there would never be reached
+const RESERVED_PREFIXES: [&str; 3] = ["/api/", "/health/", "/swagger-ui/"];
+
+#[derive(Debug, thiserror::Error)]
+pub enum RedirectCommandError {
+    #[error(
+        "Source path must start with '/', have no query string and be at most {MAX_SOURCE_PATH_LENGTH} characters; '/' and API paths can't redirect"
+    )]
+    InvalidSourcePath,
+
+    #[error(
+        "Target must be an absolute http(s) URL or a path starting with '/', at most {MAX_TARGET_URL_LENGTH} characters"
+    )]
+    InvalidTargetUrl,
+
+    #[error("A redirect can't point to its own source path")]
+    SelfRedirect,
+}
+
+/// The form a source path is stored and looked up in: trimmed, without a
+/// trailing slash. `None` if it can't be a redirect source.
+pub fn normalize_source_path(path: &str) -> Option<String> {
+    let path = path.trim();
+    let path = path.strip_suffix('/').unwrap_or(path);
+
+    let valid = path.starts_with('/')
+        && !path.starts_with("//")
+        && path.chars().count() <= MAX_SOURCE_PATH_LENGTH
+        && !path
+            .chars()
+            .any(|c| c == '?' || c == '#' || c.is_whitespace() || c.is_control())
+        && !RESERVED_PREFIXES
+            .iter()
+            .any(|prefix| path.starts_with(prefix) || path == prefix.trim_end_matches('/'));
+    valid.then(|| path.to_string())
+}
+
+pub(super) fn source_path(path: String) -> Result<String, RedirectCommandError> {
+    normalize_source_path(&path).ok_or(RedirectCommandError::InvalidSourcePath)
+}
+
+pub(super) fn target_url(url: String) -> Result<String, RedirectCommandError> {
+    let url = url.trim();
+    let lower = url.to_ascii_lowercase();
+    let has_host = |rest: &str| !rest.is_empty() && !rest.starts_with('/');
+
+    let valid = url.chars().count() <= MAX_TARGET_URL_LENGTH
+        && !url.chars().any(|c| c.is_whitespace() || c.is_control())
+        && ((url.starts_with('/') && !url.starts_with("//"))
+            || lower.strip_prefix("https://").is_some_and(has_host)
+            || lower.strip_prefix("http://").is_some_and(has_host));
+
+    if !valid {
+        return Err(RedirectCommandError::InvalidTargetUrl);
+    }
+    Ok(url.to_string())
+}
+
+/// A target on this site that is the source itself would loop forever
+pub fn ensure_no_loop(source_path: &str, target_url: &str) -> Result<(), RedirectCommandError> {
+    let target = target_url.split(['?', '#']).next().unwrap_or_default();
+    if normalize_source_path(target).as_deref() == Some(source_path) {
+        return Err(RedirectCommandError::SelfRedirect);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_paths_are_normalized() {
+        assert_eq!(
+            normalize_source_path(" /2019/05/old-post/ ").as_deref(),
+            Some("/2019/05/old-post")
+        );
+        assert_eq!(normalize_source_path("/r/cv").as_deref(), Some("/r/cv"));
+
+        for bad in [
+            "",
+            "/",
+            "old-post",
+            "//evil.example",
+            "/a?b=1",
+            "/a#top",
+            "/a b",
+            "/api/pages",
+            "/api",
+            "/health/ready",
+        ] {
+            assert_eq!(
+                normalize_source_path(bad),
+                None,
+                "{bad:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_targets_are_absolute_http_urls_or_site_paths() {
+        for ok in [
+            "https://example.com/new",
+            "HTTP://example.com",
+            "/projects?tab=all",
+        ] {
+            assert!(
+                target_url(ok.to_string()).is_ok(),
+                "{ok:?} should be accepted"
+            );
+        }
+        for bad in [
+            "",
+            "example.com",
+            "//example.com",
+            "https://",
+            "javascript:alert(1)",
+            "ftp://example.com",
+            "/a b",
+        ] {
+            assert!(
+                matches!(
+                    target_url(bad.to_string()),
+                    Err(RedirectCommandError::InvalidTargetUrl)
+                ),
+                "{bad:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_loops_to_the_source_are_rejected() {
+        assert!(matches!(
+            ensure_no_loop("/old", "/old/?utm=x"),
+            Err(RedirectCommandError::SelfRedirect)
+        ));
+        assert!(ensure_no_loop("/old", "/new").is_ok());
+        assert!(ensure_no_loop("/old", "https://example.com/old").is_ok());
+    }
+}
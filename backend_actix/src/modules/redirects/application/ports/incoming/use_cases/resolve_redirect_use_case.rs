@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+
+use crate::redirects::application::domain::entities::Redirect;
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ResolveRedirectError {
+    /// No redirect from this path, or the path can't be a redirect source
+    #[error("Redirect not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait ResolveRedirectUseCase: Send + Sync {
+    /// The redirect for a requested path, counting the hit. The path is
+    /// normalized like source paths are, so a trailing slash doesn't matter.
+    async fn execute(&self, path: &str) -> Result<Redirect, ResolveRedirectError>;
+}
+
+metered_use_case!(
+    "redirects",
+    "resolve_redirect",
+    ResolveRedirectUseCase,
+    fn execute(&self, path: &str) -> Result<Redirect, ResolveRedirectError>
+);
@@ -0,0 +1,104 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::redirect_command::{self, RedirectCommandError};
+use crate::redirects::application::domain::entities::{Redirect, RedirectStatus};
+use crate::shared::metrics::metered_use_case;
+
+//
+// ──────────────────────────────────────────────────────────
+// Update Redirect Command
+// ──────────────────────────────────────────────────────────
+//
+
+/// `None` keeps the current value. The hit counter is never reset.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateRedirectCommand {
+    source_path: Option<String>,
+    target_url: Option<String>,
+    status: Option<RedirectStatus>,
+}
+
+impl UpdateRedirectCommand {
+    pub fn new(
+        source_path: Option<String>,
+        target_url: Option<String>,
+        status: Option<RedirectStatus>,
+    ) -> Result<Self, RedirectCommandError> {
+        let source_path = source_path.map(redirect_command::source_path).transpose()?;
+        let target_url = target_url.map(redirect_command::target_url).transpose()?;
+        if let (Some(source), Some(target)) = (&source_path, &target_url) {
+            redirect_command::ensure_no_loop(source, target)?;
+        }
+
+        Ok(Self {
+            source_path,
+            target_url,
+            status,
+        })
+    }
+
+    pub fn source_path(&self) -> Option<&str> {
+        self.source_path.as_deref()
+    }
+
+    pub fn target_url(&self) -> Option<&str> {
+        self.target_url.as_deref()
+    }
+
+    pub fn status(&self) -> Option<RedirectStatus> {
+        self.status
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.source_path.is_none() && self.target_url.is_none() && self.status.is_none()
+    }
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Use Case Error
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum UpdateRedirectError {
+    #[error("Redirect not found")]
+    NotFound,
+
+    #[error("A redirect from this path already exists")]
+    SourceAlreadyExists,
+
+    /// The change would point the redirect at its own source path
+    #[error("A redirect can't point to its own source path")]
+    SelfRedirect,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Incoming Port (Use Case)
+// ──────────────────────────────────────────────────────────
+//
+
+#[async_trait]
+pub trait UpdateRedirectUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        id: Uuid,
+        command: UpdateRedirectCommand,
+    ) -> Result<Redirect, UpdateRedirectError>;
+}
+
+metered_use_case!(
+    "redirects",
+    "update_redirect",
+    UpdateRedirectUseCase,
+    fn execute(
+        &self,
+        id: Uuid,
+        command: UpdateRedirectCommand,
+    ) -> Result<Redirect, UpdateRedirectError>
+);
@@ -0,0 +1,3 @@
+pub mod redirect_repository;
+
+pub use redirect_repository::{RedirectPage, RedirectRepository, RedirectRepositoryError};
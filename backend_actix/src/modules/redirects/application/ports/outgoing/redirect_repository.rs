@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::redirects::application::domain::entities::Redirect;
+use crate::redirects::application::ports::incoming::use_cases::{
+    CreateRedirectCommand, UpdateRedirectCommand,
+};
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum RedirectRepositoryError {
+    #[error("Redirect not found")]
+    NotFound,
+
+    #[error("Source path already exists")]
+    SourceAlreadyExists,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RedirectPage {
+    pub items: Vec<Redirect>,
+    pub total: u64,
+}
+
+#[async_trait]
+pub trait RedirectRepository: Send + Sync {
+    async fn create(
+        &self,
+        command: &CreateRedirectCommand,
+    ) -> Result<Redirect, RedirectRepositoryError>;
+
+    /// By source path
+    async fn list(&self, offset: u64, limit: u32) -> Result<RedirectPage, RedirectRepositoryError>;
+
+    async fn get(&self, id: Uuid) -> Result<Redirect, RedirectRepositoryError>;
+
+    async fn update(
+        &self,
+        id: Uuid,
+        command: &UpdateRedirectCommand,
+    ) -> Result<Redirect, RedirectRepositoryError>;
+
+    async fn delete(&self, id: Uuid) -> Result<(), RedirectRepositoryError>;
+
+    /// Counts a hit on the redirect from `source_path` in one statement, so
+    /// concurrent visits are never lost. `None` if there is no such redirect.
+    async fn record_hit(
+        &self,
+        source_path: &str,
+    ) -> Result<Option<Redirect>, RedirectRepositoryError>;
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use crate::redirects::application::ports::incoming::use_cases::{
+    CreateRedirectUseCase, DeleteRedirectUseCase, ListRedirectsUseCase, ResolveRedirectUseCase,
+    UpdateRedirectUseCase,
+};
+use crate::redirects::application::ports::outgoing::RedirectRepository;
+use crate::redirects::application::services::{
+    CreateRedirectService, DeleteRedirectService, ListRedirectsService, ResolveRedirectService,
+    UpdateRedirectService,
+};
+use crate::shared::metrics::Metered;
+
+#[derive(Clone)]
+pub struct RedirectUseCases {
+    pub create: Arc<dyn CreateRedirectUseCase + Send + Sync>,
+    pub list: Arc<dyn ListRedirectsUseCase + Send + Sync>,
+    pub update: Arc<dyn UpdateRedirectUseCase + Send + Sync>,
+    pub delete: Arc<dyn DeleteRedirectUseCase + Send + Sync>,
+    pub resolve: Arc<dyn ResolveRedirectUseCase + Send + Sync>,
+}
+
+impl RedirectUseCases {
+    pub fn build<R>(repository: R) -> Self
+    where
+        R: RedirectRepository + Clone + 'static,
+    {
+        Self {
+            create: Arc::new(Metered(CreateRedirectService::new(repository.clone()))),
+            list: Arc::new(Metered(ListRedirectsService::new(repository.clone()))),
+            update: Arc::new(Metered(UpdateRedirectService::new(repository.clone()))),
+            delete: Arc::new(Metered(DeleteRedirectService::new(repository.clone()))),
+            resolve: Arc::new(Metered(ResolveRedirectService::new(repository))),
+        }
+    }
+}
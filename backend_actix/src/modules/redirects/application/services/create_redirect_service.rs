@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+
+use crate::redirects::application::domain::entities::Redirect;
+use crate::redirects::application::ports::{
+    incoming::use_cases::{CreateRedirectCommand, CreateRedirectError, CreateRedirectUseCase},
+    outgoing::{RedirectRepository, RedirectRepositoryError},
+};
+
+pub struct CreateRedirectService<R>
+where
+    R: RedirectRepository,
+{
+    repository: R,
+}
+
+impl<R> CreateRedirectService<R>
+where
+    R: RedirectRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> CreateRedirectUseCase for CreateRedirectService<R>
+where
+    R: RedirectRepository,
+{
+    async fn execute(
+        &self,
+        command: CreateRedirectCommand,
+    ) -> Result<Redirect, CreateRedirectError> {
+        self.repository.create(&command).await.map_err(|e| match e {
+            RedirectRepositoryError::SourceAlreadyExists => {
+                CreateRedirectError::SourceAlreadyExists
+            }
+            other => CreateRedirectError::RepositoryError(other.to_string()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::redirects::adapter::outgoing::InMemoryRedirectStore;
+    use crate::redirects::application::domain::entities::RedirectStatus;
+
+    fn command(source: &str) -> CreateRedirectCommand {
+        CreateRedirectCommand::new(
+            source.to_string(),
+            "/projects".to_string(),
+            RedirectStatus::Permanent,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_source_is_rejected() {
+        let service = CreateRedirectService::new(InMemoryRedirectStore::default());
+        let created = service.execute(command("/work")).await.unwrap();
+        assert_eq!(created.hits, 0);
+
+        // Same path once normalized
+        let result = service.execute(command("/work/")).await;
+
+        assert!(matches!(
+            result,
+            Err(CreateRedirectError::SourceAlreadyExists)
+        ));
+    }
+}
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::redirects::application::ports::{
+    incoming::use_cases::{DeleteRedirectError, DeleteRedirectUseCase},
+    outgoing::{RedirectRepository, RedirectRepositoryError},
+};
+
+pub struct DeleteRedirectService<R>
+where
+    R: RedirectRepository,
+{
+    repository: R,
+}
+
+impl<R> DeleteRedirectService<R>
+where
+    R: RedirectRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> DeleteRedirectUseCase for DeleteRedirectService<R>
+where
+    R: RedirectRepository,
+{
+    async fn execute(&self, id: Uuid) -> Result<(), DeleteRedirectError> {
+        self.repository.delete(id).await.map_err(|e| match e {
+            RedirectRepositoryError::NotFound => DeleteRedirectError::NotFound,
+            other => DeleteRedirectError::RepositoryError(other.to_string()),
+        })
+    }
+}
@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+
+use crate::redirects::application::ports::{
+    incoming::use_cases::{ListRedirectsError, ListRedirectsUseCase},
+    outgoing::{RedirectPage, RedirectRepository},
+};
+
+pub struct ListRedirectsService<R>
+where
+    R: RedirectRepository,
+{
+    repository: R,
+}
+
+impl<R> ListRedirectsService<R>
+where
+    R: RedirectRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> ListRedirectsUseCase for ListRedirectsService<R>
+where
+    R: RedirectRepository,
+{
+    async fn execute(&self, offset: u64, limit: u32) -> Result<RedirectPage, ListRedirectsError> {
+        self.repository
+            .list(offset, limit)
+            .await
+            .map_err(|e| ListRedirectsError::QueryFailed(e.to_string()))
+    }
+}
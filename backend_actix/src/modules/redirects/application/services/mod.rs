@@ -0,0 +1,11 @@
+mod create_redirect_service;
+mod delete_redirect_service;
+mod list_redirects_service;
+mod resolve_redirect_service;
+mod update_redirect_service;
+
+pub use create_redirect_service::CreateRedirectService;
+pub use delete_redirect_service::DeleteRedirectService;
+pub use list_redirects_service::ListRedirectsService;
+pub use resolve_redirect_service::ResolveRedirectService;
+pub use update_redirect_service::UpdateRedirectService;
@@ -0,0 +1,87 @@
+use async_trait::async_trait;
+
+use crate::redirects::application::domain::entities::Redirect;
+use crate::redirects::application::ports::{
+    incoming::use_cases::{normalize_source_path, ResolveRedirectError, ResolveRedirectUseCase},
+    outgoing::RedirectRepository,
+};
+
+pub struct ResolveRedirectService<R>
+where
+    R: RedirectRepository,
+{
+    repository: R,
+}
+
+impl<R> ResolveRedirectService<R>
+where
+    R: RedirectRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> ResolveRedirectUseCase for ResolveRedirectService<R>
+where
+    R: RedirectRepository,
+{
+    async fn execute(&self, path: &str) -> Result<Redirect, ResolveRedirectError> {
+        // Query strings are not part of the source; `/old?ref=x` follows `/old`
+        let path = path.split(['?', '#']).next().unwrap_or_default();
+        let Some(source_path) = normalize_source_path(path) else {
+            return Err(ResolveRedirectError::NotFound);
+        };
+
+        self.repository
+            .record_hit(&source_path)
+            .await
+            .map_err(|e| ResolveRedirectError::RepositoryError(e.to_string()))?
+            .ok_or(ResolveRedirectError::NotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::redirects::adapter::outgoing::InMemoryRedirectStore;
+    use crate::redirects::application::domain::entities::RedirectStatus;
+    use crate::redirects::application::ports::incoming::use_cases::CreateRedirectCommand;
+
+    #[tokio::test]
+    async fn test_each_resolve_counts_a_hit() {
+        let store = InMemoryRedirectStore::default();
+        store
+            .create(
+                &CreateRedirectCommand::new(
+                    "/r/cv".to_string(),
+                    "https://example.com/cv.pdf".to_string(),
+                    RedirectStatus::Temporary,
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let service = ResolveRedirectService::new(store);
+
+        service.execute("/r/cv").await.unwrap();
+        let redirect = service.execute("/r/cv/?utm_source=mail").await.unwrap();
+
+        assert_eq!(redirect.hits, 2);
+        assert!(redirect.last_hit_at.is_some());
+        assert_eq!(redirect.target_url, "https://example.com/cv.pdf");
+    }
+
+    #[tokio::test]
+    async fn test_unknown_and_invalid_paths_are_not_found() {
+        let service = ResolveRedirectService::new(InMemoryRedirectStore::default());
+
+        for path in ["/missing", "", "/api/pages"] {
+            assert!(matches!(
+                service.execute(path).await,
+                Err(ResolveRedirectError::NotFound)
+            ));
+        }
+    }
+}
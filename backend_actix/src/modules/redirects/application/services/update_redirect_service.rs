@@ -0,0 +1,140 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::redirects::application::domain::entities::Redirect;
+use crate::redirects::application::ports::{
+    incoming::use_cases::{
+        ensure_no_loop, UpdateRedirectCommand, UpdateRedirectError, UpdateRedirectUseCase,
+    },
+    outgoing::{RedirectRepository, RedirectRepositoryError},
+};
+
+pub struct UpdateRedirectService<R>
+where
+    R: RedirectRepository,
+{
+    repository: R,
+}
+
+impl<R> UpdateRedirectService<R>
+where
+    R: RedirectRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> UpdateRedirectUseCase for UpdateRedirectService<R>
+where
+    R: RedirectRepository,
+{
+    async fn execute(
+        &self,
+        id: Uuid,
+        command: UpdateRedirectCommand,
+    ) -> Result<Redirect, UpdateRedirectError> {
+        let map_err = |e| match e {
+            RedirectRepositoryError::NotFound => UpdateRedirectError::NotFound,
+            RedirectRepositoryError::SourceAlreadyExists => {
+                UpdateRedirectError::SourceAlreadyExists
+            }
+            other => UpdateRedirectError::RepositoryError(other.to_string()),
+        };
+
+        let current = self.repository.get(id).await.map_err(map_err)?;
+        // An empty patch must not bump `updated_at`
+        if command.is_empty() {
+            return Ok(current);
+        }
+
+        // Changing only one side can still close the loop
+        let source = command
+            .source_path()
+            .unwrap_or(current.source_path.as_str());
+        let target = command.target_url().unwrap_or(current.target_url.as_str());
+        if ensure_no_loop(source, target).is_err() {
+            return Err(UpdateRedirectError::SelfRedirect);
+        }
+
+        self.repository.update(id, &command).await.map_err(map_err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::redirects::adapter::outgoing::InMemoryRedirectStore;
+    use crate::redirects::application::domain::entities::RedirectStatus;
+    use crate::redirects::application::ports::incoming::use_cases::CreateRedirectCommand;
+
+    async fn seed(store: &InMemoryRedirectStore, source: &str, target: &str) -> Redirect {
+        store
+            .create(
+                &CreateRedirectCommand::new(
+                    source.to_string(),
+                    target.to_string(),
+                    RedirectStatus::Permanent,
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_target_pointing_back_at_current_source_is_rejected() {
+        let store = InMemoryRedirectStore::default();
+        let redirect = seed(&store, "/old", "/new").await;
+        let service = UpdateRedirectService::new(store);
+
+        let result = service
+            .execute(
+                redirect.id,
+                UpdateRedirectCommand::new(None, Some("/old/".to_string()), None).unwrap(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(UpdateRedirectError::SelfRedirect)));
+    }
+
+    #[tokio::test]
+    async fn test_status_change_keeps_other_fields() {
+        let store = InMemoryRedirectStore::default();
+        let redirect = seed(&store, "/old", "/new").await;
+        let service = UpdateRedirectService::new(store);
+
+        let updated = service
+            .execute(
+                redirect.id,
+                UpdateRedirectCommand::new(None, None, Some(RedirectStatus::Temporary)).unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(updated.status_code, RedirectStatus::Temporary);
+        assert_eq!(updated.source_path, "/old");
+        assert_eq!(updated.target_url, "/new");
+    }
+
+    #[tokio::test]
+    async fn test_moving_onto_an_existing_source_is_a_conflict() {
+        let store = InMemoryRedirectStore::default();
+        seed(&store, "/taken", "/new").await;
+        let redirect = seed(&store, "/old", "/new").await;
+        let service = UpdateRedirectService::new(store);
+
+        let result = service
+            .execute(
+                redirect.id,
+                UpdateRedirectCommand::new(Some("/taken".to_string()), None, None).unwrap(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(UpdateRedirectError::SourceAlreadyExists)
+        ));
+    }
+}
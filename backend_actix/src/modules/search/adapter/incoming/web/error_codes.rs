@@ -0,0 +1,6 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    INVALID_QUERY = (BAD_REQUEST, "Invalid search query");
+    INVALID_KIND = (BAD_REQUEST, "Unknown content kind");
+}
@@ -0,0 +1,9 @@
+use actix_web::web;
+
+pub mod error_codes;
+pub mod routes;
+
+/// Routes for signed-in users with a verified email
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::search_content_handler);
+}
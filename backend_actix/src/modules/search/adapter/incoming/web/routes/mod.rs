@@ -0,0 +1,3 @@
+mod search_content;
+
+pub use search_content::{__path_search_content_handler, search_content_handler};
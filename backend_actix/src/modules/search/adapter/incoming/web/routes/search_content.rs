@@ -0,0 +1,194 @@
+use actix_web::{get, web, Responder};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use utoipa::ToSchema;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::search::adapter::incoming::web::error_codes::{INVALID_KIND, INVALID_QUERY};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::VerifiedUser,
+    search::application::{
+        domain::entities::{ContentKind, KindCounts, SearchHit},
+        ports::incoming::use_cases::{SearchContentError, SearchQuery},
+    },
+    shared::api::{
+        pagination::{PageParams, PagedResponse},
+        ApiResponse,
+    },
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct SearchContentParams {
+    pub q: String,
+    /// Comma-separated `ContentKind`s
+    pub kinds: Option<String>,
+}
+
+/// `PagedResponse` plus the per-kind counts
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SearchContentResponse {
+    pub items: Vec<SearchHit>,
+    pub next_cursor: Option<String>,
+    pub total: u64,
+    pub facets: KindCounts,
+}
+
+fn parse_kinds(kinds: Option<&str>) -> Result<Vec<ContentKind>, String> {
+    kinds
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|kind| !kind.is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+/// Search the caller's content
+///
+/// Matches CV names, roles and bios, project and page titles, slugs and
+/// text, and media file names, case-insensitively. Drafts are included,
+/// trashed items are not. Hits come most recently updated first; `facets`
+/// counts the matches of every kind even when `kinds` narrows the list.
+#[utoipa::path(
+    get,
+    path = "/api/search",
+    tag = "search",
+    params(
+        ("q" = String, Query, description = "Text to find, 2-100 characters"),
+        ("kinds" = Option<String>, Query, description = "Comma-separated subset of `cv`, `project`, `page`, `media` (default all)"),
+        ("limit" = Option<u32>, Query, description = "Page size, 1-100 (default 20)"),
+        ("cursor" = Option<String>, Query, description = "`next_cursor` from the previous page"),
+    ),
+    responses(
+        (status = 200, description = "One page of hits", body = inline(SuccessResponse<SearchContentResponse>)),
+        (status = 400, description = "Invalid search text, kind or pagination parameters", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/search")]
+pub async fn search_content_handler(
+    user: VerifiedUser,
+    params: web::Query<SearchContentParams>,
+    page: PageParams,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let params = params.into_inner();
+    let kinds = match parse_kinds(params.kinds.as_deref()) {
+        Ok(kinds) => kinds,
+        Err(msg) => return INVALID_KIND.with_message(&msg),
+    };
+    let query = match SearchQuery::new(params.q, kinds) {
+        Ok(query) => query,
+        Err(err) => return INVALID_QUERY.with_message(&err.to_string()),
+    };
+
+    match data
+        .search_content_use_case
+        .execute(user.user_id, query, page.offset, page.limit)
+        .await
+    {
+        Ok(results) => {
+            let paged = PagedResponse::new(results.items, results.total, &page);
+            ApiResponse::success(SearchContentResponse {
+                items: paged.items,
+                next_cursor: paged.next_cursor,
+                total: paged.total,
+                facets: results.facets,
+            })
+        }
+        Err(SearchContentError::QueryFailed(msg)) => {
+            error!(user = %user.user_id, "Failed to search content: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubSearchContentUseCase,
+        },
+    };
+
+    async fn call(state: web::Data<AppState>, uri: &str) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(search_content_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_hits_come_with_facets() {
+        let state = TestAppStateBuilder::default()
+            .with_search_content(StubSearchContentUseCase::one_project())
+            .build();
+
+        let resp = call(state, "/api/search?q=rust&kinds=project,page").await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["total"], 1);
+        assert_eq!(json["data"]["items"][0]["kind"], "project");
+        assert_eq!(json["data"]["facets"]["project"], 1);
+        assert_eq!(json["data"]["facets"]["media"], 2);
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_kind_is_rejected() {
+        let resp = call(
+            TestAppStateBuilder::default().build(),
+            "/api/search?q=rust&kinds=post",
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "INVALID_KIND");
+    }
+
+    #[actix_web::test]
+    async fn test_short_text_is_rejected() {
+        let resp = call(TestAppStateBuilder::default().build(), "/api/search?q=r").await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "INVALID_QUERY");
+    }
+
+    #[actix_web::test]
+    async fn test_kinds_are_parsed_leniently() {
+        assert_eq!(
+            parse_kinds(Some(" cv, ,media ")).unwrap(),
+            vec![ContentKind::Cv, ContentKind::Media]
+        );
+        assert!(parse_kinds(None).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,315 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::search::application::domain::entities::{ContentKind, KindCounts, SearchHit};
+use crate::search::application::ports::incoming::use_cases::SearchQuery;
+use crate::search::application::ports::outgoing::{ContentSearchError, ContentSearchQuery};
+use crate::shared::sql;
+
+#[derive(Clone)]
+pub struct ContentSearchQueryPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl ContentSearchQueryPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    /// Live matches of one kind as `(kind, id, title, subtitle, updated_at)`.
+    /// `$1` is the owner, `$2` the [`Self::pattern`].
+    fn kind_select(backend: DatabaseBackend, kind: ContentKind) -> String {
+        let ilike = sql::ilike(backend);
+        match kind {
+            ContentKind::Cv => format!(
+                r#"
+                SELECT 'cv' AS kind, id, display_name AS title, role AS subtitle, updated_at
+                FROM resumes
+                WHERE user_id = $1 AND is_deleted = false
+                  AND (display_name {ilike} $2 ESCAPE '\'
+                       OR role {ilike} $2 ESCAPE '\'
+                       OR bio {ilike} $2 ESCAPE '\')
+                "#
+            ),
+            ContentKind::Project => format!(
+                r#"
+                SELECT 'project' AS kind, id, title, slug AS subtitle, updated_at
+                FROM projects
+                WHERE user_id = $1 AND is_deleted = false
+                  AND (title {ilike} $2 ESCAPE '\'
+                       OR slug {ilike} $2 ESCAPE '\'
+                       OR description {ilike} $2 ESCAPE '\')
+                "#
+            ),
+            ContentKind::Page => format!(
+                r#"
+                SELECT 'page' AS kind, id, title, slug AS subtitle, updated_at
+                FROM pages
+                WHERE user_id = $1
+                  AND (title {ilike} $2 ESCAPE '\'
+                       OR slug {ilike} $2 ESCAPE '\'
+                       OR body {ilike} $2 ESCAPE '\')
+                "#
+            ),
+            ContentKind::Media => format!(
+                r#"
+                SELECT 'media' AS kind, id, original_filename AS title,
+                       CAST(NULL AS TEXT) AS subtitle, updated_at
+                FROM media
+                WHERE user_id = $1 AND deleted_at IS NULL
+                  AND original_filename {ilike} $2 ESCAPE '\'
+                "#
+            ),
+        }
+    }
+
+    fn union(backend: DatabaseBackend, kinds: &[ContentKind]) -> String {
+        kinds
+            .iter()
+            .map(|kind| Self::kind_select(backend, *kind))
+            .collect::<Vec<_>>()
+            .join("UNION ALL")
+    }
+
+    /// `%text%`, with the text's own `%`, `_` and `\` matched literally
+    fn pattern(query: &SearchQuery) -> String {
+        format!("%{}%", sql::escape_like(query.text()))
+    }
+
+    fn count_stmt(backend: DatabaseBackend, owner_id: Uuid, query: &SearchQuery) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                SELECT kind, COUNT(*) AS total
+                FROM ({hits}) hits
+                GROUP BY kind
+                "#,
+                hits = Self::union(backend, &ContentKind::ALL),
+            ),
+            vec![owner_id.into(), Self::pattern(query).into()],
+        )
+    }
+
+    fn search_stmt(
+        backend: DatabaseBackend,
+        owner_id: Uuid,
+        query: &SearchQuery,
+        offset: u64,
+        limit: u32,
+    ) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                SELECT kind, id, title, subtitle, updated_at
+                FROM ({hits}) hits
+                ORDER BY updated_at DESC, id
+                LIMIT $4
+                OFFSET $3
+                "#,
+                hits = Self::union(backend, query.kinds()),
+            ),
+            vec![
+                owner_id.into(),
+                Self::pattern(query).into(),
+                (offset as i64).into(),
+                (limit as i64).into(),
+            ],
+        )
+    }
+
+    // =====================================================
+    // Mapping
+    // =====================================================
+
+    fn to_kind(row: &QueryResult) -> Result<ContentKind, ContentSearchError> {
+        let kind: String = row.try_get("", "kind").map_err(Self::map_db_err)?;
+        kind.parse().map_err(ContentSearchError::DatabaseError)
+    }
+
+    fn to_hit(row: &QueryResult) -> Result<SearchHit, ContentSearchError> {
+        Ok(SearchHit {
+            kind: Self::to_kind(row)?,
+            id: row.try_get("", "id").map_err(Self::map_db_err)?,
+            title: row.try_get("", "title").map_err(Self::map_db_err)?,
+            subtitle: row.try_get("", "subtitle").map_err(Self::map_db_err)?,
+            updated_at: row.try_get("", "updated_at").map_err(Self::map_db_err)?,
+        })
+    }
+
+    fn map_db_err(e: DbErr) -> ContentSearchError {
+        ContentSearchError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl ContentSearchQuery for ContentSearchQueryPostgres {
+    async fn count_by_kind(
+        &self,
+        owner_id: Uuid,
+        query: &SearchQuery,
+    ) -> Result<KindCounts, ContentSearchError> {
+        let rows = self
+            .db
+            .query_all(Self::count_stmt(
+                self.db.get_database_backend(),
+                owner_id,
+                query,
+            ))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        let mut counts = KindCounts::default();
+        for row in &rows {
+            let total: i64 = row.try_get("", "total").map_err(Self::map_db_err)?;
+            counts.add(Self::to_kind(row)?, total.max(0) as u64);
+        }
+        Ok(counts)
+    }
+
+    async fn search(
+        &self,
+        owner_id: Uuid,
+        query: &SearchQuery,
+        offset: u64,
+        limit: u32,
+    ) -> Result<Vec<SearchHit>, ContentSearchError> {
+        self.db
+            .query_all(Self::search_stmt(
+                self.db.get_database_backend(),
+                owner_id,
+                query,
+                offset,
+                limit,
+            ))
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(Self::to_hit)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use sea_orm::{MockDatabase, Value};
+    use std::collections::BTreeMap;
+
+    fn query(kinds: Vec<ContentKind>) -> SearchQuery {
+        SearchQuery::new("rust".to_string(), kinds).unwrap()
+    }
+
+    #[test]
+    fn test_search_unions_only_the_requested_kinds() {
+        let stmt = ContentSearchQueryPostgres::search_stmt(
+            DatabaseBackend::Postgres,
+            Uuid::new_v4(),
+            &query(vec![ContentKind::Page, ContentKind::Media]),
+            0,
+            20,
+        );
+
+        assert!(stmt.sql.contains("FROM pages"));
+        assert!(stmt.sql.contains("FROM media"));
+        assert!(!stmt.sql.contains("FROM resumes"));
+        assert!(!stmt.sql.contains("FROM projects"));
+        assert_eq!(stmt.sql.matches("UNION ALL").count(), 1);
+        assert_eq!(stmt.values.unwrap().0[1], Value::from("%rust%"));
+    }
+
+    #[test]
+    fn test_facets_cover_every_kind() {
+        let stmt = ContentSearchQueryPostgres::count_stmt(
+            DatabaseBackend::Postgres,
+            Uuid::new_v4(),
+            &query(vec![ContentKind::Cv]),
+        );
+
+        assert_eq!(stmt.sql.matches("UNION ALL").count(), 3);
+        assert!(stmt.sql.contains("GROUP BY kind"));
+    }
+
+    #[tokio::test]
+    async fn test_counts_are_read_per_kind() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![
+                BTreeMap::from([
+                    ("kind".to_string(), Value::from("project")),
+                    ("total".to_string(), Value::from(3i64)),
+                ]),
+                BTreeMap::from([
+                    ("kind".to_string(), Value::from("media")),
+                    ("total".to_string(), Value::from(2i64)),
+                ]),
+            ]])
+            .into_connection();
+
+        let repo = ContentSearchQueryPostgres::new(Arc::new(db));
+        let counts = repo
+            .count_by_kind(Uuid::new_v4(), &query(vec![]))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            counts,
+            KindCounts {
+                cv: 0,
+                project: 3,
+                page: 0,
+                media: 2,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hits_are_mapped() {
+        let id = Uuid::new_v4();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![BTreeMap::from([
+                ("kind".to_string(), Value::from("media")),
+                ("id".to_string(), Value::from(id)),
+                ("title".to_string(), Value::from("rust-logo.png")),
+                ("subtitle".to_string(), Value::String(None)),
+                ("updated_at".to_string(), Value::from(Utc::now())),
+            ])]])
+            .into_connection();
+
+        let repo = ContentSearchQueryPostgres::new(Arc::new(db));
+        let hits = repo
+            .search(Uuid::new_v4(), &query(vec![]), 0, 20)
+            .await
+            .unwrap();
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].kind, ContentKind::Media);
+        assert_eq!(hits[0].id, id);
+        assert_eq!(hits[0].subtitle, None);
+    }
+
+    #[test]
+    fn test_wildcards_in_the_text_are_matched_literally() {
+        let query = SearchQuery::new("100%_off\\".to_string(), vec![]).unwrap();
+        let stmt = ContentSearchQueryPostgres::search_stmt(
+            DatabaseBackend::Postgres,
+            Uuid::new_v4(),
+            &query,
+            0,
+            20,
+        );
+
+        assert_eq!(stmt.values.unwrap().0[1], Value::from("%100\\%\\_off\\\\%"));
+        assert_eq!(stmt.sql.matches("ILIKE $2 ESCAPE '\\'").count(), 10);
+        assert_eq!(stmt.sql.matches("ILIKE").count(), 10);
+    }
+}
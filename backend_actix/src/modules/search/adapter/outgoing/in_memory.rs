@@ -0,0 +1,158 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::cv::adapter::outgoing::InMemoryCvStore;
+use crate::multimedia::adapter::outgoing::db::InMemoryMediaStore;
+use crate::pages::adapter::outgoing::InMemoryPageStore;
+use crate::project::adapter::outgoing::InMemoryProjectStore;
+use crate::search::application::domain::entities::{ContentKind, KindCounts, SearchHit};
+use crate::search::application::ports::incoming::use_cases::SearchQuery;
+use crate::search::application::ports::outgoing::{ContentSearchError, ContentSearchQuery};
+
+/// `ContentSearchQuery` over the other modules' in-memory stores
+#[derive(Clone)]
+pub struct InMemoryContentSearch {
+    cvs: InMemoryCvStore,
+    projects: InMemoryProjectStore,
+    pages: InMemoryPageStore,
+    media: InMemoryMediaStore,
+}
+
+impl InMemoryContentSearch {
+    pub fn new(
+        cvs: InMemoryCvStore,
+        projects: InMemoryProjectStore,
+        pages: InMemoryPageStore,
+        media: InMemoryMediaStore,
+    ) -> Self {
+        Self {
+            cvs,
+            projects,
+            pages,
+            media,
+        }
+    }
+
+    /// Every live match of `kind`, matched on the columns the Postgres
+    /// adapter searches
+    fn matches(&self, owner_id: Uuid, kind: ContentKind, text: &str) -> Vec<SearchHit> {
+        let term = text.to_lowercase();
+        let contains = |fields: &[&str]| {
+            fields
+                .iter()
+                .any(|field| field.to_lowercase().contains(&term))
+        };
+        let owner = UserId::from(owner_id);
+
+        match kind {
+            ContentKind::Cv => self.cvs.cvs.read(|rows| {
+                rows.iter()
+                    .filter(|row| row.deleted_at.is_none() && row.cv.user_id == owner_id)
+                    .filter(|row| {
+                        contains(&[
+                            row.cv.display_name.as_str(),
+                            row.cv.role.as_str(),
+                            row.cv.bio.as_str(),
+                        ])
+                    })
+                    .map(|row| SearchHit {
+                        kind,
+                        id: row.cv.id,
+                        title: row.cv.display_name.clone(),
+                        subtitle: Some(row.cv.role.clone()),
+                        updated_at: row.cv.updated_at,
+                    })
+                    .collect()
+            }),
+            ContentKind::Project => self.projects.projects.read(|rows| {
+                rows.iter()
+                    .map(|row| (&row.project, row.deleted_at))
+                    .filter(|(project, deleted_at)| deleted_at.is_none() && project.owner == owner)
+                    .filter(|(project, _)| {
+                        contains(&[
+                            project.title.as_str(),
+                            project.slug.as_str(),
+                            project.description.as_str(),
+                        ])
+                    })
+                    .map(|(project, _)| SearchHit {
+                        kind,
+                        id: project.id,
+                        title: project.title.clone(),
+                        subtitle: Some(project.slug.clone()),
+                        updated_at: project.updated_at,
+                    })
+                    .collect()
+            }),
+            ContentKind::Page => self.pages.pages.read(|pages| {
+                pages
+                    .iter()
+                    .filter(|page| page.owner_id == owner_id)
+                    .filter(|page| {
+                        contains(&[page.title.as_str(), page.slug.as_str(), page.body.as_str()])
+                    })
+                    .map(|page| SearchHit {
+                        kind,
+                        id: page.id,
+                        title: page.title.clone(),
+                        subtitle: Some(page.slug.clone()),
+                        updated_at: page.updated_at,
+                    })
+                    .collect()
+            }),
+            ContentKind::Media => self.media.media.read(|rows| {
+                rows.iter()
+                    .filter(|row| row.deleted_at.is_none() && row.owner == owner)
+                    .filter(|row| contains(&[row.original_name.as_str()]))
+                    .map(|row| SearchHit {
+                        kind,
+                        id: row.media_id,
+                        title: row.original_name.clone(),
+                        subtitle: None,
+                        updated_at: row.updated_at,
+                    })
+                    .collect()
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl ContentSearchQuery for InMemoryContentSearch {
+    async fn count_by_kind(
+        &self,
+        owner_id: Uuid,
+        query: &SearchQuery,
+    ) -> Result<KindCounts, ContentSearchError> {
+        let mut counts = KindCounts::default();
+        for kind in ContentKind::ALL {
+            counts.add(
+                kind,
+                self.matches(owner_id, kind, query.text()).len() as u64,
+            );
+        }
+        Ok(counts)
+    }
+
+    async fn search(
+        &self,
+        owner_id: Uuid,
+        query: &SearchQuery,
+        offset: u64,
+        limit: u32,
+    ) -> Result<Vec<SearchHit>, ContentSearchError> {
+        let mut hits: Vec<SearchHit> = query
+            .kinds()
+            .iter()
+            .flat_map(|kind| self.matches(owner_id, *kind, query.text()))
+            .collect();
+        hits.sort_by(|a, b| b.updated_at.cmp(&a.updated_at).then(a.id.cmp(&b.id)));
+
+        Ok(hits
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect())
+    }
+}
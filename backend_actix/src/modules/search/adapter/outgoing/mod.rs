@@ -0,0 +1,5 @@
+mod content_search_query_postgres;
+mod in_memory;
+
+pub use content_search_query_postgres::ContentSearchQueryPostgres;
+pub use in_memory::InMemoryContentSearch;
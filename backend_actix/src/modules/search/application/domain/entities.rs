@@ -0,0 +1,86 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// What a search hit is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentKind {
+    Cv,
+    Project,
+    Page,
+    /// Matched on the uploaded file name
+    Media,
+}
+
+impl ContentKind {
+    pub const ALL: [ContentKind; 4] = [
+        ContentKind::Cv,
+        ContentKind::Project,
+        ContentKind::Page,
+        ContentKind::Media,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ContentKind::Cv => "cv",
+            ContentKind::Project => "project",
+            ContentKind::Page => "page",
+            ContentKind::Media => "media",
+        }
+    }
+}
+
+impl FromStr for ContentKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ContentKind::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == s)
+            .ok_or_else(|| format!("unknown content kind '{s}'"))
+    }
+}
+
+/// One matching item, enough for the admin UI to link to its editor
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct SearchHit {
+    pub kind: ContentKind,
+    pub id: Uuid,
+    /// CV display name, project or page title, or media file name
+    pub title: String,
+    /// CV role, or the slug of a project or page
+    pub subtitle: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Matches per kind, whatever kinds the search was narrowed to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct KindCounts {
+    pub cv: u64,
+    pub project: u64,
+    pub page: u64,
+    pub media: u64,
+}
+
+impl KindCounts {
+    pub fn get(&self, kind: ContentKind) -> u64 {
+        match kind {
+            ContentKind::Cv => self.cv,
+            ContentKind::Project => self.project,
+            ContentKind::Page => self.page,
+            ContentKind::Media => self.media,
+        }
+    }
+
+    pub fn add(&mut self, kind: ContentKind, count: u64) {
+        match kind {
+            ContentKind::Cv => self.cv += count,
+            ContentKind::Project => self.project += count,
+            ContentKind::Page => self.page += count,
+            ContentKind::Media => self.media += count,
+        }
+    }
+}
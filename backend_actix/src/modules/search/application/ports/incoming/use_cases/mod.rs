@@ -0,0 +1,6 @@
+mod search_content_use_case;
+
+pub use search_content_use_case::{
+    SearchContentError, SearchContentUseCase, SearchQuery, SearchQueryError, SearchResults,
+    MAX_QUERY_LENGTH, MIN_QUERY_LENGTH,
+};
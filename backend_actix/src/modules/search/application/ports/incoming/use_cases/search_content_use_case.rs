@@ -0,0 +1,138 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::search::application::domain::entities::{ContentKind, KindCounts, SearchHit};
+use crate::shared::metrics::metered_use_case;
+
+pub const MIN_QUERY_LENGTH: usize = 2;
+pub const MAX_QUERY_LENGTH: usize = 100;
+
+//
+// ──────────────────────────────────────────────────────────
+// Search Query
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone)]
+pub struct SearchQuery {
+    text: String,
+    kinds: Vec<ContentKind>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SearchQueryError {
+    #[error("Search text must be {MIN_QUERY_LENGTH} to {MAX_QUERY_LENGTH} characters")]
+    InvalidLength,
+}
+
+impl SearchQuery {
+    /// No `kinds` searches every kind
+    pub fn new(text: String, kinds: Vec<ContentKind>) -> Result<Self, SearchQueryError> {
+        let text = text.trim();
+        if !(MIN_QUERY_LENGTH..=MAX_QUERY_LENGTH).contains(&text.chars().count()) {
+            return Err(SearchQueryError::InvalidLength);
+        }
+
+        let kinds = if kinds.is_empty() {
+            ContentKind::ALL.to_vec()
+        } else {
+            ContentKind::ALL
+                .into_iter()
+                .filter(|kind| kinds.contains(kind))
+                .collect()
+        };
+
+        Ok(Self {
+            text: text.to_string(),
+            kinds,
+        })
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Without duplicates, in `ContentKind::ALL` order
+    pub fn kinds(&self) -> &[ContentKind] {
+        &self.kinds
+    }
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Use Case
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone)]
+pub struct SearchResults {
+    /// Most recently updated first
+    pub items: Vec<SearchHit>,
+    /// Matches of the kinds searched
+    pub total: u64,
+    /// Matches of every kind, so the UI can show what narrowing would leave
+    pub facets: KindCounts,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SearchContentError {
+    #[error("Search failed: {0}")]
+    QueryFailed(String),
+}
+
+#[async_trait]
+pub trait SearchContentUseCase: Send + Sync {
+    /// The owner's content, drafts and unpublished items included; trashed
+    /// items are left out
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        query: SearchQuery,
+        offset: u64,
+        limit: u32,
+    ) -> Result<SearchResults, SearchContentError>;
+}
+
+metered_use_case!(
+    "search",
+    "search_content",
+    SearchContentUseCase,
+    fn execute(
+        &self,
+        owner_id: Uuid,
+        query: SearchQuery,
+        offset: u64,
+        limit: u32,
+    ) -> Result<SearchResults, SearchContentError>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_is_trimmed_and_length_checked() {
+        let query = SearchQuery::new("  rust ".to_string(), vec![]).unwrap();
+        assert_eq!(query.text(), "rust");
+
+        for text in ["", " a ", &"x".repeat(MAX_QUERY_LENGTH + 1)] {
+            assert!(matches!(
+                SearchQuery::new(text.to_string(), vec![]),
+                Err(SearchQueryError::InvalidLength)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_kinds_default_to_all_and_are_deduplicated() {
+        let all = SearchQuery::new("rust".to_string(), vec![]).unwrap();
+        assert_eq!(all.kinds(), ContentKind::ALL);
+
+        let some = SearchQuery::new(
+            "rust".to_string(),
+            vec![ContentKind::Media, ContentKind::Cv, ContentKind::Media],
+        )
+        .unwrap();
+        assert_eq!(some.kinds(), [ContentKind::Cv, ContentKind::Media]);
+    }
+}
@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::search::application::domain::entities::{KindCounts, SearchHit};
+use crate::search::application::ports::incoming::use_cases::SearchQuery;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ContentSearchError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Case-insensitive substring search over the other modules' tables, the
+/// same match the CV and project list filters use
+#[async_trait]
+pub trait ContentSearchQuery: Send + Sync {
+    /// Matches of every kind, ignoring `query.kinds()`
+    async fn count_by_kind(
+        &self,
+        owner_id: Uuid,
+        query: &SearchQuery,
+    ) -> Result<KindCounts, ContentSearchError>;
+
+    /// Matches of `query.kinds()`, most recently updated first
+    async fn search(
+        &self,
+        owner_id: Uuid,
+        query: &SearchQuery,
+        offset: u64,
+        limit: u32,
+    ) -> Result<Vec<SearchHit>, ContentSearchError>;
+}
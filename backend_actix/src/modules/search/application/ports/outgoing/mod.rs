@@ -0,0 +1,3 @@
+mod content_search_query;
+
+pub use content_search_query::{ContentSearchError, ContentSearchQuery};
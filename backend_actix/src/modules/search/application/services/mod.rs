@@ -0,0 +1,3 @@
+mod search_content_service;
+
+pub use search_content_service::SearchContentService;
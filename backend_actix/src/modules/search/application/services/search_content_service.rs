@@ -0,0 +1,145 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::search::application::ports::{
+    incoming::use_cases::{SearchContentError, SearchContentUseCase, SearchQuery, SearchResults},
+    outgoing::{ContentSearchError, ContentSearchQuery},
+};
+
+pub struct SearchContentService<Q>
+where
+    Q: ContentSearchQuery,
+{
+    query: Q,
+}
+
+impl<Q> SearchContentService<Q>
+where
+    Q: ContentSearchQuery,
+{
+    pub fn new(query: Q) -> Self {
+        Self { query }
+    }
+}
+
+impl From<ContentSearchError> for SearchContentError {
+    fn from(err: ContentSearchError) -> Self {
+        SearchContentError::QueryFailed(err.to_string())
+    }
+}
+
+#[async_trait]
+impl<Q> SearchContentUseCase for SearchContentService<Q>
+where
+    Q: ContentSearchQuery,
+{
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        query: SearchQuery,
+        offset: u64,
+        limit: u32,
+    ) -> Result<SearchResults, SearchContentError> {
+        let facets = self.query.count_by_kind(owner_id, &query).await?;
+        let total = query.kinds().iter().map(|kind| facets.get(*kind)).sum();
+
+        // The counts already tell when the page would be empty
+        let items = if offset < total {
+            self.query.search(owner_id, &query, offset, limit).await?
+        } else {
+            Vec::new()
+        };
+
+        Ok(SearchResults {
+            items,
+            total,
+            facets,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    use crate::auth::application::domain::entities::UserId;
+    use crate::pages::adapter::outgoing::InMemoryPageStore;
+    use crate::pages::application::domain::entities::PageStatus;
+    use crate::pages::application::ports::incoming::use_cases::CreatePageCommand;
+    use crate::pages::application::ports::outgoing::PageRepository;
+    use crate::project::adapter::outgoing::InMemoryProjectStore;
+    use crate::project::application::ports::outgoing::project_repository::{
+        CreateProjectData, ProjectRepository,
+    };
+    use crate::search::adapter::outgoing::InMemoryContentSearch;
+    use crate::search::application::domain::entities::ContentKind;
+
+    async fn seed(owner: Uuid) -> InMemoryContentSearch {
+        let pages = InMemoryPageStore::default();
+        pages
+            .create(
+                owner,
+                &CreatePageCommand::new(
+                    "uses".to_string(),
+                    "Uses".to_string(),
+                    "My Rust toolchain".to_string(),
+                    PageStatus::Draft,
+                    None,
+                    None,
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let projects = InMemoryProjectStore::default();
+        projects
+            .create_project(CreateProjectData {
+                owner: UserId::from(owner),
+                title: "Rust CMS".to_string(),
+                slug: "rust-cms".to_string(),
+                description: String::new(),
+                tech_stack: vec![],
+                screenshots: vec![],
+                repo_url: None,
+                live_demo_url: None,
+                seo_title: None,
+                seo_description: None,
+                canonical_url: None,
+                noindex: false,
+            })
+            .await
+            .unwrap();
+
+        InMemoryContentSearch::new(Default::default(), projects, pages, Default::default())
+    }
+
+    #[tokio::test]
+    async fn test_facets_count_every_kind_while_items_are_narrowed() {
+        let owner = Uuid::new_v4();
+        let service = SearchContentService::new(seed(owner).await);
+        let query = SearchQuery::new("rust".to_string(), vec![ContentKind::Page]).unwrap();
+
+        let results = service.execute(owner, query, 0, 20).await.unwrap();
+
+        assert_eq!(results.total, 1);
+        assert_eq!(results.items.len(), 1);
+        assert_eq!(results.items[0].kind, ContentKind::Page);
+        assert_eq!(results.items[0].subtitle.as_deref(), Some("uses"));
+        assert_eq!(results.facets.page, 1);
+        assert_eq!(results.facets.project, 1);
+        assert!(results.items[0].updated_at <= Utc::now());
+    }
+
+    #[tokio::test]
+    async fn test_other_owners_content_is_not_found() {
+        let service = SearchContentService::new(seed(Uuid::new_v4()).await);
+        let query = SearchQuery::new("rust".to_string(), vec![]).unwrap();
+
+        let results = service.execute(Uuid::new_v4(), query, 0, 20).await.unwrap();
+
+        assert_eq!(results.total, 0);
+        assert!(results.items.is_empty());
+    }
+}
@@ -0,0 +1,9 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    TAGLINE_TOO_LONG = (BAD_REQUEST, "Tagline is too long");
+    INVALID_SOCIAL_LINKS = (BAD_REQUEST, "Invalid social links");
+    INVALID_ANALYTICS_ID = (BAD_REQUEST, "Invalid analytics id");
+    INVALID_THEME = (BAD_REQUEST, "Invalid theme");
+    THEME_VERSION_MISMATCH = (CONFLICT, "The theme was changed since it was read");
+}
@@ -0,0 +1,17 @@
+use actix_web::web;
+
+pub mod error_codes;
+pub mod routes;
+
+/// Routes anyone can call
+pub fn configure_public(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::get_site_profile_handler)
+        .service(routes::get_theme_handler)
+        .service(routes::get_theme_schema_handler);
+}
+
+/// Routes for signed-in users with a verified email
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::update_site_settings_handler)
+        .service(routes::update_theme_handler);
+}
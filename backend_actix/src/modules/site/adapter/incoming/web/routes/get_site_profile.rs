@@ -0,0 +1,78 @@
+use actix_web::{get, web, Responder};
+use tracing::error;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    shared::api::ApiResponse,
+    site::application::{
+        domain::settings::SiteProfile, ports::incoming::use_cases::GetSiteProfileError,
+    },
+    AppState,
+};
+
+/// Site title, tagline, social links, analytics id and theme
+///
+/// Settings the owners never set come back with their defaults.
+#[utoipa::path(
+    get,
+    path = "/api/public/site",
+    tag = "site",
+    responses(
+        (status = 200, description = "Site profile", body = inline(SuccessResponse<SiteProfile>)),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    )
+)]
+#[get("/api/public/site")]
+pub async fn get_site_profile_handler(data: web::Data<AppState>) -> impl Responder {
+    match data.site.get.execute().await {
+        Ok(profile) => ApiResponse::success(profile),
+        Err(GetSiteProfileError::RepositoryError(msg)) => {
+            error!("Failed to load site settings: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, stubs::StubGetSiteProfileUseCase,
+    };
+
+    async fn call(state: web::Data<AppState>) -> actix_web::dev::ServiceResponse {
+        let app =
+            test::init_service(App::new().app_data(state).service(get_site_profile_handler)).await;
+        let req = test::TestRequest::get()
+            .uri("/api/public/site")
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_returns_profile_without_auth() {
+        let state = TestAppStateBuilder::default().build();
+
+        let resp = call(state).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["title"], "Ekstion");
+        assert_eq!(json["data"]["theme"], "system");
+        assert_eq!(json["data"]["social_links"], serde_json::json!([]));
+    }
+
+    #[actix_web::test]
+    async fn test_store_failure_returns_internal_error() {
+        let state = TestAppStateBuilder::default()
+            .with_get_site_profile(StubGetSiteProfileUseCase::failure("db down"))
+            .build();
+
+        let resp = call(state).await;
+
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,74 @@
+use actix_web::{get, web, Responder};
+use tracing::error;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    shared::api::ApiResponse,
+    site::application::{domain::theme::ThemeSettings, ports::incoming::use_cases::GetThemeError},
+    AppState,
+};
+
+/// Theme configuration for the frontend
+///
+/// `version` is the theme schema version the config was saved under; a
+/// frontend built for another version should fall back to its defaults.
+#[utoipa::path(
+    get,
+    path = "/api/site/theme",
+    tag = "site",
+    responses(
+        (status = 200, description = "Theme settings", body = inline(SuccessResponse<ThemeSettings>)),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    )
+)]
+#[get("/api/site/theme")]
+pub async fn get_theme_handler(data: web::Data<AppState>) -> impl Responder {
+    match data.site.get_theme.execute().await {
+        Ok(theme) => ApiResponse::success(theme),
+        Err(GetThemeError::RepositoryError(msg)) => {
+            error!("Failed to load theme: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+
+    use crate::site::application::domain::theme::THEME_SCHEMA_VERSION;
+    use crate::tests::support::{
+        app_state_builder::TestAppStateBuilder, stubs::StubGetThemeUseCase,
+    };
+
+    async fn call(state: web::Data<AppState>) -> actix_web::dev::ServiceResponse {
+        let app = test::init_service(App::new().app_data(state).service(get_theme_handler)).await;
+        let req = test::TestRequest::get().uri("/api/site/theme").to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_returns_theme_without_auth() {
+        let state = TestAppStateBuilder::default().build();
+
+        let resp = call(state).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["version"], THEME_SCHEMA_VERSION);
+        assert_eq!(json["data"]["config"], serde_json::json!({}));
+    }
+
+    #[actix_web::test]
+    async fn test_store_failure_returns_internal_error() {
+        let state = TestAppStateBuilder::default()
+            .with_get_theme(StubGetThemeUseCase::failure("db down"))
+            .build();
+
+        let resp = call(state).await;
+
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
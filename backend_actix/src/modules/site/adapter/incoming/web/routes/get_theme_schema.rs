@@ -0,0 +1,41 @@
+use actix_web::{get, Responder};
+
+use crate::api::schemas::SuccessResponse;
+use crate::{shared::api::ApiResponse, site::application::domain::theme::ThemeSchema};
+
+/// The JSON schema theme configs are checked against, with its version
+#[utoipa::path(
+    get,
+    path = "/api/site/theme/schema",
+    tag = "site",
+    responses(
+        (status = 200, description = "Theme schema", body = inline(SuccessResponse<ThemeSchema>)),
+    )
+)]
+#[get("/api/site/theme/schema")]
+pub async fn get_theme_schema_handler() -> impl Responder {
+    ApiResponse::success(ThemeSchema::current())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+
+    use crate::site::application::domain::theme::THEME_SCHEMA_VERSION;
+
+    #[actix_web::test]
+    async fn test_schema_is_served_with_its_version() {
+        let app = test::init_service(App::new().service(get_theme_schema_handler)).await;
+        let req = test::TestRequest::get()
+            .uri("/api/site/theme/schema")
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["version"], THEME_SCHEMA_VERSION);
+        assert_eq!(json["data"]["schema"]["type"], "object");
+    }
+}
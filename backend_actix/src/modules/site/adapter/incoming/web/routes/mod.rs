@@ -0,0 +1,11 @@
+mod get_site_profile;
+mod get_theme;
+mod get_theme_schema;
+mod update_site_settings;
+mod update_theme;
+
+pub use get_site_profile::{__path_get_site_profile_handler, get_site_profile_handler};
+pub use get_theme::{__path_get_theme_handler, get_theme_handler};
+pub use get_theme_schema::{__path_get_theme_schema_handler, get_theme_schema_handler};
+pub use update_site_settings::{__path_update_site_settings_handler, update_site_settings_handler};
+pub use update_theme::{__path_update_theme_handler, update_theme_handler};
@@ -0,0 +1,216 @@
+use actix_web::{patch, web, HttpResponse, Responder};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use utoipa::ToSchema;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::shared::api::error_codes::TITLE_TOO_LONG;
+use crate::site::adapter::incoming::web::error_codes::{
+    INVALID_ANALYTICS_ID, INVALID_SOCIAL_LINKS, TAGLINE_TOO_LONG,
+};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    project::application::ports::outgoing::project_repository::PatchField,
+    shared::api::ApiResponse,
+    site::application::{
+        domain::settings::{SiteProfile, SocialLink, Theme},
+        ports::incoming::use_cases::{
+            UpdateSiteSettingsCommand, UpdateSiteSettingsCommandError, UpdateSiteSettingsError,
+        },
+    },
+    AppState,
+};
+
+/// Omitted fields keep their value; `null` restores the default
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct UpdateSiteSettingsRequest {
+    /// Up to 100 characters
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub title: PatchField<String>,
+
+    /// Up to 200 characters
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub tagline: PatchField<String>,
+
+    /// Up to 20 links with absolute http(s) URLs; replaces the whole list
+    #[serde(default)]
+    #[schema(value_type = Option<Vec<SocialLink>>)]
+    pub social_links: PatchField<Vec<SocialLink>>,
+
+    /// Letters, digits, '-' and '_'
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub analytics_id: PatchField<String>,
+
+    #[serde(default)]
+    #[schema(value_type = Option<Theme>)]
+    pub theme: PatchField<Theme>,
+}
+
+/// Change site settings
+#[utoipa::path(
+    patch,
+    path = "/api/admin/site",
+    tag = "site",
+    request_body = UpdateSiteSettingsRequest,
+    responses(
+        (status = 200, description = "Settings saved", body = inline(SuccessResponse<SiteProfile>)),
+        (status = 400, description = "Invalid field or malformed request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[patch("/api/admin/site")]
+pub async fn update_site_settings_handler(
+    admin: AdminUser,
+    payload: web::Json<UpdateSiteSettingsRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let payload = payload.into_inner();
+    let command = match UpdateSiteSettingsCommand::new(
+        payload.title,
+        payload.tagline,
+        payload.social_links,
+        payload.analytics_id,
+        payload.theme,
+    ) {
+        Ok(cmd) => cmd,
+        Err(err) => return map_command_error(err),
+    };
+
+    match data.site.update.execute(command).await {
+        Ok(profile) => ApiResponse::success(profile),
+        Err(UpdateSiteSettingsError::RepositoryError(msg)) => {
+            error!(admin = %admin.user_id, "Failed to save site settings: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+fn map_command_error(err: UpdateSiteSettingsCommandError) -> HttpResponse {
+    let code = match err {
+        UpdateSiteSettingsCommandError::TitleTooLong => TITLE_TOO_LONG,
+        UpdateSiteSettingsCommandError::TaglineTooLong => TAGLINE_TOO_LONG,
+        UpdateSiteSettingsCommandError::InvalidSocialLinks => INVALID_SOCIAL_LINKS,
+        UpdateSiteSettingsCommandError::InvalidAnalyticsId => INVALID_ANALYTICS_ID,
+    };
+    code.with_message(&err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::json;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service,
+            stubs::StubUpdateSiteSettingsUseCase,
+        },
+    };
+
+    async fn call(
+        state: web::Data<AppState>,
+        user_id: Uuid,
+        body: serde_json::Value,
+    ) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(user_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(update_site_settings_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::patch()
+            .uri("/api/admin/site")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(body)
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_admin_updates_settings() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .build();
+
+        let resp = call(
+            state,
+            admin,
+            json!({
+                "title": "Jane's Portfolio",
+                "social_links": [{ "label": "GitHub", "url": "https://github.com/jane" }],
+                "theme": "dark"
+            }),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["title"], "Jane's Portfolio");
+        assert_eq!(json["data"]["social_links"][0]["label"], "GitHub");
+        assert_eq!(json["data"]["theme"], "dark");
+    }
+
+    #[actix_web::test]
+    async fn test_invalid_social_link_is_rejected() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .build();
+
+        let resp = call(
+            state,
+            admin,
+            json!({ "social_links": [{ "label": "Me", "url": "javascript:alert(1)" }] }),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "INVALID_SOCIAL_LINKS");
+    }
+
+    #[actix_web::test]
+    async fn test_non_admin_is_forbidden() {
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![Uuid::new_v4()])
+            .build();
+
+        let resp = call(state, Uuid::new_v4(), json!({ "title": "Mine now" })).await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn test_store_failure_returns_internal_error() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .with_update_site_settings(StubUpdateSiteSettingsUseCase::failure("db down"))
+            .build();
+
+        let resp = call(state, admin, json!({ "title": "Jane's Portfolio" })).await;
+
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
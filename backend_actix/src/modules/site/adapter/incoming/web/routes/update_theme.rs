@@ -0,0 +1,209 @@
+use actix_web::{patch, web, Responder};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::error;
+use utoipa::ToSchema;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::site::adapter::incoming::web::error_codes::{INVALID_THEME, THEME_VERSION_MISMATCH};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    shared::api::ApiResponse,
+    site::application::{
+        domain::theme::ThemeSettings,
+        ports::incoming::use_cases::{
+            UpdateThemeCommand, UpdateThemeCommandError, UpdateThemeError,
+        },
+    },
+    AppState,
+};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct UpdateThemeRequest {
+    /// Theme schema version the payload was written for; must be the
+    /// server's (see `GET /api/site/theme/schema`)
+    pub version: u32,
+
+    /// JSON merge patch: objects merge key by key, `null` removes a key
+    #[schema(value_type = Object)]
+    pub config: Value,
+}
+
+/// Change the theme configuration
+///
+/// The patched config as a whole must pass the theme schema.
+#[utoipa::path(
+    patch,
+    path = "/api/site/theme",
+    tag = "site",
+    request_body = UpdateThemeRequest,
+    responses(
+        (status = 200, description = "Theme saved", body = inline(SuccessResponse<ThemeSettings>)),
+        (status = 400, description = "Config breaks the theme schema or malformed request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 409, description = "Payload written for another theme schema version", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[patch("/api/site/theme")]
+pub async fn update_theme_handler(
+    admin: AdminUser,
+    payload: web::Json<UpdateThemeRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let payload = payload.into_inner();
+    let command = match UpdateThemeCommand::new(payload.version, payload.config) {
+        Ok(cmd) => cmd,
+        Err(err @ UpdateThemeCommandError::VersionMismatch { .. }) => {
+            return THEME_VERSION_MISMATCH.with_message(&err.to_string())
+        }
+        Err(err @ UpdateThemeCommandError::NotAnObject) => {
+            return INVALID_THEME.with_message(&err.to_string())
+        }
+    };
+
+    match data.site.update_theme.execute(command).await {
+        Ok(theme) => ApiResponse::success(theme),
+        Err(err @ UpdateThemeError::InvalidConfig(_)) => {
+            INVALID_THEME.with_message(&err.to_string())
+        }
+        Err(UpdateThemeError::RepositoryError(msg)) => {
+            error!(admin = %admin.user_id, "Failed to save theme: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::json;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        site::application::domain::theme::THEME_SCHEMA_VERSION,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubUpdateThemeUseCase,
+        },
+    };
+
+    async fn call(
+        state: web::Data<AppState>,
+        user_id: Uuid,
+        body: serde_json::Value,
+    ) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(user_id, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(update_theme_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::patch()
+            .uri("/api/site/theme")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(body)
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_admin_updates_theme() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .build();
+
+        let resp = call(
+            state,
+            admin,
+            json!({
+                "version": THEME_SCHEMA_VERSION,
+                "config": { "colors": { "primary": "#336699" } }
+            }),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["config"]["colors"]["primary"], "#336699");
+    }
+
+    #[actix_web::test]
+    async fn test_other_schema_version_is_a_conflict() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .build();
+
+        let resp = call(
+            state,
+            admin,
+            json!({ "version": THEME_SCHEMA_VERSION + 1, "config": {} }),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "THEME_VERSION_MISMATCH");
+    }
+
+    #[actix_web::test]
+    async fn test_schema_violations_are_listed() {
+        let admin = Uuid::new_v4();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .with_update_theme(StubUpdateThemeUseCase::invalid(&[
+                "/colors/primary: must match ^#([0-9a-fA-F]{3}|[0-9a-fA-F]{6})$",
+            ]))
+            .build();
+
+        let resp = call(
+            state,
+            admin,
+            json!({
+                "version": THEME_SCHEMA_VERSION,
+                "config": { "colors": { "primary": "blue" } }
+            }),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "INVALID_THEME");
+        assert!(json["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("/colors/primary"));
+    }
+
+    #[actix_web::test]
+    async fn test_non_admin_is_forbidden() {
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![Uuid::new_v4()])
+            .build();
+
+        let resp = call(
+            state,
+            Uuid::new_v4(),
+            json!({ "version": THEME_SCHEMA_VERSION, "config": {} }),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::shared::in_memory::Table;
+use crate::site::application::domain::settings::{SiteSettingKey, SiteSettings};
+use crate::site::application::ports::outgoing::{
+    SiteSettingChange, SiteSettingsRepository, SiteSettingsRepositoryError,
+};
+
+/// Process-local `SiteSettingsRepository`, one row per key like the table
+#[derive(Clone, Default)]
+pub struct InMemorySiteSettings {
+    rows: Table<(SiteSettingKey, Value)>,
+}
+
+impl InMemorySiteSettings {
+    fn settings(rows: &[(SiteSettingKey, Value)]) -> SiteSettings {
+        let mut settings = SiteSettings::default();
+        for (key, value) in rows {
+            settings.set(*key, value.clone());
+        }
+        settings
+    }
+}
+
+#[async_trait]
+impl SiteSettingsRepository for InMemorySiteSettings {
+    async fn load(&self) -> Result<SiteSettings, SiteSettingsRepositoryError> {
+        Ok(self.rows.read(Self::settings))
+    }
+
+    async fn apply(
+        &self,
+        changes: &[SiteSettingChange],
+    ) -> Result<SiteSettings, SiteSettingsRepositoryError> {
+        Ok(self.rows.write(|rows| {
+            for change in changes {
+                rows.retain(|(key, _)| *key != change.key);
+                if let Some(value) = &change.value {
+                    rows.push((change.key, value.clone()));
+                }
+            }
+            Self::settings(rows)
+        }))
+    }
+}
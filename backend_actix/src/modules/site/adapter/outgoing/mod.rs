@@ -0,0 +1,5 @@
+mod in_memory;
+mod site_settings_repository_postgres;
+
+pub use in_memory::InMemorySiteSettings;
+pub use site_settings_repository_postgres::SiteSettingsRepositoryPostgres;
@@ -0,0 +1,195 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement,
+    TransactionTrait,
+};
+use std::sync::Arc;
+
+use crate::shared::sql;
+use crate::site::application::domain::settings::{SiteSettingKey, SiteSettings};
+use crate::site::application::ports::outgoing::{
+    SiteSettingChange, SiteSettingsRepository, SiteSettingsRepositoryError,
+};
+
+#[derive(Clone)]
+pub struct SiteSettingsRepositoryPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl SiteSettingsRepositoryPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    fn select_stmt(backend: DatabaseBackend) -> Statement {
+        Statement::from_string(backend, "SELECT key, value FROM site_settings")
+    }
+
+    fn upsert_stmt(
+        backend: DatabaseBackend,
+        key: SiteSettingKey,
+        value: &serde_json::Value,
+    ) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                INSERT INTO site_settings (key, value)
+                VALUES ($1, $2)
+                ON CONFLICT (key) DO UPDATE
+                SET value = excluded.value, updated_at = {now}
+                "#,
+                now = sql::now(backend),
+            ),
+            vec![key.as_str().into(), value.clone().into()],
+        )
+    }
+
+    fn delete_stmt(backend: DatabaseBackend, key: SiteSettingKey) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            "DELETE FROM site_settings WHERE key = $1",
+            vec![key.as_str().into()],
+        )
+    }
+
+    // =====================================================
+    // Mapping
+    // =====================================================
+
+    fn to_settings(rows: &[QueryResult]) -> Result<SiteSettings, SiteSettingsRepositoryError> {
+        let mut entries = Vec::with_capacity(rows.len());
+        for row in rows {
+            let key: String = row.try_get("", "key").map_err(Self::map_db_err)?;
+            let value: serde_json::Value = row.try_get("", "value").map_err(Self::map_db_err)?;
+            entries.push((key, value));
+        }
+
+        Ok(SiteSettings::from_entries(entries))
+    }
+
+    fn map_db_err(e: DbErr) -> SiteSettingsRepositoryError {
+        SiteSettingsRepositoryError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl SiteSettingsRepository for SiteSettingsRepositoryPostgres {
+    async fn load(&self) -> Result<SiteSettings, SiteSettingsRepositoryError> {
+        let rows = self
+            .db
+            .query_all(Self::select_stmt(self.db.get_database_backend()))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        Self::to_settings(&rows)
+    }
+
+    async fn apply(
+        &self,
+        changes: &[SiteSettingChange],
+    ) -> Result<SiteSettings, SiteSettingsRepositoryError> {
+        let backend = self.db.get_database_backend();
+        let txn = self.db.begin().await.map_err(Self::map_db_err)?;
+
+        for change in changes {
+            let stmt = match &change.value {
+                Some(value) => Self::upsert_stmt(backend, change.key, value),
+                None => Self::delete_stmt(backend, change.key),
+            };
+            txn.execute(stmt).await.map_err(Self::map_db_err)?;
+        }
+
+        let rows = txn
+            .query_all(Self::select_stmt(backend))
+            .await
+            .map_err(Self::map_db_err)?;
+        txn.commit().await.map_err(Self::map_db_err)?;
+
+        Self::to_settings(&rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{MockDatabase, MockExecResult, Value};
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    use crate::site::application::domain::settings::{Theme, DEFAULT_TITLE};
+
+    fn setting_row(key: &str, value: serde_json::Value) -> BTreeMap<String, Value> {
+        BTreeMap::from([
+            ("key".to_string(), Value::from(key)),
+            ("value".to_string(), Value::Json(Some(Box::new(value)))),
+        ])
+    }
+
+    fn exec_result() -> MockExecResult {
+        MockExecResult {
+            last_insert_id: 0,
+            rows_affected: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_load_reads_rows_into_settings() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![
+                setting_row("tagline", json!("Notes on Rust")),
+                setting_row("theme", json!("light")),
+            ]])
+            .into_connection();
+
+        let repo = SiteSettingsRepositoryPostgres::new(Arc::new(db));
+        let settings = repo.load().await.unwrap();
+
+        assert_eq!(settings.title(), DEFAULT_TITLE);
+        assert_eq!(settings.tagline().as_deref(), Some("Notes on Rust"));
+        assert_eq!(settings.theme(), Theme::Light);
+    }
+
+    #[tokio::test]
+    async fn test_apply_writes_each_change_then_reloads() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([exec_result(), exec_result()])
+            .append_query_results(vec![vec![setting_row("title", json!("Jane's Portfolio"))]])
+            .into_connection();
+
+        // The mock fails the call if either write does not run
+        let repo = SiteSettingsRepositoryPostgres::new(Arc::new(db));
+        let settings = repo
+            .apply(&[
+                SiteSettingChange {
+                    key: SiteSettingKey::Title,
+                    value: Some(json!("Jane's Portfolio")),
+                },
+                SiteSettingChange {
+                    key: SiteSettingKey::Tagline,
+                    value: None,
+                },
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(settings.title(), "Jane's Portfolio");
+        assert_eq!(settings.tagline(), None);
+    }
+
+    #[test]
+    fn test_upsert_replaces_existing_value() {
+        let stmt = SiteSettingsRepositoryPostgres::upsert_stmt(
+            DatabaseBackend::Postgres,
+            SiteSettingKey::Theme,
+            &json!("dark"),
+        );
+
+        assert!(stmt.sql.contains("ON CONFLICT (key) DO UPDATE"));
+        assert!(stmt.sql.contains("value = excluded.value"));
+    }
+}
@@ -0,0 +1,11 @@
+//! Cache keys for site settings reads (see `shared::cache`).
+
+/// The profile served by the public route
+pub fn public_profile() -> String {
+    "cache:site:profile".to_string()
+}
+
+/// The theme served by the public route
+pub fn public_theme() -> String {
+    "cache:site:theme".to_string()
+}
@@ -0,0 +1,217 @@
+//! Site-wide settings, stored one row per key.
+//!
+//! Values are JSON so every key fits the same table. A key without a row, or
+//! whose stored value no longer parses, reads as its default.
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use utoipa::ToSchema;
+
+use crate::site::application::domain::theme::ThemeSettings;
+
+pub const DEFAULT_TITLE: &str = "Ekstion";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SiteSettingKey {
+    Title,
+    Tagline,
+    SocialLinks,
+    AnalyticsId,
+    Theme,
+    ThemeConfig,
+}
+
+impl SiteSettingKey {
+    pub const ALL: [SiteSettingKey; 6] = [
+        SiteSettingKey::Title,
+        SiteSettingKey::Tagline,
+        SiteSettingKey::SocialLinks,
+        SiteSettingKey::AnalyticsId,
+        SiteSettingKey::Theme,
+        SiteSettingKey::ThemeConfig,
+    ];
+
+    /// Name in the `key` column
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SiteSettingKey::Title => "title",
+            SiteSettingKey::Tagline => "tagline",
+            SiteSettingKey::SocialLinks => "social_links",
+            SiteSettingKey::AnalyticsId => "analytics_id",
+            SiteSettingKey::Theme => "theme",
+            SiteSettingKey::ThemeConfig => "theme_config",
+        }
+    }
+}
+
+impl fmt::Display for SiteSettingKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SiteSettingKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|key| key.as_str() == s)
+            .ok_or_else(|| format!("unknown site setting '{s}'"))
+    }
+}
+
+/// A link to the owner's profile elsewhere, e.g. GitHub
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct SocialLink {
+    /// e.g. `GitHub`
+    pub label: String,
+    pub url: String,
+}
+
+/// Color scheme of the frontend
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    Light,
+    Dark,
+    /// Follow the visitor's system preference
+    #[default]
+    System,
+}
+
+/// The stored settings, with typed getters
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SiteSettings {
+    values: BTreeMap<SiteSettingKey, Value>,
+}
+
+impl SiteSettings {
+    /// Settings from stored `(key, value)` rows. Unknown keys are ignored.
+    pub fn from_entries(entries: impl IntoIterator<Item = (String, Value)>) -> Self {
+        Self {
+            values: entries
+                .into_iter()
+                .filter_map(|(key, value)| Some((key.parse().ok()?, value)))
+                .collect(),
+        }
+    }
+
+    pub fn set(&mut self, key: SiteSettingKey, value: Value) {
+        self.values.insert(key, value);
+    }
+
+    pub fn remove(&mut self, key: SiteSettingKey) {
+        self.values.remove(&key);
+    }
+
+    fn get<T: DeserializeOwned>(&self, key: SiteSettingKey) -> Option<T> {
+        serde_json::from_value(self.values.get(&key)?.clone()).ok()
+    }
+
+    pub fn title(&self) -> String {
+        self.get(SiteSettingKey::Title)
+            .unwrap_or_else(|| DEFAULT_TITLE.to_string())
+    }
+
+    pub fn tagline(&self) -> Option<String> {
+        self.get(SiteSettingKey::Tagline)
+    }
+
+    pub fn social_links(&self) -> Vec<SocialLink> {
+        self.get(SiteSettingKey::SocialLinks).unwrap_or_default()
+    }
+
+    pub fn analytics_id(&self) -> Option<String> {
+        self.get(SiteSettingKey::AnalyticsId)
+    }
+
+    pub fn theme(&self) -> Theme {
+        self.get(SiteSettingKey::Theme).unwrap_or_default()
+    }
+
+    pub fn theme_settings(&self) -> ThemeSettings {
+        self.get(SiteSettingKey::ThemeConfig).unwrap_or_default()
+    }
+}
+
+/// Every setting with its default filled in, as the frontend shell reads it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct SiteProfile {
+    pub title: String,
+    pub tagline: Option<String>,
+    pub social_links: Vec<SocialLink>,
+    /// e.g. a Google Analytics measurement id
+    pub analytics_id: Option<String>,
+    pub theme: Theme,
+}
+
+impl From<&SiteSettings> for SiteProfile {
+    fn from(settings: &SiteSettings) -> Self {
+        Self {
+            title: settings.title(),
+            tagline: settings.tagline(),
+            social_links: settings.social_links(),
+            analytics_id: settings.analytics_id(),
+            theme: settings.theme(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_missing_and_unreadable_values_read_as_defaults() {
+        let settings = SiteSettings::from_entries([
+            ("tagline".to_string(), json!("Notes on Rust")),
+            ("theme".to_string(), json!("sepia")),
+            ("retired_key".to_string(), json!(true)),
+        ]);
+
+        let profile = SiteProfile::from(&settings);
+
+        assert_eq!(profile.title, DEFAULT_TITLE);
+        assert_eq!(profile.tagline.as_deref(), Some("Notes on Rust"));
+        assert!(profile.social_links.is_empty());
+        assert_eq!(profile.analytics_id, None);
+        assert_eq!(profile.theme, Theme::System);
+    }
+
+    #[test]
+    fn test_typed_getters_read_stored_values() {
+        let mut settings = SiteSettings::default();
+        settings.set(SiteSettingKey::Title, json!("Jane's Portfolio"));
+        settings.set(
+            SiteSettingKey::SocialLinks,
+            json!([{ "label": "GitHub", "url": "https://github.com/jane" }]),
+        );
+        settings.set(SiteSettingKey::Theme, json!("dark"));
+
+        assert_eq!(settings.title(), "Jane's Portfolio");
+        assert_eq!(
+            settings.social_links(),
+            vec![SocialLink {
+                label: "GitHub".to_string(),
+                url: "https://github.com/jane".to_string(),
+            }]
+        );
+        assert_eq!(settings.theme(), Theme::Dark);
+
+        settings.remove(SiteSettingKey::Title);
+        assert_eq!(settings.title(), DEFAULT_TITLE);
+    }
+
+    #[test]
+    fn test_keys_round_trip() {
+        for key in SiteSettingKey::ALL {
+            assert_eq!(key.as_str().parse::<SiteSettingKey>(), Ok(key));
+        }
+        assert!("colour".parse::<SiteSettingKey>().is_err());
+    }
+}
@@ -0,0 +1,161 @@
+//! Free-form theme configuration (colors, fonts, layout toggles), checked
+//! against the JSON schema in `theme.schema.json`.
+//!
+//! The schema is versioned. A stored config keeps the version it was checked
+//! against and writes must name the current one, so a frontend built for
+//! another version notices instead of misreading or clobbering the theme.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::sync::LazyLock;
+use utoipa::ToSchema;
+
+use crate::shared::json_schema;
+
+/// Bump with every change to `theme.schema.json` that old payloads may not
+/// pass, or that changes what a key means
+pub const THEME_SCHEMA_VERSION: u32 = 1;
+
+static THEME_SCHEMA: LazyLock<Value> = LazyLock::new(|| {
+    serde_json::from_str(include_str!("theme.schema.json")).expect("theme schema is valid JSON")
+});
+
+pub fn theme_schema() -> &'static Value {
+    &THEME_SCHEMA
+}
+
+/// The stored theme; an empty `config` until one is saved
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ThemeSettings {
+    /// Schema version `config` was checked against
+    pub version: u32,
+    #[schema(value_type = Object)]
+    pub config: Value,
+}
+
+impl Default for ThemeSettings {
+    fn default() -> Self {
+        Self {
+            version: THEME_SCHEMA_VERSION,
+            config: Value::Object(Map::new()),
+        }
+    }
+}
+
+impl ThemeSettings {
+    /// `patch` merged into the config as a JSON merge patch (RFC 7386):
+    /// objects merge key by key and `null` removes a key. A config saved
+    /// under another schema version is dropped rather than carried over.
+    pub fn patched(&self, patch: &Value) -> Value {
+        let mut config = if self.version == THEME_SCHEMA_VERSION {
+            self.config.clone()
+        } else {
+            Value::Object(Map::new())
+        };
+        merge_patch(&mut config, patch);
+        config
+    }
+}
+
+/// Every way `config` breaks the current theme schema
+pub fn validate_theme(config: &Value) -> Vec<String> {
+    json_schema::validate(theme_schema(), config)
+}
+
+/// The server-held schema, for admin UIs that build their form from it
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ThemeSchema {
+    pub version: u32,
+    #[schema(value_type = Object)]
+    pub schema: &'static Value,
+}
+
+impl ThemeSchema {
+    pub fn current() -> Self {
+        Self {
+            version: THEME_SCHEMA_VERSION,
+            schema: theme_schema(),
+        }
+    }
+}
+
+fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    let Value::Object(target) = target else {
+        unreachable!("target was just made an object");
+    };
+
+    for (key, value) in patch {
+        if value.is_null() {
+            target.remove(key);
+        } else {
+            merge_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_patch_merges_nested_keys_and_null_removes() {
+        let current = ThemeSettings {
+            version: THEME_SCHEMA_VERSION,
+            config: json!({
+                "colors": { "primary": "#336699", "accent": "#ff0000" },
+                "layout": { "show_tags": true }
+            }),
+        };
+
+        let config = current.patched(&json!({
+            "colors": { "accent": null, "text": "#111" },
+            "layout": null
+        }));
+
+        assert_eq!(
+            config,
+            json!({ "colors": { "primary": "#336699", "text": "#111" } })
+        );
+    }
+
+    #[test]
+    fn test_config_from_another_version_is_not_carried_over() {
+        let current = ThemeSettings {
+            version: THEME_SCHEMA_VERSION + 1,
+            config: json!({ "colors": { "primary": "#336699" } }),
+        };
+
+        let config = current.patched(&json!({ "fonts": { "body": "Inter" } }));
+
+        assert_eq!(config, json!({ "fonts": { "body": "Inter" } }));
+    }
+
+    #[test]
+    fn test_schema_accepts_a_full_theme_and_rejects_unknown_keys() {
+        let theme = json!({
+            "colors": { "primary": "#336699", "background": "#fff" },
+            "fonts": { "heading": "Fraunces", "body": "Inter", "base_size": 16 },
+            "layout": {
+                "max_width": 1200,
+                "header": "centered",
+                "card_style": "shadow",
+                "radius": 8,
+                "show_sidebar": false
+            }
+        });
+        assert!(validate_theme(&theme).is_empty());
+
+        assert_eq!(
+            validate_theme(&json!({ "colors": { "primary": "blue" }, "logo": "x.png" })).len(),
+            2
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub mod cache_keys;
+pub mod domain;
+pub mod ports;
+pub mod services;
+pub mod site_use_cases;
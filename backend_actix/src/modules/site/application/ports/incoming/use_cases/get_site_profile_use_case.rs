@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+use crate::shared::metrics::metered_use_case;
+use crate::site::application::domain::settings::SiteProfile;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum GetSiteProfileError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait GetSiteProfileUseCase: Send + Sync {
+    async fn execute(&self) -> Result<SiteProfile, GetSiteProfileError>;
+}
+
+metered_use_case!(
+    "site",
+    "get_site_profile",
+    GetSiteProfileUseCase,
+    fn execute(&self) -> Result<SiteProfile, GetSiteProfileError>
+);
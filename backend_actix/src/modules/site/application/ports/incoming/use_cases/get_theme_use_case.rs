@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+use crate::shared::metrics::metered_use_case;
+use crate::site::application::domain::theme::ThemeSettings;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum GetThemeError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait GetThemeUseCase: Send + Sync {
+    async fn execute(&self) -> Result<ThemeSettings, GetThemeError>;
+}
+
+metered_use_case!(
+    "site",
+    "get_theme",
+    GetThemeUseCase,
+    fn execute(&self) -> Result<ThemeSettings, GetThemeError>
+);
@@ -0,0 +1,14 @@
+mod get_site_profile_use_case;
+mod get_theme_use_case;
+mod update_site_settings_use_case;
+mod update_theme_use_case;
+
+pub use get_site_profile_use_case::{GetSiteProfileError, GetSiteProfileUseCase};
+pub use get_theme_use_case::{GetThemeError, GetThemeUseCase};
+pub use update_site_settings_use_case::{
+    UpdateSiteSettingsCommand, UpdateSiteSettingsCommandError, UpdateSiteSettingsError,
+    UpdateSiteSettingsUseCase,
+};
+pub use update_theme_use_case::{
+    UpdateThemeCommand, UpdateThemeCommandError, UpdateThemeError, UpdateThemeUseCase,
+};
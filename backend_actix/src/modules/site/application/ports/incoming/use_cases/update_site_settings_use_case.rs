@@ -0,0 +1,283 @@
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::project::application::ports::outgoing::project_repository::PatchField;
+use crate::shared::metrics::metered_use_case;
+use crate::site::application::domain::settings::{SiteProfile, SiteSettingKey, SocialLink, Theme};
+use crate::site::application::ports::outgoing::SiteSettingChange;
+
+pub const MAX_TITLE_LENGTH: usize = 100;
+pub const MAX_TAGLINE_LENGTH: usize = 200;
+pub const MAX_SOCIAL_LINKS: usize = 20;
+pub const MAX_LINK_LABEL_LENGTH: usize = 50;
+pub const MAX_URL_LENGTH: usize = 2048;
+pub const MAX_ANALYTICS_ID_LENGTH: usize = 64;
+
+//
+// ──────────────────────────────────────────────────────────
+// Update Site Settings Command
+// ──────────────────────────────────────────────────────────
+//
+
+/// Validated changes. Per field: unset keeps the current value, `null`
+/// restores the default, a value replaces it. Blank strings count as `null`.
+#[derive(Debug, Clone)]
+pub struct UpdateSiteSettingsCommand {
+    changes: Vec<SiteSettingChange>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateSiteSettingsCommandError {
+    #[error("Title must be at most {MAX_TITLE_LENGTH} characters")]
+    TitleTooLong,
+
+    #[error("Tagline must be at most {MAX_TAGLINE_LENGTH} characters")]
+    TaglineTooLong,
+
+    #[error(
+        "At most {MAX_SOCIAL_LINKS} social links, each with a label of 1-{MAX_LINK_LABEL_LENGTH} characters and an absolute http(s) URL"
+    )]
+    InvalidSocialLinks,
+
+    #[error("Analytics id must be 1-{MAX_ANALYTICS_ID_LENGTH} letters, digits, '-' or '_'")]
+    InvalidAnalyticsId,
+}
+
+/// Trimmed text, with blank text treated as `null`
+fn text(field: PatchField<String>) -> PatchField<String> {
+    match field {
+        PatchField::Value(value) if value.trim().is_empty() => PatchField::Null,
+        PatchField::Value(value) => PatchField::Value(value.trim().to_string()),
+        other => other,
+    }
+}
+
+fn is_http_url(url: &str) -> bool {
+    let host = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"));
+
+    host.is_some_and(|host| !host.is_empty() && !host.starts_with('/'))
+        && url.len() <= MAX_URL_LENGTH
+        && !url.chars().any(char::is_whitespace)
+}
+
+impl UpdateSiteSettingsCommand {
+    pub fn new(
+        title: PatchField<String>,
+        tagline: PatchField<String>,
+        social_links: PatchField<Vec<SocialLink>>,
+        analytics_id: PatchField<String>,
+        theme: PatchField<Theme>,
+    ) -> Result<Self, UpdateSiteSettingsCommandError> {
+        let title = text(title);
+        if title
+            .as_value()
+            .is_some_and(|title| title.chars().count() > MAX_TITLE_LENGTH)
+        {
+            return Err(UpdateSiteSettingsCommandError::TitleTooLong);
+        }
+
+        let tagline = text(tagline);
+        if tagline
+            .as_value()
+            .is_some_and(|tagline| tagline.chars().count() > MAX_TAGLINE_LENGTH)
+        {
+            return Err(UpdateSiteSettingsCommandError::TaglineTooLong);
+        }
+
+        let social_links = match social_links {
+            PatchField::Value(links) => PatchField::Value(
+                links
+                    .into_iter()
+                    .map(|link| SocialLink {
+                        label: link.label.trim().to_string(),
+                        url: link.url.trim().to_string(),
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            other => other,
+        };
+        if social_links.as_value().is_some_and(|links| {
+            links.len() > MAX_SOCIAL_LINKS
+                || links.iter().any(|link| {
+                    link.label.is_empty()
+                        || link.label.chars().count() > MAX_LINK_LABEL_LENGTH
+                        || !is_http_url(&link.url)
+                })
+        }) {
+            return Err(UpdateSiteSettingsCommandError::InvalidSocialLinks);
+        }
+
+        let analytics_id = text(analytics_id);
+        if analytics_id.as_value().is_some_and(|id| {
+            id.len() > MAX_ANALYTICS_ID_LENGTH
+                || !id
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        }) {
+            return Err(UpdateSiteSettingsCommandError::InvalidAnalyticsId);
+        }
+
+        let mut changes = Vec::new();
+        let mut push = |key: SiteSettingKey, field: PatchField<Value>| match field {
+            PatchField::Unset => {}
+            PatchField::Null => changes.push(SiteSettingChange { key, value: None }),
+            PatchField::Value(value) => changes.push(SiteSettingChange {
+                key,
+                value: Some(value),
+            }),
+        };
+        push(SiteSettingKey::Title, to_json(title));
+        push(SiteSettingKey::Tagline, to_json(tagline));
+        push(SiteSettingKey::SocialLinks, to_json(social_links));
+        push(SiteSettingKey::AnalyticsId, to_json(analytics_id));
+        push(SiteSettingKey::Theme, to_json(theme));
+
+        Ok(Self { changes })
+    }
+
+    pub fn changes(&self) -> &[SiteSettingChange] {
+        &self.changes
+    }
+}
+
+fn to_json<T: serde::Serialize>(field: PatchField<T>) -> PatchField<Value> {
+    match field {
+        PatchField::Unset => PatchField::Unset,
+        PatchField::Null => PatchField::Null,
+        PatchField::Value(value) => PatchField::Value(
+            serde_json::to_value(value).expect("settings values serialize to JSON"),
+        ),
+    }
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Use Case Error
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum UpdateSiteSettingsError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Incoming Port (Use Case)
+// ──────────────────────────────────────────────────────────
+//
+
+#[async_trait]
+pub trait UpdateSiteSettingsUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        command: UpdateSiteSettingsCommand,
+    ) -> Result<SiteProfile, UpdateSiteSettingsError>;
+}
+
+metered_use_case!(
+    "site",
+    "update_site_settings",
+    UpdateSiteSettingsUseCase,
+    fn execute(
+        &self,
+        command: UpdateSiteSettingsCommand,
+    ) -> Result<SiteProfile, UpdateSiteSettingsError>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn link(label: &str, url: &str) -> SocialLink {
+        SocialLink {
+            label: label.to_string(),
+            url: url.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_command_keeps_unset_fields_out_and_blank_text_resets() {
+        let command = UpdateSiteSettingsCommand::new(
+            PatchField::Value(" Jane's Portfolio ".to_string()),
+            PatchField::Value("   ".to_string()),
+            PatchField::Unset,
+            PatchField::Null,
+            PatchField::Value(Theme::Dark),
+        )
+        .unwrap();
+
+        assert_eq!(
+            command.changes(),
+            &[
+                SiteSettingChange {
+                    key: SiteSettingKey::Title,
+                    value: Some(json!("Jane's Portfolio")),
+                },
+                SiteSettingChange {
+                    key: SiteSettingKey::Tagline,
+                    value: None,
+                },
+                SiteSettingChange {
+                    key: SiteSettingKey::AnalyticsId,
+                    value: None,
+                },
+                SiteSettingChange {
+                    key: SiteSettingKey::Theme,
+                    value: Some(json!("dark")),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_command_rejects_invalid_values() {
+        let links = |links: Vec<SocialLink>| {
+            UpdateSiteSettingsCommand::new(
+                PatchField::Unset,
+                PatchField::Unset,
+                PatchField::Value(links),
+                PatchField::Unset,
+                PatchField::Unset,
+            )
+        };
+
+        assert!(links(vec![link("GitHub", "https://github.com/jane")]).is_ok());
+        for bad in [
+            vec![link(" ", "https://github.com/jane")],
+            vec![link("GitHub", "javascript:alert(1)")],
+            vec![link("GitHub", "https:///path")],
+            vec![link("GitHub", "https://example.com"); MAX_SOCIAL_LINKS + 1],
+        ] {
+            assert!(matches!(
+                links(bad),
+                Err(UpdateSiteSettingsCommandError::InvalidSocialLinks)
+            ));
+        }
+
+        assert!(matches!(
+            UpdateSiteSettingsCommand::new(
+                PatchField::Value("t".repeat(MAX_TITLE_LENGTH + 1)),
+                PatchField::Unset,
+                PatchField::Unset,
+                PatchField::Unset,
+                PatchField::Unset,
+            ),
+            Err(UpdateSiteSettingsCommandError::TitleTooLong)
+        ));
+        assert!(matches!(
+            UpdateSiteSettingsCommand::new(
+                PatchField::Unset,
+                PatchField::Unset,
+                PatchField::Unset,
+                PatchField::Value("G-ABC 123".to_string()),
+                PatchField::Unset,
+            ),
+            Err(UpdateSiteSettingsCommandError::InvalidAnalyticsId)
+        ));
+    }
+}
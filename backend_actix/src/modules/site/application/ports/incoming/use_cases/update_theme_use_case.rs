@@ -0,0 +1,99 @@
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::shared::metrics::metered_use_case;
+use crate::site::application::domain::theme::{ThemeSettings, THEME_SCHEMA_VERSION};
+
+//
+// ──────────────────────────────────────────────────────────
+// Update Theme Command
+// ──────────────────────────────────────────────────────────
+//
+
+/// A merge patch for the theme config, written for the current schema
+#[derive(Debug, Clone)]
+pub struct UpdateThemeCommand {
+    patch: Value,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateThemeCommandError {
+    #[error(
+        "Payload is for theme schema version {given}, the server is on {THEME_SCHEMA_VERSION}"
+    )]
+    VersionMismatch { given: u32 },
+
+    #[error("Theme config must be a JSON object")]
+    NotAnObject,
+}
+
+impl UpdateThemeCommand {
+    pub fn new(version: u32, patch: Value) -> Result<Self, UpdateThemeCommandError> {
+        if version != THEME_SCHEMA_VERSION {
+            return Err(UpdateThemeCommandError::VersionMismatch { given: version });
+        }
+        if !patch.is_object() {
+            return Err(UpdateThemeCommandError::NotAnObject);
+        }
+
+        Ok(Self { patch })
+    }
+
+    pub fn patch(&self) -> &Value {
+        &self.patch
+    }
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Use Case Error
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum UpdateThemeError {
+    /// The patched config breaks the schema; one message per violation
+    #[error("Invalid theme: {}", .0.join("; "))]
+    InvalidConfig(Vec<String>),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Incoming Port (Use Case)
+// ──────────────────────────────────────────────────────────
+//
+
+#[async_trait]
+pub trait UpdateThemeUseCase: Send + Sync {
+    async fn execute(&self, command: UpdateThemeCommand)
+        -> Result<ThemeSettings, UpdateThemeError>;
+}
+
+metered_use_case!(
+    "site",
+    "update_theme",
+    UpdateThemeUseCase,
+    fn execute(&self, command: UpdateThemeCommand) -> Result<ThemeSettings, UpdateThemeError>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_command_requires_current_version_and_an_object() {
+        assert!(UpdateThemeCommand::new(THEME_SCHEMA_VERSION, json!({})).is_ok());
+        assert!(matches!(
+            UpdateThemeCommand::new(THEME_SCHEMA_VERSION + 1, json!({})),
+            Err(UpdateThemeCommandError::VersionMismatch { .. })
+        ));
+        assert!(matches!(
+            UpdateThemeCommand::new(THEME_SCHEMA_VERSION, json!(["dark"])),
+            Err(UpdateThemeCommandError::NotAnObject)
+        ));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod site_settings_repository;
+
+pub use site_settings_repository::{
+    SiteSettingChange, SiteSettingsRepository, SiteSettingsRepositoryError,
+};
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::site::application::domain::settings::{SiteSettingKey, SiteSettings};
+
+/// New value for one key; `None` deletes the row so the default applies again
+#[derive(Debug, Clone, PartialEq)]
+pub struct SiteSettingChange {
+    pub key: SiteSettingKey,
+    pub value: Option<Value>,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SiteSettingsRepositoryError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait SiteSettingsRepository: Send + Sync {
+    async fn load(&self) -> Result<SiteSettings, SiteSettingsRepositoryError>;
+
+    /// Apply `changes` together and return the settings afterwards
+    async fn apply(
+        &self,
+        changes: &[SiteSettingChange],
+    ) -> Result<SiteSettings, SiteSettingsRepositoryError>;
+}
@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::shared::cache::{self, CachePort};
+use crate::site::application::cache_keys;
+use crate::site::application::domain::settings::SiteProfile;
+use crate::site::application::ports::{
+    incoming::use_cases::{GetSiteProfileError, GetSiteProfileUseCase},
+    outgoing::SiteSettingsRepository,
+};
+
+pub struct GetSiteProfileService<R>
+where
+    R: SiteSettingsRepository,
+{
+    repository: R,
+    cache: Arc<dyn CachePort>,
+}
+
+impl<R> GetSiteProfileService<R>
+where
+    R: SiteSettingsRepository,
+{
+    pub fn new(repository: R, cache: Arc<dyn CachePort>) -> Self {
+        Self { repository, cache }
+    }
+}
+
+#[async_trait]
+impl<R> GetSiteProfileUseCase for GetSiteProfileService<R>
+where
+    R: SiteSettingsRepository,
+{
+    async fn execute(&self) -> Result<SiteProfile, GetSiteProfileError> {
+        // Every page of the frontend shell asks for this
+        cache::read_through(
+            self.cache.as_ref(),
+            &cache_keys::public_profile(),
+            || async {
+                self.repository
+                    .load()
+                    .await
+                    .map(|settings| SiteProfile::from(&settings))
+                    .map_err(|e| GetSiteProfileError::RepositoryError(e.to_string()))
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::site::adapter::outgoing::InMemorySiteSettings;
+    use crate::site::application::domain::settings::{SiteSettingKey, DEFAULT_TITLE};
+    use crate::site::application::ports::outgoing::SiteSettingChange;
+    use crate::tests::support::stubs::InMemoryCache;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_profile_is_cached_until_invalidated() {
+        let store = InMemorySiteSettings::default();
+        let cache = Arc::new(InMemoryCache::default());
+        let service = GetSiteProfileService::new(store.clone(), cache.clone());
+
+        assert_eq!(service.execute().await.unwrap().title, DEFAULT_TITLE);
+        assert_eq!(cache.keys(), vec![cache_keys::public_profile()]);
+
+        store
+            .apply(&[SiteSettingChange {
+                key: SiteSettingKey::Title,
+                value: Some(json!("Jane's Portfolio")),
+            }])
+            .await
+            .unwrap();
+        assert_eq!(service.execute().await.unwrap().title, DEFAULT_TITLE);
+
+        cache::invalidate(cache.as_ref(), &cache_keys::public_profile()).await;
+        assert_eq!(service.execute().await.unwrap().title, "Jane's Portfolio");
+    }
+}
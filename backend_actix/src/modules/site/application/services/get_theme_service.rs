@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::shared::cache::{self, CachePort};
+use crate::site::application::cache_keys;
+use crate::site::application::domain::theme::ThemeSettings;
+use crate::site::application::ports::{
+    incoming::use_cases::{GetThemeError, GetThemeUseCase},
+    outgoing::SiteSettingsRepository,
+};
+
+pub struct GetThemeService<R>
+where
+    R: SiteSettingsRepository,
+{
+    repository: R,
+    cache: Arc<dyn CachePort>,
+}
+
+impl<R> GetThemeService<R>
+where
+    R: SiteSettingsRepository,
+{
+    pub fn new(repository: R, cache: Arc<dyn CachePort>) -> Self {
+        Self { repository, cache }
+    }
+}
+
+#[async_trait]
+impl<R> GetThemeUseCase for GetThemeService<R>
+where
+    R: SiteSettingsRepository,
+{
+    async fn execute(&self) -> Result<ThemeSettings, GetThemeError> {
+        // Read by the frontend shell on every page, like the profile
+        cache::read_through(self.cache.as_ref(), &cache_keys::public_theme(), || async {
+            self.repository
+                .load()
+                .await
+                .map(|settings| settings.theme_settings())
+                .map_err(|e| GetThemeError::RepositoryError(e.to_string()))
+        })
+        .await
+    }
+}
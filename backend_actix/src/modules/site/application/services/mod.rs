@@ -0,0 +1,9 @@
+mod get_site_profile_service;
+mod get_theme_service;
+mod update_site_settings_service;
+mod update_theme_service;
+
+pub use get_site_profile_service::GetSiteProfileService;
+pub use get_theme_service::GetThemeService;
+pub use update_site_settings_service::UpdateSiteSettingsService;
+pub use update_theme_service::UpdateThemeService;
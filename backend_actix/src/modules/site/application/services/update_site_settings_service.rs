@@ -0,0 +1,99 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::shared::cache::{self, CachePort};
+use crate::site::application::cache_keys;
+use crate::site::application::domain::settings::SiteProfile;
+use crate::site::application::ports::{
+    incoming::use_cases::{
+        UpdateSiteSettingsCommand, UpdateSiteSettingsError, UpdateSiteSettingsUseCase,
+    },
+    outgoing::SiteSettingsRepository,
+};
+
+pub struct UpdateSiteSettingsService<R>
+where
+    R: SiteSettingsRepository,
+{
+    repository: R,
+    cache: Arc<dyn CachePort>,
+}
+
+impl<R> UpdateSiteSettingsService<R>
+where
+    R: SiteSettingsRepository,
+{
+    pub fn new(repository: R, cache: Arc<dyn CachePort>) -> Self {
+        Self { repository, cache }
+    }
+}
+
+#[async_trait]
+impl<R> UpdateSiteSettingsUseCase for UpdateSiteSettingsService<R>
+where
+    R: SiteSettingsRepository,
+{
+    async fn execute(
+        &self,
+        command: UpdateSiteSettingsCommand,
+    ) -> Result<SiteProfile, UpdateSiteSettingsError> {
+        let settings = self
+            .repository
+            .apply(command.changes())
+            .await
+            .map_err(|e| UpdateSiteSettingsError::RepositoryError(e.to_string()))?;
+
+        cache::invalidate(self.cache.as_ref(), &cache_keys::public_profile()).await;
+
+        Ok(SiteProfile::from(&settings))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::application::ports::outgoing::project_repository::PatchField;
+    use crate::site::adapter::outgoing::InMemorySiteSettings;
+    use crate::site::application::domain::settings::{Theme, DEFAULT_TITLE};
+    use crate::tests::support::stubs::InMemoryCache;
+
+    fn command(title: PatchField<String>, theme: PatchField<Theme>) -> UpdateSiteSettingsCommand {
+        UpdateSiteSettingsCommand::new(
+            title,
+            PatchField::Unset,
+            PatchField::Unset,
+            PatchField::Unset,
+            theme,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_update_applies_changes_and_drops_cached_profile() {
+        let cache = Arc::new(InMemoryCache::default());
+        cache
+            .set(&cache_keys::public_profile(), "{}".to_string())
+            .await
+            .unwrap();
+        let service =
+            UpdateSiteSettingsService::new(InMemorySiteSettings::default(), cache.clone());
+
+        let profile = service
+            .execute(command(
+                PatchField::Value("Jane's Portfolio".to_string()),
+                PatchField::Value(Theme::Dark),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(profile.title, "Jane's Portfolio");
+        assert_eq!(profile.theme, Theme::Dark);
+        assert!(cache.keys().is_empty());
+
+        let profile = service
+            .execute(command(PatchField::Null, PatchField::Unset))
+            .await
+            .unwrap();
+        assert_eq!(profile.title, DEFAULT_TITLE);
+        assert_eq!(profile.theme, Theme::Dark);
+    }
+}
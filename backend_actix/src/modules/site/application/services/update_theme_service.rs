@@ -0,0 +1,133 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::shared::cache::{self, CachePort};
+use crate::site::application::cache_keys;
+use crate::site::application::domain::settings::SiteSettingKey;
+use crate::site::application::domain::theme::{
+    validate_theme, ThemeSettings, THEME_SCHEMA_VERSION,
+};
+use crate::site::application::ports::{
+    incoming::use_cases::{UpdateThemeCommand, UpdateThemeError, UpdateThemeUseCase},
+    outgoing::{SiteSettingChange, SiteSettingsRepository},
+};
+
+pub struct UpdateThemeService<R>
+where
+    R: SiteSettingsRepository,
+{
+    repository: R,
+    cache: Arc<dyn CachePort>,
+}
+
+impl<R> UpdateThemeService<R>
+where
+    R: SiteSettingsRepository,
+{
+    pub fn new(repository: R, cache: Arc<dyn CachePort>) -> Self {
+        Self { repository, cache }
+    }
+}
+
+#[async_trait]
+impl<R> UpdateThemeUseCase for UpdateThemeService<R>
+where
+    R: SiteSettingsRepository,
+{
+    async fn execute(
+        &self,
+        command: UpdateThemeCommand,
+    ) -> Result<ThemeSettings, UpdateThemeError> {
+        let current = self
+            .repository
+            .load()
+            .await
+            .map_err(|e| UpdateThemeError::RepositoryError(e.to_string()))?
+            .theme_settings();
+
+        // The whole result must pass, not just the patch
+        let config = current.patched(command.patch());
+        let errors = validate_theme(&config);
+        if !errors.is_empty() {
+            return Err(UpdateThemeError::InvalidConfig(errors));
+        }
+
+        let theme = ThemeSettings {
+            version: THEME_SCHEMA_VERSION,
+            config,
+        };
+        let settings = self
+            .repository
+            .apply(&[SiteSettingChange {
+                key: SiteSettingKey::ThemeConfig,
+                value: Some(serde_json::to_value(&theme).expect("theme serializes to JSON")),
+            }])
+            .await
+            .map_err(|e| UpdateThemeError::RepositoryError(e.to_string()))?;
+
+        cache::invalidate(self.cache.as_ref(), &cache_keys::public_theme()).await;
+
+        Ok(settings.theme_settings())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    use crate::site::adapter::outgoing::InMemorySiteSettings;
+    use crate::tests::support::stubs::InMemoryCache;
+
+    fn command(patch: serde_json::Value) -> UpdateThemeCommand {
+        UpdateThemeCommand::new(THEME_SCHEMA_VERSION, patch).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_patches_merge_and_drop_cached_theme() {
+        let cache = Arc::new(InMemoryCache::default());
+        cache
+            .set(&cache_keys::public_theme(), "{}".to_string())
+            .await
+            .unwrap();
+        let service = UpdateThemeService::new(InMemorySiteSettings::default(), cache.clone());
+
+        service
+            .execute(command(json!({ "colors": { "primary": "#336699" } })))
+            .await
+            .unwrap();
+        let theme = service
+            .execute(command(json!({ "layout": { "show_tags": false } })))
+            .await
+            .unwrap();
+
+        assert_eq!(theme.version, THEME_SCHEMA_VERSION);
+        assert_eq!(
+            theme.config,
+            json!({
+                "colors": { "primary": "#336699" },
+                "layout": { "show_tags": false }
+            })
+        );
+        assert!(cache.keys().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_result_is_rejected_and_nothing_saved() {
+        let store = InMemorySiteSettings::default();
+        let service = UpdateThemeService::new(store.clone(), Arc::new(InMemoryCache::default()));
+
+        let result = service
+            .execute(command(json!({ "fonts": { "base_size": 40 } })))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(UpdateThemeError::InvalidConfig(errors)) if errors == ["/fonts/base_size: must be at most 24"]
+        ));
+        assert_eq!(
+            store.load().await.unwrap().theme_settings(),
+            ThemeSettings::default()
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use crate::shared::cache::CachePort;
+use crate::shared::metrics::Metered;
+use crate::site::application::ports::incoming::use_cases::{
+    GetSiteProfileUseCase, GetThemeUseCase, UpdateSiteSettingsUseCase, UpdateThemeUseCase,
+};
+use crate::site::application::ports::outgoing::SiteSettingsRepository;
+use crate::site::application::services::{
+    GetSiteProfileService, GetThemeService, UpdateSiteSettingsService, UpdateThemeService,
+};
+
+#[derive(Clone)]
+pub struct SiteUseCases {
+    pub get: Arc<dyn GetSiteProfileUseCase + Send + Sync>,
+    pub update: Arc<dyn UpdateSiteSettingsUseCase + Send + Sync>,
+    pub get_theme: Arc<dyn GetThemeUseCase + Send + Sync>,
+    pub update_theme: Arc<dyn UpdateThemeUseCase + Send + Sync>,
+}
+
+impl SiteUseCases {
+    pub fn build<R>(repository: R, cache: Arc<dyn CachePort>) -> Self
+    where
+        R: SiteSettingsRepository + Clone + 'static,
+    {
+        Self {
+            get: Arc::new(Metered(GetSiteProfileService::new(
+                repository.clone(),
+                Arc::clone(&cache),
+            ))),
+            update: Arc::new(Metered(UpdateSiteSettingsService::new(
+                repository.clone(),
+                Arc::clone(&cache),
+            ))),
+            get_theme: Arc::new(Metered(GetThemeService::new(
+                repository.clone(),
+                Arc::clone(&cache),
+            ))),
+            update_theme: Arc::new(Metered(UpdateThemeService::new(repository, cache))),
+        }
+    }
+}
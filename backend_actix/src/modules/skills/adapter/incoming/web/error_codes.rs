@@ -0,0 +1,9 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    INVALID_SKILL_QUERY = (BAD_REQUEST, "Invalid skill search text or limit");
+    INVALID_SKILL_NAME = (BAD_REQUEST, "Invalid skill name");
+    CANNOT_MERGE_SKILL_INTO_ITSELF = (BAD_REQUEST, "A skill can't be merged into itself");
+    SKILL_NOT_FOUND = (NOT_FOUND, "Skill not found");
+    SKILL_NAME_TAKEN = (CONFLICT, "Another skill already has this name; merge the two instead");
+}
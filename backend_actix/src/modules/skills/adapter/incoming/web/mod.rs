@@ -0,0 +1,11 @@
+use actix_web::web;
+
+pub mod error_codes;
+pub mod routes;
+
+/// Routes for signed-in users with a verified email
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::suggest_skills_handler)
+        .service(routes::rename_skill_handler)
+        .service(routes::merge_skills_handler);
+}
@@ -0,0 +1,130 @@
+use actix_web::{post, web, Responder};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    shared::api::ApiResponse,
+    skills::{
+        adapter::incoming::web::error_codes::{CANNOT_MERGE_SKILL_INTO_ITSELF, SKILL_NOT_FOUND},
+        application::{domain::entities::Skill, ports::incoming::use_cases::MergeSkillsError},
+    },
+    AppState,
+};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct MergeSkillsRequest {
+    /// The skill that is kept
+    pub into: Uuid,
+}
+
+/// Fold a skill into another
+///
+/// The skill's spelling and aliases become aliases of `into`, and the skill
+/// is removed; suggestions show `into` for all of them from now on. CVs and
+/// projects are not rewritten.
+#[utoipa::path(
+    post,
+    path = "/api/admin/skills/{skill_id}/merge",
+    tag = "skills",
+    params(
+        ("skill_id" = Uuid, Path, description = "Skill to fold away"),
+    ),
+    request_body = MergeSkillsRequest,
+    responses(
+        (status = 200, description = "Skills merged; the kept skill", body = inline(SuccessResponse<Skill>)),
+        (status = 400, description = "Same skill twice, or malformed request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 404, description = "Either skill not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/admin/skills/{skill_id}/merge")]
+pub async fn merge_skills_handler(
+    admin: AdminUser,
+    path: web::Path<Uuid>,
+    payload: web::Json<MergeSkillsRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let source_id = path.into_inner();
+    let target_id = payload.into_inner().into;
+
+    match data.skills.merge.execute(source_id, target_id).await {
+        Ok(skill) => ApiResponse::success(skill),
+        Err(MergeSkillsError::NotFound) => SKILL_NOT_FOUND.response(),
+        Err(MergeSkillsError::SameSkill) => CANNOT_MERGE_SKILL_INTO_ITSELF.response(),
+        Err(MergeSkillsError::RepositoryError(msg)) => {
+            error!(
+                admin = %admin.user_id,
+                "Failed to merge skill {} into {}: {}", source_id, target_id, msg
+            );
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        skills::{
+            adapter::outgoing::InMemorySkillStore, application::skill_use_cases::SkillUseCases,
+        },
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service,
+        },
+    };
+
+    #[actix_web::test]
+    async fn test_skill_is_merged_once() {
+        let admin = Uuid::new_v4();
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(admin, true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let store = InMemorySkillStore::with_names(&["Postgres", "PostgreSQL"]);
+        let postgres = store.find("postgres").unwrap();
+        let postgresql = store.find("postgresql").unwrap();
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![admin])
+            .with_skills(SkillUseCases::build(store))
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(merge_skills_handler),
+        )
+        .await;
+        let merge = || {
+            test::TestRequest::post()
+                .uri(&format!("/api/admin/skills/{}/merge", postgres.id))
+                .insert_header(("Authorization", format!("Bearer {}", token)))
+                .set_json(json!({ "into": postgresql.id }))
+                .to_request()
+        };
+
+        let resp = test::call_service(&app, merge()).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["name"], "PostgreSQL");
+
+        let resp = test::call_service(&app, merge()).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "SKILL_NOT_FOUND");
+    }
+}
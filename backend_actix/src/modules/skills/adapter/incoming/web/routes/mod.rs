@@ -0,0 +1,7 @@
+mod merge_skills;
+mod rename_skill;
+mod suggest_skills;
+
+pub use merge_skills::{__path_merge_skills_handler, merge_skills_handler};
+pub use rename_skill::{__path_rename_skill_handler, rename_skill_handler};
+pub use suggest_skills::{__path_suggest_skills_handler, suggest_skills_handler};
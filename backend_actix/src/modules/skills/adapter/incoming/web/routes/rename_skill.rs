@@ -0,0 +1,119 @@
+use actix_web::{patch, web, Responder};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::AdminUser,
+    shared::api::ApiResponse,
+    skills::{
+        adapter::incoming::web::error_codes::{
+            INVALID_SKILL_NAME, SKILL_NAME_TAKEN, SKILL_NOT_FOUND,
+        },
+        application::{
+            domain::entities::Skill,
+            ports::incoming::use_cases::{RenameSkillCommand, RenameSkillError},
+        },
+    },
+    AppState,
+};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct RenameSkillRequest {
+    /// How the skill is shown from now on, e.g. `PostgreSQL`
+    pub name: String,
+}
+
+/// Rename a skill
+///
+/// The old spelling stays an alias, so content still using it is suggested
+/// under the new name. CVs and projects are not rewritten.
+#[utoipa::path(
+    patch,
+    path = "/api/admin/skills/{skill_id}",
+    tag = "skills",
+    params(
+        ("skill_id" = Uuid, Path, description = "Skill id"),
+    ),
+    request_body = RenameSkillRequest,
+    responses(
+        (status = 200, description = "Skill renamed", body = inline(SuccessResponse<Skill>)),
+        (status = 400, description = "Invalid name or malformed request body", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified or not an administrator", body = ErrorResponse),
+        (status = 404, description = "Skill not found", body = ErrorResponse),
+        (status = 409, description = "Another skill already has this name", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[patch("/api/admin/skills/{skill_id}")]
+pub async fn rename_skill_handler(
+    admin: AdminUser,
+    path: web::Path<Uuid>,
+    payload: web::Json<RenameSkillRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let skill_id = path.into_inner();
+    let command = match RenameSkillCommand::new(payload.into_inner().name) {
+        Ok(cmd) => cmd,
+        Err(err) => return INVALID_SKILL_NAME.with_message(&err.to_string()),
+    };
+
+    match data.skills.rename.execute(skill_id, command).await {
+        Ok(skill) => ApiResponse::success(skill),
+        Err(RenameSkillError::NotFound) => SKILL_NOT_FOUND.response(),
+        Err(RenameSkillError::NameTaken) => SKILL_NAME_TAKEN.response(),
+        Err(RenameSkillError::RepositoryError(msg)) => {
+            error!(admin = %admin.user_id, "Failed to rename skill {}: {}", skill_id, msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service,
+        },
+    };
+
+    #[actix_web::test]
+    async fn test_non_admin_is_forbidden() {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_admin_user_ids(vec![Uuid::new_v4()])
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(rename_skill_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::patch()
+            .uri(&format!("/api/admin/skills/{}", Uuid::new_v4()))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(json!({ "name": "PostgreSQL" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+}
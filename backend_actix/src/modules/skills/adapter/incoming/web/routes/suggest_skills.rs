@@ -0,0 +1,142 @@
+use actix_web::{get, web, Responder};
+use serde::Deserialize;
+use tracing::error;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::VerifiedUser,
+    shared::api::ApiResponse,
+    skills::{
+        adapter::incoming::web::error_codes::INVALID_SKILL_QUERY,
+        application::{
+            domain::entities::Skill,
+            ports::incoming::use_cases::{SkillQuery, SuggestSkillsError},
+        },
+    },
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct SuggestSkillsParams {
+    pub q: String,
+    pub limit: Option<u32>,
+}
+
+/// Autocomplete skills for CV core skills and project tech stacks
+///
+/// Matches skill names and their other spellings ("postgres" finds
+/// `PostgreSQL` once merged) ignoring case; names starting with the text
+/// come first. The catalog is shared by every account.
+#[utoipa::path(
+    get,
+    path = "/api/skills/suggest",
+    tag = "skills",
+    params(
+        ("q" = String, Query, description = "Text to complete, 1-50 characters"),
+        ("limit" = Option<u32>, Query, description = "Suggestions, 1-20 (default 10)"),
+    ),
+    responses(
+        (status = 200, description = "Matching skills", body = inline(SuccessResponse<Vec<Skill>>)),
+        (status = 400, description = "Invalid text or limit", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/skills/suggest")]
+pub async fn suggest_skills_handler(
+    user: VerifiedUser,
+    params: web::Query<SuggestSkillsParams>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let params = params.into_inner();
+    let query = match SkillQuery::new(params.q, params.limit) {
+        Ok(query) => query,
+        Err(err) => return INVALID_SKILL_QUERY.with_message(&err.to_string()),
+    };
+
+    match data.skills.suggest.execute(query).await {
+        Ok(skills) => ApiResponse::success(skills),
+        Err(SuggestSkillsError::QueryFailed(msg)) => {
+            error!(user = %user.user_id, "Failed to suggest skills: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        skills::{
+            adapter::outgoing::InMemorySkillStore, application::skill_use_cases::SkillUseCases,
+        },
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service,
+        },
+    };
+
+    async fn call(state: web::Data<AppState>, uri: &str) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(suggest_skills_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_prefix_matches_come_first() {
+        let state = TestAppStateBuilder::default()
+            .with_skills(SkillUseCases::build(InMemorySkillStore::with_names(&[
+                "SQLite",
+                "PostgreSQL",
+                "Rust",
+            ])))
+            .build();
+
+        let resp = call(state, "/api/skills/suggest?q=SQL").await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        let names: Vec<&str> = json["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|skill| skill["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["SQLite", "PostgreSQL"]);
+    }
+
+    #[actix_web::test]
+    async fn test_blank_query_is_rejected() {
+        let state = TestAppStateBuilder::default().build();
+
+        let resp = call(state, "/api/skills/suggest?q=%20").await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "INVALID_SKILL_QUERY");
+    }
+}
@@ -0,0 +1,217 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::cv::adapter::outgoing::InMemoryCvStore;
+use crate::project::adapter::outgoing::InMemoryProjectStore;
+use crate::shared::in_memory::Table;
+use crate::skills::application::domain::entities::{skill_key, Skill, MAX_SKILL_NAME_LENGTH};
+use crate::skills::application::ports::incoming::use_cases::{RenameSkillCommand, SkillQuery};
+use crate::skills::application::ports::outgoing::{SkillRepository, SkillRepositoryError};
+
+struct SkillRow {
+    skill: Skill,
+    key: String,
+}
+
+struct AliasRow {
+    key: String,
+    skill_id: Uuid,
+}
+
+/// Process-local `SkillRepository`. There are no triggers here, so every
+/// call first reads in the names of the CV and project stores it was built
+/// with, by the same rules.
+#[derive(Clone)]
+pub struct InMemorySkillStore {
+    skills: Table<SkillRow>,
+    aliases: Table<AliasRow>,
+    cvs: InMemoryCvStore,
+    projects: InMemoryProjectStore,
+}
+
+impl InMemorySkillStore {
+    pub fn new(cvs: InMemoryCvStore, projects: InMemoryProjectStore) -> Self {
+        Self {
+            skills: Table::default(),
+            aliases: Table::default(),
+            cvs,
+            projects,
+        }
+    }
+
+    /// Adds `name` unless it is blank, too long, or a known spelling
+    pub(crate) fn record(&self, name: &str) {
+        let name = name.trim();
+        let key = skill_key(name);
+        if name.is_empty()
+            || name.chars().count() > MAX_SKILL_NAME_LENGTH
+            || self
+                .aliases
+                .read(|aliases| aliases.iter().any(|a| a.key == key))
+        {
+            return;
+        }
+
+        self.skills.write(|skills| {
+            if skills.iter().all(|row| row.key != key) {
+                skills.push(SkillRow {
+                    skill: Skill {
+                        id: Uuid::new_v4(),
+                        name: name.to_string(),
+                    },
+                    key,
+                });
+            }
+        });
+    }
+
+    fn sync(&self) {
+        let mut names: Vec<String> = self.cvs.cvs.read(|cvs| {
+            cvs.iter()
+                .flat_map(|row| row.cv.core_skills.iter().map(|s| s.title.clone()))
+                .collect()
+        });
+        names.extend(self.projects.projects.read(|rows| {
+            rows.iter()
+                .flat_map(|row| row.project.tech_stack.iter().cloned())
+                .collect::<Vec<_>>()
+        }));
+
+        for name in names {
+            self.record(&name);
+        }
+    }
+
+    fn skill(&self, id: Uuid) -> Option<(Skill, String)> {
+        self.skills.read(|skills| {
+            skills
+                .iter()
+                .find(|row| row.skill.id == id)
+                .map(|row| (row.skill.clone(), row.key.clone()))
+        })
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_names(names: &[&str]) -> Self {
+        let store = Self::new(InMemoryCvStore::default(), InMemoryProjectStore::default());
+        for name in names {
+            store.record(name);
+        }
+        store
+    }
+
+    #[cfg(test)]
+    pub(crate) fn find(&self, key: &str) -> Option<Skill> {
+        self.skills.read(|skills| {
+            skills
+                .iter()
+                .find(|row| row.key == key)
+                .map(|row| row.skill.clone())
+        })
+    }
+}
+
+#[async_trait]
+impl SkillRepository for InMemorySkillStore {
+    async fn suggest(&self, query: &SkillQuery) -> Result<Vec<Skill>, SkillRepositoryError> {
+        self.sync();
+
+        let alias_owners: Vec<Uuid> = self.aliases.read(|aliases| {
+            aliases
+                .iter()
+                .filter(|alias| alias.key.contains(query.key()))
+                .map(|alias| alias.skill_id)
+                .collect()
+        });
+
+        let mut matches: Vec<(bool, String, Skill)> = self.skills.read(|skills| {
+            skills
+                .iter()
+                .filter(|row| row.key.contains(query.key()) || alias_owners.contains(&row.skill.id))
+                .map(|row| {
+                    (
+                        !row.key.starts_with(query.key()),
+                        row.key.clone(),
+                        row.skill.clone(),
+                    )
+                })
+                .collect()
+        });
+        matches.sort_by(|a, b| (a.0, &a.1).cmp(&(b.0, &b.1)));
+
+        Ok(matches
+            .into_iter()
+            .take(query.limit() as usize)
+            .map(|(_, _, skill)| skill)
+            .collect())
+    }
+
+    async fn rename(
+        &self,
+        skill_id: Uuid,
+        command: &RenameSkillCommand,
+    ) -> Result<Skill, SkillRepositoryError> {
+        self.sync();
+
+        let (_, old_key) = self.skill(skill_id).ok_or(SkillRepositoryError::NotFound)?;
+        let key = skill_key(command.name());
+
+        let taken = self.skills.read(|skills| {
+            skills
+                .iter()
+                .any(|row| row.key == key && row.skill.id != skill_id)
+        }) || self.aliases.read(|aliases| {
+            aliases
+                .iter()
+                .any(|alias| alias.key == key && alias.skill_id != skill_id)
+        });
+        if taken {
+            return Err(SkillRepositoryError::NameTaken);
+        }
+
+        self.aliases.write(|aliases| {
+            aliases.retain(|alias| alias.key != key);
+            if old_key != key {
+                aliases.push(AliasRow {
+                    key: old_key,
+                    skill_id,
+                });
+            }
+        });
+
+        self.skills.write(|skills| {
+            let row = skills
+                .iter_mut()
+                .find(|row| row.skill.id == skill_id)
+                .ok_or(SkillRepositoryError::NotFound)?;
+            row.skill.name = command.name().to_string();
+            row.key = key;
+            Ok(row.skill.clone())
+        })
+    }
+
+    async fn merge(&self, source_id: Uuid, target_id: Uuid) -> Result<Skill, SkillRepositoryError> {
+        self.sync();
+
+        let (_, source_key) = self
+            .skill(source_id)
+            .ok_or(SkillRepositoryError::NotFound)?;
+        let (target, _) = self
+            .skill(target_id)
+            .ok_or(SkillRepositoryError::NotFound)?;
+
+        self.aliases.write(|aliases| {
+            for alias in aliases.iter_mut().filter(|a| a.skill_id == source_id) {
+                alias.skill_id = target_id;
+            }
+            aliases.push(AliasRow {
+                key: source_key,
+                skill_id: target_id,
+            });
+        });
+        self.skills
+            .write(|skills| skills.retain(|row| row.skill.id != source_id));
+
+        Ok(target)
+    }
+}
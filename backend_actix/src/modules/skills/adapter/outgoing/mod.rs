@@ -0,0 +1,5 @@
+mod in_memory;
+mod skill_repository_postgres;
+
+pub use in_memory::InMemorySkillStore;
+pub use skill_repository_postgres::SkillRepositoryPostgres;
@@ -0,0 +1,323 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DatabaseTransaction, DbErr, QueryResult,
+    Statement, TransactionTrait,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::skills::application::domain::entities::{skill_key, Skill};
+use crate::skills::application::ports::incoming::use_cases::{RenameSkillCommand, SkillQuery};
+use crate::skills::application::ports::outgoing::{SkillRepository, SkillRepositoryError};
+
+/// A skill row as read for a rename or merge
+struct SkillRecord {
+    skill: Skill,
+    key: String,
+}
+
+#[derive(Clone)]
+pub struct SkillRepositoryPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl SkillRepositoryPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    /// Keys are lowercase already, so a plain `LIKE` ignores case
+    fn suggest_stmt(backend: DatabaseBackend, query: &SkillQuery) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT s.id, s.name
+            FROM skills s
+            WHERE s.name_key LIKE $1
+               OR EXISTS (
+                    SELECT 1
+                    FROM skill_aliases a
+                    WHERE a.skill_id = s.id
+                      AND a.name_key LIKE $1
+               )
+            ORDER BY CASE WHEN s.name_key LIKE $2 THEN 0 ELSE 1 END, s.name_key
+            LIMIT $3
+            "#,
+            vec![
+                format!("%{}%", query.key()).into(),
+                format!("{}%", query.key()).into(),
+                (query.limit() as i64).into(),
+            ],
+        )
+    }
+
+    fn find_stmt(backend: DatabaseBackend, id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            "SELECT id, name, name_key FROM skills WHERE id = $1",
+            vec![id.into()],
+        )
+    }
+
+    /// Any row when `key` spells a skill other than `id`
+    fn key_taken_stmt(backend: DatabaseBackend, key: &str, id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT 1 AS taken FROM skills WHERE name_key = $1 AND id <> $2
+            UNION ALL
+            SELECT 1 AS taken FROM skill_aliases WHERE name_key = $1 AND skill_id <> $2
+            "#,
+            vec![key.into(), id.into()],
+        )
+    }
+
+    fn rename_stmt(backend: DatabaseBackend, id: Uuid, name: &str, key: &str) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            "UPDATE skills SET name = $2, name_key = $3 WHERE id = $1",
+            vec![id.into(), name.into(), key.into()],
+        )
+    }
+
+    fn insert_alias_stmt(backend: DatabaseBackend, key: &str, skill_id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            "INSERT INTO skill_aliases (name_key, skill_id) VALUES ($1, $2)",
+            vec![key.into(), skill_id.into()],
+        )
+    }
+
+    fn delete_alias_stmt(backend: DatabaseBackend, key: &str) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            "DELETE FROM skill_aliases WHERE name_key = $1",
+            vec![key.into()],
+        )
+    }
+
+    fn move_aliases_stmt(backend: DatabaseBackend, source_id: Uuid, target_id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            "UPDATE skill_aliases SET skill_id = $2 WHERE skill_id = $1",
+            vec![source_id.into(), target_id.into()],
+        )
+    }
+
+    fn delete_skill_stmt(backend: DatabaseBackend, id: Uuid) -> Statement {
+        Statement::from_sql_and_values(backend, "DELETE FROM skills WHERE id = $1", vec![id.into()])
+    }
+
+    // =====================================================
+    // Helpers
+    // =====================================================
+
+    async fn find(
+        txn: &DatabaseTransaction,
+        id: Uuid,
+    ) -> Result<SkillRecord, SkillRepositoryError> {
+        let row = txn
+            .query_one(Self::find_stmt(txn.get_database_backend(), id))
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or(SkillRepositoryError::NotFound)?;
+
+        Ok(SkillRecord {
+            skill: Self::to_skill(&row)?,
+            key: row.try_get("", "name_key").map_err(Self::map_db_err)?,
+        })
+    }
+
+    async fn execute(
+        txn: &DatabaseTransaction,
+        stmt: Statement,
+    ) -> Result<(), SkillRepositoryError> {
+        txn.execute(stmt).await.map_err(Self::map_write_err)?;
+        Ok(())
+    }
+
+    // =====================================================
+    // Mapping
+    // =====================================================
+
+    fn to_skill(row: &QueryResult) -> Result<Skill, SkillRepositoryError> {
+        Ok(Skill {
+            id: row.try_get("", "id").map_err(Self::map_db_err)?,
+            name: row.try_get("", "name").map_err(Self::map_db_err)?,
+        })
+    }
+
+    /// A spelling claimed by a concurrent write hits a unique key
+    fn map_write_err(e: DbErr) -> SkillRepositoryError {
+        let msg = e.to_string().to_lowercase();
+
+        if msg.contains("duplicate") || msg.contains("unique") || msg.contains("23505") {
+            SkillRepositoryError::NameTaken
+        } else {
+            SkillRepositoryError::DatabaseError(e.to_string())
+        }
+    }
+
+    fn map_db_err(e: DbErr) -> SkillRepositoryError {
+        SkillRepositoryError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl SkillRepository for SkillRepositoryPostgres {
+    async fn suggest(&self, query: &SkillQuery) -> Result<Vec<Skill>, SkillRepositoryError> {
+        self.db
+            .query_all(Self::suggest_stmt(self.db.get_database_backend(), query))
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(Self::to_skill)
+            .collect()
+    }
+
+    async fn rename(
+        &self,
+        skill_id: Uuid,
+        command: &RenameSkillCommand,
+    ) -> Result<Skill, SkillRepositoryError> {
+        let txn = self.db.begin().await.map_err(Self::map_db_err)?;
+        let backend = txn.get_database_backend();
+        let current = Self::find(&txn, skill_id).await?;
+        let key = skill_key(command.name());
+
+        let taken = txn
+            .query_one(Self::key_taken_stmt(backend, &key, skill_id))
+            .await
+            .map_err(Self::map_db_err)?
+            .is_some();
+        if taken {
+            return Err(SkillRepositoryError::NameTaken);
+        }
+
+        // Renaming back to an alias of the skill makes it the name again
+        Self::execute(&txn, Self::delete_alias_stmt(backend, &key)).await?;
+        if current.key != key {
+            Self::execute(
+                &txn,
+                Self::insert_alias_stmt(backend, &current.key, skill_id),
+            )
+            .await?;
+        }
+        Self::execute(
+            &txn,
+            Self::rename_stmt(backend, skill_id, command.name(), &key),
+        )
+        .await?;
+
+        txn.commit().await.map_err(Self::map_db_err)?;
+        Ok(Skill {
+            id: skill_id,
+            name: command.name().to_string(),
+        })
+    }
+
+    async fn merge(&self, source_id: Uuid, target_id: Uuid) -> Result<Skill, SkillRepositoryError> {
+        let txn = self.db.begin().await.map_err(Self::map_db_err)?;
+        let backend = txn.get_database_backend();
+        let source = Self::find(&txn, source_id).await?;
+        let target = Self::find(&txn, target_id).await?;
+
+        Self::execute(&txn, Self::move_aliases_stmt(backend, source_id, target_id)).await?;
+        Self::execute(
+            &txn,
+            Self::insert_alias_stmt(backend, &source.key, target_id),
+        )
+        .await?;
+        Self::execute(&txn, Self::delete_skill_stmt(backend, source_id)).await?;
+
+        txn.commit().await.map_err(Self::map_db_err)?;
+        Ok(target.skill)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{MockDatabase, MockExecResult, Value};
+    use std::collections::BTreeMap;
+
+    fn skill_row(id: Uuid, name: &str) -> BTreeMap<String, Value> {
+        BTreeMap::from([
+            ("id".to_string(), Value::from(id)),
+            ("name".to_string(), Value::from(name)),
+            ("name_key".to_string(), Value::from(skill_key(name))),
+        ])
+    }
+
+    fn affected(rows_affected: u64) -> MockExecResult {
+        MockExecResult {
+            last_insert_id: 0,
+            rows_affected,
+        }
+    }
+
+    #[test]
+    fn test_prefix_matches_come_first() {
+        let query = SkillQuery::new("Post".to_string(), Some(5)).unwrap();
+        let stmt = SkillRepositoryPostgres::suggest_stmt(DatabaseBackend::Postgres, &query);
+
+        assert!(stmt
+            .sql
+            .contains("CASE WHEN s.name_key LIKE $2 THEN 0 ELSE 1 END"));
+        let values = stmt.values.unwrap().0;
+        assert_eq!(values[0], Value::from("%post%"));
+        assert_eq!(values[1], Value::from("post%"));
+        assert_eq!(values[2], Value::from(5i64));
+    }
+
+    #[tokio::test]
+    async fn test_rename_to_another_skills_spelling_is_refused() {
+        let id = Uuid::new_v4();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![
+                vec![skill_row(id, "Postgres")],
+                vec![BTreeMap::from([("taken".to_string(), Value::from(1i32))])],
+            ])
+            .into_connection();
+        let repo = SkillRepositoryPostgres::new(Arc::new(db));
+
+        let result = repo
+            .rename(id, &RenameSkillCommand::new("Rust".to_string()).unwrap())
+            .await;
+
+        assert!(matches!(result, Err(SkillRepositoryError::NameTaken)));
+    }
+
+    #[tokio::test]
+    async fn test_merge_keeps_the_source_spelling_as_alias() {
+        let (source_id, target_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let db = Arc::new(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_query_results(vec![
+                    vec![skill_row(source_id, "Postgres")],
+                    vec![skill_row(target_id, "PostgreSQL")],
+                ])
+                .append_exec_results([affected(2), affected(1), affected(1)])
+                .into_connection(),
+        );
+        let repo = SkillRepositoryPostgres::new(Arc::clone(&db));
+
+        let merged = repo.merge(source_id, target_id).await.unwrap();
+        assert_eq!(merged.name, "PostgreSQL");
+        drop(repo);
+
+        let log = Arc::try_unwrap(db)
+            .expect("no other connection handles")
+            .into_transaction_log();
+        let log = format!("{log:?}");
+        let moved = log.find("UPDATE skill_aliases SET skill_id").unwrap();
+        let aliased = log.find("INSERT INTO skill_aliases").unwrap();
+        let deleted = log.find("DELETE FROM skills").unwrap();
+        assert!(moved < aliased && aliased < deleted);
+        assert!(log.contains("\"postgres\""));
+    }
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+pub const MAX_SKILL_NAME_LENGTH: usize = 100;
+
+/// A skill named in CV core skills or project tech stacks, however its
+/// content spells it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Skill {
+    pub id: Uuid,
+    /// How the skill is shown, e.g. `PostgreSQL`
+    pub name: String,
+}
+
+/// What identifies a spelling: the name trimmed and lowercased, as the
+/// database triggers feeding the catalog compute it
+pub fn skill_key(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spellings_differing_in_case_share_a_key() {
+        assert_eq!(skill_key(" PostgreSQL "), "postgresql");
+        assert_eq!(skill_key("postgresql"), skill_key("POSTGRESQL"));
+        assert_ne!(skill_key("Postgres"), skill_key("PostgreSQL"));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod domain;
+pub mod ports;
+pub mod services;
+pub mod skill_use_cases;
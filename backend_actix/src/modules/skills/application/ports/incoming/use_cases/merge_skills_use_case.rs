@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::shared::metrics::metered_use_case;
+use crate::skills::application::domain::entities::Skill;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum MergeSkillsError {
+    #[error("Skill not found")]
+    NotFound,
+
+    #[error("A skill can't be merged into itself")]
+    SameSkill,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait MergeSkillsUseCase: Send + Sync {
+    /// Folds `source_id` into `target_id`: the source's spelling and aliases
+    /// become aliases of the target, and the source is removed. Returns the
+    /// target.
+    async fn execute(&self, source_id: Uuid, target_id: Uuid) -> Result<Skill, MergeSkillsError>;
+}
+
+metered_use_case!(
+    "skills",
+    "merge_skills",
+    MergeSkillsUseCase,
+    fn execute(&self, source_id: Uuid, target_id: Uuid) -> Result<Skill, MergeSkillsError>
+);
@@ -0,0 +1,12 @@
+mod merge_skills_use_case;
+mod rename_skill_use_case;
+mod suggest_skills_use_case;
+
+pub use merge_skills_use_case::{MergeSkillsError, MergeSkillsUseCase};
+pub use rename_skill_use_case::{
+    RenameSkillCommand, RenameSkillError, RenameSkillUseCase, SkillNameError,
+};
+pub use suggest_skills_use_case::{
+    SkillQuery, SkillQueryError, SuggestSkillsError, SuggestSkillsUseCase,
+    DEFAULT_SUGGESTION_LIMIT, MAX_SKILL_QUERY_LENGTH, MAX_SUGGESTION_LIMIT,
+};
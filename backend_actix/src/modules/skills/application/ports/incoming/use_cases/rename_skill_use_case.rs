@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::shared::metrics::metered_use_case;
+use crate::skills::application::domain::entities::{Skill, MAX_SKILL_NAME_LENGTH};
+
+//
+// ──────────────────────────────────────────────────────────
+// Rename Skill Command
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, thiserror::Error)]
+pub enum SkillNameError {
+    #[error("Name must be 1-{MAX_SKILL_NAME_LENGTH} characters")]
+    InvalidName,
+}
+
+#[derive(Debug, Clone)]
+pub struct RenameSkillCommand {
+    name: String,
+}
+
+impl RenameSkillCommand {
+    pub fn new(name: String) -> Result<Self, SkillNameError> {
+        let name = name.trim();
+        if name.is_empty() || name.chars().count() > MAX_SKILL_NAME_LENGTH {
+            return Err(SkillNameError::InvalidName);
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Use Case Error
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum RenameSkillError {
+    #[error("Skill not found")]
+    NotFound,
+
+    #[error("Another skill already has this name; merge the two instead")]
+    NameTaken,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Incoming Port (Use Case)
+// ──────────────────────────────────────────────────────────
+//
+
+#[async_trait]
+pub trait RenameSkillUseCase: Send + Sync {
+    /// The old spelling stays an alias of the skill
+    async fn execute(
+        &self,
+        skill_id: Uuid,
+        command: RenameSkillCommand,
+    ) -> Result<Skill, RenameSkillError>;
+}
+
+metered_use_case!(
+    "skills",
+    "rename_skill",
+    RenameSkillUseCase,
+    fn execute(&self, skill_id: Uuid, command: RenameSkillCommand) -> Result<Skill, RenameSkillError>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_trims_the_name() {
+        let command = RenameSkillCommand::new(" PostgreSQL ".to_string()).unwrap();
+        assert_eq!(command.name(), "PostgreSQL");
+
+        assert!(RenameSkillCommand::new(" ".to_string()).is_err());
+        assert!(RenameSkillCommand::new("x".repeat(MAX_SKILL_NAME_LENGTH + 1)).is_err());
+    }
+}
@@ -0,0 +1,107 @@
+use async_trait::async_trait;
+
+use crate::shared::metrics::metered_use_case;
+use crate::skills::application::domain::entities::{skill_key, Skill};
+
+pub const MAX_SKILL_QUERY_LENGTH: usize = 50;
+pub const DEFAULT_SUGGESTION_LIMIT: u32 = 10;
+pub const MAX_SUGGESTION_LIMIT: u32 = 20;
+
+//
+// ──────────────────────────────────────────────────────────
+// Suggest Skills Query
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, thiserror::Error)]
+pub enum SkillQueryError {
+    #[error("Query must be 1-{MAX_SKILL_QUERY_LENGTH} characters")]
+    InvalidText,
+
+    #[error("Limit must be 1-{MAX_SUGGESTION_LIMIT}")]
+    InvalidLimit,
+}
+
+#[derive(Debug, Clone)]
+pub struct SkillQuery {
+    key: String,
+    limit: u32,
+}
+
+impl SkillQuery {
+    /// Matched on the lowercased text, like the stored spellings
+    pub fn new(text: String, limit: Option<u32>) -> Result<Self, SkillQueryError> {
+        let key = skill_key(&text);
+        if key.is_empty() || key.chars().count() > MAX_SKILL_QUERY_LENGTH {
+            return Err(SkillQueryError::InvalidText);
+        }
+
+        let limit = limit.unwrap_or(DEFAULT_SUGGESTION_LIMIT);
+        if !(1..=MAX_SUGGESTION_LIMIT).contains(&limit) {
+            return Err(SkillQueryError::InvalidLimit);
+        }
+
+        Ok(Self { key, limit })
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn limit(&self) -> u32 {
+        self.limit
+    }
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Use Case Error
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SuggestSkillsError {
+    #[error("Failed to suggest skills: {0}")]
+    QueryFailed(String),
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Incoming Port (Use Case)
+// ──────────────────────────────────────────────────────────
+//
+
+#[async_trait]
+pub trait SuggestSkillsUseCase: Send + Sync {
+    /// Skills whose name or one of whose aliases contains the text; names
+    /// starting with it come first
+    async fn execute(&self, query: SkillQuery) -> Result<Vec<Skill>, SuggestSkillsError>;
+}
+
+metered_use_case!(
+    "skills",
+    "suggest_skills",
+    SuggestSkillsUseCase,
+    fn execute(&self, query: SkillQuery) -> Result<Vec<Skill>, SuggestSkillsError>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_is_lowercased_and_bounded() {
+        let query = SkillQuery::new(" PostG ".to_string(), None).unwrap();
+        assert_eq!(query.key(), "postg");
+        assert_eq!(query.limit(), DEFAULT_SUGGESTION_LIMIT);
+
+        assert!(matches!(
+            SkillQuery::new("  ".to_string(), None),
+            Err(SkillQueryError::InvalidText)
+        ));
+        assert!(matches!(
+            SkillQuery::new("rust".to_string(), Some(MAX_SUGGESTION_LIMIT + 1)),
+            Err(SkillQueryError::InvalidLimit)
+        ));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod skill_repository;
+
+pub use skill_repository::{SkillRepository, SkillRepositoryError};
@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::skills::application::domain::entities::Skill;
+use crate::skills::application::ports::incoming::use_cases::{RenameSkillCommand, SkillQuery};
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SkillRepositoryError {
+    #[error("Skill not found")]
+    NotFound,
+
+    #[error("Another skill already has this name")]
+    NameTaken,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// The skill catalog. Skills are added by the database as CVs and projects
+/// name them; this port only reads and reshapes the catalog.
+#[async_trait]
+pub trait SkillRepository: Send + Sync {
+    async fn suggest(&self, query: &SkillQuery) -> Result<Vec<Skill>, SkillRepositoryError>;
+
+    /// Fails with `NameTaken` when the new spelling belongs to another skill,
+    /// as its name or an alias
+    async fn rename(
+        &self,
+        skill_id: Uuid,
+        command: &RenameSkillCommand,
+    ) -> Result<Skill, SkillRepositoryError>;
+
+    /// In one transaction; the ids are distinct
+    async fn merge(&self, source_id: Uuid, target_id: Uuid) -> Result<Skill, SkillRepositoryError>;
+}
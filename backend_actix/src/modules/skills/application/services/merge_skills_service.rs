@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::skills::application::domain::entities::Skill;
+use crate::skills::application::ports::{
+    incoming::use_cases::{MergeSkillsError, MergeSkillsUseCase},
+    outgoing::{SkillRepository, SkillRepositoryError},
+};
+
+pub struct MergeSkillsService<R>
+where
+    R: SkillRepository,
+{
+    repository: R,
+}
+
+impl<R> MergeSkillsService<R>
+where
+    R: SkillRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> MergeSkillsUseCase for MergeSkillsService<R>
+where
+    R: SkillRepository,
+{
+    async fn execute(&self, source_id: Uuid, target_id: Uuid) -> Result<Skill, MergeSkillsError> {
+        if source_id == target_id {
+            return Err(MergeSkillsError::SameSkill);
+        }
+
+        self.repository
+            .merge(source_id, target_id)
+            .await
+            .map_err(|e| match e {
+                SkillRepositoryError::NotFound => MergeSkillsError::NotFound,
+                other => MergeSkillsError::RepositoryError(other.to_string()),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skills::adapter::outgoing::InMemorySkillStore;
+    use crate::skills::application::ports::incoming::use_cases::SkillQuery;
+
+    #[tokio::test]
+    async fn test_merged_spellings_suggest_one_skill() {
+        let store = InMemorySkillStore::with_names(&["PostgreSQL", "postgresql", "Postgres"]);
+        let service = MergeSkillsService::new(store.clone());
+        let postgresql = store.find("postgresql").unwrap();
+        let postgres = store.find("postgres").unwrap();
+        assert_eq!(postgresql.name, "PostgreSQL");
+
+        let merged = service.execute(postgres.id, postgresql.id).await.unwrap();
+        assert_eq!(merged, postgresql);
+
+        let query = SkillQuery::new("postgres".to_string(), None).unwrap();
+        assert_eq!(
+            store.suggest(&query).await.unwrap(),
+            vec![postgresql.clone()]
+        );
+
+        assert!(matches!(
+            service.execute(postgresql.id, postgresql.id).await,
+            Err(MergeSkillsError::SameSkill)
+        ));
+        assert!(matches!(
+            service.execute(postgres.id, postgresql.id).await,
+            Err(MergeSkillsError::NotFound)
+        ));
+    }
+}
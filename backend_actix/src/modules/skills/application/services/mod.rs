@@ -0,0 +1,7 @@
+mod merge_skills_service;
+mod rename_skill_service;
+mod suggest_skills_service;
+
+pub use merge_skills_service::MergeSkillsService;
+pub use rename_skill_service::RenameSkillService;
+pub use suggest_skills_service::SuggestSkillsService;
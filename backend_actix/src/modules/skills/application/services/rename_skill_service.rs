@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::skills::application::domain::entities::Skill;
+use crate::skills::application::ports::{
+    incoming::use_cases::{RenameSkillCommand, RenameSkillError, RenameSkillUseCase},
+    outgoing::{SkillRepository, SkillRepositoryError},
+};
+
+pub struct RenameSkillService<R>
+where
+    R: SkillRepository,
+{
+    repository: R,
+}
+
+impl<R> RenameSkillService<R>
+where
+    R: SkillRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> RenameSkillUseCase for RenameSkillService<R>
+where
+    R: SkillRepository,
+{
+    async fn execute(
+        &self,
+        skill_id: Uuid,
+        command: RenameSkillCommand,
+    ) -> Result<Skill, RenameSkillError> {
+        self.repository
+            .rename(skill_id, &command)
+            .await
+            .map_err(|e| match e {
+                SkillRepositoryError::NotFound => RenameSkillError::NotFound,
+                SkillRepositoryError::NameTaken => RenameSkillError::NameTaken,
+                other => RenameSkillError::RepositoryError(other.to_string()),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skills::adapter::outgoing::InMemorySkillStore;
+    use crate::skills::application::ports::incoming::use_cases::SkillQuery;
+
+    #[tokio::test]
+    async fn test_old_spelling_stays_with_the_skill() {
+        let store = InMemorySkillStore::with_names(&["Postgres", "Rust"]);
+        let service = RenameSkillService::new(store.clone());
+        let postgres = store.find("postgres").unwrap();
+
+        let renamed = service
+            .execute(
+                postgres.id,
+                RenameSkillCommand::new("PostgreSQL".to_string()).unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(renamed.name, "PostgreSQL");
+
+        // Content still saying "postgres" doesn't bring the old skill back
+        store.record("postgres");
+        let query = SkillQuery::new("postgres".to_string(), None).unwrap();
+        assert_eq!(store.suggest(&query).await.unwrap(), vec![renamed]);
+
+        let result = service
+            .execute(
+                postgres.id,
+                RenameSkillCommand::new("rust".to_string()).unwrap(),
+            )
+            .await;
+        assert!(matches!(result, Err(RenameSkillError::NameTaken)));
+    }
+}
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+
+use crate::skills::application::domain::entities::Skill;
+use crate::skills::application::ports::{
+    incoming::use_cases::{SkillQuery, SuggestSkillsError, SuggestSkillsUseCase},
+    outgoing::SkillRepository,
+};
+
+pub struct SuggestSkillsService<R>
+where
+    R: SkillRepository,
+{
+    repository: R,
+}
+
+impl<R> SuggestSkillsService<R>
+where
+    R: SkillRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> SuggestSkillsUseCase for SuggestSkillsService<R>
+where
+    R: SkillRepository,
+{
+    async fn execute(&self, query: SkillQuery) -> Result<Vec<Skill>, SuggestSkillsError> {
+        self.repository
+            .suggest(&query)
+            .await
+            .map_err(|e| SuggestSkillsError::QueryFailed(e.to_string()))
+    }
+}
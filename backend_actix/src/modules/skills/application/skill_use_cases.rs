@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use crate::shared::metrics::Metered;
+use crate::skills::application::ports::incoming::use_cases::{
+    MergeSkillsUseCase, RenameSkillUseCase, SuggestSkillsUseCase,
+};
+use crate::skills::application::ports::outgoing::SkillRepository;
+use crate::skills::application::services::{
+    MergeSkillsService, RenameSkillService, SuggestSkillsService,
+};
+
+#[derive(Clone)]
+pub struct SkillUseCases {
+    pub suggest: Arc<dyn SuggestSkillsUseCase + Send + Sync>,
+    pub rename: Arc<dyn RenameSkillUseCase + Send + Sync>,
+    pub merge: Arc<dyn MergeSkillsUseCase + Send + Sync>,
+}
+
+impl SkillUseCases {
+    pub fn build<R>(repository: R) -> Self
+    where
+        R: SkillRepository + Clone + 'static,
+    {
+        Self {
+            suggest: Arc::new(Metered(SuggestSkillsService::new(repository.clone()))),
+            rename: Arc::new(Metered(RenameSkillService::new(repository.clone()))),
+            merge: Arc::new(Metered(MergeSkillsService::new(repository))),
+        }
+    }
+}
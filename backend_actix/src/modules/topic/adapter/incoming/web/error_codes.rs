@@ -0,0 +1,8 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    EMPTY_TITLE = (BAD_REQUEST, "Title cannot be empty");
+    TOPIC_NOT_FOUND = (NOT_FOUND, "Topic not found");
+    TOPIC_ALREADY_EXISTS = (CONFLICT, "Topic already exists");
+    FORBIDDEN = (FORBIDDEN, "You are not the owner of this topic");
+}
@@ -1 +1,11 @@
+use actix_web::web;
+
+pub mod error_codes;
 pub mod routes;
+
+/// Routes for signed-in users with a verified email
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::get_topics_handler)
+        .service(routes::create_topic_handler)
+        .service(routes::soft_delete_topic_handler);
+}
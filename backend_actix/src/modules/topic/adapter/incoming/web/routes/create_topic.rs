@@ -1,6 +1,11 @@
 use actix_web::{post, web, Responder};
 use serde::Deserialize;
+use utoipa::ToSchema;
 
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::shared::api::error_codes::TITLE_TOO_LONG;
+use crate::topic::adapter::incoming::web::error_codes::{EMPTY_TITLE, TOPIC_ALREADY_EXISTS};
+use crate::topic::application::ports::outgoing::TopicResult;
 use crate::{
     auth::{
         adapter::incoming::web::extractors::auth::VerifiedUser,
@@ -19,7 +24,7 @@ use crate::{
 // ──────────────────────────────────────────────────────────
 //
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 struct CreateTopicRequest {
     pub title: String,
     pub description: Option<String>,
@@ -31,6 +36,24 @@ struct CreateTopicRequest {
 // ──────────────────────────────────────────────────────────
 //
 
+/// Create a topic
+#[utoipa::path(
+    post,
+    path = "/api/topics",
+    tag = "topics",
+    request_body = CreateTopicRequest,
+    responses(
+        (status = 201, description = "Topic created", body = inline(SuccessResponse<TopicResult>)),
+        (status = 400, description = "Empty or too long title", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 409, description = "Topic already exists", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[post("/api/topics")]
 pub async fn create_topic_handler(
     user: VerifiedUser,
@@ -47,7 +70,7 @@ pub async fn create_topic_handler(
         };
 
     // 2️⃣ Execute use case
-    match data.create_topic_use_case.execute(command).await {
+    match data.topics.create.execute(command).await {
         Ok(topic) => ApiResponse::created(topic),
         Err(err) => map_create_topic_error(err),
     }
@@ -61,20 +84,16 @@ pub async fn create_topic_handler(
 
 fn map_command_error(err: CreateTopicCommandError) -> actix_web::HttpResponse {
     match err {
-        CreateTopicCommandError::EmptyTitle => {
-            ApiResponse::bad_request("EMPTY_TITLE", "Title cannot be empty")
-        }
+        CreateTopicCommandError::EmptyTitle => EMPTY_TITLE.response(),
         CreateTopicCommandError::TitleTooLong => {
-            ApiResponse::bad_request("TITLE_TOO_LONG", "Title must not exceed 100 characters")
+            TITLE_TOO_LONG.with_message("Title must not exceed 100 characters")
         }
     }
 }
 
 fn map_create_topic_error(err: CreateTopicError) -> actix_web::HttpResponse {
     match err {
-        CreateTopicError::TopicAlreadyExists => {
-            ApiResponse::conflict("TOPIC_ALREADY_EXISTS", "Topic already exists")
-        }
+        CreateTopicError::TopicAlreadyExists => TOPIC_ALREADY_EXISTS.response(),
         CreateTopicError::RepositoryError(_) => ApiResponse::internal_error(),
     }
 }
@@ -114,6 +133,7 @@ mod tests {
             &self,
             _user_id: Uuid,
             _is_verified: bool,
+            _token_version: i32,
         ) -> Result<String, TokenError> {
             unimplemented!("Not used in create_topic tests")
         }
@@ -122,6 +142,8 @@ mod tests {
             &self,
             _user_id: Uuid,
             _is_verified: bool,
+            _token_version: i32,
+            _remember_me: bool,
         ) -> Result<String, TokenError> {
             unimplemented!("Not used in create_topic tests")
         }
@@ -134,6 +156,11 @@ mod tests {
                 nbf: 0,
                 token_type: "access".to_string(),
                 is_verified: self.is_verified,
+                token_version: 0,
+                remember_me: false,
+                iss: String::new(),
+                aud: String::new(),
+                jti: None,
             })
         }
 
@@ -145,7 +172,7 @@ mod tests {
             unimplemented!("Not used in create_topic tests")
         }
 
-        fn verify_verification_token(&self, _token: &str) -> Result<Uuid, TokenError> {
+        fn verify_verification_token(&self, _token: &str) -> Result<TokenClaims, TokenError> {
             unimplemented!("Not used in create_topic tests")
         }
     }
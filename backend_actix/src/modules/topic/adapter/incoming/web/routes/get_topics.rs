@@ -1,29 +1,59 @@
-use actix_web::{get, web, Responder};
+use actix_web::{get, web, HttpRequest, Responder};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
 use crate::{
     auth::{
         adapter::incoming::web::extractors::auth::VerifiedUser,
         application::domain::entities::UserId,
     },
-    shared::api::ApiResponse,
+    shared::api::{
+        pagination::{PageParams, PagedResponse},
+        ApiResponse,
+    },
     topic::application::ports::incoming::use_cases::GetTopicsError,
     AppState,
 };
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 struct TopicResponse {
     id: Uuid,
     title: String,
     description: String,
 }
 
+/// List the current user's topics
+#[utoipa::path(
+    get,
+    path = "/api/topics",
+    tag = "topics",
+    params(
+        ("limit" = Option<u32>, Query, description = "Page size, 1-100 (default 20)"),
+        ("cursor" = Option<String>, Query, description = "`next_cursor` from the previous page"),
+    ),
+    responses(
+        (status = 200, description = "One page of topics (paging also in `X-Total-Count` and `Link`)", body = inline(SuccessResponse<PagedResponse<TopicResponse>>)),
+        (status = 400, description = "Invalid pagination parameters", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[get("/api/topics")]
-pub async fn get_topics_handler(user: VerifiedUser, data: web::Data<AppState>) -> impl Responder {
+pub async fn get_topics_handler(
+    req: HttpRequest,
+    user: VerifiedUser,
+    page: PageParams,
+    data: web::Data<AppState>,
+) -> impl Responder {
     let owner = user.user_id.clone();
 
-    match data.get_topics_use_case.execute(UserId::from(owner)).await {
+    match data.topics.get_list.execute(UserId::from(owner)).await {
         Ok(topics) => {
             let response = topics
                 .into_iter()
@@ -34,7 +64,7 @@ pub async fn get_topics_handler(user: VerifiedUser, data: web::Data<AppState>) -
                 })
                 .collect::<Vec<_>>();
 
-            ApiResponse::success(response)
+            PagedResponse::from_all(response, &page).respond(&req, &page)
         }
 
         Err(err) => map_get_topics_error(err),
@@ -78,6 +108,7 @@ mod tests {
             &self,
             _user_id: Uuid,
             _is_verified: bool,
+            _token_version: i32,
         ) -> Result<String, TokenError> {
             unimplemented!("Not used in get_topics tests")
         }
@@ -86,6 +117,8 @@ mod tests {
             &self,
             _user_id: Uuid,
             _is_verified: bool,
+            _token_version: i32,
+            _remember_me: bool,
         ) -> Result<String, TokenError> {
             unimplemented!("Not used in get_topics tests")
         }
@@ -98,6 +131,11 @@ mod tests {
                 nbf: 0,
                 token_type: "access".to_string(),
                 is_verified: true,
+                token_version: 0,
+                remember_me: false,
+                iss: String::new(),
+                aud: String::new(),
+                jti: None,
             })
         }
 
@@ -109,7 +147,7 @@ mod tests {
             unimplemented!("Not used in get_topics tests")
         }
 
-        fn verify_verification_token(&self, _token: &str) -> Result<Uuid, TokenError> {
+        fn verify_verification_token(&self, _token: &str) -> Result<TokenClaims, TokenError> {
             unimplemented!("Not used in get_topics tests")
         }
     }
@@ -174,10 +212,14 @@ mod tests {
 
         // Assert
         assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers().get("x-total-count").unwrap(), "2");
+        assert!(resp.headers().get("link").is_none());
 
         let json = read_json(resp).await;
         assert_eq!(json["success"], true);
-        assert_eq!(json["data"].as_array().unwrap().len(), 2);
+        assert_eq!(json["data"]["items"].as_array().unwrap().len(), 2);
+        assert_eq!(json["data"]["total"], 2);
+        assert!(json["data"]["next_cursor"].is_null());
     }
 
     #[actix_web::test]
@@ -1,6 +1,6 @@
 mod create_topic;
 mod get_topics;
 mod soft_delete_topic;
-pub use create_topic::create_topic_handler;
-pub use get_topics::get_topics_handler;
-pub use soft_delete_topic::soft_delete_topic_handler;
+pub use create_topic::{__path_create_topic_handler, create_topic_handler};
+pub use get_topics::{__path_get_topics_handler, get_topics_handler};
+pub use soft_delete_topic::{__path_soft_delete_topic_handler, soft_delete_topic_handler};
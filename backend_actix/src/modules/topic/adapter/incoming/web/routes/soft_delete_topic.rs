@@ -2,6 +2,8 @@
 use actix_web::{delete, web, Responder};
 use uuid::Uuid;
 
+use crate::api::schemas::ErrorResponse;
+use crate::topic::adapter::incoming::web::error_codes::{FORBIDDEN, TOPIC_NOT_FOUND};
 use crate::{
     auth::{
         adapter::incoming::web::extractors::auth::VerifiedUser,
@@ -18,6 +20,25 @@ use crate::{
 // ──────────────────────────────────────────────────────────
 //
 
+/// Soft delete a topic
+#[utoipa::path(
+    delete,
+    path = "/api/topics/{topic_id}",
+    tag = "topics",
+    params(
+        ("topic_id" = Uuid, Path, description = "Topic id"),
+    ),
+    responses(
+        (status = 204, description = "Topic deleted"),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified, or the topic belongs to someone else", body = ErrorResponse),
+        (status = 404, description = "Topic not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
 #[delete("/api/topics/{topic_id}")]
 pub async fn soft_delete_topic_handler(
     user: VerifiedUser,
@@ -27,11 +48,7 @@ pub async fn soft_delete_topic_handler(
     let owner = UserId::from(user.user_id);
     let topic_id = path.into_inner();
 
-    match data
-        .soft_delete_topic_use_case
-        .execute(owner, topic_id)
-        .await
-    {
+    match data.topics.soft_delete.execute(owner, topic_id).await {
         Ok(_) => ApiResponse::no_content(),
         Err(err) => map_soft_delete_topic_error(err),
     }
@@ -45,12 +62,8 @@ pub async fn soft_delete_topic_handler(
 
 fn map_soft_delete_topic_error(err: SoftDeleteTopicError) -> actix_web::HttpResponse {
     match err {
-        SoftDeleteTopicError::TopicNotFound => {
-            ApiResponse::not_found("TOPIC_NOT_FOUND", "Topic not found")
-        }
-        SoftDeleteTopicError::Forbidden => {
-            ApiResponse::forbidden("FORBIDDEN", "You are not the owner of this topic")
-        }
+        SoftDeleteTopicError::TopicNotFound => TOPIC_NOT_FOUND.response(),
+        SoftDeleteTopicError::Forbidden => FORBIDDEN.response(),
         SoftDeleteTopicError::DatabaseError(_) => ApiResponse::internal_error(),
     }
 }
@@ -93,6 +106,7 @@ mod tests {
             &self,
             _user_id: Uuid,
             _is_verified: bool,
+            _token_version: i32,
         ) -> Result<String, TokenError> {
             unimplemented!("Not used in soft_delete_topic tests")
         }
@@ -101,6 +115,8 @@ mod tests {
             &self,
             _user_id: Uuid,
             _is_verified: bool,
+            _token_version: i32,
+            _remember_me: bool,
         ) -> Result<String, TokenError> {
             unimplemented!("Not used in soft_delete_topic tests")
         }
@@ -113,6 +129,11 @@ mod tests {
                 nbf: 0,
                 token_type: "access".to_string(),
                 is_verified: self.is_verified,
+                token_version: 0,
+                remember_me: false,
+                iss: String::new(),
+                aud: String::new(),
+                jti: None,
             })
         }
 
@@ -124,7 +145,7 @@ mod tests {
             unimplemented!("Not used in soft_delete_topic tests")
         }
 
-        fn verify_verification_token(&self, _token: &str) -> Result<Uuid, TokenError> {
+        fn verify_verification_token(&self, _token: &str) -> Result<TokenClaims, TokenError> {
             unimplemented!("Not used in soft_delete_topic tests")
         }
     }
@@ -0,0 +1,152 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::modules::topic::application::ports::outgoing::{
+    CreateTopicData, TopicQuery, TopicQueryError, TopicQueryResult, TopicRepository,
+    TopicRepositoryError, TopicResult,
+};
+use crate::shared::in_memory::Table;
+
+pub(crate) struct TopicRow {
+    pub(crate) topic: TopicQueryResult,
+    /// Set while the topic is in the trash
+    pub(crate) deleted_at: Option<DateTime<Utc>>,
+}
+
+impl TopicRow {
+    fn to_repository_result(&self) -> TopicResult {
+        TopicResult {
+            id: self.topic.id,
+            owner: self.topic.owner,
+            title: self.topic.title.clone(),
+            description: self.topic.description.clone(),
+        }
+    }
+}
+
+/// Process-local topics, implementing both `TopicRepository` and
+/// `TopicQuery`. Titles are unique per owner, case-insensitively, deleted
+/// topics included, as in Postgres.
+#[derive(Clone, Default)]
+pub struct InMemoryTopicStore {
+    pub(crate) topics: Table<TopicRow>,
+}
+
+impl InMemoryTopicStore {
+    fn set_deleted_at(
+        &self,
+        topic_id: Uuid,
+        deleted_at: Option<DateTime<Utc>>,
+    ) -> Result<TopicResult, TopicRepositoryError> {
+        self.topics.write(|topics| {
+            let row = topics
+                .iter_mut()
+                .find(|row| row.topic.id == topic_id)
+                .ok_or(TopicRepositoryError::TopicNotFound)?;
+            row.deleted_at = deleted_at;
+            row.topic.updated_at = Utc::now();
+            Ok(row.to_repository_result())
+        })
+    }
+}
+
+#[async_trait]
+impl TopicRepository for InMemoryTopicStore {
+    async fn create_topic(
+        &self,
+        data: CreateTopicData,
+    ) -> Result<TopicResult, TopicRepositoryError> {
+        self.topics.write(|topics| {
+            let title = data.title.to_lowercase();
+            if topics
+                .iter()
+                .any(|row| row.topic.owner == data.owner && row.topic.title.to_lowercase() == title)
+            {
+                return Err(TopicRepositoryError::TopicAlreadyExists);
+            }
+
+            let now = Utc::now();
+            let row = TopicRow {
+                topic: TopicQueryResult {
+                    id: Uuid::new_v4(),
+                    owner: data.owner,
+                    title: data.title,
+                    description: data.description,
+                    created_at: now,
+                    updated_at: now,
+                },
+                deleted_at: None,
+            };
+            let result = row.to_repository_result();
+            topics.push(row);
+            Ok(result)
+        })
+    }
+
+    async fn restore_topic(&self, topic_id: Uuid) -> Result<TopicResult, TopicRepositoryError> {
+        self.set_deleted_at(topic_id, None)
+    }
+
+    async fn soft_delete_topic(&self, topic_id: Uuid) -> Result<(), TopicRepositoryError> {
+        self.set_deleted_at(topic_id, Some(Utc::now())).map(|_| ())
+    }
+}
+
+#[async_trait]
+impl TopicQuery for InMemoryTopicStore {
+    /// The owner's live topics, newest first
+    async fn get_topics(&self, owner: UserId) -> Result<Vec<TopicQueryResult>, TopicQueryError> {
+        let mut topics: Vec<TopicQueryResult> = self.topics.read(|topics| {
+            topics
+                .iter()
+                .filter(|row| row.topic.owner == owner && row.deleted_at.is_none())
+                .map(|row| row.topic.clone())
+                .collect()
+        });
+        topics.sort_by_key(|topic| std::cmp::Reverse(topic.created_at));
+        Ok(topics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn topic(owner: UserId, title: &str) -> CreateTopicData {
+        CreateTopicData {
+            owner,
+            title: title.to_string(),
+            description: String::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_title_is_unique_per_owner_case_insensitively() {
+        let store = InMemoryTopicStore::default();
+        let owner = UserId::from(Uuid::new_v4());
+
+        store.create_topic(topic(owner, "Rust")).await.unwrap();
+
+        assert!(matches!(
+            store.create_topic(topic(owner, "rust")).await,
+            Err(TopicRepositoryError::TopicAlreadyExists)
+        ));
+        let other = UserId::from(Uuid::new_v4());
+        assert!(store.create_topic(topic(other, "Rust")).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_deleted_topics_are_hidden_until_restored() {
+        let store = InMemoryTopicStore::default();
+        let owner = UserId::from(Uuid::new_v4());
+        let created = store.create_topic(topic(owner, "Rust")).await.unwrap();
+
+        store.soft_delete_topic(created.id).await.unwrap();
+        assert!(store.get_topics(owner).await.unwrap().is_empty());
+
+        store.restore_topic(created.id).await.unwrap();
+        assert_eq!(store.get_topics(owner).await.unwrap().len(), 1);
+    }
+}
@@ -4,3 +4,6 @@ mod topic_repository_postgres;
 
 pub use topic_query_postgres::TopicQueryPostgres;
 pub use topic_repository_postgres::TopicRepositoryPostgres;
+
+mod in_memory;
+pub use in_memory::InMemoryTopicStore;
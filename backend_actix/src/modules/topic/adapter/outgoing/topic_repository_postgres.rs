@@ -6,6 +6,7 @@ use uuid::Uuid;
 use crate::modules::topic::application::ports::outgoing::{
     CreateTopicData, TopicRepository, TopicRepositoryError, TopicResult,
 };
+use crate::shared::unit_of_work::connection;
 
 // SeaORM entity imports
 use super::sea_orm_entity::topics::{ActiveModel as TopicActiveModel, Model as TopicModel};
@@ -37,7 +38,7 @@ impl TopicRepository for TopicRepositoryPostgres {
         };
 
         let inserted: TopicModel = active
-            .insert(&*self.db)
+            .insert(&connection(&self.db))
             .await
             .map_err(|e| TopicRepositoryError::DatabaseError(e.to_string()))?;
 
@@ -52,7 +53,7 @@ impl TopicRepository for TopicRepositoryPostgres {
         };
 
         let result = active
-            .update(&*self.db)
+            .update(&connection(&self.db))
             .await
             .map_err(|e| TopicRepositoryError::DatabaseError(e.to_string()))?;
 
@@ -72,7 +73,7 @@ impl TopicRepository for TopicRepositoryPostgres {
         };
 
         let result = active
-            .update(&*self.db)
+            .update(&connection(&self.db))
             .await
             .map_err(|e| TopicRepositoryError::DatabaseError(e.to_string()))?;
 
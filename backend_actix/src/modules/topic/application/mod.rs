@@ -1,3 +1,4 @@
 pub mod domain;
 pub mod ports;
 pub mod services;
+pub mod topic_use_cases;
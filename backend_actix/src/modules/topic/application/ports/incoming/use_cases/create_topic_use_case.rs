@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 
+use crate::shared::metrics::metered_use_case;
 use crate::{
     auth::application::domain::entities::UserId, topic::application::ports::outgoing::TopicResult,
 };
@@ -87,3 +88,10 @@ pub enum CreateTopicError {
 pub trait CreateTopicUseCase: Send + Sync {
     async fn execute(&self, command: CreateTopicCommand) -> Result<TopicResult, CreateTopicError>;
 }
+
+metered_use_case!(
+    "topic",
+    "create_topic",
+    CreateTopicUseCase,
+    fn execute(&self, command: CreateTopicCommand) -> Result<TopicResult, CreateTopicError>
+);
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 
+use crate::shared::metrics::metered_use_case;
 use crate::{
     auth::application::domain::entities::UserId,
     topic::application::ports::outgoing::TopicQueryResult,
@@ -15,3 +16,10 @@ pub enum GetTopicsError {
 pub trait GetTopicsUseCase: Send + Sync {
     async fn execute(&self, owner: UserId) -> Result<Vec<TopicQueryResult>, GetTopicsError>;
 }
+
+metered_use_case!(
+    "topic",
+    "get_topics",
+    GetTopicsUseCase,
+    fn execute(&self, owner: UserId) -> Result<Vec<TopicQueryResult>, GetTopicsError>
+);
@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
+use crate::shared::metrics::metered_use_case;
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum SoftDeleteTopicError {
@@ -19,3 +20,10 @@ pub enum SoftDeleteTopicError {
 pub trait SoftDeleteTopicUseCase: Send + Sync {
     async fn execute(&self, owner: UserId, topic_id: Uuid) -> Result<(), SoftDeleteTopicError>;
 }
+
+metered_use_case!(
+    "topic",
+    "soft_delete_topic",
+    SoftDeleteTopicUseCase,
+    fn execute(&self, owner: UserId, topic_id: Uuid) -> Result<(), SoftDeleteTopicError>
+);
@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
+use crate::shared::faults::faulty_repository;
 
 /// Read-only DTO for topic queries
 /// Contains all persisted fields except `is_deleted`
@@ -25,3 +26,9 @@ pub enum TopicQueryError {
 pub trait TopicQuery: Send + Sync {
     async fn get_topics(&self, owner: UserId) -> Result<Vec<TopicQueryResult>, TopicQueryError>;
 }
+
+faulty_repository! {
+    TopicQuery,
+    TopicQueryError::DatabaseError,
+    fn get_topics(&self, owner: UserId) -> Result<Vec<TopicQueryResult>, TopicQueryError>;
+}
@@ -1,8 +1,10 @@
 use async_trait::async_trait;
 use serde::Serialize;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
+use crate::shared::faults::faulty_repository;
 
 // Input DTO for creating a user
 #[derive(Debug, Clone)]
@@ -14,7 +16,7 @@ pub struct CreateTopicData {
 
 // Unified output DTO for all user operations that return user data
 // This represents the essential user information after any state change
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct TopicResult {
     pub id: Uuid,
     pub owner: UserId,
@@ -45,3 +47,11 @@ pub trait TopicRepository: Send + Sync {
 
     async fn soft_delete_topic(&self, topic_id: Uuid) -> Result<(), TopicRepositoryError>;
 }
+
+faulty_repository! {
+    TopicRepository,
+    TopicRepositoryError::DatabaseError,
+    fn create_topic(&self, data: CreateTopicData) -> Result<TopicResult, TopicRepositoryError>;
+    fn restore_topic(&self, topic_id: Uuid) -> Result<TopicResult, TopicRepositoryError>;
+    fn soft_delete_topic(&self, topic_id: Uuid) -> Result<(), TopicRepositoryError>;
+}
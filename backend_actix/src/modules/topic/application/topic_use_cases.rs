@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use crate::shared::metrics::Metered;
+use crate::topic::application::ports::incoming::use_cases::{
+    CreateTopicUseCase, GetTopicsUseCase, SoftDeleteTopicUseCase,
+};
+use crate::topic::application::ports::outgoing::{TopicQuery, TopicRepository};
+use crate::topic::application::services::{
+    CreateTopicService, GetTopicsService, SoftDeleteTopicService,
+};
+
+#[derive(Clone)]
+pub struct TopicUseCases {
+    pub create: Arc<dyn CreateTopicUseCase + Send + Sync>,
+    pub get_list: Arc<dyn GetTopicsUseCase + Send + Sync>,
+    pub soft_delete: Arc<dyn SoftDeleteTopicUseCase + Send + Sync>,
+}
+
+impl TopicUseCases {
+    pub fn build<R, Q>(repository: R, query: Q) -> Self
+    where
+        R: TopicRepository + Clone + 'static,
+        Q: TopicQuery + Clone + 'static,
+    {
+        Self {
+            create: Arc::new(Metered(CreateTopicService::new(repository.clone()))),
+            get_list: Arc::new(Metered(GetTopicsService::new(query.clone()))),
+            soft_delete: Arc::new(Metered(SoftDeleteTopicService::new(query, repository))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::application::domain::entities::UserId;
+    use crate::topic::adapter::outgoing::InMemoryTopicStore;
+    use crate::topic::application::ports::incoming::use_cases::CreateTopicCommand;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_use_cases_share_the_store() {
+        let store = InMemoryTopicStore::default();
+        let topics = TopicUseCases::build(store.clone(), store);
+        let owner = UserId::from(Uuid::new_v4());
+
+        let created = topics
+            .create
+            .execute(CreateTopicCommand::new(owner, "Rust".to_string(), None).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(topics.get_list.execute(owner).await.unwrap().len(), 1);
+
+        topics.soft_delete.execute(owner, created.id).await.unwrap();
+        assert!(topics.get_list.execute(owner).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,8 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    FIELD_TOO_LONG = (BAD_REQUEST, "A translated field is too long");
+    NO_FIELDS = (BAD_REQUEST, "At least one field is required");
+    CONTENT_NOT_FOUND = (NOT_FOUND, "Content not found");
+    TRANSLATION_NOT_FOUND = (NOT_FOUND, "Translation not found");
+}
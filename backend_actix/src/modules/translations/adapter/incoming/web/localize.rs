@@ -0,0 +1,126 @@
+//! Serving public content in the visitor's locale.
+//!
+//! Public handlers look up the translations of what they serve, swap in the
+//! translated fields and mark the response with [`mark_localized`]; for a
+//! single item [`respond_localized`] does all of it. A failed lookup is
+//! logged and the original is served: a missing translation is never worth
+//! an error page.
+
+use actix_web::{
+    http::header::{HeaderValue, ACCEPT_LANGUAGE, CONTENT_LANGUAGE, VARY},
+    web, HttpRequest, HttpResponse,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::shared::api::{cache::CachePolicy, etag::ETag, owner_view::OwnerView};
+use crate::translations::application::domain::entities::{
+    Translatable, TranslatableKind, Translation,
+};
+use crate::translations::application::domain::negotiation::LocalePreferences;
+use crate::AppState;
+
+#[derive(Debug, Deserialize)]
+struct LangQuery {
+    lang: Option<String>,
+}
+
+/// `?lang=`, then `Accept-Language`
+pub fn locale_preferences(req: &HttpRequest) -> LocalePreferences {
+    let lang = web::Query::<LangQuery>::from_query(req.query_string())
+        .ok()
+        .and_then(|query| query.into_inner().lang);
+    let accept_language = req
+        .headers()
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok());
+
+    LocalePreferences::new(lang.as_deref(), accept_language)
+}
+
+/// The translation each item is served in; empty if the lookup fails
+pub async fn translations_for(
+    data: &AppState,
+    kind: TranslatableKind,
+    content_ids: &[Uuid],
+    preferences: &LocalePreferences,
+) -> HashMap<Uuid, Translation> {
+    data.translations
+        .localize
+        .execute(kind, content_ids, preferences)
+        .await
+        .unwrap_or_else(|e| {
+            warn!("Serving {:?} untranslated: {}", kind, e);
+            HashMap::new()
+        })
+}
+
+/// Caches must key the response on `Accept-Language`; `Content-Language`
+/// names the translation served, if any
+pub fn mark_localized(resp: &mut HttpResponse, locale: Option<&str>) {
+    resp.headers_mut()
+        .append(VARY, HeaderValue::from_static("Accept-Language"));
+    if let Some(value) = locale.and_then(|locale| HeaderValue::from_str(locale).ok()) {
+        resp.headers_mut().insert(CONTENT_LANGUAGE, value);
+    }
+}
+
+/// One item in the visitor's locale, with an `ETag` that changes with the
+/// translation as well as the item
+pub async fn respond_localized<T: Translatable + Serialize>(
+    req: &HttpRequest,
+    data: &AppState,
+    kind: TranslatableKind,
+    id: Uuid,
+    updated_at: DateTime<Utc>,
+    item: T,
+) -> HttpResponse {
+    localized(req, data, kind, id, updated_at, item, "").await
+}
+
+/// [`respond_localized`] for the owner browsing their own content: never
+/// stored, and under an `ETag` of its own so a copy cached for visitors
+/// can't be revalidated in its place
+pub async fn respond_localized_to_owner<T: Translatable + Serialize>(
+    req: &HttpRequest,
+    data: &AppState,
+    kind: TranslatableKind,
+    id: Uuid,
+    updated_at: DateTime<Utc>,
+    item: OwnerView<T>,
+) -> HttpResponse {
+    let mut resp = localized(req, data, kind, id, updated_at, item, "owner:").await;
+    CachePolicy::NoStore.apply(&mut resp);
+    resp
+}
+
+async fn localized<T: Translatable + Serialize>(
+    req: &HttpRequest,
+    data: &AppState,
+    kind: TranslatableKind,
+    id: Uuid,
+    updated_at: DateTime<Utc>,
+    mut item: T,
+    etag_prefix: &str,
+) -> HttpResponse {
+    let translation = translations_for(data, kind, &[id], &locale_preferences(req))
+        .await
+        .remove(&id);
+    let etag = match &translation {
+        Some(translation) => {
+            item.translate(translation);
+            ETag::from_version(
+                format!("{etag_prefix}{id}:{}", translation.locale),
+                updated_at.max(translation.updated_at),
+            )
+        }
+        None => ETag::from_version(format!("{etag_prefix}{id}"), updated_at),
+    };
+
+    let mut resp = etag.respond(req, item);
+    mark_localized(&mut resp, translation.as_ref().map(|t| t.locale.as_str()));
+    resp
+}
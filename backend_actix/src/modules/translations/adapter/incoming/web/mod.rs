@@ -0,0 +1,12 @@
+use actix_web::web;
+
+pub mod error_codes;
+pub mod localize;
+pub mod routes;
+
+/// Routes for signed-in users with a verified email
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::list_translations_handler)
+        .service(routes::put_translation_handler)
+        .service(routes::delete_translation_handler);
+}
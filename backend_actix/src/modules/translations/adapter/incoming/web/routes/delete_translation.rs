@@ -0,0 +1,121 @@
+use actix_web::{delete, web, Responder};
+use tracing::error;
+
+use super::TranslationPath;
+use crate::api::schemas::ErrorResponse;
+use crate::translations::adapter::incoming::web::error_codes::TRANSLATION_NOT_FOUND;
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::VerifiedUser,
+    shared::api::ApiResponse,
+    translations::application::{
+        domain::entities::TranslatableKind, ports::incoming::use_cases::DeleteTranslationError,
+    },
+    AppState,
+};
+
+/// Remove a translation; the item is served in the original again
+#[utoipa::path(
+    delete,
+    path = "/api/translations/{kind}/{content_id}/{locale}",
+    tag = "translations",
+    params(
+        ("kind" = TranslatableKind, Path, description = "`project` or `page`"),
+        ("content_id" = Uuid, Path, description = "Project or page id"),
+        ("locale" = String, Path, description = "Locale of the translation"),
+    ),
+    responses(
+        (status = 204, description = "Translation deleted"),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Content or translation not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[delete("/api/translations/{kind}/{content_id}/{locale}")]
+pub async fn delete_translation_handler(
+    user: VerifiedUser,
+    path: web::Path<TranslationPath>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let path = path.into_inner();
+
+    match data
+        .translations
+        .delete
+        .execute(user.user_id, path.kind, path.content_id, &path.locale)
+        .await
+    {
+        Ok(()) => ApiResponse::no_content(),
+        Err(DeleteTranslationError::NotFound) => TRANSLATION_NOT_FOUND.response(),
+        Err(DeleteTranslationError::RepositoryError(msg)) => {
+            error!(
+                "Failed to delete {} translation of {:?} {}: {}",
+                path.locale, path.kind, path.content_id, msg
+            );
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service,
+            stubs::StubDeleteTranslationUseCase,
+        },
+    };
+
+    async fn call(state: web::Data<AppState>) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(delete_translation_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/translations/page/{}/id", Uuid::new_v4()))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_translation_is_deleted() {
+        let state = TestAppStateBuilder::default().build();
+
+        let resp = call(state).await;
+
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[actix_web::test]
+    async fn test_missing_translation_is_not_found() {
+        let state = TestAppStateBuilder::default()
+            .with_delete_translation(StubDeleteTranslationUseCase::not_found())
+            .build();
+
+        let resp = call(state).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "TRANSLATION_NOT_FOUND");
+    }
+}
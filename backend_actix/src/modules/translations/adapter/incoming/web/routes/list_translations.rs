@@ -0,0 +1,123 @@
+use actix_web::{get, web, Responder};
+use tracing::error;
+
+use super::{content_not_found, ContentPath};
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::VerifiedUser,
+    shared::api::ApiResponse,
+    translations::application::{
+        domain::entities::{TranslatableKind, Translation},
+        ports::incoming::use_cases::ListTranslationsError,
+    },
+    AppState,
+};
+
+/// Every translation of one of the caller's projects or pages
+#[utoipa::path(
+    get,
+    path = "/api/translations/{kind}/{content_id}",
+    tag = "translations",
+    params(
+        ("kind" = TranslatableKind, Path, description = "`project` or `page`"),
+        ("content_id" = Uuid, Path, description = "Project or page id"),
+    ),
+    responses(
+        (status = 200, description = "Translations by locale", body = inline(SuccessResponse<Vec<Translation>>)),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Content not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/translations/{kind}/{content_id}")]
+pub async fn list_translations_handler(
+    user: VerifiedUser,
+    path: web::Path<ContentPath>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let path = path.into_inner();
+
+    match data
+        .translations
+        .list
+        .execute(user.user_id, path.kind, path.content_id)
+        .await
+    {
+        Ok(translations) => ApiResponse::success(translations),
+        Err(ListTranslationsError::ContentNotFound) => content_not_found(),
+        Err(ListTranslationsError::RepositoryError(msg)) => {
+            error!(
+                "Failed to list translations of {:?} {}: {}",
+                path.kind, path.content_id, msg
+            );
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubListTranslationsUseCase,
+        },
+    };
+
+    async fn call(state: web::Data<AppState>, uri: &str) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(list_translations_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_translations_are_listed() {
+        let state = TestAppStateBuilder::default().build();
+
+        let resp = call(state, &format!("/api/translations/page/{}", Uuid::new_v4())).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"][0]["locale"], "id");
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_content_is_not_found() {
+        let state = TestAppStateBuilder::default()
+            .with_list_translations(StubListTranslationsUseCase::content_not_found())
+            .build();
+
+        let resp = call(
+            state,
+            &format!("/api/translations/project/{}", Uuid::new_v4()),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
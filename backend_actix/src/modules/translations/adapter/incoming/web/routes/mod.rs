@@ -0,0 +1,31 @@
+mod delete_translation;
+mod list_translations;
+mod put_translation;
+
+pub use delete_translation::{__path_delete_translation_handler, delete_translation_handler};
+pub use list_translations::{__path_list_translations_handler, list_translations_handler};
+pub use put_translation::{__path_put_translation_handler, put_translation_handler};
+
+use actix_web::HttpResponse;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::translations::adapter::incoming::web::error_codes::CONTENT_NOT_FOUND;
+use crate::translations::application::domain::entities::TranslatableKind;
+
+#[derive(Debug, Deserialize)]
+pub struct ContentPath {
+    pub kind: TranslatableKind,
+    pub content_id: Uuid,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TranslationPath {
+    pub kind: TranslatableKind,
+    pub content_id: Uuid,
+    pub locale: String,
+}
+
+fn content_not_found() -> HttpResponse {
+    CONTENT_NOT_FOUND.response()
+}
@@ -0,0 +1,187 @@
+use actix_web::{put, web, Responder};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tracing::error;
+use utoipa::ToSchema;
+
+use super::{content_not_found, TranslationPath};
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::shared::api::error_codes::{INVALID_LOCALE, UNKNOWN_FIELD};
+use crate::translations::adapter::incoming::web::error_codes::{FIELD_TOO_LONG, NO_FIELDS};
+use crate::{
+    auth::adapter::incoming::web::extractors::auth::VerifiedUser,
+    shared::api::ApiResponse,
+    translations::application::{
+        domain::entities::{TranslatableKind, Translation},
+        ports::incoming::use_cases::{
+            PutTranslationCommand, PutTranslationError, TranslationCommandError,
+        },
+    },
+    AppState,
+};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct PutTranslationRequest {
+    /// Projects: `title`, `description`. Pages: `title`, `body`, `seo_title`,
+    /// `seo_description`. Blank or missing fields are served in the original.
+    pub fields: BTreeMap<String, String>,
+}
+
+/// Translate one of the caller's projects or pages into a locale
+///
+/// Replaces any earlier translation into that locale as a whole.
+#[utoipa::path(
+    put,
+    path = "/api/translations/{kind}/{content_id}/{locale}",
+    tag = "translations",
+    params(
+        ("kind" = TranslatableKind, Path, description = "`project` or `page`"),
+        ("content_id" = Uuid, Path, description = "Project or page id"),
+        ("locale" = String, Path, description = "`ll` or `ll-RR`, e.g. `id` or `pt-BR`"),
+    ),
+    request_body = PutTranslationRequest,
+    responses(
+        (status = 200, description = "Translation saved", body = inline(SuccessResponse<Translation>)),
+        (status = 400, description = "Invalid locale, unknown or too long field, or no fields", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Content not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[put("/api/translations/{kind}/{content_id}/{locale}")]
+pub async fn put_translation_handler(
+    user: VerifiedUser,
+    path: web::Path<TranslationPath>,
+    payload: web::Json<PutTranslationRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let path = path.into_inner();
+    let command =
+        match PutTranslationCommand::new(path.kind, &path.locale, payload.into_inner().fields) {
+            Ok(command) => command,
+            Err(err) => {
+                let code = match &err {
+                    TranslationCommandError::InvalidLocale => INVALID_LOCALE,
+                    TranslationCommandError::UnknownField(_) => UNKNOWN_FIELD,
+                    TranslationCommandError::FieldTooLong { .. } => FIELD_TOO_LONG,
+                    TranslationCommandError::NoFields => NO_FIELDS,
+                };
+                return code.with_message(&err.to_string());
+            }
+        };
+
+    match data
+        .translations
+        .put
+        .execute(user.user_id, path.content_id, command)
+        .await
+    {
+        Ok(translation) => ApiResponse::success(translation),
+        Err(PutTranslationError::ContentNotFound) => content_not_found(),
+        Err(PutTranslationError::RepositoryError(msg)) => {
+            error!(
+                "Failed to save {} translation of {:?} {}: {}",
+                path.locale, path.kind, path.content_id, msg
+            );
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use serde_json::json;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubPutTranslationUseCase,
+        },
+    };
+
+    async fn call(
+        state: web::Data<AppState>,
+        uri: &str,
+        body: serde_json::Value,
+    ) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(put_translation_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::put()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(body)
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_translation_is_saved() {
+        let state = TestAppStateBuilder::default().build();
+
+        let resp = call(
+            state,
+            &format!("/api/translations/project/{}/pt_br", Uuid::new_v4()),
+            json!({ "fields": { "title": "Projeto" } }),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["locale"], "pt-BR");
+        assert_eq!(json["data"]["fields"]["title"], "Projeto");
+    }
+
+    #[actix_web::test]
+    async fn test_field_of_another_kind_is_rejected() {
+        let state = TestAppStateBuilder::default().build();
+
+        let resp = call(
+            state,
+            &format!("/api/translations/project/{}/id", Uuid::new_v4()),
+            json!({ "fields": { "body": "Isi" } }),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "UNKNOWN_FIELD");
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_content_is_not_found() {
+        let state = TestAppStateBuilder::default()
+            .with_put_translation(StubPutTranslationUseCase::content_not_found())
+            .build();
+
+        let resp = call(
+            state,
+            &format!("/api/translations/page/{}/id", Uuid::new_v4()),
+            json!({ "fields": { "title": "Tentang" } }),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "CONTENT_NOT_FOUND");
+    }
+}
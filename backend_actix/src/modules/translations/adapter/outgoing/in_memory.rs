@@ -0,0 +1,153 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::pages::adapter::outgoing::InMemoryPageStore;
+use crate::project::adapter::outgoing::InMemoryProjectStore;
+use crate::shared::in_memory::Table;
+use crate::translations::application::domain::entities::{TranslatableKind, Translation};
+use crate::translations::application::ports::incoming::use_cases::PutTranslationCommand;
+use crate::translations::application::ports::outgoing::{
+    TranslationRepository, TranslationRepositoryError,
+};
+
+struct TranslationRow {
+    kind: TranslatableKind,
+    content_id: Uuid,
+    translation: Translation,
+}
+
+/// Process-local `TranslationRepository`. Items are checked against the
+/// project and page stores it was built with; translations of deleted items
+/// are not cleaned up, but nothing reads them.
+#[derive(Clone)]
+pub struct InMemoryTranslationStore {
+    translations: Table<TranslationRow>,
+    projects: InMemoryProjectStore,
+    pages: InMemoryPageStore,
+}
+
+impl InMemoryTranslationStore {
+    pub fn new(projects: InMemoryProjectStore, pages: InMemoryPageStore) -> Self {
+        Self {
+            translations: Table::default(),
+            projects,
+            pages,
+        }
+    }
+
+    /// Like the Postgres adapter: live items of the owner only
+    fn owns(&self, owner_id: Uuid, kind: TranslatableKind, content_id: Uuid) -> bool {
+        match kind {
+            TranslatableKind::Project => self.projects.projects.read(|rows| {
+                rows.iter().any(|row| {
+                    row.project.id == content_id
+                        && row.project.owner == UserId::from(owner_id)
+                        && row.deleted_at.is_none()
+                })
+            }),
+            TranslatableKind::Page => self.pages.pages.read(|pages| {
+                pages
+                    .iter()
+                    .any(|page| page.id == content_id && page.owner_id == owner_id)
+            }),
+        }
+    }
+
+    fn of(&self, kind: TranslatableKind, content_id: Uuid) -> Vec<Translation> {
+        let mut translations: Vec<Translation> = self.translations.read(|rows| {
+            rows.iter()
+                .filter(|row| row.kind == kind && row.content_id == content_id)
+                .map(|row| row.translation.clone())
+                .collect()
+        });
+        translations.sort_by(|a, b| a.locale.cmp(&b.locale));
+        translations
+    }
+}
+
+#[async_trait]
+impl TranslationRepository for InMemoryTranslationStore {
+    async fn put(
+        &self,
+        owner_id: Uuid,
+        content_id: Uuid,
+        command: &PutTranslationCommand,
+    ) -> Result<Translation, TranslationRepositoryError> {
+        let kind = command.kind();
+        if !self.owns(owner_id, kind, content_id) {
+            return Err(TranslationRepositoryError::ContentNotFound);
+        }
+
+        let translation = Translation {
+            locale: command.locale().to_string(),
+            fields: command.fields().clone(),
+            updated_at: Utc::now(),
+        };
+        self.translations.write(|rows| {
+            rows.retain(|row| {
+                !(row.kind == kind
+                    && row.content_id == content_id
+                    && row.translation.locale == translation.locale)
+            });
+            rows.push(TranslationRow {
+                kind,
+                content_id,
+                translation: translation.clone(),
+            });
+        });
+        Ok(translation)
+    }
+
+    async fn list(
+        &self,
+        owner_id: Uuid,
+        kind: TranslatableKind,
+        content_id: Uuid,
+    ) -> Result<Vec<Translation>, TranslationRepositoryError> {
+        if !self.owns(owner_id, kind, content_id) {
+            return Err(TranslationRepositoryError::ContentNotFound);
+        }
+        Ok(self.of(kind, content_id))
+    }
+
+    async fn delete(
+        &self,
+        owner_id: Uuid,
+        kind: TranslatableKind,
+        content_id: Uuid,
+        locale: &str,
+    ) -> Result<(), TranslationRepositoryError> {
+        if !self.owns(owner_id, kind, content_id) {
+            return Err(TranslationRepositoryError::NotFound);
+        }
+
+        let removed = self.translations.write(|rows| {
+            let before = rows.len();
+            rows.retain(|row| {
+                !(row.kind == kind
+                    && row.content_id == content_id
+                    && row.translation.locale == locale)
+            });
+            before - rows.len()
+        });
+        if removed == 0 {
+            return Err(TranslationRepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn for_content(
+        &self,
+        kind: TranslatableKind,
+        content_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<Translation>>, TranslationRepositoryError> {
+        Ok(content_ids
+            .iter()
+            .map(|&id| (id, self.of(kind, id)))
+            .filter(|(_, translations)| !translations.is_empty())
+            .collect())
+    }
+}
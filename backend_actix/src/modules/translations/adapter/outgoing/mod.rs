@@ -0,0 +1,5 @@
+mod in_memory;
+mod translation_repository_postgres;
+
+pub use in_memory::InMemoryTranslationStore;
+pub use translation_repository_postgres::TranslationRepositoryPostgres;
@@ -0,0 +1,413 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement,
+    TransactionTrait, Value,
+};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::translations::application::domain::entities::{TranslatableKind, Translation};
+use crate::translations::application::ports::incoming::use_cases::PutTranslationCommand;
+use crate::translations::application::ports::outgoing::{
+    TranslationRepository, TranslationRepositoryError,
+};
+
+/// `content_translations` has one row per item, locale and field, with the
+/// item in `project_id` or `page_id`
+#[derive(Clone)]
+pub struct TranslationRepositoryPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl TranslationRepositoryPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn column(kind: TranslatableKind) -> &'static str {
+        match kind {
+            TranslatableKind::Project => "project_id",
+            TranslatableKind::Page => "page_id",
+        }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    /// A row if the item is the owner's and not in the trash
+    fn owned_stmt(
+        backend: DatabaseBackend,
+        owner_id: Uuid,
+        kind: TranslatableKind,
+        content_id: Uuid,
+    ) -> Statement {
+        let sql = match kind {
+            TranslatableKind::Project => {
+                "SELECT id FROM projects WHERE id = $1 AND user_id = $2 AND is_deleted = false"
+            }
+            TranslatableKind::Page => "SELECT id FROM pages WHERE id = $1 AND user_id = $2",
+        };
+        Statement::from_sql_and_values(backend, sql, vec![content_id.into(), owner_id.into()])
+    }
+
+    fn delete_stmt(
+        backend: DatabaseBackend,
+        kind: TranslatableKind,
+        content_id: Uuid,
+        locale: &str,
+    ) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                "DELETE FROM content_translations WHERE {} = $1 AND locale = $2",
+                Self::column(kind)
+            ),
+            vec![content_id.into(), locale.into()],
+        )
+    }
+
+    /// One row per field; the command always has at least one
+    fn insert_stmt(
+        backend: DatabaseBackend,
+        content_id: Uuid,
+        command: &PutTranslationCommand,
+    ) -> Statement {
+        let mut rows = Vec::new();
+        let mut values: Vec<Value> = vec![content_id.into(), command.locale().into()];
+        for (field, text) in command.fields() {
+            values.push(Uuid::new_v4().into());
+            values.push(field.as_str().into());
+            values.push(text.as_str().into());
+            let n = values.len();
+            rows.push(format!("(${}, $1, $2, ${}, ${})", n - 2, n - 1, n));
+        }
+
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                INSERT INTO content_translations (id, {column}, locale, field, value)
+                VALUES {rows}
+                RETURNING locale, field, value, updated_at
+                "#,
+                column = Self::column(command.kind()),
+                rows = rows.join(", "),
+            ),
+            values,
+        )
+    }
+
+    fn list_stmt(
+        backend: DatabaseBackend,
+        kind: TranslatableKind,
+        content_ids: &[Uuid],
+    ) -> Statement {
+        let placeholders = (1..=content_ids.len())
+            .map(|n| format!("${n}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                SELECT {column} AS content_id, locale, field, value, updated_at
+                FROM content_translations
+                WHERE {column} IN ({placeholders})
+                ORDER BY locale
+                "#,
+                column = Self::column(kind),
+            ),
+            content_ids.iter().copied().map(Value::from),
+        )
+    }
+
+    /// Deletes only if the item is the owner's
+    fn guarded_delete_stmt(
+        backend: DatabaseBackend,
+        owner_id: Uuid,
+        kind: TranslatableKind,
+        content_id: Uuid,
+        locale: &str,
+    ) -> Statement {
+        let table = match kind {
+            TranslatableKind::Project => "projects",
+            TranslatableKind::Page => "pages",
+        };
+
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                DELETE FROM content_translations
+                WHERE {column} = $1 AND locale = $2
+                  AND EXISTS (SELECT 1 FROM {table} WHERE id = $1 AND user_id = $3)
+                "#,
+                column = Self::column(kind),
+            ),
+            vec![content_id.into(), locale.into(), owner_id.into()],
+        )
+    }
+
+    // =====================================================
+    // Mapping
+    // =====================================================
+
+    /// Rows of one item, ordered by locale, into one translation per locale
+    fn to_translations(
+        rows: &[QueryResult],
+    ) -> Result<Vec<Translation>, TranslationRepositoryError> {
+        let mut by_locale: BTreeMap<String, Translation> = BTreeMap::new();
+        for row in rows {
+            let locale: String = row.try_get("", "locale").map_err(Self::map_db_err)?;
+            let field: String = row.try_get("", "field").map_err(Self::map_db_err)?;
+            let value: String = row.try_get("", "value").map_err(Self::map_db_err)?;
+            let updated_at: DateTime<Utc> =
+                row.try_get("", "updated_at").map_err(Self::map_db_err)?;
+
+            let translation = by_locale
+                .entry(locale.clone())
+                .or_insert_with(|| Translation {
+                    locale,
+                    fields: BTreeMap::new(),
+                    updated_at,
+                });
+            translation.fields.insert(field, value);
+            translation.updated_at = translation.updated_at.max(updated_at);
+        }
+        Ok(by_locale.into_values().collect())
+    }
+
+    fn map_db_err(e: DbErr) -> TranslationRepositoryError {
+        TranslationRepositoryError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl TranslationRepository for TranslationRepositoryPostgres {
+    async fn put(
+        &self,
+        owner_id: Uuid,
+        content_id: Uuid,
+        command: &PutTranslationCommand,
+    ) -> Result<Translation, TranslationRepositoryError> {
+        let backend = self.db.get_database_backend();
+        let kind = command.kind();
+        let txn = self.db.begin().await.map_err(Self::map_db_err)?;
+
+        txn.query_one(Self::owned_stmt(backend, owner_id, kind, content_id))
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or(TranslationRepositoryError::ContentNotFound)?;
+
+        // Fields left out of the new translation fall back to the original
+        txn.execute(Self::delete_stmt(
+            backend,
+            kind,
+            content_id,
+            command.locale(),
+        ))
+        .await
+        .map_err(Self::map_db_err)?;
+        let rows = txn
+            .query_all(Self::insert_stmt(backend, content_id, command))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        txn.commit().await.map_err(Self::map_db_err)?;
+
+        Self::to_translations(&rows)?.pop().ok_or_else(|| {
+            TranslationRepositoryError::DatabaseError("insert returned no row".into())
+        })
+    }
+
+    async fn list(
+        &self,
+        owner_id: Uuid,
+        kind: TranslatableKind,
+        content_id: Uuid,
+    ) -> Result<Vec<Translation>, TranslationRepositoryError> {
+        let backend = self.db.get_database_backend();
+
+        self.db
+            .query_one(Self::owned_stmt(backend, owner_id, kind, content_id))
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or(TranslationRepositoryError::ContentNotFound)?;
+
+        let rows = self
+            .db
+            .query_all(Self::list_stmt(backend, kind, &[content_id]))
+            .await
+            .map_err(Self::map_db_err)?;
+        Self::to_translations(&rows)
+    }
+
+    async fn delete(
+        &self,
+        owner_id: Uuid,
+        kind: TranslatableKind,
+        content_id: Uuid,
+        locale: &str,
+    ) -> Result<(), TranslationRepositoryError> {
+        let res = self
+            .db
+            .execute(Self::guarded_delete_stmt(
+                self.db.get_database_backend(),
+                owner_id,
+                kind,
+                content_id,
+                locale,
+            ))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        if res.rows_affected() == 0 {
+            return Err(TranslationRepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn for_content(
+        &self,
+        kind: TranslatableKind,
+        content_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<Translation>>, TranslationRepositoryError> {
+        if content_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let rows = self
+            .db
+            .query_all(Self::list_stmt(
+                self.db.get_database_backend(),
+                kind,
+                content_ids,
+            ))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        let mut by_item: HashMap<Uuid, Vec<QueryResult>> = HashMap::new();
+        for row in rows {
+            let id: Uuid = row.try_get("", "content_id").map_err(Self::map_db_err)?;
+            by_item.entry(id).or_default().push(row);
+        }
+        by_item
+            .into_iter()
+            .map(|(id, rows)| Ok((id, Self::to_translations(&rows)?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{MockDatabase, MockExecResult};
+
+    fn translation_row(
+        content_id: Uuid,
+        locale: &str,
+        field: &str,
+        value: &str,
+    ) -> BTreeMap<String, Value> {
+        BTreeMap::from([
+            ("content_id".to_string(), Value::from(content_id)),
+            ("locale".to_string(), Value::from(locale)),
+            ("field".to_string(), Value::from(field)),
+            ("value".to_string(), Value::from(value)),
+            ("updated_at".to_string(), Value::from(Utc::now())),
+        ])
+    }
+
+    #[tokio::test]
+    async fn test_for_content_groups_rows_by_item_and_locale() {
+        let (about, now) = (Uuid::new_v4(), Uuid::new_v4());
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![
+                translation_row(about, "id", "title", "Tentang"),
+                translation_row(about, "id", "body", "Halo"),
+                translation_row(now, "id", "title", "Sekarang"),
+                translation_row(about, "pt", "title", "Sobre"),
+            ]])
+            .into_connection();
+
+        let repo = TranslationRepositoryPostgres::new(Arc::new(db));
+        let translations = repo
+            .for_content(TranslatableKind::Page, &[about, now])
+            .await
+            .unwrap();
+
+        let about = &translations[&about];
+        assert_eq!(about.len(), 2);
+        assert_eq!(about[0].locale, "id");
+        assert_eq!(about[0].field("body"), Some("Halo"));
+        assert_eq!(about[1].field("title"), Some("Sobre"));
+        assert_eq!(translations[&now][0].field("title"), Some("Sekarang"));
+    }
+
+    #[tokio::test]
+    async fn test_put_on_someone_elses_item_is_content_not_found() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![Vec::<BTreeMap<String, Value>>::new()])
+            .into_connection();
+        let command = PutTranslationCommand::new(
+            TranslatableKind::Project,
+            "id",
+            BTreeMap::from([("title".to_string(), "Proyek".to_string())]),
+        )
+        .unwrap();
+
+        let repo = TranslationRepositoryPostgres::new(Arc::new(db));
+        let result = repo.put(Uuid::new_v4(), Uuid::new_v4(), &command).await;
+
+        assert!(matches!(
+            result,
+            Err(TranslationRepositoryError::ContentNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_delete_missing_translation_is_not_found() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }])
+            .into_connection();
+
+        let repo = TranslationRepositoryPostgres::new(Arc::new(db));
+        let result = repo
+            .delete(Uuid::new_v4(), TranslatableKind::Page, Uuid::new_v4(), "id")
+            .await;
+
+        assert!(matches!(result, Err(TranslationRepositoryError::NotFound)));
+    }
+
+    #[test]
+    fn test_insert_writes_one_row_per_field() {
+        let command = PutTranslationCommand::new(
+            TranslatableKind::Page,
+            "id",
+            BTreeMap::from([
+                ("body".to_string(), "Halo".to_string()),
+                ("title".to_string(), "Tentang".to_string()),
+            ]),
+        )
+        .unwrap();
+
+        let stmt = TranslationRepositoryPostgres::insert_stmt(
+            DatabaseBackend::Postgres,
+            Uuid::new_v4(),
+            &command,
+        );
+
+        assert!(stmt.sql.contains("(id, page_id, locale, field, value)"));
+        assert!(stmt
+            .sql
+            .contains("($3, $1, $2, $4, $5), ($6, $1, $2, $7, $8)"));
+        assert_eq!(stmt.values.unwrap().0.len(), 8);
+    }
+}
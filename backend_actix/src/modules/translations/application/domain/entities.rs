@@ -0,0 +1,122 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+use crate::pages::application::domain::entities::Page;
+use crate::project::application::ports::outgoing::project_query::{ProjectCardView, ProjectView};
+use crate::shared::api::owner_view::OwnerView;
+
+/// Content that can be translated. There are no blog posts in the tree;
+/// pages are the long-form content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TranslatableKind {
+    Project,
+    Page,
+}
+
+impl TranslatableKind {
+    /// Translatable fields and their maximum length in characters
+    pub fn fields(self) -> &'static [(&'static str, usize)] {
+        match self {
+            TranslatableKind::Project => &[("title", 150), ("description", 100_000)],
+            TranslatableKind::Page => &[
+                ("title", 200),
+                ("body", 100_000),
+                ("seo_title", 100),
+                ("seo_description", 300),
+            ],
+        }
+    }
+}
+
+/// One locale of an item. Fields it leaves out are served in the original.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct Translation {
+    /// `ll` or `ll-RR`, e.g. `id` or `pt-BR`
+    pub locale: String,
+    /// Field name to translated text
+    pub fields: BTreeMap<String, String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Translation {
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields.get(name).map(String::as_str)
+    }
+}
+
+/// A public view whose text can be swapped for a translation, field by field
+pub trait Translatable {
+    fn translate(&mut self, translation: &Translation);
+}
+
+fn replace(target: &mut String, translated: Option<&str>) {
+    if let Some(text) = translated {
+        *target = text.to_string();
+    }
+}
+
+fn replace_optional(target: &mut Option<String>, translated: Option<&str>) {
+    if let Some(text) = translated {
+        *target = Some(text.to_string());
+    }
+}
+
+impl<T: Translatable> Translatable for OwnerView<T> {
+    fn translate(&mut self, translation: &Translation) {
+        self.item.translate(translation);
+    }
+}
+
+impl Translatable for ProjectView {
+    fn translate(&mut self, translation: &Translation) {
+        replace(&mut self.title, translation.field("title"));
+        replace(&mut self.description, translation.field("description"));
+    }
+}
+
+impl Translatable for ProjectCardView {
+    fn translate(&mut self, translation: &Translation) {
+        replace(&mut self.title, translation.field("title"));
+    }
+}
+
+impl Translatable for Page {
+    fn translate(&mut self, translation: &Translation) {
+        replace(&mut self.title, translation.field("title"));
+        replace(&mut self.body, translation.field("body"));
+        replace_optional(&mut self.seo_title, translation.field("seo_title"));
+        replace_optional(
+            &mut self.seo_description,
+            translation.field("seo_description"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::support::stubs::sample_page;
+
+    #[test]
+    fn test_missing_fields_keep_the_original() {
+        let mut page = sample_page();
+        let translation = Translation {
+            locale: "id".to_string(),
+            fields: BTreeMap::from([
+                ("title".to_string(), "Tentang".to_string()),
+                ("seo_title".to_string(), "Tentang saya".to_string()),
+            ]),
+            updated_at: Utc::now(),
+        };
+
+        page.translate(&translation);
+
+        assert_eq!(page.title, "Tentang");
+        assert_eq!(page.seo_title.as_deref(), Some("Tentang saya"));
+        assert_eq!(page.body, "# Hello");
+        assert_eq!(page.seo_description, None);
+    }
+}
@@ -0,0 +1,112 @@
+//! Which translation a visitor gets.
+//!
+//! `?lang=` names one locale and wins over `Accept-Language`, whose tags are
+//! taken in order of quality. For each wanted locale an exact translation is
+//! used first, then one in its language (`pt-BR` takes `pt`). When nothing
+//! matches, the original text is served.
+
+use crate::auth::application::domain::entities::normalize_locale;
+use crate::translations::application::domain::entities::Translation;
+
+/// Locales a visitor asked for, most wanted first
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LocalePreferences(Vec<String>);
+
+impl LocalePreferences {
+    /// Tags that aren't `ll` or `ll-RR`, `*` and those with `q=0` are ignored
+    pub fn new(lang: Option<&str>, accept_language: Option<&str>) -> Self {
+        if let Some(locale) = lang.and_then(normalize_locale) {
+            return Self(vec![locale]);
+        }
+
+        let mut weighted: Vec<(String, f32)> = accept_language
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|entry| {
+                let mut parts = entry.split(';');
+                let locale = normalize_locale(parts.next()?)?;
+                let quality = parts
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok())?;
+                (quality > 0.0).then_some((locale, quality))
+            })
+            .collect();
+        // Stable: equal qualities keep the header's order
+        weighted.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        Self(weighted.into_iter().map(|(locale, _)| locale).collect())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn pick<'a>(&self, translations: &'a [Translation]) -> Option<&'a Translation> {
+        self.0.iter().find_map(|wanted| {
+            let language = wanted.split('-').next().unwrap_or(wanted);
+            translations
+                .iter()
+                .find(|t| t.locale == *wanted)
+                .or_else(|| translations.iter().find(|t| t.locale == language))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn translations(locales: &[&str]) -> Vec<Translation> {
+        locales
+            .iter()
+            .map(|locale| Translation {
+                locale: locale.to_string(),
+                fields: Default::default(),
+                updated_at: Utc::now(),
+            })
+            .collect()
+    }
+
+    fn picked(preferences: &LocalePreferences, available: &[&str]) -> Option<String> {
+        preferences
+            .pick(&translations(available))
+            .map(|t| t.locale.clone())
+    }
+
+    #[test]
+    fn test_accept_language_is_ordered_by_quality() {
+        let preferences =
+            LocalePreferences::new(None, Some("fr;q=0.5, id-ID, *;q=0.1, de;q=0, en;q=0.8"));
+
+        assert_eq!(
+            preferences,
+            LocalePreferences(vec!["id-ID".into(), "en".into(), "fr".into()])
+        );
+    }
+
+    #[test]
+    fn test_lang_parameter_wins() {
+        let preferences = LocalePreferences::new(Some("pt_br"), Some("id"));
+
+        assert_eq!(preferences, LocalePreferences(vec!["pt-BR".into()]));
+        // An invalid one is ignored
+        assert_eq!(
+            LocalePreferences::new(Some("klingon"), Some("id")),
+            LocalePreferences(vec!["id".into()])
+        );
+    }
+
+    #[test]
+    fn test_exact_locale_then_language() {
+        let preferences = LocalePreferences::new(None, Some("pt-BR, id"));
+
+        assert_eq!(
+            picked(&preferences, &["pt", "pt-BR"]).as_deref(),
+            Some("pt-BR")
+        );
+        assert_eq!(picked(&preferences, &["id", "pt"]).as_deref(), Some("pt"));
+        assert_eq!(picked(&preferences, &["id"]).as_deref(), Some("id"));
+        assert_eq!(picked(&preferences, &["de"]), None);
+    }
+}
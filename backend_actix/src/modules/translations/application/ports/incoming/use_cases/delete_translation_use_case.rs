@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::shared::metrics::metered_use_case;
+use crate::translations::application::domain::entities::TranslatableKind;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DeleteTranslationError {
+    /// No translation into that locale, or the item isn't the owner's
+    #[error("Translation not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait DeleteTranslationUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        kind: TranslatableKind,
+        content_id: Uuid,
+        locale: &str,
+    ) -> Result<(), DeleteTranslationError>;
+}
+
+metered_use_case!(
+    "translations",
+    "delete_translation",
+    DeleteTranslationUseCase,
+    fn execute(
+        &self,
+        owner_id: Uuid,
+        kind: TranslatableKind,
+        content_id: Uuid,
+        locale: &str,
+    ) -> Result<(), DeleteTranslationError>
+);
@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::shared::metrics::metered_use_case;
+use crate::translations::application::domain::entities::{TranslatableKind, Translation};
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ListTranslationsError {
+    #[error("Content not found")]
+    ContentNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait ListTranslationsUseCase: Send + Sync {
+    /// Every translation of one of the owner's items, by locale
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        kind: TranslatableKind,
+        content_id: Uuid,
+    ) -> Result<Vec<Translation>, ListTranslationsError>;
+}
+
+metered_use_case!(
+    "translations",
+    "list_translations",
+    ListTranslationsUseCase,
+    fn execute(
+        &self,
+        owner_id: Uuid,
+        kind: TranslatableKind,
+        content_id: Uuid,
+    ) -> Result<Vec<Translation>, ListTranslationsError>
+);
@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::shared::metrics::metered_use_case;
+use crate::translations::application::domain::entities::{TranslatableKind, Translation};
+use crate::translations::application::domain::negotiation::LocalePreferences;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum LocalizeContentError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait LocalizeContentUseCase: Send + Sync {
+    /// The translation each item is served in for these preferences. Items
+    /// without a matching one are left out and served in the original.
+    async fn execute(
+        &self,
+        kind: TranslatableKind,
+        content_ids: &[Uuid],
+        preferences: &LocalePreferences,
+    ) -> Result<HashMap<Uuid, Translation>, LocalizeContentError>;
+}
+
+metered_use_case!(
+    "translations",
+    "localize_content",
+    LocalizeContentUseCase,
+    fn execute(
+        &self,
+        kind: TranslatableKind,
+        content_ids: &[Uuid],
+        preferences: &LocalePreferences,
+    ) -> Result<HashMap<Uuid, Translation>, LocalizeContentError>
+);
@@ -0,0 +1,11 @@
+mod delete_translation_use_case;
+mod list_translations_use_case;
+mod localize_content_use_case;
+mod put_translation_use_case;
+
+pub use delete_translation_use_case::{DeleteTranslationError, DeleteTranslationUseCase};
+pub use list_translations_use_case::{ListTranslationsError, ListTranslationsUseCase};
+pub use localize_content_use_case::{LocalizeContentError, LocalizeContentUseCase};
+pub use put_translation_use_case::{
+    PutTranslationCommand, PutTranslationError, PutTranslationUseCase, TranslationCommandError,
+};
@@ -0,0 +1,173 @@
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::normalize_locale;
+use crate::shared::metrics::metered_use_case;
+use crate::translations::application::domain::entities::{TranslatableKind, Translation};
+
+//
+// ──────────────────────────────────────────────────────────
+// Put Translation Command
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, thiserror::Error)]
+pub enum TranslationCommandError {
+    #[error("Locale must look like 'en' or 'pt-BR'")]
+    InvalidLocale,
+
+    #[error("'{0}' can't be translated")]
+    UnknownField(String),
+
+    #[error("'{field}' must be at most {max} characters")]
+    FieldTooLong { field: String, max: usize },
+
+    #[error("A translation needs at least one field")]
+    NoFields,
+}
+
+/// The whole translation of an item into one locale; it replaces any earlier one
+#[derive(Debug, Clone)]
+pub struct PutTranslationCommand {
+    kind: TranslatableKind,
+    locale: String,
+    fields: BTreeMap<String, String>,
+}
+
+impl PutTranslationCommand {
+    /// Blank fields are dropped, so they fall back to the original
+    pub fn new(
+        kind: TranslatableKind,
+        locale: &str,
+        fields: BTreeMap<String, String>,
+    ) -> Result<Self, TranslationCommandError> {
+        let locale = normalize_locale(locale).ok_or(TranslationCommandError::InvalidLocale)?;
+
+        let mut kept = BTreeMap::new();
+        for (field, text) in fields {
+            let Some(&(_, max)) = kind.fields().iter().find(|(name, _)| *name == field) else {
+                return Err(TranslationCommandError::UnknownField(field));
+            };
+            if text.trim().is_empty() {
+                continue;
+            }
+            if text.chars().count() > max {
+                return Err(TranslationCommandError::FieldTooLong { field, max });
+            }
+            kept.insert(field, text);
+        }
+        if kept.is_empty() {
+            return Err(TranslationCommandError::NoFields);
+        }
+
+        Ok(Self {
+            kind,
+            locale,
+            fields: kept,
+        })
+    }
+
+    pub fn kind(&self) -> TranslatableKind {
+        self.kind
+    }
+
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    pub fn fields(&self) -> &BTreeMap<String, String> {
+        &self.fields
+    }
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Use Case
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum PutTranslationError {
+    /// No such item, or it belongs to someone else or is in the trash
+    #[error("Content not found")]
+    ContentNotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait PutTranslationUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        content_id: Uuid,
+        command: PutTranslationCommand,
+    ) -> Result<Translation, PutTranslationError>;
+}
+
+metered_use_case!(
+    "translations",
+    "put_translation",
+    PutTranslationUseCase,
+    fn execute(
+        &self,
+        owner_id: Uuid,
+        content_id: Uuid,
+        command: PutTranslationCommand,
+    ) -> Result<Translation, PutTranslationError>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(field, text)| (field.to_string(), text.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_locale_is_normalized_and_blank_fields_dropped() {
+        let command = PutTranslationCommand::new(
+            TranslatableKind::Project,
+            "pt_br",
+            fields(&[("title", "Projeto"), ("description", "  ")]),
+        )
+        .unwrap();
+
+        assert_eq!(command.locale(), "pt-BR");
+        assert_eq!(command.fields(), &fields(&[("title", "Projeto")]));
+    }
+
+    #[test]
+    fn test_fields_are_checked_against_the_kind() {
+        assert!(matches!(
+            PutTranslationCommand::new(
+                TranslatableKind::Project,
+                "id",
+                fields(&[("body", "Isi")])
+            ),
+            Err(TranslationCommandError::UnknownField(field)) if field == "body"
+        ));
+        assert!(matches!(
+            PutTranslationCommand::new(
+                TranslatableKind::Page,
+                "id",
+                fields(&[("seo_title", &"x".repeat(101))])
+            ),
+            Err(TranslationCommandError::FieldTooLong { max: 100, .. })
+        ));
+        assert!(matches!(
+            PutTranslationCommand::new(TranslatableKind::Page, "id", fields(&[("title", "")])),
+            Err(TranslationCommandError::NoFields)
+        ));
+        assert!(matches!(
+            PutTranslationCommand::new(TranslatableKind::Page, "english", fields(&[])),
+            Err(TranslationCommandError::InvalidLocale)
+        ));
+    }
+}
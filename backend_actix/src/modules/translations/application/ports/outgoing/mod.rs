@@ -0,0 +1,3 @@
+pub mod translation_repository;
+
+pub use translation_repository::{TranslationRepository, TranslationRepositoryError};
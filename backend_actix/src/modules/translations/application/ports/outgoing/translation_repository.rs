@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::translations::application::domain::entities::{TranslatableKind, Translation};
+use crate::translations::application::ports::incoming::use_cases::PutTranslationCommand;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum TranslationRepositoryError {
+    /// The item doesn't exist, isn't the owner's or is in the trash
+    #[error("Content not found")]
+    ContentNotFound,
+
+    #[error("Translation not found")]
+    NotFound,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+#[async_trait]
+pub trait TranslationRepository: Send + Sync {
+    /// Replaces the item's translation into the command's locale
+    async fn put(
+        &self,
+        owner_id: Uuid,
+        content_id: Uuid,
+        command: &PutTranslationCommand,
+    ) -> Result<Translation, TranslationRepositoryError>;
+
+    /// By locale
+    async fn list(
+        &self,
+        owner_id: Uuid,
+        kind: TranslatableKind,
+        content_id: Uuid,
+    ) -> Result<Vec<Translation>, TranslationRepositoryError>;
+
+    async fn delete(
+        &self,
+        owner_id: Uuid,
+        kind: TranslatableKind,
+        content_id: Uuid,
+        locale: &str,
+    ) -> Result<(), TranslationRepositoryError>;
+
+    /// Every translation of the given items, for public reads; items without
+    /// any are left out
+    async fn for_content(
+        &self,
+        kind: TranslatableKind,
+        content_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<Translation>>, TranslationRepositoryError>;
+}
@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::normalize_locale;
+use crate::translations::application::domain::entities::TranslatableKind;
+use crate::translations::application::ports::{
+    incoming::use_cases::{DeleteTranslationError, DeleteTranslationUseCase},
+    outgoing::{TranslationRepository, TranslationRepositoryError},
+};
+
+pub struct DeleteTranslationService<R>
+where
+    R: TranslationRepository,
+{
+    repository: R,
+}
+
+impl<R> DeleteTranslationService<R>
+where
+    R: TranslationRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> DeleteTranslationUseCase for DeleteTranslationService<R>
+where
+    R: TranslationRepository,
+{
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        kind: TranslatableKind,
+        content_id: Uuid,
+        locale: &str,
+    ) -> Result<(), DeleteTranslationError> {
+        // Stored normalized; a tag that can't be one was never stored
+        let locale = normalize_locale(locale).ok_or(DeleteTranslationError::NotFound)?;
+
+        self.repository
+            .delete(owner_id, kind, content_id, &locale)
+            .await
+            .map_err(|e| match e {
+                TranslationRepositoryError::NotFound
+                | TranslationRepositoryError::ContentNotFound => DeleteTranslationError::NotFound,
+                other => DeleteTranslationError::RepositoryError(other.to_string()),
+            })
+    }
+}
@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::translations::application::domain::entities::{TranslatableKind, Translation};
+use crate::translations::application::ports::{
+    incoming::use_cases::{ListTranslationsError, ListTranslationsUseCase},
+    outgoing::{TranslationRepository, TranslationRepositoryError},
+};
+
+pub struct ListTranslationsService<R>
+where
+    R: TranslationRepository,
+{
+    repository: R,
+}
+
+impl<R> ListTranslationsService<R>
+where
+    R: TranslationRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> ListTranslationsUseCase for ListTranslationsService<R>
+where
+    R: TranslationRepository,
+{
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        kind: TranslatableKind,
+        content_id: Uuid,
+    ) -> Result<Vec<Translation>, ListTranslationsError> {
+        self.repository
+            .list(owner_id, kind, content_id)
+            .await
+            .map_err(|e| match e {
+                TranslationRepositoryError::ContentNotFound => {
+                    ListTranslationsError::ContentNotFound
+                }
+                other => ListTranslationsError::RepositoryError(other.to_string()),
+            })
+    }
+}
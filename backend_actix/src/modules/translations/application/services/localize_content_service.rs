@@ -0,0 +1,116 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::translations::application::domain::entities::{TranslatableKind, Translation};
+use crate::translations::application::domain::negotiation::LocalePreferences;
+use crate::translations::application::ports::{
+    incoming::use_cases::{LocalizeContentError, LocalizeContentUseCase},
+    outgoing::TranslationRepository,
+};
+
+pub struct LocalizeContentService<R>
+where
+    R: TranslationRepository,
+{
+    repository: R,
+}
+
+impl<R> LocalizeContentService<R>
+where
+    R: TranslationRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> LocalizeContentUseCase for LocalizeContentService<R>
+where
+    R: TranslationRepository,
+{
+    async fn execute(
+        &self,
+        kind: TranslatableKind,
+        content_ids: &[Uuid],
+        preferences: &LocalePreferences,
+    ) -> Result<HashMap<Uuid, Translation>, LocalizeContentError> {
+        if content_ids.is_empty() || preferences.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let translations = self
+            .repository
+            .for_content(kind, content_ids)
+            .await
+            .map_err(|e| LocalizeContentError::RepositoryError(e.to_string()))?;
+
+        Ok(translations
+            .into_iter()
+            .filter_map(|(id, translations)| {
+                preferences
+                    .pick(&translations)
+                    .cloned()
+                    .map(|translation| (id, translation))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    use crate::pages::adapter::outgoing::InMemoryPageStore;
+    use crate::pages::application::domain::entities::PageStatus;
+    use crate::pages::application::ports::incoming::use_cases::CreatePageCommand;
+    use crate::pages::application::ports::outgoing::PageRepository;
+    use crate::project::adapter::outgoing::InMemoryProjectStore;
+    use crate::translations::adapter::outgoing::InMemoryTranslationStore;
+    use crate::translations::application::ports::incoming::use_cases::PutTranslationCommand;
+
+    #[tokio::test]
+    async fn test_each_item_gets_its_best_locale() {
+        let pages = InMemoryPageStore::default();
+        let store = InMemoryTranslationStore::new(InMemoryProjectStore::default(), pages.clone());
+        let owner = Uuid::new_v4();
+        let mut ids = Vec::new();
+        for slug in ["about", "now", "uses"] {
+            let command = CreatePageCommand::new(
+                slug.to_string(),
+                slug.to_string(),
+                String::new(),
+                PageStatus::Published,
+                None,
+                None,
+            )
+            .unwrap();
+            ids.push(pages.create(owner, &command).await.unwrap().id);
+        }
+        for (id, locale) in [(ids[0], "id"), (ids[0], "pt"), (ids[1], "pt")] {
+            let command = PutTranslationCommand::new(
+                TranslatableKind::Page,
+                locale,
+                BTreeMap::from([("title".to_string(), format!("title-{locale}"))]),
+            )
+            .unwrap();
+            store.put(owner, id, &command).await.unwrap();
+        }
+        let service = LocalizeContentService::new(store);
+
+        let picked = service
+            .execute(
+                TranslatableKind::Page,
+                &ids,
+                &LocalePreferences::new(None, Some("id, pt-BR;q=0.5")),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(picked[&ids[0]].locale, "id");
+        assert_eq!(picked[&ids[1]].locale, "pt");
+        assert!(!picked.contains_key(&ids[2]));
+    }
+}
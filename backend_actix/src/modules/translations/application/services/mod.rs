@@ -0,0 +1,9 @@
+mod delete_translation_service;
+mod list_translations_service;
+mod localize_content_service;
+mod put_translation_service;
+
+pub use delete_translation_service::DeleteTranslationService;
+pub use list_translations_service::ListTranslationsService;
+pub use localize_content_service::LocalizeContentService;
+pub use put_translation_service::PutTranslationService;
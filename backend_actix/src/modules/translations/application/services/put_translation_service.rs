@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::translations::application::domain::entities::Translation;
+use crate::translations::application::ports::{
+    incoming::use_cases::{PutTranslationCommand, PutTranslationError, PutTranslationUseCase},
+    outgoing::{TranslationRepository, TranslationRepositoryError},
+};
+
+pub struct PutTranslationService<R>
+where
+    R: TranslationRepository,
+{
+    repository: R,
+}
+
+impl<R> PutTranslationService<R>
+where
+    R: TranslationRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> PutTranslationUseCase for PutTranslationService<R>
+where
+    R: TranslationRepository,
+{
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        content_id: Uuid,
+        command: PutTranslationCommand,
+    ) -> Result<Translation, PutTranslationError> {
+        self.repository
+            .put(owner_id, content_id, &command)
+            .await
+            .map_err(|e| match e {
+                TranslationRepositoryError::ContentNotFound => PutTranslationError::ContentNotFound,
+                other => PutTranslationError::RepositoryError(other.to_string()),
+            })
+    }
+}
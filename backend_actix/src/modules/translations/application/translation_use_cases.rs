@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use crate::shared::metrics::Metered;
+use crate::translations::application::ports::incoming::use_cases::{
+    DeleteTranslationUseCase, ListTranslationsUseCase, LocalizeContentUseCase,
+    PutTranslationUseCase,
+};
+use crate::translations::application::ports::outgoing::TranslationRepository;
+use crate::translations::application::services::{
+    DeleteTranslationService, ListTranslationsService, LocalizeContentService,
+    PutTranslationService,
+};
+
+#[derive(Clone)]
+pub struct TranslationUseCases {
+    pub put: Arc<dyn PutTranslationUseCase + Send + Sync>,
+    pub list: Arc<dyn ListTranslationsUseCase + Send + Sync>,
+    pub delete: Arc<dyn DeleteTranslationUseCase + Send + Sync>,
+    pub localize: Arc<dyn LocalizeContentUseCase + Send + Sync>,
+}
+
+impl TranslationUseCases {
+    pub fn build<R>(repository: R) -> Self
+    where
+        R: TranslationRepository + Clone + 'static,
+    {
+        Self {
+            put: Arc::new(Metered(PutTranslationService::new(repository.clone()))),
+            list: Arc::new(Metered(ListTranslationsService::new(repository.clone()))),
+            delete: Arc::new(Metered(DeleteTranslationService::new(repository.clone()))),
+            localize: Arc::new(Metered(LocalizeContentService::new(repository))),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    TRASH_ITEM_NOT_FOUND = (NOT_FOUND, "Item not found in trash");
+}
@@ -0,0 +1,10 @@
+use actix_web::web;
+
+pub mod error_codes;
+pub mod routes;
+
+/// Routes for signed-in users with a verified email
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::list_trash_handler)
+        .service(routes::restore_trash_item_handler);
+}
@@ -0,0 +1,5 @@
+mod list_trash;
+mod restore_trash_item;
+
+pub use list_trash::{__path_list_trash_handler, list_trash_handler};
+pub use restore_trash_item::{__path_restore_trash_item_handler, restore_trash_item_handler};
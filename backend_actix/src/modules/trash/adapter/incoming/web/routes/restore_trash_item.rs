@@ -0,0 +1,136 @@
+use actix_web::{post, web, HttpResponse, Responder};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::api::schemas::ErrorResponse;
+use crate::trash::adapter::incoming::web::error_codes::TRASH_ITEM_NOT_FOUND;
+use crate::{
+    auth::{
+        adapter::incoming::web::extractors::auth::VerifiedUser,
+        application::domain::entities::UserId,
+    },
+    shared::api::ApiResponse,
+    trash::application::ports::{
+        incoming::use_cases::RestoreTrashItemError, outgoing::TrashItemKind,
+    },
+    AppState,
+};
+
+/// Move an item out of the trash
+#[utoipa::path(
+    post,
+    path = "/api/trash/{type}/{id}/restore",
+    tag = "trash",
+    params(
+        ("type" = TrashItemKind, Path, description = "Item type, as returned by the trash listing"),
+        ("id" = Uuid, Path, description = "Item id"),
+    ),
+    responses(
+        (status = 204, description = "Item restored"),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "No such item in the user's trash", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/trash/{type}/{id}/restore")]
+pub async fn restore_trash_item_handler(
+    user: VerifiedUser,
+    path: web::Path<(TrashItemKind, Uuid)>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let owner = UserId::from(user.user_id);
+    let (kind, id) = path.into_inner();
+
+    match data.trash.restore.execute(owner, kind, id).await {
+        Ok(()) => ApiResponse::no_content(),
+        Err(err) => map_restore_error(err),
+    }
+}
+
+fn map_restore_error(err: RestoreTrashItemError) -> HttpResponse {
+    match err {
+        RestoreTrashItemError::NotFound => TRASH_ITEM_NOT_FOUND.response(),
+        RestoreTrashItemError::RepositoryError(msg) => {
+            error!("Failed to restore trash item: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, web, App};
+    use std::sync::Arc;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubRestoreTrashItemUseCase,
+        },
+    };
+
+    async fn call(state: web::Data<AppState>, uri: &str) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(restore_trash_item_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_restore_returns_no_content() {
+        let state = TestAppStateBuilder::default()
+            .with_restore_trash_item(StubRestoreTrashItemUseCase::success())
+            .build();
+
+        let uri = format!("/api/trash/cv/{}/restore", Uuid::new_v4());
+        let resp = call(state, &uri).await;
+
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[actix_web::test]
+    async fn test_restore_missing_item_returns_not_found() {
+        let state = TestAppStateBuilder::default()
+            .with_restore_trash_item(StubRestoreTrashItemUseCase::not_found())
+            .build();
+
+        let uri = format!("/api/trash/media/{}/restore", Uuid::new_v4());
+        let resp = call(state, &uri).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "TRASH_ITEM_NOT_FOUND");
+    }
+
+    #[actix_web::test]
+    async fn test_restore_repository_error_returns_internal_error() {
+        let state = TestAppStateBuilder::default()
+            .with_restore_trash_item(StubRestoreTrashItemUseCase::failure("db down"))
+            .build();
+
+        let uri = format!("/api/trash/topic/{}/restore", Uuid::new_v4());
+        let resp = call(state, &uri).await;
+
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
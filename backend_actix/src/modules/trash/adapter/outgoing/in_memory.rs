@@ -0,0 +1,302 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::cv::adapter::outgoing::InMemoryCvStore;
+use crate::multimedia::adapter::outgoing::db::InMemoryMediaStore;
+use crate::project::adapter::outgoing::InMemoryProjectStore;
+use crate::topic::adapter::outgoing::InMemoryTopicStore;
+use crate::trash::application::ports::outgoing::{
+    TrashItem, TrashItemKind, TrashPage, TrashRepository, TrashRepositoryError,
+};
+
+/// `TrashRepository` over the other modules' in-memory stores
+#[derive(Clone)]
+pub struct InMemoryTrashRepository {
+    cvs: InMemoryCvStore,
+    projects: InMemoryProjectStore,
+    topics: InMemoryTopicStore,
+    media: InMemoryMediaStore,
+}
+
+impl InMemoryTrashRepository {
+    pub fn new(
+        cvs: InMemoryCvStore,
+        projects: InMemoryProjectStore,
+        topics: InMemoryTopicStore,
+        media: InMemoryMediaStore,
+    ) -> Self {
+        Self {
+            cvs,
+            projects,
+            topics,
+            media,
+        }
+    }
+
+    fn items(&self, owner: UserId) -> Vec<TrashItem> {
+        let owner_uuid: Uuid = owner.into();
+        let item = |kind, id, title: &str, deleted_at| TrashItem {
+            kind,
+            id,
+            title: title.to_string(),
+            deleted_at,
+        };
+
+        let mut items = Vec::new();
+        self.cvs.cvs.read(|cvs| {
+            for row in cvs.iter().filter(|row| row.cv.user_id == owner_uuid) {
+                if let Some(deleted_at) = row.deleted_at {
+                    items.push(item(
+                        TrashItemKind::Cv,
+                        row.cv.id,
+                        &row.cv.display_name,
+                        deleted_at,
+                    ));
+                }
+            }
+        });
+        self.projects.projects.read(|projects| {
+            for row in projects.iter().filter(|row| row.project.owner == owner) {
+                if let Some(deleted_at) = row.deleted_at {
+                    items.push(item(
+                        TrashItemKind::Project,
+                        row.project.id,
+                        &row.project.title,
+                        deleted_at,
+                    ));
+                }
+            }
+        });
+        self.topics.topics.read(|topics| {
+            for row in topics.iter().filter(|row| row.topic.owner == owner) {
+                if let Some(deleted_at) = row.deleted_at {
+                    items.push(item(
+                        TrashItemKind::Topic,
+                        row.topic.id,
+                        &row.topic.title,
+                        deleted_at,
+                    ));
+                }
+            }
+        });
+        self.media.media.read(|media| {
+            for row in media.iter().filter(|row| row.owner == owner) {
+                if let Some(deleted_at) = row.deleted_at {
+                    items.push(item(
+                        TrashItemKind::Media,
+                        row.media_id,
+                        &row.original_name,
+                        deleted_at,
+                    ));
+                }
+            }
+        });
+
+        items.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at).then(a.id.cmp(&b.id)));
+        items
+    }
+}
+
+/// Clears `deleted_at` on the row `matches` picks, if it is trashed;
+/// `false` otherwise
+fn untrash<T>(
+    rows: &mut [T],
+    matches: impl Fn(&T) -> bool,
+    deleted_at: impl Fn(&mut T) -> &mut Option<DateTime<Utc>>,
+) -> bool {
+    match rows.iter_mut().find(|row| matches(row)) {
+        Some(row) => deleted_at(row).take().is_some(),
+        None => false,
+    }
+}
+
+#[async_trait]
+impl TrashRepository for InMemoryTrashRepository {
+    async fn list(
+        &self,
+        owner: UserId,
+        kind: Option<TrashItemKind>,
+        offset: u64,
+        limit: u32,
+    ) -> Result<TrashPage, TrashRepositoryError> {
+        let items: Vec<TrashItem> = self
+            .items(owner)
+            .into_iter()
+            .filter(|item| kind.is_none_or(|kind| item.kind == kind))
+            .collect();
+
+        Ok(TrashPage {
+            total: items.len() as u64,
+            items: items
+                .into_iter()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .collect(),
+        })
+    }
+
+    async fn restore(
+        &self,
+        owner: UserId,
+        kind: TrashItemKind,
+        id: Uuid,
+    ) -> Result<(), TrashRepositoryError> {
+        let owner_uuid: Uuid = owner.into();
+        let restored = match kind {
+            TrashItemKind::Cv => self.cvs.cvs.write(|cvs| {
+                untrash(
+                    cvs,
+                    |row| row.cv.id == id && row.cv.user_id == owner_uuid,
+                    |row| &mut row.deleted_at,
+                )
+            }),
+            TrashItemKind::Project => self.projects.projects.write(|projects| {
+                untrash(
+                    projects,
+                    |row| row.project.id == id && row.project.owner == owner,
+                    |row| &mut row.deleted_at,
+                )
+            }),
+            TrashItemKind::Topic => self.topics.topics.write(|topics| {
+                untrash(
+                    topics,
+                    |row| row.topic.id == id && row.topic.owner == owner,
+                    |row| &mut row.deleted_at,
+                )
+            }),
+            TrashItemKind::Media => self.media.media.write(|media| {
+                untrash(
+                    media,
+                    |row| row.media_id == id && row.owner == owner,
+                    |row| &mut row.deleted_at,
+                )
+            }),
+        };
+
+        if !restored {
+            return Err(TrashRepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn purge_deleted_before(
+        &self,
+        cutoff: DateTime<Utc>,
+    ) -> Result<u64, TrashRepositoryError> {
+        let expired = |deleted_at: Option<DateTime<Utc>>| deleted_at.is_some_and(|at| at < cutoff);
+
+        let cvs = self.cvs.cvs.write(|cvs| {
+            let before = cvs.len();
+            cvs.retain(|row| !expired(row.deleted_at));
+            before - cvs.len()
+        });
+
+        let project_ids: Vec<Uuid> = self.projects.projects.write(|projects| {
+            let ids = projects
+                .iter()
+                .filter(|row| expired(row.deleted_at))
+                .map(|row| row.project.id)
+                .collect();
+            projects.retain(|row| !expired(row.deleted_at));
+            ids
+        });
+
+        let topic_ids: Vec<Uuid> = self.topics.topics.write(|topics| {
+            let ids = topics
+                .iter()
+                .filter(|row| expired(row.deleted_at))
+                .map(|row| row.topic.id)
+                .collect();
+            topics.retain(|row| !expired(row.deleted_at));
+            ids
+        });
+
+        // Links go with either end, like the FK cascades
+        self.projects.links.write(|links| {
+            links.retain(|link| {
+                !project_ids.contains(&link.project_id) && !topic_ids.contains(&link.topic_id)
+            })
+        });
+
+        Ok((cvs + project_ids.len() + topic_ids.len()) as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::application::ports::outgoing::project_archiver::ProjectArchiver;
+    use crate::project::application::ports::outgoing::project_repository::{
+        CreateProjectData, ProjectRepository,
+    };
+    use crate::project::application::ports::outgoing::project_topic_repository::ProjectTopicRepository;
+    use crate::topic::application::ports::outgoing::{CreateTopicData, TopicRepository};
+
+    #[tokio::test]
+    async fn test_trashed_items_are_listed_restored_and_purged_with_links() {
+        let topics = InMemoryTopicStore::default();
+        let projects = InMemoryProjectStore::new(topics.clone());
+        let trash = InMemoryTrashRepository::new(
+            InMemoryCvStore::default(),
+            projects.clone(),
+            topics.clone(),
+            InMemoryMediaStore::default(),
+        );
+        let owner = UserId::from(Uuid::new_v4());
+
+        let project = projects
+            .create_project(CreateProjectData {
+                owner,
+                title: "Portfolio".to_string(),
+                slug: "portfolio".to_string(),
+                description: String::new(),
+                tech_stack: vec![],
+                screenshots: vec![],
+                repo_url: None,
+                live_demo_url: None,
+                seo_title: None,
+                seo_description: None,
+                canonical_url: None,
+                noindex: false,
+            })
+            .await
+            .unwrap();
+        let topic = topics
+            .create_topic(CreateTopicData {
+                owner,
+                title: "Rust".to_string(),
+                description: String::new(),
+            })
+            .await
+            .unwrap();
+        projects
+            .add_project_topic(owner, project.id, topic.id)
+            .await
+            .unwrap();
+
+        topics.soft_delete_topic(topic.id).await.unwrap();
+        projects.soft_delete(owner, project.id).await.unwrap();
+
+        let page = trash.list(owner, None, 0, 10).await.unwrap();
+        assert_eq!(page.total, 2);
+        assert_eq!(page.items[0].kind, TrashItemKind::Project);
+
+        trash
+            .restore(owner, TrashItemKind::Topic, topic.id)
+            .await
+            .unwrap();
+        assert!(matches!(
+            trash.restore(owner, TrashItemKind::Topic, topic.id).await,
+            Err(TrashRepositoryError::NotFound)
+        ));
+
+        let purged = trash
+            .purge_deleted_before(Utc::now() + chrono::Duration::seconds(1))
+            .await
+            .unwrap();
+        assert_eq!(purged, 1);
+        assert!(projects.links.read(|links| links.is_empty()));
+    }
+}
@@ -0,0 +1,5 @@
+mod in_memory;
+mod trash_repository_postgres;
+
+pub use in_memory::InMemoryTrashRepository;
+pub use trash_repository_postgres::TrashRepositoryPostgres;
@@ -0,0 +1,362 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, Statement};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::trash::application::ports::outgoing::{
+    TrashItem, TrashItemKind, TrashPage, TrashRepository, TrashRepositoryError,
+};
+
+/// Every soft-deleted row an owner can see, tagged with its kind.
+/// `$1` is the owner.
+const TRASH_CTE: &str = r#"
+    WITH trash AS (
+        SELECT 'cv' AS kind, id, display_name AS title, deleted_at
+        FROM resumes
+        WHERE user_id = $1 AND is_deleted = true
+        UNION ALL
+        SELECT 'project', id, title, deleted_at
+        FROM projects
+        WHERE user_id = $1 AND is_deleted = true
+        UNION ALL
+        SELECT 'topic', id, title, deleted_at
+        FROM topics
+        WHERE user_id = $1 AND is_deleted = true
+        UNION ALL
+        SELECT 'media', id, original_filename, deleted_at
+        FROM media
+        WHERE user_id = $1 AND deleted_at IS NOT NULL
+    )
+"#;
+
+/// Tables the retention purge hard-deletes from
+const PURGED_TABLES: [&str; 3] = ["resumes", "projects", "topics"];
+
+#[derive(Clone)]
+pub struct TrashRepositoryPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl TrashRepositoryPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    fn list_stmt(owner: Uuid, kind: Option<TrashItemKind>, offset: u64, limit: u32) -> Statement {
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!(
+                r#"{TRASH_CTE}
+                SELECT kind, id, title, deleted_at
+                FROM trash
+                WHERE CAST($2 AS TEXT) IS NULL OR kind = $2
+                ORDER BY deleted_at DESC, id
+                LIMIT $4
+                OFFSET $3
+                "#
+            ),
+            vec![
+                owner.into(),
+                kind.map(kind_str).into(),
+                (offset as i64).into(),
+                (limit as i64).into(),
+            ],
+        )
+    }
+
+    fn count_stmt(owner: Uuid, kind: Option<TrashItemKind>) -> Statement {
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!(
+                r#"{TRASH_CTE}
+                SELECT COUNT(*) AS total
+                FROM trash
+                WHERE CAST($2 AS TEXT) IS NULL OR kind = $2
+                "#
+            ),
+            vec![owner.into(), kind.map(kind_str).into()],
+        )
+    }
+
+    fn restore_stmt(owner: Uuid, kind: TrashItemKind, id: Uuid) -> Statement {
+        let sql = match kind {
+            TrashItemKind::Cv => {
+                "UPDATE resumes SET is_deleted = false \
+                 WHERE id = $1 AND user_id = $2 AND is_deleted = true"
+            }
+            TrashItemKind::Project => {
+                "UPDATE projects SET is_deleted = false \
+                 WHERE id = $1 AND user_id = $2 AND is_deleted = true"
+            }
+            TrashItemKind::Topic => {
+                "UPDATE topics SET is_deleted = false \
+                 WHERE id = $1 AND user_id = $2 AND is_deleted = true"
+            }
+            TrashItemKind::Media => {
+                "UPDATE media SET deleted_at = NULL \
+                 WHERE id = $1 AND user_id = $2 AND deleted_at IS NOT NULL"
+            }
+        };
+
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            sql,
+            vec![id.into(), owner.into()],
+        )
+    }
+
+    fn purge_stmt(table: &str, cutoff: DateTime<Utc>) -> Statement {
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!("DELETE FROM {table} WHERE is_deleted = true AND deleted_at < $1"),
+            vec![cutoff.into()],
+        )
+    }
+}
+
+fn kind_str(kind: TrashItemKind) -> &'static str {
+    match kind {
+        TrashItemKind::Cv => "cv",
+        TrashItemKind::Project => "project",
+        TrashItemKind::Topic => "topic",
+        TrashItemKind::Media => "media",
+    }
+}
+
+fn parse_kind(value: &str) -> Result<TrashItemKind, TrashRepositoryError> {
+    match value {
+        "cv" => Ok(TrashItemKind::Cv),
+        "project" => Ok(TrashItemKind::Project),
+        "topic" => Ok(TrashItemKind::Topic),
+        "media" => Ok(TrashItemKind::Media),
+        other => Err(TrashRepositoryError::DatabaseError(format!(
+            "unknown trash kind '{other}'"
+        ))),
+    }
+}
+
+fn map_db_err(e: DbErr) -> TrashRepositoryError {
+    TrashRepositoryError::DatabaseError(e.to_string())
+}
+
+#[async_trait]
+impl TrashRepository for TrashRepositoryPostgres {
+    async fn list(
+        &self,
+        owner: UserId,
+        kind: Option<TrashItemKind>,
+        offset: u64,
+        limit: u32,
+    ) -> Result<TrashPage, TrashRepositoryError> {
+        let owner_uuid: Uuid = owner.into();
+
+        let rows = self
+            .db
+            .query_all(Self::list_stmt(owner_uuid, kind, offset, limit))
+            .await
+            .map_err(map_db_err)?;
+
+        let items = rows
+            .iter()
+            .map(|row| {
+                let kind: String = row.try_get("", "kind").map_err(map_db_err)?;
+                Ok(TrashItem {
+                    kind: parse_kind(&kind)?,
+                    id: row.try_get("", "id").map_err(map_db_err)?,
+                    title: row.try_get("", "title").map_err(map_db_err)?,
+                    deleted_at: row.try_get("", "deleted_at").map_err(map_db_err)?,
+                })
+            })
+            .collect::<Result<Vec<_>, TrashRepositoryError>>()?;
+
+        let total: i64 = self
+            .db
+            .query_one(Self::count_stmt(owner_uuid, kind))
+            .await
+            .map_err(map_db_err)?
+            .ok_or_else(|| TrashRepositoryError::DatabaseError("count returned no row".into()))?
+            .try_get("", "total")
+            .map_err(map_db_err)?;
+
+        Ok(TrashPage {
+            items,
+            total: total.max(0) as u64,
+        })
+    }
+
+    async fn restore(
+        &self,
+        owner: UserId,
+        kind: TrashItemKind,
+        id: Uuid,
+    ) -> Result<(), TrashRepositoryError> {
+        let res = self
+            .db
+            .execute(Self::restore_stmt(owner.into(), kind, id))
+            .await
+            .map_err(map_db_err)?;
+
+        if res.rows_affected() == 0 {
+            return Err(TrashRepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn purge_deleted_before(
+        &self,
+        cutoff: DateTime<Utc>,
+    ) -> Result<u64, TrashRepositoryError> {
+        let mut purged = 0;
+        for table in PURGED_TABLES {
+            let res = self
+                .db
+                .execute(Self::purge_stmt(table, cutoff))
+                .await
+                .map_err(map_db_err)?;
+            purged += res.rows_affected();
+        }
+
+        Ok(purged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{MockDatabase, MockExecResult, Value};
+    use std::collections::BTreeMap;
+
+    fn item_row(kind: &str, id: Uuid, title: &str) -> BTreeMap<String, Value> {
+        BTreeMap::from([
+            ("kind".to_string(), Value::from(kind)),
+            ("id".to_string(), Value::from(id)),
+            ("title".to_string(), Value::from(title)),
+            ("deleted_at".to_string(), Value::from(Utc::now())),
+        ])
+    }
+
+    fn total_row(total: i64) -> BTreeMap<String, Value> {
+        BTreeMap::from([("total".to_string(), Value::BigInt(Some(total)))])
+    }
+
+    fn exec(rows_affected: u64) -> MockExecResult {
+        MockExecResult {
+            last_insert_id: 0,
+            rows_affected,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_maps_rows_and_total() {
+        let cv_id = Uuid::new_v4();
+        let media_id = Uuid::new_v4();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![
+                item_row("cv", cv_id, "Jane Doe"),
+                item_row("media", media_id, "photo.png"),
+            ]])
+            .append_query_results(vec![vec![total_row(7)]])
+            .into_connection();
+
+        let repo = TrashRepositoryPostgres::new(Arc::new(db));
+        let page = repo
+            .list(UserId::from(Uuid::new_v4()), None, 0, 2)
+            .await
+            .unwrap();
+
+        assert_eq!(page.total, 7);
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.items[0].kind, TrashItemKind::Cv);
+        assert_eq!(page.items[0].id, cv_id);
+        assert_eq!(page.items[1].kind, TrashItemKind::Media);
+        assert_eq!(page.items[1].title, "photo.png");
+    }
+
+    #[tokio::test]
+    async fn test_list_rejects_unknown_kind() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![item_row("post", Uuid::new_v4(), "x")]])
+            .into_connection();
+
+        let repo = TrashRepositoryPostgres::new(Arc::new(db));
+        let err = repo
+            .list(UserId::from(Uuid::new_v4()), None, 0, 20)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, TrashRepositoryError::DatabaseError(msg) if msg.contains("post")));
+    }
+
+    #[tokio::test]
+    async fn test_restore_success() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([exec(1)])
+            .into_connection();
+
+        let repo = TrashRepositoryPostgres::new(Arc::new(db));
+        let res = repo
+            .restore(
+                UserId::from(Uuid::new_v4()),
+                TrashItemKind::Topic,
+                Uuid::new_v4(),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_restore_not_found() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([exec(0)])
+            .into_connection();
+
+        let repo = TrashRepositoryPostgres::new(Arc::new(db));
+        let res = repo
+            .restore(
+                UserId::from(Uuid::new_v4()),
+                TrashItemKind::Media,
+                Uuid::new_v4(),
+            )
+            .await;
+
+        assert!(matches!(res, Err(TrashRepositoryError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_restore_database_error() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_errors([DbErr::Custom("connection error".to_string())])
+            .into_connection();
+
+        let repo = TrashRepositoryPostgres::new(Arc::new(db));
+        let res = repo
+            .restore(
+                UserId::from(Uuid::new_v4()),
+                TrashItemKind::Cv,
+                Uuid::new_v4(),
+            )
+            .await;
+
+        assert!(matches!(res, Err(TrashRepositoryError::DatabaseError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_purge_sums_rows_across_tables() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([exec(1), exec(2), exec(0)])
+            .into_connection();
+
+        let repo = TrashRepositoryPostgres::new(Arc::new(db));
+        let purged = repo.purge_deleted_before(Utc::now()).await.unwrap();
+
+        assert_eq!(purged, 3);
+    }
+}
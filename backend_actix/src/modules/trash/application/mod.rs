@@ -0,0 +1,3 @@
+pub mod ports;
+pub mod services;
+pub mod trash_use_cases;
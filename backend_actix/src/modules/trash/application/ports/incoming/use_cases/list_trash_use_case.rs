@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::shared::metrics::metered_use_case;
+use crate::trash::application::ports::outgoing::{TrashItemKind, TrashPage};
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ListTrashError {
+    #[error("Failed to list trash: {0}")]
+    QueryFailed(String),
+}
+
+#[async_trait]
+pub trait ListTrashUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        owner: UserId,
+        kind: Option<TrashItemKind>,
+        offset: u64,
+        limit: u32,
+    ) -> Result<TrashPage, ListTrashError>;
+}
+
+metered_use_case!(
+    "trash",
+    "list_trash",
+    ListTrashUseCase,
+    fn execute(
+        &self,
+        owner: UserId,
+        kind: Option<TrashItemKind>,
+        offset: u64,
+        limit: u32,
+    ) -> Result<TrashPage, ListTrashError>
+);
@@ -0,0 +1,5 @@
+mod list_trash_use_case;
+mod restore_trash_item_use_case;
+
+pub use list_trash_use_case::{ListTrashError, ListTrashUseCase};
+pub use restore_trash_item_use_case::{RestoreTrashItemError, RestoreTrashItemUseCase};
@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::shared::metrics::metered_use_case;
+use crate::trash::application::ports::outgoing::TrashItemKind;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum RestoreTrashItemError {
+    #[error("Item not found in trash")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait RestoreTrashItemUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        owner: UserId,
+        kind: TrashItemKind,
+        id: Uuid,
+    ) -> Result<(), RestoreTrashItemError>;
+}
+
+metered_use_case!(
+    "trash",
+    "restore_trash_item",
+    RestoreTrashItemUseCase,
+    fn execute(
+        &self,
+        owner: UserId,
+        kind: TrashItemKind,
+        id: Uuid,
+    ) -> Result<(), RestoreTrashItemError>
+);
@@ -0,0 +1,5 @@
+mod trash_repository;
+
+pub use trash_repository::{
+    TrashItem, TrashItemKind, TrashPage, TrashRepository, TrashRepositoryError,
+};
@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+
+/// What a trashed item is; also the `{type}` segment of the restore route
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TrashItemKind {
+    Cv,
+    Project,
+    Topic,
+    Media,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct TrashItem {
+    #[serde(rename = "type")]
+    pub kind: TrashItemKind,
+    pub id: Uuid,
+    /// CV display name, project or topic title, media file name
+    pub title: String,
+    pub deleted_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrashPage {
+    pub items: Vec<TrashItem>,
+    pub total: u64,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum TrashRepositoryError {
+    /// Item doesn't exist, isn't deleted, or belongs to someone else.
+    #[error("Item not found in trash")]
+    NotFound,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Soft-deleted content across modules, owner-scoped.
+#[async_trait]
+pub trait TrashRepository: Send + Sync {
+    /// Most recently deleted first
+    async fn list(
+        &self,
+        owner: UserId,
+        kind: Option<TrashItemKind>,
+        offset: u64,
+        limit: u32,
+    ) -> Result<TrashPage, TrashRepositoryError>;
+
+    async fn restore(
+        &self,
+        owner: UserId,
+        kind: TrashItemKind,
+        id: Uuid,
+    ) -> Result<(), TrashRepositoryError>;
+
+    /// Hard-delete CVs, projects and topics trashed before `cutoff`; returns how many.
+    /// Media is left alone: its files live in storage and need their own cleanup.
+    async fn purge_deleted_before(
+        &self,
+        cutoff: DateTime<Utc>,
+    ) -> Result<u64, TrashRepositoryError>;
+}
@@ -0,0 +1,138 @@
+use async_trait::async_trait;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::trash::application::ports::{
+    incoming::use_cases::{ListTrashError, ListTrashUseCase},
+    outgoing::{TrashItemKind, TrashPage, TrashRepository},
+};
+
+pub struct ListTrashService<R>
+where
+    R: TrashRepository,
+{
+    repository: R,
+}
+
+impl<R> ListTrashService<R>
+where
+    R: TrashRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> ListTrashUseCase for ListTrashService<R>
+where
+    R: TrashRepository,
+{
+    async fn execute(
+        &self,
+        owner: UserId,
+        kind: Option<TrashItemKind>,
+        offset: u64,
+        limit: u32,
+    ) -> Result<TrashPage, ListTrashError> {
+        self.repository
+            .list(owner, kind, offset, limit)
+            .await
+            .map_err(|e| ListTrashError::QueryFailed(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+    use std::sync::{Arc, Mutex};
+    use uuid::Uuid;
+
+    use crate::trash::application::ports::outgoing::{TrashItem, TrashRepositoryError};
+
+    /// Kind filter, offset and limit of one listing
+    type ListCall = (Option<TrashItemKind>, u64, u32);
+
+    #[derive(Clone, Default)]
+    struct MockTrashRepository {
+        fail: bool,
+        calls: Arc<Mutex<Vec<ListCall>>>,
+    }
+
+    #[async_trait]
+    impl TrashRepository for MockTrashRepository {
+        async fn list(
+            &self,
+            _owner: UserId,
+            kind: Option<TrashItemKind>,
+            offset: u64,
+            limit: u32,
+        ) -> Result<TrashPage, TrashRepositoryError> {
+            self.calls.lock().unwrap().push((kind, offset, limit));
+            if self.fail {
+                return Err(TrashRepositoryError::DatabaseError("db down".to_string()));
+            }
+            Ok(TrashPage {
+                items: vec![TrashItem {
+                    kind: TrashItemKind::Project,
+                    id: Uuid::new_v4(),
+                    title: "Old project".to_string(),
+                    deleted_at: Utc::now(),
+                }],
+                total: 1,
+            })
+        }
+
+        async fn restore(
+            &self,
+            _owner: UserId,
+            _kind: TrashItemKind,
+            _id: Uuid,
+        ) -> Result<(), TrashRepositoryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn purge_deleted_before(
+            &self,
+            _cutoff: DateTime<Utc>,
+        ) -> Result<u64, TrashRepositoryError> {
+            unimplemented!("not needed for these tests")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_trash_passes_filter_and_page() {
+        let repository = MockTrashRepository::default();
+        let service = ListTrashService::new(repository.clone());
+
+        let page = service
+            .execute(
+                UserId::from(Uuid::new_v4()),
+                Some(TrashItemKind::Project),
+                20,
+                10,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(page.total, 1);
+        assert_eq!(
+            *repository.calls.lock().unwrap(),
+            vec![(Some(TrashItemKind::Project), 20, 10)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_trash_maps_repository_error() {
+        let service = ListTrashService::new(MockTrashRepository {
+            fail: true,
+            ..Default::default()
+        });
+
+        let result = service
+            .execute(UserId::from(Uuid::new_v4()), None, 0, 20)
+            .await;
+
+        assert!(matches!(result, Err(ListTrashError::QueryFailed(msg)) if msg.contains("db down")));
+    }
+}
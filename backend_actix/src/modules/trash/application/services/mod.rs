@@ -0,0 +1,7 @@
+mod list_trash_service;
+mod restore_trash_item_service;
+mod trash_purger;
+
+pub use list_trash_service::ListTrashService;
+pub use restore_trash_item_service::RestoreTrashItemService;
+pub use trash_purger::TrashPurger;
@@ -0,0 +1,179 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::shared::cache::{self, CachePort};
+use crate::trash::application::ports::{
+    incoming::use_cases::{RestoreTrashItemError, RestoreTrashItemUseCase},
+    outgoing::{TrashItemKind, TrashRepository, TrashRepositoryError},
+};
+use crate::{cv, project};
+
+pub struct RestoreTrashItemService<R>
+where
+    R: TrashRepository,
+{
+    repository: R,
+    cache: Arc<dyn CachePort>,
+}
+
+impl<R> RestoreTrashItemService<R>
+where
+    R: TrashRepository,
+{
+    pub fn new(repository: R, cache: Arc<dyn CachePort>) -> Self {
+        Self { repository, cache }
+    }
+}
+
+#[async_trait]
+impl<R> RestoreTrashItemUseCase for RestoreTrashItemService<R>
+where
+    R: TrashRepository,
+{
+    async fn execute(
+        &self,
+        owner: UserId,
+        kind: TrashItemKind,
+        id: Uuid,
+    ) -> Result<(), RestoreTrashItemError> {
+        self.repository
+            .restore(owner, kind, id)
+            .await
+            .map_err(|e| match e {
+                TrashRepositoryError::NotFound => RestoreTrashItemError::NotFound,
+                TrashRepositoryError::DatabaseError(msg) => {
+                    RestoreTrashItemError::RepositoryError(msg)
+                }
+            })?;
+
+        // Listings may have been cached while the item was gone
+        match kind {
+            TrashItemKind::Cv => {
+                cache::invalidate(
+                    self.cache.as_ref(),
+                    &cv::application::cache_keys::public_cv(id),
+                )
+                .await
+            }
+            TrashItemKind::Project => {
+                cache::invalidate_prefix(
+                    self.cache.as_ref(),
+                    &project::application::cache_keys::owner_prefix(owner),
+                )
+                .await
+            }
+            TrashItemKind::Topic | TrashItemKind::Media => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+
+    use crate::shared::cache::NoopCache;
+    use crate::tests::support::stubs::InMemoryCache;
+    use crate::trash::application::ports::outgoing::TrashPage;
+
+    #[derive(Clone)]
+    struct MockTrashRepository {
+        result: Result<(), TrashRepositoryError>,
+    }
+
+    #[async_trait]
+    impl TrashRepository for MockTrashRepository {
+        async fn list(
+            &self,
+            _owner: UserId,
+            _kind: Option<TrashItemKind>,
+            _offset: u64,
+            _limit: u32,
+        ) -> Result<TrashPage, TrashRepositoryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn restore(
+            &self,
+            _owner: UserId,
+            _kind: TrashItemKind,
+            _id: Uuid,
+        ) -> Result<(), TrashRepositoryError> {
+            self.result.clone()
+        }
+
+        async fn purge_deleted_before(
+            &self,
+            _cutoff: DateTime<Utc>,
+        ) -> Result<u64, TrashRepositoryError> {
+            unimplemented!("not needed for these tests")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_restore_project_invalidates_owner_cache() {
+        let owner = UserId::from(Uuid::new_v4());
+        let cache = InMemoryCache::default();
+        let key = project::application::cache_keys::by_slug(owner, "slug");
+        cache.set(&key, "{}".to_string()).await.unwrap();
+
+        let service = RestoreTrashItemService::new(
+            MockTrashRepository { result: Ok(()) },
+            Arc::new(cache.clone()),
+        );
+
+        service
+            .execute(owner, TrashItemKind::Project, Uuid::new_v4())
+            .await
+            .unwrap();
+
+        assert!(cache.keys().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_restore_maps_not_found() {
+        let service = RestoreTrashItemService::new(
+            MockTrashRepository {
+                result: Err(TrashRepositoryError::NotFound),
+            },
+            Arc::new(NoopCache),
+        );
+
+        let result = service
+            .execute(
+                UserId::from(Uuid::new_v4()),
+                TrashItemKind::Cv,
+                Uuid::new_v4(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(RestoreTrashItemError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_restore_maps_database_error() {
+        let service = RestoreTrashItemService::new(
+            MockTrashRepository {
+                result: Err(TrashRepositoryError::DatabaseError("db down".to_string())),
+            },
+            Arc::new(NoopCache),
+        );
+
+        let result = service
+            .execute(
+                UserId::from(Uuid::new_v4()),
+                TrashItemKind::Topic,
+                Uuid::new_v4(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(RestoreTrashItemError::RepositoryError(msg)) if msg == "db down"
+        ));
+    }
+}
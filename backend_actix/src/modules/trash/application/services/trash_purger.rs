@@ -0,0 +1,124 @@
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::shared::lifecycle::ShutdownSignal;
+use crate::trash::application::ports::outgoing::{TrashRepository, TrashRepositoryError};
+
+/// Hard-deletes trashed items once they are older than the retention period.
+///
+/// Runs as a background job (`BackgroundJobs`) and is only started when a
+/// retention period is configured.
+pub struct TrashPurger<R>
+where
+    R: TrashRepository,
+{
+    repository: R,
+    retention: chrono::Duration,
+    interval: Duration,
+}
+
+impl<R> TrashPurger<R>
+where
+    R: TrashRepository,
+{
+    pub fn new(repository: R, retention: chrono::Duration, interval: Duration) -> Self {
+        Self {
+            repository,
+            retention,
+            interval,
+        }
+    }
+
+    pub async fn run(self, mut signal: ShutdownSignal) {
+        info!(
+            retention_days = self.retention.num_days(),
+            "Trash purger started"
+        );
+
+        loop {
+            tokio::select! {
+                _ = signal.wait() => break,
+                _ = tokio::time::sleep(self.interval) => {}
+            }
+
+            match self.purge_expired(Utc::now()).await {
+                Ok(0) => {}
+                Ok(purged) => info!(purged, "Purged expired trash"),
+                Err(e) => warn!(error = %e, "Trash purge failed"),
+            }
+        }
+    }
+
+    /// Delete everything trashed more than `retention` before `now`.
+    pub async fn purge_expired(&self, now: DateTime<Utc>) -> Result<u64, TrashRepositoryError> {
+        self.repository
+            .purge_deleted_before(now - self.retention)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+    use uuid::Uuid;
+
+    use crate::auth::application::domain::entities::UserId;
+    use crate::trash::application::ports::outgoing::{TrashItemKind, TrashPage};
+
+    #[derive(Clone, Default)]
+    struct MockTrashRepository {
+        cutoffs: Arc<Mutex<Vec<DateTime<Utc>>>>,
+    }
+
+    #[async_trait]
+    impl TrashRepository for MockTrashRepository {
+        async fn list(
+            &self,
+            _owner: UserId,
+            _kind: Option<TrashItemKind>,
+            _offset: u64,
+            _limit: u32,
+        ) -> Result<TrashPage, TrashRepositoryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn restore(
+            &self,
+            _owner: UserId,
+            _kind: TrashItemKind,
+            _id: Uuid,
+        ) -> Result<(), TrashRepositoryError> {
+            unimplemented!("not needed for these tests")
+        }
+
+        async fn purge_deleted_before(
+            &self,
+            cutoff: DateTime<Utc>,
+        ) -> Result<u64, TrashRepositoryError> {
+            self.cutoffs.lock().unwrap().push(cutoff);
+            Ok(3)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_purge_expired_uses_retention_cutoff() {
+        let repository = MockTrashRepository::default();
+        let purger = TrashPurger::new(
+            repository.clone(),
+            chrono::Duration::days(30),
+            Duration::from_secs(3600),
+        );
+        let now = Utc::now();
+
+        let purged = purger.purge_expired(now).await.unwrap();
+
+        assert_eq!(purged, 3);
+        assert_eq!(
+            *repository.cutoffs.lock().unwrap(),
+            vec![now - chrono::Duration::days(30)]
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use crate::shared::cache::CachePort;
+use crate::shared::metrics::Metered;
+use crate::trash::application::ports::incoming::use_cases::{
+    ListTrashUseCase, RestoreTrashItemUseCase,
+};
+use crate::trash::application::ports::outgoing::TrashRepository;
+use crate::trash::application::services::{ListTrashService, RestoreTrashItemService};
+
+#[derive(Clone)]
+pub struct TrashUseCases {
+    pub list: Arc<dyn ListTrashUseCase + Send + Sync>,
+    pub restore: Arc<dyn RestoreTrashItemUseCase + Send + Sync>,
+}
+
+impl TrashUseCases {
+    pub fn build<R>(repository: R, cache: Arc<dyn CachePort>) -> Self
+    where
+        R: TrashRepository + Clone + 'static,
+    {
+        Self {
+            list: Arc::new(Metered(ListTrashService::new(repository.clone()))),
+            restore: Arc::new(Metered(RestoreTrashItemService::new(repository, cache))),
+        }
+    }
+}
@@ -0,0 +1,7 @@
+use crate::shared::api::error_codes;
+
+error_codes! {
+    INVALID_WEBHOOK_URL = (BAD_REQUEST, "URL must be an absolute http(s) URL");
+    NO_WEBHOOK_EVENTS = (BAD_REQUEST, "At least one event is required");
+    WEBHOOK_NOT_FOUND = (NOT_FOUND, "Webhook not found");
+}
@@ -0,0 +1,12 @@
+use actix_web::web;
+
+pub mod error_codes;
+pub mod routes;
+
+/// Routes for signed-in users with a verified email
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(routes::create_webhook_handler)
+        .service(routes::list_webhooks_handler)
+        .service(routes::delete_webhook_handler)
+        .service(routes::list_webhook_deliveries_handler);
+}
@@ -0,0 +1,165 @@
+use actix_web::{post, web, HttpResponse, Responder};
+use serde::Deserialize;
+use tracing::error;
+use utoipa::ToSchema;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::webhooks::adapter::incoming::web::error_codes::{
+    INVALID_WEBHOOK_URL, NO_WEBHOOK_EVENTS,
+};
+use crate::{
+    auth::{
+        adapter::incoming::web::extractors::auth::VerifiedUser,
+        application::domain::entities::UserId,
+    },
+    shared::api::ApiResponse,
+    webhooks::application::{
+        domain::entities::WebhookEvent,
+        ports::incoming::use_cases::{
+            CreateWebhookCommand, CreateWebhookCommandError, CreateWebhookError, CreatedWebhook,
+        },
+    },
+    AppState,
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateWebhookRequest {
+    /// Absolute http(s) URL that receives the POSTs
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+}
+
+/// Register a webhook endpoint
+///
+/// The response carries the signing secret; it is not shown again.
+#[utoipa::path(
+    post,
+    path = "/api/webhooks",
+    tag = "webhooks",
+    request_body = CreateWebhookRequest,
+    responses(
+        (status = 201, description = "Endpoint registered", body = inline(SuccessResponse<CreatedWebhook>)),
+        (status = 400, description = "Invalid URL, no events or unknown event", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[post("/api/webhooks")]
+pub async fn create_webhook_handler(
+    user: VerifiedUser,
+    data: web::Data<AppState>,
+    payload: web::Json<CreateWebhookRequest>,
+) -> impl Responder {
+    let payload = payload.into_inner();
+    let command =
+        match CreateWebhookCommand::new(UserId::from(user.user_id), payload.url, payload.events) {
+            Ok(cmd) => cmd,
+            Err(err) => return map_command_error(err),
+        };
+
+    match data.webhooks.create.execute(command).await {
+        Ok(created) => ApiResponse::created(created),
+        Err(CreateWebhookError::RepositoryError(msg)) => {
+            error!("Failed to create webhook: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+fn map_command_error(err: CreateWebhookCommandError) -> HttpResponse {
+    match err {
+        CreateWebhookCommandError::InvalidUrl => INVALID_WEBHOOK_URL.response(),
+        CreateWebhookCommandError::NoEvents => NO_WEBHOOK_EVENTS.response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, web, App};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        shared::api::custom_json_config,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubCreateWebhookUseCase,
+        },
+    };
+
+    async fn call(body: serde_json::Value) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_create_webhook(StubCreateWebhookUseCase)
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .app_data(custom_json_config())
+                .service(create_webhook_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/webhooks")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .set_json(body)
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_create_returns_endpoint_with_secret() {
+        let resp = call(serde_json::json!({
+            "url": "https://builder.example.com/rebuild",
+            "events": ["project.updated", "media.ready"]
+        }))
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["url"], "https://builder.example.com/rebuild");
+        assert_eq!(
+            json["data"]["events"],
+            serde_json::json!(["media.ready", "project.updated"])
+        );
+        assert!(json["data"]["secret"].is_string());
+    }
+
+    #[actix_web::test]
+    async fn test_invalid_url_is_rejected() {
+        let resp = call(serde_json::json!({
+            "url": "ftp://builder.example.com",
+            "events": ["project.updated"]
+        }))
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "INVALID_WEBHOOK_URL");
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_event_is_rejected() {
+        let resp = call(serde_json::json!({
+            "url": "https://builder.example.com/rebuild",
+            "events": ["post.published"]
+        }))
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "VALIDATION_ERROR");
+    }
+}
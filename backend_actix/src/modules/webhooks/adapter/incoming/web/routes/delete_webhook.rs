@@ -0,0 +1,116 @@
+use actix_web::{delete, web, HttpResponse, Responder};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::api::schemas::ErrorResponse;
+use crate::webhooks::adapter::incoming::web::error_codes::WEBHOOK_NOT_FOUND;
+use crate::{
+    auth::{
+        adapter::incoming::web::extractors::auth::VerifiedUser,
+        application::domain::entities::UserId,
+    },
+    shared::api::ApiResponse,
+    webhooks::application::ports::incoming::use_cases::DeleteWebhookError,
+    AppState,
+};
+
+/// Remove a webhook endpoint, its delivery log and anything still queued for it
+#[utoipa::path(
+    delete,
+    path = "/api/webhooks/{webhook_id}",
+    tag = "webhooks",
+    params(
+        ("webhook_id" = Uuid, Path, description = "Endpoint id"),
+    ),
+    responses(
+        (status = 204, description = "Endpoint removed"),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Endpoint not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[delete("/api/webhooks/{webhook_id}")]
+pub async fn delete_webhook_handler(
+    user: VerifiedUser,
+    path: web::Path<Uuid>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data
+        .webhooks
+        .delete
+        .execute(UserId::from(user.user_id), path.into_inner())
+        .await
+    {
+        Ok(()) => ApiResponse::no_content(),
+        Err(err) => map_delete_error(err),
+    }
+}
+
+fn map_delete_error(err: DeleteWebhookError) -> HttpResponse {
+    match err {
+        DeleteWebhookError::NotFound => WEBHOOK_NOT_FOUND.response(),
+        DeleteWebhookError::RepositoryError(msg) => {
+            error!("Failed to delete webhook: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, web, App};
+    use std::sync::Arc;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubDeleteWebhookUseCase,
+        },
+    };
+
+    async fn call(stub: StubDeleteWebhookUseCase) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_delete_webhook(stub)
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(delete_webhook_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/webhooks/{}", Uuid::new_v4()))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_delete_returns_no_content() {
+        let resp = call(StubDeleteWebhookUseCase::success()).await;
+
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_unknown_webhook_returns_not_found() {
+        let resp = call(StubDeleteWebhookUseCase::not_found()).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["error"]["code"], "WEBHOOK_NOT_FOUND");
+    }
+}
@@ -0,0 +1,133 @@
+use actix_web::{get, web, HttpResponse, Responder};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::webhooks::adapter::incoming::web::error_codes::WEBHOOK_NOT_FOUND;
+use crate::{
+    auth::{
+        adapter::incoming::web::extractors::auth::VerifiedUser,
+        application::domain::entities::UserId,
+    },
+    shared::api::{
+        pagination::{PageParams, PagedResponse},
+        ApiResponse,
+    },
+    webhooks::application::{
+        domain::entities::WebhookDelivery, ports::incoming::use_cases::ListWebhookDeliveriesError,
+    },
+    AppState,
+};
+
+/// Delivery log of one webhook endpoint, newest first
+#[utoipa::path(
+    get,
+    path = "/api/webhooks/{webhook_id}/deliveries",
+    tag = "webhooks",
+    params(
+        ("webhook_id" = Uuid, Path, description = "Endpoint id"),
+        ("limit" = Option<u32>, Query, description = "Page size, 1-100 (default 20)"),
+        ("cursor" = Option<String>, Query, description = "`next_cursor` from the previous page"),
+    ),
+    responses(
+        (status = 200, description = "One page of deliveries", body = inline(SuccessResponse<PagedResponse<WebhookDelivery>>)),
+        (status = 400, description = "Invalid pagination parameters", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 404, description = "Endpoint not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/webhooks/{webhook_id}/deliveries")]
+pub async fn list_webhook_deliveries_handler(
+    user: VerifiedUser,
+    path: web::Path<Uuid>,
+    page: PageParams,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data
+        .webhooks
+        .list_deliveries
+        .execute(
+            UserId::from(user.user_id),
+            path.into_inner(),
+            page.offset,
+            page.limit,
+        )
+        .await
+    {
+        Ok(result) => ApiResponse::success(PagedResponse::new(result.items, result.total, &page)),
+        Err(err) => map_list_error(err),
+    }
+}
+
+fn map_list_error(err: ListWebhookDeliveriesError) -> HttpResponse {
+    match err {
+        ListWebhookDeliveriesError::NotFound => WEBHOOK_NOT_FOUND.response(),
+        ListWebhookDeliveriesError::QueryFailed(msg) => {
+            error!("Failed to list webhook deliveries: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, web, App};
+    use std::sync::Arc;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service,
+            stubs::StubListWebhookDeliveriesUseCase,
+        },
+    };
+
+    async fn call(stub: StubListWebhookDeliveriesUseCase) -> actix_web::dev::ServiceResponse {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_list_webhook_deliveries(stub)
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(list_webhook_deliveries_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/api/webhooks/{}/deliveries", Uuid::new_v4()))
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_deliveries_are_listed() {
+        let resp = call(StubListWebhookDeliveriesUseCase::one_failed()).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["total"], 1);
+        assert_eq!(json["data"]["items"][0]["event"], "media.ready");
+        assert_eq!(json["data"]["items"][0]["last_status_code"], 502);
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_webhook_returns_not_found() {
+        let resp = call(StubListWebhookDeliveriesUseCase::not_found()).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
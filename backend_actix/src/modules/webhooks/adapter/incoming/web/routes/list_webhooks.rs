@@ -0,0 +1,98 @@
+use actix_web::{get, web, Responder};
+use tracing::error;
+
+use crate::api::schemas::{ErrorResponse, SuccessResponse};
+use crate::{
+    auth::{
+        adapter::incoming::web::extractors::auth::VerifiedUser,
+        application::domain::entities::UserId,
+    },
+    shared::api::{
+        pagination::{PageParams, PagedResponse},
+        ApiResponse,
+    },
+    webhooks::application::{
+        domain::entities::WebhookEndpoint, ports::incoming::use_cases::ListWebhooksError,
+    },
+    AppState,
+};
+
+/// List the current user's webhook endpoints
+#[utoipa::path(
+    get,
+    path = "/api/webhooks",
+    tag = "webhooks",
+    params(
+        ("limit" = Option<u32>, Query, description = "Page size, 1-100 (default 20)"),
+        ("cursor" = Option<String>, Query, description = "`next_cursor` from the previous page"),
+    ),
+    responses(
+        (status = 200, description = "One page of endpoints, oldest first", body = inline(SuccessResponse<PagedResponse<WebhookEndpoint>>)),
+        (status = 400, description = "Invalid pagination parameters", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid access token", body = ErrorResponse),
+        (status = 403, description = "Email not verified", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("BearerAuth" = [])
+    )
+)]
+#[get("/api/webhooks")]
+pub async fn list_webhooks_handler(
+    user: VerifiedUser,
+    page: PageParams,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match data.webhooks.list.execute(UserId::from(user.user_id)).await {
+        Ok(endpoints) => ApiResponse::success(PagedResponse::from_all(endpoints, &page)),
+        Err(ListWebhooksError::QueryFailed(msg)) => {
+            error!("Failed to list webhooks: {}", msg);
+            ApiResponse::internal_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, web, App};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::application::ports::outgoing::token_provider::TokenProvider,
+        tests::support::{
+            app_state_builder::TestAppStateBuilder,
+            auth_helper::test_helpers::create_test_jwt_service, stubs::StubListWebhooksUseCase,
+        },
+    };
+
+    #[actix_web::test]
+    async fn test_secrets_are_not_listed() {
+        let jwt = create_test_jwt_service();
+        let token = jwt.generate_access_token(Uuid::new_v4(), true, 0).unwrap();
+        let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt);
+        let state = TestAppStateBuilder::default()
+            .with_list_webhooks(StubListWebhooksUseCase::one())
+            .build();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(state)
+                .app_data(web::Data::new(token_provider))
+                .service(list_webhooks_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/api/webhooks")
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(json["data"]["total"], 1);
+        assert!(json["data"]["items"][0].get("secret").is_none());
+    }
+}
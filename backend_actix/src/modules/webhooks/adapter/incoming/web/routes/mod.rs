@@ -0,0 +1,11 @@
+mod create_webhook;
+mod delete_webhook;
+mod list_webhook_deliveries;
+mod list_webhooks;
+
+pub use create_webhook::{__path_create_webhook_handler, create_webhook_handler};
+pub use delete_webhook::{__path_delete_webhook_handler, delete_webhook_handler};
+pub use list_webhook_deliveries::{
+    __path_list_webhook_deliveries_handler, list_webhook_deliveries_handler,
+};
+pub use list_webhooks::{__path_list_webhooks_handler, list_webhooks_handler};
@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use std::time::Duration;
+
+use crate::webhooks::application::ports::outgoing::{
+    WebhookRequest, WebhookSendError, WebhookSender,
+};
+
+/// Sends deliveries over HTTP. Redirects are not followed: the registered URL is
+/// the one that gets the signed payload.
+#[derive(Clone)]
+pub struct HttpWebhookSender {
+    client: reqwest::Client,
+}
+
+impl HttpWebhookSender {
+    pub fn new(timeout: Duration) -> Result<Self, WebhookSendError> {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .redirect(reqwest::redirect::Policy::none())
+            .user_agent(concat!(
+                "port-blog-cms-webhooks/",
+                env!("CARGO_PKG_VERSION")
+            ))
+            .build()
+            .map_err(|e| WebhookSendError::RequestFailed(e.to_string()))?;
+
+        Ok(Self { client })
+    }
+}
+
+#[async_trait]
+impl WebhookSender for HttpWebhookSender {
+    async fn send(&self, request: WebhookRequest) -> Result<u16, WebhookSendError> {
+        let mut builder = self.client.post(&request.url);
+        for (name, value) in request.headers {
+            builder = builder.header(name, value);
+        }
+
+        let response = builder
+            .body(request.body)
+            .send()
+            .await
+            .map_err(|e| WebhookSendError::RequestFailed(e.to_string()))?;
+
+        Ok(response.status().as_u16())
+    }
+}
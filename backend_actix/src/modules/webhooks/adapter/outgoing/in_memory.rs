@@ -0,0 +1,91 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::shared::in_memory::Table;
+use crate::webhooks::application::domain::entities::{WebhookEndpoint, WebhookEvent};
+use crate::webhooks::application::ports::outgoing::{
+    DeliveryPage, WebhookRepository, WebhookRepositoryError,
+};
+
+struct EndpointRow {
+    owner: UserId,
+    endpoint: WebhookEndpoint,
+}
+
+/// Process-local `WebhookRepository`. Deliveries are queued by Postgres
+/// triggers, which have no in-memory counterpart, so endpoints can be
+/// managed but their delivery log stays empty.
+#[derive(Clone, Default)]
+pub struct InMemoryWebhookStore {
+    endpoints: Table<EndpointRow>,
+}
+
+#[async_trait]
+impl WebhookRepository for InMemoryWebhookStore {
+    async fn create(
+        &self,
+        owner: UserId,
+        url: &str,
+        events: &[WebhookEvent],
+        _secret: &str,
+    ) -> Result<WebhookEndpoint, WebhookRepositoryError> {
+        let endpoint = WebhookEndpoint {
+            id: Uuid::new_v4(),
+            url: url.to_string(),
+            events: events.to_vec(),
+            created_at: Utc::now(),
+        };
+        self.endpoints.write(|endpoints| {
+            endpoints.push(EndpointRow {
+                owner,
+                endpoint: endpoint.clone(),
+            })
+        });
+        Ok(endpoint)
+    }
+
+    async fn list(&self, owner: UserId) -> Result<Vec<WebhookEndpoint>, WebhookRepositoryError> {
+        Ok(self.endpoints.read(|endpoints| {
+            endpoints
+                .iter()
+                .filter(|row| row.owner == owner)
+                .map(|row| row.endpoint.clone())
+                .collect()
+        }))
+    }
+
+    async fn delete(&self, owner: UserId, id: Uuid) -> Result<(), WebhookRepositoryError> {
+        self.endpoints.write(|endpoints| {
+            let before = endpoints.len();
+            endpoints.retain(|row| !(row.owner == owner && row.endpoint.id == id));
+            if endpoints.len() == before {
+                return Err(WebhookRepositoryError::NotFound);
+            }
+            Ok(())
+        })
+    }
+
+    async fn list_deliveries(
+        &self,
+        owner: UserId,
+        endpoint_id: Uuid,
+        _offset: u64,
+        _limit: u32,
+    ) -> Result<DeliveryPage, WebhookRepositoryError> {
+        let exists = self.endpoints.read(|endpoints| {
+            endpoints
+                .iter()
+                .any(|row| row.owner == owner && row.endpoint.id == endpoint_id)
+        });
+        if !exists {
+            return Err(WebhookRepositoryError::NotFound);
+        }
+
+        Ok(DeliveryPage {
+            items: Vec::new(),
+            total: 0,
+        })
+    }
+}
@@ -0,0 +1,9 @@
+mod http_webhook_sender;
+mod in_memory;
+mod webhook_outbox_postgres;
+mod webhook_repository_postgres;
+
+pub use http_webhook_sender::HttpWebhookSender;
+pub use in_memory::InMemoryWebhookStore;
+pub use webhook_outbox_postgres::WebhookOutboxPostgres;
+pub use webhook_repository_postgres::WebhookRepositoryPostgres;
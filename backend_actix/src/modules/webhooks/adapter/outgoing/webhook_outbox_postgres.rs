@@ -0,0 +1,222 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::shared::sql;
+use crate::webhooks::application::ports::outgoing::{
+    PendingDelivery, WebhookOutbox, WebhookOutboxError,
+};
+
+#[derive(Clone)]
+pub struct WebhookOutboxPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl WebhookOutboxPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    // SKIP LOCKED + the lease keep concurrent dispatchers off each other's rows.
+    // The endpoint is read in subqueries: SQLite's RETURNING can't see joins.
+    fn claim_due_stmt(
+        backend: DatabaseBackend,
+        limit: u32,
+        lease_until: DateTime<Utc>,
+    ) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+            UPDATE webhook_deliveries
+            SET next_attempt_at = $2,
+                attempts = attempts + 1
+            WHERE id IN (
+                SELECT id
+                FROM webhook_deliveries
+                WHERE next_attempt_at IS NOT NULL
+                  AND next_attempt_at <= {now}
+                ORDER BY next_attempt_at
+                LIMIT $1
+                {skip_locked}
+            )
+            RETURNING id, event, payload, attempts,
+                (SELECT e.url FROM webhook_endpoints e WHERE e.id = endpoint_id) AS url,
+                (SELECT e.secret FROM webhook_endpoints e WHERE e.id = endpoint_id) AS secret
+            "#,
+                now = sql::now(backend),
+                skip_locked = sql::skip_locked(backend),
+            ),
+            vec![(limit as i64).into(), lease_until.into()],
+        )
+    }
+
+    fn mark_delivered_stmt(backend: DatabaseBackend, id: Uuid, status_code: u16) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+            UPDATE webhook_deliveries
+            SET delivered_at = {},
+                next_attempt_at = NULL,
+                last_status_code = $2,
+                last_error = NULL
+            WHERE id = $1
+            "#,
+                sql::now(backend)
+            ),
+            vec![id.into(), (status_code as i32).into()],
+        )
+    }
+
+    fn mark_failed_stmt(
+        id: Uuid,
+        status_code: Option<u16>,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Statement {
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"
+            UPDATE webhook_deliveries
+            SET next_attempt_at = $2,
+                last_status_code = $3,
+                last_error = $4
+            WHERE id = $1
+            "#,
+            vec![
+                id.into(),
+                retry_at.into(),
+                status_code.map(i32::from).into(),
+                error.into(),
+            ],
+        )
+    }
+
+    // =====================================================
+    // Mapping
+    // =====================================================
+
+    fn to_pending(row: QueryResult) -> Result<PendingDelivery, WebhookOutboxError> {
+        let attempts: i32 = row.try_get("", "attempts").map_err(Self::map_db_err)?;
+
+        Ok(PendingDelivery {
+            id: row.try_get("", "id").map_err(Self::map_db_err)?,
+            event: row.try_get("", "event").map_err(Self::map_db_err)?,
+            url: row.try_get("", "url").map_err(Self::map_db_err)?,
+            secret: row.try_get("", "secret").map_err(Self::map_db_err)?,
+            payload: row.try_get("", "payload").map_err(Self::map_db_err)?,
+            attempts: attempts.max(0) as u32,
+        })
+    }
+
+    fn map_db_err(e: DbErr) -> WebhookOutboxError {
+        WebhookOutboxError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl WebhookOutbox for WebhookOutboxPostgres {
+    async fn claim_due(
+        &self,
+        limit: u32,
+        lease_until: DateTime<Utc>,
+    ) -> Result<Vec<PendingDelivery>, WebhookOutboxError> {
+        self.db
+            .query_all(Self::claim_due_stmt(
+                self.db.get_database_backend(),
+                limit,
+                lease_until,
+            ))
+            .await
+            .map_err(Self::map_db_err)?
+            .into_iter()
+            .map(Self::to_pending)
+            .collect()
+    }
+
+    async fn mark_delivered(&self, id: Uuid, status_code: u16) -> Result<(), WebhookOutboxError> {
+        self.db
+            .execute(Self::mark_delivered_stmt(
+                self.db.get_database_backend(),
+                id,
+                status_code,
+            ))
+            .await
+            .map_err(Self::map_db_err)?;
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        status_code: Option<u16>,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), WebhookOutboxError> {
+        self.db
+            .execute(Self::mark_failed_stmt(id, status_code, error, retry_at))
+            .await
+            .map_err(Self::map_db_err)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{MockDatabase, Value};
+    use std::collections::BTreeMap;
+
+    #[tokio::test]
+    async fn test_claim_due_maps_rows() {
+        let id = Uuid::new_v4();
+        let row = BTreeMap::from([
+            ("id".to_string(), Value::from(id)),
+            ("event".to_string(), Value::from("media.ready")),
+            (
+                "payload".to_string(),
+                Value::from(serde_json::json!({ "event": "media.ready" })),
+            ),
+            ("attempts".to_string(), Value::Int(Some(1))),
+            ("url".to_string(), Value::from("https://example.com/hook")),
+            ("secret".to_string(), Value::from("whsec_x")),
+        ]);
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![row]])
+            .into_connection();
+
+        let outbox = WebhookOutboxPostgres::new(Arc::new(db));
+        let claimed = outbox.claim_due(10, Utc::now()).await.unwrap();
+
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(claimed[0].id, id);
+        assert_eq!(claimed[0].attempts, 1);
+        assert_eq!(claimed[0].payload["event"], "media.ready");
+    }
+
+    #[tokio::test]
+    async fn test_database_error_is_mapped() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_errors([DbErr::Custom("connection lost".into())])
+            .into_connection();
+
+        let outbox = WebhookOutboxPostgres::new(Arc::new(db));
+        let err = outbox
+            .mark_delivered(Uuid::new_v4(), 200)
+            .await
+            .unwrap_err();
+
+        match err {
+            WebhookOutboxError::DatabaseError(msg) => assert!(msg.contains("connection lost")),
+        }
+    }
+}
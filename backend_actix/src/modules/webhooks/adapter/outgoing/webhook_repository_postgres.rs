@@ -0,0 +1,391 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, QueryResult, Statement,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::webhooks::application::domain::entities::{
+    WebhookDelivery, WebhookEndpoint, WebhookEvent,
+};
+use crate::webhooks::application::ports::outgoing::{
+    DeliveryPage, WebhookRepository, WebhookRepositoryError,
+};
+
+// `events` is TEXT[]; it crosses the driver as a comma-joined string so no
+// array support is needed. Event names never contain commas. On SQLite the
+// column already is that string.
+fn endpoint_columns(backend: DatabaseBackend) -> &'static str {
+    match backend {
+        DatabaseBackend::Sqlite => "id, url, events, created_at",
+        _ => "id, url, array_to_string(events, ',') AS events, created_at",
+    }
+}
+
+#[derive(Clone)]
+pub struct WebhookRepositoryPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl WebhookRepositoryPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // =====================================================
+    // SQL builders
+    // =====================================================
+
+    fn insert_stmt(
+        backend: DatabaseBackend,
+        owner: Uuid,
+        url: &str,
+        events: &[WebhookEvent],
+        secret: &str,
+    ) -> Statement {
+        let events = events
+            .iter()
+            .map(WebhookEvent::as_str)
+            .collect::<Vec<_>>()
+            .join(",");
+        let events_value = match backend {
+            DatabaseBackend::Sqlite => "$4",
+            _ => "string_to_array($4, ',')",
+        };
+
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                INSERT INTO webhook_endpoints (user_id, url, secret, events)
+                VALUES ($1, $2, $3, {events_value})
+                RETURNING {columns}
+                "#,
+                columns = endpoint_columns(backend),
+            ),
+            vec![owner.into(), url.into(), secret.into(), events.into()],
+        )
+    }
+
+    fn list_stmt(backend: DatabaseBackend, owner: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            backend,
+            format!(
+                r#"
+                SELECT {columns}
+                FROM webhook_endpoints
+                WHERE user_id = $1
+                ORDER BY created_at
+                "#,
+                columns = endpoint_columns(backend),
+            ),
+            vec![owner.into()],
+        )
+    }
+
+    fn delete_stmt(owner: Uuid, id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            "DELETE FROM webhook_endpoints WHERE id = $1 AND user_id = $2",
+            vec![id.into(), owner.into()],
+        )
+    }
+
+    fn owns_endpoint_stmt(owner: Uuid, id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"
+            SELECT COUNT(*) AS total
+            FROM webhook_endpoints
+            WHERE id = $1 AND user_id = $2
+            "#,
+            vec![id.into(), owner.into()],
+        )
+    }
+
+    fn deliveries_stmt(endpoint_id: Uuid, offset: u64, limit: u32) -> Statement {
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"
+            SELECT id, event, attempts, last_status_code, last_error,
+                   delivered_at, next_attempt_at, created_at
+            FROM webhook_deliveries
+            WHERE endpoint_id = $1
+            ORDER BY created_at DESC, id
+            LIMIT $3
+            OFFSET $2
+            "#,
+            vec![
+                endpoint_id.into(),
+                (offset as i64).into(),
+                (limit as i64).into(),
+            ],
+        )
+    }
+
+    fn count_deliveries_stmt(endpoint_id: Uuid) -> Statement {
+        Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"
+            SELECT COUNT(*) AS total
+            FROM webhook_deliveries
+            WHERE endpoint_id = $1
+            "#,
+            vec![endpoint_id.into()],
+        )
+    }
+
+    // =====================================================
+    // Mapping
+    // =====================================================
+
+    fn to_endpoint(row: &QueryResult) -> Result<WebhookEndpoint, WebhookRepositoryError> {
+        let events: String = row.try_get("", "events").map_err(Self::map_db_err)?;
+
+        Ok(WebhookEndpoint {
+            id: row.try_get("", "id").map_err(Self::map_db_err)?,
+            url: row.try_get("", "url").map_err(Self::map_db_err)?,
+            events: events
+                .split(',')
+                .filter(|name| !name.is_empty())
+                .map(|name| name.parse().map_err(WebhookRepositoryError::DatabaseError))
+                .collect::<Result<_, _>>()?,
+            created_at: row.try_get("", "created_at").map_err(Self::map_db_err)?,
+        })
+    }
+
+    fn to_delivery(row: &QueryResult) -> Result<WebhookDelivery, WebhookRepositoryError> {
+        let event: String = row.try_get("", "event").map_err(Self::map_db_err)?;
+        let attempts: i32 = row.try_get("", "attempts").map_err(Self::map_db_err)?;
+        let status: Option<i32> = row
+            .try_get("", "last_status_code")
+            .map_err(Self::map_db_err)?;
+
+        Ok(WebhookDelivery {
+            id: row.try_get("", "id").map_err(Self::map_db_err)?,
+            event: event
+                .parse()
+                .map_err(WebhookRepositoryError::DatabaseError)?,
+            attempts: attempts.max(0) as u32,
+            last_status_code: status.and_then(|code| u16::try_from(code).ok()),
+            last_error: row.try_get("", "last_error").map_err(Self::map_db_err)?,
+            delivered_at: row.try_get("", "delivered_at").map_err(Self::map_db_err)?,
+            next_attempt_at: row
+                .try_get::<Option<DateTime<Utc>>>("", "next_attempt_at")
+                .map_err(Self::map_db_err)?,
+            created_at: row.try_get("", "created_at").map_err(Self::map_db_err)?,
+        })
+    }
+
+    async fn count(&self, stmt: Statement) -> Result<u64, WebhookRepositoryError> {
+        let total: i64 = self
+            .db
+            .query_one(stmt)
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or_else(|| WebhookRepositoryError::DatabaseError("count returned no row".into()))?
+            .try_get("", "total")
+            .map_err(Self::map_db_err)?;
+
+        Ok(total.max(0) as u64)
+    }
+
+    fn map_db_err(e: DbErr) -> WebhookRepositoryError {
+        WebhookRepositoryError::DatabaseError(e.to_string())
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for WebhookRepositoryPostgres {
+    async fn create(
+        &self,
+        owner: UserId,
+        url: &str,
+        events: &[WebhookEvent],
+        secret: &str,
+    ) -> Result<WebhookEndpoint, WebhookRepositoryError> {
+        let row = self
+            .db
+            .query_one(Self::insert_stmt(
+                self.db.get_database_backend(),
+                owner.into(),
+                url,
+                events,
+                secret,
+            ))
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or_else(|| {
+                WebhookRepositoryError::DatabaseError("insert returned no row".into())
+            })?;
+
+        Self::to_endpoint(&row)
+    }
+
+    async fn list(&self, owner: UserId) -> Result<Vec<WebhookEndpoint>, WebhookRepositoryError> {
+        self.db
+            .query_all(Self::list_stmt(
+                self.db.get_database_backend(),
+                owner.into(),
+            ))
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(Self::to_endpoint)
+            .collect()
+    }
+
+    async fn delete(&self, owner: UserId, id: Uuid) -> Result<(), WebhookRepositoryError> {
+        let res = self
+            .db
+            .execute(Self::delete_stmt(owner.into(), id))
+            .await
+            .map_err(Self::map_db_err)?;
+
+        if res.rows_affected() == 0 {
+            return Err(WebhookRepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn list_deliveries(
+        &self,
+        owner: UserId,
+        endpoint_id: Uuid,
+        offset: u64,
+        limit: u32,
+    ) -> Result<DeliveryPage, WebhookRepositoryError> {
+        if self
+            .count(Self::owns_endpoint_stmt(owner.into(), endpoint_id))
+            .await?
+            == 0
+        {
+            return Err(WebhookRepositoryError::NotFound);
+        }
+
+        let items = self
+            .db
+            .query_all(Self::deliveries_stmt(endpoint_id, offset, limit))
+            .await
+            .map_err(Self::map_db_err)?
+            .iter()
+            .map(Self::to_delivery)
+            .collect::<Result<Vec<_>, _>>()?;
+        let total = self.count(Self::count_deliveries_stmt(endpoint_id)).await?;
+
+        Ok(DeliveryPage { items, total })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{MockDatabase, MockExecResult, Value};
+    use std::collections::BTreeMap;
+
+    fn endpoint_row(id: Uuid, events: &str) -> BTreeMap<String, Value> {
+        BTreeMap::from([
+            ("id".to_string(), Value::from(id)),
+            ("url".to_string(), Value::from("https://example.com/hook")),
+            ("events".to_string(), Value::from(events)),
+            ("created_at".to_string(), Value::from(Utc::now())),
+        ])
+    }
+
+    fn total_row(total: i64) -> BTreeMap<String, Value> {
+        BTreeMap::from([("total".to_string(), Value::BigInt(Some(total)))])
+    }
+
+    #[tokio::test]
+    async fn test_create_maps_returned_row() {
+        let id = Uuid::new_v4();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![endpoint_row(id, "media.ready,project.updated")]])
+            .into_connection();
+
+        let repo = WebhookRepositoryPostgres::new(Arc::new(db));
+        let endpoint = repo
+            .create(
+                UserId::from(Uuid::new_v4()),
+                "https://example.com/hook",
+                &[WebhookEvent::MediaReady, WebhookEvent::ProjectUpdated],
+                "whsec_x",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(endpoint.id, id);
+        assert_eq!(
+            endpoint.events,
+            vec![WebhookEvent::MediaReady, WebhookEvent::ProjectUpdated]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_missing_endpoint_is_not_found() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }])
+            .into_connection();
+
+        let repo = WebhookRepositoryPostgres::new(Arc::new(db));
+        let result = repo
+            .delete(UserId::from(Uuid::new_v4()), Uuid::new_v4())
+            .await;
+
+        assert!(matches!(result, Err(WebhookRepositoryError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_deliveries_of_foreign_endpoint_are_not_found() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![total_row(0)]])
+            .into_connection();
+
+        let repo = WebhookRepositoryPostgres::new(Arc::new(db));
+        let result = repo
+            .list_deliveries(UserId::from(Uuid::new_v4()), Uuid::new_v4(), 0, 20)
+            .await;
+
+        assert!(matches!(result, Err(WebhookRepositoryError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_deliveries_are_mapped() {
+        let delivery_row = BTreeMap::from([
+            ("id".to_string(), Value::from(Uuid::new_v4())),
+            ("event".to_string(), Value::from("media.ready")),
+            ("attempts".to_string(), Value::Int(Some(2))),
+            ("last_status_code".to_string(), Value::Int(Some(502))),
+            (
+                "last_error".to_string(),
+                Value::from("endpoint answered HTTP 502"),
+            ),
+            ("delivered_at".to_string(), Value::ChronoDateTimeUtc(None)),
+            ("next_attempt_at".to_string(), Value::from(Utc::now())),
+            ("created_at".to_string(), Value::from(Utc::now())),
+        ]);
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![total_row(1)]])
+            .append_query_results(vec![vec![delivery_row]])
+            .append_query_results(vec![vec![total_row(1)]])
+            .into_connection();
+
+        let repo = WebhookRepositoryPostgres::new(Arc::new(db));
+        let page = repo
+            .list_deliveries(UserId::from(Uuid::new_v4()), Uuid::new_v4(), 0, 20)
+            .await
+            .unwrap();
+
+        assert_eq!(page.total, 1);
+        assert_eq!(page.items[0].event, WebhookEvent::MediaReady);
+        assert_eq!(page.items[0].attempts, 2);
+        assert_eq!(page.items[0].last_status_code, Some(502));
+        assert!(page.items[0].delivered_at.is_none());
+    }
+}
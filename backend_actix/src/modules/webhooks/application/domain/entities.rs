@@ -0,0 +1,118 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Content change an endpoint can subscribe to. Events are queued by database
+/// triggers (see the webhooks migration), so the names must match them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+pub enum WebhookEvent {
+    #[serde(rename = "cv.created")]
+    CvCreated,
+    #[serde(rename = "cv.updated")]
+    CvUpdated,
+    #[serde(rename = "cv.deleted")]
+    CvDeleted,
+    #[serde(rename = "project.created")]
+    ProjectCreated,
+    #[serde(rename = "project.updated")]
+    ProjectUpdated,
+    #[serde(rename = "project.deleted")]
+    ProjectDeleted,
+    #[serde(rename = "media.ready")]
+    MediaReady,
+    /// Data carries `code` when the server failed it, e.g. `PROCESSING_TIMEOUT`
+    #[serde(rename = "media.failed")]
+    MediaFailed,
+}
+
+impl WebhookEvent {
+    pub const ALL: [WebhookEvent; 8] = [
+        WebhookEvent::CvCreated,
+        WebhookEvent::CvUpdated,
+        WebhookEvent::CvDeleted,
+        WebhookEvent::ProjectCreated,
+        WebhookEvent::ProjectUpdated,
+        WebhookEvent::ProjectDeleted,
+        WebhookEvent::MediaReady,
+        WebhookEvent::MediaFailed,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEvent::CvCreated => "cv.created",
+            WebhookEvent::CvUpdated => "cv.updated",
+            WebhookEvent::CvDeleted => "cv.deleted",
+            WebhookEvent::ProjectCreated => "project.created",
+            WebhookEvent::ProjectUpdated => "project.updated",
+            WebhookEvent::ProjectDeleted => "project.deleted",
+            WebhookEvent::MediaReady => "media.ready",
+            WebhookEvent::MediaFailed => "media.failed",
+        }
+    }
+}
+
+impl fmt::Display for WebhookEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for WebhookEvent {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        WebhookEvent::ALL
+            .into_iter()
+            .find(|event| event.as_str() == s)
+            .ok_or_else(|| format!("unknown webhook event '{s}'"))
+    }
+}
+
+/// A registered endpoint. The secret is never part of this view; it is only
+/// returned once, when the endpoint is created.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct WebhookEndpoint {
+    pub id: Uuid,
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// One queued notification and the outcome of its latest attempt
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub event: WebhookEvent,
+    pub attempts: u32,
+    /// HTTP status of the latest attempt; absent if the request never got a response
+    pub last_status_code: Option<u16>,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    /// Next retry; absent once delivered or given up on
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_names_round_trip() {
+        for event in WebhookEvent::ALL {
+            assert_eq!(event.as_str().parse::<WebhookEvent>(), Ok(event));
+            assert_eq!(
+                serde_json::to_value(event).unwrap(),
+                serde_json::json!(event.as_str())
+            );
+        }
+    }
+
+    #[test]
+    fn test_unknown_event_is_rejected() {
+        assert!("post.published".parse::<WebhookEvent>().is_err());
+    }
+}
@@ -0,0 +1,4 @@
+pub mod domain;
+pub mod ports;
+pub mod services;
+pub mod webhook_use_cases;
@@ -0,0 +1,176 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::shared::metrics::metered_use_case;
+use crate::webhooks::application::domain::entities::{WebhookEndpoint, WebhookEvent};
+
+pub const MAX_URL_LENGTH: usize = 2048;
+
+//
+// ──────────────────────────────────────────────────────────
+// Create Webhook Command
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone)]
+pub struct CreateWebhookCommand {
+    owner: UserId,
+    url: String,
+    events: Vec<WebhookEvent>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateWebhookCommandError {
+    #[error("URL must be an absolute http(s) URL")]
+    InvalidUrl,
+
+    #[error("At least one event is required")]
+    NoEvents,
+}
+
+impl CreateWebhookCommand {
+    pub fn new(
+        owner: UserId,
+        url: String,
+        events: Vec<WebhookEvent>,
+    ) -> Result<Self, CreateWebhookCommandError> {
+        let url = url.trim();
+        let host = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))
+            .ok_or(CreateWebhookCommandError::InvalidUrl)?;
+
+        if host.is_empty()
+            || host.starts_with('/')
+            || url.len() > MAX_URL_LENGTH
+            || url.chars().any(char::is_whitespace)
+        {
+            return Err(CreateWebhookCommandError::InvalidUrl);
+        }
+
+        let mut events = events;
+        events.sort_by_key(|event| event.as_str());
+        events.dedup();
+        if events.is_empty() {
+            return Err(CreateWebhookCommandError::NoEvents);
+        }
+
+        Ok(Self {
+            owner,
+            url: url.to_string(),
+            events,
+        })
+    }
+
+    pub fn owner(&self) -> UserId {
+        self.owner
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn events(&self) -> &[WebhookEvent] {
+        &self.events
+    }
+}
+
+/// A new endpoint, with the signing secret. This is the only time it is shown.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CreatedWebhook {
+    #[serde(flatten)]
+    pub endpoint: WebhookEndpoint,
+    pub secret: String,
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Use Case Error
+// ──────────────────────────────────────────────────────────
+//
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum CreateWebhookError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+//
+// ──────────────────────────────────────────────────────────
+// Incoming Port (Use Case)
+// ──────────────────────────────────────────────────────────
+//
+
+#[async_trait]
+pub trait CreateWebhookUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        command: CreateWebhookCommand,
+    ) -> Result<CreatedWebhook, CreateWebhookError>;
+}
+
+metered_use_case!(
+    "webhooks",
+    "create_webhook",
+    CreateWebhookUseCase,
+    fn execute(&self, command: CreateWebhookCommand) -> Result<CreatedWebhook, CreateWebhookError>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn owner() -> UserId {
+        UserId::from(Uuid::new_v4())
+    }
+
+    #[test]
+    fn test_command_accepts_https_url_and_dedups_events() {
+        let command = CreateWebhookCommand::new(
+            owner(),
+            "  https://builder.example.com/hooks/rebuild ".to_string(),
+            vec![
+                WebhookEvent::ProjectUpdated,
+                WebhookEvent::MediaReady,
+                WebhookEvent::ProjectUpdated,
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(command.url(), "https://builder.example.com/hooks/rebuild");
+        assert_eq!(
+            command.events(),
+            &[WebhookEvent::MediaReady, WebhookEvent::ProjectUpdated]
+        );
+    }
+
+    #[test]
+    fn test_command_rejects_bad_urls() {
+        for url in [
+            "",
+            "builder.example.com",
+            "ftp://builder.example.com",
+            "https://",
+            "https:///path",
+            "https://exa mple.com",
+        ] {
+            let result =
+                CreateWebhookCommand::new(owner(), url.to_string(), vec![WebhookEvent::CvUpdated]);
+            assert!(
+                matches!(result, Err(CreateWebhookCommandError::InvalidUrl)),
+                "{url} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_command_requires_an_event() {
+        let result =
+            CreateWebhookCommand::new(owner(), "https://example.com".to_string(), Vec::new());
+
+        assert!(matches!(result, Err(CreateWebhookCommandError::NoEvents)));
+    }
+}
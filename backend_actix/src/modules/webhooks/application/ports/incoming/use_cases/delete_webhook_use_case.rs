@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::shared::metrics::metered_use_case;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DeleteWebhookError {
+    #[error("Webhook not found")]
+    NotFound,
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+#[async_trait]
+pub trait DeleteWebhookUseCase: Send + Sync {
+    async fn execute(&self, owner: UserId, id: Uuid) -> Result<(), DeleteWebhookError>;
+}
+
+metered_use_case!(
+    "webhooks",
+    "delete_webhook",
+    DeleteWebhookUseCase,
+    fn execute(&self, owner: UserId, id: Uuid) -> Result<(), DeleteWebhookError>
+);
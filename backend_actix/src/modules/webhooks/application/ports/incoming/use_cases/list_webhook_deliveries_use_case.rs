@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::shared::metrics::metered_use_case;
+use crate::webhooks::application::ports::outgoing::DeliveryPage;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ListWebhookDeliveriesError {
+    #[error("Webhook not found")]
+    NotFound,
+
+    #[error("Failed to list deliveries: {0}")]
+    QueryFailed(String),
+}
+
+#[async_trait]
+pub trait ListWebhookDeliveriesUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        owner: UserId,
+        endpoint_id: Uuid,
+        offset: u64,
+        limit: u32,
+    ) -> Result<DeliveryPage, ListWebhookDeliveriesError>;
+}
+
+metered_use_case!(
+    "webhooks",
+    "list_webhook_deliveries",
+    ListWebhookDeliveriesUseCase,
+    fn execute(
+        &self,
+        owner: UserId,
+        endpoint_id: Uuid,
+        offset: u64,
+        limit: u32,
+    ) -> Result<DeliveryPage, ListWebhookDeliveriesError>
+);
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::shared::metrics::metered_use_case;
+use crate::webhooks::application::domain::entities::WebhookEndpoint;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ListWebhooksError {
+    #[error("Failed to list webhooks: {0}")]
+    QueryFailed(String),
+}
+
+#[async_trait]
+pub trait ListWebhooksUseCase: Send + Sync {
+    async fn execute(&self, owner: UserId) -> Result<Vec<WebhookEndpoint>, ListWebhooksError>;
+}
+
+metered_use_case!(
+    "webhooks",
+    "list_webhooks",
+    ListWebhooksUseCase,
+    fn execute(&self, owner: UserId) -> Result<Vec<WebhookEndpoint>, ListWebhooksError>
+);
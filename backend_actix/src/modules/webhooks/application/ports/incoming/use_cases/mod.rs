@@ -0,0 +1,14 @@
+mod create_webhook_use_case;
+mod delete_webhook_use_case;
+mod list_webhook_deliveries_use_case;
+mod list_webhooks_use_case;
+
+pub use create_webhook_use_case::{
+    CreateWebhookCommand, CreateWebhookCommandError, CreateWebhookError, CreateWebhookUseCase,
+    CreatedWebhook,
+};
+pub use delete_webhook_use_case::{DeleteWebhookError, DeleteWebhookUseCase};
+pub use list_webhook_deliveries_use_case::{
+    ListWebhookDeliveriesError, ListWebhookDeliveriesUseCase,
+};
+pub use list_webhooks_use_case::{ListWebhooksError, ListWebhooksUseCase};
@@ -0,0 +1,7 @@
+pub mod webhook_outbox;
+pub mod webhook_repository;
+pub mod webhook_sender;
+
+pub use webhook_outbox::{PendingDelivery, WebhookOutbox, WebhookOutboxError};
+pub use webhook_repository::{DeliveryPage, WebhookRepository, WebhookRepositoryError};
+pub use webhook_sender::{WebhookRequest, WebhookSendError, WebhookSender};
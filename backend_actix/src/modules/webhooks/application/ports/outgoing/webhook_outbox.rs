@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// A delivery claimed by the dispatcher, with where and how to send it
+#[derive(Debug, Clone)]
+pub struct PendingDelivery {
+    pub id: Uuid,
+    pub event: String,
+    pub url: String,
+    pub secret: String,
+    pub payload: serde_json::Value,
+    /// Including the attempt this claim is for
+    pub attempts: u32,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum WebhookOutboxError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Delivery side of the webhook queue. Rows are written by database triggers
+/// on the content tables.
+#[async_trait]
+pub trait WebhookOutbox: Send + Sync {
+    /// Take up to `limit` due deliveries and lease them until `lease_until`, so
+    /// another dispatcher skips them while this one is sending.
+    async fn claim_due(
+        &self,
+        limit: u32,
+        lease_until: DateTime<Utc>,
+    ) -> Result<Vec<PendingDelivery>, WebhookOutboxError>;
+
+    async fn mark_delivered(&self, id: Uuid, status_code: u16) -> Result<(), WebhookOutboxError>;
+
+    /// Record a failed attempt. `retry_at: None` gives up on the delivery.
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        status_code: Option<u16>,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), WebhookOutboxError>;
+}
@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::webhooks::application::domain::entities::{
+    WebhookDelivery, WebhookEndpoint, WebhookEvent,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeliveryPage {
+    pub items: Vec<WebhookDelivery>,
+    pub total: u64,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum WebhookRepositoryError {
+    /// Endpoint doesn't exist or belongs to someone else
+    #[error("Webhook not found")]
+    NotFound,
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Owner-side management of endpoints and their delivery log.
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    async fn create(
+        &self,
+        owner: UserId,
+        url: &str,
+        events: &[WebhookEvent],
+        secret: &str,
+    ) -> Result<WebhookEndpoint, WebhookRepositoryError>;
+
+    /// Oldest first
+    async fn list(&self, owner: UserId) -> Result<Vec<WebhookEndpoint>, WebhookRepositoryError>;
+
+    /// Also drops the endpoint's delivery log and anything still queued
+    async fn delete(&self, owner: UserId, id: Uuid) -> Result<(), WebhookRepositoryError>;
+
+    /// Newest first
+    async fn list_deliveries(
+        &self,
+        owner: UserId,
+        endpoint_id: Uuid,
+        offset: u64,
+        limit: u32,
+    ) -> Result<DeliveryPage, WebhookRepositoryError>;
+}
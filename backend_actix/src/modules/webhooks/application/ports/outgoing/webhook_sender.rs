@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+/// A signed POST, ready to go
+#[derive(Debug, Clone)]
+pub struct WebhookRequest {
+    pub url: String,
+    pub headers: Vec<(&'static str, String)>,
+    pub body: Vec<u8>,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum WebhookSendError {
+    /// No response: DNS, connect, TLS or timeout failure
+    #[error("Request failed: {0}")]
+    RequestFailed(String),
+}
+
+#[async_trait]
+pub trait WebhookSender: Send + Sync {
+    /// POST the request and return the response status, whatever it is
+    async fn send(&self, request: WebhookRequest) -> Result<u16, WebhookSendError>;
+}
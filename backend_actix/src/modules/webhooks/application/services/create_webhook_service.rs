@@ -0,0 +1,91 @@
+use async_trait::async_trait;
+
+use crate::webhooks::application::domain::signature;
+use crate::webhooks::application::ports::{
+    incoming::use_cases::{
+        CreateWebhookCommand, CreateWebhookError, CreateWebhookUseCase, CreatedWebhook,
+    },
+    outgoing::WebhookRepository,
+};
+
+pub struct CreateWebhookService<R>
+where
+    R: WebhookRepository,
+{
+    repository: R,
+}
+
+impl<R> CreateWebhookService<R>
+where
+    R: WebhookRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> CreateWebhookUseCase for CreateWebhookService<R>
+where
+    R: WebhookRepository,
+{
+    async fn execute(
+        &self,
+        command: CreateWebhookCommand,
+    ) -> Result<CreatedWebhook, CreateWebhookError> {
+        let secret = signature::generate_secret();
+
+        let endpoint = self
+            .repository
+            .create(command.owner(), command.url(), command.events(), &secret)
+            .await
+            .map_err(|e| CreateWebhookError::RepositoryError(e.to_string()))?;
+
+        Ok(CreatedWebhook { endpoint, secret })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    use crate::auth::application::domain::entities::UserId;
+    use crate::tests::support::stubs::InMemoryWebhookRepository;
+    use crate::webhooks::application::domain::entities::WebhookEvent;
+
+    fn command() -> CreateWebhookCommand {
+        CreateWebhookCommand::new(
+            UserId::from(Uuid::new_v4()),
+            "https://builder.example.com/rebuild".to_string(),
+            vec![WebhookEvent::ProjectUpdated],
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_stores_and_returns_generated_secret() {
+        let repository = InMemoryWebhookRepository::default();
+        let service = CreateWebhookService::new(repository.clone());
+
+        let created = service.execute(command()).await.unwrap();
+
+        let stored = repository.endpoints.lock().unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].secret, created.secret);
+        assert!(created.secret.starts_with("whsec_"));
+        assert_eq!(created.endpoint.events, vec![WebhookEvent::ProjectUpdated]);
+    }
+
+    #[tokio::test]
+    async fn test_create_maps_repository_error() {
+        let service = CreateWebhookService::new(InMemoryWebhookRepository::failing());
+
+        let result = service.execute(command()).await;
+
+        assert!(matches!(
+            result,
+            Err(CreateWebhookError::RepositoryError(_))
+        ));
+    }
+}
@@ -0,0 +1,91 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::webhooks::application::ports::{
+    incoming::use_cases::{DeleteWebhookError, DeleteWebhookUseCase},
+    outgoing::{WebhookRepository, WebhookRepositoryError},
+};
+
+pub struct DeleteWebhookService<R>
+where
+    R: WebhookRepository,
+{
+    repository: R,
+}
+
+impl<R> DeleteWebhookService<R>
+where
+    R: WebhookRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> DeleteWebhookUseCase for DeleteWebhookService<R>
+where
+    R: WebhookRepository,
+{
+    async fn execute(&self, owner: UserId, id: Uuid) -> Result<(), DeleteWebhookError> {
+        self.repository
+            .delete(owner, id)
+            .await
+            .map_err(|e| match e {
+                WebhookRepositoryError::NotFound => DeleteWebhookError::NotFound,
+                WebhookRepositoryError::DatabaseError(msg) => {
+                    DeleteWebhookError::RepositoryError(msg)
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::support::stubs::InMemoryWebhookRepository;
+    use crate::webhooks::application::domain::entities::WebhookEvent;
+
+    #[tokio::test]
+    async fn test_delete_own_endpoint() {
+        let repository = InMemoryWebhookRepository::default();
+        let owner = UserId::from(Uuid::new_v4());
+        let endpoint = repository
+            .create(
+                owner,
+                "https://a.example.com",
+                &[WebhookEvent::CvUpdated],
+                "s",
+            )
+            .await
+            .unwrap();
+
+        let service = DeleteWebhookService::new(repository.clone());
+        service.execute(owner, endpoint.id).await.unwrap();
+
+        assert!(repository.endpoints.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_delete_someone_elses_endpoint_is_not_found() {
+        let repository = InMemoryWebhookRepository::default();
+        let endpoint = repository
+            .create(
+                UserId::from(Uuid::new_v4()),
+                "https://a.example.com",
+                &[WebhookEvent::CvUpdated],
+                "s",
+            )
+            .await
+            .unwrap();
+
+        let service = DeleteWebhookService::new(repository);
+        let result = service
+            .execute(UserId::from(Uuid::new_v4()), endpoint.id)
+            .await;
+
+        assert!(matches!(result, Err(DeleteWebhookError::NotFound)));
+    }
+}
@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::webhooks::application::ports::{
+    incoming::use_cases::{ListWebhookDeliveriesError, ListWebhookDeliveriesUseCase},
+    outgoing::{DeliveryPage, WebhookRepository, WebhookRepositoryError},
+};
+
+pub struct ListWebhookDeliveriesService<R>
+where
+    R: WebhookRepository,
+{
+    repository: R,
+}
+
+impl<R> ListWebhookDeliveriesService<R>
+where
+    R: WebhookRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> ListWebhookDeliveriesUseCase for ListWebhookDeliveriesService<R>
+where
+    R: WebhookRepository,
+{
+    async fn execute(
+        &self,
+        owner: UserId,
+        endpoint_id: Uuid,
+        offset: u64,
+        limit: u32,
+    ) -> Result<DeliveryPage, ListWebhookDeliveriesError> {
+        self.repository
+            .list_deliveries(owner, endpoint_id, offset, limit)
+            .await
+            .map_err(|e| match e {
+                WebhookRepositoryError::NotFound => ListWebhookDeliveriesError::NotFound,
+                WebhookRepositoryError::DatabaseError(msg) => {
+                    ListWebhookDeliveriesError::QueryFailed(msg)
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::support::stubs::InMemoryWebhookRepository;
+
+    #[tokio::test]
+    async fn test_unknown_endpoint_is_not_found() {
+        let service = ListWebhookDeliveriesService::new(InMemoryWebhookRepository::default());
+
+        let result = service
+            .execute(UserId::from(Uuid::new_v4()), Uuid::new_v4(), 0, 20)
+            .await;
+
+        assert!(matches!(result, Err(ListWebhookDeliveriesError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_database_error_is_mapped() {
+        let service = ListWebhookDeliveriesService::new(InMemoryWebhookRepository::failing());
+
+        let result = service
+            .execute(UserId::from(Uuid::new_v4()), Uuid::new_v4(), 0, 20)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ListWebhookDeliveriesError::QueryFailed(msg)) if msg == "db down"
+        ));
+    }
+}
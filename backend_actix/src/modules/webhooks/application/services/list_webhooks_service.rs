@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::webhooks::application::domain::entities::WebhookEndpoint;
+use crate::webhooks::application::ports::{
+    incoming::use_cases::{ListWebhooksError, ListWebhooksUseCase},
+    outgoing::WebhookRepository,
+};
+
+pub struct ListWebhooksService<R>
+where
+    R: WebhookRepository,
+{
+    repository: R,
+}
+
+impl<R> ListWebhooksService<R>
+where
+    R: WebhookRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> ListWebhooksUseCase for ListWebhooksService<R>
+where
+    R: WebhookRepository,
+{
+    async fn execute(&self, owner: UserId) -> Result<Vec<WebhookEndpoint>, ListWebhooksError> {
+        self.repository
+            .list(owner)
+            .await
+            .map_err(|e| ListWebhooksError::QueryFailed(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    use crate::tests::support::stubs::InMemoryWebhookRepository;
+    use crate::webhooks::application::domain::entities::WebhookEvent;
+
+    #[tokio::test]
+    async fn test_list_only_returns_own_endpoints() {
+        let repository = InMemoryWebhookRepository::default();
+        let owner = UserId::from(Uuid::new_v4());
+        let events = [WebhookEvent::MediaReady];
+        repository
+            .create(owner, "https://a.example.com", &events, "s1")
+            .await
+            .unwrap();
+        repository
+            .create(
+                UserId::from(Uuid::new_v4()),
+                "https://b.example.com",
+                &events,
+                "s2",
+            )
+            .await
+            .unwrap();
+
+        let service = ListWebhooksService::new(repository);
+        let endpoints = service.execute(owner).await.unwrap();
+
+        assert_eq!(endpoints.len(), 1);
+        assert_eq!(endpoints[0].url, "https://a.example.com");
+    }
+}
@@ -0,0 +1,11 @@
+mod create_webhook_service;
+mod delete_webhook_service;
+mod list_webhook_deliveries_service;
+mod list_webhooks_service;
+mod webhook_dispatcher;
+
+pub use create_webhook_service::CreateWebhookService;
+pub use delete_webhook_service::DeleteWebhookService;
+pub use list_webhook_deliveries_service::ListWebhookDeliveriesService;
+pub use list_webhooks_service::ListWebhooksService;
+pub use webhook_dispatcher::WebhookDispatcher;
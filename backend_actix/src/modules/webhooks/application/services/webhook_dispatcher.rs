@@ -0,0 +1,335 @@
+use chrono::{DateTime, Utc};
+use tracing::{info, warn};
+
+use crate::email::application::services::DispatchPolicy;
+use crate::shared::lifecycle::ShutdownSignal;
+use crate::webhooks::application::domain::signature::{
+    self, DELIVERY_HEADER, EVENT_HEADER, SIGNATURE_HEADER, TIMESTAMP_HEADER,
+};
+use crate::webhooks::application::ports::outgoing::{
+    PendingDelivery, WebhookOutbox, WebhookOutboxError, WebhookRequest, WebhookSender,
+};
+
+/// Posts queued webhook deliveries, signed, with retries and exponential backoff.
+///
+/// Same polling and retry model as the email `OutboxDispatcher`. Any 2xx response
+/// counts as delivered; everything else is retried until `max_attempts`.
+pub struct WebhookDispatcher<O, S>
+where
+    O: WebhookOutbox,
+    S: WebhookSender,
+{
+    outbox: O,
+    sender: S,
+    policy: DispatchPolicy,
+}
+
+impl<O, S> WebhookDispatcher<O, S>
+where
+    O: WebhookOutbox,
+    S: WebhookSender,
+{
+    pub fn new(outbox: O, sender: S, policy: DispatchPolicy) -> Self {
+        Self {
+            outbox,
+            sender,
+            policy,
+        }
+    }
+
+    pub async fn run(self, mut signal: ShutdownSignal) {
+        info!("Webhook dispatcher started");
+
+        loop {
+            tokio::select! {
+                _ = signal.wait() => break,
+                _ = tokio::time::sleep(self.policy.poll_interval) => {}
+            }
+
+            if let Err(e) = self.dispatch_due(Utc::now()).await {
+                warn!(error = %e, "Webhook queue poll failed");
+            }
+        }
+    }
+
+    /// Deliver one batch of due deliveries. Returns how many succeeded.
+    pub async fn dispatch_due(&self, now: DateTime<Utc>) -> Result<usize, WebhookOutboxError> {
+        let deliveries = self
+            .outbox
+            .claim_due(self.policy.batch_size, now + self.policy.lease)
+            .await?;
+
+        let mut delivered = 0;
+        for delivery in deliveries {
+            match self.sender.send(Self::signed_request(&delivery, now)).await {
+                Ok(status) if (200..300).contains(&status) => {
+                    self.outbox.mark_delivered(delivery.id, status).await?;
+                    delivered += 1;
+                }
+                Ok(status) => {
+                    let reason = format!("endpoint answered HTTP {status}");
+                    self.record_failure(&delivery, Some(status), &reason, now)
+                        .await?
+                }
+                Err(e) => {
+                    self.record_failure(&delivery, None, &e.to_string(), now)
+                        .await?
+                }
+            }
+        }
+
+        Ok(delivered)
+    }
+
+    fn signed_request(delivery: &PendingDelivery, now: DateTime<Utc>) -> WebhookRequest {
+        let body = delivery.payload.to_string().into_bytes();
+        let timestamp = now.timestamp();
+
+        WebhookRequest {
+            url: delivery.url.clone(),
+            headers: vec![
+                ("Content-Type", "application/json".to_string()),
+                (EVENT_HEADER, delivery.event.clone()),
+                (DELIVERY_HEADER, delivery.id.to_string()),
+                (TIMESTAMP_HEADER, timestamp.to_string()),
+                (
+                    SIGNATURE_HEADER,
+                    signature::sign(&delivery.secret, timestamp, &body),
+                ),
+            ],
+            body,
+        }
+    }
+
+    async fn record_failure(
+        &self,
+        delivery: &PendingDelivery,
+        status_code: Option<u16>,
+        reason: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(), WebhookOutboxError> {
+        let retry_at = if delivery.attempts >= self.policy.max_attempts {
+            warn!(
+                delivery_id = %delivery.id,
+                attempts = delivery.attempts,
+                error = reason,
+                "Giving up on webhook delivery"
+            );
+            None
+        } else {
+            let delay = self.policy.backoff(delivery.attempts);
+            warn!(
+                delivery_id = %delivery.id,
+                attempts = delivery.attempts,
+                retry_in_secs = delay.as_secs(),
+                error = reason,
+                "Webhook delivery failed, will retry"
+            );
+            Some(now + delay)
+        };
+
+        self.outbox
+            .mark_failed(delivery.id, status_code, reason, retry_at)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    use crate::webhooks::application::ports::outgoing::WebhookSendError;
+
+    // =====================================================
+    // Mocks
+    // =====================================================
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Outcome {
+        Delivered(Uuid, u16),
+        Failed(Uuid, Option<u16>, Option<DateTime<Utc>>),
+    }
+
+    #[derive(Clone, Default)]
+    struct MockOutbox {
+        due: Vec<PendingDelivery>,
+        outcomes: Arc<Mutex<Vec<Outcome>>>,
+    }
+
+    #[async_trait]
+    impl WebhookOutbox for MockOutbox {
+        async fn claim_due(
+            &self,
+            limit: u32,
+            _lease_until: DateTime<Utc>,
+        ) -> Result<Vec<PendingDelivery>, WebhookOutboxError> {
+            Ok(self.due.iter().take(limit as usize).cloned().collect())
+        }
+
+        async fn mark_delivered(
+            &self,
+            id: Uuid,
+            status_code: u16,
+        ) -> Result<(), WebhookOutboxError> {
+            self.outcomes
+                .lock()
+                .unwrap()
+                .push(Outcome::Delivered(id, status_code));
+            Ok(())
+        }
+
+        async fn mark_failed(
+            &self,
+            id: Uuid,
+            status_code: Option<u16>,
+            _error: &str,
+            retry_at: Option<DateTime<Utc>>,
+        ) -> Result<(), WebhookOutboxError> {
+            self.outcomes
+                .lock()
+                .unwrap()
+                .push(Outcome::Failed(id, status_code, retry_at));
+            Ok(())
+        }
+    }
+
+    #[derive(Clone)]
+    struct MockSender {
+        response: Result<u16, WebhookSendError>,
+        requests: Arc<Mutex<Vec<WebhookRequest>>>,
+    }
+
+    impl MockSender {
+        fn answering(response: Result<u16, WebhookSendError>) -> Self {
+            Self {
+                response,
+                requests: Arc::default(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl WebhookSender for MockSender {
+        async fn send(&self, request: WebhookRequest) -> Result<u16, WebhookSendError> {
+            self.requests.lock().unwrap().push(request);
+            self.response.clone()
+        }
+    }
+
+    // =====================================================
+    // Helpers
+    // =====================================================
+
+    fn delivery(attempts: u32) -> PendingDelivery {
+        PendingDelivery {
+            id: Uuid::new_v4(),
+            event: "project.updated".to_string(),
+            url: "https://builder.example.com/rebuild".to_string(),
+            secret: "whsec_test".to_string(),
+            payload: serde_json::json!({ "event": "project.updated", "data": { "id": 1 } }),
+            attempts,
+        }
+    }
+
+    fn dispatcher(
+        outbox: MockOutbox,
+        sender: MockSender,
+    ) -> WebhookDispatcher<MockOutbox, MockSender> {
+        WebhookDispatcher::new(outbox, sender, DispatchPolicy::default())
+    }
+
+    // =====================================================
+    // Tests
+    // =====================================================
+
+    #[tokio::test]
+    async fn test_success_is_marked_delivered_and_signed() {
+        let now = Utc::now();
+        let queued = delivery(1);
+        let outbox = MockOutbox {
+            due: vec![queued.clone()],
+            ..Default::default()
+        };
+        let sender = MockSender::answering(Ok(204));
+
+        let delivered = dispatcher(outbox.clone(), sender.clone())
+            .dispatch_due(now)
+            .await
+            .unwrap();
+
+        assert_eq!(delivered, 1);
+        assert_eq!(
+            *outbox.outcomes.lock().unwrap(),
+            vec![Outcome::Delivered(queued.id, 204)]
+        );
+
+        let requests = sender.requests.lock().unwrap();
+        let request = &requests[0];
+        let header = |name: &str| {
+            request
+                .headers
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.clone())
+                .unwrap()
+        };
+        assert_eq!(request.url, queued.url);
+        assert_eq!(header(EVENT_HEADER), "project.updated");
+        assert_eq!(header(DELIVERY_HEADER), queued.id.to_string());
+        assert_eq!(
+            header(SIGNATURE_HEADER),
+            signature::sign("whsec_test", now.timestamp(), &request.body)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_error_status_is_retried_with_backoff() {
+        let now = Utc::now();
+        let queued = delivery(2);
+        let outbox = MockOutbox {
+            due: vec![queued.clone()],
+            ..Default::default()
+        };
+
+        let delivered = dispatcher(outbox.clone(), MockSender::answering(Ok(500)))
+            .dispatch_due(now)
+            .await
+            .unwrap();
+
+        assert_eq!(delivered, 0);
+        assert_eq!(
+            *outbox.outcomes.lock().unwrap(),
+            vec![Outcome::Failed(
+                queued.id,
+                Some(500),
+                Some(now + Duration::from_secs(60))
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_attempts() {
+        let queued = delivery(DispatchPolicy::default().max_attempts);
+        let outbox = MockOutbox {
+            due: vec![queued.clone()],
+            ..Default::default()
+        };
+        let sender = MockSender::answering(Err(WebhookSendError::RequestFailed(
+            "connection refused".to_string(),
+        )));
+
+        dispatcher(outbox.clone(), sender)
+            .dispatch_due(Utc::now())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *outbox.outcomes.lock().unwrap(),
+            vec![Outcome::Failed(queued.id, None, None)]
+        );
+    }
+}
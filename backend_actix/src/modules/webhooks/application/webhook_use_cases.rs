@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use crate::shared::metrics::Metered;
+use crate::webhooks::application::ports::incoming::use_cases::{
+    CreateWebhookUseCase, DeleteWebhookUseCase, ListWebhookDeliveriesUseCase, ListWebhooksUseCase,
+};
+use crate::webhooks::application::ports::outgoing::WebhookRepository;
+use crate::webhooks::application::services::{
+    CreateWebhookService, DeleteWebhookService, ListWebhookDeliveriesService, ListWebhooksService,
+};
+
+#[derive(Clone)]
+pub struct WebhookUseCases {
+    pub create: Arc<dyn CreateWebhookUseCase + Send + Sync>,
+    pub list: Arc<dyn ListWebhooksUseCase + Send + Sync>,
+    pub delete: Arc<dyn DeleteWebhookUseCase + Send + Sync>,
+    pub list_deliveries: Arc<dyn ListWebhookDeliveriesUseCase + Send + Sync>,
+}
+
+impl WebhookUseCases {
+    pub fn build<R>(repository: R) -> Self
+    where
+        R: WebhookRepository + Clone + 'static,
+    {
+        Self {
+            create: Arc::new(Metered(CreateWebhookService::new(repository.clone()))),
+            list: Arc::new(Metered(ListWebhooksService::new(repository.clone()))),
+            delete: Arc::new(Metered(DeleteWebhookService::new(repository.clone()))),
+            list_deliveries: Arc::new(Metered(ListWebhookDeliveriesService::new(repository))),
+        }
+    }
+}
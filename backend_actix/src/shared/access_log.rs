@@ -0,0 +1,314 @@
+// src/shared/access_log.rs
+use std::time::Instant;
+
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    http::{header::HeaderMap, StatusCode},
+    middleware::Next,
+    Error, HttpMessage, HttpRequest,
+};
+use uuid::Uuid;
+
+use crate::shared::request_id;
+
+/// Replaces the value of every sensitive field
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Field names containing any of these (case-insensitive) are never logged
+const SENSITIVE_FIELDS: [&str; 4] = ["password", "token", "authorization", "secret"];
+
+/// User id of the authenticated caller, stored in the request extensions by
+/// the auth extractors so the access log can report it.
+#[derive(Debug, Clone, Copy)]
+pub struct AccessLogUser(pub Uuid);
+
+/// Attribute the current request to `user_id` in its access log entry.
+pub fn record_user(req: &HttpRequest, user_id: Uuid) {
+    req.extensions_mut().insert(AccessLogUser(user_id));
+}
+
+pub fn is_sensitive(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    SENSITIVE_FIELDS.iter().any(|field| name.contains(field))
+}
+
+/// Path with the segments bound to sensitive route parameters replaced,
+/// e.g. `/api/auth/email-verification/{token}` hides the token.
+pub fn redact_path(path: &str, pattern: Option<&str>) -> String {
+    let Some(pattern) = pattern else {
+        return path.to_string();
+    };
+    let params: Vec<&str> = pattern.split('/').collect();
+
+    path.split('/')
+        .enumerate()
+        .map(|(i, segment)| match params.get(i) {
+            Some(param) if is_sensitive_param(param) => REDACTED,
+            _ => segment,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// `{name}` or `{name:regex}` segment of a route pattern with a sensitive name
+fn is_sensitive_param(segment: &str) -> bool {
+    segment
+        .strip_prefix('{')
+        .and_then(|rest| rest.strip_suffix('}'))
+        .map(|inner| inner.split(':').next().unwrap_or(inner))
+        .is_some_and(is_sensitive)
+}
+
+/// Query string with the values of sensitive parameters replaced,
+/// e.g. `token=abc&page=2` becomes `token=[REDACTED]&page=2`.
+pub fn redact_query(query: &str) -> String {
+    query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((key, _)) if is_sensitive(key) => format!("{key}={REDACTED}"),
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Headers as `name: value` pairs, sensitive values replaced
+pub fn redact_headers(headers: &HeaderMap) -> Vec<String> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if is_sensitive(name.as_str()) || name == "cookie" {
+                REDACTED
+            } else {
+                value.to_str().unwrap_or("<binary>")
+            };
+            format!("{name}: {value}")
+        })
+        .collect()
+}
+
+/// JSON value with the values of sensitive keys replaced, at any depth
+pub fn redact_json(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if is_sensitive(key) {
+                    *value = serde_json::Value::String(REDACTED.to_string());
+                } else {
+                    redact_json(value);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_json),
+        _ => {}
+    }
+}
+
+/// Access log middleware
+///
+/// - Emits one event per request on the `access` target, with method, path,
+///   query, status, latency, user id and request id
+/// - 5xx responses are logged at `error`, everything else at `info`; request
+///   headers are added at `debug`
+/// - Sensitive path parameters, query parameters and headers are redacted
+///
+/// Must run inside `request_id_middleware` to pick up the request id.
+pub async fn access_log_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let started = Instant::now();
+    let method = req.method().to_string();
+    let path = redact_path(req.path(), req.match_pattern().as_deref());
+    let query = redact_query(req.query_string());
+    let headers = tracing::enabled!(target: "access", tracing::Level::DEBUG)
+        .then(|| redact_headers(req.headers()));
+
+    let result = next.call(req).await;
+
+    let (status, user_id) = match &result {
+        Ok(res) => (
+            res.status(),
+            res.request()
+                .extensions()
+                .get::<AccessLogUser>()
+                .map(|user| user.0),
+        ),
+        Err(err) => (err.as_response_error().status_code(), None),
+    };
+
+    let entry = AccessLogEntry {
+        method: &method,
+        path: &path,
+        query: &query,
+        status,
+        latency_ms: started.elapsed().as_millis(),
+        user_id,
+        request_id: request_id::current(),
+    };
+    entry.emit();
+    if let Some(headers) = headers {
+        tracing::debug!(target: "access", ?headers, "request headers");
+    }
+
+    result
+}
+
+struct AccessLogEntry<'a> {
+    method: &'a str,
+    path: &'a str,
+    query: &'a str,
+    status: StatusCode,
+    latency_ms: u128,
+    user_id: Option<Uuid>,
+    request_id: Option<String>,
+}
+
+impl AccessLogEntry<'_> {
+    fn emit(&self) {
+        let user_id = self.user_id.map(|id| id.to_string());
+        macro_rules! log {
+            ($level:ident) => {
+                tracing::$level!(
+                    target: "access",
+                    method = self.method,
+                    path = self.path,
+                    query = self.query,
+                    status = self.status.as_u16(),
+                    latency_ms = self.latency_ms as u64,
+                    user_id = user_id.as_deref(),
+                    request_id = self.request_id.as_deref(),
+                    "request completed"
+                )
+            };
+        }
+
+        if self.status.is_server_error() {
+            log!(error);
+        } else {
+            log!(info);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::api::ApiResponse;
+    use crate::shared::request_id::request_id_middleware;
+    use actix_web::{middleware::from_fn, test, web, App, HttpResponse};
+    use serde_json::json;
+
+    #[actix_web::test]
+    async fn test_sensitive_field_names() {
+        assert!(is_sensitive("password"));
+        assert!(is_sensitive("new_Password"));
+        assert!(is_sensitive("refresh_token"));
+        assert!(is_sensitive("Authorization"));
+        assert!(is_sensitive("webhook_secret"));
+        assert!(!is_sensitive("email"));
+        assert!(!is_sensitive("page"));
+    }
+
+    #[actix_web::test]
+    async fn test_redact_path_masks_sensitive_route_params() {
+        assert_eq!(
+            redact_path(
+                "/api/auth/email-verification/eyJ.abc",
+                Some("/api/auth/email-verification/{token}")
+            ),
+            "/api/auth/email-verification/[REDACTED]"
+        );
+        assert_eq!(
+            redact_path("/api/projects/p-1", Some("/api/projects/{id}")),
+            "/api/projects/p-1"
+        );
+        assert_eq!(redact_path("/unknown/x", None), "/unknown/x");
+    }
+
+    #[actix_web::test]
+    async fn test_redact_query_masks_only_sensitive_values() {
+        assert_eq!(
+            redact_query("token=abc.def&page=2&per_page=10"),
+            "token=[REDACTED]&page=2&per_page=10"
+        );
+        assert_eq!(redact_query(""), "");
+        assert_eq!(redact_query("flag"), "flag");
+    }
+
+    #[actix_web::test]
+    async fn test_redact_headers_masks_authorization_and_cookie() {
+        let req = test::TestRequest::default()
+            .insert_header(("authorization", "Bearer abc"))
+            .insert_header(("cookie", "session=xyz"))
+            .insert_header(("user-agent", "curl/8"))
+            .to_http_request();
+
+        let mut headers = redact_headers(req.headers());
+        headers.sort();
+
+        assert_eq!(
+            headers,
+            vec![
+                "authorization: [REDACTED]",
+                "cookie: [REDACTED]",
+                "user-agent: curl/8",
+            ]
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_redact_json_masks_nested_fields() {
+        let mut value = json!({
+            "email": "a@b.c",
+            "password": "hunter2",
+            "tokens": [{ "access_token": "x", "kind": "bearer" }],
+            "profile": { "Authorization": "Bearer y", "name": "A" }
+        });
+
+        redact_json(&mut value);
+
+        assert_eq!(value["email"], "a@b.c");
+        assert_eq!(value["password"], REDACTED);
+        // The key itself matches, so the whole array is replaced
+        assert_eq!(value["tokens"], REDACTED);
+        assert_eq!(value["profile"]["Authorization"], REDACTED);
+        assert_eq!(value["profile"]["name"], "A");
+    }
+
+    async fn who(req: HttpRequest) -> HttpResponse {
+        let user_id = Uuid::nil();
+        record_user(&req, user_id);
+        ApiResponse::success(user_id.to_string())
+    }
+
+    #[actix_web::test]
+    async fn test_middleware_passes_responses_through() {
+        let app = test::init_service(
+            App::new()
+                .wrap(from_fn(access_log_middleware))
+                .wrap(from_fn(request_id_middleware))
+                .route("/who", web::get().to(who))
+                .route(
+                    "/fail",
+                    web::get().to(|| async { ApiResponse::internal_error() }),
+                ),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/who?token=abc").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        let stored = resp
+            .request()
+            .extensions()
+            .get::<AccessLogUser>()
+            .map(|user| user.0);
+        assert_eq!(stored, Some(Uuid::nil()));
+
+        let req = test::TestRequest::get().uri("/fail").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 500);
+    }
+}
@@ -0,0 +1,198 @@
+// src/shared/api/body_limit.rs
+use actix_web::{
+    body::{EitherBody, MessageBody},
+    dev::{Payload, ServiceRequest, ServiceResponse},
+    error::PayloadError,
+    http::header::CONTENT_LENGTH,
+    middleware::Next,
+    Error, HttpMessage, HttpResponse,
+};
+use futures::StreamExt;
+
+use crate::shared::api::error_codes::PAYLOAD_TOO_LARGE;
+
+/// Login, register, refresh and logout take a few short fields
+pub const AUTH_LIMIT_BYTES: usize = 16 * 1024;
+
+/// CV replace and patch carry the whole document
+pub const CV_LIMIT_BYTES: usize = 1024 * 1024;
+
+/// Every other JSON body
+pub const DEFAULT_LIMIT_BYTES: usize = 256 * 1024;
+
+/// Content import takes a zip archive, not JSON
+pub const IMPORT_LIMIT_BYTES: usize = 10 * 1024 * 1024;
+
+/// The largest JSON limit. `JsonConfig` uses it as a backstop; the
+/// per-route limit is enforced by [`body_limit_middleware`].
+pub const MAX_LIMIT_BYTES: usize = CV_LIMIT_BYTES;
+
+/// Body size limit for a request path.
+///
+/// File bytes never pass through the API (clients upload to storage with a
+/// signed URL, sized by `UploadPolicy`), so `/api/media/...` only takes
+/// upload metadata and gets the default.
+pub fn limit_for(path: &str) -> usize {
+    if path.starts_with("/api/auth/") {
+        AUTH_LIMIT_BYTES
+    } else if path.starts_with("/api/cvs") {
+        CV_LIMIT_BYTES
+    } else if path == "/api/admin/import" {
+        IMPORT_LIMIT_BYTES
+    } else {
+        DEFAULT_LIMIT_BYTES
+    }
+}
+
+/// 413 in the API's error format
+pub fn payload_too_large(limit: usize) -> HttpResponse {
+    PAYLOAD_TOO_LARGE.with_message(&format!(
+        "Request body exceeds the {} KiB limit",
+        limit / 1024
+    ))
+}
+
+fn declared_length(req: &ServiceRequest) -> Option<usize> {
+    req.headers()
+        .get(CONTENT_LENGTH)?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()
+}
+
+/// Enforces [`limit_for`] on request bodies.
+///
+/// - A `Content-Length` over the limit is rejected before the handler runs
+/// - Chunked bodies are cut off once they pass the limit; the extractor then
+///   fails with an overflow, which `custom_json_config` turns into the same 413
+pub async fn body_limit_middleware(
+    mut req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let limit = limit_for(req.path());
+
+    if declared_length(&req).is_some_and(|length| length > limit) {
+        return Ok(req
+            .into_response(payload_too_large(limit))
+            .map_into_right_body());
+    }
+
+    let mut received = 0;
+    let limited = req.take_payload().map(move |chunk| {
+        let chunk = chunk?;
+        received += chunk.len();
+        if received > limit {
+            Err(PayloadError::Overflow)
+        } else {
+            Ok(chunk)
+        }
+    });
+    req.set_payload(Payload::from(limited.boxed_local()));
+
+    next.call(req)
+        .await
+        .map(ServiceResponse::map_into_left_body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::api::{custom_json_config, ApiResponse};
+    use actix_web::{
+        http::StatusCode,
+        middleware::from_fn,
+        test::{self, TestRequest},
+        web, App,
+    };
+    use futures::stream;
+
+    async fn echo_len(body: web::Json<serde_json::Value>) -> HttpResponse {
+        ApiResponse::success(body.to_string().len())
+    }
+
+    macro_rules! app {
+        () => {
+            test::init_service(
+                App::new()
+                    .app_data(custom_json_config())
+                    .wrap(from_fn(body_limit_middleware))
+                    .route("/api/auth/login", web::post().to(echo_len))
+                    .route("/api/cvs/{id}", web::put().to(echo_len))
+                    .route("/api/topics", web::post().to(echo_len)),
+            )
+            .await
+        };
+    }
+
+    fn body_of(size: usize) -> serde_json::Value {
+        serde_json::json!({ "text": "x".repeat(size) })
+    }
+
+    #[test]
+    fn test_limit_for_paths() {
+        assert_eq!(limit_for("/api/auth/login"), AUTH_LIMIT_BYTES);
+        assert_eq!(limit_for("/api/cvs/abc"), CV_LIMIT_BYTES);
+        assert_eq!(limit_for("/api/projects"), DEFAULT_LIMIT_BYTES);
+        assert_eq!(limit_for("/api/admin/import"), IMPORT_LIMIT_BYTES);
+        assert!(MAX_LIMIT_BYTES >= AUTH_LIMIT_BYTES.max(DEFAULT_LIMIT_BYTES));
+    }
+
+    #[actix_web::test]
+    async fn test_accepts_body_within_limit() {
+        let app = app!();
+
+        let req = TestRequest::post()
+            .uri("/api/auth/login")
+            .set_json(body_of(1024))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_rejects_declared_length_over_route_limit() {
+        let app = app!();
+
+        let req = TestRequest::post()
+            .uri("/api/auth/login")
+            .set_json(body_of(AUTH_LIMIT_BYTES))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "PAYLOAD_TOO_LARGE");
+    }
+
+    #[actix_web::test]
+    async fn test_cv_routes_allow_larger_bodies() {
+        let app = app!();
+
+        let req = TestRequest::put()
+            .uri("/api/cvs/1")
+            .set_json(body_of(DEFAULT_LIMIT_BYTES))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_rejects_streamed_body_over_limit() {
+        let app = app!();
+
+        let chunk = web::Bytes::from(vec![b' '; 4096]);
+        let chunks = (0..8).map(move |_| Ok::<_, PayloadError>(chunk.clone()));
+        // No Content-Length, so only the streamed byte count can catch it
+        let (req, _) = TestRequest::post()
+            .uri("/api/auth/login")
+            .insert_header(("content-type", "application/json"))
+            .to_request()
+            .replace_payload(Payload::from(stream::iter(chunks).boxed_local()));
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
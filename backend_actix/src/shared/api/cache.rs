@@ -0,0 +1,203 @@
+// src/shared/api/cache.rs
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    http::{
+        header::{HeaderValue, AUTHORIZATION, CACHE_CONTROL},
+        Method, StatusCode,
+    },
+    middleware::{Compress, Next},
+    Error, HttpResponse,
+};
+
+/// Public, unauthenticated reads (`/api/public/...`): short shared cache
+pub const PUBLIC_MAX_AGE_SECS: u32 = 300;
+
+const PUBLIC_PREFIX: &str = "/api/public/";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CachePolicy {
+    /// Authenticated or mutable responses: never stored
+    NoStore,
+    /// Per-user responses a browser may reuse for a while
+    Private { max_age: u32 },
+    /// Shared-cacheable responses (CDN, proxies)
+    Public { max_age: u32 },
+}
+
+impl CachePolicy {
+    pub fn header_value(&self) -> String {
+        match self {
+            CachePolicy::NoStore => "no-store".to_string(),
+            CachePolicy::Private { max_age } => format!("private, max-age={max_age}"),
+            CachePolicy::Public { max_age } => format!("public, max-age={max_age}"),
+        }
+    }
+
+    /// Default policy when a handler sets none.
+    ///
+    /// - Anything carrying credentials -> no-store
+    /// - GET/HEAD under `/api/public/` -> public, `PUBLIC_MAX_AGE_SECS`
+    /// - Everything else -> no-store
+    pub fn default_for(method: &Method, path: &str, authenticated: bool) -> Self {
+        let is_read = method == Method::GET || method == Method::HEAD;
+
+        if !authenticated && is_read && path.starts_with(PUBLIC_PREFIX) {
+            CachePolicy::Public {
+                max_age: PUBLIC_MAX_AGE_SECS,
+            }
+        } else {
+            CachePolicy::NoStore
+        }
+    }
+
+    /// Set this policy on a handler's response; the middleware keeps it.
+    pub fn apply(&self, resp: &mut HttpResponse) {
+        if let Ok(value) = HeaderValue::from_str(&self.header_value()) {
+            resp.headers_mut().insert(CACHE_CONTROL, value);
+        }
+    }
+}
+
+/// gzip/brotli (and zstd) response compression, negotiated from `Accept-Encoding`.
+pub fn compression() -> Compress {
+    Compress::default()
+}
+
+/// Adds `Cache-Control` to responses that don't set it themselves.
+///
+/// Error responses are always `no-store`, so a transient failure is never cached.
+pub async fn cache_control_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let default = CachePolicy::default_for(
+        req.method(),
+        req.path(),
+        req.headers().contains_key(AUTHORIZATION),
+    );
+
+    let mut res = next.call(req).await?;
+
+    if !res.headers().contains_key(CACHE_CONTROL) {
+        // 304 revalidates a cacheable response, so it keeps the same policy
+        let policy = if res.status().is_success() || res.status() == StatusCode::NOT_MODIFIED {
+            default
+        } else {
+            CachePolicy::NoStore
+        };
+        if let Ok(value) = HeaderValue::from_str(&policy.header_value()) {
+            res.headers_mut().insert(CACHE_CONTROL, value);
+        }
+    }
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{middleware::from_fn, test, web, App};
+
+    fn cache_control<B>(resp: &ServiceResponse<B>) -> String {
+        resp.headers()
+            .get(CACHE_CONTROL)
+            .expect("missing Cache-Control")
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    macro_rules! app {
+        () => {
+            test::init_service(
+                App::new()
+                    .wrap(from_fn(cache_control_middleware))
+                    .route(
+                        "/api/public/projects/{username}",
+                        web::get().to(|| async { HttpResponse::Ok().finish() }),
+                    )
+                    .route(
+                        "/api/public/missing",
+                        web::get().to(|| async { HttpResponse::NotFound().finish() }),
+                    )
+                    .route(
+                        "/api/projects",
+                        web::get().to(|| async { HttpResponse::Ok().finish() }),
+                    )
+                    .route(
+                        "/api/variant",
+                        web::get().to(|| async {
+                            let mut resp = HttpResponse::Ok().finish();
+                            CachePolicy::Private { max_age: 120 }.apply(&mut resp);
+                            resp
+                        }),
+                    ),
+            )
+            .await
+        };
+    }
+
+    #[actix_web::test]
+    async fn test_header_values() {
+        assert_eq!(CachePolicy::NoStore.header_value(), "no-store");
+        assert_eq!(
+            CachePolicy::Public { max_age: 60 }.header_value(),
+            "public, max-age=60"
+        );
+        assert_eq!(
+            CachePolicy::Private { max_age: 60 }.header_value(),
+            "private, max-age=60"
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_public_reads_are_cacheable() {
+        let app = app!();
+
+        let req = test::TestRequest::get()
+            .uri("/api/public/projects/alice")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(cache_control(&resp), "public, max-age=300");
+    }
+
+    #[actix_web::test]
+    async fn test_public_reads_with_credentials_are_not_stored() {
+        let app = app!();
+
+        let req = test::TestRequest::get()
+            .uri("/api/public/projects/alice")
+            .insert_header((AUTHORIZATION, "Bearer token"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(cache_control(&resp), "no-store");
+    }
+
+    #[actix_web::test]
+    async fn test_errors_and_private_endpoints_are_not_stored() {
+        let app = app!();
+
+        let req = test::TestRequest::get()
+            .uri("/api/public/missing")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(cache_control(&resp), "no-store");
+
+        let req = test::TestRequest::get().uri("/api/projects").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(cache_control(&resp), "no-store");
+    }
+
+    #[actix_web::test]
+    async fn test_handler_policy_is_kept() {
+        let app = app!();
+
+        let req = test::TestRequest::get().uri("/api/variant").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(cache_control(&resp), "private, max-age=120");
+    }
+}
@@ -0,0 +1,57 @@
+// src/shared/api/error_code.rs
+use super::ApiResponse;
+use actix_web::{http::StatusCode, HttpResponse};
+
+/// A machine-readable error code, with the status it is answered with and
+/// a default English message. Codes are declared with `error_codes!`, in
+/// `shared::api::error_codes` when several modules use them and in the
+/// module's `error_codes` otherwise; all of them are listed at
+/// `GET /api/meta/error-codes`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorCode {
+    pub code: &'static str,
+    pub status: StatusCode,
+    pub message: &'static str,
+}
+
+impl ErrorCode {
+    /// The error with its default message
+    pub fn response(&self) -> HttpResponse {
+        ApiResponse::error(self.status, self.code, self.message)
+    }
+
+    /// The error with a message specific to this occurrence
+    pub fn with_message(&self, message: &str) -> HttpResponse {
+        ApiResponse::error(self.status, self.code, message)
+    }
+
+    /// The error under another status. Only for the token codes, which are
+    /// 401 on a session and 400 on an emailed link.
+    pub fn with_status(&self, status: StatusCode, message: &str) -> HttpResponse {
+        ApiResponse::error(status, self.code, message)
+    }
+}
+
+/// Declares one `ErrorCode` constant per line, named after its code, and
+/// `ALL` listing them in order:
+///
+/// ```ignore
+/// error_codes! {
+///     CV_NOT_FOUND = (NOT_FOUND, "CV not found");
+/// }
+/// ```
+macro_rules! error_codes {
+    ($($name:ident = ($status:ident, $message:literal);)*) => {
+        $(
+            pub const $name: $crate::shared::api::ErrorCode = $crate::shared::api::ErrorCode {
+                code: stringify!($name),
+                status: actix_web::http::StatusCode::$status,
+                message: $message,
+            };
+        )*
+
+        /// Every code declared here
+        pub const ALL: &[$crate::shared::api::ErrorCode] = &[$($name),*];
+    };
+}
+pub(crate) use error_codes;
@@ -0,0 +1,28 @@
+// src/shared/api/error_codes.rs
+// Codes more than one module answers with
+use super::error_codes;
+
+error_codes! {
+    INTERNAL_ERROR = (INTERNAL_SERVER_ERROR, "An unexpected error occurred");
+    VALIDATION_ERROR = (BAD_REQUEST, "The request is invalid");
+    INVALID_JSON = (BAD_REQUEST, "The request body is not valid JSON");
+    MISSING_FIELD = (BAD_REQUEST, "A required field is missing");
+    UNKNOWN_FIELD = (BAD_REQUEST, "The request has a field that is not accepted");
+    INVALID_VALUE = (BAD_REQUEST, "A field has a value of the wrong type");
+    INVALID_PAGINATION = (BAD_REQUEST, "Invalid page or page size");
+    INVALID_EMAIL = (BAD_REQUEST, "Invalid email address");
+    INVALID_LOCALE = (BAD_REQUEST, "Unsupported locale");
+    TITLE_TOO_LONG = (BAD_REQUEST, "Title is too long");
+    SEO_TITLE_TOO_LONG = (BAD_REQUEST, "SEO title is too long");
+    SEO_DESCRIPTION_TOO_LONG = (BAD_REQUEST, "SEO description is too long");
+    INVALID_CANONICAL_URL = (BAD_REQUEST, "Canonical URL must be an absolute http(s) URL");
+    SLUG_ALREADY_EXISTS = (CONFLICT, "Slug already exists");
+    STALE_WRITE = (CONFLICT, "It was changed since you last loaded it; reload and try again");
+    NOT_FOUND = (NOT_FOUND, "Not found");
+    UNAUTHORIZED = (UNAUTHORIZED, "Missing or invalid secret");
+    PAYLOAD_TOO_LARGE = (PAYLOAD_TOO_LARGE, "Request body is too large");
+    RATE_LIMITED = (TOO_MANY_REQUESTS, "Too many requests, please try again later");
+    BOT_CHECK_REQUIRED = (BAD_REQUEST, "Complete the bot check and send its token in X-Bot-Check-Token");
+    BOT_CHECK_FAILED = (FORBIDDEN, "Bot check failed, please retry");
+    BOT_CHECK_UNAVAILABLE = (SERVICE_UNAVAILABLE, "Bot check is temporarily unavailable");
+}
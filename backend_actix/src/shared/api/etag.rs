@@ -0,0 +1,148 @@
+// src/shared/api/etag.rs
+use actix_web::{
+    http::header::{ETAG, IF_NONE_MATCH},
+    HttpRequest, HttpResponse,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::fmt::Display;
+
+use super::ApiResponse;
+
+/// Weak validator for public GET responses.
+///
+/// Pollers (e.g. the static-site regeneration job) send it back in
+/// `If-None-Match` and get an empty 304 when nothing changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ETag(String);
+
+impl ETag {
+    /// From a row's identity and last modification time.
+    pub fn from_version(id: impl Display, updated_at: DateTime<Utc>) -> Self {
+        Self::digest(format!("{}:{}", id, updated_at.timestamp_micros()).as_bytes())
+    }
+
+    /// From the serialized payload, for responses without a single `updated_at`
+    /// (lists, or entities that don't carry one).
+    pub fn from_body<T: Serialize>(body: &T) -> Self {
+        Self::digest(&serde_json::to_vec(body).unwrap_or_default())
+    }
+
+    fn digest(input: &[u8]) -> Self {
+        let hash = Sha256::digest(input);
+        ETag(format!("W/\"{}\"", hex_prefix(&hash[..16])))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Weak comparison against `If-None-Match` (RFC 9110 §13.1.2).
+    pub fn matches(&self, req: &HttpRequest) -> bool {
+        let Some(header) = req
+            .headers()
+            .get(IF_NONE_MATCH)
+            .and_then(|v| v.to_str().ok())
+        else {
+            return false;
+        };
+
+        let ours = opaque(&self.0);
+        header
+            .split(',')
+            .map(str::trim)
+            .any(|candidate| candidate == "*" || opaque(candidate) == ours)
+    }
+
+    /// 304 when the client already has this version, otherwise the usual success body.
+    /// Both carry the `ETag` header.
+    pub fn respond<T: Serialize>(self, req: &HttpRequest, data: T) -> HttpResponse {
+        let mut resp = if self.matches(req) {
+            HttpResponse::NotModified().finish()
+        } else {
+            ApiResponse::success(data)
+        };
+
+        if let Ok(value) = self.as_str().parse() {
+            resp.headers_mut().insert(ETAG, value);
+        }
+        resp
+    }
+}
+
+fn opaque(tag: &str) -> &str {
+    tag.strip_prefix("W/").unwrap_or(tag)
+}
+
+fn hex_prefix(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::TestRequest;
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    fn updated_at() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_version_etag_is_weak_and_stable() {
+        let id = Uuid::nil();
+        let a = ETag::from_version(id, updated_at());
+        let b = ETag::from_version(id, updated_at());
+
+        assert_eq!(a, b);
+        assert!(a.as_str().starts_with("W/\""));
+    }
+
+    #[test]
+    fn test_version_etag_changes_with_updated_at() {
+        let id = Uuid::nil();
+        let a = ETag::from_version(id, updated_at());
+        let b = ETag::from_version(id, updated_at() + chrono::Duration::seconds(1));
+
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_matches_weak_strong_lists_and_wildcard() {
+        let tag = ETag::from_body(&serde_json::json!({ "title": "x" }));
+        let strong = tag.as_str().trim_start_matches("W/").to_string();
+
+        for header in [
+            tag.as_str().to_string(),
+            strong,
+            format!("W/\"other\", {}", tag.as_str()),
+            "*".to_string(),
+        ] {
+            let req = TestRequest::default()
+                .insert_header((IF_NONE_MATCH, header.as_str()))
+                .to_http_request();
+            assert!(tag.matches(&req), "should match {header}");
+        }
+
+        let req = TestRequest::default()
+            .insert_header((IF_NONE_MATCH, "W/\"other\""))
+            .to_http_request();
+        assert!(!tag.matches(&req));
+        assert!(!tag.matches(&TestRequest::default().to_http_request()));
+    }
+
+    #[test]
+    fn test_respond_returns_304_with_etag_when_matching() {
+        let tag = ETag::from_version(Uuid::nil(), updated_at());
+        let req = TestRequest::default()
+            .insert_header((IF_NONE_MATCH, tag.as_str()))
+            .to_http_request();
+
+        let resp = tag.clone().respond(&req, "body");
+
+        assert_eq!(resp.status(), 304);
+        assert_eq!(resp.headers().get(ETAG).unwrap(), tag.as_str());
+    }
+}
@@ -0,0 +1,329 @@
+// src/shared/api/json_case.rs
+//! Key casing of JSON bodies (`JSON_CASE`). DTOs are written in snake_case;
+//! with `camel` the middleware renames keys on the way out and back on the
+//! way in, so the handlers never see the difference.
+use actix_web::{
+    body::{to_bytes, BoxBody, MessageBody},
+    dev::{Payload, ServiceRequest, ServiceResponse},
+    error::ErrorInternalServerError,
+    http::header::{HeaderValue, CONTENT_LENGTH, CONTENT_TYPE},
+    middleware::Next,
+    web::Bytes,
+    Error, HttpMessage,
+};
+use serde_json::{Map, Value};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// Served as is: the OpenAPI document's keys are paths and schema names, not
+/// fields, and oEmbed consumers expect the keys the spec spells out
+const AS_IS_PATHS: [&str; 2] = ["/api/openapi.json", "/api/public/oembed"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JsonCase {
+    /// `full_name`, as the DTOs are declared
+    #[default]
+    Snake,
+    /// `fullName`
+    Camel,
+}
+
+impl FromStr for JsonCase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "snake" => Ok(Self::Snake),
+            "camel" => Ok(Self::Camel),
+            _ => Err("expected 'snake' or 'camel'".to_string()),
+        }
+    }
+}
+
+static JSON_CASE: OnceLock<JsonCase> = OnceLock::new();
+
+/// Called once from `main` before the server starts; later calls are ignored.
+pub fn set_json_case(case: JsonCase) {
+    let _ = JSON_CASE.set(case);
+}
+
+fn json_case() -> JsonCase {
+    JSON_CASE.get().copied().unwrap_or_default()
+}
+
+/// `full_name` -> `fullName`. Keys that aren't snake_case identifiers (paths,
+/// locales, ids used as map keys) are left alone.
+pub fn to_camel(key: &str) -> String {
+    if !is_identifier(key, |c| c.is_ascii_lowercase() || c == '_') {
+        return key.to_string();
+    }
+    let mut out = String::with_capacity(key.len());
+    let mut upper = false;
+    for c in key.chars() {
+        if c == '_' {
+            upper = !out.is_empty();
+        } else if upper {
+            out.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// `fullName` -> `full_name`; snake_case keys pass through unchanged
+pub fn to_snake(key: &str) -> String {
+    if !is_identifier(key, |c| c.is_ascii_alphabetic()) {
+        return key.to_string();
+    }
+    let mut out = String::with_capacity(key.len() + 4);
+    for c in key.chars() {
+        if c.is_ascii_uppercase() {
+            out.push('_');
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Starts with a lowercase letter; otherwise digits or `allowed`
+fn is_identifier(key: &str, allowed: impl Fn(char) -> bool) -> bool {
+    key.starts_with(|c: char| c.is_ascii_lowercase())
+        && key.chars().all(|c| c.is_ascii_digit() || allowed(c))
+}
+
+/// Every object key in `value`, at any depth, renamed with `rename`
+pub fn rename_keys(value: Value, rename: &impl Fn(&str) -> String) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| (rename(&key), rename_keys(value, rename)))
+                .collect::<Map<_, _>>(),
+        ),
+        Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .map(|item| rename_keys(item, rename))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+/// `bytes` with renamed keys, or `None` if they aren't JSON
+fn rename_body(bytes: &[u8], rename: &impl Fn(&str) -> String) -> Option<Vec<u8>> {
+    let value: Value = serde_json::from_slice(bytes).ok()?;
+    serde_json::to_vec(&rename_keys(value, rename)).ok()
+}
+
+/// Response `bytes` in camelCase, including the field names that validation
+/// errors carry as values (`errors[].field`), or `None` if they aren't JSON
+fn camel_body(bytes: &[u8]) -> Option<Vec<u8>> {
+    let mut value = rename_keys(serde_json::from_slice(bytes).ok()?, &to_camel);
+    // Envelope or problem+json
+    let errors = if value.get("error").is_some() {
+        value
+            .get_mut("error")
+            .and_then(|error| error.get_mut("errors"))
+    } else {
+        value.get_mut("errors")
+    };
+    for error in errors.and_then(Value::as_array_mut).into_iter().flatten() {
+        if let Some(Value::String(field)) = error.get_mut("field") {
+            *field = to_camel(field);
+        }
+    }
+    serde_json::to_vec(&value).ok()
+}
+
+fn is_json(content_type: Option<&HeaderValue>) -> bool {
+    content_type
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            let mime = value.split(';').next().unwrap_or_default().trim();
+            mime == "application/json" || mime.ends_with("+json")
+        })
+}
+
+/// Applies [`JsonCase::Camel`] to JSON request and response bodies. Query
+/// parameters, headers and non-JSON bodies keep their names.
+pub async fn json_case_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<BoxBody>, Error> {
+    apply(json_case(), req, next).await
+}
+
+async fn apply(
+    case: JsonCase,
+    mut req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<BoxBody>, Error> {
+    if case == JsonCase::Snake || AS_IS_PATHS.contains(&req.path()) {
+        return next
+            .call(req)
+            .await
+            .map(ServiceResponse::map_into_boxed_body);
+    }
+
+    if is_json(req.headers().get(CONTENT_TYPE)) {
+        // Already capped by the body limit middleware, which runs first
+        let mut body = Vec::new();
+        let mut payload = req.take_payload();
+        while let Some(chunk) = futures::StreamExt::next(&mut payload).await {
+            body.extend_from_slice(&chunk?);
+        }
+        // Malformed JSON goes through untouched, for the extractor to reject
+        let body = rename_body(&body, &to_snake).unwrap_or(body);
+        req.headers_mut()
+            .insert(CONTENT_LENGTH, HeaderValue::from(body.len()));
+        req.set_payload(Payload::from(Bytes::from(body)));
+    }
+
+    let res = next.call(req).await?;
+    if !is_json(res.headers().get(CONTENT_TYPE)) {
+        return Ok(res.map_into_boxed_body());
+    }
+
+    let (req, res) = res.into_parts();
+    let (mut res, body) = res.into_parts();
+    let bytes = to_bytes(body).await.map_err(|e| {
+        let e: Box<dyn std::error::Error> = e.into();
+        ErrorInternalServerError(e.to_string())
+    })?;
+    let body = camel_body(&bytes).unwrap_or_else(|| bytes.to_vec());
+    res.headers_mut()
+        .insert(CONTENT_LENGTH, HeaderValue::from(body.len()));
+    Ok(ServiceResponse::new(
+        req,
+        res.set_body(body).map_into_boxed_body(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::api::{custom_json_config, ApiResponse};
+    use actix_web::{middleware::from_fn, test, web, App, HttpResponse};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Serialize, Deserialize)]
+    struct Profile {
+        full_name: String,
+        preferred_locale: Option<String>,
+    }
+
+    async fn echo(body: web::Json<Profile>) -> HttpResponse {
+        ApiResponse::success(body.into_inner())
+    }
+
+    macro_rules! app {
+        ($case:expr) => {
+            test::init_service(
+                App::new()
+                    .app_data(custom_json_config())
+                    .wrap(from_fn(move |req, next| apply($case, req, next)))
+                    .route("/profile", web::put().to(echo)),
+            )
+            .await
+        };
+    }
+
+    async fn put(case: JsonCase, body: Value) -> (u16, Value) {
+        let app = app!(case);
+        let req = test::TestRequest::put()
+            .uri("/profile")
+            .set_json(body)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        (resp.status().as_u16(), test::read_body_json(resp).await)
+    }
+
+    #[actix_web::test]
+    async fn test_snake_case_contract() {
+        let (status, body) = put(
+            JsonCase::Snake,
+            json!({ "full_name": "Jane Doe", "preferred_locale": "en" }),
+        )
+        .await;
+
+        assert_eq!(status, 200);
+        assert_eq!(
+            body,
+            json!({ "success": true, "data": { "full_name": "Jane Doe", "preferred_locale": "en" } })
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_camel_case_contract() {
+        let (status, body) = put(
+            JsonCase::Camel,
+            json!({ "fullName": "Jane Doe", "preferredLocale": "en" }),
+        )
+        .await;
+
+        assert_eq!(status, 200);
+        assert_eq!(
+            body,
+            json!({ "success": true, "data": { "fullName": "Jane Doe", "preferredLocale": "en" } })
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_camel_case_errors_name_fields_in_camel_case() {
+        let (status, body) = put(JsonCase::Camel, json!({ "preferredLocale": "en" })).await;
+
+        assert_eq!(status, 400);
+        assert_eq!(body["error"]["code"], "VALIDATION_ERROR");
+        assert_eq!(body["error"]["errors"][0]["field"], "fullName");
+    }
+
+    #[actix_web::test]
+    async fn test_key_conversions() {
+        assert_eq!(to_camel("full_name"), "fullName");
+        assert_eq!(to_camel("is_verified"), "isVerified");
+        assert_eq!(to_camel("success"), "success");
+        assert_eq!(to_snake("fullName"), "full_name");
+        assert_eq!(to_snake("full_name"), "full_name");
+        // Not field names
+        assert_eq!(to_camel("/api/foo_bar"), "/api/foo_bar");
+        assert_eq!(to_camel("pt-BR"), "pt-BR");
+        assert_eq!(to_snake("pt-BR"), "pt-BR");
+        assert_eq!(to_snake("Content"), "Content");
+    }
+
+    #[actix_web::test]
+    async fn test_renames_keys_at_every_depth_but_not_values() {
+        let value = json!({
+            "next_cursor": null,
+            "items": [{ "full_name": "first_name last_name", "tags": ["snake_case"] }]
+        });
+
+        assert_eq!(
+            rename_keys(value, &to_camel),
+            json!({
+                "nextCursor": null,
+                "items": [{ "fullName": "first_name last_name", "tags": ["snake_case"] }]
+            })
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_camel_round_trip_restores_dto_keys() {
+        let dto = json!({ "preferred_locale": "en", "remember_me": true, "email": "a@b.c" });
+        let camel = rename_keys(dto.clone(), &to_camel);
+
+        assert_eq!(rename_keys(camel, &to_snake), dto);
+    }
+
+    #[actix_web::test]
+    async fn test_parse_json_case() {
+        assert_eq!("camel".parse(), Ok(JsonCase::Camel));
+        assert_eq!("Snake".parse(), Ok(JsonCase::Snake));
+        assert!("kebab".parse::<JsonCase>().is_err());
+    }
+}
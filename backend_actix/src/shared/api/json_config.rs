@@ -1,14 +1,114 @@
 // src/shared/api/json_config.rs
+use crate::shared::api::body_limit::{limit_for, payload_too_large, MAX_LIMIT_BYTES};
+use crate::shared::api::error_codes::{
+    INVALID_JSON, INVALID_VALUE, MISSING_FIELD, UNKNOWN_FIELD, VALIDATION_ERROR,
+};
 use crate::shared::api::ApiResponse;
-use actix_web::web::JsonConfig;
+use crate::shared::validation::ValidationErrors;
+use actix_web::{error::JsonPayloadError, http::StatusCode, web::JsonConfig, ResponseError};
 
 pub fn custom_json_config() -> JsonConfig {
-    JsonConfig::default().error_handler(|err, _req| {
-        let message = err.to_string();
-        actix_web::error::InternalError::from_response(
-            err,
-            ApiResponse::bad_request("VALIDATION_ERROR", &message),
-        )
-        .into()
-    })
+    JsonConfig::default()
+        .limit(MAX_LIMIT_BYTES)
+        .error_handler(|err, req| {
+            let response = if err.status_code() == StatusCode::PAYLOAD_TOO_LARGE {
+                payload_too_large(limit_for(req.path()))
+            } else {
+                ApiResponse::bad_request_with_fields(
+                    VALIDATION_ERROR.code,
+                    &err.to_string(),
+                    field_errors(&err).fields(),
+                )
+            };
+            actix_web::error::InternalError::from_response(err, response).into()
+        })
+}
+
+/// The field behind a body that didn't deserialize. serde stops at the first
+/// problem, so this names at most one field; type mismatches don't carry the
+/// field name and are reported against `body`.
+fn field_errors(err: &JsonPayloadError) -> ValidationErrors {
+    let JsonPayloadError::Deserialize(e) = err else {
+        return ValidationErrors::new();
+    };
+    let text = e.to_string();
+    // serde_json appends the position, which is noise next to a field name
+    let message = text
+        .rsplit_once(" at line ")
+        .map_or(text.as_str(), |(message, _)| message);
+
+    if !e.is_data() {
+        return ValidationErrors::single("body", INVALID_JSON.code, message);
+    }
+    let named = |prefix: &str| {
+        message
+            .strip_prefix(prefix)
+            .and_then(|rest| rest.split('`').next())
+            .map(str::to_string)
+    };
+    match (named("missing field `"), named("unknown field `")) {
+        (Some(field), _) => ValidationErrors::single(&field, MISSING_FIELD.code, message),
+        (_, Some(field)) => ValidationErrors::single(&field, UNKNOWN_FIELD.code, message),
+        _ => ValidationErrors::single("body", INVALID_VALUE.code, message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{post, test, web, App, HttpResponse};
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[allow(dead_code)]
+    struct Body {
+        name: String,
+        count: u32,
+    }
+
+    #[post("/echo")]
+    async fn echo(_: web::Json<Body>) -> HttpResponse {
+        HttpResponse::Ok().finish()
+    }
+
+    async fn errors_for(payload: &str) -> serde_json::Value {
+        let app = test::init_service(App::new().app_data(custom_json_config()).service(echo)).await;
+        let req = test::TestRequest::post()
+            .uri("/echo")
+            .insert_header(("content-type", "application/json"))
+            .set_payload(payload.to_string())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "VALIDATION_ERROR");
+        body["error"]["errors"].clone()
+    }
+
+    #[actix_web::test]
+    async fn test_names_the_missing_field() {
+        let errors = errors_for(r#"{"name": "a"}"#).await;
+
+        assert_eq!(errors[0]["field"], "count");
+        assert_eq!(errors[0]["code"], "MISSING_FIELD");
+        assert_eq!(errors[0]["message"], "missing field `count`");
+    }
+
+    #[actix_web::test]
+    async fn test_names_the_unknown_field() {
+        let errors = errors_for(r#"{"name": "a", "count": 1, "colour": "red"}"#).await;
+
+        assert_eq!(errors[0]["field"], "colour");
+        assert_eq!(errors[0]["code"], "UNKNOWN_FIELD");
+    }
+
+    #[actix_web::test]
+    async fn test_malformed_json_is_reported_against_the_body() {
+        let errors = errors_for(r#"{"name": "#).await;
+
+        assert_eq!(errors[0]["field"], "body");
+        assert_eq!(errors[0]["code"], "INVALID_JSON");
+    }
 }
@@ -1,5 +1,17 @@
+pub mod body_limit;
+pub mod cache;
+mod error_code;
+pub mod error_codes;
+pub mod etag;
+pub mod json_case;
 mod json_config;
+pub mod owner_view;
+pub mod pagination;
+pub mod precondition;
+pub mod problem;
 mod response;
 
+pub(crate) use error_code::error_codes;
+pub use error_code::ErrorCode;
 pub use json_config::custom_json_config;
 pub use response::{ApiError, ApiResponse};
@@ -0,0 +1,41 @@
+//! Owner-only additions to public responses.
+//!
+//! Public handlers take a `MaybeUser`; when it is the owner of what they
+//! serve, they wrap the item in an [`OwnerView`] so the site can show its
+//! owner edit links the visitors never see.
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Shown only to the owner browsing their own site
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct OwnerControls {
+    /// Authenticated endpoint that edits the item
+    #[schema(example = "/api/pages/123e4567-e89b-12d3-a456-426614174000")]
+    pub edit_url: String,
+}
+
+impl OwnerControls {
+    pub fn new(edit_url: impl Into<String>) -> Self {
+        Self {
+            edit_url: edit_url.into(),
+        }
+    }
+}
+
+/// The item's own fields plus `owner_controls`
+#[derive(Debug, Clone, Serialize)]
+pub struct OwnerView<T> {
+    #[serde(flatten)]
+    pub item: T,
+    pub owner_controls: OwnerControls,
+}
+
+impl<T> OwnerView<T> {
+    pub fn new(item: T, owner_controls: OwnerControls) -> Self {
+        Self {
+            item,
+            owner_controls,
+        }
+    }
+}
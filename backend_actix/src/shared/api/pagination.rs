@@ -0,0 +1,326 @@
+// src/shared/api/pagination.rs
+use crate::shared::api::error_codes::INVALID_PAGINATION;
+use actix_web::{
+    dev::Payload,
+    error::InternalError,
+    http::header::{HeaderName, HeaderValue, LINK},
+    web, FromRequest, HttpRequest, HttpResponse,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::future::{ready, Ready};
+use utoipa::ToSchema;
+
+use super::ApiResponse;
+
+pub const DEFAULT_LIMIT: u32 = 20;
+pub const MAX_LIMIT: u32 = 100;
+
+/// Number of items across all pages, for tooling that doesn't read the body
+pub const TOTAL_COUNT_HEADER: &str = "x-total-count";
+
+/// Envelope shared by every list endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PagedResponse<T> {
+    pub items: Vec<T>,
+    /// Opaque; send it back as `cursor` to get the next page. `null` on the last page.
+    pub next_cursor: Option<String>,
+    pub total: u64,
+}
+
+impl<T> PagedResponse<T> {
+    /// Wrap one page that was already fetched with `params.offset`/`params.limit`.
+    pub fn new<S>(items: Vec<T>, total: u64, params: &PageParams<S>) -> Self {
+        let next = params.offset + items.len() as u64;
+        let next_cursor = (!items.is_empty() && next < total).then(|| encode_cursor(next));
+
+        Self {
+            items,
+            next_cursor,
+            total,
+        }
+    }
+
+    /// Page through a list that the use case returns whole (small per-owner collections).
+    pub fn from_all<S>(all: Vec<T>, params: &PageParams<S>) -> Self {
+        let total = all.len() as u64;
+        let items = all
+            .into_iter()
+            .skip(params.offset as usize)
+            .take(params.limit as usize)
+            .collect();
+
+        Self::new(items, total, params)
+    }
+}
+
+impl<T: Serialize> PagedResponse<T> {
+    /// 200 with the page in the envelope and in [`PageHeaders`]
+    pub fn respond<S>(self, req: &HttpRequest, params: &PageParams<S>) -> HttpResponse {
+        let headers = PageHeaders::new(req, params, &self);
+        let mut resp = ApiResponse::success(self);
+        headers.apply(&mut resp);
+        resp
+    }
+}
+
+/// `X-Total-Count` and RFC 8288 `Link` headers (`next`, `prev`) for one page.
+/// Links repeat the request's path and query with another `cursor`.
+pub struct PageHeaders {
+    total: u64,
+    links: Vec<String>,
+}
+
+impl PageHeaders {
+    pub fn new<T, S>(req: &HttpRequest, params: &PageParams<S>, body: &PagedResponse<T>) -> Self {
+        let mut links = Vec::new();
+        if let Some(next) = &body.next_cursor {
+            links.push(link(req, Some(next), "next"));
+        }
+        if params.offset > 0 {
+            let prev = params.offset.saturating_sub(u64::from(params.limit));
+            let cursor = (prev > 0).then(|| encode_cursor(prev));
+            links.push(link(req, cursor.as_deref(), "prev"));
+        }
+
+        Self {
+            total: body.total,
+            links,
+        }
+    }
+
+    pub fn apply(self, resp: &mut HttpResponse) {
+        let headers = resp.headers_mut();
+        headers.insert(
+            HeaderName::from_static(TOTAL_COUNT_HEADER),
+            HeaderValue::from(self.total),
+        );
+        if self.links.is_empty() {
+            return;
+        }
+        // Paths and queries come from a request that already parsed
+        if let Ok(value) = HeaderValue::from_str(&self.links.join(", ")) {
+            headers.insert(LINK, value);
+        }
+    }
+}
+
+/// `<path?query>; rel="..."`, with `cursor` replaced (or dropped for the first page)
+fn link(req: &HttpRequest, cursor: Option<&str>, rel: &str) -> String {
+    let mut query: Vec<&str> = req
+        .query_string()
+        .split('&')
+        .filter(|pair| !pair.is_empty() && *pair != "cursor" && !pair.starts_with("cursor="))
+        .collect();
+    let cursor = cursor.map(|cursor| format!("cursor={cursor}"));
+    query.extend(cursor.as_deref());
+
+    if query.is_empty() {
+        format!("<{}>; rel=\"{rel}\"", req.path())
+    } else {
+        format!("<{}?{}>; rel=\"{rel}\"", req.path(), query.join("&"))
+    }
+}
+
+/// Endpoint without sort options; any `sort` value is rejected.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum NoSort {}
+
+/// `limit`, `cursor` and `sort` query params, validated.
+///
+/// Extract it alongside the handler's own query struct; unrelated params are ignored.
+/// Invalid values are answered with 400 `INVALID_PAGINATION` before the handler runs.
+#[derive(Debug, Clone)]
+pub struct PageParams<S = NoSort> {
+    pub limit: u32,
+    pub offset: u64,
+    pub sort: Option<S>,
+}
+
+#[derive(Deserialize)]
+struct RawPageParams<S> {
+    limit: Option<u32>,
+    cursor: Option<String>,
+    sort: Option<S>,
+}
+
+impl<S: DeserializeOwned> PageParams<S> {
+    pub fn from_query(query: &str) -> Result<Self, String> {
+        let raw = web::Query::<RawPageParams<S>>::from_query(query)
+            .map_err(|e| e.to_string())?
+            .into_inner();
+
+        let limit = raw.limit.unwrap_or(DEFAULT_LIMIT);
+        if !(1..=MAX_LIMIT).contains(&limit) {
+            return Err(format!("limit must be between 1 and {}", MAX_LIMIT));
+        }
+
+        let offset = match raw.cursor.as_deref() {
+            None | Some("") => 0,
+            Some(cursor) => decode_cursor(cursor).ok_or("cursor is malformed")?,
+        };
+
+        Ok(Self {
+            limit,
+            offset,
+            sort: raw.sort,
+        })
+    }
+}
+
+impl<S: DeserializeOwned> FromRequest for PageParams<S> {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(Self::from_query(req.query_string()).map_err(|message| {
+            InternalError::from_response("", INVALID_PAGINATION.with_message(&message)).into()
+        }))
+    }
+}
+
+// Cursors are offsets today; keeping them opaque lets us switch to keyset later
+fn encode_cursor(offset: u64) -> String {
+    URL_SAFE_NO_PAD.encode(format!("o:{}", offset))
+}
+
+fn decode_cursor(cursor: &str) -> Option<u64> {
+    let bytes = URL_SAFE_NO_PAD.decode(cursor).ok()?;
+    String::from_utf8(bytes)
+        .ok()?
+        .strip_prefix("o:")?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+    enum TestSort {
+        Newest,
+        Oldest,
+    }
+
+    #[test]
+    fn test_defaults() {
+        let params = PageParams::<TestSort>::from_query("").unwrap();
+
+        assert_eq!(params.limit, DEFAULT_LIMIT);
+        assert_eq!(params.offset, 0);
+        assert_eq!(params.sort, None);
+    }
+
+    #[test]
+    fn test_parses_limit_cursor_and_sort_ignoring_other_params() {
+        let query = format!(
+            "search=rust&limit=5&cursor={}&sort=Oldest",
+            encode_cursor(10)
+        );
+        let params = PageParams::<TestSort>::from_query(&query).unwrap();
+
+        assert_eq!(params.limit, 5);
+        assert_eq!(params.offset, 10);
+        assert_eq!(params.sort, Some(TestSort::Oldest));
+    }
+
+    #[test]
+    fn test_rejects_invalid_values() {
+        for query in [
+            "limit=0",
+            "limit=101",
+            "limit=abc",
+            "cursor=not-a-cursor",
+            "sort=Sideways",
+        ] {
+            assert!(
+                PageParams::<TestSort>::from_query(query).is_err(),
+                "{query} should be rejected"
+            );
+        }
+
+        assert!(PageParams::<NoSort>::from_query("sort=Newest").is_err());
+    }
+
+    #[test]
+    fn test_next_cursor_until_last_page() {
+        let all: Vec<u32> = (0..5).collect();
+
+        let first = PagedResponse::from_all(
+            all.clone(),
+            &PageParams::<NoSort>::from_query("limit=2").unwrap(),
+        );
+        assert_eq!(first.items, vec![0, 1]);
+        assert_eq!(first.total, 5);
+
+        let query = format!("limit=2&cursor={}", first.next_cursor.unwrap());
+        let second = PagedResponse::from_all(
+            all.clone(),
+            &PageParams::<NoSort>::from_query(&query).unwrap(),
+        );
+        assert_eq!(second.items, vec![2, 3]);
+
+        let query = format!("limit=2&cursor={}", second.next_cursor.unwrap());
+        let last = PagedResponse::from_all(all, &PageParams::<NoSort>::from_query(&query).unwrap());
+        assert_eq!(last.items, vec![4]);
+        assert!(last.next_cursor.is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_page_headers_link_next_and_prev() {
+        use actix_web::test::TestRequest;
+
+        let all: Vec<u32> = (0..5).collect();
+        let query = format!("search=rust&cursor={}&limit=2", encode_cursor(2));
+        let req = TestRequest::get()
+            .uri(&format!("/api/projects?{query}"))
+            .to_http_request();
+        let params = PageParams::<NoSort>::from_query(&query).unwrap();
+
+        let resp = PagedResponse::from_all(all, &params).respond(&req, &params);
+
+        assert_eq!(resp.headers().get(TOTAL_COUNT_HEADER).unwrap(), "5");
+        assert_eq!(
+            resp.headers().get(LINK).unwrap().to_str().unwrap(),
+            format!(
+                "</api/projects?search=rust&limit=2&cursor={}>; rel=\"next\", \
+                 </api/projects?search=rust&limit=2>; rel=\"prev\"",
+                encode_cursor(4)
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_single_page_has_no_links() {
+        let req = actix_web::test::TestRequest::get()
+            .uri("/api/topics")
+            .to_http_request();
+        let params = PageParams::<NoSort>::from_query("").unwrap();
+
+        let resp = PagedResponse::from_all(vec![1, 2], &params).respond(&req, &params);
+
+        assert_eq!(resp.headers().get(TOTAL_COUNT_HEADER).unwrap(), "2");
+        assert!(resp.headers().get(LINK).is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_extractor_answers_bad_request() {
+        use actix_web::{http::StatusCode, test, App, HttpResponse};
+
+        let app = test::init_service(App::new().route(
+            "/items",
+            web::get().to(|_page: PageParams| async { HttpResponse::Ok().finish() }),
+        ))
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/items?limit=500")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "INVALID_PAGINATION");
+    }
+}
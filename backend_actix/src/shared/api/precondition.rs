@@ -0,0 +1,70 @@
+// src/shared/api/precondition.rs
+use actix_web::{http::header::IF_UNMODIFIED_SINCE, HttpRequest};
+use chrono::{DateTime, Duration, Utc};
+
+/// The `updated_at` a write expects to overwrite, so an edit made on
+/// another device in between answers 409 `STALE_WRITE` instead of being
+/// silently lost.
+///
+/// Taken from the body's `expected_updated_at` when given, else from the
+/// `If-Unmodified-Since` header; a header that is not an HTTP-date is
+/// ignored. Without either the write is unconditional.
+///
+/// Repositories guard with `updated_at <= expected`. Every write moves
+/// `updated_at` forward, so for the value a client last read this is the
+/// same as equality, and it also accepts the header's whole-second dates.
+pub fn expected_updated_at(
+    req: &HttpRequest,
+    from_body: Option<DateTime<Utc>>,
+) -> Option<DateTime<Utc>> {
+    from_body.or_else(|| {
+        let header = req.headers().get(IF_UNMODIFIED_SINCE)?.to_str().ok()?;
+        let since = DateTime::parse_from_rfc2822(header).ok()?;
+        // HTTP-dates drop the fraction of the second the row was saved in
+        Some(since.with_timezone(&Utc) + Duration::microseconds(999_999))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::TestRequest;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_body_wins_over_header() {
+        let body = Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap();
+        let req = TestRequest::default()
+            .insert_header((IF_UNMODIFIED_SINCE, "Fri, 16 Oct 2026 09:00:00 GMT"))
+            .to_http_request();
+
+        assert_eq!(expected_updated_at(&req, Some(body)), Some(body));
+    }
+
+    #[test]
+    fn test_header_covers_the_whole_second() {
+        let req = TestRequest::default()
+            .insert_header((IF_UNMODIFIED_SINCE, "Fri, 16 Oct 2026 09:00:00 GMT"))
+            .to_http_request();
+
+        let expected = expected_updated_at(&req, None).unwrap();
+
+        let saved =
+            Utc.with_ymd_and_hms(2026, 10, 16, 9, 0, 0).unwrap() + Duration::microseconds(123_456);
+        assert!(saved <= expected);
+        assert!(saved + Duration::seconds(1) > expected);
+    }
+
+    #[test]
+    fn test_missing_or_malformed_header_is_unconditional() {
+        let req = TestRequest::default()
+            .insert_header((IF_UNMODIFIED_SINCE, "yesterday"))
+            .to_http_request();
+
+        assert_eq!(expected_updated_at(&req, None), None);
+        assert_eq!(
+            expected_updated_at(&TestRequest::default().to_http_request(), None),
+            None
+        );
+    }
+}
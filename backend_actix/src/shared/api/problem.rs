@@ -0,0 +1,187 @@
+// src/shared/api/problem.rs
+use actix_web::{http::StatusCode, HttpResponse};
+use serde::Serialize;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use super::{ApiError, ApiResponse};
+use crate::shared::request_id;
+use crate::shared::validation::FieldError;
+
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// Shape of error bodies, chosen once at startup (`ERROR_FORMAT`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorFormat {
+    /// `{ "success": false, "error": { "code", "message", "request_id", "errors" } }`
+    #[default]
+    Envelope,
+    /// RFC 7807 `application/problem+json`
+    Problem,
+}
+
+impl FromStr for ErrorFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "envelope" => Ok(Self::Envelope),
+            "problem" => Ok(Self::Problem),
+            _ => Err("expected 'envelope' or 'problem'".to_string()),
+        }
+    }
+}
+
+static ERROR_FORMAT: OnceLock<ErrorFormat> = OnceLock::new();
+
+/// Called once from `main` before the server starts; later calls are ignored.
+pub fn set_error_format(format: ErrorFormat) {
+    let _ = ERROR_FORMAT.set(format);
+}
+
+pub(super) fn error_format() -> ErrorFormat {
+    ERROR_FORMAT.get().copied().unwrap_or_default()
+}
+
+/// RFC 7807 body. `code`, `request_id` and `errors` are extension members carrying
+/// the same values as the envelope format, so clients can switch without losing detail.
+#[derive(Serialize)]
+struct ProblemDetails<'a> {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    title: &'a str,
+    status: u16,
+    detail: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instance: Option<String>,
+    code: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    errors: Option<&'a [FieldError]>,
+}
+
+/// `errors` lists invalid fields; it is left out of the body when empty
+pub(super) fn render(
+    format: ErrorFormat,
+    status: StatusCode,
+    code: &str,
+    message: &str,
+    errors: &[FieldError],
+) -> HttpResponse {
+    match format {
+        ErrorFormat::Envelope => HttpResponse::build(status).json(ApiResponse::<()> {
+            success: false,
+            data: None,
+            error: Some(ApiError {
+                code: code.to_string(),
+                message: message.to_string(),
+                request_id: request_id::current(),
+                errors: (!errors.is_empty()).then(|| errors.to_vec()),
+            }),
+        }),
+        ErrorFormat::Problem => {
+            HttpResponse::build(status)
+                .content_type(PROBLEM_JSON)
+                .json(ProblemDetails {
+                    // No per-code documentation pages; `code` identifies the problem
+                    kind: "about:blank",
+                    title: status.canonical_reason().unwrap_or("Error"),
+                    status: status.as_u16(),
+                    detail: message,
+                    instance: request_id::current_path(),
+                    code,
+                    request_id: request_id::current(),
+                    errors: (!errors.is_empty()).then_some(errors),
+                })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{body::to_bytes, http::header::CONTENT_TYPE};
+    use serde_json::Value;
+
+    async fn body(resp: HttpResponse) -> (String, Value) {
+        let content_type = resp
+            .headers()
+            .get(CONTENT_TYPE)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+        let bytes = to_bytes(resp.into_body()).await.unwrap();
+        (content_type, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[actix_web::test]
+    async fn test_envelope_is_the_default_shape() {
+        let resp = render(
+            ErrorFormat::default(),
+            StatusCode::NOT_FOUND,
+            "PROJECT_NOT_FOUND",
+            "Project not found",
+            &[],
+        );
+        let (content_type, json) = body(resp).await;
+
+        assert_eq!(content_type, "application/json");
+        assert_eq!(json["success"], false);
+        assert_eq!(json["error"]["code"], "PROJECT_NOT_FOUND");
+        assert!(json["error"].get("errors").is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_problem_details_shape() {
+        let resp = render(
+            ErrorFormat::Problem,
+            StatusCode::NOT_FOUND,
+            "PROJECT_NOT_FOUND",
+            "Project not found",
+            &[],
+        );
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let (content_type, json) = body(resp).await;
+
+        assert_eq!(content_type, PROBLEM_JSON);
+        assert_eq!(json["type"], "about:blank");
+        assert_eq!(json["title"], "Not Found");
+        assert_eq!(json["status"], 404);
+        assert_eq!(json["detail"], "Project not found");
+        assert_eq!(json["code"], "PROJECT_NOT_FOUND");
+        assert!(json.get("success").is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_field_errors_in_both_formats() {
+        let errors = [FieldError {
+            field: "email".to_string(),
+            code: "INVALID_EMAIL".to_string(),
+            message: "Invalid email format".to_string(),
+        }];
+
+        for (format, pointer) in [
+            (ErrorFormat::Envelope, "/error/errors/0/field"),
+            (ErrorFormat::Problem, "/errors/0/field"),
+        ] {
+            let resp = render(
+                format,
+                StatusCode::BAD_REQUEST,
+                "INVALID_EMAIL",
+                "Invalid email format",
+                &errors,
+            );
+            let (_, json) = body(resp).await;
+            assert_eq!(json.pointer(pointer).unwrap(), "email", "{format:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_error_format() {
+        assert_eq!("problem".parse(), Ok(ErrorFormat::Problem));
+        assert_eq!("Envelope".parse(), Ok(ErrorFormat::Envelope));
+        assert!("xml".parse::<ErrorFormat>().is_err());
+    }
+}
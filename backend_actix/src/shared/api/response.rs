@@ -1,4 +1,7 @@
 // src/shared/api/response.rs
+use super::error_codes::{INTERNAL_ERROR, VALIDATION_ERROR};
+use super::problem;
+use crate::shared::validation::{FieldError, ValidationErrors};
 use actix_web::{http::StatusCode, HttpResponse};
 use serde::Serialize;
 
@@ -15,6 +18,12 @@ pub struct ApiResponse<T: Serialize> {
 pub struct ApiError {
     pub code: String,
     pub message: String,
+    /// Id of the failed request (also in the `X-Request-Id` header), to quote when reporting
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// Every invalid field, on validation failures
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<FieldError>>,
 }
 
 impl<T: Serialize> ApiResponse<T> {
@@ -40,15 +49,39 @@ impl ApiResponse<()> {
         HttpResponse::NoContent().finish()
     }
 
+    /// Envelope or `application/problem+json`, depending on `ERROR_FORMAT`.
     pub fn error(status: StatusCode, code: &str, message: &str) -> HttpResponse {
-        HttpResponse::build(status).json(ApiResponse::<()> {
-            success: false,
-            data: None,
-            error: Some(ApiError {
-                code: code.to_string(),
-                message: message.to_string(),
-            }),
-        })
+        problem::render(problem::error_format(), status, code, message, &[])
+    }
+
+    /// 400 listing every invalid field. A single problem keeps its own code and
+    /// message at the top level, so clients reading only `code` still work;
+    /// several are summed up as `VALIDATION_ERROR`.
+    pub fn validation_failed(errors: &ValidationErrors) -> HttpResponse {
+        let fields = errors.fields();
+        let (code, message) = match fields {
+            [only] => (only.code.clone(), only.message.clone()),
+            _ => (
+                VALIDATION_ERROR.code.to_string(),
+                format!("{} fields are invalid", fields.len()),
+            ),
+        };
+        Self::bad_request_with_fields(&code, &message, fields)
+    }
+
+    /// 400 with an explicit top-level code alongside the invalid fields
+    pub fn bad_request_with_fields(
+        code: &str,
+        message: &str,
+        fields: &[FieldError],
+    ) -> HttpResponse {
+        problem::render(
+            problem::error_format(),
+            StatusCode::BAD_REQUEST,
+            code,
+            message,
+            fields,
+        )
     }
 
     pub fn not_found(code: &str, message: &str) -> HttpResponse {
@@ -72,10 +105,6 @@ impl ApiResponse<()> {
     }
 
     pub fn internal_error() -> HttpResponse {
-        Self::error(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "INTERNAL_ERROR",
-            "An unexpected error occurred",
-        )
+        INTERNAL_ERROR.response()
     }
 }
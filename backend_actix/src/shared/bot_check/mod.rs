@@ -0,0 +1,357 @@
+// src/shared/bot_check/mod.rs
+//! Bot checks (CAPTCHA) in front of public write endpoints.
+//!
+//! The client solves a challenge in the browser and sends the provider's token
+//! in the `X-Bot-Check-Token` header; [`BotCheck`] adapters verify it with the
+//! provider. [`BotGate`] decides which endpoints need a token:
+//! - `register`, `contact`: every request
+//! - `login`: once an email has failed `login_after_failures` times in a row
+//!   within [`LOGIN_FAILURE_WINDOW`]. Counts are kept per instance.
+
+mod site_verify;
+
+use actix_web::{HttpRequest, HttpResponse};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::shared::api::error_codes::{
+    BOT_CHECK_FAILED, BOT_CHECK_REQUIRED, BOT_CHECK_UNAVAILABLE,
+};
+use crate::shared::client_ip::client_ip;
+
+pub use site_verify::SiteVerifyBotCheck;
+
+/// Header carrying the provider's response token
+pub const BOT_CHECK_HEADER: &str = "x-bot-check-token";
+
+/// Failed logins older than this no longer count
+pub const LOGIN_FAILURE_WINDOW: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum BotCheckError {
+    #[error("Bot check token missing")]
+    Missing,
+
+    #[error("Bot check rejected: {0}")]
+    Rejected(String),
+
+    #[error("Bot check provider unavailable: {0}")]
+    Unavailable(String),
+}
+
+impl BotCheckError {
+    pub fn to_response(&self) -> HttpResponse {
+        match self {
+            BotCheckError::Missing => BOT_CHECK_REQUIRED.response(),
+            BotCheckError::Rejected(_) => BOT_CHECK_FAILED.response(),
+            BotCheckError::Unavailable(_) => BOT_CHECK_UNAVAILABLE.response(),
+        }
+    }
+}
+
+#[async_trait]
+pub trait BotCheck: Send + Sync {
+    /// Verify a response token; `remote_ip` is passed on to the provider when known.
+    async fn verify(&self, token: &str, remote_ip: Option<&str>) -> Result<(), BotCheckError>;
+}
+
+/// Accepts any token. For development and tests.
+pub struct NoopBotCheck;
+
+#[async_trait]
+impl BotCheck for NoopBotCheck {
+    async fn verify(&self, _token: &str, _remote_ip: Option<&str>) -> Result<(), BotCheckError> {
+        Ok(())
+    }
+}
+
+/// `BOT_CHECK_PROVIDER`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BotCheckProvider {
+    /// No check; gated endpoints stay open
+    #[default]
+    None,
+    /// Accepts any non-empty token, so the client flow can be exercised locally
+    Noop,
+    HCaptcha,
+    Turnstile,
+}
+
+impl FromStr for BotCheckProvider {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "none" => Ok(Self::None),
+            "noop" => Ok(Self::Noop),
+            "hcaptcha" => Ok(Self::HCaptcha),
+            "turnstile" => Ok(Self::Turnstile),
+            _ => Err("expected 'none', 'noop', 'hcaptcha' or 'turnstile'".to_string()),
+        }
+    }
+}
+
+/// Endpoints a bot check can be enabled on (`BOT_CHECK_ENDPOINTS`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BotCheckedEndpoint {
+    Register,
+    Login,
+    Contact,
+}
+
+impl FromStr for BotCheckedEndpoint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "register" => Ok(Self::Register),
+            "login" => Ok(Self::Login),
+            "contact" => Ok(Self::Contact),
+            _ => Err("expected 'register', 'login' or 'contact'".to_string()),
+        }
+    }
+}
+
+/// Bot check settings from the application config
+#[derive(Clone)]
+pub struct BotCheckConfig {
+    pub provider: BotCheckProvider,
+    /// Provider secret key; empty for `none` and `noop`
+    pub secret: String,
+    pub endpoints: Vec<BotCheckedEndpoint>,
+    /// Failed logins per email before login needs a token; 0 means always
+    pub login_after_failures: u32,
+}
+
+/// Which requests need a bot check, and the check itself.
+#[derive(Clone)]
+pub struct BotGate {
+    check: Option<Arc<dyn BotCheck>>,
+    endpoints: Vec<BotCheckedEndpoint>,
+    login_after_failures: u32,
+    login_failures: Arc<Mutex<HashMap<String, (u32, Instant)>>>,
+}
+
+impl BotGate {
+    pub fn new(
+        check: Arc<dyn BotCheck>,
+        endpoints: Vec<BotCheckedEndpoint>,
+        login_after_failures: u32,
+    ) -> Self {
+        Self {
+            check: Some(check),
+            endpoints,
+            login_after_failures,
+            login_failures: Arc::default(),
+        }
+    }
+
+    /// Never asks for a token
+    pub fn disabled() -> Self {
+        Self {
+            check: None,
+            endpoints: Vec::new(),
+            login_after_failures: 0,
+            login_failures: Arc::default(),
+        }
+    }
+
+    pub fn from_config(config: &BotCheckConfig) -> Result<Self, BotCheckError> {
+        let check: Arc<dyn BotCheck> = match config.provider {
+            BotCheckProvider::None => return Ok(Self::disabled()),
+            BotCheckProvider::Noop => Arc::new(NoopBotCheck),
+            BotCheckProvider::HCaptcha => Arc::new(SiteVerifyBotCheck::hcaptcha(&config.secret)?),
+            BotCheckProvider::Turnstile => Arc::new(SiteVerifyBotCheck::turnstile(&config.secret)?),
+        };
+        Ok(Self::new(
+            check,
+            config.endpoints.clone(),
+            config.login_after_failures,
+        ))
+    }
+
+    fn enabled_for(&self, endpoint: BotCheckedEndpoint) -> bool {
+        self.check.is_some() && self.endpoints.contains(&endpoint)
+    }
+
+    /// Require a valid token if `endpoint` is gated
+    pub async fn check(
+        &self,
+        endpoint: BotCheckedEndpoint,
+        req: &HttpRequest,
+    ) -> Result<(), BotCheckError> {
+        if !self.enabled_for(endpoint) {
+            return Ok(());
+        }
+        self.verify(req).await
+    }
+
+    /// Require a valid token if login is gated and `email` has failed too often
+    pub async fn check_login(&self, email: &str, req: &HttpRequest) -> Result<(), BotCheckError> {
+        if !self.enabled_for(BotCheckedEndpoint::Login)
+            || self.recent_login_failures(email) < self.login_after_failures
+        {
+            return Ok(());
+        }
+        self.verify(req).await
+    }
+
+    pub fn record_login_failure(&self, email: &str) {
+        let now = Instant::now();
+        let mut failures = self.login_failures.lock().unwrap();
+        failures.retain(|_, (_, last)| now.duration_since(*last) < LOGIN_FAILURE_WINDOW);
+        let entry = failures.entry(email.to_lowercase()).or_insert((0, now));
+        entry.0 += 1;
+        entry.1 = now;
+    }
+
+    pub fn clear_login_failures(&self, email: &str) {
+        self.login_failures
+            .lock()
+            .unwrap()
+            .remove(&email.to_lowercase());
+    }
+
+    fn recent_login_failures(&self, email: &str) -> u32 {
+        self.login_failures
+            .lock()
+            .unwrap()
+            .get(&email.to_lowercase())
+            .filter(|(_, last)| last.elapsed() < LOGIN_FAILURE_WINDOW)
+            .map_or(0, |(count, _)| *count)
+    }
+
+    async fn verify(&self, req: &HttpRequest) -> Result<(), BotCheckError> {
+        let Some(check) = &self.check else {
+            return Ok(());
+        };
+        let token = req
+            .headers()
+            .get(BOT_CHECK_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+            .ok_or(BotCheckError::Missing)?;
+        let remote_ip = client_ip(req).map(|ip| ip.to_string());
+
+        check.verify(token, remote_ip.as_deref()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::TestRequest;
+
+    struct RejectAll;
+
+    #[async_trait]
+    impl BotCheck for RejectAll {
+        async fn verify(&self, _token: &str, _ip: Option<&str>) -> Result<(), BotCheckError> {
+            Err(BotCheckError::Rejected(
+                "invalid-input-response".to_string(),
+            ))
+        }
+    }
+
+    fn with_token(token: &str) -> HttpRequest {
+        TestRequest::default()
+            .insert_header((BOT_CHECK_HEADER, token))
+            .to_http_request()
+    }
+
+    #[actix_web::test]
+    async fn test_disabled_gate_never_asks_for_a_token() {
+        let gate = BotGate::disabled();
+        let req = TestRequest::default().to_http_request();
+
+        assert!(gate.check(BotCheckedEndpoint::Register, &req).await.is_ok());
+        assert!(gate.check_login("a@b.c", &req).await.is_ok());
+    }
+
+    #[actix_web::test]
+    async fn test_gated_endpoint_requires_a_token() {
+        let gate = BotGate::new(
+            Arc::new(NoopBotCheck),
+            vec![BotCheckedEndpoint::Register],
+            3,
+        );
+        let without = TestRequest::default().to_http_request();
+
+        assert!(matches!(
+            gate.check(BotCheckedEndpoint::Register, &without).await,
+            Err(BotCheckError::Missing)
+        ));
+        assert!(gate
+            .check(BotCheckedEndpoint::Register, &with_token("tok"))
+            .await
+            .is_ok());
+        // Not in the endpoint list
+        assert!(gate.check_login("a@b.c", &without).await.is_ok());
+    }
+
+    #[actix_web::test]
+    async fn test_adapter_rejection_is_returned() {
+        let gate = BotGate::new(Arc::new(RejectAll), vec![BotCheckedEndpoint::Register], 0);
+
+        let result = gate
+            .check(BotCheckedEndpoint::Register, &with_token("tok"))
+            .await;
+
+        assert!(matches!(result, Err(BotCheckError::Rejected(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_login_needs_a_token_after_repeated_failures() {
+        let gate = BotGate::new(Arc::new(RejectAll), vec![BotCheckedEndpoint::Login], 2);
+        let req = TestRequest::default().to_http_request();
+
+        gate.record_login_failure("a@b.c");
+        assert!(gate.check_login("a@b.c", &req).await.is_ok());
+
+        gate.record_login_failure("A@B.C");
+        assert!(matches!(
+            gate.check_login("a@b.c", &req).await,
+            Err(BotCheckError::Missing)
+        ));
+        // Other emails are unaffected
+        assert!(gate.check_login("x@y.z", &req).await.is_ok());
+
+        gate.clear_login_failures("a@b.c");
+        assert!(gate.check_login("a@b.c", &req).await.is_ok());
+    }
+
+    #[test]
+    fn test_error_responses() {
+        assert_eq!(BotCheckError::Missing.to_response().status(), 400);
+        assert_eq!(
+            BotCheckError::Rejected(String::new())
+                .to_response()
+                .status(),
+            403
+        );
+        assert_eq!(
+            BotCheckError::Unavailable(String::new())
+                .to_response()
+                .status(),
+            503
+        );
+    }
+
+    #[test]
+    fn test_parse_provider_and_endpoints() {
+        assert_eq!(
+            "Turnstile".parse::<BotCheckProvider>(),
+            Ok(BotCheckProvider::Turnstile)
+        );
+        assert!("recaptcha".parse::<BotCheckProvider>().is_err());
+        assert_eq!(
+            "login".parse::<BotCheckedEndpoint>(),
+            Ok(BotCheckedEndpoint::Login)
+        );
+        assert!("comments".parse::<BotCheckedEndpoint>().is_err());
+    }
+}
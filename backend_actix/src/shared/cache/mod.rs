@@ -0,0 +1,159 @@
+// src/shared/cache/mod.rs
+//! Read-through cache for hot public reads.
+//!
+//! Entries are JSON strings with a TTL set by the adapter. Mutation use cases
+//! invalidate the keys they affect; the TTL only bounds staleness when an
+//! invalidation is lost. The cache is never the source of truth: every failure
+//! is logged and the caller falls back to the database.
+
+mod redis_cache;
+
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+use std::future::Future;
+use tracing::warn;
+
+pub use redis_cache::RedisCache;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum CacheError {
+    #[error("Cache backend error: {0}")]
+    Backend(String),
+}
+
+#[async_trait]
+pub trait CachePort: Send + Sync {
+    async fn get(&self, key: &str) -> Result<Option<String>, CacheError>;
+
+    async fn set(&self, key: &str, value: String) -> Result<(), CacheError>;
+
+    async fn delete(&self, key: &str) -> Result<(), CacheError>;
+
+    /// Drop every key starting with `prefix`
+    async fn delete_prefix(&self, prefix: &str) -> Result<(), CacheError>;
+}
+
+/// Used when caching is turned off (`CACHE_TTL_SECS=0`): every read misses.
+pub struct NoopCache;
+
+#[async_trait]
+impl CachePort for NoopCache {
+    async fn get(&self, _key: &str) -> Result<Option<String>, CacheError> {
+        Ok(None)
+    }
+
+    async fn set(&self, _key: &str, _value: String) -> Result<(), CacheError> {
+        Ok(())
+    }
+
+    async fn delete(&self, _key: &str) -> Result<(), CacheError> {
+        Ok(())
+    }
+
+    async fn delete_prefix(&self, _prefix: &str) -> Result<(), CacheError> {
+        Ok(())
+    }
+}
+
+/// Return the cached value for `key`, or run `load` and cache its `Ok` result.
+pub async fn read_through<T, E, F, Fut>(cache: &dyn CachePort, key: &str, load: F) -> Result<T, E>
+where
+    T: Serialize + DeserializeOwned,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    match cache.get(key).await {
+        Ok(Some(raw)) => match serde_json::from_str(&raw) {
+            Ok(value) => return Ok(value),
+            // Shape changed since the entry was written; reload and overwrite
+            Err(e) => warn!(key, error = %e, "Discarding unreadable cache entry"),
+        },
+        Ok(None) => {}
+        Err(e) => warn!(key, error = %e, "Cache read failed"),
+    }
+
+    let value = load().await?;
+
+    match serde_json::to_string(&value) {
+        Ok(raw) => {
+            if let Err(e) = cache.set(key, raw).await {
+                warn!(key, error = %e, "Cache write failed");
+            }
+        }
+        Err(e) => warn!(key, error = %e, "Value not cacheable"),
+    }
+
+    Ok(value)
+}
+
+/// Drop `key`. Failures are logged; the entry then lives until its TTL.
+pub async fn invalidate(cache: &dyn CachePort, key: &str) {
+    if let Err(e) = cache.delete(key).await {
+        warn!(key, error = %e, "Cache invalidation failed");
+    }
+}
+
+/// Drop every key under `prefix`. Failures are logged; entries then live until their TTL.
+pub async fn invalidate_prefix(cache: &dyn CachePort, prefix: &str) {
+    if let Err(e) = cache.delete_prefix(prefix).await {
+        warn!(prefix, error = %e, "Cache invalidation failed");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::tests::support::stubs::InMemoryCache;
+
+    #[tokio::test]
+    async fn test_read_through_loads_once() {
+        let cache = InMemoryCache::default();
+        let loads = AtomicUsize::new(0);
+
+        for _ in 0..2 {
+            let value: Result<Vec<u32>, ()> = read_through(&cache, "k", || async {
+                loads.fetch_add(1, Ordering::SeqCst);
+                Ok(vec![1, 2, 3])
+            })
+            .await;
+            assert_eq!(value, Ok(vec![1, 2, 3]));
+        }
+
+        assert_eq!(loads.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_errors_are_not_cached() {
+        let cache = InMemoryCache::default();
+
+        let value: Result<u32, &str> = read_through(&cache, "k", || async { Err("db down") }).await;
+
+        assert_eq!(value, Err("db down"));
+        assert!(cache.keys().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unreadable_entries_are_reloaded() {
+        let cache = InMemoryCache::default();
+        cache.set("k", "not json".to_string()).await.unwrap();
+
+        let value: Result<u32, ()> = read_through(&cache, "k", || async { Ok(7) }).await;
+
+        assert_eq!(value, Ok(7));
+        assert_eq!(cache.get("k").await.unwrap().as_deref(), Some("7"));
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_prefix_only_drops_matching_keys() {
+        let cache = InMemoryCache::default();
+        cache.set("a:1", "1".to_string()).await.unwrap();
+        cache.set("a:2", "2".to_string()).await.unwrap();
+        cache.set("b:1", "3".to_string()).await.unwrap();
+
+        invalidate_prefix(&cache, "a:").await;
+
+        assert_eq!(cache.keys(), vec!["b:1".to_string()]);
+    }
+}
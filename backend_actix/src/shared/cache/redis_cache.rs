@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use deadpool_redis::{redis::AsyncCommands, Pool};
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::{CacheError, CachePort};
+
+/// Redis-backed `CachePort`.
+///
+/// Every entry is written with `SET key value EX ttl`, so Redis cleans up on
+/// its own. Keys are namespaced by the callers (`cache:...`).
+#[derive(Clone)]
+pub struct RedisCache {
+    pool: Arc<Pool>,
+    ttl: Duration,
+}
+
+impl RedisCache {
+    pub fn new(pool: Arc<Pool>, ttl: Duration) -> Self {
+        Self { pool, ttl }
+    }
+
+    async fn get_conn(&self) -> Result<deadpool_redis::Connection, CacheError> {
+        self.pool
+            .get()
+            .await
+            .map_err(|e| CacheError::Backend(format!("Pool error: {}", e)))
+    }
+}
+
+#[async_trait]
+impl CachePort for RedisCache {
+    async fn get(&self, key: &str) -> Result<Option<String>, CacheError> {
+        let mut conn = self.get_conn().await?;
+        conn.get(key)
+            .await
+            .map_err(|e| CacheError::Backend(e.to_string()))
+    }
+
+    async fn set(&self, key: &str, value: String) -> Result<(), CacheError> {
+        let mut conn = self.get_conn().await?;
+        conn.set_ex(key, value, self.ttl.as_secs().max(1))
+            .await
+            .map_err(|e| CacheError::Backend(e.to_string()))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), CacheError> {
+        let mut conn = self.get_conn().await?;
+        conn.del(key)
+            .await
+            .map_err(|e| CacheError::Backend(e.to_string()))
+    }
+
+    /// `SCAN MATCH prefix*` + `UNLINK`, batch by batch. Never `KEYS`: it blocks Redis.
+    async fn delete_prefix(&self, prefix: &str) -> Result<(), CacheError> {
+        let mut conn = self.get_conn().await?;
+        let pattern = format!("{prefix}*");
+        let mut cursor: u64 = 0;
+
+        loop {
+            let (next, keys): (u64, Vec<String>) = deadpool_redis::redis::cmd("SCAN")
+                .arg(cursor)
+                .arg("MATCH")
+                .arg(&pattern)
+                .arg("COUNT")
+                .arg(100)
+                .query_async(&mut *conn)
+                .await
+                .map_err(|e| CacheError::Backend(e.to_string()))?;
+
+            if !keys.is_empty() {
+                conn.unlink::<_, ()>(keys)
+                    .await
+                    .map_err(|e| CacheError::Backend(e.to_string()))?;
+            }
+
+            if next == 0 {
+                return Ok(());
+            }
+            cursor = next;
+        }
+    }
+}
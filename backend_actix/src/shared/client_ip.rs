@@ -0,0 +1,161 @@
+// src/shared/client_ip.rs
+//! The address of the client behind a request, for rate limits, bot checks,
+//! the blocklist and the addresses stored with contact messages and page
+//! views. `Forwarded` and `X-Forwarded-For` are written by whoever sends the
+//! request, so they are only believed when the connection comes from a proxy
+//! in `TRUSTED_PROXIES`; otherwise the connection's own address is the client.
+
+use actix_web::{http::header, HttpRequest};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::OnceLock;
+
+use crate::shared::ip_range::IpRange;
+
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+
+/// The load balancers and reverse proxies in front of the server
+#[derive(Debug, Clone, Default)]
+pub struct TrustedProxies(Vec<IpRange>);
+
+impl TrustedProxies {
+    pub fn new(ranges: Vec<IpRange>) -> Self {
+        Self(ranges)
+    }
+
+    fn contains(&self, ip: IpAddr) -> bool {
+        self.0.iter().any(|range| range.contains(ip))
+    }
+
+    /// The peer address, or, when the peer is a trusted proxy, the nearest
+    /// forwarded address that isn't one. Proxies append to the chain, so
+    /// everything left of the first untrusted hop came from the client.
+    pub fn client_of(&self, req: &HttpRequest) -> Option<IpAddr> {
+        let mut client = req.peer_addr()?.ip().to_canonical();
+        if !self.contains(client) {
+            return Some(client);
+        }
+
+        for hop in forwarded_chain(req).into_iter().rev() {
+            // A hop a trusted proxy couldn't name ends the chain at that proxy
+            let Some(ip) = hop else { break };
+            client = ip;
+            if !self.contains(ip) {
+                break;
+            }
+        }
+        Some(client)
+    }
+}
+
+/// The forwarded addresses, client first: `Forwarded` `for=` parameters, or
+/// `X-Forwarded-For` when there is no `Forwarded` header. `None` for a hop
+/// that isn't an address (`unknown`, obfuscated identifiers).
+fn forwarded_chain(req: &HttpRequest) -> Vec<Option<IpAddr>> {
+    let values = |name: &str| {
+        req.headers()
+            .get_all(name)
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .collect::<Vec<_>>()
+    };
+
+    let forwarded = values(header::FORWARDED.as_str());
+    if !forwarded.is_empty() {
+        return forwarded
+            .into_iter()
+            .map(|element| {
+                element
+                    .split(';')
+                    .filter_map(|pair| pair.trim().split_once('='))
+                    .find(|(name, _)| name.eq_ignore_ascii_case("for"))
+                    .and_then(|(_, node)| parse_node(node))
+            })
+            .collect();
+    }
+    values(X_FORWARDED_FOR)
+        .into_iter()
+        .map(parse_node)
+        .collect()
+}
+
+/// `203.0.113.7`, `203.0.113.7:4711`, `"[2001:db8::1]:4711"` or `2001:db8::1`
+fn parse_node(node: &str) -> Option<IpAddr> {
+    let node = node.trim().trim_matches('"');
+    node.parse::<IpAddr>()
+        .or_else(|_| node.parse::<SocketAddr>().map(|addr| addr.ip()))
+        .or_else(|_| {
+            node.trim_start_matches('[')
+                .trim_end_matches(']')
+                .parse::<IpAddr>()
+        })
+        .ok()
+        .map(|ip| ip.to_canonical())
+}
+
+static TRUSTED_PROXIES: OnceLock<TrustedProxies> = OnceLock::new();
+
+/// Called once from `main` before the server starts; later calls are ignored.
+pub fn set_trusted_proxies(proxies: TrustedProxies) {
+    let _ = TRUSTED_PROXIES.set(proxies);
+}
+
+/// The client of `req` under the process-wide `TRUSTED_PROXIES`; with none
+/// set, the peer address
+pub fn client_ip(req: &HttpRequest) -> Option<IpAddr> {
+    static NONE: TrustedProxies = TrustedProxies(Vec::new());
+    TRUSTED_PROXIES.get().unwrap_or(&NONE).client_of(req)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::TestRequest;
+
+    fn proxies(ranges: &[&str]) -> TrustedProxies {
+        TrustedProxies::new(ranges.iter().map(|r| r.parse().unwrap()).collect())
+    }
+
+    fn ip(s: &str) -> Option<IpAddr> {
+        Some(s.parse().unwrap())
+    }
+
+    #[test]
+    fn test_forwarded_headers_count_only_from_trusted_proxies() {
+        let proxies = proxies(&["10.0.0.0/8"]);
+
+        let direct = TestRequest::default()
+            .peer_addr("203.0.113.7:4000".parse().unwrap())
+            .insert_header((X_FORWARDED_FOR, "198.51.100.1"))
+            .insert_header((header::FORWARDED, "for=198.51.100.2"))
+            .to_http_request();
+        assert_eq!(proxies.client_of(&direct), ip("203.0.113.7"));
+
+        // The client prepended a made-up hop; the proxies appended the rest
+        let proxied = TestRequest::default()
+            .peer_addr("10.0.0.2:4000".parse().unwrap())
+            .insert_header((X_FORWARDED_FOR, "198.51.100.1, 203.0.113.7, 10.0.0.9"))
+            .to_http_request();
+        assert_eq!(proxies.client_of(&proxied), ip("203.0.113.7"));
+
+        let forwarded = TestRequest::default()
+            .peer_addr("10.0.0.2:4000".parse().unwrap())
+            .insert_header((
+                header::FORWARDED,
+                r#"for="[2001:db8::7]:4711";proto=https, for=10.0.0.9"#,
+            ))
+            .to_http_request();
+        assert_eq!(proxies.client_of(&forwarded), ip("2001:db8::7"));
+
+        let unnamed = TestRequest::default()
+            .peer_addr("10.0.0.2:4000".parse().unwrap())
+            .insert_header((X_FORWARDED_FOR, "203.0.113.7, unknown"))
+            .to_http_request();
+        assert_eq!(proxies.client_of(&unnamed), ip("10.0.0.2"));
+
+        assert_eq!(
+            proxies.client_of(&TestRequest::default().to_http_request()),
+            None
+        );
+    }
+}
@@ -0,0 +1,25 @@
+// src/shared/constant_time.rs
+//! Comparison of shared secrets (endpoint tokens, CSRF tokens) that takes
+//! the same time wherever the first difference is, so response timing
+//! doesn't leak how much of a guess was right.
+
+/// Whether `a` and `b` are equal. Only the length can leak, which for
+/// secrets of a fixed format tells an attacker nothing new.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constant_time_eq() {
+        let token = b"0123456789abcdef";
+
+        assert!(constant_time_eq(token, b"0123456789abcdef"));
+        assert!(!constant_time_eq(token, b"0123456789abcdeF"));
+        assert!(!constant_time_eq(token, b"0123"));
+        assert!(constant_time_eq(b"", b""));
+    }
+}
@@ -0,0 +1,195 @@
+// src/shared/faults.rs
+//! Failures injected into outgoing ports on demand, so end-to-end suites can
+//! assert error paths deterministically: the next N repository calls fail,
+//! emails are sent late, storage refuses to sign URLs.
+//!
+//! Only builds with the `test-helpers` feature, which refuse to start in
+//! production, wrap adapters in `Faulty` and serve the `/test/faults` routes
+//! arming them. Each port trait gets its impl for `Faulty` next to the trait.
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::LazyLock;
+use std::time::Duration;
+
+/// Message of the errors injected repository calls return
+pub const INJECTED_FAILURE: &str = "injected fault";
+
+static FAULTS: LazyLock<FaultInjector> = LazyLock::new(FaultInjector::default);
+
+/// The process-wide faults `Faulty` adapters consult
+pub fn faults() -> &'static FaultInjector {
+    &FAULTS
+}
+
+/// Wraps an outgoing adapter so it fails or stalls as `faults()` says
+#[derive(Clone)]
+pub struct Faulty<P>(pub P);
+
+/// What is armed. In a change, `None` keeps the current value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FaultSettings {
+    /// Repository calls left to fail
+    pub repository_failures: Option<u32>,
+    /// Wait before each email is handed to the provider
+    pub smtp_delay_ms: Option<u64>,
+    /// Signing upload and read URLs fails
+    pub storage_signing_fails: Option<bool>,
+}
+
+#[derive(Default)]
+pub struct FaultInjector {
+    repository_failures: AtomicU32,
+    smtp_delay_ms: AtomicU64,
+    storage_signing_fails: AtomicBool,
+}
+
+impl FaultInjector {
+    /// Applies the settings given, keeping the others
+    pub fn apply(&self, change: &FaultSettings) {
+        if let Some(n) = change.repository_failures {
+            self.repository_failures.store(n, Ordering::SeqCst);
+        }
+        if let Some(ms) = change.smtp_delay_ms {
+            self.smtp_delay_ms.store(ms, Ordering::SeqCst);
+        }
+        if let Some(fails) = change.storage_signing_fails {
+            self.storage_signing_fails.store(fails, Ordering::SeqCst);
+        }
+    }
+
+    /// Disarms everything
+    pub fn reset(&self) {
+        self.apply(&FaultSettings {
+            repository_failures: Some(0),
+            smtp_delay_ms: Some(0),
+            storage_signing_fails: Some(false),
+        });
+    }
+
+    /// Everything, as currently armed
+    pub fn current(&self) -> FaultSettings {
+        FaultSettings {
+            repository_failures: Some(self.repository_failures.load(Ordering::SeqCst)),
+            smtp_delay_ms: Some(self.smtp_delay_ms.load(Ordering::SeqCst)),
+            storage_signing_fails: Some(self.storage_signing_fails.load(Ordering::SeqCst)),
+        }
+    }
+
+    /// `true` when this repository call must fail, counting it
+    pub fn take_repository_failure(&self) -> bool {
+        self.repository_failures
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok()
+    }
+
+    pub fn smtp_delay(&self) -> Duration {
+        Duration::from_millis(self.smtp_delay_ms.load(Ordering::SeqCst))
+    }
+
+    pub fn storage_signing_fails(&self) -> bool {
+        self.storage_signing_fails.load(Ordering::SeqCst)
+    }
+}
+
+/// Implements a repository port for `Faulty`: while repository failures are
+/// armed, each call fails with `$error(INJECTED_FAILURE)` instead of reaching
+/// the adapter. The signatures repeat the trait's.
+macro_rules! faulty_repository {
+    ($trait:ident, $error:path,
+        $(fn $method:ident(&self $(, $arg:ident: $ty:ty)* $(,)?) -> $ret:ty;)+) => {
+        #[async_trait::async_trait]
+        impl<R: $trait + Send + Sync> $trait for $crate::shared::faults::Faulty<R> {
+            $(
+                async fn $method(&self $(, $arg: $ty)*) -> $ret {
+                    if $crate::shared::faults::faults().take_repository_failure() {
+                        return Err($error(
+                            $crate::shared::faults::INJECTED_FAILURE.to_string(),
+                        ));
+                    }
+                    self.0.$method($($arg),*).await
+                }
+            )+
+        }
+    };
+}
+pub(crate) use faulty_repository;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::application::domain::entities::UserId;
+    use crate::topic::application::ports::outgoing::{
+        TopicQuery, TopicQueryError, TopicQueryResult,
+    };
+    use uuid::Uuid;
+
+    struct NoTopics;
+
+    #[async_trait::async_trait]
+    impl TopicQuery for NoTopics {
+        async fn get_topics(
+            &self,
+            _owner: UserId,
+        ) -> Result<Vec<TopicQueryResult>, TopicQueryError> {
+            Ok(vec![])
+        }
+    }
+
+    #[test]
+    fn test_repository_failures_count_down() {
+        let faults = FaultInjector::default();
+        faults.apply(&FaultSettings {
+            repository_failures: Some(2),
+            ..Default::default()
+        });
+
+        assert!(faults.take_repository_failure());
+        assert!(faults.take_repository_failure());
+        assert!(!faults.take_repository_failure());
+        assert_eq!(faults.current().repository_failures, Some(0));
+    }
+
+    #[test]
+    fn test_apply_keeps_what_is_not_given() {
+        let faults = FaultInjector::default();
+        faults.apply(&FaultSettings {
+            smtp_delay_ms: Some(1500),
+            storage_signing_fails: Some(true),
+            ..Default::default()
+        });
+        faults.apply(&FaultSettings {
+            repository_failures: Some(3),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            faults.current(),
+            FaultSettings {
+                repository_failures: Some(3),
+                smtp_delay_ms: Some(1500),
+                storage_signing_fails: Some(true),
+            }
+        );
+        assert_eq!(faults.smtp_delay(), Duration::from_millis(1500));
+
+        faults.reset();
+        assert!(!faults.storage_signing_fails());
+        assert_eq!(faults.smtp_delay(), Duration::ZERO);
+    }
+
+    // The only test arming the process-wide faults
+    #[tokio::test]
+    async fn test_faulty_repository_fails_while_armed() {
+        let topics = Faulty(NoTopics);
+        let owner = UserId::from(Uuid::new_v4());
+        faults().apply(&FaultSettings {
+            repository_failures: Some(1),
+            ..Default::default()
+        });
+
+        let err = topics.get_topics(owner).await.unwrap_err();
+        assert!(matches!(err, TopicQueryError::DatabaseError(m) if m == INJECTED_FAILURE));
+        assert!(topics.get_topics(owner).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,301 @@
+// src/shared/field_cipher.rs
+//! Encryption at rest of sensitive fields: CV contact details and contact
+//! form messages.
+//!
+//! Keys come from `FIELD_ENCRYPTION_KEYS`, comma-separated `id:base64` entries
+//! of 32 random bytes each; the first one encrypts, all of them decrypt, so a
+//! new key goes first and the old ones stay until `cli keys re-encrypt` has
+//! moved every row over. Each account encrypts under its own subkey, the
+//! HMAC-SHA256 of the account id keyed with the master key.
+//!
+//! A stored value is `enc:v1:{key id}:{base64(nonce || ciphertext)}`
+//! (AES-256-GCM). Values without the prefix were written before encryption
+//! was turned on and read back as they are.
+
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const PREFIX: &str = "enc:v1:";
+const KEY_BYTES: usize = 32;
+const NONCE_BYTES: usize = 12;
+
+#[derive(Debug, thiserror::Error)]
+pub enum FieldCipherError {
+    #[error("encrypted with unknown key '{0}'")]
+    UnknownKey(String),
+
+    #[error("malformed encrypted value")]
+    Malformed,
+
+    #[error("encrypted value does not decrypt")]
+    Decrypt,
+}
+
+/// One entry of `FIELD_ENCRYPTION_KEYS`
+#[derive(Clone)]
+pub struct FieldKey {
+    id: String,
+    secret: [u8; KEY_BYTES],
+}
+
+impl FieldKey {
+    pub fn new(id: &str, secret: [u8; KEY_BYTES]) -> Result<Self, String> {
+        if id.is_empty()
+            || !id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err("key ids are letters, digits, '-' and '_'".to_string());
+        }
+        Ok(Self {
+            id: id.to_string(),
+            secret,
+        })
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// Shows the id only
+impl fmt::Debug for FieldKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldKey")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Parses `id:base64`. Errors never repeat the key material.
+impl FromStr for FieldKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (id, secret) = s
+            .split_once(':')
+            .ok_or_else(|| "expected 'id:base64'".to_string())?;
+        let secret: [u8; KEY_BYTES] = STANDARD
+            .decode(secret.trim())
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| format!("key '{}' must be {KEY_BYTES} base64 bytes", id.trim()))?;
+
+        Self::new(id.trim(), secret)
+    }
+}
+
+/// Keys of a comma-separated `FIELD_ENCRYPTION_KEYS`, current first
+pub fn parse_keys(raw: &str) -> Result<Vec<FieldKey>, String> {
+    let keys = raw
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(FieldKey::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (i, key) in keys.iter().enumerate() {
+        if keys[..i].iter().any(|k| k.id == key.id) {
+            return Err(format!("key '{}' is listed twice", key.id));
+        }
+    }
+    Ok(keys)
+}
+
+/// Encrypts and decrypts fields for one account at a time. Without keys it
+/// is off: values are stored and read as they are.
+#[derive(Clone, Default)]
+pub struct FieldCipher {
+    keys: Arc<Vec<FieldKey>>,
+}
+
+impl FieldCipher {
+    /// Scope of what belongs to the site rather than to an account, like
+    /// contact form messages
+    pub const SITE: Uuid = Uuid::nil();
+
+    pub fn new(keys: Vec<FieldKey>) -> Self {
+        Self {
+            keys: Arc::new(keys),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.keys.is_empty()
+    }
+
+    /// Id of the key new values are encrypted with
+    pub fn current_key_id(&self) -> Option<&str> {
+        self.keys.first().map(FieldKey::id)
+    }
+
+    fn cipher(key: &FieldKey, account: Uuid) -> Aes256Gcm {
+        let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(&key.secret)
+            .expect("HMAC accepts any key length");
+        mac.update(b"field:");
+        mac.update(account.as_bytes());
+        Aes256Gcm::new(&mac.finalize().into_bytes())
+    }
+
+    /// `plaintext` as stored for `account`
+    pub fn encrypt(&self, account: Uuid, plaintext: &str) -> String {
+        let Some(key) = self.keys.first() else {
+            return plaintext.to_string();
+        };
+
+        let nonce: [u8; NONCE_BYTES] = rand::random();
+        let ciphertext = Self::cipher(key, account)
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_bytes())
+            .expect("AES-GCM encrypts any plaintext that fits in memory");
+
+        let mut sealed = nonce.to_vec();
+        sealed.extend_from_slice(&ciphertext);
+        format!("{PREFIX}{}:{}", key.id, STANDARD.encode(sealed))
+    }
+
+    /// A stored value of `account` as it was written
+    pub fn decrypt(&self, account: Uuid, stored: &str) -> Result<String, FieldCipherError> {
+        let Some(sealed) = stored.strip_prefix(PREFIX) else {
+            return Ok(stored.to_string());
+        };
+        let (key_id, sealed) = sealed.split_once(':').ok_or(FieldCipherError::Malformed)?;
+        let key = self
+            .keys
+            .iter()
+            .find(|k| k.id == key_id)
+            .ok_or_else(|| FieldCipherError::UnknownKey(key_id.to_string()))?;
+
+        let sealed = STANDARD
+            .decode(sealed)
+            .map_err(|_| FieldCipherError::Malformed)?;
+        if sealed.len() < NONCE_BYTES {
+            return Err(FieldCipherError::Malformed);
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_BYTES);
+
+        let plaintext = Self::cipher(key, account)
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| FieldCipherError::Decrypt)?;
+        String::from_utf8(plaintext).map_err(|_| FieldCipherError::Decrypt)
+    }
+
+    /// `true` when `stored` is already as the current key would write it:
+    /// encrypted with it, or plaintext while encryption is off
+    pub fn is_current(&self, stored: &str) -> bool {
+        match self.current_key_id() {
+            Some(id) => stored
+                .strip_prefix(PREFIX)
+                .and_then(|rest| rest.split_once(':'))
+                .is_some_and(|(key_id, _)| key_id == id),
+            None => !stored.starts_with(PREFIX),
+        }
+    }
+
+    /// `stored` rewritten under the current key, or `None` when it already is
+    pub fn reencrypt(
+        &self,
+        account: Uuid,
+        stored: &str,
+    ) -> Result<Option<String>, FieldCipherError> {
+        if self.is_current(stored) {
+            return Ok(None);
+        }
+        let plaintext = self.decrypt(account, stored)?;
+        Ok(Some(self.encrypt(account, &plaintext)))
+    }
+}
+
+/// Keeps the keys out of logs
+impl fmt::Debug for FieldCipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldCipher")
+            .field("current_key", &self.current_key_id())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(id: &str, byte: u8) -> FieldKey {
+        FieldKey::new(id, [byte; KEY_BYTES]).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip_is_scoped_to_the_account() {
+        let cipher = FieldCipher::new(vec![key("k1", 7)]);
+        let alice = Uuid::new_v4();
+
+        let stored = cipher.encrypt(alice, "+62 812 0000 0000");
+        assert!(stored.starts_with("enc:v1:k1:"));
+        assert_ne!(stored, cipher.encrypt(alice, "+62 812 0000 0000"));
+        assert_eq!(cipher.decrypt(alice, &stored).unwrap(), "+62 812 0000 0000");
+
+        assert!(matches!(
+            cipher.decrypt(Uuid::new_v4(), &stored),
+            Err(FieldCipherError::Decrypt)
+        ));
+    }
+
+    #[test]
+    fn test_plaintext_passes_through() {
+        let account = Uuid::new_v4();
+        let off = FieldCipher::default();
+        assert!(!off.is_enabled());
+        assert_eq!(off.encrypt(account, "hello"), "hello");
+
+        let on = FieldCipher::new(vec![key("k1", 7)]);
+        assert_eq!(on.decrypt(account, "hello").unwrap(), "hello");
+        assert!(matches!(
+            on.decrypt(account, "enc:v1:k9:AAAA"),
+            Err(FieldCipherError::UnknownKey(id)) if id == "k9"
+        ));
+        assert!(matches!(
+            on.decrypt(account, "enc:v1:k1:AAAA"),
+            Err(FieldCipherError::Malformed)
+        ));
+    }
+
+    #[test]
+    fn test_reencrypt_moves_values_to_the_current_key() {
+        let account = Uuid::new_v4();
+        let old = FieldCipher::new(vec![key("k1", 7)]);
+        let rotated = FieldCipher::new(vec![key("k2", 9), key("k1", 7)]);
+
+        let stored = old.encrypt(account, "jane@example.com");
+        let moved = rotated.reencrypt(account, &stored).unwrap().unwrap();
+        assert!(moved.starts_with("enc:v1:k2:"));
+        assert_eq!(rotated.reencrypt(account, &moved).unwrap(), None);
+        assert_eq!(
+            rotated.decrypt(account, &moved).unwrap(),
+            "jane@example.com"
+        );
+
+        // Plaintext written before encryption was on gets encrypted too
+        assert!(rotated.reencrypt(account, "legacy").unwrap().is_some());
+    }
+
+    #[test]
+    fn test_parse_keys() {
+        let secret = STANDARD.encode([1u8; KEY_BYTES]);
+        let keys = parse_keys(&format!("2026-10:{secret}, 2026-01:{secret}")).unwrap();
+        assert_eq!(
+            keys.iter().map(FieldKey::id).collect::<Vec<_>>(),
+            vec!["2026-10", "2026-01"]
+        );
+
+        let err = parse_keys("k1:c2hvcnQ=").unwrap_err();
+        assert_eq!(err, "key 'k1' must be 32 base64 bytes");
+        assert!(!err.contains("c2hvcnQ"));
+        assert!(parse_keys(&format!("k1:{secret},k1:{secret}")).is_err());
+        assert!(parse_keys(&format!("k:1:{secret}")).is_err());
+    }
+}
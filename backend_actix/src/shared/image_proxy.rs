@@ -0,0 +1,177 @@
+// src/shared/image_proxy.rs
+//! Origins whose images `GET /api/proxy/image` may fetch
+//! (`IMAGE_PROXY_ORIGINS`), and the rewrite that points Markdown image links
+//! on those origins at the proxy when a body is saved, so published pages
+//! only load images from this API.
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{LazyLock, OnceLock};
+
+use regex::{Captures, Regex};
+
+pub const PROXY_PATH: &str = "/api/proxy/image";
+
+/// `scheme://host[:port]` of an http(s) URL, lowercased, with the default
+/// port left out; `None` for anything else
+pub fn origin_of(url: &str) -> Option<String> {
+    let url = reqwest::Url::parse(url.trim()).ok()?;
+    let allowed = matches!(url.scheme(), "http" | "https")
+        && url.host_str().is_some()
+        && url.username().is_empty()
+        && url.password().is_none();
+
+    allowed.then(|| url.origin().ascii_serialization())
+}
+
+/// One entry of `IMAGE_PROXY_ORIGINS`, e.g. `https://i.imgur.com`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageOrigin(String);
+
+impl FromStr for ImageOrigin {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bare = s.trim().trim_end_matches('/');
+        match origin_of(bare) {
+            Some(origin) if origin.eq_ignore_ascii_case(bare) => Ok(Self(origin)),
+            _ => {
+                Err("expected an http(s) origin without a path, e.g. 'https://i.imgur.com'".into())
+            }
+        }
+    }
+}
+
+impl fmt::Display for ImageOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AllowedOrigins(Vec<ImageOrigin>);
+
+impl AllowedOrigins {
+    pub fn new(origins: Vec<ImageOrigin>) -> Self {
+        Self(origins)
+    }
+
+    /// Whether `url` is an http(s) URL on one of the origins
+    pub fn allows(&self, url: &str) -> bool {
+        origin_of(url).is_some_and(|origin| self.0.iter().any(|allowed| allowed.0 == origin))
+    }
+
+    /// `body` with every Markdown image (`![alt](url)`) on an allowed origin
+    /// loaded through the proxy instead. Other links are left alone.
+    pub fn rewrite_markdown(&self, body: String) -> String {
+        static IMAGE: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(r"(?P<head>!\[[^\]]*\]\(\s*<?)(?P<url>https?://[^\s)>]+)")
+                .expect("valid image pattern")
+        });
+
+        if self.0.is_empty() {
+            return body;
+        }
+        IMAGE
+            .replace_all(&body, |caps: &Captures| {
+                let url = &caps["url"];
+                if self.allows(url) {
+                    format!("{}{}", &caps["head"], proxy_url(url))
+                } else {
+                    caps[0].to_string()
+                }
+            })
+            .into_owned()
+    }
+}
+
+/// The proxy path that serves `url`
+pub fn proxy_url(url: &str) -> String {
+    let mut encoded = String::with_capacity(url.len() * 3);
+    for byte in url.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    format!("{PROXY_PATH}?url={encoded}")
+}
+
+static ALLOWED_ORIGINS: OnceLock<AllowedOrigins> = OnceLock::new();
+
+/// Called once from `main` before the server starts; later calls are ignored.
+pub fn set_allowed_origins(origins: AllowedOrigins) {
+    let _ = ALLOWED_ORIGINS.set(origins);
+}
+
+/// The process-wide allowlist; empty until set, which turns the rewrite off
+pub fn allowed_origins() -> &'static AllowedOrigins {
+    static NONE: AllowedOrigins = AllowedOrigins(Vec::new());
+    ALLOWED_ORIGINS.get().unwrap_or(&NONE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn origins(list: &[&str]) -> AllowedOrigins {
+        AllowedOrigins::new(list.iter().map(|o| o.parse().unwrap()).collect())
+    }
+
+    #[test]
+    fn test_origin_entries_must_be_bare_http_origins() {
+        assert_eq!(
+            "HTTPS://i.imgur.com/"
+                .parse::<ImageOrigin>()
+                .unwrap()
+                .to_string(),
+            "https://i.imgur.com"
+        );
+        assert_eq!(
+            "http://cdn.example.com:8080"
+                .parse::<ImageOrigin>()
+                .unwrap()
+                .to_string(),
+            "http://cdn.example.com:8080"
+        );
+        for bad in ["i.imgur.com", "ftp://i.imgur.com", "https://i.imgur.com/a"] {
+            assert!(bad.parse::<ImageOrigin>().is_err(), "{bad:?} accepted");
+        }
+    }
+
+    #[test]
+    fn test_allows_only_listed_origins() {
+        let allowed = origins(&["https://i.imgur.com"]);
+
+        assert!(allowed.allows("https://i.imgur.com/cat.png?x=1"));
+        assert!(allowed.allows("https://I.IMGUR.COM:443/cat.png"));
+        assert!(!allowed.allows("http://i.imgur.com/cat.png"));
+        assert!(!allowed.allows("https://i.imgur.com.evil.test/cat.png"));
+        assert!(!allowed.allows("https://user@i.imgur.com/cat.png"));
+        assert!(!allowed.allows("/local.png"));
+    }
+
+    #[test]
+    fn test_rewrites_images_on_allowed_origins_only() {
+        let allowed = origins(&["https://i.imgur.com"]);
+        let body = "![cat](https://i.imgur.com/cat.png?a=1&b=2 \"Cat\")\n\
+                    ![dog](<https://i.imgur.com/dog.png>)\n\
+                    ![elsewhere](https://example.com/x.png)\n\
+                    [not an image](https://i.imgur.com/page)"
+            .to_string();
+
+        let rewritten = allowed.rewrite_markdown(body);
+
+        assert_eq!(
+            rewritten,
+            "![cat](/api/proxy/image?url=https%3A%2F%2Fi.imgur.com%2Fcat.png%3Fa%3D1%26b%3D2 \"Cat\")\n\
+             ![dog](</api/proxy/image?url=https%3A%2F%2Fi.imgur.com%2Fdog.png>)\n\
+             ![elsewhere](https://example.com/x.png)\n\
+             [not an image](https://i.imgur.com/page)"
+        );
+        // Already rewritten bodies stay as they are
+        assert_eq!(allowed.rewrite_markdown(rewritten.clone()), rewritten);
+    }
+}
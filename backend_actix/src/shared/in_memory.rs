@@ -0,0 +1,46 @@
+//! Storage behind the in-memory adapters that `RUN_MODE=standalone` wires in.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// A process-local table. Clones share the same rows, the way every
+/// Postgres adapter shares one database, so one module's adapters can read
+/// another module's rows (the trash reads CVs, projects and topics).
+///
+/// A panic while the lock is held does not poison the table for everyone
+/// else: a half-finished write is no worse than an aborted transaction here.
+#[derive(Debug)]
+pub struct Table<T> {
+    rows: Arc<Mutex<Vec<T>>>,
+}
+
+impl<T> Table<T> {
+    pub fn read<R>(&self, f: impl FnOnce(&[T]) -> R) -> R {
+        f(&self.lock())
+    }
+
+    pub fn write<R>(&self, f: impl FnOnce(&mut Vec<T>) -> R) -> R {
+        f(&mut self.lock())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<T>> {
+        self.rows
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<T> Clone for Table<T> {
+    fn clone(&self) -> Self {
+        Self {
+            rows: Arc::clone(&self.rows),
+        }
+    }
+}
+
+impl<T> Default for Table<T> {
+    fn default() -> Self {
+        Self {
+            rows: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+// src/shared/ip_range.rs
+//! CIDR ranges, for the blocklist's IP rules and `TRUSTED_PROXIES`.
+
+use std::fmt;
+use std::net::IpAddr;
+use std::str::FromStr;
+
+/// A CIDR range such as `203.0.113.0/24` or `2001:db8::/32`. A bare address
+/// is a range of one. Host bits are cleared, so equal ranges print the same.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IpRange {
+    network: IpAddr,
+    prefix: u8,
+}
+
+impl IpRange {
+    /// Whether `ip` falls in the range. IPv4-mapped IPv6 addresses
+    /// (`::ffff:203.0.113.7`) count as the IPv4 address.
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        match (self.network, ip.to_canonical()) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                mask_v4(u32::from(ip), self.prefix) == u32::from(network)
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                mask_v6(u128::from(ip), self.prefix) == u128::from(network)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// `bits` with all but the first `prefix` bits cleared
+fn mask_v4(bits: u32, prefix: u8) -> u32 {
+    match prefix {
+        0 => 0,
+        _ => bits & (u32::MAX << (32 - u32::from(prefix))),
+    }
+}
+
+fn mask_v6(bits: u128, prefix: u8) -> u128 {
+    match prefix {
+        0 => 0,
+        _ => bits & (u128::MAX << (128 - u32::from(prefix))),
+    }
+}
+
+impl FromStr for IpRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("expected an IP address or CIDR range, got '{s}'");
+        let (address, prefix) = match s.trim().split_once('/') {
+            Some((address, prefix)) => (address, Some(prefix)),
+            None => (s.trim(), None),
+        };
+        let address = address
+            .parse::<IpAddr>()
+            .map_err(|_| invalid())?
+            .to_canonical();
+        let max = if address.is_ipv4() { 32 } else { 128 };
+        let prefix = match prefix {
+            Some(prefix) => prefix
+                .parse::<u8>()
+                .ok()
+                .filter(|prefix| *prefix <= max)
+                .ok_or_else(invalid)?,
+            None => max,
+        };
+
+        let network = match address {
+            IpAddr::V4(ip) => IpAddr::from(mask_v4(u32::from(ip), prefix).to_be_bytes()),
+            IpAddr::V6(ip) => IpAddr::from(mask_v6(u128::from(ip), prefix).to_be_bytes()),
+        };
+        Ok(Self { network, prefix })
+    }
+}
+
+impl fmt::Display for IpRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.network, self.prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_ranges_are_stored_canonically() {
+        let range: IpRange = "203.0.113.77/24".parse().unwrap();
+        assert_eq!(range.to_string(), "203.0.113.0/24");
+        assert_eq!(
+            "2001:db8::1".parse::<IpRange>().unwrap().to_string(),
+            "2001:db8::1/128"
+        );
+        assert_eq!(
+            "0.0.0.0/0".parse::<IpRange>().unwrap().to_string(),
+            "0.0.0.0/0"
+        );
+        for bad in ["203.0.113.0/33", "spam.example", "203.0.113/24", "::/129"] {
+            assert!(bad.parse::<IpRange>().is_err(), "{bad:?} accepted");
+        }
+    }
+
+    #[test]
+    fn test_contains() {
+        let range: IpRange = "203.0.113.0/24".parse().unwrap();
+        assert!(range.contains(ip("203.0.113.200")));
+        assert!(range.contains(ip("::ffff:203.0.113.9")));
+        assert!(!range.contains(ip("203.0.114.1")));
+        assert!(!range.contains(ip("2001:db8::1")));
+
+        let everything: IpRange = "::/0".parse().unwrap();
+        assert!(everything.contains(ip("2001:db8::1")));
+        assert!("198.51.100.7"
+            .parse::<IpRange>()
+            .unwrap()
+            .contains(ip("198.51.100.7")));
+    }
+}
@@ -0,0 +1,225 @@
+// src/shared/json_schema.rs
+//! Minimal JSON Schema validator for the schemas this server ships. Knows
+//! `type`, `enum`, `properties`, `required`, `additionalProperties`,
+//! `minLength`/`maxLength`, `pattern`, `minimum`/`maximum`, `items` and
+//! `minItems`/`maxItems`; every other keyword (`$ref`, `oneOf`, `format`, ...)
+//! is ignored, so schemas must stick to that subset.
+
+use regex::Regex;
+use serde_json::{Map, Value};
+
+/// Every violation of `schema` by `value`, as `<JSON pointer>: <problem>`;
+/// empty if the value is valid
+pub fn validate(schema: &Value, value: &Value) -> Vec<String> {
+    let mut errors = Vec::new();
+    check(schema, value, "", &mut errors);
+    errors
+}
+
+fn check(schema: &Value, value: &Value, path: &str, errors: &mut Vec<String>) {
+    let Some(schema) = schema.as_object() else {
+        return;
+    };
+    let mut fail = |message: String| {
+        let path = if path.is_empty() { "/" } else { path };
+        errors.push(format!("{path}: {message}"));
+    };
+
+    if let Some(expected) = schema.get("type") {
+        let types: Vec<&str> = match expected {
+            Value::String(name) => vec![name.as_str()],
+            Value::Array(names) => names.iter().filter_map(Value::as_str).collect(),
+            _ => vec![],
+        };
+        if !types.is_empty() && !types.iter().any(|name| has_type(value, name)) {
+            fail(format!("must be of type {}", types.join(" or ")));
+            // Nothing else applies to a value of the wrong type
+            return;
+        }
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+        if !allowed.contains(value) {
+            let allowed: Vec<String> = allowed.iter().map(Value::to_string).collect();
+            fail(format!("must be one of {}", allowed.join(", ")));
+        }
+    }
+
+    match value {
+        Value::String(text) => {
+            let length = text.chars().count() as u64;
+            if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+                if length < min {
+                    fail(format!("must be at least {min} characters"));
+                }
+            }
+            if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+                if length > max {
+                    fail(format!("must be at most {max} characters"));
+                }
+            }
+            if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+                // An unusable pattern is a bug in the schema, not in the value
+                if Regex::new(pattern).is_ok_and(|re| !re.is_match(text)) {
+                    fail(format!("must match {pattern}"));
+                }
+            }
+        }
+        Value::Number(number) => {
+            let number = number.as_f64().unwrap_or_default();
+            if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+                if number < min {
+                    fail(format!("must be at least {min}"));
+                }
+            }
+            if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+                if number > max {
+                    fail(format!("must be at most {max}"));
+                }
+            }
+        }
+        Value::Array(items) => {
+            let count = items.len() as u64;
+            if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+                if count < min {
+                    fail(format!("must have at least {min} items"));
+                }
+            }
+            if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+                if count > max {
+                    fail(format!("must have at most {max} items"));
+                }
+            }
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    check(item_schema, item, &format!("{path}/{index}"), errors);
+                }
+            }
+        }
+        Value::Object(object) => check_object(schema, object, path, errors),
+        Value::Null | Value::Bool(_) => {}
+    }
+}
+
+fn check_object(
+    schema: &Map<String, Value>,
+    object: &Map<String, Value>,
+    path: &str,
+    errors: &mut Vec<String>,
+) {
+    let empty = Map::new();
+    let properties = schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+
+    if let Some(required) = schema.get("required").and_then(Value::as_array) {
+        for name in required.iter().filter_map(Value::as_str) {
+            if !object.contains_key(name) {
+                errors.push(format!("{path}/{}: is required", escape(name)));
+            }
+        }
+    }
+
+    for (name, value) in object {
+        let property_path = format!("{path}/{}", escape(name));
+        match (properties.get(name), schema.get("additionalProperties")) {
+            (Some(property), _) => check(property, value, &property_path, errors),
+            (None, Some(Value::Bool(false))) => {
+                errors.push(format!("{property_path}: is not allowed"));
+            }
+            (None, Some(additional @ Value::Object(_))) => {
+                check(additional, value, &property_path, errors)
+            }
+            (None, _) => {}
+        }
+    }
+}
+
+fn has_type(value: &Value, name: &str) -> bool {
+    match name {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        _ => true,
+    }
+}
+
+/// JSON pointer escaping of one path segment
+fn escape(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "additionalProperties": false,
+            "required": ["name"],
+            "properties": {
+                "name": { "type": "string", "minLength": 1, "maxLength": 5 },
+                "color": { "type": "string", "pattern": "^#[0-9a-f]{6}$" },
+                "size": { "type": "integer", "minimum": 1, "maximum": 10 },
+                "align": { "enum": ["left", "center"] },
+                "tags": { "type": "array", "maxItems": 2, "items": { "type": "string" } }
+            }
+        })
+    }
+
+    #[test]
+    fn test_valid_value_has_no_errors() {
+        let value = json!({
+            "name": "Jane",
+            "color": "#336699",
+            "size": 4,
+            "align": "center",
+            "tags": ["a", "b"]
+        });
+
+        assert!(validate(&schema(), &value).is_empty());
+    }
+
+    #[test]
+    fn test_every_violation_is_reported_with_its_path() {
+        let value = json!({
+            "color": "red",
+            "size": 4.5,
+            "align": "right",
+            "tags": ["a", 2, "c"],
+            "extra": true
+        });
+
+        // Property order depends on serde_json features; compare sorted
+        let mut errors = validate(&schema(), &value);
+        errors.sort();
+
+        assert_eq!(
+            errors,
+            vec![
+                "/align: must be one of \"left\", \"center\"",
+                "/color: must match ^#[0-9a-f]{6}$",
+                "/extra: is not allowed",
+                "/name: is required",
+                "/size: must be of type integer",
+                "/tags/1: must be of type string",
+                "/tags: must have at most 2 items",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wrong_root_type_is_reported_once() {
+        assert_eq!(
+            validate(&schema(), &json!([])),
+            vec!["/: must be of type object"]
+        );
+    }
+}
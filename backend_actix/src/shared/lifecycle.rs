@@ -0,0 +1,139 @@
+// src/shared/lifecycle.rs
+use std::future::Future;
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+/// Handed to every background job; resolves when the process starts shutting down.
+///
+/// Jobs that buffer work (queues, counters) should flush it after `wait()` returns.
+#[derive(Clone)]
+pub struct ShutdownSignal(watch::Receiver<bool>);
+
+impl ShutdownSignal {
+    pub async fn wait(&mut self) {
+        // Err means the sender is gone, which also means shutdown
+        let _ = self.0.wait_for(|stopping| *stopping).await;
+    }
+
+    #[allow(dead_code)]
+    pub fn is_shutting_down(&self) -> bool {
+        *self.0.borrow()
+    }
+}
+
+/// Long-running tasks started at boot and stopped after the HTTP server has drained.
+///
+/// Shutdown order (see `main.rs`):
+/// 1. SIGTERM -> server stops accepting, in-flight requests drain (`SHUTDOWN_TIMEOUT_SECS`)
+/// 2. `BackgroundJobs::shutdown` -> jobs get the signal and flush, bounded by the same timeout
+/// 3. The database pool is closed
+pub struct BackgroundJobs {
+    shutdown: watch::Sender<bool>,
+    handles: Vec<(&'static str, JoinHandle<()>)>,
+}
+
+impl Default for BackgroundJobs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BackgroundJobs {
+    pub fn new() -> Self {
+        let (shutdown, _) = watch::channel(false);
+        Self {
+            shutdown,
+            handles: Vec::new(),
+        }
+    }
+
+    pub fn spawn<F, Fut>(&mut self, name: &'static str, job: F)
+    where
+        F: FnOnce(ShutdownSignal) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let signal = ShutdownSignal(self.shutdown.subscribe());
+        self.handles.push((name, tokio::spawn(job(signal))));
+    }
+
+    /// Signal every job and wait for them to finish. Jobs still running when
+    /// `timeout` expires are aborted. Returns the names of aborted jobs.
+    pub async fn shutdown(self, timeout: Duration) -> Vec<&'static str> {
+        let _ = self.shutdown.send(true);
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut aborted = Vec::new();
+
+        for (name, mut handle) in self.handles {
+            match tokio::time::timeout_at(deadline, &mut handle).await {
+                Ok(Ok(())) => info!(job = name, "Background job stopped"),
+                Ok(Err(e)) => warn!(job = name, error = %e, "Background job ended abnormally"),
+                Err(_) => {
+                    warn!(job = name, "Background job did not stop in time, aborting");
+                    handle.abort();
+                    aborted.push(name);
+                }
+            }
+        }
+
+        aborted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_jobs_flush_on_shutdown() {
+        let flushed = Arc::new(AtomicUsize::new(0));
+        let mut jobs = BackgroundJobs::new();
+
+        let counter = Arc::clone(&flushed);
+        jobs.spawn("buffer", move |mut signal| async move {
+            signal.wait().await;
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let aborted = jobs.shutdown(Duration::from_secs(1)).await;
+
+        assert!(aborted.is_empty());
+        assert_eq!(flushed.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_stuck_jobs_are_aborted_after_timeout() {
+        let mut jobs = BackgroundJobs::new();
+        jobs.spawn("stuck", |_signal| async {
+            std::future::pending::<()>().await;
+        });
+        jobs.spawn("polite", |mut signal| async move {
+            signal.wait().await;
+        });
+
+        let aborted = jobs.shutdown(Duration::from_millis(50)).await;
+
+        assert_eq!(aborted, vec!["stuck"]);
+    }
+
+    #[tokio::test]
+    async fn test_signal_reports_state() {
+        let mut jobs = BackgroundJobs::new();
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        // Read before the task first runs, which may be after shutdown is sent
+        jobs.spawn("probe", move |mut signal| {
+            let before = signal.is_shutting_down();
+            async move {
+                signal.wait().await;
+                let _ = tx.send((before, signal.is_shutting_down()));
+            }
+        });
+
+        jobs.shutdown(Duration::from_secs(1)).await;
+
+        assert_eq!(rx.await.unwrap(), (false, true));
+    }
+}
@@ -0,0 +1,117 @@
+// src/shared/log_filter.rs
+use crate::shared::telemetry::LogFormat;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tracing_subscriber::{
+    layer::{Layered, SubscriberExt},
+    reload,
+    util::SubscriberInitExt,
+    EnvFilter, Layer, Registry,
+};
+
+/// Used in production when neither `RUST_LOG` nor `LOG_FILTER` is set
+pub const DEFAULT_LOG_FILTER: &str = "info,actix_web=info";
+/// Used elsewhere: the server's own debug events as well
+pub const DEV_LOG_FILTER: &str = "info,actix_web=info,backend_actix=debug";
+
+type Filtered = Layered<reload::Layer<EnvFilter, Registry>, Registry>;
+type FormatLayer = Box<dyn Layer<Filtered> + Send + Sync>;
+
+/// Directives used without `RUST_LOG` and `LOG_FILTER` under `rust_env`
+pub fn default_filter(rust_env: &str) -> &'static str {
+    if rust_env == "production" {
+        DEFAULT_LOG_FILTER
+    } else {
+        DEV_LOG_FILTER
+    }
+}
+
+/// `RUST_LOG` directives, e.g. `info,sqlx::query=debug`
+pub fn parse(directives: &str) -> Result<EnvFilter, String> {
+    EnvFilter::try_new(directives).map_err(|e| e.to_string())
+}
+
+fn format_layer(format: LogFormat) -> FormatLayer {
+    match format {
+        LogFormat::Pretty => tracing_subscriber::fmt::layer().boxed(),
+        LogFormat::Json => tracing_subscriber::fmt::layer().json().boxed(),
+    }
+}
+
+/// The `EnvFilter` deciding which tracing events are logged, and the format
+/// they are written in, both changeable while the server runs.
+#[derive(Clone)]
+pub struct LogFilter {
+    handle: reload::Handle<EnvFilter, Registry>,
+    format_handle: reload::Handle<FormatLayer, Filtered>,
+    json: Arc<AtomicBool>,
+}
+
+impl LogFilter {
+    /// Install the global subscriber, writing `format` and filtered by
+    /// `RUST_LOG` (or the default of `RUST_ENV`) until [`set`](Self::set)
+    /// is called.
+    pub fn init(format: LogFormat) -> Self {
+        let rust_env = std::env::var("RUST_ENV").unwrap_or_default();
+        let filter = EnvFilter::try_from_default_env()
+            .unwrap_or_else(|_| EnvFilter::new(default_filter(&rust_env)));
+        let (filter, handle) = reload::Layer::new(filter);
+        let (output, format_handle) = reload::Layer::new(format_layer(format));
+
+        tracing_subscriber::registry()
+            .with(filter)
+            .with(output)
+            .init();
+
+        Self {
+            handle,
+            format_handle,
+            json: Arc::new(AtomicBool::new(format == LogFormat::Json)),
+        }
+    }
+
+    /// Directives in effect, e.g. `info,sqlx::query=debug`
+    pub fn current(&self) -> String {
+        self.handle
+            .with_current(|filter| filter.to_string())
+            .unwrap_or_default()
+    }
+
+    /// Replace the directives. Invalid ones leave the filter as it was.
+    pub fn set(&self, directives: &str) -> Result<(), String> {
+        let filter = parse(directives)?;
+        self.handle.reload(filter).map_err(|e| e.to_string())
+    }
+
+    /// Format lines are written in
+    pub fn format(&self) -> LogFormat {
+        if self.json.load(Ordering::Relaxed) {
+            LogFormat::Json
+        } else {
+            LogFormat::Pretty
+        }
+    }
+
+    /// Write the lines from now on in `format`
+    pub fn set_format(&self, format: LogFormat) -> Result<(), String> {
+        self.format_handle
+            .reload(format_layer(format))
+            .map_err(|e| e.to_string())?;
+        self.json
+            .store(format == LogFormat::Json, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_filter_depends_on_environment() {
+        assert_eq!(default_filter("production"), DEFAULT_LOG_FILTER);
+        assert_eq!(default_filter("development"), DEV_LOG_FILTER);
+        assert!(parse(DEV_LOG_FILTER).is_ok());
+        assert!(parse("info,sqlx::query=loud").is_err());
+    }
+}
@@ -0,0 +1,258 @@
+// src/shared/metrics.rs
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Write};
+use std::future::Future;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+/// Upper bounds, in seconds, of the latency histogram buckets
+const BUCKETS: [f64; 12] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0,
+];
+
+static USE_CASE_METRICS: LazyLock<UseCaseMetrics> = LazyLock::new(UseCaseMetrics::default);
+
+/// The process-wide registry `Metered` use cases record into
+pub fn use_case_metrics() -> &'static UseCaseMetrics {
+    &USE_CASE_METRICS
+}
+
+/// Runs one use case execution, recording its latency and, when it fails,
+/// its error code under `module` and `use_case`.
+pub async fn observe<T, E, F>(
+    module: &'static str,
+    use_case: &'static str,
+    execution: F,
+) -> Result<T, E>
+where
+    E: Debug,
+    F: Future<Output = Result<T, E>>,
+{
+    let started = Instant::now();
+    let result = execution.await;
+    let code = result.as_ref().err().map(error_code);
+    use_case_metrics().record(module, use_case, started.elapsed(), code.as_deref());
+    result
+}
+
+/// Error variant name in the style of the API error codes,
+/// e.g. `MediaNotFound` or `RepositoryError("...")` become `MEDIA_NOT_FOUND`
+/// and `REPOSITORY_ERROR`.
+pub fn error_code<E: Debug>(error: &E) -> String {
+    let debug = format!("{error:?}");
+    let variant = debug
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .next()
+        .unwrap_or_default();
+
+    let mut code = String::with_capacity(variant.len() + 4);
+    let mut previous: Option<char> = None;
+    for c in variant.chars() {
+        if c.is_uppercase() && previous.is_some_and(|p| p.is_lowercase() || p.is_ascii_digit()) {
+            code.push('_');
+        }
+        code.push(c.to_ascii_uppercase());
+        previous = Some(c);
+    }
+    code
+}
+
+/// Wraps a use case service so every execution is timed and every error
+/// counted. The use case trait gets its impl for `Metered` from
+/// `metered_use_case!`, next to the trait.
+pub struct Metered<U>(pub U);
+
+/// Implements a use case trait for `Metered`, recording each `execute` under
+/// the module and use case name given. The signature repeats the trait's.
+macro_rules! metered_use_case {
+    ($module:literal, $use_case:literal, $trait:ident,
+        fn execute(&self $(, $arg:ident: $ty:ty)* $(,)?) -> $ret:ty) => {
+        #[async_trait::async_trait]
+        impl<U: $trait + Send + Sync> $trait for $crate::shared::metrics::Metered<U> {
+            async fn execute(&self $(, $arg: $ty)*) -> $ret {
+                $crate::shared::metrics::observe($module, $use_case, self.0.execute($($arg),*))
+                    .await
+            }
+        }
+    };
+}
+pub(crate) use metered_use_case;
+
+#[derive(Default)]
+struct Latency {
+    /// Executions at or under each of `BUCKETS`, not cumulative
+    buckets: [u64; BUCKETS.len()],
+    sum: f64,
+    count: u64,
+}
+
+#[derive(Default)]
+struct Series {
+    latency: BTreeMap<(&'static str, &'static str), Latency>,
+    errors: BTreeMap<(&'static str, &'static str, String), u64>,
+}
+
+/// Latency histograms and error counters of use case executions, by module
+/// and use case name. Kept in memory; each instance reports its own.
+#[derive(Default)]
+pub struct UseCaseMetrics {
+    series: Mutex<Series>,
+}
+
+impl UseCaseMetrics {
+    pub fn record(
+        &self,
+        module: &'static str,
+        use_case: &'static str,
+        elapsed: Duration,
+        error_code: Option<&str>,
+    ) {
+        let seconds = elapsed.as_secs_f64();
+        let mut series = self.series.lock().unwrap();
+
+        let latency = series.latency.entry((module, use_case)).or_default();
+        if let Some(i) = BUCKETS.iter().position(|bound| seconds <= *bound) {
+            latency.buckets[i] += 1;
+        }
+        latency.sum += seconds;
+        latency.count += 1;
+
+        if let Some(code) = error_code {
+            *series
+                .errors
+                .entry((module, use_case, code.to_string()))
+                .or_default() += 1;
+        }
+    }
+
+    /// Everything recorded so far, in the Prometheus text format
+    pub fn render(&self) -> String {
+        let series = self.series.lock().unwrap();
+        let mut out = String::new();
+
+        out.push_str("# HELP use_case_duration_seconds Time spent executing a use case\n");
+        out.push_str("# TYPE use_case_duration_seconds histogram\n");
+        for ((module, use_case), latency) in &series.latency {
+            let labels = format!("module=\"{module}\",use_case=\"{use_case}\"");
+            let mut cumulative = 0;
+            for (bound, count) in BUCKETS.iter().zip(latency.buckets) {
+                cumulative += count;
+                let _ = writeln!(
+                    out,
+                    "use_case_duration_seconds_bucket{{{labels},le=\"{bound}\"}} {cumulative}"
+                );
+            }
+            let _ = writeln!(
+                out,
+                "use_case_duration_seconds_bucket{{{labels},le=\"+Inf\"}} {}",
+                latency.count
+            );
+            let _ = writeln!(
+                out,
+                "use_case_duration_seconds_sum{{{labels}}} {}",
+                latency.sum
+            );
+            let _ = writeln!(
+                out,
+                "use_case_duration_seconds_count{{{labels}}} {}",
+                latency.count
+            );
+        }
+
+        out.push_str("# HELP use_case_errors_total Use case executions that returned an error\n");
+        out.push_str("# TYPE use_case_errors_total counter\n");
+        for ((module, use_case, code), count) in &series.errors {
+            let _ = writeln!(
+                out,
+                "use_case_errors_total{{module=\"{module}\",use_case=\"{use_case}\",code=\"{code}\"}} {count}"
+            );
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    enum SampleError {
+        MediaNotFound,
+        RepositoryError(String),
+        Invalid { field: String },
+    }
+
+    #[test]
+    fn test_error_code_is_the_variant_name() {
+        assert_eq!(error_code(&SampleError::MediaNotFound), "MEDIA_NOT_FOUND");
+        assert_eq!(
+            error_code(&SampleError::RepositoryError("pool timed out".into())),
+            "REPOSITORY_ERROR"
+        );
+        assert_eq!(
+            error_code(&SampleError::Invalid {
+                field: "slug".into()
+            }),
+            "INVALID"
+        );
+    }
+
+    #[test]
+    fn test_render_has_cumulative_buckets() {
+        let metrics = UseCaseMetrics::default();
+        metrics.record("media", "list_media", Duration::from_millis(3), None);
+        metrics.record("media", "list_media", Duration::from_millis(40), None);
+        metrics.record("media", "list_media", Duration::from_secs(60), None);
+
+        let out = metrics.render();
+
+        assert!(out.contains(
+            "use_case_duration_seconds_bucket{module=\"media\",use_case=\"list_media\",le=\"0.005\"} 1\n"
+        ));
+        assert!(out.contains(
+            "use_case_duration_seconds_bucket{module=\"media\",use_case=\"list_media\",le=\"0.05\"} 2\n"
+        ));
+        // Slower than the last bucket only counts toward +Inf
+        assert!(out.contains(
+            "use_case_duration_seconds_bucket{module=\"media\",use_case=\"list_media\",le=\"30\"} 2\n"
+        ));
+        assert!(out.contains(
+            "use_case_duration_seconds_bucket{module=\"media\",use_case=\"list_media\",le=\"+Inf\"} 3\n"
+        ));
+        assert!(out.contains(
+            "use_case_duration_seconds_count{module=\"media\",use_case=\"list_media\"} 3\n"
+        ));
+    }
+
+    #[test]
+    fn test_render_counts_errors_by_code() {
+        let metrics = UseCaseMetrics::default();
+        metrics.record("cv", "get_cv", Duration::ZERO, Some("CV_NOT_FOUND"));
+        metrics.record("cv", "get_cv", Duration::ZERO, Some("CV_NOT_FOUND"));
+        metrics.record("cv", "get_cv", Duration::ZERO, None);
+
+        let out = metrics.render();
+
+        assert!(out.contains(
+            "use_case_errors_total{module=\"cv\",use_case=\"get_cv\",code=\"CV_NOT_FOUND\"} 2\n"
+        ));
+        assert!(
+            out.contains("use_case_duration_seconds_count{module=\"cv\",use_case=\"get_cv\"} 3\n")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_observe_records_into_the_process_registry() {
+        let result: Result<(), SampleError> = observe("metrics_test", "failing", async {
+            Err(SampleError::MediaNotFound)
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert!(use_case_metrics().render().contains(
+            "use_case_errors_total{module=\"metrics_test\",use_case=\"failing\",code=\"MEDIA_NOT_FOUND\"} 1\n"
+        ));
+    }
+}
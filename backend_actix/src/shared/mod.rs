@@ -1 +1,25 @@
+pub mod access_log;
 pub mod api;
+pub mod bot_check;
+pub mod cache;
+pub mod client_ip;
+pub mod constant_time;
+pub mod faults;
+pub mod field_cipher;
+pub mod image_proxy;
+pub mod in_memory;
+pub mod ip_range;
+pub mod json_schema;
+pub mod lifecycle;
+pub mod log_filter;
+pub mod metrics;
+pub mod rate_limit;
+pub mod request_id;
+pub mod schema_check;
+pub mod seo;
+pub(crate) mod sql;
+pub mod sql_log;
+pub mod telemetry;
+pub mod unit_of_work;
+pub mod validation;
+pub mod zip;
@@ -0,0 +1,210 @@
+// src/shared/rate_limit.rs
+//! Fixed-window request limits for public write endpoints, keyed by client
+//! address. Counts are kept per instance, like the login failures in
+//! `BotGate`, so behind N instances a client gets up to N times the limit.
+
+use actix_web::{http::header::RETRY_AFTER, HttpRequest, HttpResponse};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::shared::api::{error_codes::RATE_LIMITED, ErrorCode};
+use crate::shared::client_ip::client_ip;
+
+/// Clones share their counts and their limit
+#[derive(Clone)]
+pub struct RateLimiter {
+    /// Requests allowed per window; 0 means unlimited
+    limit: Arc<AtomicU32>,
+    window: Duration,
+    hits: Arc<Mutex<HashMap<String, (u32, Instant)>>>,
+}
+
+impl RateLimiter {
+    pub fn new(limit: u32, window: Duration) -> Self {
+        Self {
+            limit: Arc::new(AtomicU32::new(limit)),
+            window,
+            hits: Arc::default(),
+        }
+    }
+
+    pub fn limit(&self) -> u32 {
+        self.limit.load(Ordering::Relaxed)
+    }
+
+    /// Change the limit of this limiter and its clones; counts so far are kept
+    pub fn set_limit(&self, limit: u32) {
+        self.limit.store(limit, Ordering::Relaxed);
+    }
+
+    /// Never limits
+    pub fn disabled() -> Self {
+        Self::new(0, Duration::ZERO)
+    }
+
+    /// Count a request for `key`. Over the limit, returns how long until the
+    /// key's window resets.
+    pub fn hit(&self, key: &str) -> Result<(), Duration> {
+        self.hit_many(key, 1)
+    }
+
+    /// Count `n` requests for `key` at once, all or none: when they do not
+    /// all fit in the window none are counted.
+    pub fn hit_many(&self, key: &str, n: u32) -> Result<(), Duration> {
+        let limit = self.limit();
+        if limit == 0 {
+            return Ok(());
+        }
+
+        let now = Instant::now();
+        let mut hits = self.hits.lock().unwrap();
+        hits.retain(|_, (_, started)| now.duration_since(*started) < self.window);
+        let (count, started) = hits.entry(key.to_string()).or_insert((0, now));
+        if count.saturating_add(n) > limit {
+            return Err(self.window - now.duration_since(*started));
+        }
+        *count += n;
+        Ok(())
+    }
+
+    /// Requests `key` has left in its window, without counting one; `None`
+    /// when unlimited
+    pub fn remaining(&self, key: &str) -> Option<u32> {
+        let limit = self.limit();
+        if limit == 0 {
+            return None;
+        }
+
+        let hits = self.hits.lock().unwrap();
+        let used = match hits.get(key) {
+            Some((count, started)) if started.elapsed() < self.window => *count,
+            _ => 0,
+        };
+        Some(limit.saturating_sub(used))
+    }
+
+    /// [`hit`](Self::hit) keyed by the client address, as a 429 response
+    pub fn check(&self, req: &HttpRequest) -> Result<(), HttpResponse> {
+        let key = client_ip(req).map_or_else(|| "unknown".to_string(), |ip| ip.to_string());
+
+        self.hit(&key).map_err(too_many_requests)
+    }
+}
+
+/// 429 in the API's error format, with `Retry-After` in whole seconds
+pub fn too_many_requests(retry_after: Duration) -> HttpResponse {
+    rate_limited(RATE_LIMITED, RATE_LIMITED.message, retry_after)
+}
+
+/// [`too_many_requests`] with a code and message of its own
+pub fn rate_limited(code: ErrorCode, message: &str, retry_after: Duration) -> HttpResponse {
+    let mut response = code.with_message(message);
+    response
+        .headers_mut()
+        .insert(RETRY_AFTER, retry_after_secs(retry_after).into());
+    response
+}
+
+/// Whole seconds, rounded up and at least 1
+pub fn retry_after_secs(retry_after: Duration) -> u64 {
+    let seconds = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+    seconds.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test::TestRequest};
+
+    #[test]
+    fn test_limits_each_key_separately() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(60));
+
+        assert!(limiter.hit("203.0.113.7").is_ok());
+        assert!(limiter.hit("203.0.113.7").is_ok());
+        let retry_after = limiter.hit("203.0.113.7").unwrap_err();
+        assert!(retry_after <= Duration::from_secs(60));
+        assert!(limiter.hit("198.51.100.1").is_ok());
+    }
+
+    #[test]
+    fn test_hit_many_counts_all_or_none() {
+        let limiter = RateLimiter::new(5, Duration::from_secs(60));
+
+        assert!(limiter.hit_many("a", 3).is_ok());
+        assert!(limiter.hit_many("a", 3).is_err());
+        assert_eq!(limiter.remaining("a"), Some(2));
+        assert!(limiter.hit_many("a", 2).is_ok());
+        assert!(limiter.hit("a").is_err());
+    }
+
+    #[test]
+    fn test_remaining_does_not_count() {
+        let limiter = RateLimiter::new(3, Duration::from_secs(60));
+
+        assert_eq!(limiter.remaining("a"), Some(3));
+        limiter.hit("a").unwrap();
+        assert_eq!(limiter.remaining("a"), Some(2));
+        assert_eq!(limiter.remaining("a"), Some(2));
+        assert_eq!(RateLimiter::disabled().remaining("a"), None);
+    }
+
+    #[test]
+    fn test_window_resets() {
+        let limiter = RateLimiter::new(1, Duration::from_millis(20));
+
+        assert!(limiter.hit("a").is_ok());
+        assert!(limiter.hit("a").is_err());
+        std::thread::sleep(Duration::from_millis(25));
+        assert!(limiter.hit("a").is_ok());
+    }
+
+    #[test]
+    fn test_new_limit_applies_to_clones() {
+        let limiter = RateLimiter::new(1, Duration::from_secs(60));
+        let clone = limiter.clone();
+
+        assert!(clone.hit("a").is_ok());
+        assert!(clone.hit("a").is_err());
+        limiter.set_limit(2);
+        assert!(clone.hit("a").is_ok());
+        assert!(clone.hit("a").is_err());
+    }
+
+    #[test]
+    fn test_disabled_never_limits() {
+        let limiter = RateLimiter::disabled();
+
+        assert!((0..100).all(|_| limiter.hit("a").is_ok()));
+    }
+
+    #[test]
+    fn test_rejection_is_429_with_retry_after() {
+        let limiter = RateLimiter::new(1, Duration::from_secs(3600));
+        let req = TestRequest::default()
+            .peer_addr("203.0.113.7:5000".parse().unwrap())
+            .to_http_request();
+
+        assert!(limiter.check(&req).is_ok());
+        let response = limiter.check(&req).unwrap_err();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers().get(RETRY_AFTER).unwrap(), "3600");
+    }
+
+    #[test]
+    fn test_forwarded_for_header_does_not_reset_the_limit() {
+        let limiter = RateLimiter::new(1, Duration::from_secs(3600));
+        let req = |forwarded_for: &str| {
+            TestRequest::default()
+                .peer_addr("203.0.113.7:5000".parse().unwrap())
+                .insert_header(("X-Forwarded-For", forwarded_for))
+                .to_http_request()
+        };
+
+        assert!(limiter.check(&req("198.51.100.1")).is_ok());
+        assert!(limiter.check(&req("198.51.100.2")).is_err());
+    }
+}
@@ -0,0 +1,189 @@
+// src/shared/request_id.rs
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    http::header::{HeaderName, HeaderValue},
+    middleware::Next,
+    Error,
+};
+use tracing::Instrument;
+
+use crate::shared::access_log::redact_path;
+use uuid::Uuid;
+
+/// Header used to receive and return the request id
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Incoming ids longer than this are replaced with a generated one
+const MAX_INCOMING_LEN: usize = 128;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+    static REQUEST_PATH: String;
+}
+
+/// Id of the request currently being handled, if called inside the middleware.
+pub fn current() -> Option<String> {
+    REQUEST_ID.try_with(|id| id.clone()).ok()
+}
+
+/// Path of the request currently being handled (problem+json `instance`).
+pub fn current_path() -> Option<String> {
+    REQUEST_PATH.try_with(|path| path.clone()).ok()
+}
+
+/// Accept a caller-supplied id (load balancer, frontend) only if it is short and
+/// made of safe characters, so it can be logged and echoed back verbatim.
+fn incoming_id(req: &ServiceRequest) -> Option<String> {
+    let value = req.headers().get(REQUEST_ID_HEADER)?.to_str().ok()?.trim();
+
+    let valid = !value.is_empty()
+        && value.len() <= MAX_INCOMING_LEN
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':'));
+
+    valid.then(|| value.to_string())
+}
+
+/// Request id middleware
+///
+/// - Reuses a valid incoming `X-Request-Id`, otherwise generates a UUID v4
+/// - Runs the request inside a `request` span, so every tracing event carries the id
+/// - Makes the id and path available to `ApiResponse` error bodies via `current()`/`current_path()`
+/// - Echoes the id in the `X-Request-Id` response header
+pub async fn request_id_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let id = incoming_id(&req).unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    let span = tracing::info_span!(
+        "request",
+        request_id = %id,
+        method = %req.method(),
+        path = %redact_path(req.path(), req.match_pattern().as_deref()),
+    );
+
+    let path = req.path().to_string();
+    let mut res = REQUEST_ID
+        .scope(id.clone(), REQUEST_PATH.scope(path, next.call(req)))
+        .instrument(span)
+        .await?;
+
+    if let Ok(value) = HeaderValue::from_str(&id) {
+        res.headers_mut()
+            .insert(HeaderName::from_static(REQUEST_ID_HEADER), value);
+    }
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::api::ApiResponse;
+    use actix_web::{middleware::from_fn, test, web, App, HttpResponse};
+
+    async fn failing_handler() -> HttpResponse {
+        ApiResponse::internal_error()
+    }
+
+    async fn ok_handler() -> HttpResponse {
+        ApiResponse::success("fine")
+    }
+
+    macro_rules! app {
+        () => {
+            test::init_service(
+                App::new()
+                    .wrap(from_fn(request_id_middleware))
+                    .route("/fail", web::get().to(failing_handler))
+                    .route("/ok", web::get().to(ok_handler)),
+            )
+            .await
+        };
+    }
+
+    fn header_id<B>(resp: &ServiceResponse<B>) -> String {
+        resp.headers()
+            .get(REQUEST_ID_HEADER)
+            .expect("missing X-Request-Id")
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[actix_web::test]
+    async fn test_generates_request_id_when_missing() {
+        let app = app!();
+
+        let req = test::TestRequest::get().uri("/ok").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), 200);
+        assert!(Uuid::parse_str(&header_id(&resp)).is_ok());
+    }
+
+    #[actix_web::test]
+    async fn test_propagates_valid_incoming_request_id() {
+        let app = app!();
+
+        let req = test::TestRequest::get()
+            .uri("/ok")
+            .insert_header((REQUEST_ID_HEADER, "lb-7f3a:42"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(header_id(&resp), "lb-7f3a:42");
+    }
+
+    #[actix_web::test]
+    async fn test_replaces_unsafe_incoming_request_id() {
+        let app = app!();
+
+        let req = test::TestRequest::get()
+            .uri("/ok")
+            .insert_header((REQUEST_ID_HEADER, "<script>alert(1)</script>"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert!(Uuid::parse_str(&header_id(&resp)).is_ok());
+    }
+
+    #[actix_web::test]
+    async fn test_error_body_includes_request_id() {
+        let app = app!();
+
+        let req = test::TestRequest::get()
+            .uri("/fail")
+            .insert_header((REQUEST_ID_HEADER, "abc-123"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), 500);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"]["code"], "INTERNAL_ERROR");
+        assert_eq!(body["error"]["request_id"], "abc-123");
+    }
+
+    #[actix_web::test]
+    async fn test_current_path_is_set_inside_a_request() {
+        let app = test::init_service(App::new().wrap(from_fn(request_id_middleware)).route(
+            "/where",
+            web::get().to(|| async { ApiResponse::success(current_path()) }),
+        ))
+        .await;
+
+        let req = test::TestRequest::get().uri("/where?x=1").to_request();
+        let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(body["data"], "/where");
+    }
+
+    #[actix_web::test]
+    async fn test_current_is_none_outside_a_request() {
+        assert!(current().is_none());
+        assert!(current_path().is_none());
+    }
+}
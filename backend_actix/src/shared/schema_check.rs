@@ -0,0 +1,204 @@
+//! Whether the database schema is the one this build was written for.
+//!
+//! Pending migrations are applied (or refused) at startup, but a build can
+//! still meet a schema it doesn't match: a rollback to a build older than the
+//! applied migrations, a database restored from an old backup, a column
+//! dropped by hand. Every query reading a missing column then fails with a
+//! 500. The check compares the applied migrations with the build's and looks
+//! for the columns the busiest queries read.
+
+use migration::{Migrator, MigratorTrait};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, Statement};
+use serde::Serialize;
+use std::collections::HashSet;
+use utoipa::ToSchema;
+
+use crate::shared::sql;
+
+/// Where sea-orm records the applied migrations
+const MIGRATIONS_TABLE: &str = "seaql_migrations";
+
+/// Columns read on every request to the tables behind sign-in, public pages
+/// and media. Mostly ones added after a table was created, which are the ones
+/// an older schema lacks.
+const CRITICAL_COLUMNS: &[(&str, &[&str])] = &[
+    (
+        "users",
+        &[
+            "id",
+            "preferred_locale",
+            "email_bounced_at",
+            "email_unsubscribed_at",
+            "token_version",
+            "is_active",
+            "timezone",
+            "avatar_media_id",
+        ],
+    ),
+    (
+        "projects",
+        &[
+            "id",
+            "deleted_at",
+            "seo_title",
+            "seo_description",
+            "canonical_url",
+            "noindex",
+        ],
+    ),
+    (
+        "pages",
+        &[
+            "id",
+            "canonical_url",
+            "noindex",
+            "autosave_body",
+            "autosaved_at",
+        ],
+    ),
+    ("media", &["id", "failure_code", "checksum_sha256"]),
+    ("media_variants", &["id", "checksum_sha256"]),
+];
+
+/// Differences between the database and this build
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, ToSchema)]
+pub struct SchemaReport {
+    /// Migrations of this build the database hasn't applied
+    pub pending: Vec<String>,
+    /// Applied migrations this build doesn't know: the database is newer
+    pub unknown: Vec<String>,
+    /// `table.column` read by this build but missing from the database
+    pub missing_columns: Vec<String>,
+}
+
+impl SchemaReport {
+    fn compare(known: &[String], applied: &[String], columns: &[(String, String)]) -> Self {
+        let pending = known
+            .iter()
+            .filter(|name| !applied.contains(name))
+            .cloned()
+            .collect();
+        let unknown = applied
+            .iter()
+            .filter(|name| !known.contains(name))
+            .cloned()
+            .collect();
+
+        let present: HashSet<(&str, &str)> = columns
+            .iter()
+            .map(|(table, column)| (table.as_str(), column.as_str()))
+            .collect();
+        let missing_columns = CRITICAL_COLUMNS
+            .iter()
+            .flat_map(|(table, columns)| columns.iter().map(move |column| (*table, *column)))
+            .filter(|key| !present.contains(key))
+            .map(|(table, column)| format!("{table}.{column}"))
+            .collect();
+
+        Self {
+            pending,
+            unknown,
+            missing_columns,
+        }
+    }
+
+    pub fn has_drift(&self) -> bool {
+        !(self.pending.is_empty() && self.unknown.is_empty() && self.missing_columns.is_empty())
+    }
+}
+
+/// Compare the database with this build's migrations and critical columns
+pub async fn check(db: &DatabaseConnection) -> Result<SchemaReport, DbErr> {
+    // A new database has no migrations table yet
+    Migrator::install(db).await?;
+    let backend = db.get_database_backend();
+
+    let known: Vec<String> = Migrator::migrations()
+        .iter()
+        .map(|migration| migration.name().to_string())
+        .collect();
+
+    let applied = db
+        .query_all(Statement::from_string(
+            backend,
+            format!("SELECT version FROM {MIGRATIONS_TABLE}"),
+        ))
+        .await?
+        .iter()
+        .map(|row| row.try_get::<String>("", "version"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let columns = db
+        .query_all(Statement::from_string(backend, sql::table_columns(backend)))
+        .await?
+        .iter()
+        .map(|row| {
+            Ok((
+                row.try_get::<String>("", "table_name")?,
+                row.try_get::<String>("", "column_name")?,
+            ))
+        })
+        .collect::<Result<Vec<_>, DbErr>>()?;
+
+    Ok(SchemaReport::compare(&known, &applied, &columns))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    fn all_critical_columns() -> Vec<(String, String)> {
+        CRITICAL_COLUMNS
+            .iter()
+            .flat_map(|(table, columns)| {
+                columns
+                    .iter()
+                    .map(|column| (table.to_string(), column.to_string()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_matching_schema_has_no_drift() {
+        let known = names(&["m1", "m2"]);
+
+        let report = SchemaReport::compare(&known, &known, &all_critical_columns());
+
+        assert_eq!(report, SchemaReport::default());
+        assert!(!report.has_drift());
+    }
+
+    #[test]
+    fn test_reports_pending_unknown_and_missing_columns() {
+        let columns: Vec<_> = all_critical_columns()
+            .into_iter()
+            .filter(|(table, column)| !(table == "users" && column == "token_version"))
+            .collect();
+
+        let report = SchemaReport::compare(
+            &names(&["m1", "m2"]),
+            &names(&["m1", "m0_hotfix"]),
+            &columns,
+        );
+
+        assert_eq!(report.pending, names(&["m2"]));
+        assert_eq!(report.unknown, names(&["m0_hotfix"]));
+        assert_eq!(report.missing_columns, names(&["users.token_version"]));
+        assert!(report.has_drift());
+    }
+
+    #[tokio::test]
+    async fn test_migrated_database_has_no_drift() {
+        let Some(db) = crate::tests::support::database::test_db().await else {
+            return;
+        };
+
+        let report = check(&db).await.unwrap();
+
+        assert_eq!(report, SchemaReport::default());
+    }
+}
@@ -0,0 +1,103 @@
+// src/shared/seo.rs
+//! Rules for the search-engine fields pages and projects share:
+//! `seo_title`, `seo_description`, `canonical_url` and `noindex`.
+
+use actix_web::{
+    http::header::{HeaderName, HeaderValue},
+    HttpResponse,
+};
+
+pub const MAX_SEO_TITLE_LENGTH: usize = 100;
+pub const MAX_SEO_DESCRIPTION_LENGTH: usize = 300;
+pub const MAX_CANONICAL_URL_LENGTH: usize = 2048;
+
+/// Trimmed; blank counts as absent
+pub fn seo_title(title: String) -> Result<Option<String>, String> {
+    bounded(title, MAX_SEO_TITLE_LENGTH)
+        .ok_or_else(|| format!("SEO title must be at most {MAX_SEO_TITLE_LENGTH} characters"))
+}
+
+/// Trimmed; blank counts as absent
+pub fn seo_description(description: String) -> Result<Option<String>, String> {
+    bounded(description, MAX_SEO_DESCRIPTION_LENGTH).ok_or_else(|| {
+        format!("SEO description must be at most {MAX_SEO_DESCRIPTION_LENGTH} characters")
+    })
+}
+
+/// An absolute http(s) URL, trimmed; blank counts as absent
+pub fn canonical_url(url: String) -> Result<Option<String>, String> {
+    let url = url.trim();
+    if url.is_empty() {
+        return Ok(None);
+    }
+
+    let host = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"));
+    let valid = host.is_some_and(|host| !host.is_empty() && !host.starts_with('/'))
+        && url.len() <= MAX_CANONICAL_URL_LENGTH
+        && !url.chars().any(char::is_whitespace);
+
+    if !valid {
+        return Err(format!(
+            "Canonical URL must be an absolute http(s) URL of at most {MAX_CANONICAL_URL_LENGTH} characters"
+        ));
+    }
+    Ok(Some(url.to_string()))
+}
+
+/// Keeps the response out of search engines with `X-Robots-Tag: noindex`
+pub fn forbid_indexing(resp: &mut HttpResponse) {
+    resp.headers_mut().insert(
+        HeaderName::from_static("x-robots-tag"),
+        HeaderValue::from_static("noindex"),
+    );
+}
+
+/// `None` when longer than `max` characters
+fn bounded(text: String, max: usize) -> Option<Option<String>> {
+    let text = text.trim();
+    if text.chars().count() > max {
+        return None;
+    }
+    Some(Some(text.to_string()).filter(|text| !text.is_empty()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_url_must_be_absolute_http() {
+        assert_eq!(
+            canonical_url(" https://dev.to/jane/post ".to_string()).unwrap(),
+            Some("https://dev.to/jane/post".to_string())
+        );
+        assert_eq!(canonical_url("  ".to_string()).unwrap(), None);
+        for bad in [
+            "/about",
+            "dev.to/jane",
+            "ftp://example.com/a",
+            "https://",
+            "https:///path",
+            "https://example.com/a b",
+        ] {
+            assert!(canonical_url(bad.to_string()).is_err(), "{bad:?} accepted");
+        }
+        let long = format!(
+            "https://example.com/{}",
+            "a".repeat(MAX_CANONICAL_URL_LENGTH)
+        );
+        assert!(canonical_url(long).is_err());
+    }
+
+    #[test]
+    fn test_text_is_trimmed_and_bounded() {
+        assert_eq!(
+            seo_title(" Hi ".to_string()).unwrap(),
+            Some("Hi".to_string())
+        );
+        assert_eq!(seo_description(" ".to_string()).unwrap(), None);
+        assert!(seo_title("t".repeat(MAX_SEO_TITLE_LENGTH + 1)).is_err());
+    }
+}
@@ -0,0 +1,98 @@
+//! SQL fragments that differ between database backends.
+//!
+//! The `*Postgres` adapters are written for Postgres. With the `sqlite`
+//! feature they also run on SQLite, so the few non-portable bits of their raw
+//! SQL go through here, keyed on the connection's backend.
+
+use sea_orm::sea_query::extension::postgres::PgExpr;
+use sea_orm::sea_query::{Expr, IntoColumnRef, SimpleExpr};
+use sea_orm::DatabaseBackend;
+
+/// The current time. On SQLite this is the RFC 3339 text sqlx stores chrono
+/// timestamps as, so it compares correctly with bound values.
+pub(crate) fn now(backend: DatabaseBackend) -> &'static str {
+    match backend {
+        DatabaseBackend::Sqlite => "strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')",
+        _ => "NOW()",
+    }
+}
+
+/// Case-insensitive `LIKE`. SQLite's `LIKE` already ignores ASCII case.
+pub(crate) fn ilike(backend: DatabaseBackend) -> &'static str {
+    match backend {
+        DatabaseBackend::Sqlite => "LIKE",
+        _ => "ILIKE",
+    }
+}
+
+/// `text` with the `LIKE` wildcards escaped, for patterns matched with
+/// `ESCAPE '\'`
+pub(crate) fn escape_like(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// [`ilike`] for query-builder conditions
+pub(crate) fn ilike_expr(
+    backend: DatabaseBackend,
+    column: impl IntoColumnRef,
+    pattern: &str,
+) -> SimpleExpr {
+    match backend {
+        DatabaseBackend::Sqlite => Expr::col(column).like(pattern),
+        _ => Expr::col(column).ilike(pattern),
+    }
+}
+
+/// Row locking for queue claims. SQLite has a single writer, so a claim
+/// inside a transaction can't race and needs no lock clause.
+pub(crate) fn skip_locked(backend: DatabaseBackend) -> &'static str {
+    match backend {
+        DatabaseBackend::Sqlite => "",
+        _ => "FOR UPDATE SKIP LOCKED",
+    }
+}
+
+/// `expr::ty` on Postgres, for casts to Postgres-only types (enums) that
+/// are plain text on SQLite
+pub(crate) fn pg_cast(backend: DatabaseBackend, expr: &str, ty: &str) -> String {
+    match backend {
+        DatabaseBackend::Postgres => format!("{expr}::{ty}"),
+        _ => expr.to_string(),
+    }
+}
+
+/// The UTC day of a timestamp column as `YYYY-MM-DD` text. SQLite stores
+/// the timestamps as RFC 3339 text in UTC, so the day is its first ten characters.
+pub(crate) fn utc_day(backend: DatabaseBackend, column: &str) -> String {
+    match backend {
+        DatabaseBackend::Sqlite => format!("substr({column}, 1, 10)"),
+        _ => format!("to_char({column} AT TIME ZONE 'UTC', 'YYYY-MM-DD')"),
+    }
+}
+
+/// Condition keeping rows whose owner, in `owner_column`, is a live account.
+/// Public reads add it so a soft-deleted or deactivated owner's content is
+/// gone from the query itself, not only behind the username lookup.
+pub(crate) fn owner_is_live(owner_column: &str) -> String {
+    format!(
+        "EXISTS (SELECT 1 FROM users u WHERE u.id = {owner_column} \
+         AND u.is_deleted = false AND u.is_active = true)"
+    )
+}
+
+/// `(table_name, column_name)` of every column of the current schema's tables
+pub(crate) fn table_columns(backend: DatabaseBackend) -> &'static str {
+    match backend {
+        DatabaseBackend::Sqlite => {
+            "SELECT m.name AS table_name, p.name AS column_name \
+             FROM sqlite_master AS m JOIN pragma_table_info(m.name) AS p \
+             WHERE m.type = 'table'"
+        }
+        _ => {
+            "SELECT table_name::text AS table_name, column_name::text AS column_name \
+             FROM information_schema.columns WHERE table_schema = current_schema()"
+        }
+    }
+}
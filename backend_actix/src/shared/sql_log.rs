@@ -0,0 +1,112 @@
+// src/shared/sql_log.rs
+use std::time::Duration;
+
+use sea_orm::metric::Info;
+use tracing::warn;
+
+/// Longest statement text put in a log event
+const MAX_STATEMENT_CHARS: usize = 2000;
+
+/// Warns about statements the database took at least `slow_after` to run.
+///
+/// Installed as the connection's metric callback, which runs in the task that
+/// sent the statement, so the warning carries the `request` span (request id,
+/// method, path) of the request it slowed down.
+#[derive(Debug, Clone, Copy)]
+pub struct SlowStatementLog {
+    slow_after: Duration,
+}
+
+impl SlowStatementLog {
+    pub fn new(slow_after: Duration) -> Self {
+        Self { slow_after }
+    }
+
+    pub fn record(&self, info: &Info<'_>) {
+        if info.elapsed < self.slow_after {
+            return;
+        }
+        warn!(
+            target: "sql",
+            statement = %sanitize(&info.statement.sql),
+            elapsed_ms = info.elapsed.as_millis() as u64,
+            failed = info.failed,
+            "Slow SQL statement"
+        );
+    }
+}
+
+/// Statement text fit for logs: string literals are replaced by `'?'` (bound
+/// values never appear, but raw SQL may inline some) and whitespace is
+/// collapsed.
+pub fn sanitize(sql: &str) -> String {
+    let mut out = String::with_capacity(sql.len().min(MAX_STATEMENT_CHARS));
+    let mut chars = sql.chars().peekable();
+    let mut last_space = true;
+
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            // `''` inside a literal is an escaped quote
+            loop {
+                match chars.next() {
+                    Some('\'') if chars.peek() == Some(&'\'') => {
+                        chars.next();
+                    }
+                    Some('\'') | None => break,
+                    Some(_) => {}
+                }
+            }
+            out.push_str("'?'");
+            last_space = false;
+        } else if c.is_whitespace() {
+            if !last_space {
+                out.push(' ');
+                last_space = true;
+            }
+        } else {
+            out.push(c);
+            last_space = false;
+        }
+    }
+
+    let out = out.trim_end();
+    match out.char_indices().nth(MAX_STATEMENT_CHARS) {
+        Some((cut, _)) => format!("{} …", &out[..cut]),
+        None => out.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_hides_string_literals() {
+        assert_eq!(
+            sanitize("SELECT id FROM users WHERE email = 'ann@example.com' AND id = $1"),
+            "SELECT id FROM users WHERE email = '?' AND id = $1"
+        );
+        assert_eq!(
+            sanitize("UPDATE pages SET title = 'It''s here' WHERE id = 3"),
+            "UPDATE pages SET title = '?' WHERE id = 3"
+        );
+    }
+
+    #[test]
+    fn test_sanitize_collapses_whitespace() {
+        assert_eq!(
+            sanitize("\n  SELECT id\n    FROM media\n   WHERE id IN ($1, $2)\n"),
+            "SELECT id FROM media WHERE id IN ($1, $2)"
+        );
+    }
+
+    #[test]
+    fn test_sanitize_truncates_long_statements() {
+        let sql = format!("SELECT {}", "a, ".repeat(1000));
+
+        let sanitized = sanitize(&sql);
+
+        assert!(sanitized.ends_with(" …"));
+        assert_eq!(sanitized.chars().count(), MAX_STATEMENT_CHARS + 2);
+    }
+}
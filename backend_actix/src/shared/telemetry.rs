@@ -0,0 +1,351 @@
+// src/shared/telemetry.rs
+//! Business events, logged under the `business_event` target with the same
+//! field names whichever module records them: `event`, `user_id`, the id of
+//! the item concerned (`page_id`, `media_id`), `reason` and `skipped`, the
+//! number of events of that kind left out since the last one logged.
+//!
+//! Each kind can be sampled (`EVENT_LOG_SAMPLE`, log 1 in N) and capped
+//! (`EVENT_LOG_MAX_PER_MINUTE`), so a burst of media failures can't drown
+//! the rest of the log.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{LazyLock, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// How log lines are written, from `LOG_FORMAT`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Human-readable lines
+    #[default]
+    Pretty,
+    /// One JSON object per line, for log collectors
+    Json,
+}
+
+impl LogFormat {
+    /// `LOG_FORMAT` from the environment; unset means JSON in production and
+    /// pretty elsewhere. Read before the configuration, like `RUST_LOG`.
+    pub fn from_env() -> Result<Self, String> {
+        match std::env::var("LOG_FORMAT") {
+            Ok(raw) if !raw.trim().is_empty() => raw.trim().parse(),
+            _ => Ok(Self::for_environment(
+                &std::env::var("RUST_ENV").unwrap_or_default(),
+            )),
+        }
+    }
+
+    /// Default of `RUST_ENV`
+    pub fn for_environment(rust_env: &str) -> Self {
+        if rust_env == "production" {
+            Self::Json
+        } else {
+            Self::Pretty
+        }
+    }
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "pretty" => Ok(Self::Pretty),
+            "json" => Ok(Self::Json),
+            _ => Err(format!(
+                "LOG_FORMAT: expected 'pretty' or 'json', got '{s}'"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum EventKind {
+    UserRegistered,
+    PagePublished,
+    MediaFailed,
+}
+
+impl EventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventKind::UserRegistered => "user_registered",
+            EventKind::PagePublished => "page_published",
+            EventKind::MediaFailed => "media_failed",
+        }
+    }
+}
+
+impl fmt::Display for EventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for EventKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "user_registered" => Ok(EventKind::UserRegistered),
+            "page_published" => Ok(EventKind::PagePublished),
+            "media_failed" => Ok(EventKind::MediaFailed),
+            _ => Err(format!(
+                "expected 'user_registered', 'page_published' or 'media_failed', got '{s}'"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BusinessEvent {
+    UserRegistered {
+        user_id: Uuid,
+    },
+    PagePublished {
+        user_id: Uuid,
+        page_id: Uuid,
+    },
+    MediaFailed {
+        user_id: Uuid,
+        media_id: Uuid,
+        /// `MediaFailure` code, e.g. `PROCESSING_TIMEOUT` or the processor's
+        /// own once its retries ran out; `None` when a manifest settled it
+        reason: Option<&'static str>,
+    },
+}
+
+impl BusinessEvent {
+    pub fn kind(&self) -> EventKind {
+        match self {
+            BusinessEvent::UserRegistered { .. } => EventKind::UserRegistered,
+            BusinessEvent::PagePublished { .. } => EventKind::PagePublished,
+            BusinessEvent::MediaFailed { .. } => EventKind::MediaFailed,
+        }
+    }
+}
+
+/// Sampling and cap of one event kind. The default logs every event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventPolicy {
+    /// Log 1 in this many; 0 and 1 log all
+    pub sample_every: u32,
+    /// Events logged per minute at most; 0 turns the cap off
+    pub max_per_minute: u32,
+}
+
+impl Default for EventPolicy {
+    fn default() -> Self {
+        Self {
+            sample_every: 1,
+            max_per_minute: 0,
+        }
+    }
+}
+
+/// Policies by kind, from `EVENT_LOG_SAMPLE` and `EVENT_LOG_MAX_PER_MINUTE`
+pub type EventPolicies = BTreeMap<EventKind, EventPolicy>;
+
+/// `kind=number` pairs, comma-separated, e.g. `media_failed=10,user_registered=1`
+pub fn parse_event_settings(raw: &str) -> Result<Vec<(EventKind, u32)>, String> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (kind, value) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("expected 'event=number', got '{pair}'"))?;
+            let value = value
+                .trim()
+                .parse()
+                .map_err(|_| format!("expected a number for '{}'", kind.trim()))?;
+            Ok((kind.trim().parse()?, value))
+        })
+        .collect()
+}
+
+const WINDOW: Duration = Duration::from_secs(60);
+
+#[derive(Debug)]
+struct Counter {
+    seen: u64,
+    window_start: Instant,
+    logged_in_window: u32,
+    skipped: u64,
+}
+
+/// Decides which events are logged. One process-wide instance backs
+/// [`record`]; the policies are set once at startup.
+#[derive(Debug, Default)]
+pub struct EventLog {
+    policies: OnceLock<EventPolicies>,
+    counters: Mutex<HashMap<EventKind, Counter>>,
+}
+
+impl EventLog {
+    /// Called once from `main`; later calls are ignored.
+    pub fn set_policies(&self, policies: EventPolicies) {
+        let _ = self.policies.set(policies);
+    }
+
+    fn policy(&self, kind: EventKind) -> EventPolicy {
+        self.policies
+            .get()
+            .and_then(|policies| policies.get(&kind).copied())
+            .unwrap_or_default()
+    }
+
+    /// `Some(skipped)` when an event of `kind` seen at `now` is to be logged
+    pub fn admit(&self, kind: EventKind, now: Instant) -> Option<u64> {
+        let policy = self.policy(kind);
+        let mut counters = self.counters.lock().unwrap_or_else(|e| e.into_inner());
+        let counter = counters.entry(kind).or_insert(Counter {
+            seen: 0,
+            window_start: now,
+            logged_in_window: 0,
+            skipped: 0,
+        });
+
+        counter.seen += 1;
+        if !(counter.seen - 1).is_multiple_of(u64::from(policy.sample_every.max(1))) {
+            counter.skipped += 1;
+            return None;
+        }
+
+        if now.duration_since(counter.window_start) >= WINDOW {
+            counter.window_start = now;
+            counter.logged_in_window = 0;
+        }
+        if policy.max_per_minute > 0 && counter.logged_in_window >= policy.max_per_minute {
+            counter.skipped += 1;
+            return None;
+        }
+
+        counter.logged_in_window += 1;
+        Some(std::mem::take(&mut counter.skipped))
+    }
+
+    pub fn record(&self, event: BusinessEvent) {
+        let Some(skipped) = self.admit(event.kind(), Instant::now()) else {
+            return;
+        };
+        let kind = event.kind().as_str();
+
+        match event {
+            BusinessEvent::UserRegistered { user_id } => info!(
+                target: "business_event",
+                event = kind,
+                user_id = %user_id,
+                skipped,
+                "User registered"
+            ),
+            BusinessEvent::PagePublished { user_id, page_id } => info!(
+                target: "business_event",
+                event = kind,
+                user_id = %user_id,
+                page_id = %page_id,
+                skipped,
+                "Page published"
+            ),
+            BusinessEvent::MediaFailed {
+                user_id,
+                media_id,
+                reason,
+            } => info!(
+                target: "business_event",
+                event = kind,
+                user_id = %user_id,
+                media_id = %media_id,
+                reason,
+                skipped,
+                "Media failed"
+            ),
+        }
+    }
+}
+
+static EVENT_LOG: LazyLock<EventLog> = LazyLock::new(EventLog::default);
+
+/// The process-wide event log
+pub fn event_log() -> &'static EventLog {
+    &EVENT_LOG
+}
+
+/// Log `event` unless its sampling or cap leaves it out
+pub fn record(event: BusinessEvent) {
+    event_log().record(event);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log_with(kind: EventKind, policy: EventPolicy) -> EventLog {
+        let log = EventLog::default();
+        log.set_policies(BTreeMap::from([(kind, policy)]));
+        log
+    }
+
+    #[test]
+    fn test_sampling_logs_one_in_n_and_counts_the_rest() {
+        let log = log_with(
+            EventKind::MediaFailed,
+            EventPolicy {
+                sample_every: 3,
+                max_per_minute: 0,
+            },
+        );
+        let now = Instant::now();
+
+        let admitted: Vec<_> = (0..7)
+            .map(|_| log.admit(EventKind::MediaFailed, now))
+            .collect();
+
+        assert_eq!(
+            admitted,
+            vec![Some(0), None, None, Some(2), None, None, Some(2)]
+        );
+        // Other kinds keep the default
+        assert_eq!(log.admit(EventKind::UserRegistered, now), Some(0));
+    }
+
+    #[test]
+    fn test_cap_resets_each_minute() {
+        let log = log_with(
+            EventKind::MediaFailed,
+            EventPolicy {
+                sample_every: 1,
+                max_per_minute: 2,
+            },
+        );
+        let start = Instant::now();
+
+        assert_eq!(log.admit(EventKind::MediaFailed, start), Some(0));
+        assert_eq!(log.admit(EventKind::MediaFailed, start), Some(0));
+        assert_eq!(log.admit(EventKind::MediaFailed, start), None);
+        assert_eq!(log.admit(EventKind::MediaFailed, start), None);
+        assert_eq!(log.admit(EventKind::MediaFailed, start + WINDOW), Some(2));
+    }
+
+    #[test]
+    fn test_parse_event_settings() {
+        assert_eq!(
+            parse_event_settings(" media_failed=10 , user_registered=1,").unwrap(),
+            vec![(EventKind::MediaFailed, 10), (EventKind::UserRegistered, 1)]
+        );
+        for bad in ["media_failed", "post_published=2", "media_failed=often"] {
+            assert!(parse_event_settings(bad).is_err(), "{bad:?} accepted");
+        }
+        assert_eq!("JSON".parse::<LogFormat>(), Ok(LogFormat::Json));
+        assert!("xml".parse::<LogFormat>().is_err());
+        assert_eq!(LogFormat::for_environment("production"), LogFormat::Json);
+        assert_eq!(LogFormat::for_environment("development"), LogFormat::Pretty);
+    }
+}
@@ -0,0 +1,347 @@
+//! Several writes committed together or not at all.
+//!
+//! A service that writes through more than one use case or repository runs
+//! them in [`UnitOfWork::run`]. With [`UnitOfWorkPostgres`] that opens a
+//! transaction for the task, and the `*Postgres` adapters that write through
+//! [`connection`] join it instead of committing on their own. Outside a unit
+//! of work they write straight to the pool as before. A unit of work run
+//! inside another becomes a savepoint.
+
+use async_trait::async_trait;
+use sea_orm::{
+    AccessMode, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, DbErr,
+    ExecResult, IsolationLevel, QueryResult, Statement, TransactionError, TransactionTrait,
+};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+tokio::task_local! {
+    static CURRENT: Arc<DatabaseTransaction>;
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum UnitOfWorkError {
+    #[error("Failed to begin the transaction: {0}")]
+    Begin(String),
+
+    #[error("Failed to commit the transaction: {0}")]
+    Commit(String),
+}
+
+#[async_trait]
+pub trait UnitOfWork: Send + Sync {
+    /// Runs `work`, keeping its writes only if it returns `Ok`
+    async fn run<F, T, E>(&self, work: F) -> Result<T, E>
+    where
+        F: Future<Output = Result<T, E>> + Send,
+        T: Send,
+        E: From<UnitOfWorkError> + Send;
+}
+
+/// Runs the work in a database transaction
+#[derive(Clone)]
+pub struct UnitOfWorkPostgres {
+    db: Arc<DatabaseConnection>,
+}
+
+impl UnitOfWorkPostgres {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for UnitOfWorkPostgres {
+    async fn run<F, T, E>(&self, work: F) -> Result<T, E>
+    where
+        F: Future<Output = Result<T, E>> + Send,
+        T: Send,
+        E: From<UnitOfWorkError> + Send,
+    {
+        let txn = connection(&self.db)
+            .begin()
+            .await
+            .map_err(|e| UnitOfWorkError::Begin(e.to_string()))?;
+        let txn = Arc::new(txn);
+
+        let result = CURRENT.scope(Arc::clone(&txn), work).await;
+
+        // Adapters only hold the transaction for the length of a call
+        let Ok(txn) = Arc::try_unwrap(txn) else {
+            return Err(UnitOfWorkError::Commit("transaction still in use".to_string()).into());
+        };
+        match result {
+            Ok(value) => {
+                txn.commit()
+                    .await
+                    .map_err(|e| UnitOfWorkError::Commit(e.to_string()))?;
+                Ok(value)
+            }
+            Err(e) => {
+                // Dropping it rolls back too; this just doesn't wait for it
+                let _ = txn.rollback().await;
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Runs the work as it is. The in-memory adapters have no transactions, so
+/// writes made before a failure stay.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryUnitOfWork;
+
+#[async_trait]
+impl UnitOfWork for InMemoryUnitOfWork {
+    async fn run<F, T, E>(&self, work: F) -> Result<T, E>
+    where
+        F: Future<Output = Result<T, E>> + Send,
+        T: Send,
+        E: From<UnitOfWorkError> + Send,
+    {
+        work.await
+    }
+}
+
+/// The transaction of the unit of work this task is running, or else `db`
+pub fn connection(db: &DatabaseConnection) -> Connection<'_> {
+    match CURRENT.try_with(Arc::clone) {
+        Ok(txn) => Connection::Transaction(txn),
+        Err(_) => Connection::Database(db),
+    }
+}
+
+/// What an adapter writes through: the pool, or the open unit of work.
+/// Beginning a transaction on it starts a savepoint inside the latter.
+pub enum Connection<'a> {
+    Database(&'a DatabaseConnection),
+    Transaction(Arc<DatabaseTransaction>),
+}
+
+impl Connection<'_> {
+    fn get(&self) -> &(dyn ConnectionTrait + Send + Sync) {
+        match self {
+            Connection::Database(db) => *db,
+            Connection::Transaction(txn) => txn.as_ref(),
+        }
+    }
+}
+
+#[async_trait]
+impl<'a> ConnectionTrait for Connection<'a> {
+    fn get_database_backend(&self) -> DbBackend {
+        self.get().get_database_backend()
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.get().execute(stmt).await
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.get().execute_unprepared(sql).await
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.get().query_one(stmt).await
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.get().query_all(stmt).await
+    }
+
+    fn support_returning(&self) -> bool {
+        self.get().support_returning()
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.get().is_mock_connection()
+    }
+}
+
+#[async_trait]
+impl<'a> TransactionTrait for Connection<'a> {
+    async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        match self {
+            Connection::Database(db) => db.begin().await,
+            Connection::Transaction(txn) => txn.begin().await,
+        }
+    }
+
+    async fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        match self {
+            Connection::Database(db) => db.begin_with_config(isolation_level, access_mode).await,
+            Connection::Transaction(txn) => {
+                txn.begin_with_config(isolation_level, access_mode).await
+            }
+        }
+    }
+
+    async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        match self {
+            Connection::Database(db) => db.transaction(callback).await,
+            Connection::Transaction(txn) => txn.transaction(callback).await,
+        }
+    }
+
+    async fn transaction_with_config<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        match self {
+            Connection::Database(db) => {
+                db.transaction_with_config(callback, isolation_level, access_mode)
+                    .await
+            }
+            Connection::Transaction(txn) => {
+                txn.transaction_with_config(callback, isolation_level, access_mode)
+                    .await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{MockDatabase, MockExecResult};
+
+    #[derive(Debug, PartialEq)]
+    struct Failed(String);
+
+    impl From<UnitOfWorkError> for Failed {
+        fn from(e: UnitOfWorkError) -> Self {
+            Failed(e.to_string())
+        }
+    }
+
+    impl From<DbErr> for Failed {
+        fn from(e: DbErr) -> Self {
+            Failed(e.to_string())
+        }
+    }
+
+    fn insert(table: &str) -> Statement {
+        Statement::from_string(
+            DbBackend::Postgres,
+            format!("INSERT INTO {table} DEFAULT VALUES"),
+        )
+    }
+
+    fn mock_db(writes: usize) -> Arc<DatabaseConnection> {
+        let results = (0..writes).map(|_| MockExecResult {
+            last_insert_id: 0,
+            rows_affected: 1,
+        });
+        Arc::new(
+            MockDatabase::new(DbBackend::Postgres)
+                .append_exec_results(results)
+                .into_connection(),
+        )
+    }
+
+    fn log(db: Arc<DatabaseConnection>) -> String {
+        let log = Arc::try_unwrap(db)
+            .expect("no other connection handles")
+            .into_transaction_log();
+        format!("{log:?}")
+    }
+
+    #[tokio::test]
+    async fn test_writes_join_the_unit_of_work() {
+        let db = mock_db(2);
+        let uow = UnitOfWorkPostgres::new(Arc::clone(&db));
+
+        uow.run(async {
+            connection(&db).execute(insert("projects")).await?;
+            connection(&db).execute(insert("project_topics")).await?;
+            Ok::<_, Failed>(())
+        })
+        .await
+        .unwrap();
+        drop(uow);
+
+        let log = log(db);
+        let begin = log.find("BEGIN").unwrap();
+        let project = log.find("INSERT INTO projects").unwrap();
+        let link = log.find("INSERT INTO project_topics").unwrap();
+        let commit = log.find("COMMIT").unwrap();
+        assert!(begin < project && project < link && link < commit);
+        assert!(!log.contains("ROLLBACK"));
+    }
+
+    #[tokio::test]
+    async fn test_failed_work_is_rolled_back() {
+        let db = mock_db(1);
+        let uow = UnitOfWorkPostgres::new(Arc::clone(&db));
+
+        let result: Result<(), Failed> = uow
+            .run(async {
+                connection(&db).execute(insert("projects")).await?;
+                Err(Failed("topic link failed".to_string()))
+            })
+            .await;
+        drop(uow);
+
+        assert_eq!(result, Err(Failed("topic link failed".to_string())));
+        let log = log(db);
+        assert!(log.contains("INSERT INTO projects"));
+        assert!(log.contains("ROLLBACK"));
+        assert!(!log.contains("COMMIT"));
+    }
+
+    #[tokio::test]
+    async fn test_nested_unit_of_work_is_a_savepoint() {
+        let db = mock_db(1);
+        let uow = UnitOfWorkPostgres::new(Arc::clone(&db));
+
+        uow.run(async {
+            uow.run(async {
+                connection(&db).execute(insert("topics")).await?;
+                Ok::<_, Failed>(())
+            })
+            .await
+        })
+        .await
+        .unwrap();
+        drop(uow);
+
+        let log = log(db);
+        assert!(log.contains("RELEASE SAVEPOINT savepoint_1"));
+        assert_eq!(log.matches("BEGIN").count(), 1);
+        assert!(log.contains("COMMIT"));
+    }
+
+    #[tokio::test]
+    async fn test_outside_a_unit_of_work_writes_go_to_the_pool() {
+        let db = mock_db(1);
+
+        connection(&db).execute(insert("projects")).await.unwrap();
+
+        let log = log(db);
+        assert!(log.contains("INSERT INTO projects"));
+        assert!(!log.contains("BEGIN"));
+    }
+}
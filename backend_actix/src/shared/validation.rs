@@ -0,0 +1,115 @@
+// src/shared/validation.rs
+//! Field-level validation results. Use cases collect every problem with their
+//! input instead of stopping at the first, so a form can mark all invalid
+//! fields at once; `ApiResponse::validation_failed` turns them into a 400.
+
+use serde::Serialize;
+use std::fmt;
+use utoipa::ToSchema;
+
+/// One invalid input field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct FieldError {
+    /// Name of the field as sent by the client; `body` when the whole body is unusable
+    #[schema(example = "username")]
+    pub field: String,
+    /// Machine-readable reason
+    #[schema(example = "INVALID_USERNAME")]
+    pub code: String,
+    #[schema(example = "Username must be 3-50 characters")]
+    pub message: String,
+}
+
+/// Every invalid field of one input, in the order they were checked
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationErrors(Vec<FieldError>);
+
+impl ValidationErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A single problem, for inputs with only one field
+    pub fn single(field: &str, code: &str, message: impl Into<String>) -> Self {
+        let mut errors = Self::new();
+        errors.add(field, code, message);
+        errors
+    }
+
+    pub fn add(&mut self, field: &str, code: &str, message: impl Into<String>) {
+        self.0.push(FieldError {
+            field: field.to_string(),
+            code: code.to_string(),
+            message: message.into(),
+        });
+    }
+
+    /// Record the problem of a check that failed, keeping its value otherwise
+    pub fn check<T>(&mut self, field: &str, code: &str, result: Result<T, String>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(message) => {
+                self.add(field, code, message);
+                None
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn fields(&self) -> &[FieldError] {
+        &self.0
+    }
+
+    /// Whether `field` has a problem
+    pub fn has(&self, field: &str) -> bool {
+        self.0.iter().any(|e| e.field == field)
+    }
+
+    /// `Err(self)` if any field failed
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = self
+            .0
+            .iter()
+            .map(|e| format!("{}: {}", e.field, e.message))
+            .collect();
+        f.write_str(&parts.join("; "))
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collects_every_failed_check() {
+        let mut errors = ValidationErrors::new();
+        let name = errors.check("name", "INVALID_NAME", Ok::<_, String>("Ada"));
+        let age = errors.check::<u8>("age", "INVALID_AGE", Err("must be a number".to_string()));
+        errors.add("email", "INVALID_EMAIL", "Invalid email format");
+
+        assert_eq!(name, Some("Ada"));
+        assert_eq!(age, None);
+        assert!(errors.has("age") && errors.has("email") && !errors.has("name"));
+        assert_eq!(
+            errors.to_string(),
+            "age: must be a number; email: Invalid email format"
+        );
+        assert!(errors.into_result().is_err());
+        assert!(ValidationErrors::new().into_result().is_ok());
+    }
+}
@@ -0,0 +1,343 @@
+// src/shared/zip.rs
+//! Minimal streaming ZIP writer: stored (uncompressed) entries with data
+//! descriptors, so an entry can be written in pieces and its bytes handed
+//! out before the archive is complete. Only the central directory (one
+//! small record per entry) stays in memory. No ZIP64, so an archive is
+//! limited to 65534 entries and 4 GiB; going past either is an error rather
+//! than a corrupt archive.
+
+use chrono::{DateTime, Datelike, Timelike, Utc};
+
+const LOCAL_HEADER: u32 = 0x0403_4b50;
+const DATA_DESCRIPTOR: u32 = 0x0807_4b50;
+const CENTRAL_HEADER: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x0605_4b50;
+/// 2.0, the lowest version that knows data descriptors
+const VERSION: u16 = 20;
+/// Sizes and CRC follow the data (bit 3); names are UTF-8 (bit 11)
+const FLAGS: u16 = 0x0808;
+/// 0xFFFF and 0xFFFFFFFF would tell readers to look for ZIP64 records
+const MAX_ENTRIES: usize = u16::MAX as usize - 1;
+const MAX_OFFSET: u64 = u32::MAX as u64 - 1;
+const LOCAL_HEADER_LEN: usize = 30;
+const DATA_DESCRIPTOR_LEN: usize = 16;
+const CENTRAL_HEADER_LEN: usize = 46;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ZipLimitError {
+    #[error("more than {MAX_ENTRIES} entries")]
+    TooManyEntries,
+    #[error("an entry name is longer than 65535 bytes")]
+    NameTooLong,
+    #[error("larger than 4 GiB")]
+    TooLarge,
+}
+
+struct Entry {
+    name: String,
+    offset: u32,
+    crc: u32,
+    size: u32,
+}
+
+pub struct ZipStream {
+    out: Vec<u8>,
+    written: u64,
+    entries: Vec<Entry>,
+    open: Option<Entry>,
+    crc: Crc32,
+    time: u16,
+    date: u16,
+}
+
+impl ZipStream {
+    /// Every entry gets `modified` as its timestamp
+    pub fn new(modified: DateTime<Utc>) -> Self {
+        let (time, date) = dos_datetime(modified);
+        Self {
+            out: Vec::new(),
+            written: 0,
+            entries: Vec::new(),
+            open: None,
+            crc: Crc32::new(),
+            time,
+            date,
+        }
+    }
+
+    /// Starts an entry, finishing the previous one
+    pub fn start_file(&mut self, name: &str) -> Result<(), ZipLimitError> {
+        self.finish_file()?;
+        if self.entries.len() >= MAX_ENTRIES {
+            return Err(ZipLimitError::TooManyEntries);
+        }
+        if name.len() > usize::from(u16::MAX) {
+            return Err(ZipLimitError::NameTooLong);
+        }
+        self.reserve(LOCAL_HEADER_LEN + name.len())?;
+
+        let offset = self.written as u32;
+        self.put_u32(LOCAL_HEADER);
+        self.put_u16(VERSION);
+        self.put_u16(FLAGS);
+        self.put_u16(0); // stored
+        self.put_u16(self.time);
+        self.put_u16(self.date);
+        self.put_u32(0); // CRC, size and compressed size are in the descriptor
+        self.put_u32(0);
+        self.put_u32(0);
+        self.put_u16(name.len() as u16);
+        self.put_u16(0); // extra field
+        self.put(name.as_bytes());
+
+        self.crc = Crc32::new();
+        self.open = Some(Entry {
+            name: name.to_string(),
+            offset,
+            crc: 0,
+            size: 0,
+        });
+        Ok(())
+    }
+
+    /// Appends to the open entry
+    pub fn write(&mut self, data: &[u8]) -> Result<(), ZipLimitError> {
+        self.reserve(data.len())?;
+        let entry = self
+            .open
+            .as_mut()
+            .expect("start_file must be called before write");
+        // The entry is part of the archive, so it fits too
+        entry.size += data.len() as u32;
+        self.crc.update(data);
+        self.put(data);
+        Ok(())
+    }
+
+    /// Writes the open entry's data descriptor, if any
+    pub fn finish_file(&mut self) -> Result<(), ZipLimitError> {
+        if self.open.is_some() {
+            self.reserve(DATA_DESCRIPTOR_LEN)?;
+        }
+        let Some(mut entry) = self.open.take() else {
+            return Ok(());
+        };
+        entry.crc = self.crc.finish();
+        self.put_u32(DATA_DESCRIPTOR);
+        self.put_u32(entry.crc);
+        self.put_u32(entry.size);
+        self.put_u32(entry.size);
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    /// Bytes produced since the last call
+    pub fn take_output(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.out)
+    }
+
+    /// Writes the central directory and returns the remaining bytes. Nothing
+    /// may be written afterwards, nor after an error: the archive can't be
+    /// completed then.
+    pub fn finish(&mut self) -> Result<Vec<u8>, ZipLimitError> {
+        self.finish_file()?;
+        let directory_size: usize = self
+            .entries
+            .iter()
+            .map(|entry| CENTRAL_HEADER_LEN + entry.name.len())
+            .sum();
+        if directory_size as u64 > MAX_OFFSET {
+            return Err(ZipLimitError::TooLarge);
+        }
+
+        let directory_offset = self.written as u32;
+        let entries = std::mem::take(&mut self.entries);
+        for entry in &entries {
+            self.put_u32(CENTRAL_HEADER);
+            self.put_u16(VERSION); // made by
+            self.put_u16(VERSION); // needed
+            self.put_u16(FLAGS);
+            self.put_u16(0);
+            self.put_u16(self.time);
+            self.put_u16(self.date);
+            self.put_u32(entry.crc);
+            self.put_u32(entry.size);
+            self.put_u32(entry.size);
+            self.put_u16(entry.name.len() as u16);
+            self.put_u16(0); // extra field
+            self.put_u16(0); // comment
+            self.put_u16(0); // disk
+            self.put_u16(0); // internal attributes
+            self.put_u32(0); // external attributes
+            self.put_u32(entry.offset);
+            self.put(entry.name.as_bytes());
+        }
+
+        self.put_u32(END_OF_CENTRAL_DIRECTORY);
+        self.put_u16(0); // this disk
+        self.put_u16(0); // directory disk
+        self.put_u16(entries.len() as u16);
+        self.put_u16(entries.len() as u16);
+        self.put_u32(directory_size as u32);
+        self.put_u32(directory_offset);
+        self.put_u16(0); // comment
+
+        Ok(self.take_output())
+    }
+
+    /// Fails when `len` more bytes would put offsets past what the format
+    /// holds
+    fn reserve(&self, len: usize) -> Result<(), ZipLimitError> {
+        if self.written + len as u64 > MAX_OFFSET {
+            return Err(ZipLimitError::TooLarge);
+        }
+        Ok(())
+    }
+
+    fn put(&mut self, bytes: &[u8]) {
+        self.out.extend_from_slice(bytes);
+        self.written += bytes.len() as u64;
+    }
+
+    fn put_u16(&mut self, value: u16) {
+        self.put(&value.to_le_bytes());
+    }
+
+    fn put_u32(&mut self, value: u32) {
+        self.put(&value.to_le_bytes());
+    }
+}
+
+/// MS-DOS time and date; years before 1980 can't be represented
+fn dos_datetime(at: DateTime<Utc>) -> (u16, u16) {
+    let time = (at.hour() << 11) | (at.minute() << 5) | (at.second() / 2);
+    let year = at.year().clamp(1980, 2107) as u32 - 1980;
+    let date = (year << 9) | (at.month() << 5) | at.day();
+    (time as u16, date as u16)
+}
+
+/// CRC-32 (IEEE), as ZIP uses it
+struct Crc32 {
+    value: u32,
+}
+
+impl Crc32 {
+    fn new() -> Self {
+        Self { value: 0xFFFF_FFFF }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        for byte in data {
+            let mut crc = self.value ^ u32::from(*byte);
+            for _ in 0..8 {
+                let mask = (crc & 1).wrapping_neg();
+                crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+            }
+            self.value = crc;
+        }
+    }
+
+    fn finish(&self) -> u32 {
+        !self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn u16_at(bytes: &[u8], at: usize) -> u16 {
+        u16::from_le_bytes([bytes[at], bytes[at + 1]])
+    }
+
+    fn u32_at(bytes: &[u8], at: usize) -> u32 {
+        u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+    }
+
+    #[test]
+    fn test_crc32_check_value() {
+        let mut crc = Crc32::new();
+        crc.update(b"1234");
+        crc.update(b"56789");
+        assert_eq!(crc.finish(), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_dos_datetime() {
+        let at = Utc.with_ymd_and_hms(2026, 10, 16, 13, 45, 31).unwrap();
+        let (time, date) = dos_datetime(at);
+        assert_eq!(time, (13 << 11) | (45 << 5) | 15);
+        assert_eq!(date, (46 << 9) | (10 << 5) | 16);
+    }
+
+    #[test]
+    fn test_archive_layout() {
+        let mut zip = ZipStream::new(Utc::now());
+        zip.start_file("a.md").unwrap();
+        zip.write(b"hello ").unwrap();
+        let mut archive = zip.take_output();
+        zip.write(b"world").unwrap();
+        zip.start_file("b/c.json").unwrap();
+        zip.write(b"[]").unwrap();
+        archive.extend(zip.take_output());
+        archive.extend(zip.finish().unwrap());
+
+        // First local header, then its data right after the name
+        assert_eq!(u32_at(&archive, 0), LOCAL_HEADER);
+        assert_eq!(u16_at(&archive, 26), 4);
+        assert_eq!(&archive[30..34], b"a.md");
+        assert_eq!(&archive[34..45], b"hello world");
+        assert_eq!(u32_at(&archive, 45), DATA_DESCRIPTOR);
+        assert_eq!(u32_at(&archive, 53), 11);
+
+        // End record points at a directory of both entries
+        let end = archive.len() - 22;
+        assert_eq!(u32_at(&archive, end), END_OF_CENTRAL_DIRECTORY);
+        assert_eq!(u16_at(&archive, end + 10), 2);
+        let directory = u32_at(&archive, end + 16) as usize;
+        assert_eq!(u32_at(&archive, end + 12) as usize, end - directory);
+        assert_eq!(u32_at(&archive, directory), CENTRAL_HEADER);
+        assert_eq!(u32_at(&archive, directory + 42), 0);
+
+        let mut crc = Crc32::new();
+        crc.update(b"hello world");
+        assert_eq!(u32_at(&archive, directory + 16), crc.finish());
+    }
+
+    #[test]
+    fn test_entry_count_is_capped() {
+        let mut zip = ZipStream::new(Utc::now());
+        for i in 0..MAX_ENTRIES {
+            zip.start_file(&i.to_string()).unwrap();
+        }
+
+        assert_eq!(
+            zip.start_file("one-more"),
+            Err(ZipLimitError::TooManyEntries)
+        );
+        let archive = zip.finish().unwrap();
+        let end = archive.len() - 22;
+        assert_eq!(u16_at(&archive, end + 10) as usize, MAX_ENTRIES);
+    }
+
+    #[test]
+    fn test_size_is_capped() {
+        let mut zip = ZipStream::new(Utc::now());
+        zip.start_file("big.bin").unwrap();
+        // As if nearly 4 GiB had been streamed already
+        zip.written = MAX_OFFSET - 10;
+
+        assert_eq!(zip.write(&[0; 11]), Err(ZipLimitError::TooLarge));
+        assert_eq!(zip.start_file("next.bin"), Err(ZipLimitError::TooLarge));
+        assert_eq!(zip.finish(), Err(ZipLimitError::TooLarge));
+    }
+
+    #[test]
+    fn test_long_name_is_refused() {
+        let mut zip = ZipStream::new(Utc::now());
+        let name = "a".repeat(usize::from(u16::MAX) + 1);
+
+        assert_eq!(zip.start_file(&name), Err(ZipLimitError::NameTooLong));
+    }
+}
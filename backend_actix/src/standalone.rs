@@ -0,0 +1,409 @@
+//! `RUN_MODE=standalone`: the full API over in-memory adapters, for demos and
+//! frontend work without Postgres, Redis, GCS or SMTP. Everything is lost on
+//! restart.
+//!
+//! Differences from a standard run:
+//! - emails are logged instead of sent, so verification links show up in the log
+//! - upload and read URLs use a `memory://` scheme nothing serves, and media
+//!   never leaves `pending` because no image processor runs. With
+//!   `LOCAL_STORAGE_DIR` they point at an image processor in
+//!   `PROCESSOR_MODE=local` instead, whose completion callback settles the media
+//! - webhook endpoints can be managed, but no deliveries are queued
+//! - the activity feed stays empty; it is recorded by database triggers
+//! - `/health/ready` is not served; there is nothing to be ready for
+#![cfg(not(tarpaulin_include))]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use actix_web::web;
+use tracing::warn;
+
+use crate::activity::adapter::outgoing::InMemoryActivityLog;
+use crate::activity::application::services::ListActivitiesService;
+use crate::admin::adapter::outgoing::{
+    InMemoryIntegrityQuery, InMemoryQuickSearchQuery, InMemoryStatsQuery,
+    InMemoryStorageReportQuery,
+};
+use crate::admin::application::services::{
+    CheckIntegrityService, GetAdminStatsService, GetStorageReportService, QuickSearchService,
+};
+use crate::analytics::adapter::outgoing::InMemoryPageViewStore;
+use crate::analytics::application::analytics_use_cases::AnalyticsUseCases;
+use crate::analytics::application::domain::visitor_id::VisitorHasher;
+use crate::analytics::application::services::{PageViewBuffer, PageViewFlusher};
+use crate::auth::adapter::outgoing::in_memory::{
+    InMemoryConsumedTokens, InMemoryPasskeyChallenges, InMemoryPasskeyStore,
+    InMemoryTokenRepository, InMemoryUserStore,
+};
+use crate::auth::adapter::outgoing::jwt::JwtTokenService;
+use crate::auth::adapter::outgoing::security::argon2_hasher::Argon2Hasher;
+use crate::auth::application::auth_use_cases::AuthUseCases;
+use crate::auth::application::passkey_use_cases::PasskeyUseCases;
+use crate::auth::application::ports::outgoing::token_provider::TokenProvider;
+use crate::batch::application::services::{BatchTargets, RunBatchService};
+use crate::blocklist::adapter::outgoing::InMemoryBlockRuleStore;
+use crate::blocklist::application::blocklist_use_cases::BlocklistUseCases;
+use crate::config::{reload::ConfigReloader, AppConfig};
+use crate::contact::adapter::outgoing::InMemoryContactStore;
+use crate::contact::application::contact_use_cases::ContactUseCases;
+use crate::cv::adapter::outgoing::{InMemoryCvStore, PlainTextCvRenderer};
+use crate::cv::application::cv_use_cases::CvUseCases;
+use crate::email::adapter::outgoing::in_memory::InMemoryEmailOutbox;
+use crate::email::adapter::outgoing::log_sender::LogEmailSender;
+use crate::email::application::domain::unsubscribe_token::UnsubscribeTokens;
+use crate::email::application::email_use_cases::EmailUseCases;
+use crate::email::application::services::{DispatchPolicy, OutboxDispatcher, UserEmailService};
+use crate::export::adapter::outgoing::InMemoryExportSource;
+use crate::export::application::services::ExportContentService;
+use crate::import::adapter::outgoing::InMemorySlugLookup;
+use crate::import::application::services::{ImportContentService, ImportTargets};
+use crate::modules::auth::application::helpers::{TokenVersionGuard, UserIdentityResolver};
+use crate::multimedia::adapter::outgoing::cloud_storage::{
+    HttpMediaTransfer, InMemoryStorage, LocalStorageQuery,
+};
+use crate::multimedia::adapter::outgoing::db::{InMemoryMediaStore, InMemoryStorageOverrides};
+use crate::multimedia::adapter::outgoing::processor::UnconfiguredMediaProcessor;
+use crate::multimedia::application::domain::policies::upload_policy::UploadPolicy;
+use crate::multimedia::application::media_use_cases::MultimediaUseCases;
+use crate::multimedia::application::ports::incoming::services::{
+    IngestMediaManifestService, MediaRetrier, ProxyImageService, RetryFailedMediaService,
+};
+use crate::multimedia::application::ports::outgoing::cloud_storage::StorageQuery;
+use crate::pages::adapter::outgoing::InMemoryPageStore;
+use crate::pages::application::domain::preview_token::PreviewTokens;
+use crate::pages::application::page_use_cases::PageUseCases;
+use crate::pages::application::services::{AutosaveBuffer, AutosaveFlusher};
+use crate::project::adapter::outgoing::{GitHubRepoMetadataProvider, InMemoryProjectStore};
+use crate::project::application::ports::incoming::use_cases::SyncProjectReadmeUseCase;
+use crate::project::application::project_use_cases::ProjectUseCases;
+use crate::project::application::service::{ReadmeSyncer, SyncProjectReadmeService};
+use crate::redirects::adapter::outgoing::InMemoryRedirectStore;
+use crate::redirects::application::redirect_use_cases::RedirectUseCases;
+use crate::search::adapter::outgoing::InMemoryContentSearch;
+use crate::search::application::services::SearchContentService;
+use crate::shared::bot_check::BotGate;
+use crate::shared::cache::{CachePort, NoopCache};
+use crate::shared::image_proxy::AllowedOrigins;
+use crate::shared::lifecycle::BackgroundJobs;
+use crate::shared::log_filter::LogFilter;
+use crate::shared::metrics::Metered;
+use crate::shared::rate_limit::RateLimiter;
+use crate::shared::unit_of_work::InMemoryUnitOfWork;
+use crate::site::adapter::outgoing::InMemorySiteSettings;
+use crate::site::application::site_use_cases::SiteUseCases;
+use crate::skills::adapter::outgoing::InMemorySkillStore;
+use crate::skills::application::skill_use_cases::SkillUseCases;
+use crate::topic::adapter::outgoing::InMemoryTopicStore;
+use crate::topic::application::topic_use_cases::TopicUseCases;
+use crate::translations::adapter::outgoing::InMemoryTranslationStore;
+use crate::translations::application::translation_use_cases::TranslationUseCases;
+use crate::trash::adapter::outgoing::InMemoryTrashRepository;
+use crate::trash::application::services::TrashPurger;
+use crate::trash::application::trash_use_cases::TrashUseCases;
+use crate::webhooks::adapter::outgoing::InMemoryWebhookStore;
+use crate::webhooks::application::webhook_use_cases::WebhookUseCases;
+use crate::AppState;
+
+pub(crate) async fn start(config: AppConfig, log_filter: LogFilter) -> std::io::Result<()> {
+    warn!("RUN_MODE=standalone: in-memory storage, nothing is persisted and no email is sent");
+
+    // Nothing to invalidate, and reads are already in memory
+    let cache: Arc<dyn CachePort> = Arc::new(NoopCache);
+
+    // Stores shared across modules, like tables in one database
+    let email_outbox = InMemoryEmailOutbox::default();
+    let users = InMemoryUserStore::with_email_outbox(email_outbox.clone());
+    let tokens = InMemoryTokenRepository::default();
+    let cvs = InMemoryCvStore::default();
+    let token_version_guard = TokenVersionGuard::new(Arc::new(users.clone()), Arc::clone(&cache));
+    let topics = InMemoryTopicStore::default();
+    let projects = InMemoryProjectStore::new(topics.clone());
+    let media = InMemoryMediaStore::default();
+    let webhooks = InMemoryWebhookStore::default();
+    let contacts = InMemoryContactStore::with_notifications(users.clone(), email_outbox.clone());
+    let trash =
+        InMemoryTrashRepository::new(cvs.clone(), projects.clone(), topics.clone(), media.clone());
+
+    // Auth
+    let jwt_service = JwtTokenService::new(config.jwt.clone());
+    let auth_use_cases = AuthUseCases::build(
+        users.clone(),
+        users.clone(),
+        tokens,
+        Arc::new(InMemoryConsumedTokens::default()),
+        Arc::new(Argon2Hasher::fast_env()),
+        Arc::new(jwt_service.clone()),
+        token_version_guard.clone(),
+        Arc::clone(&cache),
+        InMemoryUnitOfWork,
+    );
+    let passkey_use_cases = PasskeyUseCases::build(
+        Arc::new(users.clone()),
+        Arc::new(InMemoryPasskeyStore::default()),
+        Arc::new(InMemoryPasskeyChallenges::default()),
+        crate::passkey_ceremony(&config),
+        Arc::new(jwt_service.clone()),
+    );
+    let unsubscribe_tokens = UnsubscribeTokens::new(&config.unsubscribe_secret);
+    let email_outbox_dispatcher = OutboxDispatcher::new(
+        email_outbox.clone(),
+        UserEmailService::new(
+            jwt_service.clone(),
+            LogEmailSender,
+            config.verification_handler_url.clone(),
+            crate::load_email_templates(),
+            unsubscribe_tokens.clone(),
+        ),
+        DispatchPolicy::default(),
+    );
+
+    // Projects
+    let sync_readme_uc: Arc<dyn SyncProjectReadmeUseCase + Send + Sync> =
+        Arc::new(Metered(SyncProjectReadmeService::new(
+            projects.clone(),
+            projects.clone(),
+            projects.clone(),
+            Arc::new(
+                GitHubRepoMetadataProvider::new(config.github_token.clone())
+                    .expect("Failed to build GitHub HTTP client"),
+            ),
+            Arc::clone(&cache),
+        )));
+    let project_use_cases = ProjectUseCases::build(
+        projects.clone(),
+        projects.clone(),
+        projects.clone(),
+        projects.clone(),
+        projects.clone(),
+        Arc::clone(&sync_readme_uc),
+        Arc::clone(&cache),
+    );
+
+    // Multimedia: the folders of a local image processor, or nothing
+    let storage: Arc<dyn StorageQuery> =
+        match (&config.local_storage_dir, &config.image_processor_url) {
+            (Some(dir), Some(processor_url)) => {
+                Arc::new(LocalStorageQuery::new(dir, processor_url))
+            }
+            _ => Arc::new(InMemoryStorage),
+        };
+    let upload_url_rate_limiter =
+        RateLimiter::new(config.upload_url_rate_limit, Duration::from_secs(3600));
+    let media_use_cases = MultimediaUseCases::build(
+        Arc::clone(&storage),
+        media.clone(),
+        media.clone(),
+        media.clone(),
+        InMemoryUnitOfWork,
+        Arc::new(UnconfiguredMediaProcessor),
+        upload_url_rate_limiter.clone(),
+        Arc::new(Metered(ProxyImageService::new(
+            storage,
+            HttpMediaTransfer::allowing_only(AllowedOrigins::new(
+                config.image_proxy_origins.clone(),
+            ))
+            .expect("Failed to build image proxy HTTP client"),
+            UploadPolicy::new(config.multimedia_upload_bucket.clone()),
+            config.multimedia_ready_bucket.clone(),
+            AllowedOrigins::new(config.image_proxy_origins.clone()),
+        ))),
+        Arc::new(Metered(IngestMediaManifestService::new(
+            media.clone(),
+            media.clone(),
+            media.clone(),
+            config.multimedia_ready_bucket.clone(),
+        ))),
+        InMemoryStorageOverrides::default(),
+        vec![
+            config.multimedia_upload_bucket.clone(),
+            config.multimedia_ready_bucket.clone(),
+        ],
+    );
+
+    let webhook_use_cases = WebhookUseCases::build(webhooks);
+
+    let contact_use_cases = ContactUseCases::build(contacts, config.admin_user_ids.clone());
+
+    let site_use_cases = SiteUseCases::build(InMemorySiteSettings::default(), Arc::clone(&cache));
+
+    let page_views = InMemoryPageViewStore::default();
+    let page_view_buffer = PageViewBuffer::default();
+    let analytics_use_cases = AnalyticsUseCases::build(
+        VisitorHasher::new(&config.analytics_secret),
+        page_view_buffer.clone(),
+        page_views.clone(),
+    );
+    let page_view_flusher =
+        PageViewFlusher::new(page_view_buffer, page_views, Duration::from_secs(10));
+
+    let pages = InMemoryPageStore::default();
+    let autosave_buffer = AutosaveBuffer::default();
+    let page_use_cases = PageUseCases::build(
+        pages.clone(),
+        PreviewTokens::new(&config.preview_secret),
+        Arc::clone(&cache),
+        autosave_buffer.clone(),
+    );
+    let autosave_flusher =
+        AutosaveFlusher::new(autosave_buffer, pages.clone(), Duration::from_secs(5));
+
+    let redirects = InMemoryRedirectStore::default();
+    let redirect_use_cases = RedirectUseCases::build(redirects);
+
+    let blocklist_use_cases = BlocklistUseCases::build(InMemoryBlockRuleStore::default());
+
+    let translations = InMemoryTranslationStore::new(projects.clone(), pages.clone());
+    let translation_use_cases = TranslationUseCases::build(translations);
+
+    let skill_use_cases =
+        SkillUseCases::build(InMemorySkillStore::new(cvs.clone(), projects.clone()));
+
+    let content_search =
+        InMemoryContentSearch::new(cvs.clone(), projects.clone(), pages.clone(), media.clone());
+    let export_source =
+        InMemoryExportSource::new(cvs.clone(), projects.clone(), pages.clone(), media.clone());
+    let integrity_query =
+        InMemoryIntegrityQuery::new(cvs.clone(), projects.clone(), media.clone(), pages.clone());
+    let quick_search_query = InMemoryQuickSearchQuery::new(
+        users.clone(),
+        cvs.clone(),
+        projects.clone(),
+        pages.clone(),
+        media.clone(),
+    );
+    let topic_use_cases = TopicUseCases::build(topics.clone(), topics);
+    let import_content = ImportContentService::new(
+        InMemorySlugLookup::new(pages, projects.clone()),
+        ImportTargets {
+            create_page: Arc::clone(&page_use_cases.create),
+            create_project: Arc::clone(&project_use_cases.create),
+            add_project_topic: Arc::clone(&project_use_cases.add_topic),
+            get_topics: Arc::clone(&topic_use_cases.get_list),
+            create_topic: Arc::clone(&topic_use_cases.create),
+        },
+        InMemoryUnitOfWork,
+    );
+    let run_batch = RunBatchService::new(BatchTargets {
+        patch_project: Arc::clone(&project_use_cases.patch),
+        get_project: Arc::clone(&project_use_cases.get_single),
+        add_project_topic: Arc::clone(&project_use_cases.add_topic),
+        remove_project_topic: Arc::clone(&project_use_cases.remove_topic),
+        get_page: Arc::clone(&page_use_cases.get),
+        update_page: Arc::clone(&page_use_cases.update),
+    });
+
+    let contact_rate_limiter =
+        RateLimiter::new(config.contact_rate_limit, Duration::from_secs(3600));
+
+    let state = AppState {
+        cv: CvUseCases::build(
+            cvs.clone(),
+            cvs.clone(),
+            cvs,
+            Arc::new(PlainTextCvRenderer),
+            Arc::clone(&cache),
+        ),
+        auth: auth_use_cases,
+        token_version_guard,
+        auth_cookies: crate::auth_cookies(&config),
+        passkeys: passkey_use_cases,
+        topics: topic_use_cases,
+        project: project_use_cases,
+        multimedia: media_use_cases,
+        user_identity_resolver: UserIdentityResolver::new(Arc::new(users.clone())),
+        multimedia_upload_policy: UploadPolicy::new(config.multimedia_upload_bucket.clone()),
+        upload_url_rate_limiter: upload_url_rate_limiter.clone(),
+        admin_stats_use_case: Arc::new(Metered(GetAdminStatsService::new(
+            InMemoryStatsQuery::new(users, projects.clone(), media.clone()),
+        ))),
+        integrity_check_use_case: Arc::new(Metered(CheckIntegrityService::new(
+            integrity_query,
+            InMemoryStorage,
+        ))),
+        quick_search_use_case: Arc::new(Metered(QuickSearchService::new(quick_search_query))),
+        storage_report_use_case: Arc::new(Metered(GetStorageReportService::new(
+            InMemoryStorageReportQuery::new(media.clone()),
+        ))),
+        admin_user_ids: config.admin_user_ids.clone(),
+        trash: TrashUseCases::build(trash.clone(), Arc::clone(&cache)),
+        webhooks: webhook_use_cases,
+        email: EmailUseCases::build(email_outbox, unsubscribe_tokens),
+        email_events_token: config.email_events_token.clone(),
+        media_callback_secret: config.media_callback_secret.clone(),
+        introspection_token: config.introspection_token.clone(),
+        metrics_token: config.metrics_token.clone(),
+        config_reloader: ConfigReloader::new(
+            Arc::new(AppConfig::load),
+            contact_rate_limiter.clone(),
+            upload_url_rate_limiter,
+            Some(log_filter),
+        ),
+        contact: contact_use_cases,
+        contact_rate_limiter: contact_rate_limiter.clone(),
+        bot_gate: BotGate::from_config(&config.bot_check)
+            .expect("Failed to build bot check HTTP client"),
+        site: site_use_cases,
+        pages: page_use_cases,
+        analytics: analytics_use_cases,
+        redirects: redirect_use_cases,
+        blocklist: blocklist_use_cases,
+        search_content_use_case: Arc::new(Metered(SearchContentService::new(content_search))),
+        export_content_use_case: Arc::new(ExportContentService::new(export_source)),
+        import_content_use_case: Arc::new(Metered(import_content)),
+        run_batch_use_case: Arc::new(Metered(run_batch)),
+        list_activities_use_case: Arc::new(Metered(ListActivitiesService::new(
+            InMemoryActivityLog::default(),
+        ))),
+        translations: translation_use_cases,
+        skills: skill_use_cases,
+    };
+
+    let mut background_jobs = BackgroundJobs::new();
+    background_jobs.spawn("email_outbox", move |signal| {
+        email_outbox_dispatcher.run(signal)
+    });
+    background_jobs.spawn("page_views", move |signal| page_view_flusher.run(signal));
+    background_jobs.spawn("page_autosaves", move |signal| autosave_flusher.run(signal));
+    // No processor to retry with: queued failures are marked failed
+    let media_retrier = MediaRetrier::new(Arc::new(Metered(RetryFailedMediaService::new(
+        media.clone(),
+        media.clone(),
+        Arc::new(UnconfiguredMediaProcessor),
+        config.media_retry_max_attempts,
+    ))));
+    background_jobs.spawn("media_retry", move |signal| media_retrier.run(signal));
+    #[cfg(unix)]
+    {
+        let config_reloader = state.config_reloader.clone();
+        background_jobs.spawn("config_reload", move |signal| config_reloader.run(signal));
+    }
+    if config.trash_retention_days > 0 {
+        let trash_purger = TrashPurger::new(
+            trash,
+            chrono::Duration::days(config.trash_retention_days.into()),
+            Duration::from_secs(3600),
+        );
+        background_jobs.spawn("trash_purge", move |signal| trash_purger.run(signal));
+    }
+    if config.readme_sync_interval_secs > 0 {
+        let readme_syncer = ReadmeSyncer::new(
+            projects,
+            sync_readme_uc,
+            Duration::from_secs(config.readme_sync_interval_secs),
+        );
+        background_jobs.spawn("readme_sync", move |signal| readme_syncer.run(signal));
+    }
+
+    let token_provider: Arc<dyn TokenProvider + Send + Sync> = Arc::new(jwt_service);
+
+    // No infrastructure to expose to handlers
+    crate::serve(
+        &config,
+        state,
+        token_provider,
+        background_jobs,
+        |_: &mut web::ServiceConfig| {},
+    )
+    .await
+}
@@ -0,0 +1,456 @@
+use actix_web::{web, HttpResponse, Result};
+use futures::future::join_all;
+use sea_orm::{ConnectionTrait, DatabaseConnection, Statement, TransactionTrait, Value};
+use serde::{Deserialize, Serialize};
+use shared_domain::buckets::{upload_object_key, DEFAULT_READY_BUCKET, DEFAULT_UPLOAD_BUCKET};
+use shared_domain::variants::VariantSize;
+use std::sync::Arc;
+use std::time::Instant;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::auth::application::use_cases::create_user::CreateUserInput;
+use crate::pages::application::domain::entities::PageStatus;
+use crate::pages::application::ports::incoming::use_cases::CreatePageCommand;
+use crate::project::application::ports::outgoing::project_repository::CreateProjectData;
+use crate::shared::sql;
+use crate::topic::application::ports::incoming::use_cases::CreateTopicCommand;
+use crate::AppState;
+
+const MAX_USERS: u32 = 500;
+const MAX_PER_USER: u32 = 200;
+const MAX_BATCH_SIZE: u32 = 100;
+
+/// Every generated account logs in with it
+const FIXTURE_PASSWORD: &str = "bulk-fixture-password";
+
+/// Title and body words, cycled so listings and search see varied text
+const WORDS: [&str; 16] = [
+    "rust",
+    "actix",
+    "postgres",
+    "portfolio",
+    "pipeline",
+    "image",
+    "search",
+    "cache",
+    "deploy",
+    "webhook",
+    "markdown",
+    "analytics",
+    "redis",
+    "docker",
+    "testing",
+    "design",
+];
+const TECH_STACK: [&str; 8] = [
+    "Rust",
+    "TypeScript",
+    "PostgreSQL",
+    "Redis",
+    "Docker",
+    "React",
+    "Svelte",
+    "GCP",
+];
+
+fn default_users() -> u32 {
+    10
+}
+
+fn default_projects_per_user() -> u32 {
+    20
+}
+
+fn default_posts_per_user() -> u32 {
+    20
+}
+
+fn default_topics_per_user() -> u32 {
+    5
+}
+
+fn default_media_per_user() -> u32 {
+    10
+}
+
+fn default_batch_size() -> u32 {
+    20
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BulkFixturesRequest {
+    #[serde(default = "default_users")]
+    users: u32,
+    #[serde(default = "default_projects_per_user")]
+    projects_per_user: u32,
+    /// Published pages
+    #[serde(default = "default_posts_per_user")]
+    posts_per_user: u32,
+    #[serde(default = "default_topics_per_user")]
+    topics_per_user: u32,
+    /// Ready media with every variant, attached to the user's projects
+    #[serde(default = "default_media_per_user")]
+    media_per_user: u32,
+    /// Users generated concurrently, and written together in one transaction
+    #[serde(default = "default_batch_size")]
+    batch_size: u32,
+}
+
+impl BulkFixturesRequest {
+    fn check(&self) -> Result<(), String> {
+        if self.users == 0 || self.users > MAX_USERS {
+            return Err(format!("users must be 1-{MAX_USERS}"));
+        }
+        let per_user = [
+            self.projects_per_user,
+            self.posts_per_user,
+            self.topics_per_user,
+            self.media_per_user,
+        ];
+        if per_user.iter().any(|n| *n > MAX_PER_USER) {
+            return Err(format!("per-user counts must be 0-{MAX_PER_USER}"));
+        }
+        if self.media_per_user > 0 && self.projects_per_user == 0 {
+            return Err("media is attached to projects; ask for at least one".to_string());
+        }
+        if self.batch_size == 0 || self.batch_size > MAX_BATCH_SIZE {
+            return Err(format!("batch_size must be 1-{MAX_BATCH_SIZE}"));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize)]
+pub struct FixtureUser {
+    id: Uuid,
+    email: String,
+    user_name: String,
+}
+
+#[derive(Serialize)]
+pub struct BulkFixturesResponse {
+    /// Part of every generated name and slug
+    run_id: String,
+    password: String,
+    users: Vec<FixtureUser>,
+    topics: u32,
+    projects: u32,
+    posts: u32,
+    media: u32,
+    elapsed_ms: u128,
+}
+
+/// What was generated for one user through the use cases
+struct UserFixtures {
+    user: FixtureUser,
+    topics: u32,
+    posts: u32,
+    project_ids: Vec<Uuid>,
+}
+
+fn word(n: u32) -> &'static str {
+    WORDS[n as usize % WORDS.len()]
+}
+
+/// Generate users with topics, projects, published pages and ready media,
+/// for load tests of the listing and search endpoints. Users are verified
+/// and their verification emails dropped; all share one password.
+/// POST /test/fixtures/bulk
+/// {"users": 50, "projects_per_user": 40, "posts_per_user": 40, "topics_per_user": 8,
+///  "media_per_user": 20, "batch_size": 25}
+pub async fn create_bulk_fixtures(
+    body: web::Json<BulkFixturesRequest>,
+    state: web::Data<AppState>,
+    db: web::Data<Arc<DatabaseConnection>>,
+) -> Result<HttpResponse> {
+    if let Some(refused) = super::refuse_in_production() {
+        return Ok(refused);
+    }
+    let request = body.into_inner();
+    request.check().map_err(actix_web::error::ErrorBadRequest)?;
+
+    let started = Instant::now();
+    let run_id = Uuid::new_v4().simple().to_string()[..8].to_string();
+    let mut response = BulkFixturesResponse {
+        run_id: run_id.clone(),
+        password: FIXTURE_PASSWORD.to_string(),
+        users: Vec::with_capacity(request.users as usize),
+        topics: 0,
+        projects: 0,
+        posts: 0,
+        media: 0,
+        elapsed_ms: 0,
+    };
+
+    let indexes: Vec<u32> = (0..request.users).collect();
+    for batch in indexes.chunks(request.batch_size as usize) {
+        let generated = join_all(
+            batch
+                .iter()
+                .map(|&i| generate_user(&state, &request, &run_id, i)),
+        )
+        .await;
+
+        let mut users = Vec::with_capacity(generated.len());
+        for result in generated {
+            users.push(result.map_err(actix_web::error::ErrorInternalServerError)?);
+        }
+        let media = write_batch(db.get_ref(), &request, &run_id, &users)
+            .await
+            .map_err(|e| {
+                actix_web::error::ErrorInternalServerError(format!("Batch write failed: {}", e))
+            })?;
+
+        response.media += media;
+        for user in users {
+            response.topics += user.topics;
+            response.projects += user.project_ids.len() as u32;
+            response.posts += user.posts;
+            response.users.push(user.user);
+        }
+    }
+
+    response.elapsed_ms = started.elapsed().as_millis();
+    tracing::info!(
+        run_id = %run_id,
+        users = response.users.len(),
+        projects = response.projects,
+        posts = response.posts,
+        media = response.media,
+        elapsed_ms = response.elapsed_ms,
+        "Bulk fixtures generated"
+    );
+    Ok(HttpResponse::Ok().json(response))
+}
+
+/// One user and their content, through the same use cases as the API
+async fn generate_user(
+    state: &AppState,
+    request: &BulkFixturesRequest,
+    run_id: &str,
+    i: u32,
+) -> Result<UserFixtures, String> {
+    let registered = state
+        .auth
+        .register
+        .register_user(CreateUserInput {
+            username: format!("bulk_{run_id}_{i}"),
+            email: format!("bulk.{run_id}.{i}@example.test"),
+            password: FIXTURE_PASSWORD.to_string(),
+            full_name: format!("Bulk User {i}"),
+            preferred_locale: None,
+        })
+        .await
+        .map_err(|e| format!("User {i}: {e}"))?;
+    let owner = UserId::from(registered.user_id);
+
+    let mut topic_ids = Vec::with_capacity(request.topics_per_user as usize);
+    for n in 0..request.topics_per_user {
+        let command = CreateTopicCommand::new(owner, format!("{} {n}", word(i + n)), None)
+            .map_err(|e| e.to_string())?;
+        let topic = state
+            .topics
+            .create
+            .execute(command)
+            .await
+            .map_err(|e| format!("Topic {n} of user {i}: {e}"))?;
+        topic_ids.push(topic.id);
+    }
+
+    let mut project_ids = Vec::with_capacity(request.projects_per_user as usize);
+    for n in 0..request.projects_per_user {
+        let project = state
+            .project
+            .create
+            .execute(CreateProjectData {
+                owner,
+                title: format!("{} {} project {n}", word(i + n), word(n * 3 + 1)),
+                slug: format!("bulk-{run_id}-{i}-{n}"),
+                description: format!(
+                    "A {} service built around {} and {}.",
+                    word(n),
+                    word(n + 5),
+                    word(i + n * 7)
+                ),
+                tech_stack: (0..3)
+                    .map(|k| TECH_STACK[((n + k) as usize) % TECH_STACK.len()].to_string())
+                    .collect(),
+                screenshots: Vec::new(),
+                repo_url: Some(format!("https://github.com/example/bulk-{run_id}-{i}-{n}")),
+                live_demo_url: None,
+                seo_title: None,
+                seo_description: None,
+                canonical_url: None,
+                noindex: false,
+            })
+            .await
+            .map_err(|e| format!("Project {n} of user {i}: {e}"))?;
+
+        if !topic_ids.is_empty() {
+            let topic_id = topic_ids[n as usize % topic_ids.len()];
+            state
+                .project
+                .add_topic
+                .execute(owner, project.id, topic_id)
+                .await
+                .map_err(|e| format!("Topic link of project {n} of user {i}: {e}"))?;
+        }
+        project_ids.push(project.id);
+    }
+
+    for n in 0..request.posts_per_user {
+        let command = CreatePageCommand::new(
+            format!("bulk-{run_id}-{i}-{n}"),
+            format!("Notes on {} and {} #{n}", word(n + 2), word(i + n * 5)),
+            format!(
+                "## {}\n\nWhat building with {} taught me about {}.\n\n- {}\n- {}\n",
+                word(n),
+                word(n + 1),
+                word(i + 3),
+                word(n + 7),
+                word(n + 11)
+            ),
+            PageStatus::Published,
+            None,
+            None,
+        )
+        .map_err(|e| e.to_string())?;
+        state
+            .pages
+            .create
+            .execute(registered.user_id, command)
+            .await
+            .map_err(|e| format!("Post {n} of user {i}: {e}"))?;
+    }
+
+    Ok(UserFixtures {
+        user: FixtureUser {
+            id: registered.user_id,
+            email: registered.email,
+            user_name: registered.username,
+        },
+        topics: request.topics_per_user,
+        posts: request.posts_per_user,
+        project_ids,
+    })
+}
+
+/// In one transaction: verifies the batch's users, drops their queued
+/// verification emails and records their ready media. Ready media only
+/// comes from the image processor, so it is written here directly.
+async fn write_batch(
+    db: &DatabaseConnection,
+    request: &BulkFixturesRequest,
+    run_id: &str,
+    users: &[UserFixtures],
+) -> Result<u32, sea_orm::DbErr> {
+    let backend = db.get_database_backend();
+    let txn = db.begin().await?;
+
+    let placeholders = (1..=users.len())
+        .map(|n| format!("${n}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let user_ids: Vec<Value> = users.iter().map(|u| Value::from(u.user.id)).collect();
+    txn.execute(Statement::from_sql_and_values(
+        backend,
+        format!("UPDATE users SET is_verified = true WHERE id IN ({placeholders})"),
+        user_ids.clone(),
+    ))
+    .await?;
+    txn.execute(Statement::from_sql_and_values(
+        backend,
+        format!("DELETE FROM email_outbox WHERE sent_at IS NULL AND user_id IN ({placeholders})"),
+        user_ids,
+    ))
+    .await?;
+
+    let mut media = 0;
+    for user in users {
+        for n in 0..request.media_per_user {
+            let media_id = Uuid::new_v4();
+            let file_name = format!("bulk-{run_id}-{n}.jpg");
+            insert_ready_media(&txn, media_id, user.user.id, &file_name).await?;
+
+            let project_id = user.project_ids[n as usize % user.project_ids.len()];
+            txn.execute(Statement::from_sql_and_values(
+                backend,
+                r#"
+                INSERT INTO media_attachments (
+                  id, media_id, attachable_type, attachable_id, role, position, created_at
+                )
+                VALUES ($1, $2, 'project', $3, 'gallery', $4, NOW())
+                "#,
+                vec![
+                    Uuid::new_v4().into(),
+                    media_id.into(),
+                    project_id.into(),
+                    (n as i32).into(),
+                ],
+            ))
+            .await?;
+            media += 1;
+        }
+    }
+
+    txn.commit().await?;
+    Ok(media)
+}
+
+async fn insert_ready_media<C: ConnectionTrait>(
+    txn: &C,
+    media_id: Uuid,
+    owner: Uuid,
+    file_name: &str,
+) -> Result<(), sea_orm::DbErr> {
+    let backend = txn.get_database_backend();
+    txn.execute(Statement::from_sql_and_values(
+        backend,
+        format!(
+            r#"
+            INSERT INTO media (
+              id, user_id, bucket_name, object_key, original_filename, mime_type,
+              file_size_bytes, width, height, status, metadata, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, 'image/jpeg', 480000, 1600, 1200, {status}, '{{}}',
+                    NOW(), NOW())
+            "#,
+            status = sql::pg_cast(backend, "'ready'", "media_status"),
+        ),
+        vec![
+            media_id.into(),
+            owner.into(),
+            DEFAULT_UPLOAD_BUCKET.into(),
+            upload_object_key(&media_id.to_string(), file_name).into(),
+            file_name.into(),
+        ],
+    ))
+    .await?;
+
+    for size in VariantSize::ALL {
+        let width = size.width() as i32;
+        txn.execute(Statement::from_sql_and_values(
+            backend,
+            r#"
+            INSERT INTO media_variants (
+              id, media_id, variant_type, bucket_name, object_key, mime_type,
+              file_size_bytes, width, height, created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, 'image/webp', $6, $7, $8, NOW())
+            "#,
+            vec![
+                Uuid::new_v4().into(),
+                media_id.into(),
+                size.as_str().into(),
+                DEFAULT_READY_BUCKET.into(),
+                format!("variants/{media_id}/{}_{width}.webp", size.as_str()).into(),
+                (i64::from(width) * 40).into(),
+                width.into(),
+                (width * 3 / 4).into(),
+            ],
+        ))
+        .await?;
+    }
+    Ok(())
+}
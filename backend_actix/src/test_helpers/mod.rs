@@ -7,6 +7,9 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::auth::application::ports::outgoing::token_provider::TokenClaims;
+use crate::shared::faults::{faults, FaultSettings};
+
+mod fixtures;
 
 #[derive(Serialize)]
 pub struct RandomAccountResponse {
@@ -133,6 +136,7 @@ pub async fn cleanup_test_user(
     use sea_orm::{ConnectionTrait, Statement};
 
     let user_id = user_id.into_inner();
+    let backend = db.get_database_backend();
 
     // Dereference web::Data to get Arc<DatabaseConnection>, then dereference Arc to get &DatabaseConnection
     let txn = db.as_ref().begin().await.map_err(|e| {
@@ -142,7 +146,7 @@ pub async fn cleanup_test_user(
     // Delete resumes first (foreign key constraint)
     let resumes_result = txn
         .execute(Statement::from_sql_and_values(
-            sea_orm::DatabaseBackend::Postgres,
+            backend,
             "DELETE FROM resumes WHERE user_id = $1",
             vec![user_id.into()],
         ))
@@ -154,7 +158,7 @@ pub async fn cleanup_test_user(
     // Delete user
     let user_result = txn
         .execute(Statement::from_sql_and_values(
-            sea_orm::DatabaseBackend::Postgres,
+            backend,
             "DELETE FROM users WHERE id = $1",
             vec![user_id.into()],
         ))
@@ -181,26 +185,62 @@ pub async fn cleanup_test_user(
     }))
 }
 
+fn environment() -> String {
+    std::env::var("RUST_ENV").unwrap_or_else(|_| "development".to_string())
+}
+
+/// Additional safety check, for routes that change how the app behaves
+fn refuse_in_production() -> Option<HttpResponse> {
+    if environment() != "production" {
+        return None;
+    }
+    tracing::error!("🚨 Test helper routes active in production!");
+    Some(HttpResponse::InternalServerError().json(serde_json::json!({
+        "status": "error",
+        "reason": "test-helper-running-in-production"
+    })))
+}
+
 /// Health check for test helpers
 /// GET /test/health
 pub async fn health_check() -> Result<HttpResponse> {
-    let env = std::env::var("RUST_ENV").unwrap_or_else(|_| "development".to_string());
-
-    // Additional safety check
-    if env == "production" {
-        tracing::error!("🚨 Test helper routes active in production!");
-        return Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-            "status": "error",
-            "reason": "test-helper-running-in-production"
-        })));
+    if let Some(refused) = refuse_in_production() {
+        return Ok(refused);
     }
 
     Ok(HttpResponse::Ok().json(HealthResponse {
         status: "ok".to_string(),
-        environment: env,
+        environment: environment(),
     }))
 }
 
+/// Faults currently injected into outgoing ports
+/// GET /test/faults
+pub async fn get_faults() -> Result<HttpResponse> {
+    Ok(HttpResponse::Ok().json(faults().current()))
+}
+
+/// Inject faults; fields left out keep their value
+/// PUT /test/faults
+/// {"repository_failures": 2, "smtp_delay_ms": 3000, "storage_signing_fails": true}
+pub async fn set_faults(body: web::Json<FaultSettings>) -> Result<HttpResponse> {
+    if let Some(refused) = refuse_in_production() {
+        return Ok(refused);
+    }
+
+    faults().apply(&body);
+    let current = faults().current();
+    tracing::warn!(faults = ?current, "⚠️  Faults injected into outgoing ports");
+    Ok(HttpResponse::Ok().json(current))
+}
+
+/// Stop injecting faults
+/// DELETE /test/faults
+pub async fn clear_faults() -> Result<HttpResponse> {
+    faults().reset();
+    Ok(HttpResponse::Ok().json(faults().current()))
+}
+
 /// Generate test JWT tokens with various states (Valid, Expired, NotYetValid, InvalidSignature, Malformed)
 /// GET /test/token/{token_type}/{token_kind}/{user_id}?is_verified=true
 pub async fn generate_test_token(
@@ -240,6 +280,10 @@ pub async fn generate_test_token(
     // Get JWT secret from environment (must match production config)
     let valid_secret = std::env::var("JWT_SECRET").unwrap_or_else(|_| "test-secret".to_string());
 
+    // Same defaults as `AppConfig`, or `verify_token` rejects the token
+    let issuer = std::env::var("JWT_ISSUER").unwrap_or_else(|_| "Ekstion".to_string());
+    let audience = std::env::var("JWT_AUDIENCE").unwrap_or_else(|_| "Ekstion API".to_string());
+
     // Intentionally wrong secret for InvalidSignature testing
     let invalid_secret = "wrong-secret";
 
@@ -254,6 +298,11 @@ pub async fn generate_test_token(
                 nbf: now - 32,
                 token_type: token_type.as_str().to_string(),
                 is_verified,
+                token_version: 0,
+                remember_me: false,
+                iss: issuer.clone(),
+                aud: audience.clone(),
+                jti: None,
             };
             (claims, valid_secret.as_str())
         }
@@ -265,6 +314,11 @@ pub async fn generate_test_token(
                 exp: now - 60, // Expired 60 seconds ago
                 token_type: token_type.as_str().to_string(),
                 is_verified,
+                token_version: 0,
+                remember_me: false,
+                iss: issuer.clone(),
+                aud: audience.clone(),
+                jti: None,
             };
             (claims, valid_secret.as_str())
         }
@@ -276,6 +330,11 @@ pub async fn generate_test_token(
                 exp: now + 3600,
                 token_type: token_type.as_str().to_string(),
                 is_verified,
+                token_version: 0,
+                remember_me: false,
+                iss: issuer.clone(),
+                aud: audience.clone(),
+                jti: None,
             };
             (claims, valid_secret.as_str())
         }
@@ -287,6 +346,11 @@ pub async fn generate_test_token(
                 exp: now + 3600,
                 token_type: token_type.as_str().to_string(),
                 is_verified,
+                token_version: 0,
+                remember_me: false,
+                iss: issuer.clone(),
+                aud: audience.clone(),
+                jti: None,
             };
             (claims, invalid_secret)
         }
@@ -314,6 +378,13 @@ pub fn configure_routes(cfg: &mut web::ServiceConfig) {
         web::scope("/test")
             .route("/health", web::get().to(health_check))
             .route("/account/random", web::get().to(generate_random_account))
+            .route("/faults", web::get().to(get_faults))
+            .route("/faults", web::put().to(set_faults))
+            .route("/faults", web::delete().to(clear_faults))
+            .route(
+                "/fixtures/bulk",
+                web::post().to(fixtures::create_bulk_fixtures),
+            )
             .route(
                 "/cleanup/all/{user_id}",
                 web::delete().to(cleanup_test_user),
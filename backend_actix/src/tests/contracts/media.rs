@@ -0,0 +1,305 @@
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::multimedia::adapter::outgoing::db::{
+    InMemoryMediaStore, MediaQueryPostgres, MediaRepositoryPostgres,
+};
+use crate::multimedia::application::domain::entities::{
+    AttachmentTarget, MediaFailure, MediaRole, MediaSize, MediaState, MediaStateInfo,
+};
+use crate::multimedia::application::ports::outgoing::db::{
+    MediaQuery, MediaQueryError, MediaRepository, MediaRepositoryError, MediaVariantRecord,
+    NewMedia, NewMediaAttachment, RecordMediaTx, UpdateMediaStateData,
+};
+use crate::tests::support::database::{create_owner, delete_owner, test_db};
+
+fn upload(owner: UserId, target_id: Uuid, position: u8, name: &str) -> RecordMediaTx {
+    RecordMediaTx {
+        media: NewMedia {
+            owner,
+            state: MediaState::Pending,
+            bucket_name: " contract-bucket ".to_string(),
+            original_name: name.to_string(),
+            mime_type: "image/png".to_string(),
+            file_size_bytes: 1024,
+            width_px: None,
+            height_px: None,
+            duration_seconds: None,
+            checksum_sha256: None,
+        },
+        attachment: NewMediaAttachment {
+            owner,
+            attachment_target: AttachmentTarget::Project,
+            attachment_target_id: target_id,
+            role: MediaRole::Gallery,
+            position,
+            alt_text: Some("alt".to_string()),
+            caption: None,
+        },
+    }
+}
+
+fn variant(owner: UserId, media_id: Uuid) -> MediaVariantRecord {
+    MediaVariantRecord {
+        owner,
+        media_id,
+        size: MediaSize::Thumbnail,
+        bucket_name: "contract-bucket".to_string(),
+        object_key: format!("{media_id}/thumbnail.webp"),
+        mime_type: "image/webp".to_string(),
+        file_size_bytes: 256,
+        width_px: Some(64),
+        height_px: Some(64),
+        checksum_sha256: None,
+    }
+}
+
+/// `repo` and `query` must share storage. `owner` and `stranger` must be
+/// accounts the adapter accepts as owners.
+pub async fn media_contract<R: MediaRepository, Q: MediaQuery>(
+    repo: &R,
+    query: &Q,
+    owner: UserId,
+    stranger: UserId,
+) {
+    let target_id = Uuid::new_v4();
+
+    // record trims names and keeps the requested state
+    let second = repo
+        .record_media_tx(upload(owner, target_id, 1, " second.png "))
+        .await
+        .unwrap();
+    let first = repo
+        .record_media_tx(upload(owner, target_id, 0, "first.PNG"))
+        .await
+        .unwrap();
+    assert_eq!(second.owner, owner);
+    assert_eq!(second.bucket_name, "contract-bucket");
+    assert_eq!(second.original_name, "second.png");
+    assert_eq!(second.state, MediaState::Pending);
+
+    // files without a supported extension are refused
+    assert!(repo
+        .record_media_tx(upload(owner, target_id, 2, "notes.txt"))
+        .await
+        .is_err());
+
+    // state and attachment read back
+    let state = query.get_state(first.media_id).await.unwrap();
+    assert_eq!(state.owner, owner);
+    assert_eq!(state.status, MediaState::Pending);
+    let info = query.get_attachment_info(first.media_id).await.unwrap();
+    assert_eq!(info.attachment_target, AttachmentTarget::Project);
+    assert_eq!(info.attachment_target_id, target_id);
+    assert_eq!(info.role, MediaRole::Gallery);
+    assert_eq!(info.position, 0);
+    assert_eq!(info.alt_text, "alt");
+    assert_eq!(info.caption, "");
+    assert!(info.variants.is_empty());
+    let object = query.get_upload_object(first.media_id).await.unwrap();
+    assert_eq!(object.owner, owner);
+    assert_eq!(object.status, MediaState::Pending);
+    assert_eq!(object.attachment_target, AttachmentTarget::Project);
+    assert_eq!(object.bucket_name, "contract-bucket");
+    assert_eq!(object.object_key, format!("{}/first.PNG", first.media_id));
+
+    // listing is per owner and target, in position order within an entity
+    let listed = query
+        .list_by_target(owner, AttachmentTarget::Project)
+        .await
+        .unwrap();
+    let ours: Vec<Uuid> = listed
+        .iter()
+        .filter(|m| m.attachment_target_id == target_id)
+        .map(|m| m.media_id)
+        .collect();
+    assert_eq!(ours, vec![first.media_id, second.media_id]);
+    assert!(query
+        .list_by_target(owner, AttachmentTarget::Resume)
+        .await
+        .unwrap()
+        .iter()
+        .all(|m| m.attachment_target == AttachmentTarget::Resume));
+    assert!(query
+        .list_by_target(stranger, AttachmentTarget::Project)
+        .await
+        .unwrap()
+        .iter()
+        .all(|m| m.attachment_target_id != target_id));
+
+    // a transition only applies from the given state
+    let processing = UpdateMediaStateData {
+        owner,
+        media_id: second.media_id,
+        status: MediaState::Processing,
+    };
+    assert!(repo
+        .transition_media_state(processing.clone(), MediaState::Ready)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(repo
+        .transition_media_state(
+            UpdateMediaStateData {
+                owner: stranger,
+                ..processing.clone()
+            },
+            MediaState::Pending
+        )
+        .await
+        .unwrap()
+        .is_none());
+    let moved = repo
+        .transition_media_state(processing.clone(), MediaState::Pending)
+        .await
+        .unwrap()
+        .expect("pending media moves on");
+    assert_eq!(moved.status, MediaState::Processing);
+    assert!(repo
+        .transition_media_state(processing, MediaState::Pending)
+        .await
+        .unwrap()
+        .is_none());
+
+    // failing also only applies to the state the media was read in
+    let stale = MediaStateInfo {
+        status: MediaState::Pending,
+        ..moved.clone()
+    };
+    assert!(repo
+        .fail_media(&stale, MediaFailure::ProcessingTimeout)
+        .await
+        .unwrap()
+        .is_none());
+    let failed = repo
+        .fail_media(&moved, MediaFailure::ProcessingTimeout)
+        .await
+        .unwrap()
+        .expect("processing media can be failed");
+    assert_eq!(failed.status, MediaState::Failed);
+    assert_eq!(
+        query.get_state(second.media_id).await.unwrap().status,
+        MediaState::Failed
+    );
+
+    // only the owner can change state
+    let ready = UpdateMediaStateData {
+        owner,
+        media_id: first.media_id,
+        status: MediaState::Ready,
+    };
+    assert!(matches!(
+        repo.set_media_state(UpdateMediaStateData {
+            owner: stranger,
+            ..ready.clone()
+        })
+        .await,
+        Err(MediaRepositoryError::NotFound)
+    ));
+    let updated = repo.set_media_state(ready).await.unwrap();
+    assert_eq!(updated.status, MediaState::Ready);
+    assert_eq!(
+        query.get_state(first.media_id).await.unwrap().status,
+        MediaState::Ready
+    );
+
+    // variants attach to the owner's media; recording one again replaces it
+    assert!(matches!(
+        repo.record_single_variant(variant(stranger, first.media_id))
+            .await,
+        Err(MediaRepositoryError::NotFound)
+    ));
+    let recorded = repo
+        .record_single_variant(variant(owner, first.media_id))
+        .await
+        .unwrap();
+    assert_eq!(recorded.size, MediaSize::Thumbnail);
+    repo.record_single_variant(variant(owner, first.media_id))
+        .await
+        .unwrap();
+    let variants = query
+        .get_attachment_info(first.media_id)
+        .await
+        .unwrap()
+        .variants;
+    assert_eq!(variants.len(), 1);
+    assert_eq!(variants[0].width, 64);
+    assert_eq!(variants[0].mime_type, "image/webp");
+
+    // batch lookups skip unknown ids and carry the variants
+    let mut batch = query
+        .get_attachment_infos(&[second.media_id, Uuid::new_v4(), first.media_id])
+        .await
+        .unwrap();
+    batch.sort_by_key(|m| m.position);
+    assert_eq!(
+        batch.iter().map(|m| m.media_id).collect::<Vec<_>>(),
+        vec![first.media_id, second.media_id]
+    );
+    assert_eq!(batch[0].variants.len(), 1);
+    assert!(query.get_attachment_infos(&[]).await.unwrap().is_empty());
+
+    // duplicates are found per owner by checksum
+    let checksum = "ab".repeat(32);
+    let mut declared = upload(owner, target_id, 2, "third.png");
+    declared.media.checksum_sha256 = Some(checksum.to_uppercase());
+    let third = repo.record_media_tx(declared).await.unwrap();
+    let duplicate = query.find_duplicate(owner, &checksum).await.unwrap();
+    assert_eq!(duplicate.map(|d| d.media_id), Some(third.media_id));
+    assert!(query
+        .find_duplicate(stranger, &checksum)
+        .await
+        .unwrap()
+        .is_none());
+
+    // unknown ids
+    let unknown = Uuid::new_v4();
+    assert_eq!(
+        query
+            .existing_media_ids(&[first.media_id, unknown])
+            .await
+            .unwrap(),
+        std::collections::HashSet::from([first.media_id])
+    );
+    assert!(matches!(
+        query.get_state(Uuid::new_v4()).await,
+        Err(MediaQueryError::MediaNotFound)
+    ));
+    assert!(matches!(
+        query.get_attachment_info(Uuid::new_v4()).await,
+        Err(MediaQueryError::MediaNotFound)
+    ));
+}
+
+#[tokio::test]
+async fn in_memory_media_meets_contract() {
+    let media = InMemoryMediaStore::default();
+
+    media_contract(
+        &media,
+        &media,
+        UserId::from(Uuid::new_v4()),
+        UserId::from(Uuid::new_v4()),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn postgres_media_meets_contract() {
+    let Some(db) = test_db().await else {
+        return;
+    };
+    let owner = create_owner(&db).await;
+    let stranger = create_owner(&db).await;
+
+    media_contract(
+        &MediaRepositoryPostgres::new(db.clone()),
+        &MediaQueryPostgres::new(db.clone()),
+        owner,
+        stranger,
+    )
+    .await;
+
+    delete_owner(&db, owner).await;
+    delete_owner(&db, stranger).await;
+}
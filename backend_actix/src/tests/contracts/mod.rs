@@ -0,0 +1,16 @@
+//! Port contracts.
+//!
+//! Each contract is a generic async function that drives one outgoing port
+//! and asserts the behaviour every adapter must share: error variants,
+//! ownership checks, normalisation. It runs against the module's in-memory
+//! adapter (the one `RUN_MODE=standalone` serves from) on every `cargo test`,
+//! and against the Postgres adapter when `TEST_DATABASE_URL` is set.
+//!
+//! A new backend proves conformance by adding one test that builds the
+//! adapter and calls the contract.
+
+pub mod media;
+pub mod project_repository;
+pub mod project_update_repository;
+pub mod user_repository;
+pub mod webhook_repository;
@@ -0,0 +1,158 @@
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::project::adapter::outgoing::{InMemoryProjectStore, ProjectRepositoryPostgres};
+use crate::project::application::ports::outgoing::project_repository::{
+    CreateProjectData, PatchField, PatchProjectData, ProjectRepository, ProjectRepositoryError,
+};
+use crate::tests::support::database::{create_owner, delete_owner, test_db};
+
+fn new_project(owner: UserId, slug: &str) -> CreateProjectData {
+    CreateProjectData {
+        owner,
+        title: "  Contract Project  ".to_string(),
+        slug: slug.to_string(),
+        description: "Checks the port".to_string(),
+        tech_stack: vec!["Rust".to_string()],
+        screenshots: vec![],
+        repo_url: Some("https://example.com/repo".to_string()),
+        live_demo_url: None,
+        seo_title: None,
+        seo_description: None,
+        canonical_url: None,
+        noindex: false,
+    }
+}
+
+/// `owner` and `stranger` must be accounts the adapter accepts as owners
+pub async fn project_repository_contract<R: ProjectRepository>(
+    repo: &R,
+    owner: UserId,
+    stranger: UserId,
+) {
+    let slug = format!("Contract-{}", Uuid::new_v4().simple());
+
+    // create trims the title and lowercases the slug
+    let project = repo
+        .create_project(new_project(owner, &format!("  {slug} ")))
+        .await
+        .unwrap();
+    assert_eq!(project.owner, owner);
+    assert_eq!(project.title, "Contract Project");
+    assert_eq!(project.slug, slug.to_lowercase());
+    assert_eq!(project.tech_stack, vec!["Rust".to_string()]);
+
+    // slugs are unique regardless of case or owner
+    assert!(matches!(
+        repo.create_project(new_project(stranger, &slug.to_uppercase()))
+            .await,
+        Err(ProjectRepositoryError::SlugAlreadyExists)
+    ));
+
+    // an empty patch returns the stored project
+    let unchanged = repo
+        .patch_project(owner, project.id, PatchProjectData::default())
+        .await
+        .unwrap();
+    assert_eq!(unchanged.title, project.title);
+    assert_eq!(unchanged.repo_url, project.repo_url);
+
+    // Value replaces, Null clears, Unset keeps
+    let patched = repo
+        .patch_project(
+            owner,
+            project.id,
+            PatchProjectData {
+                title: PatchField::Value(" Renamed ".to_string()),
+                tech_stack: PatchField::Value(vec!["Go".to_string(), "SQL".to_string()]),
+                repo_url: PatchField::Null,
+                live_demo_url: PatchField::Value("https://example.com/demo".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(patched.title, "Renamed");
+    assert_eq!(
+        patched.tech_stack,
+        vec!["Go".to_string(), "SQL".to_string()]
+    );
+    assert_eq!(patched.repo_url, None);
+    assert_eq!(
+        patched.live_demo_url.as_deref(),
+        Some("https://example.com/demo")
+    );
+    assert_eq!(patched.description, project.description);
+    assert_eq!(patched.slug, project.slug);
+    assert!(patched.updated_at >= project.updated_at);
+
+    // a write made against an older version is refused
+    let stale = repo
+        .patch_project(
+            owner,
+            project.id,
+            PatchProjectData {
+                title: PatchField::Value("Overwritten".to_string()),
+                expected_updated_at: Some(patched.updated_at - chrono::Duration::seconds(1)),
+                ..Default::default()
+            },
+        )
+        .await;
+    assert!(matches!(stale, Err(ProjectRepositoryError::Stale)));
+    let current = repo
+        .patch_project(
+            owner,
+            project.id,
+            PatchProjectData {
+                description: PatchField::Value("Still mine".to_string()),
+                expected_updated_at: Some(patched.updated_at),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(current.title, "Renamed");
+
+    // only the owner can patch; unknown ids look the same
+    let rename = || PatchProjectData {
+        title: PatchField::Value("Hijacked".to_string()),
+        ..Default::default()
+    };
+    assert!(matches!(
+        repo.patch_project(stranger, project.id, rename()).await,
+        Err(ProjectRepositoryError::NotFound)
+    ));
+    assert!(matches!(
+        repo.patch_project(owner, Uuid::new_v4(), rename()).await,
+        Err(ProjectRepositoryError::NotFound)
+    ));
+    assert!(matches!(
+        repo.patch_project(stranger, project.id, PatchProjectData::default())
+            .await,
+        Err(ProjectRepositoryError::NotFound)
+    ));
+}
+
+#[tokio::test]
+async fn in_memory_project_repository_meets_contract() {
+    project_repository_contract(
+        &InMemoryProjectStore::default(),
+        UserId::from(Uuid::new_v4()),
+        UserId::from(Uuid::new_v4()),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn postgres_project_repository_meets_contract() {
+    let Some(db) = test_db().await else {
+        return;
+    };
+    let owner = create_owner(&db).await;
+    let stranger = create_owner(&db).await;
+
+    project_repository_contract(&ProjectRepositoryPostgres::new(db.clone()), owner, stranger).await;
+
+    delete_owner(&db, owner).await;
+    delete_owner(&db, stranger).await;
+}
@@ -0,0 +1,152 @@
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::project::adapter::outgoing::{
+    InMemoryProjectStore, ProjectRepositoryPostgres, ProjectUpdateRepositoryPostgres,
+};
+use crate::project::application::ports::outgoing::project_repository::{
+    CreateProjectData, ProjectRepository,
+};
+use crate::project::application::ports::outgoing::project_update_repository::{
+    CreateProjectUpdateData, PatchProjectUpdateData, ProjectUpdateRepository,
+    ProjectUpdateRepositoryError,
+};
+use crate::tests::support::database::{create_owner, delete_owner, test_db};
+
+async fn create_project<P: ProjectRepository>(projects: &P, owner: UserId) -> Uuid {
+    projects
+        .create_project(CreateProjectData {
+            owner,
+            title: "Timeline".to_string(),
+            slug: format!("timeline-{}", Uuid::new_v4().simple()),
+            description: "Has updates".to_string(),
+            tech_stack: vec![],
+            screenshots: vec![],
+            repo_url: None,
+            live_demo_url: None,
+            seo_title: None,
+            seo_description: None,
+            canonical_url: None,
+            noindex: false,
+        })
+        .await
+        .unwrap()
+        .id
+}
+
+fn entry(title: &str) -> CreateProjectUpdateData {
+    CreateProjectUpdateData {
+        title: title.to_string(),
+        body: "Shipped *something*".to_string(),
+        posted_at: None,
+    }
+}
+
+/// `project_id` must be a live project of `owner`
+pub async fn project_update_repository_contract<R: ProjectUpdateRepository>(
+    repo: &R,
+    owner: UserId,
+    stranger: UserId,
+    project_id: Uuid,
+) {
+    // posted_at defaults to now, or keeps a backdated value
+    let before = Utc::now() - Duration::seconds(1);
+    let first = repo
+        .create_update(owner, project_id, entry("v0.1"))
+        .await
+        .unwrap();
+    assert_eq!(first.title, "v0.1");
+    assert!(first.posted_at >= before);
+
+    let backdated = Utc::now() - Duration::days(30);
+    let older = repo
+        .create_update(
+            owner,
+            project_id,
+            CreateProjectUpdateData {
+                posted_at: Some(backdated),
+                ..entry("v0.0")
+            },
+        )
+        .await
+        .unwrap();
+    assert!((older.posted_at - backdated).num_milliseconds().abs() < 1000);
+
+    // absent fields are kept
+    let patched = repo
+        .patch_update(
+            owner,
+            project_id,
+            first.id,
+            PatchProjectUpdateData {
+                title: Some("v0.1.1".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(patched.title, "v0.1.1");
+    assert_eq!(patched.body, first.body);
+
+    // only the owner's live project can be written
+    assert!(matches!(
+        repo.create_update(stranger, project_id, entry("nope"))
+            .await,
+        Err(ProjectUpdateRepositoryError::ProjectNotFound)
+    ));
+    assert!(matches!(
+        repo.patch_update(stranger, project_id, first.id, Default::default())
+            .await,
+        Err(ProjectUpdateRepositoryError::ProjectNotFound)
+    ));
+    assert!(matches!(
+        repo.delete_update(owner, Uuid::new_v4(), first.id).await,
+        Err(ProjectUpdateRepositoryError::ProjectNotFound)
+    ));
+
+    // entries are looked up within their project
+    assert!(matches!(
+        repo.patch_update(owner, project_id, Uuid::new_v4(), Default::default())
+            .await,
+        Err(ProjectUpdateRepositoryError::UpdateNotFound)
+    ));
+    repo.delete_update(owner, project_id, older.id)
+        .await
+        .unwrap();
+    assert!(matches!(
+        repo.delete_update(owner, project_id, older.id).await,
+        Err(ProjectUpdateRepositoryError::UpdateNotFound)
+    ));
+}
+
+#[tokio::test]
+async fn in_memory_project_update_repository_meets_contract() {
+    let store = InMemoryProjectStore::default();
+    let owner = UserId::from(Uuid::new_v4());
+    let project_id = create_project(&store, owner).await;
+
+    project_update_repository_contract(&store, owner, UserId::from(Uuid::new_v4()), project_id)
+        .await;
+}
+
+#[tokio::test]
+async fn postgres_project_update_repository_meets_contract() {
+    let Some(db) = test_db().await else {
+        return;
+    };
+    let owner = create_owner(&db).await;
+    let stranger = create_owner(&db).await;
+    let project_id = create_project(&ProjectRepositoryPostgres::new(db.clone()), owner).await;
+
+    project_update_repository_contract(
+        &ProjectUpdateRepositoryPostgres::new(db.clone()),
+        owner,
+        stranger,
+        project_id,
+    )
+    .await;
+
+    delete_owner(&db, owner).await;
+    delete_owner(&db, stranger).await;
+}
@@ -0,0 +1,139 @@
+use uuid::Uuid;
+
+use crate::auth::adapter::outgoing::in_memory::InMemoryUserStore;
+use crate::auth::adapter::outgoing::user_repository_postgres::UserRepositoryPostgres;
+use crate::auth::application::ports::outgoing::user_repository::{
+    CreateUserData, UserRepository, UserRepositoryError,
+};
+use crate::tests::support::database::test_db;
+
+fn new_user(tag: &str) -> CreateUserData {
+    CreateUserData {
+        email: format!("contract-{tag}@example.com"),
+        username: format!("contract_{tag}"),
+        password_hash: "hash".to_string(),
+        full_name: "Contract User".to_string(),
+        preferred_locale: "en".to_string(),
+    }
+}
+
+pub async fn user_repository_contract<R: UserRepository>(repo: &R) {
+    let tag = Uuid::new_v4().simple().to_string()[..16].to_string();
+    let data = new_user(&tag);
+
+    // create returns what was stored
+    let user = repo.create_user(data.clone()).await.unwrap();
+    assert_eq!(user.email, data.email);
+    assert_eq!(user.username, data.username);
+    assert_eq!(user.full_name, data.full_name);
+    assert_eq!(user.preferred_locale, data.preferred_locale);
+
+    // email and username are unique
+    let same_email = CreateUserData {
+        username: format!("other_{tag}"),
+        ..data.clone()
+    };
+    assert!(matches!(
+        repo.create_user(same_email).await,
+        Err(UserRepositoryError::UserAlreadyExists)
+    ));
+    let same_username = CreateUserData {
+        email: format!("other-{tag}@example.com"),
+        ..data.clone()
+    };
+    assert!(matches!(
+        repo.create_user(same_username).await,
+        Err(UserRepositoryError::UserAlreadyExists)
+    ));
+
+    // updates on a live account
+    let renamed = repo
+        .update_profile(user.id, "Renamed User".to_string(), Some("id".to_string()))
+        .await
+        .unwrap();
+    assert_eq!(renamed.full_name, "Renamed User");
+    assert_eq!(renamed.preferred_locale, "id");
+    // no locale keeps the current one
+    let renamed = repo
+        .update_profile(user.id, "Renamed User".to_string(), None)
+        .await
+        .unwrap();
+    assert_eq!(renamed.preferred_locale, "id");
+    assert_eq!(repo.activate_user(user.id).await.unwrap().id, user.id);
+    repo.update_password(user.id, "new-hash".to_string())
+        .await
+        .unwrap();
+
+    // restore only applies to deleted accounts
+    assert!(matches!(
+        repo.restore_user(user.id).await,
+        Err(UserRepositoryError::UserNotFound)
+    ));
+
+    // a soft-deleted account can't be changed or deleted again
+    repo.soft_delete_user(user.id).await.unwrap();
+    assert!(matches!(
+        repo.soft_delete_user(user.id).await,
+        Err(UserRepositoryError::UserNotFound)
+    ));
+    assert!(matches!(
+        repo.update_profile(user.id, "Nope".to_string(), None).await,
+        Err(UserRepositoryError::UserNotFound)
+    ));
+    assert!(matches!(
+        repo.update_password(user.id, "nope".to_string()).await,
+        Err(UserRepositoryError::UserNotFound)
+    ));
+    assert!(matches!(
+        repo.activate_user(user.id).await,
+        Err(UserRepositoryError::UserNotFound)
+    ));
+
+    // ...but it keeps its email and username reserved
+    assert!(matches!(
+        repo.create_user(data.clone()).await,
+        Err(UserRepositoryError::UserAlreadyExists)
+    ));
+
+    // restore brings it back as it was
+    let restored = repo.restore_user(user.id).await.unwrap();
+    assert_eq!(restored.email, data.email);
+    assert_eq!(restored.full_name, "Renamed User");
+
+    // hard delete is final
+    repo.delete_user(user.id).await.unwrap();
+    assert!(matches!(
+        repo.delete_user(user.id).await,
+        Err(UserRepositoryError::UserNotFound)
+    ));
+    assert!(matches!(
+        repo.restore_user(user.id).await,
+        Err(UserRepositoryError::UserNotFound)
+    ));
+
+    // unknown ids
+    let unknown = Uuid::new_v4();
+    assert!(matches!(
+        repo.update_profile(unknown, "Nobody".to_string(), None)
+            .await,
+        Err(UserRepositoryError::UserNotFound)
+    ));
+    assert!(matches!(
+        repo.soft_delete_user(unknown).await,
+        Err(UserRepositoryError::UserNotFound)
+    ));
+}
+
+#[tokio::test]
+async fn in_memory_user_repository_meets_contract() {
+    user_repository_contract(&InMemoryUserStore::default()).await;
+}
+
+#[tokio::test]
+async fn postgres_user_repository_meets_contract() {
+    let Some(db) = test_db().await else {
+        return;
+    };
+
+    user_repository_contract(&UserRepositoryPostgres::new(db)).await;
+}
@@ -0,0 +1,82 @@
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
+use crate::tests::support::database::{create_owner, delete_owner, test_db};
+use crate::webhooks::adapter::outgoing::{InMemoryWebhookStore, WebhookRepositoryPostgres};
+use crate::webhooks::application::domain::entities::WebhookEvent;
+use crate::webhooks::application::ports::outgoing::{WebhookRepository, WebhookRepositoryError};
+
+/// `owner` and `stranger` must be accounts the adapter accepts as owners
+pub async fn webhook_repository_contract<R: WebhookRepository>(
+    repo: &R,
+    owner: UserId,
+    stranger: UserId,
+) {
+    let events = [WebhookEvent::MediaReady, WebhookEvent::ProjectUpdated];
+
+    // create returns the endpoint with its events
+    let endpoint = repo
+        .create(owner, "https://example.com/hook", &events, "whsec_contract")
+        .await
+        .unwrap();
+    assert_eq!(endpoint.url, "https://example.com/hook");
+    assert_eq!(endpoint.events, events.to_vec());
+
+    // listing is per owner
+    let listed = repo.list(owner).await.unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].id, endpoint.id);
+    assert!(repo.list(stranger).await.unwrap().is_empty());
+
+    // a new endpoint has an empty delivery log, visible to its owner only
+    let page = repo
+        .list_deliveries(owner, endpoint.id, 0, 20)
+        .await
+        .unwrap();
+    assert!(page.items.is_empty());
+    assert_eq!(page.total, 0);
+    assert!(matches!(
+        repo.list_deliveries(stranger, endpoint.id, 0, 20).await,
+        Err(WebhookRepositoryError::NotFound)
+    ));
+
+    // only the owner can delete, and only once
+    assert!(matches!(
+        repo.delete(stranger, endpoint.id).await,
+        Err(WebhookRepositoryError::NotFound)
+    ));
+    repo.delete(owner, endpoint.id).await.unwrap();
+    assert!(matches!(
+        repo.delete(owner, endpoint.id).await,
+        Err(WebhookRepositoryError::NotFound)
+    ));
+    assert!(repo.list(owner).await.unwrap().is_empty());
+    assert!(matches!(
+        repo.list_deliveries(owner, Uuid::new_v4(), 0, 20).await,
+        Err(WebhookRepositoryError::NotFound)
+    ));
+}
+
+#[tokio::test]
+async fn in_memory_webhook_repository_meets_contract() {
+    webhook_repository_contract(
+        &InMemoryWebhookStore::default(),
+        UserId::from(Uuid::new_v4()),
+        UserId::from(Uuid::new_v4()),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn postgres_webhook_repository_meets_contract() {
+    let Some(db) = test_db().await else {
+        return;
+    };
+    let owner = create_owner(&db).await;
+    let stranger = create_owner(&db).await;
+
+    webhook_repository_contract(&WebhookRepositoryPostgres::new(db.clone()), owner, stranger).await;
+
+    delete_owner(&db, owner).await;
+    delete_owner(&db, stranger).await;
+}
@@ -1 +1,2 @@
+pub mod contracts;
 pub mod support;
@@ -1,41 +1,132 @@
-use crate::auth::application::helpers::UserIdentityResolver;
+use crate::activity::application::ports::incoming::use_cases::ListActivitiesUseCase;
+use crate::admin::application::ports::incoming::use_cases::{
+    CheckIntegrityUseCase, GetAdminStatsUseCase, GetStorageReportUseCase, QuickSearchUseCase,
+};
+use crate::analytics::application::analytics_use_cases::AnalyticsUseCases;
+use crate::analytics::application::ports::incoming::use_cases::{
+    GetDailyVisitorsUseCase, GetPathReportUseCase, GetTopPagesUseCase, GetTopReferrersUseCase,
+    RecordPageViewUseCase,
+};
+use crate::auth::adapter::incoming::web::cookies::AuthCookies;
+use crate::auth::adapter::outgoing::in_memory::{
+    InMemoryPasskeyChallenges, InMemoryPasskeyStore, InMemoryTokenRepository, InMemoryUserStore,
+};
+use crate::auth::adapter::outgoing::webauthn_ceremony::DisabledPasskeys;
+use crate::auth::application::auth_use_cases::AuthUseCases;
+use crate::auth::application::helpers::{TokenVersionGuard, UserIdentityResolver};
 use crate::auth::application::orchestrator::user_registration::UserRegistrationOrchestrator;
+use crate::auth::application::passkey_use_cases::PasskeyUseCases;
+use crate::auth::application::ports::outgoing::TokenVersionRepository;
+use crate::auth::application::services::{GetUserProfileService, PatchUserProfileService};
 use crate::auth::application::use_cases::fetch_profile::FetchUserProfileUseCase;
 use crate::auth::application::use_cases::refresh_token::IRefreshTokenUseCase;
 use crate::auth::application::use_cases::soft_delete_user::ISoftDeleteUserUseCase;
 use crate::auth::application::use_cases::update_profile::UpdateUserProfileUseCase;
+use crate::auth::application::use_cases::user_profile::{
+    GetUserProfileUseCase, PatchUserProfileUseCase,
+};
 use crate::auth::application::use_cases::{
-    login_user::ILoginUserUseCase, logout_user::ILogoutUseCase,
+    deactivate_account::IDeactivateAccountUseCase,
+    introspect_token::{IIntrospectTokenUseCase, IntrospectTokenUseCase},
+    lockdown_user_content::ILockdownUserContentUseCase,
+    login_user::ILoginUserUseCase,
+    logout_all::ILogoutAllUseCase,
+    logout_user::ILogoutUseCase,
+    reactivate_account::IReactivateAccountUseCase,
     verify_user_email::IVerifyUserEmailUseCase,
 };
+use crate::batch::application::ports::incoming::use_cases::RunBatchUseCase;
+use crate::blocklist::adapter::outgoing::InMemoryBlockRuleStore;
+use crate::blocklist::application::blocklist_use_cases::BlocklistUseCases;
+use crate::config::reload::ConfigReloader;
+use crate::config::ConfigError;
+use crate::contact::application::contact_use_cases::ContactUseCases;
+use crate::contact::application::ports::incoming::use_cases::{
+    DeleteContactMessageUseCase, ListContactMessagesUseCase, MarkContactMessageReadUseCase,
+    SubmitContactMessageUseCase,
+};
+use crate::cv::application::cv_use_cases::CvUseCases;
 use crate::cv::application::use_cases::create_cv::ICreateCVUseCase;
+use crate::cv::application::use_cases::export_cv::IExportCvUseCase;
 use crate::cv::application::use_cases::fetch_cv_by_id::IFetchCVByIdUseCase;
 use crate::cv::application::use_cases::fetch_user_cvs::IFetchCVUseCase;
 use crate::cv::application::use_cases::get_public_single_cv::GetPublicSingleCvUseCase;
 use crate::cv::application::use_cases::hard_delete_cv::HardDeleteCvUseCase;
 use crate::cv::application::use_cases::patch_cv::IPatchCVUseCase;
 use crate::cv::application::use_cases::update_cv::IUpdateCVUseCase;
+use crate::email::application::email_use_cases::EmailUseCases;
+use crate::email::application::ports::incoming::use_cases::{
+    ListOutboxEmailsUseCase, RecordEmailEventsUseCase, UnsubscribeUseCase,
+};
+use crate::export::application::ports::incoming::use_cases::ExportContentUseCase;
+use crate::import::application::ports::incoming::use_cases::ImportContentUseCase;
 use crate::modules::project::application::ports::incoming::use_cases::CreateProjectUseCase;
 use crate::modules::project::application::project_use_cases::ProjectUseCases;
+use crate::multimedia::adapter::outgoing::db::InMemoryStorageOverrides;
 use crate::multimedia::application::domain::policies::upload_policy::UploadPolicy;
 use crate::multimedia::application::media_use_cases::MultimediaUseCases;
+use crate::multimedia::application::ports::incoming::services::{
+    DeleteStorageOverrideService, GetStorageOverrideService, SetStorageOverrideService,
+};
 use crate::multimedia::application::ports::incoming::use_cases::{
-    CreateUploadMediaUrlUseCase, GetVariantReadUrlUseCase, ListMediaUseCase,
+    CreateUploadBatchError, CreateUploadBatchUseCase, CreateUploadMediaUrlUseCase,
+    FinalizeUploadError, FinalizeUploadUseCase, GetPreviewImageUseCase, GetUploadBatchError,
+    GetUploadBatchUseCase, GetVariantReadUrlUseCase, GetVariantReadUrlsUseCase,
+    IngestMediaManifestUseCase, ListMediaUseCase, ProxyImageError, ProxyImageUseCase,
+    RegenerateMediaError, RegenerateMediaUseCase,
+};
+use crate::pages::application::page_use_cases::PageUseCases;
+use crate::pages::application::ports::incoming::use_cases::{
+    AcquirePageLockUseCase, AutosavePageUseCase, CreatePageUseCase, CreatePreviewTokenUseCase,
+    DeletePageUseCase, GetPageAutosaveUseCase, GetPagePreviewUseCase, GetPageUseCase,
+    GetPublicPageUseCase, ListPagesUseCase, UpdatePageUseCase,
 };
 use crate::project::application::ports::incoming::use_cases::{
     GetProjectsUseCase, GetPublicSingleProjectUseCase, GetSingleProjectUseCase, PatchProjectUseCase,
 };
+use crate::redirects::application::ports::incoming::use_cases::{
+    CreateRedirectUseCase, DeleteRedirectUseCase, ListRedirectsUseCase, ResolveRedirectUseCase,
+    UpdateRedirectUseCase,
+};
+use crate::redirects::application::redirect_use_cases::RedirectUseCases;
+use crate::search::application::ports::incoming::use_cases::SearchContentUseCase;
+use crate::shared::bot_check::BotGate;
+use crate::shared::cache::NoopCache;
+use crate::shared::rate_limit::RateLimiter;
+use crate::site::application::ports::incoming::use_cases::{
+    GetSiteProfileUseCase, GetThemeUseCase, UpdateSiteSettingsUseCase, UpdateThemeUseCase,
+};
+use crate::site::application::site_use_cases::SiteUseCases;
+use crate::skills::adapter::outgoing::InMemorySkillStore;
+use crate::skills::application::skill_use_cases::SkillUseCases;
+use crate::tests::support::auth_helper::test_helpers::create_test_jwt_service;
 use crate::tests::support::stubs::*;
 use crate::topic::application::ports::incoming::use_cases::{
     CreateTopicUseCase, GetTopicsUseCase, SoftDeleteTopicUseCase,
 };
+use crate::topic::application::topic_use_cases::TopicUseCases;
+use crate::translations::application::ports::incoming::use_cases::{
+    DeleteTranslationUseCase, ListTranslationsUseCase, LocalizeContentUseCase,
+    PutTranslationUseCase,
+};
+use crate::translations::application::translation_use_cases::TranslationUseCases;
+use crate::trash::application::ports::incoming::use_cases::{
+    ListTrashUseCase, RestoreTrashItemUseCase,
+};
+use crate::trash::application::trash_use_cases::TrashUseCases;
+use crate::webhooks::application::ports::incoming::use_cases::{
+    CreateWebhookUseCase, DeleteWebhookUseCase, ListWebhookDeliveriesUseCase, ListWebhooksUseCase,
+};
+use crate::webhooks::application::webhook_use_cases::WebhookUseCases;
 use crate::AppState;
 use actix_web::web;
 use std::sync::Arc;
+use uuid::Uuid;
 
 pub struct TestAppStateBuilder {
     fetch_cv: Option<Arc<dyn IFetchCVUseCase + Send + Sync>>,
     fetch_cv_by_id: Option<Arc<dyn IFetchCVByIdUseCase + Send + Sync>>,
+    export_cv_text: Option<Arc<dyn IExportCvUseCase + Send + Sync>>,
     get_public_single_cv_use_case: Option<Arc<dyn GetPublicSingleCvUseCase + Send + Sync>>,
     create_cv: Option<Arc<dyn ICreateCVUseCase + Send + Sync>>,
     update_cv: Option<Arc<dyn IUpdateCVUseCase + Send + Sync>>,
@@ -45,9 +136,18 @@ pub struct TestAppStateBuilder {
     login_user: Option<Arc<dyn ILoginUserUseCase + Send + Sync>>,
     refresh_token: Option<Arc<dyn IRefreshTokenUseCase + Send + Sync>>,
     logout_user: Option<Arc<dyn ILogoutUseCase + Send + Sync>>,
+    logout_all: Option<Arc<dyn ILogoutAllUseCase + Send + Sync>>,
+    token_version_guard: Option<TokenVersionGuard>,
+    auth_cookies: Option<AuthCookies>,
+    passkeys: Option<PasskeyUseCases>,
     soft_delete_user: Option<Arc<dyn ISoftDeleteUserUseCase + Send + Sync>>,
+    lockdown_user_content: Option<Arc<dyn ILockdownUserContentUseCase + Send + Sync>>,
+    deactivate_account: Option<Arc<dyn IDeactivateAccountUseCase + Send + Sync>>,
+    reactivate_account: Option<Arc<dyn IReactivateAccountUseCase + Send + Sync>>,
     fetch_user_profile: Option<Arc<dyn FetchUserProfileUseCase + Send + Sync>>,
     update_user_profile: Option<Arc<dyn UpdateUserProfileUseCase + Send + Sync>>,
+    get_profile: Option<Arc<dyn GetUserProfileUseCase + Send + Sync>>,
+    patch_profile: Option<Arc<dyn PatchUserProfileUseCase + Send + Sync>>,
     hard_delete_cv: Option<Arc<dyn HardDeleteCvUseCase + Send + Sync>>,
     create_topic: Option<Arc<dyn CreateTopicUseCase + Send + Sync>>,
     get_topics: Option<Arc<dyn GetTopicsUseCase + Send + Sync>>,
@@ -55,24 +155,55 @@ pub struct TestAppStateBuilder {
     project: Option<ProjectUseCases>,
     multimedia: Option<MultimediaUseCases>,
     user_identity_resolver: Option<UserIdentityResolver>,
+    admin_stats: Option<Arc<dyn GetAdminStatsUseCase + Send + Sync>>,
+    integrity_check: Option<Arc<dyn CheckIntegrityUseCase + Send + Sync>>,
+    quick_search: Option<Arc<dyn QuickSearchUseCase + Send + Sync>>,
+    storage_report: Option<Arc<dyn GetStorageReportUseCase + Send + Sync>>,
+    admin_user_ids: Vec<Uuid>,
+    list_trash: Option<Arc<dyn ListTrashUseCase + Send + Sync>>,
+    restore_trash_item: Option<Arc<dyn RestoreTrashItemUseCase + Send + Sync>>,
+    webhooks: Option<WebhookUseCases>,
+    list_outbox_emails: Option<Arc<dyn ListOutboxEmailsUseCase + Send + Sync>>,
+    record_email_events: Option<Arc<dyn RecordEmailEventsUseCase + Send + Sync>>,
+    email_events_token: Option<String>,
+    media_callback_secret: Option<String>,
+    introspect_token: Option<Arc<dyn IIntrospectTokenUseCase + Send + Sync>>,
+    introspection_token: Option<String>,
+    metrics_token: Option<String>,
+    config_reloader: Option<ConfigReloader>,
+    unsubscribe: Option<Arc<dyn UnsubscribeUseCase + Send + Sync>>,
+    contact: Option<ContactUseCases>,
+    contact_rate_limiter: RateLimiter,
+    upload_url_rate_limiter: RateLimiter,
+    bot_gate: BotGate,
+    site: Option<SiteUseCases>,
+    pages: Option<PageUseCases>,
+    analytics: Option<AnalyticsUseCases>,
+    redirects: Option<RedirectUseCases>,
+    blocklist: Option<BlocklistUseCases>,
+    search_content: Option<Arc<dyn SearchContentUseCase + Send + Sync>>,
+    export_content: Option<Arc<dyn ExportContentUseCase + Send + Sync>>,
+    import_content: Option<Arc<dyn ImportContentUseCase + Send + Sync>>,
+    run_batch: Option<Arc<dyn RunBatchUseCase + Send + Sync>>,
+    list_activities: Option<Arc<dyn ListActivitiesUseCase + Send + Sync>>,
+    translations: Option<TranslationUseCases>,
+    skills: Option<SkillUseCases>,
 }
 
 pub fn default_test_user_registration_orchestrator() -> Arc<UserRegistrationOrchestrator> {
     let create_user = Arc::new(StubCreateUserUseCase);
-    let email_notifier = Arc::new(StubUserEmailNotifier);
 
-    Arc::new(UserRegistrationOrchestrator::new(
-        create_user,
-        email_notifier,
-    ))
+    Arc::new(UserRegistrationOrchestrator::new(create_user))
 }
 
 impl Default for TestAppStateBuilder {
     fn default() -> Self {
         let user_identity_resolver = UserIdentityResolver::new(Arc::new(DummyUserQuery));
+        let storage_overrides = InMemoryStorageOverrides::default();
         Self {
             fetch_cv: Some(Arc::new(StubFetchCVUseCase)),
             fetch_cv_by_id: Some(Arc::new(StubFetchCVByIdUseCase)),
+            export_cv_text: Some(Arc::new(StubExportCvUseCase)),
             get_public_single_cv_use_case: Some(StubGetPublicSingleCvUseCase::not_found()),
             create_cv: Some(Arc::new(StubCreateCVUseCase)),
             update_cv: Some(Arc::new(StubUpdateCVUseCase)),
@@ -82,9 +213,26 @@ impl Default for TestAppStateBuilder {
             login_user: Some(Arc::new(StubLoginUserUseCase)),
             refresh_token: Some(Arc::new(StubRefreshTokenUseCase)),
             logout_user: Some(Arc::new(StubLogoutUserUseCase)),
+            logout_all: Some(Arc::new(StubLogoutAllUseCase::success())),
+            token_version_guard: Some(TokenVersionGuard::new(
+                Arc::new(StubTokenVersionRepository::at(0)),
+                Arc::new(NoopCache),
+            )),
+            auth_cookies: None,
+            passkeys: None,
             soft_delete_user: Some(Arc::new(StubSoftDeleteUserUseCase)),
+            lockdown_user_content: Some(Arc::new(StubLockdownUserContentUseCase::success())),
+            deactivate_account: Some(Arc::new(StubDeactivateAccountUseCase::success())),
+            reactivate_account: Some(Arc::new(StubReactivateAccountUseCase::success())),
             fetch_user_profile: Some(Arc::new(StubFetchUserProfileUseCase)),
             update_user_profile: Some(Arc::new(StubUpdateUserProfileUseCase)),
+            // No users, so every profile is not found
+            get_profile: Some(Arc::new(GetUserProfileService::new(
+                InMemoryUserStore::default(),
+            ))),
+            patch_profile: Some(Arc::new(PatchUserProfileService::new(
+                InMemoryUserStore::default(),
+            ))),
             hard_delete_cv: Some(Arc::new(StubHardDeleteCvUseCase)),
             create_topic: Some(Arc::new(StubCreateTopicUseCase)),
             get_topics: Some(Arc::new(StubGetTopicsUseCase::success(vec![]))),
@@ -101,14 +249,124 @@ impl Default for TestAppStateBuilder {
                 get_topics: Arc::new(StubGetProjectTopicsUseCase),
                 remove_topic: Arc::new(StubRemoveProjectTopicUseCase),
                 clear_topics: Arc::new(StubClearProjectTopicsUseCase),
+                create_update: Arc::new(StubCreateProjectUpdateUseCase),
+                patch_update: Arc::new(StubPatchProjectUpdateUseCase),
+                delete_update: Arc::new(StubDeleteProjectUpdateUseCase),
                 hard_delete: Arc::new(StubHardDeleteProjectUseCase),
+                sync_readme: Arc::new(StubSyncProjectReadmeUseCase),
             }),
             multimedia: Some(MultimediaUseCases {
                 create_signed_post_url: Arc::new(StubCreateUploadMediaUrlUseCase),
+                create_upload_batch: Arc::new(StubCreateUploadBatchUseCase::failure(
+                    CreateUploadBatchError::RepositoryError("not configured".to_string()),
+                )),
+                get_upload_batch: Arc::new(StubGetUploadBatchUseCase::failure(
+                    GetUploadBatchError::NotFound,
+                )),
+                finalize_upload: Arc::new(StubFinalizeUploadUseCase::failure(
+                    FinalizeUploadError::MediaNotFound,
+                )),
                 create_signed_get_url: Arc::new(StubGetVariantReadUrlService),
+                create_signed_get_urls: Arc::new(StubGetVariantReadUrlsUseCase::signing()),
+                get_preview_image: Arc::new(StubGetPreviewImageUseCase::none()),
                 list_media: Arc::new(StubListMediaUseCase),
+                regenerate_media: Arc::new(StubRegenerateMediaUseCase::failure(
+                    RegenerateMediaError::MediaNotFound,
+                )),
+                proxy_image: Arc::new(StubProxyImageUseCase::failure(
+                    ProxyImageError::OriginNotAllowed,
+                )),
+                ingest_manifest: Arc::new(StubIngestMediaManifestUseCase::success()),
+                get_storage_override: Arc::new(GetStorageOverrideService::new(
+                    storage_overrides.clone(),
+                )),
+                set_storage_override: Arc::new(SetStorageOverrideService::new(
+                    storage_overrides.clone(),
+                    Vec::new(),
+                )),
+                delete_storage_override: Arc::new(DeleteStorageOverrideService::new(
+                    storage_overrides,
+                )),
             }),
             user_identity_resolver: Some(user_identity_resolver),
+            admin_stats: Some(Arc::new(StubGetAdminStatsUseCase::success())),
+            integrity_check: Some(Arc::new(StubCheckIntegrityUseCase::clean())),
+            quick_search: Some(Arc::new(StubQuickSearchUseCase::empty())),
+            storage_report: Some(Arc::new(StubGetStorageReportUseCase::with_avif())),
+            admin_user_ids: Vec::new(),
+            list_trash: Some(Arc::new(StubListTrashUseCase::success())),
+            restore_trash_item: Some(Arc::new(StubRestoreTrashItemUseCase::success())),
+            webhooks: Some(WebhookUseCases {
+                create: Arc::new(StubCreateWebhookUseCase),
+                list: Arc::new(StubListWebhooksUseCase::empty()),
+                delete: Arc::new(StubDeleteWebhookUseCase::success()),
+                list_deliveries: Arc::new(StubListWebhookDeliveriesUseCase::empty()),
+            }),
+            list_outbox_emails: Some(Arc::new(StubListOutboxEmailsUseCase::empty())),
+            record_email_events: Some(Arc::new(StubRecordEmailEventsUseCase::success())),
+            email_events_token: None,
+            media_callback_secret: None,
+            introspect_token: None,
+            introspection_token: None,
+            metrics_token: None,
+            config_reloader: None,
+            unsubscribe: Some(Arc::new(StubUnsubscribeUseCase::success())),
+            contact: Some(ContactUseCases {
+                submit: Arc::new(StubSubmitContactMessageUseCase::success()),
+                list: Arc::new(StubListContactMessagesUseCase::empty()),
+                mark_read: Arc::new(StubMarkContactMessageReadUseCase::success()),
+                delete: Arc::new(StubDeleteContactMessageUseCase::success()),
+            }),
+            contact_rate_limiter: RateLimiter::disabled(),
+            upload_url_rate_limiter: RateLimiter::disabled(),
+            bot_gate: BotGate::disabled(),
+            site: Some(SiteUseCases {
+                get: Arc::new(StubGetSiteProfileUseCase::defaults()),
+                update: Arc::new(StubUpdateSiteSettingsUseCase::success()),
+                get_theme: Arc::new(StubGetThemeUseCase::defaults()),
+                update_theme: Arc::new(StubUpdateThemeUseCase::success()),
+            }),
+            pages: Some(PageUseCases {
+                create: Arc::new(StubCreatePageUseCase::success()),
+                list: Arc::new(StubListPagesUseCase::empty()),
+                get: Arc::new(StubGetPageUseCase::found()),
+                update: Arc::new(StubUpdatePageUseCase::success()),
+                delete: Arc::new(StubDeletePageUseCase::success()),
+                create_preview_token: Arc::new(StubCreatePreviewTokenUseCase::success()),
+                get_preview: Arc::new(StubGetPagePreviewUseCase::draft()),
+                get_public: Arc::new(StubGetPublicPageUseCase::found()),
+                acquire_lock: Arc::new(StubAcquirePageLockUseCase::success()),
+                release_lock: Arc::new(StubReleasePageLockUseCase::success()),
+                autosave: Arc::new(StubAutosavePageUseCase::success()),
+                get_autosave: Arc::new(StubGetPageAutosaveUseCase::none()),
+            }),
+            analytics: Some(AnalyticsUseCases {
+                record: Arc::new(StubRecordPageViewUseCase),
+                top_pages: Arc::new(StubGetTopPagesUseCase::one()),
+                top_referrers: Arc::new(StubGetTopReferrersUseCase),
+                daily_visitors: Arc::new(StubGetDailyVisitorsUseCase),
+                path_report: Arc::new(StubGetPathReportUseCase),
+            }),
+            redirects: Some(RedirectUseCases {
+                create: Arc::new(StubCreateRedirectUseCase::success()),
+                list: Arc::new(StubListRedirectsUseCase::empty()),
+                update: Arc::new(StubUpdateRedirectUseCase::success()),
+                delete: Arc::new(StubDeleteRedirectUseCase::success()),
+                resolve: Arc::new(StubResolveRedirectUseCase::none()),
+            }),
+            blocklist: None,
+            search_content: Some(Arc::new(StubSearchContentUseCase::empty())),
+            export_content: Some(Arc::new(StubExportContentUseCase::with_chunks(vec![]))),
+            import_content: Some(Arc::new(StubImportContentUseCase::success())),
+            run_batch: Some(Arc::new(StubRunBatchUseCase::success())),
+            list_activities: Some(Arc::new(StubListActivitiesUseCase::empty())),
+            translations: Some(TranslationUseCases {
+                put: Arc::new(StubPutTranslationUseCase::success()),
+                list: Arc::new(StubListTranslationsUseCase::success()),
+                delete: Arc::new(StubDeleteTranslationUseCase::success()),
+                localize: Arc::new(StubLocalizeContentUseCase::none()),
+            }),
+            skills: None,
         }
     }
 }
@@ -131,6 +389,11 @@ impl TestAppStateBuilder {
         self
     }
 
+    pub fn with_export_cv_text(mut self, uc: impl IExportCvUseCase + 'static) -> Self {
+        self.export_cv_text = Some(Arc::new(uc));
+        self
+    }
+
     // In TestAppStateBuilder
     pub fn with_register_user_orchestrator(
         mut self,
@@ -176,6 +439,34 @@ impl TestAppStateBuilder {
         self
     }
 
+    pub fn with_logout_all(mut self, uc: impl ILogoutAllUseCase + 'static) -> Self {
+        self.logout_all = Some(Arc::new(uc));
+        self
+    }
+
+    /// Token versions the extractors check access tokens against
+    pub fn with_token_version_repository(
+        mut self,
+        repository: impl TokenVersionRepository + 'static,
+    ) -> Self {
+        self.token_version_guard = Some(TokenVersionGuard::new(
+            Arc::new(repository),
+            Arc::new(NoopCache),
+        ));
+        self
+    }
+
+    /// Cookie mode (`AUTH_TRANSPORT=cookie`)
+    pub fn with_auth_cookies(mut self, cookies: AuthCookies) -> Self {
+        self.auth_cookies = Some(cookies);
+        self
+    }
+
+    pub fn with_passkeys(mut self, passkeys: PasskeyUseCases) -> Self {
+        self.passkeys = Some(passkeys);
+        self
+    }
+
     pub fn with_soft_delete_user(
         mut self,
         uc: impl ISoftDeleteUserUseCase + Send + Sync + 'static,
@@ -184,6 +475,24 @@ impl TestAppStateBuilder {
         self
     }
 
+    pub fn with_lockdown_user_content(
+        mut self,
+        uc: impl ILockdownUserContentUseCase + 'static,
+    ) -> Self {
+        self.lockdown_user_content = Some(Arc::new(uc));
+        self
+    }
+
+    pub fn with_deactivate_account(mut self, uc: impl IDeactivateAccountUseCase + 'static) -> Self {
+        self.deactivate_account = Some(Arc::new(uc));
+        self
+    }
+
+    pub fn with_reactivate_account(mut self, uc: impl IReactivateAccountUseCase + 'static) -> Self {
+        self.reactivate_account = Some(Arc::new(uc));
+        self
+    }
+
     pub fn with_fetch_user_profile(
         mut self,
         uc: impl FetchUserProfileUseCase + Send + Sync + 'static,
@@ -192,6 +501,16 @@ impl TestAppStateBuilder {
         self
     }
 
+    pub fn with_get_profile(mut self, uc: impl GetUserProfileUseCase + 'static) -> Self {
+        self.get_profile = Some(Arc::new(uc));
+        self
+    }
+
+    pub fn with_patch_profile(mut self, uc: impl PatchUserProfileUseCase + 'static) -> Self {
+        self.patch_profile = Some(Arc::new(uc));
+        self
+    }
+
     pub fn with_update_user_profile(
         mut self,
         uc: impl UpdateUserProfileUseCase + Send + Sync + 'static,
@@ -376,6 +695,58 @@ impl TestAppStateBuilder {
         project.hard_delete = std::sync::Arc::new(uc);
         self
     }
+    pub fn with_create_project_update<U>(mut self, uc: U) -> Self
+    where
+        U: crate::modules::project::application::ports::incoming::use_cases::CreateProjectUpdateUseCase
+            + 'static,
+    {
+        let project = self
+            .project
+            .as_mut()
+            .expect("Project use cases must be initialized");
+
+        project.create_update = std::sync::Arc::new(uc);
+        self
+    }
+    pub fn with_patch_project_update<U>(mut self, uc: U) -> Self
+    where
+        U: crate::modules::project::application::ports::incoming::use_cases::PatchProjectUpdateUseCase
+            + 'static,
+    {
+        let project = self
+            .project
+            .as_mut()
+            .expect("Project use cases must be initialized");
+
+        project.patch_update = std::sync::Arc::new(uc);
+        self
+    }
+    pub fn with_delete_project_update<U>(mut self, uc: U) -> Self
+    where
+        U: crate::modules::project::application::ports::incoming::use_cases::DeleteProjectUpdateUseCase
+            + 'static,
+    {
+        let project = self
+            .project
+            .as_mut()
+            .expect("Project use cases must be initialized");
+
+        project.delete_update = std::sync::Arc::new(uc);
+        self
+    }
+    pub fn with_sync_project_readme<U>(mut self, uc: U) -> Self
+    where
+        U: crate::modules::project::application::ports::incoming::use_cases::SyncProjectReadmeUseCase
+            + 'static,
+    {
+        let project = self
+            .project
+            .as_mut()
+            .expect("Project use cases must be initialized");
+
+        project.sync_readme = std::sync::Arc::new(uc);
+        self
+    }
     pub fn with_create_upload_media_url(
         mut self,
         uc: impl CreateUploadMediaUrlUseCase + Send + Sync + 'static,
@@ -388,6 +759,63 @@ impl TestAppStateBuilder {
         multimedia.create_signed_post_url = Arc::new(uc);
         self
     }
+    pub fn with_create_upload_batch(mut self, uc: impl CreateUploadBatchUseCase + 'static) -> Self {
+        let multimedia = self
+            .multimedia
+            .as_mut()
+            .expect("Multimedia use cases must be initialized");
+
+        multimedia.create_upload_batch = Arc::new(uc);
+        self
+    }
+    pub fn with_get_upload_batch(mut self, uc: impl GetUploadBatchUseCase + 'static) -> Self {
+        let multimedia = self
+            .multimedia
+            .as_mut()
+            .expect("Multimedia use cases must be initialized");
+
+        multimedia.get_upload_batch = Arc::new(uc);
+        self
+    }
+    pub fn with_finalize_upload(mut self, uc: impl FinalizeUploadUseCase + 'static) -> Self {
+        let multimedia = self
+            .multimedia
+            .as_mut()
+            .expect("Multimedia use cases must be initialized");
+
+        multimedia.finalize_upload = Arc::new(uc);
+        self
+    }
+    pub fn with_regenerate_media(mut self, uc: impl RegenerateMediaUseCase + 'static) -> Self {
+        let multimedia = self
+            .multimedia
+            .as_mut()
+            .expect("Multimedia use cases must be initialized");
+
+        multimedia.regenerate_media = Arc::new(uc);
+        self
+    }
+    pub fn with_proxy_image(mut self, uc: impl ProxyImageUseCase + 'static) -> Self {
+        let multimedia = self
+            .multimedia
+            .as_mut()
+            .expect("Multimedia use cases must be initialized");
+
+        multimedia.proxy_image = Arc::new(uc);
+        self
+    }
+    pub fn with_ingest_media_manifest(
+        mut self,
+        uc: impl IngestMediaManifestUseCase + 'static,
+    ) -> Self {
+        let multimedia = self
+            .multimedia
+            .as_mut()
+            .expect("Multimedia use cases must be initialized");
+
+        multimedia.ingest_manifest = Arc::new(uc);
+        self
+    }
     pub fn with_create_signed_get_url(
         mut self,
         uc: impl GetVariantReadUrlUseCase + Send + Sync + 'static,
@@ -400,6 +828,27 @@ impl TestAppStateBuilder {
         multimedia.create_signed_get_url = Arc::new(uc);
         self
     }
+    pub fn with_create_signed_get_urls(
+        mut self,
+        uc: impl GetVariantReadUrlsUseCase + 'static,
+    ) -> Self {
+        let multimedia = self
+            .multimedia
+            .as_mut()
+            .expect("Multimedia use cases must be initialized");
+
+        multimedia.create_signed_get_urls = Arc::new(uc);
+        self
+    }
+    pub fn with_get_preview_image(mut self, uc: impl GetPreviewImageUseCase + 'static) -> Self {
+        let multimedia = self
+            .multimedia
+            .as_mut()
+            .expect("Multimedia use cases must be initialized");
+
+        multimedia.get_preview_image = Arc::new(uc);
+        self
+    }
     pub fn with_list_media(mut self, uc: impl ListMediaUseCase + Send + Sync + 'static) -> Self {
         let multimedia = self
             .multimedia
@@ -409,32 +858,433 @@ impl TestAppStateBuilder {
         multimedia.list_media = Arc::new(uc);
         self
     }
+    pub fn with_admin_stats(mut self, uc: impl GetAdminStatsUseCase + 'static) -> Self {
+        self.admin_stats = Some(Arc::new(uc));
+        self
+    }
+    pub fn with_integrity_check(mut self, uc: impl CheckIntegrityUseCase + 'static) -> Self {
+        self.integrity_check = Some(Arc::new(uc));
+        self
+    }
+    pub fn with_quick_search(mut self, uc: impl QuickSearchUseCase + 'static) -> Self {
+        self.quick_search = Some(Arc::new(uc));
+        self
+    }
+    pub fn with_storage_report(mut self, uc: impl GetStorageReportUseCase + 'static) -> Self {
+        self.storage_report = Some(Arc::new(uc));
+        self
+    }
+    pub fn with_admin_user_ids(mut self, ids: Vec<Uuid>) -> Self {
+        self.admin_user_ids = ids;
+        self
+    }
+    pub fn with_list_outbox_emails(mut self, uc: impl ListOutboxEmailsUseCase + 'static) -> Self {
+        self.list_outbox_emails = Some(Arc::new(uc));
+        self
+    }
+    pub fn with_record_email_events(mut self, uc: impl RecordEmailEventsUseCase + 'static) -> Self {
+        self.record_email_events = Some(Arc::new(uc));
+        self
+    }
+    pub fn with_email_events_token(mut self, token: &str) -> Self {
+        self.email_events_token = Some(token.to_string());
+        self
+    }
+    pub fn with_media_callback_secret(mut self, secret: &str) -> Self {
+        self.media_callback_secret = Some(secret.to_string());
+        self
+    }
+
+    /// Defaults to the real use case over an empty blacklist, the test JWT
+    /// service and the builder's token versions
+    pub fn with_introspect_token(mut self, uc: impl IIntrospectTokenUseCase + 'static) -> Self {
+        self.introspect_token = Some(Arc::new(uc));
+        self
+    }
+
+    pub fn with_introspection_token(mut self, token: &str) -> Self {
+        self.introspection_token = Some(token.to_string());
+        self
+    }
+
+    pub fn with_metrics_token(mut self, token: &str) -> Self {
+        self.metrics_token = Some(token.to_string());
+        self
+    }
+
+    pub fn with_config_reloader(mut self, reloader: ConfigReloader) -> Self {
+        self.config_reloader = Some(reloader);
+        self
+    }
+    pub fn with_unsubscribe(mut self, uc: impl UnsubscribeUseCase + 'static) -> Self {
+        self.unsubscribe = Some(Arc::new(uc));
+        self
+    }
+    pub fn with_bot_gate(mut self, gate: BotGate) -> Self {
+        self.bot_gate = gate;
+        self
+    }
+    pub fn with_list_trash(mut self, uc: impl ListTrashUseCase + 'static) -> Self {
+        self.list_trash = Some(Arc::new(uc));
+        self
+    }
+    pub fn with_restore_trash_item(mut self, uc: impl RestoreTrashItemUseCase + 'static) -> Self {
+        self.restore_trash_item = Some(Arc::new(uc));
+        self
+    }
+    pub fn with_create_webhook(mut self, uc: impl CreateWebhookUseCase + 'static) -> Self {
+        self.webhooks_mut().create = Arc::new(uc);
+        self
+    }
+    pub fn with_list_webhooks(mut self, uc: impl ListWebhooksUseCase + 'static) -> Self {
+        self.webhooks_mut().list = Arc::new(uc);
+        self
+    }
+    pub fn with_delete_webhook(mut self, uc: impl DeleteWebhookUseCase + 'static) -> Self {
+        self.webhooks_mut().delete = Arc::new(uc);
+        self
+    }
+    pub fn with_list_webhook_deliveries(
+        mut self,
+        uc: impl ListWebhookDeliveriesUseCase + 'static,
+    ) -> Self {
+        self.webhooks_mut().list_deliveries = Arc::new(uc);
+        self
+    }
+    fn webhooks_mut(&mut self) -> &mut WebhookUseCases {
+        self.webhooks
+            .as_mut()
+            .expect("Webhook use cases must be initialized")
+    }
+    pub fn with_submit_contact_message(
+        mut self,
+        uc: impl SubmitContactMessageUseCase + 'static,
+    ) -> Self {
+        self.contact_mut().submit = Arc::new(uc);
+        self
+    }
+    pub fn with_list_contact_messages(
+        mut self,
+        uc: impl ListContactMessagesUseCase + 'static,
+    ) -> Self {
+        self.contact_mut().list = Arc::new(uc);
+        self
+    }
+    pub fn with_mark_contact_message_read(
+        mut self,
+        uc: impl MarkContactMessageReadUseCase + 'static,
+    ) -> Self {
+        self.contact_mut().mark_read = Arc::new(uc);
+        self
+    }
+    pub fn with_delete_contact_message(
+        mut self,
+        uc: impl DeleteContactMessageUseCase + 'static,
+    ) -> Self {
+        self.contact_mut().delete = Arc::new(uc);
+        self
+    }
+    pub fn with_contact_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.contact_rate_limiter = limiter;
+        self
+    }
+    pub fn with_upload_url_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.upload_url_rate_limiter = limiter;
+        self
+    }
+    fn contact_mut(&mut self) -> &mut ContactUseCases {
+        self.contact
+            .as_mut()
+            .expect("Contact use cases must be initialized")
+    }
+    pub fn with_get_site_profile(mut self, uc: impl GetSiteProfileUseCase + 'static) -> Self {
+        self.site_mut().get = Arc::new(uc);
+        self
+    }
+    pub fn with_update_site_settings(
+        mut self,
+        uc: impl UpdateSiteSettingsUseCase + 'static,
+    ) -> Self {
+        self.site_mut().update = Arc::new(uc);
+        self
+    }
+    pub fn with_get_theme(mut self, uc: impl GetThemeUseCase + 'static) -> Self {
+        self.site_mut().get_theme = Arc::new(uc);
+        self
+    }
+    pub fn with_update_theme(mut self, uc: impl UpdateThemeUseCase + 'static) -> Self {
+        self.site_mut().update_theme = Arc::new(uc);
+        self
+    }
+    fn site_mut(&mut self) -> &mut SiteUseCases {
+        self.site
+            .as_mut()
+            .expect("Site use cases must be initialized")
+    }
+    pub fn with_create_page(mut self, uc: impl CreatePageUseCase + 'static) -> Self {
+        self.pages_mut().create = Arc::new(uc);
+        self
+    }
+    pub fn with_list_pages(mut self, uc: impl ListPagesUseCase + 'static) -> Self {
+        self.pages_mut().list = Arc::new(uc);
+        self
+    }
+    pub fn with_get_page(mut self, uc: impl GetPageUseCase + 'static) -> Self {
+        self.pages_mut().get = Arc::new(uc);
+        self
+    }
+    pub fn with_update_page(mut self, uc: impl UpdatePageUseCase + 'static) -> Self {
+        self.pages_mut().update = Arc::new(uc);
+        self
+    }
+    pub fn with_delete_page(mut self, uc: impl DeletePageUseCase + 'static) -> Self {
+        self.pages_mut().delete = Arc::new(uc);
+        self
+    }
+    pub fn with_get_public_page(mut self, uc: impl GetPublicPageUseCase + 'static) -> Self {
+        self.pages_mut().get_public = Arc::new(uc);
+        self
+    }
+    pub fn with_create_preview_token(
+        mut self,
+        uc: impl CreatePreviewTokenUseCase + 'static,
+    ) -> Self {
+        self.pages_mut().create_preview_token = Arc::new(uc);
+        self
+    }
+    pub fn with_get_page_preview(mut self, uc: impl GetPagePreviewUseCase + 'static) -> Self {
+        self.pages_mut().get_preview = Arc::new(uc);
+        self
+    }
+    pub fn with_acquire_page_lock(mut self, uc: impl AcquirePageLockUseCase + 'static) -> Self {
+        self.pages_mut().acquire_lock = Arc::new(uc);
+        self
+    }
+    pub fn with_autosave_page(mut self, uc: impl AutosavePageUseCase + 'static) -> Self {
+        self.pages_mut().autosave = Arc::new(uc);
+        self
+    }
+    pub fn with_get_page_autosave(mut self, uc: impl GetPageAutosaveUseCase + 'static) -> Self {
+        self.pages_mut().get_autosave = Arc::new(uc);
+        self
+    }
+    fn pages_mut(&mut self) -> &mut PageUseCases {
+        self.pages
+            .as_mut()
+            .expect("Page use cases must be initialized")
+    }
+    pub fn with_record_page_view(mut self, uc: impl RecordPageViewUseCase + 'static) -> Self {
+        self.analytics_mut().record = Arc::new(uc);
+        self
+    }
+    pub fn with_get_top_pages(mut self, uc: impl GetTopPagesUseCase + 'static) -> Self {
+        self.analytics_mut().top_pages = Arc::new(uc);
+        self
+    }
+    pub fn with_get_top_referrers(mut self, uc: impl GetTopReferrersUseCase + 'static) -> Self {
+        self.analytics_mut().top_referrers = Arc::new(uc);
+        self
+    }
+    pub fn with_get_daily_visitors(mut self, uc: impl GetDailyVisitorsUseCase + 'static) -> Self {
+        self.analytics_mut().daily_visitors = Arc::new(uc);
+        self
+    }
+    pub fn with_get_path_report(mut self, uc: impl GetPathReportUseCase + 'static) -> Self {
+        self.analytics_mut().path_report = Arc::new(uc);
+        self
+    }
+    fn analytics_mut(&mut self) -> &mut AnalyticsUseCases {
+        self.analytics
+            .as_mut()
+            .expect("Analytics use cases must be initialized")
+    }
+    pub fn with_create_redirect(mut self, uc: impl CreateRedirectUseCase + 'static) -> Self {
+        self.redirects_mut().create = Arc::new(uc);
+        self
+    }
+    pub fn with_list_redirects(mut self, uc: impl ListRedirectsUseCase + 'static) -> Self {
+        self.redirects_mut().list = Arc::new(uc);
+        self
+    }
+    pub fn with_update_redirect(mut self, uc: impl UpdateRedirectUseCase + 'static) -> Self {
+        self.redirects_mut().update = Arc::new(uc);
+        self
+    }
+    pub fn with_delete_redirect(mut self, uc: impl DeleteRedirectUseCase + 'static) -> Self {
+        self.redirects_mut().delete = Arc::new(uc);
+        self
+    }
+    pub fn with_resolve_redirect(mut self, uc: impl ResolveRedirectUseCase + 'static) -> Self {
+        self.redirects_mut().resolve = Arc::new(uc);
+        self
+    }
+    fn redirects_mut(&mut self) -> &mut RedirectUseCases {
+        self.redirects
+            .as_mut()
+            .expect("Redirect use cases must be initialized")
+    }
+    pub fn with_blocklist(mut self, blocklist: BlocklistUseCases) -> Self {
+        self.blocklist = Some(blocklist);
+        self
+    }
+    pub fn with_search_content(mut self, uc: impl SearchContentUseCase + 'static) -> Self {
+        self.search_content = Some(Arc::new(uc));
+        self
+    }
+    pub fn with_export_content(mut self, uc: impl ExportContentUseCase + 'static) -> Self {
+        self.export_content = Some(Arc::new(uc));
+        self
+    }
+    pub fn with_import_content(mut self, uc: impl ImportContentUseCase + 'static) -> Self {
+        self.import_content = Some(Arc::new(uc));
+        self
+    }
+    pub fn with_run_batch(mut self, uc: impl RunBatchUseCase + 'static) -> Self {
+        self.run_batch = Some(Arc::new(uc));
+        self
+    }
+    pub fn with_list_activities(mut self, uc: impl ListActivitiesUseCase + 'static) -> Self {
+        self.list_activities = Some(Arc::new(uc));
+        self
+    }
+    pub fn with_put_translation(mut self, uc: impl PutTranslationUseCase + 'static) -> Self {
+        self.translations_mut().put = Arc::new(uc);
+        self
+    }
+    pub fn with_list_translations(mut self, uc: impl ListTranslationsUseCase + 'static) -> Self {
+        self.translations_mut().list = Arc::new(uc);
+        self
+    }
+    pub fn with_delete_translation(mut self, uc: impl DeleteTranslationUseCase + 'static) -> Self {
+        self.translations_mut().delete = Arc::new(uc);
+        self
+    }
+    pub fn with_localize_content(mut self, uc: impl LocalizeContentUseCase + 'static) -> Self {
+        self.translations_mut().localize = Arc::new(uc);
+        self
+    }
+    fn translations_mut(&mut self) -> &mut TranslationUseCases {
+        self.translations
+            .as_mut()
+            .expect("Translation use cases must be initialized")
+    }
+    pub fn with_skills(mut self, skills: SkillUseCases) -> Self {
+        self.skills = Some(skills);
+        self
+    }
     pub fn build(self) -> web::Data<AppState> {
+        let token_version_guard = self.token_version_guard.unwrap();
+        let introspect_token = self.introspect_token.unwrap_or_else(|| {
+            Arc::new(IntrospectTokenUseCase::new(
+                InMemoryTokenRepository::default(),
+                Arc::new(create_test_jwt_service()),
+                token_version_guard.clone(),
+            ))
+        });
+
         web::Data::new(AppState {
-            fetch_cv_use_case: self.fetch_cv.unwrap(),
-            fetch_cv_by_id_use_case: self.fetch_cv_by_id.unwrap(),
-            get_public_single_cv_use_case: self
-                .get_public_single_cv_use_case
-                .expect("get_public_single_cv_use_case not set"),
-            create_cv_use_case: self.create_cv.unwrap(),
-            update_cv_use_case: self.update_cv.unwrap(),
-            patch_cv_use_case: self.patch_cv.unwrap(),
-            register_user_orchestrator: self.register_user.unwrap(),
-            verify_user_email_use_case: self.verify_user_email.unwrap(),
-            login_user_use_case: self.login_user.unwrap(),
-            refresh_token_use_case: self.refresh_token.unwrap(),
-            logout_user_use_case: self.logout_user.unwrap(),
-            soft_delete_user_use_case: self.soft_delete_user.unwrap(),
-            fetch_user_profile_use_case: self.fetch_user_profile.unwrap(),
-            update_user_profile_use_case: self.update_user_profile.unwrap(),
-            hard_delete_cv_use_case: self.hard_delete_cv.unwrap(),
-            create_topic_use_case: self.create_topic.unwrap(),
-            get_topics_use_case: self.get_topics.unwrap(),
-            soft_delete_topic_use_case: self.soft_delete_topic.unwrap(),
+            cv: CvUseCases {
+                fetch_list: self.fetch_cv.unwrap(),
+                fetch_by_id: self.fetch_cv_by_id.unwrap(),
+                export_text: self.export_cv_text.unwrap(),
+                get_public_single: self
+                    .get_public_single_cv_use_case
+                    .expect("get_public_single_cv_use_case not set"),
+                create: self.create_cv.unwrap(),
+                update: self.update_cv.unwrap(),
+                patch: self.patch_cv.unwrap(),
+                hard_delete: self.hard_delete_cv.unwrap(),
+            },
+            auth: AuthUseCases {
+                register: self.register_user.unwrap(),
+                verify_email: self.verify_user_email.unwrap(),
+                login: self.login_user.unwrap(),
+                refresh_token: self.refresh_token.unwrap(),
+                logout: self.logout_user.unwrap(),
+                logout_all: self.logout_all.unwrap(),
+                soft_delete_user: self.soft_delete_user.unwrap(),
+                lockdown_user_content: self.lockdown_user_content.unwrap(),
+                deactivate_account: self.deactivate_account.unwrap(),
+                reactivate_account: self.reactivate_account.unwrap(),
+                fetch_profile: self.fetch_user_profile.unwrap(),
+                update_profile: self.update_user_profile.unwrap(),
+                get_profile: self.get_profile.unwrap(),
+                patch_profile: self.patch_profile.unwrap(),
+                introspect_token,
+            },
+            token_version_guard,
+            auth_cookies: self.auth_cookies,
+            // Disabled unless a test brings its own
+            passkeys: self.passkeys.unwrap_or_else(|| {
+                PasskeyUseCases::build(
+                    Arc::new(InMemoryUserStore::default()),
+                    Arc::new(InMemoryPasskeyStore::default()),
+                    Arc::new(InMemoryPasskeyChallenges::default()),
+                    Arc::new(DisabledPasskeys),
+                    Arc::new(create_test_jwt_service()),
+                )
+            }),
+            topics: TopicUseCases {
+                create: self.create_topic.unwrap(),
+                get_list: self.get_topics.unwrap(),
+                soft_delete: self.soft_delete_topic.unwrap(),
+            },
             project: self.project.unwrap(),
             multimedia: self.multimedia.unwrap(),
             user_identity_resolver: self.user_identity_resolver.unwrap(),
             multimedia_upload_policy: UploadPolicy::from_env(),
+            upload_url_rate_limiter: self.upload_url_rate_limiter,
+            admin_stats_use_case: self.admin_stats.unwrap(),
+            integrity_check_use_case: self.integrity_check.unwrap(),
+            quick_search_use_case: self.quick_search.unwrap(),
+            storage_report_use_case: self.storage_report.unwrap(),
+            admin_user_ids: self.admin_user_ids,
+            trash: TrashUseCases {
+                list: self.list_trash.unwrap(),
+                restore: self.restore_trash_item.unwrap(),
+            },
+            webhooks: self.webhooks.unwrap(),
+            email: EmailUseCases {
+                list_outbox: self.list_outbox_emails.unwrap(),
+                record_events: self.record_email_events.unwrap(),
+                unsubscribe: self.unsubscribe.unwrap(),
+            },
+            email_events_token: self.email_events_token,
+            media_callback_secret: self.media_callback_secret,
+            introspection_token: self.introspection_token,
+            metrics_token: self.metrics_token,
+            config_reloader: self.config_reloader.unwrap_or_else(|| {
+                ConfigReloader::new(
+                    Arc::new(|| Err(ConfigError::Source("no configuration in tests".into()))),
+                    RateLimiter::disabled(),
+                    RateLimiter::disabled(),
+                    None,
+                )
+            }),
+            contact: self.contact.unwrap(),
+            contact_rate_limiter: self.contact_rate_limiter,
+            bot_gate: self.bot_gate,
+            site: self.site.unwrap(),
+            pages: self.pages.unwrap(),
+            analytics: self.analytics.unwrap(),
+            redirects: self.redirects.unwrap(),
+            // Empty unless a test brings its own
+            blocklist: self
+                .blocklist
+                .unwrap_or_else(|| BlocklistUseCases::build(InMemoryBlockRuleStore::default())),
+            search_content_use_case: self.search_content.unwrap(),
+            export_content_use_case: self.export_content.unwrap(),
+            import_content_use_case: self.import_content.unwrap(),
+            run_batch_use_case: self.run_batch.unwrap(),
+            list_activities_use_case: self.list_activities.unwrap(),
+            translations: self.translations.unwrap(),
+            // Empty unless a test brings its own
+            skills: self.skills.unwrap_or_else(|| {
+                SkillUseCases::build(InMemorySkillStore::new(
+                    Default::default(),
+                    Default::default(),
+                ))
+            }),
         })
     }
 }
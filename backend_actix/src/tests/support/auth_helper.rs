@@ -1,17 +1,28 @@
 #[cfg(test)]
 pub mod test_helpers {
+    use crate::auth::adapter::incoming::web::cookies::AuthCookies;
     use crate::auth::adapter::outgoing::jwt::{JwtConfig, JwtTokenService};
 
-    pub fn create_test_jwt_service() -> JwtTokenService {
-        let jwt_config = JwtConfig {
+    pub fn create_test_jwt_config() -> JwtConfig {
+        JwtConfig {
             issuer: "Ekstion".to_string(),
+            audience: "test_audience".to_string(),
             secret_key: std::env::var("TEST_JWT_SECRET")
                 .unwrap_or_else(|_| "FAKE_JWT_SECRET_DO_NOT_USE".to_string()),
 
             access_token_expiry: 3600,
             refresh_token_expiry: 86400,
             verification_token_expiry: 86400,
-        };
-        JwtTokenService::new(jwt_config)
+            remember_me_refresh_token_expiry: 2592000,
+        }
+    }
+
+    pub fn create_test_jwt_service() -> JwtTokenService {
+        JwtTokenService::new(create_test_jwt_config())
+    }
+
+    /// Cookie mode with the lifetimes of [`create_test_jwt_config`]
+    pub fn create_test_auth_cookies() -> AuthCookies {
+        AuthCookies::new(&create_test_jwt_config(), None, true)
     }
 }
@@ -0,0 +1,68 @@
+//! Real Postgres for tests that need one (EXPLAIN checks, port contracts).
+//!
+//! Set `TEST_DATABASE_URL` (env or `.env.test`) to a scratch database to run
+//! them; it is migrated on first use. Without it those tests are skipped.
+//! With the `sqlite` feature it may also be a SQLite file, which runs the port
+//! contracts but skips the EXPLAIN checks.
+
+use migration::{Migrator, MigratorTrait};
+use sea_orm::{ConnectionTrait, Database, DatabaseBackend, DatabaseConnection};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::load_test_env;
+use crate::auth::adapter::outgoing::user_repository_postgres::UserRepositoryPostgres;
+use crate::auth::application::domain::entities::UserId;
+use crate::auth::application::ports::outgoing::user_repository::{CreateUserData, UserRepository};
+
+pub async fn test_db() -> Option<Arc<DatabaseConnection>> {
+    load_test_env();
+    let Ok(url) = std::env::var("TEST_DATABASE_URL") else {
+        eprintln!("TEST_DATABASE_URL not set; skipping database test");
+        return None;
+    };
+
+    let db = Database::connect(&url)
+        .await
+        .expect("Failed to connect to TEST_DATABASE_URL");
+    Migrator::up(&db, None)
+        .await
+        .expect("Failed to migrate the test database");
+
+    Some(Arc::new(db))
+}
+
+/// [`test_db`] if it is Postgres, for checks of Postgres query plans
+pub async fn test_postgres_db() -> Option<Arc<DatabaseConnection>> {
+    let db = test_db().await?;
+    if db.get_database_backend() != DatabaseBackend::Postgres {
+        eprintln!("TEST_DATABASE_URL is not Postgres; skipping EXPLAIN test");
+        return None;
+    }
+
+    Some(db)
+}
+
+/// A fresh account to own rows with user foreign keys. Deleting it cascades.
+pub async fn create_owner(db: &Arc<DatabaseConnection>) -> UserId {
+    let tag = Uuid::new_v4().simple().to_string();
+    let user = UserRepositoryPostgres::new(Arc::clone(db))
+        .create_user(CreateUserData {
+            email: format!("owner-{tag}@example.com"),
+            username: format!("owner_{}", &tag[..16]),
+            password_hash: "not-a-real-hash".to_string(),
+            full_name: "Test Owner".to_string(),
+            preferred_locale: "en".to_string(),
+        })
+        .await
+        .expect("Failed to create test owner");
+
+    UserId::from(user.id)
+}
+
+pub async fn delete_owner(db: &Arc<DatabaseConnection>, owner: UserId) {
+    UserRepositoryPostgres::new(Arc::clone(db))
+        .delete_user(owner.into())
+        .await
+        .ok();
+}
@@ -0,0 +1,28 @@
+//! EXPLAIN checks; run against `database::test_db()`.
+
+use sea_orm::{ConnectionTrait, DatabaseConnection, Statement, TransactionTrait};
+
+/// Text plan of `stmt`. Sequential scans are disabled so the result shows
+/// whether an index can serve the query even on near-empty test tables.
+pub async fn explain(db: &DatabaseConnection, stmt: Statement) -> String {
+    let txn = db.begin().await.expect("Failed to open transaction");
+    txn.execute_unprepared("SET LOCAL enable_seqscan = off")
+        .await
+        .expect("Failed to disable sequential scans");
+
+    let explained = Statement {
+        sql: format!("EXPLAIN {}", stmt.sql),
+        ..stmt
+    };
+    let plan = txn
+        .query_all(explained)
+        .await
+        .expect("EXPLAIN failed")
+        .iter()
+        .map(|row| row.try_get::<String>("", "QUERY PLAN").unwrap())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    txn.rollback().await.ok();
+    plan
+}
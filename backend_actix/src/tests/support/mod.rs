@@ -1,5 +1,7 @@
 pub mod app_state_builder;
 pub mod auth_helper;
+pub mod database;
+pub mod explain;
 pub mod project_test_fixtures;
 pub mod stubs;
 
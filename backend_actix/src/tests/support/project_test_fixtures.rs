@@ -1,12 +1,37 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::auth::application::domain::entities::UserId;
 use crate::modules::project::application::ports::outgoing::project_query::{
-    PageResult, ProjectCardView,
+    PageResult, ProjectCardView, ProjectView,
 };
 
 pub fn empty_page_result() -> PageResult<ProjectCardView> {
     PageResult {
         items: vec![],
-        page: 1,
-        per_page: 10,
         total: 0,
     }
 }
+
+/// A public project with no SEO fields, topics or updates
+pub fn sample_project_view(owner: UserId, slug: &str) -> ProjectView {
+    ProjectView {
+        id: Uuid::new_v4(),
+        owner,
+        title: "Public Project".to_string(),
+        slug: slug.to_string(),
+        description: "A portfolio CMS".to_string(),
+        tech_stack: vec!["Rust".to_string(), "Svelte".to_string()],
+        screenshots: vec![],
+        repo_url: Some("https://github.com/jane/cms".to_string()),
+        live_demo_url: None,
+        topics: vec![],
+        updates: vec![],
+        seo_title: None,
+        seo_description: None,
+        canonical_url: None,
+        noindex: false,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
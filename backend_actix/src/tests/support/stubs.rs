@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use shared_domain::manifest::Manifest;
 use uuid::Uuid;
 
 use crate::auth::application::domain::entities::UserId;
@@ -30,30 +31,44 @@ use crate::cv::application::use_cases::hard_delete_cv::{HardDeleteCVError, HardD
 use crate::cv::application::use_cases::restore_cv::{RestoreCVError, RestoreDeletedCvUseCase};
 use crate::cv::application::use_cases::soft_delete_cv::{SoftDeleteCVError, SoftDeleteCvUseCase};
 use crate::cv::domain::entities::CVInfo;
-use crate::email::application::ports::outgoing::user_email_notifier::{
-    UserEmailNotificationError, UserEmailNotifier,
-};
+use crate::multimedia::application::domain::entities::MediaState;
 use crate::multimedia::application::ports::incoming::use_cases::{
-    CreateAttachmentCommand, CreateMediaCommand, CreateMediaResult, CreateUploadMediaUrlUseCase,
-    CreateUrlError, GetReadUrlError, GetUrlCommand, GetUrlResult, GetVariantReadUrlUseCase,
-    ListMediaCommand, ListMediaError, ListMediaUseCase, MediaItem,
+    CreateAttachmentCommand, CreateMediaCommand, CreateMediaResult, CreateUploadBatchCommand,
+    CreateUploadBatchError, CreateUploadBatchUseCase, CreateUploadMediaUrlUseCase, CreateUrlError,
+    FinalizeUploadError, FinalizeUploadResult, FinalizeUploadUseCase, GetPreviewImageCommand,
+    GetPreviewImageUseCase, GetReadUrlError, GetUploadBatchError, GetUploadBatchUseCase,
+    GetUrlCommand, GetUrlResult, GetUrlsCommand, GetVariantReadUrlUseCase,
+    GetVariantReadUrlsUseCase, IngestMediaManifestError, IngestMediaManifestResult,
+    IngestMediaManifestUseCase, ListMediaCommand, ListMediaError, ListMediaUseCase,
+    ManifestOutcome, MediaItem, PreviewImage, ProxyImageError, ProxyImageUseCase,
+    RegenerateMediaCommand, RegenerateMediaError, RegenerateMediaResult, RegenerateMediaUseCase,
+    UploadBatch, UploadBatchStatus, VariantUrlOutcome,
 };
+use crate::multimedia::application::ports::outgoing::db::UploadBatchItem;
 
 use crate::project::application::ports::incoming::use_cases::{
     AddProjectTopicError, AddProjectTopicUseCase, ClearProjectTopicsError,
-    ClearProjectTopicsUseCase, GetProjectTopicsError, GetProjectTopicsUseCase, GetProjectsUseCase,
-    GetPublicSingleProjectError, GetPublicSingleProjectUseCase, GetSingleProjectError,
-    GetSingleProjectUseCase, HardDeleteProjectError, HardDeleteProjectUseCase, PatchProjectError,
-    PatchProjectUseCase, RemoveProjectTopicError, RemoveProjectTopicUseCase,
+    ClearProjectTopicsUseCase, CreateProjectUpdateError, CreateProjectUpdateUseCase,
+    DeleteProjectUpdateError, DeleteProjectUpdateUseCase, GetProjectTopicsError,
+    GetProjectTopicsUseCase, GetProjectsUseCase, GetPublicSingleProjectError,
+    GetPublicSingleProjectUseCase, GetSingleProjectError, GetSingleProjectUseCase,
+    HardDeleteProjectError, HardDeleteProjectUseCase, PatchProjectError, PatchProjectUpdateError,
+    PatchProjectUpdateUseCase, PatchProjectUseCase, ReadmeSyncResult, RemoveProjectTopicError,
+    RemoveProjectTopicUseCase, SyncProjectReadmeError, SyncProjectReadmeUseCase,
 };
 use crate::project::application::ports::outgoing::project_query::{ProjectTopicItem, ProjectView};
 use crate::project::application::ports::outgoing::project_repository::PatchProjectData;
+use crate::project::application::ports::outgoing::project_update_repository::{
+    CreateProjectUpdateData, PatchProjectUpdateData, ProjectUpdate,
+};
+use crate::shared::cache::{CacheError, CachePort};
 use crate::tests::support::project_test_fixtures::empty_page_result;
 use crate::topic::application::ports::outgoing::TopicResult;
 use crate::{
     auth::application::use_cases::login_user::{LoginError, LoginRequest, LoginUserResponse},
     cv::application::use_cases::{
         create_cv::{CreateCVError, ICreateCVUseCase},
+        export_cv::{ExportedCv, IExportCvUseCase},
         fetch_cv_by_id::{FetchCVByIdError, IFetchCVByIdUseCase},
         fetch_user_cvs::{FetchCVError, IFetchCVUseCase},
         patch_cv::{IPatchCVUseCase, PatchCVError},
@@ -102,6 +117,16 @@ impl IFetchCVByIdUseCase for StubFetchCVByIdUseCase {
     }
 }
 
+#[derive(Default, Clone)]
+pub struct StubExportCvUseCase;
+
+#[async_trait]
+impl IExportCvUseCase for StubExportCvUseCase {
+    async fn execute(&self, _user_id: Uuid, _cv_id: Uuid) -> Result<ExportedCv, FetchCVByIdError> {
+        unimplemented!("Not used in this test")
+    }
+}
+
 #[derive(Default, Clone)]
 pub struct StubCreateCVUseCase;
 
@@ -126,6 +151,7 @@ impl IUpdateCVUseCase for StubUpdateCVUseCase {
         _user_id: Uuid,
         _cv_id: Uuid,
         _data: crate::cv::application::ports::outgoing::UpdateCVData,
+        _expected_updated_at: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<CVInfo, UpdateCVError> {
         unimplemented!("Not used in this test")
     }
@@ -141,6 +167,7 @@ impl IPatchCVUseCase for StubPatchCVUseCase {
         _user_id: Uuid,
         _cv_id: Uuid,
         _data: crate::cv::application::ports::outgoing::PatchCVData,
+        _expected_updated_at: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<CVInfo, PatchCVError> {
         unimplemented!("Not used in this test")
     }
@@ -209,19 +236,6 @@ impl ISoftDeleteUserUseCase for StubSoftDeleteUserUseCase {
     }
 }
 
-#[derive(Default, Clone)]
-pub struct StubUserEmailNotifier;
-
-#[async_trait]
-impl UserEmailNotifier for StubUserEmailNotifier {
-    async fn send_verification_email(
-        &self,
-        _user: CreateUserOutput,
-    ) -> Result<(), UserEmailNotificationError> {
-        unimplemented!()
-    }
-}
-
 #[derive(Default, Clone)]
 pub struct StubFetchUserProfileUseCase;
 
@@ -233,6 +247,7 @@ impl FetchUserProfileUseCase for StubFetchUserProfileUseCase {
             email: "stub@example.com".to_string(),
             username: "stubuser".to_string(),
             full_name: "Stub User".to_string(),
+            preferred_locale: "en".to_string(),
         })
     }
 }
@@ -248,6 +263,7 @@ impl UpdateUserProfileUseCase for StubUpdateUserProfileUseCase {
             email: "stub@example.com".to_string(),
             username: "stubuser".to_string(),
             full_name: data.full_name,
+            preferred_locale: "en".to_string(),
         })
     }
 }
@@ -385,7 +401,7 @@ pub struct DefaultStubGetProjectsUseCase;
 impl GetProjectsUseCase for DefaultStubGetProjectsUseCase {
     async fn execute(
         &self,
-        _owner: crate::auth::application::domain::entities::UserId,
+        _owner: UserId,
         _filter: crate::modules::project::application::ports::outgoing::project_query::ProjectListFilter,
         _sort: crate::modules::project::application::ports::outgoing::project_query::ProjectSort,
         _page: crate::modules::project::application::ports::outgoing::project_query::PageRequest,
@@ -498,6 +514,49 @@ impl UserQuery for DummyUserQuery {
     }
 }
 
+/// Finds every username as the active user `id`
+#[derive(Clone)]
+pub struct StubUserQuery {
+    id: Uuid,
+}
+
+impl StubUserQuery {
+    pub fn found(id: Uuid) -> Self {
+        Self { id }
+    }
+}
+
+#[async_trait]
+impl UserQuery for StubUserQuery {
+    async fn find_by_id(&self, _user_id: Uuid) -> Result<Option<UserQueryResult>, UserQueryError> {
+        Ok(None)
+    }
+
+    async fn find_by_email(&self, _email: &str) -> Result<Option<UserQueryResult>, UserQueryError> {
+        Ok(None)
+    }
+
+    async fn find_by_username(
+        &self,
+        username: &str,
+    ) -> Result<Option<UserQueryResult>, UserQueryError> {
+        Ok(Some(UserQueryResult {
+            id: self.id,
+            email: format!("{username}@example.com"),
+            username: username.to_string(),
+            password_hash: "hashed".to_string(),
+            full_name: "Test User".to_string(),
+            preferred_locale: "en".to_string(),
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+            is_verified: true,
+            is_deleted: false,
+            token_version: 0,
+            is_active: true,
+        }))
+    }
+}
+
 #[derive(Clone)]
 pub struct StubGetPublicSingleProjectUseCase {
     result: Result<ProjectView, GetPublicSingleProjectError>,
@@ -640,6 +699,67 @@ impl HardDeleteProjectUseCase for StubHardDeleteProjectUseCase {
     }
 }
 
+#[derive(Clone, Default)]
+pub struct StubCreateProjectUpdateUseCase;
+
+#[async_trait]
+impl CreateProjectUpdateUseCase for StubCreateProjectUpdateUseCase {
+    async fn execute(
+        &self,
+        _owner: UserId,
+        _project_id: Uuid,
+        _data: CreateProjectUpdateData,
+    ) -> Result<ProjectUpdate, CreateProjectUpdateError> {
+        unimplemented!("StubCreateProjectUpdateUseCase not configured for this test")
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct StubPatchProjectUpdateUseCase;
+
+#[async_trait]
+impl PatchProjectUpdateUseCase for StubPatchProjectUpdateUseCase {
+    async fn execute(
+        &self,
+        _owner: UserId,
+        _project_id: Uuid,
+        _update_id: Uuid,
+        _data: PatchProjectUpdateData,
+    ) -> Result<ProjectUpdate, PatchProjectUpdateError> {
+        unimplemented!("StubPatchProjectUpdateUseCase not configured for this test")
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct StubDeleteProjectUpdateUseCase;
+
+#[async_trait]
+impl DeleteProjectUpdateUseCase for StubDeleteProjectUpdateUseCase {
+    async fn execute(
+        &self,
+        _owner: UserId,
+        _project_id: Uuid,
+        _update_id: Uuid,
+    ) -> Result<(), DeleteProjectUpdateError> {
+        unimplemented!("StubDeleteProjectUpdateUseCase not configured for this test")
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct StubSyncProjectReadmeUseCase;
+
+#[async_trait]
+impl SyncProjectReadmeUseCase for StubSyncProjectReadmeUseCase {
+    async fn execute(
+        &self,
+        _owner: UserId,
+        _project_id: Uuid,
+        _keep_in_sync: Option<bool>,
+    ) -> Result<ReadmeSyncResult, SyncProjectReadmeError> {
+        unimplemented!("StubSyncProjectReadmeUseCase not configured for this test")
+    }
+}
+
 #[derive(Clone, Default)]
 pub struct StubCreateUploadMediaUrlUseCase;
 
@@ -654,6 +774,208 @@ impl CreateUploadMediaUrlUseCase for StubCreateUploadMediaUrlUseCase {
     }
 }
 
+/// Signs every file of the batch, naming its position in the URL, or fails
+/// with `error`
+pub struct StubCreateUploadBatchUseCase {
+    error: Option<CreateUploadBatchError>,
+}
+
+impl StubCreateUploadBatchUseCase {
+    pub fn success() -> Self {
+        Self { error: None }
+    }
+
+    pub fn failure(err: CreateUploadBatchError) -> Self {
+        Self { error: Some(err) }
+    }
+}
+
+#[async_trait]
+impl CreateUploadBatchUseCase for StubCreateUploadBatchUseCase {
+    async fn execute(
+        &self,
+        command: CreateUploadBatchCommand,
+    ) -> Result<UploadBatch, CreateUploadBatchError> {
+        if let Some(err) = &self.error {
+            return Err(err.clone());
+        }
+        let uploads = command
+            .into_uploads()
+            .into_iter()
+            .map(|(_, attachment)| CreateMediaResult {
+                url: format!("https://storage.test/upload/{}", attachment.position()),
+                upload_headers: Default::default(),
+                media_id: Uuid::new_v4(),
+            })
+            .collect();
+
+        Ok(UploadBatch {
+            batch_id: Uuid::new_v4(),
+            uploads,
+        })
+    }
+}
+
+/// A batch of media in `statuses`, or fails with `error`
+pub struct StubGetUploadBatchUseCase {
+    result: Result<Vec<MediaState>, GetUploadBatchError>,
+}
+
+impl StubGetUploadBatchUseCase {
+    pub fn success(statuses: Vec<MediaState>) -> Self {
+        Self {
+            result: Ok(statuses),
+        }
+    }
+
+    pub fn failure(err: GetUploadBatchError) -> Self {
+        Self { result: Err(err) }
+    }
+}
+
+#[async_trait]
+impl GetUploadBatchUseCase for StubGetUploadBatchUseCase {
+    async fn execute(
+        &self,
+        _owner: UserId,
+        batch_id: Uuid,
+    ) -> Result<UploadBatchStatus, GetUploadBatchError> {
+        let statuses = self.result.clone()?;
+        let items = statuses
+            .into_iter()
+            .enumerate()
+            .map(|(i, status)| UploadBatchItem {
+                media_id: Uuid::new_v4(),
+                original_filename: format!("shot-{i}.png"),
+                status,
+                failure_code: None,
+            })
+            .collect();
+
+        Ok(UploadBatchStatus::new(batch_id, items))
+    }
+}
+
+/// Finalizes into `status`, or fails with `error`
+#[derive(Clone)]
+pub struct StubFinalizeUploadUseCase {
+    result: Result<MediaState, FinalizeUploadError>,
+}
+
+impl StubFinalizeUploadUseCase {
+    pub fn success(status: MediaState) -> Self {
+        Self { result: Ok(status) }
+    }
+
+    pub fn failure(err: FinalizeUploadError) -> Self {
+        Self { result: Err(err) }
+    }
+}
+
+#[async_trait]
+impl FinalizeUploadUseCase for StubFinalizeUploadUseCase {
+    async fn execute(
+        &self,
+        _owner: UserId,
+        media_id: Uuid,
+    ) -> Result<FinalizeUploadResult, FinalizeUploadError> {
+        self.result
+            .clone()
+            .map(|status| FinalizeUploadResult { media_id, status })
+    }
+}
+
+/// Queues every regeneration with the widths asked for, or fails with `error`
+#[derive(Clone)]
+pub struct StubRegenerateMediaUseCase {
+    error: Option<RegenerateMediaError>,
+}
+
+impl StubRegenerateMediaUseCase {
+    pub fn success() -> Self {
+        Self { error: None }
+    }
+
+    pub fn failure(err: RegenerateMediaError) -> Self {
+        Self { error: Some(err) }
+    }
+}
+
+#[async_trait]
+impl RegenerateMediaUseCase for StubRegenerateMediaUseCase {
+    async fn execute(
+        &self,
+        command: RegenerateMediaCommand,
+    ) -> Result<RegenerateMediaResult, RegenerateMediaError> {
+        match &self.error {
+            Some(err) => Err(err.clone()),
+            None => Ok(RegenerateMediaResult {
+                media_id: command.media_id,
+                status: MediaState::Processing,
+                widths: command.widths,
+            }),
+        }
+    }
+}
+
+pub struct StubProxyImageUseCase {
+    result: Result<String, ProxyImageError>,
+}
+
+impl StubProxyImageUseCase {
+    pub fn success(url: &str) -> Self {
+        Self {
+            result: Ok(url.to_string()),
+        }
+    }
+
+    pub fn failure(err: ProxyImageError) -> Self {
+        Self { result: Err(err) }
+    }
+}
+
+#[async_trait]
+impl ProxyImageUseCase for StubProxyImageUseCase {
+    async fn execute(&self, _url: &str) -> Result<String, ProxyImageError> {
+        self.result.clone()
+    }
+}
+
+/// Queues every manifest for a retry unless given an error
+pub struct StubIngestMediaManifestUseCase {
+    error: Option<IngestMediaManifestError>,
+}
+
+impl StubIngestMediaManifestUseCase {
+    pub fn success() -> Self {
+        Self { error: None }
+    }
+
+    pub fn failure(err: IngestMediaManifestError) -> Self {
+        Self { error: Some(err) }
+    }
+}
+
+#[async_trait]
+impl IngestMediaManifestUseCase for StubIngestMediaManifestUseCase {
+    async fn execute(
+        &self,
+        manifest: Manifest,
+    ) -> Result<IngestMediaManifestResult, IngestMediaManifestError> {
+        if let Some(err) = &self.error {
+            return Err(err.clone());
+        }
+        let media_id = match &manifest {
+            Manifest::Ready(ready) => ready.media_id.as_str(),
+            Manifest::Failed { media_id, .. } => media_id.as_str(),
+        };
+        Ok(IngestMediaManifestResult {
+            media_id: Uuid::parse_str(media_id).unwrap_or_default(),
+            outcome: ManifestOutcome::QueuedForRetry,
+        })
+    }
+}
+
 #[derive(Clone, Default)]
 pub struct StubGetVariantReadUrlService;
 
@@ -664,6 +986,96 @@ impl GetVariantReadUrlUseCase for StubGetVariantReadUrlService {
     }
 }
 
+/// Signs every variant as `https://signed.example/{media_id}/{size}`,
+/// except those of `unreadable`
+#[derive(Default)]
+pub struct StubGetVariantReadUrlsUseCase {
+    unreadable: Option<(Uuid, GetReadUrlError)>,
+    failure: Option<GetReadUrlError>,
+}
+
+impl StubGetVariantReadUrlsUseCase {
+    pub fn signing() -> Self {
+        Self::default()
+    }
+
+    pub fn signing_except(media_id: Uuid, err: GetReadUrlError) -> Self {
+        Self {
+            unreadable: Some((media_id, err)),
+            failure: None,
+        }
+    }
+
+    pub fn failure(err: GetReadUrlError) -> Self {
+        Self {
+            unreadable: None,
+            failure: Some(err),
+        }
+    }
+}
+
+#[async_trait]
+impl GetVariantReadUrlsUseCase for StubGetVariantReadUrlsUseCase {
+    async fn execute(
+        &self,
+        command: GetUrlsCommand,
+    ) -> Result<Vec<VariantUrlOutcome>, GetReadUrlError> {
+        if let Some(err) = &self.failure {
+            return Err(err.clone());
+        }
+
+        Ok(command
+            .variants
+            .into_iter()
+            .map(|(media_id, size)| match &self.unreadable {
+                Some((unreadable, err)) if *unreadable == media_id => Err(err.clone()),
+                _ => Ok(GetUrlResult {
+                    media_id,
+                    url: format!("https://signed.example/{media_id}/{size}"),
+                    size,
+                    expires_at: chrono::Utc::now() + chrono::Duration::minutes(15),
+                }),
+            })
+            .collect())
+    }
+}
+
+/// Previews every target with `https://signed.example/{target_id}/medium`,
+/// 800x450, unless built with `none`
+pub struct StubGetPreviewImageUseCase {
+    found: bool,
+}
+
+impl StubGetPreviewImageUseCase {
+    pub fn image() -> Self {
+        Self { found: true }
+    }
+
+    pub fn none() -> Self {
+        Self { found: false }
+    }
+}
+
+#[async_trait]
+impl GetPreviewImageUseCase for StubGetPreviewImageUseCase {
+    async fn execute(
+        &self,
+        command: GetPreviewImageCommand,
+    ) -> Result<Option<PreviewImage>, GetReadUrlError> {
+        Ok(self.found.then(|| PreviewImage {
+            media_id: Uuid::new_v4(),
+            url: format!(
+                "https://signed.example/{}/medium",
+                command.attachment_target_id
+            ),
+            width: 800,
+            height: 450,
+            alt_text: "Screenshot".to_string(),
+            expires_at: chrono::Utc::now() + chrono::Duration::minutes(15),
+        }))
+    }
+}
+
 pub struct StubListMediaUseCase;
 
 #[async_trait]
@@ -672,3 +1084,2248 @@ impl ListMediaUseCase for StubListMediaUseCase {
         unimplemented!()
     }
 }
+
+use crate::admin::application::ports::incoming::use_cases::{
+    AdminStats, GetAdminStatsError, GetAdminStatsUseCase,
+};
+use crate::admin::application::ports::outgoing::{MediaCounts, UserCounts};
+
+pub struct StubGetAdminStatsUseCase {
+    result: Result<AdminStats, GetAdminStatsError>,
+}
+
+impl StubGetAdminStatsUseCase {
+    pub fn success() -> Self {
+        Self {
+            result: Ok(AdminStats {
+                users: UserCounts {
+                    total: 3,
+                    verified: 2,
+                },
+                published_projects: 4,
+                media: MediaCounts {
+                    pending: 0,
+                    processing: 1,
+                    ready: 5,
+                    failed: 1,
+                    storage_bytes: 2048,
+                },
+                failed_processing_last_24h: 1,
+                generated_at: chrono::Utc::now(),
+            }),
+        }
+    }
+
+    pub fn failure(msg: &str) -> Self {
+        Self {
+            result: Err(GetAdminStatsError::QueryFailed(msg.into())),
+        }
+    }
+}
+
+#[async_trait]
+impl GetAdminStatsUseCase for StubGetAdminStatsUseCase {
+    async fn execute(&self) -> Result<AdminStats, GetAdminStatsError> {
+        self.result.clone()
+    }
+}
+
+use crate::admin::application::ports::incoming::use_cases::{
+    CheckIntegrityCommand, CheckIntegrityError, CheckIntegrityUseCase, IntegrityIssue,
+    IntegrityIssueKind, IntegrityReport,
+};
+
+pub struct StubCheckIntegrityUseCase {
+    result: Result<Vec<IntegrityIssue>, CheckIntegrityError>,
+}
+
+impl StubCheckIntegrityUseCase {
+    pub fn clean() -> Self {
+        Self { result: Ok(vec![]) }
+    }
+
+    /// One CV highlighting a project that is gone
+    pub fn with_dangling_highlight() -> Self {
+        Self {
+            result: Ok(vec![IntegrityIssue {
+                kind: IntegrityIssueKind::DanglingHighlightedProject,
+                resource: "cv",
+                resource_id: Uuid::new_v4(),
+                owner_id: Some(Uuid::new_v4()),
+                target: Uuid::new_v4().to_string(),
+                suggestion: "Remove it from the CV's highlighted_projects".to_string(),
+            }]),
+        }
+    }
+
+    pub fn failure(error: CheckIntegrityError) -> Self {
+        Self { result: Err(error) }
+    }
+}
+
+#[async_trait]
+impl CheckIntegrityUseCase for StubCheckIntegrityUseCase {
+    async fn execute(
+        &self,
+        command: CheckIntegrityCommand,
+    ) -> Result<IntegrityReport, CheckIntegrityError> {
+        self.result.clone().map(|issues| IntegrityReport {
+            issues,
+            storage_checked: command.check_storage,
+            generated_at: chrono::Utc::now(),
+        })
+    }
+}
+
+use crate::admin::application::ports::incoming::use_cases::{
+    MatchedField, QuickLink, QuickSearchCommand, QuickSearchError, QuickSearchHit,
+    QuickSearchUseCase,
+};
+
+pub struct StubQuickSearchUseCase {
+    result: Result<Vec<QuickSearchHit>, QuickSearchError>,
+}
+
+impl StubQuickSearchUseCase {
+    pub fn empty() -> Self {
+        Self { result: Ok(vec![]) }
+    }
+
+    /// One user matched by email
+    pub fn with_user() -> Self {
+        Self {
+            result: Ok(vec![QuickSearchHit {
+                label: "ada".to_string(),
+                detail: Some("ada@example.com".to_string()),
+                matched: MatchedField::Email,
+                deleted: false,
+                link: QuickLink::User {
+                    id: Uuid::new_v4(),
+                    username: "ada".to_string(),
+                },
+            }]),
+        }
+    }
+
+    pub fn failure(error: QuickSearchError) -> Self {
+        Self { result: Err(error) }
+    }
+}
+
+#[async_trait]
+impl QuickSearchUseCase for StubQuickSearchUseCase {
+    async fn execute(
+        &self,
+        command: QuickSearchCommand,
+    ) -> Result<Vec<QuickSearchHit>, QuickSearchError> {
+        self.result.clone().map(|mut hits| {
+            hits.truncate(command.limit() as usize);
+            hits
+        })
+    }
+}
+
+use crate::admin::application::ports::incoming::use_cases::{
+    GetStorageReportUseCase, StorageGroup, StorageReport, StorageReportCommand, StorageReportError,
+};
+use crate::admin::application::ports::outgoing::StorageUsage;
+
+pub struct StubGetStorageReportUseCase {
+    result: Result<StorageReport, StorageReportError>,
+}
+
+impl StubGetStorageReportUseCase {
+    /// AVIF variants written this month next to older WebP ones
+    pub fn with_avif() -> Self {
+        let avif = StorageUsage {
+            objects: 1,
+            bytes: 2048,
+            bytes_added_this_month: 2048,
+            bytes_added_last_month: 0,
+        };
+        let webp = StorageUsage {
+            objects: 2,
+            bytes: 1024,
+            bytes_added_this_month: 0,
+            bytes_added_last_month: 1024,
+        };
+        let group = |key: &str, usage: &StorageUsage| StorageGroup {
+            key: key.to_string(),
+            usage: usage.clone(),
+        };
+        let mut total = avif.clone();
+        total.add(&webp);
+        let now = chrono::Utc::now();
+
+        Self {
+            result: Ok(StorageReport {
+                by_bucket: vec![group("media", &total)],
+                by_size: vec![group("large", &avif), group("small", &webp)],
+                by_format: vec![group("image/avif", &avif), group("image/webp", &webp)],
+                by_user: vec![group(&Uuid::new_v4().to_string(), &total)],
+                total,
+                this_month_from: now,
+                last_month_from: now,
+                generated_at: now,
+            }),
+        }
+    }
+
+    pub fn failure(error: StorageReportError) -> Self {
+        Self { result: Err(error) }
+    }
+}
+
+#[async_trait]
+impl GetStorageReportUseCase for StubGetStorageReportUseCase {
+    async fn execute(
+        &self,
+        _command: StorageReportCommand,
+    ) -> Result<StorageReport, StorageReportError> {
+        self.result.clone()
+    }
+}
+
+use crate::trash::application::ports::incoming::use_cases::{
+    ListTrashError, ListTrashUseCase, RestoreTrashItemError, RestoreTrashItemUseCase,
+};
+use crate::trash::application::ports::outgoing::{TrashItem, TrashItemKind, TrashPage};
+
+pub struct StubListTrashUseCase {
+    result: Result<TrashPage, ListTrashError>,
+}
+
+impl StubListTrashUseCase {
+    pub fn success() -> Self {
+        Self {
+            result: Ok(TrashPage {
+                items: vec![TrashItem {
+                    kind: TrashItemKind::Project,
+                    id: Uuid::new_v4(),
+                    title: "Old project".to_string(),
+                    deleted_at: chrono::Utc::now(),
+                }],
+                total: 1,
+            }),
+        }
+    }
+
+    pub fn failure(msg: &str) -> Self {
+        Self {
+            result: Err(ListTrashError::QueryFailed(msg.into())),
+        }
+    }
+}
+
+#[async_trait]
+impl ListTrashUseCase for StubListTrashUseCase {
+    async fn execute(
+        &self,
+        _owner: UserId,
+        _kind: Option<TrashItemKind>,
+        _offset: u64,
+        _limit: u32,
+    ) -> Result<TrashPage, ListTrashError> {
+        self.result.clone()
+    }
+}
+
+pub struct StubRestoreTrashItemUseCase {
+    result: Result<(), RestoreTrashItemError>,
+}
+
+impl StubRestoreTrashItemUseCase {
+    pub fn success() -> Self {
+        Self { result: Ok(()) }
+    }
+
+    pub fn not_found() -> Self {
+        Self {
+            result: Err(RestoreTrashItemError::NotFound),
+        }
+    }
+
+    pub fn failure(msg: &str) -> Self {
+        Self {
+            result: Err(RestoreTrashItemError::RepositoryError(msg.into())),
+        }
+    }
+}
+
+#[async_trait]
+impl RestoreTrashItemUseCase for StubRestoreTrashItemUseCase {
+    async fn execute(
+        &self,
+        _owner: UserId,
+        _kind: TrashItemKind,
+        _id: Uuid,
+    ) -> Result<(), RestoreTrashItemError> {
+        self.result.clone()
+    }
+}
+
+/// Process-local `CachePort` for exercising read-through and invalidation.
+#[derive(Clone, Default)]
+pub struct InMemoryCache {
+    entries: Arc<std::sync::Mutex<std::collections::BTreeMap<String, String>>>,
+}
+
+impl InMemoryCache {
+    pub fn keys(&self) -> Vec<String> {
+        self.entries.lock().unwrap().keys().cloned().collect()
+    }
+}
+
+#[async_trait]
+impl CachePort for InMemoryCache {
+    async fn get(&self, key: &str) -> Result<Option<String>, CacheError> {
+        Ok(self.entries.lock().unwrap().get(key).cloned())
+    }
+
+    async fn set(&self, key: &str, value: String) -> Result<(), CacheError> {
+        self.entries.lock().unwrap().insert(key.to_string(), value);
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), CacheError> {
+        self.entries.lock().unwrap().remove(key);
+        Ok(())
+    }
+
+    async fn delete_prefix(&self, prefix: &str) -> Result<(), CacheError> {
+        self.entries
+            .lock()
+            .unwrap()
+            .retain(|key, _| !key.starts_with(prefix));
+        Ok(())
+    }
+}
+
+use crate::webhooks::application::domain::entities::{
+    WebhookDelivery, WebhookEndpoint, WebhookEvent,
+};
+use crate::webhooks::application::ports::incoming::use_cases::{
+    CreateWebhookCommand, CreateWebhookError, CreateWebhookUseCase, CreatedWebhook,
+    DeleteWebhookError, DeleteWebhookUseCase, ListWebhookDeliveriesError,
+    ListWebhookDeliveriesUseCase, ListWebhooksError, ListWebhooksUseCase,
+};
+use crate::webhooks::application::ports::outgoing::{
+    DeliveryPage, WebhookRepository, WebhookRepositoryError,
+};
+
+/// Endpoint as kept by `InMemoryWebhookRepository`
+#[derive(Clone)]
+pub struct StoredEndpoint {
+    pub owner: UserId,
+    pub secret: String,
+    pub endpoint: WebhookEndpoint,
+    pub deliveries: Vec<WebhookDelivery>,
+}
+
+/// Process-local `WebhookRepository` for the webhook service tests.
+#[derive(Clone, Default)]
+pub struct InMemoryWebhookRepository {
+    pub endpoints: Arc<std::sync::Mutex<Vec<StoredEndpoint>>>,
+    pub fail: bool,
+}
+
+impl InMemoryWebhookRepository {
+    pub fn failing() -> Self {
+        Self {
+            fail: true,
+            ..Default::default()
+        }
+    }
+
+    fn check(&self) -> Result<(), WebhookRepositoryError> {
+        if self.fail {
+            return Err(WebhookRepositoryError::DatabaseError("db down".to_string()));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for InMemoryWebhookRepository {
+    async fn create(
+        &self,
+        owner: UserId,
+        url: &str,
+        events: &[WebhookEvent],
+        secret: &str,
+    ) -> Result<WebhookEndpoint, WebhookRepositoryError> {
+        self.check()?;
+        let endpoint = WebhookEndpoint {
+            id: Uuid::new_v4(),
+            url: url.to_string(),
+            events: events.to_vec(),
+            created_at: chrono::Utc::now(),
+        };
+        self.endpoints.lock().unwrap().push(StoredEndpoint {
+            owner,
+            secret: secret.to_string(),
+            endpoint: endpoint.clone(),
+            deliveries: Vec::new(),
+        });
+        Ok(endpoint)
+    }
+
+    async fn list(&self, owner: UserId) -> Result<Vec<WebhookEndpoint>, WebhookRepositoryError> {
+        self.check()?;
+        Ok(self
+            .endpoints
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|stored| stored.owner == owner)
+            .map(|stored| stored.endpoint.clone())
+            .collect())
+    }
+
+    async fn delete(&self, owner: UserId, id: Uuid) -> Result<(), WebhookRepositoryError> {
+        self.check()?;
+        let mut endpoints = self.endpoints.lock().unwrap();
+        let before = endpoints.len();
+        endpoints.retain(|stored| !(stored.owner == owner && stored.endpoint.id == id));
+        if endpoints.len() == before {
+            return Err(WebhookRepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn list_deliveries(
+        &self,
+        owner: UserId,
+        endpoint_id: Uuid,
+        offset: u64,
+        limit: u32,
+    ) -> Result<DeliveryPage, WebhookRepositoryError> {
+        self.check()?;
+        let endpoints = self.endpoints.lock().unwrap();
+        let stored = endpoints
+            .iter()
+            .find(|stored| stored.owner == owner && stored.endpoint.id == endpoint_id)
+            .ok_or(WebhookRepositoryError::NotFound)?;
+
+        Ok(DeliveryPage {
+            items: stored
+                .deliveries
+                .iter()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .cloned()
+                .collect(),
+            total: stored.deliveries.len() as u64,
+        })
+    }
+}
+
+fn sample_endpoint(events: Vec<WebhookEvent>) -> WebhookEndpoint {
+    WebhookEndpoint {
+        id: Uuid::new_v4(),
+        url: "https://builder.example.com/rebuild".to_string(),
+        events,
+        created_at: chrono::Utc::now(),
+    }
+}
+
+/// Echoes the command back with a fixed secret
+pub struct StubCreateWebhookUseCase;
+
+#[async_trait]
+impl CreateWebhookUseCase for StubCreateWebhookUseCase {
+    async fn execute(
+        &self,
+        command: CreateWebhookCommand,
+    ) -> Result<CreatedWebhook, CreateWebhookError> {
+        Ok(CreatedWebhook {
+            endpoint: WebhookEndpoint {
+                url: command.url().to_string(),
+                ..sample_endpoint(command.events().to_vec())
+            },
+            secret: "whsec_test".to_string(),
+        })
+    }
+}
+
+pub struct StubListWebhooksUseCase {
+    result: Result<Vec<WebhookEndpoint>, ListWebhooksError>,
+}
+
+impl StubListWebhooksUseCase {
+    pub fn empty() -> Self {
+        Self { result: Ok(vec![]) }
+    }
+
+    pub fn one() -> Self {
+        Self {
+            result: Ok(vec![sample_endpoint(vec![WebhookEvent::ProjectUpdated])]),
+        }
+    }
+}
+
+#[async_trait]
+impl ListWebhooksUseCase for StubListWebhooksUseCase {
+    async fn execute(&self, _owner: UserId) -> Result<Vec<WebhookEndpoint>, ListWebhooksError> {
+        self.result.clone()
+    }
+}
+
+pub struct StubDeleteWebhookUseCase {
+    result: Result<(), DeleteWebhookError>,
+}
+
+impl StubDeleteWebhookUseCase {
+    pub fn success() -> Self {
+        Self { result: Ok(()) }
+    }
+
+    pub fn not_found() -> Self {
+        Self {
+            result: Err(DeleteWebhookError::NotFound),
+        }
+    }
+}
+
+#[async_trait]
+impl DeleteWebhookUseCase for StubDeleteWebhookUseCase {
+    async fn execute(&self, _owner: UserId, _id: Uuid) -> Result<(), DeleteWebhookError> {
+        self.result.clone()
+    }
+}
+
+pub struct StubListWebhookDeliveriesUseCase {
+    result: Result<Vec<WebhookDelivery>, ListWebhookDeliveriesError>,
+}
+
+impl StubListWebhookDeliveriesUseCase {
+    pub fn empty() -> Self {
+        Self { result: Ok(vec![]) }
+    }
+
+    pub fn one_failed() -> Self {
+        let now = chrono::Utc::now();
+        Self {
+            result: Ok(vec![WebhookDelivery {
+                id: Uuid::new_v4(),
+                event: WebhookEvent::MediaReady,
+                attempts: 1,
+                last_status_code: Some(502),
+                last_error: Some("endpoint answered HTTP 502".to_string()),
+                delivered_at: None,
+                next_attempt_at: Some(now),
+                created_at: now,
+            }]),
+        }
+    }
+
+    pub fn not_found() -> Self {
+        Self {
+            result: Err(ListWebhookDeliveriesError::NotFound),
+        }
+    }
+}
+
+#[async_trait]
+impl ListWebhookDeliveriesUseCase for StubListWebhookDeliveriesUseCase {
+    async fn execute(
+        &self,
+        _owner: UserId,
+        _endpoint_id: Uuid,
+        _offset: u64,
+        _limit: u32,
+    ) -> Result<DeliveryPage, ListWebhookDeliveriesError> {
+        self.result.clone().map(|items| DeliveryPage {
+            total: items.len() as u64,
+            items,
+        })
+    }
+}
+
+use crate::email::application::ports::incoming::use_cases::{
+    ListOutboxEmailsError, ListOutboxEmailsUseCase,
+};
+use crate::email::application::ports::outgoing::{
+    OutboxEmailPage, OutboxEmailStatus, OutboxEmailSummary,
+};
+
+pub struct StubListOutboxEmailsUseCase {
+    result: Result<OutboxEmailPage, ListOutboxEmailsError>,
+}
+
+impl StubListOutboxEmailsUseCase {
+    pub fn empty() -> Self {
+        Self {
+            result: Ok(OutboxEmailPage {
+                items: Vec::new(),
+                total: 0,
+            }),
+        }
+    }
+
+    /// A verification email given up on after repeated SMTP failures
+    pub fn one_failed() -> Self {
+        Self {
+            result: Ok(OutboxEmailPage {
+                items: vec![OutboxEmailSummary {
+                    id: Uuid::new_v4(),
+                    user_id: Uuid::new_v4(),
+                    recipient: "jane@example.com".to_string(),
+                    kind: "verification".to_string(),
+                    status: OutboxEmailStatus::Failed,
+                    attempts: 8,
+                    last_error: Some("535 5.7.8 authentication failed".to_string()),
+                    next_attempt_at: None,
+                    sent_at: None,
+                    created_at: chrono::Utc::now(),
+                }],
+                total: 1,
+            }),
+        }
+    }
+
+    pub fn failure(msg: &str) -> Self {
+        Self {
+            result: Err(ListOutboxEmailsError::QueryFailed(msg.into())),
+        }
+    }
+}
+
+#[async_trait]
+impl ListOutboxEmailsUseCase for StubListOutboxEmailsUseCase {
+    async fn execute(
+        &self,
+        _status: Option<OutboxEmailStatus>,
+        _offset: u64,
+        _limit: u32,
+    ) -> Result<OutboxEmailPage, ListOutboxEmailsError> {
+        self.result.clone()
+    }
+}
+
+use crate::email::application::ports::incoming::use_cases::{
+    EmailEvent, EmailEventKind, EmailEventsSummary, RecordEmailEventsError,
+    RecordEmailEventsUseCase,
+};
+
+/// Counts every hard bounce and complaint as a known address
+pub struct StubRecordEmailEventsUseCase {
+    failure: Option<RecordEmailEventsError>,
+}
+
+impl StubRecordEmailEventsUseCase {
+    pub fn success() -> Self {
+        Self { failure: None }
+    }
+
+    pub fn failure(msg: &str) -> Self {
+        Self {
+            failure: Some(RecordEmailEventsError::StoreFailed(msg.into())),
+        }
+    }
+}
+
+#[async_trait]
+impl RecordEmailEventsUseCase for StubRecordEmailEventsUseCase {
+    async fn execute(
+        &self,
+        events: Vec<EmailEvent>,
+    ) -> Result<EmailEventsSummary, RecordEmailEventsError> {
+        if let Some(err) = &self.failure {
+            return Err(err.clone());
+        }
+        Ok(EmailEventsSummary {
+            received: events.len(),
+            bounced: events
+                .iter()
+                .filter(|event| {
+                    matches!(
+                        event.kind,
+                        EmailEventKind::HardBounce | EmailEventKind::Complaint
+                    )
+                })
+                .count(),
+        })
+    }
+}
+
+use crate::contact::application::domain::entities::ContactMessage;
+use crate::contact::application::ports::incoming::use_cases::{
+    DeleteContactMessageError, DeleteContactMessageUseCase, ListContactMessagesError,
+    ListContactMessagesUseCase, MarkContactMessageReadError, MarkContactMessageReadUseCase,
+    SubmitContactMessageCommand, SubmitContactMessageError, SubmitContactMessageUseCase,
+};
+use crate::contact::application::ports::outgoing::ContactMessagePage;
+
+pub fn sample_contact_message() -> ContactMessage {
+    ContactMessage {
+        id: Uuid::new_v4(),
+        name: "Jane".to_string(),
+        email: "jane@example.com".to_string(),
+        subject: Some("Hello".to_string()),
+        message: "Are you available for hire?".to_string(),
+        ip_address: Some("203.0.113.7".to_string()),
+        read_at: None,
+        created_at: chrono::Utc::now(),
+    }
+}
+
+/// Echoes the command back as the stored message
+pub struct StubSubmitContactMessageUseCase {
+    failure: Option<SubmitContactMessageError>,
+}
+
+impl StubSubmitContactMessageUseCase {
+    pub fn success() -> Self {
+        Self { failure: None }
+    }
+
+    pub fn failure(msg: &str) -> Self {
+        Self {
+            failure: Some(SubmitContactMessageError::RepositoryError(msg.into())),
+        }
+    }
+}
+
+#[async_trait]
+impl SubmitContactMessageUseCase for StubSubmitContactMessageUseCase {
+    async fn execute(
+        &self,
+        command: SubmitContactMessageCommand,
+    ) -> Result<ContactMessage, SubmitContactMessageError> {
+        if let Some(err) = &self.failure {
+            return Err(err.clone());
+        }
+        Ok(ContactMessage {
+            name: command.name().to_string(),
+            email: command.email().to_string(),
+            subject: command.subject().map(str::to_string),
+            message: command.message().to_string(),
+            ip_address: command.ip_address().map(str::to_string),
+            ..sample_contact_message()
+        })
+    }
+}
+
+pub struct StubListContactMessagesUseCase {
+    result: Result<Vec<ContactMessage>, ListContactMessagesError>,
+}
+
+impl StubListContactMessagesUseCase {
+    pub fn empty() -> Self {
+        Self { result: Ok(vec![]) }
+    }
+
+    pub fn one_unread() -> Self {
+        Self {
+            result: Ok(vec![sample_contact_message()]),
+        }
+    }
+}
+
+#[async_trait]
+impl ListContactMessagesUseCase for StubListContactMessagesUseCase {
+    async fn execute(
+        &self,
+        _unread_only: bool,
+        _offset: u64,
+        _limit: u32,
+    ) -> Result<ContactMessagePage, ListContactMessagesError> {
+        self.result.clone().map(|items| ContactMessagePage {
+            total: items.len() as u64,
+            items,
+        })
+    }
+}
+
+pub struct StubMarkContactMessageReadUseCase {
+    result: Result<ContactMessage, MarkContactMessageReadError>,
+}
+
+impl StubMarkContactMessageReadUseCase {
+    pub fn success() -> Self {
+        Self {
+            result: Ok(ContactMessage {
+                read_at: Some(chrono::Utc::now()),
+                ..sample_contact_message()
+            }),
+        }
+    }
+
+    pub fn not_found() -> Self {
+        Self {
+            result: Err(MarkContactMessageReadError::NotFound),
+        }
+    }
+}
+
+#[async_trait]
+impl MarkContactMessageReadUseCase for StubMarkContactMessageReadUseCase {
+    async fn execute(&self, _id: Uuid) -> Result<ContactMessage, MarkContactMessageReadError> {
+        self.result.clone()
+    }
+}
+
+pub struct StubDeleteContactMessageUseCase {
+    result: Result<(), DeleteContactMessageError>,
+}
+
+impl StubDeleteContactMessageUseCase {
+    pub fn success() -> Self {
+        Self { result: Ok(()) }
+    }
+
+    pub fn not_found() -> Self {
+        Self {
+            result: Err(DeleteContactMessageError::NotFound),
+        }
+    }
+}
+
+#[async_trait]
+impl DeleteContactMessageUseCase for StubDeleteContactMessageUseCase {
+    async fn execute(&self, _id: Uuid) -> Result<(), DeleteContactMessageError> {
+        self.result.clone()
+    }
+}
+
+use crate::email::application::ports::incoming::use_cases::{UnsubscribeError, UnsubscribeUseCase};
+
+pub struct StubUnsubscribeUseCase {
+    result: Result<(), UnsubscribeError>,
+}
+
+impl StubUnsubscribeUseCase {
+    pub fn success() -> Self {
+        Self { result: Ok(()) }
+    }
+
+    pub fn invalid_token() -> Self {
+        Self {
+            result: Err(UnsubscribeError::InvalidToken),
+        }
+    }
+
+    pub fn failure(msg: &str) -> Self {
+        Self {
+            result: Err(UnsubscribeError::StoreFailed(msg.into())),
+        }
+    }
+}
+
+#[async_trait]
+impl UnsubscribeUseCase for StubUnsubscribeUseCase {
+    async fn execute(&self, _token: &str) -> Result<(), UnsubscribeError> {
+        self.result.clone()
+    }
+}
+
+use crate::site::application::domain::settings::{SiteProfile, SiteSettings};
+use crate::site::application::domain::theme::ThemeSettings;
+use crate::site::application::ports::incoming::use_cases::{
+    GetSiteProfileError, GetSiteProfileUseCase, GetThemeError, GetThemeUseCase,
+    UpdateSiteSettingsCommand, UpdateSiteSettingsError, UpdateSiteSettingsUseCase,
+    UpdateThemeCommand, UpdateThemeError, UpdateThemeUseCase,
+};
+
+pub struct StubGetSiteProfileUseCase {
+    result: Result<SiteProfile, GetSiteProfileError>,
+}
+
+impl StubGetSiteProfileUseCase {
+    /// Nothing set: every field has its default
+    pub fn defaults() -> Self {
+        Self {
+            result: Ok(SiteProfile::from(&SiteSettings::default())),
+        }
+    }
+
+    pub fn failure(msg: &str) -> Self {
+        Self {
+            result: Err(GetSiteProfileError::RepositoryError(msg.into())),
+        }
+    }
+}
+
+#[async_trait]
+impl GetSiteProfileUseCase for StubGetSiteProfileUseCase {
+    async fn execute(&self) -> Result<SiteProfile, GetSiteProfileError> {
+        self.result.clone()
+    }
+}
+
+/// Applies the command to empty settings, as if nothing had been set before
+pub struct StubUpdateSiteSettingsUseCase {
+    failure: Option<UpdateSiteSettingsError>,
+}
+
+impl StubUpdateSiteSettingsUseCase {
+    pub fn success() -> Self {
+        Self { failure: None }
+    }
+
+    pub fn failure(msg: &str) -> Self {
+        Self {
+            failure: Some(UpdateSiteSettingsError::RepositoryError(msg.into())),
+        }
+    }
+}
+
+#[async_trait]
+impl UpdateSiteSettingsUseCase for StubUpdateSiteSettingsUseCase {
+    async fn execute(
+        &self,
+        command: UpdateSiteSettingsCommand,
+    ) -> Result<SiteProfile, UpdateSiteSettingsError> {
+        if let Some(err) = &self.failure {
+            return Err(err.clone());
+        }
+        let mut settings = SiteSettings::default();
+        for change in command.changes() {
+            if let Some(value) = &change.value {
+                settings.set(change.key, value.clone());
+            }
+        }
+        Ok(SiteProfile::from(&settings))
+    }
+}
+
+pub struct StubGetThemeUseCase {
+    result: Result<ThemeSettings, GetThemeError>,
+}
+
+impl StubGetThemeUseCase {
+    /// Nothing saved yet
+    pub fn defaults() -> Self {
+        Self {
+            result: Ok(ThemeSettings::default()),
+        }
+    }
+
+    pub fn failure(msg: &str) -> Self {
+        Self {
+            result: Err(GetThemeError::RepositoryError(msg.into())),
+        }
+    }
+}
+
+#[async_trait]
+impl GetThemeUseCase for StubGetThemeUseCase {
+    async fn execute(&self) -> Result<ThemeSettings, GetThemeError> {
+        self.result.clone()
+    }
+}
+
+/// Applies the patch to an empty theme without checking it
+pub struct StubUpdateThemeUseCase {
+    failure: Option<UpdateThemeError>,
+}
+
+impl StubUpdateThemeUseCase {
+    pub fn success() -> Self {
+        Self { failure: None }
+    }
+
+    pub fn invalid(errors: &[&str]) -> Self {
+        Self {
+            failure: Some(UpdateThemeError::InvalidConfig(
+                errors.iter().map(|e| e.to_string()).collect(),
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl UpdateThemeUseCase for StubUpdateThemeUseCase {
+    async fn execute(
+        &self,
+        command: UpdateThemeCommand,
+    ) -> Result<ThemeSettings, UpdateThemeError> {
+        if let Some(err) = &self.failure {
+            return Err(err.clone());
+        }
+        let theme = ThemeSettings::default();
+        Ok(ThemeSettings {
+            config: theme.patched(command.patch()),
+            ..theme
+        })
+    }
+}
+
+use crate::pages::application::domain::autosave::PageAutosave;
+use crate::pages::application::domain::edit_lock::EditLock;
+use crate::pages::application::domain::entities::{Page, PageStatus};
+use crate::pages::application::ports::incoming::use_cases::{
+    AcquirePageLockError, AcquirePageLockUseCase, AutosavePageError, AutosavePageUseCase,
+    CreatePageCommand, CreatePageError, CreatePageUseCase, CreatePreviewTokenError,
+    CreatePreviewTokenUseCase, DeletePageError, DeletePageUseCase, GetPageAutosaveError,
+    GetPageAutosaveUseCase, GetPageError, GetPagePreviewError, GetPagePreviewUseCase,
+    GetPageUseCase, GetPublicPageError, GetPublicPageUseCase, ListPagesError, ListPagesUseCase,
+    PreviewToken, ReleasePageLockError, ReleasePageLockUseCase, UpdatePageCommand, UpdatePageError,
+    UpdatePageUseCase,
+};
+
+pub fn sample_page() -> Page {
+    Page {
+        id: Uuid::new_v4(),
+        owner_id: Uuid::new_v4(),
+        slug: "about".to_string(),
+        title: "About".to_string(),
+        body: "# Hello".to_string(),
+        status: PageStatus::Published,
+        seo_title: None,
+        seo_description: None,
+        canonical_url: None,
+        noindex: false,
+        published_at: Some(chrono::Utc::now()),
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+    }
+}
+
+pub struct StubCreatePageUseCase {
+    failure: Option<CreatePageError>,
+}
+
+impl StubCreatePageUseCase {
+    pub fn success() -> Self {
+        Self { failure: None }
+    }
+
+    pub fn slug_taken() -> Self {
+        Self {
+            failure: Some(CreatePageError::SlugAlreadyExists),
+        }
+    }
+}
+
+#[async_trait]
+impl CreatePageUseCase for StubCreatePageUseCase {
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        command: CreatePageCommand,
+    ) -> Result<Page, CreatePageError> {
+        if let Some(err) = &self.failure {
+            return Err(err.clone());
+        }
+        Ok(Page {
+            owner_id,
+            slug: command.slug().to_string(),
+            title: command.title().to_string(),
+            body: command.body().to_string(),
+            status: command.status(),
+            seo_title: command.seo_title().map(str::to_string),
+            seo_description: command.seo_description().map(str::to_string),
+            canonical_url: command.canonical_url().map(str::to_string),
+            noindex: command.noindex(),
+            published_at: None,
+            ..sample_page()
+        })
+    }
+}
+
+pub struct StubListPagesUseCase {
+    result: Result<Vec<Page>, ListPagesError>,
+}
+
+impl StubListPagesUseCase {
+    pub fn empty() -> Self {
+        Self { result: Ok(vec![]) }
+    }
+}
+
+#[async_trait]
+impl ListPagesUseCase for StubListPagesUseCase {
+    async fn execute(&self, _owner_id: Uuid) -> Result<Vec<Page>, ListPagesError> {
+        self.result.clone()
+    }
+}
+
+pub struct StubGetPageUseCase {
+    result: Result<Page, GetPageError>,
+}
+
+impl StubGetPageUseCase {
+    pub fn found() -> Self {
+        Self {
+            result: Ok(sample_page()),
+        }
+    }
+}
+
+#[async_trait]
+impl GetPageUseCase for StubGetPageUseCase {
+    async fn execute(&self, _owner_id: Uuid, _id: Uuid) -> Result<Page, GetPageError> {
+        self.result.clone()
+    }
+}
+
+pub struct StubUpdatePageUseCase {
+    result: Result<Page, UpdatePageError>,
+}
+
+impl StubUpdatePageUseCase {
+    pub fn success() -> Self {
+        Self {
+            result: Ok(sample_page()),
+        }
+    }
+
+    pub fn not_found() -> Self {
+        Self {
+            result: Err(UpdatePageError::NotFound),
+        }
+    }
+
+    pub fn stale() -> Self {
+        Self {
+            result: Err(UpdatePageError::Stale),
+        }
+    }
+
+    pub fn locked() -> Self {
+        Self {
+            result: Err(UpdatePageError::Locked(sample_lock())),
+        }
+    }
+}
+
+#[async_trait]
+impl UpdatePageUseCase for StubUpdatePageUseCase {
+    async fn execute(
+        &self,
+        _owner_id: Uuid,
+        _id: Uuid,
+        _command: UpdatePageCommand,
+    ) -> Result<Page, UpdatePageError> {
+        self.result.clone()
+    }
+}
+
+/// Held by a session calling itself "Firefox on laptop"
+pub fn sample_lock() -> EditLock {
+    EditLock::lease(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        Some("Firefox on laptop".to_string()),
+        chrono::Utc::now(),
+    )
+}
+
+pub struct StubAcquirePageLockUseCase {
+    holder: Option<EditLock>,
+}
+
+impl StubAcquirePageLockUseCase {
+    /// Grants every request
+    pub fn success() -> Self {
+        Self { holder: None }
+    }
+
+    pub fn locked() -> Self {
+        Self {
+            holder: Some(sample_lock()),
+        }
+    }
+
+    pub fn holder_lock_id(&self) -> Uuid {
+        self.holder
+            .as_ref()
+            .map(|lock| lock.lock_id)
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl AcquirePageLockUseCase for StubAcquirePageLockUseCase {
+    async fn execute(
+        &self,
+        _owner_id: Uuid,
+        page_id: Uuid,
+        lock_id: Option<Uuid>,
+        label: Option<String>,
+    ) -> Result<EditLock, AcquirePageLockError> {
+        if let Some(holder) = &self.holder {
+            return Err(AcquirePageLockError::Locked(holder.clone()));
+        }
+        Ok(EditLock::lease(
+            page_id,
+            lock_id.unwrap_or_else(Uuid::new_v4),
+            label,
+            chrono::Utc::now(),
+        ))
+    }
+}
+
+pub struct StubReleasePageLockUseCase {
+    result: Result<(), ReleasePageLockError>,
+}
+
+impl StubReleasePageLockUseCase {
+    pub fn success() -> Self {
+        Self { result: Ok(()) }
+    }
+}
+
+#[async_trait]
+impl ReleasePageLockUseCase for StubReleasePageLockUseCase {
+    async fn execute(
+        &self,
+        _owner_id: Uuid,
+        _page_id: Uuid,
+        _lock_id: Uuid,
+    ) -> Result<(), ReleasePageLockError> {
+        self.result.clone()
+    }
+}
+
+pub struct StubAutosavePageUseCase {
+    failure: Option<AutosavePageError>,
+}
+
+impl StubAutosavePageUseCase {
+    pub fn success() -> Self {
+        Self { failure: None }
+    }
+
+    pub fn body_too_long() -> Self {
+        Self {
+            failure: Some(AutosavePageError::BodyTooLong),
+        }
+    }
+}
+
+#[async_trait]
+impl AutosavePageUseCase for StubAutosavePageUseCase {
+    async fn execute(
+        &self,
+        _owner_id: Uuid,
+        page_id: Uuid,
+        body: String,
+    ) -> Result<PageAutosave, AutosavePageError> {
+        if let Some(err) = &self.failure {
+            return Err(err.clone());
+        }
+        Ok(PageAutosave {
+            page_id,
+            body,
+            saved_at: chrono::Utc::now(),
+        })
+    }
+}
+
+pub struct StubGetPageAutosaveUseCase {
+    result: Result<Option<String>, GetPageAutosaveError>,
+}
+
+impl StubGetPageAutosaveUseCase {
+    pub fn none() -> Self {
+        Self { result: Ok(None) }
+    }
+
+    /// "# Hello, unsaved", taken after the page's last save
+    pub fn restorable() -> Self {
+        Self {
+            result: Ok(Some("# Hello, unsaved".to_string())),
+        }
+    }
+
+    pub fn failing() -> Self {
+        Self {
+            result: Err(GetPageAutosaveError::RepositoryError("down".to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl GetPageAutosaveUseCase for StubGetPageAutosaveUseCase {
+    async fn execute(&self, page: &Page) -> Result<Option<PageAutosave>, GetPageAutosaveError> {
+        self.result.clone().map(|body| {
+            body.map(|body| PageAutosave {
+                page_id: page.id,
+                body,
+                saved_at: page.updated_at + chrono::Duration::seconds(5),
+            })
+        })
+    }
+}
+
+pub struct StubDeletePageUseCase {
+    result: Result<(), DeletePageError>,
+}
+
+impl StubDeletePageUseCase {
+    pub fn success() -> Self {
+        Self { result: Ok(()) }
+    }
+}
+
+#[async_trait]
+impl DeletePageUseCase for StubDeletePageUseCase {
+    async fn execute(&self, _owner_id: Uuid, _id: Uuid) -> Result<(), DeletePageError> {
+        self.result.clone()
+    }
+}
+
+pub struct StubGetPublicPageUseCase {
+    result: Result<Page, GetPublicPageError>,
+}
+
+impl StubGetPublicPageUseCase {
+    pub fn found() -> Self {
+        Self {
+            result: Ok(sample_page()),
+        }
+    }
+
+    pub fn noindex() -> Self {
+        Self {
+            result: Ok(Page {
+                canonical_url: Some("https://dev.to/jane/about".to_string()),
+                noindex: true,
+                ..sample_page()
+            }),
+        }
+    }
+
+    pub fn not_found() -> Self {
+        Self {
+            result: Err(GetPublicPageError::NotFound),
+        }
+    }
+
+    pub fn gone() -> Self {
+        Self {
+            result: Err(GetPublicPageError::Gone(chrono::Utc::now())),
+        }
+    }
+}
+
+#[async_trait]
+impl GetPublicPageUseCase for StubGetPublicPageUseCase {
+    async fn execute(&self, _owner_id: Uuid, _slug: &str) -> Result<Page, GetPublicPageError> {
+        self.result.clone()
+    }
+}
+
+pub struct StubCreatePreviewTokenUseCase {
+    failure: Option<CreatePreviewTokenError>,
+}
+
+impl StubCreatePreviewTokenUseCase {
+    pub fn success() -> Self {
+        Self { failure: None }
+    }
+
+    pub fn invalid_expiry() -> Self {
+        Self {
+            failure: Some(CreatePreviewTokenError::InvalidExpiry),
+        }
+    }
+
+    pub fn not_found() -> Self {
+        Self {
+            failure: Some(CreatePreviewTokenError::NotFound),
+        }
+    }
+}
+
+#[async_trait]
+impl CreatePreviewTokenUseCase for StubCreatePreviewTokenUseCase {
+    async fn execute(
+        &self,
+        _owner_id: Uuid,
+        page_id: Uuid,
+        _expires_in_hours: Option<u32>,
+    ) -> Result<PreviewToken, CreatePreviewTokenError> {
+        if let Some(err) = &self.failure {
+            return Err(err.clone());
+        }
+        Ok(PreviewToken {
+            token: format!("{page_id}.signature"),
+            expires_at: chrono::Utc::now() + chrono::Duration::days(7),
+        })
+    }
+}
+
+pub struct StubGetPagePreviewUseCase {
+    result: Result<Page, GetPagePreviewError>,
+}
+
+impl StubGetPagePreviewUseCase {
+    pub fn draft() -> Self {
+        Self {
+            result: Ok(Page {
+                status: PageStatus::Draft,
+                published_at: None,
+                ..sample_page()
+            }),
+        }
+    }
+
+    pub fn invalid() -> Self {
+        Self {
+            result: Err(GetPagePreviewError::InvalidToken),
+        }
+    }
+
+    pub fn expired() -> Self {
+        Self {
+            result: Err(GetPagePreviewError::Expired),
+        }
+    }
+}
+
+#[async_trait]
+impl GetPagePreviewUseCase for StubGetPagePreviewUseCase {
+    async fn execute(&self, _token: &str) -> Result<Page, GetPagePreviewError> {
+        self.result.clone()
+    }
+}
+
+use crate::analytics::application::domain::entities::{
+    DailyVisitors, PageStats, PathReport, ReferrerStats,
+};
+use crate::analytics::application::ports::incoming::use_cases::{
+    AnalyticsReportError, GetDailyVisitorsUseCase, GetPathReportUseCase, GetTopPagesUseCase,
+    GetTopReferrersUseCase, RecordPageViewCommand, RecordPageViewUseCase, ReportRange,
+};
+
+/// Drops every view
+pub struct StubRecordPageViewUseCase;
+
+#[async_trait]
+impl RecordPageViewUseCase for StubRecordPageViewUseCase {
+    async fn execute(&self, _command: RecordPageViewCommand) {}
+}
+
+/// Keeps the path of every view; clones share them
+#[derive(Clone, Default)]
+pub struct RecordingPageViewUseCase {
+    paths: Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+impl RecordingPageViewUseCase {
+    pub fn paths(&self) -> Vec<String> {
+        self.paths.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl RecordPageViewUseCase for RecordingPageViewUseCase {
+    async fn execute(&self, command: RecordPageViewCommand) {
+        self.paths.lock().unwrap().push(command.path().to_string());
+    }
+}
+
+pub struct StubGetTopPagesUseCase {
+    result: Result<Vec<PageStats>, AnalyticsReportError>,
+}
+
+impl StubGetTopPagesUseCase {
+    pub fn one() -> Self {
+        Self {
+            result: Ok(vec![PageStats {
+                path: "/projects".to_string(),
+                views: 12,
+                visitors: 4,
+            }]),
+        }
+    }
+
+    pub fn failure(msg: &str) -> Self {
+        Self {
+            result: Err(AnalyticsReportError::QueryFailed(msg.into())),
+        }
+    }
+}
+
+#[async_trait]
+impl GetTopPagesUseCase for StubGetTopPagesUseCase {
+    async fn execute(
+        &self,
+        _range: ReportRange,
+        _limit: u32,
+    ) -> Result<Vec<PageStats>, AnalyticsReportError> {
+        self.result.clone()
+    }
+}
+
+pub struct StubGetTopReferrersUseCase;
+
+#[async_trait]
+impl GetTopReferrersUseCase for StubGetTopReferrersUseCase {
+    async fn execute(
+        &self,
+        _range: ReportRange,
+        _limit: u32,
+    ) -> Result<Vec<ReferrerStats>, AnalyticsReportError> {
+        Ok(vec![ReferrerStats {
+            referrer: "news.ycombinator.com".to_string(),
+            views: 7,
+            visitors: 6,
+        }])
+    }
+}
+
+/// Five views by three visitors on every day of the range
+pub struct StubGetDailyVisitorsUseCase;
+
+#[async_trait]
+impl GetDailyVisitorsUseCase for StubGetDailyVisitorsUseCase {
+    async fn execute(
+        &self,
+        range: ReportRange,
+    ) -> Result<Vec<DailyVisitors>, AnalyticsReportError> {
+        Ok(range
+            .days()
+            .map(|day| DailyVisitors {
+                day,
+                views: 5,
+                visitors: 3,
+            })
+            .collect())
+    }
+}
+
+/// Two views by two visitors on every day of the range, all from one referrer
+pub struct StubGetPathReportUseCase;
+
+#[async_trait]
+impl GetPathReportUseCase for StubGetPathReportUseCase {
+    async fn execute(
+        &self,
+        _path: &str,
+        range: ReportRange,
+        _referrer_limit: u32,
+    ) -> Result<PathReport, AnalyticsReportError> {
+        let days = range.days().count() as u64;
+        Ok(PathReport {
+            views: 2 * days,
+            visitors: 2 * days,
+            daily: range
+                .days()
+                .map(|day| DailyVisitors {
+                    day,
+                    views: 2,
+                    visitors: 2,
+                })
+                .collect(),
+            referrers: vec![ReferrerStats {
+                referrer: "www.linkedin.com".to_string(),
+                views: 2 * days,
+                visitors: 2 * days,
+            }],
+        })
+    }
+}
+
+use crate::redirects::application::domain::entities::{Redirect, RedirectStatus};
+use crate::redirects::application::ports::incoming::use_cases::{
+    CreateRedirectCommand, CreateRedirectError, CreateRedirectUseCase, DeleteRedirectError,
+    DeleteRedirectUseCase, ListRedirectsError, ListRedirectsUseCase, ResolveRedirectError,
+    ResolveRedirectUseCase, UpdateRedirectCommand, UpdateRedirectError, UpdateRedirectUseCase,
+};
+use crate::redirects::application::ports::outgoing::RedirectPage;
+
+pub fn sample_redirect() -> Redirect {
+    Redirect {
+        id: Uuid::new_v4(),
+        source_path: "/r/cv".to_string(),
+        target_url: "https://example.com/cv.pdf".to_string(),
+        status_code: RedirectStatus::Permanent,
+        hits: 12,
+        last_hit_at: Some(chrono::Utc::now()),
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+    }
+}
+
+pub struct StubCreateRedirectUseCase {
+    failure: Option<CreateRedirectError>,
+}
+
+impl StubCreateRedirectUseCase {
+    pub fn success() -> Self {
+        Self { failure: None }
+    }
+
+    pub fn conflict() -> Self {
+        Self {
+            failure: Some(CreateRedirectError::SourceAlreadyExists),
+        }
+    }
+}
+
+#[async_trait]
+impl CreateRedirectUseCase for StubCreateRedirectUseCase {
+    async fn execute(
+        &self,
+        command: CreateRedirectCommand,
+    ) -> Result<Redirect, CreateRedirectError> {
+        if let Some(err) = &self.failure {
+            return Err(err.clone());
+        }
+        Ok(Redirect {
+            source_path: command.source_path().to_string(),
+            target_url: command.target_url().to_string(),
+            status_code: command.status(),
+            hits: 0,
+            last_hit_at: None,
+            ..sample_redirect()
+        })
+    }
+}
+
+pub struct StubListRedirectsUseCase {
+    result: Result<RedirectPage, ListRedirectsError>,
+}
+
+impl StubListRedirectsUseCase {
+    pub fn empty() -> Self {
+        Self {
+            result: Ok(RedirectPage::default()),
+        }
+    }
+
+    pub fn one() -> Self {
+        Self {
+            result: Ok(RedirectPage {
+                items: vec![sample_redirect()],
+                total: 1,
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl ListRedirectsUseCase for StubListRedirectsUseCase {
+    async fn execute(&self, _offset: u64, _limit: u32) -> Result<RedirectPage, ListRedirectsError> {
+        self.result.clone()
+    }
+}
+
+pub struct StubUpdateRedirectUseCase {
+    result: Result<Redirect, UpdateRedirectError>,
+}
+
+impl StubUpdateRedirectUseCase {
+    pub fn success() -> Self {
+        Self {
+            result: Ok(sample_redirect()),
+        }
+    }
+
+    pub fn not_found() -> Self {
+        Self {
+            result: Err(UpdateRedirectError::NotFound),
+        }
+    }
+}
+
+#[async_trait]
+impl UpdateRedirectUseCase for StubUpdateRedirectUseCase {
+    async fn execute(
+        &self,
+        _id: Uuid,
+        _command: UpdateRedirectCommand,
+    ) -> Result<Redirect, UpdateRedirectError> {
+        self.result.clone()
+    }
+}
+
+pub struct StubDeleteRedirectUseCase {
+    result: Result<(), DeleteRedirectError>,
+}
+
+impl StubDeleteRedirectUseCase {
+    pub fn success() -> Self {
+        Self { result: Ok(()) }
+    }
+
+    pub fn not_found() -> Self {
+        Self {
+            result: Err(DeleteRedirectError::NotFound),
+        }
+    }
+}
+
+#[async_trait]
+impl DeleteRedirectUseCase for StubDeleteRedirectUseCase {
+    async fn execute(&self, _id: Uuid) -> Result<(), DeleteRedirectError> {
+        self.result.clone()
+    }
+}
+
+/// Resolves only `source_path`
+pub struct StubResolveRedirectUseCase {
+    redirect: Option<Redirect>,
+}
+
+impl StubResolveRedirectUseCase {
+    pub fn none() -> Self {
+        Self { redirect: None }
+    }
+
+    pub fn temporary(source_path: &str, target_url: &str) -> Self {
+        Self {
+            redirect: Some(Redirect {
+                source_path: source_path.to_string(),
+                target_url: target_url.to_string(),
+                status_code: RedirectStatus::Temporary,
+                ..sample_redirect()
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl ResolveRedirectUseCase for StubResolveRedirectUseCase {
+    async fn execute(&self, path: &str) -> Result<Redirect, ResolveRedirectError> {
+        self.redirect
+            .clone()
+            .filter(|redirect| redirect.source_path == path)
+            .ok_or(ResolveRedirectError::NotFound)
+    }
+}
+
+use crate::search::application::domain::entities::{ContentKind, KindCounts, SearchHit};
+use crate::search::application::ports::incoming::use_cases::{
+    SearchContentError, SearchContentUseCase, SearchQuery, SearchResults,
+};
+
+pub struct StubSearchContentUseCase {
+    results: SearchResults,
+}
+
+impl StubSearchContentUseCase {
+    pub fn empty() -> Self {
+        Self {
+            results: SearchResults {
+                items: vec![],
+                total: 0,
+                facets: KindCounts::default(),
+            },
+        }
+    }
+
+    /// One project hit, with two media matches left out by the kind filter
+    pub fn one_project() -> Self {
+        Self {
+            results: SearchResults {
+                items: vec![SearchHit {
+                    kind: ContentKind::Project,
+                    id: Uuid::new_v4(),
+                    title: "Rust CMS".to_string(),
+                    subtitle: Some("rust-cms".to_string()),
+                    updated_at: chrono::Utc::now(),
+                }],
+                total: 1,
+                facets: KindCounts {
+                    project: 1,
+                    media: 2,
+                    ..KindCounts::default()
+                },
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl SearchContentUseCase for StubSearchContentUseCase {
+    async fn execute(
+        &self,
+        _owner_id: Uuid,
+        _query: SearchQuery,
+        _offset: u64,
+        _limit: u32,
+    ) -> Result<SearchResults, SearchContentError> {
+        Ok(self.results.clone())
+    }
+}
+
+use crate::export::application::ports::incoming::use_cases::{
+    ContentArchive, ExportContentUseCase,
+};
+use futures::StreamExt;
+
+/// Streams the given chunks as the archive
+pub struct StubExportContentUseCase {
+    chunks: Vec<Vec<u8>>,
+}
+
+impl StubExportContentUseCase {
+    pub fn with_chunks(chunks: Vec<Vec<u8>>) -> Self {
+        Self { chunks }
+    }
+}
+
+impl ExportContentUseCase for StubExportContentUseCase {
+    fn execute(&self) -> ContentArchive {
+        futures::stream::iter(self.chunks.clone().into_iter().map(Ok)).boxed()
+    }
+}
+
+use crate::import::application::domain::entities::{ImportKind, ImportReport, ImportedItem};
+use crate::import::application::ports::incoming::use_cases::{
+    ImportContentError, ImportContentUseCase,
+};
+
+/// Answers with a fixed result and remembers the last call as
+/// `(owner, archive size, dry_run)`
+#[derive(Clone)]
+pub struct StubImportContentUseCase {
+    result: Result<ImportReport, ImportContentError>,
+    received: Arc<std::sync::Mutex<Option<(Uuid, usize, bool)>>>,
+}
+
+impl StubImportContentUseCase {
+    /// One page imported
+    pub fn success() -> Self {
+        Self {
+            result: Ok(ImportReport {
+                dry_run: false,
+                items: vec![ImportedItem {
+                    path: "about.md".to_string(),
+                    kind: ImportKind::Page,
+                    id: None,
+                    title: "About".to_string(),
+                    slug: "about".to_string(),
+                    requested_slug: None,
+                    status: Some("published".to_string()),
+                    topics: vec![],
+                    warnings: vec![],
+                }],
+                topics_created: vec![],
+                skipped: vec![],
+            }),
+            received: Arc::default(),
+        }
+    }
+
+    pub fn failure(err: ImportContentError) -> Self {
+        Self {
+            result: Err(err),
+            received: Arc::default(),
+        }
+    }
+
+    pub fn received(&self) -> Option<(Uuid, usize, bool)> {
+        *self.received.lock().unwrap()
+    }
+}
+
+#[async_trait]
+impl ImportContentUseCase for StubImportContentUseCase {
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        archive: Vec<u8>,
+        dry_run: bool,
+    ) -> Result<ImportReport, ImportContentError> {
+        *self.received.lock().unwrap() = Some((owner_id, archive.len(), dry_run));
+        self.result
+            .clone()
+            .map(|report| ImportReport { dry_run, ..report })
+    }
+}
+
+use crate::batch::application::domain::entities::{
+    BatchItemResult, BatchItemStatus, BatchMode, BatchOperation, BatchReport,
+};
+use crate::batch::application::ports::incoming::use_cases::{RunBatchError, RunBatchUseCase};
+
+/// Owner, mode and operations of one batch run
+pub type BatchCall = (Uuid, BatchMode, Vec<BatchOperation>);
+
+/// Reports every operation as applied, or fails the whole batch, and
+/// remembers the last call
+#[derive(Clone)]
+pub struct StubRunBatchUseCase {
+    error: Option<RunBatchError>,
+    received: Arc<std::sync::Mutex<Option<BatchCall>>>,
+}
+
+impl StubRunBatchUseCase {
+    pub fn success() -> Self {
+        Self {
+            error: None,
+            received: Arc::default(),
+        }
+    }
+
+    pub fn failure(err: RunBatchError) -> Self {
+        Self {
+            error: Some(err),
+            received: Arc::default(),
+        }
+    }
+
+    pub fn received(&self) -> Option<BatchCall> {
+        self.received.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl RunBatchUseCase for StubRunBatchUseCase {
+    async fn execute(
+        &self,
+        owner_id: Uuid,
+        mode: BatchMode,
+        operations: Vec<BatchOperation>,
+    ) -> Result<BatchReport, RunBatchError> {
+        *self.received.lock().unwrap() = Some((owner_id, mode, operations.clone()));
+        if let Some(err) = &self.error {
+            return Err(err.clone());
+        }
+        Ok(BatchReport {
+            mode,
+            succeeded: true,
+            items: operations
+                .iter()
+                .enumerate()
+                .map(|(index, operation)| BatchItemResult {
+                    index,
+                    op: operation.name().to_string(),
+                    status: BatchItemStatus::Ok,
+                    code: None,
+                    message: None,
+                })
+                .collect(),
+        })
+    }
+}
+
+use crate::activity::application::ports::incoming::use_cases::{
+    ListActivitiesError, ListActivitiesUseCase,
+};
+use crate::activity::application::ports::outgoing::{
+    Activity, ActivityAction, ActivityKind, ActivityPage,
+};
+
+pub struct StubListActivitiesUseCase {
+    result: Result<ActivityPage, ListActivitiesError>,
+}
+
+impl StubListActivitiesUseCase {
+    /// One published page
+    pub fn success() -> Self {
+        Self {
+            result: Ok(ActivityPage {
+                items: vec![Activity {
+                    id: Uuid::new_v4(),
+                    kind: ActivityKind::Page,
+                    target_id: Uuid::new_v4(),
+                    action: ActivityAction::Published,
+                    title: Some("About".to_string()),
+                    occurred_at: chrono::Utc::now(),
+                }],
+                total: 1,
+            }),
+        }
+    }
+
+    pub fn empty() -> Self {
+        Self {
+            result: Ok(ActivityPage {
+                items: vec![],
+                total: 0,
+            }),
+        }
+    }
+
+    pub fn failure(msg: &str) -> Self {
+        Self {
+            result: Err(ListActivitiesError::QueryFailed(msg.into())),
+        }
+    }
+}
+
+#[async_trait]
+impl ListActivitiesUseCase for StubListActivitiesUseCase {
+    async fn execute(
+        &self,
+        _owner: UserId,
+        _kind: Option<ActivityKind>,
+        _offset: u64,
+        _limit: u32,
+    ) -> Result<ActivityPage, ListActivitiesError> {
+        self.result.clone()
+    }
+}
+
+use std::collections::HashMap;
+
+use crate::translations::application::domain::entities::{TranslatableKind, Translation};
+use crate::translations::application::domain::negotiation::LocalePreferences;
+use crate::translations::application::ports::incoming::use_cases::{
+    DeleteTranslationError, DeleteTranslationUseCase, ListTranslationsError,
+    ListTranslationsUseCase, LocalizeContentError, LocalizeContentUseCase, PutTranslationCommand,
+    PutTranslationError, PutTranslationUseCase,
+};
+
+pub struct StubPutTranslationUseCase {
+    failure: Option<PutTranslationError>,
+}
+
+impl StubPutTranslationUseCase {
+    pub fn success() -> Self {
+        Self { failure: None }
+    }
+
+    pub fn content_not_found() -> Self {
+        Self {
+            failure: Some(PutTranslationError::ContentNotFound),
+        }
+    }
+}
+
+#[async_trait]
+impl PutTranslationUseCase for StubPutTranslationUseCase {
+    async fn execute(
+        &self,
+        _owner_id: Uuid,
+        _content_id: Uuid,
+        command: PutTranslationCommand,
+    ) -> Result<Translation, PutTranslationError> {
+        if let Some(err) = &self.failure {
+            return Err(err.clone());
+        }
+        Ok(Translation {
+            locale: command.locale().to_string(),
+            fields: command.fields().clone(),
+            updated_at: chrono::Utc::now(),
+        })
+    }
+}
+
+pub struct StubListTranslationsUseCase {
+    result: Result<Vec<Translation>, ListTranslationsError>,
+}
+
+impl StubListTranslationsUseCase {
+    /// One Indonesian title
+    pub fn success() -> Self {
+        Self {
+            result: Ok(vec![Translation {
+                locale: "id".to_string(),
+                fields: [("title".to_string(), "Tentang".to_string())].into(),
+                updated_at: chrono::Utc::now(),
+            }]),
+        }
+    }
+
+    pub fn content_not_found() -> Self {
+        Self {
+            result: Err(ListTranslationsError::ContentNotFound),
+        }
+    }
+}
+
+#[async_trait]
+impl ListTranslationsUseCase for StubListTranslationsUseCase {
+    async fn execute(
+        &self,
+        _owner_id: Uuid,
+        _kind: TranslatableKind,
+        _content_id: Uuid,
+    ) -> Result<Vec<Translation>, ListTranslationsError> {
+        self.result.clone()
+    }
+}
+
+pub struct StubDeleteTranslationUseCase {
+    failure: Option<DeleteTranslationError>,
+}
+
+impl StubDeleteTranslationUseCase {
+    pub fn success() -> Self {
+        Self { failure: None }
+    }
+
+    pub fn not_found() -> Self {
+        Self {
+            failure: Some(DeleteTranslationError::NotFound),
+        }
+    }
+}
+
+#[async_trait]
+impl DeleteTranslationUseCase for StubDeleteTranslationUseCase {
+    async fn execute(
+        &self,
+        _owner_id: Uuid,
+        _kind: TranslatableKind,
+        _content_id: Uuid,
+        _locale: &str,
+    ) -> Result<(), DeleteTranslationError> {
+        match &self.failure {
+            Some(err) => Err(err.clone()),
+            None => Ok(()),
+        }
+    }
+}
+
+pub struct StubLocalizeContentUseCase {
+    translation: Option<Translation>,
+}
+
+impl StubLocalizeContentUseCase {
+    /// Everything is served in the original
+    pub fn none() -> Self {
+        Self { translation: None }
+    }
+
+    /// Every requested item has this translation
+    pub fn with(translation: Translation) -> Self {
+        Self {
+            translation: Some(translation),
+        }
+    }
+}
+
+#[async_trait]
+impl LocalizeContentUseCase for StubLocalizeContentUseCase {
+    async fn execute(
+        &self,
+        _kind: TranslatableKind,
+        content_ids: &[Uuid],
+        _preferences: &LocalePreferences,
+    ) -> Result<HashMap<Uuid, Translation>, LocalizeContentError> {
+        Ok(match &self.translation {
+            Some(translation) => content_ids
+                .iter()
+                .map(|id| (*id, translation.clone()))
+                .collect(),
+            None => HashMap::new(),
+        })
+    }
+}
+
+use crate::auth::application::ports::outgoing::{TokenVersionRepository, UserRepositoryError};
+use crate::auth::application::use_cases::logout_all::{ILogoutAllUseCase, LogoutAllError};
+
+/// Every user exists and is at the same token version
+pub struct StubTokenVersionRepository {
+    version: i32,
+}
+
+impl StubTokenVersionRepository {
+    pub fn at(version: i32) -> Self {
+        Self { version }
+    }
+}
+
+#[async_trait]
+impl TokenVersionRepository for StubTokenVersionRepository {
+    async fn current_version(&self, _user_id: Uuid) -> Result<Option<i32>, UserRepositoryError> {
+        Ok(Some(self.version))
+    }
+
+    async fn bump_version(&self, _user_id: Uuid) -> Result<i32, UserRepositoryError> {
+        Ok(self.version + 1)
+    }
+}
+
+pub struct StubLogoutAllUseCase {
+    result: Result<(), LogoutAllError>,
+}
+
+impl StubLogoutAllUseCase {
+    pub fn success() -> Self {
+        Self { result: Ok(()) }
+    }
+
+    pub fn failure() -> Self {
+        Self {
+            result: Err(LogoutAllError::DatabaseError("db down".to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl ILogoutAllUseCase for StubLogoutAllUseCase {
+    async fn execute(&self, _user_id: Uuid) -> Result<(), LogoutAllError> {
+        self.result.clone()
+    }
+}
+
+use crate::auth::application::use_cases::deactivate_account::{
+    DeactivateAccountError, IDeactivateAccountUseCase,
+};
+use crate::auth::application::use_cases::lockdown_user_content::{
+    ILockdownUserContentUseCase, LockdownUserContentError,
+};
+use crate::auth::application::use_cases::reactivate_account::{
+    IReactivateAccountUseCase, ReactivateAccountError,
+};
+
+pub struct StubDeactivateAccountUseCase {
+    result: Result<(), DeactivateAccountError>,
+}
+
+impl StubDeactivateAccountUseCase {
+    pub fn success() -> Self {
+        Self { result: Ok(()) }
+    }
+
+    pub fn failure() -> Self {
+        Self {
+            result: Err(DeactivateAccountError::DatabaseError("db down".to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl IDeactivateAccountUseCase for StubDeactivateAccountUseCase {
+    async fn execute(&self, _user_id: Uuid) -> Result<(), DeactivateAccountError> {
+        self.result.clone()
+    }
+}
+
+pub struct StubLockdownUserContentUseCase {
+    result: Result<(), LockdownUserContentError>,
+}
+
+impl StubLockdownUserContentUseCase {
+    pub fn success() -> Self {
+        Self { result: Ok(()) }
+    }
+
+    pub fn failure() -> Self {
+        Self {
+            result: Err(LockdownUserContentError::DatabaseError(
+                "db down".to_string(),
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl ILockdownUserContentUseCase for StubLockdownUserContentUseCase {
+    async fn execute(&self, _user_id: Uuid) -> Result<(), LockdownUserContentError> {
+        self.result.clone()
+    }
+}
+
+pub struct StubReactivateAccountUseCase {
+    result: Result<(), ReactivateAccountError>,
+}
+
+impl StubReactivateAccountUseCase {
+    pub fn success() -> Self {
+        Self { result: Ok(()) }
+    }
+
+    pub fn failing(error: ReactivateAccountError) -> Self {
+        Self { result: Err(error) }
+    }
+}
+
+#[async_trait]
+impl IReactivateAccountUseCase for StubReactivateAccountUseCase {
+    async fn execute(&self, _request: LoginRequest) -> Result<(), ReactivateAccountError> {
+        self.result.clone()
+    }
+}
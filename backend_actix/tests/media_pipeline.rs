@@ -0,0 +1,334 @@
+//! End to end media pipeline: a standalone backend keeping media in the
+//! folders of an image processor running with `PROCESSOR_MODE=local`, which
+//! calls the backend back when it is done. A fixture is uploaded through the
+//! API the way a client would, then its variants and its attachment listing
+//! are checked.
+//!
+//! Needs a built processor, so it only runs with the `media-e2e` feature:
+//!
+//! ```text
+//! cargo build --manifest-path ../image-processor-function/Cargo.toml
+//! cargo test --features media-e2e --test media_pipeline
+//! ```
+//!
+//! `IMAGE_PROCESSOR_BIN` points at another processor binary. The servers'
+//! logs are kept in the printed folder when the test fails.
+
+use std::fs::File;
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+use backend_actix::auth::adapter::outgoing::jwt::{JwtConfig, JwtTokenService};
+use backend_actix::auth::application::ports::outgoing::token_provider::TokenProvider;
+use reqwest::{Client, Method, StatusCode};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+const FIXTURE: &[u8] = include_bytes!("fixtures/checkerboard.png");
+const JWT_SECRET: &str = "media-pipeline-test-secret-0123456789abcdef";
+const JWT_ISSUER: &str = "media-pipeline-test";
+const JWT_AUDIENCE: &str = "media-pipeline-test";
+const CALLBACK_SECRET: &str = "media-pipeline-callback-secret";
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(60);
+const PROCESSING_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Storage folder and server logs, removed unless the test failed
+struct Workspace(PathBuf);
+
+impl Workspace {
+    fn new() -> Self {
+        let path = std::env::temp_dir().join(format!("media-pipeline-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+
+    fn log(&self, name: &str) -> Stdio {
+        File::create(self.0.join(name)).unwrap().into()
+    }
+}
+
+impl Drop for Workspace {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            eprintln!("Storage and server logs kept in {}", self.0.display());
+        } else {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+}
+
+/// A server process, stopped with the test
+struct Server(Child);
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+fn free_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+fn processor_bin() -> PathBuf {
+    std::env::var_os("IMAGE_PROCESSOR_BIN")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("../image-processor-function/target/debug/image-processor-function")
+        })
+}
+
+fn start_processor(workspace: &Workspace, port: u16, backend_url: &str) -> Server {
+    let bin = processor_bin();
+    assert!(
+        bin.is_file(),
+        "no image processor at {}; build it or set IMAGE_PROCESSOR_BIN",
+        bin.display()
+    );
+
+    let child = Command::new(bin)
+        .current_dir(&workspace.0)
+        .env("PROCESSOR_MODE", "local")
+        .env("PORT", port.to_string())
+        .env("LOCAL_STORAGE_DIR", workspace.0.join("storage"))
+        .env(
+            "CALLBACK_URL",
+            format!("{backend_url}/api/internal/media/manifest"),
+        )
+        .env("CALLBACK_SECRET", CALLBACK_SECRET)
+        .stdout(workspace.log("processor.log"))
+        .stderr(workspace.log("processor.err.log"))
+        .spawn()
+        .expect("failed to start the image processor");
+    Server(child)
+}
+
+fn start_backend(workspace: &Workspace, port: u16, processor_url: &str) -> Server {
+    // Run from the workspace, so no `.env` or `config.toml` is picked up
+    let child = Command::new(env!("CARGO_BIN_EXE_backend_actix"))
+        .current_dir(&workspace.0)
+        .env("RUN_MODE", "standalone")
+        .env("RUST_ENV", "development")
+        .env("HOST", "127.0.0.1")
+        .env("PORT", port.to_string())
+        .env("JWT_SECRET", JWT_SECRET)
+        .env("JWT_ISSUER", JWT_ISSUER)
+        .env("JWT_AUDIENCE", JWT_AUDIENCE)
+        .env("IMAGE_PROCESSOR_URL", processor_url)
+        .env("LOCAL_STORAGE_DIR", workspace.0.join("storage"))
+        .env("MEDIA_CALLBACK_SECRET", CALLBACK_SECRET)
+        .stdout(workspace.log("backend.log"))
+        .stderr(workspace.log("backend.err.log"))
+        .spawn()
+        .expect("failed to start the backend");
+    Server(child)
+}
+
+async fn wait_until_up(http: &Client, url: &str) {
+    let started = Instant::now();
+    while http.get(url).send().await.is_err() {
+        assert!(started.elapsed() < STARTUP_TIMEOUT, "{url} never came up");
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    }
+}
+
+/// Sends a JSON request and returns the status with the `data` of the body
+async fn call(
+    http: &Client,
+    method: Method,
+    url: &str,
+    token: Option<&str>,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let mut req = http.request(method, url);
+    if let Some(token) = token {
+        req = req.bearer_auth(token);
+    }
+    if let Some(body) = body {
+        req = req
+            .header("content-type", "application/json")
+            .body(body.to_string());
+    }
+
+    let resp = req.send().await.unwrap();
+    let status = resp.status();
+    let body: Value = serde_json::from_slice(&resp.bytes().await.unwrap()).unwrap();
+    (status, body["data"].clone())
+}
+
+/// Registers a user, verifies its email with a token signed like the
+/// backend's, and signs in
+async fn verified_user_token(http: &Client, backend: &str) -> String {
+    let (status, registered) = call(
+        http,
+        Method::POST,
+        &format!("{backend}/api/auth/register"),
+        None,
+        Some(json!({
+            "username": "pipeline",
+            "email": "pipeline@example.com",
+            "password": "pipeline-password",
+            "full_name": "Pipeline Test",
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{registered}");
+    let user_id: Uuid = registered["user"]["id"].as_str().unwrap().parse().unwrap();
+
+    let jwt = JwtTokenService::new(JwtConfig {
+        secret_key: JWT_SECRET.to_string(),
+        issuer: JWT_ISSUER.to_string(),
+        audience: JWT_AUDIENCE.to_string(),
+        access_token_expiry: 900,
+        refresh_token_expiry: 3600,
+        remember_me_refresh_token_expiry: 3600,
+        verification_token_expiry: 3600,
+    });
+    let verification = jwt.generate_verification_token(user_id).unwrap();
+    let (status, _) = call(
+        http,
+        Method::GET,
+        &format!("{backend}/api/auth/email-verification/{verification}"),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, session) = call(
+        http,
+        Method::POST,
+        &format!("{backend}/api/auth/login"),
+        None,
+        Some(json!({
+            "email": "pipeline@example.com",
+            "password": "pipeline-password",
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    session["access_token"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_uploaded_image_gets_its_variants() {
+    let workspace = Workspace::new();
+    let http = Client::new();
+    let (backend_port, processor_port) = (free_port(), free_port());
+    let backend = format!("http://127.0.0.1:{backend_port}");
+    let processor = format!("http://127.0.0.1:{processor_port}");
+
+    let _processor = start_processor(&workspace, processor_port, &backend);
+    let _backend = start_backend(&workspace, backend_port, &processor);
+    wait_until_up(&http, &format!("{processor}/health")).await;
+    wait_until_up(&http, &format!("{backend}/health/live")).await;
+
+    let token = verified_user_token(&http, &backend).await;
+    let project_id = Uuid::new_v4();
+
+    // Register the upload and PUT the file where the backend says
+    let (status, upload) = call(
+        &http,
+        Method::POST,
+        &format!("{backend}/api/media/upload-url"),
+        Some(&token),
+        Some(json!({
+            "fileName": "checkerboard.png",
+            "mimeType": "image/png",
+            "fileSizeBytes": FIXTURE.len(),
+            "widthPx": 640,
+            "heightPx": 480,
+            "attachmentTarget": "Project",
+            "attachmentTargetId": project_id,
+            "role": "Gallery",
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{upload}");
+    let media_id = upload["mediaId"].as_str().unwrap().to_string();
+
+    let mut put = http
+        .put(upload["uploadUrl"].as_str().unwrap())
+        .body(FIXTURE.to_vec());
+    for (name, value) in upload["uploadHeaders"].as_object().unwrap() {
+        put = put.header(name.as_str(), value.as_str().unwrap());
+    }
+    let put = put.send().await.unwrap();
+    assert!(
+        put.status().is_success(),
+        "upload refused: {}",
+        put.status()
+    );
+
+    let (status, finalized) = call(
+        &http,
+        Method::POST,
+        &format!("{backend}/api/media/{media_id}/finalize"),
+        Some(&token),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{finalized}");
+
+    // The processor's callback settles the media
+    let started = Instant::now();
+    let listed = loop {
+        let (status, page) = call(
+            &http,
+            Method::GET,
+            &format!("{backend}/api/media/project"),
+            Some(&token),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let item = page["items"][0].clone();
+        match item["status"].as_str() {
+            Some("ready") => break item,
+            Some("failed") => panic!("processing failed: {item}"),
+            _ => {}
+        }
+        assert!(
+            started.elapsed() < PROCESSING_TIMEOUT,
+            "media never became ready: {item}"
+        );
+        tokio::time::sleep(Duration::from_millis(250)).await;
+    };
+    assert_eq!(listed["media_id"], media_id.as_str());
+    assert_eq!(listed["original_filename"], "checkerboard.png");
+    assert_eq!(listed["attachment_target_id"], project_id.to_string());
+
+    // Every size has a readable variant
+    let sizes = ["thumbnail", "small", "medium", "large"];
+    let (status, urls) = call(
+        &http,
+        Method::POST,
+        &format!("{backend}/api/media/variant-urls"),
+        Some(&token),
+        Some(json!({
+            "variants": sizes
+                .iter()
+                .map(|size| json!({ "media_id": media_id, "size": size }))
+                .collect::<Vec<_>>(),
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    for (item, size) in urls["items"].as_array().unwrap().iter().zip(sizes) {
+        let url = item["url"]
+            .as_str()
+            .unwrap_or_else(|| panic!("no {size} variant: {item}"));
+        let variant = http.get(url).send().await.unwrap();
+        assert_eq!(variant.status(), StatusCode::OK, "{size} at {url}");
+        assert_eq!(variant.headers()["content-type"], "image/webp");
+        assert!(!variant.bytes().await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,16 @@
+import type { Handle } from '@sveltejs/kit';
+import { resolveRedirect } from '$lib/api/redirects';
+
+// Old URLs and short links: only paths the site can't serve are looked up
+export const handle: Handle = async ({ event, resolve }) => {
+	const response = await resolve(event);
+	if (response.status !== 404 || event.request.method !== 'GET') return response;
+
+	const redirect = await resolveRedirect(event.fetch, event.url.pathname);
+	if (!redirect) return response;
+
+	return new Response(null, {
+		status: redirect.status_code,
+		headers: { location: redirect.target_url, 'cache-control': 'no-store' }
+	});
+};
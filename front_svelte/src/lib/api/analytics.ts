@@ -0,0 +1,11 @@
+import { env } from '$env/dynamic/public';
+
+// Fire and forget: a lost page view must never break navigation
+export function trackPageView(path: string, referrer: string | null): void {
+	fetch(`${env.PUBLIC_API_URL ?? ''}/api/public/analytics/pageview`, {
+		method: 'POST',
+		headers: { 'Content-Type': 'application/json' },
+		body: JSON.stringify({ path, referrer: referrer || null }),
+		keepalive: true
+	}).catch(() => {});
+}
@@ -0,0 +1,23 @@
+import { env } from '$env/dynamic/public';
+
+export interface ResolvedRedirect {
+	target_url: string;
+	status_code: 301 | 302;
+}
+
+// Counts a hit on the redirect; null when the path has none or the API is down
+export async function resolveRedirect(
+	fetch: typeof globalThis.fetch,
+	path: string
+): Promise<ResolvedRedirect | null> {
+	try {
+		const res = await fetch(
+			`${env.PUBLIC_API_URL ?? ''}/api/public/redirects/resolve?path=${encodeURIComponent(path)}`
+		);
+		if (!res.ok) return null;
+		const body = await res.json();
+		return body.data as ResolvedRedirect;
+	} catch {
+		return null;
+	}
+}
@@ -0,0 +1,22 @@
+import { env } from '$env/dynamic/public';
+import type ISiteProfile from '$lib/types/isiteprofile';
+
+export const defaultSiteProfile: ISiteProfile = {
+	title: 'Ekstion',
+	tagline: null,
+	social_links: [],
+	analytics_id: null,
+	theme: 'system'
+};
+
+// The shell still renders with defaults when the API is unreachable
+export async function fetchSiteProfile(fetch: typeof globalThis.fetch): Promise<ISiteProfile> {
+	try {
+		const res = await fetch(`${env.PUBLIC_API_URL ?? ''}/api/public/site`);
+		if (!res.ok) return defaultSiteProfile;
+		const body = await res.json();
+		return body.data as ISiteProfile;
+	} catch {
+		return defaultSiteProfile;
+	}
+}
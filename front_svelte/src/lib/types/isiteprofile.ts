@@ -0,0 +1,7 @@
+export default interface ISiteProfile {
+	title: string;
+	tagline: string | null;
+	social_links: { label: string; url: string }[];
+	analytics_id: string | null;
+	theme: 'light' | 'dark' | 'system';
+}
@@ -0,0 +1,6 @@
+import { fetchSiteProfile } from '$lib/api/site';
+import type { LayoutLoad } from './$types';
+
+export const load: LayoutLoad = async ({ fetch }) => {
+	return { site: await fetchSiteProfile(fetch) };
+};
@@ -73,13 +73,19 @@ echo "📦 Artifact Registry repo already exists, skipping..."
 # =============================================================================
 echo "🔨 Building container image..."
 
+# The build context is the repository root: the processor depends on
+# backend_actix/shared_domain.
+
 # Option 1: Build locally and push (faster iteration)
-# docker build -t "${IMAGE_URI}" .
+# docker build -f image-processor-function/Dockerfile -t "${IMAGE_URI}" ..
 # docker push "${IMAGE_URI}"
 
 # Option 2: Build with Cloud Build (no local Docker needed)
 # Uncomment below and comment out the docker commands above to use Cloud Build
-gcloud builds submit --tag "${IMAGE_URI}" .
+gcloud builds submit \
+    --config cloudbuild.yaml \
+    --substitutions=_IMAGE_URI="${IMAGE_URI}" \
+    ..
 
 # =============================================================================
 # Deploy to Cloud Run
@@ -0,0 +1,116 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::time::Duration;
+use tracing::{info, warn};
+
+// =============================================================================
+// Completion callback (optional)
+// =============================================================================
+//
+// When CALLBACK_URL is set, every final manifest (ready or failed) is POSTed to
+// the backend right after it has been written to the manifest bucket, so the
+// backend does not have to poll for it.
+//
+// The body is the manifest JSON, unchanged. It is signed with HMAC-SHA256 over
+// "{timestamp}.{body}" using CALLBACK_SECRET:
+//
+//   X-Callback-Timestamp: 1700000000
+//   X-Callback-Signature: sha256=<hex digest>
+//
+// The callback is best effort: the manifest in GCS stays the source of truth,
+// so a failed delivery is logged and never fails the pipeline.
+
+type HmacSha256 = Hmac<Sha256>;
+
+pub const SIGNATURE_HEADER: &str = "X-Callback-Signature";
+pub const TIMESTAMP_HEADER: &str = "X-Callback-Timestamp";
+
+const DEFAULT_TIMEOUT_SECS: u64 = 5;
+
+pub struct CallbackNotifier {
+    http: reqwest::Client,
+    url: String,
+    secret: String,
+}
+
+impl CallbackNotifier {
+    /// Returns `None` when callbacks are not configured.
+    ///
+    /// A URL without a secret is treated as a misconfiguration: we never send
+    /// unsigned payloads to the backend.
+    pub fn from_env() -> Option<Self> {
+        let url = std::env::var("CALLBACK_URL")
+            .ok()
+            .filter(|s| !s.trim().is_empty())?;
+
+        let secret = match std::env::var("CALLBACK_SECRET") {
+            Ok(s) if !s.is_empty() => s,
+            _ => {
+                warn!("CALLBACK_URL is set but CALLBACK_SECRET is missing; callbacks disabled");
+                return None;
+            }
+        };
+
+        let timeout_secs = std::env::var("CALLBACK_TIMEOUT_SECS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_TIMEOUT_SECS);
+
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(timeout_secs))
+            .build()
+            .ok()?;
+
+        Some(Self { http, url, secret })
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// POST the manifest JSON to the backend (best effort).
+    pub async fn notify(&self, media_id: &str, manifest_json: &[u8]) {
+        let timestamp = chrono::Utc::now().timestamp().to_string();
+        let signature = sign(&self.secret, &timestamp, manifest_json);
+
+        let result = self
+            .http
+            .post(&self.url)
+            .header("Content-Type", "application/json")
+            .header(TIMESTAMP_HEADER, &timestamp)
+            .header(SIGNATURE_HEADER, format!("sha256={}", signature))
+            .body(manifest_json.to_vec())
+            .send()
+            .await;
+
+        match result {
+            Ok(resp) if resp.status().is_success() => {
+                info!(
+                    media_id,
+                    status = resp.status().as_u16(),
+                    "Callback delivered"
+                );
+            }
+            Ok(resp) => {
+                warn!(
+                    media_id,
+                    status = resp.status().as_u16(),
+                    "Callback rejected by backend"
+                );
+            }
+            Err(e) => {
+                warn!(media_id, error = %e, "Callback delivery failed");
+            }
+        }
+    }
+}
+
+/// Hex-encoded HMAC-SHA256 of "{timestamp}.{body}".
+pub fn sign(secret: &str, timestamp: &str, body: &[u8]) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(timestamp.as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
@@ -0,0 +1,156 @@
+use futures::StreamExt;
+use google_cloud_storage::{
+    client::Client as GcsClient,
+    http::objects::{download::Range, get::GetObjectRequest},
+};
+use sha2::{Digest, Sha256};
+use std::io::{BufRead, BufReader, Cursor, Seek, SeekFrom};
+use tokio::io::AsyncWriteExt;
+
+// =============================================================================
+// Streamed download
+// =============================================================================
+//
+// The original is streamed from GCS and the byte limit is enforced per chunk,
+// so an oversized object is rejected as soon as it crosses the limit instead
+// of after it has been fully buffered. Small originals stay in memory; once a
+// download grows past the spool threshold it continues into an anonymous temp
+// file (removed automatically when dropped). The SHA-256 of the original is
+// computed on the same pass.
+
+const DEFAULT_SPOOL_THRESHOLD_BYTES: usize = 1024 * 1024; // 1MB
+
+/// Bytes kept from the start of the object for magic-byte detection.
+const HEAD_LEN: usize = 16;
+
+pub fn spool_threshold_bytes() -> usize {
+    std::env::var("SPOOL_THRESHOLD_BYTES")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_SPOOL_THRESHOLD_BYTES)
+}
+
+pub enum Original {
+    Memory(Vec<u8>),
+    Spooled {
+        file: std::fs::File,
+        len: usize,
+        head: Vec<u8>,
+    },
+}
+
+impl Original {
+    pub fn len(&self) -> usize {
+        match self {
+            Original::Memory(bytes) => bytes.len(),
+            Original::Spooled { len, .. } => *len,
+        }
+    }
+
+    /// First bytes of the object (for format sniffing).
+    pub fn head(&self) -> &[u8] {
+        match self {
+            Original::Memory(bytes) => &bytes[..bytes.len().min(HEAD_LEN)],
+            Original::Spooled { head, .. } => head,
+        }
+    }
+
+    /// Reader positioned at the start of the object (blocking I/O for spooled files).
+    pub fn into_reader(self) -> std::io::Result<Box<dyn BufReadSeek + Send>> {
+        match self {
+            Original::Memory(bytes) => Ok(Box::new(Cursor::new(bytes))),
+            Original::Spooled { mut file, .. } => {
+                file.seek(SeekFrom::Start(0))?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+        }
+    }
+}
+
+pub trait BufReadSeek: BufRead + Seek {}
+impl<T: BufRead + Seek> BufReadSeek for T {}
+
+#[derive(Debug)]
+pub enum DownloadError {
+    /// The stream crossed `max_bytes`; nothing past the limit was read.
+    TooLarge {
+        max_bytes: usize,
+    },
+    Failed(String),
+}
+
+/// Returns the original and its lowercase hex SHA-256.
+pub async fn download_bounded(
+    client: &GcsClient,
+    bucket: &str,
+    name: &str,
+    max_bytes: usize,
+    spool_threshold: usize,
+) -> Result<(Original, String), DownloadError> {
+    let request = GetObjectRequest {
+        bucket: bucket.to_string(),
+        object: name.to_string(),
+        ..Default::default()
+    };
+
+    let mut stream = client
+        .download_streamed_object(&request, &Range::default())
+        .await
+        .map_err(|e| DownloadError::Failed(format!("Failed to download from GCS: {}", e)))?;
+
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut spool: Option<tokio::fs::File> = None;
+    let mut head: Vec<u8> = Vec::with_capacity(HEAD_LEN);
+    let mut total: usize = 0;
+    let mut hasher = Sha256::new();
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk
+            .map_err(|e| DownloadError::Failed(format!("Failed to download from GCS: {}", e)))?;
+
+        total += chunk.len();
+        if total > max_bytes {
+            return Err(DownloadError::TooLarge { max_bytes });
+        }
+
+        hasher.update(&chunk);
+
+        if head.len() < HEAD_LEN {
+            let take = (HEAD_LEN - head.len()).min(chunk.len());
+            head.extend_from_slice(&chunk[..take]);
+        }
+
+        if spool.is_none() && total > spool_threshold {
+            let file = tempfile::tempfile().map_err(spool_error)?;
+            let mut file = tokio::fs::File::from_std(file);
+            file.write_all(&buffer).await.map_err(spool_error)?;
+            buffer = Vec::new();
+            spool = Some(file);
+        }
+
+        match spool.as_mut() {
+            Some(file) => file.write_all(&chunk).await.map_err(spool_error)?,
+            None => buffer.extend_from_slice(&chunk),
+        }
+    }
+
+    let sha256 = hex::encode(hasher.finalize());
+
+    let original = match spool {
+        None => Original::Memory(buffer),
+        Some(mut file) => {
+            file.flush().await.map_err(spool_error)?;
+            Original::Spooled {
+                file: file.into_std().await,
+                len: total,
+                head,
+            }
+        }
+    };
+
+    Ok((original, sha256))
+}
+
+fn spool_error(e: std::io::Error) -> DownloadError {
+    DownloadError::Failed(format!("Failed to spool download to disk: {}", e))
+}
@@ -0,0 +1,174 @@
+use actix_web::HttpRequest;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+// =============================================================================
+// Event normalization
+// =============================================================================
+//
+// The push endpoint accepts the same storage event in three envelopes:
+//
+// 1. Binary-mode CloudEvent: ce-* headers, body is the GCS object JSON.
+// 2. Structured-mode CloudEvent: `Content-Type: application/cloudevents+json`,
+//    body is `{ "specversion", "id", "type", "source", "data", ... }`.
+// 3. Pub/Sub-wrapped: a push subscription body `{ "message": { "data": <b64>,
+//    "attributes": {...} }, "subscription" }`, either posted directly or as the
+//    data of a `messagePublished` CloudEvent.
+//
+// All of them end up as a `NormalizedEvent` carrying the storage event type and
+// the raw object JSON, so the pipeline never needs to know how it was delivered.
+
+pub const GCS_FINALIZED: &str = "google.cloud.storage.object.v1.finalized";
+const PUBSUB_PUBLISHED: &str = "google.cloud.pubsub.topic.v1.messagePublished";
+const STRUCTURED_CONTENT_TYPE: &str = "application/cloudevents+json";
+
+#[derive(Debug)]
+pub struct CloudEventHeaders {
+    pub id: String,
+    pub source: String,
+    pub event_type: String,
+    pub subject: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct NormalizedEvent {
+    pub meta: CloudEventHeaders,
+    /// GCS object JSON (`bucket`, `name`, `contentType`, `size`, ...)
+    pub data: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+struct StructuredCloudEvent {
+    id: String,
+    source: String,
+    #[serde(rename = "type")]
+    event_type: String,
+    subject: Option<String>,
+    data: Option<serde_json::Value>,
+    data_base64: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct PubSubPushEnvelope {
+    message: PubSubPushMessage,
+    #[serde(default)]
+    subscription: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PubSubPushMessage {
+    #[serde(default)]
+    data: String,
+    #[serde(default)]
+    attributes: HashMap<String, String>,
+    #[serde(default)]
+    message_id: String,
+}
+
+pub fn normalize(req: &HttpRequest, body: &[u8]) -> Result<NormalizedEvent, String> {
+    let event = if is_structured(req) {
+        from_structured(body)?
+    } else if let Some(meta) = binary_headers(req) {
+        NormalizedEvent {
+            meta,
+            data: body.to_vec(),
+        }
+    } else {
+        // No CloudEvent envelope at all: a plain Pub/Sub push subscription.
+        return from_pubsub_envelope(body);
+    };
+
+    if event.meta.event_type == PUBSUB_PUBLISHED {
+        return from_pubsub_envelope(&event.data);
+    }
+
+    Ok(event)
+}
+
+fn is_structured(req: &HttpRequest) -> bool {
+    req.headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .map(|ct| ct.starts_with(STRUCTURED_CONTENT_TYPE))
+        .unwrap_or(false)
+}
+
+fn binary_headers(req: &HttpRequest) -> Option<CloudEventHeaders> {
+    let get_header = |name: &str| -> Option<String> {
+        req.headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(String::from)
+    };
+
+    Some(CloudEventHeaders {
+        id: get_header("ce-id")?,
+        source: get_header("ce-source")?,
+        event_type: get_header("ce-type")?,
+        subject: get_header("ce-subject"),
+    })
+}
+
+fn from_structured(body: &[u8]) -> Result<NormalizedEvent, String> {
+    let ce: StructuredCloudEvent = serde_json::from_slice(body)
+        .map_err(|e| format!("Invalid structured CloudEvent: {}", e))?;
+
+    let data = match (ce.data, ce.data_base64) {
+        (Some(value), _) => serde_json::to_vec(&value)
+            .map_err(|e| format!("Invalid structured CloudEvent data: {}", e))?,
+        (None, Some(b64)) => BASE64
+            .decode(b64.as_bytes())
+            .map_err(|e| format!("Invalid data_base64: {}", e))?,
+        (None, None) => return Err("Structured CloudEvent has no data".to_string()),
+    };
+
+    Ok(NormalizedEvent {
+        meta: CloudEventHeaders {
+            id: ce.id,
+            source: ce.source,
+            event_type: ce.event_type,
+            subject: ce.subject,
+        },
+        data,
+    })
+}
+
+fn from_pubsub_envelope(body: &[u8]) -> Result<NormalizedEvent, String> {
+    let envelope: PubSubPushEnvelope = serde_json::from_slice(body)
+        .map_err(|_| "Missing required CloudEvent headers".to_string())?;
+
+    let data = BASE64
+        .decode(envelope.message.data.as_bytes())
+        .map_err(|e| format!("Invalid Pub/Sub message data: {}", e))?;
+
+    let attributes = &envelope.message.attributes;
+    let event_type = attributes
+        .get("eventType")
+        .map(|t| storage_event_type(t))
+        .unwrap_or_default();
+
+    Ok(NormalizedEvent {
+        meta: CloudEventHeaders {
+            id: envelope.message.message_id,
+            source: format!("//pubsub.googleapis.com/{}", envelope.subscription),
+            event_type,
+            subject: attributes
+                .get("objectId")
+                .map(|name| format!("objects/{}", name)),
+        },
+        data,
+    })
+}
+
+/// Map GCS notification event types onto their CloudEvent equivalents.
+pub fn storage_event_type(notification_type: &str) -> String {
+    match notification_type {
+        "OBJECT_FINALIZE" => GCS_FINALIZED.to_string(),
+        "OBJECT_DELETE" => "google.cloud.storage.object.v1.deleted".to_string(),
+        "OBJECT_ARCHIVE" => "google.cloud.storage.object.v1.archived".to_string(),
+        "OBJECT_METADATA_UPDATE" => "google.cloud.storage.object.v1.metadataUpdated".to_string(),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+// =============================================================================
+// Bounded concurrency
+// =============================================================================
+//
+// Each job holds the decoded original plus every resized buffer in memory, so a
+// burst of uploads can OOM the instance. Jobs take a permit first; when none is
+// left the push endpoint answers 429 + Retry-After and Eventarc redelivers later.
+
+const DEFAULT_MAX_CONCURRENT_JOBS: usize = 2;
+const DEFAULT_RETRY_AFTER_SECS: u64 = 10;
+
+#[derive(Clone)]
+pub struct JobLimiter {
+    semaphore: Arc<Semaphore>,
+    max_jobs: usize,
+    retry_after_secs: u64,
+}
+
+impl JobLimiter {
+    pub fn new(max_jobs: usize, retry_after_secs: u64) -> Self {
+        let max_jobs = max_jobs.max(1);
+        Self {
+            semaphore: Arc::new(Semaphore::new(max_jobs)),
+            max_jobs,
+            retry_after_secs,
+        }
+    }
+
+    /// `MAX_CONCURRENT_JOBS` (default 2) and `RETRY_AFTER_SECS` (default 10).
+    pub fn from_env() -> Self {
+        let max_jobs = std::env::var("MAX_CONCURRENT_JOBS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_MAX_CONCURRENT_JOBS);
+
+        let retry_after_secs = std::env::var("RETRY_AFTER_SECS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_RETRY_AFTER_SECS);
+
+        Self::new(max_jobs, retry_after_secs)
+    }
+
+    /// Non-blocking: `None` means the instance is at capacity.
+    pub fn try_acquire(&self) -> Option<OwnedSemaphorePermit> {
+        self.semaphore.clone().try_acquire_owned().ok()
+    }
+
+    /// Wait for a free slot (pull mode, where there is no client to push back on).
+    pub async fn acquire(&self) -> OwnedSemaphorePermit {
+        self.semaphore
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("job semaphore is never closed")
+    }
+
+    pub fn in_flight(&self) -> usize {
+        self.max_jobs - self.semaphore.available_permits()
+    }
+
+    pub fn max_jobs(&self) -> usize {
+        self.max_jobs
+    }
+
+    pub fn retry_after_secs(&self) -> u64 {
+        self.retry_after_secs
+    }
+}
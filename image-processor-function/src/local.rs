@@ -0,0 +1,279 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use shared_domain::buckets::{
+    manifest_object_key, media_id_from_object_key, DEFAULT_UPLOAD_BUCKET,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+use tracing::{error, info, warn};
+
+use crate::limiter::JobLimiter;
+use crate::reprocess::reply;
+use crate::storage::{LocalObject, LocalStore};
+use crate::{process_gcs_object, GcsObjectData, Outcome, PipelineContext};
+
+// =============================================================================
+// Local-filesystem mode (development)
+// =============================================================================
+//
+// PROCESSOR_MODE=local runs the same pipeline against folders instead of GCS,
+// so upload -> process -> attach can be tried on a laptop without cloud
+// credentials. Every bucket is a folder under LOCAL_STORAGE_DIR (default
+// ./local-storage), with the usual bucket names:
+//
+//   blogport-cms-upload/{media_id}/{file}               originals (UPLOAD_BUCKET)
+//   blogport-cms-ready/variants/{media_id}/...          variants (OUTPUT_BUCKET)
+//   blogport-cms-manifests/{media_id}/manifest.json     manifests (MANIFEST_BUCKET)
+//
+// Originals come in two ways:
+//
+//   - dropped into the upload folder, which is polled every
+//     LOCAL_POLL_INTERVAL_MS (default 1000). A file is processed once its size
+//     and modification time hold still between two polls. Files found at
+//     startup that already have a manifest are left alone.
+//   - PUT (or POST) /local/{upload bucket}/{name} with the raw bytes, the way
+//     a client PUTs to a signed URL. `x-goog-meta-*` headers become object
+//     metadata (e.g. `x-goog-meta-role: avatar`). The pipeline runs before
+//     the response, which is its result.
+//
+// GET /local/{bucket}/{name} serves any stored file, so variants can be shown
+// straight from the processor. CALLBACK_URL works as usual; /reprocess and
+// /migrate need GCS and are not served.
+
+const DEFAULT_POLL_INTERVAL_MS: u64 = 1000;
+
+const METADATA_HEADER_PREFIX: &str = "x-goog-meta-";
+
+/// Size and modification time, to tell a changed file from a handled one
+type FileStamp = (u64, SystemTime);
+
+pub struct LocalWatcher {
+    store: LocalStore,
+    upload_bucket: String,
+    interval: Duration,
+    /// Originals already handed to the pipeline, as they were then
+    handled: Mutex<HashMap<String, FileStamp>>,
+}
+
+impl LocalWatcher {
+    pub fn from_env(store: LocalStore) -> Self {
+        let upload_bucket = std::env::var("UPLOAD_BUCKET")
+            .ok()
+            .filter(|s| !s.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_UPLOAD_BUCKET.to_string());
+
+        let interval_ms = std::env::var("LOCAL_POLL_INTERVAL_MS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .filter(|ms: &u64| *ms > 0)
+            .unwrap_or(DEFAULT_POLL_INTERVAL_MS);
+
+        Self {
+            store,
+            upload_bucket,
+            interval: Duration::from_millis(interval_ms),
+            handled: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn store(&self) -> &LocalStore {
+        &self.store
+    }
+
+    pub fn upload_bucket(&self) -> &str {
+        &self.upload_bucket
+    }
+
+    /// `true` the first time an original is seen in this state
+    fn claim(&self, name: &str, stamp: FileStamp) -> bool {
+        let mut handled = self.handled.lock().unwrap_or_else(|e| e.into_inner());
+        handled.insert(name.to_string(), stamp) != Some(stamp)
+    }
+
+    async fn has_manifest(&self, ctx: &PipelineContext, name: &str) -> bool {
+        match ctx.tenants.resolve(name, None) {
+            Ok(dest) => {
+                let media_id = media_id_from_object_key(dest.key);
+                self.store
+                    .exists(dest.manifest_bucket, &manifest_object_key(media_id))
+                    .await
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Polls the upload folder until the process stops.
+    pub async fn watch(self: Arc<Self>, ctx: Arc<PipelineContext>, limiter: JobLimiter) {
+        let mut last_poll: HashMap<String, FileStamp> = HashMap::new();
+        let mut first_poll = true;
+
+        loop {
+            let store = self.store.clone();
+            let bucket = self.upload_bucket.clone();
+            let objects = match tokio::task::spawn_blocking(move || store.list(&bucket)).await {
+                Ok(Ok(objects)) => objects,
+                Ok(Err(e)) => {
+                    warn!(error = %e, "Failed to list the upload folder");
+                    Vec::new()
+                }
+                Err(e) => {
+                    error!(error = %e, "Upload folder listing panicked");
+                    Vec::new()
+                }
+            };
+
+            let mut this_poll = HashMap::with_capacity(objects.len());
+            for object in objects {
+                let stamp = (object.size, object.modified);
+                this_poll.insert(object.name.clone(), stamp);
+
+                if first_poll && self.has_manifest(&ctx, &object.name).await {
+                    self.claim(&object.name, stamp);
+                    continue;
+                }
+                // Still being written, or not seen before: wait a poll
+                if last_poll.get(&object.name) != Some(&stamp) || !self.claim(&object.name, stamp) {
+                    continue;
+                }
+
+                let _permit = limiter.acquire().await;
+                info!(object = %object.name, "Processing local upload");
+                let data = self.object_data(&object, None, None);
+                if let Outcome::Retry(resp) = process_gcs_object(&ctx, data).await {
+                    warn!(
+                        object = %object.name,
+                        message = %resp.message,
+                        "Local upload failed; touch the file to retry"
+                    );
+                }
+            }
+
+            last_poll = this_poll;
+            first_poll = false;
+            tokio::time::sleep(self.interval).await;
+        }
+    }
+
+    fn object_data(
+        &self,
+        object: &LocalObject,
+        content_type: Option<String>,
+        metadata: Option<HashMap<String, String>>,
+    ) -> GcsObjectData {
+        GcsObjectData {
+            bucket: self.upload_bucket.clone(),
+            name: object.name.clone(),
+            content_type: content_type
+                .or_else(|| content_type_for(&object.name).map(str::to_string)),
+            size: Some(object.size.to_string()),
+            metadata,
+        }
+    }
+}
+
+/// Content type of a stored file, from its extension
+fn content_type_for(name: &str) -> Option<&'static str> {
+    let ext = name.rsplit_once('.')?.1.to_ascii_lowercase();
+    Some(match ext.as_str() {
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "pdf" => "application/pdf",
+        "json" => "application/json",
+        _ => return None,
+    })
+}
+
+pub async fn handle_get(
+    path: web::Path<(String, String)>,
+    watcher: web::Data<LocalWatcher>,
+) -> HttpResponse {
+    let (bucket, name) = path.into_inner();
+    let Some(file) = watcher.store().path(&bucket, &name) else {
+        return HttpResponse::BadRequest().json(reply("error", "Invalid object name"));
+    };
+
+    match tokio::fs::read(&file).await {
+        Ok(bytes) => HttpResponse::Ok()
+            .content_type(content_type_for(&name).unwrap_or("application/octet-stream"))
+            .body(bytes),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            HttpResponse::NotFound().json(reply("error", "Object not found"))
+        }
+        Err(e) => HttpResponse::InternalServerError()
+            .json(reply("error", format!("Failed to read object: {}", e))),
+    }
+}
+
+pub async fn handle_upload(
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    body: web::Bytes,
+    watcher: web::Data<LocalWatcher>,
+    ctx: web::Data<PipelineContext>,
+    limiter: web::Data<JobLimiter>,
+) -> HttpResponse {
+    let (bucket, name) = path.into_inner();
+    if bucket != watcher.upload_bucket() {
+        return HttpResponse::NotFound().json(reply(
+            "error",
+            format!("Uploads go to /local/{}/...", watcher.upload_bucket()),
+        ));
+    }
+
+    let _permit = match limiter.try_acquire() {
+        Some(p) => p,
+        None => {
+            return HttpResponse::TooManyRequests()
+                .insert_header(("Retry-After", limiter.retry_after_secs().to_string()))
+                .json(reply("busy", "Too many images in flight, retry later"));
+        }
+    };
+
+    if let Err(e) = watcher.store().write(&bucket, &name, &body).await {
+        warn!(error = %e, object = %name, "Failed to store local upload");
+        return HttpResponse::BadRequest().json(reply("error", e));
+    }
+
+    // The watcher must not pick the same file up again
+    let modified = match watcher.store().path(&bucket, &name) {
+        Some(file) => tokio::fs::metadata(file)
+            .await
+            .and_then(|meta| meta.modified())
+            .ok(),
+        None => None,
+    };
+    let object = LocalObject {
+        name: name.clone(),
+        size: body.len() as u64,
+        modified: modified.unwrap_or_else(SystemTime::now),
+    };
+    watcher.claim(&object.name, (object.size, object.modified));
+
+    let content_type = req
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let metadata: HashMap<String, String> = req
+        .headers()
+        .iter()
+        .filter_map(|(key, value)| {
+            let key = key.as_str().strip_prefix(METADATA_HEADER_PREFIX)?;
+            Some((key.to_string(), value.to_str().ok()?.to_string()))
+        })
+        .collect();
+
+    info!(object = %name, "Processing local upload");
+    let data = watcher.object_data(
+        &object,
+        content_type,
+        Some(metadata).filter(|m| !m.is_empty()),
+    );
+
+    match process_gcs_object(ctx.get_ref(), data).await {
+        Outcome::Done(resp) => HttpResponse::Ok().json(resp),
+        Outcome::Retry(resp) => HttpResponse::InternalServerError().json(resp),
+    }
+}
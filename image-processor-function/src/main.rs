@@ -1,27 +1,59 @@
+mod callback;
+mod download;
+mod events;
+mod limiter;
+mod local;
+mod metadata;
+mod migrate;
+mod moderation;
+mod pdf;
+mod pubsub_worker;
+mod reprocess;
+mod selftest;
+mod settings;
+mod storage;
+mod svg;
+mod tenants;
+
 #[global_allocator]
 static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 
 use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer};
+use callback::CallbackNotifier;
 use chrono::Utc;
-use fast_image_resize::{images::Image, FilterType, PixelType, ResizeAlg, ResizeOptions, Resizer};
+use download::{DownloadError, Original};
+use fast_image_resize::{images::Image, PixelType, ResizeAlg, ResizeOptions, Resizer};
 use futures::future::join_all;
-use google_cloud_storage::{
-    client::{Client as GcsClient, ClientConfig},
-    http::objects::{
-        delete::DeleteObjectRequest,
-        download::Range,
-        get::GetObjectRequest,
-        upload::{Media, UploadObjectRequest, UploadType},
-    },
+use google_cloud_storage::client::{Client as GcsClient, ClientConfig};
+use image::{
+    codecs::png::PngEncoder, DynamicImage, ExtendedColorType, GenericImageView, ImageEncoder,
+    ImageReader,
 };
-use image::{DynamicImage, GenericImageView, ImageReader};
+use limiter::JobLimiter;
+use moderation::ModerationClient;
 use rayon::prelude::*;
+use reprocess::ReprocessAuth;
 use serde::{Deserialize, Serialize};
+use settings::{hex_color, PipelineSettings};
+use sha2::{Digest, Sha256};
+use shared_domain::buckets::{
+    manifest_object_key, media_id_from_object_key, DEFAULT_MANIFEST_BUCKET, DEFAULT_READY_BUCKET,
+};
+use shared_domain::error_codes;
+use shared_domain::limits;
+use shared_domain::manifest::{
+    Manifest, ManifestError, ManifestFile, ManifestMetrics, ManifestOriginal, ManifestVariant,
+    ReadyManifest, PIPELINE_VERSION,
+};
+use shared_domain::variants::VariantSize;
 use std::borrow::Cow;
-use std::io::Cursor;
+use std::collections::HashMap;
+use std::io::{BufRead, Cursor, Read, Seek};
 use std::path::Path;
 use std::sync::Arc;
 use std::time::Instant;
+use storage::{LocalStore, Storage};
+use tenants::TenantRouter;
 use tracing::{error, info, warn};
 use webp::Encoder;
 
@@ -29,35 +61,98 @@ use webp::Encoder;
 // Configuration
 // =============================================================================
 
-const PIPELINE_VERSION: &str = "v1";
-
 fn output_bucket() -> String {
-    std::env::var("OUTPUT_BUCKET").unwrap_or_else(|_| "blogport-cms-ready".to_string())
+    std::env::var("OUTPUT_BUCKET").unwrap_or_else(|_| DEFAULT_READY_BUCKET.to_string())
 }
 
 fn manifest_bucket() -> String {
-    std::env::var("MANIFEST_BUCKET").unwrap_or_else(|_| "blogport-cms-manifests".to_string())
+    std::env::var("MANIFEST_BUCKET").unwrap_or_else(|_| DEFAULT_MANIFEST_BUCKET.to_string())
 }
 
-/// Widths to generate (in addition to 150x150 thumbnail)
-const RESIZE_WIDTHS: [u32; 3] = [320, 768, 1200];
+/// Widths to generate (in addition to the square thumbnail)
+const RESIZE_WIDTHS: [u32; 3] = [
+    VariantSize::Small.width(),
+    VariantSize::Medium.width(),
+    VariantSize::Large.width(),
+];
+
+/// Side of the square thumbnail
+const THUMBNAIL_SIZE: u32 = VariantSize::Thumbnail.width();
+
+/// How events reach the processor (`PROCESSOR_MODE`).
+#[derive(Debug, Clone, Copy)]
+enum RuntimeMode {
+    /// Eventarc pushes CloudEvents to `POST /` (Cloud Run, default)
+    Http,
+    /// Pull GCS notifications from a Pub/Sub subscription (self-managed VMs)
+    PubSubPull,
+    /// Run one pipeline-version migration sweep and exit
+    Migrate,
+    /// Read and write folders instead of GCS, for development (see local.rs)
+    Local,
+}
 
-/// WebP quality (0-100)
-const WEBP_QUALITY: f32 = 80.0;
+impl RuntimeMode {
+    fn from_env() -> Self {
+        match std::env::var("PROCESSOR_MODE").as_deref() {
+            Ok("pubsub") | Ok("pubsub-pull") => RuntimeMode::PubSubPull,
+            Ok("migrate") => RuntimeMode::Migrate,
+            Ok("local") => RuntimeMode::Local,
+            _ => RuntimeMode::Http,
+        }
+    }
+}
 
 // =============================================================================
 // Business rules
 // =============================================================================
 
-const MAX_FILE_BYTES: usize = 5 * 1024 * 1024; // 5MB
-const MAX_TOTAL_PIXELS: u64 = 20_000_000; // 20MP
-const MAX_DIMENSION: u32 = 6000; // max width/height
+// Shared with the backend, which tells clients about them before they upload
+const MAX_FILE_BYTES: usize = limits::MAX_IMAGE_BYTES as usize;
+const MAX_DOCUMENT_BYTES: usize = limits::MAX_DOCUMENT_BYTES as usize;
+const MAX_TOTAL_PIXELS: u64 = limits::MAX_TOTAL_PIXELS;
+const MAX_DIMENSION: u32 = limits::MAX_DIMENSION;
 
 #[derive(Clone, Copy, Debug)]
 enum AllowedFormat {
     Jpeg,
     Png,
     Webp,
+    Pdf,
+    Svg,
+}
+
+impl AllowedFormat {
+    fn max_bytes(&self) -> usize {
+        match self {
+            AllowedFormat::Pdf => MAX_DOCUMENT_BYTES,
+            _ => MAX_FILE_BYTES,
+        }
+    }
+
+    /// Manifest `kind`: PDFs only get preview variants of their first page.
+    fn kind(&self) -> &'static str {
+        match self {
+            AllowedFormat::Pdf => "document",
+            _ => "image",
+        }
+    }
+
+    /// Extension and content type of the passthrough copy (raster formats only)
+    fn passthrough_file(&self) -> Option<(&'static str, &'static str)> {
+        match self {
+            AllowedFormat::Jpeg => Some(("jpg", "image/jpeg")),
+            AllowedFormat::Png => Some(("png", "image/png")),
+            AllowedFormat::Webp => Some(("webp", "image/webp")),
+            AllowedFormat::Pdf | AllowedFormat::Svg => None,
+        }
+    }
+
+    /// Whether the decoded raster reflects the original's own pixel size
+    /// (PDF pages and SVGs are rendered at a size we pick).
+    fn has_pixel_size(&self) -> bool {
+        !matches!(self, AllowedFormat::Pdf | AllowedFormat::Svg)
+    }
 }
 
 fn detect_format(bytes: &[u8]) -> Option<AllowedFormat> {
@@ -73,6 +168,14 @@ fn detect_format(bytes: &[u8]) -> Option<AllowedFormat> {
     if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
         return Some(AllowedFormat::Webp);
     }
+    // PDF: %PDF-
+    if bytes.len() >= 5 && &bytes[..5] == b"%PDF-" {
+        return Some(AllowedFormat::Pdf);
+    }
+    // SVG: text markup, no magic bytes (parsing is the real check)
+    if svg::looks_like_svg(bytes) {
+        return Some(AllowedFormat::Svg);
+    }
     None
 }
 
@@ -83,16 +186,18 @@ enum RuleCode {
     TooLargePixels,
     TooLargeDimensions,
     DecodeFailed,
+    ContentRejected,
 }
 
 impl RuleCode {
     fn as_str(&self) -> &'static str {
         match self {
-            RuleCode::InvalidType => "INVALID_TYPE",
-            RuleCode::TooLargeBytes => "MAX_SIZE_EXCEEDED",
-            RuleCode::TooLargePixels => "MAX_PIXELS_EXCEEDED",
-            RuleCode::TooLargeDimensions => "MAX_DIM_EXCEEDED",
-            RuleCode::DecodeFailed => "DECODE_FAILED",
+            RuleCode::InvalidType => error_codes::INVALID_TYPE,
+            RuleCode::TooLargeBytes => error_codes::MAX_SIZE_EXCEEDED,
+            RuleCode::TooLargePixels => error_codes::MAX_PIXELS_EXCEEDED,
+            RuleCode::TooLargeDimensions => error_codes::MAX_DIM_EXCEEDED,
+            RuleCode::DecodeFailed => error_codes::DECODE_FAILED,
+            RuleCode::ContentRejected => error_codes::CONTENT_REJECTED,
         }
     }
 }
@@ -101,43 +206,124 @@ impl RuleCode {
 struct RuleError {
     code: RuleCode,
     message: String,
-    stage: &'static str, // "validation" | "processing"
+    stage: &'static str, // "validation" | "processing" | "moderation"
 }
 
-fn validate_and_decode(bytes: &[u8]) -> Result<(DynamicImage, AllowedFormat), RuleError> {
+/// Files written next to the resized variants
+#[derive(Default)]
+struct ExtraOutputs {
+    /// Sanitized markup for SVG uploads
+    sanitized_svg: Option<Vec<u8>>,
+    /// Metadata-stripped copy of a raster original (`PASSTHROUGH_ORIGINAL`)
+    passthrough: Option<Vec<u8>>,
+}
+
+struct DecodedOriginal {
+    img: DynamicImage,
+    fmt: AllowedFormat,
+    extras: ExtraOutputs,
+}
+
+fn validate_and_decode(
+    original: Original,
+    keep_original: bool,
+) -> Result<DecodedOriginal, RuleError> {
+    let fmt = detect_format(original.head()).ok_or_else(|| RuleError {
+        code: RuleCode::InvalidType,
+        message: "Only JPEG, PNG, WEBP, SVG, and PDF are allowed".to_string(),
+        stage: "validation",
+    })?;
+
     // Authoritative max size enforcement (do not remove)
-    if bytes.len() > MAX_FILE_BYTES {
+    if original.len() > fmt.max_bytes() {
         return Err(RuleError {
             code: RuleCode::TooLargeBytes,
             message: format!(
                 "File too large: {} bytes (max {} bytes)",
-                bytes.len(),
-                MAX_FILE_BYTES
+                original.len(),
+                fmt.max_bytes()
             ),
             stage: "validation",
         });
     }
 
-    let fmt = detect_format(bytes).ok_or_else(|| RuleError {
-        code: RuleCode::InvalidType,
-        message: "Only JPEG, PNG, and WEBP are allowed".to_string(),
+    let mut reader = original.into_reader().map_err(|e| RuleError {
+        code: RuleCode::DecodeFailed,
+        message: format!("Failed to read original: {e}"),
         stage: "validation",
     })?;
 
-    // Decode (CPU-bound)
-    let img = ImageReader::new(Cursor::new(bytes))
-        .with_guessed_format()
-        .map_err(|e| RuleError {
-            code: RuleCode::DecodeFailed,
-            message: format!("Failed to guess format: {e}"),
-            stage: "validation",
-        })?
-        .decode()
-        .map_err(|e| RuleError {
-            code: RuleCode::DecodeFailed,
-            message: format!("Failed to decode image: {e}"),
-            stage: "validation",
-        })?;
+    let mut extras = ExtraOutputs::default();
+
+    // Decode (CPU-bound); PDFs are rasterized from their first page, SVGs are
+    // sanitized and rasterized at the largest variant width
+    let img = match fmt {
+        AllowedFormat::Svg => {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).map_err(|e| RuleError {
+                code: RuleCode::DecodeFailed,
+                message: format!("Failed to read original: {e}"),
+                stage: "validation",
+            })?;
+
+            let sanitized = svg::sanitize(&bytes).map_err(|msg| RuleError {
+                code: RuleCode::DecodeFailed,
+                message: msg,
+                stage: "validation",
+            })?;
+
+            // Check before allocating the raster
+            let raster_width = RESIZE_WIDTHS[RESIZE_WIDTHS.len() - 1];
+            let (w, h) = svg::raster_size(&sanitized.tree, raster_width);
+            if h > MAX_DIMENSION {
+                return Err(RuleError {
+                    code: RuleCode::TooLargeDimensions,
+                    message: format!(
+                        "SVG aspect ratio too extreme: {}x{} at {}px wide (max {}x{})",
+                        w, h, raster_width, MAX_DIMENSION, MAX_DIMENSION
+                    ),
+                    stage: "validation",
+                });
+            }
+
+            let img = svg::rasterize(&sanitized.tree, raster_width).map_err(|msg| RuleError {
+                code: RuleCode::DecodeFailed,
+                message: msg,
+                stage: "validation",
+            })?;
+            extras.sanitized_svg = Some(sanitized.markup);
+            img
+        }
+        AllowedFormat::Pdf => {
+            pdf::render_first_page(reader, pdf::render_size()).map_err(|msg| RuleError {
+                code: RuleCode::DecodeFailed,
+                message: msg,
+                stage: "validation",
+            })?
+        }
+        _ if keep_original => {
+            // Needs the whole file anyway; decode from the same buffer
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).map_err(|e| RuleError {
+                code: RuleCode::DecodeFailed,
+                message: format!("Failed to read original: {e}"),
+                stage: "validation",
+            })?;
+            let img = decode_raster(Cursor::new(&bytes))?;
+
+            let stripped = match fmt {
+                AllowedFormat::Jpeg => metadata::strip_jpeg(&bytes),
+                AllowedFormat::Png => metadata::strip_png(&bytes),
+                _ => metadata::strip_webp(&bytes),
+            };
+            match stripped {
+                Ok(copy) => extras.passthrough = Some(copy),
+                Err(e) => warn!(error = %e, "Could not strip metadata, skipping passthrough copy"),
+            }
+            img
+        }
+        _ => decode_raster(reader)?,
+    };
 
     let (w, h) = img.dimensions();
 
@@ -164,21 +350,29 @@ fn validate_and_decode(bytes: &[u8]) -> Result<(DynamicImage, AllowedFormat), Ru
         });
     }
 
-    Ok((img, fmt))
+    Ok(DecodedOriginal { img, fmt, extras })
+}
+
+fn decode_raster(reader: impl BufRead + Seek) -> Result<DynamicImage, RuleError> {
+    ImageReader::new(reader)
+        .with_guessed_format()
+        .map_err(|e| RuleError {
+            code: RuleCode::DecodeFailed,
+            message: format!("Failed to guess format: {e}"),
+            stage: "validation",
+        })?
+        .decode()
+        .map_err(|e| RuleError {
+            code: RuleCode::DecodeFailed,
+            message: format!("Failed to decode image: {e}"),
+            stage: "validation",
+        })
 }
 
 // =============================================================================
 // CloudEvent structures
 // =============================================================================
 
-#[derive(Debug)]
-struct CloudEventHeaders {
-    id: String,
-    source: String,
-    event_type: String,
-    subject: Option<String>,
-}
-
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct GcsObjectData {
@@ -186,6 +380,9 @@ struct GcsObjectData {
     name: String,
     content_type: Option<String>,
     size: Option<String>,
+    /// Custom object metadata (`x-goog-meta-*`), e.g. `role`
+    #[serde(default)]
+    metadata: Option<HashMap<String, String>>,
 }
 
 #[derive(Serialize)]
@@ -196,65 +393,9 @@ struct FunctionResponse {
     variants_created: Option<Vec<String>>,
 }
 
-// =============================================================================
-// Manifest structures
-// =============================================================================
-
-#[derive(Serialize)]
-struct ManifestOriginal {
-    bucket: String,
-    path: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    width: Option<u32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    height: Option<u32>,
-}
-
-#[derive(Serialize)]
-struct ManifestVariant {
-    size: u32,
-    path: String,
-    width: u32,
-    height: u32,
-    file_size_bytes: usize,
-}
-
-#[derive(Serialize)]
-struct ManifestMetrics {
-    total_ms: u64,
-    download_ms: u64,
-    processing_ms: u64,
-    upload_ms: u64,
-    encoder: String,
-    quality: u32,
-}
-
-#[derive(Serialize)]
-struct ManifestError {
-    code: String,
-    message: String,
-    stage: String,
-}
-
-#[derive(Serialize)]
-#[serde(tag = "state")]
-enum Manifest {
-    #[serde(rename = "ready")]
-    Ready {
-        media_id: String,
-        pipeline_version: String,
-        updated_at: String,
-        original: ManifestOriginal,
-        variants: Vec<ManifestVariant>,
-        metrics: ManifestMetrics,
-    },
-    #[serde(rename = "failed")]
-    Failed {
-        media_id: String,
-        pipeline_version: String,
-        updated_at: String,
-        error: ManifestError,
-    },
+/// Lowercase hex SHA-256, as recorded in the manifest.
+fn sha256_hex(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
 }
 
 fn now_iso8601() -> String {
@@ -287,13 +428,22 @@ struct ResizeTarget {
     height: u32,
     suffix: String,
     crop_square: Option<u32>,
+    quality: f32,
+    /// Composite RGBA sources onto this color (drops alpha)
+    flatten: Option<[u8; 3]>,
+    /// Also encode a PNG of the same pixels
+    png_fallback: bool,
 }
 
 struct ImageVariant {
     suffix: String,
     width: u32,
     height: u32,
+    quality: f32,
+    has_alpha: bool,
+    flattened_onto: Option<[u8; 3]>,
     data: Vec<u8>,
+    png: Option<Vec<u8>>,
 }
 
 struct ProcessedImage {
@@ -306,14 +456,16 @@ fn resize_to_webp(
     src: &Image,
     target: &ResizeTarget,
     resizer: &mut Resizer,
-) -> Result<(Vec<u8>, u32, u32), String> {
+    settings: &PipelineSettings,
+) -> Result<EncodedVariant, String> {
     let mut dst_image = Image::new(target.width, target.height, src.pixel_type());
 
     resizer
         .resize(
             src,
             &mut dst_image,
-            &ResizeOptions::new().resize_alg(ResizeAlg::Convolution(FilterType::Bilinear)),
+            &ResizeOptions::new()
+                .resize_alg(ResizeAlg::Convolution(settings.resize_filter.filter_type())),
         )
         .map_err(|e| format!("Resize failed: {}", e))?;
 
@@ -350,47 +502,149 @@ fn resize_to_webp(
         };
 
     // EXIF is effectively stripped: outputs are re-encoded from raw pixels to WebP.
-    let webp_data = match src.pixel_type() {
-        PixelType::U8x4 => {
-            Encoder::from_rgba(&final_pixels, final_width, final_height).encode(WEBP_QUALITY)
-        }
-        _ => Encoder::from_rgb(&final_pixels, final_width, final_height).encode(WEBP_QUALITY),
+    let (pixels, has_alpha, flattened_onto): (Cow<[u8]>, bool, Option<[u8; 3]>) =
+        match (src.pixel_type(), target.flatten) {
+            (PixelType::U8x4, Some(bg)) => {
+                (Cow::Owned(flatten_rgba(&final_pixels, bg)), false, Some(bg))
+            }
+            (PixelType::U8x4, None) => (final_pixels, true, None),
+            _ => (final_pixels, false, None),
+        };
+
+    let webp_data = if has_alpha {
+        Encoder::from_rgba(&pixels, final_width, final_height).encode(target.quality)
+    } else {
+        Encoder::from_rgb(&pixels, final_width, final_height).encode(target.quality)
+    };
+
+    let png = if target.png_fallback {
+        Some(encode_png(&pixels, final_width, final_height, has_alpha)?)
+    } else {
+        None
     };
 
-    Ok((webp_data.to_vec(), final_width, final_height))
+    Ok(EncodedVariant {
+        data: webp_data.to_vec(),
+        width: final_width,
+        height: final_height,
+        has_alpha,
+        flattened_onto,
+        png,
+    })
 }
 
-fn process_dynamic_image(img: DynamicImage) -> Result<ProcessedImage, String> {
+fn encode_png(pixels: &[u8], width: u32, height: u32, has_alpha: bool) -> Result<Vec<u8>, String> {
+    let color = if has_alpha {
+        ExtendedColorType::Rgba8
+    } else {
+        ExtendedColorType::Rgb8
+    };
+
+    let mut out = Vec::new();
+    PngEncoder::new(&mut out)
+        .write_image(pixels, width, height, color)
+        .map_err(|e| format!("PNG encode failed: {}", e))?;
+    Ok(out)
+}
+
+struct EncodedVariant {
+    data: Vec<u8>,
+    width: u32,
+    height: u32,
+    has_alpha: bool,
+    flattened_onto: Option<[u8; 3]>,
+    png: Option<Vec<u8>>,
+}
+
+/// Alpha-composite RGBA pixels onto an opaque background, producing RGB.
+fn flatten_rgba(rgba: &[u8], bg: [u8; 3]) -> Vec<u8> {
+    let mut rgb = Vec::with_capacity(rgba.len() / 4 * 3);
+    for px in rgba.chunks_exact(4) {
+        let a = px[3] as u16;
+        for (&fg, &back) in px[..3].iter().zip(bg.iter()) {
+            let blended = (fg as u16 * a + back as u16 * (255 - a) + 127) / 255;
+            rgb.push(blended as u8);
+        }
+    }
+    rgb
+}
+
+fn process_dynamic_image(
+    img: DynamicImage,
+    settings: &PipelineSettings,
+    role: Option<&str>,
+    png_fallbacks: bool,
+) -> Result<ProcessedImage, String> {
     let (w, h) = img.dimensions();
+    let src_image = resize_source(img)?;
+    let targets = resize_targets(w, h, settings, role, png_fallbacks);
+
+    // Parallel variants (kept)
+    let variants: Result<Vec<ImageVariant>, String> = targets
+        .par_iter()
+        .map(|target| {
+            let mut resizer = Resizer::new();
+            let encoded = resize_to_webp(&src_image, target, &mut resizer, settings)?;
+            Ok(ImageVariant {
+                suffix: target.suffix.clone(),
+                width: encoded.width,
+                height: encoded.height,
+                quality: target.quality,
+                has_alpha: encoded.has_alpha,
+                flattened_onto: encoded.flattened_onto,
+                data: encoded.data,
+                png: encoded.png,
+            })
+        })
+        .collect();
+
+    Ok(ProcessedImage {
+        original_width: w,
+        original_height: h,
+        variants: variants?,
+    })
+}
 
-    // Convert to fast_image_resize::Image without cloning entire buffers
-    let src_image = match img {
-        DynamicImage::ImageRgb8(rgb) => Image::from_vec_u8(w, h, rgb.into_raw(), PixelType::U8x3)
-            .map_err(|e| format!("Failed to create image: {}", e))?,
+/// Convert to fast_image_resize::Image without cloning entire buffers
+fn resize_source(img: DynamicImage) -> Result<Image<'static>, String> {
+    let (w, h) = img.dimensions();
+    let image = match img {
+        DynamicImage::ImageRgb8(rgb) => Image::from_vec_u8(w, h, rgb.into_raw(), PixelType::U8x3),
         DynamicImage::ImageRgba8(rgba) => {
             Image::from_vec_u8(w, h, rgba.into_raw(), PixelType::U8x4)
-                .map_err(|e| format!("Failed to create image: {}", e))?
         }
         other => {
             let rgb = other.to_rgb8();
             Image::from_vec_u8(w, h, rgb.into_raw(), PixelType::U8x3)
-                .map_err(|e| format!("Failed to create image: {}", e))?
         }
     };
+    image.map_err(|e| format!("Failed to create image: {}", e))
+}
 
-    // Build resize targets
+/// The square thumbnail and the responsive widths of a `w`x`h` original
+fn resize_targets(
+    w: u32,
+    h: u32,
+    settings: &PipelineSettings,
+    role: Option<&str>,
+    png_fallbacks: bool,
+) -> Vec<ResizeTarget> {
     let mut targets: Vec<ResizeTarget> = Vec::with_capacity(4);
 
-    // 150x150 thumbnail (scale then crop)
-    let thumb_scale = 150.0 / (w.min(h) as f32);
-    let thumb_w = ((w as f32 * thumb_scale).round() as u32).max(150);
-    let thumb_h = ((h as f32 * thumb_scale).round() as u32).max(150);
+    // Square thumbnail (scale then crop)
+    let thumb_scale = THUMBNAIL_SIZE as f32 / (w.min(h) as f32);
+    let thumb_w = ((w as f32 * thumb_scale).round() as u32).max(THUMBNAIL_SIZE);
+    let thumb_h = ((h as f32 * thumb_scale).round() as u32).max(THUMBNAIL_SIZE);
+    let thumb_suffix = THUMBNAIL_SIZE.to_string();
 
     targets.push(ResizeTarget {
         width: thumb_w,
         height: thumb_h,
-        suffix: "150".to_string(),
-        crop_square: Some(150),
+        quality: settings.quality_for(&thumb_suffix),
+        flatten: settings.flatten_for(&thumb_suffix, role),
+        suffix: thumb_suffix,
+        crop_square: Some(THUMBNAIL_SIZE),
+        png_fallback: png_fallbacks,
     });
 
     // Responsive widths
@@ -401,117 +655,63 @@ fn process_dynamic_image(img: DynamicImage) -> Result<ProcessedImage, String> {
             height: nh.max(1),
             suffix: tw.to_string(),
             crop_square: None,
+            quality: settings.quality_for(&tw.to_string()),
+            flatten: settings.flatten_for(&tw.to_string(), role),
+            png_fallback: png_fallbacks,
         });
     }
 
-    // Parallel variants (kept)
-    let variants: Result<Vec<ImageVariant>, String> = targets
-        .par_iter()
-        .map(|target| {
-            let mut resizer = Resizer::new();
-            let (webp_data, final_w, final_h) = resize_to_webp(&src_image, target, &mut resizer)?;
-            Ok(ImageVariant {
-                suffix: target.suffix.clone(),
-                width: final_w,
-                height: final_h,
-                data: webp_data,
-            })
-        })
-        .collect();
-
-    Ok(ProcessedImage {
-        original_width: w,
-        original_height: h,
-        variants: variants?,
-    })
+    targets
 }
 
 // =============================================================================
-// GCS operations
+// Storage operations
 // =============================================================================
 
-async fn download_from_gcs(
-    client: &GcsClient,
-    bucket: &str,
-    name: &str,
-) -> Result<Vec<u8>, String> {
-    let request = GetObjectRequest {
-        bucket: bucket.to_string(),
-        object: name.to_string(),
-        ..Default::default()
-    };
-
-    client
-        .download_object(&request, &Range::default())
-        .await
-        .map_err(|e| format!("Failed to download from GCS: {}", e))
-}
-
-async fn upload_to_gcs(
-    client: &GcsClient,
+async fn upload_manifest(
+    storage: &Storage,
     bucket: &str,
-    name: &str,
-    data: Vec<u8>,
-    content_type: &str,
+    media_id: &str,
+    manifest: &Manifest,
 ) -> Result<(), String> {
-    let upload_type = UploadType::Simple(Media {
-        name: name.to_string().into(),
-        content_type: content_type.to_string().into(),
-        content_length: Some(data.len() as u64),
-    });
+    let json = serde_json::to_string_pretty(manifest)
+        .map_err(|e| format!("Failed to serialize manifest: {}", e))?;
 
-    client
-        .upload_object(
-            &UploadObjectRequest {
-                bucket: bucket.to_string(),
-                ..Default::default()
-            },
-            data,
-            &upload_type,
+    storage
+        .upload(
+            bucket,
+            &manifest_object_key(media_id),
+            json.into_bytes(),
+            "application/json",
         )
         .await
-        .map_err(|e| format!("Failed to upload to GCS: {}", e))?;
-
-    Ok(())
 }
 
-async fn upload_manifest(
-    client: &GcsClient,
+/// Write the manifest and, when configured, push it to the backend callback.
+///
+/// Use this for final manifests only; intermediate writes go through
+/// `upload_manifest` so the backend is notified exactly once per run.
+async fn publish_manifest(
+    storage: &Storage,
+    callback: Option<&CallbackNotifier>,
+    bucket: &str,
     media_id: &str,
     manifest: &Manifest,
 ) -> Result<(), String> {
-    let json = serde_json::to_string_pretty(manifest)
-        .map_err(|e| format!("Failed to serialize manifest: {}", e))?;
+    upload_manifest(storage, bucket, media_id, manifest).await?;
 
-    let manifest_path = format!("{}/manifest.json", media_id);
-
-    upload_to_gcs(
-        client,
-        &manifest_bucket(),
-        &manifest_path,
-        json.into_bytes(),
-        "application/json",
-    )
-    .await
-}
-
-async fn delete_from_gcs(client: &GcsClient, bucket: &str, name: &str) -> Result<(), String> {
-    let req = DeleteObjectRequest {
-        bucket: bucket.to_string(),
-        object: name.to_string(),
-        ..Default::default()
-    };
-
-    client
-        .delete_object(&req)
-        .await
-        .map_err(|e| format!("Failed to delete from GCS: {}", e))?;
+    if let Some(cb) = callback {
+        match serde_json::to_vec(manifest) {
+            Ok(body) => cb.notify(media_id, &body).await,
+            Err(e) => warn!(error = %e, media_id, "Failed to serialize manifest for callback"),
+        }
+    }
 
     Ok(())
 }
 
-async fn delete_original_best_effort(client: &GcsClient, bucket: &str, name: &str) {
-    match delete_from_gcs(client, bucket, name).await {
+async fn delete_original_best_effort(storage: &Storage, bucket: &str, name: &str) {
+    match storage.delete(bucket, name).await {
         Ok(_) => info!(bucket = bucket, object = name, "Deleted original object"),
         Err(e) => {
             warn!(error = %e, bucket = bucket, object = name, "Failed to delete original object")
@@ -523,37 +723,34 @@ async fn delete_original_best_effort(client: &GcsClient, bucket: &str, name: &st
 // Request handler
 // =============================================================================
 
-fn extract_cloud_event_headers(req: &HttpRequest) -> Option<CloudEventHeaders> {
-    let get_header = |name: &str| -> Option<String> {
-        req.headers()
-            .get(name)
-            .and_then(|v| v.to_str().ok())
-            .map(String::from)
-    };
-
-    Some(CloudEventHeaders {
-        id: get_header("ce-id")?,
-        source: get_header("ce-source")?,
-        event_type: get_header("ce-type")?,
-        subject: get_header("ce-subject"),
-    })
-}
-
 /// Fast skip using metadata only (real enforcement happens after download via magic-bytes + decode)
 fn is_processable_image_by_metadata(name: &str, content_type: Option<&str>) -> bool {
     let lower = name.to_lowercase();
     let by_extension = lower.ends_with(".jpg")
         || lower.ends_with(".jpeg")
         || lower.ends_with(".png")
-        || lower.ends_with(".webp");
+        || lower.ends_with(".webp")
+        || lower.ends_with(".svg")
+        || lower.ends_with(".pdf");
 
     let by_content_type = content_type
-        .map(|ct| ct.starts_with("image/"))
+        .map(|ct| ct.starts_with("image/") || ct == "application/pdf")
         .unwrap_or(false);
 
     by_extension || by_content_type
 }
 
+/// Byte limit to apply before the format is known (documents get a larger budget)
+fn max_bytes_by_metadata(name: &str, content_type: Option<&str>) -> usize {
+    let is_pdf = name.to_lowercase().ends_with(".pdf") || content_type == Some("application/pdf");
+
+    if is_pdf {
+        MAX_DOCUMENT_BYTES
+    } else {
+        MAX_FILE_BYTES
+    }
+}
+
 /// Check if this is a folder creation event (not a real file)
 fn is_folder_marker(name: &str, size: Option<&str>) -> bool {
     if name.ends_with('/') {
@@ -566,46 +763,36 @@ fn is_folder_marker(name: &str, size: Option<&str>) -> bool {
     is_zero_bytes && has_no_extension
 }
 
-/// Extract media_id from path like "fold-097/photo.jpg" -> "fold-097"
-fn extract_media_id(name: &str) -> String {
-    if let Some((folder, _)) = name.split_once('/') {
-        folder.to_string()
-    } else {
-        name.rsplit_once('.')
-            .map(|(s, _)| s)
-            .unwrap_or(name)
-            .to_string()
-    }
-}
-
 async fn handle_gcs_event(
     req: HttpRequest,
     body: web::Bytes,
-    gcs_client: web::Data<Arc<GcsClient>>,
+    ctx: web::Data<PipelineContext>,
+    limiter: web::Data<JobLimiter>,
 ) -> HttpResponse {
-    let total_start = Instant::now();
-
-    // Extract CloudEvent metadata
-    let headers = match extract_cloud_event_headers(&req) {
-        Some(h) => h,
-        None => {
-            warn!("Missing required CloudEvent headers");
+    // Normalize binary, structured and Pub/Sub-wrapped deliveries
+    let event = match events::normalize(&req, &body) {
+        Ok(e) => e,
+        Err(e) => {
+            warn!(error = %e, "Unrecognized event envelope");
             return HttpResponse::BadRequest().json(FunctionResponse {
                 status: "error".to_string(),
-                message: "Missing required CloudEvent headers".to_string(),
+                message: e,
                 variants_created: None,
             });
         }
     };
+    let headers = &event.meta;
 
     info!(
         event_id = %headers.id,
         event_type = %headers.event_type,
+        source = %headers.source,
+        subject = ?headers.subject,
         "Received CloudEvent"
     );
 
     // Only process finalize events
-    if headers.event_type != "google.cloud.storage.object.v1.finalized" {
+    if headers.event_type != events::GCS_FINALIZED {
         return HttpResponse::Ok().json(FunctionResponse {
             status: "ignored".to_string(),
             message: format!("Event type {} not handled", headers.event_type),
@@ -614,7 +801,7 @@ async fn handle_gcs_event(
     }
 
     // Parse GCS object data
-    let gcs_data: GcsObjectData = match serde_json::from_slice(&body) {
+    let gcs_data: GcsObjectData = match serde_json::from_slice(&event.data) {
         Ok(data) => data,
         Err(e) => {
             error!(error = %e, "Failed to parse CloudEvent data");
@@ -626,6 +813,62 @@ async fn handle_gcs_event(
         }
     };
 
+    // Backpressure: refuse instead of queueing so the instance doesn't OOM on bursts.
+    // Eventarc retries 429s with backoff.
+    let _permit = match limiter.try_acquire() {
+        Some(p) => p,
+        None => {
+            warn!(
+                in_flight = limiter.in_flight(),
+                max = limiter.max_jobs(),
+                file_name = %gcs_data.name,
+                "At capacity, asking sender to retry"
+            );
+            return HttpResponse::TooManyRequests()
+                .insert_header(("Retry-After", limiter.retry_after_secs().to_string()))
+                .json(FunctionResponse {
+                    status: "busy".to_string(),
+                    message: "Too many images in flight, retry later".to_string(),
+                    variants_created: None,
+                });
+        }
+    };
+
+    match process_gcs_object(ctx.get_ref(), gcs_data).await {
+        Outcome::Done(resp) => HttpResponse::Ok().json(resp),
+        Outcome::Retry(resp) => HttpResponse::InternalServerError().json(resp),
+    }
+}
+
+/// Everything a pipeline run needs, shared by every delivery mode.
+struct PipelineContext {
+    /// Where the pipeline reads and writes objects
+    storage: Storage,
+    /// GCS-only work: object metadata for `/reprocess`, listing for `/migrate`
+    gcs: GcsClient,
+    callback: Option<CallbackNotifier>,
+    moderation: Option<ModerationClient>,
+    settings: Arc<PipelineSettings>,
+    tenants: TenantRouter,
+}
+
+/// Result of running the pipeline for one object.
+///
+/// `Done` covers success and business-rule rejections (nothing to retry);
+/// `Retry` means a transient failure where redelivery may succeed.
+enum Outcome {
+    Done(FunctionResponse),
+    Retry(FunctionResponse),
+}
+
+/// Run the full pipeline for one finalized object, independent of how the
+/// event was delivered (HTTP push or Pub/Sub pull).
+async fn process_gcs_object(ctx: &PipelineContext, gcs_data: GcsObjectData) -> Outcome {
+    let total_start = Instant::now();
+    let client = &ctx.storage;
+    let callback = ctx.callback.as_ref();
+    let settings = &ctx.settings;
+
     info!(
         bucket = %gcs_data.bucket,
         file_name = %gcs_data.name,
@@ -634,13 +877,28 @@ async fn handle_gcs_event(
         "Processing upload event"
     );
 
-    let media_id = extract_media_id(&gcs_data.name);
-    let client = gcs_client.get_ref();
+    let dest = match ctx
+        .tenants
+        .resolve(&gcs_data.name, gcs_data.metadata.as_ref())
+    {
+        Ok(dest) => dest,
+        Err(tenant) => {
+            // No manifest: there is no bucket this tenant's manifests may go to
+            error!(file_name = %gcs_data.name, tenant = %tenant, "Unknown tenant, not processing");
+            return Outcome::Done(FunctionResponse {
+                status: "error".to_string(),
+                message: format!("Unknown tenant: {}", tenant),
+                variants_created: None,
+            });
+        }
+    };
+    let media_id = media_id_from_object_key(dest.key).to_string();
+    let manifests = dest.manifest_bucket;
 
     // Skip folder creation events
     if is_folder_marker(&gcs_data.name, gcs_data.size.as_deref()) {
         info!(file_name = %gcs_data.name, "Skipping folder marker");
-        return HttpResponse::Ok().json(FunctionResponse {
+        return Outcome::Done(FunctionResponse {
             status: "skipped".to_string(),
             message: "Folder marker, not a file".to_string(),
             variants_created: None,
@@ -653,16 +911,16 @@ async fn handle_gcs_event(
 
         let manifest = failed_manifest(
             media_id.clone(),
-            "INVALID_FILE_TYPE",
+            error_codes::INVALID_FILE_TYPE,
             "Not a processable image (metadata)".to_string(),
             "validation",
         );
-        let _ = upload_manifest(client, &media_id, &manifest).await;
+        let _ = publish_manifest(client, callback, manifests, &media_id, &manifest).await;
 
         // Business rule: invalid type -> delete immediately (best effort)
         delete_original_best_effort(client, &gcs_data.bucket, &gcs_data.name).await;
 
-        return HttpResponse::Ok().json(FunctionResponse {
+        return Outcome::Done(FunctionResponse {
             status: "skipped".to_string(),
             message: "Not a processable image".to_string(),
             variants_created: None,
@@ -673,12 +931,13 @@ async fn handle_gcs_event(
     // Minimal metadata pre-check (size only): skip download if object is too big.
     // Still keep the authoritative bytes.len() check after download.
     // -------------------------------------------------------------------------
+    let max_bytes = max_bytes_by_metadata(&gcs_data.name, gcs_data.content_type.as_deref());
     if let Some(size_str) = gcs_data.size.as_deref() {
         if let Ok(size) = size_str.parse::<u64>() {
-            if size > MAX_FILE_BYTES as u64 {
+            if size > max_bytes as u64 {
                 info!(
                     size,
-                    max = MAX_FILE_BYTES as u64,
+                    max = max_bytes as u64,
                     "Skipping: file too large (metadata)"
                 );
 
@@ -687,16 +946,16 @@ async fn handle_gcs_event(
                     RuleCode::TooLargeBytes.as_str(),
                     format!(
                         "File too large per metadata: {} bytes (max {})",
-                        size, MAX_FILE_BYTES
+                        size, max_bytes
                     ),
                     "validation",
                 );
-                let _ = upload_manifest(client, &media_id, &manifest).await;
+                let _ = publish_manifest(client, callback, manifests, &media_id, &manifest).await;
 
                 // Business rule: too large -> delete immediately (best effort)
                 delete_original_best_effort(client, &gcs_data.bucket, &gcs_data.name).await;
 
-                return HttpResponse::Ok().json(FunctionResponse {
+                return Outcome::Done(FunctionResponse {
                     status: "skipped".to_string(),
                     message: "File too large (metadata pre-check)".to_string(),
                     variants_created: None,
@@ -712,18 +971,45 @@ async fn handle_gcs_event(
         warn!("Missing gcs_data.size; continuing to download");
     }
 
-    // Download original
+    // Download original (streamed; aborts as soon as max_bytes is exceeded)
     let download_start = Instant::now();
-    let image_bytes = match download_from_gcs(client, &gcs_data.bucket, &gcs_data.name).await {
-        Ok(bytes) => bytes,
-        Err(e) => {
+    let (original, original_sha256) = match client
+        .download_bounded(&gcs_data.bucket, &gcs_data.name, max_bytes)
+        .await
+    {
+        Ok(downloaded) => downloaded,
+        Err(DownloadError::TooLarge { max_bytes }) => {
+            info!(max = max_bytes, "Aborted download: file too large");
+
+            let manifest = failed_manifest(
+                media_id.clone(),
+                RuleCode::TooLargeBytes.as_str(),
+                format!("File too large: exceeds {} bytes", max_bytes),
+                "validation",
+            );
+            let _ = publish_manifest(client, callback, manifests, &media_id, &manifest).await;
+
+            // Business rule: too large -> delete immediately (best effort)
+            delete_original_best_effort(client, &gcs_data.bucket, &gcs_data.name).await;
+
+            return Outcome::Done(FunctionResponse {
+                status: "skipped".to_string(),
+                message: "File too large".to_string(),
+                variants_created: None,
+            });
+        }
+        Err(DownloadError::Failed(e)) => {
             error!(error = %e, "Failed to download image");
 
-            let manifest =
-                failed_manifest(media_id.clone(), "DOWNLOAD_ERROR", e.clone(), "download");
-            let _ = upload_manifest(client, &media_id, &manifest).await;
+            let manifest = failed_manifest(
+                media_id.clone(),
+                error_codes::DOWNLOAD_ERROR,
+                e.clone(),
+                "download",
+            );
+            let _ = publish_manifest(client, callback, manifests, &media_id, &manifest).await;
 
-            return HttpResponse::InternalServerError().json(FunctionResponse {
+            return Outcome::Retry(FunctionResponse {
                 status: "error".to_string(),
                 message: e,
                 variants_created: None,
@@ -734,51 +1020,65 @@ async fn handle_gcs_event(
 
     // Validate + decode + process (CPU)
     let processing_start = Instant::now();
-    let processed_or_rule_err: Result<ProcessedImage, RuleError> =
-        match tokio::task::spawn_blocking(move || {
-            let (img, _fmt) = validate_and_decode(&image_bytes)?;
-            process_dynamic_image(img).map_err(|msg| RuleError {
-                code: RuleCode::DecodeFailed,
-                message: msg,
-                stage: "processing",
-            })
-        })
-        .await
-        {
-            Ok(r) => r,
-            Err(e) => {
-                error!(error = %e, "Task panicked");
-                let manifest = failed_manifest(
-                    media_id.clone(),
-                    "INTERNAL_ERROR",
-                    "Internal processing error".to_string(),
-                    "processing",
-                );
-                let _ = upload_manifest(client, &media_id, &manifest).await;
+    let blocking_settings = Arc::clone(settings);
+    let role = gcs_data
+        .metadata
+        .as_ref()
+        .and_then(|m| m.get("role"))
+        .cloned();
+    let processed_or_rule_err = match tokio::task::spawn_blocking(move || {
+        let decoded = validate_and_decode(original, blocking_settings.passthrough_original)?;
+        // SVG consumers that can't inline the sanitized markup get PNGs
+        let png_fallbacks = decoded.extras.sanitized_svg.is_some();
+        let processed = process_dynamic_image(
+            decoded.img,
+            &blocking_settings,
+            role.as_deref(),
+            png_fallbacks,
+        )
+        .map_err(|msg| RuleError {
+            code: RuleCode::DecodeFailed,
+            message: msg,
+            stage: "processing",
+        })?;
+        Ok::<_, RuleError>((processed, decoded.fmt, decoded.extras))
+    })
+    .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            error!(error = %e, "Task panicked");
+            let manifest = failed_manifest(
+                media_id.clone(),
+                error_codes::INTERNAL_ERROR,
+                "Internal processing error".to_string(),
+                "processing",
+            );
+            let _ = publish_manifest(client, callback, manifests, &media_id, &manifest).await;
 
-                return HttpResponse::InternalServerError().json(FunctionResponse {
-                    status: "error".to_string(),
-                    message: "Internal processing error".to_string(),
-                    variants_created: None,
-                });
-            }
-        };
+            return Outcome::Retry(FunctionResponse {
+                status: "error".to_string(),
+                message: "Internal processing error".to_string(),
+                variants_created: None,
+            });
+        }
+    };
 
     let processing_ms = processing_start.elapsed().as_millis() as u64;
 
-    let processed = match processed_or_rule_err {
+    let (processed, fmt, extras) = match processed_or_rule_err {
         Ok(p) => p,
         Err(rule_err) => {
             error!(error = ?rule_err, "Validation/processing failed");
 
             let manifest = failed_manifest_from_rule(media_id.clone(), rule_err);
-            let _ = upload_manifest(client, &media_id, &manifest).await;
+            let _ = publish_manifest(client, callback, manifests, &media_id, &manifest).await;
 
             // Business rules not met -> delete immediately (best effort)
             // (We delete the original object in the upload bucket.)
             delete_original_best_effort(client, &gcs_data.bucket, &gcs_data.name).await;
 
-            return HttpResponse::Ok().json(FunctionResponse {
+            return Outcome::Done(FunctionResponse {
                 status: "skipped".to_string(),
                 message: "Business rule validation failed".to_string(),
                 variants_created: None,
@@ -786,13 +1086,83 @@ async fn handle_gcs_event(
         }
     };
 
+    // Content moderation (optional): a rejected image is never published
+    if let Some(moderation) = ctx.moderation.as_ref() {
+        let preview = processed
+            .variants
+            .iter()
+            .find(|v| v.suffix == moderation::PREVIEW_SUFFIX)
+            .or_else(|| processed.variants.last());
+
+        if let Some(preview) = preview {
+            match moderation.check(&media_id, &preview.data).await {
+                Ok(verdict) if verdict.allowed => {}
+                Ok(verdict) => {
+                    let reason = verdict.reason.unwrap_or_else(|| "unspecified".to_string());
+                    info!(media_id = %media_id, reason = %reason, "Image rejected by moderation");
+
+                    let manifest = failed_manifest_from_rule(
+                        media_id.clone(),
+                        RuleError {
+                            code: RuleCode::ContentRejected,
+                            message: format!("Rejected by content policy: {}", reason),
+                            stage: "moderation",
+                        },
+                    );
+                    let _ =
+                        publish_manifest(client, callback, manifests, &media_id, &manifest).await;
+
+                    // Policy violation -> delete immediately (best effort)
+                    delete_original_best_effort(client, &gcs_data.bucket, &gcs_data.name).await;
+
+                    return Outcome::Done(FunctionResponse {
+                        status: "skipped".to_string(),
+                        message: "Rejected by content moderation".to_string(),
+                        variants_created: None,
+                    });
+                }
+                Err(e) if moderation.fail_open() => {
+                    warn!(error = %e, "Moderation unavailable, publishing (fail-open)");
+                }
+                Err(e) => {
+                    error!(error = %e, "Moderation unavailable");
+
+                    let manifest = failed_manifest(
+                        media_id.clone(),
+                        error_codes::MODERATION_ERROR,
+                        e.clone(),
+                        "moderation",
+                    );
+                    let _ =
+                        publish_manifest(client, callback, manifests, &media_id, &manifest).await;
+
+                    return Outcome::Retry(FunctionResponse {
+                        status: "error".to_string(),
+                        message: e,
+                        variants_created: None,
+                    });
+                }
+            }
+        }
+    }
+
     // Output naming
     let stem = Path::new(&gcs_data.name)
         .file_stem()
         .and_then(|n| n.to_str())
         .unwrap_or(&gcs_data.name);
+    let variant_name = |suffix: &str, format: &str| {
+        settings
+            .output_names
+            .render(&media_id, stem, suffix, format, PIPELINE_VERSION)
+    };
+    let original_name = |format: &str| {
+        settings
+            .output_names
+            .render_original(&media_id, stem, format, PIPELINE_VERSION)
+    };
 
-    let out_bucket = output_bucket();
+    let out_bucket = dest.output_bucket;
 
     // Manifest variant info
     let variant_info: Vec<ManifestVariant> = processed
@@ -802,25 +1172,71 @@ async fn handle_gcs_event(
             let size: u32 = v.suffix.parse().unwrap_or(0);
             ManifestVariant {
                 size,
-                path: format!("variants/{}/{}_{}.webp", media_id, stem, v.suffix),
+                path: variant_name(&v.suffix, "webp"),
                 width: v.width,
                 height: v.height,
-                file_size_bytes: v.data.len(), // ← Add this line
+                file_size_bytes: v.data.len(),
+                sha256: sha256_hex(&v.data),
+                quality: v.quality.round() as u32,
+                has_alpha: v.has_alpha,
+                flattened_onto: v.flattened_onto.map(hex_color),
+                png_path: v.png.as_ref().map(|_| variant_name(&v.suffix, "png")),
+                png_sha256: v.png.as_deref().map(sha256_hex),
             }
         })
         .collect();
 
+    let svg_info = extras.sanitized_svg.as_ref().map(|markup| ManifestFile {
+        path: original_name("svg"),
+        file_size_bytes: markup.len(),
+        sha256: sha256_hex(markup),
+    });
+
+    let passthrough_file = fmt.passthrough_file();
+    let passthrough_info =
+        extras
+            .passthrough
+            .as_ref()
+            .zip(passthrough_file)
+            .map(|(copy, (ext, _))| ManifestFile {
+                path: original_name(ext),
+                file_size_bytes: copy.len(),
+                sha256: sha256_hex(copy),
+            });
+
+    // Everything to write to the output bucket: (object name, bytes, content type)
+    let mut outputs: Vec<(String, Vec<u8>, &'static str)> = Vec::new();
+    for variant in processed.variants {
+        outputs.push((
+            variant_name(&variant.suffix, "webp"),
+            variant.data,
+            "image/webp",
+        ));
+        if let Some(png) = variant.png {
+            outputs.push((variant_name(&variant.suffix, "png"), png, "image/png"));
+        }
+    }
+    if let (Some(markup), Some(info)) = (extras.sanitized_svg, svg_info.as_ref()) {
+        outputs.push((info.path.clone(), markup, "image/svg+xml"));
+    }
+    if let (Some(copy), Some(info), Some((_, content_type))) = (
+        extras.passthrough,
+        passthrough_info.as_ref(),
+        passthrough_file,
+    ) {
+        outputs.push((info.path.clone(), copy, content_type));
+    }
+
     // Upload futures for variants
-    let upload_futures: Vec<_> = processed
-        .variants
+    let upload_futures: Vec<_> = outputs
         .into_iter()
-        .map(|variant| {
-            let output_name = format!("variants/{}/{}_{}.webp", media_id, stem, variant.suffix);
-            let bucket = out_bucket.clone();
+        .map(|(output_name, data, content_type)| {
             let client = client.clone();
 
             async move {
-                upload_to_gcs(&client, &bucket, &output_name, variant.data, "image/webp").await?;
+                client
+                    .upload(out_bucket, &output_name, data, content_type)
+                    .await?;
                 Ok::<String, String>(output_name)
             }
         })
@@ -845,13 +1261,13 @@ async fn handle_gcs_event(
 
         let manifest = failed_manifest(
             media_id.clone(),
-            "UPLOAD_ERROR",
+            error_codes::UPLOAD_ERROR,
             format!("Some variant uploads failed: {:?}", errors),
             "upload",
         );
-        let _ = upload_manifest(client, &media_id, &manifest).await;
+        let _ = publish_manifest(client, callback, manifests, &media_id, &manifest).await;
 
-        return HttpResponse::InternalServerError().json(FunctionResponse {
+        return Outcome::Retry(FunctionResponse {
             status: "partial_error".to_string(),
             message: format!("Some uploads failed: {:?}", errors),
             variants_created: Some(created),
@@ -859,16 +1275,23 @@ async fn handle_gcs_event(
     }
 
     // Build ready manifest (we will overwrite once to ensure upload_ms/total_ms are correct)
-    let mut ready_manifest = Manifest::Ready {
+    let mut ready_manifest = Manifest::Ready(Box::new(ReadyManifest {
         media_id: media_id.clone(),
         pipeline_version: PIPELINE_VERSION.to_string(),
         updated_at: now_iso8601(),
+        kind: fmt.kind().to_string(),
+        tenant: dest.tenant.map(str::to_string),
+        output_bucket: Some(out_bucket.to_string()),
+        // A rendered page or SVG size says nothing about the original
         original: ManifestOriginal {
             bucket: gcs_data.bucket.clone(),
             path: gcs_data.name.clone(),
-            width: Some(processed.original_width),
-            height: Some(processed.original_height),
+            sha256: original_sha256,
+            width: fmt.has_pixel_size().then_some(processed.original_width),
+            height: fmt.has_pixel_size().then_some(processed.original_height),
         },
+        svg: svg_info,
+        passthrough: passthrough_info,
         variants: variant_info,
         metrics: ManifestMetrics {
             total_ms: 0,
@@ -876,22 +1299,23 @@ async fn handle_gcs_event(
             processing_ms,
             upload_ms: 0,
             encoder: "webp".to_string(),
-            quality: WEBP_QUALITY as u32,
+            quality: settings.default_quality.round() as u32,
+            resize_filter: settings.resize_filter.as_str().to_string(),
         },
-    };
+    }));
 
     // Upload manifest once (then overwrite with accurate upload_ms/total_ms)
-    if let Err(e) = upload_manifest(client, &media_id, &ready_manifest).await {
+    if let Err(e) = upload_manifest(client, manifests, &media_id, &ready_manifest).await {
         error!(error = %e, "Failed to upload manifest");
         let manifest = failed_manifest(
             media_id.clone(),
-            "UPLOAD_ERROR",
+            error_codes::UPLOAD_ERROR,
             format!("Manifest upload failed: {}", e),
             "upload",
         );
-        let _ = upload_manifest(client, &media_id, &manifest).await;
+        let _ = publish_manifest(client, callback, manifests, &media_id, &manifest).await;
 
-        return HttpResponse::InternalServerError().json(FunctionResponse {
+        return Outcome::Retry(FunctionResponse {
             status: "error".to_string(),
             message: format!("Manifest upload failed: {}", e),
             variants_created: Some(created),
@@ -903,14 +1327,15 @@ async fn handle_gcs_event(
     let upload_ms = upload_start.elapsed().as_millis() as u64;
     let total_ms = total_start.elapsed().as_millis() as u64;
 
-    if let Manifest::Ready { metrics, .. } = &mut ready_manifest {
-        metrics.upload_ms = upload_ms;
-        metrics.total_ms = total_ms;
+    if let Manifest::Ready(ready) = &mut ready_manifest {
+        ready.metrics.upload_ms = upload_ms;
+        ready.metrics.total_ms = total_ms;
     }
 
-    if let Err(e) = upload_manifest(client, &media_id, &ready_manifest).await {
+    if let Err(e) = publish_manifest(client, callback, manifests, &media_id, &ready_manifest).await
+    {
         error!(error = %e, "Failed to upload final manifest with metrics");
-        return HttpResponse::InternalServerError().json(FunctionResponse {
+        return Outcome::Retry(FunctionResponse {
             status: "error".to_string(),
             message: format!("Failed to upload final manifest with metrics: {}", e),
             variants_created: Some(created),
@@ -926,15 +1351,19 @@ async fn handle_gcs_event(
         "Successfully processed image"
     );
 
-    HttpResponse::Ok().json(FunctionResponse {
+    Outcome::Done(FunctionResponse {
         status: "success".to_string(),
         message: format!("Created {} variants", created.len()),
         variants_created: Some(created),
     })
 }
 
-async fn health() -> HttpResponse {
-    HttpResponse::Ok().json(serde_json::json!({ "status": "healthy" }))
+async fn health(limiter: web::Data<JobLimiter>) -> HttpResponse {
+    HttpResponse::Ok().json(serde_json::json!({
+        "status": "healthy",
+        "in_flight": limiter.in_flight(),
+        "max_concurrent_jobs": limiter.max_jobs(),
+    }))
 }
 
 #[tokio::main]
@@ -965,11 +1394,33 @@ async fn main() -> std::io::Result<()> {
         .flatten_event(true)
         .init();
 
-    let gcs_config = ClientConfig::default()
-        .with_auth()
-        .await
-        .expect("Failed to create GCS client config");
-    let gcs_client = Arc::new(GcsClient::new(gcs_config));
+    let mode = RuntimeMode::from_env();
+
+    // Local mode has no credentials; its GCS client is never called
+    let gcs = match mode {
+        RuntimeMode::Local => GcsClient::new(ClientConfig::default()),
+        _ => GcsClient::new(
+            ClientConfig::default()
+                .with_auth()
+                .await
+                .expect("Failed to create GCS client config"),
+        ),
+    };
+    let local_store = matches!(mode, RuntimeMode::Local).then(LocalStore::from_env);
+    let ctx = web::Data::new(PipelineContext {
+        storage: match &local_store {
+            Some(store) => Storage::Local(store.clone()),
+            None => Storage::Gcs(Box::new(gcs.clone())),
+        },
+        gcs,
+        callback: CallbackNotifier::from_env(),
+        moderation: ModerationClient::from_env(),
+        settings: Arc::new(PipelineSettings::from_env()),
+        tenants: TenantRouter::from_env(output_bucket(), manifest_bucket()),
+    });
+    let limiter = web::Data::new(JobLimiter::from_env());
+    let reprocess_auth = web::Data::new(ReprocessAuth::from_env());
+    let sweep_guard = web::Data::new(migrate::SweepGuard::default());
 
     let port: u16 = std::env::var("PORT")
         .unwrap_or_else(|_| "8080".to_string())
@@ -978,19 +1429,125 @@ async fn main() -> std::io::Result<()> {
 
     info!(
         port = port,
-        output_bucket = %output_bucket(),
-        manifest_bucket = %manifest_bucket(),
+        mode = ?mode,
+        output_bucket = ctx.tenants.default_output(),
+        manifest_bucket = ctx.tenants.default_manifest(),
+        tenants = ctx.tenants.tenant_count(),
         rayon_threads = num_threads,
+        max_concurrent_jobs = limiter.max_jobs(),
+        callback_url = ctx.callback.as_ref().map(|c| c.url()).unwrap_or("disabled"),
+        moderation_url = ctx.moderation.as_ref().map(|m| m.url()).unwrap_or("disabled"),
+        resize_filter = ctx.settings.resize_filter.as_str(),
+        output_name_template = ctx.settings.output_names.as_str(),
+        webp_quality = ctx.settings.default_quality,
+        reprocess_enabled = reprocess_auth.enabled(),
         "Starting image processing function"
     );
 
-    HttpServer::new(move || {
-        App::new()
-            .app_data(web::Data::new(gcs_client.clone()))
-            .route("/", web::post().to(handle_gcs_event))
-            .route("/health", web::get().to(health))
-    })
-    .bind(("0.0.0.0", port))?
-    .run()
-    .await
+    match mode {
+        RuntimeMode::Http => {
+            HttpServer::new(move || {
+                App::new()
+                    .app_data(ctx.clone())
+                    .app_data(limiter.clone())
+                    .app_data(reprocess_auth.clone())
+                    .app_data(sweep_guard.clone())
+                    .route("/", web::post().to(handle_gcs_event))
+                    .route("/reprocess", web::post().to(reprocess::handle_reprocess))
+                    .route("/migrate", web::post().to(migrate::handle_migrate))
+                    .route("/health", web::get().to(health))
+                    .route("/selftest", web::get().to(selftest::handle_selftest))
+            })
+            .bind(("0.0.0.0", port))?
+            .run()
+            .await
+        }
+        RuntimeMode::PubSubPull => {
+            let config = pubsub_worker::PullWorkerConfig::from_env()
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+
+            // Only /health, /reprocess and /migrate are served; events come from the subscription.
+            let http_ctx = ctx.clone();
+            let http_limiter = limiter.clone();
+            let server = HttpServer::new(move || {
+                App::new()
+                    .app_data(http_ctx.clone())
+                    .app_data(http_limiter.clone())
+                    .app_data(reprocess_auth.clone())
+                    .app_data(sweep_guard.clone())
+                    .route("/reprocess", web::post().to(reprocess::handle_reprocess))
+                    .route("/migrate", web::post().to(migrate::handle_migrate))
+                    .route("/health", web::get().to(health))
+                    .route("/selftest", web::get().to(selftest::handle_selftest))
+            })
+            .bind(("0.0.0.0", port))?
+            .run();
+
+            let worker = pubsub_worker::run(config, ctx.into_inner(), limiter.get_ref().clone());
+
+            tokio::select! {
+                res = server => res,
+                res = worker => res.map_err(std::io::Error::other),
+            }
+        }
+        RuntimeMode::Local => {
+            let store = local_store.expect("built for local mode");
+            let watcher = web::Data::new(local::LocalWatcher::from_env(store));
+            info!(
+                root = %watcher.store().root().display(),
+                upload_bucket = watcher.upload_bucket(),
+                "Local mode: watching the upload folder"
+            );
+
+            let http_ctx = ctx.clone();
+            let http_limiter = limiter.clone();
+            let http_watcher = watcher.clone();
+            let server = HttpServer::new(move || {
+                App::new()
+                    .app_data(http_ctx.clone())
+                    .app_data(http_limiter.clone())
+                    .app_data(http_watcher.clone())
+                    // Larger bodies are refused, as the signed upload URL would
+                    .app_data(web::PayloadConfig::new(MAX_DOCUMENT_BYTES))
+                    .route("/health", web::get().to(health))
+                    .route("/selftest", web::get().to(selftest::handle_selftest))
+                    .route(
+                        "/local/{bucket}/{name:.*}",
+                        web::get().to(local::handle_get),
+                    )
+                    .route(
+                        "/local/{bucket}/{name:.*}",
+                        web::put().to(local::handle_upload),
+                    )
+                    .route(
+                        "/local/{bucket}/{name:.*}",
+                        web::post().to(local::handle_upload),
+                    )
+            })
+            .bind(("0.0.0.0", port))?
+            .run();
+
+            let watch = watcher
+                .into_inner()
+                .watch(ctx.into_inner(), limiter.get_ref().clone());
+
+            tokio::select! {
+                res = server => res,
+                () = watch => Ok(()),
+            }
+        }
+        RuntimeMode::Migrate => {
+            let report = migrate::sweep(&ctx, &limiter)
+                .await
+                .map_err(std::io::Error::other)?;
+
+            if report.failed > 0 {
+                return Err(std::io::Error::other(format!(
+                    "{} of {} outdated manifests failed to migrate",
+                    report.failed, report.outdated
+                )));
+            }
+            Ok(())
+        }
+    }
 }
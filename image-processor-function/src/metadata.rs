@@ -0,0 +1,135 @@
+// =============================================================================
+// Lossless metadata stripping
+// =============================================================================
+//
+// Used for the passthrough copy of the original: the encoded image data is
+// copied untouched and only metadata containers are dropped (EXIF, XMP, IPTC,
+// comments and text chunks). Color profiles are kept so the copy renders the
+// same as the upload.
+
+/// JPEG: drop APP1 (EXIF/XMP), APP13 (IPTC) and COM segments.
+pub fn strip_jpeg(bytes: &[u8]) -> Result<Vec<u8>, String> {
+    if bytes.len() < 4 || bytes[..2] != [0xFF, 0xD8] {
+        return Err("Not a JPEG".to_string());
+    }
+
+    let mut out = Vec::with_capacity(bytes.len());
+    out.extend_from_slice(&bytes[..2]);
+    let mut pos = 2;
+
+    while pos + 1 < bytes.len() {
+        if bytes[pos] != 0xFF {
+            return Err(format!("Malformed JPEG segment at byte {}", pos));
+        }
+        let marker = bytes[pos + 1];
+
+        // Fill bytes before a marker
+        if marker == 0xFF {
+            pos += 1;
+            continue;
+        }
+
+        // Standalone markers carry no length
+        if marker == 0x01 || (0xD0..=0xD7).contains(&marker) {
+            out.extend_from_slice(&bytes[pos..pos + 2]);
+            pos += 2;
+            continue;
+        }
+
+        // Start of scan / end of image: the rest is entropy-coded data
+        if marker == 0xDA || marker == 0xD9 {
+            out.extend_from_slice(&bytes[pos..]);
+            return Ok(out);
+        }
+
+        if pos + 4 > bytes.len() {
+            return Err("Truncated JPEG segment header".to_string());
+        }
+        let len = u16::from_be_bytes([bytes[pos + 2], bytes[pos + 3]]) as usize;
+        let end = pos + 2 + len;
+        if len < 2 || end > bytes.len() {
+            return Err("Truncated JPEG segment".to_string());
+        }
+
+        if !matches!(marker, 0xE1 | 0xED | 0xFE) {
+            out.extend_from_slice(&bytes[pos..end]);
+        }
+        pos = end;
+    }
+
+    Err("JPEG has no image data".to_string())
+}
+
+/// PNG: drop eXIf, tEXt, zTXt, iTXt and tIME chunks.
+pub fn strip_png(bytes: &[u8]) -> Result<Vec<u8>, String> {
+    const SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+    if !bytes.starts_with(SIGNATURE) {
+        return Err("Not a PNG".to_string());
+    }
+
+    let mut out = Vec::with_capacity(bytes.len());
+    out.extend_from_slice(SIGNATURE);
+    let mut pos = SIGNATURE.len();
+
+    while pos + 8 <= bytes.len() {
+        let len = u32::from_be_bytes(bytes[pos..pos + 4].try_into().unwrap()) as usize;
+        let chunk_type = &bytes[pos + 4..pos + 8];
+        // length + type + data + crc
+        let end = pos + 12 + len;
+        if end > bytes.len() {
+            return Err("Truncated PNG chunk".to_string());
+        }
+
+        if !matches!(chunk_type, b"eXIf" | b"tEXt" | b"zTXt" | b"iTXt" | b"tIME") {
+            out.extend_from_slice(&bytes[pos..end]);
+        }
+        pos = end;
+
+        if chunk_type == b"IEND" {
+            return Ok(out);
+        }
+    }
+
+    Err("PNG has no IEND chunk".to_string())
+}
+
+/// WebP: drop EXIF and XMP chunks and clear their VP8X flags.
+pub fn strip_webp(bytes: &[u8]) -> Result<Vec<u8>, String> {
+    if bytes.len() < 12 || &bytes[..4] != b"RIFF" || &bytes[8..12] != b"WEBP" {
+        return Err("Not a WebP".to_string());
+    }
+
+    const EXIF_FLAG: u8 = 0x08;
+    const XMP_FLAG: u8 = 0x04;
+
+    let mut out = Vec::with_capacity(bytes.len());
+    out.extend_from_slice(&bytes[..12]);
+    let mut pos = 12;
+
+    while pos + 8 <= bytes.len() {
+        let fourcc = &bytes[pos..pos + 4];
+        let len = u32::from_le_bytes(bytes[pos + 4..pos + 8].try_into().unwrap()) as usize;
+        // Chunks are padded to an even size
+        let end = (pos + 8 + len + (len & 1)).min(bytes.len());
+        if pos + 8 + len > bytes.len() {
+            return Err("Truncated WebP chunk".to_string());
+        }
+
+        match fourcc {
+            b"EXIF" | b"XMP " => {}
+            b"VP8X" => {
+                let start = out.len();
+                out.extend_from_slice(&bytes[pos..end]);
+                if len > 0 {
+                    out[start + 8] &= !(EXIF_FLAG | XMP_FLAG);
+                }
+            }
+            _ => out.extend_from_slice(&bytes[pos..end]),
+        }
+        pos = end;
+    }
+
+    let riff_size = (out.len() - 8) as u32;
+    out[4..8].copy_from_slice(&riff_size.to_le_bytes());
+    Ok(out)
+}
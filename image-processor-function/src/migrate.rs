@@ -0,0 +1,233 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use google_cloud_storage::http::objects::{
+    download::Range, get::GetObjectRequest, list::ListObjectsRequest,
+};
+use serde::Deserialize;
+use shared_domain::manifest::ManifestState;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tracing::{error, info, warn};
+
+use crate::limiter::JobLimiter;
+use crate::reprocess::{reply, stored_object, ReprocessAuth};
+use crate::{process_gcs_object, Outcome, PipelineContext, PIPELINE_VERSION};
+
+// =============================================================================
+// Pipeline-version migration sweep
+// =============================================================================
+//
+// When widths, quality or encoders change, PIPELINE_VERSION is bumped and the
+// existing media has to catch up. The sweep lists every manifest, and for each
+// ready manifest written by an older pipeline version it runs the regular
+// pipeline again on the original (which stays in the upload bucket), rewriting
+// the variants and the manifest with the current settings. With TENANT_ROUTES
+// set, every tenant's manifest bucket is swept as well.
+//
+// Two ways to start it:
+//
+//   PROCESSOR_MODE=migrate              run one sweep and exit (e.g. a Cloud Run job)
+//   POST /migrate                       start a sweep in the background (same auth
+//                                       as /reprocess); 409 while one is running
+//
+// Objects are migrated one at a time and each takes a job permit, so a sweep
+// running next to live traffic never exceeds MAX_CONCURRENT_JOBS.
+
+const MANIFEST_SUFFIX: &str = "/manifest.json";
+
+/// The fields of a stored manifest the sweep needs.
+#[derive(Deserialize)]
+struct StoredManifest {
+    state: ManifestState,
+    pipeline_version: String,
+    original: Option<StoredOriginal>,
+}
+
+#[derive(Deserialize)]
+struct StoredOriginal {
+    bucket: String,
+    path: String,
+}
+
+#[derive(Debug, Default)]
+pub struct SweepReport {
+    pub scanned: usize,
+    pub outdated: usize,
+    pub migrated: usize,
+    pub failed: usize,
+    /// Unreadable manifests, failed manifests and missing originals
+    pub skipped: usize,
+}
+
+/// "v3" -> 3. Unparseable versions sort before every real one.
+fn version_number(version: &str) -> u32 {
+    version.trim_start_matches('v').parse().unwrap_or(0)
+}
+
+fn is_outdated(version: &str) -> bool {
+    version_number(version) < version_number(PIPELINE_VERSION)
+}
+
+pub async fn sweep(ctx: &PipelineContext, limiter: &JobLimiter) -> Result<SweepReport, String> {
+    let mut report = SweepReport::default();
+
+    // Tenants have their own manifest buckets
+    for bucket in ctx.tenants.manifest_buckets() {
+        sweep_bucket(ctx, limiter, bucket, &mut report).await?;
+    }
+
+    info!(?report, "Migration sweep finished");
+    Ok(report)
+}
+
+async fn sweep_bucket(
+    ctx: &PipelineContext,
+    limiter: &JobLimiter,
+    bucket: &str,
+    report: &mut SweepReport,
+) -> Result<(), String> {
+    let mut page_token: Option<String> = None;
+
+    info!(bucket = %bucket, current = PIPELINE_VERSION, "Starting migration sweep");
+
+    loop {
+        let page = ctx
+            .gcs
+            .list_objects(&ListObjectsRequest {
+                bucket: bucket.to_string(),
+                page_token: page_token.take(),
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| format!("Failed to list manifests in {}: {}", bucket, e))?;
+
+        for object in page.items.unwrap_or_default() {
+            if !object.name.ends_with(MANIFEST_SUFFIX) {
+                continue;
+            }
+            report.scanned += 1;
+            migrate_one(ctx, limiter, bucket, &object.name, report).await;
+        }
+
+        match page.next_page_token {
+            Some(token) if !token.is_empty() => page_token = Some(token),
+            _ => return Ok(()),
+        }
+    }
+}
+
+async fn migrate_one(
+    ctx: &PipelineContext,
+    limiter: &JobLimiter,
+    bucket: &str,
+    manifest_name: &str,
+    report: &mut SweepReport,
+) {
+    let manifest = match ctx
+        .gcs
+        .download_object(
+            &GetObjectRequest {
+                bucket: bucket.to_string(),
+                object: manifest_name.to_string(),
+                ..Default::default()
+            },
+            &Range::default(),
+        )
+        .await
+        .map_err(|e| e.to_string())
+        .and_then(|bytes| {
+            serde_json::from_slice::<StoredManifest>(&bytes).map_err(|e| e.to_string())
+        }) {
+        Ok(m) => m,
+        Err(e) => {
+            warn!(error = %e, manifest = manifest_name, "Skipping unreadable manifest");
+            report.skipped += 1;
+            return;
+        }
+    };
+
+    if !is_outdated(&manifest.pipeline_version) {
+        return;
+    }
+    report.outdated += 1;
+
+    // Failed manifests don't reference the original; nothing to regenerate from
+    let original = match (manifest.state, manifest.original) {
+        (ManifestState::Ready, Some(original)) => original,
+        _ => {
+            report.skipped += 1;
+            return;
+        }
+    };
+
+    let gcs_data = match stored_object(&ctx.gcs, &original.bucket, &original.path).await {
+        Ok(data) => data,
+        Err(e) => {
+            warn!(
+                error = %e,
+                bucket = %original.bucket,
+                object = %original.path,
+                "Original unavailable, skipping"
+            );
+            report.skipped += 1;
+            return;
+        }
+    };
+
+    let _permit = limiter.acquire().await;
+
+    match process_gcs_object(ctx, gcs_data).await {
+        Outcome::Done(resp) if resp.status == "success" => report.migrated += 1,
+        Outcome::Done(resp) | Outcome::Retry(resp) => {
+            error!(
+                manifest = manifest_name,
+                status = %resp.status,
+                detail = %resp.message,
+                "Migration failed"
+            );
+            report.failed += 1;
+        }
+    }
+}
+
+/// Prevents overlapping sweeps started over HTTP.
+#[derive(Default)]
+pub struct SweepGuard {
+    running: AtomicBool,
+}
+
+pub async fn handle_migrate(
+    req: HttpRequest,
+    auth: web::Data<ReprocessAuth>,
+    guard: web::Data<SweepGuard>,
+    ctx: web::Data<PipelineContext>,
+    limiter: web::Data<JobLimiter>,
+) -> HttpResponse {
+    if !auth.enabled() {
+        return HttpResponse::NotFound().finish();
+    }
+    if !auth.allows(&req) {
+        warn!("Rejected migrate request: missing or invalid token");
+        return HttpResponse::Unauthorized().json(reply("error", "Unauthorized"));
+    }
+
+    if guard.running.swap(true, Ordering::SeqCst) {
+        return HttpResponse::Conflict()
+            .json(reply("busy", "A migration sweep is already running"));
+    }
+
+    let guard: Arc<SweepGuard> = guard.into_inner();
+    let ctx: Arc<PipelineContext> = ctx.into_inner();
+    let limiter = limiter.get_ref().clone();
+
+    tokio::spawn(async move {
+        if let Err(e) = sweep(&ctx, &limiter).await {
+            error!(error = %e, "Migration sweep aborted");
+        }
+        guard.running.store(false, Ordering::SeqCst);
+    });
+
+    HttpResponse::Accepted().json(reply(
+        "accepted",
+        format!("Migrating manifests older than {}", PIPELINE_VERSION),
+    ))
+}
@@ -0,0 +1,117 @@
+use serde::Deserialize;
+use std::time::Duration;
+use tracing::warn;
+
+// =============================================================================
+// Content moderation hook (optional)
+// =============================================================================
+//
+// When MODERATION_URL is set, every decoded image is checked before anything
+// is published. The processor POSTs one preview variant (WebP, raw body) to
+// the endpoint and expects a JSON verdict:
+//
+//   request:  Content-Type: image/webp, X-Media-Id: <media_id>
+//   response: { "allowed": false, "reason": "nudity" }
+//
+// A rejected image gets a failed manifest with code CONTENT_REJECTED and is
+// never uploaded. The endpoint can wrap a hosted API or a local NSFW model;
+// the processor only needs the verdict.
+//
+//   MODERATION_URL=https://...          endpoint (hook disabled when unset)
+//   MODERATION_TOKEN=...                optional bearer token
+//   MODERATION_TIMEOUT_SECS=10
+//   MODERATION_FAIL_OPEN=false          publish anyway when the endpoint is down
+
+const DEFAULT_TIMEOUT_SECS: u64 = 10;
+
+/// Variant sent for review (big enough to judge, small enough to send).
+pub const PREVIEW_SUFFIX: &str = "768";
+
+#[derive(Debug, Deserialize)]
+pub struct Verdict {
+    pub allowed: bool,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+pub struct ModerationClient {
+    http: reqwest::Client,
+    url: String,
+    token: Option<String>,
+    fail_open: bool,
+}
+
+impl ModerationClient {
+    /// Returns `None` when moderation is not configured.
+    pub fn from_env() -> Option<Self> {
+        let url = std::env::var("MODERATION_URL")
+            .ok()
+            .filter(|s| !s.trim().is_empty())?;
+
+        let timeout_secs = std::env::var("MODERATION_TIMEOUT_SECS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_TIMEOUT_SECS);
+
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(timeout_secs))
+            .build()
+            .ok()?;
+
+        Some(Self {
+            http,
+            url,
+            token: std::env::var("MODERATION_TOKEN")
+                .ok()
+                .filter(|s| !s.is_empty()),
+            fail_open: matches!(
+                std::env::var("MODERATION_FAIL_OPEN").as_deref(),
+                Ok("1") | Ok("true")
+            ),
+        })
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn fail_open(&self) -> bool {
+        self.fail_open
+    }
+
+    /// Ask the endpoint for a verdict. `Err` means no verdict could be obtained.
+    pub async fn check(&self, media_id: &str, preview_webp: &[u8]) -> Result<Verdict, String> {
+        let mut request = self
+            .http
+            .post(&self.url)
+            .header("Content-Type", "image/webp")
+            .header("X-Media-Id", media_id)
+            .body(preview_webp.to_vec());
+
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+
+        let resp = request
+            .send()
+            .await
+            .map_err(|e| format!("Moderation request failed: {}", e))?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            warn!(
+                media_id,
+                status = status.as_u16(),
+                "Moderation endpoint error"
+            );
+            return Err(format!("Moderation endpoint returned {}", status));
+        }
+
+        let body = resp
+            .bytes()
+            .await
+            .map_err(|e| format!("Failed to read moderation response: {}", e))?;
+
+        serde_json::from_slice(&body).map_err(|e| format!("Invalid moderation verdict: {}", e))
+    }
+}
@@ -0,0 +1,59 @@
+use image::DynamicImage;
+use std::io::Read;
+use std::process::{Command, Stdio};
+
+// =============================================================================
+// PDF first-page rendering
+// =============================================================================
+//
+// PDFs are accepted as document attachments. Only the first page is rendered
+// (poppler's `pdftoppm`, installed in the runtime image) to a PNG whose longer
+// side is PDF_RENDER_SIZE pixels; that raster then goes through the normal
+// variant pipeline as the document's preview.
+//
+//   PDF_RENDER_SIZE=1600                longer side of the rendered page (px)
+
+const DEFAULT_RENDER_SIZE: u32 = 1600;
+
+pub fn render_size() -> u32 {
+    std::env::var("PDF_RENDER_SIZE")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .filter(|&n: &u32| n > 0)
+        .unwrap_or(DEFAULT_RENDER_SIZE)
+}
+
+/// Render page 1 of the PDF read from `reader` (blocking: runs a subprocess).
+pub fn render_first_page(mut reader: impl Read, size: u32) -> Result<DynamicImage, String> {
+    let out_dir = tempfile::tempdir().map_err(|e| format!("Failed to create temp dir: {e}"))?;
+    let out_root = out_dir.path().join("page");
+
+    // `-` reads the PDF from stdin; `-singlefile` writes `<root>.png` without a page suffix.
+    let mut child = Command::new("pdftoppm")
+        .args(["-f", "1", "-l", "1", "-singlefile", "-png", "-scale-to"])
+        .arg(size.to_string())
+        .arg("-")
+        .arg(&out_root)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to start pdftoppm: {e}"))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // A broken pipe means pdftoppm bailed early; its exit status says why.
+        let _ = std::io::copy(&mut reader, &mut stdin);
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("pdftoppm failed: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to render PDF: {}", stderr.trim()));
+    }
+
+    image::open(out_root.with_extension("png"))
+        .map_err(|e| format!("Failed to decode rendered page: {e}"))
+}
@@ -0,0 +1,179 @@
+use google_cloud_pubsub::client::{Client as PubSubClient, ClientConfig as PubSubClientConfig};
+use google_cloud_pubsub::subscriber::ReceivedMessage;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+use crate::events::{storage_event_type, GCS_FINALIZED};
+use crate::limiter::JobLimiter;
+use crate::{process_gcs_object, GcsObjectData, Outcome, PipelineContext};
+
+// =============================================================================
+// Pub/Sub pull-worker mode
+// =============================================================================
+//
+// Alternative to the HTTP CloudEvent push endpoint for self-managed VMs: the
+// processor pulls GCS notifications (JSON_API_V1 payload format) from a
+// subscription and runs the same pipeline.
+//
+// While an image is being processed the ack deadline is extended periodically,
+// so long jobs are not redelivered to another worker mid-flight.
+
+/// GCS notification attribute carrying the event type.
+const EVENT_TYPE_ATTRIBUTE: &str = "eventType";
+
+const DEFAULT_MAX_MESSAGES: i32 = 1;
+const DEFAULT_ACK_DEADLINE_SECS: i32 = 60;
+
+/// Back-off after a failed pull, so a broken subscription does not spin.
+const PULL_ERROR_BACKOFF: Duration = Duration::from_secs(5);
+
+pub struct PullWorkerConfig {
+    pub subscription: String,
+    pub max_messages: i32,
+    pub ack_deadline_secs: i32,
+}
+
+impl PullWorkerConfig {
+    pub fn from_env() -> Result<Self, String> {
+        let subscription = std::env::var("PUBSUB_SUBSCRIPTION")
+            .map_err(|_| "PUBSUB_SUBSCRIPTION must be set in pubsub mode".to_string())?;
+
+        let max_messages = std::env::var("PUBSUB_MAX_MESSAGES")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .filter(|n: &i32| *n > 0)
+            .unwrap_or(DEFAULT_MAX_MESSAGES);
+
+        let ack_deadline_secs = std::env::var("PUBSUB_ACK_DEADLINE_SECS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .filter(|n: &i32| (10..=600).contains(n))
+            .unwrap_or(DEFAULT_ACK_DEADLINE_SECS);
+
+        Ok(Self {
+            subscription,
+            max_messages,
+            ack_deadline_secs,
+        })
+    }
+}
+
+/// Pull messages forever. Only returns if the Pub/Sub client cannot be created.
+pub async fn run(
+    config: PullWorkerConfig,
+    ctx: Arc<PipelineContext>,
+    limiter: JobLimiter,
+) -> Result<(), String> {
+    let client_config = PubSubClientConfig::default()
+        .with_auth()
+        .await
+        .map_err(|e| format!("Failed to create Pub/Sub client config: {}", e))?;
+    let client = PubSubClient::new(client_config)
+        .await
+        .map_err(|e| format!("Failed to create Pub/Sub client: {}", e))?;
+    let subscription = client.subscription(&config.subscription);
+
+    info!(
+        subscription = %config.subscription,
+        max_messages = config.max_messages,
+        ack_deadline_secs = config.ack_deadline_secs,
+        "Starting Pub/Sub pull worker"
+    );
+
+    loop {
+        let messages = match subscription.pull(config.max_messages, None).await {
+            Ok(m) => m,
+            Err(e) => {
+                error!(error = %e, "Pub/Sub pull failed");
+                tokio::time::sleep(PULL_ERROR_BACKOFF).await;
+                continue;
+            }
+        };
+
+        // Messages are handled one by one: rayon already uses every core per image.
+        for message in messages {
+            let _permit = limiter.acquire().await;
+            handle_message(message, config.ack_deadline_secs, &ctx).await;
+        }
+    }
+}
+
+async fn handle_message(message: ReceivedMessage, ack_deadline_secs: i32, ctx: &PipelineContext) {
+    let message_id = message.message.message_id.clone();
+
+    let event_type = message
+        .message
+        .attributes
+        .get(EVENT_TYPE_ATTRIBUTE)
+        .map(|t| storage_event_type(t))
+        .unwrap_or_default();
+
+    if event_type != GCS_FINALIZED {
+        info!(message_id = %message_id, event_type = %event_type, "Ignoring notification");
+        ack(&message).await;
+        return;
+    }
+
+    let gcs_data: GcsObjectData = match serde_json::from_slice(&message.message.data) {
+        Ok(d) => d,
+        Err(e) => {
+            // Redelivering a malformed payload will never succeed.
+            error!(message_id = %message_id, error = %e, "Failed to parse notification payload");
+            ack(&message).await;
+            return;
+        }
+    };
+
+    // Shared with the extender, which only renews it; the ack stays here
+    let message = Arc::new(message);
+    let extender = spawn_ack_extender(Arc::clone(&message), ack_deadline_secs);
+    let outcome = process_gcs_object(ctx, gcs_data).await;
+    extender.abort();
+
+    match outcome {
+        Outcome::Done(resp) => {
+            info!(message_id = %message_id, status = %resp.status, "Notification handled");
+            ack(&message).await;
+        }
+        Outcome::Retry(resp) => {
+            warn!(
+                message_id = %message_id,
+                status = %resp.status,
+                detail = %resp.message,
+                "Notification failed; leaving it for redelivery"
+            );
+            if let Err(e) = message.nack().await {
+                warn!(message_id = %message_id, error = %e, "Failed to nack message");
+            }
+        }
+    }
+}
+
+/// Keep pushing the ack deadline forward until aborted.
+fn spawn_ack_extender(
+    message: Arc<ReceivedMessage>,
+    ack_deadline_secs: i32,
+) -> tokio::task::JoinHandle<()> {
+    // Renew at half the deadline so a slow renewal call still lands in time.
+    let every = Duration::from_secs((ack_deadline_secs / 2).max(1) as u64);
+
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(every).await;
+            if let Err(e) = message.modify_ack_deadline(ack_deadline_secs).await {
+                warn!(
+                    message_id = %message.message.message_id,
+                    error = %e,
+                    "Failed to extend ack deadline"
+                );
+            }
+        }
+    })
+}
+
+async fn ack(message: &ReceivedMessage) {
+    if let Err(e) = message.ack().await {
+        warn!(message_id = %message.message.message_id, error = %e, "Failed to ack message");
+    }
+}
@@ -0,0 +1,150 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use google_cloud_storage::{
+    client::Client as GcsClient,
+    http::{objects::get::GetObjectRequest, Error as GcsError},
+};
+use serde::Deserialize;
+use tracing::{info, warn};
+
+use crate::limiter::JobLimiter;
+use crate::{process_gcs_object, FunctionResponse, GcsObjectData, Outcome, PipelineContext};
+
+// =============================================================================
+// Manual reprocess trigger
+// =============================================================================
+//
+// `POST /reprocess` with `{"bucket", "name"}` runs the regular pipeline for an
+// object that is already in storage (failed runs, uploads from before the
+// pipeline existed) without re-uploading it. Object metadata is read from GCS,
+// so the request only has to name the object.
+//
+// The endpoint is off unless one of these is configured:
+//
+//   REPROCESS_TOKEN=...                 shared secret, sent as X-Reprocess-Token
+//   REPROCESS_TRUST_PLATFORM_AUTH=true  rely on Cloud Run IAM (OIDC identity token
+//                                       checked before the request reaches us)
+
+const TOKEN_HEADER: &str = "x-reprocess-token";
+
+pub struct ReprocessAuth {
+    token: Option<String>,
+    trust_platform_auth: bool,
+}
+
+impl ReprocessAuth {
+    pub fn from_env() -> Self {
+        Self {
+            token: std::env::var("REPROCESS_TOKEN")
+                .ok()
+                .filter(|s| !s.is_empty()),
+            trust_platform_auth: matches!(
+                std::env::var("REPROCESS_TRUST_PLATFORM_AUTH").as_deref(),
+                Ok("1") | Ok("true")
+            ),
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.token.is_some() || self.trust_platform_auth
+    }
+
+    pub fn allows(&self, req: &HttpRequest) -> bool {
+        match &self.token {
+            Some(expected) => req
+                .headers()
+                .get(TOKEN_HEADER)
+                .and_then(|v| v.to_str().ok())
+                .map(|given| constant_time_eq(given.as_bytes(), expected.as_bytes()))
+                .unwrap_or(false),
+            None => self.trust_platform_auth,
+        }
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[derive(Deserialize)]
+pub struct ReprocessRequest {
+    bucket: String,
+    name: String,
+}
+
+/// Build the pipeline input for an object already in storage, as a finalize
+/// event would have carried it.
+pub async fn stored_object(
+    client: &GcsClient,
+    bucket: &str,
+    name: &str,
+) -> Result<GcsObjectData, GcsError> {
+    let object = client
+        .get_object(&GetObjectRequest {
+            bucket: bucket.to_string(),
+            object: name.to_string(),
+            ..Default::default()
+        })
+        .await?;
+
+    Ok(GcsObjectData {
+        bucket: bucket.to_string(),
+        name: name.to_string(),
+        content_type: object.content_type,
+        size: Some(object.size.to_string()),
+        metadata: object.metadata,
+    })
+}
+
+pub fn reply(status: &str, message: impl Into<String>) -> FunctionResponse {
+    FunctionResponse {
+        status: status.to_string(),
+        message: message.into(),
+        variants_created: None,
+    }
+}
+
+pub async fn handle_reprocess(
+    req: HttpRequest,
+    body: web::Json<ReprocessRequest>,
+    auth: web::Data<ReprocessAuth>,
+    ctx: web::Data<PipelineContext>,
+    limiter: web::Data<JobLimiter>,
+) -> HttpResponse {
+    if !auth.enabled() {
+        return HttpResponse::NotFound().finish();
+    }
+    if !auth.allows(&req) {
+        warn!("Rejected reprocess request: missing or invalid token");
+        return HttpResponse::Unauthorized().json(reply("error", "Unauthorized"));
+    }
+
+    let ReprocessRequest { bucket, name } = body.into_inner();
+
+    let gcs_data = match stored_object(&ctx.gcs, &bucket, &name).await {
+        Ok(data) => data,
+        Err(GcsError::Response(e)) if e.code == 404 => {
+            return HttpResponse::NotFound().json(reply("error", "Object not found"));
+        }
+        Err(e) => {
+            warn!(error = %e, bucket = %bucket, object = %name, "Failed to read object metadata");
+            return HttpResponse::BadGateway()
+                .json(reply("error", format!("Failed to read object: {}", e)));
+        }
+    };
+
+    let _permit = match limiter.try_acquire() {
+        Some(p) => p,
+        None => {
+            return HttpResponse::TooManyRequests()
+                .insert_header(("Retry-After", limiter.retry_after_secs().to_string()))
+                .json(reply("busy", "Too many images in flight, retry later"));
+        }
+    };
+
+    info!(bucket = %bucket, object = %name, "Manual reprocess requested");
+
+    match process_gcs_object(ctx.get_ref(), gcs_data).await {
+        Outcome::Done(resp) => HttpResponse::Ok().json(resp),
+        Outcome::Retry(resp) => HttpResponse::InternalServerError().json(resp),
+    }
+}
@@ -0,0 +1,309 @@
+use fast_image_resize::FilterType;
+use std::collections::{HashMap, HashSet};
+use tracing::warn;
+
+// =============================================================================
+// Pipeline settings (env)
+// =============================================================================
+//
+// Defaults reproduce the original pipeline (bilinear, WebP quality 80 for every
+// variant). Whatever is used ends up in the manifest, so each variant records
+// how it was produced.
+//
+//   RESIZE_FILTER=lanczos3              bilinear | lanczos3 | catmullrom
+//   WEBP_QUALITY=80                     default quality (0-100)
+//   WEBP_QUALITY_OVERRIDES=150=90,1200=75   per-variant quality by size suffix
+//
+// Transparency: by default alpha is preserved everywhere. Variants listed in
+// FLATTEN_VARIANTS, and every variant of uploads whose `role` object metadata
+// is listed in FLATTEN_ROLES, are composited onto FLATTEN_BACKGROUND instead.
+//
+//   FLATTEN_BACKGROUND=#ffffff          background color (default white)
+//   FLATTEN_VARIANTS=150                size suffixes that never keep alpha
+//   FLATTEN_ROLES=avatar                roles (x-goog-meta-role) that never keep alpha
+//
+// Passthrough: with PASSTHROUGH_ORIGINAL=true the validated original (metadata
+// stripped, pixels untouched) is also copied next to the variants, so full-size
+// links don't need access to the private upload bucket. JPEG/PNG/WebP only.
+//
+//   PASSTHROUGH_ORIGINAL=false          true | false
+//
+// Output naming: variant object keys come from a template, so an existing CDN
+// URL scheme can be kept. Placeholders: {media_id}, {stem} (original file name
+// without extension), {width} (size suffix, "150" for the square thumbnail),
+// {format} (webp | png) and {version} (pipeline version). {media_id}, {width}
+// and {format} are required so every output gets its own key. The sanitized
+// SVG and the passthrough copy use the same template with {width} "original"
+// and {format} svg, or the original's extension.
+//
+//   OUTPUT_NAME_TEMPLATE=variants/{media_id}/{stem}_{width}.{format}
+
+const DEFAULT_WEBP_QUALITY: f32 = 80.0;
+const DEFAULT_FLATTEN_BACKGROUND: [u8; 3] = [0xFF, 0xFF, 0xFF];
+const DEFAULT_OUTPUT_NAME_TEMPLATE: &str = "variants/{media_id}/{stem}_{width}.{format}";
+const REQUIRED_PLACEHOLDERS: [&str; 3] = ["{media_id}", "{width}", "{format}"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResizeFilter {
+    Bilinear,
+    Lanczos3,
+    CatmullRom,
+}
+
+impl ResizeFilter {
+    fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "bilinear" => Some(ResizeFilter::Bilinear),
+            "lanczos3" | "lanczos" => Some(ResizeFilter::Lanczos3),
+            "catmullrom" | "catmull-rom" => Some(ResizeFilter::CatmullRom),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResizeFilter::Bilinear => "bilinear",
+            ResizeFilter::Lanczos3 => "lanczos3",
+            ResizeFilter::CatmullRom => "catmullrom",
+        }
+    }
+
+    pub fn filter_type(&self) -> FilterType {
+        match self {
+            ResizeFilter::Bilinear => FilterType::Bilinear,
+            ResizeFilter::Lanczos3 => FilterType::Lanczos3,
+            ResizeFilter::CatmullRom => FilterType::CatmullRom,
+        }
+    }
+}
+
+/// Object key template for variant outputs (`OUTPUT_NAME_TEMPLATE`).
+#[derive(Clone, Debug)]
+pub struct NameTemplate(String);
+
+impl NameTemplate {
+    fn parse(raw: &str) -> Option<Self> {
+        let raw = raw.trim().trim_start_matches('/');
+        REQUIRED_PLACEHOLDERS
+            .iter()
+            .all(|p| raw.contains(p))
+            .then(|| NameTemplate(raw.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn render(
+        &self,
+        media_id: &str,
+        stem: &str,
+        width: &str,
+        format: &str,
+        version: &str,
+    ) -> String {
+        self.0
+            .replace("{media_id}", media_id)
+            .replace("{stem}", stem)
+            .replace("{width}", width)
+            .replace("{format}", format)
+            .replace("{version}", version)
+    }
+
+    /// Key of a full-size output: the sanitized SVG or the passthrough copy.
+    pub fn render_original(
+        &self,
+        media_id: &str,
+        stem: &str,
+        format: &str,
+        version: &str,
+    ) -> String {
+        self.render(media_id, stem, "original", format, version)
+    }
+}
+
+impl Default for NameTemplate {
+    fn default() -> Self {
+        NameTemplate(DEFAULT_OUTPUT_NAME_TEMPLATE.to_string())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PipelineSettings {
+    pub resize_filter: ResizeFilter,
+    pub default_quality: f32,
+    /// Size suffix ("150", "320", ...) -> WebP quality
+    pub quality_overrides: HashMap<String, f32>,
+    pub flatten_background: [u8; 3],
+    /// Size suffixes that are always flattened
+    pub flatten_variants: HashSet<String>,
+    /// Upload roles whose variants are all flattened
+    pub flatten_roles: HashSet<String>,
+    /// Copy the stripped original into the output bucket
+    pub passthrough_original: bool,
+    pub output_names: NameTemplate,
+}
+
+impl Default for PipelineSettings {
+    fn default() -> Self {
+        Self {
+            resize_filter: ResizeFilter::Bilinear,
+            default_quality: DEFAULT_WEBP_QUALITY,
+            quality_overrides: HashMap::new(),
+            flatten_background: DEFAULT_FLATTEN_BACKGROUND,
+            flatten_variants: HashSet::new(),
+            flatten_roles: HashSet::new(),
+            passthrough_original: false,
+            output_names: NameTemplate::default(),
+        }
+    }
+}
+
+impl PipelineSettings {
+    pub fn from_env() -> Self {
+        let mut settings = Self::default();
+
+        if let Ok(raw) = std::env::var("RESIZE_FILTER") {
+            match ResizeFilter::parse(&raw) {
+                Some(f) => settings.resize_filter = f,
+                None => warn!(value = %raw, "Unknown RESIZE_FILTER, using bilinear"),
+            }
+        }
+
+        if let Ok(raw) = std::env::var("WEBP_QUALITY") {
+            match parse_quality(&raw) {
+                Some(q) => settings.default_quality = q,
+                None => warn!(value = %raw, "Invalid WEBP_QUALITY, using default"),
+            }
+        }
+
+        if let Ok(raw) = std::env::var("WEBP_QUALITY_OVERRIDES") {
+            for pair in raw.split(',').filter(|p| !p.trim().is_empty()) {
+                match pair
+                    .split_once('=')
+                    .and_then(|(size, q)| Some((size.trim().to_string(), parse_quality(q)?)))
+                {
+                    Some((size, q)) => {
+                        settings.quality_overrides.insert(size, q);
+                    }
+                    None => warn!(entry = %pair, "Ignoring invalid WEBP_QUALITY_OVERRIDES entry"),
+                }
+            }
+        }
+
+        if let Ok(raw) = std::env::var("FLATTEN_BACKGROUND") {
+            match parse_hex_color(&raw) {
+                Some(rgb) => settings.flatten_background = rgb,
+                None => warn!(value = %raw, "Invalid FLATTEN_BACKGROUND, using white"),
+            }
+        }
+
+        if let Ok(raw) = std::env::var("FLATTEN_VARIANTS") {
+            settings.flatten_variants = parse_list(&raw);
+        }
+
+        if let Ok(raw) = std::env::var("FLATTEN_ROLES") {
+            settings.flatten_roles = parse_list(&raw);
+        }
+
+        if let Ok(raw) = std::env::var("PASSTHROUGH_ORIGINAL") {
+            settings.passthrough_original = matches!(
+                raw.trim().to_ascii_lowercase().as_str(),
+                "1" | "true" | "yes"
+            );
+        }
+
+        if let Ok(raw) = std::env::var("OUTPUT_NAME_TEMPLATE") {
+            match NameTemplate::parse(&raw) {
+                Some(t) => settings.output_names = t,
+                None => warn!(
+                    value = %raw,
+                    "OUTPUT_NAME_TEMPLATE must contain {{media_id}}, {{width}} and {{format}}, using default"
+                ),
+            }
+        }
+
+        settings
+    }
+
+    /// Background to composite onto, or `None` to keep alpha.
+    pub fn flatten_for(&self, suffix: &str, role: Option<&str>) -> Option<[u8; 3]> {
+        let by_role = role
+            .map(|r| self.flatten_roles.contains(&r.to_ascii_lowercase()))
+            .unwrap_or(false);
+
+        (by_role || self.flatten_variants.contains(suffix)).then_some(self.flatten_background)
+    }
+
+    pub fn quality_for(&self, suffix: &str) -> f32 {
+        self.quality_overrides
+            .get(suffix)
+            .copied()
+            .unwrap_or(self.default_quality)
+    }
+}
+
+fn parse_quality(raw: &str) -> Option<f32> {
+    raw.trim()
+        .parse::<f32>()
+        .ok()
+        .filter(|q| (0.0..=100.0).contains(q))
+}
+
+fn parse_list(raw: &str) -> HashSet<String> {
+    raw.split(',')
+        .map(|s| s.trim().to_ascii_lowercase())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// "#rrggbb" or "rrggbb"
+fn parse_hex_color(raw: &str) -> Option<[u8; 3]> {
+    let hex = raw.trim().trim_start_matches('#');
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+/// "#rrggbb" for the manifest.
+pub fn hex_color(rgb: [u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", rgb[0], rgb[1], rgb[2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_template_applies_to_svg_and_passthrough_outputs() {
+        let names = NameTemplate::parse("/cdn/{version}/{media_id}/{width}.{format}").unwrap();
+
+        assert_eq!(
+            names.render("fold-097", "logo", "320", "webp", "v1"),
+            "cdn/v1/fold-097/320.webp"
+        );
+        assert_eq!(
+            names.render_original("fold-097", "logo", "svg", "v1"),
+            "cdn/v1/fold-097/original.svg"
+        );
+        assert_eq!(
+            names.render_original("fold-097", "photo", "jpg", "v1"),
+            "cdn/v1/fold-097/original.jpg"
+        );
+    }
+
+    #[test]
+    fn template_without_required_placeholders_is_rejected() {
+        assert!(NameTemplate::parse("variants/{media_id}/{stem}.{format}").is_none());
+    }
+
+    #[test]
+    fn default_template_names_originals_after_the_stem() {
+        assert_eq!(
+            NameTemplate::default().render_original("fold-097", "photo", "png", "v1"),
+            "variants/fold-097/photo_original.png"
+        );
+    }
+}
@@ -0,0 +1,266 @@
+use google_cloud_storage::{
+    client::Client as GcsClient,
+    http::objects::{
+        delete::DeleteObjectRequest,
+        upload::{Media, UploadObjectRequest, UploadType},
+    },
+};
+use sha2::{Digest, Sha256};
+use std::path::{Component, Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::download::{self, DownloadError, Original};
+
+// =============================================================================
+// Object storage
+// =============================================================================
+//
+// Where the pipeline reads originals from and writes variants and manifests
+// to. That is GCS, except with PROCESSOR_MODE=local, where every bucket is a
+// folder under LOCAL_STORAGE_DIR and an object `{bucket}/{name}` is the file
+// at that path, so the pipeline runs without cloud credentials.
+
+const DEFAULT_LOCAL_STORAGE_DIR: &str = "./local-storage";
+
+/// Appended to a file while it is being written
+const PARTIAL_SUFFIX: &str = ".partial";
+
+#[derive(Clone)]
+pub enum Storage {
+    /// Boxed, a GCS client being much larger than a local store
+    Gcs(Box<GcsClient>),
+    Local(LocalStore),
+}
+
+impl Storage {
+    /// Streams the original, refusing it once it crosses `max_bytes`.
+    /// Returns it with its lowercase hex SHA-256.
+    pub async fn download_bounded(
+        &self,
+        bucket: &str,
+        name: &str,
+        max_bytes: usize,
+    ) -> Result<(Original, String), DownloadError> {
+        match self {
+            Storage::Gcs(client) => {
+                download::download_bounded(
+                    client,
+                    bucket,
+                    name,
+                    max_bytes,
+                    download::spool_threshold_bytes(),
+                )
+                .await
+            }
+            Storage::Local(store) => store.read_bounded(bucket, name, max_bytes).await,
+        }
+    }
+
+    pub async fn upload(
+        &self,
+        bucket: &str,
+        name: &str,
+        data: Vec<u8>,
+        content_type: &str,
+    ) -> Result<(), String> {
+        match self {
+            Storage::Gcs(client) => {
+                let upload_type = UploadType::Simple(Media {
+                    name: name.to_string().into(),
+                    content_type: content_type.to_string().into(),
+                    content_length: Some(data.len() as u64),
+                });
+
+                client
+                    .upload_object(
+                        &UploadObjectRequest {
+                            bucket: bucket.to_string(),
+                            ..Default::default()
+                        },
+                        data,
+                        &upload_type,
+                    )
+                    .await
+                    .map_err(|e| format!("Failed to upload to GCS: {}", e))?;
+
+                Ok(())
+            }
+            Storage::Local(store) => store.write(bucket, name, &data).await,
+        }
+    }
+
+    pub async fn delete(&self, bucket: &str, name: &str) -> Result<(), String> {
+        match self {
+            Storage::Gcs(client) => {
+                let req = DeleteObjectRequest {
+                    bucket: bucket.to_string(),
+                    object: name.to_string(),
+                    ..Default::default()
+                };
+
+                client
+                    .delete_object(&req)
+                    .await
+                    .map_err(|e| format!("Failed to delete from GCS: {}", e))?;
+
+                Ok(())
+            }
+            Storage::Local(store) => store.remove(bucket, name).await,
+        }
+    }
+}
+
+/// A file in a local bucket folder
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalObject {
+    /// Object name: the path inside the bucket folder, `/`-separated
+    pub name: String,
+    pub size: u64,
+    pub modified: SystemTime,
+}
+
+#[derive(Clone)]
+pub struct LocalStore {
+    root: PathBuf,
+}
+
+impl LocalStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(
+            std::env::var("LOCAL_STORAGE_DIR")
+                .ok()
+                .filter(|s| !s.trim().is_empty())
+                .unwrap_or_else(|| DEFAULT_LOCAL_STORAGE_DIR.to_string()),
+        )
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// File of an object, or `None` when the bucket or name would step out of
+    /// its folder (`..`, absolute paths, empty segments).
+    pub fn path(&self, bucket: &str, name: &str) -> Option<PathBuf> {
+        let mut path = self.root.join(safe_segment(bucket)?);
+        for segment in name.split('/') {
+            path.push(safe_segment(segment)?);
+        }
+        Some(path)
+    }
+
+    pub async fn exists(&self, bucket: &str, name: &str) -> bool {
+        match self.path(bucket, name) {
+            Some(path) => tokio::fs::try_exists(path).await.unwrap_or(false),
+            None => false,
+        }
+    }
+
+    async fn read_bounded(
+        &self,
+        bucket: &str,
+        name: &str,
+        max_bytes: usize,
+    ) -> Result<(Original, String), DownloadError> {
+        let path = self
+            .path(bucket, name)
+            .ok_or_else(|| DownloadError::Failed(format!("Invalid object name: {}", name)))?;
+
+        let len = tokio::fs::metadata(&path)
+            .await
+            .map_err(|e| DownloadError::Failed(format!("Failed to read {}: {}", name, e)))?
+            .len();
+        if len > max_bytes as u64 {
+            return Err(DownloadError::TooLarge { max_bytes });
+        }
+
+        let bytes = tokio::fs::read(&path)
+            .await
+            .map_err(|e| DownloadError::Failed(format!("Failed to read {}: {}", name, e)))?;
+        // Written to while we read it
+        if bytes.len() > max_bytes {
+            return Err(DownloadError::TooLarge { max_bytes });
+        }
+
+        let sha256 = hex::encode(Sha256::digest(&bytes));
+        Ok((Original::Memory(bytes), sha256))
+    }
+
+    pub async fn write(&self, bucket: &str, name: &str, data: &[u8]) -> Result<(), String> {
+        let path = self
+            .path(bucket, name)
+            .ok_or_else(|| format!("Invalid object name: {}", name))?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+
+        // Written aside and renamed, so the watcher never sees half a file
+        let mut partial = path.clone().into_os_string();
+        partial.push(PARTIAL_SUFFIX);
+        tokio::fs::write(&partial, data)
+            .await
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        tokio::fs::rename(&partial, &path)
+            .await
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+
+    async fn remove(&self, bucket: &str, name: &str) -> Result<(), String> {
+        let path = self
+            .path(bucket, name)
+            .ok_or_else(|| format!("Invalid object name: {}", name))?;
+        tokio::fs::remove_file(&path)
+            .await
+            .map_err(|e| format!("Failed to delete {}: {}", path.display(), e))
+    }
+
+    /// Every file in a bucket folder, in name order. A missing folder is an
+    /// empty bucket.
+    pub fn list(&self, bucket: &str) -> std::io::Result<Vec<LocalObject>> {
+        let Some(bucket) = safe_segment(bucket) else {
+            return Ok(Vec::new());
+        };
+        let dir = self.root.join(bucket);
+        let mut objects = Vec::new();
+        if dir.is_dir() {
+            collect(&dir, "", &mut objects)?;
+        }
+        objects.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(objects)
+    }
+}
+
+fn safe_segment(segment: &str) -> Option<&str> {
+    let mut components = Path::new(segment).components();
+    match (components.next(), components.next()) {
+        (Some(Component::Normal(_)), None) if !segment.contains('\\') => Some(segment),
+        _ => None,
+    }
+}
+
+fn collect(dir: &Path, prefix: &str, out: &mut Vec<LocalObject>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let Ok(file_name) = entry.file_name().into_string() else {
+            continue;
+        };
+        let name = format!("{prefix}{file_name}");
+        let meta = entry.metadata()?;
+
+        if meta.is_dir() {
+            collect(&entry.path(), &format!("{name}/"), out)?;
+        } else if meta.is_file() && !file_name.ends_with(PARTIAL_SUFFIX) {
+            out.push(LocalObject {
+                name,
+                size: meta.len(),
+                modified: meta.modified()?,
+            });
+        }
+    }
+    Ok(())
+}
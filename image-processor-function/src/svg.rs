@@ -0,0 +1,101 @@
+use image::{DynamicImage, RgbaImage};
+use resvg::{tiny_skia, usvg};
+use std::sync::{Arc, OnceLock};
+
+// =============================================================================
+// SVG sanitization + rasterization
+// =============================================================================
+//
+// Uploaded SVG is never served as-is. It is parsed into usvg's render tree and
+// written back out: the tree has no notion of <script>, event handler
+// attributes, <foreignObject> or external references, so none of them survive
+// the round trip. Only `data:` images are resolved; href strings (file paths,
+// URLs) are refused so an upload can't read from the instance's filesystem.
+//
+// The sanitized markup is stored next to the variants, and the tree is
+// rasterized once at the largest variant width to feed the normal pipeline.
+
+pub struct SanitizedSvg {
+    pub tree: usvg::Tree,
+    /// Re-serialized markup, safe to serve as `image/svg+xml`
+    pub markup: Vec<u8>,
+}
+
+/// Text is converted to paths while parsing, which needs fonts; scanning the
+/// system fonts is slow, so it happens once per process.
+fn fonts() -> Arc<usvg::fontdb::Database> {
+    static FONTS: OnceLock<Arc<usvg::fontdb::Database>> = OnceLock::new();
+    FONTS
+        .get_or_init(|| {
+            let mut db = usvg::fontdb::Database::new();
+            db.load_system_fonts();
+            Arc::new(db)
+        })
+        .clone()
+}
+
+fn options() -> usvg::Options<'static> {
+    usvg::Options {
+        image_href_resolver: usvg::ImageHrefResolver {
+            resolve_data: usvg::ImageHrefResolver::default_data_resolver(),
+            resolve_string: Box::new(|_, _| None),
+        },
+        fontdb: fonts(),
+        ..Default::default()
+    }
+}
+
+pub fn sanitize(bytes: &[u8]) -> Result<SanitizedSvg, String> {
+    let tree = usvg::Tree::from_data(bytes, &options()).map_err(|e| format!("Invalid SVG: {e}"))?;
+    let markup = tree.to_string(&usvg::WriteOptions::default()).into_bytes();
+
+    Ok(SanitizedSvg { tree, markup })
+}
+
+/// Pixel size of a raster `width` pixels wide, keeping the SVG's aspect ratio.
+pub fn raster_size(tree: &usvg::Tree, width: u32) -> (u32, u32) {
+    let size = tree.size();
+    let height = (size.height() * width as f32 / size.width()).round() as u32;
+    (width, height.max(1))
+}
+
+pub fn rasterize(tree: &usvg::Tree, width: u32) -> Result<DynamicImage, String> {
+    let (w, h) = raster_size(tree, width);
+    let scale = w as f32 / tree.size().width();
+
+    let mut pixmap =
+        tiny_skia::Pixmap::new(w, h).ok_or_else(|| format!("Failed to allocate {w}x{h} raster"))?;
+    resvg::render(
+        tree,
+        tiny_skia::Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    // tiny-skia stores premultiplied alpha
+    let rgba: Vec<u8> = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|px| {
+            let c = px.demultiply();
+            [c.red(), c.green(), c.blue(), c.alpha()]
+        })
+        .collect();
+
+    RgbaImage::from_raw(w, h, rgba)
+        .map(DynamicImage::ImageRgba8)
+        .ok_or_else(|| "Rasterized buffer has unexpected size".to_string())
+}
+
+/// Loose sniff for markup; `sanitize` is the authoritative check.
+pub fn looks_like_svg(head: &[u8]) -> bool {
+    let head = head.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(head);
+    let start = head
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(head.len());
+    let head = &head[start..];
+
+    [&b"<svg"[..], b"<?xml", b"<!--", b"<!DOCTYPE svg"]
+        .iter()
+        .any(|prefix| head.starts_with(prefix))
+}
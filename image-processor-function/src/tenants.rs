@@ -0,0 +1,152 @@
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use tracing::warn;
+
+// =============================================================================
+// Multi-tenant bucket routing
+// =============================================================================
+//
+// One processor instance can serve several sites. Each tenant gets its own
+// output and manifest buckets; objects that match no tenant keep using
+// OUTPUT_BUCKET / MANIFEST_BUCKET.
+//
+// The tenant of an upload is taken from its `tenant` object metadata
+// (x-goog-meta-tenant) or, failing that, from the first route whose `prefix`
+// the object name starts with. The prefix is not part of the media id:
+// "acme/fold-097/photo.jpg" under prefix "acme/" is media "fold-097".
+//
+//   TENANT_ROUTES='{
+//     "acme": {"output_bucket": "acme-ready", "manifest_bucket": "acme-manifests", "prefix": "acme/"},
+//     "beta": {"output_bucket": "beta-ready", "manifest_bucket": "beta-manifests"}
+//   }'
+//
+// An upload that names a tenant with no route is rejected rather than written
+// to the default buckets, so one site's media never lands in another's.
+
+const TENANT_METADATA_KEY: &str = "tenant";
+
+#[derive(Debug, Deserialize)]
+struct TenantRoute {
+    output_bucket: String,
+    manifest_bucket: String,
+    #[serde(default)]
+    prefix: Option<String>,
+}
+
+/// Where the outputs of one object go.
+#[derive(Debug)]
+pub struct Destination<'a> {
+    pub tenant: Option<&'a str>,
+    pub output_bucket: &'a str,
+    pub manifest_bucket: &'a str,
+    /// Object name with the tenant prefix removed (media id source)
+    pub key: &'a str,
+}
+
+pub struct TenantRouter {
+    default_output: String,
+    default_manifest: String,
+    /// Ordered by name so prefix matching is deterministic
+    routes: BTreeMap<String, TenantRoute>,
+}
+
+impl TenantRouter {
+    pub fn from_env(default_output: String, default_manifest: String) -> Self {
+        let routes = match std::env::var("TENANT_ROUTES") {
+            Ok(raw) if !raw.trim().is_empty() => {
+                match serde_json::from_str::<BTreeMap<String, TenantRoute>>(&raw) {
+                    Ok(routes) => routes,
+                    Err(e) => {
+                        warn!(error = %e, "Invalid TENANT_ROUTES, routing everything to the default buckets");
+                        BTreeMap::new()
+                    }
+                }
+            }
+            _ => BTreeMap::new(),
+        };
+
+        Self {
+            default_output,
+            default_manifest,
+            routes,
+        }
+    }
+
+    pub fn tenant_count(&self) -> usize {
+        self.routes.len()
+    }
+
+    pub fn default_output(&self) -> &str {
+        &self.default_output
+    }
+
+    pub fn default_manifest(&self) -> &str {
+        &self.default_manifest
+    }
+
+    /// Resolve the destination of an object. `Err` carries the unknown tenant
+    /// named in the object metadata.
+    pub fn resolve<'a>(
+        &'a self,
+        name: &'a str,
+        metadata: Option<&'a HashMap<String, String>>,
+    ) -> Result<Destination<'a>, String> {
+        let by_metadata = metadata
+            .and_then(|m| m.get(TENANT_METADATA_KEY))
+            .map(|t| t.trim())
+            .filter(|t| !t.is_empty());
+
+        if let Some(tenant) = by_metadata {
+            let (id, route) = self
+                .routes
+                .get_key_value(tenant)
+                .ok_or_else(|| tenant.to_string())?;
+            let key = route
+                .prefix
+                .as_deref()
+                .and_then(|p| name.strip_prefix(p))
+                .unwrap_or(name);
+            return Ok(self.destination(Some((id, route)), key));
+        }
+
+        for (id, route) in &self.routes {
+            if let Some(key) = route.prefix.as_deref().and_then(|p| name.strip_prefix(p)) {
+                return Ok(self.destination(Some((id, route)), key));
+            }
+        }
+
+        Ok(self.destination(None, name))
+    }
+
+    fn destination<'a>(
+        &'a self,
+        route: Option<(&'a String, &'a TenantRoute)>,
+        key: &'a str,
+    ) -> Destination<'a> {
+        match route {
+            Some((id, route)) => Destination {
+                tenant: Some(id.as_str()),
+                output_bucket: &route.output_bucket,
+                manifest_bucket: &route.manifest_bucket,
+                key,
+            },
+            None => Destination {
+                tenant: None,
+                output_bucket: &self.default_output,
+                manifest_bucket: &self.default_manifest,
+                key,
+            },
+        }
+    }
+
+    /// Every manifest bucket in use, default first, without duplicates.
+    pub fn manifest_buckets(&self) -> Vec<&str> {
+        let mut buckets = vec![self.default_manifest.as_str()];
+        for route in self.routes.values() {
+            if !buckets.contains(&route.manifest_bucket.as_str()) {
+                buckets.push(&route.manifest_bucket);
+            }
+        }
+        buckets
+    }
+}
@@ -5,8 +5,9 @@ const { Pool } = require("pg");
 
 const storage = new Storage();
 
-// Bucket configuration - variants are stored separately from uploads
-const READY_BUCKET = "blogport-cms-ready";
+// Bucket configuration - variants are stored separately from uploads.
+// Manifests name the bucket they were written to; this is for older ones.
+const DEFAULT_READY_BUCKET = process.env.READY_BUCKET || "blogport-cms-ready";
 
 // Initialize PostgreSQL connection pool
 const pool = new Pool({
@@ -55,18 +56,30 @@ function getMimeTypeFromPath(path) {
   return mimeTypes[ext] || "application/octet-stream";
 }
 
+/**
+ * Lowercase hex SHA-256 from the manifest, or null when absent or malformed
+ */
+function normalizeChecksum(sha256) {
+  if (typeof sha256 !== "string") {
+    return null;
+  }
+  const checksum = sha256.trim().toLowerCase();
+  return /^[0-9a-f]{64}$/.test(checksum) ? checksum : null;
+}
+
 /**
  * Insert media variants with conflict resolution
  * All data comes from the manifest - no GCS calls needed
  */
-async function insertVariants(client, mediaId, variantsData) {
+async function insertVariants(client, mediaId, variantsData, outputBucket) {
   if (!variantsData || variantsData.length === 0) {
     console.log("No variants to insert");
     return;
   }
 
+  const bucketName = outputBucket || DEFAULT_READY_BUCKET;
   console.log(
-    `Inserting ${variantsData.length} variants (bucket: ${READY_BUCKET})`,
+    `Inserting ${variantsData.length} variants (bucket: ${bucketName})`,
   );
 
   for (const variant of variantsData) {
@@ -85,9 +98,10 @@ async function insertVariants(client, mediaId, variantsData) {
           mime_type,
           file_size_bytes,
           width,
-          height
+          height,
+          checksum_sha256
         )
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
         ON CONFLICT (media_id, variant_type)
         DO UPDATE SET
           bucket_name = EXCLUDED.bucket_name,
@@ -96,6 +110,7 @@ async function insertVariants(client, mediaId, variantsData) {
           file_size_bytes = EXCLUDED.file_size_bytes,
           width = EXCLUDED.width,
           height = EXCLUDED.height,
+          checksum_sha256 = EXCLUDED.checksum_sha256,
           created_at = CASE
             WHEN media_variants.bucket_name = EXCLUDED.bucket_name
               AND media_variants.object_key = EXCLUDED.object_key
@@ -107,12 +122,13 @@ async function insertVariants(client, mediaId, variantsData) {
         [
           mediaId,
           variantType,
-          READY_BUCKET,
+          bucketName,
           variant.path,
           mimeType,
           variant.file_size_bytes || 0, // From manifest
           variant.width, // From manifest
           variant.height, // From manifest
+          normalizeChecksum(variant.sha256), // From manifest
         ],
       );
 
@@ -154,7 +170,7 @@ functions.cloudEvent("updateMediaRecord", async (cloudEvent) => {
       .download();
     const manifest = JSON.parse(fileBuffer.toString());
 
-    const { media_id, state, updated_at, variants } = manifest;
+    const { media_id, state, updated_at, variants, output_bucket } = manifest;
 
     // Validate required fields
     if (!media_id || !state || !updated_at) {
@@ -186,6 +202,45 @@ functions.cloudEvent("updateMediaRecord", async (cloudEvent) => {
     await client.query("BEGIN");
 
     try {
+      // A failure is not final yet: file it for the backend's retry job,
+      // which sends transient ones back to the processor and marks the
+      // rest failed. A later retry's failure replaces the entry.
+      if (state === "failed") {
+        const failureCode =
+          (manifest.error && manifest.error.code) || "INTERNAL_ERROR";
+        const queued = await client.query(
+          `
+          INSERT INTO media_retries (media_id, failure_code, failed_at)
+          SELECT id, $2, $3
+          FROM media
+          WHERE
+            id = $1
+            AND deleted_at IS NULL
+            AND status NOT IN ('ready', 'failed')
+            AND updated_at <= $3
+          ON CONFLICT (media_id)
+          DO UPDATE SET
+            failure_code = EXCLUDED.failure_code,
+            failed_at = EXCLUDED.failed_at
+          `,
+          [media_id, failureCode, updated_at],
+        );
+
+        if (queued.rowCount === 0) {
+          console.log(
+            `Media ID ${media_id} is missing, deleted, settled or newer, skipping failure`,
+          );
+        } else {
+          console.log(
+            `✓ Queued failure '${failureCode}' of media ${media_id} for retry`,
+          );
+        }
+
+        await client.query("COMMIT");
+        console.log("--------------------------");
+        return;
+      }
+
       // Update media status (idempotent with ordering protection)
       const result = await client.query(
         `
@@ -230,7 +285,7 @@ functions.cloudEvent("updateMediaRecord", async (cloudEvent) => {
             console.log(
               "Terminal state reached, but inserting/updating variants...",
             );
-            await insertVariants(client, media_id, variants);
+            await insertVariants(client, media_id, variants, output_bucket);
           }
         } else if (new Date(current.updated_at) > new Date(updated_at)) {
           console.log(
@@ -250,7 +305,14 @@ functions.cloudEvent("updateMediaRecord", async (cloudEvent) => {
 
       // If state is 'ready', insert variants
       if (state === "ready" && variants && variants.length > 0) {
-        await insertVariants(client, media_id, variants);
+        await insertVariants(client, media_id, variants, output_bucket);
+      }
+
+      // A retry that succeeded no longer needs its queue entry
+      if (state === "ready") {
+        await client.query(`DELETE FROM media_retries WHERE media_id = $1`, [
+          media_id,
+        ]);
       }
 
       await client.query("COMMIT");